@@ -9,6 +9,7 @@ use url::Url;
 use uv_once_map::OnceMap;
 
 use crate::credentials::{Credentials, Username};
+use crate::secret::SecretCredentials;
 use crate::Realm;
 
 type FxOnceMap<K, V> = OnceMap<K, V, BuildHasherDefault<FxHasher>>;
@@ -19,7 +20,11 @@ pub struct CredentialsCache {
     /// A cache tracking the result of fetches from external services
     pub(crate) fetches: FxOnceMap<(Realm, Username), Option<Arc<Credentials>>>,
     /// A cache per URL, uses a trie for efficient prefix queries.
-    urls: RwLock<UrlTrie>,
+    urls: RwLock<UrlTrie<Arc<Credentials>>>,
+    /// Credentials per URL that are provided as secrets, to be resolved at request time.
+    secrets: RwLock<UrlTrie<Arc<SecretCredentials>>>,
+    /// A cache tracking the result of resolving secrets, per URL
+    pub(crate) resolutions: FxOnceMap<Url, Result<Arc<Credentials>, String>>,
 }
 
 impl Default for CredentialsCache {
@@ -35,6 +40,8 @@ impl CredentialsCache {
             fetches: FxOnceMap::default(),
             realms: RwLock::new(FxHashMap::default()),
             urls: RwLock::new(UrlTrie::new()),
+            secrets: RwLock::new(UrlTrie::new()),
+            resolutions: FxOnceMap::default(),
         }
     }
 
@@ -109,6 +116,19 @@ impl CredentialsCache {
         urls.insert(url, credentials);
     }
 
+    /// Return the secret credentials that apply to a URL, if any.
+    pub(crate) fn get_secrets(&self, url: &Url) -> Option<Arc<SecretCredentials>> {
+        let secrets = self.secrets.read().unwrap();
+        secrets.get(url).cloned()
+    }
+
+    /// Register credentials for a URL that are provided as secrets, to be resolved on first use.
+    pub(crate) fn insert_secrets(&self, secrets: SecretCredentials) {
+        let url = secrets.url().clone();
+        let mut trie = self.secrets.write().unwrap();
+        trie.insert(&url, Arc::new(secrets));
+    }
+
     /// Private interface to update a realm cache entry.
     ///
     /// Returns replaced credentials, if any.
@@ -142,24 +162,33 @@ impl CredentialsCache {
 }
 
 #[derive(Debug)]
-struct UrlTrie {
-    states: Vec<TrieState>,
+struct UrlTrie<T> {
+    states: Vec<TrieState<T>>,
 }
 
-#[derive(Debug, Default)]
-struct TrieState {
+#[derive(Debug)]
+struct TrieState<T> {
     children: Vec<(String, usize)>,
-    value: Option<Arc<Credentials>>,
+    value: Option<T>,
+}
+
+impl<T> Default for TrieState<T> {
+    fn default() -> Self {
+        Self {
+            children: Vec::new(),
+            value: None,
+        }
+    }
 }
 
-impl UrlTrie {
-    fn new() -> UrlTrie {
+impl<T> UrlTrie<T> {
+    fn new() -> UrlTrie<T> {
         let mut trie = UrlTrie { states: vec![] };
         trie.alloc();
         trie
     }
 
-    fn get(&self, url: &Url) -> Option<&Arc<Credentials>> {
+    fn get(&self, url: &Url) -> Option<&T> {
         let mut state = 0;
         let realm = Realm::from(url).to_string();
         for component in [realm.as_str()]
@@ -174,7 +203,7 @@ impl UrlTrie {
         self.states[state].value.as_ref()
     }
 
-    fn insert(&mut self, url: &Url, value: Arc<Credentials>) {
+    fn insert(&mut self, url: &Url, value: T) {
         let mut state = 0;
         let realm = Realm::from(url).to_string();
         for component in [realm.as_str()]
@@ -202,7 +231,7 @@ impl UrlTrie {
     }
 }
 
-impl TrieState {
+impl<T> TrieState<T> {
     fn get(&self, component: &str) -> Option<usize> {
         let i = self.index(component).ok()?;
         Some(self.children[i].1)
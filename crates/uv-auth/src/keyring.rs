@@ -1,4 +1,6 @@
+use std::io;
 use std::process::Stdio;
+use tokio::io::AsyncWriteExt;
 use tokio::process::Command;
 use tracing::{instrument, trace, warn};
 use url::Url;
@@ -35,7 +37,7 @@ impl KeyringProvider {
     /// Returns [`None`] if no password was found for the username or if any errors
     /// are encountered in the keyring backend.
     #[instrument(skip_all, fields(url = % url.to_string(), username))]
-    pub(crate) async fn fetch(&self, url: &Url, username: &str) -> Option<Credentials> {
+    pub async fn fetch(&self, url: &Url, username: &str) -> Option<Credentials> {
         // Validate the request
         debug_assert!(
             url.host_str().is_some(),
@@ -82,6 +84,67 @@ impl KeyringProvider {
         password.map(|password| Credentials::new(Some(username.to_string()), Some(password)))
     }
 
+    /// Store the password for the given [`Url`] and username in the keyring.
+    #[instrument(skip_all, fields(url = % url.to_string(), username))]
+    pub async fn store(&self, url: &Url, username: &str, password: &str) -> io::Result<()> {
+        match self.backend {
+            KeyringProviderBackend::Subprocess => {
+                let mut child = Command::new("keyring")
+                    .arg("set")
+                    .arg(url.as_str())
+                    .arg(username)
+                    .stdin(Stdio::piped())
+                    .stdout(Stdio::null())
+                    .stderr(Stdio::inherit())
+                    .spawn()?;
+
+                // `keyring set` reads the password from `stdin`.
+                if let Some(mut stdin) = child.stdin.take() {
+                    stdin.write_all(password.as_bytes()).await?;
+                    stdin.write_all(b"\n").await?;
+                }
+
+                let status = child.wait().await?;
+                if status.success() {
+                    Ok(())
+                } else {
+                    Err(io::Error::other(format!(
+                        "`keyring set` failed with {status}"
+                    )))
+                }
+            }
+            #[cfg(test)]
+            KeyringProviderBackend::Dummy(_) => Ok(()),
+        }
+    }
+
+    /// Remove the password for the given [`Url`] and username from the keyring.
+    #[instrument(skip_all, fields(url = % url.to_string(), username))]
+    pub async fn remove(&self, url: &Url, username: &str) -> io::Result<()> {
+        match self.backend {
+            KeyringProviderBackend::Subprocess => {
+                let status = Command::new("keyring")
+                    .arg("del")
+                    .arg(url.as_str())
+                    .arg(username)
+                    .stdin(Stdio::null())
+                    .stdout(Stdio::null())
+                    .stderr(Stdio::inherit())
+                    .status()
+                    .await?;
+                if status.success() {
+                    Ok(())
+                } else {
+                    Err(io::Error::other(format!(
+                        "`keyring del` failed with {status}"
+                    )))
+                }
+            }
+            #[cfg(test)]
+            KeyringProviderBackend::Dummy(_) => Ok(()),
+        }
+    }
+
     #[instrument(skip(self))]
     async fn fetch_subprocess(&self, service_name: &str, username: &str) -> Option<String> {
         // https://github.com/pypa/pip/blob/24.0/src/pip/_internal/network/auth.py#L136-L141
@@ -1,4 +1,4 @@
-use std::sync::{Arc, LazyLock};
+use std::sync::{Arc, LazyLock, OnceLock};
 
 use tracing::trace;
 use url::Url;
@@ -8,12 +8,17 @@ pub use credentials::Credentials;
 pub use keyring::KeyringProvider;
 pub use middleware::AuthMiddleware;
 use realm::Realm;
+pub use secret::{Secret, SecretCredentials, SecretError};
+pub use store::{CredentialStore, StoreError, StoredCredentials};
 
 mod cache;
 mod credentials;
 mod keyring;
 mod middleware;
+mod providers;
 mod realm;
+mod secret;
+mod store;
 
 // TODO(zanieb): Consider passing a cache explicitly throughout
 
@@ -23,6 +28,12 @@ mod realm;
 pub(crate) static CREDENTIALS_CACHE: LazyLock<CredentialsCache> =
     LazyLock::new(CredentialsCache::default);
 
+/// The personal access token for Azure Artifacts feeds, as provided via the `azure-artifacts-pat`
+/// setting.
+///
+/// `UV_AZURE_ARTIFACTS_PAT` takes precedence.
+pub static AZURE_ARTIFACTS_PAT: OnceLock<Secret> = OnceLock::new();
+
 /// Populate the global authentication store with credentials on a URL, if there are any.
 ///
 /// Returns `true` if the store was updated.
@@ -43,3 +54,11 @@ pub fn store_credentials(url: &Url, credentials: Credentials) {
     trace!("Caching credentials for {url}");
     CREDENTIALS_CACHE.insert(url, Arc::new(credentials));
 }
+
+/// Populate the global authentication store with credentials that are provided as secrets.
+///
+/// The secrets are resolved when the credentials are first needed by a request.
+pub fn store_secret_credentials(secrets: SecretCredentials) {
+    trace!("Caching secret credentials for {}", secrets.url());
+    CREDENTIALS_CACHE.insert_secrets(secrets);
+}
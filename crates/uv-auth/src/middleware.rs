@@ -5,8 +5,9 @@ use url::Url;
 
 use crate::{
     credentials::{Credentials, Username},
+    providers::TokenProvider,
     realm::Realm,
-    CredentialsCache, KeyringProvider, CREDENTIALS_CACHE,
+    CredentialStore, CredentialsCache, KeyringProvider, CREDENTIALS_CACHE,
 };
 use anyhow::{anyhow, format_err};
 use netrc::Netrc;
@@ -48,14 +49,67 @@ impl NetrcMode {
     }
 }
 
+/// Strategy for loading the credential store managed by `uv auth`.
+enum StoreMode {
+    Automatic(LazyLock<Option<CredentialStore>>),
+    Enabled(CredentialStore),
+    Disabled,
+}
+
+impl Default for StoreMode {
+    fn default() -> Self {
+        StoreMode::Automatic(LazyLock::new(|| {
+            let path = match CredentialStore::default_path() {
+                Ok(path) => path,
+                Err(err) => {
+                    warn!("Failed to determine the path to the credentials file: {err}");
+                    return None;
+                }
+            };
+            match CredentialStore::read(&path) {
+                Ok(store) if store.is_empty() => None,
+                Ok(store) => Some(store),
+                Err(err) => {
+                    warn!("Error reading credentials file: {err}");
+                    None
+                }
+            }
+        }))
+    }
+}
+
+impl StoreMode {
+    /// Get the credential store if enabled.
+    fn get(&self) -> Option<&CredentialStore> {
+        match self {
+            StoreMode::Automatic(lock) => lock.as_ref(),
+            StoreMode::Enabled(store) => Some(store),
+            StoreMode::Disabled => None,
+        }
+    }
+}
+
 /// A middleware that adds basic authentication to requests.
 ///
 /// Uses a cache to propagate credentials from previously seen requests and
-/// fetches credentials from a netrc file and the keyring.
+/// fetches credentials from the `uv auth` credential store, a netrc file, and the keyring.
+/// Credentials that were registered as secrets (e.g., `env:NAME` or `file:PATH`) are resolved
+/// before the first request to their URL.
+///
+/// Requests to registries with a built-in token provider (e.g., AWS CodeArtifact or Google
+/// Artifact Registry) are authenticated with a short-lived token, which is refreshed as it expires,
+/// unless credentials for the URL are available from the credential store, netrc file, or
+/// keyring. Token providers are only consulted if a client was provided via
+/// [`AuthMiddleware::with_provider_client`].
+/// Registries that support interactive sign-in (e.g., Azure Artifacts) prompt the user to sign in
+/// if no other credentials are found.
 pub struct AuthMiddleware {
     netrc: NetrcMode,
+    store: StoreMode,
     keyring: Option<KeyringProvider>,
     cache: Option<CredentialsCache>,
+    /// The client used to request tokens from built-in token providers.
+    provider_client: Option<reqwest::Client>,
     /// We know that the endpoint needs authentication, so we don't try to send an unauthenticated
     /// request, avoiding cloning an uncloneable request.
     only_authenticated: bool,
@@ -65,8 +119,10 @@ impl AuthMiddleware {
     pub fn new() -> Self {
         Self {
             netrc: NetrcMode::default(),
+            store: StoreMode::default(),
             keyring: None,
             cache: None,
+            provider_client: None,
             only_authenticated: false,
         }
     }
@@ -84,6 +140,19 @@ impl AuthMiddleware {
         self
     }
 
+    /// Configure the [`CredentialStore`] to use.
+    ///
+    /// `None` disables authentication via the credential store.
+    #[must_use]
+    pub fn with_store(mut self, store: Option<CredentialStore>) -> Self {
+        self.store = if let Some(store) = store {
+            StoreMode::Enabled(store)
+        } else {
+            StoreMode::Disabled
+        };
+        self
+    }
+
     /// Configure the [`KeyringProvider`] to use.
     #[must_use]
     pub fn with_keyring(mut self, keyring: Option<KeyringProvider>) -> Self {
@@ -98,6 +167,17 @@ impl AuthMiddleware {
         self
     }
 
+    /// Configure the client used to request tokens from built-in token providers (e.g., Google
+    /// Artifact Registry).
+    ///
+    /// The client should share the TLS, proxy, and timeout settings of the client wrapped by this
+    /// middleware. `None` disables built-in token providers.
+    #[must_use]
+    pub fn with_provider_client(mut self, client: Option<reqwest::Client>) -> Self {
+        self.provider_client = client;
+        self
+    }
+
     /// We know that the endpoint needs authentication, so we don't try to send an unauthenticated
     /// request, avoiding cloning an uncloneable request.
     #[must_use]
@@ -138,6 +218,7 @@ impl Middleware for AuthMiddleware {
     /// The discovered credentials must have the requested username to be used.
     ///
     /// - Check the cache (realm key) for a password
+    /// - Check the credential store for a password
     /// - Check the netrc for a password
     /// - Check the keyring for a password
     /// - Perform the request
@@ -155,6 +236,7 @@ impl Middleware for AuthMiddleware {
     /// - Perform the request
     /// - On 401, 403, or 404 check for authentication if there was a cache miss
     ///     - Check the cache (realm key) for the username and password
+    ///     - Check the credential store for a username and password
     ///     - Check the netrc for a username and password
     ///     - Perform the request again if found
     ///     - Add the username and password to the cache if successful
@@ -172,6 +254,44 @@ impl Middleware for AuthMiddleware {
         let url = tracing_url(&request, credentials.as_ref());
         trace!("Handling request for {url}");
 
+        // If credentials were provided as secrets for the URL, resolve them, such that they're
+        // available in the cache below.
+        if credentials
+            .as_ref()
+            .map_or(true, |credentials| credentials.password().is_none())
+        {
+            self.resolve_secrets(request.url()).await?;
+        }
+
+        // If no credentials were provided (or configured via the credential store or netrc) for a
+        // registry with a built-in token provider, use a token from the provider. If a keyring is
+        // configured, it's consulted first, and the provider is only used if the keyring has no
+        // credentials for the URL (see below).
+        if credentials.is_none()
+            && self.keyring.is_none()
+            && self
+                .cache()
+                .get_url(request.url(), &Username::none())
+                .is_none()
+            && !self.has_configured_credentials(request.url())
+        {
+            if let Some((provider, client)) = self.token_provider(request.url()) {
+                if let Some(credentials) = provider.credentials(client, None).await {
+                    trace!("Authenticating request for {url} with token from {provider:?}");
+                    return self
+                        .complete_token_request(
+                            &provider,
+                            client,
+                            credentials,
+                            request,
+                            extensions,
+                            next,
+                        )
+                        .await;
+                }
+            }
+        }
+
         if let Some(credentials) = credentials {
             let credentials = Arc::new(credentials);
 
@@ -312,6 +432,25 @@ impl Middleware for AuthMiddleware {
                 .await;
         }
 
+        // If the keyring had no credentials, fall back to a built-in token provider.
+        if self.keyring.is_some() && !attempt_has_username {
+            if let Some((provider, client)) = self.token_provider(retry_request.url()) {
+                if let Some(credentials) = provider.credentials(client, None).await {
+                    trace!("Retrying request for {url} with token from {provider:?}");
+                    return self
+                        .complete_token_request(
+                            &provider,
+                            client,
+                            credentials,
+                            retry_request,
+                            extensions,
+                            next,
+                        )
+                        .await;
+                }
+            }
+        }
+
         if let Some(credentials) = credentials.as_ref() {
             if !attempt_has_username {
                 trace!("Retrying request for {url} with username from cache {credentials:?}");
@@ -363,9 +502,109 @@ impl AuthMiddleware {
         result
     }
 
+    /// Returns `true` if the credential store or netrc file contain credentials for the URL.
+    ///
+    /// Such credentials take precedence over those from a built-in [`TokenProvider`], which are
+    /// otherwise attached to the initial request.
+    fn has_configured_credentials(&self, url: &Url) -> bool {
+        self.store
+            .get()
+            .is_some_and(|store| store.get(url).is_some())
+            || self
+                .netrc
+                .get()
+                .is_some_and(|netrc| Credentials::from_netrc(netrc, url, None).is_some())
+    }
+
+    /// Return the built-in [`TokenProvider`] for the URL, along with the client with which to
+    /// request tokens, if any.
+    fn token_provider(&self, url: &Url) -> Option<(TokenProvider, &reqwest::Client)> {
+        let client = self.provider_client.as_ref()?;
+        let provider = TokenProvider::from_url(url)?;
+        Some((provider, client))
+    }
+
+    /// Run a request authenticated with a token from a [`TokenProvider`] to completion.
+    ///
+    /// If the token is rejected (e.g., because it expired mid-invocation), a new token is
+    /// fetched and the request is retried once.
+    async fn complete_token_request(
+        &self,
+        provider: &TokenProvider,
+        client: &reqwest::Client,
+        credentials: Arc<Credentials>,
+        request: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let retry_request = request.try_clone();
+        let response = next
+            .clone()
+            .run(credentials.authenticate(request), extensions)
+            .await?;
+
+        if !matches!(
+            response.status(),
+            StatusCode::FORBIDDEN | StatusCode::UNAUTHORIZED
+        ) {
+            return Ok(response);
+        }
+        let Some(retry_request) = retry_request else {
+            return Ok(response);
+        };
+        let Some(refreshed) = provider.credentials(client, Some(&credentials)).await else {
+            return Ok(response);
+        };
+
+        trace!(
+            "Retrying request for {} with refreshed token",
+            retry_request.url()
+        );
+        next.run(refreshed.authenticate(retry_request), extensions)
+            .await
+    }
+
+    /// Resolve the credentials provided as secrets for a URL, if any, and add them to the cache.
+    ///
+    /// Secrets are only resolved _once_ per URL. All other requests for the same URL will wait
+    /// until the first resolution completes.
+    async fn resolve_secrets(&self, url: &Url) -> reqwest_middleware::Result<()> {
+        let Some(secrets) = self.cache().get_secrets(url) else {
+            return Ok(());
+        };
+        let key = secrets.url().clone();
+
+        let result = if self.cache().resolutions.register(key.clone()) {
+            debug!("Resolving secret credentials for {key}");
+            let result = secrets
+                .resolve()
+                .await
+                .map(Arc::new)
+                .map_err(|err| err.to_string());
+            if let Ok(credentials) = result.as_ref() {
+                self.cache().insert(&key, credentials.clone());
+            }
+            self.cache().resolutions.done(key.clone(), result.clone());
+            result
+        } else {
+            self.cache()
+                .resolutions
+                .wait(&key)
+                .await
+                .expect("The key must exist after register is called")
+        };
+
+        result.map(|_| ()).map_err(|err| {
+            Error::Middleware(format_err!(
+                "Failed to resolve credentials for {key}: {err}"
+            ))
+        })
+    }
+
     /// Fetch credentials for a URL.
     ///
-    /// Supports netrc file and keyring lookups.
+    /// Supports credential store, netrc file, and keyring lookups, along with interactive sign-in
+    /// for supported registries.
     async fn fetch_credentials(
         &self,
         credentials: Option<&Credentials>,
@@ -398,8 +637,42 @@ impl AuthMiddleware {
             return credentials;
         }
 
+        // Credentials stored via `uv auth` take precedence.
+        let stored = self.store.get().and_then(|store| {
+            debug!("Checking credential store for credentials for {url}");
+            store.get(url).filter(|stored| {
+                credentials
+                    .and_then(|credentials| credentials.username())
+                    .map_or(true, |username| username == stored.username())
+            })
+        });
+
+        let credentials = if let Some(stored) = stored {
+            if stored.password().is_some() {
+                debug!("Found credentials in credential store for {url}");
+                Some(stored.to_credentials())
+            } else {
+                // The password was stored in the keyring, so use it even if a keyring provider
+                // wasn't requested.
+                debug!(
+                    "Checking keyring for credentials for {}@{url} from credential store",
+                    stored.username()
+                );
+                let credentials = match self.keyring {
+                    Some(ref keyring) => keyring.fetch(stored.url(), stored.username()).await,
+                    None => {
+                        KeyringProvider::subprocess()
+                            .fetch(stored.url(), stored.username())
+                            .await
+                    }
+                };
+                if credentials.is_some() {
+                    debug!("Found credentials in keyring for {url}");
+                }
+                credentials
+            }
         // Netrc support based on: <https://github.com/gribouille/netrc>.
-        let credentials = if let Some(credentials) = self.netrc.get().and_then(|netrc| {
+        } else if let Some(credentials) = self.netrc.get().and_then(|netrc| {
             debug!("Checking netrc for credentials for {url}");
             Credentials::from_netrc(
                 netrc,
@@ -429,6 +702,9 @@ impl AuthMiddleware {
         } {
             debug!("Found credentials in keyring for {url}");
             Some(credentials)
+        // As a last resort, sign in interactively to registries that support it.
+        } else if let Some((provider, client)) = self.token_provider(url) {
+            provider.sign_in(client).await
         } else {
             None
         }
@@ -9,6 +9,7 @@ use wiremock::matchers::{basic_auth, method, path_regex};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 use super::*;
+use crate::{Secret, SecretCredentials};
 
 type Error = Box<dyn std::error::Error>;
 
@@ -264,6 +265,97 @@ async fn test_netrc_file_default_host() -> Result<(), Error> {
     Ok(())
 }
 
+#[test(tokio::test)]
+async fn test_credential_store() -> Result<(), Error> {
+    let username = "user";
+    let password = "password";
+    let server = start_test_server(username, password).await;
+    let base_url = Url::parse(&server.uri())?;
+
+    let mut store = CredentialStore::default();
+    store.insert(crate::StoredCredentials::new(
+        base_url.join("simple")?,
+        username.to_string(),
+        Some(password.to_string()),
+    ));
+
+    let client = test_client_builder()
+        .with(
+            AuthMiddleware::new()
+                .with_cache(CredentialsCache::new())
+                .with_netrc(None)
+                .with_store(Some(store)),
+        )
+        .build();
+
+    assert_eq!(
+        client
+            .get(format!("{}/simple/foo", server.uri()))
+            .send()
+            .await?
+            .status(),
+        200,
+        "Credentials should be pulled from the credential store"
+    );
+
+    let client = test_client_builder()
+        .with(
+            AuthMiddleware::new()
+                .with_cache(CredentialsCache::new())
+                .with_netrc(None)
+                .with_store(Some(CredentialStore::default())),
+        )
+        .build();
+
+    assert_eq!(
+        client
+            .get(format!("{}/simple/foo", server.uri()))
+            .send()
+            .await?
+            .status(),
+        401,
+        "Credentials should not be found in an empty store"
+    );
+
+    Ok(())
+}
+
+#[test(tokio::test)]
+async fn test_credential_store_path_prefix() -> Result<(), Error> {
+    let username = "user";
+    let password = "password";
+    let server = start_test_server(username, password).await;
+    let base_url = Url::parse(&server.uri())?;
+
+    let mut store = CredentialStore::default();
+    store.insert(crate::StoredCredentials::new(
+        base_url.join("simple")?,
+        username.to_string(),
+        Some(password.to_string()),
+    ));
+
+    let client = test_client_builder()
+        .with(
+            AuthMiddleware::new()
+                .with_cache(CredentialsCache::new())
+                .with_netrc(None)
+                .with_store(Some(store)),
+        )
+        .build();
+
+    assert_eq!(
+        client
+            .get(format!("{}/simple-other/foo", server.uri()))
+            .send()
+            .await?
+            .status(),
+        401,
+        "Credentials should only apply to URLs under the stored path"
+    );
+
+    Ok(())
+}
+
 #[test(tokio::test)]
 async fn test_netrc_file_matching_host() -> Result<(), Error> {
     let username = "user";
@@ -1077,3 +1169,70 @@ async fn test_credentials_from_keyring_mixed_authentication_in_realm_same_userna
 
     Ok(())
 }
+
+/// Credentials provided as secrets are resolved when the URL is first requested.
+#[test(tokio::test)]
+async fn test_credentials_from_secrets() -> Result<(), Error> {
+    let username = "user";
+    let password = "password";
+
+    let server = start_test_server(username, password).await;
+    let base_url = Url::parse(&server.uri())?;
+
+    let mut file = NamedTempFile::new()?;
+    writeln!(file, "{password}")?;
+
+    let cache = CredentialsCache::new();
+    cache.insert_secrets(SecretCredentials::new(
+        base_url.clone(),
+        Some(Secret::Literal(username.to_string())),
+        Some(Secret::File(file.path().to_path_buf())),
+    ));
+    let client = test_client_builder()
+        .with(AuthMiddleware::new().with_cache(cache))
+        .build();
+
+    assert_eq!(
+        client.get(server.uri()).send().await?.status(),
+        200,
+        "The password should be read from the file"
+    );
+
+    // Once resolved, the file is not read again.
+    drop(file);
+    assert_eq!(
+        client
+            .get(format!("{}/foo", server.uri()))
+            .send()
+            .await?
+            .status(),
+        200,
+        "Requests can be to different paths under the same URL"
+    );
+
+    Ok(())
+}
+
+/// Requests fail if credentials provided as secrets can't be resolved.
+#[test(tokio::test)]
+async fn test_credentials_from_secrets_missing() -> Result<(), Error> {
+    let server = start_test_server("user", "password").await;
+    let base_url = Url::parse(&server.uri())?;
+
+    let cache = CredentialsCache::new();
+    cache.insert_secrets(SecretCredentials::new(
+        base_url,
+        Some(Secret::Literal("user".to_string())),
+        Some(Secret::Env("UV_TEST_SECRET_DOES_NOT_EXIST".to_string())),
+    ));
+    let client = test_client_builder()
+        .with(AuthMiddleware::new().with_cache(cache))
+        .build();
+
+    let err = client.get(server.uri()).send().await.unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("Environment variable `UV_TEST_SECRET_DOES_NOT_EXIST` is not set"));
+
+    Ok(())
+}
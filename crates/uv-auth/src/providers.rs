@@ -0,0 +1,207 @@
+use std::hash::Hash;
+use std::sync::{Arc, LazyLock};
+
+use jiff::{Span, Timestamp};
+use rustc_hash::FxHashMap;
+use tokio::sync::Mutex;
+use tracing::{debug, trace};
+use url::Url;
+
+use crate::credentials::Credentials;
+use artifact_registry::ArtifactRegistry;
+use azure_artifacts::AzureArtifacts;
+use codeartifact::CodeArtifactDomain;
+
+mod artifact_registry;
+mod azure_artifacts;
+mod codeartifact;
+
+/// Tokens that expire within this many minutes are refreshed before use.
+const EXPIRY_MARGIN_MINUTES: i64 = 5;
+
+/// Failed token fetches are retried after this many seconds.
+const FAILURE_TTL_SECONDS: i64 = 60;
+
+static CODEARTIFACT_TOKENS: LazyLock<TokenCache<CodeArtifactDomain>> =
+    LazyLock::new(TokenCache::default);
+
+static ARTIFACT_REGISTRY_TOKENS: LazyLock<TokenCache<ArtifactRegistry>> =
+    LazyLock::new(TokenCache::default);
+
+static AZURE_ARTIFACTS_TOKENS: LazyLock<TokenCache<AzureArtifacts>> =
+    LazyLock::new(TokenCache::default);
+
+/// A built-in provider of short-lived tokens for a cloud package registry.
+///
+/// Unlike other credentials, tokens are not stored in the [`crate::CredentialsCache`], since they
+/// may expire (and need to be refreshed) during a long-running invocation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum TokenProvider {
+    /// An AWS CodeArtifact repository, authenticated via the AWS CLI.
+    CodeArtifact(CodeArtifactDomain),
+    /// A Google Artifact Registry repository, authenticated via Application Default Credentials.
+    ArtifactRegistry(ArtifactRegistry),
+    /// An Azure DevOps Artifacts feed, authenticated via a personal access token or an
+    /// interactive sign-in.
+    AzureArtifacts(AzureArtifacts),
+}
+
+impl TokenProvider {
+    /// Return the [`TokenProvider`] for the given URL, if it belongs to a supported registry.
+    pub(crate) fn from_url(url: &Url) -> Option<Self> {
+        if let Some(domain) = CodeArtifactDomain::from_url(url) {
+            return Some(Self::CodeArtifact(domain));
+        }
+        if ArtifactRegistry::matches(url) {
+            return Some(Self::ArtifactRegistry(ArtifactRegistry));
+        }
+        if AzureArtifacts::matches(url) {
+            return Some(Self::AzureArtifacts(AzureArtifacts));
+        }
+        None
+    }
+
+    /// Return credentials for the registry, fetching a new token if no unexpired token is cached.
+    ///
+    /// Tokens are requested with the given `client`, such that the user's TLS, proxy, and timeout
+    /// settings apply to the token endpoints.
+    ///
+    /// If `stale` is provided, the registry rejected those credentials, so the cached token is
+    /// discarded and a new token is fetched, even if it has not yet expired.
+    ///
+    /// Returns `None` if a token could not be fetched; failures are cached for
+    /// [`FAILURE_TTL_SECONDS`], after which the fetch is retried.
+    pub(crate) async fn credentials(
+        &self,
+        client: &reqwest::Client,
+        stale: Option<&Credentials>,
+    ) -> Option<Arc<Credentials>> {
+        match self {
+            Self::CodeArtifact(domain) => {
+                CODEARTIFACT_TOKENS
+                    .get(domain, stale, || domain.fetch_token())
+                    .await
+            }
+            Self::ArtifactRegistry(registry) => {
+                ARTIFACT_REGISTRY_TOKENS
+                    .get(registry, stale, || registry.fetch_token(client))
+                    .await
+            }
+            Self::AzureArtifacts(feed) => {
+                AZURE_ARTIFACTS_TOKENS
+                    .get(feed, stale, || feed.fetch_token(client))
+                    .await
+            }
+        }
+    }
+
+    /// Return credentials for the registry by prompting the user to sign in, if the registry
+    /// supports interactive authentication.
+    ///
+    /// Used as a last resort, when no other credentials are available.
+    pub(crate) async fn sign_in(&self, client: &reqwest::Client) -> Option<Credentials> {
+        match self {
+            Self::CodeArtifact(_) | Self::ArtifactRegistry(_) => None,
+            Self::AzureArtifacts(feed) => feed.sign_in(client).await,
+        }
+    }
+}
+
+/// A short-lived token issued by a cloud package registry.
+#[derive(Debug, Clone)]
+pub(crate) struct Token {
+    credentials: Arc<Credentials>,
+    expires_at: Timestamp,
+}
+
+impl Token {
+    pub(crate) fn new(credentials: Credentials, expires_at: Timestamp) -> Self {
+        Self {
+            credentials: Arc::new(credentials),
+            expires_at,
+        }
+    }
+
+    /// Returns `true` if the token will remain valid for at least [`EXPIRY_MARGIN_MINUTES`].
+    fn is_fresh(&self) -> bool {
+        Timestamp::now()
+            .checked_add(Span::new().minutes(EXPIRY_MARGIN_MINUTES))
+            .is_ok_and(|deadline| deadline < self.expires_at)
+    }
+}
+
+/// The result of a token fetch, as stored in a [`TokenCache`].
+#[derive(Debug, Clone)]
+enum CachedToken {
+    /// A token was fetched successfully.
+    Token(Token),
+    /// The fetch failed at the given time.
+    Failed(Timestamp),
+}
+
+/// A cache of tokens, keyed by the resource to which they grant access.
+///
+/// Fetches are serialized, such that concurrent requests wait on a single fetch rather than each
+/// requesting a new token.
+struct TokenCache<K> {
+    tokens: Mutex<FxHashMap<K, CachedToken>>,
+}
+
+impl<K> Default for TokenCache<K> {
+    fn default() -> Self {
+        Self {
+            tokens: Mutex::new(FxHashMap::default()),
+        }
+    }
+}
+
+impl<K: Clone + Eq + Hash + std::fmt::Display> TokenCache<K> {
+    async fn get<F, Fut>(
+        &self,
+        key: &K,
+        stale: Option<&Credentials>,
+        fetch: F,
+    ) -> Option<Arc<Credentials>>
+    where
+        F: FnOnce() -> Fut,
+        Fut: std::future::Future<Output = Option<Token>>,
+    {
+        let mut tokens = self.tokens.lock().await;
+        match tokens.get(key) {
+            // A previous fetch failed recently; don't retry yet.
+            Some(CachedToken::Failed(failed_at)) => {
+                let retry_at = failed_at
+                    .checked_add(Span::new().seconds(FAILURE_TTL_SECONDS))
+                    .unwrap_or(*failed_at);
+                if Timestamp::now() < retry_at {
+                    trace!("Skipping token fetch for {key}, previous attempt failed");
+                    return None;
+                }
+                debug!("Retrying token fetch for {key}");
+            }
+            Some(CachedToken::Token(token)) => {
+                // If the token was rejected, but has since been replaced by another request,
+                // use the replacement.
+                let rejected = stale.is_some_and(|stale| *stale == *token.credentials);
+                if !rejected && token.is_fresh() {
+                    trace!("Using cached token for {key}");
+                    return Some(token.credentials.clone());
+                }
+                debug!("Refreshing token for {key}");
+            }
+            None => debug!("Fetching token for {key}"),
+        }
+
+        if let Some(token) = fetch().await {
+            let credentials = token.credentials.clone();
+            tokens.insert(key.clone(), CachedToken::Token(token));
+            Some(credentials)
+        } else {
+            tokens.insert(key.clone(), CachedToken::Failed(Timestamp::now()));
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
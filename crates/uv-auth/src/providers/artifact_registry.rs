@@ -0,0 +1,314 @@
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+use std::time::Duration;
+
+use base64::prelude::{BASE64_STANDARD, BASE64_URL_SAFE_NO_PAD};
+use base64::Engine;
+use jiff::{Span, Timestamp};
+use ring::rand::SystemRandom;
+use ring::signature::{RsaKeyPair, RSA_PKCS1_SHA256};
+use serde::Deserialize;
+use tracing::{debug, warn};
+use url::Url;
+
+use uv_static::EnvVars;
+
+use crate::credentials::Credentials;
+use crate::providers::Token;
+
+/// The username used to authenticate with an OAuth access token.
+const USERNAME: &str = "oauth2accesstoken";
+
+/// The OAuth scope requested for access tokens.
+const SCOPE: &str = "https://www.googleapis.com/auth/cloud-platform";
+
+/// The default endpoint from which to request access tokens.
+const TOKEN_URI: &str = "https://oauth2.googleapis.com/token";
+
+/// The default host of the Compute Engine metadata server.
+const METADATA_HOST: &str = "metadata.google.internal";
+
+/// The path at which Linux exposes the DMI product name, used to detect Compute Engine.
+const DMI_PRODUCT_NAME: &str = "/sys/class/dmi/id/product_name";
+
+#[derive(Debug, thiserror::Error)]
+enum Error {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error("Failed to parse application default credentials")]
+    Json(#[from] serde_json::Error),
+    #[error(transparent)]
+    Reqwest(#[from] reqwest::Error),
+    #[error("Invalid service account private key: {0}")]
+    InvalidKey(String),
+    #[error("Failed to sign service account assertion")]
+    Sign,
+    #[error("Unsupported application default credentials; only `authorized_user` and `service_account` credentials are supported")]
+    Unsupported,
+}
+
+/// Google Artifact Registry, authenticated via Application Default Credentials (ADC).
+///
+/// Mirrors the behavior of the `keyrings.google-artifactregistry-auth` plugin, without requiring
+/// a `keyring` subprocess.
+///
+/// See: <https://cloud.google.com/docs/authentication/application-default-credentials>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct ArtifactRegistry;
+
+impl ArtifactRegistry {
+    /// Returns `true` if the URL refers to an Artifact Registry repository, e.g.,
+    /// `https://us-python.pkg.dev/project/repository/simple`.
+    pub(crate) fn matches(url: &Url) -> bool {
+        url.scheme() == "https"
+            && url
+                .host_str()
+                .is_some_and(|host| host.ends_with(".pkg.dev"))
+    }
+
+    /// Request an access token using the application default credentials or, if none are
+    /// configured and uv is running on Compute Engine, from the metadata server.
+    pub(crate) async fn fetch_token(&self, client: &reqwest::Client) -> Option<Token> {
+        match ApplicationDefaultCredentials::read() {
+            Ok(Some(credentials)) => credentials
+                .fetch_token(client)
+                .await
+                .inspect_err(|err| {
+                    warn!("Failed to fetch an access token for Google Artifact Registry: {err}");
+                })
+                .ok(),
+            Ok(None) => {
+                let Some(host) = metadata_host() else {
+                    debug!("No application default credentials found for Google Artifact Registry");
+                    return None;
+                };
+                debug!("No application default credentials found, checking the metadata server");
+                fetch_metadata_token(client, &host)
+                    .await
+                    .inspect_err(|err| {
+                        debug!("Failed to fetch an access token from the metadata server: {err}");
+                    })
+                    .ok()
+            }
+            Err(err) => {
+                warn!("Failed to read application default credentials: {err}");
+                None
+            }
+        }
+    }
+}
+
+impl Display for ArtifactRegistry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Google Artifact Registry")
+    }
+}
+
+/// The contents of an application default credentials file.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ApplicationDefaultCredentials {
+    /// User credentials, as created by `gcloud auth application-default login`.
+    AuthorizedUser {
+        client_id: String,
+        client_secret: String,
+        refresh_token: String,
+    },
+    /// A service account key.
+    ServiceAccount {
+        client_email: String,
+        private_key: String,
+        #[serde(default)]
+        token_uri: Option<String>,
+    },
+    /// Any other type of credentials, e.g., `external_account`.
+    #[serde(other)]
+    Other,
+}
+
+impl ApplicationDefaultCredentials {
+    /// Read the application default credentials from `GOOGLE_APPLICATION_CREDENTIALS`, or from
+    /// the well-known location used by the Google Cloud SDK.
+    fn read() -> Result<Option<Self>, Error> {
+        if let Some(path) = std::env::var_os(EnvVars::GOOGLE_APPLICATION_CREDENTIALS)
+            .filter(|path| !path.is_empty())
+        {
+            let contents = fs_err::read_to_string(PathBuf::from(path))?;
+            return Ok(Some(serde_json::from_str(&contents)?));
+        }
+
+        let Some(path) = Self::well_known_path() else {
+            return Ok(None);
+        };
+        match fs_err::read_to_string(&path) {
+            Ok(contents) => Ok(Some(serde_json::from_str(&contents)?)),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Return the path at which `gcloud auth application-default login` stores credentials.
+    fn well_known_path() -> Option<PathBuf> {
+        let config = if let Some(config) =
+            std::env::var_os(EnvVars::CLOUDSDK_CONFIG).filter(|config| !config.is_empty())
+        {
+            PathBuf::from(config)
+        } else if cfg!(windows) {
+            PathBuf::from(std::env::var_os(EnvVars::APPDATA)?).join("gcloud")
+        } else {
+            home::home_dir()?.join(".config").join("gcloud")
+        };
+        Some(config.join("application_default_credentials.json"))
+    }
+
+    /// Exchange the credentials for an access token.
+    async fn fetch_token(&self, client: &reqwest::Client) -> Result<Token, Error> {
+        let request = match self {
+            Self::AuthorizedUser {
+                client_id,
+                client_secret,
+                refresh_token,
+            } => client.post(TOKEN_URI).form(&[
+                ("grant_type", "refresh_token"),
+                ("client_id", client_id.as_str()),
+                ("client_secret", client_secret.as_str()),
+                ("refresh_token", refresh_token.as_str()),
+            ]),
+            Self::ServiceAccount {
+                client_email,
+                private_key,
+                token_uri,
+            } => {
+                let token_uri = token_uri.as_deref().unwrap_or(TOKEN_URI);
+                let assertion = sign_assertion(client_email, private_key, token_uri)?;
+                client.post(token_uri).form(&[
+                    ("grant_type", "urn:ietf:params:oauth:grant-type:jwt-bearer"),
+                    ("assertion", assertion.as_str()),
+                ])
+            }
+            Self::Other => return Err(Error::Unsupported),
+        };
+
+        let response = request
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<TokenResponse>()
+            .await?;
+        Ok(response.into_token())
+    }
+}
+
+/// Create a signed JWT asserting the identity of a service account, for exchange with the token
+/// endpoint.
+///
+/// See: <https://developers.google.com/identity/protocols/oauth2/service-account#authorizingrequests>
+fn sign_assertion(client_email: &str, private_key: &str, audience: &str) -> Result<String, Error> {
+    let issued_at = Timestamp::now().as_second();
+    let header = BASE64_URL_SAFE_NO_PAD.encode(r#"{"alg":"RS256","typ":"JWT"}"#);
+    let claims = BASE64_URL_SAFE_NO_PAD.encode(
+        serde_json::json!({
+            "iss": client_email,
+            "scope": SCOPE,
+            "aud": audience,
+            "iat": issued_at,
+            "exp": issued_at + 3600,
+        })
+        .to_string(),
+    );
+    let message = format!("{header}.{claims}");
+
+    // Service account keys are PEM-encoded PKCS #8 keys.
+    let der = BASE64_STANDARD
+        .decode(
+            private_key
+                .lines()
+                .filter(|line| !line.starts_with("-----"))
+                .collect::<String>(),
+        )
+        .map_err(|err| Error::InvalidKey(err.to_string()))?;
+    let key_pair =
+        RsaKeyPair::from_pkcs8(&der).map_err(|err| Error::InvalidKey(err.to_string()))?;
+
+    let mut signature = vec![0; key_pair.public().modulus_len()];
+    key_pair
+        .sign(
+            &RSA_PKCS1_SHA256,
+            &SystemRandom::new(),
+            message.as_bytes(),
+            &mut signature,
+        )
+        .map_err(|_| Error::Sign)?;
+
+    Ok(format!(
+        "{message}.{}",
+        BASE64_URL_SAFE_NO_PAD.encode(signature)
+    ))
+}
+
+/// Return the host of the Compute Engine metadata server, if uv is running on Compute Engine.
+///
+/// The metadata server is only reachable from within Google Cloud, so rather than probing it on
+/// every invocation, we require that `GCE_METADATA_HOST` is set or that the DMI product name
+/// identifies the machine as a Google Compute Engine instance (as on GCE, GKE, and Cloud Run).
+fn metadata_host() -> Option<String> {
+    if let Some(host) = std::env::var(EnvVars::GCE_METADATA_HOST)
+        .ok()
+        .filter(|host| !host.is_empty())
+    {
+        return Some(host);
+    }
+    if cfg!(target_os = "linux")
+        && fs_err::read_to_string(DMI_PRODUCT_NAME)
+            .is_ok_and(|product_name| is_google_product_name(&product_name))
+    {
+        return Some(METADATA_HOST.to_string());
+    }
+    None
+}
+
+/// Returns `true` if the DMI product name identifies a Google Compute Engine instance.
+fn is_google_product_name(product_name: &str) -> bool {
+    let product_name = product_name.trim();
+    product_name == "Google" || product_name == "Google Compute Engine"
+}
+
+/// Request an access token for the default service account from the Compute Engine metadata
+/// server.
+async fn fetch_metadata_token(client: &reqwest::Client, host: &str) -> Result<Token, Error> {
+    let response = client
+        .get(format!(
+            "http://{host}/computeMetadata/v1/instance/service-accounts/default/token"
+        ))
+        .header("Metadata-Flavor", "Google")
+        .timeout(Duration::from_secs(3))
+        .send()
+        .await?
+        .error_for_status()?
+        .json::<TokenResponse>()
+        .await?;
+    Ok(response.into_token())
+}
+
+/// An OAuth access token response.
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    /// The lifetime of the token, in seconds.
+    expires_in: i64,
+}
+
+impl TokenResponse {
+    fn into_token(self) -> Token {
+        let expires_at = Timestamp::now()
+            .checked_add(Span::new().seconds(self.expires_in))
+            .unwrap_or(Timestamp::MAX);
+        Token::new(
+            Credentials::new(Some(USERNAME.to_string()), Some(self.access_token)),
+            expires_at,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,343 @@
+use std::fmt::{Display, Formatter};
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use jiff::{Span, Timestamp};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
+use url::Url;
+
+use uv_static::EnvVars;
+use uv_warnings::warn_user;
+
+use crate::credentials::Credentials;
+use crate::providers::Token;
+use crate::store::{read_secret, write_secret};
+use crate::{CredentialStore, AZURE_ARTIFACTS_PAT};
+
+/// The username used to authenticate with a personal access token or Entra ID access token; Azure
+/// DevOps ignores the username, but requires one to be present.
+const USERNAME: &str = "uv";
+
+/// The Microsoft identity platform endpoints for work and school accounts.
+const DEVICE_CODE_URI: &str =
+    "https://login.microsoftonline.com/organizations/oauth2/v2.0/devicecode";
+const TOKEN_URI: &str = "https://login.microsoftonline.com/organizations/oauth2/v2.0/token";
+
+/// The public client ID of the Azure CLI, which is pre-authorized for Azure DevOps.
+const CLIENT_ID: &str = "04b07795-8ddb-461a-bbee-02f9e1bf7b46";
+
+/// The scopes requested at sign-in: the Azure DevOps resource, to which access tokens are scoped,
+/// and `offline_access`, such that a refresh token is issued.
+const SCOPE: &str = "499b84ac-1321-427f-aa17-267ca6975798/.default offline_access";
+
+/// The name of the file in the credentials directory in which the sign-in session is persisted.
+const SESSION_FILE: &str = "azure-artifacts.session";
+
+/// An Azure DevOps Artifacts feed, e.g.,
+/// `https://pkgs.dev.azure.com/{organization}/{project}/_packaging/{feed}/pypi/simple/`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct AzureArtifacts;
+
+impl AzureArtifacts {
+    /// Returns `true` if the URL refers to an Azure Artifacts feed.
+    ///
+    /// Credentials are only attached to requests to these hosts, such that a personal access token
+    /// isn't leaked to other indexes.
+    pub(crate) fn matches(url: &Url) -> bool {
+        url.scheme() == "https"
+            && url.host_str().is_some_and(|host| {
+                host == "pkgs.dev.azure.com" || host.ends_with(".pkgs.visualstudio.com")
+            })
+    }
+
+    /// Return a token for Azure DevOps: the personal access token from `UV_AZURE_ARTIFACTS_PAT`
+    /// or the `azure-artifacts-pat` setting, if set, or the access token from a previous sign-in,
+    /// refreshing it if necessary.
+    pub(crate) async fn fetch_token(&self, client: &reqwest::Client) -> Option<Token> {
+        if let Some(token) = personal_access_token().await {
+            return Some(Token::new(
+                Credentials::new(Some(USERNAME.to_string()), Some(token)),
+                Timestamp::MAX,
+            ));
+        }
+
+        let path = Session::path()?;
+        let session = match Session::read(&path) {
+            Ok(Some(session)) => session,
+            Ok(None) => return None,
+            Err(err) => {
+                warn!("Failed to read Azure Artifacts session: {err}");
+                return None;
+            }
+        };
+        let token = session.to_token();
+        if token.is_fresh() {
+            debug!("Using access token for Azure Artifacts from previous sign-in");
+            return Some(token);
+        }
+
+        let refresh_token = session.refresh_token?;
+        debug!("Refreshing access token for Azure Artifacts");
+        let request = client.post(TOKEN_URI).form(&[
+            ("grant_type", "refresh_token"),
+            ("client_id", CLIENT_ID),
+            ("scope", SCOPE),
+            ("refresh_token", refresh_token.as_str()),
+        ]);
+        match request_token(request).await {
+            Ok(session) => {
+                session.write(&path);
+                Some(session.to_token())
+            }
+            Err(err) => {
+                debug!("Failed to refresh access token for Azure Artifacts: {err}");
+                None
+            }
+        }
+    }
+
+    /// Sign in interactively via the OAuth device authorization flow, returning an access token
+    /// for Azure DevOps.
+    ///
+    /// The session is persisted in the credentials directory, such that subsequent invocations
+    /// reuse (and refresh) the access token, rather than prompting again.
+    ///
+    /// Returns `None` if `stderr` is not a terminal, or if the sign-in fails.
+    pub(crate) async fn sign_in(&self, client: &reqwest::Client) -> Option<Credentials> {
+        if !std::io::stderr().is_terminal() {
+            debug!("Skipping interactive sign-in for Azure Artifacts in non-interactive mode");
+            return None;
+        }
+
+        match device_code_flow(client).await {
+            Ok(session) => {
+                if let Some(path) = Session::path() {
+                    session.write(&path);
+                }
+                Some(Credentials::new(
+                    Some(USERNAME.to_string()),
+                    Some(session.access_token),
+                ))
+            }
+            Err(err) => {
+                warn!("Failed to sign in to Azure Artifacts: {err}");
+                None
+            }
+        }
+    }
+}
+
+/// Return the personal access token from `UV_AZURE_ARTIFACTS_PAT` or, if unset, the
+/// `azure-artifacts-pat` setting.
+async fn personal_access_token() -> Option<String> {
+    if let Some(token) = std::env::var(EnvVars::UV_AZURE_ARTIFACTS_PAT)
+        .ok()
+        .filter(|token| !token.is_empty())
+    {
+        debug!("Using personal access token for Azure Artifacts from `UV_AZURE_ARTIFACTS_PAT`");
+        return Some(token);
+    }
+
+    let secret = AZURE_ARTIFACTS_PAT.get()?;
+    match secret.resolve().await {
+        Ok(token) if !token.is_empty() => {
+            debug!("Using personal access token for Azure Artifacts from `azure-artifacts-pat`");
+            Some(token)
+        }
+        Ok(_) => None,
+        Err(err) => {
+            warn_user!("Failed to read the personal access token for Azure Artifacts: {err}");
+            None
+        }
+    }
+}
+
+/// An access token obtained by signing in, persisted across invocations.
+#[derive(Debug, Serialize, Deserialize)]
+struct Session {
+    access_token: String,
+    refresh_token: Option<String>,
+    expires_at: Timestamp,
+}
+
+impl Session {
+    /// Return the path at which the session is persisted, alongside the credential store.
+    fn path() -> Option<PathBuf> {
+        CredentialStore::default_path()
+            .ok()
+            .map(|path| path.with_file_name(SESSION_FILE))
+    }
+
+    /// Read a persisted session, if any.
+    fn read(path: &Path) -> Result<Option<Self>, Error> {
+        let Some(contents) = read_secret(path)? else {
+            return Ok(None);
+        };
+        Ok(Some(serde_json::from_str(&contents)?))
+    }
+
+    /// Persist the session, encrypted in the same manner as the credential store.
+    ///
+    /// Failures are logged, since the session remains usable for the current invocation.
+    fn write(&self, path: &Path) {
+        let result = serde_json::to_string(self)
+            .map_err(Error::from)
+            .and_then(|contents| write_secret(path, &contents).map_err(Error::from));
+        if let Err(err) = result {
+            warn!("Failed to persist Azure Artifacts session: {err}");
+        }
+    }
+
+    fn to_token(&self) -> Token {
+        Token::new(
+            Credentials::new(Some(USERNAME.to_string()), Some(self.access_token.clone())),
+            self.expires_at,
+        )
+    }
+}
+
+impl Display for AzureArtifacts {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Azure Artifacts")
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum Error {
+    #[error(transparent)]
+    Reqwest(#[from] reqwest::Error),
+    #[error(transparent)]
+    Store(#[from] crate::StoreError),
+    #[error("Failed to parse Azure Artifacts session")]
+    Json(#[from] serde_json::Error),
+    #[error("{0}")]
+    Authorization(String),
+    #[error("The device code expired before sign-in completed")]
+    Expired,
+}
+
+/// The response to a device authorization request.
+///
+/// See: <https://learn.microsoft.com/en-us/entra/identity-platform/v2-oauth2-device-code>
+#[derive(Debug, Deserialize)]
+struct DeviceCodeResponse {
+    device_code: String,
+    /// Instructions for the user, including the verification URL and user code.
+    message: String,
+    /// The lifetime of the device code, in seconds.
+    expires_in: i64,
+    /// The polling interval, in seconds.
+    #[serde(default = "default_interval")]
+    interval: u64,
+}
+
+fn default_interval() -> u64 {
+    5
+}
+
+/// The response to a token request, which is either an access token or an error.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum TokenResponse {
+    Token {
+        access_token: String,
+        #[serde(default)]
+        refresh_token: Option<String>,
+        /// The lifetime of the access token, in seconds.
+        expires_in: i64,
+    },
+    Error {
+        error: String,
+        error_description: Option<String>,
+    },
+}
+
+impl TokenResponse {
+    /// Convert a successful response into a [`Session`].
+    fn into_session(self) -> Result<Session, Error> {
+        match self {
+            Self::Token {
+                access_token,
+                refresh_token,
+                expires_in,
+            } => Ok(Session {
+                access_token,
+                refresh_token,
+                expires_at: Timestamp::now()
+                    .checked_add(Span::new().seconds(expires_in))
+                    .unwrap_or(Timestamp::MAX),
+            }),
+            Self::Error {
+                error,
+                error_description,
+            } => Err(Error::Authorization(error_description.unwrap_or(error))),
+        }
+    }
+}
+
+/// Send a token request, returning the resulting [`Session`].
+async fn request_token(request: reqwest::RequestBuilder) -> Result<Session, Error> {
+    request
+        .send()
+        .await?
+        .json::<TokenResponse>()
+        .await?
+        .into_session()
+}
+
+/// Perform the device authorization flow, prompting the user to sign in via a browser.
+async fn device_code_flow(client: &reqwest::Client) -> Result<Session, Error> {
+    let device_code = client
+        .post(DEVICE_CODE_URI)
+        .form(&[("client_id", CLIENT_ID), ("scope", SCOPE)])
+        .send()
+        .await?
+        .error_for_status()?
+        .json::<DeviceCodeResponse>()
+        .await?;
+
+    warn_user!("{}", device_code.message);
+
+    let deadline = Timestamp::now()
+        .checked_add(Span::new().seconds(device_code.expires_in))
+        .unwrap_or(Timestamp::MAX);
+    let mut interval = Duration::from_secs(device_code.interval);
+    loop {
+        tokio::time::sleep(interval).await;
+        if Timestamp::now() > deadline {
+            return Err(Error::Expired);
+        }
+
+        let response = client
+            .post(TOKEN_URI)
+            .form(&[
+                ("grant_type", "urn:ietf:params:oauth:grant-type:device_code"),
+                ("client_id", CLIENT_ID),
+                ("device_code", device_code.device_code.as_str()),
+            ])
+            .send()
+            .await?
+            .json::<TokenResponse>()
+            .await?;
+
+        match response {
+            response @ TokenResponse::Token { .. } => return response.into_session(),
+            TokenResponse::Error { error, .. } if error == "authorization_pending" => {}
+            TokenResponse::Error { error, .. } if error == "slow_down" => {
+                interval += Duration::from_secs(5);
+            }
+            TokenResponse::Error { error, .. } if error == "expired_token" => {
+                return Err(Error::Expired);
+            }
+            TokenResponse::Error {
+                error,
+                error_description,
+            } => return Err(Error::Authorization(error_description.unwrap_or(error))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,68 @@
+use super::*;
+
+#[test]
+fn matches() {
+    for url in [
+        "https://pkgs.dev.azure.com/org/project/_packaging/feed/pypi/simple/",
+        "https://org.pkgs.visualstudio.com/project/_packaging/feed/pypi/simple/",
+    ] {
+        assert!(AzureArtifacts::matches(&Url::parse(url).unwrap()), "{url}");
+    }
+
+    for url in [
+        "https://pypi.org/simple/",
+        "https://dev.azure.com/org/project/",
+        "https://pkgs.dev.azure.com.example.com/org/project/_packaging/feed/pypi/simple/",
+        "http://pkgs.dev.azure.com/org/project/_packaging/feed/pypi/simple/",
+    ] {
+        assert!(!AzureArtifacts::matches(&Url::parse(url).unwrap()), "{url}");
+    }
+}
+
+#[test]
+fn parse_token_response() {
+    let response: TokenResponse = serde_json::from_str(
+        r#"{"token_type": "Bearer", "expires_in": 3599, "access_token": "token"}"#,
+    )
+    .unwrap();
+    assert!(
+        matches!(response, TokenResponse::Token { ref access_token, .. } if access_token == "token")
+    );
+    let session = response.into_session().unwrap();
+    assert_eq!(session.refresh_token, None);
+    assert!(session.to_token().is_fresh());
+
+    let response: TokenResponse = serde_json::from_str(
+        r#"{"error": "authorization_pending", "error_description": "AADSTS70016: Pending"}"#,
+    )
+    .unwrap();
+    assert!(
+        matches!(response, TokenResponse::Error { error, .. } if error == "authorization_pending")
+    );
+}
+
+#[test]
+fn round_trip_session() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    let path = temp_dir.path().join(SESSION_FILE);
+
+    assert!(Session::read(&path)?.is_none());
+
+    let session = Session {
+        access_token: "access".to_string(),
+        refresh_token: Some("refresh".to_string()),
+        expires_at: Timestamp::now(),
+    };
+    session.write(&path);
+
+    // The session should be encrypted at rest.
+    let contents = fs_err::read_to_string(&path)?;
+    assert!(!contents.contains("refresh"));
+
+    let session = Session::read(&path)?.unwrap();
+    assert_eq!(session.access_token, "access");
+    assert_eq!(session.refresh_token.as_deref(), Some("refresh"));
+    assert!(!session.to_token().is_fresh());
+
+    Ok(())
+}
@@ -0,0 +1,143 @@
+use std::fmt::{Display, Formatter};
+use std::process::Stdio;
+
+use jiff::Timestamp;
+use serde::Deserialize;
+use tokio::process::Command;
+use tracing::{debug, warn};
+use url::Url;
+
+use crate::credentials::Credentials;
+use crate::providers::Token;
+
+/// The username used to authenticate with a CodeArtifact authorization token.
+const USERNAME: &str = "aws";
+
+/// An AWS CodeArtifact domain, as identified by the host of a repository endpoint, e.g.,
+/// `my-domain-111122223333.d.codeartifact.us-west-2.amazonaws.com`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct CodeArtifactDomain {
+    domain: String,
+    owner: String,
+    region: String,
+}
+
+impl CodeArtifactDomain {
+    /// Parse the [`CodeArtifactDomain`] from a repository URL, if it's a CodeArtifact URL.
+    pub(crate) fn from_url(url: &Url) -> Option<Self> {
+        if url.scheme() != "https" {
+            return None;
+        }
+        let host = url.host_str()?;
+        let (name, rest) = host.split_once('.')?;
+        let rest = rest.strip_prefix("d.codeartifact.")?;
+        let region = rest
+            .strip_suffix(".amazonaws.com")
+            .or_else(|| rest.strip_suffix(".amazonaws.com.cn"))?;
+        if region.is_empty() || region.contains('.') {
+            return None;
+        }
+
+        // The domain name may itself contain hyphens, but the owner is always a 12-digit
+        // AWS account ID.
+        let (domain, owner) = name.rsplit_once('-')?;
+        if domain.is_empty() || owner.len() != 12 || !owner.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+
+        Some(Self {
+            domain: domain.to_string(),
+            owner: owner.to_string(),
+            region: region.to_string(),
+        })
+    }
+
+    /// Request an authorization token via `aws codeartifact get-authorization-token`, using the
+    /// configured AWS credentials.
+    pub(crate) async fn fetch_token(&self) -> Option<Token> {
+        let output = Command::new("aws")
+            .arg("codeartifact")
+            .arg("get-authorization-token")
+            .arg("--domain")
+            .arg(&self.domain)
+            .arg("--domain-owner")
+            .arg(&self.owner)
+            .arg("--region")
+            .arg(&self.region)
+            .arg("--output")
+            .arg("json")
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .output()
+            .await
+            .inspect_err(|err| warn!("Failure running `aws` command: {err}"))
+            .ok()?;
+
+        if !output.status.success() {
+            warn!(
+                "Failed to fetch an authorization token for CodeArtifact domain {self}: `aws` exited with {}",
+                output.status
+            );
+            return None;
+        }
+
+        let token = serde_json::from_slice::<AuthorizationTokenResponse>(&output.stdout)
+            .inspect_err(|err| warn!("Failed to parse response from `aws` command: {err}"))
+            .ok()?
+            .into_token()?;
+        debug!(
+            "Fetched authorization token for CodeArtifact domain {self}, expiring at {}",
+            token.expires_at
+        );
+        Some(token)
+    }
+}
+
+impl Display for CodeArtifactDomain {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{} ({})", self.domain, self.owner, self.region)
+    }
+}
+
+/// The response to the CodeArtifact `GetAuthorizationToken` API.
+///
+/// See: <https://docs.aws.amazon.com/codeartifact/latest/APIReference/API_GetAuthorizationToken.html>
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AuthorizationTokenResponse {
+    authorization_token: String,
+    expiration: Expiration,
+}
+
+impl AuthorizationTokenResponse {
+    fn into_token(self) -> Option<Token> {
+        let expires_at = self.expiration.timestamp()?;
+        Some(Token::new(
+            Credentials::new(Some(USERNAME.to_string()), Some(self.authorization_token)),
+            expires_at,
+        ))
+    }
+}
+
+/// The expiration of a token, formatted as an ISO 8601 timestamp or, depending on the
+/// `cli_timestamp_format` setting of the AWS CLI, as seconds since the Unix epoch.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Expiration {
+    Timestamp(Timestamp),
+    Seconds(f64),
+}
+
+impl Expiration {
+    fn timestamp(&self) -> Option<Timestamp> {
+        match self {
+            Self::Timestamp(timestamp) => Some(*timestamp),
+            #[allow(clippy::cast_possible_truncation)]
+            Self::Seconds(seconds) => Timestamp::from_second(*seconds as i64).ok(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,65 @@
+use super::*;
+
+#[test]
+fn from_url() {
+    let url = Url::parse(
+        "https://my-domain-111122223333.d.codeartifact.us-west-2.amazonaws.com/pypi/my-repo/simple/",
+    )
+    .unwrap();
+    assert_eq!(
+        CodeArtifactDomain::from_url(&url),
+        Some(CodeArtifactDomain {
+            domain: "my-domain".to_string(),
+            owner: "111122223333".to_string(),
+            region: "us-west-2".to_string(),
+        })
+    );
+
+    let url = Url::parse(
+        "https://example-111122223333.d.codeartifact.cn-north-1.amazonaws.com.cn/pypi/repo/simple/",
+    )
+    .unwrap();
+    assert_eq!(
+        CodeArtifactDomain::from_url(&url),
+        Some(CodeArtifactDomain {
+            domain: "example".to_string(),
+            owner: "111122223333".to_string(),
+            region: "cn-north-1".to_string(),
+        })
+    );
+}
+
+#[test]
+fn from_url_invalid() {
+    for url in [
+        // Not a CodeArtifact host.
+        "https://pypi.org/simple/",
+        "https://my-domain-111122223333.d.codeartifact.us-west-2.example.com/pypi/my-repo/simple/",
+        // The owner must be a 12-digit account ID.
+        "https://my-domain-1111.d.codeartifact.us-west-2.amazonaws.com/pypi/my-repo/simple/",
+        "https://my-domain.d.codeartifact.us-west-2.amazonaws.com/pypi/my-repo/simple/",
+        // Tokens are only sent over HTTPS.
+        "http://my-domain-111122223333.d.codeartifact.us-west-2.amazonaws.com/pypi/my-repo/simple/",
+    ] {
+        let url = Url::parse(url).unwrap();
+        assert_eq!(CodeArtifactDomain::from_url(&url), None, "{url}");
+    }
+}
+
+#[test]
+fn parse_response() {
+    let response: AuthorizationTokenResponse = serde_json::from_str(
+        r#"{"authorizationToken": "token", "expiration": "2024-10-16T22:31:05+02:00"}"#,
+    )
+    .unwrap();
+    let token = response.into_token().unwrap();
+    assert_eq!(token.credentials.username(), Some("aws"));
+    assert_eq!(token.credentials.password(), Some("token"));
+    assert_eq!(token.expires_at.as_second(), 1_729_110_665);
+
+    let response: AuthorizationTokenResponse =
+        serde_json::from_str(r#"{"authorizationToken": "token", "expiration": 1729110665.0}"#)
+            .unwrap();
+    let token = response.into_token().unwrap();
+    assert_eq!(token.expires_at.as_second(), 1_729_110_665);
+}
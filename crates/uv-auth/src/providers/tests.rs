@@ -0,0 +1,118 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use super::*;
+
+fn token(password: &str, expires_in: Span) -> Token {
+    Token::new(
+        Credentials::new(Some("user".to_string()), Some(password.to_string())),
+        Timestamp::now().checked_add(expires_in).unwrap(),
+    )
+}
+
+#[tokio::test]
+async fn token_cache_reuses_fresh_token() {
+    let cache = TokenCache::<String>::default();
+    let key = "registry".to_string();
+    let fetches = AtomicUsize::new(0);
+
+    for _ in 0..2 {
+        let credentials = cache
+            .get(&key, None, || {
+                fetches.fetch_add(1, Ordering::SeqCst);
+                std::future::ready(Some(token("first", Span::new().hours(1))))
+            })
+            .await
+            .unwrap();
+        assert_eq!(credentials.password(), Some("first"));
+    }
+    assert_eq!(fetches.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn token_cache_refreshes_expiring_token() {
+    let cache = TokenCache::<String>::default();
+    let key = "registry".to_string();
+
+    // A token that expires within the margin should be refreshed on the next use.
+    let credentials = cache
+        .get(&key, None, || {
+            std::future::ready(Some(token("first", Span::new().minutes(1))))
+        })
+        .await
+        .unwrap();
+    assert_eq!(credentials.password(), Some("first"));
+
+    let credentials = cache
+        .get(&key, None, || {
+            std::future::ready(Some(token("second", Span::new().hours(1))))
+        })
+        .await
+        .unwrap();
+    assert_eq!(credentials.password(), Some("second"));
+}
+
+#[tokio::test]
+async fn token_cache_refreshes_rejected_token() {
+    let cache = TokenCache::<String>::default();
+    let key = "registry".to_string();
+
+    let first = cache
+        .get(&key, None, || {
+            std::future::ready(Some(token("first", Span::new().hours(1))))
+        })
+        .await
+        .unwrap();
+
+    // If the registry rejects the token, a new one should be fetched, even if it hasn't expired.
+    let second = cache
+        .get(&key, Some(&first), || {
+            std::future::ready(Some(token("second", Span::new().hours(1))))
+        })
+        .await
+        .unwrap();
+    assert_eq!(second.password(), Some("second"));
+
+    // A request that still holds the first token should use the replacement.
+    let replacement = cache
+        .get(&key, Some(&first), || {
+            std::future::ready(Some(token("third", Span::new().hours(1))))
+        })
+        .await
+        .unwrap();
+    assert_eq!(replacement.password(), Some("second"));
+}
+
+#[tokio::test]
+async fn token_cache_failures_expire() {
+    let cache = TokenCache::<String>::default();
+    let key = "registry".to_string();
+
+    // A failed fetch should not be retried immediately.
+    assert!(cache
+        .get(&key, None, || std::future::ready(None))
+        .await
+        .is_none());
+    assert!(cache
+        .get(&key, None, || {
+            std::future::ready(Some(token("first", Span::new().hours(1))))
+        })
+        .await
+        .is_none());
+
+    // Once the failure has expired, the fetch should be retried.
+    cache.tokens.lock().await.insert(
+        key.clone(),
+        CachedToken::Failed(
+            Timestamp::now()
+                .checked_sub(Span::new().seconds(FAILURE_TTL_SECONDS + 1))
+                .unwrap(),
+        ),
+    );
+    let credentials = cache
+        .get(&key, None, || {
+            std::future::ready(Some(token("first", Span::new().hours(1))))
+        })
+        .await
+        .unwrap();
+    assert_eq!(credentials.password(), Some("first"));
+}
@@ -0,0 +1,201 @@
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+use std::process::Stdio;
+use std::str::FromStr;
+
+use thiserror::Error;
+use tokio::process::Command;
+use tracing::trace;
+use url::Url;
+
+use crate::credentials::Credentials;
+
+/// A secret (e.g., a username, password, or token) provided in a configuration file.
+///
+/// Secrets are either provided literally, or as a reference that is resolved when the secret is
+/// first needed:
+///
+/// - `env:NAME` reads the secret from the `NAME` environment variable.
+/// - `file:PATH` reads the secret from the file at `PATH` (e.g., `/run/secrets/token`).
+/// - `cmd:COMMAND` reads the secret from the output of `COMMAND`, run in a shell.
+///
+/// Trailing newlines are removed from secrets read from files and commands.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Secret {
+    /// A secret provided literally.
+    Literal(String),
+    /// A secret read from an environment variable.
+    Env(String),
+    /// A secret read from a file.
+    File(PathBuf),
+    /// A secret read from the output of a command.
+    Command(String),
+}
+
+impl Secret {
+    /// Returns `true` if resolving the secret reads a local file or runs a command (i.e., for
+    /// `file:` and `cmd:` references).
+    pub fn is_local(&self) -> bool {
+        matches!(self, Self::File(_) | Self::Command(_))
+    }
+
+    /// Resolve the secret to its value.
+    pub async fn resolve(&self) -> Result<String, SecretError> {
+        match self {
+            Self::Literal(value) => Ok(value.clone()),
+            Self::Env(name) => {
+                trace!("Reading secret from environment variable `{name}`");
+                std::env::var(name).map_err(|_| SecretError::MissingEnv(name.clone()))
+            }
+            Self::File(path) => {
+                trace!("Reading secret from `{}`", path.display());
+                let contents = fs_err::read_to_string(path)?;
+                Ok(contents.trim_end_matches(['\r', '\n']).to_string())
+            }
+            Self::Command(command) => {
+                trace!("Reading secret from output of `{command}`");
+                let mut child = if cfg!(windows) {
+                    let mut child = Command::new("cmd");
+                    child.arg("/C").arg(command);
+                    child
+                } else {
+                    let mut child = Command::new("sh");
+                    child.arg("-c").arg(command);
+                    child
+                };
+                let output = child
+                    .stdin(Stdio::null())
+                    .stdout(Stdio::piped())
+                    .stderr(Stdio::inherit())
+                    .output()
+                    .await
+                    .map_err(|err| SecretError::Command(command.clone(), err))?;
+                if !output.status.success() {
+                    return Err(SecretError::CommandStatus(
+                        command.clone(),
+                        output.status.to_string(),
+                    ));
+                }
+                let stdout = String::from_utf8(output.stdout)
+                    .map_err(|_| SecretError::CommandOutput(command.clone()))?;
+                Ok(stdout.trim_end_matches(['\r', '\n']).to_string())
+            }
+        }
+    }
+}
+
+impl FromStr for Secret {
+    type Err = SecretError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (kind, value) = if let Some(name) = s.strip_prefix("env:") {
+            ("env", name.trim())
+        } else if let Some(path) = s.strip_prefix("file:") {
+            ("file", path.trim())
+        } else if let Some(command) = s.strip_prefix("cmd:") {
+            ("cmd", command.trim())
+        } else {
+            return Ok(Self::Literal(s.to_string()));
+        };
+        if value.is_empty() {
+            return Err(SecretError::Empty(kind));
+        }
+        Ok(match kind {
+            "env" => Self::Env(value.to_string()),
+            "file" => Self::File(PathBuf::from(value)),
+            _ => Self::Command(value.to_string()),
+        })
+    }
+}
+
+impl Display for Secret {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Literal(value) => write!(f, "{value}"),
+            Self::Env(name) => write!(f, "env:{name}"),
+            Self::File(path) => write!(f, "file:{}", path.display()),
+            Self::Command(command) => write!(f, "cmd:{command}"),
+        }
+    }
+}
+
+impl serde::Serialize for Secret {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Secret {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+/// The username and password for a URL, as [`Secret`]s to be resolved at request time.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SecretCredentials {
+    /// The URL to which the credentials apply.
+    url: Url,
+    /// The name of the user for authentication.
+    username: Option<Secret>,
+    /// The password to use for authentication.
+    password: Option<Secret>,
+}
+
+impl SecretCredentials {
+    /// Create a new [`SecretCredentials`] for a URL.
+    pub fn new(url: Url, username: Option<Secret>, password: Option<Secret>) -> Self {
+        Self {
+            url,
+            username,
+            password,
+        }
+    }
+
+    /// Return the URL to which the credentials apply.
+    pub(crate) fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Resolve the username and password to [`Credentials`].
+    ///
+    /// If no username was provided, the username is taken from the URL, if present.
+    pub async fn resolve(&self) -> Result<Credentials, SecretError> {
+        let username = match self.username.as_ref() {
+            Some(username) => Some(username.resolve().await?),
+            None => Credentials::from_url(&self.url)
+                .and_then(|credentials| credentials.username().map(ToString::to_string)),
+        };
+        let password = match self.password.as_ref() {
+            Some(password) => Some(password.resolve().await?),
+            None => None,
+        };
+        Ok(Credentials::new(username, password))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum SecretError {
+    #[error("Secret reference `{0}:` must not be empty")]
+    Empty(&'static str),
+    #[error("Environment variable `{0}` is not set")]
+    MissingEnv(String),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error("Failed to run `{0}`: {1}")]
+    Command(String, std::io::Error),
+    #[error("Command `{0}` failed with {1}")]
+    CommandStatus(String, String),
+    #[error("Command `{0}` returned a non-UTF-8 secret")]
+    CommandOutput(String),
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,54 @@
+use super::*;
+
+#[test]
+fn parse() {
+    assert_eq!(
+        Secret::from_str("hunter2").unwrap(),
+        Secret::Literal("hunter2".to_string())
+    );
+    assert_eq!(
+        Secret::from_str("env:PYPI_TOKEN").unwrap(),
+        Secret::Env("PYPI_TOKEN".to_string())
+    );
+    assert_eq!(
+        Secret::from_str("file:/run/secrets/token").unwrap(),
+        Secret::File(PathBuf::from("/run/secrets/token"))
+    );
+    assert_eq!(
+        Secret::from_str("cmd:vault read -field=token secret/pypi").unwrap(),
+        Secret::Command("vault read -field=token secret/pypi".to_string())
+    );
+    assert!(Secret::from_str("env:").is_err());
+}
+
+#[tokio::test]
+async fn resolve_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("token");
+    fs_err::write(&path, "s3cret\n").unwrap();
+
+    let secret = Secret::File(path);
+    assert_eq!(secret.resolve().await.unwrap(), "s3cret");
+}
+
+#[tokio::test]
+async fn resolve_env_missing() {
+    let secret = Secret::Env("UV_TEST_SECRET_DOES_NOT_EXIST".to_string());
+    assert!(matches!(
+        secret.resolve().await,
+        Err(SecretError::MissingEnv(_))
+    ));
+}
+
+#[tokio::test]
+#[cfg(unix)]
+async fn resolve_command() {
+    let secret = Secret::Command("echo s3cret".to_string());
+    assert_eq!(secret.resolve().await.unwrap(), "s3cret");
+
+    let secret = Secret::Command("exit 1".to_string());
+    assert!(matches!(
+        secret.resolve().await,
+        Err(SecretError::CommandStatus(..))
+    ));
+}
@@ -0,0 +1,382 @@
+use std::io;
+use std::path::{Path, PathBuf};
+
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, CHACHA20_POLY1305, NONCE_LEN};
+use ring::rand::{SecureRandom, SystemRandom};
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+use url::Url;
+
+use uv_state::{StateBucket, StateStore};
+use uv_static::EnvVars;
+
+use crate::credentials::Credentials;
+use crate::realm::Realm;
+
+#[derive(Debug, thiserror::Error)]
+pub enum StoreError {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error("Failed to parse credentials file at: `{0}`")]
+    Parse(PathBuf, #[source] Box<toml::de::Error>),
+    #[error("Failed to serialize credentials")]
+    Serialize(#[from] toml::ser::Error),
+    #[error("Failed to decrypt the stored password for `{0}`; the credentials key at `{1}` may be missing or invalid")]
+    Decrypt(Url, PathBuf),
+    #[error("Failed to encrypt credentials")]
+    Encrypt,
+}
+
+/// A persistent store of index credentials, managed via `uv auth`.
+///
+/// Credentials are stored in a `credentials.toml` file in the uv state directory, which is only
+/// readable by the current user. Passwords are encrypted (with ChaCha20-Poly1305) using a key that
+/// is stored alongside the credentials file, in `credentials.key`; passwords may instead be stored
+/// in the system keyring, in which case only the username is recorded in the file.
+#[derive(Debug, Clone, Default)]
+pub struct CredentialStore {
+    entries: Vec<StoredCredentials>,
+}
+
+/// The credentials for a single index URL in the [`CredentialStore`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoredCredentials {
+    /// The URL to which the credentials apply, including any URL with this URL as a prefix.
+    url: Url,
+    /// The username to authenticate with.
+    username: String,
+    /// The password to authenticate with, or `None` if the password is stored in the system
+    /// keyring.
+    password: Option<String>,
+}
+
+/// The on-disk representation of the [`CredentialStore`].
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CredentialStoreWire {
+    #[serde(default, rename = "credentials")]
+    entries: Vec<StoredCredentialsWire>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct StoredCredentialsWire {
+    url: Url,
+    username: String,
+    /// The password, encrypted with the credentials key and encoded as base64 (with the nonce
+    /// prepended).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    encrypted_password: Option<String>,
+    /// A plaintext password, as written by earlier versions of uv. Such passwords are read, but
+    /// are encrypted the next time the store is written.
+    #[serde(default, skip_serializing)]
+    password: Option<String>,
+}
+
+impl StoredCredentials {
+    /// Create a new [`StoredCredentials`] entry.
+    ///
+    /// If `password` is `None`, the password is expected to be in the system keyring.
+    pub fn new(url: Url, username: String, password: Option<String>) -> Self {
+        Self {
+            url: normalize_url(url),
+            username,
+            password,
+        }
+    }
+
+    /// The URL to which the credentials apply.
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// The username to authenticate with.
+    pub fn username(&self) -> &str {
+        &self.username
+    }
+
+    /// The password to authenticate with, if it's stored in the file (rather than the keyring).
+    pub fn password(&self) -> Option<&str> {
+        self.password.as_deref()
+    }
+
+    /// Returns `true` if the credentials apply to the given URL.
+    fn matches(&self, url: &Url) -> bool {
+        Realm::from(&self.url) == Realm::from(url)
+            && url
+                .path()
+                .strip_prefix(self.url.path().trim_end_matches('/'))
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+    }
+
+    /// Convert the stored entry to [`Credentials`].
+    pub(crate) fn to_credentials(&self) -> Credentials {
+        Credentials::new(Some(self.username.clone()), self.password.clone())
+    }
+}
+
+impl CredentialStore {
+    /// Return the path to the credentials file, respecting `UV_CREDENTIALS_DIR`.
+    pub fn default_path() -> Result<PathBuf, io::Error> {
+        let directory = if let Some(directory) =
+            std::env::var_os(EnvVars::UV_CREDENTIALS_DIR).filter(|dir| !dir.is_empty())
+        {
+            std::path::absolute(directory)?
+        } else {
+            StateStore::from_settings(None)?.bucket(StateBucket::Credentials)
+        };
+        Ok(directory.join("credentials.toml"))
+    }
+
+    /// Read the [`CredentialStore`] from the given path.
+    ///
+    /// If the file does not exist, returns an empty store.
+    pub fn read(path: &Path) -> Result<Self, StoreError> {
+        let wire: CredentialStoreWire = match fs_err::read_to_string(path) {
+            Ok(contents) => toml::from_str(&contents)
+                .map_err(|err| StoreError::Parse(path.to_path_buf(), Box::new(err)))?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                debug!("No credentials file found at: `{}`", path.display());
+                return Ok(Self::default());
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        // Only read the key if there are encrypted passwords to decrypt.
+        let key_path = key_path(path);
+        let key = if wire
+            .entries
+            .iter()
+            .any(|entry| entry.encrypted_password.is_some())
+        {
+            read_key(&key_path)?
+        } else {
+            None
+        };
+
+        let entries = wire
+            .entries
+            .into_iter()
+            .map(|entry| {
+                let password = match (entry.encrypted_password, entry.password) {
+                    (Some(encrypted), _) => Some(
+                        key.as_ref()
+                            .and_then(|key| decrypt(key, &encrypted))
+                            .ok_or_else(|| {
+                                StoreError::Decrypt(entry.url.clone(), key_path.clone())
+                            })?,
+                    ),
+                    (None, password) => password,
+                };
+                Ok(StoredCredentials {
+                    url: entry.url,
+                    username: entry.username,
+                    password,
+                })
+            })
+            .collect::<Result<_, StoreError>>()?;
+
+        Ok(Self { entries })
+    }
+
+    /// Write the [`CredentialStore`] to the given path, readable only by the current user.
+    ///
+    /// Any passwords are encrypted with the key stored alongside the file, which is created if
+    /// necessary.
+    pub fn write(&self, path: &Path) -> Result<(), StoreError> {
+        if let Some(parent) = path.parent() {
+            fs_err::create_dir_all(parent)?;
+        }
+
+        let key = if self.entries.iter().any(|entry| entry.password.is_some()) {
+            Some(read_or_create_key(&key_path(path))?)
+        } else {
+            None
+        };
+
+        let wire = CredentialStoreWire {
+            entries: self
+                .entries
+                .iter()
+                .map(|entry| {
+                    let encrypted_password = match (&entry.password, &key) {
+                        (Some(password), Some(key)) => Some(encrypt(key, password)?),
+                        _ => None,
+                    };
+                    Ok(StoredCredentialsWire {
+                        url: entry.url.clone(),
+                        username: entry.username.clone(),
+                        encrypted_password,
+                        password: None,
+                    })
+                })
+                .collect::<Result<_, StoreError>>()?,
+        };
+
+        write_private(path, toml::to_string(&wire)?.as_bytes())?;
+        Ok(())
+    }
+
+    /// Return the credentials that apply to the given URL, if any.
+    ///
+    /// If multiple entries match, the one with the longest (i.e., most specific) URL is used.
+    pub fn get(&self, url: &Url) -> Option<&StoredCredentials> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.matches(url))
+            .max_by_key(|entry| entry.url.path().len())
+    }
+
+    /// Add credentials to the store, returning the entry they replaced, if any.
+    pub fn insert(&mut self, credentials: StoredCredentials) -> Option<StoredCredentials> {
+        let previous = self.remove(&credentials.url);
+        self.entries.push(credentials);
+        self.entries.sort_by(|a, b| a.url.cmp(&b.url));
+        previous
+    }
+
+    /// Remove the credentials for exactly the given URL, returning them if they existed.
+    pub fn remove(&mut self, url: &Url) -> Option<StoredCredentials> {
+        let url = normalize_url(url.clone());
+        let index = self.entries.iter().position(|entry| entry.url == url)?;
+        Some(self.entries.remove(index))
+    }
+
+    /// Iterate over the stored credentials, sorted by URL.
+    pub fn iter(&self) -> impl Iterator<Item = &StoredCredentials> {
+        self.entries.iter()
+    }
+
+    /// Returns `true` if the store contains no credentials.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Return the path to the key used to encrypt the passwords in the credentials file at `path`.
+fn key_path(path: &Path) -> PathBuf {
+    path.with_file_name("credentials.key")
+}
+
+/// Read a secret written with [`write_secret`], if it exists.
+///
+/// Returns `None` if the file or the credentials key in the same directory is missing, or if the
+/// secret can't be decrypted.
+pub(crate) fn read_secret(path: &Path) -> Result<Option<String>, StoreError> {
+    let encrypted = match fs_err::read_to_string(path) {
+        Ok(encrypted) => encrypted,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+    let Some(key) = read_key(&key_path(path))? else {
+        return Ok(None);
+    };
+    Ok(decrypt(&key, encrypted.trim()))
+}
+
+/// Write a secret to the given path, encrypted with the credentials key in the same directory,
+/// such that it's protected in the same manner as the passwords in the credential store.
+pub(crate) fn write_secret(path: &Path, secret: &str) -> Result<(), StoreError> {
+    if let Some(parent) = path.parent() {
+        fs_err::create_dir_all(parent)?;
+    }
+    let key = read_or_create_key(&key_path(path))?;
+    write_private(path, encrypt(&key, secret)?.as_bytes())?;
+    Ok(())
+}
+
+/// Read the credentials key at the given path, if it exists.
+fn read_key(path: &Path) -> Result<Option<LessSafeKey>, StoreError> {
+    match fs_err::read(path) {
+        Ok(bytes) => Ok(UnboundKey::new(&CHACHA20_POLY1305, &bytes)
+            .ok()
+            .map(LessSafeKey::new)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Read the credentials key at the given path, generating a new key if none exists.
+fn read_or_create_key(path: &Path) -> Result<LessSafeKey, StoreError> {
+    if let Some(key) = read_key(path)? {
+        return Ok(key);
+    }
+
+    debug!("Generating credentials key at: `{}`", path.display());
+    let mut bytes = [0u8; 32];
+    SystemRandom::new()
+        .fill(&mut bytes)
+        .map_err(|_| StoreError::Encrypt)?;
+    write_private(path, &bytes)?;
+    let key = UnboundKey::new(&CHACHA20_POLY1305, &bytes).map_err(|_| StoreError::Encrypt)?;
+    Ok(LessSafeKey::new(key))
+}
+
+/// Encrypt a password, returning the base64-encoded nonce and ciphertext.
+fn encrypt(key: &LessSafeKey, password: &str) -> Result<String, StoreError> {
+    let mut nonce = [0u8; NONCE_LEN];
+    SystemRandom::new()
+        .fill(&mut nonce)
+        .map_err(|_| StoreError::Encrypt)?;
+    let mut buffer = password.as_bytes().to_vec();
+    key.seal_in_place_append_tag(
+        Nonce::assume_unique_for_key(nonce),
+        Aad::empty(),
+        &mut buffer,
+    )
+    .map_err(|_| StoreError::Encrypt)?;
+    Ok(BASE64_STANDARD.encode([nonce.as_slice(), buffer.as_slice()].concat()))
+}
+
+/// Decrypt a password encrypted with [`encrypt`], returning `None` if decryption fails.
+fn decrypt(key: &LessSafeKey, encrypted: &str) -> Option<String> {
+    let bytes = BASE64_STANDARD.decode(encrypted).ok()?;
+    if bytes.len() < NONCE_LEN {
+        return None;
+    }
+    let (nonce, ciphertext) = bytes.split_at(NONCE_LEN);
+    let nonce = Nonce::try_assume_unique_for_key(nonce).ok()?;
+    let mut buffer = ciphertext.to_vec();
+    let plaintext = key.open_in_place(nonce, Aad::empty(), &mut buffer).ok()?;
+    String::from_utf8(plaintext.to_vec()).ok()
+}
+
+/// Write a file that's only readable by the current user.
+///
+/// The permissions are applied on every write, such that a file created with broader permissions
+/// (e.g., by an earlier version of uv, or by the user) is restricted.
+fn write_private(path: &Path, contents: &[u8]) -> Result<(), io::Error> {
+    let mut options = fs_err::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use fs_err::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(path)?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        file.set_permissions(std::fs::Permissions::from_mode(0o600))?;
+    }
+    io::Write::write_all(&mut file, contents)?;
+    Ok(())
+}
+
+/// Normalize a URL for use as a key in the store, stripping any embedded credentials, query, or
+/// fragment, along with any trailing slash.
+fn normalize_url(mut url: Url) -> Url {
+    let _ = url.set_username("");
+    let _ = url.set_password(None);
+    url.set_query(None);
+    url.set_fragment(None);
+    if url.path().len() > 1 && url.path().ends_with('/') {
+        let path = url.path().trim_end_matches('/').to_string();
+        url.set_path(&path);
+    }
+    url
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,107 @@
+use url::Url;
+
+use super::{CredentialStore, StoredCredentials};
+
+#[test]
+fn round_trip_encrypted() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    let path = temp_dir.path().join("credentials.toml");
+
+    let mut store = CredentialStore::default();
+    store.insert(StoredCredentials::new(
+        Url::parse("https://example.com/simple/")?,
+        "user".to_string(),
+        Some("secret".to_string()),
+    ));
+    store.insert(StoredCredentials::new(
+        Url::parse("https://keyring.example.com/simple")?,
+        "user".to_string(),
+        None,
+    ));
+    store.write(&path)?;
+
+    // The password should not be stored in plaintext.
+    let contents = fs_err::read_to_string(&path)?;
+    assert!(!contents.contains("secret"));
+    assert!(contents.contains("encrypted-password"));
+    assert!(temp_dir.path().join("credentials.key").exists());
+
+    let store = CredentialStore::read(&path)?;
+    let entry = store
+        .get(&Url::parse("https://example.com/simple/anyio")?)
+        .unwrap();
+    assert_eq!(entry.username(), "user");
+    assert_eq!(entry.password(), Some("secret"));
+    let entry = store
+        .get(&Url::parse("https://keyring.example.com/simple")?)
+        .unwrap();
+    assert_eq!(entry.password(), None);
+
+    // Without the key, the password can't be decrypted.
+    fs_err::remove_file(temp_dir.path().join("credentials.key"))?;
+    assert!(CredentialStore::read(&path).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn read_plaintext() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    let path = temp_dir.path().join("credentials.toml");
+
+    // Passwords written by earlier versions of uv are stored in plaintext.
+    fs_err::write(
+        &path,
+        "[[credentials]]\nurl = \"https://example.com/simple\"\nusername = \"user\"\npassword = \"secret\"\n",
+    )?;
+
+    let store = CredentialStore::read(&path)?;
+    let entry = store
+        .get(&Url::parse("https://example.com/simple")?)
+        .unwrap();
+    assert_eq!(entry.password(), Some("secret"));
+
+    // Once rewritten, the password should be encrypted.
+    store.write(&path)?;
+    let contents = fs_err::read_to_string(&path)?;
+    assert!(!contents.contains("secret"));
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn write_restricts_permissions() -> anyhow::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let temp_dir = tempfile::tempdir()?;
+    let path = temp_dir.path().join("credentials.toml");
+
+    // Create the file with broader permissions.
+    fs_err::write(&path, "")?;
+    fs_err::set_permissions(&path, std::fs::Permissions::from_mode(0o644))?;
+
+    CredentialStore::default().write(&path)?;
+    let mode = fs_err::metadata(&path)?.permissions().mode();
+    assert_eq!(mode & 0o777, 0o600);
+
+    Ok(())
+}
+
+#[test]
+fn round_trip_secret() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    let path = temp_dir.path().join("secret");
+
+    assert_eq!(super::read_secret(&path)?, None);
+
+    super::write_secret(&path, "secret")?;
+    assert!(!fs_err::read_to_string(&path)?.contains("secret"));
+    assert_eq!(super::read_secret(&path)?.as_deref(), Some("secret"));
+
+    // Without the key, the secret can't be read.
+    fs_err::remove_file(temp_dir.path().join("credentials.key"))?;
+    assert_eq!(super::read_secret(&path)?, None);
+
+    Ok(())
+}
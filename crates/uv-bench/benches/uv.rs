@@ -86,13 +86,14 @@ mod resolver {
     use uv_cache::Cache;
     use uv_client::RegistryClient;
     use uv_configuration::{
-        BuildOptions, Concurrency, ConfigSettings, Constraints, IndexStrategy, LowerBound,
-        SourceStrategy,
+        BuildContainers, BuildOptions, Concurrency, ConfigSettings, Constraints, IndexStrategy,
+        LowerBound, SourceStrategy,
     };
     use uv_dispatch::BuildDispatch;
     use uv_distribution::DistributionDatabase;
     use uv_distribution_types::{DependencyMetadata, IndexCapabilities, IndexLocations};
     use uv_git::GitResolver;
+    use uv_hg::HgResolver;
     use uv_install_wheel::linker::LinkMode;
     use uv_pep440::Version;
     use uv_pep508::{MarkerEnvironment, MarkerEnvironmentBuilder};
@@ -144,6 +145,7 @@ mod resolver {
         let build_options = BuildOptions::default();
         let concurrency = Concurrency::default();
         let config_settings = ConfigSettings::default();
+        let build_containers = BuildContainers::default();
         let exclude_newer = Some(
             jiff::civil::date(2024, 8, 8)
                 .to_zoned(jiff::tz::TimeZone::UTC)
@@ -155,6 +157,7 @@ mod resolver {
         let capabilities = IndexCapabilities::default();
         let flat_index = FlatIndex::default();
         let git = GitResolver::default();
+        let hg = HgResolver::default();
         let hashes = HashStrategy::default();
         let in_flight = InFlight::default();
         let index = InMemoryIndex::default();
@@ -183,10 +186,12 @@ mod resolver {
             &dependency_metadata,
             &index,
             &git,
+            &hg,
             &capabilities,
             &in_flight,
             IndexStrategy::default(),
             &config_settings,
+            &build_containers,
             build_isolation,
             LinkMode::default(),
             &build_options,
@@ -214,7 +219,7 @@ mod resolver {
             &hashes,
             &build_context,
             installed_packages,
-            DistributionDatabase::new(client, &build_context, concurrency.downloads),
+            DistributionDatabase::new(client, &build_context, concurrency),
         )?;
 
         Ok(resolver.resolve().await?)
@@ -1,15 +1,17 @@
 mod metadata;
 mod pep639_glob;
 
-use crate::metadata::{PyProjectToml, ValidationError};
-use crate::pep639_glob::Pep639GlobError;
+use crate::metadata::{BuildHook, EditableMode, PyProjectToml, ValidationError};
+use crate::pep639_glob::{parse_pep639_glob, Pep639GlobError};
 use fs_err::File;
-use glob::{GlobError, PatternError};
+use glob::{GlobError, MatchOptions, Pattern, PatternError};
 use itertools::Itertools;
 use sha2::{Digest, Sha256};
+use std::collections::BTreeSet;
 use std::fs::FileType;
 use std::io::{BufReader, Read, Write};
 use std::path::{Path, PathBuf, StripPrefixError};
+use std::process::{Command, ExitStatus};
 use std::{io, mem};
 use thiserror::Error;
 use tracing::{debug, trace};
@@ -53,6 +55,18 @@ pub enum Error {
     MissingModule(PathBuf),
     #[error("Inconsistent metadata between prepare and build step: `{0}`")]
     InconsistentSteps(&'static str),
+    #[error("Invalid `tool.uv.build-backend.{0}` glob expression: `{1}`")]
+    SettingsGlob(&'static str, String, #[source] Pep639GlobError),
+    #[error("Unknown wheel data category `{0}` in `tool.uv.build-backend.data`, expected one of: `purelib`, `platlib`, `headers`, `scripts`, `data`")]
+    InvalidDataCategory(String),
+    #[error("The `tool.uv.build-backend.data.{0}` directory does not exist: `{}`", _1.user_display())]
+    MissingDataDirectory(String, PathBuf),
+    #[error("Build hooks must have a non-empty `command`")]
+    EmptyHook,
+    #[error("Failed to run build hook: `{0}`")]
+    HookSpawn(String, #[source] io::Error),
+    #[error("Build hook `{0}` failed with {1}")]
+    HookFailed(String, ExitStatus),
 }
 
 /// Allow dispatching between writing to a directory, writing to zip and writing to a `.tar.gz`.
@@ -99,7 +113,6 @@ impl ZipDirectoryWriter {
     /// A wheel writer with no (stored) compression.
     ///
     /// Since editables are temporary, we save time be skipping compression and decompression.
-    #[expect(dead_code)]
     fn new_editable(file: File) -> Self {
         Self {
             writer: ZipWriter::new(file),
@@ -288,6 +301,8 @@ pub fn build(
 
     check_metadata_directory(source_tree, metadata_directory, &pyproject_toml)?;
 
+    run_hooks(source_tree, pyproject_toml.hooks())?;
+
     let filename = WheelFilename {
         name: pyproject_toml.name().clone(),
         version: pyproject_toml.version().clone(),
@@ -301,32 +316,102 @@ pub fn build(
     debug!("Writing wheel at {}", wheel_path.user_display());
     let mut wheel_writer = ZipDirectoryWriter::new_wheel(File::create(&wheel_path)?);
 
+    let include = compile_globs("include", pyproject_toml.include())?;
+    let exclude = compile_globs("exclude", pyproject_toml.exclude())?;
+    // Directories written to the wheel so far, to add parent directories of included files.
+    let mut directories = BTreeSet::new();
+
     debug!("Adding content files to {}", wheel_path.user_display());
     let strip_root = source_tree.join("src");
     let module_root = strip_root.join(pyproject_toml.name().as_dist_info_name().as_ref());
     if !module_root.join("__init__.py").is_file() {
         return Err(Error::MissingModule(module_root));
     }
-    for entry in WalkDir::new(module_root) {
+    // Only walk the entire `src` directory if there are files to include beyond the module.
+    let walk_root = if include.is_empty() {
+        &module_root
+    } else {
+        &strip_root
+    };
+    for entry in WalkDir::new(walk_root)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|entry| !is_excluded(source_tree, entry.path(), &exclude))
+    {
         let entry = entry.map_err(|err| Error::WalkDir {
             root: source_tree.to_path_buf(),
             err,
         })?;
 
         let relative_path = entry.path().strip_prefix(&strip_root)?;
+        if relative_path.as_os_str().is_empty() {
+            continue;
+        }
         let relative_path_str = relative_path
             .to_str()
             .ok_or_else(|| Error::NotUtf8Path(relative_path.to_path_buf()))?;
+        let in_module = entry.path().starts_with(&module_root);
         if entry.file_type().is_dir() {
-            wheel_writer.write_directory(relative_path_str)?;
+            if in_module {
+                wheel_writer.write_directory(relative_path_str)?;
+                directories.insert(relative_path.to_path_buf());
+            }
         } else if entry.file_type().is_file() {
-            wheel_writer.write_file(relative_path_str, entry.path())?;
-        } else {
+            if in_module || is_included(source_tree, entry.path(), &include) {
+                write_parent_directories(&mut wheel_writer, &mut directories, relative_path)?;
+                wheel_writer.write_file(relative_path_str, entry.path())?;
+            }
+        } else if in_module {
             // TODO(konsti): We may want to support symlinks, there is support for installing them.
             return Err(Error::UnsupportedFileType(entry.file_type()));
         }
+    }
 
-        entry.path();
+    if let Some(data) = pyproject_toml.data() {
+        let data_dir = PathBuf::from(format!(
+            "{}-{}.data",
+            pyproject_toml.name().as_dist_info_name(),
+            pyproject_toml.version()
+        ));
+        for (category, directory) in data {
+            if !matches!(
+                category.as_str(),
+                "purelib" | "platlib" | "headers" | "scripts" | "data"
+            ) {
+                return Err(Error::InvalidDataCategory(category.clone()));
+            }
+            let data_root = source_tree.join(directory);
+            if !data_root.is_dir() {
+                return Err(Error::MissingDataDirectory(category.clone(), data_root));
+            }
+            debug!(
+                "Adding {category} files from {} to {}",
+                data_root.user_display(),
+                wheel_path.user_display()
+            );
+            for entry in WalkDir::new(&data_root)
+                .sort_by_file_name()
+                .into_iter()
+                .filter_entry(|entry| !is_excluded(source_tree, entry.path(), &exclude))
+            {
+                let entry = entry.map_err(|err| Error::WalkDir {
+                    root: data_root.clone(),
+                    err,
+                })?;
+                if !entry.file_type().is_file() {
+                    continue;
+                }
+                let relative_path = data_dir
+                    .join(category)
+                    .join(entry.path().strip_prefix(&data_root)?);
+                let relative_path_str = relative_path
+                    .to_str()
+                    .ok_or_else(|| Error::NotUtf8Path(relative_path.clone()))?
+                    .replace('\\', "/");
+                write_parent_directories(&mut wheel_writer, &mut directories, &relative_path)?;
+                wheel_writer.write_file(&relative_path_str, entry.path())?;
+            }
+        }
     }
 
     debug!("Adding metadata files to {}", wheel_path.user_display());
@@ -342,6 +427,119 @@ pub fn build(
     Ok(filename)
 }
 
+/// The import hook for strict editable installs, see [`EditableMode::Strict`].
+///
+/// `{module}` and `{path}` are replaced with Python string literals of the module name and the
+/// module directory.
+const STRICT_EDITABLE_FINDER: &str = r#""""Import hook for an editable install, generated by the uv build backend.
+
+Only the module of the project is importable, other modules in the source tree are not exposed.
+"""
+
+import importlib.abc
+import importlib.util
+import os
+import sys
+
+MODULE = {module}
+PATH = {path}
+
+
+class _EditableFinder(importlib.abc.MetaPathFinder):
+    def find_spec(self, fullname, path=None, target=None):
+        if fullname != MODULE:
+            return None
+        return importlib.util.spec_from_file_location(
+            fullname,
+            os.path.join(PATH, "__init__.py"),
+            submodule_search_locations=[PATH],
+        )
+
+
+sys.meta_path.append(_EditableFinder())
+"#;
+
+/// Build an editable wheel from the source tree and place it in the output directory.
+///
+/// Instead of the module contents, the wheel contains a `.pth` file that makes the module in the
+/// source tree importable, using the mechanism from `tool.uv.build-backend.editable-mode`.
+pub fn build_editable(
+    source_tree: &Path,
+    wheel_dir: &Path,
+    metadata_directory: Option<&Path>,
+    uv_version: &str,
+) -> Result<WheelFilename, Error> {
+    let contents = fs_err::read_to_string(source_tree.join("pyproject.toml"))?;
+    let pyproject_toml = PyProjectToml::parse(&contents)?;
+    pyproject_toml.check_build_system("1.0.0+test");
+
+    check_metadata_directory(source_tree, metadata_directory, &pyproject_toml)?;
+
+    run_hooks(source_tree, pyproject_toml.hooks())?;
+
+    let filename = WheelFilename {
+        name: pyproject_toml.name().clone(),
+        version: pyproject_toml.version().clone(),
+        build_tag: None,
+        python_tag: vec!["py3".to_string()],
+        abi_tag: vec!["none".to_string()],
+        platform_tag: vec!["any".to_string()],
+    };
+
+    let wheel_path = wheel_dir.join(filename.to_string());
+    debug!("Writing editable wheel at {}", wheel_path.user_display());
+    let mut wheel_writer = ZipDirectoryWriter::new_editable(File::create(&wheel_path)?);
+
+    let module_name = pyproject_toml.name().as_dist_info_name();
+    let strip_root = std::path::absolute(source_tree.join("src"))?;
+    let module_root = strip_root.join(module_name.as_ref());
+    if !module_root.join("__init__.py").is_file() {
+        return Err(Error::MissingModule(module_root));
+    }
+
+    match pyproject_toml.editable_mode() {
+        EditableMode::Pth => {
+            debug!("Adding `.pth` file for {}", strip_root.user_display());
+            let strip_root_str = strip_root
+                .to_str()
+                .ok_or_else(|| Error::NotUtf8Path(strip_root.clone()))?;
+            wheel_writer.write_bytes(&format!("{module_name}.pth"), strip_root_str.as_bytes())?;
+        }
+        EditableMode::Strict => {
+            debug!("Adding import hook for {}", module_root.user_display());
+            let module_root_str = module_root
+                .to_str()
+                .ok_or_else(|| Error::NotUtf8Path(module_root.clone()))?;
+            let finder = STRICT_EDITABLE_FINDER
+                .replace("{module}", &python_string_literal(module_name.as_ref()))
+                .replace("{path}", &python_string_literal(module_root_str));
+            let finder_module = format!("_{module_name}_editable");
+            wheel_writer.write_bytes(&format!("{finder_module}.py"), finder.as_bytes())?;
+            wheel_writer.write_bytes(
+                &format!("{finder_module}.pth"),
+                format!("import {finder_module}\n").as_bytes(),
+            )?;
+        }
+    }
+
+    debug!("Adding metadata files to {}", wheel_path.user_display());
+    let dist_info_dir = write_dist_info(
+        &mut wheel_writer,
+        &pyproject_toml,
+        &filename,
+        source_tree,
+        uv_version,
+    )?;
+    wheel_writer.close(&dist_info_dir)?;
+
+    Ok(filename)
+}
+
+/// Quote a string as a Python string literal.
+fn python_string_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\\', r"\\").replace('\'', r"\'"))
+}
+
 /// Write the dist-info directory to the output directory without building the wheel.
 pub fn metadata(
     source_tree: &Path,
@@ -378,6 +576,93 @@ pub fn metadata(
     Ok(dist_info_dir)
 }
 
+/// Parse the `tool.uv.build-backend` glob patterns for the given field.
+fn compile_globs(field: &'static str, globs: &[String]) -> Result<Vec<Pattern>, Error> {
+    globs
+        .iter()
+        .map(|glob| {
+            parse_pep639_glob(glob).map_err(|err| Error::SettingsGlob(field, glob.clone(), err))
+        })
+        .collect()
+}
+
+/// Whether the path, relative to the project root, matches any of the patterns.
+fn matches_any(source_tree: &Path, path: &Path, patterns: &[Pattern]) -> bool {
+    let Ok(relative) = path.strip_prefix(source_tree) else {
+        return false;
+    };
+    let relative = relative.portable_display().to_string();
+    let options = MatchOptions {
+        require_literal_separator: true,
+        ..MatchOptions::default()
+    };
+    patterns
+        .iter()
+        .any(|pattern| pattern.matches_with(&relative, options))
+}
+
+/// Whether the path is excluded by `tool.uv.build-backend.exclude`.
+fn is_excluded(source_tree: &Path, path: &Path, exclude: &[Pattern]) -> bool {
+    let excluded = matches_any(source_tree, path, exclude);
+    if excluded {
+        trace!("Excluding {}", path.user_display());
+    }
+    excluded
+}
+
+/// Whether the file is included by `tool.uv.build-backend.include`.
+fn is_included(source_tree: &Path, path: &Path, include: &[Pattern]) -> bool {
+    matches_any(source_tree, path, include)
+}
+
+/// Add the parent directories of a file to the archive, if they were not added before.
+fn write_parent_directories(
+    writer: &mut dyn DirectoryWriter,
+    directories: &mut BTreeSet<PathBuf>,
+    relative_path: &Path,
+) -> Result<(), Error> {
+    let parents: Vec<_> = relative_path
+        .ancestors()
+        .skip(1)
+        .filter(|parent| !parent.as_os_str().is_empty())
+        .collect();
+    for parent in parents.into_iter().rev() {
+        if directories.insert(parent.to_path_buf()) {
+            let parent_str = parent
+                .to_str()
+                .ok_or_else(|| Error::NotUtf8Path(parent.to_path_buf()))?
+                .replace('\\', "/");
+            writer.write_directory(&parent_str)?;
+        }
+    }
+    Ok(())
+}
+
+/// Run the custom build steps from `tool.uv.build-backend.hooks` in the order they are declared.
+///
+/// The hooks run in the source tree with the environment of the build backend, which is the
+/// isolated build environment when invoked by a build frontend.
+fn run_hooks(source_tree: &Path, hooks: &[BuildHook]) -> Result<(), Error> {
+    for hook in hooks {
+        let Some((program, args)) = hook.command.split_first() else {
+            return Err(Error::EmptyHook);
+        };
+        let command = hook.command.join(" ");
+        debug!("Running build hook: `{command}`");
+        let status = Command::new(program)
+            .args(args)
+            .current_dir(source_tree)
+            // The frontend reads the filename from stdout, so we forward the output to stderr.
+            .stdout(io::stderr())
+            .status()
+            .map_err(|err| Error::HookSpawn(command.clone(), err))?;
+        if !status.success() {
+            return Err(Error::HookFailed(command, status));
+        }
+    }
+    Ok(())
+}
+
 /// PEP 517 requires that the metadata directory from the prepare metadata call is identical to the
 /// build wheel call. This method performs a prudence check that `METADATA` and `entry_points.txt`
 /// match.
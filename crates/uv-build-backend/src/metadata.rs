@@ -59,6 +59,8 @@ pub(crate) struct PyProjectToml {
     project: Project,
     /// Build-related data
     build_system: BuildSystem,
+    /// Tool-specific settings, of which only `tool.uv.build-backend` is used.
+    tool: Option<Tool>,
 }
 
 impl PyProjectToml {
@@ -70,6 +72,49 @@ impl PyProjectToml {
         &self.project.version
     }
 
+    /// The `tool.uv.build-backend` section, if any.
+    fn build_backend(&self) -> Option<&BuildBackendSettings> {
+        self.tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.build_backend.as_ref())
+    }
+
+    /// The custom build steps declared in `tool.uv.build-backend.hooks`.
+    pub(crate) fn hooks(&self) -> &[BuildHook] {
+        self.build_backend()
+            .map(|build_backend| build_backend.hooks.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// The glob patterns of additional files to include, from `tool.uv.build-backend.include`.
+    pub(crate) fn include(&self) -> &[String] {
+        self.build_backend()
+            .map(|build_backend| build_backend.include.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// The glob patterns of files to exclude, from `tool.uv.build-backend.exclude`.
+    pub(crate) fn exclude(&self) -> &[String] {
+        self.build_backend()
+            .map(|build_backend| build_backend.exclude.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// How editable installs expose the module, from `tool.uv.build-backend.editable-mode`.
+    pub(crate) fn editable_mode(&self) -> EditableMode {
+        self.build_backend()
+            .map(|build_backend| build_backend.editable_mode)
+            .unwrap_or_default()
+    }
+
+    /// The directories to add to the wheel's `.data` categories, from
+    /// `tool.uv.build-backend.data`.
+    pub(crate) fn data(&self) -> Option<&BTreeMap<String, PathBuf>> {
+        self.build_backend()
+            .map(|build_backend| &build_backend.data)
+    }
+
     pub(crate) fn parse(contents: &str) -> Result<Self, Error> {
         Ok(toml::from_str(contents)?)
     }
@@ -620,6 +665,79 @@ enum Contact {
     Email { email: String },
 }
 
+/// The `[tool]` section of a pyproject.toml.
+#[derive(Deserialize, Debug, Clone)]
+struct Tool {
+    /// The `[tool.uv]` section.
+    uv: Option<ToolUv>,
+}
+
+/// The `[tool.uv]` section, of which the build backend only reads `build-backend`.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "kebab-case")]
+struct ToolUv {
+    /// The `[tool.uv.build-backend]` section.
+    build_backend: Option<BuildBackendSettings>,
+}
+
+/// The `[tool.uv.build-backend]` section with the settings of the uv build backend.
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(default, rename_all = "kebab-case")]
+struct BuildBackendSettings {
+    /// Custom build steps, such as code generation or asset compilation, that run before the wheel
+    /// is assembled.
+    hooks: Vec<BuildHook>,
+    /// Glob patterns for additional files to include, relative to the project root.
+    ///
+    /// By default, only the module in `src/<module>` is included in the wheel. Matching files in
+    /// the `src` directory, e.g., another top-level module, are added with their path relative to
+    /// `src`.
+    include: Vec<String>,
+    /// Glob patterns for files and directories to exclude, relative to the project root, e.g.,
+    /// `**/__pycache__`.
+    ///
+    /// Exclusions take precedence over both the module and the `include` patterns.
+    exclude: Vec<String>,
+    /// Directories to install into the wheel's `.data` categories, relative to the project root.
+    ///
+    /// The keys are the categories from the wheel specification, i.e., `purelib`, `platlib`,
+    /// `headers`, `scripts` and `data`. For example, `scripts = "scripts"` installs the files in
+    /// the `scripts` directory as executables, alongside the console and GUI entry points.
+    data: BTreeMap<String, PathBuf>,
+    /// How editable installs expose the module.
+    editable_mode: EditableMode,
+}
+
+/// The mechanism used by editable installs to make the module importable.
+#[derive(Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum EditableMode {
+    /// Add the `src` directory to `sys.path` with a `.pth` file.
+    ///
+    /// This is the most compatible mechanism, e.g., with static analysis tools, but it also exposes
+    /// any other modules in the `src` directory.
+    #[default]
+    Pth,
+    /// Install an import hook that only exposes the module of the project.
+    Strict,
+}
+
+/// A custom build step declared in `tool.uv.build-backend.hooks`.
+///
+/// ```toml
+/// [[tool.uv.build-backend.hooks]]
+/// command = ["python", "scripts/generate.py"]
+/// ```
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub(crate) struct BuildHook {
+    /// The program to run, followed by its arguments.
+    ///
+    /// The command is run in the source tree and inherits the environment of the build backend,
+    /// i.e., `python` refers to the interpreter of the isolated build environment.
+    pub(crate) command: Vec<String>,
+}
+
 /// The `[build-system]` section of a pyproject.toml as specified in PEP 517.
 #[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
 #[serde(rename_all = "kebab-case")]
@@ -1,4 +1,5 @@
 use super::*;
+use indoc::{formatdoc, indoc};
 use insta::assert_snapshot;
 use std::str::FromStr;
 use tempfile::TempDir;
@@ -126,3 +127,221 @@ fn test_prepare_metadata() {
         Tag: py3-none-any
     "###);
 }
+
+/// Write a minimal project with the given `tool.uv.build-backend` section.
+fn backend_project(build_backend: &str) -> TempDir {
+    let src = TempDir::new().unwrap();
+    fs_err::write(
+        src.path().join("pyproject.toml"),
+        formatdoc! {r#"
+            [project]
+            name = "hooks"
+            version = "0.1.0"
+
+            [build-system]
+            requires = ["uv>=0.4.15,<5"]
+            build-backend = "uv"
+
+            [tool.uv.build-backend]
+            {build_backend}
+        "#},
+    )
+    .unwrap();
+    fs_err::create_dir_all(src.path().join("src").join("hooks")).unwrap();
+    fs_err::write(src.path().join("src").join("hooks").join("__init__.py"), "").unwrap();
+    src
+}
+
+/// Check that files generated by a build hook are included in the wheel.
+#[test]
+#[cfg(unix)]
+fn test_build_hooks() {
+    let src = backend_project(indoc! {r#"
+        hooks = [
+            { command = ["sh", "-c", "echo 'VERSION = \"0.1.0\"' > src/hooks/_version.py"] },
+        ]
+    "#});
+    let dist = TempDir::new().unwrap();
+    let filename = build(src.path(), dist.path(), None, "1.0.0+test").unwrap();
+
+    let wheel = File::open(dist.path().join(filename.to_string())).unwrap();
+    let mut archive = zip::ZipArchive::new(wheel).unwrap();
+    let mut files: Vec<_> = archive.file_names().map(ToString::to_string).collect();
+    files.sort();
+    assert_snapshot!(files.join("\n"), @r"
+        hooks-0.1.0.dist-info/
+        hooks-0.1.0.dist-info/METADATA
+        hooks-0.1.0.dist-info/RECORD
+        hooks-0.1.0.dist-info/WHEEL
+        hooks/
+        hooks/__init__.py
+        hooks/_version.py
+        ");
+
+    let mut generated = String::new();
+    archive
+        .by_name("hooks/_version.py")
+        .unwrap()
+        .read_to_string(&mut generated)
+        .unwrap();
+    assert_eq!(generated, "VERSION = \"0.1.0\"\n");
+}
+
+/// A failing build hook aborts the build.
+#[test]
+#[cfg(unix)]
+fn test_build_hooks_failure() {
+    let src = backend_project(indoc! {r#"
+        hooks = [
+            { command = ["sh", "-c", "exit 3"] },
+        ]
+    "#});
+    let dist = TempDir::new().unwrap();
+    let err = build(src.path(), dist.path(), None, "1.0.0+test").unwrap_err();
+    assert_snapshot!(err.to_string(), @"Build hook `sh -c exit 3` failed with exit status: 3");
+}
+
+/// Build hooks require a command.
+#[test]
+fn test_build_hooks_empty_command() {
+    let src = backend_project("hooks = [{ command = [] }]");
+    let dist = TempDir::new().unwrap();
+    let err = build(src.path(), dist.path(), None, "1.0.0+test").unwrap_err();
+    assert_snapshot!(err.to_string(), @"Build hooks must have a non-empty `command`");
+}
+
+/// List the files in a wheel, in archive order.
+fn wheel_files(path: &Path) -> Vec<String> {
+    let mut archive = zip::ZipArchive::new(File::open(path).unwrap()).unwrap();
+    (0..archive.len())
+        .map(|index| archive.by_index(index).unwrap().name().to_string())
+        .collect()
+}
+
+/// Check the `include`, `exclude` and `data` settings, and that the archive order is
+/// deterministic.
+#[test]
+fn test_include_exclude_data() {
+    let src = backend_project(indoc! {r#"
+        include = ["src/extra/**/*.py"]
+        exclude = ["**/__pycache__", "**/*.tmp"]
+
+        [tool.uv.build-backend.data]
+        scripts = "scripts"
+        data = "share"
+    "#});
+    let root = src.path();
+    for (path, contents) in [
+        ("src/hooks/py.typed", ""),
+        ("src/hooks/__pycache__/__init__.cpython-312.pyc", ""),
+        ("src/hooks/scratch.tmp", ""),
+        ("src/extra/b.py", ""),
+        ("src/extra/a.py", ""),
+        ("src/extra/notes.txt", ""),
+        ("src/unrelated.py", ""),
+        ("scripts/hello", "#!python\nprint('hello')\n"),
+        ("share/hooks/config.toml", ""),
+        ("share/hooks/scratch.tmp", ""),
+    ] {
+        let path = root.join(path);
+        fs_err::create_dir_all(path.parent().unwrap()).unwrap();
+        fs_err::write(path, contents).unwrap();
+    }
+
+    let dist = TempDir::new().unwrap();
+    let filename = build(root, dist.path(), None, "1.0.0+test").unwrap();
+    assert_snapshot!(wheel_files(&dist.path().join(filename.to_string())).join("\n"), @r"
+        extra/
+        extra/a.py
+        extra/b.py
+        hooks/
+        hooks/__init__.py
+        hooks/py.typed
+        hooks-0.1.0.data/
+        hooks-0.1.0.data/data/
+        hooks-0.1.0.data/data/hooks/
+        hooks-0.1.0.data/data/hooks/config.toml
+        hooks-0.1.0.data/scripts/
+        hooks-0.1.0.data/scripts/hello
+        hooks-0.1.0.dist-info/
+        hooks-0.1.0.dist-info/WHEEL
+        hooks-0.1.0.dist-info/METADATA
+        hooks-0.1.0.dist-info/RECORD
+        ");
+}
+
+/// Only the categories from the wheel specification are supported.
+#[test]
+fn test_invalid_data_category() {
+    let src = backend_project(indoc! {r#"
+        [tool.uv.build-backend.data]
+        bin = "scripts"
+    "#});
+    let dist = TempDir::new().unwrap();
+    let err = build(src.path(), dist.path(), None, "1.0.0+test").unwrap_err();
+    assert_snapshot!(err.to_string(), @"Unknown wheel data category `bin` in `tool.uv.build-backend.data`, expected one of: `purelib`, `platlib`, `headers`, `scripts`, `data`");
+}
+
+/// Snapshot the contents of an editable wheel, in both modes.
+#[test]
+fn test_build_editable() {
+    for (mode, expected) in [
+        (
+            "pth",
+            vec![
+                "hooks.pth",
+                "hooks-0.1.0.dist-info/",
+                "hooks-0.1.0.dist-info/WHEEL",
+                "hooks-0.1.0.dist-info/METADATA",
+                "hooks-0.1.0.dist-info/RECORD",
+            ],
+        ),
+        (
+            "strict",
+            vec![
+                "_hooks_editable.py",
+                "_hooks_editable.pth",
+                "hooks-0.1.0.dist-info/",
+                "hooks-0.1.0.dist-info/WHEEL",
+                "hooks-0.1.0.dist-info/METADATA",
+                "hooks-0.1.0.dist-info/RECORD",
+            ],
+        ),
+    ] {
+        let src = backend_project(&format!("editable-mode = \"{mode}\""));
+        let dist = TempDir::new().unwrap();
+        let filename = build_editable(src.path(), dist.path(), None, "1.0.0+test").unwrap();
+        let wheel = dist.path().join(filename.to_string());
+        assert_eq!(wheel_files(&wheel), expected);
+
+        let mut archive = zip::ZipArchive::new(File::open(&wheel).unwrap()).unwrap();
+        if mode == "strict" {
+            let mut pth = String::new();
+            archive
+                .by_name("_hooks_editable.pth")
+                .unwrap()
+                .read_to_string(&mut pth)
+                .unwrap();
+            assert_eq!(pth, "import _hooks_editable\n");
+
+            let mut finder = String::new();
+            archive
+                .by_name("_hooks_editable.py")
+                .unwrap()
+                .read_to_string(&mut finder)
+                .unwrap();
+            assert!(finder.contains("MODULE = 'hooks'"));
+        } else {
+            let mut pth = String::new();
+            archive
+                .by_name("hooks.pth")
+                .unwrap()
+                .read_to_string(&mut pth)
+                .unwrap();
+            assert_eq!(
+                Path::new(&pth),
+                std::path::absolute(src.path().join("src")).unwrap()
+            );
+        }
+    }
+}
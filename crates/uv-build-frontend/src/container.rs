@@ -0,0 +1,193 @@
+//! Build wheels inside a container, rather than in a build environment on the host.
+
+use std::ffi::OsString;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use fs_err as fs;
+use tokio::process::Command;
+use tracing::debug;
+
+use uv_configuration::Constraints;
+use uv_distribution_types::{Index, IndexLocations};
+use uv_fs::Simplified;
+use uv_pypi_types::RequirementSource;
+
+use crate::Error;
+
+/// The directory at which the source tree is mounted in the container.
+pub(crate) const SOURCE_DIR: &str = "/uv/source";
+
+/// The directory at which the output directory is mounted in the container.
+pub(crate) const OUTPUT_DIR: &str = "/uv/output";
+
+/// The name of the build constraints file, written to the output directory.
+const CONSTRAINTS: &str = "constraints.txt";
+
+/// A container in which to build a wheel.
+///
+/// `pip` within the container is configured to match the host's index settings, such that build
+/// requirements are fetched from the same indexes (with the same credentials), subject to the same
+/// build constraints, and not at all when running offline.
+#[derive(Debug, Clone)]
+pub(crate) struct Container {
+    /// The image from which to create the container.
+    image: String,
+    /// The environment variables with which to configure `pip` in the container.
+    environment: Vec<(&'static str, String)>,
+    /// Local index and `--find-links` directories to mount into the container.
+    mounts: Vec<(PathBuf, String)>,
+    /// The build constraints, in `requirements.txt` format.
+    constraints: Option<String>,
+    /// Whether to disable network access within the container.
+    offline: bool,
+}
+
+impl Container {
+    /// Create a [`Container`] from the given image, mirroring the host's index settings.
+    pub(crate) async fn new(
+        image: String,
+        locations: &IndexLocations,
+        constraints: &Constraints,
+        offline: bool,
+    ) -> Result<Self, Error> {
+        let mut mounts = Vec::new();
+        let mut environment = Vec::new();
+
+        if offline || locations.no_index() {
+            environment.push(("PIP_NO_INDEX", "1".to_string()));
+        } else {
+            if let Some(index) = locations.default_index() {
+                if let Some(url) = container_url(index, &mut mounts).await? {
+                    environment.push(("PIP_INDEX_URL", url));
+                }
+            }
+            let mut extra = Vec::new();
+            for index in locations.implicit_indexes() {
+                extra.extend(container_url(index, &mut mounts).await?);
+            }
+            if !extra.is_empty() {
+                environment.push(("PIP_EXTRA_INDEX_URL", extra.join(" ")));
+            }
+        }
+
+        // When offline, only local `--find-links` directories remain usable.
+        let mut find_links = Vec::new();
+        for index in locations.flat_indexes() {
+            if offline && index.url().url().scheme() != "file" {
+                continue;
+            }
+            find_links.extend(container_url(index, &mut mounts).await?);
+        }
+        if !find_links.is_empty() {
+            environment.push(("PIP_FIND_LINKS", find_links.join(" ")));
+        }
+
+        // `pip` applies `PIP_CONSTRAINT` to the isolated build environment, too.
+        let mut contents = String::new();
+        for requirement in constraints.requirements() {
+            let RequirementSource::Registry { specifier, .. } = &requirement.source else {
+                debug!("Ignoring non-registry build constraint in container: `{requirement}`");
+                continue;
+            };
+            write!(contents, "{}{specifier}", requirement.name).unwrap();
+            if let Some(marker) = requirement.marker.contents() {
+                write!(contents, " ; {marker}").unwrap();
+            }
+            contents.push('\n');
+        }
+        let constraints = if contents.is_empty() {
+            None
+        } else {
+            environment.push(("PIP_CONSTRAINT", format!("{OUTPUT_DIR}/{CONSTRAINTS}")));
+            Some(contents)
+        };
+
+        Ok(Self {
+            image,
+            environment,
+            mounts,
+            constraints,
+            offline,
+        })
+    }
+
+    /// The image from which to create the container.
+    pub(crate) fn image(&self) -> &str {
+        &self.image
+    }
+
+    /// Add the index, network, and constraint settings to a container `run` command, writing any
+    /// build constraints to the output directory.
+    ///
+    /// Values are passed via the environment of the container runtime, rather than on its command
+    /// line, to avoid exposing credentials in the process list.
+    pub(crate) fn configure(
+        &self,
+        command: &mut Command,
+        output_dir: &Path,
+    ) -> std::io::Result<()> {
+        if self.offline {
+            command.args(["--network", "none"]);
+        }
+        for (host, container) in &self.mounts {
+            let mut mount = mount(host, container);
+            mount.push(":ro");
+            command.arg("--volume").arg(mount);
+        }
+        if let Some(constraints) = &self.constraints {
+            fs::write(output_dir.join(CONSTRAINTS), constraints)?;
+        }
+        for (key, value) in &self.environment {
+            command.arg("--env").arg(key).env(key, value);
+        }
+        Ok(())
+    }
+}
+
+/// Format a bind mount of a host path into a container, as in `--volume <host>:<container>`.
+pub(crate) fn mount(host: &Path, container: &str) -> OsString {
+    let mut mount = host.simplified().as_os_str().to_os_string();
+    mount.push(":");
+    mount.push(container);
+    mount
+}
+
+/// Return the URL at which an index is reachable from within the container.
+///
+/// Local directories are mounted into the container; remote URLs include the index credentials, if
+/// any.
+async fn container_url(
+    index: &Index,
+    mounts: &mut Vec<(PathBuf, String)>,
+) -> Result<Option<String>, Error> {
+    let url = index.url().url();
+    if url.scheme() == "file" {
+        let Ok(path) = url.to_file_path() else {
+            debug!("Ignoring non-local file index in container: `{url}`");
+            return Ok(None);
+        };
+        let container = format!("/uv/index/{}", mounts.len());
+        let container_url = format!("file://{container}");
+        mounts.push((path, container));
+        return Ok(Some(container_url));
+    }
+    // Credentials from the environment take precedence over those configured on the index.
+    let credentials = if let Some(credentials) = index.credentials() {
+        Some(credentials)
+    } else if let Some(secrets) = index.secret_credentials() {
+        let credentials = secrets
+            .resolve()
+            .await
+            .map_err(|err| Error::ContainerCredentials(index.url().redacted().to_string(), err))?;
+        Some(credentials)
+    } else {
+        None
+    };
+    let url = if let Some(credentials) = credentials {
+        credentials.apply(url.clone())
+    } else {
+        url.clone()
+    };
+    Ok(Some(url.to_string()))
+}
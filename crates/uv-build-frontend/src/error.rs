@@ -73,6 +73,8 @@ pub enum Error {
     RequirementsInstall(&'static str, #[source] anyhow::Error),
     #[error("Failed to create temporary virtualenv")]
     Virtualenv(#[from] uv_virtualenv::Error),
+    #[error("Failed to read cached build environment")]
+    CachedEnvironment(#[source] uv_python::Error),
     #[error("Failed to run `{0}`")]
     CommandFailed(PathBuf, #[source] io::Error),
     #[error(transparent)]
@@ -81,6 +83,12 @@ pub enum Error {
     MissingHeader(#[from] MissingHeaderError),
     #[error("Failed to build PATH for build script")]
     BuildScriptPath(#[source] env::JoinPathsError),
+    #[error("Failed to resolve the credentials for `{0}` to pass to the build container")]
+    ContainerCredentials(String, #[source] uv_auth::SecretError),
+    #[error("The wheel built in container `{0}` is not compatible with the target Python interpreter: `{1}`")]
+    IncompatibleContainerWheel(String, String),
+    #[error(transparent)]
+    Tags(#[from] uv_platform_tags::TagsError),
 }
 
 #[derive(Debug)]
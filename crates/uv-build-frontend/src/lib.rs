@@ -2,6 +2,7 @@
 //!
 //! <https://packaging.python.org/en/latest/specifications/source-distribution-format/>
 
+mod container;
 mod error;
 
 use fs_err as fs;
@@ -27,8 +28,13 @@ use tokio::process::Command;
 use tokio::sync::{Mutex, Semaphore};
 use tracing::{debug, info_span, instrument, Instrument};
 
-use uv_configuration::{BuildKind, BuildOutput, ConfigSettings, LowerBound, SourceStrategy};
+use uv_cache::CacheBucket;
+use uv_cache_key::{cache_digest, hash_digest};
+use uv_configuration::{
+    BuildKind, BuildOutput, ConfigSettings, Constraints, LowerBound, SourceStrategy,
+};
 use uv_distribution::RequiresDist;
+use uv_distribution_filename::WheelFilename;
 use uv_distribution_types::{IndexLocations, Resolution};
 use uv_fs::{rename_with_retry, PythonExt, Simplified};
 use uv_pep440::Version;
@@ -38,6 +44,7 @@ use uv_python::{Interpreter, PythonEnvironment};
 use uv_static::EnvVars;
 use uv_types::{BuildContext, BuildIsolation, SourceBuildTrait};
 
+use crate::container::{Container, OUTPUT_DIR, SOURCE_DIR};
 pub use crate::error::{Error, MissingHeaderCause};
 
 /// The default backend to use when PEP 517 is used without a `build-system` section.
@@ -187,10 +194,23 @@ impl Pep517Backend {
 }
 
 /// Uses an [`Rc`] internally, clone freely.
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Clone)]
 pub struct SourceBuildContext {
     /// An in-memory resolution of the default backend's requirements for PEP 517 builds.
     default_resolution: Rc<Mutex<Option<Resolution>>>,
+    /// A semaphore bounding the number of build backend processes across all builds.
+    build_permits: Rc<Semaphore>,
+}
+
+impl SourceBuildContext {
+    /// Create a [`SourceBuildContext`] that runs at most `concurrent_builds` build backend
+    /// processes at once.
+    pub fn new(concurrent_builds: usize) -> Self {
+        Self {
+            default_resolution: Rc::default(),
+            build_permits: Rc::new(Semaphore::new(concurrent_builds)),
+        }
+    }
 }
 
 /// Holds the state through a series of PEP 517 frontend to backend calls or a single `setup.py`
@@ -208,6 +228,9 @@ pub struct SourceBuild {
     project: Option<Project>,
     /// The virtual environment in which to build the source distribution.
     venv: PythonEnvironment,
+    /// The container in which to build the wheel, if the build should run in a container rather
+    /// than on the host.
+    container: Option<Container>,
     /// Populated if `prepare_metadata_for_build_wheel` was called.
     ///
     /// > If the build frontend has previously called `prepare_metadata_for_build_wheel` and depends
@@ -255,11 +278,12 @@ impl SourceBuild {
         locations: &IndexLocations,
         source_strategy: SourceStrategy,
         config_settings: ConfigSettings,
+        build_constraints: &Constraints,
+        offline: bool,
         build_isolation: BuildIsolation<'_>,
         build_kind: BuildKind,
         mut environment_variables: FxHashMap<OsString, OsString>,
         level: BuildOutput,
-        concurrent_builds: usize,
     ) -> Result<Self, Error> {
         let temp_dir = build_context.cache().environment()?;
 
@@ -278,6 +302,7 @@ impl SourceBuild {
             fallback_package_name,
             locations,
             source_strategy,
+            build_context.variants(),
             &default_backend,
         )
         .await
@@ -294,41 +319,60 @@ impl SourceBuild {
             .or(fallback_package_version)
             .cloned();
 
-        // Create a virtual environment, or install into the shared environment if requested.
-        let venv = if let Some(venv) = build_isolation.shared_environment(package_name.as_ref()) {
-            venv.clone()
+        // Determine whether to build in a container. Editable and source distribution builds always
+        // run on the host, since they don't require a native toolchain. The image is selected by
+        // the requested package name, which is also used to key the built wheel in the cache.
+        let container = if let (BuildKind::Wheel, Some(image)) = (
+            build_kind,
+            build_context
+                .build_containers()
+                .image(fallback_package_name),
+        ) {
+            Some(Container::new(image.to_string(), locations, build_constraints, offline).await?)
         } else {
-            uv_virtualenv::create_venv(
-                temp_dir.path(),
-                interpreter.clone(),
-                uv_virtualenv::Prompt::None,
-                false,
-                false,
-                false,
-                false,
-            )?
+            None
         };
 
-        // Set up the build environment. If build isolation is disabled, we assume the build
-        // environment is already setup.
-        if build_isolation.is_isolated(package_name.as_ref()) {
-            debug!("Resolving build requirements");
+        // Set up the build environment. If build isolation is disabled, we install into the
+        // shared environment, which we assume is already set up. If building in a container, the
+        // build requirements are installed within the container instead.
+        let mut build_environment =
+            if let Some(venv) = build_isolation.shared_environment(package_name.as_ref()) {
+                debug!("Proceeding without build isolation");
+                BuildEnvironment::Shared(venv.clone())
+            } else if let Some(container) = &container {
+                debug!(
+                    "Deferring build environment setup to container: `{}`",
+                    container.image()
+                );
+                BuildEnvironment::Temporary(uv_virtualenv::create_venv(
+                    temp_dir.path(),
+                    interpreter.clone(),
+                    uv_virtualenv::Prompt::None,
+                    false,
+                    false,
+                    false,
+                    false,
+                )?)
+            } else {
+                debug!("Resolving build requirements");
 
-            let resolved_requirements = Self::get_resolved_requirements(
-                build_context,
-                source_build_context,
-                &default_backend,
-                &pep517_backend,
-            )
-            .await?;
+                let resolved_requirements = Self::get_resolved_requirements(
+                    build_context,
+                    source_build_context.clone(),
+                    &default_backend,
+                    &pep517_backend,
+                )
+                .await?;
 
-            build_context
-                .install(&resolved_requirements, &venv)
-                .await
-                .map_err(|err| Error::RequirementsInstall("`build-system.requires`", err))?;
-        } else {
-            debug!("Proceeding without build isolation");
-        }
+                BuildEnvironment::setup(
+                    build_context,
+                    interpreter,
+                    &resolved_requirements,
+                    &temp_dir,
+                )
+                .await?
+            };
 
         // Figure out what the modified path should be, and remove the PATH variable from the
         // environment variables if it's there.
@@ -338,7 +382,7 @@ impl SourceBuild {
         let os_path = env::var_os(EnvVars::PATH);
 
         // Prepend the user supplied PATH to the existing OS PATH
-        let modified_path = if let Some(user_path) = user_path {
+        let base_path = if let Some(user_path) = user_path {
             match os_path {
                 // Prepend the user supplied PATH to the existing PATH
                 Some(env_path) => {
@@ -354,24 +398,26 @@ impl SourceBuild {
         };
 
         // Prepend the venv bin directory to the modified path
-        let modified_path = if let Some(path) = modified_path {
-            let venv_path = iter::once(venv.scripts().to_path_buf()).chain(env::split_paths(&path));
-            env::join_paths(venv_path).map_err(Error::BuildScriptPath)?
-        } else {
-            OsString::from(venv.scripts())
-        };
+        let mut modified_path = prepend_scripts(build_environment.venv(), base_path.as_ref())?;
 
         // Create the PEP 517 build environment. If build isolation is disabled, we assume the build
         // environment is already setup.
-        let runner = PythonRunner::new(concurrent_builds, level);
-        if build_isolation.is_isolated(package_name.as_ref()) {
+        let runner = PythonRunner::new(
+            source_build_context.build_permits.clone(),
+            level,
+            version_id
+                .clone()
+                .or_else(|| package_name.as_ref().map(ToString::to_string)),
+        );
+        if container.is_none() && build_isolation.is_isolated(package_name.as_ref()) {
             debug!("Creating PEP 517 build environment");
 
-            create_pep517_build_environment(
+            let extended = create_pep517_build_environment(
                 &runner,
                 &source_tree,
                 install_path,
-                &venv,
+                interpreter,
+                &build_environment,
                 &pep517_backend,
                 build_context,
                 package_name.as_ref(),
@@ -387,6 +433,13 @@ impl SourceBuild {
                 &temp_dir,
             )
             .await?;
+
+            // If the build backend required additional requirements, and the build environment
+            // came from the cache, switch to an environment that includes them.
+            if let Some(extended) = extended {
+                build_environment = extended;
+                modified_path = prepend_scripts(build_environment.venv(), base_path.as_ref())?;
+            }
         }
 
         Ok(Self {
@@ -394,7 +447,8 @@ impl SourceBuild {
             source_tree,
             pep517_backend,
             project,
-            venv,
+            venv: build_environment.into_venv(),
+            container,
             build_kind,
             level,
             config_settings,
@@ -443,6 +497,7 @@ impl SourceBuild {
         package_name: Option<&PackageName>,
         locations: &IndexLocations,
         source_strategy: SourceStrategy,
+        variants: &[String],
         default_backend: &Pep517Backend,
     ) -> Result<(Pep517Backend, Option<Project>), Box<Error>> {
         match fs::read_to_string(source_tree.join("pyproject.toml")) {
@@ -477,6 +532,7 @@ impl SourceBuild {
                                     None,
                                     locations,
                                     source_strategy,
+                                    variants,
                                     LowerBound::Allow,
                                 )
                                 .await
@@ -544,6 +600,12 @@ impl SourceBuild {
             return Ok(Some(metadata_dir.clone()));
         }
 
+        // When building in a container, the build backend isn't available on the host, so the
+        // metadata is read from the built wheel instead.
+        if self.container.is_some() {
+            return Ok(None);
+        }
+
         // Hatch allows for highly dynamic customization of metadata via hooks. In such cases, Hatch
         // can't uphold the PEP 517 contract, in that the metadata Hatch would return by
         // `prepare_metadata_for_build_wheel` isn't guaranteed to match that of the built wheel.
@@ -659,9 +721,12 @@ impl SourceBuild {
 
         // Prevent clashes from two uv processes building distributions in parallel.
         let tmp_dir = tempdir_in(&wheel_dir)?;
-        let filename = self
-            .pep517_build(tmp_dir.path(), &self.pep517_backend)
-            .await?;
+        let filename = if let Some(container) = &self.container {
+            self.container_build(tmp_dir.path(), container).await?
+        } else {
+            self.pep517_build(tmp_dir.path(), &self.pep517_backend)
+                .await?
+        };
 
         let from = tmp_dir.path().join(&filename);
         let to = wheel_dir.join(&filename);
@@ -669,6 +734,120 @@ impl SourceBuild {
         Ok(filename)
     }
 
+    /// Build a wheel inside the given container.
+    ///
+    /// The source tree and output directory are mounted into the container, and the wheel is
+    /// built by `pip` with the container's Python interpreter of the same minor version as the
+    /// build interpreter, such that the container's native toolchain is used in lieu of the host's.
+    async fn container_build(
+        &self,
+        output_dir: &Path,
+        container: &Container,
+    ) -> Result<String, Error> {
+        let image = container.image();
+        let runtime = env::var_os(EnvVars::UV_BUILD_CONTAINER_RUNTIME)
+            .filter(|runtime| !runtime.is_empty())
+            .map_or_else(|| PathBuf::from("docker"), PathBuf::from);
+        let interpreter = self.venv.interpreter();
+        let python = format!(
+            "python{}.{}",
+            interpreter.python_major(),
+            interpreter.python_minor()
+        );
+
+        let mut command = Command::new(&runtime);
+        command
+            .args(["run", "--rm"])
+            .arg("--volume")
+            .arg(container::mount(&self.source_tree, SOURCE_DIR))
+            .arg("--volume")
+            .arg(container::mount(output_dir, OUTPUT_DIR))
+            .args(["--workdir", SOURCE_DIR])
+            .args(["--env", "HOME=/tmp"]);
+
+        // Run as the owner of the source tree, such that any files written by the build remain
+        // accessible (and removable) on the host.
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::MetadataExt;
+
+            let metadata = fs::metadata(&self.source_tree)?;
+            command
+                .arg("--user")
+                .arg(format!("{}:{}", metadata.uid(), metadata.gid()));
+        }
+
+        container.configure(&mut command, output_dir)?;
+        for (key, value) in &self.environment_variables {
+            command.arg("--env").arg(key).env(key, value);
+        }
+
+        command.arg(image).arg(&python).args([
+            "-m",
+            "pip",
+            "wheel",
+            "--no-deps",
+            "--no-cache-dir",
+            "--wheel-dir",
+            OUTPUT_DIR,
+        ]);
+        for (key, value) in self.config_settings.entries() {
+            command
+                .arg("--config-settings")
+                .arg(format!("{key}={value}"));
+        }
+        command.arg(SOURCE_DIR);
+
+        debug!("Building wheel in container `{image}` with `{python}`");
+        let span = info_span!("run_container", image = image, version_id = self.version_id,);
+        let output = self
+            .runner
+            .run_command(command, &runtime)
+            .instrument(span)
+            .await?;
+        if !output.status.success() {
+            return Err(Error::from_command_output(
+                format!("Failed to build wheel in container `{}`", image.green()),
+                &output,
+                self.level,
+                self.package_name.as_ref(),
+                self.package_version.as_ref(),
+                self.version_id.as_deref(),
+            ));
+        }
+
+        // Identify the wheel that was written to the output directory.
+        let filename = fs::read_dir(output_dir)?
+            .filter_map(Result::ok)
+            .map(|entry| entry.file_name().to_string_lossy().to_string())
+            .find(|filename| filename.ends_with(".whl"));
+        let Some(filename) = filename else {
+            return Err(Error::from_command_output(
+                format!(
+                    "Failed to build wheel in container `{}`: no wheel found",
+                    image.green()
+                ),
+                &output,
+                self.level,
+                self.package_name.as_ref(),
+                self.package_version.as_ref(),
+                self.version_id.as_deref(),
+            ));
+        };
+
+        // The container's platform or Python ABI may differ from that of the target interpreter
+        // (e.g., a `musllinux` image on a `glibc` host), in which case the wheel is unusable.
+        let tags = interpreter.tags()?;
+        if !WheelFilename::from_str(&filename).is_ok_and(|wheel| wheel.is_compatible(tags)) {
+            return Err(Error::IncompatibleContainerWheel(
+                image.to_string(),
+                filename,
+            ));
+        }
+
+        Ok(filename)
+    }
+
     /// Perform a PEP 517 build for a wheel or source distribution (sdist).
     async fn pep517_build(
         &self,
@@ -804,12 +983,17 @@ fn escape_path_for_python(path: &Path) -> String {
         .replace('"', "\\\"")
 }
 
-/// Not a method because we call it before the builder is completely initialized
+/// Not a method because we call it before the builder is completely initialized.
+///
+/// Returns a replacement for the build environment if the backend requires additional
+/// requirements that can't be installed into the existing environment (i.e., because it's shared
+/// with other builds via the cache).
 async fn create_pep517_build_environment(
     runner: &PythonRunner,
     source_tree: &Path,
     install_path: &Path,
-    venv: &PythonEnvironment,
+    interpreter: &Interpreter,
+    build_environment: &BuildEnvironment,
     pep517_backend: &Pep517Backend,
     build_context: &impl BuildContext,
     package_name: Option<&PackageName>,
@@ -823,7 +1007,9 @@ async fn create_pep517_build_environment(
     environment_variables: &FxHashMap<OsString, OsString>,
     modified_path: &OsString,
     temp_dir: &TempDir,
-) -> Result<(), Error> {
+) -> Result<Option<BuildEnvironment>, Error> {
+    let venv = build_environment.venv();
+
     // Write the hook output to a file so that we can read it back reliably.
     let outfile = temp_dir
         .path()
@@ -931,6 +1117,7 @@ async fn create_pep517_build_environment(
                     None,
                     locations,
                     source_strategy,
+                    build_context.variants(),
                     LowerBound::Allow,
                 )
                 .await
@@ -963,21 +1150,161 @@ async fn create_pep517_build_environment(
             .await
             .map_err(|err| Error::RequirementsResolve("`build-system.requires`", err))?;
 
+        if matches!(build_environment, BuildEnvironment::Cached(_)) {
+            return Ok(Some(
+                BuildEnvironment::setup(build_context, interpreter, &resolution, temp_dir).await?,
+            ));
+        }
+
         build_context
             .install(&resolution, venv)
             .await
             .map_err(|err| Error::RequirementsInstall("`build-system.requires`", err))?;
     }
 
-    Ok(())
+    Ok(None)
+}
+
+/// The virtual environment in which a source distribution is built.
+#[derive(Debug)]
+enum BuildEnvironment {
+    /// An environment shared with the caller, as when build isolation is disabled.
+    Shared(PythonEnvironment),
+    /// An environment in the cache, shared with any builds that have the same interpreter and
+    /// build requirements. Must not be modified.
+    Cached(PythonEnvironment),
+    /// An environment created for this build alone.
+    Temporary(PythonEnvironment),
+}
+
+impl BuildEnvironment {
+    /// Create an environment with the given build requirements installed, reusing an environment
+    /// from the cache if one exists for the same interpreter and requirements.
+    ///
+    /// If the requirements include any distributions that don't come from a registry (like a
+    /// local build backend), the environment is created in `temp_dir` instead, since their
+    /// contents can change without changing the resolution.
+    async fn setup(
+        build_context: &impl BuildContext,
+        interpreter: &Interpreter,
+        resolution: &Resolution,
+        temp_dir: &TempDir,
+    ) -> Result<Self, Error> {
+        if resolution
+            .distributions()
+            .any(|dist| dist.index().is_none())
+        {
+            let venv = uv_virtualenv::create_venv(
+                temp_dir.path(),
+                interpreter.clone(),
+                uv_virtualenv::Prompt::None,
+                false,
+                false,
+                false,
+                false,
+            )?;
+            build_context
+                .install(resolution, &venv)
+                .await
+                .map_err(|err| Error::RequirementsInstall("`build-system.requires`", err))?;
+            return Ok(Self::Temporary(venv));
+        }
+
+        let cache = build_context.cache();
+
+        // Hash the resolution, along with the interpreter (based on its path).
+        let resolution_hash = {
+            let distributions = resolution.distributions().collect::<Vec<_>>();
+            hash_digest(&distributions)
+        };
+        let interpreter_hash = cache_digest(&interpreter.sys_executable());
+
+        // Search in the content-addressed cache.
+        let cache_entry = cache.entry(
+            CacheBucket::BuildEnvironments,
+            interpreter_hash,
+            resolution_hash,
+        );
+
+        // Reuse the environment, unless any of the build requirements should be refreshed.
+        let fresh = resolution.packages().all(|package| {
+            cache
+                .freshness(&cache_entry, Some(package))
+                .is_ok_and(uv_cache::Freshness::is_fresh)
+        });
+        if fresh {
+            if let Ok(root) = fs_err::read_link(cache_entry.path()) {
+                if let Ok(venv) = PythonEnvironment::from_root(&root, cache) {
+                    debug!("Using cached build environment: `{}`", root.user_display());
+                    return Ok(Self::Cached(venv));
+                }
+            }
+        }
+
+        // Create the environment in the cache, then relocate it to its content-addressed location.
+        let temp_dir = cache.environment()?;
+        let venv = uv_virtualenv::create_venv(
+            temp_dir.path(),
+            interpreter.clone(),
+            uv_virtualenv::Prompt::None,
+            false,
+            false,
+            true,
+            false,
+        )?;
+        build_context
+            .install(resolution, &venv)
+            .await
+            .map_err(|err| Error::RequirementsInstall("`build-system.requires`", err))?;
+
+        let id = cache
+            .persist(temp_dir.into_path(), cache_entry.path())
+            .await?;
+        let root = cache.archive(&id);
+        debug!("Cached build environment at: `{}`", root.user_display());
+
+        Ok(Self::Cached(
+            PythonEnvironment::from_root(root, cache).map_err(Error::CachedEnvironment)?,
+        ))
+    }
+
+    /// Return the underlying [`PythonEnvironment`].
+    fn venv(&self) -> &PythonEnvironment {
+        match self {
+            Self::Shared(venv) | Self::Cached(venv) | Self::Temporary(venv) => venv,
+        }
+    }
+
+    /// Convert the [`BuildEnvironment`] into its underlying [`PythonEnvironment`].
+    fn into_venv(self) -> PythonEnvironment {
+        match self {
+            Self::Shared(venv) | Self::Cached(venv) | Self::Temporary(venv) => venv,
+        }
+    }
+}
+
+/// Prepend the scripts directory of the given environment to a `PATH`.
+fn prepend_scripts(venv: &PythonEnvironment, path: Option<&OsString>) -> Result<OsString, Error> {
+    if let Some(path) = path {
+        let venv_path = iter::once(venv.scripts().to_path_buf()).chain(env::split_paths(path));
+        env::join_paths(venv_path).map_err(Error::BuildScriptPath)
+    } else {
+        Ok(OsString::from(venv.scripts()))
+    }
 }
 
 /// A runner that manages the execution of external python processes with a
 /// concurrency limit.
+///
+/// The concurrency limit is shared across all builds, such that multiple source distributions
+/// can be built in parallel without exceeding the limit.
 #[derive(Debug)]
 struct PythonRunner {
-    control: Semaphore,
+    control: Rc<Semaphore>,
     level: BuildOutput,
+    /// A label identifying the distribution being built, used to disambiguate interleaved output
+    /// from concurrent builds.
+    label: Option<String>,
 }
 
 #[derive(Debug)]
@@ -988,11 +1315,12 @@ struct PythonRunnerOutput {
 }
 
 impl PythonRunner {
-    /// Create a `PythonRunner` with the provided concurrency limit and output level.
-    fn new(concurrency: usize, level: BuildOutput) -> Self {
+    /// Create a `PythonRunner` with the provided concurrency limit, output level, and label.
+    fn new(control: Rc<Semaphore>, level: BuildOutput, label: Option<String>) -> Self {
         Self {
-            control: Semaphore::new(concurrency),
+            control,
             level,
+            label,
         }
     }
 
@@ -1009,11 +1337,36 @@ impl PythonRunner {
         source_tree: &Path,
         environment_variables: &FxHashMap<OsString, OsString>,
         modified_path: &OsString,
+    ) -> Result<PythonRunnerOutput, Error> {
+        let mut command = Command::new(venv.python_executable());
+        command
+            .args(["-c", script])
+            .current_dir(source_tree.simplified())
+            .envs(environment_variables)
+            .env(EnvVars::PATH, modified_path)
+            .env(EnvVars::VIRTUAL_ENV, venv.root())
+            .env(EnvVars::CLICOLOR_FORCE, "1")
+            .env(EnvVars::PYTHONIOENCODING, "utf-8:backslashreplace");
+        self.run_command(command, venv.python_executable()).await
+    }
+
+    /// Spawn the given command, capturing its output.
+    ///
+    /// If the concurrency limit has been reached this method will wait until a pending
+    /// process completes before spawning this one.
+    async fn run_command(
+        &self,
+        mut command: Command,
+        program: &Path,
     ) -> Result<PythonRunnerOutput, Error> {
         /// Read lines from a reader and store them in a buffer.
+        ///
+        /// When logging, each line is prefixed with the label of the build (if any), such that the
+        /// output of concurrent builds remains readable when interleaved.
         async fn read_from(
             mut reader: tokio::io::Split<tokio::io::BufReader<impl tokio::io::AsyncRead + Unpin>>,
             mut printer: Printer,
+            label: Option<&str>,
             buffer: &mut Vec<String>,
         ) -> io::Result<()> {
             loop {
@@ -1021,7 +1374,14 @@ impl PythonRunner {
                     Some(line_buf) => {
                         let line_buf = line_buf.strip_suffix(b"\r").unwrap_or(&line_buf);
                         let line = String::from_utf8_lossy(line_buf).into();
-                        let _ = write!(printer, "{line}");
+                        match (printer, label) {
+                            (Printer::Debug, Some(label)) => {
+                                let _ = write!(printer, "[{label}] {line}");
+                            }
+                            _ => {
+                                let _ = write!(printer, "{line}");
+                            }
+                        }
                         buffer.push(line);
                     }
                     None => return Ok(()),
@@ -1031,18 +1391,11 @@ impl PythonRunner {
 
         let _permit = self.control.acquire().await.unwrap();
 
-        let mut child = Command::new(venv.python_executable())
-            .args(["-c", script])
-            .current_dir(source_tree.simplified())
-            .envs(environment_variables)
-            .env(EnvVars::PATH, modified_path)
-            .env(EnvVars::VIRTUAL_ENV, venv.root())
-            .env(EnvVars::CLICOLOR_FORCE, "1")
-            .env(EnvVars::PYTHONIOENCODING, "utf-8:backslashreplace")
+        let mut child = command
             .stdout(std::process::Stdio::piped())
             .stderr(std::process::Stdio::piped())
             .spawn()
-            .map_err(|err| Error::CommandFailed(venv.python_executable().to_path_buf(), err))?;
+            .map_err(|err| Error::CommandFailed(program.to_path_buf(), err))?;
 
         // Create buffers to capture `stdout` and `stderr`.
         let mut stdout_buf = Vec::with_capacity(1024);
@@ -1055,16 +1408,23 @@ impl PythonRunner {
         // Asynchronously read from the in-memory pipes.
         let printer = Printer::from(self.level);
         let result = tokio::join!(
-            read_from(stdout_reader, printer, &mut stdout_buf),
-            read_from(stderr_reader, printer, &mut stderr_buf),
+            read_from(
+                stdout_reader,
+                printer,
+                self.label.as_deref(),
+                &mut stdout_buf
+            ),
+            read_from(
+                stderr_reader,
+                printer,
+                self.label.as_deref(),
+                &mut stderr_buf
+            ),
         );
         match result {
             (Ok(()), Ok(())) => {}
             (Err(err), _) | (_, Err(err)) => {
-                return Err(Error::CommandFailed(
-                    venv.python_executable().to_path_buf(),
-                    err,
-                ))
+                return Err(Error::CommandFailed(program.to_path_buf(), err))
             }
         }
 
@@ -1072,7 +1432,7 @@ impl PythonRunner {
         let status = child
             .wait()
             .await
-            .map_err(|err| Error::CommandFailed(venv.python_executable().to_path_buf(), err))?;
+            .map_err(|err| Error::CommandFailed(program.to_path_buf(), err))?;
 
         Ok(PythonRunnerOutput {
             stdout: stdout_buf,
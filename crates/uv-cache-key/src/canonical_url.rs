@@ -127,8 +127,9 @@ impl RepositoryUrl {
     pub fn new(url: &Url) -> Self {
         let mut url = CanonicalUrl::new(url).0;
 
-        // If a Git URL ends in a reference (like a branch, tag, or commit), remove it.
-        if url.scheme().starts_with("git+") {
+        // If a Git (or Mercurial) URL ends in a reference (like a branch, tag, or commit), remove
+        // it.
+        if url.scheme().starts_with("git+") || url.scheme().starts_with("hg+") {
             if let Some(prefix) = url
                 .path()
                 .rsplit_once('@')
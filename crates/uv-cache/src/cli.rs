@@ -29,6 +29,24 @@ pub struct CacheArgs {
     /// To view the location of the cache directory, run `uv cache dir`.
     #[arg(global = true, long, env = EnvVars::UV_CACHE_DIR)]
     pub cache_dir: Option<PathBuf>,
+
+    /// A namespace in which to store artifacts built from source distributions.
+    ///
+    /// Wheels built from source distributions are isolated from those built in other namespaces
+    /// (and from those built without a namespace), while downloaded wheels and other artifacts
+    /// remain shared. Useful for projects that build packages with mutually incompatible local
+    /// patches.
+    #[arg(global = true, long, env = EnvVars::UV_CACHE_NAMESPACE)]
+    pub cache_namespace: Option<String>,
+
+    /// The time (in seconds) for which remote requirements and constraints files are cached
+    /// before they're fetched again.
+    ///
+    /// Defaults to `0`, such that remote files (e.g., `-r https://example.com/requirements.txt`)
+    /// are fetched on every invocation. Cached files are always used when running with
+    /// `--offline`, and `--refresh` forces them to be fetched again.
+    #[arg(global = true, long, env = EnvVars::UV_REQUIREMENTS_CACHE_TTL, value_name = "SECONDS")]
+    pub requirements_cache_ttl: Option<u64>,
 }
 
 impl Cache {
@@ -82,7 +100,7 @@ impl TryFrom<CacheArgs> for Cache {
     type Error = io::Error;
 
     fn try_from(value: CacheArgs) -> Result<Self, Self::Error> {
-        Cache::from_settings(value.no_cache, value.cache_dir)
+        Cache::from_settings(value.no_cache, value.cache_dir)?.with_namespace(value.cache_namespace)
     }
 }
 
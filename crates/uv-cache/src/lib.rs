@@ -5,9 +5,11 @@ use std::io::Write;
 use std::ops::Deref;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Duration;
 
 use rustc_hash::FxHashSet;
 use tracing::debug;
+use url::Url;
 
 pub use archive::ArchiveId;
 use uv_cache_info::Timestamp;
@@ -20,6 +22,7 @@ pub use crate::by_timestamp::CachedByTimestamp;
 #[cfg(feature = "clap")]
 pub use crate::cli::CacheArgs;
 pub use crate::removal::{rm_rf, Removal};
+pub use crate::stats::{BuildActivity, CacheActivity, CacheRecorder};
 pub use crate::wheel::WheelCache;
 use crate::wheel::WheelCacheKind;
 
@@ -28,6 +31,7 @@ mod by_timestamp;
 #[cfg(feature = "clap")]
 mod cli;
 mod removal;
+mod stats;
 mod wheel;
 
 /// A [`CacheEntry`] which may or may not exist yet.
@@ -112,11 +116,25 @@ impl Deref for CacheShard {
     }
 }
 
+/// The directory within the source distribution bucket that contains the namespaced entries.
+const NAMESPACES: &str = "namespaces";
+
 /// The main cache abstraction.
 #[derive(Debug, Clone)]
 pub struct Cache {
     /// The cache directory.
     root: PathBuf,
+    /// The namespace in which to store built artifacts, if any.
+    ///
+    /// Source distributions and the wheels built from them are isolated per namespace, while all
+    /// other entries (e.g., downloaded wheels) are shared across namespaces.
+    namespace: Option<String>,
+    /// The URL of a remote cache of wheels built from source distributions, if any.
+    build_cache_url: Option<Url>,
+    /// Whether to upload built wheels to the remote build cache.
+    build_cache_upload: bool,
+    /// The time for which remote requirements files are reused before they're fetched again.
+    requirements_ttl: Duration,
     /// The refresh strategy to use when reading from the cache.
     refresh: Refresh,
     /// A temporary cache directory, if the user requested `--no-cache`.
@@ -124,6 +142,8 @@ pub struct Cache {
     /// Included to ensure that the temporary directory exists for the length of the operation, but
     /// is dropped at the end as appropriate.
     temp_dir: Option<Arc<tempfile::TempDir>>,
+    /// The activity recorded against the cache, for `uv stats`.
+    recorder: CacheRecorder,
 }
 
 impl Cache {
@@ -131,8 +151,13 @@ impl Cache {
     pub fn from_path(root: impl Into<PathBuf>) -> Self {
         Self {
             root: root.into(),
+            namespace: None,
+            build_cache_url: None,
+            build_cache_upload: false,
+            requirements_ttl: Duration::ZERO,
             refresh: Refresh::None(Timestamp::now()),
             temp_dir: None,
+            recorder: CacheRecorder::default(),
         }
     }
 
@@ -141,8 +166,13 @@ impl Cache {
         let temp_dir = tempfile::tempdir()?;
         Ok(Self {
             root: temp_dir.path().to_path_buf(),
+            namespace: None,
+            build_cache_url: None,
+            build_cache_upload: false,
+            requirements_ttl: Duration::ZERO,
             refresh: Refresh::None(Timestamp::now()),
             temp_dir: Some(Arc::new(temp_dir)),
+            recorder: CacheRecorder::default(),
         })
     }
 
@@ -152,24 +182,123 @@ impl Cache {
         Self { refresh, ..self }
     }
 
+    /// Set the namespace in which to store built artifacts.
+    ///
+    /// Namespaces may only contain ASCII letters, digits, `-`, `_`, and `.`.
+    pub fn with_namespace(self, namespace: Option<String>) -> Result<Self, io::Error> {
+        if let Some(namespace) = namespace.as_deref() {
+            if namespace.is_empty()
+                || namespace.starts_with('.')
+                || !namespace
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+            {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!(
+                        "Invalid cache namespace `{namespace}`: namespaces may only contain ASCII letters, digits, `-`, `_`, and `.`"
+                    ),
+                ));
+            }
+        }
+        Ok(Self { namespace, ..self })
+    }
+
+    /// Set the remote cache of wheels built from source distributions, and whether to upload
+    /// built wheels to it.
+    #[must_use]
+    pub fn with_build_cache(self, build_cache_url: Option<Url>, build_cache_upload: bool) -> Self {
+        Self {
+            build_cache_url,
+            build_cache_upload,
+            ..self
+        }
+    }
+
+    /// Set the time for which remote requirements files are reused before they're fetched again.
+    #[must_use]
+    pub fn with_requirements_ttl(self, requirements_ttl: Duration) -> Self {
+        Self {
+            requirements_ttl,
+            ..self
+        }
+    }
+
     /// Return the root of the cache.
     pub fn root(&self) -> &Path {
         &self.root
     }
 
+    /// Return the namespace in which built artifacts are stored, if any.
+    pub fn namespace(&self) -> Option<&str> {
+        self.namespace.as_deref()
+    }
+
+    /// Return the URL of the remote build cache, if any.
+    pub fn build_cache_url(&self) -> Option<&Url> {
+        self.build_cache_url.as_ref()
+    }
+
+    /// Whether to upload built wheels to the remote build cache.
+    pub fn build_cache_upload(&self) -> bool {
+        self.build_cache_upload
+    }
+
+    /// Return the time for which remote requirements files are reused before they're fetched
+    /// again.
+    pub fn requirements_ttl(&self) -> Duration {
+        self.requirements_ttl
+    }
+
     /// Return the [`Refresh`] policy for the cache.
     pub fn refresh(&self) -> &Refresh {
         &self.refresh
     }
 
+    /// Return the [`CacheRecorder`] for the activity against the cache.
+    pub fn recorder(&self) -> &CacheRecorder {
+        &self.recorder
+    }
+
+    /// Persist the activity recorded against the cache, such that it's reported by `uv stats`.
+    ///
+    /// Activity against a temporary cache is discarded.
+    pub fn persist_stats(&self) -> Result<(), io::Error> {
+        if self.is_temporary() {
+            return Ok(());
+        }
+        self.recorder.persist(&self.root)
+    }
+
+    /// Read the activity recorded against the cache by recent operations, from oldest to newest.
+    pub fn stats(&self) -> Result<Vec<CacheActivity>, io::Error> {
+        stats::read(&self.root)
+    }
+
+    /// Return the total size of the files in a cache bucket, in bytes.
+    pub fn bucket_size(&self, cache_bucket: CacheBucket) -> Result<u64, io::Error> {
+        stats::disk_usage(&self.root.join(cache_bucket.to_str()))
+    }
+
     /// The folder for a specific cache bucket
     pub fn bucket(&self, cache_bucket: CacheBucket) -> PathBuf {
         self.root.join(cache_bucket.to_str())
     }
 
+    /// The folder for the entries in a specific cache bucket, scoped to the cache namespace for
+    /// buckets that contain built artifacts.
+    fn namespaced_bucket(&self, cache_bucket: CacheBucket) -> PathBuf {
+        match (&self.namespace, cache_bucket) {
+            (Some(namespace), CacheBucket::SourceDistributions) => {
+                self.bucket(cache_bucket).join(NAMESPACES).join(namespace)
+            }
+            _ => self.bucket(cache_bucket),
+        }
+    }
+
     /// Compute an entry in the cache.
     pub fn shard(&self, cache_bucket: CacheBucket, dir: impl AsRef<Path>) -> CacheShard {
-        CacheShard(self.bucket(cache_bucket).join(dir.as_ref()))
+        CacheShard(self.namespaced_bucket(cache_bucket).join(dir.as_ref()))
     }
 
     /// Compute an entry in the cache.
@@ -179,7 +308,7 @@ impl Cache {
         dir: impl AsRef<Path>,
         file: impl AsRef<Path>,
     ) -> CacheEntry {
-        CacheEntry::new(self.bucket(cache_bucket).join(dir), file)
+        CacheEntry::new(self.namespaced_bucket(cache_bucket).join(dir), file)
     }
 
     /// Return the path to an archive in the cache.
@@ -324,6 +453,31 @@ impl Cache {
         rm_rf(&self.root)
     }
 
+    /// Remove all cached build environments, along with the archives that back them.
+    ///
+    /// Returns the number of entries removed from the cache.
+    pub fn clear_build_environments(&self) -> Result<Removal, io::Error> {
+        let bucket = self.bucket(CacheBucket::BuildEnvironments);
+        if !bucket.is_dir() {
+            return Ok(Removal::default());
+        }
+
+        // Each entry is a symlink into the archive bucket, which is only referenced by this bucket.
+        let mut summary = Removal::default();
+        for entry in walkdir::WalkDir::new(&bucket) {
+            let entry = entry?;
+            if entry.file_type().is_symlink() {
+                if let Ok(target) = fs_err::canonicalize(entry.path()) {
+                    debug!("Removing build environment: {}", target.display());
+                    summary += rm_rf(target)?;
+                }
+            }
+        }
+        summary += rm_rf(bucket)?;
+
+        Ok(summary)
+    }
+
     /// Remove a package from the cache.
     ///
     /// Returns the number of entries removed from the cache.
@@ -400,6 +554,8 @@ impl Cache {
             if entry.file_name() == "CACHEDIR.TAG"
                 || entry.file_name() == ".gitignore"
                 || entry.file_name() == ".git"
+                || entry.file_name() == stats::STATS_FILE
+                || entry.file_name() == stats::STATS_LOCK
             {
                 continue;
             }
@@ -434,6 +590,30 @@ impl Cache {
             Err(err) => return Err(err),
         }
 
+        // Unlike cached environments, build environments are reused across invocations, so only
+        // remove those that are stale, i.e., whose archive is missing or whose base interpreter no
+        // longer exists. The archives themselves are removed below, once unreferenced.
+        let bucket = self.bucket(CacheBucket::BuildEnvironments);
+        if bucket.is_dir() {
+            for entry in walkdir::WalkDir::new(&bucket).contents_first(true) {
+                let entry = entry?;
+                let path = entry.path();
+                if entry.file_type().is_symlink() {
+                    let stale = fs_err::canonicalize(path)
+                        .map_or(true, |target| !has_base_interpreter(&target));
+                    if stale {
+                        debug!("Removing stale build environment: {}", path.display());
+                        summary += rm_rf(path)?;
+                    }
+                } else if entry.file_type().is_dir() && path != bucket {
+                    // Remove the per-interpreter directory once it's empty.
+                    if fs_err::read_dir(path)?.next().is_none() {
+                        summary += rm_rf(path)?;
+                    }
+                }
+            }
+        }
+
         // Third, if enabled, remove all unzipped wheels, leaving only the wheel archives.
         if ci {
             // Remove the entire pre-built wheel cache, since every entry is an unzipped wheel.
@@ -517,6 +697,19 @@ impl Cache {
     }
 }
 
+/// Returns `true` if the base interpreter of the virtual environment at the given root, as recorded
+/// in its `pyvenv.cfg`, still exists.
+fn has_base_interpreter(root: &Path) -> bool {
+    let Ok(contents) = fs_err::read_to_string(root.join("pyvenv.cfg")) else {
+        return false;
+    };
+    contents
+        .lines()
+        .filter_map(|line| line.split_once('='))
+        .find(|(key, _)| key.trim() == "home")
+        .is_some_and(|(_, home)| Path::new(home.trim()).is_dir())
+}
+
 /// The different kinds of data in the cache are stored in different bucket, which in our case
 /// are subdirectories of the cache root.
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
@@ -702,6 +895,14 @@ pub enum CacheBucket {
     FlatIndex,
     /// Git repositories.
     Git,
+    /// Mercurial repositories.
+    ///
+    /// Cache structure:
+    ///  * `hg-v0/db/<digest(repository_url)>` for a clone of each repository (without a working
+    ///    directory).
+    ///  * `hg-v0/checkouts/<digest(repository_url)>/<short node ID>` for an export of each
+    ///    changeset.
+    Hg,
     /// Information about an interpreter at a path.
     ///
     /// To avoid caching pyenv shims, bash scripts which may redirect to a new python version
@@ -759,6 +960,20 @@ pub enum CacheBucket {
     Builds,
     /// Reusable virtual environments used to invoke Python tools.
     Environments,
+    /// Reusable virtual environments used to execute PEP 517 builds, keyed by the interpreter
+    /// and the resolved build requirements.
+    ///
+    /// Like [`CacheBucket::Environments`], each entry is a symlink into [`CacheBucket::Archive`].
+    ///
+    /// Cache structure:
+    ///  * `build-environments-v0/<digest(interpreter)>/<digest(build requirements)>`
+    BuildEnvironments,
+    /// Remote `requirements.txt` and constraints files, as included via `-r` or `-c` with an
+    /// HTTP(S) URL.
+    ///
+    /// Cache structure:
+    ///  * `requirements-v0/<digest(url)>.txt`
+    Requirements,
 }
 
 impl CacheBucket {
@@ -767,7 +982,8 @@ impl CacheBucket {
             Self::SourceDistributions => "sdists-v5",
             Self::FlatIndex => "flat-index-v1",
             Self::Git => "git-v0",
-            Self::Interpreter => "interpreter-v2",
+            Self::Hg => "hg-v0",
+            Self::Interpreter => "interpreter-v3",
             // Note that when bumping this, you'll also need to bump it
             // in crates/uv/tests/cache_clean.rs.
             Self::Simple => "simple-v13",
@@ -777,6 +993,8 @@ impl CacheBucket {
             Self::Archive => "archive-v0",
             Self::Builds => "builds-v0",
             Self::Environments => "environments-v1",
+            Self::BuildEnvironments => "build-environments-v0",
+            Self::Requirements => "requirements-v0",
         }
     }
 
@@ -817,45 +1035,61 @@ impl CacheBucket {
                 }
             }
             Self::SourceDistributions => {
-                // For `pypi` wheels, we expect a directory per package (indexed by name).
-                let root = cache.bucket(self).join(WheelCacheKind::Pypi);
-                summary += rm_rf(root.join(name.to_string()))?;
+                // Remove the package from the shared entries, along with those in every
+                // namespace.
+                let roots = std::iter::once(cache.bucket(self))
+                    .chain(directories(cache.bucket(self).join(NAMESPACES)));
+                for bucket in roots {
+                    // For `pypi` wheels, we expect a directory per package (indexed by name).
+                    let root = bucket.join(WheelCacheKind::Pypi);
+                    summary += rm_rf(root.join(name.to_string()))?;
+
+                    // For alternate indices, we expect a directory for every index (under an `index`
+                    // subdirectory), followed by a directory per package (indexed by name).
+                    let root = bucket.join(WheelCacheKind::Index);
+                    for directory in directories(root) {
+                        summary += rm_rf(directory.join(name.to_string()))?;
+                    }
 
-                // For alternate indices, we expect a directory for every index (under an `index`
-                // subdirectory), followed by a directory per package (indexed by name).
-                let root = cache.bucket(self).join(WheelCacheKind::Index);
-                for directory in directories(root) {
-                    summary += rm_rf(directory.join(name.to_string()))?;
-                }
+                    // For direct URLs, we expect a directory for every URL, followed by a
+                    // directory per version. To determine whether the URL is relevant, we need to
+                    // search for a wheel matching the package name.
+                    let root = bucket.join(WheelCacheKind::Url);
+                    for url in directories(root) {
+                        if directories(&url).any(|version| is_match(&version, name)) {
+                            summary += rm_rf(url)?;
+                        }
+                    }
 
-                // For direct URLs, we expect a directory for every URL, followed by a
-                // directory per version. To determine whether the URL is relevant, we need to
-                // search for a wheel matching the package name.
-                let root = cache.bucket(self).join(WheelCacheKind::Url);
-                for url in directories(root) {
-                    if directories(&url).any(|version| is_match(&version, name)) {
-                        summary += rm_rf(url)?;
+                    // For local dependencies, we expect a directory for every path, followed by a
+                    // directory per version. To determine whether the path is relevant, we need to
+                    // search for a wheel matching the package name.
+                    let root = bucket.join(WheelCacheKind::Path);
+                    for path in directories(root) {
+                        if directories(&path).any(|version| is_match(&version, name)) {
+                            summary += rm_rf(path)?;
+                        }
                     }
-                }
 
-                // For local dependencies, we expect a directory for every path, followed by a
-                // directory per version. To determine whether the path is relevant, we need to
-                // search for a wheel matching the package name.
-                let root = cache.bucket(self).join(WheelCacheKind::Path);
-                for path in directories(root) {
-                    if directories(&path).any(|version| is_match(&version, name)) {
-                        summary += rm_rf(path)?;
+                    // For Git dependencies, we expect a directory for every repository, followed by a
+                    // directory for every SHA. To determine whether the SHA is relevant, we need to
+                    // search for a wheel matching the package name.
+                    let root = bucket.join(WheelCacheKind::Git);
+                    for repository in directories(root) {
+                        for sha in directories(repository) {
+                            if is_match(&sha, name) {
+                                summary += rm_rf(sha)?;
+                            }
+                        }
                     }
-                }
 
-                // For Git dependencies, we expect a directory for every repository, followed by a
-                // directory for every SHA. To determine whether the SHA is relevant, we need to
-                // search for a wheel matching the package name.
-                let root = cache.bucket(self).join(WheelCacheKind::Git);
-                for repository in directories(root) {
-                    for sha in directories(repository) {
-                        if is_match(&sha, name) {
-                            summary += rm_rf(sha)?;
+                    // Likewise for Mercurial dependencies, with a directory for every node ID.
+                    let root = bucket.join(WheelCacheKind::Hg);
+                    for repository in directories(root) {
+                        for node in directories(repository) {
+                            if is_match(&node, name) {
+                                summary += rm_rf(node)?;
+                            }
                         }
                     }
                 }
@@ -881,6 +1115,9 @@ impl CacheBucket {
             Self::Git => {
                 // Nothing to do.
             }
+            Self::Hg => {
+                // Nothing to do.
+            }
             Self::Interpreter => {
                 // Nothing to do.
             }
@@ -893,6 +1130,12 @@ impl CacheBucket {
             Self::Environments => {
                 // Nothing to do.
             }
+            Self::BuildEnvironments => {
+                // Nothing to do.
+            }
+            Self::Requirements => {
+                // Nothing to do.
+            }
         }
         Ok(summary)
     }
@@ -904,11 +1147,14 @@ impl CacheBucket {
             Self::SourceDistributions,
             Self::FlatIndex,
             Self::Git,
+            Self::Hg,
             Self::Interpreter,
             Self::Simple,
             Self::Archive,
             Self::Builds,
             Self::Environments,
+            Self::BuildEnvironments,
+            Self::Requirements,
         ]
         .iter()
         .copied()
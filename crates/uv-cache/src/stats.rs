@@ -0,0 +1,170 @@
+use std::io;
+use std::io::Write;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use uv_fs::LockedFile;
+use uv_normalize::PackageName;
+
+/// The name of the file, in the cache root, to which cache activity is appended.
+pub(crate) const STATS_FILE: &str = "stats.jsonl";
+
+/// The name of the file, in the cache root, used to synchronize writes to the [`STATS_FILE`]
+/// across processes.
+pub(crate) const STATS_LOCK: &str = "stats.lock";
+
+/// The number of operations retained in the [`STATS_FILE`].
+const MAX_OPERATIONS: usize = 100;
+
+/// The cache activity recorded during a single uv invocation.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct CacheActivity {
+    /// The time at which the operation completed, in seconds since the Unix epoch.
+    pub timestamp: u64,
+    /// The number of distributions that were available in the cache.
+    pub hits: u64,
+    /// The number of distributions that had to be downloaded or built.
+    pub misses: u64,
+    /// The number of bytes that didn't need to be downloaded, as they were available in the cache.
+    pub bytes_saved: u64,
+    /// The source distributions that were built.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub builds: Vec<BuildActivity>,
+}
+
+impl CacheActivity {
+    /// Returns `true` if no activity was recorded.
+    pub fn is_empty(&self) -> bool {
+        self.hits == 0 && self.misses == 0 && self.builds.is_empty()
+    }
+}
+
+/// A source distribution that was built during a uv invocation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BuildActivity {
+    pub name: PackageName,
+    pub version: String,
+    /// The duration of the build, in seconds.
+    pub duration: f64,
+}
+
+/// A recorder for the activity against a [`crate::Cache`], shared across all clones of the cache.
+#[derive(Debug, Default, Clone)]
+pub struct CacheRecorder(Arc<Mutex<CacheActivity>>);
+
+impl CacheRecorder {
+    /// Record that the given number of distributions were available in the cache, saving the
+    /// given number of bytes from being downloaded.
+    pub fn record_hits(&self, count: usize, bytes_saved: u64) {
+        let mut activity = self.0.lock().unwrap();
+        activity.hits += count as u64;
+        activity.bytes_saved += bytes_saved;
+    }
+
+    /// Record that the given number of distributions had to be downloaded or built.
+    pub fn record_misses(&self, count: usize) {
+        let mut activity = self.0.lock().unwrap();
+        activity.misses += count as u64;
+    }
+
+    /// Record that a source distribution was built.
+    pub fn record_build(&self, name: &PackageName, version: String, duration: Duration) {
+        let mut activity = self.0.lock().unwrap();
+        activity.builds.push(BuildActivity {
+            name: name.clone(),
+            version,
+            duration: duration.as_secs_f64(),
+        });
+    }
+
+    /// Append the recorded activity to the statistics file in the given cache directory.
+    ///
+    /// Nothing is written if no activity was recorded, or if the cache directory doesn't exist.
+    ///
+    /// The file is locked for the duration of the write, such that concurrent uv processes sharing
+    /// a cache don't interleave their appends with a truncation.
+    pub(crate) fn persist(&self, root: &Path) -> io::Result<()> {
+        let mut activity = self.0.lock().unwrap().clone();
+        if activity.is_empty() || !root.is_dir() {
+            return Ok(());
+        }
+        activity.timestamp = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+
+        let path = root.join(STATS_FILE);
+        let _lock = LockedFile::acquire_blocking(root.join(STATS_LOCK), path.display())?;
+
+        let mut line = serde_json::to_string(&activity).map_err(io::Error::other)?;
+        line.push('\n');
+        fs_err::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)?
+            .write_all(line.as_bytes())?;
+
+        // Periodically truncate the file to the most recent operations.
+        let contents = fs_err::read_to_string(&path)?;
+        let lines = contents.lines().collect::<Vec<_>>();
+        if lines.len() > MAX_OPERATIONS * 2 {
+            debug!("Truncating cache statistics at: {}", path.display());
+            let mut contents = String::new();
+            for line in &lines[lines.len() - MAX_OPERATIONS..] {
+                contents.push_str(line);
+                contents.push('\n');
+            }
+            uv_fs::write_atomic_sync(&path, contents)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Read the most recent operations from the statistics file in the given cache directory.
+///
+/// Malformed entries (e.g., from an interrupted write) are skipped.
+pub(crate) fn read(root: &Path) -> io::Result<Vec<CacheActivity>> {
+    let contents = match fs_err::read_to_string(root.join(STATS_FILE)) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err),
+    };
+    let mut operations = contents
+        .lines()
+        .filter_map(|line| serde_json::from_str::<CacheActivity>(line).ok())
+        .collect::<Vec<_>>();
+    if operations.len() > MAX_OPERATIONS {
+        operations.drain(..operations.len() - MAX_OPERATIONS);
+    }
+    Ok(operations)
+}
+
+/// Return the total size of the files in the given directory, in bytes.
+///
+/// Note: like [`crate::Removal`], this over-counts hard-linked files and measures the exact byte
+/// size of each file, rather than its block size.
+pub(crate) fn disk_usage(path: &Path) -> io::Result<u64> {
+    let mut total = 0;
+    for entry in walkdir::WalkDir::new(path) {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(err)
+                if err
+                    .io_error()
+                    .is_some_and(|err| err.kind() == io::ErrorKind::NotFound) =>
+            {
+                continue;
+            }
+            Err(err) => return Err(err.into()),
+        };
+        if entry.file_type().is_file() {
+            total += entry.metadata()?.len();
+        }
+    }
+    Ok(total)
+}
@@ -21,6 +21,10 @@ pub enum WheelCache<'a> {
     /// Note that this variant only exists for source distributions; wheels can't be delivered
     /// through Git.
     Git(&'a Url, &'a str),
+    /// A Mercurial dependency, which we key by URL and node ID.
+    ///
+    /// Like [`WheelCache::Git`], this variant only exists for source distributions.
+    Hg(&'a Url, &'a str),
 }
 
 impl<'a> WheelCache<'a> {
@@ -44,6 +48,10 @@ impl<'a> WheelCache<'a> {
                 .root()
                 .join(cache_digest(&CanonicalUrl::new(url)))
                 .join(sha),
+            WheelCache::Hg(url, node) => WheelCacheKind::Hg
+                .root()
+                .join(cache_digest(&CanonicalUrl::new(url)))
+                .join(node),
         }
     }
 
@@ -67,6 +75,8 @@ pub(crate) enum WheelCacheKind {
     Editable,
     /// A cache of data from a Git repository.
     Git,
+    /// A cache of data from a Mercurial repository.
+    Hg,
 }
 
 impl WheelCacheKind {
@@ -78,6 +88,7 @@ impl WheelCacheKind {
             Self::Path => "path",
             Self::Editable => "editable",
             Self::Git => "git",
+            Self::Hg => "hg",
         }
     }
 
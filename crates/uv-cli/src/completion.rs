@@ -0,0 +1,238 @@
+//! Dynamic completions for argument values, resolved when the shell requests them.
+//!
+//! Completers run on every `<TAB>`, so they must be fast and infallible: any error (e.g., a
+//! missing cache or a malformed `pyproject.toml`) results in no candidates, rather than a message.
+
+use std::collections::BTreeSet;
+use std::ffi::{OsStr, OsString};
+use std::path::{Path, PathBuf};
+
+use clap_complete::engine::CompletionCandidate;
+
+use uv_cache::{Cache, CacheBucket};
+use uv_settings::{Combine, FilesystemOptions};
+use uv_static::EnvVars;
+
+/// Complete package names from the index metadata in the cache.
+///
+/// Candidates are limited to packages for which uv has cached a Simple API response, across all
+/// indexes.
+pub(crate) fn complete_package_name(current: &OsStr) -> Vec<CompletionCandidate> {
+    let Some(current) = current.to_str() else {
+        return Vec::new();
+    };
+
+    // Only complete bare names, not version specifiers, extras, paths, or URLs.
+    if !current
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+    {
+        return Vec::new();
+    }
+
+    let Ok(cache) = Cache::from_settings(false, cache_dir()) else {
+        return Vec::new();
+    };
+
+    package_names(&cache.bucket(CacheBucket::Simple), current)
+        .into_iter()
+        .map(CompletionCandidate::new)
+        .collect()
+}
+
+/// Return the names of the packages with cached Simple API responses that start with the current
+/// value.
+fn package_names(simple: &Path, current: &str) -> BTreeSet<String> {
+    // Responses are cached as `<index>/<name>.rkyv`, keyed by the normalized package name.
+    let prefix = current.to_ascii_lowercase().replace(['_', '.'], "-");
+    let Ok(indexes) = fs_err::read_dir(simple) else {
+        return BTreeSet::new();
+    };
+    indexes
+        .filter_map(Result::ok)
+        .filter_map(|index| fs_err::read_dir(index.path()).ok())
+        .flatten()
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let path = entry.path();
+            if path.extension().is_some_and(|ext| ext == "rkyv") {
+                path.file_stem()?.to_str().map(ToString::to_string)
+            } else {
+                None
+            }
+        })
+        .filter(|name| name.starts_with(&prefix))
+        .collect()
+}
+
+/// Determine the cache directory for the command line that's being completed.
+///
+/// As when running the command, `--cache-dir` (or `UV_CACHE_DIR`) takes precedence over the
+/// `cache-dir` setting in the nearest configuration file, followed by the user- and system-level
+/// configuration files.
+fn cache_dir() -> Option<PathBuf> {
+    // The command line that's being completed is passed as uv's own arguments.
+    if let Some(cache_dir) = cache_dir_arg(std::env::args_os()) {
+        return Some(cache_dir);
+    }
+    if let Some(cache_dir) = std::env::var_os(EnvVars::UV_CACHE_DIR).filter(|dir| !dir.is_empty()) {
+        return Some(PathBuf::from(cache_dir));
+    }
+    let cwd = std::env::current_dir().ok()?;
+    let options = FilesystemOptions::find(&cwd)
+        .ok()
+        .flatten()
+        .combine(FilesystemOptions::user().ok().flatten())
+        .combine(FilesystemOptions::system().ok().flatten())?;
+    options.globals.cache_dir.clone()
+}
+
+/// Return the value of the last `--cache-dir` argument in the given command line, if any.
+fn cache_dir_arg(args: impl IntoIterator<Item = OsString>) -> Option<PathBuf> {
+    let mut cache_dir = None;
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if arg == "--cache-dir" {
+            cache_dir = args.next().map(PathBuf::from);
+        } else if let Some(value) = arg
+            .to_str()
+            .and_then(|arg| arg.strip_prefix("--cache-dir="))
+        {
+            cache_dir = Some(PathBuf::from(value));
+        }
+    }
+    cache_dir
+}
+
+/// Complete extra names from the `project.optional-dependencies` table of the current project.
+pub(crate) fn complete_extra(current: &OsStr) -> Vec<CompletionCandidate> {
+    let Some(pyproject) = read_pyproject() else {
+        return Vec::new();
+    };
+    let extras = pyproject
+        .get("project")
+        .and_then(|project| project.get("optional-dependencies"))
+        .and_then(toml::Value::as_table)
+        .map(|table| table.keys().cloned().collect::<BTreeSet<_>>())
+        .unwrap_or_default();
+    candidates(extras, current)
+}
+
+/// Complete dependency group names from the `dependency-groups` table of the current project.
+pub(crate) fn complete_group(current: &OsStr) -> Vec<CompletionCandidate> {
+    let Some(pyproject) = read_pyproject() else {
+        return Vec::new();
+    };
+    let mut groups = pyproject
+        .get("dependency-groups")
+        .and_then(toml::Value::as_table)
+        .map(|table| table.keys().cloned().collect::<BTreeSet<_>>())
+        .unwrap_or_default();
+
+    // The legacy `tool.uv.dev-dependencies` are included in the `dev` group.
+    if pyproject
+        .get("tool")
+        .and_then(|tool| tool.get("uv"))
+        .and_then(|uv| uv.get("dev-dependencies"))
+        .is_some()
+    {
+        groups.insert("dev".to_string());
+    }
+
+    candidates(groups, current)
+}
+
+/// Read the `pyproject.toml` nearest to the current directory.
+fn read_pyproject() -> Option<toml::Table> {
+    let cwd = std::env::current_dir().ok()?;
+    let path = cwd
+        .ancestors()
+        .map(|dir| dir.join("pyproject.toml"))
+        .find(|path| path.is_file())?;
+    let contents = fs_err::read_to_string(path).ok()?;
+    toml::from_str(&contents).ok()
+}
+
+/// Convert the names that start with the current value into completion candidates.
+fn candidates(names: BTreeSet<String>, current: &OsStr) -> Vec<CompletionCandidate> {
+    let current = current.to_string_lossy();
+    names
+        .into_iter()
+        .filter(|name| name.starts_with(current.as_ref()))
+        .map(CompletionCandidate::new)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::{OsStr, OsString};
+    use std::path::PathBuf;
+
+    use super::{cache_dir_arg, candidates, package_names};
+
+    #[test]
+    fn filter_candidates() {
+        let names = ["dev", "docs", "lint"].map(ToString::to_string).into();
+        let values = candidates(names, OsStr::new("d"))
+            .into_iter()
+            .map(|candidate| candidate.get_value().to_string_lossy().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(values, ["dev", "docs"]);
+    }
+
+    #[test]
+    fn cache_dir_argument() {
+        let args = |args: &[&str]| args.iter().map(OsString::from).collect::<Vec<_>>();
+
+        assert_eq!(
+            cache_dir_arg(args(&[
+                "uv",
+                "--",
+                "uv",
+                "add",
+                "--cache-dir",
+                "/tmp/cache",
+                "ini"
+            ])),
+            Some(PathBuf::from("/tmp/cache"))
+        );
+        assert_eq!(
+            cache_dir_arg(args(&[
+                "uv",
+                "--",
+                "uv",
+                "--cache-dir=/tmp/cache",
+                "add",
+                "ini"
+            ])),
+            Some(PathBuf::from("/tmp/cache"))
+        );
+        assert_eq!(cache_dir_arg(args(&["uv", "--", "uv", "add", "ini"])), None);
+    }
+
+    #[test]
+    fn cached_package_names() -> anyhow::Result<()> {
+        let simple = tempfile::tempdir()?;
+        for (index, file) in [
+            ("pypi", "iniconfig.rkyv"),
+            ("pypi", "anyio.rkyv"),
+            ("pypi", "ini.lock"),
+            ("internal", "ini-internal.rkyv"),
+        ] {
+            fs_err::create_dir_all(simple.path().join(index))?;
+            fs_err::write(simple.path().join(index).join(file), "")?;
+        }
+
+        let names = package_names(simple.path(), "Ini");
+        assert_eq!(
+            names.into_iter().collect::<Vec<_>>(),
+            ["ini-internal", "iniconfig"]
+        );
+
+        // Names are compared in their normalized form.
+        let names = package_names(simple.path(), "ini_i");
+        assert_eq!(names.into_iter().collect::<Vec<_>>(), ["ini-internal"]);
+
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
 use std::ffi::OsString;
+use std::num::NonZeroUsize;
 use std::ops::{Deref, DerefMut};
 use std::path::PathBuf;
 use std::str::FromStr;
@@ -7,22 +8,33 @@ use anyhow::{anyhow, Result};
 use clap::builder::styling::{AnsiColor, Effects, Style};
 use clap::builder::Styles;
 use clap::{Args, Parser, Subcommand};
+use clap_complete::engine::ArgValueCompleter;
 
 use url::Url;
 use uv_cache::CacheArgs;
 use uv_configuration::{
-    ConfigSettingEntry, ExportFormat, IndexStrategy, KeyringProviderType, PackageNameSpecifier,
-    ProjectBuildBackend, TargetTriple, TrustedHost, TrustedPublishing, VersionControlSystem,
+    BuildContainerEntry, BytecodeOptimization, ConfigSettingEntry, ExportFormat, IndexStrategy,
+    KeyringProviderType, PackageNameSpecifier, ProjectBuildBackend, TargetTriple, TrustedHost,
+    TrustedPublishing, VersionControlSystem,
+};
+use uv_distribution_types::{
+    Index, IndexName, IndexUrl, Origin, PipExtraIndex, PipFindLinks, PipIndex,
 };
-use uv_distribution_types::{Index, IndexUrl, Origin, PipExtraIndex, PipFindLinks, PipIndex};
 use uv_normalize::{ExtraName, GroupName, PackageName};
+use uv_pep440::Version;
 use uv_pep508::Requirement;
 use uv_pypi_types::VerbatimParsedUrl;
-use uv_python::{PythonDownloads, PythonPreference, PythonVersion};
-use uv_resolver::{AnnotationStyle, ExcludeNewer, PrereleaseMode, ResolutionMode};
+use uv_python::platform::Arch;
+use uv_python::{PythonDownloads, PythonPreference, PythonVariant, PythonVersion};
+use uv_resolver::{
+    AnnotationStyle, ExcludeNewer, LockfileFormat, PrereleaseMode, ResolutionMode, YankedMode,
+};
 use uv_static::EnvVars;
 
+use crate::completion::{complete_extra, complete_group, complete_package_name};
+
 pub mod compat;
+mod completion;
 pub mod options;
 pub mod version;
 
@@ -34,6 +46,126 @@ pub enum VersionFormat {
     Json,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum VersionBump {
+    /// Increase the major version (e.g., 1.2.3 => 2.0.0).
+    Major,
+    /// Increase the minor version (e.g., 1.2.3 => 1.3.0).
+    Minor,
+    /// Increase the patch version (e.g., 1.2.3 => 1.2.4).
+    Patch,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum LockFormat {
+    /// Display the lockfile changes as human-readable text.
+    #[default]
+    Text,
+    /// Display the lockfile changes in a machine-readable JSON format.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum PythonListFormat {
+    /// Display the Python installations as human-readable text.
+    #[default]
+    Text,
+    /// Display the Python installations in a machine-readable JSON format.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum WorkspaceListFormat {
+    /// Display the workspace members as human-readable text.
+    #[default]
+    Text,
+    /// Display the workspace members in a machine-readable JSON format.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum StatsFormat {
+    /// Display the report as human-readable text.
+    #[default]
+    Text,
+    /// Display the report in a machine-readable JSON format.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum DoctorFormat {
+    /// Display the diagnostics as human-readable text.
+    #[default]
+    Text,
+    /// Display the diagnostics in a machine-readable JSON format.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum SearchFormat {
+    /// Display the matching packages as human-readable text.
+    #[default]
+    Text,
+    /// Display the matching packages in a machine-readable JSON format.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum InfoFormat {
+    /// Display the package information as human-readable text.
+    #[default]
+    Text,
+    /// Display the package information in a machine-readable JSON format.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum ConfigFormat {
+    /// Display the settings as TOML.
+    #[default]
+    Text,
+    /// Display the settings in a machine-readable JSON format.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum LicensesFormat {
+    /// Display the packages grouped by license as human-readable text.
+    #[default]
+    Text,
+    /// Display the license of each package in a machine-readable JSON format.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum OutdatedFormat {
+    /// Display the outdated dependencies as a table, along with the commands to upgrade them.
+    #[default]
+    Text,
+    /// Display the outdated dependencies in a machine-readable JSON format.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum WorkspaceGraphFormat {
+    /// Display the dependencies of each workspace member as human-readable text.
+    #[default]
+    Text,
+    /// Display the graph in the Graphviz DOT format.
+    Dot,
+    /// Display the graph in a machine-readable JSON format.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum DryRunFormat {
+    /// Display the planned changes as human-readable text.
+    #[default]
+    Text,
+    /// Display the planned changes in a machine-readable JSON format.
+    Json,
+}
+
 #[derive(Debug, Default, Clone, clap::ValueEnum)]
 pub enum ListFormat {
     /// Display the list of packages in a human-readable table.
@@ -46,6 +178,25 @@ pub enum ListFormat {
     Json,
 }
 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum SelfUpdateChannel {
+    /// Update to final releases only.
+    #[default]
+    Stable,
+    /// Update to the latest release, including pre-releases.
+    Preview,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum FreezeFormat {
+    /// Display the installed packages in the `requirements.txt` format.
+    #[default]
+    Requirements,
+    /// Display the installed packages in a machine-readable JSON format, including the installer
+    /// and location of each package.
+    Json,
+}
+
 fn extra_name_with_clap_error(arg: &str) -> Result<ExtraName> {
     ExtraName::from_str(arg).map_err(|_err| {
         anyhow!(
@@ -111,6 +262,19 @@ pub struct TopLevelArgs {
     #[arg(global = true, long, env = EnvVars::UV_NO_CONFIG, value_parser = clap::builder::BoolishValueParser::new(), help_heading = "Global options")]
     pub no_config: bool,
 
+    /// Apply the named configuration profile.
+    ///
+    /// Profiles are defined under `profile` in a `uv.toml` (e.g., `[profile.airgapped]`), or
+    /// under `tool.uv.profile` in a `pyproject.toml`. The profile's settings take precedence over
+    /// the settings defined outside of any profile.
+    #[arg(
+        global = true,
+        long,
+        env = EnvVars::UV_PROFILE,
+        help_heading = "Global options"
+    )]
+    pub profile: Option<String>,
+
     /// Display the concise help for this command.
     #[arg(global = true, short, long, action = clap::ArgAction::HelpShort, help_heading = "Global options")]
     help: Option<bool>,
@@ -181,6 +345,21 @@ pub struct GlobalArgs {
     )]
     pub color: ColorChoice,
 
+    /// The format in which to emit log messages.
+    ///
+    /// Log messages are written to stderr when enabled via `--verbose` or `RUST_LOG`. With `json`,
+    /// each message is written as a JSON object on its own line, along with the spans in which it
+    /// was emitted, for consumption by log aggregation tools.
+    #[arg(
+        global = true,
+        long,
+        value_enum,
+        default_value = "text",
+        env = EnvVars::UV_LOG_FORMAT,
+        value_name = "LOG_FORMAT"
+    )]
+    pub log_format: LogFormat,
+
     /// Whether to load TLS certificates from the platform's native certificate store.
     ///
     /// By default, uv loads certificates from the bundled `webpki-roots` crate. The
@@ -199,12 +378,35 @@ pub struct GlobalArgs {
     /// Disable network access.
     ///
     /// When disabled, uv will only use locally cached data and locally available files.
-    #[arg(global = true, long, overrides_with("no_offline"))]
-    pub offline: bool,
+    ///
+    /// With `--offline=strict`, uv will additionally verify that every distribution required by an
+    /// installation is available locally before making any changes, and fail with a complete list
+    /// of the missing distributions, rather than erroring partway through. The build dependencies
+    /// of source distributions that must be built are not verified in advance.
+    #[arg(
+        global = true,
+        long,
+        num_args = 0..=1,
+        require_equals = true,
+        value_name = "MODE",
+        overrides_with("no_offline")
+    )]
+    pub offline: Option<Option<OfflineMode>>,
 
     #[arg(global = true, long, overrides_with("offline"), hide = true)]
     pub no_offline: bool,
 
+    /// The maximum number of source distributions that uv will build concurrently.
+    ///
+    /// When a resolution or sync requires building multiple source distributions, builds are
+    /// performed in parallel up to this limit. In verbose output, the build backend logs of
+    /// each build are prefixed with the distribution being built.
+    ///
+    /// Defaults to the number of available CPU cores. Overrides the `concurrent-builds` setting
+    /// and `UV_CONCURRENT_BUILDS`.
+    #[arg(global = true, long, value_name = "JOBS")]
+    pub build_jobs: Option<NonZeroUsize>,
+
     /// Whether to enable experimental, preview features.
     ///
     /// Preview features may change without warning.
@@ -271,6 +473,22 @@ pub enum ColorChoice {
     Never,
 }
 
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, clap::ValueEnum)]
+pub enum LogFormat {
+    /// Display log messages as human-readable text.
+    #[default]
+    Text,
+
+    /// Display log messages as newline-delimited JSON objects.
+    Json,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, clap::ValueEnum)]
+pub enum OfflineMode {
+    /// Verify that all required distributions are available locally before installing.
+    Strict,
+}
+
 impl From<ColorChoice> for anstream::ColorChoice {
     fn from(value: ColorChoice) -> Self {
         match value {
@@ -368,6 +586,18 @@ pub enum Commands {
         after_long_help = ""
     )]
     Venv(VenvArgs),
+    /// Manage project environments.
+    #[command(
+        after_help = "Use `uv help env` for more details.",
+        after_long_help = ""
+    )]
+    Env(EnvNamespace),
+    /// Manage workspaces.
+    #[command(
+        after_help = "Use `uv help workspace` for more details.",
+        after_long_help = ""
+    )]
+    Workspace(WorkspaceNamespace),
     /// Build Python packages into source distributions and wheels.
     ///
     /// `uv build` accepts a path to a directory or source distribution,
@@ -391,6 +621,48 @@ pub enum Commands {
     Build(BuildArgs),
     /// Upload distributions to an index.
     Publish(PublishArgs),
+    /// Manage credentials for package indexes.
+    ///
+    /// Credentials are stored in a file in the uv state directory that is only readable by the
+    /// current user, or, with `--keyring-provider subprocess`, in the system keyring. Stored
+    /// credentials are used automatically when an index requests authentication.
+    #[command(
+        after_help = "Use `uv help auth` for more details.",
+        after_long_help = ""
+    )]
+    Auth(AuthNamespace),
+    /// Manage package indexes in the project or user configuration.
+    #[command(
+        after_help = "Use `uv help index` for more details.",
+        after_long_help = ""
+    )]
+    Index(IndexNamespace),
+    /// Read and write settings in the project, user, or system configuration.
+    #[command(
+        after_help = "Use `uv help config` for more details.",
+        after_long_help = ""
+    )]
+    Config(ConfigNamespace),
+    /// Search the configured package indexes for packages by name.
+    ///
+    /// Packages whose names start with the query are listed, along with their latest version and,
+    /// for packages on PyPI, their summary. Names are compared after normalization, such that
+    /// (e.g.) `zope_interface` matches `zope.interface`.
+    ///
+    /// Indexes are searched in priority order. If a package is available on multiple indexes, only
+    /// the first is shown.
+    Search(SearchArgs),
+    /// Display information about a package from the configured package indexes.
+    ///
+    /// Shows the metadata of the package's latest version (its summary, license, Python
+    /// requirement, dependencies, and extras), along with its available versions and whether each
+    /// provides a wheel that's compatible with the current platform.
+    ///
+    /// Compatibility is determined against the Python interpreter in the active virtual
+    /// environment, or, if there isn't one, the first interpreter found on the system. Metadata is
+    /// read from the latest version's wheels, so it's unavailable for versions that only provide a
+    /// source distribution.
+    Info(InfoArgs),
     /// The implementation of the build backend.
     ///
     /// These commands are not directly exposed to the user, instead users invoke their build
@@ -406,6 +678,24 @@ pub enum Commands {
         after_long_help = ""
     )]
     Cache(CacheNamespace),
+    /// Summarize the size of uv's cache and its recent activity.
+    ///
+    /// Displays the size of each cache bucket, the rate of cache hits and misses across recent
+    /// operations, the slowest source distributions built recently, and the number of bytes that
+    /// didn't need to be downloaded, as they were available in the cache.
+    ///
+    /// Activity is recorded by the operations that install packages, and the most recent 100
+    /// operations are retained. Clearing the cache discards the recorded activity.
+    Stats(StatsArgs),
+    /// Diagnose common problems with uv's environment and configuration.
+    ///
+    /// Checks that a Python interpreter can be found, that the virtual environment is usable,
+    /// that the cache directory is writable, that the TLS and proxy configuration is valid, that
+    /// the configured package indexes are reachable, and that the lockfile is consistent with the
+    /// workspace, suggesting a fix for any problems found.
+    ///
+    /// Exits with a non-zero status if any check fails.
+    Doctor(DoctorArgs),
     /// Manage the uv executable.
     #[command(name = "self")]
     Self_(SelfNamespace),
@@ -413,10 +703,11 @@ pub enum Commands {
     #[command(hide = true)]
     Clean(CleanArgs),
     /// Display uv's version
-    Version {
-        #[arg(long, value_enum, default_value = "text")]
-        output_format: VersionFormat,
-    },
+    ///
+    /// When a version, `--bump`, `--package`, or `--all-packages` is provided, displays or updates
+    /// the version of the current project instead, along with any dependencies on the project
+    /// within the workspace.
+    Version(VersionArgs),
     /// Generate shell completion
     #[command(alias = "--generate-shell-completion", hide = true)]
     GenerateShellCompletion(GenerateShellCompletionArgs),
@@ -438,6 +729,56 @@ pub enum Commands {
     Help(HelpArgs),
 }
 
+#[derive(Args, Debug)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct VersionArgs {
+    /// Set the project's version to the given value.
+    ///
+    /// Dependencies on the project from other workspace members that reference the previous
+    /// version (e.g., `foo>=0.1.0`) are updated to reference the new version.
+    #[arg(conflicts_with = "bump")]
+    pub value: Option<Version>,
+
+    /// Bump the project's version by the given component.
+    ///
+    /// Any pre-release, post-release, development, or local segments are dropped from the bumped
+    /// version.
+    #[arg(long, value_enum)]
+    pub bump: Option<VersionBump>,
+
+    /// Display or update the version of a specific package in the workspace.
+    ///
+    /// The workspace member must exist and declare a static `project.version`.
+    #[arg(long)]
+    pub package: Option<PackageName>,
+
+    /// Update the version of all workspace members in lockstep.
+    ///
+    /// The given version is applied to every workspace member with a static `project.version`.
+    /// With `--bump`, the new version is computed from the version of the package provided via
+    /// `--package`, which is then required.
+    ///
+    /// Without a version or `--bump`, the versions of all workspace members are displayed. Unlike
+    /// other forms of `uv version`, this can be run from a virtual workspace root.
+    #[arg(long)]
+    pub all_packages: bool,
+
+    /// Display the changes that would be made, without writing to any `pyproject.toml`.
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// The format in which to display the version.
+    #[arg(long, value_enum, default_value = "text")]
+    pub output_format: VersionFormat,
+}
+
+impl VersionArgs {
+    /// Returns `true` if the command targets a project, rather than uv itself.
+    pub fn is_project(&self) -> bool {
+        self.value.is_some() || self.bump.is_some() || self.package.is_some() || self.all_packages
+    }
+}
+
 #[derive(Args, Debug)]
 pub struct HelpArgs {
     /// Disable pager when printing help
@@ -447,6 +788,99 @@ pub struct HelpArgs {
     pub command: Option<Vec<String>>,
 }
 
+#[derive(Args, Debug)]
+pub struct StatsArgs {
+    /// The maximum number of recently built source distributions to display, from slowest to
+    /// fastest.
+    #[arg(long, default_value_t = 5)]
+    pub builds: usize,
+
+    /// The format in which to display the report.
+    #[arg(long, value_enum, default_value_t = StatsFormat::default())]
+    pub output_format: StatsFormat,
+}
+
+#[derive(Args)]
+pub struct DoctorArgs {
+    /// The format in which to display the diagnostics.
+    #[arg(long, value_enum, default_value_t = DoctorFormat::default())]
+    pub output_format: DoctorFormat,
+
+    /// Display the diagnostics as JSON.
+    ///
+    /// Equivalent to `--output-format json`.
+    #[arg(long, conflicts_with = "output_format")]
+    pub json: bool,
+
+    #[command(flatten)]
+    pub index_args: IndexArgs,
+
+    /// The Python interpreter to check for.
+    ///
+    /// By default, uv checks for the interpreter requested by a `.python-version` file, if any.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
+#[derive(Args)]
+pub struct SearchArgs {
+    /// The name, or start of the name, of the package to search for.
+    pub query: String,
+
+    /// The maximum number of matching packages to display.
+    #[arg(long, default_value_t = 10)]
+    pub limit: usize,
+
+    /// The format in which to display the matching packages.
+    #[arg(long, value_enum, default_value_t = SearchFormat::default())]
+    pub output_format: SearchFormat,
+
+    #[command(flatten)]
+    pub index_args: IndexArgs,
+}
+
+#[derive(Args)]
+pub struct InfoArgs {
+    /// The package to display information about.
+    pub package: PackageName,
+
+    /// The maximum number of versions to display, from newest to oldest.
+    #[arg(long, default_value_t = 10)]
+    pub versions: usize,
+
+    /// The format in which to display the package information.
+    #[arg(long, value_enum, default_value_t = InfoFormat::default())]
+    pub output_format: InfoFormat,
+
+    #[command(flatten)]
+    pub index_args: IndexArgs,
+
+    /// The Python interpreter against which to determine wheel compatibility.
+    ///
+    /// By default, uv uses the Python interpreter in the active virtual environment, or, if there
+    /// isn't one, the first interpreter found on the system.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
 #[derive(Args)]
 pub struct SelfNamespace {
     #[command(subcommand)]
@@ -457,19 +891,49 @@ pub struct SelfNamespace {
 pub enum SelfCommand {
     /// Update uv.
     Update(SelfUpdateArgs),
+    /// Uninstall uv.
+    ///
+    /// Removes the tools installed with `uv tool install`, the cache, and any changes made to
+    /// shell configuration files by uv. If uv was installed via the standalone installer, the uv
+    /// binaries and the installer's shell integration are removed too.
+    ///
+    /// Managed Python installations are only removed when `--managed-python` is provided.
+    Uninstall(SelfUninstallArgs),
 }
 
 #[derive(Args, Debug)]
 pub struct SelfUpdateArgs {
     /// Update to the specified version. If not provided, uv will update to the latest version.
+    ///
+    /// Accepts either an exact version (e.g., `0.5.1`) or a range of versions, in which case uv
+    /// will update to the latest version in the range. Ranges can be provided with a wildcard
+    /// (e.g., `0.5.x` or `0.5.*`) or as PEP 440 specifiers (e.g., `>=0.5.0,<0.6.0`).
     pub target_version: Option<String>,
 
+    /// The release channel from which to update.
+    ///
+    /// The `preview` channel includes pre-releases, while the `stable` channel only includes final
+    /// releases. Ignored when an exact version is requested.
+    #[arg(long, value_enum, default_value_t = SelfUpdateChannel::default())]
+    pub channel: SelfUpdateChannel,
+
     /// A GitHub token for authentication.
     /// A token is not required but can be used to reduce the chance of encountering rate limits.
     #[arg(long, env = EnvVars::UV_GITHUB_TOKEN)]
     pub token: Option<String>,
 }
 
+#[derive(Args, Debug)]
+pub struct SelfUninstallArgs {
+    /// Also remove the Python installations managed by uv.
+    #[arg(long)]
+    pub managed_python: bool,
+
+    /// Display the files and directories that would be removed, without removing them.
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct CacheNamespace {
@@ -483,6 +947,13 @@ pub enum CacheCommand {
     Clean(CleanArgs),
     /// Prune all unreachable objects from the cache.
     Prune(PruneArgs),
+    /// Verify the integrity of the cached wheels, removing any corrupt entries.
+    ///
+    /// Re-hashes the contents of each cached wheel, including wheels built from source
+    /// distributions, against the hashes recorded in the wheel's `RECORD` file. Any entries that
+    /// fail verification are reported and removed from the cache, such that they're re-downloaded
+    /// or rebuilt when next needed.
+    Verify(VerifyArgs),
     /// Show the cache directory.
     ///
     ///
@@ -505,6 +976,13 @@ pub enum CacheCommand {
 pub struct CleanArgs {
     /// The packages to remove from the cache.
     pub package: Vec<PackageName>,
+
+    /// Remove only the cached build environments, rather than clearing the entire cache.
+    ///
+    /// Build environments are reused across source distributions with the same build
+    /// requirements, and are recreated on demand.
+    #[arg(long, conflicts_with = "package")]
+    pub build_environments: bool,
 }
 
 #[derive(Args, Debug)]
@@ -527,6 +1005,82 @@ pub struct PruneArgs {
     pub ci: bool,
 }
 
+#[derive(Args, Debug)]
+pub struct VerifyArgs {
+    /// Report any corrupt entries, without removing them from the cache.
+    ///
+    /// Exits with a non-zero status if any corrupt entries are found.
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+#[derive(Args)]
+pub struct EnvNamespace {
+    #[command(subcommand)]
+    pub command: EnvCommand,
+}
+
+#[derive(Subcommand)]
+pub enum EnvCommand {
+    /// Remove the project's virtual environment.
+    ///
+    /// Removes the project environment (i.e., `.venv`, or the path set by
+    /// `UV_PROJECT_ENVIRONMENT`), along with any cached environments that layer on top of it
+    /// (e.g., those created by `uv run --with`).
+    ///
+    /// The environment will be recreated on the next `uv sync` or `uv run`.
+    Remove(EnvRemoveArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct EnvRemoveArgs {
+    /// Also remove any virtual environments in the directories of workspace members.
+    ///
+    /// By default, only the environment at the workspace root is removed, as it's the only
+    /// environment used by project commands. With `--all`, stale `.venv` directories within
+    /// workspace members (e.g., created by `uv venv`) are removed as well.
+    #[arg(long)]
+    pub all: bool,
+}
+
+#[derive(Args)]
+pub struct WorkspaceNamespace {
+    #[command(subcommand)]
+    pub command: WorkspaceCommand,
+}
+
+#[derive(Subcommand)]
+pub enum WorkspaceCommand {
+    /// List the members of the workspace.
+    ///
+    /// Displays each workspace member along with its version, path, and Python requirement, and
+    /// whether it's the workspace root or a virtual (non-package) project.
+    List(WorkspaceListArgs),
+    /// Display the dependencies between the members of the workspace.
+    ///
+    /// A member depends on another member if it declares a dependency on it (including optional
+    /// and development dependencies) that's resolved from the workspace, i.e., via a
+    /// `workspace = true` source.
+    ///
+    /// With `--output-format json`, both the dependencies and dependents of each member are
+    /// included, e.g., to determine which members are affected by a change.
+    Graph(WorkspaceGraphArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct WorkspaceListArgs {
+    /// The format in which to display the workspace members.
+    #[arg(long, value_enum, default_value_t = WorkspaceListFormat::default())]
+    pub output_format: WorkspaceListFormat,
+}
+
+#[derive(Args, Debug)]
+pub struct WorkspaceGraphArgs {
+    /// The format in which to display the workspace graph.
+    #[arg(long, value_enum, default_value_t = WorkspaceGraphFormat::default())]
+    pub output_format: WorkspaceGraphFormat,
+}
+
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct PipNamespace {
@@ -590,6 +1144,15 @@ pub enum PipCommand {
         after_long_help = ""
     )]
     Check(PipCheckArgs),
+    /// Verify installed packages against the hashes and sizes in their `RECORD` files.
+    ///
+    /// Reports files that were modified or removed since installation, along with any
+    /// unrecorded files in the directories that each package installed.
+    #[command(
+        after_help = "Use `uv help pip verify` for more details.",
+        after_long_help = ""
+    )]
+    Verify(PipVerifyArgs),
 }
 
 #[derive(Subcommand)]
@@ -618,6 +1181,11 @@ pub enum ProjectCommand {
     /// arguments to uv. All options to uv must be provided before the command,
     /// e.g., `uv run --verbose foo`. A `--` can be used to separate the command
     /// from uv options for clarity, e.g., `uv run --python 3.12 -- python`.
+    ///
+    /// If the command matches a task declared in `tool.uv.tasks`, the task is run instead, with
+    /// any following arguments appended to the task's command. For a task defined as a shell
+    /// string, the arguments are appended to the end of the string, so in a task like
+    /// `ruff check && pytest`, only the last command (`pytest`) receives them.
     #[command(
         after_help = "Use `uv help run` for more details.",
         after_long_help = ""
@@ -730,7 +1298,7 @@ pub enum ProjectCommand {
     Lock(LockArgs),
     /// Export the project's lockfile to an alternate format.
     ///
-    /// At present, only `requirements-txt` is supported.
+    /// Supports `requirements-txt`, `zipapp`, and `pex` formats.
     ///
     /// The project is re-locked before exporting unless the `--locked` or `--frozen` flag is
     /// provided.
@@ -745,6 +1313,107 @@ pub enum ProjectCommand {
         after_long_help = ""
     )]
     Export(ExportArgs),
+    /// Bundle the project environment into a relocatable archive.
+    ///
+    /// The bundle contains a relocatable virtual environment with the project and its locked
+    /// dependencies installed, such that it can be unpacked and run on another machine of the
+    /// same platform without uv installed.
+    ///
+    /// If the project's Python interpreter is a managed Python installation, it is included in the
+    /// bundle, such that the bundle can be unpacked anywhere. Otherwise, or with
+    /// `--no-include-python`, the bundled environment refers to the Python interpreter it was
+    /// created with, which must be present at the same path on the target machine.
+    ///
+    /// The project is re-locked before bundling unless the `--locked` or `--frozen` flag is
+    /// provided.
+    ///
+    /// uv will search for a project in the current directory or any parent directory. If a project
+    /// cannot be found, uv will exit with an error.
+    #[command(
+        after_help = "Use `uv help bundle` for more details.",
+        after_long_help = ""
+    )]
+    Bundle(BundleArgs),
+    /// Install the project into a target prefix for deployment.
+    ///
+    /// Only the project's required dependencies (and any requested extras) are installed;
+    /// development dependencies and other dependency groups are always omitted, and workspace
+    /// members are installed as non-editable. Bytecode is compiled, and any non-essential
+    /// files are removed from each package's `.dist-info` directory, such that the prefix can be
+    /// copied directly into a container image.
+    ///
+    /// The lockfile must be up-to-date; uv will exit with an error if it needs to be updated.
+    ///
+    /// uv will search for a project in the current directory or any parent directory. If a project
+    /// cannot be found, uv will exit with an error.
+    #[command(
+        after_help = "Use `uv help deploy` for more details.",
+        after_long_help = ""
+    )]
+    Deploy(DeployArgs),
+    /// Download the project's locked distributions into a local index.
+    ///
+    /// Every wheel and source distribution referenced by `uv.lock` (across all platforms, extras,
+    /// and dependency groups) is downloaded into a directory laid out as a PEP 503 "simple"
+    /// index, such that the project can later be installed without network access by passing the
+    /// directory to `--index-url` as a `file://` URL.
+    ///
+    /// Distributions that were not resolved from a registry (e.g., Git or direct URL dependencies)
+    /// are not vendored.
+    ///
+    /// The project is re-locked before vendoring unless the `--locked` or `--frozen` flag is
+    /// provided.
+    ///
+    /// uv will search for a project in the current directory or any parent directory. If a project
+    /// cannot be found, uv will exit with an error.
+    #[command(
+        after_help = "Use `uv help vendor` for more details.",
+        after_long_help = ""
+    )]
+    Vendor(VendorArgs),
+    /// Display the licenses of the project's locked dependencies.
+    ///
+    /// The license of every package in `uv.lock` (across all platforms, extras, and dependency
+    /// groups) is read from the metadata of its wheels, and the packages are grouped by license.
+    /// Packages that don't provide a wheel (e.g., Git or source distribution dependencies) are
+    /// built to read their metadata. Workspace members are omitted.
+    ///
+    /// With `--policy`, the licenses are checked against an allow and deny list, and uv will exit
+    /// with an error if any package has a disallowed license, or if the metadata of any package
+    /// can't be read.
+    ///
+    /// The project is re-locked before reading the licenses unless the `--locked` or `--frozen`
+    /// flag is provided.
+    ///
+    /// uv will search for a project in the current directory or any parent directory. If a project
+    /// cannot be found, uv will exit with an error.
+    #[command(
+        after_help = "Use `uv help licenses` for more details.",
+        after_long_help = ""
+    )]
+    Licenses(LicensesArgs),
+    /// List the project's direct dependencies that have newer releases.
+    ///
+    /// For each outdated dependency, displays the locked version, the newest version allowed by
+    /// the dependency's current version specifier, and the newest version available, followed by
+    /// the commands to perform the upgrades: `uv lock --upgrade-package` for upgrades within the
+    /// current specifiers, and `uv add` for upgrades that require updating the specifier in the
+    /// `pyproject.toml`.
+    ///
+    /// Dependencies across all workspace members, extras, and dependency groups are included.
+    /// Dependencies on URLs, local paths, or other sources declared in `tool.uv.sources` are
+    /// omitted. Pre-releases are only considered for dependencies with a locked pre-release.
+    ///
+    /// The project is re-locked before checking for newer releases unless the `--locked` or
+    /// `--frozen` flag is provided.
+    ///
+    /// uv will search for a project in the current directory or any parent directory. If a project
+    /// cannot be found, uv will exit with an error.
+    #[command(
+        after_help = "Use `uv help outdated` for more details.",
+        after_long_help = ""
+    )]
+    Outdated(OutdatedArgs),
     /// Display the project's dependency tree.
     Tree(TreeArgs),
 }
@@ -924,6 +1593,17 @@ pub struct PipCompileArgs {
     #[arg(long, short, env = EnvVars::UV_CONSTRAINT, value_delimiter = ' ', value_parser = parse_maybe_file_path)]
     pub constraint: Vec<Maybe<PathBuf>>,
 
+    /// Constrain versions to those pinned in the given `uv.lock` file.
+    ///
+    /// Every package that was locked from a registry is constrained to its locked version, such
+    /// that the compiled requirements remain consistent with the project's lockfile. Packages
+    /// locked from other sources, like Git repositories or local directories, are not constrained.
+    ///
+    /// As with `--constraint`, including a package in the lockfile will _not_ trigger the
+    /// installation of that package.
+    #[arg(long, value_parser = parse_file_path)]
+    pub constraint_from_lock: Option<PathBuf>,
+
     /// Override versions using the given requirements files.
     ///
     /// Overrides files are `requirements.txt`-like files that force a specific version of a
@@ -948,7 +1628,7 @@ pub struct PipCompileArgs {
     /// Include optional dependencies from the specified extra name; may be provided more than once.
     ///
     /// Only applies to `pyproject.toml`, `setup.py`, and `setup.cfg` sources.
-    #[arg(long, conflicts_with = "all_extras", value_parser = extra_name_with_clap_error)]
+    #[arg(long, conflicts_with = "all_extras", value_parser = extra_name_with_clap_error, add = ArgValueCompleter::new(complete_extra))]
     pub extra: Option<Vec<ExtraName>>,
 
     /// Include all optional dependencies.
@@ -1122,16 +1802,25 @@ pub struct PipCompileArgs {
     ///
     /// If a patch version is omitted, the minimum patch version is assumed. For
     /// example, `3.8` is mapped to `3.8.0`.
+    ///
+    /// May be provided multiple times to compile a separate output file for each version, in
+    /// which case `--output-file` is required. When combined with multiple `--python-platform`
+    /// values, versions and platforms are paired in the order provided.
     #[arg(long, short, help_heading = "Python options")]
-    pub python_version: Option<PythonVersion>,
+    pub python_version: Vec<PythonVersion>,
 
     /// The platform for which requirements should be resolved.
     ///
     /// Represented as a "target triple", a string that describes the target platform in terms of
     /// its CPU, vendor, and operating system name, like `x86_64-unknown-linux-gnu` or
     /// `aarch64-apple-darwin`.
+    ///
+    /// May be provided multiple times to compile a separate output file for each platform, in
+    /// which case `--output-file` is required. Each output file is named after its target, e.g.,
+    /// `--output-file requirements.txt` with `--python-platform linux` writes
+    /// `requirements-linux.txt`.
     #[arg(long)]
-    pub python_platform: Option<TargetTriple>,
+    pub python_platform: Vec<TargetTriple>,
 
     /// Perform a universal resolution, attempting to generate a single `requirements.txt` output
     /// file that is compatible with all operating systems, architectures, and Python
@@ -1394,6 +2083,24 @@ pub struct PipSyncArgs {
     #[arg(long, conflicts_with = "no_build")]
     pub only_binary: Option<Vec<PackageNameSpecifier>>,
 
+    /// The strategy to use when considering yanked versions.
+    ///
+    /// By default, uv will reject yanked versions unless they're pinned by an exact requirement
+    /// (e.g., `==1.0.0`) or `--allow-yanked`, and will emit a warning when a yanked version is
+    /// selected (`warn`).
+    #[arg(long, value_enum, env = EnvVars::UV_YANKED)]
+    pub yanked: Option<YankedMode>,
+
+    /// Allow yanked versions of a specific package.
+    ///
+    /// Accepts both standalone package names (`attrs`), which permit any yanked version of the
+    /// package, and exact version specifiers (`attrs==21.1.0`), which permit only the given
+    /// version.
+    ///
+    /// May be provided multiple times.
+    #[arg(long)]
+    pub allow_yanked: Vec<Requirement<VerbatimParsedUrl>>,
+
     /// Allow sync of empty requirements, which will clear the environment of all packages.
     #[arg(long, overrides_with("no_allow_empty_requirements"))]
     pub allow_empty_requirements: bool,
@@ -1436,6 +2143,14 @@ pub struct PipSyncArgs {
     #[arg(long)]
     pub dry_run: bool,
 
+    /// The format in which to display the planned changes during a dry run.
+    ///
+    /// With `--output-format json`, each package that would be installed, reinstalled, or
+    /// uninstalled is written to stdout as JSON, along with the reason for the change (e.g., a
+    /// version mismatch or an extraneous package).
+    #[arg(long, value_enum, default_value_t = DryRunFormat::default(), requires = "dry_run")]
+    pub output_format: DryRunFormat,
+
     #[command(flatten)]
     pub compat_args: compat::PipSyncCompatArgs,
 }
@@ -1497,7 +2212,7 @@ pub struct PipInstallArgs {
     /// Include optional dependencies from the specified extra name; may be provided more than once.
     ///
     /// Only applies to `pyproject.toml`, `setup.py`, and `setup.cfg` sources.
-    #[arg(long, conflicts_with = "all_extras", value_parser = extra_name_with_clap_error)]
+    #[arg(long, conflicts_with = "all_extras", value_parser = extra_name_with_clap_error, add = ArgValueCompleter::new(complete_extra))]
     pub extra: Option<Vec<ExtraName>>,
 
     /// Include all optional dependencies.
@@ -1724,6 +2439,16 @@ pub struct PipInstallArgs {
     #[arg(long)]
     pub dry_run: bool,
 
+    /// Write a JSON report of the distributions selected for installation to the given path.
+    ///
+    /// The report follows the format of pip's installation report, including the download
+    /// information, direct URL, and core metadata of each distribution. Use `-` to write the
+    /// report to stdout.
+    ///
+    /// Combine with `--dry-run` to generate a report without modifying the environment.
+    #[arg(long, value_name = "PATH")]
+    pub report: Option<PathBuf>,
+
     #[command(flatten)]
     pub compat_args: compat::PipInstallCompatArgs,
 }
@@ -1842,6 +2567,28 @@ pub struct PipFreezeArgs {
     #[arg(long)]
     pub exclude_editable: bool,
 
+    /// Emit packages that were installed from a direct URL as `name==version`, rather than as a
+    /// direct URL or VCS reference.
+    ///
+    /// By default, packages installed from a direct URL (e.g., a Git repository or a local
+    /// directory) are emitted as `name @ url`, with VCS references pinned to the installed commit.
+    #[arg(long, overrides_with("direct_url"))]
+    pub no_direct_url: bool,
+
+    #[arg(long, overrides_with("no_direct_url"), hide = true)]
+    pub direct_url: bool,
+
+    /// Restrict to the packages installed in the specified path(s).
+    ///
+    /// Paths are treated as `--target` directories, such that packages installed with
+    /// `--target` can be listed. Paths that don't exist are ignored.
+    #[arg(long)]
+    pub path: Option<Vec<PathBuf>>,
+
+    /// Select the output format between: `requirements` (default) or `json`.
+    #[arg(long, value_enum, default_value_t = FreezeFormat::default())]
+    pub format: FreezeFormat,
+
     /// Validate the Python environment, to detect packages with missing dependencies and other
     /// issues.
     #[arg(long, overrides_with("no_strict"))]
@@ -1991,6 +2738,48 @@ pub struct PipCheckArgs {
     pub no_system: bool,
 }
 
+#[derive(Args)]
+pub struct PipVerifyArgs {
+    /// The package(s) to verify.
+    ///
+    /// By default, all installed packages are verified.
+    pub package: Vec<PackageName>,
+
+    /// The Python interpreter for which packages should be verified.
+    ///
+    /// By default, uv verifies packages in a virtual environment but will verify
+    /// packages in a system Python environment if no virtual environment is
+    /// found.
+    ///
+    /// See `uv help python` for details on Python discovery and supported
+    /// request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+
+    /// Verify packages in the system Python environment.
+    ///
+    /// Disables discovery of virtual environments.
+    ///
+    /// See `uv help python` for details on Python discovery.
+    #[arg(
+        long,
+        env = EnvVars::UV_SYSTEM_PYTHON,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_system")
+    )]
+    pub system: bool,
+
+    #[arg(long, overrides_with("system"), hide = true)]
+    pub no_system: bool,
+}
+
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct PipShowArgs {
@@ -2390,7 +3179,7 @@ pub struct VenvArgs {
     ///
     /// This option is only used for installing seed packages.
     ///
-    /// Defaults to `clone` (also known as Copy-on-Write) on macOS, and `hardlink` on Linux and
+    /// Defaults to `clone` (also known as Copy-on-Write) on macOS, and `auto` on Linux and
     /// Windows.
     #[arg(long, value_enum, env = EnvVars::UV_LINK_MODE)]
     pub link_mode: Option<uv_install_wheel::linker::LinkMode>,
@@ -2583,7 +3372,7 @@ pub struct RunArgs {
     /// a `pyproject.toml`.
     ///
     /// This option is only available when running in a project.
-    #[arg(long, conflicts_with = "all_extras", value_parser = extra_name_with_clap_error)]
+    #[arg(long, conflicts_with = "all_extras", value_parser = extra_name_with_clap_error, add = ArgValueCompleter::new(complete_extra))]
     pub extra: Option<Vec<ExtraName>>,
 
     /// Include all optional dependencies.
@@ -2620,13 +3409,13 @@ pub struct RunArgs {
     /// Include dependencies from the specified dependency group.
     ///
     /// May be provided multiple times.
-    #[arg(long, conflicts_with("only_group"))]
+    #[arg(long, conflicts_with("only_group"), add = ArgValueCompleter::new(complete_group))]
     pub group: Vec<GroupName>,
 
     /// Exclude dependencies from the specified dependency group.
     ///
     /// May be provided multiple times.
-    #[arg(long)]
+    #[arg(long, add = ArgValueCompleter::new(complete_group))]
     pub no_group: Vec<GroupName>,
 
     /// Only include dependencies from the specified dependency group.
@@ -2634,7 +3423,7 @@ pub struct RunArgs {
     /// May be provided multiple times.
     ///
     /// The project itself will also be omitted.
-    #[arg(long, conflicts_with("group"))]
+    #[arg(long, conflicts_with("group"), add = ArgValueCompleter::new(complete_group))]
     pub only_group: Vec<GroupName>,
 
     /// Run a Python module.
@@ -2707,6 +3496,31 @@ pub struct RunArgs {
     #[arg(long, env = EnvVars::UV_NO_SYNC, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "frozen")]
     pub no_sync: bool,
 
+    /// Do not run the project's lifecycle hooks.
+    ///
+    /// By default, the `post-lock` hooks declared in `tool.uv.hooks` are run if the lockfile is
+    /// updated, and the `pre-sync` and `post-sync` hooks are run before and after the environment
+    /// is synced.
+    #[arg(long, env = EnvVars::UV_NO_HOOKS, value_parser = clap::builder::BoolishValueParser::new())]
+    pub no_hooks: bool,
+
+    /// Load environment variables from a `.env` file.
+    ///
+    /// May be provided multiple times, in which case variables defined in later files override
+    /// those defined in earlier files. Files provided here take precedence over those listed in
+    /// `tool.uv.env-files`, and variables that are already set in the environment take precedence
+    /// over both.
+    ///
+    /// Multiple files may be separated by the platform's path separator (`:` on Unix, `;` on
+    /// Windows), as in `PATH`.
+    #[arg(long, value_name = "PATH", env = EnvVars::UV_ENV_FILE, value_delimiter = if cfg!(windows) { ';' } else { ':' })]
+    pub env_file: Vec<PathBuf>,
+
+    /// Avoid reading environment variables from `.env` files, including those listed in
+    /// `tool.uv.env-files`.
+    #[arg(long, env = EnvVars::UV_NO_ENV_FILE, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "env_file")]
+    pub no_env_file: bool,
+
     /// Assert that the `uv.lock` will remain unchanged.
     ///
     /// Requires that the lockfile is up-to-date. If the lockfile is missing or
@@ -2731,6 +3545,14 @@ pub struct RunArgs {
     #[arg(long, short, conflicts_with = "module")]
     pub script: bool,
 
+    /// Run the given path as a Python GUI script.
+    ///
+    /// Using `--gui-script` will attempt to parse the path as a PEP 723 script
+    /// and run it with `pythonw.exe`, irrespective of its extension, such that
+    /// no console window is opened. Only available on Windows.
+    #[arg(long, alias = "gui", conflicts_with_all = ["script", "module"])]
+    pub gui_script: bool,
+
     #[command(flatten)]
     pub installer: ResolverInstallerArgs,
 
@@ -2789,7 +3611,7 @@ pub struct SyncArgs {
     ///
     /// Note that all optional dependencies are always included in the resolution; this option only
     /// affects the selection of packages to install.
-    #[arg(long, conflicts_with = "all_extras", value_parser = extra_name_with_clap_error)]
+    #[arg(long, conflicts_with = "all_extras", value_parser = extra_name_with_clap_error, add = ArgValueCompleter::new(complete_extra))]
     pub extra: Option<Vec<ExtraName>>,
 
     /// Include all optional dependencies.
@@ -2825,13 +3647,13 @@ pub struct SyncArgs {
     /// Include dependencies from the specified dependency group.
     ///
     /// May be provided multiple times.
-    #[arg(long, conflicts_with("only_group"))]
+    #[arg(long, conflicts_with("only_group"), add = ArgValueCompleter::new(complete_group))]
     pub group: Vec<GroupName>,
 
     /// Exclude dependencies from the specified dependency group.
     ///
     /// May be provided multiple times.
-    #[arg(long)]
+    #[arg(long, add = ArgValueCompleter::new(complete_group))]
     pub no_group: Vec<GroupName>,
 
     /// Only include dependencies from the specified dependency group.
@@ -2839,7 +3661,7 @@ pub struct SyncArgs {
     /// May be provided multiple times.
     ///
     /// The project itself will also be omitted.
-    #[arg(long, conflicts_with("group"))]
+    #[arg(long, conflicts_with("group"), add = ArgValueCompleter::new(complete_group))]
     pub only_group: Vec<GroupName>,
 
     /// Install any editable dependencies, including the project and any workspace members, as
@@ -2886,6 +3708,22 @@ pub struct SyncArgs {
     #[arg(long)]
     pub no_install_package: Vec<PackageName>,
 
+    /// Write a JSON report of the changes made to the environment to the given path.
+    ///
+    /// The report includes every distribution that was installed, reinstalled, removed, or kept
+    /// as-is, along with its version, direct URL (if any), hashes, and whether it was requested
+    /// directly by the project, similar to `pip install --report`.
+    #[arg(long, value_name = "PATH")]
+    pub report: Option<PathBuf>,
+
+    /// Do not run the project's lifecycle hooks.
+    ///
+    /// By default, the `pre-sync` and `post-sync` hooks declared in `tool.uv.hooks` are run before
+    /// and after the environment is synced, and the `post-lock` hooks are run after the lockfile is
+    /// updated.
+    #[arg(long, env = EnvVars::UV_NO_HOOKS, value_parser = clap::builder::BoolishValueParser::new())]
+    pub no_hooks: bool,
+
     /// Assert that the `uv.lock` will remain unchanged.
     ///
     /// Requires that the lockfile is up-to-date. If the lockfile is missing or
@@ -2903,6 +3741,15 @@ pub struct SyncArgs {
     #[arg(long, env = EnvVars::UV_FROZEN, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "locked")]
     pub frozen: bool,
 
+    /// Minimize the changes to the existing lockfile.
+    ///
+    /// By default, uv retains the locked version of each package that still satisfies the
+    /// project's requirements, but selects the latest compatible version of any package that
+    /// doesn't. With `--minimal-changes`, uv instead selects the compatible version nearest to the
+    /// locked version when updating the lockfile.
+    #[arg(long, conflicts_with = "frozen", help_heading = "Resolver options")]
+    pub minimal_changes: bool,
+
     #[command(flatten)]
     pub installer: ResolverInstallerArgs,
 
@@ -2964,6 +3811,94 @@ pub struct LockArgs {
     #[arg(long, conflicts_with = "frozen", conflicts_with = "locked")]
     pub dry_run: bool,
 
+    /// Audit the declared dependency bounds against the resolved versions in the lockfile.
+    ///
+    /// Reports any declared requirement whose upper bound excludes a newer release that's
+    /// available on the index, or whose lower bound falls below the release series of the
+    /// resolved version. Exits with a non-zero status if any such bounds are found.
+    #[arg(long)]
+    pub check_bounds: bool,
+
+    /// Verify that the lockfile can be installed in every supported environment.
+    ///
+    /// Reports any environment declared in `tool.uv.environments` (or, if none are declared, any
+    /// of Linux, macOS, and Windows) in which a locked package has neither a compatible wheel nor a
+    /// source distribution, and would thus fail at sync time. Wheels are matched against the
+    /// environment's operating system, architecture, and Python version. Exits with a non-zero
+    /// status if any such environments are found.
+    #[arg(long)]
+    pub check_platforms: bool,
+
+    /// The format in which to display the lockfile changes.
+    ///
+    /// With `--output-format json`, the added, removed, and updated packages are written to stdout
+    /// as JSON. Combine with `--dry-run` to preview the changes without writing the lockfile.
+    #[arg(long, value_enum, default_value_t = LockFormat::default())]
+    pub output_format: LockFormat,
+
+    /// Rewrite the lockfile in the given format.
+    ///
+    /// The `diffable` format writes every requirement and artifact on its own line and orders
+    /// wheels by filename, to minimize the size of lockfile diffs in version control. Once
+    /// migrated, subsequent operations retain the lockfile's format.
+    #[arg(long, value_enum, conflicts_with = "frozen", conflicts_with = "locked")]
+    pub migrate_format: Option<LockfileFormat>,
+
+    /// Write the resolved dependency graph to the given file.
+    ///
+    /// The graph includes a node for each locked package version and an edge for each dependency,
+    /// labeled with its markers, extras, and dependency groups. Packages that are only included in
+    /// some forks of the resolution are annotated with the corresponding markers.
+    ///
+    /// The format is inferred from the file extension: `.graphml` files are written as GraphML,
+    /// and all other files in the Graphviz DOT language. Use `-` to write the graph to stdout.
+    #[arg(long, value_name = "PATH")]
+    pub graph_output: Option<PathBuf>,
+
+    /// Do not run the project's lifecycle hooks.
+    ///
+    /// By default, the `post-lock` hooks declared in `tool.uv.hooks` are run after the lockfile is
+    /// updated.
+    #[arg(long, env = EnvVars::UV_NO_HOOKS, value_parser = clap::builder::BoolishValueParser::new())]
+    pub no_hooks: bool,
+
+    /// Prefer the versions pinned in the given file when resolving.
+    ///
+    /// Accepts either a `uv.lock` file (e.g., from a related workspace) or a `requirements.txt`
+    /// file. The pinned versions are treated as soft preferences: they're respected if compatible
+    /// with the project's requirements, but never at the expense of the existing `uv.lock`.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, value_parser = parse_file_path, help_heading = "Resolver options")]
+    pub preference_file: Vec<PathBuf>,
+
+    /// Minimize the changes to the existing lockfile.
+    ///
+    /// By default, uv retains the locked version of each package that still satisfies the
+    /// project's requirements, but selects the latest compatible version of any package that
+    /// doesn't. With `--minimal-changes`, uv instead selects the compatible version nearest to the
+    /// locked version, i.e., the smallest upgrade (or, if no upgrade is possible, the smallest
+    /// downgrade) that satisfies the requirements.
+    #[arg(long, help_heading = "Resolver options")]
+    pub minimal_changes: bool,
+
+    /// Advance every Git source that tracks a branch or tag to its latest commit.
+    ///
+    /// Unlike `--upgrade`, the locked versions of all other packages are retained. Git sources
+    /// pinned to a specific commit (via `rev`) are left unchanged.
+    #[arg(long, help_heading = "Resolver options")]
+    pub upgrade_git: bool,
+
+    /// Advance the Git source for a specific package to the latest commit on its branch or tag.
+    ///
+    /// The locked versions of all other packages are retained.
+    #[arg(
+        long,
+        help_heading = "Resolver options",
+        conflicts_with = "upgrade_git"
+    )]
+    pub upgrade_git_package: Vec<PackageName>,
+
     #[command(flatten)]
     pub resolver: ResolverArgs,
 
@@ -2999,7 +3934,7 @@ pub struct LockArgs {
 #[allow(clippy::struct_excessive_bools)]
 pub struct AddArgs {
     /// The packages to add, as PEP 508 requirements (e.g., `ruff==0.5.0`).
-    #[arg(group = "sources")]
+    #[arg(group = "sources", add = ArgValueCompleter::new(complete_package_name))]
     pub packages: Vec<String>,
 
     /// Add all packages listed in the given `requirements.txt` files.
@@ -3018,13 +3953,13 @@ pub struct AddArgs {
     /// `--extra` flag.
     ///
     /// To enable an optional extra for this requirement instead, see `--extra`.
-    #[arg(long, conflicts_with("dev"), conflicts_with("group"))]
+    #[arg(long, conflicts_with("dev"), conflicts_with("group"), add = ArgValueCompleter::new(complete_extra))]
     pub optional: Option<ExtraName>,
 
     /// Add the requirements to the specified dependency group.
     ///
     /// These requirements will not be included in the published metadata for the project.
-    #[arg(long, conflicts_with("dev"), conflicts_with("optional"))]
+    #[arg(long, conflicts_with("dev"), conflicts_with("optional"), add = ArgValueCompleter::new(complete_group))]
     pub group: Option<GroupName>,
 
     /// Add the requirements as editable.
@@ -3044,7 +3979,8 @@ pub struct AddArgs {
         conflicts_with = "no_editable",
         conflicts_with = "rev",
         conflicts_with = "tag",
-        conflicts_with = "branch"
+        conflicts_with = "branch",
+        conflicts_with = "bookmark"
     )]
     pub raw_sources: bool,
 
@@ -3060,6 +3996,10 @@ pub struct AddArgs {
     #[arg(long, group = "git-ref", action = clap::ArgAction::Set)]
     pub branch: Option<String>,
 
+    /// Bookmark to use when adding a dependency from Mercurial.
+    #[arg(long, group = "git-ref", action = clap::ArgAction::Set)]
+    pub bookmark: Option<String>,
+
     /// Extras to enable for the dependency.
     ///
     /// May be provided more than once.
@@ -3072,6 +4012,14 @@ pub struct AddArgs {
     #[arg(long, env = EnvVars::UV_NO_SYNC, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "frozen")]
     pub no_sync: bool,
 
+    /// Do not run the project's lifecycle hooks.
+    ///
+    /// By default, the `post-lock` hooks declared in `tool.uv.hooks` are run if the lockfile is
+    /// updated, and the `pre-sync` and `post-sync` hooks are run before and after the environment
+    /// is synced.
+    #[arg(long, env = EnvVars::UV_NO_HOOKS, value_parser = clap::builder::BoolishValueParser::new())]
+    pub no_hooks: bool,
+
     /// Assert that the `uv.lock` will remain unchanged.
     ///
     /// Requires that the lockfile is up-to-date. If the lockfile is missing or
@@ -3085,6 +4033,15 @@ pub struct AddArgs {
     #[arg(long, env = EnvVars::UV_FROZEN, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "locked")]
     pub frozen: bool,
 
+    /// Minimize the changes to the existing lockfile.
+    ///
+    /// By default, uv retains the locked version of each package that still satisfies the
+    /// project's requirements, but selects the latest compatible version of any package that
+    /// doesn't. With `--minimal-changes`, uv instead selects the compatible version nearest to the
+    /// locked version when updating the lockfile.
+    #[arg(long, conflicts_with = "frozen", help_heading = "Resolver options")]
+    pub minimal_changes: bool,
+
     #[command(flatten)]
     pub installer: ResolverInstallerArgs,
 
@@ -3137,17 +4094,63 @@ pub struct RemoveArgs {
     pub dev: bool,
 
     /// Remove the packages from the project's optional dependencies for the specified extra.
-    #[arg(long, conflicts_with("dev"), conflicts_with("group"))]
+    #[arg(long, conflicts_with("dev"), conflicts_with("group"), add = ArgValueCompleter::new(complete_extra))]
     pub optional: Option<ExtraName>,
 
     /// Remove the packages from the specified dependency group.
-    #[arg(long, conflicts_with("dev"), conflicts_with("optional"))]
+    #[arg(long, conflicts_with("dev"), conflicts_with("optional"), add = ArgValueCompleter::new(complete_group))]
     pub group: Option<GroupName>,
 
+    /// Move the packages to the specified dependency group, rather than removing them.
+    ///
+    /// The version specifier, markers, extras, and any `tool.uv.sources` entry of each dependency
+    /// are preserved.
+    #[arg(
+        long,
+        conflicts_with("move_to_optional"),
+        conflicts_with("move_to_project"),
+        conflicts_with("script")
+    )]
+    pub move_to_group: Option<GroupName>,
+
+    /// Move the packages to the project's optional dependencies for the specified extra, rather
+    /// than removing them.
+    ///
+    /// The version specifier, markers, extras, and any `tool.uv.sources` entry of each dependency
+    /// are preserved.
+    #[arg(
+        long,
+        conflicts_with("move_to_group"),
+        conflicts_with("move_to_project"),
+        conflicts_with("script")
+    )]
+    pub move_to_optional: Option<ExtraName>,
+
+    /// Move the packages to the project's dependencies (`project.dependencies`), rather than
+    /// removing them.
+    ///
+    /// The version specifier, markers, extras, and any `tool.uv.sources` entry of each dependency
+    /// are preserved.
+    #[arg(
+        long,
+        conflicts_with("move_to_group"),
+        conflicts_with("move_to_optional"),
+        conflicts_with("script")
+    )]
+    pub move_to_project: bool,
+
     /// Avoid syncing the virtual environment after re-locking the project.
     #[arg(long, env = EnvVars::UV_NO_SYNC, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "frozen")]
     pub no_sync: bool,
 
+    /// Do not run the project's lifecycle hooks.
+    ///
+    /// By default, the `post-lock` hooks declared in `tool.uv.hooks` are run if the lockfile is
+    /// updated, and the `pre-sync` and `post-sync` hooks are run before and after the environment
+    /// is synced.
+    #[arg(long, env = EnvVars::UV_NO_HOOKS, value_parser = clap::builder::BoolishValueParser::new())]
+    pub no_hooks: bool,
+
     /// Assert that the `uv.lock` will remain unchanged.
     ///
     /// Requires that the lockfile is up-to-date. If the lockfile is missing or
@@ -3238,13 +4241,13 @@ pub struct TreeArgs {
     /// Include dependencies from the specified dependency group.
     ///
     /// May be provided multiple times.
-    #[arg(long, conflicts_with("only_group"))]
+    #[arg(long, conflicts_with("only_group"), add = ArgValueCompleter::new(complete_group))]
     pub group: Vec<GroupName>,
 
     /// Exclude dependencies from the specified dependency group.
     ///
     /// May be provided multiple times.
-    #[arg(long)]
+    #[arg(long, add = ArgValueCompleter::new(complete_group))]
     pub no_group: Vec<GroupName>,
 
     /// Only include dependencies from the specified dependency group.
@@ -3252,7 +4255,7 @@ pub struct TreeArgs {
     /// May be provided multiple times.
     ///
     /// The project itself will also be omitted.
-    #[arg(long, conflicts_with("group"))]
+    #[arg(long, conflicts_with("group"), add = ArgValueCompleter::new(complete_group))]
     pub only_group: Vec<GroupName>,
 
     /// Assert that the `uv.lock` will remain unchanged.
@@ -3316,14 +4319,31 @@ pub struct TreeArgs {
 
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
-pub struct ExportArgs {
-    /// The format to which `uv.lock` should be exported.
+pub struct BundleArgs {
+    /// Write the bundle to the given path.
     ///
-    /// At present, only `requirements-txt` is supported.
-    #[arg(long, value_enum, default_value_t = ExportFormat::default())]
-    pub format: ExportFormat,
+    /// Defaults to `dist/{project}-bundle.zip` in the workspace root.
+    #[arg(long, short)]
+    pub output_file: Option<PathBuf>,
 
-    /// Export the dependencies for a specific package in the workspace.
+    /// Include the Python interpreter in the bundle.
+    ///
+    /// The interpreter must be a managed Python installation, as system interpreters are not
+    /// relocatable. When included, packages are installed directly into the bundled interpreter,
+    /// rather than into a virtual environment.
+    ///
+    /// By default, the interpreter is included if it is a managed Python installation.
+    #[arg(long, overrides_with("no_include_python"))]
+    pub include_python: bool,
+
+    /// Do not include the Python interpreter in the bundle.
+    ///
+    /// The bundled environment will refer to the Python interpreter it was created with, which
+    /// must be present at the same path on the target machine.
+    #[arg(long, overrides_with("include_python"))]
+    pub no_include_python: bool,
+
+    /// Bundle a specific package in the workspace.
     ///
     /// If the workspace member does not exist, uv will exit with an error.
     #[arg(long)]
@@ -3332,7 +4352,7 @@ pub struct ExportArgs {
     /// Include optional dependencies from the specified extra name.
     ///
     /// May be provided more than once.
-    #[arg(long, conflicts_with = "all_extras", value_parser = extra_name_with_clap_error)]
+    #[arg(long, conflicts_with = "all_extras", value_parser = extra_name_with_clap_error, add = ArgValueCompleter::new(complete_extra))]
     pub extra: Option<Vec<ExtraName>>,
 
     /// Include all optional dependencies.
@@ -3365,13 +4385,13 @@ pub struct ExportArgs {
     /// Include dependencies from the specified dependency group.
     ///
     /// May be provided multiple times.
-    #[arg(long, conflicts_with("only_group"))]
+    #[arg(long, conflicts_with("only_group"), add = ArgValueCompleter::new(complete_group))]
     pub group: Vec<GroupName>,
 
     /// Exclude dependencies from the specified dependency group.
     ///
     /// May be provided multiple times.
-    #[arg(long)]
+    #[arg(long, add = ArgValueCompleter::new(complete_group))]
     pub no_group: Vec<GroupName>,
 
     /// Only include dependencies from the specified dependency group.
@@ -3379,55 +4399,24 @@ pub struct ExportArgs {
     /// May be provided multiple times.
     ///
     /// The project itself will also be omitted.
-    #[arg(long, conflicts_with("group"))]
+    #[arg(long, conflicts_with("group"), add = ArgValueCompleter::new(complete_group))]
     pub only_group: Vec<GroupName>,
 
-    /// Exclude the comment header at the top of the generated output file.
-    #[arg(long, overrides_with("header"))]
-    pub no_header: bool,
-
-    #[arg(long, overrides_with("no_header"), hide = true)]
-    pub header: bool,
-
-    /// Install any editable dependencies, including the project and any workspace members, as
-    /// non-editable.
-    #[arg(long)]
-    pub no_editable: bool,
-
-    /// Include hashes for all dependencies.
-    #[arg(long, overrides_with("no_hashes"), hide = true)]
-    pub hashes: bool,
-
-    /// Omit hashes in the generated output.
-    #[arg(long, overrides_with("hashes"))]
-    pub no_hashes: bool,
-
-    /// Write the exported requirements to the given file.
-    #[arg(long, short)]
-    pub output_file: Option<PathBuf>,
-
-    /// Do not emit the current project.
+    /// Do not install the current project.
     ///
-    /// By default, the current project is included in the exported requirements file with all of its
-    /// dependencies. The `--no-emit-project` option allows the project to be excluded, but all of
-    /// its dependencies to remain included.
-    #[arg(long, alias = "no-install-project")]
-    pub no_emit_project: bool,
+    /// By default, the current project is installed into the bundle with all of its
+    /// dependencies. The `--no-install-project` option allows the project to be excluded, but all
+    /// of its dependencies are still installed.
+    #[arg(long)]
+    pub no_install_project: bool,
 
-    /// Do not emit any workspace members, including the root project.
-    ///
-    /// By default, all workspace members and their dependencies are included in the exported
-    /// requirements file, with all of their dependencies. The `--no-emit-workspace` option allows
-    /// exclusion of all the workspace members while retaining their dependencies.
-    #[arg(long, alias = "no-install-workspace")]
-    pub no_emit_workspace: bool,
+    /// Do not install any workspace members, including the root project.
+    #[arg(long)]
+    pub no_install_workspace: bool,
 
-    /// Do not emit the given package(s).
-    ///
-    /// By default, all of the project's dependencies are included in the exported requirements
-    /// file. The `--no-install-package` option allows exclusion of specific packages.
-    #[arg(long, alias = "no-install-package")]
-    pub no_emit_package: Vec<PackageName>,
+    /// Do not install the given package(s).
+    #[arg(long)]
+    pub no_install_package: Vec<PackageName>,
 
     /// Assert that the `uv.lock` will remain unchanged.
     ///
@@ -3436,14 +4425,14 @@ pub struct ExportArgs {
     #[arg(long, env = EnvVars::UV_LOCKED, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "frozen")]
     pub locked: bool,
 
-    /// Do not update the `uv.lock` before exporting.
+    /// Do not update the `uv.lock` before bundling.
     ///
     /// If a `uv.lock` does not exist, uv will exit with an error.
     #[arg(long, env = EnvVars::UV_FROZEN, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "locked")]
     pub frozen: bool,
 
     #[command(flatten)]
-    pub resolver: ResolverArgs,
+    pub installer: ResolverInstallerArgs,
 
     #[command(flatten)]
     pub build: BuildOptionsArgs,
@@ -3451,13 +4440,434 @@ pub struct ExportArgs {
     #[command(flatten)]
     pub refresh: RefreshArgs,
 
-    /// The Python interpreter to use during resolution.
+    /// The Python interpreter to use for the bundled environment.
     ///
-    /// A Python interpreter is required for building source distributions to
-    /// determine package metadata when there are not wheels.
+    /// By default, the first interpreter that meets the project's
+    /// `requires-python` constraint is used.
     ///
-    /// The interpreter is also used as the fallback value for the minimum
-    /// Python version if `requires-python` is not set.
+    /// See `uv help python` for details on Python discovery and supported
+    /// request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct DeployArgs {
+    /// The prefix directory into which the project should be installed.
+    ///
+    /// Packages are installed into `lib/pythonX.Y/site-packages` (or `Lib/site-packages` on
+    /// Windows) and scripts into `bin` (or `Scripts`) within the prefix, which can be added to
+    /// `PYTHONPATH` and `PATH` respectively.
+    #[arg(long)]
+    pub prefix: PathBuf,
+
+    /// Include optional dependencies from the specified extra name.
+    ///
+    /// May be provided more than once.
+    #[arg(long, conflicts_with = "all_extras", value_parser = extra_name_with_clap_error, add = ArgValueCompleter::new(complete_extra))]
+    pub extra: Option<Vec<ExtraName>>,
+
+    /// Include all optional dependencies.
+    #[arg(long, conflicts_with = "extra")]
+    pub all_extras: bool,
+
+    #[arg(long, overrides_with("all_extras"), hide = true)]
+    pub no_all_extras: bool,
+
+    /// Do not install the current project.
+    ///
+    /// By default, the current project is installed into the prefix with all of its dependencies.
+    /// The `--no-install-project` option allows the project to be excluded, but all of its
+    /// dependencies are still installed, e.g., to populate a container layer before the project
+    /// source is copied in.
+    #[arg(long)]
+    pub no_install_project: bool,
+
+    /// Do not install any workspace members, including the root project.
+    #[arg(long)]
+    pub no_install_workspace: bool,
+
+    /// Do not install the given package(s).
+    #[arg(long)]
+    pub no_install_package: Vec<PackageName>,
+
+    /// Deploy without checking if the `uv.lock` is up-to-date.
+    ///
+    /// Uses the versions in the lockfile as the source of truth. If the lockfile is missing, uv
+    /// will exit with an error.
+    #[arg(long, env = EnvVars::UV_FROZEN, value_parser = clap::builder::BoolishValueParser::new())]
+    pub frozen: bool,
+
+    #[command(flatten)]
+    pub installer: ResolverInstallerArgs,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+
+    /// Deploy a specific package in the workspace.
+    ///
+    /// If the workspace member does not exist, uv will exit with an error.
+    #[arg(long)]
+    pub package: Option<PackageName>,
+
+    /// The Python interpreter for which packages should be installed.
+    ///
+    /// By default, the first interpreter that meets the project's
+    /// `requires-python` constraint is used.
+    ///
+    /// See `uv help python` for details on Python discovery and supported
+    /// request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct VendorArgs {
+    /// The directory to write the index to.
+    ///
+    /// Defaults to a `vendor` directory in the workspace root. Files that already exist in the
+    /// directory are not downloaded again.
+    #[arg(long, short)]
+    pub output_dir: Option<PathBuf>,
+
+    /// Assert that the `uv.lock` will remain unchanged.
+    ///
+    /// Requires that the lockfile is up-to-date. If the lockfile is missing or
+    /// needs to be updated, uv will exit with an error.
+    #[arg(long, env = EnvVars::UV_LOCKED, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "frozen")]
+    pub locked: bool,
+
+    /// Do not update the `uv.lock` before vendoring.
+    ///
+    /// If a `uv.lock` does not exist, uv will exit with an error.
+    #[arg(long, env = EnvVars::UV_FROZEN, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "locked")]
+    pub frozen: bool,
+
+    #[command(flatten)]
+    pub resolver: ResolverArgs,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+
+    /// The Python interpreter to use during resolution.
+    ///
+    /// A Python interpreter is required for building source distributions to
+    /// determine package metadata when there are not wheels.
+    ///
+    /// The interpreter is also used as the fallback value for the minimum
+    /// Python version if `requires-python` is not set.
+    ///
+    /// See `uv help python` for details on Python discovery and supported
+    /// request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct LicensesArgs {
+    /// The path to a license policy file.
+    ///
+    /// The policy is a TOML file with an `allow` list and a `deny` list of license names or SPDX
+    /// identifiers (e.g., `allow = ["MIT", "Apache-2.0"]`), and an `ignore` list of packages to
+    /// exempt from the policy.
+    ///
+    /// A license is disallowed if it's denied, or if an `allow` list is provided and the license
+    /// isn't in it. SPDX expressions are evaluated, such that `MIT OR GPL-3.0-only` is allowed if
+    /// `MIT` is allowed. If an `allow` list is provided, packages with an unknown license are
+    /// disallowed.
+    #[arg(long, env = EnvVars::UV_LICENSE_POLICY)]
+    pub policy: Option<PathBuf>,
+
+    /// The format in which to display the licenses.
+    #[arg(long, value_enum, default_value_t = LicensesFormat::default())]
+    pub output_format: LicensesFormat,
+
+    /// Assert that the `uv.lock` will remain unchanged.
+    ///
+    /// Requires that the lockfile is up-to-date. If the lockfile is missing or
+    /// needs to be updated, uv will exit with an error.
+    #[arg(long, env = EnvVars::UV_LOCKED, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "frozen")]
+    pub locked: bool,
+
+    /// Do not update the `uv.lock` before reading the licenses.
+    ///
+    /// If a `uv.lock` does not exist, uv will exit with an error.
+    #[arg(long, env = EnvVars::UV_FROZEN, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "locked")]
+    pub frozen: bool,
+
+    #[command(flatten)]
+    pub resolver: ResolverArgs,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+
+    /// The Python interpreter to use during resolution.
+    ///
+    /// A Python interpreter is required for building source distributions to
+    /// determine package metadata when there are not wheels.
+    ///
+    /// The interpreter is also used as the fallback value for the minimum
+    /// Python version if `requires-python` is not set.
+    ///
+    /// See `uv help python` for details on Python discovery and supported
+    /// request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct OutdatedArgs {
+    /// The format in which to display the outdated dependencies.
+    #[arg(long, value_enum, default_value_t = OutdatedFormat::default())]
+    pub output_format: OutdatedFormat,
+
+    /// Assert that the `uv.lock` will remain unchanged.
+    ///
+    /// Requires that the lockfile is up-to-date. If the lockfile is missing or
+    /// needs to be updated, uv will exit with an error.
+    #[arg(long, env = EnvVars::UV_LOCKED, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "frozen")]
+    pub locked: bool,
+
+    /// Do not update the `uv.lock` before checking for newer releases.
+    ///
+    /// If a `uv.lock` does not exist, uv will exit with an error.
+    #[arg(long, env = EnvVars::UV_FROZEN, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "locked")]
+    pub frozen: bool,
+
+    #[command(flatten)]
+    pub resolver: ResolverArgs,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+
+    /// The Python interpreter to use during resolution.
+    ///
+    /// A Python interpreter is required for building source distributions to
+    /// determine package metadata when there are not wheels.
+    ///
+    /// The interpreter is also used as the fallback value for the minimum
+    /// Python version if `requires-python` is not set.
+    ///
+    /// See `uv help python` for details on Python discovery and supported
+    /// request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct ExportArgs {
+    /// The format to which `uv.lock` should be exported.
+    ///
+    /// With `zipapp`, the project and its locked dependencies are installed into a single
+    /// executable zip archive (as in `python -m zipapp`), which requires `--output-file` and
+    /// `--entry-point`. The `pex` format is similar, but builds a PEX file with the `pex`
+    /// executable, which must be installed separately.
+    #[arg(long, value_enum, default_value_t = ExportFormat::default())]
+    pub format: ExportFormat,
+
+    /// The entry point to invoke when running a `zipapp` or `pex` export.
+    ///
+    /// Accepts either a module (e.g., `example`), which is run as `__main__`, or a callable in the
+    /// form `module:function` (e.g., `example.cli:main`), whose return value is used as the exit
+    /// status.
+    #[arg(long, required_if_eq_any([("format", "zipapp"), ("format", "pex")]))]
+    pub entry_point: Option<String>,
+
+    /// The Python interpreter to reference in the shebang line of a `zipapp` or `pex` export.
+    ///
+    /// Defaults to `/usr/bin/env python3`.
+    #[arg(long, value_name = "INTERPRETER")]
+    pub zipapp_python: Option<String>,
+
+    /// Export the dependencies for a specific package in the workspace.
+    ///
+    /// If the workspace member does not exist, uv will exit with an error.
+    #[arg(long)]
+    pub package: Option<PackageName>,
+
+    /// Include optional dependencies from the specified extra name.
+    ///
+    /// May be provided more than once.
+    #[arg(long, conflicts_with = "all_extras", value_parser = extra_name_with_clap_error, add = ArgValueCompleter::new(complete_extra))]
+    pub extra: Option<Vec<ExtraName>>,
+
+    /// Include all optional dependencies.
+    #[arg(long, conflicts_with = "extra")]
+    pub all_extras: bool,
+
+    #[arg(long, overrides_with("all_extras"), hide = true)]
+    pub no_all_extras: bool,
+
+    /// Include the development dependency group.
+    ///
+    /// This option is an alias for `--group dev`.
+    #[arg(long, overrides_with("no_dev"), hide = true)]
+    pub dev: bool,
+
+    /// Omit the development dependency group.
+    ///
+    /// This option is an alias for `--no-group dev`.
+    #[arg(long, overrides_with("dev"))]
+    pub no_dev: bool,
+
+    /// Only include the development dependency group.
+    ///
+    /// Omit other dependencies. The project itself will also be omitted.
+    ///
+    /// This option is an alias for `--only-group dev`.
+    #[arg(long, conflicts_with("no_dev"))]
+    pub only_dev: bool,
+
+    /// Include dependencies from the specified dependency group.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, conflicts_with("only_group"), add = ArgValueCompleter::new(complete_group))]
+    pub group: Vec<GroupName>,
+
+    /// Exclude dependencies from the specified dependency group.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, add = ArgValueCompleter::new(complete_group))]
+    pub no_group: Vec<GroupName>,
+
+    /// Only include dependencies from the specified dependency group.
+    ///
+    /// May be provided multiple times.
+    ///
+    /// The project itself will also be omitted.
+    #[arg(long, conflicts_with("group"), add = ArgValueCompleter::new(complete_group))]
+    pub only_group: Vec<GroupName>,
+
+    /// Exclude the comment header at the top of the generated output file.
+    #[arg(long, overrides_with("header"))]
+    pub no_header: bool,
+
+    #[arg(long, overrides_with("no_header"), hide = true)]
+    pub header: bool,
+
+    /// Install any editable dependencies, including the project and any workspace members, as
+    /// non-editable.
+    #[arg(long)]
+    pub no_editable: bool,
+
+    /// Include hashes for all dependencies.
+    #[arg(long, overrides_with("no_hashes"), hide = true)]
+    pub hashes: bool,
+
+    /// Omit hashes in the generated output.
+    #[arg(long, overrides_with("hashes"))]
+    pub no_hashes: bool,
+
+    /// Write the exported requirements to the given file.
+    ///
+    /// Required when exporting to the `zipapp` or `pex` format.
+    #[arg(long, short, required_if_eq_any([("format", "zipapp"), ("format", "pex")]))]
+    pub output_file: Option<PathBuf>,
+
+    /// Do not emit the current project.
+    ///
+    /// By default, the current project is included in the exported requirements file with all of its
+    /// dependencies. The `--no-emit-project` option allows the project to be excluded, but all of
+    /// its dependencies to remain included.
+    #[arg(long, alias = "no-install-project")]
+    pub no_emit_project: bool,
+
+    /// Do not emit any workspace members, including the root project.
+    ///
+    /// By default, all workspace members and their dependencies are included in the exported
+    /// requirements file, with all of their dependencies. The `--no-emit-workspace` option allows
+    /// exclusion of all the workspace members while retaining their dependencies.
+    #[arg(long, alias = "no-install-workspace")]
+    pub no_emit_workspace: bool,
+
+    /// Do not emit the given package(s).
+    ///
+    /// By default, all of the project's dependencies are included in the exported requirements
+    /// file. The `--no-install-package` option allows exclusion of specific packages.
+    #[arg(long, alias = "no-install-package")]
+    pub no_emit_package: Vec<PackageName>,
+
+    /// Assert that the `uv.lock` will remain unchanged.
+    ///
+    /// Requires that the lockfile is up-to-date. If the lockfile is missing or
+    /// needs to be updated, uv will exit with an error.
+    #[arg(long, env = EnvVars::UV_LOCKED, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "frozen")]
+    pub locked: bool,
+
+    /// Do not update the `uv.lock` before exporting.
+    ///
+    /// If a `uv.lock` does not exist, uv will exit with an error.
+    #[arg(long, env = EnvVars::UV_FROZEN, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "locked")]
+    pub frozen: bool,
+
+    #[command(flatten)]
+    pub resolver: ResolverArgs,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+
+    /// The Python interpreter to use during resolution.
+    ///
+    /// A Python interpreter is required for building source distributions to
+    /// determine package metadata when there are not wheels.
+    ///
+    /// The interpreter is also used as the fallback value for the minimum
+    /// Python version if `requires-python` is not set.
     ///
     /// See `uv help python` for details on Python discovery and supported
     /// request formats.
@@ -3537,10 +4947,29 @@ pub enum ToolCommand {
     /// installation, it will continue to be respected in upgrades.
     #[command(alias = "update")]
     Upgrade(ToolUpgradeArgs),
+    /// Reinstall tools from the locked requirements in their receipts.
+    ///
+    /// When a tool is installed or upgraded, uv records the exact version of
+    /// each package in the tool environment in the tool's receipt. `uv tool
+    /// sync` recreates each tool environment from those locked requirements,
+    /// such that copying the receipts from the uv tools directory to another
+    /// machine and running `uv tool sync` reproduces the same tools.
+    ///
+    /// If no tool names are provided, all tools with a receipt are synced.
+    Sync(ToolSyncArgs),
     /// List installed tools.
     List(ToolListArgs),
     /// Uninstall a tool.
     Uninstall(ToolUninstallArgs),
+    /// Manage aliases for tool invocations.
+    ///
+    /// An alias maps a name to a tool and any arguments to pass to it, such
+    /// that, e.g., `uvx fmt` runs `uvx ruff@0.6 format` after
+    /// `uv tool alias add fmt ruff@0.6 format`. Any arguments provided after
+    /// the alias are appended to those in the alias.
+    ///
+    /// Aliases are stored in `aliases.toml` in the uv tools directory.
+    Alias(ToolAliasNamespace),
     /// Ensure that the tool executable directory is on the `PATH`.
     ///
     /// If the tool executable directory is not present on the `PATH`, uv will
@@ -3678,6 +5107,26 @@ pub struct ToolInstallArgs {
     #[arg(long)]
     pub force: bool,
 
+    /// Install the tool from the locked requirements in its existing receipt.
+    ///
+    /// Instead of resolving the tool's requirements, installs the exact
+    /// package versions recorded when the tool was last installed or
+    /// upgraded. The requested requirements must match those in the receipt.
+    #[arg(long)]
+    pub frozen: bool,
+
+    /// Install shell completions for the tool's executables.
+    ///
+    /// Generates completion scripts for the current shell using the
+    /// completion support built into the tool's command-line framework (Click
+    /// or `argcomplete`), and writes them to the shell's completion directory.
+    /// Supported for Bash, Zsh, and Fish.
+    ///
+    /// The completions are regenerated when the tool is upgraded, and removed
+    /// when the tool is uninstalled.
+    #[arg(long)]
+    pub install_completions: bool,
+
     /// The Python interpreter to use to build the tool environment.
     ///
     /// See `uv help python` for details on Python discovery and supported
@@ -3776,6 +5225,57 @@ pub struct ToolUpgradeArgs {
     pub build: BuildOptionsArgs,
 }
 
+#[derive(Args)]
+pub struct ToolSyncArgs {
+    /// The name of the tool to sync.
+    ///
+    /// If omitted, all tools with a receipt are synced.
+    pub name: Vec<PackageName>,
+
+    #[command(flatten)]
+    pub installer: ResolverInstallerArgs,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+}
+
+#[derive(Args)]
+pub struct ToolAliasNamespace {
+    #[command(subcommand)]
+    pub command: ToolAliasCommand,
+}
+
+#[derive(Subcommand)]
+pub enum ToolAliasCommand {
+    /// Add an alias for a tool invocation.
+    ///
+    /// Any existing alias with the same name will be replaced.
+    Add(ToolAliasAddArgs),
+    /// Remove an alias.
+    Remove(ToolAliasRemoveArgs),
+    /// List the defined aliases.
+    List,
+}
+
+#[derive(Args)]
+pub struct ToolAliasAddArgs {
+    /// The name of the alias.
+    pub name: String,
+
+    /// The tool to run, followed by any arguments to pass to it.
+    ///
+    /// The tool may include a version, as in `ruff@0.6` or `ruff@latest`.
+    #[arg(required = true, trailing_var_arg = true, allow_hyphen_values = true)]
+    pub command: Vec<String>,
+}
+
+#[derive(Args)]
+pub struct ToolAliasRemoveArgs {
+    /// The name of the alias to remove.
+    #[arg(required = true)]
+    pub name: Vec<String>,
+}
+
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct PythonNamespace {
@@ -3803,8 +5303,9 @@ pub enum PythonCommand {
     ///
     /// Multiple Python versions may be requested.
     ///
-    /// Supports CPython and PyPy. CPython distributions are downloaded from the
+    /// Supports CPython, PyPy, and GraalPy. CPython distributions are downloaded from the
     /// `python-build-standalone` project. PyPy distributions are downloaded from `python.org`.
+    /// GraalPy distributions are downloaded from GitHub.
     ///
     /// Python versions are installed into the uv Python directory, which can be retrieved with `uv
     /// python dir`.
@@ -3868,6 +5369,14 @@ pub struct PythonListArgs {
     /// By default, available downloads for the current platform are shown.
     #[arg(long)]
     pub only_installed: bool,
+
+    /// The format in which to display the Python installations.
+    ///
+    /// With `--output-format json`, each installation or download is written to stdout as a JSON
+    /// object, including its implementation, version, architecture, libc, download URL, and
+    /// install path.
+    #[arg(long, value_enum, default_value_t = PythonListFormat::default())]
+    pub output_format: PythonListFormat,
 }
 
 #[derive(Args)]
@@ -3907,6 +5416,34 @@ pub struct PythonInstallArgs {
     /// installed.
     #[arg(long, short, alias = "force")]
     pub reinstall: bool,
+
+    /// The build variant of the Python version(s) to install.
+    ///
+    /// By default, uv installs the most optimized build available for the platform (e.g., with PGO
+    /// and LTO), with the GIL enabled. The variant can also be requested directly, e.g., `3.13d` or
+    /// `3.13+debug`.
+    #[arg(long, value_enum)]
+    pub variant: Option<PythonVariant>,
+
+    /// Register the installed Python version(s) in the Windows registry.
+    ///
+    /// Following PEP 514, the installations are registered under the `Astral` company in
+    /// `HKEY_CURRENT_USER`, such that the `py` launcher (e.g., `py -V:3.12`) and other tools that
+    /// read the registry can discover them.
+    ///
+    /// The registry entries are removed when the Python version is uninstalled.
+    ///
+    /// This option has no effect on platforms other than Windows.
+    #[arg(
+        long,
+        env = EnvVars::UV_PYTHON_INSTALL_REGISTRY,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_registry")
+    )]
+    pub registry: bool,
+
+    #[arg(long, overrides_with("registry"), hide = true)]
+    pub no_registry: bool,
 }
 
 #[derive(Args)]
@@ -3956,6 +5493,20 @@ pub struct PythonFindArgs {
 
     #[arg(long, overrides_with("system"), hide = true)]
     pub no_system: bool,
+
+    /// Only find Python interpreters for the given architecture, e.g., `aarch64` or `x86_64`.
+    ///
+    /// Combined with the Python request, e.g., `uv python find 'cpython>=3.11,<3.13' --arch
+    /// aarch64`. Interpreters are still considered in the standard discovery order; the first
+    /// interpreter that matches all of the criteria is returned.
+    #[arg(long)]
+    pub arch: Option<Arch>,
+
+    /// Only find Python interpreters of the given build variant, e.g., `freethreaded`.
+    ///
+    /// The variant can also be requested directly, e.g., `3.13t` or `3.13+freethreaded`.
+    #[arg(long, value_enum)]
+    pub variant: Option<PythonVariant>,
 }
 
 #[derive(Args)]
@@ -3982,6 +5533,12 @@ pub struct PythonPinArgs {
     #[arg(long, overrides_with("no_resolved"), hide = true)]
     pub no_resolved: bool,
 
+    /// The build variant to pin, e.g., `debug` or `freethreaded`.
+    ///
+    /// The variant is recorded in the `.python-version` file, e.g., as `3.13d`.
+    #[arg(long, value_enum, requires = "request", conflicts_with = "resolved")]
+    pub variant: Option<PythonVariant>,
+
     /// Avoid validating the Python pin is compatible with the project or workspace.
     ///
     /// By default, a project or workspace is discovered in the current directory or any parent
@@ -4030,6 +5587,32 @@ pub struct GenerateShellCompletionArgs {
     pub version: bool,
 }
 
+/// Arguments for building source distributions in a container.
+#[derive(Args)]
+pub struct BuildContainerArgs {
+    /// Build source distributions inside a container created from the given image.
+    ///
+    /// When provided, wheels are built by running the build backend inside a container (with
+    /// `docker`, or the runtime set via `UV_BUILD_CONTAINER_RUNTIME`), such that native build
+    /// toolchains needn't exist on the host. The image must provide a Python interpreter of the
+    /// same minor version as the target environment (e.g., `python3.12`), along with `pip`.
+    #[arg(
+        long,
+        env = EnvVars::UV_BUILD_CONTAINER,
+        value_name = "IMAGE",
+        help_heading = "Build options"
+    )]
+    pub build_container: Option<String>,
+
+    /// Build source distributions for a specific package inside a container created from the
+    /// given image, specified as `PACKAGE=IMAGE` pairs.
+    ///
+    /// Takes precedence over `--build-container` for the given package. May be provided multiple
+    /// times.
+    #[arg(long, value_name = "PACKAGE=IMAGE", help_heading = "Build options")]
+    pub build_container_package: Vec<BuildContainerEntry>,
+}
+
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct IndexArgs {
@@ -4092,6 +5675,18 @@ pub struct IndexArgs {
     )]
     pub find_links: Option<Vec<Maybe<PipFindLinks>>>,
 
+    /// Search `--find-links` directories recursively.
+    ///
+    /// By default, only packages at the top level of a `--find-links` directory are considered.
+    /// When enabled, packages in any subdirectory are included as well.
+    #[arg(
+        long,
+        env = EnvVars::UV_FIND_LINKS_RECURSIVE,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        help_heading = "Index options"
+    )]
+    pub find_links_recursive: bool,
+
     /// Ignore the registry index (e.g., PyPI), instead relying on direct URL dependencies and those
     /// provided via `--find-links`.
     #[arg(long, help_heading = "Index options")]
@@ -4254,6 +5849,9 @@ pub struct InstallerArgs {
     )]
     pub config_setting: Option<Vec<ConfigSettingEntry>>,
 
+    #[command(flatten)]
+    pub build_container_args: BuildContainerArgs,
+
     /// Disable isolation when building source distributions.
     ///
     /// Assumes that build dependencies specified by PEP 518 are already installed.
@@ -4283,7 +5881,7 @@ pub struct InstallerArgs {
 
     /// The method to use when installing packages from the global cache.
     ///
-    /// Defaults to `clone` (also known as Copy-on-Write) on macOS, and `hardlink` on Linux and
+    /// Defaults to `clone` (also known as Copy-on-Write) on macOS, and `auto` on Linux and
     /// Windows.
     #[arg(
         long,
@@ -4322,6 +5920,29 @@ pub struct InstallerArgs {
     )]
     pub no_compile_bytecode: bool,
 
+    /// The optimization level to use when compiling Python files to bytecode.
+    ///
+    /// Corresponds to the `-O` (`1`) and `-OO` (`2`) flags of the Python interpreter. Level `1`
+    /// removes `assert` statements and code conditional on `__debug__`; level `2` additionally
+    /// removes docstrings.
+    ///
+    /// Only used when `--compile-bytecode` is enabled.
+    #[arg(long, value_enum, help_heading = "Installer options")]
+    pub compile_bytecode_optimization: Option<BytecodeOptimization>,
+
+    /// The number of workers to use when compiling Python files to bytecode.
+    ///
+    /// Defaults to the number of available CPU cores.
+    #[arg(long, help_heading = "Installer options")]
+    pub compile_bytecode_workers: Option<NonZeroUsize>,
+
+    /// Exclude a specific package from bytecode compilation.
+    ///
+    /// Useful for packages whose sources are slow to compile, or which contain files that fail to
+    /// compile.
+    #[arg(long, help_heading = "Installer options")]
+    pub no_compile_bytecode_package: Vec<PackageName>,
+
     /// Ignore the `tool.uv.sources` table when resolving dependencies. Used to lock against the
     /// standards-compliant, publishable package metadata, as opposed to using any local or Git
     /// sources.
@@ -4435,6 +6056,66 @@ pub struct ResolverArgs {
     #[arg(long, hide = true, help_heading = "Resolver options")]
     pub pre: bool,
 
+    /// The strategy to use when considering yanked versions.
+    ///
+    /// By default, uv will reject yanked versions unless they're pinned by an exact requirement
+    /// (e.g., `==1.0.0`), an existing lockfile, or `--allow-yanked`, and will emit a warning when
+    /// a yanked version is selected (`warn`).
+    #[arg(
+        long,
+        value_enum,
+        env = EnvVars::UV_YANKED,
+        help_heading = "Resolver options"
+    )]
+    pub yanked: Option<YankedMode>,
+
+    /// Allow the resolver to select yanked versions of a specific package.
+    ///
+    /// Accepts both standalone package names (`attrs`), which permit any yanked version of the
+    /// package, and exact version specifiers (`attrs==21.1.0`), which permit only the given
+    /// version.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, help_heading = "Resolver options")]
+    pub allow_yanked: Vec<Requirement<VerbatimParsedUrl>>,
+
+    /// Prefer versions with the given local version label (e.g., `acme` for `1.0.0+acme`).
+    ///
+    /// When a version with the label satisfies a requirement, it's selected over any other
+    /// version, even if a higher version without the label is available. Useful for preferring
+    /// patched releases of internal forks, as published to an internal index, over the
+    /// corresponding public releases.
+    #[arg(
+        long,
+        env = EnvVars::UV_PREFER_LOCAL_VERSION,
+        value_name = "LABEL",
+        help_heading = "Resolver options"
+    )]
+    pub prefer_local_version: Option<String>,
+
+    /// Abort a resolution that takes longer than the given number of seconds.
+    ///
+    /// The timeout includes time spent fetching metadata and building source distributions. On
+    /// timeout, uv reports the packages that required the most backtracking.
+    #[arg(
+        long,
+        env = EnvVars::UV_RESOLUTION_TIMEOUT,
+        value_name = "SECONDS",
+        help_heading = "Resolver options"
+    )]
+    pub resolution_timeout: Option<u64>,
+
+    /// Abort a resolution after trying the given number of package versions.
+    ///
+    /// On abort, uv reports the packages that required the most backtracking.
+    #[arg(
+        long,
+        env = EnvVars::UV_RESOLUTION_MAX_STEPS,
+        value_name = "STEPS",
+        help_heading = "Resolver options"
+    )]
+    pub resolution_max_steps: Option<usize>,
+
     /// Settings to pass to the PEP 517 build backend, specified as `KEY=VALUE` pairs.
     #[arg(
         long,
@@ -4444,6 +6125,9 @@ pub struct ResolverArgs {
     )]
     pub config_setting: Option<Vec<ConfigSettingEntry>>,
 
+    #[command(flatten)]
+    pub build_container_args: BuildContainerArgs,
+
     /// Disable isolation when building source distributions.
     ///
     /// Assumes that build dependencies specified by PEP 518 are already installed.
@@ -4481,7 +6165,7 @@ pub struct ResolverArgs {
     ///
     /// This option is only used when building source distributions.
     ///
-    /// Defaults to `clone` (also known as Copy-on-Write) on macOS, and `hardlink` on Linux and
+    /// Defaults to `clone` (also known as Copy-on-Write) on macOS, and `auto` on Linux and
     /// Windows.
     #[arg(
         long,
@@ -4496,6 +6180,19 @@ pub struct ResolverArgs {
     /// sources.
     #[arg(long, help_heading = "Resolver options")]
     pub no_sources: bool,
+
+    /// The variants to use when selecting among the sources in `tool.uv.sources`, e.g., `cu121`.
+    ///
+    /// For each dependency, uv uses the sources for the first of the given variants that the
+    /// dependency declares. Each variant must be declared by at least one source in the workspace.
+    #[arg(
+        long,
+        env = EnvVars::UV_VARIANT,
+        value_delimiter = ',',
+        value_name = "VARIANT",
+        help_heading = "Resolver options"
+    )]
+    pub variant: Vec<String>,
 }
 
 /// Arguments that are used by commands that need to resolve and install packages.
@@ -4627,6 +6324,66 @@ pub struct ResolverInstallerArgs {
     #[arg(long, hide = true)]
     pub pre: bool,
 
+    /// The strategy to use when considering yanked versions.
+    ///
+    /// By default, uv will reject yanked versions unless they're pinned by an exact requirement
+    /// (e.g., `==1.0.0`), an existing lockfile, or `--allow-yanked`, and will emit a warning when
+    /// a yanked version is selected (`warn`).
+    #[arg(
+        long,
+        value_enum,
+        env = EnvVars::UV_YANKED,
+        help_heading = "Resolver options"
+    )]
+    pub yanked: Option<YankedMode>,
+
+    /// Allow the resolver to select yanked versions of a specific package.
+    ///
+    /// Accepts both standalone package names (`attrs`), which permit any yanked version of the
+    /// package, and exact version specifiers (`attrs==21.1.0`), which permit only the given
+    /// version.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, help_heading = "Resolver options")]
+    pub allow_yanked: Vec<Requirement<VerbatimParsedUrl>>,
+
+    /// Prefer versions with the given local version label (e.g., `acme` for `1.0.0+acme`).
+    ///
+    /// When a version with the label satisfies a requirement, it's selected over any other
+    /// version, even if a higher version without the label is available. Useful for preferring
+    /// patched releases of internal forks, as published to an internal index, over the
+    /// corresponding public releases.
+    #[arg(
+        long,
+        env = EnvVars::UV_PREFER_LOCAL_VERSION,
+        value_name = "LABEL",
+        help_heading = "Resolver options"
+    )]
+    pub prefer_local_version: Option<String>,
+
+    /// Abort a resolution that takes longer than the given number of seconds.
+    ///
+    /// The timeout includes time spent fetching metadata and building source distributions. On
+    /// timeout, uv reports the packages that required the most backtracking.
+    #[arg(
+        long,
+        env = EnvVars::UV_RESOLUTION_TIMEOUT,
+        value_name = "SECONDS",
+        help_heading = "Resolver options"
+    )]
+    pub resolution_timeout: Option<u64>,
+
+    /// Abort a resolution after trying the given number of package versions.
+    ///
+    /// On abort, uv reports the packages that required the most backtracking.
+    #[arg(
+        long,
+        env = EnvVars::UV_RESOLUTION_MAX_STEPS,
+        value_name = "STEPS",
+        help_heading = "Resolver options"
+    )]
+    pub resolution_max_steps: Option<usize>,
+
     /// Settings to pass to the PEP 517 build backend, specified as `KEY=VALUE` pairs.
     #[arg(
         long,
@@ -4636,6 +6393,9 @@ pub struct ResolverInstallerArgs {
     )]
     pub config_setting: Option<Vec<ConfigSettingEntry>>,
 
+    #[command(flatten)]
+    pub build_container_args: BuildContainerArgs,
+
     /// Disable isolation when building source distributions.
     ///
     /// Assumes that build dependencies specified by PEP 518 are already installed.
@@ -4671,7 +6431,7 @@ pub struct ResolverInstallerArgs {
 
     /// The method to use when installing packages from the global cache.
     ///
-    /// Defaults to `clone` (also known as Copy-on-Write) on macOS, and `hardlink` on Linux and
+    /// Defaults to `clone` (also known as Copy-on-Write) on macOS, and `auto` on Linux and
     /// Windows.
     #[arg(
         long,
@@ -4710,11 +6470,47 @@ pub struct ResolverInstallerArgs {
     )]
     pub no_compile_bytecode: bool,
 
+    /// The optimization level to use when compiling Python files to bytecode.
+    ///
+    /// Corresponds to the `-O` (`1`) and `-OO` (`2`) flags of the Python interpreter. Level `1`
+    /// removes `assert` statements and code conditional on `__debug__`; level `2` additionally
+    /// removes docstrings.
+    ///
+    /// Only used when `--compile-bytecode` is enabled.
+    #[arg(long, value_enum, help_heading = "Installer options")]
+    pub compile_bytecode_optimization: Option<BytecodeOptimization>,
+
+    /// The number of workers to use when compiling Python files to bytecode.
+    ///
+    /// Defaults to the number of available CPU cores.
+    #[arg(long, help_heading = "Installer options")]
+    pub compile_bytecode_workers: Option<NonZeroUsize>,
+
+    /// Exclude a specific package from bytecode compilation.
+    ///
+    /// Useful for packages whose sources are slow to compile, or which contain files that fail to
+    /// compile.
+    #[arg(long, help_heading = "Installer options")]
+    pub no_compile_bytecode_package: Vec<PackageName>,
+
     /// Ignore the `tool.uv.sources` table when resolving dependencies. Used to lock against the
     /// standards-compliant, publishable package metadata, as opposed to using any local or Git
     /// sources.
     #[arg(long, help_heading = "Resolver options")]
     pub no_sources: bool,
+
+    /// The variants to use when selecting among the sources in `tool.uv.sources`, e.g., `cu121`.
+    ///
+    /// For each dependency, uv uses the sources for the first of the given variants that the
+    /// dependency declares. Each variant must be declared by at least one source in the workspace.
+    #[arg(
+        long,
+        env = EnvVars::UV_VARIANT,
+        value_delimiter = ',',
+        value_name = "VARIANT",
+        help_heading = "Resolver options"
+    )]
+    pub variant: Vec<String>,
 }
 
 #[derive(Args)]
@@ -4768,6 +6564,9 @@ pub struct PublishArgs {
     pub username: Option<String>,
 
     /// The password for the upload.
+    ///
+    /// The password may also reference a secret that's stored elsewhere, as in
+    /// `env:NAME`, `file:PATH`, or `cmd:COMMAND`.
     #[arg(short, long, env = EnvVars::UV_PUBLISH_PASSWORD)]
     pub password: Option<String>,
 
@@ -4775,6 +6574,9 @@ pub struct PublishArgs {
     ///
     /// Using a token is equivalent to passing `__token__` as `--username` and the token as `--password`.
     /// password.
+    ///
+    /// The token may also reference a secret that's stored elsewhere, as in `env:NAME`,
+    /// `file:PATH`, or `cmd:COMMAND`.
     #[arg(
         short,
         long,
@@ -4837,6 +6639,255 @@ pub struct PublishArgs {
     pub check_url: Option<IndexUrl>,
 }
 
+#[derive(Args)]
+pub struct AuthNamespace {
+    #[command(subcommand)]
+    pub command: AuthCommand,
+}
+
+#[derive(Subcommand)]
+pub enum AuthCommand {
+    /// Store credentials for an index.
+    ///
+    /// The password (or token) is read from a prompt or, with `--password-stdin`, from stdin.
+    Login(AuthLoginArgs),
+    /// Remove the stored credentials for an index.
+    Logout(AuthLogoutArgs),
+    /// List the indexes with stored credentials.
+    List,
+    /// Display the stored token (or password) for an index.
+    ///
+    /// The token is written to stdout, e.g., for use in other tools.
+    Token(AuthTokenArgs),
+}
+
+#[derive(Args)]
+pub struct AuthLoginArgs {
+    /// The index to store credentials for.
+    ///
+    /// Accepts either the URL of an index, or the name of an index defined in the `index`
+    /// setting. The credentials will also apply to any URL below the index URL.
+    pub index: String,
+
+    /// The username to authenticate with.
+    #[arg(short, long)]
+    pub username: Option<String>,
+
+    /// Read the password (or token) from stdin, rather than prompting for it.
+    #[arg(long)]
+    pub password_stdin: bool,
+
+    /// Authenticate with a token, rather than a username and password.
+    ///
+    /// Using a token is equivalent to passing `__token__` as `--username`, and providing the token
+    /// as the password.
+    #[arg(short, long, conflicts_with = "username")]
+    pub token: bool,
+
+    /// Store the password in the system keyring, rather than the uv credentials file.
+    ///
+    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to
+    /// use the `keyring` CLI to store the password.
+    ///
+    /// Defaults to `disabled`.
+    #[arg(long, value_enum, env = EnvVars::UV_KEYRING_PROVIDER)]
+    pub keyring_provider: Option<KeyringProviderType>,
+}
+
+#[derive(Args)]
+pub struct AuthLogoutArgs {
+    /// The index to remove credentials for.
+    ///
+    /// Accepts either the URL of an index, or the name of an index defined in the `index`
+    /// setting.
+    pub index: String,
+}
+
+#[derive(Args)]
+pub struct AuthTokenArgs {
+    /// The index to display the token for.
+    ///
+    /// Accepts either the URL of an index, or the name of an index defined in the `index`
+    /// setting.
+    pub index: String,
+}
+
+#[derive(Args)]
+pub struct IndexNamespace {
+    #[command(subcommand)]
+    pub command: IndexCommand,
+}
+
+#[derive(Subcommand)]
+pub enum IndexCommand {
+    /// Add an index to the configuration.
+    ///
+    /// The index is added to the same configuration file that `uv config set` edits: the
+    /// `pyproject.toml` at the workspace root (under `tool.uv.index`), or a `uv.toml` at the
+    /// workspace root, if one exists. With `--user`, the index is added to the user-level
+    /// `uv.toml`.
+    ///
+    /// If an index with the same name or URL is already defined, it's updated in place. By
+    /// default, the index is given the highest priority, ahead of any existing indexes.
+    ///
+    /// Credentials aren't configured by this command. To authenticate with the index, run `uv auth
+    /// login <name>`, set the `UV_INDEX_{name}_USERNAME` and `UV_INDEX_{name}_PASSWORD` environment
+    /// variables, or add `username` and `password` (e.g., `password = "env:TOKEN"`) to the index
+    /// definition.
+    Add(IndexAddArgs),
+    /// Remove an index from the configuration.
+    ///
+    /// An index that's still referenced by `tool.uv.sources` can't be removed; remove or update
+    /// the sources first.
+    Remove(IndexRemoveArgs),
+    /// List the indexes defined in the configuration, in priority order.
+    List(IndexListArgs),
+    /// Mark an index as the default index.
+    ///
+    /// The default index is consulted after all other indexes, in place of PyPI. Any other index
+    /// marked as the default will no longer be the default.
+    SetDefault(IndexSetDefaultArgs),
+}
+
+#[derive(Args, Debug)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct IndexAddArgs {
+    /// The name of the index.
+    ///
+    /// The name can be used to pin packages to the index via `tool.uv.sources`, and to provide
+    /// credentials via `uv auth login` or the `UV_INDEX_{name}_USERNAME` and
+    /// `UV_INDEX_{name}_PASSWORD` environment variables.
+    pub name: IndexName,
+
+    /// The URL of the index (e.g., `https://download.pytorch.org/whl/cpu`), or a local path.
+    pub url: IndexUrl,
+
+    /// Mark the index as explicit, such that it's only used for packages that are pinned to it
+    /// via `tool.uv.sources`.
+    ///
+    /// When updating an existing explicit index, omitting this flag makes the index
+    /// non-explicit.
+    #[arg(long)]
+    pub explicit: bool,
+
+    /// Mark the index as the default index, replacing PyPI (and any other default index).
+    #[arg(long)]
+    pub default: bool,
+
+    /// Give the index the lowest priority, after any existing indexes.
+    ///
+    /// By default, the index is given the highest priority.
+    #[arg(long)]
+    pub last: bool,
+
+    /// Edit the user-level `uv.toml`, rather than the project configuration.
+    #[arg(long)]
+    pub user: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct IndexRemoveArgs {
+    /// The name or URL of the index to remove.
+    pub index: String,
+
+    /// Edit the user-level `uv.toml`, rather than the project configuration.
+    #[arg(long)]
+    pub user: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct IndexListArgs {
+    /// List the indexes in the user-level `uv.toml`, rather than the project configuration.
+    #[arg(long)]
+    pub user: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct IndexSetDefaultArgs {
+    /// The name or URL of the index to mark as the default.
+    pub index: String,
+
+    /// Edit the user-level `uv.toml`, rather than the project configuration.
+    #[arg(long)]
+    pub user: bool,
+}
+
+#[derive(Args)]
+pub struct ConfigNamespace {
+    #[command(subcommand)]
+    pub command: ConfigCommand,
+}
+
+#[derive(Subcommand)]
+pub enum ConfigCommand {
+    /// Display the value of a setting.
+    ///
+    /// Settings are named as in `uv.toml`, with nested settings separated by dots (e.g.,
+    /// `pip.index-url`). Strings are displayed as-is; any other value is displayed as TOML.
+    Get(ConfigGetArgs),
+    /// Set the value of a setting.
+    ///
+    /// The value is parsed as TOML (e.g., `true`, `4`, or `["a", "b"]`), falling back to a string
+    /// if it isn't valid TOML or isn't valid for the setting. The setting is validated before the
+    /// configuration file is written, and the rest of the file, including comments and formatting,
+    /// is preserved.
+    ///
+    /// The setting is written to `tool.uv` in the `pyproject.toml` at the workspace root, or to a
+    /// `uv.toml` at the workspace root, if one exists. With `--user` or `--system`, the setting is
+    /// written to the user- or system-level `uv.toml`, which is created if necessary.
+    Set(ConfigSetArgs),
+    /// List the settings defined in the configuration.
+    List(ConfigListArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct ConfigScopeArgs {
+    /// Use the user-level `uv.toml` (e.g., `~/.config/uv/uv.toml`), rather than the project
+    /// configuration.
+    #[arg(long, conflicts_with = "system")]
+    pub user: bool,
+
+    /// Use the system-level `uv.toml` (e.g., `/etc/uv/uv.toml`), rather than the project
+    /// configuration.
+    #[arg(long)]
+    pub system: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct ConfigGetArgs {
+    /// The name of the setting (e.g., `python-preference` or `pip.index-url`).
+    pub key: String,
+
+    #[command(flatten)]
+    pub scope: ConfigScopeArgs,
+
+    /// The format in which to display the value.
+    #[arg(long, value_enum, default_value_t = ConfigFormat::default())]
+    pub output_format: ConfigFormat,
+}
+
+#[derive(Args, Debug)]
+pub struct ConfigSetArgs {
+    /// The name of the setting (e.g., `python-preference` or `pip.index-url`).
+    pub key: String,
+
+    /// The value of the setting.
+    pub value: String,
+
+    #[command(flatten)]
+    pub scope: ConfigScopeArgs,
+}
+
+#[derive(Args, Debug)]
+pub struct ConfigListArgs {
+    #[command(flatten)]
+    pub scope: ConfigScopeArgs,
+
+    /// The format in which to display the settings.
+    #[arg(long, value_enum, default_value_t = ConfigFormat::default())]
+    pub output_format: ConfigFormat,
+}
+
 /// See [PEP 517](https://peps.python.org/pep-0517/) and
 /// [PEP 660](https://peps.python.org/pep-0660/) for specifications of the parameters.
 #[derive(Subcommand)]
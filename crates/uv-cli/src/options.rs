@@ -1,12 +1,15 @@
+use std::collections::BTreeMap;
+
 use uv_cache::Refresh;
-use uv_configuration::ConfigSettings;
+use uv_configuration::{BuildContainerEntry, ConfigSettings};
 use uv_distribution_types::{PipExtraIndex, PipFindLinks, PipIndex};
+use uv_normalize::PackageName;
 use uv_resolver::PrereleaseMode;
 use uv_settings::{Combine, PipOptions, ResolverInstallerOptions, ResolverOptions};
 
 use crate::{
-    BuildOptionsArgs, IndexArgs, InstallerArgs, Maybe, RefreshArgs, ResolverArgs,
-    ResolverInstallerArgs,
+    BuildContainerArgs, BuildOptionsArgs, IndexArgs, InstallerArgs, Maybe, RefreshArgs,
+    ResolverArgs, ResolverInstallerArgs,
 };
 
 /// Given a boolean flag pair (like `--upgrade` and `--no-upgrade`), resolve the value of the flag.
@@ -19,6 +22,14 @@ pub fn flag(yes: bool, no: bool) -> Option<bool> {
     }
 }
 
+/// Collect the `--build-container-package` entries into a map from package name to image.
+fn build_container_packages(entries: Vec<BuildContainerEntry>) -> BTreeMap<PackageName, String> {
+    entries
+        .into_iter()
+        .map(|entry| (entry.package, entry.image))
+        .collect()
+}
+
 impl From<RefreshArgs> for Refresh {
     fn from(value: RefreshArgs) -> Self {
         let RefreshArgs {
@@ -44,13 +55,24 @@ impl From<ResolverArgs> for PipOptions {
             resolution,
             prerelease,
             pre,
+            yanked,
+            allow_yanked,
+            prefer_local_version,
+            resolution_timeout,
+            resolution_max_steps,
             config_setting,
+            build_container_args:
+                BuildContainerArgs {
+                    build_container,
+                    build_container_package,
+                },
             no_build_isolation,
             no_build_isolation_package,
             build_isolation,
             exclude_newer,
             link_mode,
             no_sources,
+            variant,
         } = args;
 
         Self {
@@ -70,13 +92,21 @@ impl From<ResolverArgs> for PipOptions {
             } else {
                 prerelease
             },
+            yanked,
+            allow_yanked: Some(allow_yanked),
+            prefer_local_version,
+            resolution_timeout,
+            resolution_max_steps,
             config_settings: config_setting
                 .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
+            build_container,
+            build_container_package: Some(build_container_packages(build_container_package)),
             no_build_isolation: flag(no_build_isolation, build_isolation),
             no_build_isolation_package: Some(no_build_isolation_package),
             exclude_newer,
             link_mode,
             no_sources: if no_sources { Some(true) } else { None },
+            variants: Some(variant),
             ..PipOptions::from(index_args)
         }
     }
@@ -93,12 +123,20 @@ impl From<InstallerArgs> for PipOptions {
             keyring_provider,
             allow_insecure_host,
             config_setting,
+            build_container_args:
+                BuildContainerArgs {
+                    build_container,
+                    build_container_package,
+                },
             no_build_isolation,
             build_isolation,
             exclude_newer,
             link_mode,
             compile_bytecode,
             no_compile_bytecode,
+            compile_bytecode_optimization,
+            compile_bytecode_workers,
+            no_compile_bytecode_package,
             no_sources,
         } = args;
 
@@ -115,10 +153,15 @@ impl From<InstallerArgs> for PipOptions {
             }),
             config_settings: config_setting
                 .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
+            build_container,
+            build_container_package: Some(build_container_packages(build_container_package)),
             no_build_isolation: flag(no_build_isolation, build_isolation),
             exclude_newer,
             link_mode,
             compile_bytecode: flag(compile_bytecode, no_compile_bytecode),
+            compile_bytecode_optimization,
+            compile_bytecode_workers,
+            no_compile_bytecode_package: Some(no_compile_bytecode_package),
             no_sources: if no_sources { Some(true) } else { None },
             ..PipOptions::from(index_args)
         }
@@ -141,7 +184,17 @@ impl From<ResolverInstallerArgs> for PipOptions {
             resolution,
             prerelease,
             pre,
+            yanked,
+            allow_yanked,
+            prefer_local_version,
+            resolution_timeout,
+            resolution_max_steps,
             config_setting,
+            build_container_args:
+                BuildContainerArgs {
+                    build_container,
+                    build_container_package,
+                },
             no_build_isolation,
             no_build_isolation_package,
             build_isolation,
@@ -149,7 +202,11 @@ impl From<ResolverInstallerArgs> for PipOptions {
             link_mode,
             compile_bytecode,
             no_compile_bytecode,
+            compile_bytecode_optimization,
+            compile_bytecode_workers,
+            no_compile_bytecode_package,
             no_sources,
+            variant,
         } = args;
 
         Self {
@@ -171,14 +228,25 @@ impl From<ResolverInstallerArgs> for PipOptions {
             } else {
                 prerelease
             },
+            yanked,
+            allow_yanked: Some(allow_yanked),
+            prefer_local_version,
+            resolution_timeout,
+            resolution_max_steps,
             config_settings: config_setting
                 .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
+            build_container,
+            build_container_package: Some(build_container_packages(build_container_package)),
             no_build_isolation: flag(no_build_isolation, build_isolation),
             no_build_isolation_package: Some(no_build_isolation_package),
             exclude_newer,
             link_mode,
             compile_bytecode: flag(compile_bytecode, no_compile_bytecode),
+            compile_bytecode_optimization,
+            compile_bytecode_workers,
+            no_compile_bytecode_package: Some(no_compile_bytecode_package),
             no_sources: if no_sources { Some(true) } else { None },
+            variants: Some(variant),
             ..PipOptions::from(index_args)
         }
     }
@@ -193,6 +261,7 @@ impl From<IndexArgs> for PipOptions {
             extra_index_url,
             no_index,
             find_links,
+            find_links_recursive,
         } = args;
 
         Self {
@@ -218,6 +287,11 @@ impl From<IndexArgs> for PipOptions {
                     .map(PipFindLinks::from)
                     .collect()
             }),
+            find_links_recursive: if find_links_recursive {
+                Some(true)
+            } else {
+                None
+            },
             ..PipOptions::default()
         }
     }
@@ -239,13 +313,24 @@ pub fn resolver_options(
         resolution,
         prerelease,
         pre,
+        yanked,
+        allow_yanked,
+        prefer_local_version,
+        resolution_timeout,
+        resolution_max_steps,
         config_setting,
+        build_container_args:
+            BuildContainerArgs {
+                build_container,
+                build_container_package,
+            },
         no_build_isolation,
         no_build_isolation_package,
         build_isolation,
         exclude_newer,
         link_mode,
         no_sources,
+        variant,
     } = resolver_args;
 
     let BuildOptionsArgs {
@@ -258,33 +343,6 @@ pub fn resolver_options(
     } = build_args;
 
     ResolverOptions {
-        index: index_args
-            .default_index
-            .and_then(Maybe::into_option)
-            .map(|default_index| vec![default_index])
-            .combine(
-                index_args
-                    .index
-                    .map(|index| index.into_iter().filter_map(Maybe::into_option).collect()),
-            ),
-        index_url: index_args.index_url.and_then(Maybe::into_option),
-        extra_index_url: index_args.extra_index_url.map(|extra_index_url| {
-            extra_index_url
-                .into_iter()
-                .filter_map(Maybe::into_option)
-                .collect()
-        }),
-        no_index: if index_args.no_index {
-            Some(true)
-        } else {
-            None
-        },
-        find_links: index_args.find_links.map(|find_links| {
-            find_links
-                .into_iter()
-                .filter_map(Maybe::into_option)
-                .collect()
-        }),
         upgrade: flag(upgrade, no_upgrade),
         upgrade_package: Some(upgrade_package),
         index_strategy,
@@ -301,9 +359,17 @@ pub fn resolver_options(
         } else {
             prerelease
         },
+        yanked,
+        allow_yanked: Some(allow_yanked),
+        prefer_local_version,
+        resolution_timeout,
+        resolution_max_steps,
         dependency_metadata: None,
+        dependency_overrides: None,
         config_settings: config_setting
             .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
+        build_container,
+        build_container_package: Some(build_container_packages(build_container_package)),
         no_build_isolation: flag(no_build_isolation, build_isolation),
         no_build_isolation_package: Some(no_build_isolation_package),
         exclude_newer,
@@ -313,6 +379,51 @@ pub fn resolver_options(
         no_binary: flag(no_binary, binary),
         no_binary_package: Some(no_binary_package),
         no_sources: if no_sources { Some(true) } else { None },
+        variants: Some(variant),
+        ..ResolverOptions::from(index_args)
+    }
+}
+
+impl From<IndexArgs> for ResolverOptions {
+    fn from(args: IndexArgs) -> Self {
+        let IndexArgs {
+            default_index,
+            index,
+            index_url,
+            extra_index_url,
+            no_index,
+            find_links,
+            find_links_recursive,
+        } = args;
+
+        Self {
+            index: default_index
+                .and_then(Maybe::into_option)
+                .map(|default_index| vec![default_index])
+                .combine(
+                    index.map(|index| index.into_iter().filter_map(Maybe::into_option).collect()),
+                ),
+            index_url: index_url.and_then(Maybe::into_option),
+            extra_index_url: extra_index_url.map(|extra_index_urls| {
+                extra_index_urls
+                    .into_iter()
+                    .filter_map(Maybe::into_option)
+                    .collect()
+            }),
+            no_index: if no_index { Some(true) } else { None },
+            find_links: find_links.map(|find_links| {
+                find_links
+                    .into_iter()
+                    .filter_map(Maybe::into_option)
+                    .collect()
+            }),
+            find_links_recursive: if find_links_recursive {
+                Some(true)
+            } else {
+                None
+            },
+            ..ResolverOptions::default()
+        }
     }
 }
 
@@ -335,7 +446,17 @@ pub fn resolver_installer_options(
         resolution,
         prerelease,
         pre,
+        yanked,
+        allow_yanked,
+        prefer_local_version,
+        resolution_timeout,
+        resolution_max_steps,
         config_setting,
+        build_container_args:
+            BuildContainerArgs {
+                build_container,
+                build_container_package,
+            },
         no_build_isolation,
         no_build_isolation_package,
         build_isolation,
@@ -343,7 +464,11 @@ pub fn resolver_installer_options(
         link_mode,
         compile_bytecode,
         no_compile_bytecode,
+        compile_bytecode_optimization,
+        compile_bytecode_workers,
+        no_compile_bytecode_package,
         no_sources,
+        variant,
     } = resolver_installer_args;
 
     let BuildOptionsArgs {
@@ -383,6 +508,11 @@ pub fn resolver_installer_options(
                 .filter_map(Maybe::into_option)
                 .collect()
         }),
+        find_links_recursive: if index_args.find_links_recursive {
+            Some(true)
+        } else {
+            None
+        },
         upgrade: flag(upgrade, no_upgrade),
         upgrade_package: if upgrade_package.is_empty() {
             None
@@ -409,9 +539,25 @@ pub fn resolver_installer_options(
         } else {
             prerelease
         },
+        yanked,
+        allow_yanked: if allow_yanked.is_empty() {
+            None
+        } else {
+            Some(allow_yanked)
+        },
+        prefer_local_version,
+        resolution_timeout,
+        resolution_max_steps,
         dependency_metadata: None,
+        dependency_overrides: None,
         config_settings: config_setting
             .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
+        build_container,
+        build_container_package: if build_container_package.is_empty() {
+            None
+        } else {
+            Some(build_container_packages(build_container_package))
+        },
         no_build_isolation: flag(no_build_isolation, build_isolation),
         no_build_isolation_package: if no_build_isolation_package.is_empty() {
             None
@@ -421,6 +567,13 @@ pub fn resolver_installer_options(
         exclude_newer,
         link_mode,
         compile_bytecode: flag(compile_bytecode, no_compile_bytecode),
+        compile_bytecode_optimization,
+        compile_bytecode_workers,
+        no_compile_bytecode_package: if no_compile_bytecode_package.is_empty() {
+            None
+        } else {
+            Some(no_compile_bytecode_package)
+        },
         no_build: flag(no_build, build),
         no_build_package: if no_build_package.is_empty() {
             None
@@ -434,5 +587,6 @@ pub fn resolver_installer_options(
             Some(no_binary_package)
         },
         no_sources: if no_sources { Some(true) } else { None },
+        variants: Some(variant),
     }
 }
@@ -1,19 +1,22 @@
 use itertools::Itertools;
-use reqwest::{Client, ClientBuilder, Response};
+use reqwest::{Client, ClientBuilder, Response, StatusCode};
 use reqwest_middleware::ClientWithMiddleware;
 use reqwest_retry::policies::ExponentialBackoff;
 use reqwest_retry::{
-    DefaultRetryableStrategy, RetryTransientMiddleware, Retryable, RetryableStrategy,
+    DefaultRetryableStrategy, Jitter, RetryTransientMiddleware, Retryable, RetryableStrategy,
 };
 use std::error::Error;
 use std::fmt::Debug;
 use std::path::Path;
+use std::str::FromStr;
+use std::sync::OnceLock;
 use std::time::Duration;
 use std::{env, iter};
 use tracing::debug;
 use url::Url;
 use uv_auth::AuthMiddleware;
-use uv_configuration::{KeyringProviderType, TrustedHost};
+use uv_configuration::{Backoff, KeyringProviderType, TrustedHost};
+use uv_distribution_types::Index;
 use uv_fs::Simplified;
 use uv_pep508::MarkerEnvironment;
 use uv_platform_tags::Platform;
@@ -22,12 +25,40 @@ use uv_version::version;
 use uv_warnings::warn_user_once;
 
 use crate::linehaul::LineHaul;
-use crate::middleware::OfflineMiddleware;
-use crate::tls::read_identity;
+use crate::middleware::{
+    rate_limit_delay, HostConcurrencyMiddleware, OfflineMiddleware, RateLimitMiddleware,
+};
+use crate::proxy::{apply_index_proxies, IndexProxy};
+use crate::tls::{read_certificates, read_client_identity, read_identity, IndexTls};
 use crate::Connectivity;
 
 pub const DEFAULT_RETRIES: u32 = 3;
 
+/// Global HTTP retry settings, for a uv invocation.
+///
+/// This is populated from the user's settings, and used as the default for every
+/// [`BaseClientBuilder`]. The corresponding environment variables take precedence.
+pub static HTTP_RETRY: OnceLock<RetrySettings> = OnceLock::new();
+
+/// The maximum number of concurrent requests to any single host, for a uv invocation.
+///
+/// This is populated from the user's `concurrent-downloads-per-host` setting, and used as the
+/// default for every [`BaseClientBuilder`].
+pub static CONCURRENT_REQUESTS_PER_HOST: OnceLock<usize> = OnceLock::new();
+
+/// The settings that control how failed HTTP requests are retried.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RetrySettings {
+    /// The number of times to retry a failed request.
+    pub retries: Option<u32>,
+    /// The strategy used to wait between retries.
+    pub backoff: Option<Backoff>,
+    /// The HTTP status codes on which to retry, replacing the default set.
+    pub retry_status: Option<Vec<u16>>,
+    /// The timeout for establishing a connection, in seconds.
+    pub connect_timeout: Option<u64>,
+}
+
 /// Selectively skip parts or the entire auth middleware.
 #[derive(Debug, Clone, Copy, Default)]
 pub enum AuthIntegration {
@@ -48,12 +79,18 @@ pub struct BaseClientBuilder<'a> {
     allow_insecure_host: Vec<TrustedHost>,
     native_tls: bool,
     retries: u32,
+    backoff: Backoff,
+    retry_status: Option<Vec<StatusCode>>,
+    concurrent_requests_per_host: Option<usize>,
     pub connectivity: Connectivity,
     client: Option<Client>,
     markers: Option<&'a MarkerEnvironment>,
     platform: Option<&'a Platform>,
     auth_integration: AuthIntegration,
     default_timeout: Duration,
+    connect_timeout: Option<Duration>,
+    index_proxies: Vec<IndexProxy>,
+    index_tls: Vec<IndexTls>,
 }
 
 impl Default for BaseClientBuilder<'_> {
@@ -64,17 +101,43 @@ impl Default for BaseClientBuilder<'_> {
 
 impl BaseClientBuilder<'_> {
     pub fn new() -> Self {
+        let settings = HTTP_RETRY.get_or_init(RetrySettings::default);
         Self {
             keyring: KeyringProviderType::default(),
             allow_insecure_host: vec![],
             native_tls: false,
             connectivity: Connectivity::Online,
-            retries: DEFAULT_RETRIES,
+            retries: parse_env(EnvVars::UV_HTTP_RETRIES, "an integer")
+                .or_else(|| settings.retries)
+                .unwrap_or(DEFAULT_RETRIES),
+            backoff: parse_env(
+                EnvVars::UV_HTTP_RETRY_BACKOFF,
+                "`exponential` or `constant`",
+            )
+            .or(settings.backoff)
+            .unwrap_or_default(),
+            retry_status: parse_status_codes().or_else(|| {
+                settings.retry_status.as_ref().map(|codes| {
+                    codes
+                        .iter()
+                        .filter_map(|code| StatusCode::from_u16(*code).ok())
+                        .collect()
+                })
+            }),
+            concurrent_requests_per_host: CONCURRENT_REQUESTS_PER_HOST.get().copied(),
             client: None,
             markers: None,
             platform: None,
             auth_integration: AuthIntegration::default(),
             default_timeout: Duration::from_secs(30),
+            connect_timeout: parse_env::<u64>(
+                EnvVars::UV_HTTP_CONNECT_TIMEOUT,
+                "an integer number of seconds",
+            )
+            .or(settings.connect_timeout)
+            .map(Duration::from_secs),
+            index_proxies: vec![],
+            index_tls: vec![],
         }
     }
 }
@@ -104,6 +167,48 @@ impl<'a> BaseClientBuilder<'a> {
         self
     }
 
+    #[must_use]
+    pub fn backoff(mut self, backoff: Backoff) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Set the HTTP status codes on which to retry a request, replacing the default set.
+    #[must_use]
+    pub fn retry_status(mut self, retry_status: Vec<StatusCode>) -> Self {
+        self.retry_status = Some(retry_status);
+        self
+    }
+
+    /// The policy with which to wait between retries of a failed request.
+    ///
+    /// Exposed for callers that can't use the retry middleware, e.g., for streaming uploads.
+    pub fn retry_policy(&self) -> ExponentialBackoff {
+        match self.backoff {
+            Backoff::Exponential => ExponentialBackoff::builder(),
+            Backoff::Constant => ExponentialBackoff::builder()
+                .retry_bounds(Duration::from_secs(1), Duration::from_secs(1))
+                .jitter(Jitter::None)
+                .base(1),
+        }
+        .build_with_max_retries(self.retries)
+    }
+
+    /// The strategy with which to decide whether a failed request should be retried.
+    pub fn retry_strategy(&self) -> UvRetryableStrategy {
+        UvRetryableStrategy {
+            retry_status: self.retry_status.clone(),
+            skip_rate_limited: false,
+        }
+    }
+
+    /// Limit the number of concurrent requests to any single host.
+    #[must_use]
+    pub fn concurrent_requests_per_host(mut self, per_host: usize) -> Self {
+        self.concurrent_requests_per_host = Some(per_host);
+        self
+    }
+
     #[must_use]
     pub fn native_tls(mut self, native_tls: bool) -> Self {
         self.native_tls = native_tls;
@@ -140,8 +245,32 @@ impl<'a> BaseClientBuilder<'a> {
         self
     }
 
+    #[must_use]
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    /// Apply the proxy and TLS settings declared by the given indexes to requests to their hosts.
+    #[must_use]
+    pub fn indexes<'i>(mut self, indexes: impl IntoIterator<Item = &'i Index>) -> Self {
+        for index in indexes {
+            if let Some(index_proxy) = IndexProxy::from_index(index) {
+                if !self.index_proxies.contains(&index_proxy) {
+                    self.index_proxies.push(index_proxy);
+                }
+            }
+            if let Some(index_tls) = IndexTls::from_index(index) {
+                if !self.index_tls.contains(&index_tls) {
+                    self.index_tls.push(index_tls);
+                }
+            }
+        }
+        self
+    }
+
     pub fn is_offline(&self) -> bool {
-        matches!(self.connectivity, Connectivity::Offline)
+        self.connectivity.is_offline()
     }
 
     pub fn build(&self) -> BaseClient {
@@ -184,6 +313,9 @@ impl<'a> BaseClientBuilder<'a> {
             })
             .unwrap_or(self.default_timeout);
         debug!("Using request timeout of {}s", timeout.as_secs());
+        if let Some(connect_timeout) = self.connect_timeout {
+            debug!("Using connect timeout of {}s", connect_timeout.as_secs());
+        }
 
         // Create a secure client that validates certificates.
         let raw_client = self.create_client(
@@ -191,6 +323,7 @@ impl<'a> BaseClientBuilder<'a> {
             timeout,
             ssl_cert_file_exists,
             Security::Secure,
+            None,
         );
 
         // Create an insecure client that accepts invalid certificates.
@@ -199,11 +332,32 @@ impl<'a> BaseClientBuilder<'a> {
             timeout,
             ssl_cert_file_exists,
             Security::Insecure,
+            None,
         );
 
+        // Create a dedicated client for each index with custom TLS settings.
+        let raw_index_clients = self
+            .index_tls
+            .iter()
+            .map(|index_tls| {
+                let client = self.create_client(
+                    &user_agent_string,
+                    timeout,
+                    ssl_cert_file_exists,
+                    Security::Secure,
+                    Some(index_tls),
+                );
+                (index_tls.clone(), client)
+            })
+            .collect::<Vec<_>>();
+
         // Wrap in any relevant middleware and handle connectivity.
         let client = self.apply_middleware(raw_client.clone());
         let dangerous_client = self.apply_middleware(raw_dangerous_client.clone());
+        let index_clients = raw_index_clients
+            .iter()
+            .map(|(index_tls, client)| (index_tls.clone(), self.apply_middleware(client.clone())))
+            .collect();
 
         BaseClient {
             connectivity: self.connectivity,
@@ -212,6 +366,8 @@ impl<'a> BaseClientBuilder<'a> {
             raw_client,
             dangerous_client,
             raw_dangerous_client,
+            index_clients,
+            raw_index_clients,
             timeout,
         }
     }
@@ -221,6 +377,11 @@ impl<'a> BaseClientBuilder<'a> {
         // Wrap in any relevant middleware and handle connectivity.
         let client = self.apply_middleware(existing.raw_client.clone());
         let dangerous_client = self.apply_middleware(existing.raw_dangerous_client.clone());
+        let index_clients = existing
+            .raw_index_clients
+            .iter()
+            .map(|(index_tls, client)| (index_tls.clone(), self.apply_middleware(client.clone())))
+            .collect();
 
         BaseClient {
             connectivity: self.connectivity,
@@ -229,6 +390,8 @@ impl<'a> BaseClientBuilder<'a> {
             dangerous_client,
             raw_client: existing.raw_client.clone(),
             raw_dangerous_client: existing.raw_dangerous_client.clone(),
+            index_clients,
+            raw_index_clients: existing.raw_index_clients.clone(),
             timeout: existing.timeout,
         }
     }
@@ -239,6 +402,7 @@ impl<'a> BaseClientBuilder<'a> {
         timeout: Duration,
         ssl_cert_file_exists: bool,
         security: Security,
+        index_tls: Option<&IndexTls>,
     ) -> Client {
         // Configure the builder.
         let client_builder = ClientBuilder::new()
@@ -248,20 +412,58 @@ impl<'a> BaseClientBuilder<'a> {
             .read_timeout(timeout)
             .tls_built_in_root_certs(false);
 
+        let client_builder = if let Some(connect_timeout) = self.connect_timeout {
+            client_builder.connect_timeout(connect_timeout)
+        } else {
+            client_builder
+        };
+
+        // Configure any per-index proxies.
+        let client_builder = apply_index_proxies(client_builder, &self.index_proxies);
+
         // If necessary, accept invalid certificates.
         let client_builder = match security {
             Security::Secure => client_builder,
             Security::Insecure => client_builder.danger_accept_invalid_certs(true),
         };
 
-        let client_builder = if self.native_tls || ssl_cert_file_exists {
+        // Use the index's CA certificate bundle, if provided; otherwise, use the built-in roots.
+        let certificates = index_tls.and_then(IndexTls::ca_cert).and_then(|ca_cert| {
+            match read_certificates(ca_cert) {
+                Ok(certificates) => Some(certificates),
+                Err(err) => {
+                    warn_user_once!(
+                        "Ignoring invalid CA certificate bundle `{}`: {err}",
+                        ca_cert.user_display()
+                    );
+                    None
+                }
+            }
+        });
+        let client_builder = if let Some(certificates) = certificates {
+            certificates
+                .into_iter()
+                .fold(client_builder, ClientBuilder::add_root_certificate)
+        } else if self.native_tls || ssl_cert_file_exists {
             client_builder.tls_built_in_native_certs(true)
         } else {
             client_builder.tls_built_in_webpki_certs(true)
         };
 
-        // Configure mTLS.
-        let client_builder = if let Some(ssl_client_cert) = env::var_os(EnvVars::SSL_CLIENT_CERT) {
+        // Configure mTLS, preferring the index's client certificate, if provided.
+        let client_builder = if let Some(client_cert) = index_tls.and_then(IndexTls::client_cert) {
+            let client_key = index_tls.and_then(IndexTls::client_key);
+            match read_client_identity(client_cert, client_key) {
+                Ok(identity) => client_builder.identity(identity),
+                Err(err) => {
+                    warn_user_once!(
+                        "Ignoring invalid client certificate `{}`: {err}",
+                        client_cert.user_display()
+                    );
+                    client_builder
+                }
+            }
+        } else if let Some(ssl_client_cert) = env::var_os(EnvVars::SSL_CLIENT_CERT) {
             match read_identity(&ssl_client_cert) {
                 Ok(identity) => client_builder.identity(identity),
                 Err(err) => {
@@ -281,30 +483,46 @@ impl<'a> BaseClientBuilder<'a> {
     fn apply_middleware(&self, client: Client) -> ClientWithMiddleware {
         match self.connectivity {
             Connectivity::Online => {
+                // Request tokens from built-in token providers with the same TLS and proxy
+                // settings as the index itself.
+                let provider_client = client.clone();
                 let mut client = reqwest_middleware::ClientBuilder::new(client);
 
                 // Avoid uncloneable errors with a streaming body during publish.
                 if self.retries > 0 {
                     // Initialize the retry strategy.
-                    let retry_policy =
-                        ExponentialBackoff::builder().build_with_max_retries(self.retries);
+                    // Rate-limited responses are resent by the `RateLimitMiddleware` below, within
+                    // the same retry budget.
                     let retry_strategy = RetryTransientMiddleware::new_with_policy_and_strategy(
-                        retry_policy,
-                        UvRetryableStrategy,
+                        self.retry_policy(),
+                        UvRetryableStrategy {
+                            skip_rate_limited: true,
+                            ..self.retry_strategy()
+                        },
                     );
                     client = client.with(retry_strategy);
+
+                    // Wait out rate limits announced by the server, rather than retrying with backoff.
+                    client = client.with(RateLimitMiddleware::new(
+                        self.retries,
+                        self.retry_status.clone(),
+                    ));
                 }
 
                 // Initialize the authentication middleware to set headers.
                 match self.auth_integration {
                     AuthIntegration::Default => {
-                        client = client
-                            .with(AuthMiddleware::new().with_keyring(self.keyring.to_provider()));
+                        client = client.with(
+                            AuthMiddleware::new()
+                                .with_keyring(self.keyring.to_provider())
+                                .with_provider_client(Some(provider_client)),
+                        );
                     }
                     AuthIntegration::OnlyAuthenticated => {
                         client = client.with(
                             AuthMiddleware::new()
                                 .with_keyring(self.keyring.to_provider())
+                                .with_provider_client(Some(provider_client))
                                 .with_only_authenticated(true),
                         );
                     }
@@ -313,11 +531,19 @@ impl<'a> BaseClientBuilder<'a> {
                     }
                 }
 
+                // Limit the requests to each host last, such that waiting out a retry or a rate
+                // limit doesn't hold a permit.
+                if let Some(per_host) = self.concurrent_requests_per_host {
+                    client = client.with(HostConcurrencyMiddleware::new(per_host));
+                }
+
                 client.build()
             }
-            Connectivity::Offline => reqwest_middleware::ClientBuilder::new(client)
-                .with(OfflineMiddleware)
-                .build(),
+            Connectivity::Offline | Connectivity::StrictOffline => {
+                reqwest_middleware::ClientBuilder::new(client)
+                    .with(OfflineMiddleware)
+                    .build()
+            }
         }
     }
 }
@@ -333,6 +559,10 @@ pub struct BaseClient {
     raw_client: Client,
     /// The HTTP client that accepts invalid certificates without middleware.
     raw_dangerous_client: Client,
+    /// The underlying HTTP clients for indexes with custom TLS settings.
+    index_clients: Vec<(IndexTls, ClientWithMiddleware)>,
+    /// The HTTP clients for indexes with custom TLS settings, without middleware.
+    raw_index_clients: Vec<(IndexTls, Client)>,
     /// The connectivity mode to use.
     connectivity: Connectivity,
     /// Configured client timeout, in seconds.
@@ -350,7 +580,7 @@ enum Security {
 }
 
 impl BaseClient {
-    /// Selects the appropriate client based on the host's trustworthiness.
+    /// Selects the appropriate client based on the host's trustworthiness and TLS settings.
     pub fn for_host(&self, url: &Url) -> &ClientWithMiddleware {
         if self
             .allow_insecure_host
@@ -358,6 +588,12 @@ impl BaseClient {
             .any(|allow_insecure_host| allow_insecure_host.matches(url))
         {
             &self.dangerous_client
+        } else if let Some((_, client)) = self
+            .index_clients
+            .iter()
+            .find(|(index_tls, _)| index_tls.matches(url))
+        {
+            client
         } else {
             &self.client
         }
@@ -374,24 +610,57 @@ impl BaseClient {
     }
 }
 
-/// Extends [`DefaultRetryableStrategy`], to log transient request failures and additional retry cases.
-pub struct UvRetryableStrategy;
+/// Extends [`DefaultRetryableStrategy`], to warn on transient request failures and handle
+/// additional retry cases.
+#[derive(Debug, Clone, Default)]
+pub struct UvRetryableStrategy {
+    /// The HTTP status codes on which to retry, if different from the default set.
+    retry_status: Option<Vec<StatusCode>>,
+    /// Whether responses with a rate-limit delay are left to the `RateLimitMiddleware`.
+    skip_rate_limited: bool,
+}
 
 impl RetryableStrategy for UvRetryableStrategy {
     fn handle(&self, res: &Result<Response, reqwest_middleware::Error>) -> Option<Retryable> {
+        // Responses with a rate-limit delay were already waited out and resent by the
+        // `RateLimitMiddleware`, which shares the retry budget; retrying them again here would
+        // multiply the attempts.
+        if self.skip_rate_limited {
+            if let Ok(response) = res {
+                if rate_limit_delay(response).is_some() {
+                    return Some(Retryable::Fatal);
+                }
+            }
+        }
+
         // Use the default strategy and check for additional transient error cases.
-        let retryable = match DefaultRetryableStrategy.handle(res) {
-            None | Some(Retryable::Fatal) if is_extended_transient_error(res) => {
-                Some(Retryable::Transient)
+        let retryable = match (res, self.retry_status.as_deref()) {
+            (Ok(response), Some(retry_status)) => {
+                if retry_status.contains(&response.status()) {
+                    Some(Retryable::Transient)
+                } else if response.status().is_success() {
+                    None
+                } else {
+                    Some(Retryable::Fatal)
+                }
             }
-            default => default,
+            _ => match DefaultRetryableStrategy.handle(res) {
+                None | Some(Retryable::Fatal) if is_extended_transient_error(res) => {
+                    Some(Retryable::Transient)
+                }
+                default => default,
+            },
         };
 
-        // Log on transient errors
+        // Warn on transient errors.
         if retryable == Some(Retryable::Transient) {
             match res {
                 Ok(response) => {
-                    debug!("Transient request failure for: {}", response.url());
+                    warn_user_once!(
+                        "Transient request failure for `{}`: HTTP status {}",
+                        response.url(),
+                        response.status()
+                    );
                 }
                 Err(err) => {
                     let context = iter::successors(err.source(), |&err| err.source())
@@ -401,6 +670,10 @@ impl RetryableStrategy for UvRetryableStrategy {
                         "Transient request failure for {}, retrying: {err}\n{context}",
                         err.url().map(reqwest::Url::as_str).unwrap_or("unknown URL")
                     );
+                    warn_user_once!(
+                        "Transient request failure for `{}`: {err}",
+                        err.url().map(reqwest::Url::as_str).unwrap_or("unknown URL")
+                    );
                 }
             }
         }
@@ -408,6 +681,42 @@ impl RetryableStrategy for UvRetryableStrategy {
     }
 }
 
+/// Parse a value from the environment, warning and ignoring it if it's invalid.
+fn parse_env<T: FromStr>(name: &'static str, expected: &str) -> Option<T> {
+    let value = env::var(name).ok()?;
+    match value.parse::<T>() {
+        Ok(value) => Some(value),
+        Err(_) => {
+            warn_user_once!(
+                "Ignoring invalid value from environment for `{name}`. Expected {expected}, got \"{value}\"."
+            );
+            None
+        }
+    }
+}
+
+/// Parse the comma-separated list of HTTP status codes on which to retry from the environment.
+fn parse_status_codes() -> Option<Vec<StatusCode>> {
+    let value = env::var(EnvVars::UV_HTTP_RETRY_STATUS).ok()?;
+    let codes = value
+        .split(',')
+        .map(str::trim)
+        .filter(|code| !code.is_empty())
+        .map(|code| {
+            code.parse::<u16>()
+                .ok()
+                .and_then(|code| StatusCode::from_u16(code).ok())
+        })
+        .collect::<Option<Vec<_>>>();
+    if codes.is_none() {
+        warn_user_once!(
+            "Ignoring invalid value from environment for `{}`. Expected a comma-separated list of HTTP status codes, got \"{value}\".",
+            EnvVars::UV_HTTP_RETRY_STATUS
+        );
+    }
+    codes
+}
+
 /// Check for additional transient error kinds not supported by the default retry strategy in `reqwest_retry`.
 ///
 /// These cases should be safe to retry with [`Retryable::Transient`].
@@ -83,12 +83,24 @@ impl FlatIndexEntries {
 pub struct FlatIndexClient<'a> {
     client: &'a RegistryClient,
     cache: &'a Cache,
+    recursive: bool,
 }
 
 impl<'a> FlatIndexClient<'a> {
     /// Create a new [`FlatIndexClient`].
     pub fn new(client: &'a RegistryClient, cache: &'a Cache) -> Self {
-        Self { client, cache }
+        Self {
+            client,
+            cache,
+            recursive: false,
+        }
+    }
+
+    /// Set whether `--find-links` directories should be searched recursively.
+    #[must_use]
+    pub fn with_recursive(mut self, recursive: bool) -> Self {
+        self.recursive = recursive;
+        self
     }
 
     /// Read the directories and flat remote indexes from `--find-links`.
@@ -104,7 +116,7 @@ impl<'a> FlatIndexClient<'a> {
                         let path = url
                             .to_file_path()
                             .map_err(|()| FlatIndexError::NonFileUrl(url.to_url()))?;
-                        Self::read_from_directory(&path, index)
+                        Self::read_from_directory(&path, index, self.recursive)
                             .map_err(|err| FlatIndexError::FindLinksDirectory(path.clone(), err))?
                     }
                     IndexUrl::Pypi(url) | IndexUrl::Url(url) => self
@@ -150,7 +162,7 @@ impl<'a> FlatIndexClient<'a> {
                     .freshness(&cache_entry, None)
                     .map_err(ErrorKind::Io)?,
             ),
-            Connectivity::Offline => CacheControl::AllowStale,
+            Connectivity::Offline | Connectivity::StrictOffline => CacheControl::AllowStale,
         };
 
         let flat_index_request = self
@@ -228,16 +240,43 @@ impl<'a> FlatIndexClient<'a> {
     }
 
     /// Read a flat remote index from a `--find-links` directory.
+    ///
+    /// If `recursive` is set, subdirectories are searched as well. Symlinks to directories are
+    /// never followed, to avoid cycles.
     fn read_from_directory(
         path: &Path,
         flat_index: &IndexUrl,
+        recursive: bool,
     ) -> Result<FlatIndexEntries, FindLinksDirectoryError> {
+        let mut dists = Vec::new();
+        let mut directories = vec![path.to_path_buf()];
+        while let Some(directory) = directories.pop() {
+            dists.extend(Self::read_directory_entries(
+                &directory,
+                flat_index,
+                recursive.then_some(&mut directories),
+            )?);
+        }
+        Ok(FlatIndexEntries::from_entries(dists))
+    }
+
+    /// Read the distributions at the top level of a `--find-links` directory.
+    ///
+    /// If `subdirectories` is provided, any subdirectories are pushed onto it.
+    fn read_directory_entries(
+        path: &Path,
+        flat_index: &IndexUrl,
+        mut subdirectories: Option<&mut Vec<PathBuf>>,
+    ) -> Result<Vec<(DistFilename, File, IndexUrl)>, FindLinksDirectoryError> {
         let mut dists = Vec::new();
         for entry in fs_err::read_dir(path)? {
             let entry = entry?;
             let metadata = entry.metadata()?;
 
             if metadata.is_dir() {
+                if let Some(subdirectories) = subdirectories.as_mut() {
+                    subdirectories.push(entry.path());
+                }
                 continue;
             }
 
@@ -285,6 +324,6 @@ impl<'a> FlatIndexClient<'a> {
             };
             dists.push((filename, file, flat_index.clone()));
         }
-        Ok(FlatIndexEntries::from_entries(dists))
+        Ok(dists)
     }
 }
@@ -59,6 +59,24 @@ impl SimpleHtml {
         Ok(Self { base, files })
     }
 
+    /// Parse the project names from the root page of a simple HTML index, in which each project
+    /// is listed as an `<a>` tag whose text is the project name.
+    #[instrument(skip_all, fields(url = % url))]
+    pub(crate) fn parse_projects(text: &str, url: &Url) -> Result<Vec<String>, Error> {
+        let dom = tl::parse(text, tl::ParserOptions::default())?;
+        let projects = dom
+            .nodes()
+            .iter()
+            .filter_map(|node| node.as_tag())
+            .filter(|link| link.name().as_bytes() == b"a")
+            .map(|link| {
+                html_escape::decode_html_entities(link.inner_text(dom.parser()).trim()).to_string()
+            })
+            .filter(|name| !name.is_empty())
+            .collect();
+        Ok(projects)
+    }
+
     /// Parse the `href` from a `<base>` tag.
     fn parse_base(base: &HTMLTag) -> Result<Option<Url>, Error> {
         let Some(Some(href)) = base.attributes().get("href") else {
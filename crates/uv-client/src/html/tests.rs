@@ -993,3 +993,31 @@ fn parse_core_metadata() {
     }
     "###);
 }
+
+#[test]
+fn parse_projects() {
+    let text = r#"
+<!DOCTYPE html>
+<html>
+  <head>
+    <meta name="pypi:repository-version" content="1.0">
+    <title>Simple index</title>
+  </head>
+  <body>
+    <a href="/simple/flask/">Flask</a>
+    <a href="/simple/jinja2/">Jinja2</a>
+    <a href="/simple/zope-interface/">zope.interface</a>
+    <a href="/simple/empty/"> </a>
+  </body>
+</html>
+    "#;
+    let base = Url::parse("https://pypi.org/simple/").unwrap();
+    let result = SimpleHtml::parse_projects(text, &base).unwrap();
+    insta::assert_debug_snapshot!(result, @r###"
+    [
+        "Flask",
+        "Jinja2",
+        "zope.interface",
+    ]
+    "###);
+}
@@ -1,15 +1,18 @@
 pub use base_client::{
-    AuthIntegration, BaseClient, BaseClientBuilder, UvRetryableStrategy, DEFAULT_RETRIES,
+    AuthIntegration, BaseClient, BaseClientBuilder, RetrySettings, UvRetryableStrategy,
+    CONCURRENT_REQUESTS_PER_HOST, DEFAULT_RETRIES, HTTP_RETRY,
 };
 pub use cached_client::{CacheControl, CachedClient, CachedClientError, DataWithCachePolicy};
 pub use error::{Error, ErrorKind, WrappedReqwestError};
 pub use flat_index::{FlatIndexClient, FlatIndexEntries, FlatIndexError};
 pub use linehaul::LineHaul;
+pub use proxy::IndexProxy;
 pub use registry_client::{
     Connectivity, RegistryClient, RegistryClientBuilder, SimpleMetadata, SimpleMetadatum,
     VersionFiles,
 };
 pub use rkyvutil::{Deserializer, OwnedArchive, Serializer, Validator};
+pub use tls::{CertificateError, IndexTls, IndexTlsError};
 
 mod base_client;
 mod cached_client;
@@ -19,6 +22,7 @@ mod html;
 mod httpcache;
 mod linehaul;
 mod middleware;
+mod proxy;
 mod registry_client;
 mod remote_metadata;
 mod rkyvutil;
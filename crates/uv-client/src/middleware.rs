@@ -1,9 +1,19 @@
-use http::Extensions;
+use http::header::RETRY_AFTER;
+use http::{Extensions, HeaderMap};
+use std::collections::HashMap;
 use std::fmt::Debug;
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::Duration;
 
-use reqwest::{Request, Response};
+use reqwest::{Request, Response, StatusCode};
 use reqwest_middleware::{Middleware, Next};
+use tokio::sync::Semaphore;
+use tracing::debug;
 use url::Url;
+use uv_warnings::warn_user;
+
+/// The longest we're willing to wait for a rate limit to reset before giving up on the request.
+const MAX_RATE_LIMIT_WAIT: Duration = Duration::from_secs(300);
 
 /// A custom error type for the offline middleware.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -45,3 +55,181 @@ impl Middleware for OfflineMiddleware {
         ))
     }
 }
+
+/// The per-host request limits, keyed by host and port.
+///
+/// Shared across all clients, such that the limit holds for every request uv sends to a host.
+static HOST_PERMITS: LazyLock<Mutex<HashMap<(String, Option<u16>), Arc<Semaphore>>>> =
+    LazyLock::new(Mutex::default);
+
+/// A middleware that limits the number of concurrent requests to any single host.
+///
+/// The permit is held until the response headers are received; callers that stream large response
+/// bodies, e.g., archive downloads, additionally limit the body transfer.
+pub(crate) struct HostConcurrencyMiddleware {
+    per_host: usize,
+}
+
+impl HostConcurrencyMiddleware {
+    pub(crate) fn new(per_host: usize) -> Self {
+        Self { per_host }
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for HostConcurrencyMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let Some(host) = req.url().host_str() else {
+            return next.run(req, extensions).await;
+        };
+        let semaphore = HOST_PERMITS
+            .lock()
+            .unwrap()
+            .entry((host.to_string(), req.url().port_or_known_default()))
+            .or_insert_with(|| Arc::new(Semaphore::new(self.per_host)))
+            .clone();
+        let _permit = semaphore.acquire().await.unwrap();
+        next.run(req, extensions).await
+    }
+}
+
+/// A middleware that waits out rate limits announced by the server.
+///
+/// If a `429 Too Many Requests` or `503 Service Unavailable` response includes a `Retry-After`
+/// header, or standard rate-limit headers indicating that no requests remain, the request is
+/// paused until the limit resets and then resent, rather than retried with the usual backoff.
+pub(crate) struct RateLimitMiddleware {
+    retries: u32,
+    /// The HTTP status codes on which to retry, if different from the default set.
+    retry_status: Option<Vec<StatusCode>>,
+}
+
+impl RateLimitMiddleware {
+    pub(crate) fn new(retries: u32, retry_status: Option<Vec<StatusCode>>) -> Self {
+        Self {
+            retries,
+            retry_status,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for RateLimitMiddleware {
+    async fn handle(
+        &self,
+        mut req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let mut attempt = 0;
+        loop {
+            // Streaming bodies can't be resent.
+            let Some(duplicate) = req.try_clone() else {
+                return next.run(req, extensions).await;
+            };
+
+            let response = next.clone().run(req, extensions).await?;
+            if attempt >= self.retries {
+                return Ok(response);
+            }
+            if self
+                .retry_status
+                .as_ref()
+                .is_some_and(|retry_status| !retry_status.contains(&response.status()))
+            {
+                return Ok(response);
+            }
+            let Some(wait) = rate_limit_delay(&response) else {
+                return Ok(response);
+            };
+            if wait > MAX_RATE_LIMIT_WAIT {
+                debug!(
+                    "Rate limit for `{}` resets in {}s, which exceeds the maximum wait",
+                    response.url(),
+                    wait.as_secs()
+                );
+                return Ok(response);
+            }
+
+            attempt += 1;
+            warn_user!(
+                "Rate limited by `{}` (HTTP status {}), waiting {}s before retrying ({attempt}/{})",
+                response
+                    .url()
+                    .host_str()
+                    .unwrap_or_else(|| response.url().as_str()),
+                response.status(),
+                wait.as_secs().max(1),
+                self.retries
+            );
+            tokio::time::sleep(wait).await;
+            req = duplicate;
+        }
+    }
+}
+
+/// Determine how long to wait before retrying a rate-limited response, if the server says so.
+pub(crate) fn rate_limit_delay(response: &Response) -> Option<Duration> {
+    if !matches!(
+        response.status(),
+        StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE
+    ) {
+        return None;
+    }
+    let headers = response.headers();
+    if let Some(retry_after) = header(headers, RETRY_AFTER.as_str()) {
+        return parse_retry_after(retry_after);
+    }
+
+    // Fall back to the `RateLimit-*` headers, and their widely-used `X-RateLimit-*` predecessors,
+    // but only if the server reports that the quota is exhausted.
+    let remaining = header(headers, "ratelimit-remaining")
+        .or_else(|| header(headers, "x-ratelimit-remaining"))?;
+    if remaining.parse::<u64>().ok()? != 0 {
+        return None;
+    }
+    let reset = header(headers, "ratelimit-reset")
+        .or_else(|| header(headers, "x-ratelimit-reset"))?
+        .parse::<u64>()
+        .ok()?;
+    Some(reset_delay(reset, jiff::Timestamp::now()))
+}
+
+/// Parse a `Retry-After` header, which is either a number of seconds or an HTTP date.
+fn parse_retry_after(value: &str) -> Option<Duration> {
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = jiff::fmt::rfc2822::DateTimeParser::new()
+        .parse_timestamp(value)
+        .ok()?;
+    let seconds = date.as_second() - jiff::Timestamp::now().as_second();
+    Some(Duration::from_secs(u64::try_from(seconds).unwrap_or(0)))
+}
+
+/// Convert a rate-limit reset value into a delay.
+///
+/// The reset is specified as a number of seconds, but some servers (e.g., GitHub) instead send a
+/// Unix timestamp, which we detect by its magnitude.
+fn reset_delay(reset: u64, now: jiff::Timestamp) -> Duration {
+    const UNIX_TIMESTAMP_THRESHOLD: u64 = 1_000_000_000;
+
+    if reset < UNIX_TIMESTAMP_THRESHOLD {
+        return Duration::from_secs(reset);
+    }
+    let now = u64::try_from(now.as_second()).unwrap_or(0);
+    Duration::from_secs(reset.saturating_sub(now))
+}
+
+/// Read a header as a trimmed string.
+fn header<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+}
@@ -0,0 +1,133 @@
+use std::env;
+
+use reqwest::{ClientBuilder, NoProxy, Proxy};
+use tracing::debug;
+use url::Url;
+
+use uv_distribution_types::Index;
+use uv_static::EnvVars;
+use uv_warnings::warn_user_once;
+
+/// The proxy configuration for an index, as declared in a `[[tool.uv.index]]` entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexProxy {
+    /// The host of the index.
+    host: String,
+    /// The port of the index.
+    port: Option<u16>,
+    /// The proxy through which to route requests to the index, if any.
+    proxy: Option<Url>,
+    /// Hosts that should be accessed directly, bypassing any proxy.
+    no_proxy: Vec<String>,
+}
+
+impl IndexProxy {
+    /// Extract the proxy configuration from an [`Index`], if it declares one.
+    pub fn from_index(index: &Index) -> Option<Self> {
+        if index.proxy.is_none() && index.no_proxy.is_empty() {
+            return None;
+        }
+        let url = index.raw_url();
+        let Some(host) = url.host_str() else {
+            warn_user_once!(
+                "Ignoring proxy configuration for `{}`, which is not a remote index",
+                index.url
+            );
+            return None;
+        };
+        Some(Self {
+            host: host.to_string(),
+            port: url.port_or_known_default(),
+            proxy: index.proxy.clone(),
+            no_proxy: index.no_proxy.clone(),
+        })
+    }
+}
+
+/// Configure the client to route requests through the per-index proxies.
+///
+/// Index proxies take precedence over those configured via the environment. An index's `no-proxy`
+/// hosts bypass its own proxy, but not those of other indexes. Since requests that bypass an
+/// index proxy would otherwise fall back to the environment proxies, the environment proxies are
+/// re-created to exclude those hosts too, as `reqwest` doesn't allow amending its system proxy
+/// configuration.
+pub(crate) fn apply_index_proxies(
+    client_builder: ClientBuilder,
+    index_proxies: &[IndexProxy],
+) -> ClientBuilder {
+    if index_proxies.is_empty() {
+        return client_builder;
+    }
+
+    let bypass = index_proxies
+        .iter()
+        .flat_map(|index_proxy| index_proxy.no_proxy.iter().cloned())
+        .collect::<Vec<_>>();
+
+    let mut client_builder = if bypass.is_empty() {
+        client_builder
+    } else {
+        // Disable the system proxies; we'll add them back below.
+        client_builder.no_proxy()
+    };
+
+    // Route requests to each index through its proxy.
+    for index_proxy in index_proxies {
+        let Some(proxy_url) = index_proxy.proxy.clone() else {
+            continue;
+        };
+        debug!(
+            "Using proxy {} for requests to {}",
+            proxy_url.host_str().unwrap_or_default(),
+            index_proxy.host
+        );
+        let host = index_proxy.host.clone();
+        let port = index_proxy.port;
+        let proxy = Proxy::custom(move |url| {
+            (url.host_str() == Some(host.as_str()) && url.port_or_known_default() == port)
+                .then(|| proxy_url.clone())
+        })
+        .no_proxy(NoProxy::from_string(&index_proxy.no_proxy.join(",")));
+        client_builder = client_builder.proxy(proxy);
+    }
+
+    if bypass.is_empty() {
+        return client_builder;
+    }
+
+    // Re-create the environment proxies, excluding the `no-proxy` hosts.
+    let no_proxy = var(EnvVars::NO_PROXY)
+        .into_iter()
+        .chain(bypass)
+        .collect::<Vec<_>>()
+        .join(",");
+    let proxies: [(&str, fn(String) -> reqwest::Result<Proxy>); 3] = [
+        (EnvVars::HTTP_PROXY, Proxy::http),
+        (EnvVars::HTTPS_PROXY, Proxy::https),
+        (EnvVars::ALL_PROXY, Proxy::all),
+    ];
+    for (name, constructor) in proxies {
+        let Some(value) = var(name) else {
+            continue;
+        };
+        match constructor(value) {
+            Ok(proxy) => {
+                client_builder =
+                    client_builder.proxy(proxy.no_proxy(NoProxy::from_string(&no_proxy)));
+            }
+            Err(err) => {
+                warn_user_once!("Ignoring invalid `{name}`: {err}");
+            }
+        }
+    }
+
+    client_builder
+}
+
+/// Read a proxy environment variable, falling back to its lowercase variant.
+fn var(name: &str) -> Option<String> {
+    env::var(name)
+        .or_else(|_| env::var(name.to_lowercase()))
+        .ok()
+        .filter(|value| !value.is_empty())
+}
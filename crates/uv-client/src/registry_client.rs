@@ -4,6 +4,8 @@ use http::HeaderMap;
 use itertools::Either;
 use reqwest::{Client, Response, StatusCode};
 use reqwest_middleware::ClientWithMiddleware;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 use std::collections::BTreeMap;
 use std::fmt::Debug;
 use std::path::PathBuf;
@@ -24,7 +26,9 @@ use uv_normalize::PackageName;
 use uv_pep440::Version;
 use uv_pep508::MarkerEnvironment;
 use uv_platform_tags::Platform;
-use uv_pypi_types::{ResolutionMetadata, SimpleJson};
+use uv_pypi_types::{
+    Metadata23, MetadataError, ResolutionMetadata, SimpleJson, SimpleProjectsJson,
+};
 
 use crate::base_client::BaseClientBuilder;
 use crate::cached_client::CacheControl;
@@ -92,6 +96,14 @@ impl<'a> RegistryClientBuilder<'a> {
         self
     }
 
+    #[must_use]
+    pub fn concurrent_requests_per_host(mut self, per_host: usize) -> Self {
+        self.base_client_builder = self
+            .base_client_builder
+            .concurrent_requests_per_host(per_host);
+        self
+    }
+
     #[must_use]
     pub fn native_tls(mut self, native_tls: bool) -> Self {
         self.base_client_builder = self.base_client_builder.native_tls(native_tls);
@@ -123,8 +135,11 @@ impl<'a> RegistryClientBuilder<'a> {
     }
 
     pub fn build(self) -> RegistryClient {
-        // Build a base client
-        let builder = self.base_client_builder;
+        // Build a base client, routing requests through any per-index proxies and using a
+        // dedicated client for any indexes with custom TLS settings.
+        let builder = self
+            .base_client_builder
+            .indexes(self.index_urls.all_indexes());
 
         let client = builder.build();
 
@@ -280,7 +295,9 @@ impl RegistryClient {
                 Connectivity::Online => {
                     Err(ErrorKind::PackageNotFound(package_name.to_string()).into())
                 }
-                Connectivity::Offline => Err(ErrorKind::Offline(package_name.to_string()).into()),
+                Connectivity::Offline | Connectivity::StrictOffline => {
+                    Err(ErrorKind::Offline(package_name.to_string()).into())
+                }
             };
         }
 
@@ -319,7 +336,7 @@ impl RegistryClient {
                     .freshness(&cache_entry, Some(package_name))
                     .map_err(ErrorKind::Io)?,
             ),
-            Connectivity::Offline => CacheControl::AllowStale,
+            Connectivity::Offline | Connectivity::StrictOffline => CacheControl::AllowStale,
         };
 
         if matches!(index, IndexUrl::Path(_)) {
@@ -430,6 +447,134 @@ impl RegistryClient {
         OwnedArchive::from_unarchived(&metadata)
     }
 
+    /// Fetch the names of all projects available on the given index.
+    ///
+    /// For a remote index, the names are read from the root of its Simple Repository API (e.g.,
+    /// `https://pypi.org/simple/`); for a local index, from the names of its subdirectories.
+    /// Names that aren't valid package names are skipped.
+    #[instrument(skip_all, fields(index = % index))]
+    pub async fn project_names(&self, index: &IndexUrl) -> Result<Vec<PackageName>, Error> {
+        if matches!(index, IndexUrl::Path(_)) {
+            let path = index
+                .url()
+                .to_file_path()
+                .map_err(|()| ErrorKind::NonFileUrl(index.url().clone()))?;
+            let mut names = Vec::new();
+            for entry in fs_err::read_dir(path).map_err(ErrorKind::Io)? {
+                let entry = entry.map_err(ErrorKind::Io)?;
+                if !entry.file_type().map_err(ErrorKind::Io)?.is_dir() {
+                    continue;
+                }
+                if let Some(name) = entry
+                    .file_name()
+                    .to_str()
+                    .and_then(|name| PackageName::from_str(name).ok())
+                {
+                    names.push(name);
+                }
+            }
+            names.sort_unstable();
+            names.dedup();
+            return Ok(names);
+        }
+
+        // The URL *must* end in a trailing slash for proper relative path behavior.
+        let mut url: Url = index.clone().into();
+        url.path_segments_mut()
+            .map_err(|()| ErrorKind::CannotBeABase(index.clone().into()))?
+            .pop_if_empty()
+            .push("");
+
+        trace!("Fetching the project list from {url}");
+
+        let cache_entry = self.cache.entry(
+            CacheBucket::Simple,
+            WheelCache::Index(index).root(),
+            "projects.msgpack",
+        );
+        let cache_control = match self.connectivity {
+            Connectivity::Online => CacheControl::from(
+                self.cache
+                    .freshness(&cache_entry, None)
+                    .map_err(ErrorKind::Io)?,
+            ),
+            Connectivity::Offline | Connectivity::StrictOffline => CacheControl::AllowStale,
+        };
+
+        let request = self
+            .uncached_client(&url)
+            .get(url.clone())
+            .header("Accept-Encoding", "gzip")
+            .header("Accept", MediaType::accepts())
+            .build()
+            .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
+        let parse_projects_response = |response: Response| {
+            async {
+                let url = response.url().clone();
+
+                let content_type = response
+                    .headers()
+                    .get("content-type")
+                    .ok_or_else(|| Error::from(ErrorKind::MissingContentType(url.clone())))?;
+                let content_type = content_type.to_str().map_err(|err| {
+                    Error::from(ErrorKind::InvalidContentTypeHeader(url.clone(), err))
+                })?;
+                let media_type = content_type.split(';').next().unwrap_or(content_type);
+                let media_type = MediaType::from_str(media_type).ok_or_else(|| {
+                    Error::from(ErrorKind::UnsupportedMediaType(
+                        url.clone(),
+                        media_type.to_string(),
+                    ))
+                })?;
+
+                let projects = match media_type {
+                    MediaType::Json => {
+                        let bytes = response
+                            .bytes()
+                            .await
+                            .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
+                        let data: SimpleProjectsJson = serde_json::from_slice(bytes.as_ref())
+                            .map_err(|err| Error::from_json_err(err, url.clone()))?;
+                        data.projects
+                            .into_iter()
+                            .map(|project| project.name)
+                            .collect::<Vec<_>>()
+                    }
+                    MediaType::Html => {
+                        let text = response
+                            .text()
+                            .await
+                            .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
+                        SimpleHtml::parse_projects(&text, &url)
+                            .map_err(|err| Error::from_html_err(err, url.clone()))?
+                    }
+                };
+
+                let mut names = projects
+                    .iter()
+                    .filter_map(|name| PackageName::from_str(name).ok())
+                    .collect::<Vec<_>>();
+                names.sort_unstable();
+                names.dedup();
+                Ok::<Vec<PackageName>, Error>(names)
+            }
+            .boxed_local()
+            .instrument(info_span!("parse_simple_api_projects"))
+        };
+        self.cached_client()
+            .get_serde(
+                request,
+                &cache_entry,
+                cache_control,
+                parse_projects_response,
+            )
+            .await
+            .map_err(|err| match err {
+                CachedClientError::Client(err) => err,
+                CachedClientError::Callback(err) => err,
+            })
+    }
+
     /// Fetch the metadata for a remote wheel file.
     ///
     /// For a remote wheel, we try the following ways to fetch the metadata:
@@ -500,13 +645,18 @@ impl RegistryClient {
                         })?
                     }
                     WheelLocation::Url(url) => {
-                        self.wheel_metadata_registry(&wheel.index, &wheel.file, &url, capabilities)
-                            .await?
+                        self.wheel_metadata_registry::<ResolutionMetadata>(
+                            &wheel.index,
+                            &wheel.file,
+                            &url,
+                            capabilities,
+                        )
+                        .await?
                     }
                 }
             }
             BuiltDist::DirectUrl(wheel) => {
-                self.wheel_metadata_no_pep658(
+                self.wheel_metadata_no_pep658::<ResolutionMetadata>(
                     &wheel.filename,
                     &wheel.url,
                     None,
@@ -545,14 +695,61 @@ impl RegistryClient {
         Ok(metadata)
     }
 
+    /// Fetch the full core metadata (i.e., the `METADATA` file) of a wheel on a registry.
+    ///
+    /// Unlike [`RegistryClient::wheel_metadata`], which retains only the fields that are relevant
+    /// to resolution, the returned metadata includes descriptive fields like the summary and the
+    /// license. The metadata is fetched in the same ways as [`RegistryClient::wheel_metadata`].
+    #[instrument(skip_all, fields(wheel = % file.filename))]
+    pub async fn wheel_core_metadata(
+        &self,
+        index: &IndexUrl,
+        file: &File,
+        capabilities: &IndexCapabilities,
+    ) -> Result<Metadata23, Error> {
+        let filename = WheelFilename::from_str(&file.filename).map_err(ErrorKind::WheelFilename)?;
+        let url = match &file.url {
+            FileLocation::RelativeUrl(base, url) => {
+                uv_pypi_types::base_url_join_relative(base, url)
+                    .map_err(ErrorKind::JoinRelativeUrl)?
+            }
+            FileLocation::AbsoluteUrl(url) => url.to_url(),
+        };
+
+        let text = if url.scheme() == "file" {
+            let path = url
+                .to_file_path()
+                .map_err(|()| ErrorKind::NonFileUrl(url.clone()))?;
+            let file = fs_err::tokio::File::open(&path)
+                .await
+                .map_err(ErrorKind::Io)?;
+            let reader = tokio::io::BufReader::new(file);
+            let contents = read_metadata_async_seek(&filename, reader)
+                .await
+                .map_err(|err| ErrorKind::Metadata(path.to_string_lossy().to_string(), err))?;
+            String::from_utf8_lossy(&contents).into_owned()
+        } else {
+            self.wheel_metadata_registry::<String>(index, file, &url, capabilities)
+                .await?
+        };
+
+        Metadata23::parse(text.as_bytes()).map_err(|err| {
+            Error::from(ErrorKind::MetadataParseError(
+                filename,
+                url.to_string(),
+                Box::new(err),
+            ))
+        })
+    }
+
     /// Fetch the metadata from a wheel file.
-    async fn wheel_metadata_registry(
+    async fn wheel_metadata_registry<T: WheelMetadata>(
         &self,
         index: &IndexUrl,
         file: &File,
         url: &Url,
         capabilities: &IndexCapabilities,
-    ) -> Result<ResolutionMetadata, Error> {
+    ) -> Result<T, Error> {
         // If the metadata file is available at its own url (PEP 658), download it from there.
         let filename = WheelFilename::from_str(&file.filename).map_err(ErrorKind::WheelFilename)?;
         if file.dist_info_metadata {
@@ -562,7 +759,7 @@ impl RegistryClient {
             let cache_entry = self.cache.entry(
                 CacheBucket::Wheels,
                 WheelCache::Index(index).wheel_dir(filename.name.as_ref()),
-                format!("{}.msgpack", filename.stem()),
+                T::cache_file(&filename),
             );
             let cache_control = match self.connectivity {
                 Connectivity::Online => CacheControl::from(
@@ -570,7 +767,7 @@ impl RegistryClient {
                         .freshness(&cache_entry, Some(&filename.name))
                         .map_err(ErrorKind::Io)?,
                 ),
-                Connectivity::Offline => CacheControl::AllowStale,
+                Connectivity::Offline | Connectivity::StrictOffline => CacheControl::AllowStale,
             };
 
             let response_callback = |response: Response| async {
@@ -580,7 +777,7 @@ impl RegistryClient {
                     .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
 
                 info_span!("parse_metadata21")
-                    .in_scope(|| ResolutionMetadata::parse_metadata(bytes.as_ref()))
+                    .in_scope(|| T::parse(bytes.as_ref()))
                     .map_err(|err| {
                         Error::from(ErrorKind::MetadataParseError(
                             filename,
@@ -614,18 +811,18 @@ impl RegistryClient {
     }
 
     /// Get the wheel metadata if it isn't available in an index through PEP 658
-    async fn wheel_metadata_no_pep658<'data>(
+    async fn wheel_metadata_no_pep658<'data, T: WheelMetadata>(
         &self,
         filename: &'data WheelFilename,
         url: &'data Url,
         index: Option<&'data IndexUrl>,
         cache_shard: WheelCache<'data>,
         capabilities: &'data IndexCapabilities,
-    ) -> Result<ResolutionMetadata, Error> {
+    ) -> Result<T, Error> {
         let cache_entry = self.cache.entry(
             CacheBucket::Wheels,
             cache_shard.wheel_dir(filename.name.as_ref()),
-            format!("{}.msgpack", filename.stem()),
+            T::cache_file(filename),
         );
         let cache_control = match self.connectivity {
             Connectivity::Online => CacheControl::from(
@@ -633,7 +830,7 @@ impl RegistryClient {
                     .freshness(&cache_entry, Some(&filename.name))
                     .map_err(ErrorKind::Io)?,
             ),
-            Connectivity::Offline => CacheControl::AllowStale,
+            Connectivity::Offline | Connectivity::StrictOffline => CacheControl::AllowStale,
         };
 
         // Attempt to fetch via a range request.
@@ -668,15 +865,14 @@ impl RegistryClient {
                     .map_err(|err| ErrorKind::AsyncHttpRangeReader(url.clone(), err))?;
                     trace!("Getting metadata for {filename} by range request");
                     let text = wheel_metadata_from_remote_zip(filename, url, &mut reader).await?;
-                    let metadata =
-                        ResolutionMetadata::parse_metadata(text.as_bytes()).map_err(|err| {
-                            Error::from(ErrorKind::MetadataParseError(
-                                filename.clone(),
-                                url.to_string(),
-                                Box::new(err),
-                            ))
-                        })?;
-                    Ok::<ResolutionMetadata, CachedClientError<Error>>(metadata)
+                    let metadata = T::parse(text.as_bytes()).map_err(|err| {
+                        Error::from(ErrorKind::MetadataParseError(
+                            filename.clone(),
+                            url.to_string(),
+                            Box::new(err),
+                        ))
+                    })?;
+                    Ok::<T, CachedClientError<Error>>(metadata)
                 }
                 .boxed_local()
                 .instrument(info_span!("read_metadata_range_request", wheel = %filename))
@@ -734,9 +930,16 @@ impl RegistryClient {
                     .map_err(|err| self.handle_response_errors(err))
                     .into_async_read();
 
-                read_metadata_async_stream(filename, url.as_ref(), reader)
+                let contents = read_metadata_async_stream(filename, reader)
                     .await
-                    .map_err(|err| ErrorKind::Metadata(url.to_string(), err))
+                    .map_err(|err| ErrorKind::Metadata(url.to_string(), err))?;
+                T::parse(&contents).map_err(|err| {
+                    Error::from(ErrorKind::MetadataParseError(
+                        filename.clone(),
+                        url.to_string(),
+                        Box::new(err),
+                    ))
+                })
             }
             .instrument(info_span!("read_metadata_stream", wheel = %filename))
         };
@@ -762,6 +965,36 @@ impl RegistryClient {
     }
 }
 
+/// The form in which the metadata of a remote wheel is returned and cached.
+trait WheelMetadata: Serialize + DeserializeOwned + 'static {
+    /// The name of the cache entry for the given wheel.
+    fn cache_file(filename: &WheelFilename) -> String;
+
+    /// Read the metadata from the contents of a `METADATA` file.
+    fn parse(contents: &[u8]) -> Result<Self, MetadataError>;
+}
+
+impl WheelMetadata for ResolutionMetadata {
+    fn cache_file(filename: &WheelFilename) -> String {
+        format!("{}.msgpack", filename.stem())
+    }
+
+    fn parse(contents: &[u8]) -> Result<Self, MetadataError> {
+        ResolutionMetadata::parse_metadata(contents)
+    }
+}
+
+/// The raw text of the `METADATA` file, from which the full core metadata is read.
+impl WheelMetadata for String {
+    fn cache_file(filename: &WheelFilename) -> String {
+        format!("{}.metadata.msgpack", filename.stem())
+    }
+
+    fn parse(contents: &[u8]) -> Result<Self, MetadataError> {
+        Ok(String::from_utf8_lossy(contents).into_owned())
+    }
+}
+
 #[derive(Default, Debug, rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)]
 #[rkyv(derive(Debug))]
 pub struct VersionFiles {
@@ -928,6 +1161,10 @@ pub enum Connectivity {
 
     /// Do not allow access to the network.
     Offline,
+
+    /// Do not allow access to the network, and verify that all required data is available in the
+    /// cache before starting an installation.
+    StrictOffline,
 }
 
 impl Connectivity {
@@ -936,7 +1173,11 @@ impl Connectivity {
     }
 
     pub fn is_offline(&self) -> bool {
-        matches!(self, Self::Offline)
+        matches!(self, Self::Offline | Self::StrictOffline)
+    }
+
+    pub fn is_strict_offline(&self) -> bool {
+        matches!(self, Self::StrictOffline)
     }
 }
 
@@ -1,21 +1,196 @@
-use reqwest::Identity;
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use p12_keystore::KeyStore;
+use reqwest::{Certificate, Identity};
+use std::env;
 use std::ffi::OsStr;
 use std::io::Read;
+use std::path::{Path, PathBuf};
+use url::Url;
+
+use uv_distribution_types::Index;
+use uv_fs::Simplified;
+use uv_static::EnvVars;
+use uv_warnings::warn_user_once;
 
 #[derive(thiserror::Error, Debug)]
-pub(crate) enum CertificateError {
+pub enum CertificateError {
     #[error(transparent)]
     Io(#[from] std::io::Error),
     #[error(transparent)]
     Reqwest(reqwest::Error),
+    #[error("No certificates found")]
+    Empty,
+    #[error("Failed to read PKCS#12 file (is `UV_CLIENT_CERT_PASSWORD` set correctly?): {0}")]
+    Pkcs12(String),
+    #[error("PKCS#12 file does not contain a private key")]
+    Pkcs12MissingKey,
 }
 
 /// Return the `Identity` from the provided file.
 pub(crate) fn read_identity(ssl_client_cert: &OsStr) -> Result<Identity, CertificateError> {
+    read_client_identity(Path::new(ssl_client_cert), None)
+}
+
+/// Return the `Identity` from the provided client certificate and, if stored separately, private
+/// key.
+///
+/// PKCS#12 (`.p12` or `.pfx`) files are decrypted with the password provided via
+/// `UV_CLIENT_CERT_PASSWORD`, if any; all other files are expected to be PEM-encoded.
+pub(crate) fn read_client_identity(
+    client_cert: &Path,
+    client_key: Option<&Path>,
+) -> Result<Identity, CertificateError> {
+    let mut buf = Vec::new();
+    fs_err::File::open(client_cert)?.read_to_end(&mut buf)?;
+    if client_cert
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("p12") || ext.eq_ignore_ascii_case("pfx"))
+    {
+        return read_pkcs12_identity(&buf);
+    }
+    if let Some(client_key) = client_key {
+        buf.push(b'\n');
+        fs_err::File::open(client_key)?.read_to_end(&mut buf)?;
+    }
+    Identity::from_pem(&buf).map_err(CertificateError::Reqwest)
+}
+
+/// Return the `Identity` from the provided PKCS#12 archive.
+///
+/// `rustls` only accepts PEM-encoded identities, so the archive's private key and certificate
+/// chain are re-encoded as PEM.
+fn read_pkcs12_identity(buf: &[u8]) -> Result<Identity, CertificateError> {
+    let password = env::var(EnvVars::UV_CLIENT_CERT_PASSWORD).unwrap_or_default();
+    let keystore = KeyStore::from_pkcs12(buf, &password)
+        .map_err(|err| CertificateError::Pkcs12(err.to_string()))?;
+    let (_, chain) = keystore
+        .private_key_chain()
+        .ok_or(CertificateError::Pkcs12MissingKey)?;
+
+    let mut pem = String::new();
+    write_pem(&mut pem, "PRIVATE KEY", chain.key());
+    for certificate in chain.chain() {
+        write_pem(&mut pem, "CERTIFICATE", certificate.as_der());
+    }
+    Identity::from_pem(pem.as_bytes()).map_err(CertificateError::Reqwest)
+}
+
+/// Append a PEM block with the given label and DER-encoded contents.
+fn write_pem(pem: &mut String, label: &str, der: &[u8]) {
+    let encoded = BASE64_STANDARD.encode(der);
+    pem.push_str(&format!("-----BEGIN {label}-----\n"));
+    let mut rest = encoded.as_str();
+    while !rest.is_empty() {
+        let (line, tail) = rest.split_at(rest.len().min(64));
+        pem.push_str(line);
+        pem.push('\n');
+        rest = tail;
+    }
+    pem.push_str(&format!("-----END {label}-----\n"));
+}
+
+/// Return the root certificates from the provided PEM bundle.
+pub(crate) fn read_certificates(ca_cert: &Path) -> Result<Vec<Certificate>, CertificateError> {
     let mut buf = Vec::new();
-    fs_err::File::open(ssl_client_cert)?.read_to_end(&mut buf)?;
-    Identity::from_pem(&buf).map_err(|tls_err| {
-        debug_assert!(tls_err.is_builder(), "must be a rustls::Error internally");
-        CertificateError::Reqwest(tls_err)
-    })
+    fs_err::File::open(ca_cert)?.read_to_end(&mut buf)?;
+    let certificates = Certificate::from_pem_bundle(&buf).map_err(CertificateError::Reqwest)?;
+    if certificates.is_empty() {
+        return Err(CertificateError::Empty);
+    }
+    Ok(certificates)
+}
+
+/// The TLS configuration for an index, as declared in a `[[tool.uv.index]]` entry.
+///
+/// Requests to the index's host are sent with a dedicated client, such that the index's settings
+/// don't affect requests to other hosts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexTls {
+    /// The host of the index.
+    host: String,
+    /// The port of the index.
+    port: Option<u16>,
+    /// The CA certificate bundle to use to verify the index, in lieu of the system roots.
+    ca_cert: Option<PathBuf>,
+    /// The client certificate to present to the index.
+    client_cert: Option<PathBuf>,
+    /// The private key for the client certificate, if not included in the certificate file.
+    client_key: Option<PathBuf>,
+}
+
+impl IndexTls {
+    /// Extract the TLS configuration from an [`Index`], if it declares one.
+    pub fn from_index(index: &Index) -> Option<Self> {
+        if index.ca_cert.is_none() && index.client_cert.is_none() {
+            if index.client_key.is_some() {
+                warn_user_once!(
+                    "Ignoring `client-key` for `{}`, which does not declare a `client-cert`",
+                    index.url
+                );
+            }
+            return None;
+        }
+        let url = index.raw_url();
+        let Some(host) = url.host_str() else {
+            warn_user_once!(
+                "Ignoring TLS configuration for `{}`, which is not a remote index",
+                index.url
+            );
+            return None;
+        };
+        Some(Self {
+            host: host.to_string(),
+            port: url.port_or_known_default(),
+            ca_cert: index.ca_cert.clone(),
+            client_cert: index.client_cert.clone(),
+            client_key: index.client_key.clone(),
+        })
+    }
+
+    /// Returns `true` if the [`Url`] refers to the index's host.
+    pub(crate) fn matches(&self, url: &Url) -> bool {
+        url.host_str() == Some(self.host.as_str()) && url.port_or_known_default() == self.port
+    }
+
+    /// Return the CA certificate bundle for the index, if any.
+    pub(crate) fn ca_cert(&self) -> Option<&Path> {
+        self.ca_cert.as_deref()
+    }
+
+    /// Return the client certificate for the index, if any.
+    pub(crate) fn client_cert(&self) -> Option<&Path> {
+        self.client_cert.as_deref()
+    }
+
+    /// Return the private key for the client certificate, if stored separately.
+    pub(crate) fn client_key(&self) -> Option<&Path> {
+        self.client_key.as_deref()
+    }
+
+    /// Read the index's CA certificate bundle and client certificate, if any, to verify that
+    /// they're valid.
+    ///
+    /// When building a client, invalid files are ignored with a warning; this allows callers to
+    /// surface them as errors instead.
+    pub fn validate(&self) -> Result<(), IndexTlsError> {
+        if let Some(ca_cert) = self.ca_cert.as_deref() {
+            read_certificates(ca_cert)
+                .map_err(|err| IndexTlsError::CaCert(ca_cert.to_path_buf(), err))?;
+        }
+        if let Some(client_cert) = self.client_cert.as_deref() {
+            read_client_identity(client_cert, self.client_key.as_deref())
+                .map_err(|err| IndexTlsError::ClientCert(client_cert.to_path_buf(), err))?;
+        }
+        Ok(())
+    }
+}
+
+/// An invalid TLS configuration for an index.
+#[derive(thiserror::Error, Debug)]
+pub enum IndexTlsError {
+    #[error("Invalid CA certificate bundle `{}`", _0.user_display())]
+    CaCert(PathBuf, #[source] CertificateError),
+    #[error("Invalid client certificate `{}`", _0.user_display())]
+    ClientCert(PathBuf, #[source] CertificateError),
 }
@@ -0,0 +1,28 @@
+#!/usr/bin/env sh
+# Generate the certificates used by the mutual TLS tests.
+#
+# Usage: ./generate.sh
+set -eu
+
+cd "$(dirname "$0")"
+tmp="$(mktemp -d)"
+trap 'rm -rf "$tmp"' EXIT
+
+# A certificate authority, which signs both the server and client certificates.
+openssl req -x509 -newkey rsa:2048 -nodes -keyout "$tmp/ca.key" -out ca.pem -days 36500 \
+    -subj "/CN=uv test CA" \
+    -addext "basicConstraints=critical,CA:TRUE" \
+    -addext "keyUsage=critical,keyCertSign,cRLSign"
+
+# A server certificate for `127.0.0.1`.
+openssl req -newkey rsa:2048 -nodes -keyout server.key -out "$tmp/server.csr" -subj "/CN=127.0.0.1"
+printf "subjectAltName=IP:127.0.0.1\nbasicConstraints=CA:FALSE\nextendedKeyUsage=serverAuth\n" > "$tmp/server.ext"
+openssl x509 -req -in "$tmp/server.csr" -CA ca.pem -CAkey "$tmp/ca.key" -CAcreateserial \
+    -CAserial "$tmp/ca.srl" -out server.pem -days 36500 -extfile "$tmp/server.ext"
+
+# A client certificate, in both PEM and PKCS#12 (with the password `uv-test`) formats.
+openssl req -newkey rsa:2048 -nodes -keyout client.key -out "$tmp/client.csr" -subj "/CN=uv test client"
+printf "basicConstraints=CA:FALSE\nextendedKeyUsage=clientAuth\n" > "$tmp/client.ext"
+openssl x509 -req -in "$tmp/client.csr" -CA ca.pem -CAkey "$tmp/ca.key" -CAcreateserial \
+    -CAserial "$tmp/ca.srl" -out client.pem -days 36500 -extfile "$tmp/client.ext"
+openssl pkcs12 -export -in client.pem -inkey client.key -out client.p12 -passout pass:uv-test
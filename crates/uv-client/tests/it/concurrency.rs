@@ -0,0 +1,87 @@
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Result;
+use futures::StreamExt;
+use http::StatusCode;
+use http_body_util::Full;
+use hyper::body::Bytes;
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Request, Response};
+use hyper_util::rt::TokioIo;
+use tokio::net::TcpListener;
+use url::Url;
+
+use uv_cache::Cache;
+use uv_client::RegistryClientBuilder;
+use uv_distribution_types::{IndexCapabilities, IndexUrl};
+use uv_normalize::PackageName;
+
+/// Spawn a server that answers every request with `404 Not Found` after a short delay. Returns
+/// the server address and the highest number of requests that were in flight at the same time.
+async fn slow_server() -> Result<(Url, Arc<AtomicUsize>)> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+    let in_flight = Arc::new(AtomicUsize::new(0));
+    let max_in_flight = Arc::new(AtomicUsize::new(0));
+
+    let max = max_in_flight.clone();
+    tokio::spawn(async move {
+        loop {
+            let (socket, _) = listener.accept().await.unwrap();
+            let in_flight = in_flight.clone();
+            let max = max.clone();
+            let svc = service_fn(move |_req: Request<hyper::body::Incoming>| {
+                let in_flight = in_flight.clone();
+                let max = max.clone();
+                async move {
+                    let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                    max.fetch_max(current, Ordering::SeqCst);
+                    tokio::time::sleep(Duration::from_millis(100)).await;
+                    in_flight.fetch_sub(1, Ordering::SeqCst);
+                    let mut response = Response::new(Full::new(Bytes::new()));
+                    *response.status_mut() = StatusCode::NOT_FOUND;
+                    Ok::<_, hyper::Error>(response)
+                }
+            });
+            tokio::task::spawn(async move {
+                http1::Builder::new()
+                    .serve_connection(TokioIo::new(socket), svc)
+                    .await
+                    .ok();
+            });
+        }
+    });
+
+    Ok((Url::parse(&format!("http://{addr}/simple"))?, max_in_flight))
+}
+
+#[tokio::test]
+async fn simple_requests_per_host() -> Result<()> {
+    let (url, max_in_flight) = slow_server().await?;
+
+    let cache = Cache::temp()?.init()?;
+    let client = RegistryClientBuilder::new(cache)
+        .concurrent_requests_per_host(2)
+        .build();
+    let index = IndexUrl::from_str(url.as_str())?;
+    let capabilities = IndexCapabilities::default();
+
+    // Query the index for many packages at once; the server never sees more than two requests at
+    // the same time.
+    let names = (0..10)
+        .map(|i| PackageName::from_str(&format!("package-{i}")))
+        .collect::<Result<Vec<_>, _>>()?;
+    futures::stream::iter(&names)
+        .map(|name| client.simple(name, Some(&index), &capabilities))
+        .buffer_unordered(10)
+        .collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(max_in_flight.load(Ordering::SeqCst), 2);
+
+    Ok(())
+}
@@ -1,2 +1,6 @@
+mod concurrency;
+mod proxy;
 mod remote_metadata;
+mod retry;
+mod tls;
 mod user_agent_version;
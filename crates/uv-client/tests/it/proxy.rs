@@ -0,0 +1,100 @@
+use std::net::SocketAddr;
+use std::str::FromStr;
+
+use anyhow::Result;
+use futures::future;
+use http_body_util::Full;
+use hyper::body::Bytes;
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Request, Response};
+use hyper_util::rt::TokioIo;
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+use url::Url;
+
+use uv_cache::Cache;
+use uv_client::{BaseClientBuilder, RegistryClientBuilder};
+use uv_distribution_types::{Index, IndexLocations};
+
+/// Spawn a proxy that echoes the requested URL for a single connection.
+async fn echo_proxy() -> Result<(SocketAddr, JoinHandle<()>)> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+    let server_task = tokio::spawn(async move {
+        let svc = service_fn(move |req: Request<hyper::body::Incoming>| {
+            let uri = req.uri().to_string();
+            future::ok::<_, hyper::Error>(Response::new(Full::new(Bytes::from(uri))))
+        });
+        let (socket, _) = listener.accept().await.unwrap();
+        http1::Builder::new()
+            .serve_connection(TokioIo::new(socket), svc)
+            .await
+            .expect("Server Started");
+    });
+    Ok((addr, server_task))
+}
+
+#[tokio::test]
+async fn index_proxy() -> Result<()> {
+    let (addr, server_task) = echo_proxy().await?;
+
+    // Route requests to the index through the proxy. The index host doesn't exist, so the
+    // request can only succeed if it's proxied.
+    let index = Index {
+        proxy: Some(Url::parse(&format!("http://{addr}"))?),
+        ..Index::from_str("http://pypi.internal.invalid/simple")?
+    };
+    let index_locations = IndexLocations::new(vec![index], vec![], false);
+
+    let cache = Cache::temp()?.init()?;
+    let client = RegistryClientBuilder::new(cache)
+        .index_urls(index_locations.index_urls())
+        .build();
+
+    let url = Url::parse("http://pypi.internal.invalid/simple/")?;
+    let res = client
+        .cached_client()
+        .uncached()
+        .for_host(&url)
+        .get(url.clone())
+        .send()
+        .await?;
+    assert!(res.status().is_success());
+
+    // Plain HTTP requests are forwarded to the proxy with the absolute URL.
+    assert_eq!(res.text().await?, url.as_str());
+
+    server_task.await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn index_no_proxy_is_scoped() -> Result<()> {
+    let (addr, server_task) = echo_proxy().await?;
+
+    // The `no-proxy` hosts of one index don't bypass the proxy of another.
+    let proxied = Index {
+        proxy: Some(Url::parse(&format!("http://{addr}"))?),
+        ..Index::from_str("http://pypi.internal.invalid/simple")?
+    };
+    let direct = Index {
+        no_proxy: vec!["pypi.internal.invalid".to_string()],
+        ..Index::from_str("http://other.internal.invalid/simple")?
+    };
+
+    // The proxies apply to any client, not only the registry client.
+    let client = BaseClientBuilder::new()
+        .indexes([&proxied, &direct])
+        .build();
+
+    let url = Url::parse("http://pypi.internal.invalid/simple/")?;
+    let res = client.for_host(&url).get(url.clone()).send().await?;
+    assert!(res.status().is_success());
+    assert_eq!(res.text().await?, url.as_str());
+
+    server_task.await?;
+
+    Ok(())
+}
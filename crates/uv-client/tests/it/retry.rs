@@ -0,0 +1,197 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
+
+use anyhow::Result;
+use futures::future;
+use http::StatusCode;
+use http_body_util::Full;
+use hyper::body::Bytes;
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Request, Response};
+use hyper_util::rt::TokioIo;
+use reqwest_retry::{RetryDecision, RetryPolicy};
+use tokio::net::TcpListener;
+use url::Url;
+
+use uv_client::BaseClientBuilder;
+use uv_configuration::Backoff;
+
+/// Spawn a server that responds with the given status and headers to the first `failures`
+/// requests, and with `200 OK` thereafter. Returns the server address and a counter of the
+/// requests received.
+async fn flaky_server(
+    status: StatusCode,
+    headers: &'static [(&'static str, &'static str)],
+    failures: usize,
+) -> Result<(Url, Arc<AtomicUsize>)> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+    let requests = Arc::new(AtomicUsize::new(0));
+
+    let counter = requests.clone();
+    tokio::spawn(async move {
+        loop {
+            let (socket, _) = listener.accept().await.unwrap();
+            let counter = counter.clone();
+            let svc = service_fn(move |_req: Request<hyper::body::Incoming>| {
+                let mut response = Response::new(Full::new(Bytes::new()));
+                if counter.fetch_add(1, Ordering::SeqCst) < failures {
+                    *response.status_mut() = status;
+                    for (name, value) in headers {
+                        response.headers_mut().insert(*name, value.parse().unwrap());
+                    }
+                }
+                future::ok::<_, hyper::Error>(response)
+            });
+            tokio::task::spawn(async move {
+                http1::Builder::new()
+                    .serve_connection(TokioIo::new(socket), svc)
+                    .await
+                    .ok();
+            });
+        }
+    });
+
+    Ok((Url::parse(&format!("http://{addr}"))?, requests))
+}
+
+#[test]
+fn constant_backoff() -> Result<()> {
+    let policy = BaseClientBuilder::new()
+        .retries(3)
+        .backoff(Backoff::Constant)
+        .retry_policy();
+
+    // Every retry waits one second, without growth or jitter.
+    let start = SystemTime::now();
+    for n_past_retries in 0..3 {
+        let RetryDecision::Retry { execute_after } = policy.should_retry(start, n_past_retries)
+        else {
+            panic!("Expected retry {n_past_retries} to be allowed");
+        };
+        let delay = execute_after.duration_since(start)?;
+        assert!(delay >= Duration::from_secs(1));
+        assert!(delay < Duration::from_millis(1100));
+    }
+    assert!(matches!(
+        policy.should_retry(start, 3),
+        RetryDecision::DoNotRetry
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn retry_on_configured_status() -> Result<()> {
+    let (url, requests) = flaky_server(StatusCode::IM_A_TEAPOT, &[], 1).await?;
+
+    // `418` isn't retried by default, but is retried when included in the configured set.
+    let client = BaseClientBuilder::new()
+        .retries(1)
+        .backoff(Backoff::Constant)
+        .retry_status(vec![StatusCode::IM_A_TEAPOT])
+        .build();
+    let response = client.for_host(&url).get(url.clone()).send().await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(requests.load(Ordering::SeqCst), 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn no_retry_outside_configured_status() -> Result<()> {
+    let (url, requests) = flaky_server(StatusCode::SERVICE_UNAVAILABLE, &[], 1).await?;
+
+    // `503` is retried by default, but not when excluded from the configured set.
+    let client = BaseClientBuilder::new()
+        .retries(1)
+        .retry_status(vec![StatusCode::TOO_MANY_REQUESTS])
+        .build();
+    let response = client.for_host(&url).get(url.clone()).send().await?;
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn retry_after() -> Result<()> {
+    let (url, requests) =
+        flaky_server(StatusCode::TOO_MANY_REQUESTS, &[("retry-after", "2")], 1).await?;
+
+    // The constant backoff waits one second; the server asks for two.
+    let client = BaseClientBuilder::new()
+        .retries(1)
+        .backoff(Backoff::Constant)
+        .build();
+    let start = Instant::now();
+    let response = client.for_host(&url).get(url.clone()).send().await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(requests.load(Ordering::SeqCst), 2);
+    assert!(start.elapsed() >= Duration::from_secs(2));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn rate_limit_reset() -> Result<()> {
+    let (url, requests) = flaky_server(
+        StatusCode::TOO_MANY_REQUESTS,
+        &[("x-ratelimit-remaining", "0"), ("x-ratelimit-reset", "2")],
+        1,
+    )
+    .await?;
+
+    let client = BaseClientBuilder::new()
+        .retries(1)
+        .backoff(Backoff::Constant)
+        .build();
+    let start = Instant::now();
+    let response = client.for_host(&url).get(url.clone()).send().await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(requests.load(Ordering::SeqCst), 2);
+    assert!(start.elapsed() >= Duration::from_secs(2));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn rate_limit_retry_budget() -> Result<()> {
+    let (url, requests) = flaky_server(
+        StatusCode::TOO_MANY_REQUESTS,
+        &[("retry-after", "0")],
+        usize::MAX,
+    )
+    .await?;
+
+    // Rate-limited responses are only resent by the rate limiter, not again by the retry
+    // middleware, so the request is sent once plus once per retry.
+    let client = BaseClientBuilder::new()
+        .retries(2)
+        .backoff(Backoff::Constant)
+        .build();
+    let response = client.for_host(&url).get(url.clone()).send().await?;
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(requests.load(Ordering::SeqCst), 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn rate_limit_exceeds_maximum_wait() -> Result<()> {
+    let (url, requests) =
+        flaky_server(StatusCode::TOO_MANY_REQUESTS, &[("retry-after", "3600")], 1).await?;
+
+    // A rate limit that resets too far in the future fails immediately.
+    let client = BaseClientBuilder::new()
+        .retries(2)
+        .backoff(Backoff::Constant)
+        .build();
+    let response = client.for_host(&url).get(url.clone()).send().await?;
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+    Ok(())
+}
@@ -0,0 +1,134 @@
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use anyhow::Result;
+use futures::future;
+use http_body_util::Full;
+use hyper::body::Bytes;
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::Response;
+use hyper_util::rt::TokioIo;
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use rustls::server::WebPkiClientVerifier;
+use rustls::{RootCertStore, ServerConfig};
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+use tokio_rustls::TlsAcceptor;
+use url::Url;
+
+use uv_client::BaseClientBuilder;
+use uv_distribution_types::Index;
+use uv_static::EnvVars;
+
+/// Return the path to a TLS fixture, as generated by `tests/fixtures/tls/generate.sh`.
+fn fixture(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join("tls")
+        .join(name)
+}
+
+/// Spawn an HTTPS server that requires clients to present a certificate signed by the test CA.
+async fn mtls_server() -> Result<(Url, JoinHandle<()>)> {
+    let provider = Arc::new(rustls::crypto::ring::default_provider());
+
+    let mut roots = RootCertStore::empty();
+    roots.add(CertificateDer::from_pem_file(fixture("ca.pem"))?)?;
+    let verifier =
+        WebPkiClientVerifier::builder_with_provider(Arc::new(roots), provider.clone()).build()?;
+
+    let config = ServerConfig::builder_with_provider(provider)
+        .with_safe_default_protocol_versions()?
+        .with_client_cert_verifier(verifier)
+        .with_single_cert(
+            vec![CertificateDer::from_pem_file(fixture("server.pem"))?],
+            PrivateKeyDer::from_pem_file(fixture("server.key"))?,
+        )?;
+    let acceptor = TlsAcceptor::from(Arc::new(config));
+
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let url = Url::parse(&format!("https://{}/simple/", listener.local_addr()?))?;
+    let server_task = tokio::spawn(async move {
+        while let Ok((socket, _)) = listener.accept().await {
+            let acceptor = acceptor.clone();
+            tokio::spawn(async move {
+                // Clients without a valid certificate fail the handshake.
+                let Ok(stream) = acceptor.accept(socket).await else {
+                    return;
+                };
+                let svc = service_fn(|_| {
+                    future::ok::<_, hyper::Error>(Response::new(Full::new(Bytes::from("ok"))))
+                });
+                let _ = http1::Builder::new()
+                    .serve_connection(TokioIo::new(stream), svc)
+                    .await;
+            });
+        }
+    });
+
+    Ok((url, server_task))
+}
+
+/// Send a request to the server, using the TLS settings of the given index.
+async fn request(url: &Url, index: &Index) -> Result<String> {
+    let client = BaseClientBuilder::new().retries(0).indexes([index]).build();
+    let response = client
+        .for_host(url)
+        .get(url.clone())
+        .send()
+        .await?
+        .error_for_status()?;
+    Ok(response.text().await?)
+}
+
+#[tokio::test]
+async fn mtls_pem() -> Result<()> {
+    let (url, server_task) = mtls_server().await?;
+
+    let index = Index {
+        ca_cert: Some(fixture("ca.pem")),
+        client_cert: Some(fixture("client.pem")),
+        client_key: Some(fixture("client.key")),
+        ..Index::from_str(url.as_str())?
+    };
+    assert_eq!(request(&url, &index).await?, "ok");
+
+    server_task.abort();
+    Ok(())
+}
+
+#[tokio::test]
+async fn mtls_pkcs12() -> Result<()> {
+    let (url, server_task) = mtls_server().await?;
+
+    // The PKCS#12 archive is encrypted with the password `uv-test`.
+    std::env::set_var(EnvVars::UV_CLIENT_CERT_PASSWORD, "uv-test");
+    let index = Index {
+        ca_cert: Some(fixture("ca.pem")),
+        client_cert: Some(fixture("client.p12")),
+        ..Index::from_str(url.as_str())?
+    };
+    assert_eq!(request(&url, &index).await?, "ok");
+
+    server_task.abort();
+    Ok(())
+}
+
+#[tokio::test]
+async fn mtls_missing_client_cert() -> Result<()> {
+    let (url, server_task) = mtls_server().await?;
+
+    // The server rejects clients that don't present a certificate.
+    let index = Index {
+        ca_cert: Some(fixture("ca.pem")),
+        ..Index::from_str(url.as_str())?
+    };
+    assert!(request(&url, &index).await.is_err());
+
+    server_task.abort();
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use uv_normalize::PackageName;
+
+/// A container image in which to build a specific package, e.g., `numpy=python:3.12`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuildContainerEntry {
+    /// The package to build in the container.
+    pub package: PackageName,
+    /// The image from which to create the container.
+    pub image: String,
+}
+
+impl FromStr for BuildContainerEntry {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((package, image)) = s.split_once('=') else {
+            return Err(format!(
+                "Invalid build container: {s} (expected `PACKAGE=IMAGE`)"
+            ));
+        };
+        let package = PackageName::from_str(package.trim()).map_err(|err| err.to_string())?;
+        let image = image.trim();
+        if image.is_empty() {
+            return Err(format!(
+                "Invalid build container: {s} (expected `PACKAGE=IMAGE`)"
+            ));
+        }
+        Ok(Self {
+            package,
+            image: image.to_string(),
+        })
+    }
+}
+
+/// The container images in which to build source distributions, if any.
+///
+/// When an image applies to a package, its wheels are built inside a container created from that
+/// image, rather than in a build environment on the host.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct BuildContainers {
+    /// The image to use for all packages without a package-specific image.
+    default: Option<String>,
+    /// The images to use for specific packages.
+    packages: BTreeMap<PackageName, String>,
+}
+
+impl BuildContainers {
+    /// Create a new [`BuildContainers`] from a default image and a set of per-package images.
+    pub fn new(
+        default: Option<String>,
+        packages: impl IntoIterator<Item = (PackageName, String)>,
+    ) -> Self {
+        Self {
+            default,
+            packages: packages.into_iter().collect(),
+        }
+    }
+
+    /// Returns the image in which to build the given package, if any.
+    ///
+    /// Package-specific images take precedence over the default image.
+    pub fn image(&self, package: Option<&PackageName>) -> Option<&str> {
+        package
+            .and_then(|package| self.packages.get(package))
+            .or(self.default.as_ref())
+            .map(String::as_str)
+    }
+
+    /// Returns `true` if no images are configured.
+    pub fn is_empty(&self) -> bool {
+        self.default.is_none() && self.packages.is_empty()
+    }
+}
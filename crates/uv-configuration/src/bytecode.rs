@@ -0,0 +1,75 @@
+use std::num::NonZeroUsize;
+
+use uv_normalize::PackageName;
+
+/// The optimization level to use when compiling Python source files to bytecode.
+///
+/// Mirrors the `-O` and `-OO` flags of the Python interpreter.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
+)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum BytecodeOptimization {
+    /// Compile without optimizations, retaining `assert` statements and docstrings.
+    #[default]
+    #[serde(rename = "0")]
+    #[cfg_attr(feature = "clap", value(name = "0"))]
+    Unoptimized,
+    /// Compile with `-O`, removing `assert` statements and code that depends on `__debug__`.
+    #[serde(rename = "1")]
+    #[cfg_attr(feature = "clap", value(name = "1"))]
+    RemoveAsserts,
+    /// Compile with `-OO`, additionally removing docstrings.
+    #[serde(rename = "2")]
+    #[cfg_attr(feature = "clap", value(name = "2"))]
+    RemoveDocstrings,
+}
+
+impl BytecodeOptimization {
+    /// Return the optimization level, as accepted by `compileall.compile_file`.
+    pub fn level(self) -> u8 {
+        match self {
+            Self::Unoptimized => 0,
+            Self::RemoveAsserts => 1,
+            Self::RemoveDocstrings => 2,
+        }
+    }
+}
+
+/// The options to use when compiling installed Python source files to bytecode.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct BytecodeOptions {
+    optimization: BytecodeOptimization,
+    workers: Option<NonZeroUsize>,
+    no_compile_package: Vec<PackageName>,
+}
+
+impl BytecodeOptions {
+    pub fn new(
+        optimization: BytecodeOptimization,
+        workers: Option<NonZeroUsize>,
+        no_compile_package: Vec<PackageName>,
+    ) -> Self {
+        Self {
+            optimization,
+            workers,
+            no_compile_package,
+        }
+    }
+
+    /// The optimization level to compile with.
+    pub fn optimization(&self) -> BytecodeOptimization {
+        self.optimization
+    }
+
+    /// The number of compilation workers to use, if not the number of available cores.
+    pub fn workers(&self) -> Option<NonZeroUsize> {
+        self.workers
+    }
+
+    /// The packages whose source files should be excluded from compilation.
+    pub fn no_compile_package(&self) -> &[PackageName] {
+        &self.no_compile_package
+    }
+}
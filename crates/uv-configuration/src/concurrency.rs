@@ -7,6 +7,10 @@ pub struct Concurrency {
     ///
     /// Note this value must be non-zero.
     pub downloads: usize,
+    /// The maximum number of concurrent downloads from any single host, if limited.
+    ///
+    /// Note this value must be non-zero.
+    pub downloads_per_host: Option<usize>,
     /// The maximum number of concurrent builds.
     ///
     /// Note this value must be non-zero.
@@ -21,6 +25,7 @@ impl Default for Concurrency {
     fn default() -> Self {
         Concurrency {
             downloads: Concurrency::DEFAULT_DOWNLOADS,
+            downloads_per_host: None,
             builds: Concurrency::threads(),
             installs: Concurrency::threads(),
         }
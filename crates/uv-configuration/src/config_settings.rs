@@ -119,6 +119,19 @@ impl ConfigSettings {
         self.0.is_empty()
     }
 
+    /// Iterate over the settings as `(key, value)` pairs, with an entry for each value in a list.
+    pub fn entries(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.iter().flat_map(|(key, value)| {
+            let values = match value {
+                ConfigSettingValue::String(value) => std::slice::from_ref(value),
+                ConfigSettingValue::List(values) => values.as_slice(),
+            };
+            values
+                .iter()
+                .map(move |value| (key.as_str(), value.as_str()))
+        })
+    }
+
     /// Convert the settings to a string that can be passed directly to a PEP 517 build backend.
     pub fn escape_for_python(&self) -> String {
         serde_json::to_string(self).expect("Failed to serialize config settings")
@@ -218,6 +218,49 @@ impl DevGroupsSpecification {
         Self { dev, groups }
     }
 
+    /// Return a new [`DevGroupsSpecification`] that additionally includes the given groups.
+    ///
+    /// Groups that were explicitly excluded (e.g., via `--no-group`) remain excluded.
+    #[must_use]
+    pub fn with_groups(self, groups: impl IntoIterator<Item = GroupName>) -> Self {
+        let groups = groups.into_iter().collect::<Vec<_>>();
+        if groups.is_empty() {
+            return self;
+        }
+        let groups = match self.groups {
+            None => GroupsSpecification::Include {
+                include: groups,
+                exclude: Vec::new(),
+            },
+            Some(GroupsSpecification::Include {
+                mut include,
+                exclude,
+            }) => {
+                for group in groups {
+                    if !include.contains(&group) && !exclude.contains(&group) {
+                        include.push(group);
+                    }
+                }
+                GroupsSpecification::Include { include, exclude }
+            }
+            Some(GroupsSpecification::Only {
+                mut include,
+                exclude,
+            }) => {
+                for group in groups {
+                    if !include.contains(&group) && !exclude.contains(&group) {
+                        include.push(group);
+                    }
+                }
+                GroupsSpecification::Only { include, exclude }
+            }
+        };
+        Self {
+            dev: self.dev,
+            groups: Some(groups),
+        }
+    }
+
     /// Return a new [`DevGroupsSpecification`] with development dependencies included by default.
     ///
     /// This is appropriate in projects, where the `dev` group is synced by default.
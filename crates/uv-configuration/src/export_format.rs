@@ -7,4 +7,8 @@ pub enum ExportFormat {
     /// Export in `requirements.txt` format.
     #[default]
     RequirementsTxt,
+    /// Export as an executable Python zip application, including the project and its dependencies.
+    Zipapp,
+    /// Export as an executable PEX file, including the project and its dependencies.
+    Pex,
 }
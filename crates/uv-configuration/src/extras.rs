@@ -32,4 +32,21 @@ impl ExtrasSpecification {
     pub fn is_empty(&self) -> bool {
         matches!(self, ExtrasSpecification::None)
     }
+
+    /// Return a new [`ExtrasSpecification`] that additionally includes the given extras.
+    #[must_use]
+    pub fn with_extras(self, extras: impl IntoIterator<Item = ExtraName>) -> Self {
+        match self {
+            ExtrasSpecification::All => ExtrasSpecification::All,
+            ExtrasSpecification::None => Self::from_args(false, extras.into_iter().collect()),
+            ExtrasSpecification::Some(mut existing) => {
+                for extra in extras {
+                    if !existing.contains(&extra) {
+                        existing.push(extra);
+                    }
+                }
+                ExtrasSpecification::Some(existing)
+            }
+        }
+    }
 }
@@ -0,0 +1,27 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// The strategy used to wait between retries of a failed request.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum Backoff {
+    /// Double the delay after each failed attempt.
+    #[default]
+    Exponential,
+    /// Wait a fixed delay between attempts.
+    Constant,
+}
+
+impl FromStr for Backoff {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "exponential" => Ok(Self::Exponential),
+            "constant" => Ok(Self::Constant),
+            _ => Err(format!("expected `exponential` or `constant`, got \"{s}\"")),
+        }
+    }
+}
@@ -1,6 +1,8 @@
 pub use authentication::*;
 pub use bounds::*;
+pub use build_container::*;
 pub use build_options::*;
+pub use bytecode::*;
 pub use concurrency::*;
 pub use config_settings::*;
 pub use constraints::*;
@@ -9,6 +11,7 @@ pub use editable::*;
 pub use export_format::*;
 pub use extras::*;
 pub use hash::*;
+pub use http_retry::*;
 pub use install_options::*;
 pub use name_specifiers::*;
 pub use overrides::*;
@@ -23,7 +26,9 @@ pub use vcs::*;
 
 mod authentication;
 mod bounds;
+mod build_container;
 mod build_options;
+mod bytecode;
 mod concurrency;
 mod config_settings;
 mod constraints;
@@ -32,6 +37,7 @@ mod editable;
 mod export_format;
 mod extras;
 mod hash;
+mod http_retry;
 mod install_options;
 mod name_specifiers;
 mod overrides;
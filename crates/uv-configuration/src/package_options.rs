@@ -161,6 +161,50 @@ impl Upgrade {
     }
 }
 
+/// Whether to advance Git sources that track a branch or tag to their latest commit.
+///
+/// Unlike [`Upgrade`], Git upgrades leave the locked versions of all other packages (e.g.,
+/// packages from a registry) untouched.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub enum GitUpgrade {
+    /// Respect the locked commits of all Git sources.
+    #[default]
+    None,
+
+    /// Advance all Git sources that track a branch or tag.
+    All,
+
+    /// Advance the Git sources that track a branch or tag, but only for the specified packages.
+    Packages(Vec<PackageName>),
+}
+
+impl GitUpgrade {
+    /// Determine the [`GitUpgrade`] strategy from the command-line arguments.
+    pub fn from_args(upgrade_git: bool, upgrade_git_package: Vec<PackageName>) -> Self {
+        if upgrade_git {
+            Self::All
+        } else if upgrade_git_package.is_empty() {
+            Self::None
+        } else {
+            Self::Packages(upgrade_git_package)
+        }
+    }
+
+    /// Returns `true` if no Git sources should be advanced.
+    pub fn is_none(&self) -> bool {
+        matches!(self, Self::None)
+    }
+
+    /// Returns `true` if the Git source for the specified package should be advanced.
+    pub fn contains(&self, package_name: &PackageName) -> bool {
+        match &self {
+            Self::None => false,
+            Self::All => true,
+            Self::Packages(packages) => packages.contains(package_name),
+        }
+    }
+}
+
 /// Create a [`Refresh`] policy by integrating the [`Upgrade`] policy.
 impl From<Upgrade> for Refresh {
     fn from(value: Upgrade) -> Self {
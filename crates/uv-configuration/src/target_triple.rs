@@ -100,6 +100,29 @@ pub enum TargetTriple {
 }
 
 impl TargetTriple {
+    /// Return the name of the target, as accepted by `--python-platform`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Windows => "windows",
+            Self::Linux => "linux",
+            Self::Macos => "macos",
+            Self::X8664PcWindowsMsvc => "x86_64-pc-windows-msvc",
+            Self::I686PcWindowsMsvc => "i686-pc-windows-msvc",
+            Self::X8664UnknownLinuxGnu => "x86_64-unknown-linux-gnu",
+            Self::Aarch64AppleDarwin => "aarch64-apple-darwin",
+            Self::X8664AppleDarwin => "x86_64-apple-darwin",
+            Self::Aarch64UnknownLinuxGnu => "aarch64-unknown-linux-gnu",
+            Self::Aarch64UnknownLinuxMusl => "aarch64-unknown-linux-musl",
+            Self::X8664UnknownLinuxMusl => "x86_64-unknown-linux-musl",
+            Self::X8664Manylinux217 => "x86_64-manylinux_2_17",
+            Self::X8664Manylinux228 => "x86_64-manylinux_2_28",
+            Self::X8664Manylinux231 => "x86_64-manylinux_2_31",
+            Self::Aarch64Manylinux217 => "aarch64-manylinux_2_17",
+            Self::Aarch64Manylinux228 => "aarch64-manylinux_2_28",
+            Self::Aarch64Manylinux231 => "aarch64-manylinux_2_31",
+        }
+    }
+
     /// Return the [`Platform`] for the target.
     pub fn platform(self) -> Platform {
         match self {
@@ -320,6 +343,12 @@ impl TargetTriple {
     }
 }
 
+impl std::fmt::Display for TargetTriple {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Return the macOS deployment target as parsed from the environment.
 fn macos_deployment_target() -> Option<(u16, u16)> {
     let version = std::env::var(EnvVars::MACOSX_DEPLOYMENT_TARGET).ok()?;
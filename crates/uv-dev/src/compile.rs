@@ -1,9 +1,11 @@
 use std::path::PathBuf;
 
 use clap::Parser;
+use rustc_hash::FxHashSet;
 use tracing::info;
 
 use uv_cache::{Cache, CacheArgs};
+use uv_configuration::BytecodeOptimization;
 use uv_python::{EnvironmentPreference, PythonEnvironment, PythonRequest};
 
 #[derive(Parser)]
@@ -34,6 +36,9 @@ pub(crate) async fn compile(args: CompileArgs) -> anyhow::Result<()> {
         &fs_err::canonicalize(args.root)?,
         &interpreter,
         cache.root(),
+        BytecodeOptimization::default(),
+        None,
+        &FxHashSet::default(),
     )
     .await?;
     info!("Compiled {files} files");
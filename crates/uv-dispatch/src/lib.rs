@@ -15,8 +15,8 @@ use uv_build_frontend::{SourceBuild, SourceBuildContext};
 use uv_cache::Cache;
 use uv_client::RegistryClient;
 use uv_configuration::{
-    BuildKind, BuildOptions, ConfigSettings, Constraints, IndexStrategy, LowerBound, Reinstall,
-    SourceStrategy,
+    BuildContainers, BuildKind, BuildOptions, ConfigSettings, Constraints, IndexStrategy,
+    LowerBound, Reinstall, SourceStrategy,
 };
 use uv_configuration::{BuildOutput, Concurrency};
 use uv_distribution::DistributionDatabase;
@@ -25,6 +25,7 @@ use uv_distribution_types::{
     SourceDist, VersionOrUrlRef,
 };
 use uv_git::GitResolver;
+use uv_hg::HgResolver;
 use uv_installer::{Installer, Plan, Planner, Preparer, SitePackages};
 use uv_pypi_types::Requirement;
 use uv_python::{Interpreter, PythonEnvironment};
@@ -46,6 +47,7 @@ pub struct BuildDispatch<'a> {
     flat_index: &'a FlatIndex,
     index: &'a InMemoryIndex,
     git: &'a GitResolver,
+    hg: &'a HgResolver,
     capabilities: &'a IndexCapabilities,
     dependency_metadata: &'a DependencyMetadata,
     in_flight: &'a InFlight,
@@ -53,12 +55,14 @@ pub struct BuildDispatch<'a> {
     link_mode: uv_install_wheel::linker::LinkMode,
     build_options: &'a BuildOptions,
     config_settings: &'a ConfigSettings,
+    build_containers: &'a BuildContainers,
     hasher: &'a HashStrategy,
     exclude_newer: Option<ExcludeNewer>,
     source_build_context: SourceBuildContext,
     build_extra_env_vars: FxHashMap<OsString, OsString>,
     bounds: LowerBound,
     sources: SourceStrategy,
+    variants: &'a [String],
     concurrency: Concurrency,
 }
 
@@ -73,10 +77,12 @@ impl<'a> BuildDispatch<'a> {
         dependency_metadata: &'a DependencyMetadata,
         index: &'a InMemoryIndex,
         git: &'a GitResolver,
+        hg: &'a HgResolver,
         capabilities: &'a IndexCapabilities,
         in_flight: &'a InFlight,
         index_strategy: IndexStrategy,
         config_settings: &'a ConfigSettings,
+        build_containers: &'a BuildContainers,
         build_isolation: BuildIsolation<'a>,
         link_mode: uv_install_wheel::linker::LinkMode,
         build_options: &'a BuildOptions,
@@ -95,24 +101,34 @@ impl<'a> BuildDispatch<'a> {
             flat_index,
             index,
             git,
+            hg,
             capabilities,
             dependency_metadata,
             in_flight,
             index_strategy,
             config_settings,
+            build_containers,
             build_isolation,
             link_mode,
             build_options,
             hasher,
             exclude_newer,
-            source_build_context: SourceBuildContext::default(),
+            source_build_context: SourceBuildContext::new(concurrency.builds),
             build_extra_env_vars: FxHashMap::default(),
             bounds,
             sources,
+            variants: &[],
             concurrency,
         }
     }
 
+    /// Set the variants to use when selecting among the sources in `tool.uv.sources`.
+    #[must_use]
+    pub fn with_variants(mut self, variants: &'a [String]) -> Self {
+        self.variants = variants;
+        self
+    }
+
     /// Set the environment variables to be used when building a source distribution.
     #[must_use]
     pub fn with_build_extra_env_vars<I, K, V>(mut self, sdist_build_env_variables: I) -> Self
@@ -140,6 +156,10 @@ impl<'a> BuildContext for BuildDispatch<'a> {
         self.git
     }
 
+    fn hg(&self) -> &HgResolver {
+        self.hg
+    }
+
     fn capabilities(&self) -> &IndexCapabilities {
         self.capabilities
     }
@@ -156,6 +176,10 @@ impl<'a> BuildContext for BuildDispatch<'a> {
         self.config_settings
     }
 
+    fn build_containers(&self) -> &BuildContainers {
+        self.build_containers
+    }
+
     fn bounds(&self) -> LowerBound {
         self.bounds
     }
@@ -164,6 +188,10 @@ impl<'a> BuildContext for BuildDispatch<'a> {
         self.sources
     }
 
+    fn variants(&self) -> &[String] {
+        self.variants
+    }
+
     fn locations(&self) -> &IndexLocations {
         self.index_locations
     }
@@ -188,7 +216,7 @@ impl<'a> BuildContext for BuildDispatch<'a> {
             self.hasher,
             self,
             EmptyInstalledPackages,
-            DistributionDatabase::new(self.client, self, self.concurrency.downloads),
+            DistributionDatabase::new(self.client, self, self.concurrency),
         )?;
         let graph = resolver.resolve().await.with_context(|| {
             format!(
@@ -233,6 +261,7 @@ impl<'a> BuildContext for BuildDispatch<'a> {
             cached,
             remote,
             reinstalls,
+            reinstall_reasons: _,
             extraneous: _,
         } = Planner::new(resolution).build(
             site_packages,
@@ -262,7 +291,7 @@ impl<'a> BuildContext for BuildDispatch<'a> {
                 tags,
                 self.hasher,
                 self.build_options,
-                DistributionDatabase::new(self.client, self, self.concurrency.downloads),
+                DistributionDatabase::new(self.client, self, self.concurrency),
             );
 
             debug!(
@@ -363,11 +392,12 @@ impl<'a> BuildContext for BuildDispatch<'a> {
             self.index_locations,
             sources,
             self.config_settings.clone(),
+            &self.constraints,
+            self.client.connectivity().is_offline(),
             self.build_isolation,
             build_kind,
             self.build_extra_env_vars.clone(),
             build_output,
-            self.concurrency.builds,
         )
         .boxed_local()
         .await?;
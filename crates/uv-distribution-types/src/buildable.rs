@@ -4,12 +4,13 @@ use std::path::Path;
 use url::Url;
 use uv_distribution_filename::SourceDistExtension;
 use uv_git::GitUrl;
+use uv_hg::HgUrl;
 use uv_pep440::Version;
 use uv_pep508::VerbatimUrl;
 
 use uv_normalize::PackageName;
 
-use crate::{DirectorySourceDist, GitSourceDist, Name, PathSourceDist, SourceDist};
+use crate::{DirectorySourceDist, GitSourceDist, HgSourceDist, Name, PathSourceDist, SourceDist};
 
 /// A reference to a source that can be built into a built distribution.
 ///
@@ -80,6 +81,7 @@ impl std::fmt::Display for BuildableSource<'_> {
 pub enum SourceUrl<'a> {
     Direct(DirectSourceUrl<'a>),
     Git(GitSourceUrl<'a>),
+    Hg(HgSourceUrl<'a>),
     Path(PathSourceUrl<'a>),
     Directory(DirectorySourceUrl<'a>),
 }
@@ -90,6 +92,7 @@ impl<'a> SourceUrl<'a> {
         match self {
             Self::Direct(dist) => dist.url,
             Self::Git(dist) => dist.url,
+            Self::Hg(dist) => dist.url,
             Self::Path(dist) => dist.url,
             Self::Directory(dist) => dist.url,
         }
@@ -114,6 +117,7 @@ impl std::fmt::Display for SourceUrl<'_> {
         match self {
             Self::Direct(url) => write!(f, "{url}"),
             Self::Git(url) => write!(f, "{url}"),
+            Self::Hg(url) => write!(f, "{url}"),
             Self::Path(url) => write!(f, "{url}"),
             Self::Directory(url) => write!(f, "{url}"),
         }
@@ -158,6 +162,31 @@ impl<'a> From<&'a GitSourceDist> for GitSourceUrl<'a> {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct HgSourceUrl<'a> {
+    /// The URL with the revision and subdirectory fragment.
+    pub url: &'a VerbatimUrl,
+    pub hg: &'a HgUrl,
+    /// The subdirectory within the repository in which the source distribution is located.
+    pub subdirectory: Option<&'a Path>,
+}
+
+impl std::fmt::Display for HgSourceUrl<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{url}", url = self.url)
+    }
+}
+
+impl<'a> From<&'a HgSourceDist> for HgSourceUrl<'a> {
+    fn from(dist: &'a HgSourceDist) -> Self {
+        Self {
+            url: &dist.url,
+            hg: &dist.hg,
+            subdirectory: dist.subdirectory.as_deref(),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct PathSourceUrl<'a> {
     pub url: &'a Url,
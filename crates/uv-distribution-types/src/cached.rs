@@ -99,6 +99,15 @@ impl CachedDist {
                 editable: false,
                 r#virtual: false,
             }),
+            Dist::Source(SourceDist::Hg(dist)) => Self::Url(CachedDirectUrlDist {
+                filename,
+                url: dist.url,
+                hashes,
+                cache_info,
+                path,
+                editable: false,
+                r#virtual: false,
+            }),
             Dist::Source(SourceDist::Path(dist)) => Self::Url(CachedDirectUrlDist {
                 filename,
                 url: dist.url,
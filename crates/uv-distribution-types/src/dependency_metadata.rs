@@ -1,32 +1,54 @@
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use serde::{Deserialize, Serialize};
 use tracing::{debug, warn};
 use uv_normalize::{ExtraName, PackageName};
 use uv_pep440::{Version, VersionSpecifiers};
-use uv_pep508::Requirement;
+use uv_pep508::{MarkerTree, Requirement};
 use uv_pypi_types::{ResolutionMetadata, VerbatimParsedUrl};
 
-/// Pre-defined [`StaticMetadata`] entries, indexed by [`PackageName`] and [`Version`].
+/// Pre-defined [`StaticMetadata`] entries, indexed by [`PackageName`] and [`Version`], along with
+/// any [`DependencyOverride`] patches to apply to the metadata of individual packages.
 #[derive(Debug, Clone, Default)]
-pub struct DependencyMetadata(FxHashMap<PackageName, Vec<StaticMetadata>>);
+pub struct DependencyMetadata {
+    entries: FxHashMap<PackageName, Vec<StaticMetadata>>,
+    overrides: FxHashMap<PackageName, Vec<DependencyOverride>>,
+}
 
 impl DependencyMetadata {
     /// Index a set of [`StaticMetadata`] entries by [`PackageName`] and [`Version`].
     pub fn from_entries(entries: impl IntoIterator<Item = StaticMetadata>) -> Self {
         let mut map = Self::default();
         for entry in entries {
-            map.0.entry(entry.name.clone()).or_default().push(entry);
+            map.entries
+                .entry(entry.name.clone())
+                .or_default()
+                .push(entry);
         }
         map
     }
 
+    /// Add a set of [`DependencyOverride`] entries, indexed by [`PackageName`].
+    #[must_use]
+    pub fn with_overrides(
+        mut self,
+        overrides: impl IntoIterator<Item = DependencyOverride>,
+    ) -> Self {
+        for entry in overrides {
+            self.overrides
+                .entry(entry.name.clone())
+                .or_default()
+                .push(entry);
+        }
+        self
+    }
+
     /// Retrieve a [`StaticMetadata`] entry by [`PackageName`] and [`Version`].
     pub fn get(
         &self,
         package: &PackageName,
         version: Option<&Version>,
     ) -> Option<ResolutionMetadata> {
-        let versions = self.0.get(package)?;
+        let versions = self.entries.get(package)?;
 
         if let Some(version) = version {
             // If a specific version was requested, search for an exact match, then a global match.
@@ -76,7 +98,70 @@ impl DependencyMetadata {
 
     /// Retrieve all [`StaticMetadata`] entries.
     pub fn values(&self) -> impl Iterator<Item = &StaticMetadata> {
-        self.0.values().flatten()
+        self.entries.values().flatten()
+    }
+
+    /// Retrieve all [`DependencyOverride`] entries.
+    pub fn overrides(&self) -> impl Iterator<Item = &DependencyOverride> {
+        self.overrides.values().flatten()
+    }
+
+    /// Apply any matching [`DependencyOverride`] entries to the requirements declared by the given
+    /// package.
+    ///
+    /// Overrides that omit a version apply to every version of the package; if a version is
+    /// provided, the override only applies to that exact version.
+    pub fn apply_overrides(
+        &self,
+        package: &PackageName,
+        version: &Version,
+        requires_dist: &mut Vec<uv_pypi_types::Requirement>,
+    ) {
+        let Some(overrides) = self.overrides.get(package) else {
+            return;
+        };
+
+        for entry in overrides
+            .iter()
+            .filter(|entry| entry.version.as_ref().map_or(true, |v| v == version))
+        {
+            debug!("Applying dependency override to `{package}=={version}`");
+
+            // Drop any requirements on removed packages.
+            requires_dist.retain(|requirement| !entry.remove.contains(&requirement.name));
+
+            // Rewrite any requirements on replaced packages, preserving the `extra` under which
+            // the original requirement was declared.
+            for replacement in &entry.replace {
+                let replacement = uv_pypi_types::Requirement::from(replacement.clone());
+                let mut replaced = false;
+                for requirement in requires_dist.iter_mut() {
+                    if requirement.name != replacement.name {
+                        continue;
+                    }
+                    let marker = if let Some(extra) = requirement.marker.top_level_extra() {
+                        let mut marker = MarkerTree::expression(extra);
+                        marker.and(replacement.marker.clone());
+                        marker
+                    } else {
+                        replacement.marker.clone()
+                    };
+                    *requirement = uv_pypi_types::Requirement {
+                        marker,
+                        ..replacement.clone()
+                    };
+                    replaced = true;
+                }
+                if !replaced {
+                    requires_dist.push(replacement);
+                }
+            }
+        }
+
+        // Replacing multiple requirements for the same package (e.g., one per extra) with the same
+        // replacement can lead to duplicates, which need not be adjacent.
+        let mut seen = FxHashSet::default();
+        requires_dist.retain(|requirement| seen.insert(requirement.clone()));
     }
 }
 
@@ -110,3 +195,35 @@ pub struct StaticMetadata {
     #[serde(default)]
     pub provides_extras: Vec<ExtraName>,
 }
+
+/// A patch to the declared dependencies of a package (or a specific version of a package).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct DependencyOverride {
+    /// The name of the package whose dependencies should be patched.
+    pub name: PackageName,
+    /// The version of the package to patch. If omitted, the patch applies to all versions.
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "Option<String>",
+            description = "PEP 440-style package version, e.g., `1.2.3`"
+        )
+    )]
+    pub version: Option<Version>,
+    /// The dependencies to remove from the package's metadata, by name.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub remove: Vec<PackageName>,
+    /// The dependencies to rewrite in the package's metadata. Any existing requirements on the
+    /// same package are replaced; if none exist, the requirement is added.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "Vec<String>",
+            description = "PEP 508-style requirements, e.g., `werkzeug>=2.0`."
+        )
+    )]
+    pub replace: Vec<Requirement<VerbatimParsedUrl>>,
+}
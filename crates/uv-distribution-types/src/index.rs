@@ -1,9 +1,10 @@
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 use thiserror::Error;
 use url::Url;
 
-use uv_auth::Credentials;
+use uv_auth::{Credentials, Secret, SecretCredentials};
 
 use crate::index_name::{IndexName, IndexNameError};
 use crate::origin::Origin;
@@ -57,6 +58,107 @@ pub struct Index {
     /// is given the highest priority when resolving packages.
     #[serde(default)]
     pub default: bool,
+    /// The proxy to use for requests to the index.
+    ///
+    /// Requests to the index's host will be routed through the given proxy, rather than any
+    /// proxy configured via the `HTTP_PROXY`, `HTTPS_PROXY`, or `ALL_PROXY` environment variables.
+    /// Supports `http://`, `https://`, `socks5://`, and `socks5h://` proxies, as in:
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://pypi.internal.example.com/simple"
+    /// proxy = "http://proxy.internal.example.com:8080"
+    /// ```
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<Url>,
+    /// A list of hosts that should be accessed directly, bypassing any configured proxy.
+    ///
+    /// Accepts the same syntax as the `NO_PROXY` environment variable (e.g., `example.com`,
+    /// `.example.com`, or `192.168.0.0/16`). Useful when an index serves its distributions from a
+    /// host that isn't reachable through the proxy, as in:
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://pypi.internal.example.com/simple"
+    /// no-proxy = ["files.internal.example.com"]
+    /// ```
+    #[serde(default, rename = "no-proxy", skip_serializing_if = "Vec::is_empty")]
+    pub no_proxy: Vec<String>,
+    /// The path to a CA certificate bundle to use when verifying the index's TLS certificate.
+    ///
+    /// The bundle should contain one or more PEM-encoded certificates, and will be used _instead
+    /// of_ the system or bundled root certificates for requests to the index's host. Requests to
+    /// other hosts (e.g., PyPI) are unaffected, as in:
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://pypi.internal.example.com/simple"
+    /// ca-cert = "/etc/ssl/certs/internal-ca.pem"
+    /// ```
+    #[serde(default, rename = "ca-cert", skip_serializing_if = "Option::is_none")]
+    pub ca_cert: Option<PathBuf>,
+    /// The path to a client certificate to present to the index, for mutual TLS authentication.
+    ///
+    /// The file should contain a PEM-encoded certificate, optionally followed by its private key,
+    /// or be a PKCS#12 (`.p12` or `.pfx`) archive, decrypted with the password provided via
+    /// `UV_CLIENT_CERT_PASSWORD`. If the private key is stored separately, provide it via
+    /// `client-key`, as in:
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://pypi.internal.example.com/simple"
+    /// client-cert = "/etc/ssl/certs/client.pem"
+    /// client-key = "/etc/ssl/private/client.key"
+    /// ```
+    #[serde(
+        default,
+        rename = "client-cert",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub client_cert: Option<PathBuf>,
+    /// The path to the PEM-encoded private key for the `client-cert`, if not included in the
+    /// certificate file.
+    #[serde(
+        default,
+        rename = "client-key",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub client_key: Option<PathBuf>,
+    /// The username to use when authenticating with the index.
+    ///
+    /// Accepts either a literal value or a reference to a secret, in the same formats as
+    /// `password`.
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub username: Option<Secret>,
+    /// The password (or token) to use when authenticating with the index.
+    ///
+    /// Rather than committing the secret itself, the password can be provided as a reference
+    /// that's resolved when the index is first requested: `env:NAME` reads the `NAME`
+    /// environment variable, `file:PATH` reads the file at `PATH`, and `cmd:COMMAND` reads the
+    /// output of `COMMAND`, as in:
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://pypi.internal.example.com/simple"
+    /// username = "__token__"
+    /// password = "env:INTERNAL_PYPI_TOKEN"
+    /// ```
+    ///
+    /// `file:` and `cmd:` references are only accepted in user- and system-level configuration,
+    /// unless `UV_TRUST_PROJECT_SECRETS` is set.
+    ///
+    /// Credentials provided via the `UV_INDEX_{name}_USERNAME` and `UV_INDEX_{name}_PASSWORD`
+    /// environment variables take precedence.
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password: Option<Secret>,
     /// The origin of the index (e.g., a CLI flag, a user-level configuration file, etc.).
     #[serde(skip)]
     pub origin: Option<Origin>,
@@ -89,6 +191,13 @@ impl Index {
             name: None,
             explicit: false,
             default: true,
+            proxy: None,
+            no_proxy: Vec::new(),
+            ca_cert: None,
+            client_cert: None,
+            client_key: None,
+            username: None,
+            password: None,
             origin: None,
         }
     }
@@ -100,6 +209,13 @@ impl Index {
             name: None,
             explicit: false,
             default: false,
+            proxy: None,
+            no_proxy: Vec::new(),
+            ca_cert: None,
+            client_cert: None,
+            client_key: None,
+            username: None,
+            password: None,
             origin: None,
         }
     }
@@ -111,6 +227,13 @@ impl Index {
             name: None,
             explicit: false,
             default: false,
+            proxy: None,
+            no_proxy: Vec::new(),
+            ca_cert: None,
+            client_cert: None,
+            client_key: None,
+            username: None,
+            password: None,
             origin: None,
         }
     }
@@ -122,6 +245,25 @@ impl Index {
         self
     }
 
+    /// Resolve any relative certificate paths (i.e., `ca-cert`, `client-cert`, and `client-key`)
+    /// against the given directory, e.g., that of the configuration file declaring the index.
+    #[must_use]
+    pub fn relative_to(mut self, root_dir: &Path) -> Self {
+        for path in [
+            &mut self.ca_cert,
+            &mut self.client_cert,
+            &mut self.client_key,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            if path.is_relative() {
+                *path = root_dir.join(&*path);
+            }
+        }
+        self
+    }
+
     /// Return the [`IndexUrl`] of the index.
     pub fn url(&self) -> &IndexUrl {
         &self.url
@@ -146,9 +288,28 @@ impl Index {
             }
         }
 
+        // Credentials configured on the index are resolved at request time; see
+        // [`Index::secret_credentials`].
+        if self.username.is_some() || self.password.is_some() {
+            return None;
+        }
+
         // Otherwise, extract the credentials from the URL.
         Credentials::from_url(self.url.url())
     }
+
+    /// Retrieve the credentials configured on the index via `username` and `password`, to be
+    /// resolved at request time.
+    pub fn secret_credentials(&self) -> Option<SecretCredentials> {
+        if self.username.is_none() && self.password.is_none() {
+            return None;
+        }
+        Some(SecretCredentials::new(
+            self.url.url().clone(),
+            self.username.clone(),
+            self.password.clone(),
+        ))
+    }
 }
 
 impl FromStr for Index {
@@ -165,6 +326,13 @@ impl FromStr for Index {
                     url,
                     explicit: false,
                     default: false,
+                    proxy: None,
+                    no_proxy: Vec::new(),
+                    ca_cert: None,
+                    client_cert: None,
+                    client_key: None,
+                    username: None,
+                    password: None,
                     origin: None,
                 });
             }
@@ -177,6 +345,13 @@ impl FromStr for Index {
             url,
             explicit: false,
             default: false,
+            proxy: None,
+            no_proxy: Vec::new(),
+            ca_cert: None,
+            client_cert: None,
+            client_key: None,
+            username: None,
+            password: None,
             origin: None,
         })
     }
@@ -186,6 +186,8 @@ pub struct IndexLocations {
     indexes: Vec<Index>,
     flat_index: Vec<Index>,
     no_index: bool,
+    #[serde(default)]
+    find_links_recursive: bool,
 }
 
 impl IndexLocations {
@@ -195,9 +197,17 @@ impl IndexLocations {
             indexes,
             flat_index,
             no_index,
+            find_links_recursive: false,
         }
     }
 
+    /// Set whether `--find-links` directories should be searched recursively.
+    #[must_use]
+    pub fn with_find_links_recursive(mut self, find_links_recursive: bool) -> Self {
+        self.find_links_recursive = find_links_recursive;
+        self
+    }
+
     /// Combine a set of index locations.
     ///
     /// If either the current or the other index locations have `no_index` set, the result will
@@ -210,6 +220,7 @@ impl IndexLocations {
             indexes: self.indexes.into_iter().chain(indexes).collect(),
             flat_index: self.flat_index.into_iter().chain(flat_index).collect(),
             no_index: self.no_index || no_index,
+            find_links_recursive: self.find_links_recursive,
         }
     }
 
@@ -291,6 +302,11 @@ impl<'a> IndexLocations {
         self.no_index
     }
 
+    /// Return the `--find-links-recursive` flag.
+    pub fn find_links_recursive(&self) -> bool {
+        self.find_links_recursive
+    }
+
     /// Clone the index locations into a [`IndexUrls`] instance.
     pub fn index_urls(&'a self) -> IndexUrls {
         IndexUrls {
@@ -391,6 +407,12 @@ impl<'a> IndexUrls {
     pub fn indexes(&'a self) -> impl Iterator<Item = &'a Index> + 'a {
         self.implicit_indexes().chain(self.default_index())
     }
+
+    /// Return an iterator over all [`Index`] entries, including explicit indexes, which are only
+    /// used when requested by name.
+    pub fn all_indexes(&'a self) -> impl Iterator<Item = &'a Index> + 'a {
+        self.indexes.iter()
+    }
 }
 
 bitflags::bitflags! {
@@ -42,6 +42,7 @@ use url::Url;
 use uv_distribution_filename::{DistExtension, SourceDistExtension, WheelFilename};
 use uv_fs::normalize_absolute_path;
 use uv_git::GitUrl;
+use uv_hg::HgUrl;
 use uv_normalize::PackageName;
 use uv_pep440::Version;
 use uv_pep508::{Pep508Url, VerbatimUrl};
@@ -181,13 +182,14 @@ pub enum BuiltDist {
     Path(PathBuiltDist),
 }
 
-/// A source distribution, with its possible origins (index, url, path, git)
+/// A source distribution, with its possible origins (index, url, path, git, hg)
 #[derive(Debug, Clone, Hash)]
 #[allow(clippy::large_enum_variant)]
 pub enum SourceDist {
     Registry(RegistrySourceDist),
     DirectUrl(DirectUrlSourceDist),
     Git(GitSourceDist),
+    Hg(HgSourceDist),
     Path(PathSourceDist),
     Directory(DirectorySourceDist),
 }
@@ -299,6 +301,19 @@ pub struct GitSourceDist {
     pub url: VerbatimUrl,
 }
 
+/// A source distribution that exists in a Mercurial repository.
+#[derive(Debug, Clone, Hash)]
+pub struct HgSourceDist {
+    pub name: PackageName,
+    /// The URL without the revision and subdirectory fragment.
+    pub hg: Box<HgUrl>,
+    /// The subdirectory within the Mercurial repository in which the source distribution is
+    /// located.
+    pub subdirectory: Option<PathBuf>,
+    /// The URL as it was provided by the user, including the revision and subdirectory fragment.
+    pub url: VerbatimUrl,
+}
+
 /// A source distribution that exists in a local archive (e.g., a `.tar.gz` file).
 #[derive(Debug, Clone, Hash)]
 pub struct PathSourceDist {
@@ -454,6 +469,21 @@ impl Dist {
         })))
     }
 
+    /// A remote source distribution from a `hg+https://` or `hg+ssh://` url.
+    pub fn from_hg_url(
+        name: PackageName,
+        url: VerbatimUrl,
+        hg: HgUrl,
+        subdirectory: Option<PathBuf>,
+    ) -> Result<Dist, Error> {
+        Ok(Self::Source(SourceDist::Hg(HgSourceDist {
+            name,
+            hg: Box::new(hg),
+            subdirectory,
+            url,
+        })))
+    }
+
     /// Create a [`Dist`] for a URL-based distribution.
     pub fn from_url(name: PackageName, url: VerbatimParsedUrl) -> Result<Self, Error> {
         match url.parsed_url {
@@ -477,6 +507,7 @@ impl Dist {
             ParsedUrl::Git(git) => {
                 Self::from_git_url(name, url.verbatim, git.url, git.subdirectory)
             }
+            ParsedUrl::Hg(hg) => Self::from_hg_url(name, url.verbatim, hg.url, hg.subdirectory),
         }
     }
 
@@ -557,7 +588,11 @@ impl SourceDist {
     pub fn index(&self) -> Option<&IndexUrl> {
         match self {
             Self::Registry(registry) => Some(&registry.index),
-            Self::DirectUrl(_) | Self::Git(_) | Self::Path(_) | Self::Directory(_) => None,
+            Self::DirectUrl(_)
+            | Self::Git(_)
+            | Self::Hg(_)
+            | Self::Path(_)
+            | Self::Directory(_) => None,
         }
     }
 
@@ -565,14 +600,22 @@ impl SourceDist {
     pub fn file(&self) -> Option<&File> {
         match self {
             Self::Registry(registry) => Some(&registry.file),
-            Self::DirectUrl(_) | Self::Git(_) | Self::Path(_) | Self::Directory(_) => None,
+            Self::DirectUrl(_)
+            | Self::Git(_)
+            | Self::Hg(_)
+            | Self::Path(_)
+            | Self::Directory(_) => None,
         }
     }
 
     pub fn version(&self) -> Option<&Version> {
         match self {
             Self::Registry(source_dist) => Some(&source_dist.version),
-            Self::DirectUrl(_) | Self::Git(_) | Self::Path(_) | Self::Directory(_) => None,
+            Self::DirectUrl(_)
+            | Self::Git(_)
+            | Self::Hg(_)
+            | Self::Path(_)
+            | Self::Directory(_) => None,
         }
     }
 
@@ -648,6 +691,12 @@ impl Name for GitSourceDist {
     }
 }
 
+impl Name for HgSourceDist {
+    fn name(&self) -> &PackageName {
+        &self.name
+    }
+}
+
 impl Name for PathSourceDist {
     fn name(&self) -> &PackageName {
         &self.name
@@ -666,6 +715,7 @@ impl Name for SourceDist {
             Self::Registry(dist) => dist.name(),
             Self::DirectUrl(dist) => dist.name(),
             Self::Git(dist) => dist.name(),
+            Self::Hg(dist) => dist.name(),
             Self::Path(dist) => dist.name(),
             Self::Directory(dist) => dist.name(),
         }
@@ -733,6 +783,12 @@ impl DistributionMetadata for GitSourceDist {
     }
 }
 
+impl DistributionMetadata for HgSourceDist {
+    fn version_or_url(&self) -> VersionOrUrlRef {
+        VersionOrUrlRef::Url(&self.url)
+    }
+}
+
 impl DistributionMetadata for PathSourceDist {
     fn version_or_url(&self) -> VersionOrUrlRef {
         VersionOrUrlRef::Url(&self.url)
@@ -751,6 +807,7 @@ impl DistributionMetadata for SourceDist {
             Self::Registry(dist) => dist.version_or_url(),
             Self::DirectUrl(dist) => dist.version_or_url(),
             Self::Git(dist) => dist.version_or_url(),
+            Self::Hg(dist) => dist.version_or_url(),
             Self::Path(dist) => dist.version_or_url(),
             Self::Directory(dist) => dist.version_or_url(),
         }
@@ -903,6 +960,32 @@ impl RemoteSource for GitSourceDist {
     }
 }
 
+impl RemoteSource for HgSourceDist {
+    fn filename(&self) -> Result<Cow<'_, str>, Error> {
+        // The filename is the last segment of the URL, before any `@`.
+        match self.url.filename()? {
+            Cow::Borrowed(filename) => {
+                if let Some((_, filename)) = filename.rsplit_once('@') {
+                    Ok(Cow::Borrowed(filename))
+                } else {
+                    Ok(Cow::Borrowed(filename))
+                }
+            }
+            Cow::Owned(filename) => {
+                if let Some((_, filename)) = filename.rsplit_once('@') {
+                    Ok(Cow::Owned(filename.to_owned()))
+                } else {
+                    Ok(Cow::Owned(filename))
+                }
+            }
+        }
+    }
+
+    fn size(&self) -> Option<u64> {
+        self.url.size()
+    }
+}
+
 impl RemoteSource for PathBuiltDist {
     fn filename(&self) -> Result<Cow<'_, str>, Error> {
         self.url.filename()
@@ -939,6 +1022,7 @@ impl RemoteSource for SourceDist {
             Self::Registry(dist) => dist.filename(),
             Self::DirectUrl(dist) => dist.filename(),
             Self::Git(dist) => dist.filename(),
+            Self::Hg(dist) => dist.filename(),
             Self::Path(dist) => dist.filename(),
             Self::Directory(dist) => dist.filename(),
         }
@@ -949,6 +1033,7 @@ impl RemoteSource for SourceDist {
             Self::Registry(dist) => dist.size(),
             Self::DirectUrl(dist) => dist.size(),
             Self::Git(dist) => dist.size(),
+            Self::Hg(dist) => dist.size(),
             Self::Path(dist) => dist.size(),
             Self::Directory(dist) => dist.size(),
         }
@@ -1137,12 +1222,23 @@ impl Identifier for GitSourceDist {
     }
 }
 
+impl Identifier for HgSourceDist {
+    fn distribution_id(&self) -> DistributionId {
+        self.url.distribution_id()
+    }
+
+    fn resource_id(&self) -> ResourceId {
+        self.url.resource_id()
+    }
+}
+
 impl Identifier for SourceDist {
     fn distribution_id(&self) -> DistributionId {
         match self {
             Self::Registry(dist) => dist.distribution_id(),
             Self::DirectUrl(dist) => dist.distribution_id(),
             Self::Git(dist) => dist.distribution_id(),
+            Self::Hg(dist) => dist.distribution_id(),
             Self::Path(dist) => dist.distribution_id(),
             Self::Directory(dist) => dist.distribution_id(),
         }
@@ -1153,6 +1249,7 @@ impl Identifier for SourceDist {
             Self::Registry(dist) => dist.resource_id(),
             Self::DirectUrl(dist) => dist.resource_id(),
             Self::Git(dist) => dist.resource_id(),
+            Self::Hg(dist) => dist.resource_id(),
             Self::Path(dist) => dist.resource_id(),
             Self::Directory(dist) => dist.resource_id(),
         }
@@ -1223,6 +1320,16 @@ impl Identifier for GitSourceUrl<'_> {
     }
 }
 
+impl Identifier for HgSourceUrl<'_> {
+    fn distribution_id(&self) -> DistributionId {
+        self.url.distribution_id()
+    }
+
+    fn resource_id(&self) -> ResourceId {
+        self.url.resource_id()
+    }
+}
+
 impl Identifier for PathSourceUrl<'_> {
     fn distribution_id(&self) -> DistributionId {
         self.url.distribution_id()
@@ -1248,6 +1355,7 @@ impl Identifier for SourceUrl<'_> {
         match self {
             Self::Direct(url) => url.distribution_id(),
             Self::Git(url) => url.distribution_id(),
+            Self::Hg(url) => url.distribution_id(),
             Self::Path(url) => url.distribution_id(),
             Self::Directory(url) => url.distribution_id(),
         }
@@ -1257,6 +1365,7 @@ impl Identifier for SourceUrl<'_> {
         match self {
             Self::Direct(url) => url.resource_id(),
             Self::Git(url) => url.resource_id(),
+            Self::Hg(url) => url.resource_id(),
             Self::Path(url) => url.resource_id(),
             Self::Directory(url) => url.resource_id(),
         }
@@ -230,6 +230,13 @@ impl From<&ResolvedDist> for Requirement {
                     precise: sdist.git.precise(),
                     subdirectory: sdist.subdirectory.clone(),
                 },
+                Dist::Source(SourceDist::Hg(sdist)) => RequirementSource::Hg {
+                    url: sdist.url.clone(),
+                    repository: sdist.hg.repository().clone(),
+                    reference: sdist.hg.reference().clone(),
+                    precise: sdist.hg.precise(),
+                    subdirectory: sdist.subdirectory.clone(),
+                },
                 Dist::Source(SourceDist::Path(sdist)) => RequirementSource::Path {
                     install_path: sdist.install_path.clone(),
                     url: sdist.url.clone(),
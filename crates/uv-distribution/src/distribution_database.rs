@@ -3,11 +3,11 @@ use std::io;
 use std::path::Path;
 use std::pin::Pin;
 use std::rc::Rc;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::task::{Context, Poll};
 
 use futures::{FutureExt, TryStreamExt};
-use tempfile::TempDir;
+use rustc_hash::FxHashMap;
 use tokio::io::{AsyncRead, AsyncSeekExt, ReadBuf};
 use tokio::sync::Semaphore;
 use tokio_util::compat::FuturesAsyncReadCompatExt;
@@ -19,6 +19,7 @@ use uv_cache_info::{CacheInfo, Timestamp};
 use uv_client::{
     CacheControl, CachedClientError, Connectivity, DataWithCachePolicy, RegistryClient,
 };
+use uv_configuration::Concurrency;
 use uv_distribution_filename::WheelFilename;
 use uv_distribution_types::{
     BuildableSource, BuiltDist, Dist, FileLocation, HashPolicy, Hashed, Name, SourceDist,
@@ -32,9 +33,14 @@ use uv_types::BuildContext;
 use crate::archive::Archive;
 use crate::locks::Locks;
 use crate::metadata::{ArchiveMetadata, Metadata};
+use crate::resumable::PartialDownload;
 use crate::source::SourceDistributionBuilder;
 use crate::{Error, LocalWheel, Reporter, RequiresDist};
 
+/// The size above which wheels are downloaded to disk, rather than streamed, such that an
+/// interrupted download can be resumed.
+const RESUMABLE_DOWNLOAD_THRESHOLD: u64 = 100 * 1024 * 1024;
+
 /// A cached high-level interface to convert distributions (a requirement resolved to a location)
 /// to a wheel or wheel metadata.
 ///
@@ -59,13 +65,13 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
     pub fn new(
         client: &'a RegistryClient,
         build_context: &'a Context,
-        concurrent_downloads: usize,
+        concurrency: Concurrency,
     ) -> Self {
         Self {
             build_context,
             builder: SourceDistributionBuilder::new(build_context),
             locks: Rc::new(Locks::default()),
-            client: ManagedClient::new(client, concurrent_downloads),
+            client: ManagedClient::new(client, concurrency),
             reporter: None,
         }
     }
@@ -126,13 +132,22 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         dist: &Dist,
         hashes: HashPolicy<'_>,
     ) -> Result<ArchiveMetadata, Error> {
-        match dist {
-            Dist::Built(built) => self.get_wheel_metadata(built, hashes).await,
+        let mut metadata = match dist {
+            Dist::Built(built) => self.get_wheel_metadata(built, hashes).await?,
             Dist::Source(source) => {
                 self.build_wheel_metadata(&BuildableSource::Dist(source), hashes)
-                    .await
+                    .await?
             }
-        }
+        };
+
+        // Apply any user-provided patches to the declared dependencies.
+        self.build_context.dependency_metadata().apply_overrides(
+            &metadata.metadata.name,
+            &metadata.metadata.version,
+            &mut metadata.metadata.requires_dist,
+        );
+
+        Ok(metadata)
     }
 
     /// Fetch a wheel from the cache or download it from the index.
@@ -171,6 +186,31 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                         .await;
                 }
 
+                // Download large wheels to disk, such that interrupted downloads can be resumed.
+                if wheel
+                    .file
+                    .size
+                    .is_some_and(|size| size >= RESUMABLE_DOWNLOAD_THRESHOLD)
+                {
+                    let archive = self
+                        .download_wheel(
+                            url,
+                            &wheel.filename,
+                            wheel.file.size,
+                            &wheel_entry,
+                            dist,
+                            hashes,
+                        )
+                        .await?;
+                    return Ok(LocalWheel {
+                        dist: Dist::Built(dist.clone()),
+                        archive: self.build_context.cache().archive(&archive.id),
+                        hashes: archive.hashes,
+                        filename: wheel.filename.clone(),
+                        cache: CacheInfo::default(),
+                    });
+                }
+
                 // Download and unzip.
                 match self
                     .stream_wheel(
@@ -431,8 +471,8 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                 .dependency_metadata()
                 .get(dist.name(), dist.version())
             {
-                // If we skipped the build, we should still resolve any Git dependencies to precise
-                // commits.
+                // If we skipped the build, we should still resolve any Git or Mercurial dependencies
+                // to precise commits.
                 self.builder.resolve_revision(source, &self.client).await?;
 
                 return Ok(ArchiveMetadata::from_metadata23(metadata.clone()));
@@ -550,12 +590,12 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     .freshness(&http_entry, Some(&filename.name))
                     .map_err(Error::CacheRead)?,
             ),
-            Connectivity::Offline => CacheControl::AllowStale,
+            Connectivity::Offline | Connectivity::StrictOffline => CacheControl::AllowStale,
         };
 
         let archive = self
             .client
-            .managed(|client| {
+            .managed_for(&url, |client| {
                 client
                     .cached_client()
                     .get_serde(req, &http_entry, cache_control, download)
@@ -575,7 +615,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
             archive
         } else {
             self.client
-                .managed(|client| async {
+                .managed_for(&url, |client| async {
                     client
                         .cached_client()
                         .skip_cache(self.request(url)?, &http_entry, download)
@@ -613,69 +653,82 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     .as_ref()
                     .map(|reporter| (reporter, reporter.on_download_start(dist.name(), size)));
 
-                let reader = response
-                    .bytes_stream()
-                    .map_err(|err| self.handle_response_errors(err))
-                    .into_async_read();
-
-                // Download the wheel to a temporary file.
-                let temp_file = tempfile::tempfile_in(self.build_context.cache().root())
-                    .map_err(Error::CacheWrite)?;
-                let mut writer = tokio::io::BufWriter::new(tokio::fs::File::from_std(temp_file));
-
-                match progress {
-                    Some((reporter, progress)) => {
-                        // Wrap the reader in a progress reporter. This will report 100% progress
-                        // after the download is complete, even if we still have to unzip and hash
-                        // part of the file.
-                        let mut reader =
-                            ProgressReader::new(reader.compat(), progress, &**reporter);
-
-                        tokio::io::copy(&mut reader, &mut writer)
-                            .await
-                            .map_err(Error::CacheWrite)?;
-                    }
-                    None => {
-                        tokio::io::copy(&mut reader.compat(), &mut writer)
-                            .await
-                            .map_err(Error::CacheWrite)?;
-                    }
-                }
+                // Download the wheel to a partial file in the cache, such that an interrupted
+                // download can be resumed.
+                // The partial file is locked for the duration of the download, such that
+                // concurrent processes don't write to it at the same time.
+                let partial = PartialDownload::acquire(
+                    wheel_entry
+                        .dir()
+                        .join(format!("{}.partial", filename.stem())),
+                )
+                .await?;
+                let mut file = partial
+                    .download(
+                        response,
+                        self.client.unmanaged.uncached_client(&url),
+                        &url,
+                        progress.map(|(reporter, progress)| (&**reporter, progress)),
+                        |err| self.handle_response_errors(err),
+                    )
+                    .await?;
 
                 // Unzip the wheel to a temporary directory.
                 let temp_dir = tempfile::tempdir_in(self.build_context.cache().root())
                     .map_err(Error::CacheWrite)?;
-                let mut file = writer.into_inner();
-                file.seek(io::SeekFrom::Start(0))
-                    .await
-                    .map_err(Error::CacheWrite)?;
 
-                // If no hashes are required, parallelize the unzip operation.
-                let hashes = if hashes.is_none() {
-                    let file = file.into_std().await;
-                    tokio::task::spawn_blocking({
-                        let target = temp_dir.path().to_owned();
-                        move || -> Result<(), uv_extract::Error> {
-                            // Unzip the wheel into a temporary directory.
-                            uv_extract::unzip(file, &target)?;
-                            Ok(())
+                let result = async {
+                    // If no hashes are required, parallelize the unzip operation.
+                    if hashes.is_none() {
+                        let mut fallback = file.try_clone().await.map_err(Error::CacheWrite)?;
+                        let file = file.into_std().await;
+                        let result = tokio::task::spawn_blocking({
+                            let target = temp_dir.path().to_owned();
+                            move || -> Result<(), uv_extract::Error> {
+                                // Unzip the wheel into a temporary directory.
+                                uv_extract::unzip(file, &target)?;
+                                Ok(())
+                            }
+                        })
+                        .await?;
+
+                        // If the wheel uses features that the synchronous reader doesn't support
+                        // (e.g., Zstandard compression), fall back to the streaming reader.
+                        match result {
+                            Ok(()) => {}
+                            Err(err) if err.is_unsupported_by_sync_reader() => {
+                                debug!("Falling back to streaming unzip for: {dist} ({err})");
+                                clear_dir(temp_dir.path()).await?;
+                                fallback
+                                    .seek(io::SeekFrom::Start(0))
+                                    .await
+                                    .map_err(Error::CacheWrite)?;
+                                uv_extract::stream::unzip(&mut fallback, temp_dir.path()).await?;
+                            }
+                            Err(err) => return Err(err.into()),
                         }
-                    })
-                    .await??;
-
-                    vec![]
-                } else {
-                    // Create a hasher for each hash algorithm.
-                    let algorithms = hashes.algorithms();
-                    let mut hashers = algorithms.into_iter().map(Hasher::from).collect::<Vec<_>>();
-                    let mut hasher = uv_extract::hash::HashReader::new(file, &mut hashers);
-                    uv_extract::stream::unzip(&mut hasher, temp_dir.path()).await?;
-
-                    // If necessary, exhaust the reader to compute the hash.
-                    hasher.finish().await.map_err(Error::HashExhaustion)?;
 
-                    hashers.into_iter().map(HashDigest::from).collect()
-                };
+                        Ok(vec![])
+                    } else {
+                        // Create a hasher for each hash algorithm.
+                        let algorithms = hashes.algorithms();
+                        let mut hashers =
+                            algorithms.into_iter().map(Hasher::from).collect::<Vec<_>>();
+                        let mut hasher = uv_extract::hash::HashReader::new(&mut file, &mut hashers);
+                        uv_extract::stream::unzip(&mut hasher, temp_dir.path()).await?;
+
+                        // If necessary, exhaust the reader to compute the hash.
+                        hasher.finish().await.map_err(Error::HashExhaustion)?;
+
+                        Ok(hashers.into_iter().map(HashDigest::from).collect())
+                    }
+                }
+                .await;
+
+                // The download is complete, so remove the partial file, even if the wheel
+                // couldn't be unzipped (in which case, the download shouldn't be resumed).
+                partial.remove().await;
+                let hashes = result?;
 
                 // Persist the temporary directory to the directory store.
                 let id = self
@@ -704,12 +757,12 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     .freshness(&http_entry, Some(&filename.name))
                     .map_err(Error::CacheRead)?,
             ),
-            Connectivity::Offline => CacheControl::AllowStale,
+            Connectivity::Offline | Connectivity::StrictOffline => CacheControl::AllowStale,
         };
 
         let archive = self
             .client
-            .managed(|client| {
+            .managed_for(&url, |client| {
                 client
                     .cached_client()
                     .get_serde(req, &http_entry, cache_control, download)
@@ -729,10 +782,10 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
             archive
         } else {
             self.client
-                .managed(|client| async {
+                .managed_for(&url, |client| async {
                     client
                         .cached_client()
-                        .skip_cache(self.request(url)?, &http_entry, download)
+                        .skip_cache(self.request(url.clone())?, &http_entry, download)
                         .await
                         .map_err(|err| match err {
                             CachedClientError::Callback(err) => err,
@@ -799,29 +852,21 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
             let file = fs_err::tokio::File::open(path)
                 .await
                 .map_err(Error::CacheRead)?;
-            let temp_dir = tempfile::tempdir_in(self.build_context.cache().root())
-                .map_err(Error::CacheWrite)?;
 
             // Create a hasher for each hash algorithm.
             let algorithms = hashes.algorithms();
             let mut hashers = algorithms.into_iter().map(Hasher::from).collect::<Vec<_>>();
             let mut hasher = uv_extract::hash::HashReader::new(file, &mut hashers);
 
-            // Unzip the wheel to a temporary directory.
-            uv_extract::stream::unzip(&mut hasher, temp_dir.path()).await?;
-
             // Exhaust the reader to compute the hash.
             hasher.finish().await.map_err(Error::HashExhaustion)?;
 
             let hashes = hashers.into_iter().map(HashDigest::from).collect();
 
-            // Persist the temporary directory to the directory store.
-            let id = self
-                .build_context
-                .cache()
-                .persist(temp_dir.into_path(), wheel_entry.path())
-                .await
-                .map_err(Error::CacheWrite)?;
+            // Unzip the wheel to a temporary directory. Since the wheel is already on disk, we can
+            // hash it separately and unzip it in parallel, rather than streaming it through the
+            // hasher.
+            let id = self.unzip_wheel(path, wheel_entry.path()).await?;
 
             // Create an archive.
             let archive = Archive::new(id, hashes);
@@ -845,17 +890,36 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
 
     /// Unzip a wheel into the cache, returning the path to the unzipped directory.
     async fn unzip_wheel(&self, path: &Path, target: &Path) -> Result<ArchiveId, Error> {
-        let temp_dir = tokio::task::spawn_blocking({
+        let temp_dir =
+            tempfile::tempdir_in(self.build_context.cache().root()).map_err(Error::CacheWrite)?;
+        let result = tokio::task::spawn_blocking({
             let path = path.to_owned();
-            let root = self.build_context.cache().root().to_path_buf();
-            move || -> Result<TempDir, uv_extract::Error> {
+            let target = temp_dir.path().to_owned();
+            move || -> Result<(), uv_extract::Error> {
                 // Unzip the wheel into a temporary directory.
-                let temp_dir = tempfile::tempdir_in(root)?;
-                uv_extract::unzip(fs_err::File::open(path)?, temp_dir.path())?;
-                Ok(temp_dir)
+                uv_extract::unzip(fs_err::File::open(path)?, &target)?;
+                Ok(())
             }
         })
-        .await??;
+        .await?;
+
+        // If the wheel uses features that the synchronous reader doesn't support (e.g., Zstandard
+        // compression), fall back to the streaming reader.
+        match result {
+            Ok(()) => {}
+            Err(err) if err.is_unsupported_by_sync_reader() => {
+                debug!(
+                    "Falling back to streaming unzip for: {} ({err})",
+                    path.display()
+                );
+                clear_dir(temp_dir.path()).await?;
+                let file = fs_err::tokio::File::open(path)
+                    .await
+                    .map_err(Error::CacheRead)?;
+                uv_extract::stream::unzip(file, temp_dir.path()).await?;
+            }
+            Err(err) => return Err(err.into()),
+        }
 
         // Persist the temporary directory to the directory store.
         let id = self
@@ -894,14 +958,20 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
 pub struct ManagedClient<'a> {
     pub unmanaged: &'a RegistryClient,
     control: Semaphore,
+    /// The maximum number of concurrent requests to any single host, if limited.
+    per_host: Option<usize>,
+    /// The per-host concurrency limits, keyed by host and port.
+    hosts: Mutex<FxHashMap<(String, Option<u16>), Arc<Semaphore>>>,
 }
 
 impl<'a> ManagedClient<'a> {
-    /// Create a new `ManagedClient` using the given client and concurrency limit.
-    fn new(client: &'a RegistryClient, concurrency: usize) -> ManagedClient<'a> {
+    /// Create a new `ManagedClient` using the given client and concurrency limits.
+    fn new(client: &'a RegistryClient, concurrency: Concurrency) -> ManagedClient<'a> {
         ManagedClient {
             unmanaged: client,
-            control: Semaphore::new(concurrency),
+            control: Semaphore::new(concurrency.downloads),
+            per_host: concurrency.downloads_per_host,
+            hosts: Mutex::default(),
         }
     }
 
@@ -916,6 +986,36 @@ impl<'a> ManagedClient<'a> {
         let _permit = self.control.acquire().await.unwrap();
         f(self.unmanaged).await
     }
+
+    /// Perform a request to the given URL using the client, respecting both the overall and the
+    /// per-host concurrency limits.
+    ///
+    /// The client itself limits the concurrent requests to each host, but only until the response
+    /// headers arrive; this holds the per-host permit for the entire closure, e.g., while
+    /// streaming an archive.
+    ///
+    /// The per-host permit is acquired first, such that requests waiting on a busy host don't
+    /// prevent requests to other hosts from proceeding.
+    pub async fn managed_for<F, T>(&self, url: &Url, f: impl FnOnce(&'a RegistryClient) -> F) -> T
+    where
+        F: Future<Output = T>,
+    {
+        let host = self.per_host.and_then(|per_host| {
+            let host = url.host_str()?;
+            let mut hosts = self.hosts.lock().unwrap();
+            Some(
+                hosts
+                    .entry((host.to_string(), url.port_or_known_default()))
+                    .or_insert_with(|| Arc::new(Semaphore::new(per_host)))
+                    .clone(),
+            )
+        });
+        let _host_permit = match host.as_ref() {
+            Some(host) => Some(host.acquire().await.unwrap()),
+            None => None,
+        };
+        self.managed(f).await
+    }
 }
 
 /// Returns the value of the `Content-Length` header from the [`reqwest::Response`], if present.
@@ -927,6 +1027,17 @@ fn content_length(response: &reqwest::Response) -> Option<u64> {
         .and_then(|val| val.parse::<u64>().ok())
 }
 
+/// Remove any files that were partially extracted into a directory, leaving it empty.
+async fn clear_dir(dir: &Path) -> Result<(), Error> {
+    fs_err::tokio::remove_dir_all(dir)
+        .await
+        .map_err(Error::CacheWrite)?;
+    fs_err::tokio::create_dir(dir)
+        .await
+        .map_err(Error::CacheWrite)?;
+    Ok(())
+}
+
 /// An asynchronous reader that reports progress as bytes are read.
 struct ProgressReader<'a, R> {
     reader: R,
@@ -6,7 +6,7 @@ use uv_cache_info::CacheInfo;
 use uv_cache_key::cache_digest;
 use uv_configuration::ConfigSettings;
 use uv_distribution_types::{
-    DirectUrlSourceDist, DirectorySourceDist, GitSourceDist, Hashed, PathSourceDist,
+    DirectUrlSourceDist, DirectorySourceDist, GitSourceDist, Hashed, HgSourceDist, PathSourceDist,
 };
 use uv_fs::symlinks;
 use uv_platform_tags::Tags;
@@ -183,6 +183,31 @@ impl<'a> BuiltWheelIndex<'a> {
         self.find(&cache_shard)
     }
 
+    /// Return the most compatible [`CachedWheel`] for a given source distribution at a Mercurial
+    /// URL.
+    pub fn hg(&self, source_dist: &HgSourceDist) -> Option<CachedWheel> {
+        // Enforce hash-checking, which isn't supported for Mercurial distributions.
+        if self.hasher.get(source_dist).is_validate() {
+            return None;
+        }
+
+        let node = source_dist.hg.precise()?;
+
+        let cache_shard = self.cache.shard(
+            CacheBucket::SourceDistributions,
+            WheelCache::Hg(&source_dist.url, &node.to_short_string()).root(),
+        );
+
+        // If there are build settings, we need to scope to a cache shard.
+        let cache_shard = if self.build_configuration.is_empty() {
+            cache_shard
+        } else {
+            cache_shard.shard(cache_digest(self.build_configuration))
+        };
+
+        self.find(&cache_shard)
+    }
+
     /// Find the "best" distribution in the index for a given source distribution.
     ///
     /// This lookup prefers newer versions over older versions, and aims to maximize compatibility
@@ -14,4 +14,5 @@ mod index;
 mod locks;
 mod metadata;
 mod reporter;
+mod resumable;
 mod source;
@@ -10,6 +10,7 @@ use uv_configuration::LowerBound;
 use uv_distribution_filename::DistExtension;
 use uv_distribution_types::{Index, IndexLocations, IndexName, Origin};
 use uv_git::GitReference;
+use uv_hg::HgReference;
 use uv_normalize::PackageName;
 use uv_pep440::VersionSpecifiers;
 use uv_pep508::{MarkerTree, VerbatimUrl, VersionOrUrl};
@@ -42,6 +43,7 @@ impl LoweredRequirement {
         locations: &'data IndexLocations,
         workspace: &'data Workspace,
         lower_bound: LowerBound,
+        variants: &[String],
         git_member: Option<&'data GitWorkspaceMember<'data>>,
     ) -> impl Iterator<Item = Result<Self, LoweringError>> + 'data {
         let (source, origin) = if let Some(source) = project_sources.get(&requirement.name) {
@@ -51,7 +53,9 @@ impl LoweredRequirement {
         } else {
             (None, RequirementOrigin::Project)
         };
-        let source = source.cloned();
+        let source = source
+            .cloned()
+            .map(|source| source.select_variant(variants));
 
         let workspace_package_declared =
             // We require that when you use a package that's part of the workspace, ...
@@ -116,6 +120,7 @@ impl LoweredRequirement {
                             rev,
                             tag,
                             branch,
+                            variant: _,
                             marker,
                         } => {
                             if matches!(requirement.version_or_url, Some(VersionOrUrl::Url(_))) {
@@ -130,9 +135,33 @@ impl LoweredRequirement {
                             )?;
                             (source, marker)
                         }
+                        Source::Hg {
+                            hg,
+                            subdirectory,
+                            rev,
+                            branch,
+                            bookmark,
+                            variant: _,
+                            marker,
+                        } => {
+                            if matches!(requirement.version_or_url, Some(VersionOrUrl::Url(_))) {
+                                return Err(LoweringError::ConflictingUrls);
+                            }
+                            let source = hg_source(
+                                &hg,
+                                subdirectory.map(PathBuf::from),
+                                rev,
+                                branch,
+                                bookmark,
+                            )?;
+                            (source, marker)
+                        }
                         Source::Url {
                             url,
                             subdirectory,
+                            // The expected hash is enforced via the `HashStrategy`.
+                            hash: _,
+                            variant: _,
                             marker,
                         } => {
                             if matches!(requirement.version_or_url, Some(VersionOrUrl::Url(_))) {
@@ -144,6 +173,7 @@ impl LoweredRequirement {
                         Source::Path {
                             path,
                             editable,
+                            variant: _,
                             marker,
                         } => {
                             if matches!(requirement.version_or_url, Some(VersionOrUrl::Url(_))) {
@@ -158,7 +188,11 @@ impl LoweredRequirement {
                             )?;
                             (source, marker)
                         }
-                        Source::Registry { index, marker } => {
+                        Source::Registry {
+                            index,
+                            variant: _,
+                            marker,
+                        } => {
                             // Identify the named index from either the project indexes or the workspace indexes,
                             // in that order.
                             let Some(index) = locations
@@ -182,6 +216,7 @@ impl LoweredRequirement {
                         }
                         Source::Workspace {
                             workspace: is_workspace,
+                            variant: _,
                             marker,
                         } => {
                             if !is_workspace {
@@ -284,8 +319,12 @@ impl LoweredRequirement {
         indexes: &'data [Index],
         locations: &'data IndexLocations,
         lower_bound: LowerBound,
+        variants: &[String],
     ) -> impl Iterator<Item = Result<Self, LoweringError>> + 'data {
-        let source = sources.get(&requirement.name).cloned();
+        let source = sources
+            .get(&requirement.name)
+            .cloned()
+            .map(|source| source.select_variant(variants));
 
         let Some(source) = source else {
             return Either::Left(std::iter::once(Ok(Self(Requirement::from(requirement)))));
@@ -321,6 +360,7 @@ impl LoweredRequirement {
                             rev,
                             tag,
                             branch,
+                            variant: _,
                             marker,
                         } => {
                             if matches!(requirement.version_or_url, Some(VersionOrUrl::Url(_))) {
@@ -335,9 +375,33 @@ impl LoweredRequirement {
                             )?;
                             (source, marker)
                         }
+                        Source::Hg {
+                            hg,
+                            subdirectory,
+                            rev,
+                            branch,
+                            bookmark,
+                            variant: _,
+                            marker,
+                        } => {
+                            if matches!(requirement.version_or_url, Some(VersionOrUrl::Url(_))) {
+                                return Err(LoweringError::ConflictingUrls);
+                            }
+                            let source = hg_source(
+                                &hg,
+                                subdirectory.map(PathBuf::from),
+                                rev,
+                                branch,
+                                bookmark,
+                            )?;
+                            (source, marker)
+                        }
                         Source::Url {
                             url,
                             subdirectory,
+                            // The expected hash is enforced via the `HashStrategy`.
+                            hash: _,
+                            variant: _,
                             marker,
                         } => {
                             if matches!(requirement.version_or_url, Some(VersionOrUrl::Url(_))) {
@@ -349,6 +413,7 @@ impl LoweredRequirement {
                         Source::Path {
                             path,
                             editable,
+                            variant: _,
                             marker,
                         } => {
                             if matches!(requirement.version_or_url, Some(VersionOrUrl::Url(_))) {
@@ -363,7 +428,11 @@ impl LoweredRequirement {
                             )?;
                             (source, marker)
                         }
-                        Source::Registry { index, marker } => {
+                        Source::Registry {
+                            index,
+                            variant: _,
+                            marker,
+                        } => {
                             let Some(index) = locations
                                 .indexes()
                                 .filter(|index| matches!(index.origin, Some(Origin::Cli)))
@@ -419,6 +488,8 @@ pub enum LoweringError {
     UndeclaredWorkspacePackage,
     #[error("Can only specify one of: `rev`, `tag`, or `branch`")]
     MoreThanOneGitRef,
+    #[error("Can only specify one of: `rev`, `branch`, or `bookmark`")]
+    MoreThanOneHgRef,
     #[error("Package `{0}` references an undeclared index: `{1}`")]
     MissingIndex(PackageName, IndexName),
     #[error("Workspace members are not allowed in non-workspace contexts")]
@@ -483,6 +554,46 @@ fn git_source(
     })
 }
 
+/// Convert a Mercurial source into a [`RequirementSource`].
+fn hg_source(
+    hg: &Url,
+    subdirectory: Option<PathBuf>,
+    rev: Option<String>,
+    branch: Option<String>,
+    bookmark: Option<String>,
+) -> Result<RequirementSource, LoweringError> {
+    let reference = match (rev, branch, bookmark) {
+        (None, None, None) => HgReference::DefaultBranch,
+        (Some(rev), None, None) => HgReference::from_rev(rev),
+        (None, Some(branch), None) => HgReference::Branch(branch),
+        (None, None, Some(bookmark)) => HgReference::Bookmark(bookmark),
+        _ => return Err(LoweringError::MoreThanOneHgRef),
+    };
+
+    // Create a PEP 508-compatible URL.
+    let mut url = Url::parse(&format!("hg+{hg}"))?;
+    if let Some(rev) = reference.as_str() {
+        url.set_path(&format!("{}@{}", url.path(), rev));
+    }
+    if let Some(subdirectory) = subdirectory.as_ref() {
+        let subdirectory = subdirectory
+            .to_str()
+            .ok_or_else(|| LoweringError::NonUtf8Path(subdirectory.clone()))?;
+        url.set_fragment(Some(&format!("subdirectory={subdirectory}")));
+    }
+    let url = VerbatimUrl::from_url(url);
+
+    let repository = hg.clone();
+
+    Ok(RequirementSource::Hg {
+        url,
+        repository,
+        reference,
+        precise: None,
+        subdirectory,
+    })
+}
+
 /// Convert a URL source into a [`RequirementSource`].
 fn url_source(url: Url, subdirectory: Option<PathBuf>) -> Result<RequirementSource, LoweringError> {
     let mut verbatim_url = url.clone();
@@ -68,6 +68,7 @@ impl Metadata {
         git_source: Option<&GitWorkspaceMember<'_>>,
         locations: &IndexLocations,
         sources: SourceStrategy,
+        variants: &[String],
         bounds: LowerBound,
     ) -> Result<Self, MetadataError> {
         // Lower the requirements.
@@ -87,6 +88,7 @@ impl Metadata {
             git_source,
             locations,
             sources,
+            variants,
             bounds,
         )
         .await?;
@@ -42,6 +42,7 @@ impl RequiresDist {
         git_member: Option<&GitWorkspaceMember<'_>>,
         locations: &IndexLocations,
         sources: SourceStrategy,
+        variants: &[String],
         lower_bound: LowerBound,
     ) -> Result<Self, MetadataError> {
         // TODO(konsti): Cache workspace discovery.
@@ -70,6 +71,7 @@ impl RequiresDist {
             git_member,
             locations,
             sources,
+            variants,
             lower_bound,
         )
     }
@@ -80,6 +82,7 @@ impl RequiresDist {
         git_member: Option<&GitWorkspaceMember<'_>>,
         locations: &IndexLocations,
         source_strategy: SourceStrategy,
+        variants: &[String],
         lower_bound: LowerBound,
     ) -> Result<Self, MetadataError> {
         // Collect any `tool.uv.index` entries.
@@ -130,57 +133,70 @@ impl RequiresDist {
                 .flatten()
                 .collect::<BTreeMap<_, _>>();
 
+            // Then, collect any settings for the `dependency-groups` (e.g., `requires-python`).
+            let group_settings = project_workspace
+                .current_project()
+                .pyproject_toml()
+                .tool
+                .as_ref()
+                .and_then(|tool| tool.uv.as_ref())
+                .and_then(|uv| uv.dependency_groups.as_ref());
+
             // Resolve any `include-group` entries in `dependency-groups`.
-            let dependency_groups =
-                FlatDependencyGroups::from_dependency_groups(&dependency_groups)?
+            let dependency_groups = FlatDependencyGroups::from_dependency_groups(
+                &dependency_groups,
+                group_settings,
+                project_workspace.workspace(),
+            )?
+            .into_iter()
+            .chain(
+                // Only add the `dev` group if `dev-dependencies` is defined.
+                dev_dependencies
                     .into_iter()
-                    .chain(
-                        // Only add the `dev` group if `dev-dependencies` is defined.
-                        dev_dependencies
-                            .into_iter()
-                            .map(|requirements| (DEV_DEPENDENCIES.clone(), requirements.clone())),
-                    )
-                    .map(|(name, requirements)| {
-                        let requirements = match source_strategy {
-                            SourceStrategy::Enabled => requirements
-                                .into_iter()
-                                .flat_map(|requirement| {
-                                    let group_name = name.clone();
-                                    let requirement_name = requirement.name.clone();
-                                    LoweredRequirement::from_requirement(
-                                        requirement,
-                                        &metadata.name,
-                                        project_workspace.project_root(),
-                                        project_sources,
-                                        project_indexes,
-                                        locations,
-                                        project_workspace.workspace(),
-                                        lower_bound,
-                                        git_member,
-                                    )
-                                    .map(move |requirement| {
-                                        match requirement {
-                                            Ok(requirement) => Ok(requirement.into_inner()),
-                                            Err(err) => Err(MetadataError::GroupLoweringError(
-                                                group_name.clone(),
-                                                requirement_name.clone(),
-                                                Box::new(err),
-                                            )),
-                                        }
-                                    })
-                                })
-                                .collect::<Result<Vec<_>, _>>(),
-                            SourceStrategy::Disabled => Ok(requirements
-                                .into_iter()
-                                .map(uv_pypi_types::Requirement::from)
-                                .collect()),
-                        }?;
-                        Ok::<(GroupName, Vec<uv_pypi_types::Requirement>), MetadataError>((
-                            name,
-                            requirements,
-                        ))
-                    })
-                    .collect::<Result<Vec<_>, _>>()?;
+                    .map(|requirements| (DEV_DEPENDENCIES.clone(), requirements.clone())),
+            )
+            .map(|(name, requirements)| {
+                let requirements = match source_strategy {
+                    SourceStrategy::Enabled => requirements
+                        .into_iter()
+                        .flat_map(|requirement| {
+                            let group_name = name.clone();
+                            let requirement_name = requirement.name.clone();
+                            LoweredRequirement::from_requirement(
+                                requirement,
+                                &metadata.name,
+                                project_workspace.project_root(),
+                                project_sources,
+                                project_indexes,
+                                locations,
+                                project_workspace.workspace(),
+                                lower_bound,
+                                variants,
+                                git_member,
+                            )
+                            .map(
+                                move |requirement| match requirement {
+                                    Ok(requirement) => Ok(requirement.into_inner()),
+                                    Err(err) => Err(MetadataError::GroupLoweringError(
+                                        group_name.clone(),
+                                        requirement_name.clone(),
+                                        Box::new(err),
+                                    )),
+                                },
+                            )
+                        })
+                        .collect::<Result<Vec<_>, _>>(),
+                    SourceStrategy::Disabled => Ok(requirements
+                        .into_iter()
+                        .map(uv_pypi_types::Requirement::from)
+                        .collect()),
+                }?;
+                Ok::<(GroupName, Vec<uv_pypi_types::Requirement>), MetadataError>((
+                    name,
+                    requirements,
+                ))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
 
             // Merge any overlapping groups.
             let mut map = BTreeMap::new();
@@ -211,6 +227,7 @@ impl RequiresDist {
                         locations,
                         project_workspace.workspace(),
                         lower_bound,
+                        variants,
                         git_member,
                     )
                     .map(move |requirement| match requirement {
@@ -285,6 +302,7 @@ mod test {
             None,
             &IndexLocations::default(),
             SourceStrategy::default(),
+            &[],
             LowerBound::default(),
         )?)
     }
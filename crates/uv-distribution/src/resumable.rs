@@ -0,0 +1,437 @@
+use std::io;
+use std::path::{Path, PathBuf};
+
+use futures::StreamExt;
+use reqwest::header::{HeaderValue, ACCEPT_RANGES, ETAG, IF_RANGE, LAST_MODIFIED, RANGE};
+use reqwest::StatusCode;
+use reqwest_middleware::ClientWithMiddleware;
+use tokio::io::{AsyncSeekExt, AsyncWriteExt};
+use tracing::{debug, warn};
+use url::Url;
+
+use uv_client::WrappedReqwestError;
+use uv_fs::{write_atomic, LockedFile};
+
+use crate::{Error, Reporter};
+
+/// The maximum number of times to resume an interrupted download within a single request.
+const MAX_RESUME_ATTEMPTS: usize = 3;
+
+/// A download to a partial file in the cache, which can be resumed with HTTP range requests if
+/// interrupted.
+///
+/// The partial file is guarded by an exclusive lock for the lifetime of the [`PartialDownload`],
+/// such that concurrent uv processes downloading the same resource don't interleave their writes.
+#[derive(Debug)]
+pub(crate) struct PartialDownload {
+    path: PathBuf,
+    _lock: LockedFile,
+}
+
+impl PartialDownload {
+    /// Acquire the partial download at the given path, waiting for any other process that's
+    /// downloading the same resource.
+    pub(crate) async fn acquire(path: PathBuf) -> Result<Self, Error> {
+        fs_err::tokio::create_dir_all(path.parent().expect("Cache entry to have parent"))
+            .await
+            .map_err(Error::CacheWrite)?;
+        let lock = LockedFile::acquire(path.with_extension("lock"), path.display())
+            .await
+            .map_err(Error::CacheWrite)?;
+        Ok(Self { path, _lock: lock })
+    }
+
+    /// Returns the path to the partial file.
+    pub(crate) fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the path to the file that stores the validator of the partial download.
+    fn validator_path(&self) -> PathBuf {
+        self.path.with_extension("validator")
+    }
+
+    /// Download a response body to the partial file, resuming interrupted downloads with HTTP
+    /// range requests where possible.
+    ///
+    /// If the server provides a validator (i.e., a strong `ETag` or a `Last-Modified` date) and
+    /// supports range requests, the validator is persisted alongside the partial file. A download
+    /// that's interrupted by a network failure (or by cancelling a previous invocation) then
+    /// continues from the last byte written, rather than restarting from zero. Hashes are computed
+    /// over the complete file by the caller.
+    pub(crate) async fn download(
+        &self,
+        response: reqwest::Response,
+        client: &ClientWithMiddleware,
+        url: &Url,
+        progress: Option<(&dyn Reporter, usize)>,
+        handle_response_errors: impl Fn(reqwest::Error) -> io::Error,
+    ) -> Result<tokio::fs::File, Error> {
+        let validator = validator(&response).filter(|_| accepts_ranges(&response));
+        let validator_path = self.validator_path();
+
+        // If a previous download of the same resource was interrupted, resume it.
+        let mut offset = 0;
+        if let Some(validator) = validator.as_deref() {
+            let previous = fs_err::tokio::read_to_string(&validator_path).await.ok();
+            if previous.as_deref() == Some(validator) {
+                offset = fs_err::tokio::metadata(&self.path)
+                    .await
+                    .map(|metadata| metadata.len())
+                    .unwrap_or(0);
+            } else {
+                write_atomic(&validator_path, validator)
+                    .await
+                    .map_err(Error::CacheWrite)?;
+            }
+        }
+
+        let file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .read(true)
+            .write(true)
+            .open(&self.path)
+            .await
+            .map_err(Error::CacheWrite)?;
+        let mut writer = tokio::io::BufWriter::new(file);
+
+        let mut response = match validator.as_deref() {
+            Some(validator) if offset > 0 => {
+                debug!("Resuming download of {url} from byte {offset}");
+                if let Some((reporter, progress)) = progress {
+                    reporter.on_download_progress(progress, offset);
+                }
+                request_range(client, url, offset, validator).await?
+            }
+            _ => response,
+        };
+
+        let mut attempts = 0;
+        loop {
+            // If the server ignored the range request, start over.
+            if response.status() != StatusCode::PARTIAL_CONTENT {
+                offset = 0;
+            }
+            writer.flush().await.map_err(Error::CacheWrite)?;
+            writer
+                .get_mut()
+                .set_len(offset)
+                .await
+                .map_err(Error::CacheWrite)?;
+            writer
+                .seek(io::SeekFrom::Start(offset))
+                .await
+                .map_err(Error::CacheWrite)?;
+
+            let mut stream = response.bytes_stream();
+            let err = loop {
+                match stream.next().await {
+                    Some(Ok(chunk)) => {
+                        writer.write_all(&chunk).await.map_err(Error::CacheWrite)?;
+                        offset += chunk.len() as u64;
+                        if let Some((reporter, progress)) = progress {
+                            reporter.on_download_progress(progress, chunk.len() as u64);
+                        }
+                    }
+                    Some(Err(err)) => break err,
+                    None => {
+                        writer.flush().await.map_err(Error::CacheWrite)?;
+                        let mut file = writer.into_inner();
+                        file.seek(io::SeekFrom::Start(0))
+                            .await
+                            .map_err(Error::CacheWrite)?;
+                        return Ok(file);
+                    }
+                }
+            };
+
+            // If the download was interrupted, request the remaining bytes.
+            let Some(validator) = validator.as_deref() else {
+                return Err(Error::CacheWrite(handle_response_errors(err)));
+            };
+            if attempts >= MAX_RESUME_ATTEMPTS {
+                return Err(Error::CacheWrite(handle_response_errors(err)));
+            }
+            attempts += 1;
+            warn!("Download of {url} interrupted after {offset} bytes; resuming ({err})");
+            writer.flush().await.map_err(Error::CacheWrite)?;
+            response = request_range(client, url, offset, validator).await?;
+        }
+    }
+
+    /// Remove the partial file, along with its validator, and release the lock.
+    pub(crate) async fn remove(self) {
+        for path in [self.path.clone(), self.validator_path()] {
+            if let Err(err) = fs_err::tokio::remove_file(&path).await {
+                if err.kind() != io::ErrorKind::NotFound {
+                    warn!("Failed to remove partial download: {err}");
+                }
+            }
+        }
+    }
+}
+
+/// Build a request for the resource at the given URL.
+fn request(client: &ClientWithMiddleware, url: &Url) -> reqwest_middleware::RequestBuilder {
+    client.get(url.clone()).header(
+        // `reqwest` defaults to accepting compressed responses.
+        // Specify identity encoding to get consistent .whl downloading
+        // behavior from servers. ref: https://github.com/pypa/pip/pull/1688
+        "accept-encoding",
+        HeaderValue::from_static("identity"),
+    )
+}
+
+/// Request the bytes of the resource at the given URL, starting at `offset`.
+///
+/// The `If-Range` header ensures that the server returns the full resource if it no longer
+/// matches the validator.
+async fn request_range(
+    client: &ClientWithMiddleware,
+    url: &Url,
+    offset: u64,
+    validator: &str,
+) -> Result<reqwest::Response, Error> {
+    let mut builder = request(client, url).header(RANGE, format!("bytes={offset}-"));
+    if let Ok(validator) = HeaderValue::from_str(validator) {
+        builder = builder.header(IF_RANGE, validator);
+    }
+    let response = builder.send().await.map_err(Error::from)?;
+
+    // If the partial file is already complete (or larger than the resource), start over.
+    let response = if response.status() == StatusCode::RANGE_NOT_SATISFIABLE {
+        debug!("Range not satisfiable for {url}; restarting download");
+        request(client, url).send().await.map_err(Error::from)?
+    } else {
+        response
+    };
+
+    response
+        .error_for_status()
+        .map_err(|err| Error::Reqwest(WrappedReqwestError::from(err)))
+}
+
+/// Returns the validator for the [`reqwest::Response`], used to ensure that a resumed download
+/// refers to the same resource: either a strong `ETag`, or the `Last-Modified` date.
+fn validator(response: &reqwest::Response) -> Option<String> {
+    let headers = response.headers();
+    headers
+        .get(ETAG)
+        .and_then(|val| val.to_str().ok())
+        .filter(|etag| !etag.starts_with("W/"))
+        .or_else(|| headers.get(LAST_MODIFIED).and_then(|val| val.to_str().ok()))
+        .map(ToString::to_string)
+}
+
+/// Returns `true` if the [`reqwest::Response`] indicates support for byte range requests.
+fn accepts_ranges(response: &reqwest::Response) -> bool {
+    response
+        .headers()
+        .get(ACCEPT_RANGES)
+        .and_then(|val| val.to_str().ok())
+        .is_some_and(|val| val.eq_ignore_ascii_case("bytes"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use anyhow::Result;
+    use futures::stream;
+    use http_body_util::combinators::BoxBody;
+    use http_body_util::{BodyExt, Full, StreamBody};
+    use hyper::body::{Bytes, Frame};
+    use hyper::server::conn::http1;
+    use hyper::service::service_fn;
+    use hyper::{Request, Response, StatusCode};
+    use hyper_util::rt::TokioIo;
+    use tokio::io::AsyncReadExt;
+    use tokio::net::TcpListener;
+    use url::Url;
+
+    use super::PartialDownload;
+
+    const ETAG: &str = "\"v1\"";
+
+    /// The body served by the test server.
+    fn body() -> Bytes {
+        Bytes::from((0..64 * 1024).map(|i| (i % 251) as u8).collect::<Vec<u8>>())
+    }
+
+    /// Spawn a server that serves [`body`] with a strong `ETag`, honoring range requests.
+    ///
+    /// If `truncate` is set, the first response is cut off after the given number of bytes.
+    /// Returns the server URL and the `Range` header of each request received.
+    async fn server(truncate: Option<usize>) -> Result<(Url, Arc<Mutex<Vec<Option<String>>>>)> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        let ranges = Arc::new(Mutex::new(Vec::new()));
+
+        let requests = ranges.clone();
+        tokio::spawn(async move {
+            loop {
+                let (socket, _) = listener.accept().await.unwrap();
+                let requests = requests.clone();
+                let svc = service_fn(move |req: Request<hyper::body::Incoming>| {
+                    let range = req
+                        .headers()
+                        .get("range")
+                        .and_then(|val| val.to_str().ok())
+                        .map(ToString::to_string);
+                    let if_range = req
+                        .headers()
+                        .get("if-range")
+                        .and_then(|val| val.to_str().ok());
+                    let first = {
+                        let mut requests = requests.lock().unwrap();
+                        requests.push(range.clone());
+                        requests.len() == 1
+                    };
+
+                    let body = body();
+                    let offset = range
+                        .as_deref()
+                        .filter(|_| if_range == Some(ETAG))
+                        .and_then(|range| range.strip_prefix("bytes="))
+                        .and_then(|range| range.strip_suffix('-'))
+                        .and_then(|offset| offset.parse::<usize>().ok());
+
+                    let response = if let Some(offset) = offset {
+                        Response::builder()
+                            .status(StatusCode::PARTIAL_CONTENT)
+                            .header(
+                                "content-range",
+                                format!("bytes {offset}-{}/{}", body.len() - 1, body.len()),
+                            )
+                            .body(full(body.slice(offset..)))
+                    } else {
+                        let builder = Response::builder()
+                            .header("content-length", body.len())
+                            .header("accept-ranges", "bytes")
+                            .header("etag", ETAG);
+                        match truncate.filter(|_| first) {
+                            // Send the first bytes of the body, then drop the connection.
+                            Some(truncate) => builder.body(
+                                StreamBody::new(stream::iter([
+                                    Ok(Frame::data(body.slice(..truncate))),
+                                    Err(io::Error::new(io::ErrorKind::Other, "interrupted")),
+                                ]))
+                                .boxed(),
+                            ),
+                            None => builder.body(full(body)),
+                        }
+                    };
+                    async move { response }
+                });
+                tokio::task::spawn(async move {
+                    http1::Builder::new()
+                        .serve_connection(TokioIo::new(socket), svc)
+                        .await
+                        .ok();
+                });
+            }
+        });
+
+        Ok((Url::parse(&format!("http://{addr}/file.whl"))?, ranges))
+    }
+
+    fn full(body: Bytes) -> BoxBody<Bytes, io::Error> {
+        Full::new(body).map_err(|never| match never {}).boxed()
+    }
+
+    async fn download(partial: &PartialDownload, url: &Url) -> Result<Vec<u8>> {
+        let client = reqwest_middleware::ClientBuilder::new(reqwest::Client::new()).build();
+        let response = client.get(url.clone()).send().await?;
+        let mut file = partial
+            .download(response, &client, url, None, |err| {
+                io::Error::new(io::ErrorKind::Other, err)
+            })
+            .await?;
+        let mut contents = Vec::new();
+        file.read_to_end(&mut contents).await?;
+        Ok(contents)
+    }
+
+    #[tokio::test]
+    async fn resume_interrupted() -> Result<()> {
+        let (url, ranges) = server(Some(1024)).await?;
+        let temp_dir = tempfile::tempdir()?;
+        let partial = PartialDownload::acquire(temp_dir.path().join("file.partial")).await?;
+
+        // The download is resumed from the last byte received.
+        assert_eq!(download(&partial, &url).await?, body());
+        assert_eq!(
+            *ranges.lock().unwrap(),
+            [None, Some("bytes=1024-".to_string())]
+        );
+
+        partial.remove().await;
+        assert!(!temp_dir.path().join("file.partial").exists());
+        assert!(!temp_dir.path().join("file.validator").exists());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn resume_previous() -> Result<()> {
+        let (url, ranges) = server(None).await?;
+        let temp_dir = tempfile::tempdir()?;
+
+        // Simulate a download that was cancelled by a previous invocation.
+        fs_err::write(temp_dir.path().join("file.partial"), &body()[..4096])?;
+        fs_err::write(temp_dir.path().join("file.validator"), ETAG)?;
+
+        let partial = PartialDownload::acquire(temp_dir.path().join("file.partial")).await?;
+        assert_eq!(download(&partial, &url).await?, body());
+        assert_eq!(
+            *ranges.lock().unwrap(),
+            [None, Some("bytes=4096-".to_string())]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn restart_changed() -> Result<()> {
+        let (url, ranges) = server(None).await?;
+        let temp_dir = tempfile::tempdir()?;
+
+        // Simulate a cancelled download of a resource that has since changed.
+        fs_err::write(temp_dir.path().join("file.partial"), vec![0; 4096])?;
+        fs_err::write(temp_dir.path().join("file.validator"), "\"v0\"")?;
+
+        // The partial file is discarded, rather than resumed.
+        let partial = PartialDownload::acquire(temp_dir.path().join("file.partial")).await?;
+        assert_eq!(download(&partial, &url).await?, body());
+        assert_eq!(*ranges.lock().unwrap(), [None]);
+        assert_eq!(
+            fs_err::read_to_string(temp_dir.path().join("file.validator"))?,
+            ETAG
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn exclusive() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let path = temp_dir.path().join("file.partial");
+
+        let first = PartialDownload::acquire(path.clone()).await?;
+        let mut second = tokio::spawn(PartialDownload::acquire(path));
+
+        // The second download waits for the first to complete.
+        assert!(
+            tokio::time::timeout(Duration::from_millis(200), &mut second)
+                .await
+                .is_err()
+        );
+        first.remove().await;
+        tokio::time::timeout(Duration::from_secs(10), second).await???;
+
+        Ok(())
+    }
+}
@@ -21,14 +21,15 @@ use uv_configuration::{BuildKind, BuildOutput, SourceStrategy};
 use uv_distribution_filename::{SourceDistExtension, WheelFilename};
 use uv_distribution_types::{
     BuildableSource, DirectorySourceUrl, FileLocation, GitSourceUrl, HashPolicy, Hashed,
-    PathSourceUrl, RemoteSource, SourceDist, SourceUrl,
+    HgSourceUrl, PathSourceUrl, RemoteSource, SourceDist, SourceUrl,
 };
 use uv_extract::hash::Hasher;
 use uv_fs::{rename_with_retry, write_atomic, LockedFile};
 use uv_metadata::read_archive_metadata;
 use uv_platform_tags::Tags;
-use uv_pypi_types::{HashDigest, Metadata12, RequiresTxt, ResolutionMetadata};
+use uv_pypi_types::{HashAlgorithm, HashDigest, Metadata12, RequiresTxt, ResolutionMetadata};
 use uv_types::{BuildContext, SourceBuildTrait};
+use uv_warnings::warn_user_once;
 use zip::ZipArchive;
 
 use crate::distribution_database::ManagedClient;
@@ -36,16 +37,20 @@ use crate::error::Error;
 use crate::metadata::{ArchiveMetadata, GitWorkspaceMember, Metadata};
 use crate::reporter::Facade;
 use crate::source::built_wheel_metadata::BuiltWheelMetadata;
+use crate::source::remote::{RemoteBuildCache, RemoteKey};
 use crate::source::revision::Revision;
 use crate::{Reporter, RequiresDist};
 
 mod built_wheel_metadata;
+mod remote;
 mod revision;
 
 /// Fetch and build a source distribution from a remote source, or from a local cache.
 pub(crate) struct SourceDistributionBuilder<'a, T: BuildContext> {
     build_context: &'a T,
     reporter: Option<Arc<dyn Reporter>>,
+    /// The remote cache of built wheels, if configured.
+    remote: Option<RemoteBuildCache>,
 }
 
 /// The name of the file that contains the revision ID for a remote distribution, encoded via `MsgPack`.
@@ -63,6 +68,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         Self {
             build_context,
             reporter: None,
+            remote: RemoteBuildCache::from_cache(build_context.cache()),
         }
     }
 
@@ -164,6 +170,11 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     .boxed_local()
                     .await?
             }
+            BuildableSource::Dist(SourceDist::Hg(dist)) => {
+                self.hg(source, &HgSourceUrl::from(dist), tags, hashes, client)
+                    .boxed_local()
+                    .await?
+            }
             BuildableSource::Dist(SourceDist::Directory(dist)) => {
                 self.source_tree(source, &DirectorySourceUrl::from(dist), tags, hashes)
                     .boxed_local()
@@ -215,6 +226,11 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     .boxed_local()
                     .await?
             }
+            BuildableSource::Url(SourceUrl::Hg(resource)) => {
+                self.hg(source, resource, tags, hashes, client)
+                    .boxed_local()
+                    .await?
+            }
             BuildableSource::Url(SourceUrl::Directory(resource)) => {
                 self.source_tree(source, resource, tags, hashes)
                     .boxed_local()
@@ -320,6 +336,11 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     .boxed_local()
                     .await?
             }
+            BuildableSource::Dist(SourceDist::Hg(dist)) => {
+                self.hg_metadata(source, &HgSourceUrl::from(dist), hashes)
+                    .boxed_local()
+                    .await?
+            }
             BuildableSource::Dist(SourceDist::Directory(dist)) => {
                 self.source_tree_metadata(source, &DirectorySourceUrl::from(dist), hashes)
                     .boxed_local()
@@ -364,6 +385,11 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     .boxed_local()
                     .await?
             }
+            BuildableSource::Url(SourceUrl::Hg(resource)) => {
+                self.hg_metadata(source, resource, hashes)
+                    .boxed_local()
+                    .await?
+            }
             BuildableSource::Url(SourceUrl::Directory(resource)) => {
                 self.source_tree_metadata(source, resource, hashes)
                     .boxed_local()
@@ -392,6 +418,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             None,
             self.build_context.locations(),
             self.build_context.sources(),
+            self.build_context.variants(),
             self.build_context.bounds(),
         )
         .await?;
@@ -433,18 +460,35 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let source_dist_entry = cache_shard.entry(filename);
 
         // If there are build settings, we need to scope to a cache shard.
-        let config_settings = self.build_context.config_settings();
-        let cache_shard = if config_settings.is_empty() {
-            cache_shard
-        } else {
-            cache_shard.shard(cache_digest(config_settings))
-        };
+        let cache_shard = self.build_shard(source, cache_shard);
 
         // If the cache contains a compatible wheel, return it.
         if let Some(built_wheel) = BuiltWheelMetadata::find_in_cache(tags, &cache_shard) {
             return Ok(built_wheel.with_hashes(revision.into_hashes()));
         }
 
+        // If the remote build cache contains a compatible wheel, return it.
+        let metadata_entry = cache_shard.entry(METADATA);
+        let remote_key = self.remote_key(
+            source,
+            archive_sha256(source, &revision),
+            subdirectory,
+            tags,
+        );
+        if let Some(built_wheel) = self
+            .fetch_remote(
+                source,
+                remote_key.as_ref(),
+                tags,
+                &cache_shard,
+                &metadata_entry,
+                client,
+            )
+            .await?
+        {
+            return Ok(built_wheel.with_hashes(revision.into_hashes()));
+        }
+
         // Otherwise, we need to build a wheel. Before building, ensure that the source is present.
         let revision = if source_dist_entry.path().is_dir() {
             revision
@@ -484,8 +528,18 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             }
         }
 
+        // Share the wheel via the remote build cache. The source may have been re-downloaded, so
+        // recompute the key from the current revision.
+        let remote_key = self.remote_key(
+            source,
+            archive_sha256(source, &revision),
+            subdirectory,
+            tags,
+        );
+        self.upload_remote(remote_key.as_ref(), &cache_shard, &disk_filename, client)
+            .await;
+
         // Store the metadata.
-        let metadata_entry = cache_shard.entry(METADATA);
         write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
             .await
             .map_err(Error::CacheWrite)?;
@@ -573,12 +627,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         };
 
         // If there are build settings, we need to scope to a cache shard.
-        let config_settings = self.build_context.config_settings();
-        let cache_shard = if config_settings.is_empty() {
-            cache_shard
-        } else {
-            cache_shard.shard(cache_digest(config_settings))
-        };
+        let cache_shard = self.build_shard(source, cache_shard);
 
         // Otherwise, we either need to build the metadata.
         // If the backend supports `prepare_metadata_for_build_wheel`, use it.
@@ -658,7 +707,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     .freshness(&cache_entry, source.name())
                     .map_err(Error::CacheRead)?,
             ),
-            Connectivity::Offline => CacheControl::AllowStale,
+            Connectivity::Offline | Connectivity::StrictOffline => CacheControl::AllowStale,
         };
 
         let download = |response| {
@@ -681,7 +730,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         };
         let req = Self::request(url.clone(), client.unmanaged)?;
         let revision = client
-            .managed(|client| {
+            .managed_for(url, |client| {
                 client
                     .cached_client()
                     .get_serde(req, &cache_entry, cache_control, download)
@@ -697,7 +746,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             Ok(revision)
         } else {
             client
-                .managed(|client| async move {
+                .managed_for(url, |client| async move {
                     client
                         .cached_client()
                         .skip_cache(Self::request(url.clone(), client)?, &cache_entry, download)
@@ -745,12 +794,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let source_entry = cache_shard.entry("source");
 
         // If there are build settings, we need to scope to a cache shard.
-        let config_settings = self.build_context.config_settings();
-        let cache_shard = if config_settings.is_empty() {
-            cache_shard
-        } else {
-            cache_shard.shard(cache_digest(config_settings))
-        };
+        let cache_shard = self.build_shard(source, cache_shard);
 
         // If the cache contains a compatible wheel, return it.
         if let Some(built_wheel) = BuiltWheelMetadata::find_in_cache(tags, &cache_shard) {
@@ -882,12 +926,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         }
 
         // If there are build settings, we need to scope to a cache shard.
-        let config_settings = self.build_context.config_settings();
-        let cache_shard = if config_settings.is_empty() {
-            cache_shard
-        } else {
-            cache_shard.shard(cache_digest(config_settings))
-        };
+        let cache_shard = self.build_shard(source, cache_shard);
 
         // Otherwise, we need to build a wheel.
         let task = self
@@ -1012,12 +1051,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let cache_shard = cache_shard.shard(revision.id());
 
         // If there are build settings, we need to scope to a cache shard.
-        let config_settings = self.build_context.config_settings();
-        let cache_shard = if config_settings.is_empty() {
-            cache_shard
-        } else {
-            cache_shard.shard(cache_digest(config_settings))
-        };
+        let cache_shard = self.build_shard(source, cache_shard);
 
         // If the cache contains a compatible wheel, return it.
         if let Some(built_wheel) = BuiltWheelMetadata::find_in_cache(tags, &cache_shard) {
@@ -1087,6 +1121,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     None,
                     self.build_context.locations(),
                     self.build_context.sources(),
+                    self.build_context.variants(),
                     self.build_context.bounds(),
                 )
                 .await?,
@@ -1124,6 +1159,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     None,
                     self.build_context.locations(),
                     self.build_context.sources(),
+                    self.build_context.variants(),
                     self.build_context.bounds(),
                 )
                 .await?,
@@ -1156,6 +1192,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     None,
                     self.build_context.locations(),
                     self.build_context.sources(),
+                    self.build_context.variants(),
                     self.build_context.bounds(),
                 )
                 .await?,
@@ -1163,12 +1200,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         }
 
         // If there are build settings, we need to scope to a cache shard.
-        let config_settings = self.build_context.config_settings();
-        let cache_shard = if config_settings.is_empty() {
-            cache_shard
-        } else {
-            cache_shard.shard(cache_digest(config_settings))
-        };
+        let cache_shard = self.build_shard(source, cache_shard);
 
         // Otherwise, we need to build a wheel.
         let task = self
@@ -1204,6 +1236,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 None,
                 self.build_context.locations(),
                 self.build_context.sources(),
+                self.build_context.variants(),
                 self.build_context.bounds(),
             )
             .await?,
@@ -1274,6 +1307,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .git()
             .fetch(
                 resource.git,
+                resource.subdirectory,
                 client.unmanaged.uncached_client(resource.url).clone(),
                 self.build_context.cache().bucket(CacheBucket::Git),
                 self.reporter.clone().map(Facade::from),
@@ -1290,18 +1324,34 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let _lock = lock_shard(&cache_shard).await?;
 
         // If there are build settings, we need to scope to a cache shard.
-        let config_settings = self.build_context.config_settings();
-        let cache_shard = if config_settings.is_empty() {
-            cache_shard
-        } else {
-            cache_shard.shard(cache_digest(config_settings))
-        };
+        let cache_shard = self.build_shard(source, cache_shard);
 
         // If the cache contains a compatible wheel, return it.
         if let Some(built_wheel) = BuiltWheelMetadata::find_in_cache(tags, &cache_shard) {
             return Ok(built_wheel);
         }
 
+        // If the remote build cache contains a compatible wheel, return it.
+        let remote_key = self.remote_key(
+            source,
+            Some(format!("git:{git_sha}")),
+            resource.subdirectory,
+            tags,
+        );
+        if let Some(built_wheel) = self
+            .fetch_remote(
+                source,
+                remote_key.as_ref(),
+                tags,
+                &cache_shard,
+                &metadata_entry,
+                client,
+            )
+            .await?
+        {
+            return Ok(built_wheel);
+        }
+
         let task = self
             .reporter
             .as_ref()
@@ -1323,6 +1373,10 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             }
         }
 
+        // Share the wheel via the remote build cache.
+        self.upload_remote(remote_key.as_ref(), &cache_shard, &disk_filename, client)
+            .await;
+
         // Store the metadata.
         write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
             .await
@@ -1359,6 +1413,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .git()
             .fetch(
                 resource.git,
+                resource.subdirectory,
                 client.unmanaged.uncached_client(resource.url).clone(),
                 self.build_context.cache().bucket(CacheBucket::Git),
                 self.reporter.clone().map(Facade::from),
@@ -1394,6 +1449,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     Some(&git_member),
                     self.build_context.locations(),
                     self.build_context.sources(),
+                    self.build_context.variants(),
                     self.build_context.bounds(),
                 )
                 .await?,
@@ -1423,6 +1479,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                         None,
                         self.build_context.locations(),
                         self.build_context.sources(),
+                        self.build_context.variants(),
                         self.build_context.bounds(),
                     )
                     .await?,
@@ -1456,6 +1513,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     None,
                     self.build_context.locations(),
                     self.build_context.sources(),
+                    self.build_context.variants(),
                     self.build_context.bounds(),
                 )
                 .await?,
@@ -1463,13 +1521,274 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         }
 
         // If there are build settings, we need to scope to a cache shard.
-        let config_settings = self.build_context.config_settings();
-        let cache_shard = if config_settings.is_empty() {
-            cache_shard
+        let cache_shard = self.build_shard(source, cache_shard);
+
+        // Otherwise, we need to build a wheel.
+        let task = self
+            .reporter
+            .as_ref()
+            .map(|reporter| reporter.on_build_start(source));
+
+        let (_disk_filename, _filename, metadata) = self
+            .build_distribution(
+                source,
+                fetch.path(),
+                resource.subdirectory,
+                &cache_shard,
+                self.build_context.sources(),
+            )
+            .await?;
+
+        if let Some(task) = task {
+            if let Some(reporter) = self.reporter.as_ref() {
+                reporter.on_build_complete(source, task);
+            }
+        }
+
+        // Store the metadata.
+        write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
+            .await
+            .map_err(Error::CacheWrite)?;
+
+        Ok(ArchiveMetadata::from(
+            Metadata::from_workspace(
+                metadata,
+                fetch.path(),
+                None,
+                self.build_context.locations(),
+                self.build_context.sources(),
+                self.build_context.variants(),
+                self.build_context.bounds(),
+            )
+            .await?,
+        ))
+    }
+
+    /// Build a source distribution from a Mercurial repository.
+    async fn hg(
+        &self,
+        source: &BuildableSource<'_>,
+        resource: &HgSourceUrl<'_>,
+        tags: &Tags,
+        hashes: HashPolicy<'_>,
+        client: &ManagedClient<'_>,
+    ) -> Result<BuiltWheelMetadata, Error> {
+        // Before running the build, check that the hashes match.
+        if hashes.is_validate() {
+            return Err(Error::HashesNotSupportedHg(source.to_string()));
+        }
+
+        // Fetch the Mercurial repository.
+        let fetch = self
+            .build_context
+            .hg()
+            .fetch(
+                resource.hg,
+                self.build_context.cache().bucket(CacheBucket::Hg),
+            )
+            .await?;
+
+        let node = fetch
+            .hg()
+            .precise()
+            .expect("Exact changeset after checkout");
+        let cache_shard = self.build_context.cache().shard(
+            CacheBucket::SourceDistributions,
+            WheelCache::Hg(resource.url, &node.to_short_string()).root(),
+        );
+        let metadata_entry = cache_shard.entry(METADATA);
+
+        let _lock = lock_shard(&cache_shard).await?;
+
+        // If there are build settings, we need to scope to a cache shard.
+        let cache_shard = self.build_shard(source, cache_shard);
+
+        // If the cache contains a compatible wheel, return it.
+        if let Some(built_wheel) = BuiltWheelMetadata::find_in_cache(tags, &cache_shard) {
+            return Ok(built_wheel);
+        }
+
+        // If the remote build cache contains a compatible wheel, return it.
+        let remote_key = self.remote_key(
+            source,
+            Some(format!("hg:{node}")),
+            resource.subdirectory,
+            tags,
+        );
+        if let Some(built_wheel) = self
+            .fetch_remote(
+                source,
+                remote_key.as_ref(),
+                tags,
+                &cache_shard,
+                &metadata_entry,
+                client,
+            )
+            .await?
+        {
+            return Ok(built_wheel);
+        }
+
+        let task = self
+            .reporter
+            .as_ref()
+            .map(|reporter| reporter.on_build_start(source));
+
+        let (disk_filename, filename, metadata) = self
+            .build_distribution(
+                source,
+                fetch.path(),
+                resource.subdirectory,
+                &cache_shard,
+                self.build_context.sources(),
+            )
+            .await?;
+
+        if let Some(task) = task {
+            if let Some(reporter) = self.reporter.as_ref() {
+                reporter.on_build_complete(source, task);
+            }
+        }
+
+        // Share the wheel via the remote build cache.
+        self.upload_remote(remote_key.as_ref(), &cache_shard, &disk_filename, client)
+            .await;
+
+        // Store the metadata.
+        write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
+            .await
+            .map_err(Error::CacheWrite)?;
+
+        Ok(BuiltWheelMetadata {
+            path: cache_shard.join(&disk_filename),
+            target: cache_shard.join(filename.stem()),
+            filename,
+            hashes: vec![],
+            cache_info: CacheInfo::default(),
+        })
+    }
+
+    /// Build the source distribution's metadata from a Mercurial repository.
+    ///
+    /// If the build backend supports `prepare_metadata_for_build_wheel`, this method will avoid
+    /// building the wheel.
+    async fn hg_metadata(
+        &self,
+        source: &BuildableSource<'_>,
+        resource: &HgSourceUrl<'_>,
+        hashes: HashPolicy<'_>,
+    ) -> Result<ArchiveMetadata, Error> {
+        // Before running the build, check that the hashes match.
+        if hashes.is_validate() {
+            return Err(Error::HashesNotSupportedHg(source.to_string()));
+        }
+
+        // Fetch the Mercurial repository.
+        let fetch = self
+            .build_context
+            .hg()
+            .fetch(
+                resource.hg,
+                self.build_context.cache().bucket(CacheBucket::Hg),
+            )
+            .await?;
+
+        let node = fetch
+            .hg()
+            .precise()
+            .expect("Exact changeset after checkout");
+        let cache_shard = self.build_context.cache().shard(
+            CacheBucket::SourceDistributions,
+            WheelCache::Hg(resource.url, &node.to_short_string()).root(),
+        );
+        let metadata_entry = cache_shard.entry(METADATA);
+
+        let _lock = lock_shard(&cache_shard).await?;
+
+        let path = if let Some(subdirectory) = resource.subdirectory {
+            Cow::Owned(fetch.path().join(subdirectory))
         } else {
-            cache_shard.shard(cache_digest(config_settings))
+            Cow::Borrowed(fetch.path())
         };
 
+        if let Some(metadata) =
+            Self::read_static_metadata(source, fetch.path(), resource.subdirectory).await?
+        {
+            return Ok(ArchiveMetadata::from(
+                Metadata::from_workspace(
+                    metadata,
+                    &path,
+                    None,
+                    self.build_context.locations(),
+                    self.build_context.sources(),
+                    self.build_context.variants(),
+                    self.build_context.bounds(),
+                )
+                .await?,
+            ));
+        }
+
+        // If the cache contains compatible metadata, return it.
+        if self
+            .build_context
+            .cache()
+            .freshness(&metadata_entry, source.name())
+            .map_err(Error::CacheRead)?
+            .is_fresh()
+        {
+            if let Some(metadata) = read_cached_metadata(&metadata_entry).await? {
+                debug!("Using cached metadata for: {source}");
+                return Ok(ArchiveMetadata::from(
+                    Metadata::from_workspace(
+                        metadata,
+                        &path,
+                        None,
+                        self.build_context.locations(),
+                        self.build_context.sources(),
+                        self.build_context.variants(),
+                        self.build_context.bounds(),
+                    )
+                    .await?,
+                ));
+            }
+        }
+
+        // If the backend supports `prepare_metadata_for_build_wheel`, use it.
+        if let Some(metadata) = self
+            .build_metadata(
+                source,
+                fetch.path(),
+                resource.subdirectory,
+                self.build_context.sources(),
+            )
+            .boxed_local()
+            .await?
+        {
+            // Store the metadata.
+            fs::create_dir_all(metadata_entry.dir())
+                .await
+                .map_err(Error::CacheWrite)?;
+            write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
+                .await
+                .map_err(Error::CacheWrite)?;
+
+            return Ok(ArchiveMetadata::from(
+                Metadata::from_workspace(
+                    metadata,
+                    &path,
+                    None,
+                    self.build_context.locations(),
+                    self.build_context.sources(),
+                    self.build_context.variants(),
+                    self.build_context.bounds(),
+                )
+                .await?,
+            ));
+        }
+
+        // If there are build settings, we need to scope to a cache shard.
+        let cache_shard = self.build_shard(source, cache_shard);
+
         // Otherwise, we need to build a wheel.
         let task = self
             .reporter
@@ -1504,6 +1823,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 None,
                 self.build_context.locations(),
                 self.build_context.sources(),
+                self.build_context.variants(),
                 self.build_context.bounds(),
             )
             .await?,
@@ -1522,6 +1842,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     .git()
                     .fetch(
                         &source.git,
+                        source.subdirectory.as_deref(),
                         client.unmanaged.uncached_client(&source.url).clone(),
                         self.build_context.cache().bucket(CacheBucket::Git),
                         self.reporter.clone().map(Facade::from),
@@ -1533,12 +1854,31 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     .git()
                     .fetch(
                         source.git,
+                        source.subdirectory,
                         client.unmanaged.uncached_client(source.url).clone(),
                         self.build_context.cache().bucket(CacheBucket::Git),
                         self.reporter.clone().map(Facade::from),
                     )
                     .await?;
             }
+            BuildableSource::Dist(SourceDist::Hg(source)) => {
+                self.build_context
+                    .hg()
+                    .fetch(
+                        &source.hg,
+                        self.build_context.cache().bucket(CacheBucket::Hg),
+                    )
+                    .await?;
+            }
+            BuildableSource::Url(SourceUrl::Hg(source)) => {
+                self.build_context
+                    .hg()
+                    .fetch(
+                        source.hg,
+                        self.build_context.cache().bucket(CacheBucket::Hg),
+                    )
+                    .await?;
+            }
             _ => {}
         }
         Ok(())
@@ -1591,7 +1931,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .instrument(info_span!("download", source_dist = %source))
         };
         client
-            .managed(|client| async move {
+            .managed_for(url, |client| async move {
                 client
                     .cached_client()
                     .skip_cache(Self::request(url.clone(), client)?, &cache_entry, download)
@@ -1723,6 +2063,127 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         Ok(hashes)
     }
 
+    /// Scope the cache shard to the settings that affect the built wheel, i.e., the config
+    /// settings and the container image in which the wheel is built, if any.
+    fn build_shard(&self, source: &BuildableSource<'_>, cache_shard: CacheShard) -> CacheShard {
+        let config_settings = self.build_context.config_settings();
+        let cache_shard = if config_settings.is_empty() {
+            cache_shard
+        } else {
+            cache_shard.shard(cache_digest(config_settings))
+        };
+        match self.build_context.build_containers().image(source.name()) {
+            Some(image) => cache_shard.shard(cache_digest(&format!("container:{image}"))),
+            None => cache_shard,
+        }
+    }
+
+    /// Compute the key for the wheel built from the given source in the remote build cache.
+    ///
+    /// Returns `None` if no remote build cache is configured, or if the source can't be uniquely
+    /// identified (e.g., a direct URL archive for which no hash was computed).
+    fn remote_key(
+        &self,
+        source: &BuildableSource<'_>,
+        source_id: Option<String>,
+        subdirectory: Option<&Path>,
+        tags: &Tags,
+    ) -> Option<RemoteKey> {
+        self.remote.as_ref()?;
+        RemoteKey::new(
+            &source_id?,
+            subdirectory,
+            self.build_context.config_settings(),
+            self.build_context.build_containers().image(source.name()),
+            self.build_context.cache().namespace(),
+            tags,
+        )
+    }
+
+    /// Fetch a compatible wheel from the remote build cache into the cache shard, storing its
+    /// metadata alongside it.
+    async fn fetch_remote(
+        &self,
+        source: &BuildableSource<'_>,
+        key: Option<&RemoteKey>,
+        tags: &Tags,
+        cache_shard: &CacheShard,
+        metadata_entry: &CacheEntry,
+        client: &ManagedClient<'_>,
+    ) -> Result<Option<BuiltWheelMetadata>, Error> {
+        let (Some(remote), Some(key)) = (self.remote.as_ref(), key) else {
+            return Ok(None);
+        };
+        let Some(disk_filename) = remote
+            .fetch(
+                key,
+                client.unmanaged.uncached_client(remote.url()),
+                cache_shard,
+            )
+            .await
+        else {
+            return Ok(None);
+        };
+        let path = cache_shard.join(&disk_filename);
+
+        // Validate the wheel, falling back to a local build if it doesn't match the source.
+        let wheel = WheelFilename::from_str(&disk_filename)
+            .map_err(Error::from)
+            .and_then(|filename| {
+                let metadata = read_wheel_metadata(&filename, &path)?;
+                validate(source, &metadata)?;
+                Ok((filename, metadata))
+            });
+        let (filename, metadata) = match wheel {
+            Ok((filename, metadata)) if filename.is_compatible(tags) => (filename, metadata),
+            Ok((filename, _)) => {
+                debug!("Ignoring incompatible wheel from remote build cache: {filename}");
+                fs::remove_file(&path).await.map_err(Error::CacheWrite)?;
+                return Ok(None);
+            }
+            Err(err) => {
+                warn_user_once!(
+                    "Ignoring invalid wheel from the remote build cache for {source}: {err}"
+                );
+                fs::remove_file(&path).await.map_err(Error::CacheWrite)?;
+                return Ok(None);
+            }
+        };
+
+        write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
+            .await
+            .map_err(Error::CacheWrite)?;
+
+        Ok(Some(BuiltWheelMetadata {
+            target: cache_shard.join(filename.stem()),
+            path,
+            filename,
+            hashes: vec![],
+            cache_info: CacheInfo::default(),
+        }))
+    }
+
+    /// Upload a built wheel to the remote build cache, if configured.
+    async fn upload_remote(
+        &self,
+        key: Option<&RemoteKey>,
+        cache_shard: &CacheShard,
+        disk_filename: &str,
+        client: &ManagedClient<'_>,
+    ) {
+        let (Some(remote), Some(key)) = (self.remote.as_ref(), key) else {
+            return;
+        };
+        remote
+            .upload(
+                key,
+                client.unmanaged.uncached_client(remote.url()),
+                &cache_shard.join(disk_filename),
+                disk_filename,
+            )
+            .await;
+    }
+
     /// Build a source distribution, storing the built wheel in the cache.
     ///
     /// Returns the un-normalized disk filename, the parsed, normalized filename and the metadata
@@ -1751,6 +2212,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         }
 
         // Build the wheel.
+        let start = std::time::Instant::now();
         fs::create_dir_all(&cache_shard)
             .await
             .map_err(Error::CacheWrite)?;
@@ -1783,6 +2245,16 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         // Validate the metadata.
         validate(source, &metadata)?;
 
+        // Record the build, for `uv stats`. Editable builds are excluded, since they're rebuilt
+        // whenever the project changes.
+        if !source.is_editable() {
+            self.build_context.cache().recorder().record_build(
+                &filename.name,
+                filename.version.to_string(),
+                start.elapsed(),
+            );
+        }
+
         debug!("Finished building: {source}");
         Ok((disk_filename, filename, metadata))
     }
@@ -2296,6 +2768,23 @@ async fn read_cached_metadata(
 }
 
 /// Read the [`ResolutionMetadata`] from a built wheel.
+/// Return the SHA-256 of a source distribution archive, if known, to identify it in the remote
+/// build cache.
+///
+/// For registry distributions, the hash is typically provided by the index; otherwise, it's only
+/// available if it was computed when downloading the archive.
+fn archive_sha256(source: &BuildableSource<'_>, revision: &Revision) -> Option<String> {
+    let registry_hashes = match source.as_dist() {
+        Some(SourceDist::Registry(dist)) => dist.file.hashes.as_slice(),
+        _ => &[],
+    };
+    registry_hashes
+        .iter()
+        .chain(revision.hashes())
+        .find(|digest| digest.algorithm == HashAlgorithm::Sha256)
+        .map(|digest| format!("sha256:{}", digest.digest))
+}
+
 fn read_wheel_metadata(
     filename: &WheelFilename,
     wheel: &Path,
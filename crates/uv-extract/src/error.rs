@@ -8,6 +8,10 @@ pub enum Error {
     AsyncZip(#[from] async_zip::error::ZipError),
     #[error(transparent)]
     Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Join(#[from] tokio::task::JoinError),
+    #[error(transparent)]
+    Acquire(#[from] tokio::sync::AcquireError),
     #[error("Unsupported archive type: {0}")]
     UnsupportedArchive(PathBuf),
     #[error(
@@ -29,6 +33,16 @@ impl Error {
         )
     }
 
+    /// Returns `true` if the error is due to the synchronous ZIP reader not supporting a feature
+    /// of the archive, like a Zstandard-compressed entry. Such archives can typically be
+    /// extracted with the streaming reader instead.
+    pub fn is_unsupported_by_sync_reader(&self) -> bool {
+        matches!(
+            self,
+            Self::Zip(zip::result::ZipError::UnsupportedArchive(_))
+        )
+    }
+
     /// Returns `true` if the error is due to HTTP streaming request failed.
     pub fn is_http_streaming_failed(&self) -> bool {
         match self {
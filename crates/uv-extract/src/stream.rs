@@ -1,8 +1,13 @@
+use std::num::NonZeroUsize;
 use std::path::{Component, Path, PathBuf};
 use std::pin::Pin;
+use std::sync::{Arc, LazyLock};
 
-use futures::StreamExt;
+use futures::{AsyncReadExt, StreamExt};
 use rustc_hash::FxHashSet;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
 use tokio_util::compat::{FuturesAsyncReadCompatExt, TokioAsyncReadCompatExt};
 use tracing::warn;
 
@@ -12,11 +17,31 @@ use crate::Error;
 
 const DEFAULT_BUF_SIZE: usize = 128 * 1024;
 
+/// The largest file that is decompressed into memory and handed off to a background writer, such
+/// that the next entry can be decompressed while the file is written to disk. Larger files are
+/// streamed to disk directly.
+const MAX_BUFFERED_FILE_SIZE: usize = 4 * 1024 * 1024;
+
+/// The number of files that may be written to disk in the background at any given time.
+///
+/// The permits are shared by all archives that are being unpacked concurrently, which bounds both
+/// the number of blocking threads and the memory held in decompressed buffers (at most
+/// [`MAX_BUFFERED_FILE_SIZE`] per permit).
+static WRITE_PERMITS: LazyLock<Arc<Semaphore>> = LazyLock::new(|| {
+    let threads = std::thread::available_parallelism()
+        .map(NonZeroUsize::get)
+        .unwrap_or(1);
+    Arc::new(Semaphore::new(threads))
+});
+
 /// Unpack a `.zip` archive into the target directory, without requiring `Seek`.
 ///
 /// This is useful for unzipping files as they're being downloaded. If the archive
 /// is already fully on disk, consider using `unzip_archive`, which can use multiple
 /// threads to work faster in that case.
+///
+/// Small and medium-sized files are decompressed into memory and written to disk on a bounded
+/// pool of blocking threads, such that downloading, decompression, and disk writes overlap.
 pub async fn unzip<R: tokio::io::AsyncRead + Unpin>(
     reader: R,
     target: impl AsRef<Path>,
@@ -46,6 +71,7 @@ pub async fn unzip<R: tokio::io::AsyncRead + Unpin>(
     let mut zip = async_zip::base::read::stream::ZipFileReader::new(&mut reader);
 
     let mut directories = FxHashSet::default();
+    let mut writes = JoinSet::new();
 
     while let Some(mut entry) = zip.next_with_entry().await? {
         // Construct the (expected) path to the file on-disk.
@@ -76,15 +102,51 @@ pub async fn unzip<R: tokio::io::AsyncRead + Unpin>(
             }
 
             // We don't know the file permissions here, because we haven't seen the central directory yet.
-            let file = fs_err::tokio::File::create(&path).await?;
             let size = entry.reader().entry().uncompressed_size();
-            let mut writer = if let Ok(size) = usize::try_from(size) {
-                tokio::io::BufWriter::with_capacity(std::cmp::min(size, 1024 * 1024), file)
+            if size <= MAX_BUFFERED_FILE_SIZE as u64 {
+                // Wait for a writer to become available before decompressing the file, to bound
+                // the amount of memory held in buffers.
+                let permit = WRITE_PERMITS.clone().acquire_owned().await?;
+
+                // Decompress the file into memory, then write it to disk in the background.
+                //
+                // The size in the local header is zero for entries that are followed by a data
+                // descriptor, so it can't be trusted to bound the buffer. Instead, read at most one
+                // byte beyond the limit, and stream the remainder of any larger file to disk.
+                let mut contents = Vec::with_capacity(usize::try_from(size).unwrap_or(0));
+                entry
+                    .reader_mut()
+                    .take(MAX_BUFFERED_FILE_SIZE as u64 + 1)
+                    .read_to_end(&mut contents)
+                    .await?;
+                if contents.len() <= MAX_BUFFERED_FILE_SIZE {
+                    writes.spawn_blocking(move || {
+                        let _permit = permit;
+                        fs_err::write(&path, contents)
+                    });
+                } else {
+                    drop(permit);
+                    let file = fs_err::tokio::File::create(&path).await?;
+                    let mut writer = tokio::io::BufWriter::with_capacity(1024 * 1024, file);
+                    writer.write_all(&contents).await?;
+                    let mut reader = entry.reader_mut().compat();
+                    tokio::io::copy(&mut reader, &mut writer).await?;
+                }
             } else {
-                tokio::io::BufWriter::new(file)
-            };
-            let mut reader = entry.reader_mut().compat();
-            tokio::io::copy(&mut reader, &mut writer).await?;
+                let file = fs_err::tokio::File::create(&path).await?;
+                let mut writer = if let Ok(size) = usize::try_from(size) {
+                    tokio::io::BufWriter::with_capacity(std::cmp::min(size, 1024 * 1024), file)
+                } else {
+                    tokio::io::BufWriter::new(file)
+                };
+                let mut reader = entry.reader_mut().compat();
+                tokio::io::copy(&mut reader, &mut writer).await?;
+            }
+        }
+
+        // Surface any errors from completed writes early.
+        while let Some(result) = writes.try_join_next() {
+            result??;
         }
 
         // Close current file prior to proceeding, as per:
@@ -92,6 +154,11 @@ pub async fn unzip<R: tokio::io::AsyncRead + Unpin>(
         zip = entry.skip().await?;
     }
 
+    // Wait for all pending writes, such that every file exists before we set its permissions.
+    while let Some(result) = writes.join_next().await {
+        result??;
+    }
+
     // On Unix, we need to set file permissions, which are stored in the central directory, at the
     // end of the archive. The `ZipFileReader` reads until it sees a central directory signature,
     // which indicates the first entry in the central directory. So we continue reading from there.
@@ -308,3 +375,6 @@ pub async fn archive<R: tokio::io::AsyncRead + Unpin>(
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests;
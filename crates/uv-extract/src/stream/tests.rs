@@ -0,0 +1,99 @@
+use std::io::Cursor;
+
+use async_zip::base::write::ZipFileWriter;
+use async_zip::{Compression, ZipEntryBuilder};
+use futures::AsyncWriteExt;
+
+use crate::stream::{unzip, MAX_BUFFERED_FILE_SIZE};
+
+/// Build a `.zip` archive with a single entry, compressed with the given method.
+async fn archive(name: &str, contents: &[u8], compression: Compression) -> Vec<u8> {
+    let mut writer = ZipFileWriter::with_tokio(Vec::new());
+    writer
+        .write_entry_whole(
+            ZipEntryBuilder::new(name.to_string().into(), compression),
+            contents,
+        )
+        .await
+        .unwrap();
+    writer.close().await.unwrap().into_inner()
+}
+
+#[tokio::test]
+async fn unzip_zstd() {
+    let archive = archive("pkg/__init__.py", b"print('hello')\n", Compression::Zstd).await;
+
+    let target = tempfile::tempdir().unwrap();
+    unzip(archive.as_slice(), target.path()).await.unwrap();
+
+    let contents = fs_err::read_to_string(target.path().join("pkg").join("__init__.py")).unwrap();
+    assert_eq!(contents, "print('hello')\n");
+}
+
+/// The synchronous reader doesn't support Zstandard, and should report the archive as such, such
+/// that callers can fall back to the streaming reader.
+#[tokio::test]
+async fn unzip_zstd_sync_unsupported() {
+    let archive = archive("pkg/__init__.py", b"print('hello')\n", Compression::Zstd).await;
+
+    let target = tempfile::tempdir().unwrap();
+    let err = crate::unzip(Cursor::new(archive), target.path()).unwrap_err();
+    assert!(err.is_unsupported_by_sync_reader(), "{err}");
+}
+
+/// Build a `.zip` archive with a single entry that's followed by a data descriptor, such that its
+/// local header doesn't include its size.
+async fn archive_streamed(name: &str, contents: &[u8]) -> Vec<u8> {
+    let mut writer = ZipFileWriter::with_tokio(Vec::new());
+    let mut entry = writer
+        .write_entry_stream(ZipEntryBuilder::new(
+            name.to_string().into(),
+            Compression::Deflate,
+        ))
+        .await
+        .unwrap();
+    entry.write_all(contents).await.unwrap();
+    entry.close().await.unwrap();
+    writer.close().await.unwrap().into_inner()
+}
+
+#[tokio::test]
+async fn unzip_data_descriptor() {
+    let archive = archive_streamed("pkg/__init__.py", b"print('hello')\n").await;
+
+    let target = tempfile::tempdir().unwrap();
+    unzip(archive.as_slice(), target.path()).await.unwrap();
+
+    let contents = fs_err::read_to_string(target.path().join("pkg").join("__init__.py")).unwrap();
+    assert_eq!(contents, "print('hello')\n");
+}
+
+/// A file that exceeds the in-memory buffer, but whose local header reports a size of zero, should
+/// be streamed to disk in full.
+#[tokio::test]
+async fn unzip_data_descriptor_large() {
+    let contents = (0..MAX_BUFFERED_FILE_SIZE + 1024)
+        .map(|i| u8::try_from(i % 251).unwrap())
+        .collect::<Vec<_>>();
+    let archive = archive_streamed("pkg/data.bin", &contents).await;
+
+    let target = tempfile::tempdir().unwrap();
+    unzip(archive.as_slice(), target.path()).await.unwrap();
+
+    let written = fs_err::read(target.path().join("pkg").join("data.bin")).unwrap();
+    assert_eq!(written.len(), contents.len());
+    assert!(written == contents);
+}
+
+/// A file exactly at the limit should be buffered and written in full.
+#[tokio::test]
+async fn unzip_data_descriptor_limit() {
+    let contents = vec![b'a'; MAX_BUFFERED_FILE_SIZE];
+    let archive = archive_streamed("pkg/data.bin", &contents).await;
+
+    let target = tempfile::tempdir().unwrap();
+    unzip(archive.as_slice(), target.path()).await.unwrap();
+
+    let written = fs_err::read(target.path().join("pkg").join("data.bin")).unwrap();
+    assert_eq!(written.len(), MAX_BUFFERED_FILE_SIZE);
+}
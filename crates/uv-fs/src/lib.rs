@@ -60,9 +60,12 @@ pub fn replace_symlink(src: impl AsRef<Path>, dst: impl AsRef<Path>) -> std::io:
         ));
     }
 
+    // The junction APIs don't support long paths, so use an extended-length path for the link.
+    let dst = extended_length_path(dunce::simplified(dst.as_ref()));
+
     // Remove the existing symlink, if any.
-    match junction::delete(dunce::simplified(dst.as_ref())) {
-        Ok(()) => match fs_err::remove_dir_all(dst.as_ref()) {
+    match junction::delete(&dst) {
+        Ok(()) => match fs_err::remove_dir_all(&dst) {
             Ok(()) => {}
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
             Err(err) => return Err(err),
@@ -71,11 +74,9 @@ pub fn replace_symlink(src: impl AsRef<Path>, dst: impl AsRef<Path>) -> std::io:
         Err(err) => return Err(err),
     };
 
-    // Replace it with a new symlink.
-    junction::create(
-        dunce::simplified(src.as_ref()),
-        dunce::simplified(dst.as_ref()),
-    )
+    // Replace it with a new symlink. The target must be an extended-length path, too, as it's
+    // resolved via `GetFullPathNameW`, which fails for paths that exceed `MAX_PATH`.
+    junction::create(extended_length_path(dunce::simplified(src.as_ref())), &dst)
 }
 
 /// Create a symlink at `dst` pointing to `src`, replacing any existing symlink if necessary.
@@ -127,8 +128,11 @@ pub fn symlink_or_copy_file(src: impl AsRef<Path>, dst: impl AsRef<Path>) -> std
 
 #[cfg(windows)]
 pub fn remove_symlink(path: impl AsRef<Path>) -> std::io::Result<()> {
-    match junction::delete(dunce::simplified(path.as_ref())) {
-        Ok(()) => match fs_err::remove_dir_all(path.as_ref()) {
+    // The junction APIs don't support long paths, so use an extended-length path.
+    let path = extended_length_path(dunce::simplified(path.as_ref()));
+
+    match junction::delete(&path) {
+        Ok(()) => match fs_err::remove_dir_all(&path) {
             Ok(()) => Ok(()),
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
             Err(err) => Err(err),
@@ -138,6 +142,28 @@ pub fn remove_symlink(path: impl AsRef<Path>) -> std::io::Result<()> {
     }
 }
 
+/// Returns `true` if an operation failed because its source and destination are on different
+/// devices (or, on Windows, different volumes), e.g., when hard-linking or renaming a file.
+pub fn is_cross_device(err: &std::io::Error) -> bool {
+    // `std::io::ErrorKind::CrossesDevices` requires Rust 1.83, which is newer than our MSRV, so
+    // match the platform error codes instead.
+    #[cfg(windows)]
+    {
+        #[allow(clippy::cast_possible_wrap)]
+        let not_same_device = windows_sys::Win32::Foundation::ERROR_NOT_SAME_DEVICE as i32;
+        err.raw_os_error() == Some(not_same_device)
+    }
+    #[cfg(unix)]
+    {
+        rustix::io::Errno::from_io_error(err) == Some(rustix::io::Errno::XDEV)
+    }
+    #[cfg(not(any(unix, windows)))]
+    {
+        let _ = err;
+        false
+    }
+}
+
 /// Return a [`NamedTempFile`] in the specified directory.
 ///
 /// Sets the permissions of the temporary file to `0o666`, to match the non-temporary file default.
@@ -443,3 +469,6 @@ impl<Reader: tokio::io::AsyncRead + Unpin, Callback: Fn(usize) + Unpin> tokio::i
             })
     }
 }
+
+#[cfg(test)]
+mod tests;
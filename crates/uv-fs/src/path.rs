@@ -250,6 +250,56 @@ pub fn canonicalize_executable(path: impl AsRef<Path>) -> std::io::Result<PathBu
     }
 }
 
+/// On Windows, add an extended-length (`\\?\`) prefix to an absolute path that exceeds
+/// `MAX_PATH`, such that APIs that don't handle long paths themselves (e.g., the junction APIs)
+/// can operate on it.
+///
+/// The standard library adds the prefix automatically, so this is only required when passing
+/// paths to other Windows APIs. Since extended-length paths are passed to the filesystem verbatim,
+/// the path is normalized first.
+///
+/// On other platforms, or if the path is short enough, the path is returned unchanged.
+pub fn extended_length_path(path: &Path) -> Cow<'_, Path> {
+    #[cfg(windows)]
+    {
+        use std::path::Prefix;
+
+        const MAX_PATH: usize = 260;
+
+        if path.as_os_str().len() < MAX_PATH || !path.is_absolute() {
+            return Cow::Borrowed(path);
+        }
+
+        let Some(Component::Prefix(prefix)) = path.components().next() else {
+            return Cow::Borrowed(path);
+        };
+        match prefix.kind() {
+            Prefix::Disk(_) => {
+                let mut extended = std::ffi::OsString::from(r"\\?\");
+                extended.push(normalize_path(path));
+                Cow::Owned(PathBuf::from(extended))
+            }
+            Prefix::UNC(..) => {
+                // `\\server\share\path` becomes `\\?\UNC\server\share\path`.
+                let normalized = normalize_path(path);
+                let Some(stripped) = normalized
+                    .to_str()
+                    .and_then(|path| path.strip_prefix(r"\\"))
+                else {
+                    return Cow::Borrowed(path);
+                };
+                Cow::Owned(PathBuf::from(format!(r"\\?\UNC\{stripped}")))
+            }
+            // The path is already in extended-length (or device) form.
+            _ => Cow::Borrowed(path),
+        }
+    }
+    #[cfg(not(windows))]
+    {
+        Cow::Borrowed(path)
+    }
+}
+
 /// Compute a path describing `path` relative to `base`.
 ///
 /// `lib/python/site-packages/foo/__init__.py` and `lib/python/site-packages` -> `foo/__init__.py`
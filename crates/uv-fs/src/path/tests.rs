@@ -101,3 +101,31 @@ fn test_normalize_relative() {
         assert_eq!(normalize_path(Path::new(input)), Path::new(expected));
     }
 }
+
+#[test]
+fn test_extended_length_path() {
+    // Short paths are returned unchanged.
+    let path = Path::new("/home/ferris/carcinization/lib/python/site-packages");
+    assert_eq!(extended_length_path(path), path);
+}
+
+#[test]
+#[cfg(windows)]
+fn test_extended_length_path_windows() {
+    let long = "a".repeat(300);
+
+    let path = PathBuf::from(format!(r"C:\Users\ferris\{long}\..\site-packages"));
+    assert_eq!(
+        extended_length_path(&path),
+        PathBuf::from(r"\\?\C:\Users\ferris\site-packages")
+    );
+
+    let path = PathBuf::from(format!(r"\\server\share\{long}"));
+    assert_eq!(
+        extended_length_path(&path),
+        PathBuf::from(format!(r"\\?\UNC\server\share\{long}"))
+    );
+
+    let path = PathBuf::from(format!(r"\\?\C:\{long}"));
+    assert_eq!(extended_length_path(&path), path);
+}
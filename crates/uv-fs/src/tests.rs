@@ -0,0 +1,45 @@
+use super::*;
+
+#[test]
+fn cross_device() {
+    #[cfg(unix)]
+    let err = std::io::Error::from(rustix::io::Errno::XDEV);
+    #[cfg(windows)]
+    #[allow(clippy::cast_possible_wrap)]
+    let err = std::io::Error::from_raw_os_error(
+        windows_sys::Win32::Foundation::ERROR_NOT_SAME_DEVICE as i32,
+    );
+    assert!(is_cross_device(&err));
+
+    let err = std::io::Error::from(std::io::ErrorKind::NotFound);
+    assert!(!is_cross_device(&err));
+}
+
+/// Junctions should be created and replaced when both the link and its target exceed `MAX_PATH`.
+#[test]
+#[cfg(windows)]
+fn replace_symlink_long_path() -> std::io::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    let long = temp_dir.path().join("a".repeat(120)).join("b".repeat(120));
+
+    let first = long.join("first");
+    let second = long.join("second");
+    fs_err::create_dir_all(&first)?;
+    fs_err::create_dir_all(&second)?;
+    fs_err::write(first.join("file.txt"), "first")?;
+    fs_err::write(second.join("file.txt"), "second")?;
+
+    let link = long.join("link");
+    assert!(link.as_os_str().len() > 260);
+
+    replace_symlink(&first, &link)?;
+    assert_eq!(fs_err::read_to_string(link.join("file.txt"))?, "first");
+
+    replace_symlink(&second, &link)?;
+    assert_eq!(fs_err::read_to_string(link.join("file.txt"))?, "second");
+
+    remove_symlink(&link)?;
+    assert!(!link.exists());
+
+    Ok(())
+}
@@ -7,6 +7,7 @@ use std::str::{self, FromStr};
 use std::sync::LazyLock;
 
 use crate::sha::GitOid;
+use crate::ssh::SshCommand;
 use crate::GitSha;
 use anyhow::{anyhow, Context, Result};
 use cargo_util::{paths, ProcessBuilder};
@@ -17,6 +18,7 @@ use tracing::debug;
 use url::Url;
 use uv_fs::Simplified;
 use uv_static::EnvVars;
+use uv_warnings::warn_user_once;
 
 /// A file indicates that if present, `git reset` has been done and a repo
 /// checkout is ready to go. See [`GitCheckout::reset`] for why we need this.
@@ -48,6 +50,19 @@ pub enum GitReference {
     DefaultBranch,
 }
 
+/// The depth with which to fetch a [`GitReference`].
+///
+/// Note that shallow fetches are depth-limited, but not blob-filtered (i.e., partial clones):
+/// checkouts are cloned from the [`GitDatabase`], which must therefore contain every blob in the
+/// target commit, and a depth-limited fetch already limits the blobs to those in the target commit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FetchDepth {
+    /// Fetch the referenced commit alone, without its history.
+    Shallow,
+    /// Fetch the referenced commit, along with its full history.
+    Full,
+}
+
 /// Strategy when fetching refspecs for a [`GitReference`]
 enum RefspecStrategy {
     // All refspecs should be fetched, if any fail then the fetch will fail
@@ -115,6 +130,27 @@ impl GitReference {
         }
     }
 
+    /// Returns `true` if the reference is pinned to a specific commit, i.e., it can't move to a
+    /// different commit over time.
+    pub fn is_commit(&self) -> bool {
+        matches!(self, Self::ShortCommit(_) | Self::FullCommit(_))
+    }
+
+    /// Returns `true` if the reference identifies a specific commit (or tag), such that it can be
+    /// fetched without its history.
+    ///
+    /// Branches are always fetched in full, since they're expected to move.
+    fn is_immutable(&self) -> bool {
+        matches!(
+            self,
+            Self::Tag(_)
+                | Self::ShortCommit(_)
+                | Self::BranchOrTagOrCommit(_)
+                | Self::FullCommit(_)
+                | Self::NamedRef(_)
+        )
+    }
+
     /// Returns the precise [`GitSha`] of this reference, if it's a full commit.
     pub(crate) fn as_sha(&self) -> Option<GitSha> {
         if let Self::FullCommit(rev) = self {
@@ -204,6 +240,17 @@ impl GitRepository {
         result.truncate(result.trim_end().len());
         Ok(result.parse()?)
     }
+
+    /// Returns `true` if the repository is shallow, i.e., it's missing some history.
+    fn is_shallow(&self) -> Result<bool> {
+        let result = ProcessBuilder::new(GIT.as_ref()?)
+            .arg("rev-parse")
+            .arg("--is-shallow-repository")
+            .cwd(&self.path)
+            .exec_with_output()?;
+
+        Ok(String::from_utf8(result.stdout)?.trim() == "true")
+    }
 }
 
 impl GitRemote {
@@ -240,7 +287,7 @@ impl GitRemote {
         let locked_ref = locked_rev.map(|oid| GitReference::FullCommit(oid.to_string()));
         let reference = locked_ref.as_ref().unwrap_or(reference);
         if let Some(mut db) = db {
-            fetch(&mut db.repo, self.url.as_str(), reference, client)
+            self.fetch_into(&mut db.repo, reference, locked_rev, client)
                 .with_context(|| format!("failed to fetch into: {}", into.user_display()))?;
 
             let resolved_commit_hash = match locked_rev {
@@ -262,7 +309,7 @@ impl GitRemote {
 
         paths::create_dir_all(into)?;
         let mut repo = GitRepository::init(into)?;
-        fetch(&mut repo, self.url.as_str(), reference, client)
+        self.fetch_into(&mut repo, reference, locked_rev, client)
             .with_context(|| format!("failed to clone into: {}", into.user_display()))?;
         let rev = match locked_rev {
             Some(rev) => rev,
@@ -272,6 +319,53 @@ impl GitRemote {
         Ok((GitDatabase { repo }, rev))
     }
 
+    /// Fetches a reference or a revision from this remote into the given repository.
+    ///
+    /// If the reference identifies a specific commit or tag, we first attempt a shallow fetch,
+    /// falling back to a full fetch if the shallow fetch fails (e.g., if the server doesn't
+    /// support it) or if the reference can't be peeled to a commit afterwards.
+    fn fetch_into(
+        &self,
+        repo: &mut GitRepository,
+        reference: &GitReference,
+        locked_rev: Option<GitOid>,
+        client: &ClientWithMiddleware,
+    ) -> Result<()> {
+        if reference.is_immutable() {
+            match fetch(
+                repo,
+                self.url.as_str(),
+                reference,
+                FetchDepth::Shallow,
+                client,
+            ) {
+                Ok(()) => {
+                    let resolved = match locked_rev {
+                        Some(rev) => repo.rev_parse(&format!("{rev}^0")).is_ok(),
+                        None => reference.resolve(repo).is_ok(),
+                    };
+                    if resolved {
+                        return Ok(());
+                    }
+                    debug!(
+                        "Failed to resolve {} `{}` after a shallow fetch; falling back to a full fetch",
+                        reference.kind_str(),
+                        reference.as_rev()
+                    );
+                }
+                Err(err) => {
+                    debug!(
+                        "Failed to perform a shallow fetch of {} `{}`; falling back to a full fetch: {err:#}",
+                        reference.kind_str(),
+                        reference.as_rev()
+                    );
+                }
+            }
+        }
+
+        fetch(repo, self.url.as_str(), reference, FetchDepth::Full, client)
+    }
+
     /// Creates a [`GitDatabase`] of this remote at `db_path`.
     #[allow(clippy::unused_self)]
     pub(crate) fn db_at(&self, db_path: &Path) -> Result<GitDatabase> {
@@ -282,7 +376,17 @@ impl GitRemote {
 
 impl GitDatabase {
     /// Checkouts to a revision at `destination` from this database.
-    pub(crate) fn copy_to(&self, rev: GitOid, destination: &Path) -> Result<GitCheckout> {
+    ///
+    /// The `remote` is used to fetch any files tracked by Git LFS, which aren't stored in the
+    /// database. If a `subdirectory` is provided, only that subdirectory (along with the files in
+    /// its ancestors) is checked out.
+    pub(crate) fn copy_to(
+        &self,
+        rev: GitOid,
+        destination: &Path,
+        remote: &Url,
+        subdirectory: Option<&Path>,
+    ) -> Result<GitCheckout> {
         // If the existing checkout exists, and it is fresh, use it.
         // A non-fresh checkout can happen if the checkout operation was
         // interrupted. In that case, the checkout gets deleted and a new
@@ -292,8 +396,15 @@ impl GitDatabase {
             .map(|repo| GitCheckout::new(rev, repo))
             .filter(GitCheckout::is_fresh)
         {
-            Some(co) => co,
-            None => GitCheckout::clone_into(destination, self, rev)?,
+            Some(co) => {
+                // Replace any LFS pointer files left by a previous checkout, e.g., if `git-lfs`
+                // wasn't installed at the time.
+                if co.has_lfs_pointers()? {
+                    co.fetch_lfs(remote)?;
+                }
+                co
+            }
+            None => GitCheckout::clone_into(destination, self, rev, remote, subdirectory)?,
         };
         Ok(checkout)
     }
@@ -367,7 +478,13 @@ impl GitCheckout {
 
     /// Clone a repo for a `revision` into a local path from a `database`.
     /// This is a filesystem-to-filesystem clone.
-    fn clone_into(into: &Path, database: &GitDatabase, revision: GitOid) -> Result<Self> {
+    fn clone_into(
+        into: &Path,
+        database: &GitDatabase,
+        revision: GitOid,
+        remote: &Url,
+        subdirectory: Option<&Path>,
+    ) -> Result<Self> {
         let dirname = into.parent().unwrap();
         paths::create_dir_all(dirname)?;
         if into.exists() {
@@ -388,8 +505,38 @@ impl GitCheckout {
             .exec_with_output()?;
 
         let repo = GitRepository::open(into)?;
+
+        // If the database is shallow, Git falls back to a regular clone, which only includes the
+        // database's branches and tags. Since the database stores its revisions under other refs,
+        // fetch the revision explicitly.
+        if database.repo.is_shallow()? {
+            ProcessBuilder::new(GIT.as_ref()?)
+                .arg("fetch")
+                .arg("--depth=1")
+                .arg("--force")
+                .arg(database.repo.path.simplified_display().to_string())
+                .arg(format!("+{revision}:refs/commit/{revision}"))
+                .env_remove(EnvVars::GIT_DIR)
+                .cwd(&repo.path)
+                .exec_with_output()?;
+        }
+
+        // If only a subdirectory is needed, avoid materializing the rest of the repository.
+        let sparse = subdirectory.filter(|subdirectory| {
+            match sparse_checkout(&repo, subdirectory) {
+                Ok(sparse) => sparse,
+                Err(err) => {
+                    debug!(
+                        "Failed to configure sparse checkout for `{}`; falling back to a full checkout: {err:#}",
+                        subdirectory.display()
+                    );
+                    false
+                }
+            }
+        });
+
         let checkout = GitCheckout::new(revision, repo);
-        checkout.reset()?;
+        checkout.reset(remote, sparse)?;
         Ok(checkout)
     }
 
@@ -417,32 +564,303 @@ impl GitCheckout {
     /// *doesn't* exist, and then once we're done we create the file.
     ///
     /// [`.cargo-ok`]: CHECKOUT_READY_LOCK
-    fn reset(&self) -> Result<()> {
+    fn reset(&self, remote: &Url, sparse: Option<&Path>) -> Result<()> {
         let ok_file = self.repo.path.join(CHECKOUT_READY_LOCK);
         let _ = paths::remove_file(&ok_file);
         debug!("reset {} to {}", self.repo.path.display(), self.revision);
 
         // Perform the hard reset.
+        //
+        // If Git LFS is installed, skip its smudge filter: the database doesn't contain any LFS
+        // objects, so we fetch them from the remote afterwards.
         ProcessBuilder::new(GIT.as_ref()?)
             .arg("reset")
             .arg("--hard")
             .arg(self.revision.as_str())
+            .env(EnvVars::GIT_LFS_SKIP_SMUDGE, "1")
             .cwd(&self.repo.path)
             .exec_with_output()?;
 
+        // If the project in the sparse subdirectory may depend on paths outside of it, materialize
+        // the rest of the repository.
+        if let Some(subdirectory) = sparse {
+            if requires_full_checkout(&self.repo.path, subdirectory) {
+                debug!(
+                    "Disabling sparse checkout for `{}`, which may depend on paths outside of it",
+                    subdirectory.display()
+                );
+                ProcessBuilder::new(GIT.as_ref()?)
+                    .arg("sparse-checkout")
+                    .arg("disable")
+                    .env(EnvVars::GIT_LFS_SKIP_SMUDGE, "1")
+                    .cwd(&self.repo.path)
+                    .exec_with_output()?;
+            }
+        }
+
         // Update submodules (`git submodule update --recursive`).
         ProcessBuilder::new(GIT.as_ref()?)
             .arg("submodule")
             .arg("update")
             .arg("--recursive")
             .arg("--init")
+            .env(EnvVars::GIT_LFS_SKIP_SMUDGE, "1")
             .cwd(&self.repo.path)
             .exec_with_output()
             .map(drop)?;
 
+        // Replace any LFS pointer files with their contents.
+        self.fetch_lfs(remote)?;
+
         paths::create(ok_file)?;
         Ok(())
     }
+
+    /// Fetches and checks out any files tracked by Git LFS from the `remote`, in the checkout and
+    /// in each of its submodules.
+    ///
+    /// The remote URL includes any credentials used to fetch the repository, such that the LFS
+    /// objects are fetched with the same authentication. Submodules are fetched from their own
+    /// `origin`, with Git's usual credential helpers.
+    fn fetch_lfs(&self, remote: &Url) -> Result<()> {
+        let repositories = self.lfs_repositories()?;
+        if repositories.is_empty() {
+            return Ok(());
+        }
+
+        // If Git LFS isn't installed, the pointer files are left in place.
+        if !lfs_installed(&self.repo.path) {
+            let mut url = remote.clone();
+            let _ = url.set_username("");
+            let _ = url.set_password(None);
+            warn_user_once!(
+                "`{url}` uses Git LFS, but `git-lfs` is not installed; files tracked by Git LFS will not be fetched"
+            );
+            return Ok(());
+        }
+
+        for path in repositories {
+            debug!("Fetching Git LFS objects for: {}", path.display());
+            if path == self.repo.path {
+                // Define the remote via the environment, rather than passing its URL (which may
+                // include credentials) on the command line, where it's visible to other processes.
+                let ssh = SshCommand::for_url(remote.as_str());
+                let mut cmd = ProcessBuilder::new(GIT.as_ref()?);
+                cmd.arg("lfs")
+                    .arg("fetch")
+                    .arg(LFS_REMOTE)
+                    .arg(self.revision.as_str())
+                    .cwd(&path);
+                set_config_env(&mut cmd, &format!("remote.{LFS_REMOTE}.url"), &ssh.url);
+                if let Some(command) = ssh.command.as_deref() {
+                    cmd.env(EnvVars::GIT_SSH_COMMAND, command);
+                }
+                cmd.exec_with_output()?;
+
+                ProcessBuilder::new(GIT.as_ref()?)
+                    .arg("lfs")
+                    .arg("checkout")
+                    .cwd(&path)
+                    .exec_with_output()?;
+            } else {
+                ProcessBuilder::new(GIT.as_ref()?)
+                    .arg("lfs")
+                    .arg("pull")
+                    .cwd(&path)
+                    .exec_with_output()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns `true` if any file tracked by Git LFS is checked out as a pointer file, e.g., if
+    /// `git-lfs` wasn't installed when the checkout was created.
+    fn has_lfs_pointers(&self) -> Result<bool> {
+        for path in self.lfs_repositories()? {
+            // If Git LFS isn't installed, the pointer files can't be replaced anyway.
+            let Ok(output) = ProcessBuilder::new(GIT.as_ref()?)
+                .arg("lfs")
+                .arg("ls-files")
+                .cwd(&path)
+                .exec_with_output()
+            else {
+                return Ok(false);
+            };
+
+            // Each line is formatted as `{oid} {status} {path}`, where a status of `-` indicates a
+            // pointer file.
+            if String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .any(|line| line.split(' ').nth(1) == Some("-"))
+            {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Returns the paths of the checkout and its (recursive) submodules that use Git LFS.
+    fn lfs_repositories(&self) -> Result<Vec<PathBuf>> {
+        let mut repositories = Vec::new();
+        if uses_lfs(&self.repo.path)? {
+            repositories.push(self.repo.path.clone());
+        }
+
+        if !self.repo.path.join(".gitmodules").is_file() {
+            return Ok(repositories);
+        }
+        let output = ProcessBuilder::new(GIT.as_ref()?)
+            .arg("submodule")
+            .arg("foreach")
+            .arg("--quiet")
+            .arg("--recursive")
+            .arg("echo \"$displaypath\"")
+            .cwd(&self.repo.path)
+            .exec_with_output()?;
+        for submodule in String::from_utf8(output.stdout)?.lines() {
+            let path = self.repo.path.join(submodule);
+            if uses_lfs(&path)? {
+                repositories.push(path);
+            }
+        }
+
+        Ok(repositories)
+    }
+}
+
+/// The name of the remote from which Git LFS objects are fetched for a checkout.
+const LFS_REMOTE: &str = "uv-lfs";
+
+/// Returns `true` if Git LFS is installed.
+fn lfs_installed(cwd: &Path) -> bool {
+    GIT.as_ref().is_ok_and(|git| {
+        ProcessBuilder::new(git)
+            .arg("lfs")
+            .arg("version")
+            .cwd(cwd)
+            .exec_with_output()
+            .is_ok()
+    })
+}
+
+/// Returns `true` if any `.gitattributes` file in the repository at `path` assigns the LFS filter
+/// to a path.
+fn uses_lfs(path: &Path) -> Result<bool> {
+    let output = ProcessBuilder::new(GIT.as_ref()?)
+        .arg("ls-files")
+        .arg("-z")
+        .arg("--")
+        .arg("*.gitattributes")
+        .cwd(path)
+        .exec_with_output()?;
+
+    for file in output.stdout.split(|byte| *byte == 0) {
+        if file.is_empty() {
+            continue;
+        }
+        let Ok(contents) = fs_err::read_to_string(path.join(str::from_utf8(file)?)) else {
+            continue;
+        };
+        if contents.lines().any(|line| {
+            !line.trim_start().starts_with('#')
+                && line
+                    .split_whitespace()
+                    .skip(1)
+                    .any(|attribute| attribute == "filter=lfs")
+        }) {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+/// Sets a Git configuration value for a `git` invocation via `GIT_CONFIG_COUNT`, rather than on
+/// the command line, retaining any values the user configured the same way.
+fn set_config_env(cmd: &mut ProcessBuilder, key: &str, value: &str) {
+    let index = std::env::var(EnvVars::GIT_CONFIG_COUNT)
+        .ok()
+        .and_then(|count| count.parse::<usize>().ok())
+        .unwrap_or(0);
+    cmd.env(&format!("GIT_CONFIG_KEY_{index}"), key)
+        .env(&format!("GIT_CONFIG_VALUE_{index}"), value)
+        .env(EnvVars::GIT_CONFIG_COUNT, (index + 1).to_string());
+}
+
+/// Configures a cone-mode sparse checkout of the given `subdirectory`, such that only the
+/// subdirectory (along with the files in each of its ancestors, e.g., a root `README.md`) is
+/// materialized.
+///
+/// Returns `false` if the subdirectory spans the entire repository.
+fn sparse_checkout(repo: &GitRepository, subdirectory: &Path) -> Result<bool> {
+    let mut components = Vec::new();
+    for component in subdirectory.components() {
+        match component {
+            std::path::Component::Normal(component) => {
+                components.push(component.to_string_lossy());
+            }
+            std::path::Component::CurDir => {}
+            _ => return Ok(false),
+        }
+    }
+    if components.is_empty() {
+        return Ok(false);
+    }
+
+    ProcessBuilder::new(GIT.as_ref()?)
+        .arg("sparse-checkout")
+        .arg("init")
+        .arg("--cone")
+        .cwd(&repo.path)
+        .exec_with_output()?;
+
+    ProcessBuilder::new(GIT.as_ref()?)
+        .arg("sparse-checkout")
+        .arg("set")
+        .arg(components.join("/"))
+        .cwd(&repo.path)
+        .exec_with_output()?;
+
+    Ok(true)
+}
+
+/// Returns `true` if the project in the given `subdirectory` may depend on paths outside of it,
+/// i.e., if it's part of a uv workspace, or declares a source with a relative path.
+fn requires_full_checkout(root: &Path, subdirectory: &Path) -> bool {
+    let mut directory = Some(subdirectory);
+    while let Some(current) = directory {
+        let uv = fs_err::read_to_string(root.join(current).join("pyproject.toml"))
+            .ok()
+            .and_then(|contents| toml::from_str::<toml::Table>(&contents).ok())
+            .and_then(|pyproject| pyproject.get("tool")?.get("uv")?.as_table().cloned());
+        if let Some(uv) = uv {
+            if uv.contains_key("workspace") {
+                return true;
+            }
+            if current == subdirectory {
+                let has_path_source = uv
+                    .get("sources")
+                    .and_then(toml::Value::as_table)
+                    .is_some_and(|sources| {
+                        sources.values().any(|source| {
+                            let sources = match source {
+                                toml::Value::Array(sources) => sources.iter().collect(),
+                                source => vec![source],
+                            };
+                            sources.into_iter().any(|source| {
+                                source.get("path").is_some() || source.get("workspace").is_some()
+                            })
+                        })
+                    });
+                if has_path_source {
+                    return true;
+                }
+            }
+        }
+        directory = current.parent();
+    }
+    false
 }
 
 /// Attempts to fetch the given git `reference` for a Git repository.
@@ -453,10 +871,11 @@ impl GitCheckout {
 /// * Dispatches `git fetch` using the git CLI.
 ///
 /// The `remote_url` argument is the git remote URL where we want to fetch from.
-pub(crate) fn fetch(
+fn fetch(
     repo: &mut GitRepository,
     remote_url: &str,
     reference: &GitReference,
+    depth: FetchDepth,
     client: &ClientWithMiddleware,
 ) -> Result<()> {
     let oid_to_fetch = match github_fast_path(repo, remote_url, reference, client) {
@@ -473,6 +892,7 @@ pub(crate) fn fetch(
     // which need to get fetched. Additionally record if we're fetching tags.
     let mut refspecs = Vec::new();
     let mut tags = false;
+    let mut depth = depth;
     let mut refspec_strategy = RefspecStrategy::All;
     // The `+` symbol on the refspec means to allow a forced (fast-forward)
     // update which is needed if there is ever a force push that requires a
@@ -516,6 +936,9 @@ pub(crate) fn fetch(
                 refspecs.push(String::from("+refs/heads/*:refs/remotes/origin/*"));
                 refspecs.push(String::from("+HEAD:refs/remotes/origin/HEAD"));
                 tags = true;
+
+                // Without knowing the commit, we need the full history to search for it.
+                depth = FetchDepth::Full;
             }
         }
 
@@ -542,16 +965,33 @@ pub(crate) fn fetch(
         }
     }
 
+    // If we're fetching the full history into a shallow repository (e.g., after a previous shallow
+    // fetch), we need to deepen the existing history too.
+    let unshallow = depth == FetchDepth::Full && repo.is_shallow().unwrap_or(false);
+
     debug!("Performing a Git fetch for: {remote_url}");
     let result = match refspec_strategy {
-        RefspecStrategy::All => fetch_with_cli(repo, remote_url, refspecs.as_slice(), tags),
+        RefspecStrategy::All => fetch_with_cli(
+            repo,
+            remote_url,
+            refspecs.as_slice(),
+            tags,
+            depth,
+            unshallow,
+        ),
         RefspecStrategy::First => {
             // Try each refspec
             let mut errors = refspecs
                 .iter()
                 .map_while(|refspec| {
-                    let fetch_result =
-                        fetch_with_cli(repo, remote_url, std::slice::from_ref(refspec), tags);
+                    let fetch_result = fetch_with_cli(
+                        repo,
+                        remote_url,
+                        std::slice::from_ref(refspec),
+                        tags,
+                        depth,
+                        unshallow,
+                    );
 
                     // Stop after the first success and log failures
                     match fetch_result {
@@ -596,15 +1036,29 @@ fn fetch_with_cli(
     url: &str,
     refspecs: &[String],
     tags: bool,
+    depth: FetchDepth,
+    unshallow: bool,
 ) -> Result<()> {
+    // Apply any SSH options configured for the host.
+    let ssh = SshCommand::for_url(url);
+
     let mut cmd = ProcessBuilder::new(GIT.as_ref()?);
     cmd.arg("fetch");
     if tags {
         cmd.arg("--tags");
     }
+    match depth {
+        FetchDepth::Shallow => {
+            cmd.arg("--depth=1");
+        }
+        FetchDepth::Full if unshallow => {
+            cmd.arg("--unshallow");
+        }
+        FetchDepth::Full => {}
+    }
     cmd.arg("--force") // handle force pushes
         .arg("--update-head-ok") // see discussion in #2078
-        .arg(url)
+        .arg(ssh.url.as_ref())
         .args(refspecs)
         // If cargo is run by git (for example, the `exec` command in `git
         // rebase`), the GIT_DIR is set by git and will point to the wrong
@@ -618,6 +1072,9 @@ fn fetch_with_cli(
         .env_remove(EnvVars::GIT_OBJECT_DIRECTORY)
         .env_remove(EnvVars::GIT_ALTERNATE_OBJECT_DIRECTORIES)
         .cwd(&repo.path);
+    if let Some(command) = ssh.command.as_deref() {
+        cmd.env(EnvVars::GIT_SSH_COMMAND, command);
+    }
 
     // We capture the output to avoid streaming it to the user's console during clones.
     // The required `on...line` callbacks currently do nothing.
@@ -770,3 +1227,6 @@ fn is_short_hash_of(rev: &str, oid: GitOid) -> bool {
         None => false,
     }
 }
+
+#[cfg(test)]
+mod tests;
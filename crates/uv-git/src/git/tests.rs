@@ -0,0 +1,253 @@
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::Result;
+use url::Url;
+
+use super::{GitReference, GitRemote, GitRepository};
+
+/// Run `git` in the given directory, returning its trimmed output.
+fn git(dir: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .args([
+            "-c",
+            "user.name=uv",
+            "-c",
+            "user.email=uv@example.com",
+            "-c",
+            "commit.gpgsign=false",
+            "-c",
+            "tag.gpgsign=false",
+        ])
+        .args(args)
+        .current_dir(dir)
+        .output()?;
+    anyhow::ensure!(
+        output.status.success(),
+        "`git {}` failed: {}",
+        args.join(" "),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    Ok(String::from_utf8(output.stdout)?.trim().to_string())
+}
+
+/// Create a repository with two commits, tagged `v1` and `v2`, returning the commit of each.
+fn upstream(dir: &Path) -> Result<(String, String)> {
+    git(dir, &["init"])?;
+    fs_err::write(dir.join("file.txt"), "1")?;
+    git(dir, &["add", "file.txt"])?;
+    git(dir, &["commit", "-m", "first"])?;
+    git(dir, &["tag", "-a", "v1", "-m", "v1"])?;
+    fs_err::write(dir.join("file.txt"), "2")?;
+    git(dir, &["commit", "-am", "second"])?;
+    git(dir, &["tag", "-a", "v2", "-m", "v2"])?;
+    Ok((
+        git(dir, &["rev-parse", "v1^0"])?,
+        git(dir, &["rev-parse", "v2^0"])?,
+    ))
+}
+
+fn client() -> reqwest_middleware::ClientWithMiddleware {
+    reqwest_middleware::ClientBuilder::new(reqwest::Client::new()).build()
+}
+
+#[test]
+fn fetch_tag_shallow() -> Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    let source = temp_dir.path().join("upstream");
+    fs_err::create_dir(&source)?;
+    let (_, v2) = upstream(&source)?;
+
+    let db = temp_dir.path().join("db");
+    fs_err::create_dir(&db)?;
+    let mut repo = GitRepository::init(&db)?;
+    let remote = GitRemote::new(&Url::from_directory_path(&source).unwrap());
+    let reference = GitReference::Tag("v2".to_string());
+    remote.fetch_into(&mut repo, &reference, None, &client())?;
+
+    // Only the tagged commit is fetched.
+    assert!(repo.is_shallow()?);
+    assert_eq!(reference.resolve(&repo)?.to_string(), v2);
+
+    Ok(())
+}
+
+#[test]
+fn fetch_tag_shallow_failure() -> Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    let source = temp_dir.path().join("upstream");
+    fs_err::create_dir(&source)?;
+    let (v1, v2) = upstream(&source)?;
+
+    // A held `shallow.lock` causes any shallow fetch to fail, but doesn't affect a full fetch.
+    let db = temp_dir.path().join("db");
+    fs_err::create_dir(&db)?;
+    let mut repo = GitRepository::init(&db)?;
+    fs_err::write(db.join(".git").join("shallow.lock"), "")?;
+
+    let remote = GitRemote::new(&Url::from_directory_path(&source).unwrap());
+    let reference = GitReference::Tag("v2".to_string());
+    remote.fetch_into(&mut repo, &reference, None, &client())?;
+
+    // The full history is fetched instead.
+    assert!(!repo.is_shallow()?);
+    assert_eq!(reference.resolve(&repo)?.to_string(), v2);
+    assert_eq!(repo.rev_parse(&format!("{v1}^0"))?.to_string(), v1);
+
+    Ok(())
+}
+
+#[test]
+fn fetch_tag_shallow_unresolved() -> Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    let source = temp_dir.path().join("upstream");
+    fs_err::create_dir(&source)?;
+    let (v1, _) = upstream(&source)?;
+
+    let db = temp_dir.path().join("db");
+    fs_err::create_dir(&db)?;
+    let mut repo = GitRepository::init(&db)?;
+
+    // If the tag has moved since it was locked, the locked commit isn't part of a shallow fetch
+    // of the tag, so the full history is fetched.
+    let remote = GitRemote::new(&Url::from_directory_path(&source).unwrap());
+    let reference = GitReference::Tag("v2".to_string());
+    remote.fetch_into(&mut repo, &reference, Some(v1.parse()?), &client())?;
+
+    assert!(!repo.is_shallow()?);
+    assert_eq!(repo.rev_parse(&format!("{v1}^0"))?.to_string(), v1);
+
+    Ok(())
+}
+
+#[test]
+fn checkout_lfs() -> Result<()> {
+    if !super::lfs_installed(Path::new(".")) {
+        return Ok(());
+    }
+
+    let temp_dir = tempfile::tempdir()?;
+    let source = temp_dir.path().join("upstream");
+    fs_err::create_dir(&source)?;
+    git(&source, &["init"])?;
+    git(&source, &["lfs", "install", "--local"])?;
+    git(&source, &["lfs", "track", "*.bin"])?;
+    fs_err::write(source.join("data.bin"), "contents")?;
+    git(&source, &["add", ".gitattributes", "data.bin"])?;
+    git(&source, &["commit", "-m", "lfs"])?;
+
+    let url = Url::from_directory_path(&source).unwrap();
+    let (db, rev) = GitRemote::new(&url).checkout(
+        &temp_dir.path().join("db"),
+        None,
+        &GitReference::DefaultBranch,
+        None,
+        &client(),
+    )?;
+
+    // The file tracked by Git LFS is fetched, rather than left as a pointer.
+    let checkout = temp_dir.path().join("checkout");
+    db.copy_to(rev, &checkout, &url, None)?;
+    assert_eq!(
+        fs_err::read_to_string(checkout.join("data.bin"))?,
+        "contents"
+    );
+
+    // A checkout that still contains a pointer file (e.g., because `git-lfs` wasn't installed when
+    // it was created) is repaired on the next use.
+    let pointer = git(&checkout, &["show", "HEAD:data.bin"])?;
+    assert!(pointer.starts_with("version https://git-lfs.github.com/spec/v1"));
+    fs_err::write(checkout.join("data.bin"), format!("{pointer}\n"))?;
+    db.copy_to(rev, &checkout, &url, None)?;
+    assert_eq!(
+        fs_err::read_to_string(checkout.join("data.bin"))?,
+        "contents"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn requires_full_checkout() -> Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    let root = temp_dir.path();
+    let write = |path: &str, contents: &str| -> Result<()> {
+        let path = root.join(path);
+        fs_err::create_dir_all(path.parent().unwrap())?;
+        fs_err::write(path, contents)?;
+        Ok(())
+    };
+
+    // A standalone project only needs its own directory.
+    write(
+        "standalone/pyproject.toml",
+        "[project]\nname = \"standalone\"\n",
+    )?;
+    assert!(!super::requires_full_checkout(
+        root,
+        Path::new("standalone")
+    ));
+
+    // A project with a path source may depend on its siblings.
+    write(
+        "path/pyproject.toml",
+        "[project]\nname = \"path\"\n\n[tool.uv.sources]\nsibling = { path = \"../sibling\" }\n",
+    )?;
+    assert!(super::requires_full_checkout(root, Path::new("path")));
+
+    // As may a member of a workspace defined in one of its ancestors.
+    write(
+        "workspace/pyproject.toml",
+        "[tool.uv.workspace]\nmembers = [\"packages/*\"]\n",
+    )?;
+    write(
+        "workspace/packages/member/pyproject.toml",
+        "[project]\nname = \"member\"\n",
+    )?;
+    assert!(super::requires_full_checkout(
+        root,
+        Path::new("workspace/packages/member")
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn checkout_sparse() -> Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    let source = temp_dir.path().join("upstream");
+    fs_err::create_dir_all(source.join("pkg"))?;
+    fs_err::create_dir_all(source.join("sibling"))?;
+    git(&source, &["init"])?;
+    fs_err::write(source.join("README.md"), "")?;
+    fs_err::write(
+        source.join("pkg").join("pyproject.toml"),
+        "[project]\nname = \"pkg\"\n",
+    )?;
+    fs_err::write(source.join("sibling").join("data.txt"), "")?;
+    git(&source, &["add", "."])?;
+    git(&source, &["commit", "-m", "initial"])?;
+
+    let url = Url::from_directory_path(&source).unwrap();
+    let (db, rev) = GitRemote::new(&url).checkout(
+        &temp_dir.path().join("db"),
+        None,
+        &GitReference::DefaultBranch,
+        None,
+        &client(),
+    )?;
+
+    // Only the subdirectory, and the files in its ancestors, are checked out.
+    let sparse = temp_dir.path().join("sparse");
+    db.copy_to(rev, &sparse, &url, Some(Path::new("pkg")))?;
+    assert!(sparse.join("pkg").join("pyproject.toml").is_file());
+    assert!(sparse.join("README.md").is_file());
+    assert!(!sparse.join("sibling").exists());
+
+    // Without a subdirectory (e.g., with `no-git-sparse-checkout`), the entire tree is checked out.
+    let full = temp_dir.path().join("full");
+    db.copy_to(rev, &full, &url, None)?;
+    assert!(full.join("sibling").join("data.txt").is_file());
+
+    Ok(())
+}
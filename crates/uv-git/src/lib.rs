@@ -6,13 +6,15 @@ pub use crate::resolver::{
     GitResolver, GitResolverError, RepositoryReference, ResolvedRepositoryReference,
 };
 pub use crate::sha::{GitOid, GitSha, OidParseError};
-pub use crate::source::{Fetch, GitSource, Reporter};
+pub use crate::source::{Fetch, GitSource, Reporter, GIT_SPARSE_CHECKOUT};
+pub use crate::ssh::{GitSshHost, GitSshStore, GIT_SSH};
 
 mod credentials;
 mod git;
 mod resolver;
 mod sha;
 mod source;
+mod ssh;
 
 /// A URL reference to a Git repository.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Hash, Ord)]
@@ -1,5 +1,5 @@
 use std::borrow::Cow;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use tracing::debug;
@@ -83,9 +83,13 @@ impl GitResolver {
     }
 
     /// Fetch a remote Git repository.
+    ///
+    /// If a `subdirectory` is provided, only that subdirectory (along with the files in each of its
+    /// ancestors) is guaranteed to be checked out.
     pub async fn fetch(
         &self,
         url: &GitUrl,
+        subdirectory: Option<&Path>,
         client: ClientWithMiddleware,
         cache: PathBuf,
         reporter: Option<impl Reporter + 'static>,
@@ -119,7 +123,8 @@ impl GitResolver {
             GitSource::new(url.as_ref().clone(), client, cache).with_reporter(reporter)
         } else {
             GitSource::new(url.as_ref().clone(), client, cache)
-        };
+        }
+        .with_subdirectory(subdirectory.map(Path::to_path_buf));
         let fetch = tokio::task::spawn_blocking(move || source.fetch())
             .await?
             .map_err(GitResolverError::Git)?;
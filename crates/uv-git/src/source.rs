@@ -4,6 +4,7 @@
 
 use std::borrow::Cow;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use anyhow::Result;
 use reqwest_middleware::ClientWithMiddleware;
@@ -15,6 +16,12 @@ use uv_cache_key::{cache_digest, RepositoryUrl};
 use crate::git::GitRemote;
 use crate::{GitOid, GitSha, GitUrl, GIT_STORE};
 
+/// Whether to check out only the needed subdirectory of a Git source, for a uv invocation.
+///
+/// This is populated from the user's settings, and disabled for projects whose builds read files
+/// outside of their subdirectory.
+pub static GIT_SPARSE_CHECKOUT: AtomicBool = AtomicBool::new(true);
+
 /// A remote Git source that can be checked out locally.
 pub struct GitSource {
     /// The Git reference from the manifest file.
@@ -23,6 +30,8 @@ pub struct GitSource {
     client: ClientWithMiddleware,
     /// The path to the Git source database.
     cache: PathBuf,
+    /// The subdirectory within the repository that's needed, if not the entire repository.
+    subdirectory: Option<PathBuf>,
     /// The reporter to use for this source.
     reporter: Option<Box<dyn Reporter>>,
 }
@@ -38,10 +47,24 @@ impl GitSource {
             git,
             client: client.into(),
             cache: cache.into(),
+            subdirectory: None,
             reporter: None,
         }
     }
 
+    /// Set the subdirectory within the repository that's needed, if any.
+    ///
+    /// When set, the repository is checked out sparsely, such that only the subdirectory (along
+    /// with the files in each of its ancestors) is materialized, unless sparse checkouts are
+    /// disabled via [`GIT_SPARSE_CHECKOUT`].
+    #[must_use]
+    pub fn with_subdirectory(self, subdirectory: Option<PathBuf>) -> Self {
+        Self {
+            subdirectory,
+            ..self
+        }
+    }
+
     /// Set the [`Reporter`] to use for this `GIt` source.
     #[must_use]
     pub fn with_reporter(self, reporter: impl Reporter + 'static) -> Self {
@@ -170,13 +193,26 @@ impl GitSource {
         // Check out `actual_rev` from the database to a scoped location on the
         // filesystem. This will use hard links and such to ideally make the
         // checkout operation here pretty fast.
-        let checkout_path = self
-            .cache
-            .join("checkouts")
-            .join(&ident)
-            .join(short_id.as_str());
-
-        db.copy_to(actual_rev.into(), &checkout_path)?;
+        //
+        // Sparse checkouts are stored separately, keyed by the subdirectory.
+        let subdirectory = self
+            .subdirectory
+            .as_deref()
+            .filter(|_| GIT_SPARSE_CHECKOUT.load(Ordering::Relaxed));
+        let checkout_path = self.cache.join("checkouts").join(&ident).join(
+            if let Some(subdirectory) = subdirectory {
+                format!("{short_id}-{}", cache_digest(subdirectory))
+            } else {
+                short_id.clone()
+            },
+        );
+
+        db.copy_to(
+            actual_rev.into(),
+            &checkout_path,
+            remote.url(),
+            subdirectory,
+        )?;
 
         // Report the checkout operation to the reporter.
         if let Some(task) = task {
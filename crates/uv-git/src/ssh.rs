@@ -0,0 +1,291 @@
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::{LazyLock, RwLock};
+
+use serde::Deserialize;
+use url::Url;
+
+use uv_static::EnvVars;
+
+/// Global SSH configuration for Git hosts, for a uv invocation.
+///
+/// This is populated from the user's settings, and applied when fetching from a Git host over SSH.
+pub static GIT_SSH: LazyLock<GitSshStore> = LazyLock::new(GitSshStore::default);
+
+/// The SSH options to use when fetching from a Git host.
+///
+/// Useful on machines without a full `~/.ssh/config`, e.g., in CI.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct GitSshHost {
+    /// The private key with which to authenticate, i.e., SSH's `IdentityFile`.
+    ///
+    /// When set, SSH only offers this key to the host.
+    pub identity_file: Option<PathBuf>,
+    /// The known hosts file to use when verifying the host, i.e., SSH's `UserKnownHostsFile`.
+    pub known_hosts_file: Option<PathBuf>,
+    /// The username with which to connect, if the Git URL doesn't include one.
+    pub user: Option<String>,
+}
+
+/// A store for SSH configuration, keyed by Git host.
+#[derive(Debug, Default)]
+pub struct GitSshStore(RwLock<BTreeMap<String, GitSshHost>>);
+
+impl GitSshStore {
+    /// Insert the [`GitSshHost`] options for the given host into the store.
+    pub fn insert(&self, host: String, options: GitSshHost) -> Option<GitSshHost> {
+        self.0.write().unwrap().insert(host, options)
+    }
+
+    /// Get the [`GitSshHost`] options for the given host, if they exist.
+    pub fn get(&self, host: &str) -> Option<GitSshHost> {
+        self.0.read().unwrap().get(host).cloned()
+    }
+}
+
+/// The SSH configuration to apply to a `git` invocation for a given remote.
+#[derive(Debug)]
+pub(crate) struct SshCommand<'a> {
+    /// The URL to fetch from, with the configured username applied.
+    pub(crate) url: Cow<'a, str>,
+    /// The value for `GIT_SSH_COMMAND`, if any options need to be passed to SSH.
+    pub(crate) command: Option<String>,
+}
+
+impl<'a> SshCommand<'a> {
+    /// Determine the SSH configuration for the given remote URL, either an `ssh://` URL or an
+    /// scp-like `[user@]host:path`.
+    ///
+    /// Returns the URL unchanged (and no command) if the URL doesn't use SSH, or if there are no
+    /// options for its host.
+    pub(crate) fn for_url(url: &'a str) -> Self {
+        let unchanged = Self {
+            url: Cow::Borrowed(url),
+            command: None,
+        };
+
+        let Some(remote) = SshRemote::parse(url) else {
+            return unchanged;
+        };
+        let Some(options) = GIT_SSH.get(remote.host()) else {
+            return unchanged;
+        };
+
+        // Apply the username, unless the URL already includes one.
+        let url = match options.user.as_deref() {
+            Some(user) => remote
+                .with_user(user)
+                .map_or(Cow::Borrowed(url), Cow::Owned),
+            None => Cow::Borrowed(url),
+        };
+
+        // Extend any user-provided command, such that (e.g.) a custom SSH binary is retained.
+        let mut command = std::env::var(EnvVars::GIT_SSH_COMMAND)
+            .ok()
+            .filter(|command| !command.trim().is_empty())
+            .unwrap_or_else(|| String::from("ssh"));
+        let mut configured = false;
+        if let Some(identity_file) = options.identity_file.as_ref() {
+            command.push_str(" -o IdentitiesOnly=yes -i ");
+            command.push_str(&shell_quote(&identity_file.to_string_lossy()));
+            configured = true;
+        }
+        if let Some(known_hosts_file) = options.known_hosts_file.as_ref() {
+            command.push_str(" -o ");
+            command.push_str(&shell_quote(&format!(
+                "UserKnownHostsFile={}",
+                known_hosts_file.to_string_lossy()
+            )));
+            configured = true;
+        }
+
+        Self {
+            url,
+            command: configured.then_some(command),
+        }
+    }
+}
+
+/// A Git remote accessed over SSH.
+enum SshRemote<'a> {
+    /// An `ssh://` URL, e.g., `ssh://git@github.com/astral-sh/uv.git`.
+    Url(Url),
+    /// An scp-like address, e.g., `git@github.com:astral-sh/uv.git`.
+    Scp {
+        url: &'a str,
+        user: Option<&'a str>,
+        host: &'a str,
+    },
+}
+
+impl<'a> SshRemote<'a> {
+    /// Parse an SSH remote, returning `None` if the URL doesn't use SSH.
+    fn parse(url: &'a str) -> Option<Self> {
+        if url.contains("://") {
+            let parsed = Url::parse(url).ok()?;
+            return (parsed.scheme() == "ssh" && parsed.host_str().is_some())
+                .then_some(Self::Url(parsed));
+        }
+
+        // Per `git clone`, an scp-like address is only recognized if there are no slashes before
+        // the first colon, which distinguishes it from a local path (e.g., `./foo:bar`), and if
+        // it isn't a Windows drive letter (e.g., `C:\foo`).
+        let (address, path) = url.split_once(':')?;
+        if address.len() <= 1 || address.contains(['/', '\\']) || path.is_empty() {
+            return None;
+        }
+        let (user, host) = match address.rsplit_once('@') {
+            Some((user, host)) => (Some(user), host),
+            None => (None, address),
+        };
+        if host.is_empty() {
+            return None;
+        }
+        Some(Self::Scp { url, user, host })
+    }
+
+    /// The host of the remote.
+    fn host(&self) -> &str {
+        match self {
+            Self::Url(url) => url.host_str().unwrap_or_default(),
+            Self::Scp { host, .. } => host,
+        }
+    }
+
+    /// Return the remote URL with the given username, or `None` if it already includes one.
+    fn with_user(self, user: &str) -> Option<String> {
+        match self {
+            Self::Url(mut url) => {
+                if !url.username().is_empty() {
+                    return None;
+                }
+                url.set_username(user).ok()?;
+                Some(url.to_string())
+            }
+            Self::Scp {
+                url, user: None, ..
+            } => Some(format!("{user}@{url}")),
+            Self::Scp { .. } => None,
+        }
+    }
+}
+
+impl GitSshHost {
+    /// Resolve any relative key and known hosts paths against the given directory, i.e., that of
+    /// the configuration file from which the options were read.
+    ///
+    /// Paths starting with `~` are left as-is, to be expanded by SSH.
+    #[must_use]
+    pub fn relative_to(mut self, root_dir: &Path) -> Self {
+        for path in [&mut self.identity_file, &mut self.known_hosts_file]
+            .into_iter()
+            .flatten()
+        {
+            if path.is_relative() && !path.starts_with("~") {
+                *path = root_dir.join(&*path);
+            }
+        }
+        self
+    }
+}
+
+/// Quote a value for use in a POSIX shell, as `git` runs `GIT_SSH_COMMAND` via the shell.
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::shell_quote;
+
+    #[test]
+    fn quote() {
+        assert_eq!(
+            shell_quote("/home/ci/.ssh/id_ed25519"),
+            "'/home/ci/.ssh/id_ed25519'"
+        );
+        assert_eq!(shell_quote("it's"), r"'it'\''s'");
+    }
+
+    /// Register the options for a host. Each test uses a distinct host, as the store is global.
+    fn configure(host: &str, user: Option<&str>) {
+        GIT_SSH.insert(
+            host.to_string(),
+            GitSshHost {
+                identity_file: Some(PathBuf::from("/keys/id_ci")),
+                known_hosts_file: Some(PathBuf::from("/keys/known hosts")),
+                user: user.map(ToString::to_string),
+            },
+        );
+    }
+
+    #[test]
+    fn ssh_url() {
+        configure("ssh.example.com", Some("git"));
+
+        let ssh = SshCommand::for_url("ssh://ssh.example.com/org/repo.git");
+        assert_eq!(ssh.url, "ssh://git@ssh.example.com/org/repo.git");
+        let command = ssh.command.unwrap();
+        assert!(command.ends_with(
+            " -o IdentitiesOnly=yes -i '/keys/id_ci' -o 'UserKnownHostsFile=/keys/known hosts'"
+        ));
+
+        // A username in the URL takes precedence.
+        let ssh = SshCommand::for_url("ssh://ci@ssh.example.com/org/repo.git");
+        assert_eq!(ssh.url, "ssh://ci@ssh.example.com/org/repo.git");
+        assert!(ssh.command.is_some());
+    }
+
+    #[test]
+    fn scp_url() {
+        configure("scp.example.com", Some("git"));
+
+        let ssh = SshCommand::for_url("scp.example.com:org/repo.git");
+        assert_eq!(ssh.url, "git@scp.example.com:org/repo.git");
+        assert!(ssh.command.is_some());
+
+        let ssh = SshCommand::for_url("ci@scp.example.com:org/repo.git");
+        assert_eq!(ssh.url, "ci@scp.example.com:org/repo.git");
+        assert!(ssh.command.is_some());
+    }
+
+    #[test]
+    fn unconfigured() {
+        configure("https.example.com", Some("git"));
+
+        // Options only apply to SSH remotes for the configured host.
+        for url in [
+            "https://https.example.com/org/repo.git",
+            "ssh://other.example.com/org/repo.git",
+            "git@other.example.com:org/repo.git",
+            "./https.example.com:org/repo.git",
+            "/path/to/repo",
+            r"C:\path\to\repo",
+        ] {
+            let ssh = SshCommand::for_url(url);
+            assert_eq!(ssh.url, url);
+            assert!(ssh.command.is_none(), "{url}");
+        }
+    }
+
+    #[test]
+    fn relative_to() {
+        let options = GitSshHost {
+            identity_file: Some(PathBuf::from("keys/id_ci")),
+            known_hosts_file: Some(PathBuf::from("~/.ssh/known_hosts")),
+            user: None,
+        }
+        .relative_to(Path::new("/project"));
+        assert_eq!(
+            options.identity_file.as_deref(),
+            Some(Path::new("/project/keys/id_ci"))
+        );
+        assert_eq!(
+            options.known_hosts_file.as_deref(),
+            Some(Path::new("~/.ssh/known_hosts"))
+        );
+    }
+}
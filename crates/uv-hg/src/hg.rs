@@ -0,0 +1,333 @@
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::LazyLock;
+
+use anyhow::{anyhow, Context, Result};
+use cargo_util::{paths, ProcessBuilder};
+use tracing::debug;
+use url::Url;
+
+use uv_fs::Simplified;
+use uv_static::EnvVars;
+
+use crate::HgNode;
+
+/// A global cache of the result of `which hg`.
+pub static HG: LazyLock<Result<PathBuf, which::Error>> = LazyLock::new(|| which::which("hg"));
+
+/// A reference to a Mercurial changeset.
+#[derive(
+    Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+pub enum HgReference {
+    /// The tip-most head of a specific named branch.
+    Branch(String),
+    /// A specific bookmark.
+    Bookmark(String),
+    /// An arbitrary revision, like a tag, a (short) node ID, or a branch or bookmark name.
+    Revision(String),
+    /// A specific changeset, using a full 40-character node ID.
+    FullNode(String),
+    /// The revision that `hg clone` would check out, i.e., the `@` bookmark, if it exists, or the
+    /// tip-most head of the `default` branch.
+    DefaultBranch,
+}
+
+impl HgReference {
+    /// Creates a [`HgReference`] from an arbitrary revision string, which could represent a
+    /// branch, bookmark, tag, or node ID.
+    pub fn from_rev(rev: String) -> Self {
+        if rev.len() == 40 && rev.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+            Self::FullNode(rev)
+        } else {
+            Self::Revision(rev)
+        }
+    }
+
+    /// Converts the [`HgReference`] to a `str`.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::Branch(rev) => Some(rev),
+            Self::Bookmark(rev) => Some(rev),
+            Self::Revision(rev) => Some(rev),
+            Self::FullNode(rev) => Some(rev),
+            Self::DefaultBranch => None,
+        }
+    }
+
+    /// Returns the kind of this reference.
+    pub(crate) fn kind_str(&self) -> &str {
+        match self {
+            Self::Branch(_) => "branch",
+            Self::Bookmark(_) => "bookmark",
+            Self::Revision(_) => "revision",
+            Self::FullNode(_) => "changeset",
+            Self::DefaultBranch => "default branch",
+        }
+    }
+
+    /// Returns the precise [`HgNode`] of this reference, if it's a full node ID.
+    pub(crate) fn as_node(&self) -> Option<HgNode> {
+        if let Self::FullNode(rev) = self {
+            Some(HgNode::from_str(rev).expect("Full node should be exactly 40 characters"))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the revsets that identify this reference, in order of precedence.
+    fn revsets(&self) -> Vec<String> {
+        match self {
+            Self::Branch(branch) => vec![format!("max(head() and branch({}))", literal(branch))],
+            Self::Bookmark(bookmark) => vec![format!("bookmark({})", literal(bookmark))],
+            Self::Revision(rev) | Self::FullNode(rev) => vec![rev.clone()],
+            Self::DefaultBranch => vec![
+                // Unlike a literal, a pattern doesn't error if the bookmark doesn't exist.
+                String::from("bookmark('re:^@$')"),
+                format!("max(head() and branch({}))", literal("default")),
+            ],
+        }
+    }
+}
+
+impl Display for HgReference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str().unwrap_or("default"))
+    }
+}
+
+/// Quote a value as a literal string in a revset, e.g., `'literal:stable'`.
+fn literal(value: &str) -> String {
+    format!(
+        "'literal:{}'",
+        value.replace('\\', "\\\\").replace('\'', "\\'")
+    )
+}
+
+/// Create an `hg` invocation that ignores the user's output customizations, and never prompts.
+fn hg() -> Result<ProcessBuilder> {
+    let mut cmd = ProcessBuilder::new(HG.as_ref()?);
+    cmd.env(EnvVars::HGPLAIN, "1").arg("--noninteractive");
+    Ok(cmd)
+}
+
+/// A remote Mercurial repository. It gets cloned into a local [`HgRepository`].
+#[derive(PartialEq, Clone, Debug)]
+pub(crate) struct HgRemote {
+    /// URL to a remote repository.
+    url: Url,
+}
+
+impl HgRemote {
+    /// Creates an instance for a remote repository URL.
+    pub(crate) fn new(url: &Url) -> Self {
+        Self { url: url.clone() }
+    }
+
+    /// Gets the remote repository URL.
+    pub(crate) fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Pulls from this remote into a local repository at `into`, and resolves the given reference
+    /// to a changeset.
+    ///
+    /// When `locked_node` is provided, it takes precedence over `reference`.
+    ///
+    /// If we have a previous instance of the local repository, we pull into it. Otherwise, or if
+    /// the reference can't be resolved after pulling, the repository is cloned from scratch.
+    pub(crate) fn checkout(
+        &self,
+        into: &Path,
+        db: Option<HgRepository>,
+        reference: &HgReference,
+        locked_node: Option<HgNode>,
+    ) -> Result<(HgRepository, HgNode)> {
+        if let Some(db) = db {
+            db.pull(&self.url)
+                .with_context(|| format!("failed to pull into: {}", into.user_display()))?;
+
+            let resolved_node = match locked_node {
+                Some(node) => db.contains(node).then_some(node),
+                None => db.resolve(reference).ok(),
+            };
+
+            if let Some(node) = resolved_node {
+                return Ok((db, node));
+            }
+        }
+
+        // Otherwise, start from scratch to handle corrupt repositories.
+        if into.exists() {
+            paths::remove_dir_all(into)?;
+        }
+        let db = HgRepository::clone(&self.url, into)
+            .with_context(|| format!("failed to clone into: {}", into.user_display()))?;
+        let node = match locked_node {
+            Some(node) if db.contains(node) => node,
+            Some(node) => {
+                return Err(anyhow!(
+                    "failed to find changeset `{node}` in: {}",
+                    self.url
+                ))
+            }
+            None => db.resolve(reference)?,
+        };
+
+        Ok((db, node))
+    }
+}
+
+/// A local Mercurial repository.
+pub(crate) struct HgRepository {
+    /// Path to the underlying Mercurial repository on the local filesystem.
+    path: PathBuf,
+}
+
+impl HgRepository {
+    /// Opens an existing Mercurial repository at `path`.
+    pub(crate) fn open(path: &Path) -> Result<HgRepository> {
+        // Make sure there is a Mercurial repository at the specified path.
+        hg()?.arg("root").cwd(path).exec_with_output()?;
+
+        Ok(HgRepository {
+            path: path.to_path_buf(),
+        })
+    }
+
+    /// Clones the repository at `url` into `path`, without a working directory.
+    fn clone(url: &Url, path: &Path) -> Result<HgRepository> {
+        let parent = path
+            .parent()
+            .ok_or_else(|| anyhow!("invalid repository path: {}", path.user_display()))?;
+        paths::create_dir_all(parent)?;
+
+        hg()?
+            .arg("clone")
+            .arg("--noupdate")
+            .arg("--quiet")
+            .arg(url.as_str())
+            .arg(path)
+            .cwd(parent)
+            .exec_with_output()?;
+
+        Ok(HgRepository {
+            path: path.to_path_buf(),
+        })
+    }
+
+    /// Pulls any new changesets from the repository at `url`.
+    fn pull(&self, url: &Url) -> Result<()> {
+        hg()?
+            .arg("pull")
+            .arg("--quiet")
+            .arg(url.as_str())
+            .cwd(&self.path)
+            .exec_with_output()?;
+        Ok(())
+    }
+
+    /// Returns `true` if the changeset exists in this repository.
+    pub(crate) fn contains(&self, node: HgNode) -> bool {
+        self.log(node.as_str())
+            .is_ok_and(|found| found == Some(node))
+    }
+
+    /// Resolves the given reference to a changeset in this repository.
+    fn resolve(&self, reference: &HgReference) -> Result<HgNode> {
+        for revset in reference.revsets() {
+            match self.log(&revset) {
+                Ok(Some(node)) => return Ok(node),
+                Ok(None) => {}
+                Err(err) => debug!("Failed to resolve revset `{revset}`: {err:#}"),
+            }
+        }
+        Err(anyhow!(
+            "failed to find {} `{reference}` in: {}",
+            reference.kind_str(),
+            self.path.user_display()
+        ))
+    }
+
+    /// Returns the node ID of the first changeset in the given revset, if any.
+    fn log(&self, revset: &str) -> Result<Option<HgNode>> {
+        let output = hg()?
+            .arg("log")
+            .arg("--rev")
+            .arg(revset)
+            .arg("--limit")
+            .arg("1")
+            .arg("--template")
+            .arg("{node}")
+            .cwd(&self.path)
+            .exec_with_output()?;
+
+        let stdout = String::from_utf8(output.stdout)?;
+        let node = stdout.trim();
+        if node.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(HgNode::from_str(node)?))
+    }
+
+    /// Exports the files at the given changeset (including any subrepositories) to
+    /// `destination`.
+    ///
+    /// The export is staged in a temporary directory and moved into place once complete, such that
+    /// an existing `destination` can be reused as-is. Like `hg archive`, the export includes a
+    /// `.hg_archival.txt` file, from which build backends can infer the version.
+    pub(crate) fn archive_to(&self, node: HgNode, destination: &Path) -> Result<()> {
+        if destination.is_dir() {
+            debug!(
+                "Using existing Mercurial checkout at: {}",
+                destination.user_display()
+            );
+            return Ok(());
+        }
+
+        let parent = destination
+            .parent()
+            .ok_or_else(|| anyhow!("invalid checkout path: {}", destination.user_display()))?;
+        paths::create_dir_all(parent)?;
+        let temp_dir = tempfile::tempdir_in(parent)?;
+        let staging = temp_dir.path().join("archive");
+
+        hg()?
+            .arg("archive")
+            .arg("--rev")
+            .arg(node.as_str())
+            .arg("--type")
+            .arg("files")
+            .arg("--subrepos")
+            .arg(&staging)
+            .cwd(&self.path)
+            .exec_with_output()?;
+
+        fs_err::rename(&staging, destination)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{literal, HgReference};
+
+    #[test]
+    fn revsets() {
+        assert_eq!(literal("stable"), "'literal:stable'");
+        assert_eq!(literal(r"it's\"), r"'literal:it\'s\\'");
+        assert_eq!(
+            HgReference::Branch("stable".to_string()).revsets(),
+            vec!["max(head() and branch('literal:stable'))"]
+        );
+        assert_eq!(
+            HgReference::from_rev("4a23745badf5bf5ef7928f1e346e9986bd696d82".to_string()),
+            HgReference::FullNode("4a23745badf5bf5ef7928f1e346e9986bd696d82".to_string())
+        );
+        assert_eq!(
+            HgReference::from_rev("1.0".to_string()),
+            HgReference::Revision("1.0".to_string())
+        );
+    }
+}
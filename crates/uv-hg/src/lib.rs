@@ -0,0 +1,121 @@
+use url::Url;
+
+pub use crate::hg::{HgReference, HG};
+pub use crate::node::{HgNode, NodeParseError};
+pub use crate::resolver::{
+    HgRepositoryReference, HgResolver, HgResolverError, ResolvedHgRepositoryReference,
+};
+pub use crate::source::{Fetch, HgSource};
+
+mod hg;
+mod node;
+mod resolver;
+mod source;
+
+/// A URL reference to a Mercurial repository.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Hash, Ord)]
+pub struct HgUrl {
+    /// The URL of the Mercurial repository, with any query parameters, fragments, and leading
+    /// `hg+` removed.
+    repository: Url,
+    /// The reference to the changeset to use, which could be a branch, bookmark, or revision.
+    reference: HgReference,
+    /// The precise changeset to use, if known.
+    precise: Option<HgNode>,
+}
+
+impl HgUrl {
+    /// Create a new [`HgUrl`] from a repository URL and a reference.
+    pub fn from_reference(repository: Url, reference: HgReference) -> Self {
+        let precise = reference.as_node();
+        Self {
+            repository,
+            reference,
+            precise,
+        }
+    }
+
+    /// Create a new [`HgUrl`] from a repository URL and a precise changeset.
+    pub fn from_node(repository: Url, reference: HgReference, precise: HgNode) -> Self {
+        Self {
+            repository,
+            reference,
+            precise: Some(precise),
+        }
+    }
+
+    /// Set the precise [`HgNode`] to use for this Mercurial URL.
+    #[must_use]
+    pub fn with_precise(mut self, precise: HgNode) -> Self {
+        self.precise = Some(precise);
+        self
+    }
+
+    /// Set the [`HgReference`] to use for this Mercurial URL.
+    #[must_use]
+    pub fn with_reference(mut self, reference: HgReference) -> Self {
+        self.reference = reference;
+        self
+    }
+
+    /// Return the [`Url`] of the Mercurial repository.
+    pub fn repository(&self) -> &Url {
+        &self.repository
+    }
+
+    /// Return the reference to the changeset to use, which could be a branch, bookmark, or
+    /// revision.
+    pub fn reference(&self) -> &HgReference {
+        &self.reference
+    }
+
+    /// Return the precise changeset, if known.
+    pub fn precise(&self) -> Option<HgNode> {
+        self.precise
+    }
+}
+
+impl From<Url> for HgUrl {
+    /// Initialize a [`HgUrl`] source from a URL.
+    fn from(mut url: Url) -> Self {
+        // Remove any query parameters and fragments.
+        url.set_fragment(None);
+        url.set_query(None);
+
+        // If the URL ends with a reference, like `https://hg.example.com/MyProject@1.0`, extract
+        // it.
+        let mut reference = HgReference::DefaultBranch;
+        if let Some((prefix, suffix)) = url
+            .path()
+            .rsplit_once('@')
+            .map(|(prefix, suffix)| (prefix.to_string(), suffix.to_string()))
+        {
+            reference = HgReference::from_rev(suffix);
+            url.set_path(&prefix);
+        }
+
+        Self::from_reference(url, reference)
+    }
+}
+
+impl From<HgUrl> for Url {
+    fn from(hg: HgUrl) -> Self {
+        let mut url = hg.repository;
+
+        // If we have a precise changeset, add `@` and the node ID to the URL. Otherwise, add the
+        // branch, bookmark, or revision.
+        if let Some(precise) = hg.precise {
+            url.set_path(&format!("{}@{}", url.path(), precise));
+        } else if let Some(rev) = hg.reference.as_str() {
+            url.set_path(&format!("{}@{}", url.path(), rev));
+        }
+
+        url
+    }
+}
+
+impl std::fmt::Display for HgUrl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.repository)
+    }
+}
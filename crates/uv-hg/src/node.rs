@@ -0,0 +1,91 @@
+use std::fmt::Display;
+use std::str::{self, FromStr};
+
+use thiserror::Error;
+
+/// A complete Mercurial node ID, i.e., a 40-character hexadecimal representation of a changeset.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HgNode([u8; 40]);
+
+impl HgNode {
+    /// Return the string representation of the node ID.
+    pub fn as_str(&self) -> &str {
+        str::from_utf8(&self.0).unwrap()
+    }
+
+    /// Convert the node ID to a truncated representation, i.e., the first 12 characters of the ID,
+    /// as in Mercurial's short form.
+    pub fn to_short_string(&self) -> String {
+        self.as_str()[0..12].to_string()
+    }
+}
+
+#[derive(Debug, Error, PartialEq)]
+pub enum NodeParseError {
+    #[error("Node ID must be exactly 40 characters, but found {0}")]
+    InvalidLength(usize),
+    #[error("Node ID must be hexadecimal: `{0}`")]
+    InvalidCharacter(String),
+}
+
+impl FromStr for HgNode {
+    type Err = NodeParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() != 40 {
+            return Err(NodeParseError::InvalidLength(s.len()));
+        }
+        if !s.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+            return Err(NodeParseError::InvalidCharacter(s.to_string()));
+        }
+
+        let mut out = [0; 40];
+        out.copy_from_slice(s.to_ascii_lowercase().as_bytes());
+        Ok(Self(out))
+    }
+}
+
+impl Display for HgNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl serde::Serialize for HgNode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for HgNode {
+    fn deserialize<D>(deserializer: D) -> Result<HgNode, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        HgNode::from_str(&value).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::{HgNode, NodeParseError};
+
+    #[test]
+    fn hg_node() {
+        let node = HgNode::from_str("4A23745BADF5BF5EF7928F1E346E9986BD696D82").unwrap();
+        assert_eq!(node.as_str(), "4a23745badf5bf5ef7928f1e346e9986bd696d82");
+        assert_eq!(node.to_short_string(), "4a23745badf5");
+
+        assert_eq!(HgNode::from_str(""), Err(NodeParseError::InvalidLength(0)));
+        assert_eq!(
+            HgNode::from_str(&str::repeat("g", 40)),
+            Err(NodeParseError::InvalidCharacter(str::repeat("g", 40)))
+        );
+    }
+}
@@ -0,0 +1,180 @@
+use std::borrow::Cow;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use tracing::debug;
+
+use dashmap::mapref::one::Ref;
+use dashmap::DashMap;
+use fs_err::tokio as fs;
+use uv_cache_key::{cache_digest, RepositoryUrl};
+use uv_fs::LockedFile;
+
+use crate::{Fetch, HgNode, HgReference, HgSource, HgUrl};
+
+#[derive(Debug, thiserror::Error)]
+pub enum HgResolverError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Join(#[from] tokio::task::JoinError),
+    #[error("Mercurial operation failed")]
+    Hg(#[source] anyhow::Error),
+}
+
+/// A resolver for Mercurial repositories.
+#[derive(Default, Clone)]
+pub struct HgResolver(Arc<DashMap<HgRepositoryReference, HgNode>>);
+
+impl HgResolver {
+    /// Inserts a new [`HgNode`] for the given [`HgRepositoryReference`].
+    pub fn insert(&self, reference: HgRepositoryReference, node: HgNode) {
+        self.0.insert(reference, node);
+    }
+
+    /// Returns the [`HgNode`] for the given [`HgRepositoryReference`], if it exists.
+    fn get(&self, reference: &HgRepositoryReference) -> Option<Ref<HgRepositoryReference, HgNode>> {
+        self.0.get(reference)
+    }
+
+    /// Resolve a Mercurial URL to a specific changeset.
+    pub async fn resolve(&self, url: &HgUrl, cache: PathBuf) -> Result<HgNode, HgResolverError> {
+        debug!("Resolving source distribution from Mercurial: {url}");
+
+        let reference = HgRepositoryReference::from(url);
+
+        // If we know the precise changeset already, return it.
+        if let Some(precise) = self.get(&reference) {
+            return Ok(*precise);
+        }
+
+        // Avoid races between different processes, too.
+        let lock_dir = cache.join("locks");
+        fs::create_dir_all(&lock_dir).await?;
+        let repository_url = RepositoryUrl::new(url.repository());
+        let _lock = LockedFile::acquire(
+            lock_dir.join(cache_digest(&repository_url)),
+            &repository_url,
+        )
+        .await?;
+
+        // Fetch the Mercurial repository.
+        let source = HgSource::new(url.clone(), cache);
+        let precise = tokio::task::spawn_blocking(move || source.resolve())
+            .await?
+            .map_err(HgResolverError::Hg)?;
+
+        // Insert the resolved URL into the in-memory cache. This ensures that subsequent fetches
+        // resolve to the same precise changeset.
+        self.insert(reference, precise);
+
+        Ok(precise)
+    }
+
+    /// Fetch a remote Mercurial repository.
+    pub async fn fetch(&self, url: &HgUrl, cache: PathBuf) -> Result<Fetch, HgResolverError> {
+        debug!("Fetching source distribution from Mercurial: {url}");
+
+        let reference = HgRepositoryReference::from(url);
+
+        // If we know the precise changeset already, reuse it, to ensure that all fetches within a
+        // single process are consistent.
+        let url = {
+            if let Some(precise) = self.get(&reference) {
+                Cow::Owned(url.clone().with_precise(*precise))
+            } else {
+                Cow::Borrowed(url)
+            }
+        };
+
+        // Avoid races between different processes, too.
+        let lock_dir = cache.join("locks");
+        fs::create_dir_all(&lock_dir).await?;
+        let repository_url = RepositoryUrl::new(url.repository());
+        let _lock = LockedFile::acquire(
+            lock_dir.join(cache_digest(&repository_url)),
+            &repository_url,
+        )
+        .await?;
+
+        // Fetch the Mercurial repository.
+        let source = HgSource::new(url.as_ref().clone(), cache);
+        let fetch = tokio::task::spawn_blocking(move || source.fetch())
+            .await?
+            .map_err(HgResolverError::Hg)?;
+
+        // Insert the resolved URL into the in-memory cache. This ensures that subsequent fetches
+        // resolve to the same precise changeset.
+        if let Some(precise) = fetch.hg().precise() {
+            self.insert(reference, precise);
+        }
+
+        Ok(fetch)
+    }
+
+    /// Given a remote source distribution, return a precise variant, if possible.
+    ///
+    /// For example, given a Mercurial dependency with a reference to a branch or bookmark, return
+    /// a URL with a precise reference to the current changeset of that branch or bookmark.
+    ///
+    /// This method will only return precise URLs for URLs that have already been resolved, and
+    /// will return `None` for URLs that have not been resolved.
+    pub fn precise(&self, url: HgUrl) -> Option<HgUrl> {
+        let reference = HgRepositoryReference::from(&url);
+        let precise = self.get(&reference)?;
+        Some(url.with_precise(*precise))
+    }
+
+    /// Returns `true` if the two Mercurial URLs refer to the same precise changeset.
+    pub fn same_ref(&self, a: &HgUrl, b: &HgUrl) -> bool {
+        let a_ref = HgRepositoryReference::from(a);
+        let b_ref = HgRepositoryReference::from(b);
+
+        // The URLs must refer to the same repository.
+        if a_ref.url != b_ref.url {
+            return false;
+        }
+
+        // If the URLs have the same reference, they refer to the same changeset.
+        if a_ref.reference == b_ref.reference {
+            return true;
+        }
+
+        // Otherwise, the URLs must resolve to the same precise changeset.
+        let Some(a_precise) = a.precise().or_else(|| self.get(&a_ref).map(|node| *node)) else {
+            return false;
+        };
+
+        let Some(b_precise) = b.precise().or_else(|| self.get(&b_ref).map(|node| *node)) else {
+            return false;
+        };
+
+        a_precise == b_precise
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ResolvedHgRepositoryReference {
+    /// An abstract reference to a Mercurial repository, including the URL and the changeset
+    /// (e.g., a branch, bookmark, or revision).
+    pub reference: HgRepositoryReference,
+    /// The precise node ID of the reference.
+    pub node: HgNode,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HgRepositoryReference {
+    /// The URL of the Mercurial repository, with any query parameters and fragments removed.
+    pub url: RepositoryUrl,
+    /// The reference to the changeset to use, which could be a branch, bookmark, or revision.
+    pub reference: HgReference,
+}
+
+impl From<&HgUrl> for HgRepositoryReference {
+    fn from(hg: &HgUrl) -> Self {
+        Self {
+            url: RepositoryUrl::new(hg.repository()),
+            reference: hg.reference().clone(),
+        }
+    }
+}
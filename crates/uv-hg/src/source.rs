@@ -0,0 +1,105 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use tracing::{debug, instrument};
+
+use uv_cache_key::{cache_digest, RepositoryUrl};
+
+use crate::hg::{HgRemote, HgRepository};
+use crate::{HgNode, HgUrl};
+
+/// A remote Mercurial source that can be checked out locally.
+pub struct HgSource {
+    /// The Mercurial reference from the manifest file.
+    hg: HgUrl,
+    /// The path to the Mercurial source database.
+    cache: PathBuf,
+}
+
+impl HgSource {
+    /// Initialize a new Mercurial source.
+    pub fn new(hg: HgUrl, cache: impl Into<PathBuf>) -> Self {
+        Self {
+            hg,
+            cache: cache.into(),
+        }
+    }
+
+    /// Resolve a Mercurial source to a specific changeset.
+    #[instrument(skip(self), fields(repository = %self.hg.repository, node = ?self.hg.precise))]
+    pub fn resolve(self) -> Result<HgNode> {
+        let (_db, node) = self.update()?;
+        Ok(node)
+    }
+
+    /// Fetch the underlying Mercurial repository at the given changeset.
+    #[instrument(skip(self), fields(repository = %self.hg.repository, node = ?self.hg.precise))]
+    pub fn fetch(self) -> Result<Fetch> {
+        let (db, node) = self.update()?;
+
+        // Export the changeset from the database to a scoped location on the filesystem. Don't use
+        // the full node ID, in order to contribute less to reaching the path length limit on
+        // Windows.
+        let ident = cache_digest(&RepositoryUrl::new(&self.hg.repository));
+        let checkout_path = self
+            .cache
+            .join("checkouts")
+            .join(&ident)
+            .join(node.to_short_string());
+        db.archive_to(node, &checkout_path)?;
+
+        Ok(Fetch {
+            hg: self.hg.with_precise(node),
+            path: checkout_path,
+        })
+    }
+
+    /// Update the Mercurial database for this source, returning the database along with the
+    /// changeset to which the source resolves.
+    fn update(&self) -> Result<(HgRepository, HgNode)> {
+        // The path to the repo, within the Mercurial database.
+        let ident = cache_digest(&RepositoryUrl::new(&self.hg.repository));
+        let db_path = self.cache.join("db").join(&ident);
+
+        let remote = HgRemote::new(&self.hg.repository);
+        match (self.hg.precise, HgRepository::open(&db_path).ok()) {
+            // If we have a locked changeset, and we have a preexisting database which has that
+            // changeset, then no update needs to happen.
+            (Some(node), Some(db)) if db.contains(node) => {
+                debug!("Using existing Mercurial source `{}`", self.hg.repository);
+                Ok((db, node))
+            }
+
+            // ... otherwise we use this state to update the database.
+            (locked_node, db) => {
+                debug!("Updating Mercurial source `{}`", self.hg.repository);
+                remote.checkout(&db_path, db, &self.hg.reference, locked_node)
+            }
+        }
+    }
+}
+
+pub struct Fetch {
+    /// The [`HgUrl`] reference that was fetched.
+    hg: HgUrl,
+    /// The path to the checked out repository.
+    path: PathBuf,
+}
+
+impl Fetch {
+    pub fn hg(&self) -> &HgUrl {
+        &self.hg
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn into_hg(self) -> HgUrl {
+        self.hg
+    }
+
+    pub fn into_path(self) -> PathBuf {
+        self.path
+    }
+}
@@ -7,18 +7,25 @@ use platform_info::PlatformInfoError;
 use thiserror::Error;
 use zip::result::ZipError;
 
+pub use record::RecordEntry;
 pub use uninstall::{uninstall_egg, uninstall_legacy_editable, uninstall_wheel, Uninstall};
 use uv_fs::Simplified;
 use uv_normalize::PackageName;
 use uv_pep440::Version;
 use uv_platform_tags::{Arch, Os};
 use uv_pypi_types::Scheme;
-pub use wheel::{parse_wheel_file, read_record_file, LibKind};
+pub use uv_trampoline_builder::LauncherResources;
+pub use verify::{
+    read_installed_record, verify_installed, verify_unzipped_wheel, verify_wheel_archive,
+    RecordMismatch, RecordedFile, VerifyError,
+};
+pub use wheel::{parse_wheel_file, read_record_file, write_record_file, LibKind};
 
 pub mod linker;
 mod record;
 mod script;
 mod uninstall;
+mod verify;
 mod wheel;
 
 /// The layout of the target environment into which a wheel can be installed.
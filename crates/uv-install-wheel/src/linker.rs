@@ -7,10 +7,10 @@ use std::time::SystemTime;
 
 use crate::script::{scripts_from_ini, Script};
 use crate::wheel::{
-    extra_dist_info, install_data, parse_wheel_file, read_record_file, write_script_entrypoints,
-    LibKind,
+    extra_dist_info, install_data, parse_wheel_file, read_record_file, write_record_file,
+    write_script_entrypoints, LibKind,
 };
-use crate::{Error, Layout};
+use crate::{Error, LauncherResources, Layout};
 use fs_err as fs;
 use fs_err::{DirEntry, File};
 use reflink_copy as reflink;
@@ -20,6 +20,7 @@ use tempfile::tempdir_in;
 use tracing::{debug, instrument, trace};
 use uv_cache_info::CacheInfo;
 use uv_distribution_filename::WheelFilename;
+use uv_fs::Simplified;
 use uv_pypi_types::{DirectUrl, Metadata12};
 use uv_warnings::warn_user_once;
 use walkdir::WalkDir;
@@ -43,6 +44,7 @@ pub fn install_wheel(
     direct_url: Option<&DirectUrl>,
     cache_info: Option<&CacheInfo>,
     installer: Option<&str>,
+    launcher_resources: Option<&LauncherResources>,
     link_mode: LinkMode,
     locks: &Locks,
 ) -> Result<(), Error> {
@@ -106,6 +108,7 @@ pub fn install_wheel(
             &console_scripts,
             &mut record,
             false,
+            launcher_resources,
         )?;
         write_script_entrypoints(
             layout,
@@ -114,6 +117,7 @@ pub fn install_wheel(
             &gui_scripts,
             &mut record,
             true,
+            launcher_resources,
         )?;
     }
 
@@ -152,14 +156,10 @@ pub fn install_wheel(
     )?;
 
     trace!(?name, "Writing record");
-    let mut record_writer = csv::WriterBuilder::new()
-        .has_headers(false)
-        .escape(b'"')
-        .from_path(site_packages.join(format!("{dist_info_prefix}.dist-info/RECORD")))?;
-    record.sort();
-    for entry in record {
-        record_writer.serialize(entry)?;
-    }
+    write_record_file(
+        &site_packages.join(format!("{dist_info_prefix}.dist-info/RECORD")),
+        record,
+    )?;
 
     Ok(())
 }
@@ -235,6 +235,9 @@ fn parse_scripts(
 #[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum LinkMode {
+    /// Select the fastest method supported by the target filesystem for each file, preferring to
+    /// clone, then hard link, then copy packages into the `site-packages` directory.
+    Auto,
     /// Clone (i.e., copy-on-write) packages from the wheel into the `site-packages` directory.
     Clone,
     /// Copy packages from the wheel into the `site-packages` directory.
@@ -255,7 +258,7 @@ impl Default for LinkMode {
         if cfg!(any(target_os = "macos", target_os = "ios")) {
             Self::Clone
         } else {
-            Self::Hardlink
+            Self::Auto
         }
     }
 }
@@ -270,6 +273,7 @@ impl LinkMode {
         locks: &Locks,
     ) -> Result<usize, Error> {
         match self {
+            Self::Auto => auto_wheel_files(site_packages, wheel, locks),
             Self::Clone => clone_wheel_files(site_packages, wheel, locks),
             Self::Copy => copy_wheel_files(site_packages, wheel, locks),
             Self::Hardlink => hardlink_wheel_files(site_packages, wheel, locks),
@@ -433,6 +437,14 @@ fn clone_recursive(
                         reflink::reflink(&from, &tempfile)?;
                         fs::rename(&tempfile, to)?;
                     }
+                } else if uv_fs::is_cross_device(&err) {
+                    debug!(
+                        "Failed to clone `{}` to `{}` across volumes, attempting to copy files as a fallback",
+                        from.display(),
+                        to.display()
+                    );
+                    *attempt = Attempt::UseCopyFallback;
+                    clone_recursive(site_packages, wheel, locks, entry, attempt)?;
                 } else {
                     return Err(Error::Reflink { from, to, err });
                 }
@@ -457,6 +469,129 @@ fn clone_recursive(
     Ok(())
 }
 
+/// Extract a wheel by linking each of its files into site packages with the fastest method that
+/// the target filesystem supports.
+///
+/// Files are cloned when the filesystem supports copy-on-write (e.g., APFS, btrfs, or XFS),
+/// hard-linked when the cache and the target share a device, and copied otherwise. Unlike the
+/// explicit link modes, falling back to a slower method is expected here, so no warning is shown.
+fn auto_wheel_files(
+    site_packages: impl AsRef<Path>,
+    wheel: impl AsRef<Path>,
+    locks: &Locks,
+) -> Result<usize, Error> {
+    let mut method = AutoMethod::default();
+    let mut count = 0usize;
+
+    // Walk over the directory.
+    for entry in WalkDir::new(&wheel) {
+        let entry = entry?;
+        let path = entry.path();
+
+        let relative = path.strip_prefix(&wheel).unwrap();
+        let out_path = site_packages.as_ref().join(relative);
+
+        if entry.file_type().is_dir() {
+            fs::create_dir_all(&out_path)?;
+            continue;
+        }
+
+        // The `RECORD` file is modified during installation, so we copy it instead of linking.
+        if path.ends_with("RECORD") {
+            synchronized_copy(path, &out_path, locks)?;
+            count += 1;
+            continue;
+        }
+
+        method = auto_link_file(path, &out_path, site_packages.as_ref(), method, locks)?;
+        count += 1;
+    }
+
+    Ok(count)
+}
+
+/// The method used to link a file in [`LinkMode::Auto`], in order of preference.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum AutoMethod {
+    #[default]
+    Clone,
+    Hardlink,
+    Copy,
+}
+
+impl AutoMethod {
+    /// Return the next method to attempt if this one isn't supported by the filesystem.
+    fn fallback(self) -> Self {
+        match self {
+            Self::Clone => Self::Hardlink,
+            Self::Hardlink | Self::Copy => Self::Copy,
+        }
+    }
+
+    /// Link `from` to `to` with this method.
+    fn link(self, from: &Path, to: &Path, locks: &Locks) -> std::io::Result<()> {
+        match self {
+            Self::Clone => reflink::reflink(from, to),
+            Self::Hardlink => fs::hard_link(from, to),
+            Self::Copy => synchronized_copy(from, to, locks),
+        }
+    }
+}
+
+impl std::fmt::Display for AutoMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Clone => f.write_str("clone"),
+            Self::Hardlink => f.write_str("hardlink"),
+            Self::Copy => f.write_str("copy"),
+        }
+    }
+}
+
+/// Link a single file with the preferred `method`, falling back to slower methods if the
+/// filesystem doesn't support it (e.g., reflinks on ext4, or hard links across devices).
+///
+/// Returns the method that succeeded, to be used for subsequent files.
+fn auto_link_file(
+    from: &Path,
+    to: &Path,
+    site_packages: &Path,
+    mut method: AutoMethod,
+    locks: &Locks,
+) -> Result<AutoMethod, Error> {
+    loop {
+        let err = match method.link(from, to, locks) {
+            Ok(()) => return Ok(method),
+            Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists => {
+                // If the file already exists, link to a temporary location and rename over it, as
+                // removing and recreating would lead to race conditions.
+                let tempdir = tempdir_in(site_packages)?;
+                let tempfile = tempdir.path().join(from.file_name().unwrap());
+                match method.link(from, &tempfile, locks) {
+                    Ok(()) => {
+                        fs::rename(&tempfile, to)?;
+                        return Ok(method);
+                    }
+                    Err(err) => err,
+                }
+            }
+            Err(err) => err,
+        };
+
+        if method == AutoMethod::Copy {
+            return Err(err.into());
+        }
+
+        let fallback = method.fallback();
+        debug!(
+            "Failed to {method} `{}` to `{}` ({err}), falling back to {fallback}",
+            from.display(),
+            to.display()
+        );
+        method = fallback;
+    }
+}
+
 /// Extract a wheel by copying all of its files into site packages.
 fn copy_wheel_files(
     site_packages: impl AsRef<Path>,
@@ -530,16 +665,17 @@ fn hardlink_wheel_files(
                         // Removing and recreating would lead to race conditions.
                         let tempdir = tempdir_in(&site_packages)?;
                         let tempfile = tempdir.path().join(entry.file_name());
-                        if fs::hard_link(path, &tempfile).is_ok() {
-                            fs_err::rename(&tempfile, &out_path)?;
-                        } else {
+                        if let Err(err) = fs::hard_link(path, &tempfile) {
                             debug!(
                                 "Failed to hardlink `{}` to `{}`, attempting to copy files as a fallback",
                                 out_path.display(),
                                 path.display()
                             );
+                            warn_hardlink_fallback(&err, site_packages.as_ref());
                             synchronized_copy(path, &out_path, locks)?;
                             attempt = Attempt::UseCopyFallback;
+                        } else {
+                            fs_err::rename(&tempfile, &out_path)?;
                         }
                     } else {
                         debug!(
@@ -547,6 +683,7 @@ fn hardlink_wheel_files(
                             out_path.display(),
                             path.display()
                         );
+                        warn_hardlink_fallback(&err, site_packages.as_ref());
                         synchronized_copy(path, &out_path, locks)?;
                         attempt = Attempt::UseCopyFallback;
                     }
@@ -565,6 +702,17 @@ fn hardlink_wheel_files(
                         let tempfile = tempdir.path().join(entry.file_name());
                         fs::hard_link(path, &tempfile)?;
                         fs_err::rename(&tempfile, &out_path)?;
+                    } else if uv_fs::is_cross_device(&err) {
+                        // Part of the environment may live on a different volume than the rest
+                        // (e.g., behind a mount point or junction).
+                        debug!(
+                            "Failed to hardlink `{}` to `{}` across volumes, attempting to copy files as a fallback",
+                            out_path.display(),
+                            path.display()
+                        );
+                        warn_hardlink_fallback(&err, site_packages.as_ref());
+                        synchronized_copy(path, &out_path, locks)?;
+                        attempt = Attempt::UseCopyFallback;
                     } else {
                         return Err(err.into());
                     }
@@ -572,7 +720,6 @@ fn hardlink_wheel_files(
             }
             Attempt::UseCopyFallback => {
                 synchronized_copy(path, &out_path, locks)?;
-                warn_user_once!("Failed to hardlink files; falling back to full copy. This may lead to degraded performance.\n         If the cache and target directories are on different filesystems, hardlinking may not be supported.\n         If this is intentional, set `export UV_LINK_MODE=copy` or use `--link-mode=copy` to suppress this warning.");
             }
         }
 
@@ -582,6 +729,19 @@ fn hardlink_wheel_files(
     Ok(count)
 }
 
+/// Warn the user that hard-linking into `site_packages` failed, and that files will be copied
+/// instead.
+fn warn_hardlink_fallback(err: &std::io::Error, site_packages: &Path) {
+    if uv_fs::is_cross_device(err) {
+        warn_user_once!(
+            "Failed to hardlink files because the cache and the target environment (`{}`) are on different volumes; falling back to full copy. This may lead to degraded performance.\n         To use hardlinks, move the cache to the same volume as the environment (e.g., with `UV_CACHE_DIR`).\n         If this is intentional, set `export UV_LINK_MODE=copy` or use `--link-mode=copy` to suppress this warning.",
+            site_packages.user_display()
+        );
+    } else {
+        warn_user_once!("Failed to hardlink files; falling back to full copy. This may lead to degraded performance.\n         If the cache and target directories are on different filesystems, hardlinking may not be supported.\n         If this is intentional, set `export UV_LINK_MODE=copy` or use `--link-mode=copy` to suppress this warning.");
+    }
+}
+
 /// Extract a wheel by symbolically-linking all of its files into site packages.
 fn symlink_wheel_files(
     site_packages: impl AsRef<Path>,
@@ -714,3 +874,115 @@ fn create_symlink<P: AsRef<Path>, Q: AsRef<Path>>(original: P, link: Q) -> std::
         std::os::windows::fs::symlink_file(original, link)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use anyhow::Result;
+    use assert_fs::prelude::*;
+
+    use super::{auto_link_file, AutoMethod, LinkMode, Locks};
+
+    #[test]
+    fn auto_method_fallback() {
+        assert_eq!(AutoMethod::Clone.fallback(), AutoMethod::Hardlink);
+        assert_eq!(AutoMethod::Hardlink.fallback(), AutoMethod::Copy);
+        assert_eq!(AutoMethod::Copy.fallback(), AutoMethod::Copy);
+    }
+
+    #[test]
+    fn auto_link_wheel_files() -> Result<()> {
+        let wheel = assert_fs::TempDir::new()?;
+        wheel
+            .child("pkg/__init__.py")
+            .write_str("print('hello')\n")?;
+        wheel.child("pkg/data/file.txt").write_str("data\n")?;
+        wheel
+            .child("pkg-0.1.0.dist-info/RECORD")
+            .write_str("pkg/__init__.py,,\n")?;
+
+        let site_packages = assert_fs::TempDir::new()?;
+        let count = LinkMode::Auto.link_wheel_files(
+            site_packages.path(),
+            wheel.path(),
+            &Locks::default(),
+        )?;
+        assert_eq!(count, 3);
+
+        site_packages
+            .child("pkg/__init__.py")
+            .assert("print('hello')\n");
+        site_packages.child("pkg/data/file.txt").assert("data\n");
+        site_packages
+            .child("pkg-0.1.0.dist-info/RECORD")
+            .assert("pkg/__init__.py,,\n");
+
+        // The `RECORD` file is always copied, since it's modified during installation.
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::MetadataExt;
+
+            let record = fs_err::metadata(site_packages.child("pkg-0.1.0.dist-info/RECORD"))?;
+            assert_eq!(record.nlink(), 1);
+        }
+
+        Ok(())
+    }
+
+    /// Linking over an existing file should replace it, regardless of the method that's used.
+    #[test]
+    fn auto_link_file_existing() -> Result<()> {
+        let source = assert_fs::TempDir::new()?;
+        let from = source.child("module.py");
+        from.write_str("new\n")?;
+
+        let site_packages = assert_fs::TempDir::new()?;
+        let to = site_packages.child("module.py");
+        to.write_str("old\n")?;
+
+        for method in [AutoMethod::Clone, AutoMethod::Hardlink, AutoMethod::Copy] {
+            let used = auto_link_file(
+                from.path(),
+                to.path(),
+                site_packages.path(),
+                method,
+                &Locks::default(),
+            )?;
+
+            // Falling back is allowed, but never to a faster method.
+            assert!(used == method || used == method.fallback() || used == AutoMethod::Copy);
+            to.assert("new\n");
+        }
+
+        Ok(())
+    }
+
+    /// When hard links are used, the installed file should share an inode with the cache.
+    #[test]
+    #[cfg(unix)]
+    fn auto_link_file_hardlink() -> Result<()> {
+        use std::os::unix::fs::MetadataExt;
+
+        let source = assert_fs::TempDir::new()?;
+        let from = source.child("module.py");
+        from.write_str("contents\n")?;
+
+        // Use a sibling directory, such that the source and target share a device.
+        let to = source.child("site-packages/module.py");
+        fs_err::create_dir_all(source.child("site-packages").path())?;
+
+        let used = auto_link_file(
+            from.path(),
+            to.path(),
+            &source.path().join("site-packages"),
+            AutoMethod::Hardlink,
+            &Locks::default(),
+        )?;
+        assert_eq!(used, AutoMethod::Hardlink);
+        assert_eq!(
+            fs_err::metadata(from.path())?.ino(),
+            fs_err::metadata(to.path())?.ino()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,437 @@
+//! Verify the integrity of wheels, and of installed distributions, against the hashes recorded in
+//! their `RECORD` files.
+
+use std::io::{self, BufReader, Read};
+use std::path::{Component, Path, PathBuf};
+
+use data_encoding::BASE64URL_NOPAD;
+use rustc_hash::{FxHashMap, FxHashSet};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+use walkdir::WalkDir;
+use zip::result::ZipError;
+use zip::ZipArchive;
+
+use uv_fs::normalize_path;
+
+use crate::wheel::read_record_file;
+
+/// A wheel whose contents don't match its `RECORD` file.
+#[derive(Debug, Error)]
+pub enum VerifyError {
+    #[error("Missing `RECORD` file")]
+    MissingRecord,
+    #[error("Invalid `RECORD` file")]
+    InvalidRecord(#[source] Box<crate::Error>),
+    #[error("File listed in `RECORD` is missing: `{0}`")]
+    MissingFile(String),
+    #[error("Hash mismatch for `{path}`: expected `sha256={expected}`, found `sha256={actual}`")]
+    HashMismatch {
+        path: String,
+        expected: String,
+        actual: String,
+    },
+    #[error("Failed to read `{0}`")]
+    Read(String, #[source] io::Error),
+    #[error("Invalid wheel archive")]
+    Zip(#[source] ZipError),
+}
+
+/// Verify an unzipped wheel, i.e., a directory containing the wheel's contents, against the
+/// SHA-256 hashes in its `RECORD` file.
+///
+/// Files that are not listed in the `RECORD` file, or that are listed without a hash, are not
+/// verified.
+pub fn verify_unzipped_wheel(root: &Path) -> Result<(), VerifyError> {
+    let record_path = find_unzipped_record(root).ok_or(VerifyError::MissingRecord)?;
+    let mut record = fs_err::File::open(&record_path)
+        .map_err(|err| VerifyError::Read(record_path.display().to_string(), err))?;
+    let entries =
+        read_record_file(&mut record).map_err(|err| VerifyError::InvalidRecord(Box::new(err)))?;
+
+    for entry in entries {
+        let Some(expected) = entry.hash.as_deref().and_then(sha256_digest) else {
+            continue;
+        };
+
+        // Avoid reading outside the wheel, e.g., for malformed `RECORD` files.
+        if entry
+            .path
+            .split(['/', '\\'])
+            .any(|component| component == "..")
+        {
+            continue;
+        }
+
+        let mut file = match fs_err::File::open(root.join(&entry.path)) {
+            Ok(file) => file,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                return Err(VerifyError::MissingFile(entry.path));
+            }
+            Err(err) => return Err(VerifyError::Read(entry.path, err)),
+        };
+        let actual =
+            hash_reader(&mut file).map_err(|err| VerifyError::Read(entry.path.clone(), err))?;
+        if actual != expected {
+            return Err(VerifyError::HashMismatch {
+                path: entry.path,
+                expected: expected.to_string(),
+                actual,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Verify a wheel archive (i.e., a `.whl` file) against the SHA-256 hashes in its `RECORD` file.
+///
+/// Every file in the archive is read in full, which additionally validates its CRC-32 checksum.
+pub fn verify_wheel_archive(path: &Path) -> Result<(), VerifyError> {
+    let file = fs_err::File::open(path)
+        .map_err(|err| VerifyError::Read(path.display().to_string(), err))?;
+    let mut archive = ZipArchive::new(BufReader::new(file)).map_err(VerifyError::Zip)?;
+
+    // Locate the `RECORD` file, i.e., `{name}-{version}.dist-info/RECORD`.
+    let record_path = archive
+        .file_names()
+        .find(|name| {
+            name.split_once('/').is_some_and(|(directory, file)| {
+                directory.ends_with(".dist-info") && file == "RECORD"
+            })
+        })
+        .map(ToString::to_string)
+        .ok_or(VerifyError::MissingRecord)?;
+    let entries = {
+        let mut record = archive.by_name(&record_path).map_err(VerifyError::Zip)?;
+        read_record_file(&mut record).map_err(|err| VerifyError::InvalidRecord(Box::new(err)))?
+    };
+    let mut expected = entries
+        .into_iter()
+        .filter_map(|entry| {
+            let digest = entry.hash.as_deref().and_then(sha256_digest)?.to_string();
+            Some((entry.path, digest))
+        })
+        .collect::<FxHashMap<_, _>>();
+
+    for index in 0..archive.len() {
+        let mut file = archive.by_index(index).map_err(VerifyError::Zip)?;
+        if file.is_dir() {
+            continue;
+        }
+        let name = file.name().to_string();
+        let actual = hash_reader(&mut file).map_err(|err| VerifyError::Read(name.clone(), err))?;
+        if let Some(expected) = expected.remove(&name) {
+            if actual != expected {
+                return Err(VerifyError::HashMismatch {
+                    path: name,
+                    expected,
+                    actual,
+                });
+            }
+        }
+    }
+
+    // Any remaining entries are listed in the `RECORD` file, but missing from the archive.
+    if let Some(path) = expected.into_keys().min() {
+        return Err(VerifyError::MissingFile(path));
+    }
+
+    Ok(())
+}
+
+/// A file listed in the `RECORD` file of an installed distribution.
+#[derive(Debug, Clone)]
+pub struct RecordedFile {
+    /// The absolute path to the file.
+    pub path: PathBuf,
+    /// The SHA-256 digest of the file, if recorded.
+    sha256: Option<String>,
+    /// The size of the file, if recorded.
+    size: Option<u64>,
+}
+
+/// A discrepancy between an installed distribution and its `RECORD` file.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RecordMismatch {
+    /// The file's contents don't match the recorded hash or size.
+    Modified(PathBuf),
+    /// The file is listed in the `RECORD` file, but doesn't exist.
+    Missing(PathBuf),
+    /// The file is in a directory installed by the distribution, but isn't listed in any `RECORD`
+    /// file.
+    Extra(PathBuf),
+}
+
+/// Read the files listed in the `RECORD` file of an installed distribution.
+///
+/// Paths are resolved relative to the directory containing the `.dist-info` directory (i.e., the
+/// `site-packages` directory).
+pub fn read_installed_record(dist_info: &Path) -> Result<Vec<RecordedFile>, VerifyError> {
+    let root = dist_info.parent().unwrap_or(dist_info);
+    let record_path = dist_info.join("RECORD");
+    let mut record = match fs_err::File::open(&record_path) {
+        Ok(record) => record,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            return Err(VerifyError::MissingRecord);
+        }
+        Err(err) => return Err(VerifyError::Read(record_path.display().to_string(), err)),
+    };
+    let entries =
+        read_record_file(&mut record).map_err(|err| VerifyError::InvalidRecord(Box::new(err)))?;
+
+    Ok(entries
+        .into_iter()
+        .map(|entry| RecordedFile {
+            path: normalize_path(&root.join(&entry.path)),
+            sha256: entry
+                .hash
+                .as_deref()
+                .and_then(sha256_digest)
+                .map(ToString::to_string),
+            size: entry.size,
+        })
+        .collect())
+}
+
+/// Verify the files of an installed distribution against its `RECORD` file, as read by
+/// [`read_installed_record`].
+///
+/// Files that were modified or removed since installation are reported, along with any extra
+/// files in the top-level directories that the distribution installed into `site_packages`.
+/// Since directories may be shared between distributions (e.g., namespace packages), `recorded`
+/// must contain the files recorded by _all_ distributions in the environment; only files outside
+/// that set are reported as extra. Bytecode (i.e., `__pycache__` directories) is ignored, as it's
+/// typically compiled after installation.
+pub fn verify_installed(
+    site_packages: &Path,
+    files: &[RecordedFile],
+    recorded: &FxHashSet<PathBuf>,
+) -> Result<Vec<RecordMismatch>, VerifyError> {
+    let mut mismatches = Vec::new();
+
+    for file in files {
+        let metadata = match fs_err::metadata(&file.path) {
+            Ok(metadata) => metadata,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                mismatches.push(RecordMismatch::Missing(file.path.clone()));
+                continue;
+            }
+            Err(err) => return Err(VerifyError::Read(file.path.display().to_string(), err)),
+        };
+
+        // Compare the size first, to avoid hashing files that have obviously changed.
+        if file.size.is_some_and(|size| size != metadata.len()) {
+            mismatches.push(RecordMismatch::Modified(file.path.clone()));
+            continue;
+        }
+
+        if let Some(expected) = &file.sha256 {
+            let mut reader = fs_err::File::open(&file.path)
+                .map_err(|err| VerifyError::Read(file.path.display().to_string(), err))?;
+            let actual = hash_reader(&mut reader)
+                .map_err(|err| VerifyError::Read(file.path.display().to_string(), err))?;
+            if actual != *expected {
+                mismatches.push(RecordMismatch::Modified(file.path.clone()));
+            }
+        }
+    }
+
+    // Determine the top-level directories that the distribution installed into `site-packages`,
+    // excluding the metadata directories themselves.
+    let directories = files
+        .iter()
+        .filter_map(|file| {
+            let relative = file.path.strip_prefix(site_packages).ok()?;
+            let mut components = relative.components();
+            let Some(Component::Normal(directory)) = components.next() else {
+                return None;
+            };
+            // Skip files at the top-level of `site-packages`.
+            components.next()?;
+            let name = directory.to_str()?;
+            if name.ends_with(".dist-info") || name.ends_with(".data") || name == "__pycache__" {
+                return None;
+            }
+            Some(site_packages.join(directory))
+        })
+        .collect::<FxHashSet<_>>();
+
+    for directory in directories {
+        let walker = WalkDir::new(&directory)
+            .into_iter()
+            .filter_entry(|entry| entry.file_name() != "__pycache__");
+        for entry in walker {
+            let entry = match entry {
+                Ok(entry) => entry,
+                // The directory may have been removed entirely, in which case its files are
+                // already reported as missing.
+                Err(err)
+                    if err
+                        .io_error()
+                        .is_some_and(|err| err.kind() == io::ErrorKind::NotFound) =>
+                {
+                    continue;
+                }
+                Err(err) => {
+                    return Err(VerifyError::Read(
+                        directory.display().to_string(),
+                        io::Error::from(err),
+                    ));
+                }
+            };
+            if entry.file_type().is_dir() {
+                continue;
+            }
+            if !recorded.contains(entry.path()) {
+                mismatches.push(RecordMismatch::Extra(entry.path().to_path_buf()));
+            }
+        }
+    }
+
+    mismatches.sort();
+    Ok(mismatches)
+}
+
+/// Find the `RECORD` file in an unzipped wheel.
+fn find_unzipped_record(root: &Path) -> Option<PathBuf> {
+    fs_err::read_dir(root)
+        .ok()?
+        .filter_map(Result::ok)
+        .filter(|entry| {
+            entry
+                .file_name()
+                .to_str()
+                .is_some_and(|name| name.ends_with(".dist-info"))
+        })
+        .map(|entry| entry.path().join("RECORD"))
+        .find(|path| path.is_file())
+}
+
+/// Extract the SHA-256 digest from a `RECORD` hash, e.g., `sha256=...`.
+///
+/// Hashes computed with other algorithms are not verified.
+fn sha256_digest(hash: &str) -> Option<&str> {
+    hash.strip_prefix("sha256=")
+}
+
+/// Compute the URL-safe, unpadded base64-encoded SHA-256 of a reader, as in `RECORD` files.
+fn hash_reader(reader: &mut impl Read) -> io::Result<String> {
+    let mut hasher = Sha256::new();
+    io::copy(reader, &mut hasher)?;
+    Ok(BASE64URL_NOPAD.encode(&hasher.finalize()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use assert_fs::prelude::*;
+    use zip::write::FileOptions;
+
+    use rustc_hash::FxHashSet;
+
+    use super::{
+        read_installed_record, verify_installed, verify_unzipped_wheel, verify_wheel_archive,
+        RecordMismatch, VerifyError,
+    };
+
+    const RECORD: &str = "foo/__init__.py,sha256=a4ayc_80_OGda4BO_1o_V0etpOqiLx1JwB5S3beHW0s,1\nfoo-1.0.dist-info/RECORD,,\n";
+
+    #[test]
+    fn unzipped() {
+        let root = assert_fs::TempDir::new().unwrap();
+        root.child("foo/__init__.py").write_str("1").unwrap();
+        root.child("foo-1.0.dist-info/RECORD")
+            .write_str(RECORD)
+            .unwrap();
+        assert!(verify_unzipped_wheel(root.path()).is_ok());
+
+        root.child("foo/__init__.py").write_str("2").unwrap();
+        assert!(matches!(
+            verify_unzipped_wheel(root.path()),
+            Err(VerifyError::HashMismatch { .. })
+        ));
+
+        std::fs::remove_file(root.child("foo/__init__.py").path()).unwrap();
+        assert!(matches!(
+            verify_unzipped_wheel(root.path()),
+            Err(VerifyError::MissingFile(_))
+        ));
+    }
+
+    #[test]
+    fn archive() {
+        let root = assert_fs::TempDir::new().unwrap();
+        let write = |contents: &str| {
+            let path = root.child("foo-1.0-py3-none-any.whl");
+            let mut writer = zip::ZipWriter::new(fs_err::File::create(path.path()).unwrap());
+            writer
+                .start_file("foo/__init__.py", FileOptions::default())
+                .unwrap();
+            writer.write_all(contents.as_bytes()).unwrap();
+            writer
+                .start_file("foo-1.0.dist-info/RECORD", FileOptions::default())
+                .unwrap();
+            writer.write_all(RECORD.as_bytes()).unwrap();
+            writer.finish().unwrap();
+            path
+        };
+
+        let wheel = write("1");
+        assert!(verify_wheel_archive(wheel.path()).is_ok());
+
+        let wheel = write("2");
+        assert!(matches!(
+            verify_wheel_archive(wheel.path()),
+            Err(VerifyError::HashMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn installed() {
+        let site_packages = assert_fs::TempDir::new().unwrap();
+        site_packages
+            .child("foo/__init__.py")
+            .write_str("1")
+            .unwrap();
+        site_packages
+            .child("foo-1.0.dist-info/RECORD")
+            .write_str(RECORD)
+            .unwrap();
+        site_packages
+            .child("foo/__pycache__/__init__.cpython-312.pyc")
+            .write_str("")
+            .unwrap();
+
+        let dist_info = site_packages.child("foo-1.0.dist-info");
+        let files = read_installed_record(dist_info.path()).unwrap();
+        let recorded = files
+            .iter()
+            .map(|file| file.path.clone())
+            .collect::<FxHashSet<_>>();
+        let verify = || verify_installed(site_packages.path(), &files, &recorded).unwrap();
+        assert_eq!(verify(), vec![]);
+
+        site_packages.child("foo/extra.py").write_str("").unwrap();
+        site_packages
+            .child("foo/__init__.py")
+            .write_str("2")
+            .unwrap();
+        assert_eq!(
+            verify(),
+            vec![
+                RecordMismatch::Modified(site_packages.child("foo/__init__.py").to_path_buf()),
+                RecordMismatch::Extra(site_packages.child("foo/extra.py").to_path_buf()),
+            ]
+        );
+
+        std::fs::remove_dir_all(site_packages.child("foo").path()).unwrap();
+        assert_eq!(
+            verify(),
+            vec![RecordMismatch::Missing(
+                site_packages.child("foo/__init__.py").to_path_buf()
+            )]
+        );
+    }
+}
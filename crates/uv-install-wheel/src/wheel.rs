@@ -16,7 +16,7 @@ use uv_cache_info::CacheInfo;
 use uv_fs::{relative_to, Simplified};
 use uv_normalize::PackageName;
 use uv_pypi_types::DirectUrl;
-use uv_trampoline_builder::windows_script_launcher;
+use uv_trampoline_builder::{windows_script_launcher, LauncherResources};
 
 use crate::record::RecordEntry;
 use crate::script::Script;
@@ -178,6 +178,7 @@ pub(crate) fn write_script_entrypoints(
     entrypoints: &[Script],
     record: &mut Vec<RecordEntry>,
     is_gui: bool,
+    launcher_resources: Option<&LauncherResources>,
 ) -> Result<(), Error> {
     for entrypoint in entrypoints {
         let entrypoint_absolute = entrypoint_path(entrypoint, layout);
@@ -207,7 +208,12 @@ pub(crate) fn write_script_entrypoints(
             write_file_recorded(
                 site_packages,
                 &entrypoint_relative,
-                &windows_script_launcher(&launcher_python_script, is_gui, &launcher_executable)?,
+                &windows_script_launcher(
+                    &launcher_python_script,
+                    is_gui,
+                    &launcher_executable,
+                    launcher_resources,
+                )?,
                 record,
             )?;
         } else {
@@ -700,6 +706,19 @@ pub fn read_record_file(record: &mut impl Read) -> Result<Vec<RecordEntry>, Erro
         .collect()
 }
 
+/// Write a `RECORD` file, sorting the entries by path.
+pub fn write_record_file(path: &Path, mut record: Vec<RecordEntry>) -> Result<(), Error> {
+    let mut record_writer = csv::WriterBuilder::new()
+        .has_headers(false)
+        .escape(b'"')
+        .from_path(path)?;
+    record.sort();
+    for entry in record {
+        record_writer.serialize(entry)?;
+    }
+    Ok(())
+}
+
 /// Parse a file with email message format such as WHEEL and METADATA
 fn parse_email_message_file(
     file: impl Read,
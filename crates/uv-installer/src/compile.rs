@@ -6,6 +6,7 @@ use std::time::Duration;
 use std::{io, panic};
 
 use async_channel::{Receiver, SendError};
+use rustc_hash::FxHashSet;
 use tempfile::tempdir_in;
 use thiserror::Error;
 use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
@@ -14,6 +15,7 @@ use tokio::sync::oneshot;
 use tracing::{debug, instrument};
 use walkdir::WalkDir;
 
+use uv_configuration::BytecodeOptimization;
 use uv_fs::Simplified;
 use uv_static::EnvVars;
 use uv_warnings::warn_user;
@@ -64,19 +66,27 @@ pub enum CompileError {
 /// > Uninstallers should be smart enough to remove .pyc even if it is not mentioned in RECORD.
 ///
 /// We've confirmed that both uv and pip (as of 24.0.0) remove the `__pycache__` directory.
-#[instrument(skip(python_executable))]
+///
+/// Files in `exclude` are skipped. If `workers` is not provided, one worker is started per
+/// available core.
+#[instrument(skip(python_executable, exclude))]
 pub async fn compile_tree(
     dir: &Path,
     python_executable: &Path,
     cache: &Path,
+    optimization: BytecodeOptimization,
+    workers: Option<NonZeroUsize>,
+    exclude: &FxHashSet<PathBuf>,
 ) -> Result<usize, CompileError> {
     debug_assert!(
         dir.is_absolute(),
         "compileall doesn't work with relative paths"
     );
-    let worker_count = std::thread::available_parallelism().unwrap_or_else(|err| {
-        warn_user!("Couldn't determine number of cores, compiling with a single thread: {err}");
-        NonZeroUsize::MIN
+    let worker_count = workers.unwrap_or_else(|| {
+        std::thread::available_parallelism().unwrap_or_else(|err| {
+            warn_user!("Couldn't determine number of cores, compiling with a single thread: {err}");
+            NonZeroUsize::MIN
+        })
     });
 
     // A larger buffer is significantly faster than just 1 or the worker count.
@@ -95,6 +105,7 @@ pub async fn compile_tree(
             dir.to_path_buf(),
             python_executable.to_path_buf(),
             pip_compileall_py.clone(),
+            optimization,
             receiver.clone(),
         );
 
@@ -132,6 +143,9 @@ pub async fn compile_tree(
         let entry = entry?;
         // https://github.com/pypa/pip/blob/3820b0e52c7fed2b2c43ba731b718f316e6816d1/src/pip/_internal/operations/install/wheel.py#L593-L604
         if entry.metadata()?.is_file() && entry.path().extension().is_some_and(|ext| ext == "py") {
+            if exclude.contains(entry.path()) {
+                continue;
+            }
             source_files += 1;
             if let Err(err) = sender.send(entry.path().to_owned()).await {
                 // The workers exited.
@@ -172,6 +186,7 @@ async fn worker(
     dir: PathBuf,
     interpreter: PathBuf,
     pip_compileall_py: PathBuf,
+    optimization: BytecodeOptimization,
     receiver: Receiver<PathBuf>,
 ) -> Result<(), CompileError> {
     fs_err::tokio::write(&pip_compileall_py, COMPILEALL_SCRIPT)
@@ -186,7 +201,8 @@ async fn worker(
         loop {
             // If the interpreter started successful, return it, else retry.
             if let Some(child) =
-                launch_bytecode_compiler(&dir, &interpreter, &pip_compileall_py).await?
+                launch_bytecode_compiler(&dir, &interpreter, &pip_compileall_py, optimization)
+                    .await?
             {
                 break Ok::<_, CompileError>(child);
             }
@@ -250,6 +266,7 @@ async fn launch_bytecode_compiler(
     dir: &Path,
     interpreter: &Path,
     pip_compileall_py: &Path,
+    optimization: BytecodeOptimization,
 ) -> Result<
     Option<(
         Child,
@@ -260,8 +277,13 @@ async fn launch_bytecode_compiler(
     CompileError,
 > {
     // We input the paths through stdin and get the successful paths returned through stdout.
-    let mut bytecode_compiler = Command::new(interpreter)
-        .arg(pip_compileall_py)
+    let mut command = Command::new(interpreter);
+    command.arg(pip_compileall_py);
+    // Unless requested, defer to the interpreter's optimization level (as in `compileall`).
+    if optimization != BytecodeOptimization::Unoptimized {
+        command.arg(optimization.level().to_string());
+    }
+    let mut bytecode_compiler = command
         .stdin(Stdio::piped())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
@@ -3,10 +3,11 @@ use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use std::convert;
 use tokio::sync::oneshot;
 use tracing::instrument;
-use uv_install_wheel::{linker::LinkMode, Layout};
+use uv_install_wheel::{linker::LinkMode, LauncherResources, Layout};
 
 use uv_cache::Cache;
 use uv_distribution_types::CachedDist;
+use uv_pypi_types::DirectUrl;
 use uv_python::PythonEnvironment;
 
 pub struct Installer<'a> {
@@ -15,6 +16,7 @@ pub struct Installer<'a> {
     cache: Option<&'a Cache>,
     reporter: Option<Box<dyn Reporter>>,
     installer_name: Option<String>,
+    launcher_resources: Option<&'a LauncherResources>,
 }
 
 impl<'a> Installer<'a> {
@@ -26,6 +28,7 @@ impl<'a> Installer<'a> {
             cache: None,
             reporter: None,
             installer_name: Some("uv".to_string()),
+            launcher_resources: None,
         }
     }
 
@@ -62,6 +65,18 @@ impl<'a> Installer<'a> {
         }
     }
 
+    /// Set the [`LauncherResources`] to embed into Windows launchers for entrypoints.
+    #[must_use]
+    pub fn with_launcher_resources(
+        self,
+        launcher_resources: Option<&'a LauncherResources>,
+    ) -> Self {
+        Self {
+            launcher_resources,
+            ..self
+        }
+    }
+
     /// Install a set of wheels into a Python virtual environment.
     #[instrument(skip_all, fields(num_wheels = %wheels.len()))]
     pub async fn install(self, wheels: Vec<CachedDist>) -> Result<Vec<CachedDist>> {
@@ -71,6 +86,7 @@ impl<'a> Installer<'a> {
             link_mode,
             reporter,
             installer_name,
+            launcher_resources,
         } = self;
 
         if cache.is_some_and(Cache::is_temporary) {
@@ -85,11 +101,13 @@ impl<'a> Installer<'a> {
 
         let layout = venv.interpreter().layout();
         let relocatable = venv.relocatable();
+        let launcher_resources = launcher_resources.cloned();
         rayon::spawn(move || {
             let result = install(
                 wheels,
                 layout,
                 installer_name,
+                launcher_resources.as_ref(),
                 link_mode,
                 reporter,
                 relocatable,
@@ -119,6 +137,7 @@ impl<'a> Installer<'a> {
             wheels,
             self.venv.interpreter().layout(),
             self.installer_name,
+            self.launcher_resources,
             self.link_mode,
             self.reporter,
             self.venv.relocatable(),
@@ -132,6 +151,7 @@ fn install(
     wheels: Vec<CachedDist>,
     layout: Layout,
     installer_name: Option<String>,
+    launcher_resources: Option<&LauncherResources>,
     link_mode: LinkMode,
     reporter: Option<Box<dyn Reporter>>,
     relocatable: bool,
@@ -143,18 +163,14 @@ fn install(
             relocatable,
             wheel.path(),
             wheel.filename(),
-            wheel
-                .parsed_url()?
-                .as_ref()
-                .map(uv_pypi_types::DirectUrl::try_from)
-                .transpose()?
-                .as_ref(),
+            direct_url(wheel)?.as_ref(),
             if wheel.cache_info().is_empty() {
                 None
             } else {
                 Some(wheel.cache_info())
             },
             installer_name.as_deref(),
+            launcher_resources,
             link_mode,
             &locks,
         )
@@ -170,6 +186,36 @@ fn install(
     Ok(wheels)
 }
 
+/// Return the PEP 610 `direct_url.json` contents for a wheel, if any.
+///
+/// For archives, the hashes of the archive are recorded in `archive_info`, such that subsequent
+/// installs can detect when the installed distribution no longer matches the required hashes.
+fn direct_url(wheel: &CachedDist) -> Result<Option<DirectUrl>> {
+    let Some(parsed_url) = wheel.parsed_url()? else {
+        return Ok(None);
+    };
+    let mut direct_url = DirectUrl::try_from(&parsed_url)?;
+    if let (
+        CachedDist::Url(wheel),
+        DirectUrl::ArchiveUrl {
+            ref mut archive_info,
+            ..
+        },
+    ) = (wheel, &mut direct_url)
+    {
+        if !wheel.hashes.is_empty() {
+            archive_info.hashes = Some(
+                wheel
+                    .hashes
+                    .iter()
+                    .map(|digest| (digest.algorithm.to_string(), digest.digest.to_string()))
+                    .collect(),
+            );
+        }
+    }
+    Ok(Some(direct_url))
+}
+
 pub trait Reporter: Send + Sync {
     /// Callback to invoke when a dependency is installed.
     fn on_install_progress(&self, wheel: &CachedDist);
@@ -1,6 +1,6 @@
 pub use compile::{compile_tree, CompileError};
 pub use installer::{Installer, Reporter as InstallReporter};
-pub use plan::{Plan, Planner};
+pub use plan::{Plan, Planner, ReinstallReason};
 pub use preparer::{Preparer, Reporter as PrepareReporter};
 pub use site_packages::{SatisfiesResult, SitePackages, SitePackagesDiagnostic};
 pub use uninstall::{uninstall, UninstallError};
@@ -42,6 +42,10 @@ with warnings.catch_warnings():
         except AttributeError:
             invalidation_mode = None  # guard against implementation details
 
+    # The optimization level, if provided; otherwise, use the interpreter's level, like
+    # `compileall` (i.e., `-O` or `-OO`).
+    optimize = int(sys.argv[1]) if len(sys.argv) > 1 else -1
+
     # Unlike pip, we will usually set force=False. It's unclear why pip sets force=True, but it
     # doesn't matter much for them, as pip only compiles newly installed files.
     force = False
@@ -61,7 +65,11 @@ with warnings.catch_warnings():
         # We'd like to show those errors, but given that pip thinks that's totally fine,
         # we can't really change that.
         success = compileall.compile_file(
-            path, invalidation_mode=invalidation_mode, force=force, quiet=2
+            path,
+            invalidation_mode=invalidation_mode,
+            force=force,
+            quiet=2,
+            optimize=optimize,
         )
         # We're ready for the next file.
         print(path)
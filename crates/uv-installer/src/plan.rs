@@ -1,4 +1,5 @@
 use anyhow::{bail, Result};
+use rustc_hash::FxHashMap;
 use tracing::debug;
 
 use uv_cache::{Cache, CacheBucket, WheelCache};
@@ -8,12 +9,13 @@ use uv_distribution::{
     BuiltWheelIndex, HttpArchivePointer, LocalArchivePointer, RegistryWheelIndex,
 };
 use uv_distribution_types::{
-    BuiltDist, CachedDirectUrlDist, CachedDist, Dist, Error, Hashed, IndexLocations, InstalledDist,
-    Name, Resolution, ResolvedDist, SourceDist,
+    BuiltDist, CachedDirectUrlDist, CachedDist, Dist, Error, HashPolicy, Hashed, IndexLocations,
+    InstalledDist, Name, Resolution, ResolvedDist, SourceDist,
 };
 use uv_fs::Simplified;
+use uv_normalize::PackageName;
 use uv_platform_tags::Tags;
-use uv_pypi_types::Requirement;
+use uv_pypi_types::{DirectUrl, Requirement, RequirementSource};
 use uv_python::PythonEnvironment;
 use uv_types::HashStrategy;
 
@@ -41,8 +43,9 @@ impl<'a> Planner<'a> {
     /// read those entries from the cache after revalidating them.
     ///
     /// The install plan will also respect the required hashes, such that it will never return a
-    /// cached distribution that does not match the required hash. Like pip, though, it _will_
-    /// return an _installed_ distribution that does not match the required hash.
+    /// cached distribution that does not match the required hash. Installed distributions are only
+    /// reinstalled if they record archive hashes (as for URL archives) that conflict with the
+    /// required hashes; like pip, installations from a registry are not re-verified.
     pub fn build(
         self,
         mut site_packages: SitePackages,
@@ -62,6 +65,7 @@ impl<'a> Planner<'a> {
         let mut cached = vec![];
         let mut remote = vec![];
         let mut reinstalls = vec![];
+        let mut reinstall_reasons = FxHashMap::default();
         let mut extraneous = vec![];
 
         for dist in self.resolution.distributions() {
@@ -81,14 +85,34 @@ impl<'a> Planner<'a> {
             // Determine whether the distribution is already installed.
             let installed_dists = site_packages.remove_packages(&requirement.name);
             if reinstall {
+                if !installed_dists.is_empty() {
+                    reinstall_reasons.insert(requirement.name.clone(), ReinstallReason::Requested);
+                }
                 reinstalls.extend(installed_dists);
             } else {
                 match installed_dists.as_slice() {
                     [] => {}
                     [distribution] => {
-                        match RequirementSatisfaction::check(distribution, &requirement.source)? {
+                        let reason = match RequirementSatisfaction::check(
+                            distribution,
+                            &requirement.source,
+                        )? {
                             RequirementSatisfaction::Mismatch => {
                                 debug!("Requirement installed, but mismatched: {distribution:?}");
+                                if matches!(requirement.source, RequirementSource::Registry { .. })
+                                {
+                                    ReinstallReason::VersionMismatch
+                                } else {
+                                    ReinstallReason::SourceMismatch
+                                }
+                            }
+                            RequirementSatisfaction::Satisfied
+                                if hashes_mismatch(distribution, hasher.get(dist)) =>
+                            {
+                                debug!(
+                                    "Requirement installed, but does not match the required hashes: {distribution}"
+                                );
+                                ReinstallReason::HashMismatch
                             }
                             RequirementSatisfaction::Satisfied => {
                                 debug!("Requirement already installed: {distribution}");
@@ -96,14 +120,20 @@ impl<'a> Planner<'a> {
                             }
                             RequirementSatisfaction::OutOfDate => {
                                 debug!("Requirement installed, but not fresh: {distribution}");
+                                ReinstallReason::OutOfDate
                             }
-                        }
+                        };
+                        reinstall_reasons.insert(requirement.name.clone(), reason);
                         reinstalls.push(distribution.clone());
                     }
                     // We reinstall installed distributions with multiple versions because
                     // we do not want to keep multiple incompatible versions but removing
                     // one version is likely to break another.
-                    _ => reinstalls.extend(installed_dists),
+                    _ => {
+                        reinstall_reasons
+                            .insert(requirement.name.clone(), ReinstallReason::MultipleVersions);
+                        reinstalls.extend(installed_dists);
+                    }
                 }
             }
 
@@ -273,6 +303,16 @@ impl<'a> Planner<'a> {
                         continue;
                     }
                 }
+                Dist::Source(SourceDist::Hg(sdist)) => {
+                    // Find the most-compatible wheel from the cache, since we don't know
+                    // the filename in advance.
+                    if let Some(wheel) = built_index.hg(sdist) {
+                        let cached_dist = wheel.into_url_dist(sdist.url.clone());
+                        debug!("Mercurial source requirement already cached: {cached_dist}");
+                        cached.push(CachedDist::Url(cached_dist));
+                        continue;
+                    }
+                }
                 Dist::Source(SourceDist::Path(sdist)) => {
                     // Validate that the path exists.
                     if !sdist.install_path.exists() {
@@ -338,6 +378,7 @@ impl<'a> Planner<'a> {
             cached,
             remote,
             reinstalls,
+            reinstall_reasons,
             extraneous,
         })
     }
@@ -357,7 +398,77 @@ pub struct Plan {
     /// re-installed (including upgraded) to satisfy the requirements.
     pub reinstalls: Vec<InstalledDist>,
 
+    /// The reason for which each package in [`Plan::reinstalls`] will be re-installed.
+    pub reinstall_reasons: FxHashMap<PackageName, ReinstallReason>,
+
     /// Any distributions that are already installed in the current environment, and are
     /// _not_ necessary to satisfy the requirements.
     pub extraneous: Vec<InstalledDist>,
 }
+
+/// The reason for which an installed distribution will be re-installed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ReinstallReason {
+    /// A reinstall was requested by the user (e.g., via `--reinstall`).
+    Requested,
+    /// The installed version does not satisfy the requirement.
+    VersionMismatch,
+    /// The installed distribution was installed from a different source (e.g., a different URL
+    /// or path).
+    SourceMismatch,
+    /// The installed distribution was built from a local source that has since changed.
+    OutOfDate,
+    /// Multiple versions of the package are installed.
+    MultipleVersions,
+    /// The installed distribution was installed from an archive whose recorded hashes do not
+    /// match the required hashes.
+    HashMismatch,
+}
+
+impl std::fmt::Display for ReinstallReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Requested => write!(f, "reinstall requested"),
+            Self::VersionMismatch => write!(f, "version mismatch"),
+            Self::SourceMismatch => write!(f, "source mismatch"),
+            Self::OutOfDate => write!(f, "out of date"),
+            Self::MultipleVersions => write!(f, "multiple versions installed"),
+            Self::HashMismatch => write!(f, "hash mismatch"),
+        }
+    }
+}
+
+/// Returns `true` if the installed distribution records archive hashes (in `direct_url.json`)
+/// that conflict with the required hashes.
+///
+/// Distributions installed from a registry, and archives installed without recorded hashes, are
+/// never considered mismatched, as there is nothing to compare against.
+fn hashes_mismatch(distribution: &InstalledDist, policy: HashPolicy) -> bool {
+    let required = policy.digests();
+    if required.is_empty() {
+        return false;
+    }
+    let InstalledDist::Url(distribution) = distribution else {
+        return false;
+    };
+    let DirectUrl::ArchiveUrl { archive_info, .. } = distribution.direct_url.as_ref() else {
+        return false;
+    };
+    let Some(recorded) = archive_info.hashes.as_ref() else {
+        return false;
+    };
+
+    // Only compare digests for algorithms that were recorded at install time.
+    let mut comparable = required
+        .iter()
+        .filter_map(|digest| {
+            recorded
+                .get(&digest.algorithm.to_string())
+                .map(|recorded| (digest, recorded))
+        })
+        .peekable();
+    if comparable.peek().is_none() {
+        return false;
+    }
+    !comparable.any(|(digest, recorded)| *digest.digest == **recorded)
+}
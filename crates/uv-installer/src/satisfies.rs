@@ -149,6 +149,61 @@ impl RequirementSatisfaction {
 
                 Ok(Self::Satisfied)
             }
+            RequirementSource::Hg {
+                url: _,
+                repository: requested_repository,
+                reference: requested_reference,
+                precise: requested_precise,
+                subdirectory: requested_subdirectory,
+            } => {
+                let InstalledDist::Url(InstalledDirectUrlDist { direct_url, .. }) = &distribution
+                else {
+                    return Ok(Self::Mismatch);
+                };
+                let DirectUrl::VcsUrl {
+                    url: installed_url,
+                    vcs_info:
+                        VcsInfo {
+                            vcs: VcsKind::Hg,
+                            requested_revision: installed_reference,
+                            commit_id: _,
+                        },
+                    subdirectory: installed_subdirectory,
+                } = direct_url.as_ref()
+                else {
+                    return Ok(Self::Mismatch);
+                };
+
+                if requested_subdirectory != installed_subdirectory {
+                    debug!(
+                        "Subdirectory mismatch: {:?} vs. {:?}",
+                        installed_subdirectory, requested_subdirectory
+                    );
+                    return Ok(Self::Mismatch);
+                }
+
+                if !RepositoryUrl::parse(installed_url).is_ok_and(|installed_url| {
+                    installed_url == RepositoryUrl::new(requested_repository)
+                }) {
+                    debug!(
+                        "Repository mismatch: {:?} vs. {:?}",
+                        installed_url, requested_repository
+                    );
+                    return Ok(Self::Mismatch);
+                }
+
+                if installed_reference.as_deref() != requested_reference.as_str()
+                    && installed_reference != &requested_precise.map(|node| node.to_string())
+                {
+                    debug!(
+                        "Reference mismatch: {:?} vs. {:?} and {:?}",
+                        installed_reference, requested_reference, requested_precise
+                    );
+                    return Ok(Self::OutOfDate);
+                }
+
+                Ok(Self::Satisfied)
+            }
             RequirementSource::Path {
                 install_path: requested_path,
                 ext: _,
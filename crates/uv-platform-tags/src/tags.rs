@@ -270,6 +270,25 @@ impl Tags {
         }
         max_compatibility
     }
+
+    /// Returns the highest-priority tag, e.g., `cp312-cp312-manylinux_2_17_x86_64`, which
+    /// identifies the interpreter and platform for which the tags were generated.
+    pub fn highest_priority(&self) -> Option<(&str, &str, &str)> {
+        self.map
+            .iter()
+            .flat_map(|(python_tag, abi_tags)| {
+                abi_tags.iter().flat_map(move |(abi_tag, platform_tags)| {
+                    platform_tags.iter().map(move |(platform_tag, priority)| {
+                        (
+                            *priority,
+                            (python_tag.as_str(), abi_tag.as_str(), platform_tag.as_str()),
+                        )
+                    })
+                })
+            })
+            .max_by_key(|(priority, _)| *priority)
+            .map(|(_, tag)| tag)
+    }
 }
 
 /// The priority of a platform tag.
@@ -360,11 +379,9 @@ impl Implementation {
                 implementation_version.0,
                 implementation_version.1
             ),
-            // Ex) `graalpy310_graalpy240_310_native
+            // Ex) `graalpy240_310_native`
             Self::GraalPy => format!(
-                "graalpy{}{}_graalpy{}{}_{}{}_native",
-                python_version.0,
-                python_version.1,
+                "graalpy{}{}_{}{}_native",
                 implementation_version.0,
                 implementation_version.1,
                 python_version.0,
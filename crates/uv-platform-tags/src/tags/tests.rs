@@ -1528,3 +1528,27 @@ fn test_system_tags_macos() {
     "###
     );
 }
+
+/// Check that GraalPy tags combine the GraalPy and Python versions in the ABI tag.
+#[test]
+fn test_system_tags_graalpy() {
+    let tags = Tags::from_env(
+        &Platform::new(
+            Os::Manylinux {
+                major: 2,
+                minor: 28,
+            },
+            Arch::X86_64,
+        ),
+        (3, 11),
+        "graalpy",
+        (24, 1),
+        true,
+        false,
+    )
+    .unwrap();
+    assert_eq!(
+        tags.to_string().lines().next(),
+        Some("graalpy311-graalpy241_311_native-manylinux_2_28_x86_64")
+    );
+}
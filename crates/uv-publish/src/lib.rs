@@ -11,11 +11,13 @@ use reqwest::header::AUTHORIZATION;
 use reqwest::multipart::Part;
 use reqwest::{Body, Response, StatusCode};
 use reqwest_middleware::RequestBuilder;
-use reqwest_retry::{Retryable, RetryableStrategy};
+use reqwest_retry::policies::ExponentialBackoff;
+use reqwest_retry::{RetryDecision, RetryPolicy, Retryable, RetryableStrategy};
 use rustc_hash::FxHashSet;
 use serde::Deserialize;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::SystemTime;
 use std::{env, fmt, io};
 use thiserror::Error;
 use tokio::io::{AsyncReadExt, BufReader};
@@ -29,7 +31,7 @@ use uv_fs::{ProgressReader, Simplified};
 use uv_metadata::read_metadata_async_seek;
 use uv_pypi_types::{HashAlgorithm, HashDigest, Metadata23, MetadataError};
 use uv_static::EnvVars;
-use uv_warnings::{warn_user, warn_user_once};
+use uv_warnings::warn_user_once;
 
 pub use trusted_publishing::TrustedPublishingToken;
 use uv_cache::{Cache, Refresh};
@@ -351,7 +353,8 @@ pub async fn upload(
     filename: &DistFilename,
     registry: &Url,
     client: &BaseClient,
-    retries: u32,
+    retry_policy: &ExponentialBackoff,
+    retry_strategy: &UvRetryableStrategy,
     username: Option<&str>,
     password: Option<&str>,
     check_url_client: Option<&CheckUrlClient<'_>>,
@@ -362,9 +365,9 @@ pub async fn upload(
         .map_err(|err| PublishError::PublishPrepare(file.to_path_buf(), Box::new(err)))?;
 
     // Retry loop
-    let mut attempt = 0;
+    let start_time = SystemTime::now();
+    let mut n_past_retries = 0;
     loop {
-        attempt += 1;
         let (request, idx) = build_request(
             file,
             raw_filename,
@@ -380,10 +383,19 @@ pub async fn upload(
         .map_err(|err| PublishError::PublishPrepare(file.to_path_buf(), Box::new(err)))?;
 
         let result = request.send().await;
-        if attempt < retries && UvRetryableStrategy.handle(&result) == Some(Retryable::Transient) {
-            reporter.on_download_complete(idx);
-            warn_user!("Transient request failure for {}, retrying", registry);
-            continue;
+        if retry_strategy.handle(&result) == Some(Retryable::Transient) {
+            if let RetryDecision::Retry { execute_after } =
+                retry_policy.should_retry(start_time, n_past_retries)
+            {
+                reporter.on_download_complete(idx);
+                let duration = execute_after
+                    .duration_since(SystemTime::now())
+                    .unwrap_or_default();
+                debug!("Retrying upload to {registry} in {duration:?}");
+                tokio::time::sleep(duration).await;
+                n_past_retries += 1;
+                continue;
+            }
         }
 
         let response = result.map_err(|err| {
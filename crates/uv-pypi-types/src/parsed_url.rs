@@ -4,6 +4,7 @@ use thiserror::Error;
 use url::{ParseError, Url};
 use uv_distribution_filename::{DistExtension, ExtensionError};
 use uv_git::{GitReference, GitSha, GitUrl, OidParseError};
+use uv_hg::{HgNode, HgReference, HgUrl};
 use uv_pep508::{Pep508Url, UnnamedRequirementUrl, VerbatimUrl, VerbatimUrlError};
 
 use crate::{ArchiveInfo, DirInfo, DirectUrl, VcsInfo, VcsKind};
@@ -149,6 +150,8 @@ impl Display for VerbatimParsedUrl {
 /// * The path to a file or directory (`file://`)
 /// * A Git repository (`git+https://` or `git+ssh://`), optionally with a subdirectory and/or
 ///   string to checkout.
+/// * A Mercurial repository (`hg+https://` or `hg+ssh://`), optionally with a subdirectory and/or
+///   revision to checkout.
 /// * A remote archive (`https://`), optional with a subdirectory (source dist only).
 ///
 /// A URL in a requirement `foo @ <url>` must be one of the above.
@@ -160,6 +163,8 @@ pub enum ParsedUrl {
     Directory(ParsedDirectoryUrl),
     /// The direct URL is path to a Git repository.
     Git(ParsedGitUrl),
+    /// The direct URL is path to a Mercurial repository.
+    Hg(ParsedHgUrl),
     /// The direct URL is a URL to a source archive (e.g., a `.tar.gz` file) or built archive
     /// (i.e., a `.whl` file).
     Archive(ParsedArchiveUrl),
@@ -274,6 +279,56 @@ impl TryFrom<Url> for ParsedGitUrl {
     }
 }
 
+/// A Mercurial repository URL.
+///
+/// Examples:
+/// * `hg+https://hg.example.com/MyProject`
+/// * `hg+https://hg.example.com/MyProject@1.0#egg=pkg&subdirectory=pkg_dir`
+#[derive(Debug, Clone, Eq, PartialEq, PartialOrd, Hash, Ord)]
+pub struct ParsedHgUrl {
+    pub url: HgUrl,
+    pub subdirectory: Option<PathBuf>,
+}
+
+impl ParsedHgUrl {
+    /// Construct a [`ParsedHgUrl`] from a Mercurial requirement source.
+    pub fn from_source(
+        repository: Url,
+        reference: HgReference,
+        precise: Option<HgNode>,
+        subdirectory: Option<PathBuf>,
+    ) -> Self {
+        let url = if let Some(precise) = precise {
+            HgUrl::from_node(repository, reference, precise)
+        } else {
+            HgUrl::from_reference(repository, reference)
+        };
+        Self { url, subdirectory }
+    }
+}
+
+impl TryFrom<Url> for ParsedHgUrl {
+    type Error = ParsedUrlError;
+
+    /// Supports URLs with and without the `hg+` prefix.
+    ///
+    /// When the URL includes a prefix, it's presumed to come from a PEP 508 requirement; when it's
+    /// excluded, it's presumed to come from `tool.uv.sources`.
+    fn try_from(url_in: Url) -> Result<Self, Self::Error> {
+        let subdirectory = get_subdirectory(&url_in);
+
+        let url = url_in
+            .as_str()
+            .strip_prefix("hg+")
+            .unwrap_or(url_in.as_str());
+        let url = Url::parse(url).map_err(|err| ParsedUrlError::UrlParse(url.to_string(), err))?;
+        Ok(Self {
+            url: HgUrl::from(url),
+            subdirectory,
+        })
+    }
+}
+
 /// A URL to a source or built archive.
 ///
 /// Examples:
@@ -339,11 +394,7 @@ impl TryFrom<Url> for ParsedUrl {
                     url: url.to_string(),
                     message: "Bazaar is not supported",
                 }),
-                "hg" => Err(ParsedUrlError::UnsupportedUrlPrefix {
-                    prefix: prefix.to_string(),
-                    url: url.to_string(),
-                    message: "Mercurial is not supported",
-                }),
+                "hg" => Ok(Self::Hg(ParsedHgUrl::try_from(url)?)),
                 "svn" => Err(ParsedUrlError::UnsupportedUrlPrefix {
                     prefix: prefix.to_string(),
                     url: url.to_string(),
@@ -398,6 +449,7 @@ impl TryFrom<&ParsedUrl> for DirectUrl {
             ParsedUrl::Path(value) => Self::try_from(value),
             ParsedUrl::Directory(value) => Self::try_from(value),
             ParsedUrl::Git(value) => Self::try_from(value),
+            ParsedUrl::Hg(value) => Self::try_from(value),
             ParsedUrl::Archive(value) => Self::try_from(value),
         }
     }
@@ -462,12 +514,29 @@ impl TryFrom<&ParsedGitUrl> for DirectUrl {
     }
 }
 
+impl TryFrom<&ParsedHgUrl> for DirectUrl {
+    type Error = ParsedUrlError;
+
+    fn try_from(value: &ParsedHgUrl) -> Result<Self, Self::Error> {
+        Ok(Self::VcsUrl {
+            url: value.url.repository().to_string(),
+            vcs_info: VcsInfo {
+                vcs: VcsKind::Hg,
+                commit_id: value.url.precise().as_ref().map(ToString::to_string),
+                requested_revision: value.url.reference().as_str().map(ToString::to_string),
+            },
+            subdirectory: value.subdirectory.clone(),
+        })
+    }
+}
+
 impl From<ParsedUrl> for Url {
     fn from(value: ParsedUrl) -> Self {
         match value {
             ParsedUrl::Path(value) => value.into(),
             ParsedUrl::Directory(value) => value.into(),
             ParsedUrl::Git(value) => value.into(),
+            ParsedUrl::Hg(value) => value.into(),
             ParsedUrl::Archive(value) => value.into(),
         }
     }
@@ -506,5 +575,16 @@ impl From<ParsedGitUrl> for Url {
     }
 }
 
+impl From<ParsedHgUrl> for Url {
+    fn from(value: ParsedHgUrl) -> Self {
+        let mut url = Self::parse(&format!("{}{}", "hg+", Self::from(value.url).as_str()))
+            .expect("Mercurial URL is invalid");
+        if let Some(subdirectory) = value.subdirectory {
+            url.set_fragment(Some(&format!("subdirectory={}", subdirectory.display())));
+        }
+        url
+    }
+}
+
 #[cfg(test)]
 mod tests;
@@ -31,6 +31,19 @@ fn direct_url_from_url() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn direct_url_from_hg_url() -> Result<()> {
+    let expected = Url::parse("hg+https://hg.example.com/project")?;
+    let actual = Url::from(ParsedUrl::try_from(expected.clone())?);
+    assert_eq!(expected, actual);
+
+    let expected = Url::parse("hg+https://hg.example.com/project@stable#subdirectory=pkg_dir")?;
+    let actual = Url::from(ParsedUrl::try_from(expected.clone())?);
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
+
 #[test]
 #[cfg(unix)]
 fn direct_url_from_url_absolute() -> Result<()> {
@@ -9,6 +9,7 @@ use uv_distribution_filename::DistExtension;
 
 use uv_fs::{relative_to, PortablePathBuf, CWD};
 use uv_git::{GitReference, GitSha, GitUrl};
+use uv_hg::{HgNode, HgReference, HgUrl};
 use uv_normalize::{ExtraName, PackageName};
 use uv_pep440::VersionSpecifiers;
 use uv_pep508::{
@@ -16,8 +17,8 @@ use uv_pep508::{
 };
 
 use crate::{
-    Hashes, ParsedArchiveUrl, ParsedDirectoryUrl, ParsedGitUrl, ParsedPathUrl, ParsedUrl,
-    ParsedUrlError, VerbatimParsedUrl,
+    Hashes, ParsedArchiveUrl, ParsedDirectoryUrl, ParsedGitUrl, ParsedHgUrl, ParsedPathUrl,
+    ParsedUrl, ParsedUrlError, VerbatimParsedUrl,
 };
 
 #[derive(Debug, Error)]
@@ -30,6 +31,8 @@ pub enum RequirementError {
     UrlParseError(#[from] url::ParseError),
     #[error(transparent)]
     OidParseError(#[from] uv_git::OidParseError),
+    #[error(transparent)]
+    NodeParseError(#[from] uv_hg::NodeParseError),
 }
 
 /// A representation of dependency on a package, an extension over a PEP 508's requirement.
@@ -102,6 +105,7 @@ impl From<Requirement> for uv_pep508::Requirement<VerbatimUrl> {
                 }
                 RequirementSource::Url { url, .. }
                 | RequirementSource::Git { url, .. }
+                | RequirementSource::Hg { url, .. }
                 | RequirementSource::Path { url, .. }
                 | RequirementSource::Directory { url, .. } => Some(VersionOrUrl::Url(url)),
             },
@@ -154,6 +158,26 @@ impl From<Requirement> for uv_pep508::Requirement<VerbatimParsedUrl> {
                         verbatim: url,
                     }))
                 }
+                RequirementSource::Hg {
+                    repository,
+                    reference,
+                    precise,
+                    subdirectory,
+                    url,
+                } => {
+                    let hg_url = if let Some(precise) = precise {
+                        HgUrl::from_node(repository, reference, precise)
+                    } else {
+                        HgUrl::from_reference(repository, reference)
+                    };
+                    Some(VersionOrUrl::Url(VerbatimParsedUrl {
+                        parsed_url: ParsedUrl::Hg(ParsedHgUrl {
+                            url: hg_url,
+                            subdirectory,
+                        }),
+                        verbatim: url,
+                    }))
+                }
                 RequirementSource::Path {
                     install_path,
                     ext,
@@ -253,6 +277,21 @@ impl Display for Requirement {
                     writeln!(f, "#subdirectory={}", subdirectory.display())?;
                 }
             }
+            RequirementSource::Hg {
+                url: _,
+                repository,
+                reference,
+                precise: _,
+                subdirectory,
+            } => {
+                write!(f, " @ hg+{repository}")?;
+                if let Some(reference) = reference.as_str() {
+                    write!(f, "@{reference}")?;
+                }
+                if let Some(subdirectory) = subdirectory {
+                    write!(f, "#subdirectory={}", subdirectory.display())?;
+                }
+            }
             RequirementSource::Path { url, .. } => {
                 write!(f, " @ {url}")?;
             }
@@ -315,6 +354,20 @@ pub enum RequirementSource {
         /// `git+<scheme>://<domain>/<path>@<rev>#subdirectory=<subdirectory>`.
         url: VerbatimUrl,
     },
+    /// A remote Mercurial repository, over either HTTPS or SSH.
+    Hg {
+        /// The repository URL (without the `hg+` prefix).
+        repository: Url,
+        /// Optionally, the revision, bookmark, or branch to use.
+        reference: HgReference,
+        /// The precise changeset to use, if known.
+        precise: Option<HgNode>,
+        /// The path to the source distribution if it is not in the repository root.
+        subdirectory: Option<PathBuf>,
+        /// The PEP 508 style url in the format
+        /// `hg+<scheme>://<domain>/<path>@<rev>#subdirectory=<subdirectory>`.
+        url: VerbatimUrl,
+    },
     /// A local built or source distribution, either from a path or a `file://` URL. It can either
     /// be a binary distribution (a `.whl` file) or a source distribution archive (a `.zip` or
     /// `.tar.gz` file).
@@ -365,6 +418,13 @@ impl RequirementSource {
                 precise: git.url.precise(),
                 subdirectory: git.subdirectory,
             },
+            ParsedUrl::Hg(hg) => RequirementSource::Hg {
+                url,
+                repository: hg.url.repository().clone(),
+                reference: hg.url.reference().clone(),
+                precise: hg.url.precise(),
+                subdirectory: hg.subdirectory,
+            },
             ParsedUrl::Archive(archive) => RequirementSource::Url {
                 url,
                 location: archive.url,
@@ -438,6 +498,21 @@ impl RequirementSource {
                 )),
                 verbatim: url.clone(),
             }),
+            Self::Hg {
+                repository,
+                reference,
+                precise,
+                subdirectory,
+                url,
+            } => Some(VerbatimParsedUrl {
+                parsed_url: ParsedUrl::Hg(ParsedHgUrl::from_source(
+                    repository.clone(),
+                    reference.clone(),
+                    *precise,
+                    subdirectory.clone(),
+                )),
+                verbatim: url.clone(),
+            }),
         }
     }
 
@@ -453,9 +528,11 @@ impl RequirementSource {
                     Some(VersionOrUrl::VersionSpecifier(specifier.clone()))
                 }
             }
-            Self::Url { .. } | Self::Git { .. } | Self::Path { .. } | Self::Directory { .. } => {
-                Some(VersionOrUrl::Url(self.to_verbatim_parsed_url()?))
-            }
+            Self::Url { .. }
+            | Self::Git { .. }
+            | Self::Hg { .. }
+            | Self::Path { .. }
+            | Self::Directory { .. } => Some(VersionOrUrl::Url(self.to_verbatim_parsed_url()?)),
         }
     }
 
@@ -470,6 +547,7 @@ impl RequirementSource {
             RequirementSource::Registry { specifier, .. } => Some(specifier),
             RequirementSource::Url { .. }
             | RequirementSource::Git { .. }
+            | RequirementSource::Hg { .. }
             | RequirementSource::Path { .. }
             | RequirementSource::Directory { .. } => None,
         }
@@ -480,7 +558,8 @@ impl RequirementSource {
         match self {
             RequirementSource::Registry { .. }
             | RequirementSource::Url { .. }
-            | RequirementSource::Git { .. } => Ok(self),
+            | RequirementSource::Git { .. }
+            | RequirementSource::Hg { .. } => Ok(self),
             RequirementSource::Path {
                 install_path,
                 ext,
@@ -537,6 +616,21 @@ impl Display for RequirementSource {
                     writeln!(f, "#subdirectory={}", subdirectory.display())?;
                 }
             }
+            Self::Hg {
+                url: _,
+                repository,
+                reference,
+                precise: _,
+                subdirectory,
+            } => {
+                write!(f, " hg+{repository}")?;
+                if let Some(reference) = reference.as_str() {
+                    write!(f, "@{reference}")?;
+                }
+                if let Some(subdirectory) = subdirectory {
+                    write!(f, "#subdirectory={}", subdirectory.display())?;
+                }
+            }
             Self::Path { url, .. } => {
                 write!(f, "{url}")?;
             }
@@ -553,6 +647,8 @@ impl Display for RequirementSource {
 enum RequirementSourceWire {
     /// Ex) `source = { git = "<https://github.com/astral-test/uv-public-pypackage?rev=0.0.1#0dacfd662c64cb4ceb16e6cf65a157a8b715b979>" }`
     Git { git: String },
+    /// Ex) `source = { hg = "<https://hg.example.com/project?branch=stable#4a23745badf5bf5ef7928f1e346e9986bd696d82>" }`
+    Hg { hg: String },
     /// Ex) `source = { url = "<https://example.org/foo-1.0.zip>" }`
     Direct {
         url: Url,
@@ -650,6 +746,51 @@ impl From<RequirementSource> for RequirementSourceWire {
                     git: url.to_string(),
                 }
             }
+            RequirementSource::Hg {
+                repository,
+                reference,
+                precise,
+                subdirectory,
+                url: _,
+            } => {
+                let mut url = repository;
+
+                // Redact the credentials.
+                redact_credentials(&mut url);
+
+                // Clear out any existing state.
+                url.set_fragment(None);
+                url.set_query(None);
+
+                // Put the subdirectory in the query.
+                if let Some(subdirectory) = subdirectory.as_deref().and_then(Path::to_str) {
+                    url.query_pairs_mut()
+                        .append_pair("subdirectory", subdirectory);
+                }
+
+                // Put the requested reference in the query.
+                match reference {
+                    HgReference::Branch(branch) => {
+                        url.query_pairs_mut().append_pair("branch", &branch);
+                    }
+                    HgReference::Bookmark(bookmark) => {
+                        url.query_pairs_mut().append_pair("bookmark", &bookmark);
+                    }
+                    HgReference::Revision(rev) | HgReference::FullNode(rev) => {
+                        url.query_pairs_mut().append_pair("rev", &rev);
+                    }
+                    HgReference::DefaultBranch => {}
+                }
+
+                // Put the precise changeset in the fragment.
+                if let Some(precise) = precise {
+                    url.set_fragment(Some(&precise.to_string()));
+                }
+
+                Self::Hg {
+                    hg: url.to_string(),
+                }
+            }
             RequirementSource::Path {
                 install_path,
                 ext: _,
@@ -731,6 +872,48 @@ impl TryFrom<RequirementSourceWire> for RequirementSource {
                     url,
                 })
             }
+            RequirementSourceWire::Hg { hg } => {
+                let mut repository = Url::parse(&hg)?;
+
+                let mut reference = HgReference::DefaultBranch;
+                let mut subdirectory = None;
+                for (key, val) in repository.query_pairs() {
+                    match &*key {
+                        "branch" => reference = HgReference::Branch(val.into_owned()),
+                        "bookmark" => reference = HgReference::Bookmark(val.into_owned()),
+                        "rev" => reference = HgReference::from_rev(val.into_owned()),
+                        "subdirectory" => subdirectory = Some(val.into_owned()),
+                        _ => continue,
+                    };
+                }
+
+                let precise = repository.fragment().map(HgNode::from_str).transpose()?;
+
+                // Clear out any existing state.
+                repository.set_fragment(None);
+                repository.set_query(None);
+
+                // Redact the credentials.
+                redact_credentials(&mut repository);
+
+                // Create a PEP 508-compatible URL.
+                let mut url = Url::parse(&format!("hg+{repository}"))?;
+                if let Some(rev) = reference.as_str() {
+                    url.set_path(&format!("{}@{}", url.path(), rev));
+                }
+                if let Some(subdirectory) = &subdirectory {
+                    url.set_fragment(Some(&format!("subdirectory={subdirectory}")));
+                }
+                let url = VerbatimUrl::from_url(url);
+
+                Ok(Self::Hg {
+                    repository,
+                    reference,
+                    precise,
+                    subdirectory: subdirectory.map(PathBuf::from),
+                    url,
+                })
+            }
             RequirementSourceWire::Direct { url, subdirectory } => Ok(Self::Url {
                 url: VerbatimUrl::from_url(url.clone()),
                 location: url.clone(),
@@ -785,12 +968,12 @@ impl TryFrom<RequirementSourceWire> for RequirementSource {
     }
 }
 
-/// Remove the credentials from a URL, allowing the generic `git` username (without a password)
-/// in SSH URLs, as in, `ssh://git@github.com/...`.
+/// Remove the credentials from a URL, allowing the generic `git` (or `hg`) username (without a
+/// password) in SSH URLs, as in, `ssh://git@github.com/...`.
 pub fn redact_credentials(url: &mut Url) {
-    // For URLs that use the `git` convention (i.e., `ssh://git@github.com/...`), avoid dropping the
-    // username.
-    if url.scheme() == "ssh" && url.username() == "git" && url.password().is_none() {
+    // For URLs that use the `git` convention (i.e., `ssh://git@github.com/...`), or the equivalent
+    // `hg` convention for Mercurial hosts, avoid dropping the username.
+    if url.scheme() == "ssh" && matches!(url.username(), "git" | "hg") && url.password().is_none() {
         return;
     }
     let _ = url.set_password(None);
@@ -30,6 +30,20 @@ fn sorted_simple_json_files<'de, D: Deserializer<'de>>(d: D) -> Result<Vec<File>
     Ok(files)
 }
 
+/// The list of projects available on an index, from the root of `PyPI`'s JSON API.
+///
+/// <https://peps.python.org/pep-0691/#project-list>
+#[derive(Debug, Clone, Deserialize)]
+pub struct SimpleProjectsJson {
+    pub projects: Vec<SimpleProject>,
+}
+
+/// A single project listed on an index.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SimpleProject {
+    pub name: String,
+}
+
 /// A single (remote) file belonging to a package, either a wheel or a source distribution.
 ///
 /// <https://peps.python.org/pep-0691/#project-detail>
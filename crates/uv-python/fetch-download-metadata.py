@@ -104,6 +104,7 @@ class Version(NamedTuple):
 class ImplementationName(StrEnum):
     CPYTHON = "cpython"
     PYPY = "pypy"
+    GRAALPY = "graalpy"
 
 
 class Variant(StrEnum):
@@ -492,6 +493,93 @@ class PyPyFinder(Finder):
             download.sha256 = checksums.get(download.filename)
 
 
+class GraalPyFinder(Finder):
+    implementation = ImplementationName.GRAALPY
+
+    RELEASE_URL = "https://api.github.com/repos/oracle/graalpython/releases"
+
+    PLATFORM_MAPPING = {
+        "linux": "linux",
+        "macos": "darwin",
+        "windows": "windows",
+    }
+
+    ARCH_MAPPING = {
+        "amd64": "x86_64",
+        "aarch64": "aarch64",
+    }
+
+    # The GraalPy version of a release, e.g., `graal-24.1.1`.
+    _release_re = re.compile(r"^graal-(?P<version>\d+\.\d+\.\d+)$")
+
+    # The Python version implemented by a release, as stated in its notes, e.g., `Python 3.11.7`.
+    _python_version_re = re.compile(r"Python (?P<version>3\.\d+\.\d+)")
+
+    _filename_re = re.compile(
+        r"^graalpy-(?P<version>\d+\.\d+\.\d+)-(?P<platform>\w+)-(?P<arch>\w+)\.(?:tar\.gz|zip)$"
+    )
+
+    def __init__(self, client: httpx.AsyncClient):
+        self.client = client
+
+    async def find(self) -> list[PythonDownload]:
+        downloads = await self._fetch_downloads()
+        await self._fetch_checksums(downloads)
+        return downloads
+
+    async def _fetch_downloads(self) -> list[PythonDownload]:
+        resp = await self.client.get(self.RELEASE_URL)
+        resp.raise_for_status()
+        releases = resp.json()
+
+        results = {}
+        for release in releases:
+            if release["prerelease"] or release["draft"]:
+                continue
+            if not self._release_re.match(release["tag_name"]):
+                continue
+            match = self._python_version_re.search(release["body"] or "")
+            if not match:
+                logging.debug(
+                    "Skipping %s: unknown Python version", release["tag_name"]
+                )
+                continue
+            python_version = Version.from_str(match.group("version"))
+            for asset in release["assets"]:
+                match = self._filename_re.match(asset["name"])
+                if not match:
+                    continue
+                platform = self.PLATFORM_MAPPING.get(match.group("platform"))
+                arch = self.ARCH_MAPPING.get(match.group("arch"))
+                if platform is None or arch is None:
+                    continue
+                libc = "gnu" if platform == "linux" else "none"
+                download = PythonDownload(
+                    version=python_version,
+                    triple=PlatformTriple(
+                        platform=platform,
+                        arch=arch,
+                        libc=libc,
+                    ),
+                    flavor="",
+                    implementation=self.implementation,
+                    filename=asset["name"],
+                    url=asset["browser_download_url"],
+                )
+                # Only keep the latest GraalPy version of each arch/platform
+                if (python_version, arch, platform) not in results:
+                    results[(python_version, arch, platform)] = download
+
+        return list(results.values())
+
+    async def _fetch_checksums(self, downloads: list[PythonDownload]) -> None:
+        logging.info("Fetching GraalPy checksums")
+        for download in downloads:
+            resp = await self.client.get(download.url + ".sha256")
+            resp.raise_for_status()
+            download.sha256 = resp.text.strip().split()[0]
+
+
 def render(downloads: list[PythonDownload]) -> None:
     """Render `download-metadata.json`."""
 
@@ -516,7 +604,11 @@ def render(downloads: list[PythonDownload]) -> None:
 
     def sort_key(download: PythonDownload) -> tuple:
         # Sort by implementation, version (latest first), and then by triple.
-        impl_order = [ImplementationName.CPYTHON, ImplementationName.PYPY]
+        impl_order = [
+            ImplementationName.CPYTHON,
+            ImplementationName.PYPY,
+            ImplementationName.GRAALPY,
+        ]
         prerelease = prerelease_sort_key(download.version.prerelease)
         return (
             impl_order.index(download.implementation),
@@ -571,6 +663,7 @@ async def find() -> None:
     finders = [
         CPythonFinder(client),
         PyPyFinder(client),
+        GraalPyFinder(client),
     ]
     downloads = []
 
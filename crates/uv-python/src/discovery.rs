@@ -134,10 +134,15 @@ pub enum EnvironmentPreference {
 }
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
 pub enum PythonVariant {
+    /// The default build, with the GIL enabled.
     #[default]
     Default,
+    /// A free-threaded build, with the GIL disabled.
     Freethreaded,
+    /// A debug build, e.g., with assertions enabled and without optimizations.
+    Debug,
 }
 
 /// A Python discovery version request.
@@ -1234,6 +1239,7 @@ impl PythonVariant {
         match self {
             PythonVariant::Default => !interpreter.gil_disabled(),
             PythonVariant::Freethreaded => interpreter.gil_disabled(),
+            PythonVariant::Debug => interpreter.debug() && !interpreter.gil_disabled(),
         }
     }
 
@@ -1244,6 +1250,7 @@ impl PythonVariant {
         match self {
             Self::Default => "",
             Self::Freethreaded => "t",
+            Self::Debug => "d",
         }
     }
 }
@@ -1352,6 +1359,36 @@ impl PythonRequest {
         Self::ExecutableName(value.to_string())
     }
 
+    /// Return a new [`PythonRequest`] for the given [`PythonVariant`], e.g., a debug build.
+    ///
+    /// Requests for a specific path or executable are returned unchanged, as the variant is
+    /// determined by the interpreter itself.
+    #[must_use]
+    pub fn with_variant(self, variant: PythonVariant) -> Self {
+        match self {
+            Self::Default | Self::Any => {
+                Self::Version(VersionRequest::Default.with_variant(variant))
+            }
+            Self::Version(version) => Self::Version(version.with_variant(variant)),
+            Self::Implementation(implementation) => Self::ImplementationVersion(
+                implementation,
+                VersionRequest::Default.with_variant(variant),
+            ),
+            Self::ImplementationVersion(implementation, version) => {
+                Self::ImplementationVersion(implementation, version.with_variant(variant))
+            }
+            Self::Key(request) => {
+                let version = request
+                    .version()
+                    .cloned()
+                    .unwrap_or_default()
+                    .with_variant(variant);
+                Self::Key(request.with_version(version))
+            }
+            Self::Directory(_) | Self::File(_) | Self::ExecutableName(_) => self,
+        }
+    }
+
     /// Check if a given interpreter satisfies the interpreter request.
     pub fn satisfied(&self, interpreter: &Interpreter, cache: &Cache) -> bool {
         /// Returns `true` if the two paths refer to the same interpreter executable.
@@ -1725,10 +1762,13 @@ impl VersionRequest {
             }
         }
 
-        // Include free-threaded variants
-        if self.is_freethreaded() {
+        // Include free-threaded and debug variants
+        if let Some(variant) = self
+            .variant()
+            .filter(|variant| *variant != PythonVariant::Default)
+        {
             for i in 0..names.len() {
-                let name = names[i].with_variant(PythonVariant::Freethreaded);
+                let name = names[i].with_variant(variant);
                 names.push(name);
             }
         }
@@ -2042,6 +2082,25 @@ impl VersionRequest {
         }
     }
 
+    /// Return a new [`VersionRequest`] for the given [`PythonVariant`].
+    ///
+    /// Requests without a version are treated as a request for any Python 3 version.
+    #[must_use]
+    pub fn with_variant(self, variant: PythonVariant) -> Self {
+        match self {
+            Self::Any | Self::Default => Self::Major(3, variant),
+            Self::Major(major, _) => Self::Major(major, variant),
+            Self::MajorMinor(major, minor, _) => Self::MajorMinor(major, minor, variant),
+            Self::MajorMinorPatch(major, minor, patch, _) => {
+                Self::MajorMinorPatch(major, minor, patch, variant)
+            }
+            Self::MajorMinorPrerelease(major, minor, prerelease, _) => {
+                Self::MajorMinorPrerelease(major, minor, prerelease, variant)
+            }
+            Self::Range(specifiers, _) => Self::Range(specifiers, variant),
+        }
+    }
+
     /// Return the [`PythonVariant`] of the request, if any.
     pub(crate) fn variant(&self) -> Option<PythonVariant> {
         match self {
@@ -2060,16 +2119,21 @@ impl FromStr for VersionRequest {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        // Check if the version request is for a free-threaded Python version
-        let (s, variant) = s
-            .strip_suffix('t')
-            .map_or((s, PythonVariant::Default), |s| {
-                (s, PythonVariant::Freethreaded)
-            });
+        // Check if the version request is for a free-threaded or debug Python version
+        let (s, variant) = if let Some(s) = s.strip_suffix('t') {
+            (s, PythonVariant::Freethreaded)
+        } else if let Some(s) = s.strip_suffix('d') {
+            (s, PythonVariant::Debug)
+        } else {
+            (s, PythonVariant::Default)
+        };
 
-        if variant == PythonVariant::Freethreaded && s.ends_with('t') {
-            // More than one trailing "t" is not allowed
-            return Err(Error::InvalidVersionRequest(format!("{s}t")));
+        if variant != PythonVariant::Default && (s.ends_with('t') || s.ends_with('d')) {
+            // More than one variant suffix is not allowed
+            return Err(Error::InvalidVersionRequest(format!(
+                "{s}{}",
+                variant.suffix()
+            )));
         }
 
         let Ok(version) = Version::from_str(s) else {
@@ -2099,6 +2163,7 @@ impl FromStr for VersionRequest {
 
             match local.as_str() {
                 "freethreaded" => PythonVariant::Freethreaded,
+                "debug" => PythonVariant::Debug,
                 _ => return Err(Error::InvalidVersionRequest(s.to_string())),
             }
         };
@@ -2153,6 +2218,7 @@ impl FromStr for PythonVariant {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "t" | "freethreaded" => Ok(Self::Freethreaded),
+            "d" | "debug" => Ok(Self::Debug),
             "" => Ok(Self::Default),
             _ => Err(()),
         }
@@ -2164,6 +2230,7 @@ impl std::fmt::Display for PythonVariant {
         match self {
             Self::Default => f.write_str("default"),
             Self::Freethreaded => f.write_str("freethreaded"),
+            Self::Debug => f.write_str("debug"),
         }
     }
 }
@@ -2193,23 +2260,15 @@ impl fmt::Display for VersionRequest {
         match self {
             Self::Any => f.write_str("any"),
             Self::Default => f.write_str("default"),
-            Self::Major(major, PythonVariant::Default) => write!(f, "{major}"),
-            Self::Major(major, PythonVariant::Freethreaded) => write!(f, "{major}t"),
-            Self::MajorMinor(major, minor, PythonVariant::Default) => write!(f, "{major}.{minor}"),
-            Self::MajorMinor(major, minor, PythonVariant::Freethreaded) => {
-                write!(f, "{major}.{minor}t")
-            }
-            Self::MajorMinorPatch(major, minor, patch, PythonVariant::Default) => {
-                write!(f, "{major}.{minor}.{patch}")
-            }
-            Self::MajorMinorPatch(major, minor, patch, PythonVariant::Freethreaded) => {
-                write!(f, "{major}.{minor}.{patch}t")
+            Self::Major(major, variant) => write!(f, "{major}{}", variant.suffix()),
+            Self::MajorMinor(major, minor, variant) => {
+                write!(f, "{major}.{minor}{}", variant.suffix())
             }
-            Self::MajorMinorPrerelease(major, minor, prerelease, PythonVariant::Default) => {
-                write!(f, "{major}.{minor}{prerelease}")
+            Self::MajorMinorPatch(major, minor, patch, variant) => {
+                write!(f, "{major}.{minor}.{patch}{}", variant.suffix())
             }
-            Self::MajorMinorPrerelease(major, minor, prerelease, PythonVariant::Freethreaded) => {
-                write!(f, "{major}.{minor}{prerelease}t")
+            Self::MajorMinorPrerelease(major, minor, prerelease, variant) => {
+                write!(f, "{major}.{minor}{prerelease}{}", variant.suffix())
             }
             Self::Range(specifiers, _) => write!(f, "{specifiers}"),
         }
@@ -440,6 +440,22 @@ fn version_request_from_str() {
         VersionRequest::from_str("3.13tt"),
         Err(Error::InvalidVersionRequest(_))
     ));
+    assert_eq!(
+        VersionRequest::from_str("3.13d").unwrap(),
+        VersionRequest::MajorMinor(3, 13, PythonVariant::Debug)
+    );
+    assert_eq!(
+        VersionRequest::from_str("3.13+debug").unwrap(),
+        VersionRequest::MajorMinor(3, 13, PythonVariant::Debug)
+    );
+    assert_eq!(
+        VersionRequest::from_str("3.13.0d").unwrap().to_string(),
+        "3.13.0d"
+    );
+    assert!(matches!(
+        VersionRequest::from_str("3.13td"),
+        Err(Error::InvalidVersionRequest(_))
+    ));
 }
 
 #[test]
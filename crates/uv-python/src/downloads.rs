@@ -7,7 +7,7 @@ use std::pin::Pin;
 use std::str::FromStr;
 use std::task::{Context, Poll};
 use thiserror::Error;
-use tokio::io::{AsyncRead, ReadBuf};
+use tokio::io::{AsyncRead, AsyncReadExt, ReadBuf};
 use tokio_util::compat::FuturesAsyncReadCompatExt;
 use tokio_util::either::Either;
 use tracing::{debug, instrument};
@@ -53,6 +53,8 @@ pub enum Error {
         expected: String,
         actual: String,
     },
+    #[error("Invalid checksum in {0}")]
+    InvalidChecksum(Url),
     #[error("Invalid download URL")]
     InvalidUrl(#[from] url::ParseError),
     #[error("Invalid path in file URL: `{0}`")]
@@ -479,6 +481,17 @@ impl ManagedPythonDownload {
         let filename = url.path_segments().unwrap().last().unwrap();
         let ext = SourceDistExtension::from_path(filename)
             .map_err(|err| Error::MissingExtension(url.to_string(), err))?;
+
+        // GraalPy publishes a checksum alongside each archive, which is used if the download
+        // metadata doesn't include one.
+        let sha256 = match (self.sha256, &self.key.implementation) {
+            (Some(sha256), _) => Some(sha256.to_string()),
+            (None, LenientImplementationName::Known(ImplementationName::GraalPy)) => {
+                Some(read_checksum(&url, client).await?)
+            }
+            (None, _) => None,
+        };
+
         let (reader, size) = read_url(&url, client).await?;
 
         let progress = reporter
@@ -493,9 +506,8 @@ impl ManagedPythonDownload {
             temp_dir.path().simplified().display()
         );
 
-        let mut hashers = self
-            .sha256
-            .into_iter()
+        let mut hashers = sha256
+            .iter()
             .map(|_| Hasher::from(HashAlgorithm::Sha256))
             .collect::<Vec<_>>();
         let mut hasher = uv_extract::hash::HashReader::new(reader, &mut hashers);
@@ -523,12 +535,12 @@ impl ManagedPythonDownload {
         }
 
         // Check the hash
-        if let Some(expected) = self.sha256 {
+        if let Some(expected) = sha256 {
             let actual = HashDigest::from(hashers.pop().unwrap()).digest;
-            if !actual.eq_ignore_ascii_case(expected) {
+            if !actual.eq_ignore_ascii_case(&expected) {
                 return Err(Error::HashMismatch {
                     installation: self.key.to_string(),
-                    expected: expected.to_string(),
+                    expected,
                     actual: actual.to_string(),
                 });
             }
@@ -612,6 +624,20 @@ impl ManagedPythonDownload {
                 }
             }
 
+            LenientImplementationName::Known(ImplementationName::GraalPy) => {
+                if let Ok(mirror) = std::env::var(EnvVars::UV_GRAALPY_INSTALL_MIRROR) {
+                    let Some(suffix) = self
+                        .url
+                        .strip_prefix("https://github.com/oracle/graalpython/releases/download/")
+                    else {
+                        return Err(Error::Mirror(EnvVars::UV_GRAALPY_INSTALL_MIRROR, self.url));
+                    };
+                    return Ok(Url::parse(
+                        format!("{}/{}", mirror.trim_end_matches('/'), suffix).as_str(),
+                    )?);
+                }
+            }
+
             _ => {}
         }
 
@@ -686,6 +712,21 @@ where
 }
 
 /// Convert a [`Url`] into an [`AsyncRead`] stream.
+/// Read the SHA-256 checksum published alongside the archive at the given URL, i.e., in a
+/// `.sha256` file containing the hex digest, optionally followed by the filename.
+async fn read_checksum(url: &Url, client: &uv_client::BaseClient) -> Result<String, Error> {
+    let url = Url::parse(&format!("{url}.sha256"))?;
+    let (mut reader, _) = read_url(&url, client).await?;
+    let mut contents = String::new();
+    reader.read_to_string(&mut contents).await?;
+    contents
+        .split_whitespace()
+        .next()
+        .filter(|digest| digest.len() == 64 && digest.chars().all(|c| c.is_ascii_hexdigit()))
+        .map(ToString::to_string)
+        .ok_or(Error::InvalidChecksum(url))
+}
+
 async fn read_url(
     url: &Url,
     client: &uv_client::BaseClient,
@@ -24,6 +24,8 @@ pub struct PythonEnvironment(Arc<PythonEnvironmentShared>);
 struct PythonEnvironmentShared {
     root: PathBuf,
     interpreter: Interpreter,
+    /// Whether the environment should be treated as relocatable, regardless of its `pyvenv.cfg`.
+    relocatable: bool,
 }
 
 /// The result of failed environment discovery.
@@ -196,6 +198,7 @@ impl PythonEnvironment {
         Ok(Self(Arc::new(PythonEnvironmentShared {
             root: interpreter.sys_prefix().to_path_buf(),
             interpreter,
+            relocatable: false,
         })))
     }
 
@@ -209,6 +212,7 @@ impl PythonEnvironment {
         Self(Arc::new(PythonEnvironmentShared {
             root: interpreter.sys_prefix().to_path_buf(),
             interpreter,
+            relocatable: false,
         }))
     }
 
@@ -230,6 +234,19 @@ impl PythonEnvironment {
         })))
     }
 
+    /// Treat the [`PythonEnvironment`] as relocatable, such that any scripts and entry points
+    /// installed into it refer to the interpreter by a relative path.
+    ///
+    /// Virtual environments are otherwise only relocatable if marked as such in `pyvenv.cfg`.
+    #[must_use]
+    pub fn with_relocatable(self) -> Self {
+        let inner = Arc::unwrap_or_clone(self.0);
+        Self(Arc::new(PythonEnvironmentShared {
+            relocatable: true,
+            ..inner
+        }))
+    }
+
     /// Returns the root (i.e., `prefix`) of the Python interpreter.
     pub fn root(&self) -> &Path {
         &self.0.root
@@ -250,7 +267,7 @@ impl PythonEnvironment {
 
     /// Returns `true` if the environment is "relocatable".
     pub fn relocatable(&self) -> bool {
-        self.cfg().is_ok_and(|cfg| cfg.is_relocatable())
+        self.0.relocatable || self.cfg().is_ok_and(|cfg| cfg.is_relocatable())
     }
 
     /// Returns the location of the Python executable.
@@ -306,6 +306,10 @@ impl PythonInstallationKey {
         &self.libc
     }
 
+    pub fn variant(&self) -> &PythonVariant {
+        &self.variant
+    }
+
     /// Return a canonical name for a versioned executable.
     pub fn versioned_executable_name(&self) -> String {
         format!(
@@ -322,7 +326,7 @@ impl fmt::Display for PythonInstallationKey {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let variant = match self.variant {
             PythonVariant::Default => String::new(),
-            PythonVariant::Freethreaded => format!("+{}", self.variant),
+            PythonVariant::Freethreaded | PythonVariant::Debug => format!("+{}", self.variant),
         };
         write!(
             f,
@@ -50,6 +50,7 @@ pub struct Interpreter {
     prefix: Option<Prefix>,
     pointer_size: PointerSize,
     gil_disabled: bool,
+    debug: bool,
 }
 
 impl Interpreter {
@@ -73,6 +74,7 @@ impl Interpreter {
             sys_base_exec_prefix: info.sys_base_exec_prefix,
             pointer_size: info.pointer_size,
             gil_disabled: info.gil_disabled,
+            debug: info.debug,
             sys_base_prefix: info.sys_base_prefix,
             sys_base_executable: info.sys_base_executable,
             sys_executable: info.sys_executable,
@@ -169,6 +171,8 @@ impl Interpreter {
     pub fn variant(&self) -> PythonVariant {
         if self.gil_disabled() {
             PythonVariant::Freethreaded
+        } else if self.debug() {
+            PythonVariant::Debug
         } else {
             PythonVariant::default()
         }
@@ -414,6 +418,12 @@ impl Interpreter {
         self.gil_disabled
     }
 
+    /// Return whether this is a debug build of Python, as indicated by the presence of
+    /// `sys.gettotalrefcount`.
+    pub fn debug(&self) -> bool {
+        self.debug
+    }
+
     /// Return the `--target` directory for this interpreter, if any.
     pub fn target(&self) -> Option<&Target> {
         self.target.as_ref()
@@ -607,6 +617,7 @@ struct InterpreterInfo {
     stdlib: PathBuf,
     pointer_size: PointerSize,
     gil_disabled: bool,
+    debug: bool,
 }
 
 impl InterpreterInfo {
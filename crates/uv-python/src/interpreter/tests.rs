@@ -62,7 +62,8 @@ fn test_cache_invalidation() {
                 "scripts": "bin"
             },
             "pointer_size": "64",
-            "gil_disabled": true
+            "gil_disabled": true,
+            "debug": false
         }
     "##};
 
@@ -86,6 +86,9 @@ pub enum Error {
     NameParseError(#[from] installation::PythonInstallationKeyError),
     #[error(transparent)]
     LibcDetection(#[from] LibcDetectionError),
+    #[cfg(windows)]
+    #[error("Failed to update the Windows registry entry for {0}")]
+    WindowsRegistry(PythonInstallationKey, #[source] windows_result::Error),
 }
 /// A collection of uv-managed Python installations installed on the current system.
 #[derive(Debug, Clone)]
@@ -177,7 +180,8 @@ impl ManagedPythonInstallations {
     /// Iterate over each Python installation in this directory.
     ///
     /// Pythons are sorted by [`PythonInstallationKey`], for the same implementation name, the newest versions come first.
-    /// This ensures a consistent ordering across all platforms.
+    /// This ensures a consistent ordering across all platforms. Debug builds come after all other
+    /// builds, such that a request that doesn't specify a variant prefers the default build.
     pub fn find_all(
         &self,
     ) -> Result<impl DoubleEndedIterator<Item = ManagedPythonInstallation>, Error> {
@@ -218,7 +222,12 @@ impl ManagedPythonInstallations {
                     })
                     .ok()
             })
-            .sorted_unstable_by_key(|installation| Reverse(installation.key().clone())))
+            .sorted_unstable_by_key(|installation| {
+                (
+                    installation.key().variant == PythonVariant::Debug,
+                    Reverse(installation.key().clone()),
+                )
+            }))
     }
 
     /// Iterate over Python installations that support the current platform.
@@ -302,9 +311,7 @@ impl ManagedPythonInstallation {
         let implementation = match self.implementation() {
             ImplementationName::CPython => "python",
             ImplementationName::PyPy => "pypy",
-            ImplementationName::GraalPy => {
-                unreachable!("Managed installations of GraalPy are not supported")
-            }
+            ImplementationName::GraalPy => "graalpy",
         };
 
         let version = match self.implementation() {
@@ -317,9 +324,8 @@ impl ManagedPythonInstallation {
             }
             // PyPy uses a full version number, even on Windows.
             ImplementationName::PyPy => format!("{}.{}", self.key.major, self.key.minor),
-            ImplementationName::GraalPy => {
-                unreachable!("Managed installations of GraalPy are not supported")
-            }
+            // GraalPy doesn't include a versioned executable.
+            ImplementationName::GraalPy => String::new(),
         };
 
         // On Windows, the executable is just `python.exe` even for alternative variants
@@ -334,10 +340,11 @@ impl ManagedPythonInstallation {
             exe = std::env::consts::EXE_SUFFIX
         );
 
-        let executable = if cfg!(windows) {
-            self.python_dir().join(name)
-        } else if cfg!(unix) {
+        // GraalPy places its executables in `bin`, even on Windows.
+        let executable = if cfg!(unix) || *self.implementation() == ImplementationName::GraalPy {
             self.python_dir().join("bin").join(name)
+        } else if cfg!(windows) {
+            self.python_dir().join(name)
         } else {
             unimplemented!("Only Windows and Unix systems are supported.")
         };
@@ -359,6 +366,14 @@ impl ManagedPythonInstallation {
             ));
         }
 
+        // Debug builds on Windows use the `_d` suffix, i.e., `python_d.exe`.
+        if cfg!(windows) && matches!(self.key.variant, PythonVariant::Debug) && !executable.exists()
+        {
+            return self
+                .python_dir()
+                .join(format!("python_d{}", std::env::consts::EXE_SUFFIX));
+        }
+
         executable
     }
 
@@ -393,6 +408,21 @@ impl ManagedPythonInstallation {
         &self.key
     }
 
+    /// Register the installation in the Windows registry following PEP 514, such that it can be
+    /// discovered by the `py` launcher and other tools that read the registry.
+    #[cfg(windows)]
+    pub fn register_windows_registry(&self) -> Result<(), Error> {
+        crate::py_launcher::register_managed_python(self, &self.python_dir())
+            .map_err(|err| Error::WindowsRegistry(self.key.clone(), err))
+    }
+
+    /// Remove the installation from the Windows registry, if it was registered.
+    #[cfg(windows)]
+    pub fn unregister_windows_registry(&self) -> Result<(), Error> {
+        crate::py_launcher::unregister_managed_python(self)
+            .map_err(|err| Error::WindowsRegistry(self.key.clone(), err))
+    }
+
     pub fn satisfies(&self, request: &PythonRequest) -> bool {
         match request {
             PythonRequest::File(path) => self.executable() == *path,
@@ -458,10 +488,17 @@ impl ManagedPythonInstallation {
     /// standard `EXTERNALLY-MANAGED` file.
     pub fn ensure_externally_managed(&self) -> Result<(), Error> {
         // Construct the path to the `stdlib` directory.
-        let stdlib = if matches!(self.key.os, Os(target_lexicon::OperatingSystem::Windows)) {
+        // GraalPy uses the Unix layout, even on Windows.
+        let stdlib = if matches!(self.key.os, Os(target_lexicon::OperatingSystem::Windows))
+            && *self.implementation() != ImplementationName::GraalPy
+        {
             self.python_dir().join("Lib")
         } else {
-            let lib_suffix = self.key.variant.suffix();
+            // Only free-threaded builds use a distinct `lib` directory, e.g., `python3.13t`.
+            let lib_suffix = match self.key.variant {
+                PythonVariant::Freethreaded => self.key.variant.suffix(),
+                PythonVariant::Default | PythonVariant::Debug => "",
+            };
             let python = if matches!(
                 self.key.implementation,
                 LenientImplementationName::Known(ImplementationName::PyPy)
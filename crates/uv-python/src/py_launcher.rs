@@ -1,10 +1,16 @@
-use crate::PythonVersion;
+use crate::implementation::ImplementationName;
+use crate::managed::ManagedPythonInstallation;
+use crate::{PythonVariant, PythonVersion};
 use std::cmp::Ordering;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use target_lexicon::{Architecture, PointerWidth};
 use tracing::debug;
 use windows_registry::{Key, CURRENT_USER, LOCAL_MACHINE};
 
+/// The PEP 514 company under which uv registers managed Python installations.
+const MANAGED_COMPANY: &str = "Astral";
+
 /// A Python interpreter found in the Windows registry through PEP 514 or from a known Microsoft
 /// Store path.
 ///
@@ -28,6 +34,11 @@ pub(crate) fn registry_pythons() -> Result<Vec<WindowsPython>, windows_result::E
             if company == "PyLauncher" {
                 continue;
             }
+            // Managed Python installations are discovered through the installation directory
+            // instead.
+            if company == MANAGED_COMPANY {
+                continue;
+            }
             let Ok(company_key) = key_python.open(&company) else {
                 // Ignore invalid entries
                 continue;
@@ -96,3 +107,110 @@ fn read_registry_entry(company: &str, tag: &str, tag_key: &Key) -> Option<Window
         version,
     })
 }
+
+/// Register a managed Python installation in the Windows registry following PEP 514, such that
+/// it can be discovered by the `py` launcher and other tools, e.g., IDEs.
+///
+/// The entry is written to `HKEY_CURRENT_USER`, which doesn't require elevated permissions.
+pub(crate) fn register_managed_python(
+    installation: &ManagedPythonInstallation,
+    install_path: &Path,
+) -> Result<(), windows_result::Error> {
+    let company = CURRENT_USER.create(format!(r"Software\Python\{MANAGED_COMPANY}"))?;
+    company.set_string("DisplayName", "Astral Software Inc.")?;
+    company.set_string("SupportUrl", "https://github.com/astral-sh/uv")?;
+
+    let key = installation.key();
+    let tag_name = managed_python_tag(installation);
+    let tag = company.create(&tag_name)?;
+    let bitness = match key.arch.pointer_width() {
+        Ok(PointerWidth::U16 | PointerWidth::U32) => "32bit",
+        Ok(PointerWidth::U64) | Err(()) => "64bit",
+    };
+    let variant = match key.variant {
+        PythonVariant::Default => "",
+        PythonVariant::Freethreaded => " free-threaded",
+        PythonVariant::Debug => " debug",
+    };
+    let display_name = format!(
+        "{} {}{variant} ({bitness})",
+        installation.implementation().pretty(),
+        key.version()
+    );
+    tag.set_string("DisplayName", display_name.as_str())?;
+    tag.set_string("SupportUrl", "https://github.com/astral-sh/uv")?;
+    tag.set_string("Version", key.version().to_string().as_str())?;
+    tag.set_string(
+        "SysVersion",
+        format!("{}.{}", key.major, key.minor).as_str(),
+    )?;
+    tag.set_string("SysArchitecture", bitness)?;
+
+    // The `pythonw` executable is optional, e.g., it's missing from PyPy distributions.
+    let executable = installation.executable();
+    let windowed = executable.with_file_name(format!(
+        "{}w.exe",
+        executable
+            .file_stem()
+            .map(|stem| stem.to_string_lossy())
+            .unwrap_or_default()
+    ));
+    let install = tag.create("InstallPath")?;
+    install.set_string("", &*install_path.to_string_lossy())?;
+    install.set_string("ExecutablePath", &*executable.to_string_lossy())?;
+    if windowed.is_file() {
+        install.set_string("WindowedExecutablePath", &*windowed.to_string_lossy())?;
+    }
+
+    debug!(r"Registered {key} as `HKEY_CURRENT_USER\Software\Python\{MANAGED_COMPANY}\{tag_name}`");
+
+    Ok(())
+}
+
+/// Remove the PEP 514 registry entry of a managed Python installation, if it exists.
+pub(crate) fn unregister_managed_python(
+    installation: &ManagedPythonInstallation,
+) -> Result<(), windows_result::Error> {
+    let Ok(company) = CURRENT_USER.open(format!(r"Software\Python\{MANAGED_COMPANY}")) else {
+        return Ok(());
+    };
+    let tag = managed_python_tag(installation);
+    if company.keys()?.any(|existing| existing == tag) {
+        company.remove_tree(tag.as_str())?;
+        debug!(
+            r"Removed `HKEY_CURRENT_USER\Software\Python\{MANAGED_COMPANY}\{tag}` for {}",
+            installation.key()
+        );
+    }
+
+    // Remove the company key once the last installation is unregistered.
+    if company.keys()?.next().is_none() {
+        CURRENT_USER.remove_tree(format!(r"Software\Python\{MANAGED_COMPANY}"))?;
+    }
+
+    Ok(())
+}
+
+/// The PEP 514 tag for a managed Python installation, e.g., `3.12.7` or `3.13.0t-arm64`.
+///
+/// CPython tags start with the version, matching the `PythonCore` conventions, such that requests
+/// like `py -V:3.12` resolve as expected. Other implementations are prefixed with their name, e.g.,
+/// `PyPy3.10.14`.
+fn managed_python_tag(installation: &ManagedPythonInstallation) -> String {
+    let key = installation.key();
+    let implementation = match installation.implementation() {
+        ImplementationName::CPython => "",
+        implementation => implementation.pretty(),
+    };
+    let arch = match *key.arch {
+        Architecture::X86_64 => "",
+        Architecture::Aarch64(_) => "-arm64",
+        _ if key.arch.pointer_width() == Ok(PointerWidth::U32) => "-32",
+        _ => "",
+    };
+    format!(
+        "{implementation}{}{}{arch}",
+        key.version(),
+        key.variant.suffix()
+    )
+}
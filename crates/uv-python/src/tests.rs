@@ -162,7 +162,8 @@ impl TestContext {
                         "scripts": "bin"
                     },
                     "pointer_size": "64",
-                    "gil_disabled": {FREE_THREADED}
+                    "gil_disabled": {FREE_THREADED},
+                    "debug": false
                 }
             "##};
 
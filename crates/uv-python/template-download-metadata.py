@@ -39,6 +39,8 @@ def prepare_name(name: str) -> str:
             return "CPython"
         case "pypy":
             return "PyPy"
+        case "graalpy":
+            return "GraalPy"
         case _:
             raise ValueError(f"Unknown implementation name: {name}")
 
@@ -103,8 +105,6 @@ def main() -> None:
     data["versions"] = [
         {"key": key, "value": prepare_value(value)}
         for key, value in json.loads(VERSION_METADATA.read_text()).items()
-        # Exclude debug variants for now, we don't support them in the Rust side
-        if value["variant"] != "debug"
     ]
 
     # Render the template
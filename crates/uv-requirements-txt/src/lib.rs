@@ -40,10 +40,17 @@ use std::io;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
+#[cfg(feature = "http")]
+use tracing::debug;
 use tracing::instrument;
 use unscanny::{Pattern, Scanner};
 use url::Url;
 
+use uv_cache::Cache;
+#[cfg(feature = "http")]
+use uv_cache::{CacheBucket, Freshness};
+#[cfg(feature = "http")]
+use uv_cache_key::{cache_digest, CanonicalUrl};
 #[cfg(feature = "http")]
 use uv_client::BaseClient;
 use uv_client::BaseClientBuilder;
@@ -154,6 +161,9 @@ pub struct RequirementsTxt {
 
 impl RequirementsTxt {
     /// See module level documentation
+    ///
+    /// If a [`Cache`] is provided, remote files (e.g., `-r https://example.com/requirements.txt`)
+    /// are cached, and reused for the duration set via [`Cache::with_requirements_ttl`].
     #[instrument(
         skip_all,
         fields(requirements_txt = requirements_txt.as_ref().as_os_str().to_str())
@@ -162,6 +172,7 @@ impl RequirementsTxt {
         requirements_txt: impl AsRef<Path>,
         working_dir: impl AsRef<Path>,
         client_builder: &BaseClientBuilder<'_>,
+        cache: Option<&Cache>,
     ) -> Result<Self, RequirementsTxtFileError> {
         let requirements_txt = requirements_txt.as_ref();
         let working_dir = working_dir.as_ref();
@@ -181,19 +192,7 @@ impl RequirementsTxt {
 
                 #[cfg(feature = "http")]
                 {
-                    // Avoid constructing a client if network is disabled already
-                    if client_builder.is_offline() {
-                        return Err(RequirementsTxtFileError {
-                            file: requirements_txt.to_path_buf(),
-                            error: RequirementsTxtParserError::Io(io::Error::new(
-                                io::ErrorKind::InvalidInput,
-                                format!("Network connectivity is disabled, but a remote requirements file was requested: {}", requirements_txt.display()),
-                            )),
-                        });
-                    }
-
-                    let client = client_builder.build();
-                    read_url_to_string(&requirements_txt, client).await
+                    read_remote_requirements(requirements_txt, client_builder, cache).await
                 }
             } else {
                 // Ex) `file:///home/ferris/project/requirements.txt`
@@ -212,6 +211,7 @@ impl RequirementsTxt {
             working_dir,
             requirements_dir,
             client_builder,
+            cache,
             requirements_txt,
         )
         .await
@@ -240,6 +240,7 @@ impl RequirementsTxt {
         working_dir: &Path,
         requirements_dir: &Path,
         client_builder: &BaseClientBuilder<'_>,
+        cache: Option<&Cache>,
         requirements_txt: &Path,
     ) -> Result<Self, RequirementsTxtParserError> {
         let mut s = Scanner::new(content);
@@ -256,6 +257,8 @@ impl RequirementsTxt {
                     let sub_file =
                         if filename.starts_with("http://") || filename.starts_with("https://") {
                             PathBuf::from(filename.as_ref())
+                        } else if let Some(base) = remote_url(requirements_txt) {
+                            remote_include(&base, &filename, start, end)?
                         } else if filename.starts_with("file://") {
                             requirements_txt.join(
                                 Url::parse(filename.as_ref())
@@ -276,7 +279,7 @@ impl RequirementsTxt {
                             requirements_dir.join(filename.as_ref())
                         };
                     let sub_requirements =
-                        Box::pin(Self::parse(&sub_file, working_dir, client_builder))
+                        Box::pin(Self::parse(&sub_file, working_dir, client_builder, cache))
                             .await
                             .map_err(|err| RequirementsTxtParserError::Subfile {
                                 source: Box::new(err),
@@ -311,6 +314,8 @@ impl RequirementsTxt {
                     let sub_file =
                         if filename.starts_with("http://") || filename.starts_with("https://") {
                             PathBuf::from(filename.as_ref())
+                        } else if let Some(base) = remote_url(requirements_txt) {
+                            remote_include(&base, &filename, start, end)?
                         } else if filename.starts_with("file://") {
                             requirements_txt.join(
                                 Url::parse(filename.as_ref())
@@ -331,7 +336,7 @@ impl RequirementsTxt {
                             requirements_dir.join(filename.as_ref())
                         };
                     let sub_constraints =
-                        Box::pin(Self::parse(&sub_file, working_dir, client_builder))
+                        Box::pin(Self::parse(&sub_file, working_dir, client_builder, cache))
                             .await
                             .map_err(|err| RequirementsTxtParserError::Subfile {
                                 source: Box::new(err),
@@ -784,22 +789,126 @@ fn parse_value<'a, T>(
     }
 }
 
-/// Fetch the contents of a URL and return them as a string.
+/// Return the URL of a remote `requirements.txt` file, if the path refers to one.
+fn remote_url(requirements_txt: &Path) -> Option<Url> {
+    let path = requirements_txt.to_str()?;
+    if path.starts_with("http://") || path.starts_with("https://") {
+        Url::parse(path).ok()
+    } else {
+        None
+    }
+}
+
+/// Resolve a file included (via `-r` or `-c`) by a remote `requirements.txt` file against the URL
+/// of the including file.
+///
+/// As in `pip`, absolute paths refer to the same host as the including file. Remote files may not
+/// include local files (e.g., via `file://` URLs), such that an index can't read arbitrary files
+/// from the machine that's installing from it.
+fn remote_include(
+    base: &Url,
+    filename: &str,
+    start: usize,
+    end: usize,
+) -> Result<PathBuf, RequirementsTxtParserError> {
+    let url = base
+        .join(filename)
+        .map_err(|err| RequirementsTxtParserError::Url {
+            source: err,
+            url: filename.to_string(),
+            start,
+            end,
+        })?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(RequirementsTxtParserError::LocalInclude {
+            url: filename.to_string(),
+            start,
+            end,
+        });
+    }
+    Ok(PathBuf::from(url.as_str()))
+}
+
+/// Read the contents of a remote `requirements.txt` file.
+///
+/// If a [`Cache`] is provided, the file is stored in the cache, and the cached copy is reused
+/// until it's older than the cache's requirements TTL (or `--refresh` is provided). When network connectivity is disabled, the cached copy is used regardless of its
+/// age.
 #[cfg(feature = "http")]
-async fn read_url_to_string(
-    path: impl AsRef<Path>,
-    client: BaseClient,
+async fn read_remote_requirements(
+    requirements_txt: &Path,
+    client_builder: &BaseClientBuilder<'_>,
+    cache: Option<&Cache>,
 ) -> Result<String, RequirementsTxtParserError> {
     // pip would URL-encode the non-UTF-8 bytes of the string; we just don't support them.
     let path_utf8 =
-        path.as_ref()
+        requirements_txt
             .to_str()
             .ok_or_else(|| RequirementsTxtParserError::NonUnicodeUrl {
-                url: path.as_ref().to_owned(),
+                url: requirements_txt.to_owned(),
             })?;
-
     let url = Url::from_str(path_utf8)
         .map_err(|err| RequirementsTxtParserError::InvalidUrl(path_utf8.to_string(), err))?;
+
+    let entry = cache.map(|cache| {
+        cache.entry(
+            CacheBucket::Requirements,
+            "",
+            format!("{}.txt", cache_digest(&CanonicalUrl::new(&url))),
+        )
+    });
+
+    if let (Some(cache), Some(entry)) = (cache, entry.as_ref()) {
+        if let Ok(metadata) = fs_err::metadata(entry.path()) {
+            let fresh = client_builder.is_offline()
+                || (cache.freshness(entry, None).is_ok_and(Freshness::is_fresh)
+                    && metadata
+                        .modified()
+                        .ok()
+                        .and_then(|modified| modified.elapsed().ok())
+                        .is_some_and(|age| age < cache.requirements_ttl()));
+            if fresh {
+                debug!("Using cached copy of remote requirements file: {url}");
+                return uv_fs::read_to_string_transcode(entry.path())
+                    .await
+                    .map_err(RequirementsTxtParserError::Io);
+            }
+        }
+    }
+
+    // Avoid constructing a client if network is disabled already
+    if client_builder.is_offline() {
+        return Err(RequirementsTxtParserError::Io(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "Network connectivity is disabled, but a remote requirements file was requested: {}",
+                requirements_txt.display()
+            ),
+        )));
+    }
+
+    let client = client_builder.build();
+    let contents = read_url_to_string(&url, client).await?;
+
+    if let Some(entry) = entry {
+        let write = async {
+            fs_err::create_dir_all(entry.dir())?;
+            uv_fs::write_atomic(entry.path(), &contents).await
+        };
+        if let Err(err) = write.await {
+            debug!("Failed to cache remote requirements file {url}: {err}");
+        }
+    }
+
+    Ok(contents)
+}
+
+/// Fetch the contents of a URL and return them as a string.
+#[cfg(feature = "http")]
+async fn read_url_to_string(
+    url: &Url,
+    client: BaseClient,
+) -> Result<String, RequirementsTxtParserError> {
     let response = client
         .for_host(&url)
         .get(url.clone())
@@ -837,6 +946,11 @@ pub enum RequirementsTxtParserError {
         start: usize,
         end: usize,
     },
+    LocalInclude {
+        url: String,
+        start: usize,
+        end: usize,
+    },
     VerbatimUrl {
         source: uv_pep508::VerbatimUrlError,
         url: String,
@@ -911,6 +1025,12 @@ impl Display for RequirementsTxtParserError {
             Self::FileUrl { url, start, .. } => {
                 write!(f, "Invalid file URL at position {start}: `{url}`")
             }
+            Self::LocalInclude { url, start, .. } => {
+                write!(
+                    f,
+                    "Remote requirements files can't include local files, found at position {start}: `{url}`"
+                )
+            }
             Self::VerbatimUrl { url, start, .. } => {
                 write!(f, "Invalid URL at position {start}: `{url}`")
             }
@@ -979,6 +1099,7 @@ impl std::error::Error for RequirementsTxtParserError {
             Self::Io(err) => err.source(),
             Self::Url { source, .. } => Some(source),
             Self::FileUrl { .. } => None,
+            Self::LocalInclude { .. } => None,
             Self::VerbatimUrl { source, .. } => Some(source),
             Self::UrlConversion(_) => None,
             Self::UnsupportedUrl(_) => None,
@@ -1019,6 +1140,13 @@ impl Display for RequirementsTxtFileError {
                     self.file.user_display(),
                 )
             }
+            RequirementsTxtParserError::LocalInclude { url, start, .. } => {
+                write!(
+                    f,
+                    "Remote requirements file `{}` can't include local files, found at position {start}: `{url}`",
+                    self.file.user_display(),
+                )
+            }
             RequirementsTxtParserError::VerbatimUrl { url, start, .. } => {
                 write!(
                     f,
@@ -1251,6 +1379,7 @@ mod test {
             requirements_txt.clone(),
             &working_dir,
             &BaseClientBuilder::new(),
+            None,
         )
         .await
         .unwrap();
@@ -1299,10 +1428,14 @@ mod test {
         let requirements_txt = temp_dir.path().join(path);
         fs::write(&requirements_txt, contents).unwrap();
 
-        let actual =
-            RequirementsTxt::parse(&requirements_txt, &working_dir, &BaseClientBuilder::new())
-                .await
-                .unwrap();
+        let actual = RequirementsTxt::parse(
+            &requirements_txt,
+            &working_dir,
+            &BaseClientBuilder::new(),
+            None,
+        )
+        .await
+        .unwrap();
 
         let snapshot = format!("line-endings-{}", path.to_string_lossy());
 
@@ -1321,10 +1454,14 @@ mod test {
         let working_dir = workspace_test_data_dir().join("requirements-txt");
         let requirements_txt = working_dir.join(path);
 
-        let actual =
-            RequirementsTxt::parse(requirements_txt, &working_dir, &BaseClientBuilder::new())
-                .await
-                .unwrap();
+        let actual = RequirementsTxt::parse(
+            requirements_txt,
+            &working_dir,
+            &BaseClientBuilder::new(),
+            None,
+        )
+        .await
+        .unwrap();
 
         let snapshot = format!("parse-unix-{}", path.to_string_lossy());
 
@@ -1343,10 +1480,14 @@ mod test {
         let working_dir = workspace_test_data_dir().join("requirements-txt");
         let requirements_txt = working_dir.join(path);
 
-        let actual =
-            RequirementsTxt::parse(requirements_txt, &working_dir, &BaseClientBuilder::new())
-                .await
-                .unwrap_err();
+        let actual = RequirementsTxt::parse(
+            requirements_txt,
+            &working_dir,
+            &BaseClientBuilder::new(),
+            None,
+        )
+        .await
+        .unwrap_err();
 
         let snapshot = format!("parse-unix-{}", path.to_string_lossy());
 
@@ -1365,10 +1506,14 @@ mod test {
         let working_dir = workspace_test_data_dir().join("requirements-txt");
         let requirements_txt = working_dir.join(path);
 
-        let actual =
-            RequirementsTxt::parse(requirements_txt, &working_dir, &BaseClientBuilder::new())
-                .await
-                .unwrap();
+        let actual = RequirementsTxt::parse(
+            requirements_txt,
+            &working_dir,
+            &BaseClientBuilder::new(),
+            None,
+        )
+        .await
+        .unwrap();
 
         let snapshot = format!("parse-windows-{}", path.to_string_lossy());
 
@@ -1392,6 +1537,7 @@ mod test {
             requirements_txt.path(),
             temp_dir.path(),
             &BaseClientBuilder::new(),
+            None,
         )
         .await
         .unwrap_err();
@@ -1431,6 +1577,7 @@ mod test {
             requirements_txt.path(),
             temp_dir.path(),
             &BaseClientBuilder::new(),
+            None,
         )
         .await
         .unwrap_err();
@@ -1464,6 +1611,7 @@ mod test {
             requirements_txt.path(),
             temp_dir.path(),
             &BaseClientBuilder::new(),
+            None,
         )
         .await
         .unwrap_err();
@@ -1497,6 +1645,7 @@ mod test {
             requirements_txt.path(),
             temp_dir.path(),
             &BaseClientBuilder::new(),
+            None,
         )
         .await
         .unwrap_err();
@@ -1509,7 +1658,7 @@ mod test {
         }, {
             insta::assert_snapshot!(errors, @r###"
             Couldn't parse requirement in `<REQUIREMENTS_TXT>` at position 3
-            Expected direct URL (`https://localhost:8080/`) to end in a supported file extension: `.whl`, `.tar.gz`, `.zip`, `.tar.bz2`, `.tar.lz`, `.tar.lzma`, `.tar.xz`, `.tar.zst`, `.tar`, `.tbz`, `.tgz`, `.tlz`, or `.txz`
+            Expected direct URL (`https://localhost:8080/`) to end in a supported file extension: `.whl`, `.tar.gz`, `.zip`, `.tar.bz2`, `.tar.lz`, `.tar.lzma`, `.tar.xz`, `.tar.zst`, `.tar`, `.tbz`, `.tgz`, `.tlz`, `.txz`, or `.tzst`
             https://localhost:8080/
             ^^^^^^^^^^^^^^^^^^^^^^^
             "###);
@@ -1530,6 +1679,7 @@ mod test {
             requirements_txt.path(),
             temp_dir.path(),
             &BaseClientBuilder::new(),
+            None,
         )
         .await
         .unwrap_err();
@@ -1561,6 +1711,7 @@ mod test {
             requirements_txt.path(),
             temp_dir.path(),
             &BaseClientBuilder::new(),
+            None,
         )
         .await
         .unwrap_err();
@@ -1594,6 +1745,7 @@ mod test {
             requirements_txt.path(),
             temp_dir.path(),
             &BaseClientBuilder::new(),
+            None,
         )
         .await
         .unwrap_err();
@@ -1625,6 +1777,7 @@ mod test {
             requirements_txt.path(),
             temp_dir.path(),
             &BaseClientBuilder::new(),
+            None,
         )
         .await
         .unwrap_err();
@@ -1661,6 +1814,7 @@ mod test {
             requirements_txt.path(),
             temp_dir.path(),
             &BaseClientBuilder::new(),
+            None,
         )
         .await
         .unwrap_err();
@@ -1704,6 +1858,7 @@ mod test {
             parent_txt.path(),
             temp_dir.path(),
             &BaseClientBuilder::new(),
+            None,
         )
         .await
         .unwrap();
@@ -1768,6 +1923,7 @@ mod test {
             requirements_txt.path(),
             temp_dir.path(),
             &BaseClientBuilder::new(),
+            None,
         )
         .await
         .unwrap();
@@ -1818,6 +1974,173 @@ mod test {
         Ok(())
     }
 
+    /// Remote files are read from the cache when offline, with nested includes resolved against
+    /// the URL of the including file.
+    #[cfg(feature = "http")]
+    #[tokio::test]
+    async fn remote_cached() -> Result<()> {
+        use url::Url;
+        use uv_cache::{Cache, CacheBucket};
+        use uv_cache_key::{cache_digest, CanonicalUrl};
+        use uv_client::Connectivity;
+
+        let temp_dir = assert_fs::TempDir::new()?;
+        let cache = Cache::from_path(temp_dir.child("cache").path()).init()?;
+
+        for (url, contents) in [
+            (
+                "https://example.com/requirements/base.txt",
+                "flask\n-c ../constraints.txt\n",
+            ),
+            ("https://example.com/constraints.txt", "flask<3\n"),
+        ] {
+            let entry = cache.entry(
+                CacheBucket::Requirements,
+                "",
+                format!(
+                    "{}.txt",
+                    cache_digest(&CanonicalUrl::new(&Url::parse(url)?))
+                ),
+            );
+            fs::create_dir_all(entry.dir())?;
+            fs::write(entry.path(), contents)?;
+        }
+
+        let requirements = RequirementsTxt::parse(
+            "https://example.com/requirements/base.txt",
+            temp_dir.path(),
+            &BaseClientBuilder::new().connectivity(Connectivity::Offline),
+            Some(&cache),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(requirements.requirements.len(), 1);
+        assert_eq!(requirements.constraints.len(), 1);
+        assert_eq!(requirements.constraints[0].to_string(), "flask<3");
+
+        // Without a cache, the remote file can't be read while offline.
+        let err = RequirementsTxt::parse(
+            "https://example.com/requirements/base.txt",
+            temp_dir.path(),
+            &BaseClientBuilder::new().connectivity(Connectivity::Offline),
+            None,
+        )
+        .await
+        .unwrap_err();
+        assert!(err.to_string().contains("Network connectivity is disabled"));
+
+        Ok(())
+    }
+
+    /// Remote files are written to the cache, and reused until they're older than the TTL or the
+    /// cache is refreshed.
+    #[cfg(feature = "http")]
+    #[tokio::test]
+    async fn remote_ttl() -> Result<()> {
+        use std::time::{Duration, SystemTime};
+
+        use uv_cache::{Cache, CacheBucket, Refresh};
+        use uv_cache_info::Timestamp;
+        use uv_cache_key::{cache_digest, CanonicalUrl};
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/requirements.txt"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("flask\n"))
+            .mount(&server)
+            .await;
+        let url = format!("{}/requirements.txt", server.uri());
+
+        let temp_dir = assert_fs::TempDir::new()?;
+        let cache = Cache::from_path(temp_dir.child("cache").path())
+            .init()?
+            .with_requirements_ttl(Duration::from_secs(60 * 60));
+        let entry = cache.entry(
+            CacheBucket::Requirements,
+            "",
+            format!(
+                "{}.txt",
+                cache_digest(&CanonicalUrl::new(&url::Url::parse(&url)?))
+            ),
+        );
+        let parse = |cache: Cache| {
+            let url = url.clone();
+            let working_dir = temp_dir.path().to_path_buf();
+            async move {
+                RequirementsTxt::parse(url, working_dir, &BaseClientBuilder::new(), Some(&cache))
+                    .await
+            }
+        };
+
+        // The first read fetches the file, and writes it to the cache.
+        let requirements = parse(cache.clone()).await?;
+        assert_eq!(requirements.requirements.len(), 1);
+        assert_eq!(server.received_requests().await.unwrap().len(), 1);
+        assert_eq!(fs::read_to_string(entry.path())?, "flask\n");
+
+        // Within the TTL, the cached copy is reused.
+        parse(cache.clone()).await?;
+        assert_eq!(server.received_requests().await.unwrap().len(), 1);
+
+        // Refreshing the cache fetches the file again.
+        parse(cache.clone().with_refresh(Refresh::All(Timestamp::now()))).await?;
+        assert_eq!(server.received_requests().await.unwrap().len(), 2);
+
+        // Once the cached copy is older than the TTL, the file is fetched again.
+        std::fs::File::options()
+            .write(true)
+            .open(entry.path())?
+            .set_modified(SystemTime::now() - Duration::from_secs(2 * 60 * 60))?;
+        parse(cache.clone()).await?;
+        assert_eq!(server.received_requests().await.unwrap().len(), 3);
+
+        // Without a TTL, the file is fetched on every read.
+        parse(cache.with_requirements_ttl(Duration::ZERO)).await?;
+        assert_eq!(server.received_requests().await.unwrap().len(), 4);
+
+        Ok(())
+    }
+
+    /// Remote files may include other remote files, but not local files.
+    #[cfg(feature = "http")]
+    #[tokio::test]
+    async fn remote_local_include() -> Result<()> {
+        use url::Url;
+        use uv_cache::{Cache, CacheBucket};
+        use uv_cache_key::{cache_digest, CanonicalUrl};
+        use uv_client::Connectivity;
+
+        let temp_dir = assert_fs::TempDir::new()?;
+        let cache = Cache::from_path(temp_dir.child("cache").path()).init()?;
+
+        let url = "https://example.com/requirements.txt";
+        let entry = cache.entry(
+            CacheBucket::Requirements,
+            "",
+            format!(
+                "{}.txt",
+                cache_digest(&CanonicalUrl::new(&Url::parse(url)?))
+            ),
+        );
+        fs::create_dir_all(entry.dir())?;
+        fs::write(entry.path(), "-r file:///etc/passwd\n")?;
+
+        let err = RequirementsTxt::parse(
+            url,
+            temp_dir.path(),
+            &BaseClientBuilder::new().connectivity(Connectivity::Offline),
+            Some(&cache),
+        )
+        .await
+        .unwrap_err();
+        assert!(err.to_string().contains("can't include local files"));
+
+        Ok(())
+    }
+
     #[tokio::test]
     #[cfg(not(windows))]
     async fn nested_editable() -> Result<()> {
@@ -1843,6 +2166,7 @@ mod test {
             requirements_txt.path(),
             temp_dir.path(),
             &BaseClientBuilder::new(),
+            None,
         )
         .await
         .unwrap();
@@ -1943,6 +2267,7 @@ mod test {
             requirements_txt.path(),
             temp_dir.path(),
             &BaseClientBuilder::new(),
+            None,
         )
         .await
         .unwrap_err();
@@ -1991,6 +2316,7 @@ mod test {
             requirements_txt.path(),
             temp_dir.path(),
             &BaseClientBuilder::new(),
+            None,
         )
         .await
         .unwrap();
@@ -2206,6 +2532,7 @@ mod test {
             requirements_txt.path(),
             temp_dir.path(),
             &BaseClientBuilder::new(),
+            None,
         )
         .await
         .unwrap();
@@ -2548,6 +2875,7 @@ mod test {
             requirements_txt.path(),
             temp_dir.path(),
             &BaseClientBuilder::new(),
+            None,
         )
         .await
         .unwrap_err();
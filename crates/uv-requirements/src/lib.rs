@@ -1,5 +1,6 @@
-use uv_distribution_types::{BuiltDist, Dist, GitSourceDist, SourceDist};
+use uv_distribution_types::{BuiltDist, Dist, GitSourceDist, HgSourceDist, SourceDist};
 use uv_git::GitUrl;
+use uv_hg::HgUrl;
 use uv_pypi_types::{Requirement, RequirementSource};
 
 pub use crate::extras::*;
@@ -75,6 +76,25 @@ pub(crate) fn required_dist(
                 url: url.clone(),
             }))
         }
+        RequirementSource::Hg {
+            repository,
+            reference,
+            precise,
+            subdirectory,
+            url,
+        } => {
+            let hg_url = if let Some(precise) = precise {
+                HgUrl::from_node(repository.clone(), reference.clone(), *precise)
+            } else {
+                HgUrl::from_reference(repository.clone(), reference.clone())
+            };
+            Dist::Source(SourceDist::Hg(HgSourceDist {
+                name: requirement.name.clone(),
+                hg: Box::new(hg_url),
+                subdirectory: subdirectory.clone(),
+                url: url.clone(),
+            }))
+        }
         RequirementSource::Path {
             install_path,
             ext,
@@ -177,7 +177,7 @@ impl<'a, Context: BuildContext> SourceTreeResolver<'a, Context> {
         // manual match.
         let hashes = match self.hasher {
             HashStrategy::None => HashPolicy::None,
-            HashStrategy::Generate => HashPolicy::Generate,
+            HashStrategy::Generate(_) => HashPolicy::Generate,
             HashStrategy::Verify(_) => HashPolicy::Generate,
             HashStrategy::Require(_) => {
                 return Err(anyhow::anyhow!(
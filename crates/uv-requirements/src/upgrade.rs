@@ -6,6 +6,7 @@ use uv_client::{BaseClientBuilder, Connectivity};
 use uv_configuration::Upgrade;
 use uv_fs::CWD;
 use uv_git::ResolvedRepositoryReference;
+use uv_hg::ResolvedHgRepositoryReference;
 use uv_requirements_txt::RequirementsTxt;
 use uv_resolver::{Lock, Preference, PreferenceError};
 
@@ -15,6 +16,8 @@ pub struct LockedRequirements {
     pub preferences: Vec<Preference>,
     /// The pinned Git SHAs from the lockfile.
     pub git: Vec<ResolvedRepositoryReference>,
+    /// The pinned Mercurial node IDs from the lockfile.
+    pub hg: Vec<ResolvedHgRepositoryReference>,
 }
 
 /// Load the preferred requirements from an existing `requirements.txt`, applying the upgrade strategy.
@@ -37,6 +40,7 @@ pub async fn read_requirements_txt(
         output_file,
         &*CWD,
         &BaseClientBuilder::new().connectivity(Connectivity::Offline),
+        None,
     )
     .await?;
 
@@ -66,6 +70,7 @@ pub async fn read_requirements_txt(
 pub fn read_lock_requirements(lock: &Lock, upgrade: &Upgrade) -> LockedRequirements {
     let mut preferences = Vec::new();
     let mut git = Vec::new();
+    let mut hg = Vec::new();
 
     for package in lock.packages() {
         // Skip the distribution if it's not included in the upgrade strategy.
@@ -80,7 +85,16 @@ pub fn read_lock_requirements(lock: &Lock, upgrade: &Upgrade) -> LockedRequireme
         if let Some(git_ref) = package.as_git_ref() {
             git.push(git_ref);
         }
+
+        // Map each entry in the lockfile to a Mercurial node ID.
+        if let Some(hg_ref) = package.as_hg_ref() {
+            hg.push(hg_ref);
+        }
     }
 
-    LockedRequirements { preferences, git }
+    LockedRequirements {
+        preferences,
+        git,
+        hg,
+    }
 }
@@ -7,7 +7,7 @@ use uv_configuration::IndexStrategy;
 use uv_distribution_types::{CompatibleDist, IncompatibleDist, IncompatibleSource};
 use uv_distribution_types::{DistributionMetadata, IncompatibleWheel, Name, PrioritizedDist};
 use uv_normalize::PackageName;
-use uv_pep440::Version;
+use uv_pep440::{LocalSegment, Version};
 use uv_pep508::MarkerTree;
 use uv_types::InstalledPackagesProvider;
 
@@ -23,6 +23,10 @@ pub(crate) struct CandidateSelector {
     resolution_strategy: ResolutionStrategy,
     prerelease_strategy: PrereleaseStrategy,
     index_strategy: IndexStrategy,
+    /// Whether to select the nearest version to a preference that doesn't satisfy the range.
+    minimal_changes: bool,
+    /// The local version label to prefer, e.g., `acme` to prefer `1.0.0+acme` over `1.0.1`.
+    prefer_local_version: Option<String>,
 }
 
 impl CandidateSelector {
@@ -46,6 +50,11 @@ impl CandidateSelector {
                 options.dependency_mode,
             ),
             index_strategy: options.index_strategy,
+            minimal_changes: options.minimal_changes,
+            prefer_local_version: manifest
+                .prefer_local_version
+                .as_deref()
+                .map(|label| label.trim_start_matches('+').to_ascii_lowercase()),
         }
     }
 
@@ -99,6 +108,36 @@ impl CandidateSelector {
             return Some(preferred);
         }
 
+        // If the preferences don't satisfy the range, select the version nearest to them, to
+        // minimize the changes to the existing resolution.
+        if self.minimal_changes {
+            if let Some(nearest) =
+                self.get_nearest_preferred(package_name, range, version_maps, preferences, markers)
+            {
+                trace!(
+                    "Using nearest version to preference {} {}",
+                    nearest.name,
+                    nearest.version
+                );
+                return Some(nearest);
+            }
+        }
+
+        // Prefer versions with the configured local version label, e.g., the patched releases of an
+        // internal fork, over any other version that satisfies the range.
+        if let Some(label) = self.prefer_local_version.as_deref() {
+            if let Some(local) =
+                self.get_local_preferred(package_name, range, version_maps, label, markers)
+            {
+                trace!(
+                    "Using version with local label `+{label}` {} {}",
+                    local.name,
+                    local.version
+                );
+                return Some(local);
+            }
+        }
+
         // Check for a locally installed distribution that satisfies the range and is allowed.
         if !is_excluded {
             if let Some(installed) = Self::get_installed(package_name, range, installed_packages) {
@@ -278,6 +317,102 @@ impl CandidateSelector {
         None
     }
 
+    /// If the package has a preference that doesn't satisfy the current range, select the
+    /// compatible version nearest to it: the lowest version above the preference, or, if there is
+    /// none, the highest version below it.
+    fn get_nearest_preferred<'a>(
+        &'a self,
+        package_name: &'a PackageName,
+        range: &Range<Version>,
+        version_maps: &'a [VersionMap],
+        preferences: &'a Preferences,
+        markers: &ResolverMarkers,
+    ) -> Option<Candidate<'a>> {
+        // As in `get_preferred`, prefer the preference for the current environment or fork, but fall
+        // back to any other.
+        let is_match = |marker: Option<&MarkerTree>| match markers {
+            ResolverMarkers::SpecificEnvironment(env) => {
+                marker.map_or(true, |marker| marker.evaluate(env, &[]))
+            }
+            ResolverMarkers::Universal { .. } => true,
+            ResolverMarkers::Fork(fork_markers) => {
+                marker.map_or(true, |marker| !marker.is_disjoint(fork_markers))
+            }
+        };
+        let (_, preferred) = preferences
+            .get(package_name)
+            .find(|(marker, _)| is_match(*marker))
+            .or_else(|| preferences.get(package_name).next())?;
+
+        let allow_prerelease = match self.prerelease_strategy.allows(package_name, markers) {
+            AllowPrerelease::Yes => true,
+            AllowPrerelease::No => false,
+            // Allow pre-releases if there are no stable versions available.
+            AllowPrerelease::IfNecessary => !version_maps.iter().any(VersionMap::stable),
+        };
+
+        let above = range.intersection(&Range::higher_than(preferred.clone()));
+        if let Some(candidate) = version_maps.iter().find_map(|version_map| {
+            Self::select_candidate(
+                version_map.iter(&above),
+                package_name,
+                &above,
+                allow_prerelease,
+            )
+        }) {
+            return Some(candidate);
+        }
+
+        let below = range.intersection(&Range::strictly_lower_than(preferred.clone()));
+        version_maps.iter().find_map(|version_map| {
+            Self::select_candidate(
+                version_map.iter(&below).rev(),
+                package_name,
+                &below,
+                allow_prerelease,
+            )
+        })
+    }
+
+    /// Select the highest version that carries the given local version label and satisfies the
+    /// range, across all indexes.
+    fn get_local_preferred<'a>(
+        &'a self,
+        package_name: &'a PackageName,
+        range: &Range<Version>,
+        version_maps: &'a [VersionMap],
+        label: &str,
+        markers: &ResolverMarkers,
+    ) -> Option<Candidate<'a>> {
+        let allow_prerelease = match self.prerelease_strategy.allows(package_name, markers) {
+            AllowPrerelease::Yes => true,
+            AllowPrerelease::No => false,
+            // Allow pre-releases if there are no stable versions available.
+            AllowPrerelease::IfNecessary => !version_maps.iter().any(VersionMap::stable),
+        };
+
+        version_maps
+            .iter()
+            .filter_map(|version_map| {
+                Self::select_candidate(
+                    version_map
+                        .iter(range)
+                        .rev()
+                        .filter(|(version, _)| has_local_label(version, label)),
+                    package_name,
+                    range,
+                    allow_prerelease,
+                )
+            })
+            .reduce(|best, candidate| {
+                if candidate.version > best.version {
+                    candidate
+                } else {
+                    best
+                }
+            })
+    }
+
     /// Check for an installed distribution that satisfies the current range and is allowed.
     fn get_installed<'a, InstalledPackages: InstalledPackagesProvider>(
         package_name: &'a PackageName,
@@ -496,6 +631,12 @@ impl<'a> From<&'a PrioritizedDist> for CandidateDist<'a> {
     }
 }
 
+/// Returns `true` if the local version of the given [`Version`] starts with the given label, e.g.,
+/// `1.0.0+acme` and `1.0.0+acme.2` for `acme`.
+fn has_local_label(version: &Version, label: &str) -> bool {
+    matches!(version.local().first(), Some(LocalSegment::String(segment)) if segment == label)
+}
+
 /// The reason why we selected the version of the candidate version, either a preference or being
 /// compatible.
 #[derive(Debug, Clone, Copy)]
@@ -0,0 +1,91 @@
+use pubgrub::Ranges;
+
+use uv_cache_key::CanonicalUrl;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_pep508::{Requirement, VersionOrUrl};
+use uv_pypi_types::VerbatimParsedUrl;
+use uv_workspace::pyproject::ConflictItem;
+
+/// A pair of extras or dependency groups that declare incompatible requirements on the same
+/// package, and so can't be resolved together.
+#[derive(Debug, Clone)]
+pub struct Conflict {
+    /// The package that the conflicting requirements apply to.
+    pub package: PackageName,
+    /// The first extra or group, along with its requirement on the package.
+    pub left: (ConflictItem, Requirement<VerbatimParsedUrl>),
+    /// The second extra or group, along with its requirement on the package.
+    pub right: (ConflictItem, Requirement<VerbatimParsedUrl>),
+}
+
+impl Conflict {
+    /// Identify any pairs of extras or dependency groups that declare incompatible requirements.
+    ///
+    /// Two requirements are incompatible if they refer to the same package, apply to at least one
+    /// common environment, and either declare version specifiers that can't be satisfied together
+    /// (e.g., `torch==2.1.0` in a `cpu` extra and `torch==2.2.0` in a `gpu` extra) or point to
+    /// different URLs.
+    ///
+    /// Pairs that are declared as conflicting (i.e., appear in the same set in `declared`) are
+    /// skipped. At most one conflict is reported for each pair of extras or groups.
+    pub fn find(
+        items: &[(ConflictItem, Vec<Requirement<VerbatimParsedUrl>>)],
+        declared: &[Vec<ConflictItem>],
+    ) -> Vec<Self> {
+        let mut conflicts = Vec::new();
+        for (index, (left_item, left_requirements)) in items.iter().enumerate() {
+            for (right_item, right_requirements) in &items[index + 1..] {
+                if declared
+                    .iter()
+                    .any(|set| set.contains(left_item) && set.contains(right_item))
+                {
+                    continue;
+                }
+                if let Some((left, right)) = left_requirements.iter().find_map(|left| {
+                    right_requirements
+                        .iter()
+                        .find(|right| is_incompatible(left, right))
+                        .map(|right| (left, right))
+                }) {
+                    conflicts.push(Self {
+                        package: left.name.clone(),
+                        left: (left_item.clone(), left.clone()),
+                        right: (right_item.clone(), right.clone()),
+                    });
+                }
+            }
+        }
+        conflicts
+    }
+}
+
+/// Returns `true` if the two requirements can't be satisfied in the same resolution.
+fn is_incompatible(
+    left: &Requirement<VerbatimParsedUrl>,
+    right: &Requirement<VerbatimParsedUrl>,
+) -> bool {
+    if left.name != right.name {
+        return false;
+    }
+
+    // If the requirements apply to disjoint environments, the resolver can satisfy both.
+    if left.marker.is_disjoint(&right.marker) {
+        return false;
+    }
+
+    match (left.version_or_url.as_ref(), right.version_or_url.as_ref()) {
+        (
+            Some(VersionOrUrl::VersionSpecifier(left)),
+            Some(VersionOrUrl::VersionSpecifier(right)),
+        ) => {
+            let left = Ranges::<Version>::from(left.clone());
+            let right = Ranges::<Version>::from(right.clone());
+            left.is_disjoint(&right)
+        }
+        (Some(VersionOrUrl::Url(left)), Some(VersionOrUrl::Url(right))) => {
+            CanonicalUrl::new(left.verbatim.raw()) != CanonicalUrl::new(right.verbatim.raw())
+        }
+        _ => false,
+    }
+}
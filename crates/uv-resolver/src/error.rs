@@ -12,7 +12,9 @@ use crate::fork_urls::ForkUrls;
 use crate::pubgrub::{PubGrubPackage, PubGrubPackageInner, PubGrubReportFormatter};
 use crate::python_requirement::PythonRequirement;
 use crate::resolution::ConflictingDistributionError;
-use crate::resolver::{IncompletePackage, ResolverMarkers, UnavailablePackage, UnavailableReason};
+use crate::resolver::{
+    BudgetExceeded, IncompletePackage, ResolverMarkers, UnavailablePackage, UnavailableReason,
+};
 use crate::Options;
 use tracing::trace;
 use uv_distribution_types::{
@@ -91,6 +93,9 @@ pub enum ResolveError {
     #[error(transparent)]
     NoSolution(#[from] NoSolutionError),
 
+    #[error(transparent)]
+    BudgetExceeded(Box<BudgetExceeded>),
+
     #[error("Attempted to construct an invalid version specifier")]
     InvalidVersion(#[from] uv_pep440::VersionSpecifierBuildError),
 
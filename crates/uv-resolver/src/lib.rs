@@ -1,11 +1,13 @@
+pub use conflicts::Conflict;
 pub use dependency_mode::DependencyMode;
 pub use error::{NoSolutionError, NoSolutionHeader, ResolveError};
 pub use exclude_newer::ExcludeNewer;
 pub use exclusions::Exclusions;
 pub use flat_index::{FlatDistributions, FlatIndex};
 pub use lock::{
-    Lock, LockError, LockVersion, RequirementsTxtExport, ResolverManifest, SatisfiesResult,
-    TreeDisplay, VERSION,
+    GraphExport, GraphFormat, Lock, LockError, LockVersion, LockfileFormat, Package,
+    RequirementsTxtExport, ResolverManifest, SatisfiesResult, TreeDisplay, UnsatisfiedEnvironment,
+    DIFFABLE_VERSION, VERSION,
 };
 pub use manifest::Manifest;
 pub use options::{Flexibility, Options, OptionsBuilder};
@@ -14,19 +16,21 @@ pub use prerelease::PrereleaseMode;
 pub use python_requirement::PythonRequirement;
 pub use requires_python::{RequiresPython, RequiresPythonRange};
 pub use resolution::{
-    AnnotationStyle, ConflictingDistributionError, DisplayResolutionGraph, ResolutionGraph,
+    AnnotationStyle, ConflictingDistributionError, DisplayResolutionGraph, InstallationReport,
+    InstallationReportError, ResolutionGraph,
 };
 pub use resolution_mode::ResolutionMode;
 pub use resolver::{
-    BuildId, DefaultResolverProvider, InMemoryIndex, MetadataResponse, PackageVersionsResult,
-    Reporter as ResolverReporter, Resolver, ResolverMarkers, ResolverProvider, VersionsResponse,
-    WheelMetadataResult,
+    BudgetExceeded, BuildId, DefaultResolverProvider, InMemoryIndex, MetadataResponse,
+    PackageVersionsResult, Reporter as ResolverReporter, ResolutionBudget, Resolver,
+    ResolverMarkers, ResolverProvider, VersionsResponse, WheelMetadataResult,
 };
 pub use version_map::VersionMap;
-pub use yanks::AllowedYanks;
+pub use yanks::{AllowedYanks, YankedMode};
 
 mod bare;
 mod candidate_selector;
+mod conflicts;
 
 mod dependency_mode;
 mod dependency_provider;
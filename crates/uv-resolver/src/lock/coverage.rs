@@ -0,0 +1,286 @@
+use std::collections::VecDeque;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::LazyLock;
+
+use rustc_hash::FxHashSet;
+
+use uv_distribution_filename::WheelFilename;
+use uv_pep508::MarkerTree;
+
+use crate::lock::{Package, PackageId, Source, LINUX_MARKERS, MAC_MARKERS, WINDOWS_MARKERS};
+use crate::Lock;
+
+/// The environments to check when the lockfile doesn't declare any supported environments.
+static DEFAULT_ENVIRONMENTS: LazyLock<[MarkerTree; 3]> = LazyLock::new(|| {
+    [
+        MarkerTree::from_str("sys_platform == 'linux'").unwrap(),
+        MarkerTree::from_str("sys_platform == 'darwin'").unwrap(),
+        MarkerTree::from_str("sys_platform == 'win32'").unwrap(),
+    ]
+});
+
+/// An environment in which the lockfile can't be installed, along with the packages that lack a
+/// compatible wheel or source distribution.
+#[derive(Debug)]
+pub struct UnsatisfiedEnvironment<'lock> {
+    /// The environment markers, simplified with respect to the lockfile's `requires-python`.
+    pub environment: MarkerTree,
+    /// The packages that can't be installed in the environment.
+    pub packages: Vec<&'lock Package>,
+}
+
+impl Lock {
+    /// Identify the resolution environments in which the lockfile can't be installed.
+    ///
+    /// For each supported environment (or, if none are declared, for each of Linux, macOS, and
+    /// Windows), the packages reachable from the workspace members are required to include either a
+    /// buildable source (e.g., a source distribution) or a wheel whose tags are compatible with the
+    /// environment's operating system, architecture, and Python version.
+    pub fn unsatisfied_environments(&self) -> Vec<UnsatisfiedEnvironment<'_>> {
+        let environments = if self.supported_environments.is_empty() {
+            DEFAULT_ENVIRONMENTS
+                .iter()
+                .cloned()
+                .map(|mut marker| {
+                    marker.and(self.requires_python.to_marker_tree());
+                    marker
+                })
+                .collect()
+        } else {
+            self.supported_environments.clone()
+        };
+
+        environments
+            .into_iter()
+            .filter_map(|environment| {
+                let packages = self
+                    .reachable(&environment)
+                    .into_iter()
+                    .filter(|package| !package.is_installable_in(&environment))
+                    .collect::<Vec<_>>();
+                if packages.is_empty() {
+                    None
+                } else {
+                    Some(UnsatisfiedEnvironment {
+                        environment: self.simplify_environment(environment),
+                        packages,
+                    })
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the packages that may be installed in the given environment, in lockfile order.
+    fn reachable(&self, environment: &MarkerTree) -> Vec<&Package> {
+        let mut seen = FxHashSet::default();
+        let mut queue: VecDeque<&PackageId> = VecDeque::new();
+
+        // Identify the workspace members.
+        //
+        // The members are encoded directly in the lockfile, unless the workspace contains a
+        // single member at the root, in which case, we identify it by its source.
+        for package in &self.packages {
+            let is_member = if self.members().is_empty() {
+                matches!(
+                    &package.id.source,
+                    Source::Editable(path) | Source::Virtual(path) if path == Path::new("")
+                )
+            } else {
+                self.members().contains(&package.id.name)
+            };
+            if is_member && seen.insert(&package.id) {
+                queue.push_back(&package.id);
+            }
+        }
+
+        // Add any requirements that are exclusive to the workspace root (e.g., dependency groups
+        // in non-project workspace roots).
+        for requirement in &self.manifest.requirements {
+            if requirement.marker.is_disjoint(environment) {
+                continue;
+            }
+            for package in &self.packages {
+                if package.id.name == requirement.name
+                    && is_possible(&package.fork_markers, environment)
+                    && seen.insert(&package.id)
+                {
+                    queue.push_back(&package.id);
+                }
+            }
+        }
+
+        while let Some(id) = queue.pop_front() {
+            let package = self.find_by_id(id);
+            let dependencies = package
+                .dependencies
+                .iter()
+                .chain(package.optional_dependencies.values().flatten())
+                .chain(package.dependency_groups.values().flatten());
+            for dependency in dependencies {
+                if dependency.complexified_marker.is_disjoint(environment) {
+                    continue;
+                }
+                if seen.insert(&dependency.package_id) {
+                    queue.push_back(&dependency.package_id);
+                }
+            }
+        }
+
+        self.packages
+            .iter()
+            .filter(|package| seen.contains(&package.id))
+            .collect()
+    }
+}
+
+impl Package {
+    /// Returns `true` if the package can be installed in the given environment, either by building
+    /// it from source or by installing a compatible wheel.
+    fn is_installable_in(&self, environment: &MarkerTree) -> bool {
+        // Local directories, and Git and Mercurial repositories, can always be built from source.
+        if matches!(
+            self.id.source,
+            Source::Git(..)
+                | Source::Hg(..)
+                | Source::Directory(..)
+                | Source::Editable(..)
+                | Source::Virtual(..)
+        ) {
+            return true;
+        }
+
+        if self.sdist.is_some() {
+            return true;
+        }
+
+        self.wheels
+            .iter()
+            .any(|wheel| !implied_markers(&wheel.filename).is_disjoint(environment))
+    }
+}
+
+/// Returns the markers implied by the tags of a wheel, i.e., the environments in which the wheel
+/// may be installed, with respect to the operating system, architecture, and Python version.
+///
+/// Unknown tags are assumed to be compatible with any environment.
+fn implied_markers(filename: &WheelFilename) -> MarkerTree {
+    let abi3 = filename.abi_tag.iter().any(|tag| tag == "abi3");
+
+    let mut python = MarkerTree::FALSE;
+    for tag in &filename.python_tag {
+        python.or(python_tag_markers(tag, abi3));
+    }
+
+    let mut platform = MarkerTree::FALSE;
+    for tag in &filename.platform_tag {
+        platform.or(platform_tag_markers(tag));
+    }
+
+    python.and(platform);
+    python
+}
+
+/// Returns the markers implied by a Python tag (e.g., `cp312`, `pp310`, or `py3`).
+fn python_tag_markers(tag: &str, abi3: bool) -> MarkerTree {
+    if tag.starts_with("py2") || tag.starts_with("cp2") || tag.starts_with("pp2") {
+        return MarkerTree::FALSE;
+    }
+
+    // Ex) `py3`, `py38`
+    if let Some(minor) = tag.strip_prefix("py3") {
+        return match minor.parse::<u64>() {
+            Ok(minor) => parse_markers(&format!("python_version >= '3.{minor}'")),
+            Err(_) => MarkerTree::TRUE,
+        };
+    }
+
+    // Ex) `cp312`, `pp310`
+    let (implementation, minor) = if let Some(minor) = tag.strip_prefix("cp3") {
+        ("cpython", minor)
+    } else if let Some(minor) = tag.strip_prefix("pp3") {
+        ("pypy", minor)
+    } else {
+        return MarkerTree::TRUE;
+    };
+    let Ok(minor) = minor.parse::<u64>() else {
+        return MarkerTree::TRUE;
+    };
+    if abi3 {
+        parse_markers(&format!(
+            "implementation_name == '{implementation}' and python_version >= '3.{minor}'"
+        ))
+    } else {
+        parse_markers(&format!(
+            "implementation_name == '{implementation}' and python_version == '3.{minor}'"
+        ))
+    }
+}
+
+/// Returns the markers implied by a platform tag (e.g., `manylinux_2_17_x86_64`), including the
+/// architecture, where known.
+fn platform_tag_markers(tag: &str) -> MarkerTree {
+    let (os, machines): (&MarkerTree, Vec<&str>) = if let Some(arch) = linux_arch(tag) {
+        (&*LINUX_MARKERS, vec![arch])
+    } else if let Some(arch) = tag
+        .strip_prefix("macosx_")
+        .and_then(|rest| rest.splitn(3, '_').nth(2))
+    {
+        let machines = match arch {
+            "arm64" => vec!["arm64"],
+            "x86_64" => vec!["x86_64"],
+            "universal2" => vec!["arm64", "x86_64"],
+            _ => vec![],
+        };
+        (&*MAC_MARKERS, machines)
+    } else if tag == "win32" || tag == "win_ia64" {
+        (&*WINDOWS_MARKERS, vec![])
+    } else if tag == "win_amd64" {
+        (&*WINDOWS_MARKERS, vec!["AMD64"])
+    } else if tag == "win_arm64" {
+        (&*WINDOWS_MARKERS, vec!["ARM64"])
+    } else {
+        return MarkerTree::TRUE;
+    };
+
+    let mut markers = os.clone();
+    if !machines.is_empty() {
+        let mut machine = MarkerTree::FALSE;
+        for arch in machines {
+            machine.or(parse_markers(&format!("platform_machine == '{arch}'")));
+        }
+        markers.and(machine);
+    }
+    markers
+}
+
+/// Returns the architecture of a Linux platform tag (e.g., `x86_64` for `manylinux_2_17_x86_64`).
+fn linux_arch(tag: &str) -> Option<&str> {
+    // Ex) `manylinux_2_17_x86_64`, `musllinux_1_1_aarch64`
+    if let Some(rest) = tag
+        .strip_prefix("manylinux_")
+        .or_else(|| tag.strip_prefix("musllinux_"))
+    {
+        return rest.splitn(3, '_').nth(2);
+    }
+
+    // Ex) `manylinux2014_aarch64`, `linux_armv7l`
+    ["manylinux1_", "manylinux2010_", "manylinux2014_", "linux_"]
+        .iter()
+        .find_map(|prefix| tag.strip_prefix(prefix))
+}
+
+/// Parse a marker expression derived from a wheel tag, treating invalid expressions as
+/// unconstrained.
+fn parse_markers(markers: &str) -> MarkerTree {
+    MarkerTree::from_str(markers).unwrap_or(MarkerTree::TRUE)
+}
+
+/// Returns `true` if any of the fork markers (or, if there are none, the universal marker) may
+/// apply to the given environment.
+fn is_possible(fork_markers: &[MarkerTree], environment: &MarkerTree) -> bool {
+    fork_markers.is_empty()
+        || fork_markers
+            .iter()
+            .any(|marker| !marker.is_disjoint(environment))
+}
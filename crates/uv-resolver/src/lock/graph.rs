@@ -0,0 +1,332 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use itertools::Itertools;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use uv_normalize::{ExtraName, GroupName};
+use uv_pep508::MarkerTree;
+
+use crate::lock::{Dependency, PackageId, Source};
+use crate::requires_python::SimplifiedMarkerTree;
+use crate::Lock;
+
+/// The format in which to export the resolved dependency graph.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum GraphFormat {
+    /// The Graphviz DOT language.
+    #[default]
+    Dot,
+    /// The GraphML XML format.
+    GraphMl,
+}
+
+impl GraphFormat {
+    /// Infer the [`GraphFormat`] from the extension of the output path, defaulting to DOT.
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("graphml") => Self::GraphMl,
+            _ => Self::Dot,
+        }
+    }
+}
+
+/// An export of a [`Lock`] as a dependency graph, with a node for each locked package version
+/// and an edge for each dependency, labeled with the dependency's markers, extras, and groups.
+#[derive(Debug)]
+pub struct GraphExport<'lock> {
+    nodes: Vec<Node<'lock>>,
+    edges: Vec<Edge>,
+    /// The markers of the forks in the resolution, if the resolution was forked.
+    fork_markers: Vec<String>,
+    format: GraphFormat,
+}
+
+#[derive(Debug)]
+struct Node<'lock> {
+    id: &'lock PackageId,
+    /// Whether the package is a workspace member.
+    member: bool,
+    /// Whether the package name is shared by multiple locked packages, in which case the source
+    /// is included in the label.
+    ambiguous: bool,
+    /// The markers of the forks that contain this package.
+    fork_markers: Vec<String>,
+}
+
+#[derive(Debug)]
+struct Edge {
+    source: usize,
+    target: usize,
+    marker: Option<String>,
+    extra: Option<String>,
+    group: Option<String>,
+}
+
+impl<'lock> GraphExport<'lock> {
+    /// Create a new [`GraphExport`] for the given [`Lock`].
+    pub fn from_lock(lock: &'lock Lock, format: GraphFormat) -> Self {
+        // Identify the workspace members.
+        //
+        // The members are encoded directly in the lockfile, unless the workspace contains a
+        // single member at the root, in which case, we identify it by its source.
+        let members: FxHashSet<&PackageId> = if lock.members().is_empty() {
+            lock.packages
+                .iter()
+                .filter(|package| {
+                    matches!(
+                        &package.id.source,
+                        Source::Editable(path) | Source::Virtual(path) if path == Path::new("")
+                    )
+                })
+                .map(|package| &package.id)
+                .collect()
+        } else {
+            lock.packages
+                .iter()
+                .filter(|package| lock.members().contains(&package.id.name))
+                .map(|package| &package.id)
+                .collect()
+        };
+
+        let simplify = |marker: &MarkerTree| {
+            SimplifiedMarkerTree::new(lock.requires_python(), marker.clone())
+                .try_to_string()
+                .unwrap_or_else(|| "true".to_string())
+        };
+
+        let counts = lock.packages.iter().counts_by(|package| &package.id.name);
+
+        let mut inverse = FxHashMap::default();
+        let nodes = lock
+            .packages
+            .iter()
+            .enumerate()
+            .map(|(index, package)| {
+                inverse.insert(&package.id, index);
+                Node {
+                    id: &package.id,
+                    member: members.contains(&package.id),
+                    ambiguous: counts[&package.id.name] > 1,
+                    fork_markers: package.fork_markers.iter().map(simplify).collect(),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut edges = Vec::new();
+        for (source, package) in lock.packages.iter().enumerate() {
+            let mut add_edge =
+                |dependency: &Dependency, extra: Option<&ExtraName>, group: Option<&GroupName>| {
+                    let Some(&target) = inverse.get(&dependency.package_id) else {
+                        return;
+                    };
+                    // Dependencies on the extras of the target are included in the label, e.g.,
+                    // `foo[bar]` yields an edge to `foo` labeled with `[bar]`.
+                    let marker = dependency.simplified_marker.try_to_string();
+                    let marker = if dependency.extra.is_empty() {
+                        marker
+                    } else {
+                        let extras = dependency.extra.iter().join(", ");
+                        Some(match marker {
+                            Some(marker) => format!("[{extras}] {marker}"),
+                            None => format!("[{extras}]"),
+                        })
+                    };
+                    edges.push(Edge {
+                        source,
+                        target,
+                        marker,
+                        extra: extra.map(ToString::to_string),
+                        group: group.map(ToString::to_string),
+                    });
+                };
+
+            for dependency in &package.dependencies {
+                add_edge(dependency, None, None);
+            }
+            for (extra, dependencies) in &package.optional_dependencies {
+                for dependency in dependencies {
+                    add_edge(dependency, Some(extra), None);
+                }
+            }
+            for (group, dependencies) in &package.dependency_groups {
+                for dependency in dependencies {
+                    add_edge(dependency, None, Some(group));
+                }
+            }
+        }
+
+        Self {
+            nodes,
+            edges,
+            fork_markers: lock.fork_markers().iter().map(simplify).collect(),
+            format,
+        }
+    }
+
+    /// Write the graph in the Graphviz DOT language.
+    fn write_dot(&self, f: &mut impl Write) -> std::fmt::Result {
+        writeln!(f, "digraph {{")?;
+        if !self.fork_markers.is_empty() {
+            let label = self
+                .fork_markers
+                .iter()
+                .map(|marker| format!("fork: {marker}"))
+                .join("\n");
+            writeln!(f, "    label={};", dot_string(&label))?;
+        }
+        writeln!(f, "    node [shape=ellipse];")?;
+        for (index, node) in self.nodes.iter().enumerate() {
+            let mut label = format!("{}\n{}", node.id.name, node.id.version);
+            if node.ambiguous {
+                write!(label, "\n{}", node.id.source)?;
+            }
+            for marker in &node.fork_markers {
+                write!(label, "\nfork: {marker}")?;
+            }
+            let mut attributes = vec![format!("label={}", dot_string(&label))];
+            if node.member {
+                attributes.push("shape=box".to_string());
+            }
+            if !node.fork_markers.is_empty() {
+                attributes.push("style=dashed".to_string());
+            }
+            writeln!(f, "    n{index} [{}];", attributes.join(", "))?;
+        }
+        for edge in &self.edges {
+            let label = edge
+                .extra
+                .as_ref()
+                .map(|extra| format!("extra: {extra}"))
+                .into_iter()
+                .chain(edge.group.as_ref().map(|group| format!("group: {group}")))
+                .chain(edge.marker.clone())
+                .join("; ");
+            if label.is_empty() {
+                writeln!(f, "    n{} -> n{};", edge.source, edge.target)?;
+            } else {
+                writeln!(
+                    f,
+                    "    n{} -> n{} [label={}];",
+                    edge.source,
+                    edge.target,
+                    dot_string(&label)
+                )?;
+            }
+        }
+        writeln!(f, "}}")?;
+        Ok(())
+    }
+
+    /// Write the graph in the GraphML format.
+    fn write_graphml(&self, f: &mut impl Write) -> std::fmt::Result {
+        writeln!(f, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(
+            f,
+            r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">"#
+        )?;
+        for (id, target, name, kind) in [
+            ("forks", "graph", "fork-markers", "string"),
+            ("name", "node", "name", "string"),
+            ("version", "node", "version", "string"),
+            ("source", "node", "source", "string"),
+            ("member", "node", "member", "boolean"),
+            ("node-forks", "node", "fork-markers", "string"),
+            ("marker", "edge", "marker", "string"),
+            ("extra", "edge", "extra", "string"),
+            ("group", "edge", "group", "string"),
+        ] {
+            writeln!(
+                f,
+                r#"  <key id="{id}" for="{target}" attr.name="{name}" attr.type="{kind}"/>"#
+            )?;
+        }
+        writeln!(f, r#"  <graph id="lock" edgedefault="directed">"#)?;
+        if !self.fork_markers.is_empty() {
+            writeln!(
+                f,
+                r#"    <data key="forks">{}</data>"#,
+                xml_escape(&self.fork_markers.join(" || "))
+            )?;
+        }
+        for (index, node) in self.nodes.iter().enumerate() {
+            writeln!(f, r#"    <node id="n{index}">"#)?;
+            writeln!(
+                f,
+                r#"      <data key="name">{}</data>"#,
+                xml_escape(node.id.name.as_ref())
+            )?;
+            writeln!(f, r#"      <data key="version">{}</data>"#, node.id.version)?;
+            writeln!(
+                f,
+                r#"      <data key="source">{}</data>"#,
+                xml_escape(&node.id.source.to_string())
+            )?;
+            writeln!(f, r#"      <data key="member">{}</data>"#, node.member)?;
+            if !node.fork_markers.is_empty() {
+                writeln!(
+                    f,
+                    r#"      <data key="node-forks">{}</data>"#,
+                    xml_escape(&node.fork_markers.join(" || "))
+                )?;
+            }
+            writeln!(f, "    </node>")?;
+        }
+        for edge in &self.edges {
+            let data = [
+                ("marker", edge.marker.as_deref()),
+                ("extra", edge.extra.as_deref()),
+                ("group", edge.group.as_deref()),
+            ];
+            if data.iter().all(|(_, value)| value.is_none()) {
+                writeln!(
+                    f,
+                    r#"    <edge source="n{}" target="n{}"/>"#,
+                    edge.source, edge.target
+                )?;
+                continue;
+            }
+            writeln!(
+                f,
+                r#"    <edge source="n{}" target="n{}">"#,
+                edge.source, edge.target
+            )?;
+            for (key, value) in data {
+                if let Some(value) = value {
+                    writeln!(f, r#"      <data key="{key}">{}</data>"#, xml_escape(value))?;
+                }
+            }
+            writeln!(f, "    </edge>")?;
+        }
+        writeln!(f, "  </graph>")?;
+        writeln!(f, "</graphml>")?;
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for GraphExport<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.format {
+            GraphFormat::Dot => self.write_dot(f),
+            GraphFormat::GraphMl => self.write_graphml(f),
+        }
+    }
+}
+
+/// Quote a string for use as a DOT identifier.
+fn dot_string(value: &str) -> String {
+    let escaped = value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n");
+    format!("\"{escaped}\"")
+}
+
+/// Escape a string for use as XML character data.
+fn xml_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
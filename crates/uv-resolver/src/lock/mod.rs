@@ -14,44 +14,58 @@ use std::sync::{Arc, LazyLock};
 use toml_edit::{value, Array, ArrayOfTables, InlineTable, Item, Table, Value};
 use url::Url;
 
+pub use crate::lock::coverage::UnsatisfiedEnvironment;
+pub use crate::lock::graph::{GraphExport, GraphFormat};
 pub use crate::lock::requirements_txt::RequirementsTxtExport;
 pub use crate::lock::tree::TreeDisplay;
 use crate::requires_python::SimplifiedMarkerTree;
 use crate::resolution::{AnnotatedDist, ResolutionGraphNode};
 use crate::{
     ExcludeNewer, InMemoryIndex, MetadataResponse, PrereleaseMode, RequiresPython, ResolutionGraph,
-    ResolutionMode,
+    ResolutionMode, YankedMode,
 };
 use uv_cache_key::RepositoryUrl;
 use uv_configuration::{BuildOptions, DevGroupsManifest, ExtrasSpecification, InstallOptions};
 use uv_distribution::DistributionDatabase;
 use uv_distribution_filename::{DistExtension, ExtensionError, SourceDistExtension, WheelFilename};
 use uv_distribution_types::{
-    BuiltDist, DependencyMetadata, DirectUrlBuiltDist, DirectUrlSourceDist, DirectorySourceDist,
-    Dist, DistributionMetadata, FileLocation, GitSourceDist, IndexLocations, IndexUrl, Name,
-    PathBuiltDist, PathSourceDist, RegistryBuiltDist, RegistryBuiltWheel, RegistrySourceDist,
-    RemoteSource, Resolution, ResolvedDist, StaticMetadata, ToUrlError, UrlString,
+    BuiltDist, DependencyMetadata, DependencyOverride, DirectUrlBuiltDist, DirectUrlSourceDist,
+    DirectorySourceDist, Dist, DistributionMetadata, FileLocation, GitSourceDist, HgSourceDist,
+    IndexLocations, IndexUrl, Name, PathBuiltDist, PathSourceDist, RegistryBuiltDist,
+    RegistryBuiltWheel, RegistrySourceDist, RemoteSource, Resolution, ResolvedDist, StaticMetadata,
+    ToUrlError, UrlString,
 };
 use uv_fs::{relative_to, PortablePath, PortablePathBuf};
 use uv_git::{GitReference, GitSha, RepositoryReference, ResolvedRepositoryReference};
+use uv_hg::{HgNode, HgReference, HgRepositoryReference, ResolvedHgRepositoryReference};
 use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::Version;
 use uv_pep508::{split_scheme, MarkerEnvironment, MarkerTree, VerbatimUrl, VerbatimUrlError};
 use uv_platform_tags::{TagCompatibility, TagPriority, Tags};
 use uv_pypi_types::{
-    redact_credentials, HashDigest, ParsedArchiveUrl, ParsedGitUrl, Requirement, RequirementSource,
-    ResolverMarkerEnvironment,
+    redact_credentials, HashDigest, ParsedArchiveUrl, ParsedGitUrl, ParsedHgUrl, Requirement,
+    RequirementSource, ResolverMarkerEnvironment,
 };
 use uv_types::{BuildContext, HashStrategy};
 use uv_workspace::dependency_groups::DependencyGroupError;
 use uv_workspace::{InstallTarget, Workspace};
 
+mod coverage;
+mod graph;
 mod requirements_txt;
 mod tree;
 
 /// The current version of the lockfile format.
 pub const VERSION: u32 = 1;
 
+/// The version of the lockfile format used by lockfiles written in the [`LockfileFormat::Diffable`]
+/// format.
+///
+/// Versions of uv that predate the `format` key would ignore it and silently rewrite the lockfile
+/// in the default format, so diffable lockfiles declare a newer version, which those versions of
+/// uv refuse to read.
+pub const DIFFABLE_VERSION: u32 = 2;
+
 static LINUX_MARKERS: LazyLock<MarkerTree> = LazyLock::new(|| {
     MarkerTree::from_str(
         "platform_system == 'Linux' and os_name == 'posix' and sys_platform == 'linux'",
@@ -75,6 +89,8 @@ static MAC_MARKERS: LazyLock<MarkerTree> = LazyLock::new(|| {
 #[serde(try_from = "LockWire")]
 pub struct Lock {
     version: u32,
+    /// The format in which the lockfile is written.
+    format: LockfileFormat,
     /// If this lockfile was built from a forking resolution with non-identical forks, store the
     /// forks in the lockfile so we can recreate them in subsequent resolutions.
     fork_markers: Vec<MarkerTree>,
@@ -226,6 +242,7 @@ impl Lock {
         let options = ResolverOptions {
             resolution_mode: graph.options.resolution_mode,
             prerelease_mode: graph.options.prerelease_mode,
+            yanked_mode: graph.options.yanked_mode,
             exclude_newer: graph.options.exclude_newer,
         };
         let lock = Self::new(
@@ -256,18 +273,6 @@ impl Lock {
             .retain(|wheel| requires_python.matches_wheel_tag(&wheel.filename));
 
         // Filter by platform tags.
-
-        // See https://github.com/pypi/warehouse/blob/ccff64920db7965078cf1fdb50f028e640328887/warehouse/forklift/legacy.py#L100-L169
-        // for a list of relevant platforms.
-        let linux_tags = [
-            "manylinux1_",
-            "manylinux2010_",
-            "manylinux2014_",
-            "musllinux_",
-            "manylinux_",
-        ];
-        let windows_tags = ["win32", "win_arm64", "win_amd64", "win_ia64"];
-
         locked_dist.wheels.retain(|wheel| {
             // Naively, we'd check whether `platform_system == 'Linux'` is disjoint, or
             // `os_name == 'posix'` is disjoint, or `sys_platform == 'linux'` is disjoint (each on its
@@ -275,30 +280,9 @@ impl Lock {
             // `(A ∩ (B ∩ C) = ∅) => ((A ∩ B = ∅) or (A ∩ C = ∅))`
             // a single disjointness check with the intersection is sufficient, so we have one
             // constant per platform.
-            let platform_tags = &wheel.filename.platform_tag;
-            if platform_tags.iter().all(|tag| {
-                linux_tags.into_iter().any(|linux_tag| {
-                    // These two linux tags are allowed by warehouse.
-                    tag.starts_with(linux_tag) || tag == "linux_armv6l" || tag == "linux_armv7l"
-                })
-            }) {
-                !graph.petgraph[node_index]
-                    .marker()
-                    .is_disjoint(&LINUX_MARKERS)
-            } else if platform_tags
-                .iter()
-                .all(|tag| windows_tags.contains(&&**tag))
-            {
-                !graph.petgraph[node_index]
-                    .marker()
-                    .is_disjoint(&WINDOWS_MARKERS)
-            } else if platform_tags.iter().all(|tag| tag.starts_with("macosx_")) {
-                !graph.petgraph[node_index]
-                    .marker()
-                    .is_disjoint(&MAC_MARKERS)
-            } else {
-                true
-            }
+            implied_platform_markers(&wheel.filename).map_or(true, |markers| {
+                !graph.petgraph[node_index].marker().is_disjoint(markers)
+            })
         });
     }
 
@@ -457,6 +441,7 @@ impl Lock {
         }
         let lock = Self {
             version,
+            format: LockfileFormat::default(),
             fork_markers,
             supported_environments,
             requires_python,
@@ -494,11 +479,26 @@ impl Lock {
         self
     }
 
+    /// Write this lock in the given format.
+    ///
+    /// The lockfile version is updated to the version required by the format.
+    #[must_use]
+    pub fn with_format(mut self, format: LockfileFormat) -> Self {
+        self.format = format;
+        self.version = format.version();
+        self
+    }
+
     /// Returns the lockfile version.
     pub fn version(&self) -> u32 {
         self.version
     }
 
+    /// Returns the format in which the lockfile is written.
+    pub fn format(&self) -> LockfileFormat {
+        self.format
+    }
+
     /// Returns the number of packages in the lockfile.
     pub fn len(&self) -> usize {
         self.packages.len()
@@ -529,6 +529,11 @@ impl Lock {
         self.options.prerelease_mode
     }
 
+    /// Returns the yanked mode used to generate this lock.
+    pub fn yanked_mode(&self) -> YankedMode {
+        self.options.yanked_mode
+    }
+
     /// Returns the exclude newer setting used to generate this lock.
     pub fn exclude_newer(&self) -> Option<ExcludeNewer> {
         self.options.exclude_newer
@@ -544,6 +549,65 @@ impl Lock {
         &self.manifest.members
     }
 
+    /// Verify that the distributions locked for URL sources match the hashes declared via `hash`
+    /// in the workspace's `tool.uv.sources`.
+    pub fn verify_source_hashes(&self, workspace: &Workspace) -> Result<(), LockError> {
+        for (name, url, expected) in workspace.url_source_hashes() {
+            let normalized = normalize_url(url.clone());
+            let packages = self
+                .packages
+                .iter()
+                .filter(|package| package.id.name == *name)
+                .collect::<Vec<_>>();
+            let matching = packages
+                .iter()
+                .filter(|package| {
+                    matches!(&package.id.source, Source::Direct(source, _) if *source == normalized)
+                })
+                .collect::<Vec<_>>();
+
+            // If the package is locked, but not from the declared URL, the hash can't be verified.
+            if matching.is_empty() && !packages.is_empty() {
+                return Err(LockErrorKind::MissingSourceUrl {
+                    name: name.clone(),
+                    url: url.clone(),
+                    expected: expected.clone(),
+                }
+                .into());
+            }
+
+            for package in matching {
+                let locked = package
+                    .sdist
+                    .iter()
+                    .filter_map(SourceDist::hash)
+                    .chain(
+                        package
+                            .wheels
+                            .iter()
+                            .filter_map(|wheel| wheel.hash.as_ref()),
+                    )
+                    .collect::<Vec<_>>();
+                if locked.is_empty() {
+                    return Err(LockErrorKind::MissingSourceHash {
+                        id: package.id.clone(),
+                        expected: expected.clone(),
+                    }
+                    .into());
+                }
+                if !locked.iter().any(|hash| hash.0 == *expected) {
+                    return Err(LockErrorKind::SourceHashMismatch {
+                        id: package.id.clone(),
+                        expected: expected.clone(),
+                        locked: locked.iter().map(|hash| format!("  {hash}")).join("\n"),
+                    }
+                    .into());
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Returns the supported environments that were used to generate this
     /// lock.
     ///
@@ -713,6 +777,10 @@ impl Lock {
         let mut doc = toml_edit::DocumentMut::new();
         doc.insert("version", value(i64::from(self.version)));
 
+        if self.format != LockfileFormat::default() {
+            doc.insert("format", value(self.format.to_string()));
+        }
+
         doc.insert("requires-python", value(self.requires_python.to_string()));
 
         if !self.fork_markers.is_empty() {
@@ -753,6 +821,9 @@ impl Lock {
                     value(self.options.prerelease_mode.to_string()),
                 );
             }
+            if self.options.yanked_mode != YankedMode::default() {
+                options_table.insert("yanked-mode", value(self.options.yanked_mode.to_string()));
+            }
             if let Some(exclude_newer) = self.options.exclude_newer {
                 options_table.insert("exclude-newer", value(exclude_newer.to_string()));
             }
@@ -790,11 +861,7 @@ impl Lock {
                         )
                     })
                     .collect::<Result<Vec<_>, _>>()?;
-                let requirements = match requirements.as_slice() {
-                    [] => Array::new(),
-                    [requirement] => Array::from_iter([requirement]),
-                    requirements => each_element_on_its_line_array(requirements.iter()),
-                };
+                let requirements = requirements_array(&requirements, self.format);
                 manifest_table.insert("requirements", value(requirements));
             }
 
@@ -810,11 +877,7 @@ impl Lock {
                         )
                     })
                     .collect::<Result<Vec<_>, _>>()?;
-                let constraints = match constraints.as_slice() {
-                    [] => Array::new(),
-                    [requirement] => Array::from_iter([requirement]),
-                    constraints => each_element_on_its_line_array(constraints.iter()),
-                };
+                let constraints = requirements_array(&constraints, self.format);
                 manifest_table.insert("constraints", value(constraints));
             }
 
@@ -830,11 +893,7 @@ impl Lock {
                         )
                     })
                     .collect::<Result<Vec<_>, _>>()?;
-                let overrides = match overrides.as_slice() {
-                    [] => Array::new(),
-                    [requirement] => Array::from_iter([requirement]),
-                    overrides => each_element_on_its_line_array(overrides.iter()),
-                };
+                let overrides = requirements_array(&overrides, self.format);
                 manifest_table.insert("overrides", value(overrides));
             }
 
@@ -872,6 +931,37 @@ impl Lock {
                 manifest_table.insert("dependency-metadata", Item::ArrayOfTables(tables));
             }
 
+            if !self.manifest.dependency_overrides.is_empty() {
+                let mut tables = ArrayOfTables::new();
+                for entry in &self.manifest.dependency_overrides {
+                    let mut table = Table::new();
+                    table.insert("name", value(entry.name.to_string()));
+                    if let Some(version) = entry.version.as_ref() {
+                        table.insert("version", value(version.to_string()));
+                    }
+                    if !entry.remove.is_empty() {
+                        table.insert(
+                            "remove",
+                            value(serde::Serialize::serialize(
+                                &entry.remove,
+                                toml_edit::ser::ValueSerializer::new(),
+                            )?),
+                        );
+                    }
+                    if !entry.replace.is_empty() {
+                        table.insert(
+                            "replace",
+                            value(serde::Serialize::serialize(
+                                &entry.replace,
+                                toml_edit::ser::ValueSerializer::new(),
+                            )?),
+                        );
+                    }
+                    tables.push(table);
+                }
+                manifest_table.insert("dependency-overrides", Item::ArrayOfTables(tables));
+            }
+
             if !manifest_table.is_empty() {
                 doc.insert("manifest", Item::Table(manifest_table));
             }
@@ -888,7 +978,7 @@ impl Lock {
 
         let mut packages = ArrayOfTables::new();
         for dist in &self.packages {
-            packages.push(dist.to_toml(&self.requires_python, &dist_count_by_name)?);
+            packages.push(dist.to_toml(&self.requires_python, &dist_count_by_name, self.format)?);
         }
 
         doc.insert("package", Item::ArrayOfTables(packages));
@@ -1033,7 +1123,7 @@ impl Lock {
                 .map(|requirement| normalize_requirement(requirement, workspace))
                 .collect::<Result<_, _>>()?;
             if expected != actual {
-                return Ok(SatisfiesResult::MismatchedConstraints(expected, actual));
+                return Ok(SatisfiesResult::MismatchedRequirements(expected, actual));
             }
         }
 
@@ -1087,6 +1177,20 @@ impl Lock {
             }
         }
 
+        // Validate that the lockfile was generated with the same dependency overrides.
+        {
+            let expected = dependency_metadata
+                .overrides()
+                .cloned()
+                .collect::<BTreeSet<_>>();
+            let actual = &self.manifest.dependency_overrides;
+            if expected != *actual {
+                return Ok(SatisfiesResult::MismatchedDependencyOverrides(
+                    expected, actual,
+                ));
+            }
+        }
+
         // Collect the set of available indexes (both `--index-url` and `--find-links` entries).
         let remotes = indexes.map(|locations| {
             locations
@@ -1350,6 +1454,11 @@ pub enum SatisfiesResult<'lock> {
     MismatchedOverrides(BTreeSet<Requirement>, BTreeSet<Requirement>),
     /// The lockfile uses different static metadata.
     MismatchedStaticMetadata(BTreeSet<StaticMetadata>, &'lock BTreeSet<StaticMetadata>),
+    /// The lockfile uses different dependency overrides.
+    MismatchedDependencyOverrides(
+        BTreeSet<DependencyOverride>,
+        &'lock BTreeSet<DependencyOverride>,
+    ),
     /// The lockfile is missing a workspace member.
     MissingRoot(PackageName),
     /// The lockfile referenced a remote index that was not provided
@@ -1382,6 +1491,9 @@ struct ResolverOptions {
     /// The [`PrereleaseMode`] used to generate this lock.
     #[serde(default)]
     prerelease_mode: PrereleaseMode,
+    /// The [`YankedMode`] used to generate this lock.
+    #[serde(default)]
+    yanked_mode: YankedMode,
     /// The [`ExcludeNewer`] used to generate this lock.
     exclude_newer: Option<ExcludeNewer>,
 }
@@ -1404,17 +1516,21 @@ pub struct ResolverManifest {
     /// The static metadata provided to the resolver.
     #[serde(default)]
     dependency_metadata: BTreeSet<StaticMetadata>,
+    /// The dependency overrides provided to the resolver.
+    #[serde(default)]
+    dependency_overrides: BTreeSet<DependencyOverride>,
 }
 
 impl ResolverManifest {
-    /// Initialize a [`ResolverManifest`] with the given members, requirements, constraints, and
-    /// overrides.
+    /// Initialize a [`ResolverManifest`] with the given members, requirements, constraints,
+    /// overrides, and user-provided dependency metadata.
     pub fn new(
         members: impl IntoIterator<Item = PackageName>,
         requirements: impl IntoIterator<Item = Requirement>,
         constraints: impl IntoIterator<Item = Requirement>,
         overrides: impl IntoIterator<Item = Requirement>,
         dependency_metadata: impl IntoIterator<Item = StaticMetadata>,
+        dependency_overrides: impl IntoIterator<Item = DependencyOverride>,
     ) -> Self {
         Self {
             members: members.into_iter().collect(),
@@ -1422,6 +1538,7 @@ impl ResolverManifest {
             constraints: constraints.into_iter().collect(),
             overrides: overrides.into_iter().collect(),
             dependency_metadata: dependency_metadata.into_iter().collect(),
+            dependency_overrides: dependency_overrides.into_iter().collect(),
         }
     }
 
@@ -1445,6 +1562,7 @@ impl ResolverManifest {
                 .map(|requirement| requirement.relative_to(workspace.install_path()))
                 .collect::<Result<BTreeSet<_>, _>>()?,
             dependency_metadata: self.dependency_metadata,
+            dependency_overrides: self.dependency_overrides,
         })
     }
 }
@@ -1453,6 +1571,8 @@ impl ResolverManifest {
 #[serde(rename_all = "kebab-case")]
 struct LockWire {
     version: u32,
+    #[serde(default)]
+    format: LockfileFormat,
     requires_python: RequiresPython,
     /// If this lockfile was built from a forking resolution with non-identical forks, store the
     /// forks in the lockfile so we can recreate them in subsequent resolutions.
@@ -1515,6 +1635,12 @@ impl TryFrom<LockWire> for Lock {
             fork_markers,
         )?;
 
+        // Retain the version as written, such that unsupported versions can be rejected.
+        let lock = Lock {
+            format: wire.format,
+            ..lock
+        };
+
         Ok(lock)
     }
 }
@@ -1534,6 +1660,45 @@ impl LockVersion {
     }
 }
 
+/// The format in which a lockfile is written.
+///
+/// Every format can be read by the same version of uv; the format only affects how the lockfile
+/// is laid out on disk. Unknown formats are rejected, and non-default formats are written with a
+/// newer lockfile version (see [`DIFFABLE_VERSION`]), such that older versions of uv refuse to
+/// read (and rewrite) them.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum LockfileFormat {
+    /// The default, compact format.
+    #[default]
+    Default,
+    /// A format optimized for reviewing changes in version control.
+    ///
+    /// Every requirement and artifact is written on its own line, and wheels are ordered by
+    /// filename, such that each change to the resolution affects as few lines as possible.
+    Diffable,
+}
+
+impl LockfileFormat {
+    /// Returns the lockfile version written for this format.
+    pub fn version(self) -> u32 {
+        match self {
+            Self::Default => VERSION,
+            Self::Diffable => DIFFABLE_VERSION,
+        }
+    }
+}
+
+impl std::fmt::Display for LockfileFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Default => write!(f, "default"),
+            Self::Diffable => write!(f, "diffable"),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Package {
     pub(crate) id: PackageId,
@@ -1741,8 +1906,8 @@ impl Package {
                             self.wheels[best_wheel_index].filename.clone();
                         let path_dist = PathBuiltDist {
                             filename,
-                            url: verbatim_url(workspace_root.join(path), &self.id)?,
-                            install_path: workspace_root.join(path),
+                            url: verbatim_url(absolute_path(workspace_root, path), &self.id)?,
+                            install_path: absolute_path(workspace_root, path),
                         };
                         let built_dist = BuiltDist::Path(path_dist);
                         Ok(Dist::Built(built_dist))
@@ -1768,6 +1933,11 @@ impl Package {
                         source_type: "Git",
                     }
                     .into()),
+                    Source::Hg(_, _) => Err(LockErrorKind::InvalidWheelSource {
+                        id: self.id.clone(),
+                        source_type: "Mercurial",
+                    }
+                    .into()),
                     Source::Directory(_) => Err(LockErrorKind::InvalidWheelSource {
                         id: self.id.clone(),
                         source_type: "directory",
@@ -1839,8 +2009,8 @@ impl Package {
                 };
                 let path_dist = PathSourceDist {
                     name: self.id.name.clone(),
-                    url: verbatim_url(workspace_root.join(path), &self.id)?,
-                    install_path: workspace_root.join(path),
+                    url: verbatim_url(absolute_path(workspace_root, path), &self.id)?,
+                    install_path: absolute_path(workspace_root, path),
                     ext,
                 };
                 uv_distribution_types::SourceDist::Path(path_dist)
@@ -1848,8 +2018,8 @@ impl Package {
             Source::Directory(path) => {
                 let dir_dist = DirectorySourceDist {
                     name: self.id.name.clone(),
-                    url: verbatim_url(workspace_root.join(path), &self.id)?,
-                    install_path: workspace_root.join(path),
+                    url: verbatim_url(absolute_path(workspace_root, path), &self.id)?,
+                    install_path: absolute_path(workspace_root, path),
                     editable: false,
                     r#virtual: false,
                 };
@@ -1858,8 +2028,8 @@ impl Package {
             Source::Editable(path) => {
                 let dir_dist = DirectorySourceDist {
                     name: self.id.name.clone(),
-                    url: verbatim_url(workspace_root.join(path), &self.id)?,
-                    install_path: workspace_root.join(path),
+                    url: verbatim_url(absolute_path(workspace_root, path), &self.id)?,
+                    install_path: absolute_path(workspace_root, path),
                     editable: true,
                     r#virtual: false,
                 };
@@ -1868,8 +2038,8 @@ impl Package {
             Source::Virtual(path) => {
                 let dir_dist = DirectorySourceDist {
                     name: self.id.name.clone(),
-                    url: verbatim_url(workspace_root.join(path), &self.id)?,
-                    install_path: workspace_root.join(path),
+                    url: verbatim_url(absolute_path(workspace_root, path), &self.id)?,
+                    install_path: absolute_path(workspace_root, path),
                     editable: false,
                     r#virtual: true,
                 };
@@ -1903,6 +2073,31 @@ impl Package {
                 };
                 uv_distribution_types::SourceDist::Git(git_dist)
             }
+            Source::Hg(url, hg) => {
+                // Remove the fragment and query from the URL; they're already present in the
+                // `HgSource`.
+                let mut url = url.to_url();
+                url.set_fragment(None);
+                url.set_query(None);
+
+                // Reconstruct the `HgUrl` from the `HgSource`.
+                let hg_url =
+                    uv_hg::HgUrl::from_node(url, HgReference::from(hg.kind.clone()), hg.precise);
+
+                // Reconstruct the PEP 508-compatible URL from the `HgSource`.
+                let url = Url::from(ParsedHgUrl {
+                    url: hg_url.clone(),
+                    subdirectory: hg.subdirectory.as_ref().map(PathBuf::from),
+                });
+
+                let hg_dist = HgSourceDist {
+                    name: self.id.name.clone(),
+                    url: VerbatimUrl::from_url(url),
+                    hg: Box::new(hg_url),
+                    subdirectory: hg.subdirectory.as_ref().map(PathBuf::from),
+                };
+                uv_distribution_types::SourceDist::Hg(hg_dist)
+            }
             Source::Direct(url, direct) => {
                 // A direct URL source can also be a wheel, so validate the extension.
                 let DistExtension::Source(ext) = DistExtension::from_path(url.as_ref())? else {
@@ -2017,6 +2212,7 @@ impl Package {
         &self,
         requires_python: &RequiresPython,
         dist_count_by_name: &FxHashMap<PackageName, u64>,
+        format: LockfileFormat,
     ) -> anyhow::Result<Table> {
         let mut table = Table::new();
 
@@ -2077,9 +2273,15 @@ impl Package {
         }
 
         if !self.wheels.is_empty() {
+            let mut wheels = self.wheels.iter().collect::<Vec<_>>();
+            if format == LockfileFormat::Diffable {
+                // Order the wheels by filename, such that the position of each wheel is
+                // independent of the order in which the index returned them.
+                wheels.sort_by_cached_key(|wheel| wheel.filename.to_string());
+            }
             let wheels = each_element_on_its_line_array(
-                self.wheels
-                    .iter()
+                wheels
+                    .into_iter()
                     .map(Wheel::to_toml)
                     .collect::<anyhow::Result<Vec<_>>>()?
                     .into_iter(),
@@ -2103,11 +2305,7 @@ impl Package {
                         )
                     })
                     .collect::<Result<Vec<_>, _>>()?;
-                let requires_dist = match requires_dist.as_slice() {
-                    [] => Array::new(),
-                    [requirement] => Array::from_iter([requirement]),
-                    requires_dist => each_element_on_its_line_array(requires_dist.iter()),
-                };
+                let requires_dist = requirements_array(&requires_dist, format);
                 metadata_table.insert("requires-dist", value(requires_dist));
             }
 
@@ -2123,11 +2321,7 @@ impl Package {
                             )
                         })
                         .collect::<Result<Vec<_>, _>>()?;
-                    let deps = match deps.as_slice() {
-                        [] => Array::new(),
-                        [requirement] => Array::from_iter([requirement]),
-                        deps => each_element_on_its_line_array(deps.iter()),
-                    };
+                    let deps = requirements_array(&deps, format);
                     dependency_groups.insert(extra.as_ref(), value(deps));
                 }
                 if !dependency_groups.is_empty() {
@@ -2180,11 +2374,39 @@ impl Package {
         &self.id.version
     }
 
+    /// Returns the [`IndexUrl`] from which the package was locked, if it's a registry package.
+    pub fn index(&self, root: &Path) -> Result<Option<IndexUrl>, LockError> {
+        match &self.id.source {
+            Source::Registry(RegistrySource::Url(url)) => {
+                Ok(Some(IndexUrl::from(VerbatimUrl::from_url(url.to_url()))))
+            }
+            Source::Registry(RegistrySource::Path(path)) => Ok(Some(IndexUrl::from(
+                VerbatimUrl::from_absolute_path(root.join(path))
+                    .map_err(LockErrorKind::RegistryVerbatimUrl)?,
+            ))),
+            _ => Ok(None),
+        }
+    }
+
     /// Return the fork markers for this package, if any.
     pub fn fork_markers(&self) -> &[MarkerTree] {
         self.fork_markers.as_slice()
     }
 
+    /// Returns the requirements declared by the package, as recorded in the lockfile.
+    ///
+    /// Only populated for source trees (e.g., workspace members).
+    pub fn requires_dist(&self) -> &BTreeSet<Requirement> {
+        &self.metadata.requires_dist
+    }
+
+    /// Returns the dependency groups declared by the package, as recorded in the lockfile.
+    ///
+    /// Only populated for source trees (e.g., workspace members).
+    pub fn requires_dev(&self) -> &BTreeMap<GroupName, BTreeSet<Requirement>> {
+        &self.metadata.dependency_groups
+    }
+
     /// Returns all the hashes associated with this [`Package`].
     fn hashes(&self) -> Vec<HashDigest> {
         let mut hashes = Vec::new();
@@ -2212,9 +2434,134 @@ impl Package {
             _ => None,
         }
     }
+
+    /// Returns the [`ResolvedHgRepositoryReference`] for the package, if it is a Mercurial
+    /// source.
+    pub fn as_hg_ref(&self) -> Option<ResolvedHgRepositoryReference> {
+        match &self.id.source {
+            Source::Hg(url, hg) => Some(ResolvedHgRepositoryReference {
+                reference: HgRepositoryReference {
+                    url: RepositoryUrl::new(&url.to_url()),
+                    reference: HgReference::from(hg.kind.clone()),
+                },
+                node: hg.precise,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the package was resolved from a registry or `--find-links` index.
+    pub fn is_registry(&self) -> bool {
+        matches!(self.id.source, Source::Registry(..))
+    }
+
+    /// Returns the files (i.e., the wheels and source distribution) locked for the package, if the
+    /// package was resolved from a registry.
+    ///
+    /// Returns `None` for packages from any other source, such as Git repositories, direct URLs,
+    /// or local directories.
+    pub fn registry_files(
+        &self,
+        workspace_root: &Path,
+    ) -> Result<Option<Vec<uv_distribution_types::File>>, LockError> {
+        let Source::Registry(source) = &self.id.source else {
+            return Ok(None);
+        };
+
+        let mut files = self
+            .wheels
+            .iter()
+            .map(|wheel| {
+                wheel
+                    .to_registry_dist(source, workspace_root)
+                    .map(|wheel| *wheel.file)
+            })
+            .collect::<Result<Vec<_>, LockError>>()?;
+        if let Some(uv_distribution_types::SourceDist::Registry(sdist)) =
+            self.to_source_dist(workspace_root)?
+        {
+            files.push(*sdist.file);
+        }
+
+        Ok(Some(files))
+    }
+
+    /// Returns the source distribution from which the package can be built, if any.
+    ///
+    /// Returns `None` for virtual packages, which can't be built, and for registry packages that
+    /// don't provide a source distribution.
+    pub fn source_dist(
+        &self,
+        workspace_root: &Path,
+    ) -> Result<Option<uv_distribution_types::SourceDist>, LockError> {
+        if matches!(self.id.source, Source::Virtual(..)) {
+            return Ok(None);
+        }
+        self.to_source_dist(workspace_root)
+    }
+
+    /// Returns the wheels locked for the package, if the package was resolved from a registry.
+    ///
+    /// Returns an empty list for packages from any other source, and for registry packages that
+    /// only provide a source distribution.
+    pub fn registry_wheels(
+        &self,
+        workspace_root: &Path,
+    ) -> Result<Vec<RegistryBuiltWheel>, LockError> {
+        let Source::Registry(source) = &self.id.source else {
+            return Ok(Vec::new());
+        };
+        self.wheels
+            .iter()
+            .map(|wheel| wheel.to_registry_dist(source, workspace_root))
+            .collect()
+    }
+}
+
+/// Returns the markers implied by the platform tags of a wheel, if the wheel is specific to a
+/// single platform (Linux, Windows, or macOS).
+fn implied_platform_markers(filename: &WheelFilename) -> Option<&'static MarkerTree> {
+    // See https://github.com/pypi/warehouse/blob/ccff64920db7965078cf1fdb50f028e640328887/warehouse/forklift/legacy.py#L100-L169
+    // for a list of relevant platforms.
+    let linux_tags = [
+        "manylinux1_",
+        "manylinux2010_",
+        "manylinux2014_",
+        "musllinux_",
+        "manylinux_",
+    ];
+    let windows_tags = ["win32", "win_arm64", "win_amd64", "win_ia64"];
+
+    let platform_tags = &filename.platform_tag;
+    if platform_tags.iter().all(|tag| {
+        linux_tags.into_iter().any(|linux_tag| {
+            // These two linux tags are allowed by warehouse.
+            tag.starts_with(linux_tag) || tag == "linux_armv6l" || tag == "linux_armv7l"
+        })
+    }) {
+        Some(&*LINUX_MARKERS)
+    } else if platform_tags
+        .iter()
+        .all(|tag| windows_tags.contains(&&**tag))
+    {
+        Some(&*WINDOWS_MARKERS)
+    } else if platform_tags.iter().all(|tag| tag.starts_with("macosx_")) {
+        Some(&*MAC_MARKERS)
+    } else {
+        None
+    }
 }
 
 /// Attempts to construct a `VerbatimUrl` from the given `Path`.
+/// Resolve a path recorded in the lockfile (relative to the workspace root) into a normalized
+/// absolute path.
+///
+/// Paths may point outside the workspace root (e.g., `../shared`), in which case the `..`
+/// components are collapsed, such that the path matches the one produced during resolution.
+fn absolute_path(workspace_root: &Path, path: &Path) -> PathBuf {
+    uv_fs::normalize_path(&workspace_root.join(path))
+}
+
 fn verbatim_url(path: PathBuf, id: &PackageId) -> Result<VerbatimUrl, LockError> {
     let url = VerbatimUrl::from_absolute_path(path).map_err(|err| LockErrorKind::VerbatimUrl {
         id: id.clone(),
@@ -2409,6 +2756,8 @@ enum Source {
     Editable(PathBuf),
     /// A path to a local directory that should not be built or installed.
     Virtual(PathBuf),
+    /// A Mercurial repository.
+    Hg(UrlString, HgSource),
 }
 
 impl Source {
@@ -2451,6 +2800,7 @@ impl Source {
             uv_distribution_types::SourceDist::Git(ref git_dist) => {
                 Ok(Source::from_git_dist(git_dist))
             }
+            uv_distribution_types::SourceDist::Hg(ref hg_dist) => Ok(Source::from_hg_dist(hg_dist)),
             uv_distribution_types::SourceDist::Path(ref path_dist) => {
                 Source::from_path_source_dist(path_dist, root)
             }
@@ -2560,14 +2910,32 @@ impl Source {
         )
     }
 
+    fn from_hg_dist(hg_dist: &HgSourceDist) -> Source {
+        Source::Hg(
+            UrlString::from(locked_hg_url(hg_dist)),
+            HgSource {
+                kind: HgSourceKind::from(hg_dist.hg.reference().clone()),
+                precise: hg_dist.hg.precise().unwrap_or_else(|| {
+                    panic!("Mercurial distribution is missing a precise node: {hg_dist}")
+                }),
+                subdirectory: hg_dist
+                    .subdirectory
+                    .as_deref()
+                    .and_then(Path::to_str)
+                    .map(ToString::to_string),
+            },
+        )
+    }
+
     /// Returns `true` if the source should be considered immutable.
     ///
     /// We assume that registry sources are immutable. In other words, we expect that once a
     /// package-version is published to a registry, its metadata will not change.
     ///
-    /// We also assume that Git sources are immutable, since a Git source encodes a specific commit.
+    /// We also assume that Git and Mercurial sources are immutable, since they encode a specific
+    /// commit or changeset.
     fn is_immutable(&self) -> bool {
-        matches!(self, Self::Registry(..) | Self::Git(_, _))
+        matches!(self, Self::Registry(..) | Self::Git(_, _) | Self::Hg(_, _))
     }
 
     /// Returns `true` if the source is that of a wheel.
@@ -2589,6 +2957,7 @@ impl Source {
             Source::Editable(..) => false,
             Source::Virtual(..) => false,
             Source::Git(..) => false,
+            Source::Hg(..) => false,
             Source::Registry(..) => false,
         }
     }
@@ -2610,6 +2979,9 @@ impl Source {
             Source::Git(ref url, _) => {
                 source_table.insert("git", Value::from(url.as_ref()));
             }
+            Source::Hg(ref url, _) => {
+                source_table.insert("hg", Value::from(url.as_ref()));
+            }
             Source::Direct(ref url, DirectSource { ref subdirectory }) => {
                 source_table.insert("url", Value::from(url.as_ref()));
                 if let Some(ref subdirectory) = *subdirectory {
@@ -2644,6 +3016,7 @@ impl std::fmt::Display for Source {
         match self {
             Source::Registry(RegistrySource::Url(url))
             | Source::Git(url, _)
+            | Source::Hg(url, _)
             | Source::Direct(url, _) => {
                 write!(f, "{}+{}", self.name(), url)
             }
@@ -2663,6 +3036,7 @@ impl Source {
         match *self {
             Self::Registry(..) => "registry",
             Self::Git(..) => "git",
+            Self::Hg(..) => "hg",
             Self::Direct(..) => "direct",
             Self::Path(..) => "path",
             Self::Directory(..) => "directory",
@@ -2682,9 +3056,11 @@ impl Source {
         match *self {
             Self::Registry(..) => None,
             Self::Direct(..) | Self::Path(..) => Some(true),
-            Self::Git(..) | Self::Directory(..) | Self::Editable(..) | Self::Virtual(..) => {
-                Some(false)
-            }
+            Self::Git(..)
+            | Self::Hg(..)
+            | Self::Directory(..)
+            | Self::Editable(..)
+            | Self::Virtual(..) => Some(false),
         }
     }
 }
@@ -2698,6 +3074,9 @@ enum SourceWire {
     Git {
         git: String,
     },
+    Hg {
+        hg: String,
+    },
     Direct {
         url: UrlString,
         #[serde(default)]
@@ -2747,6 +3126,27 @@ impl TryFrom<SourceWire> for Source {
 
                 Ok(Source::Git(UrlString::from(url), git_source))
             }
+            Hg { hg } => {
+                let url = Url::parse(&hg)
+                    .map_err(|err| SourceParseError::InvalidUrl {
+                        given: hg.to_string(),
+                        err,
+                    })
+                    .map_err(LockErrorKind::InvalidHgSourceUrl)?;
+
+                let hg_source = HgSource::from_url(&url)
+                    .map_err(|err| match err {
+                        HgSourceError::InvalidNode => SourceParseError::InvalidNode {
+                            given: hg.to_string(),
+                        },
+                        HgSourceError::MissingNode => SourceParseError::MissingNode {
+                            given: hg.to_string(),
+                        },
+                    })
+                    .map_err(LockErrorKind::InvalidHgSourceUrl)?;
+
+                Ok(Source::Hg(UrlString::from(url), hg_source))
+            }
             Direct { url, subdirectory } => Ok(Source::Direct(url, DirectSource { subdirectory })),
             Path { path } => Ok(Source::Path(path.into())),
             Directory { directory } => Ok(Source::Directory(directory.into())),
@@ -2871,6 +3271,108 @@ enum GitSourceKind {
     DefaultBranch,
 }
 
+/// NOTE: Care should be taken when adding variants to this enum. Namely, new
+/// variants should be added without changing the relative ordering of other
+/// variants. Otherwise, this could cause the lockfile to have a different
+/// canonical ordering of package entries.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+struct HgSource {
+    precise: HgNode,
+    subdirectory: Option<String>,
+    kind: HgSourceKind,
+}
+
+/// An error that occurs when a Mercurial source string could not be parsed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum HgSourceError {
+    InvalidNode,
+    MissingNode,
+}
+
+impl HgSource {
+    /// Extracts a Mercurial source reference from the query pairs and the hash
+    /// fragment in the given URL.
+    fn from_url(url: &Url) -> Result<HgSource, HgSourceError> {
+        let mut kind = HgSourceKind::DefaultBranch;
+        let mut subdirectory = None;
+        for (key, val) in url.query_pairs() {
+            match &*key {
+                "branch" => kind = HgSourceKind::Branch(val.into_owned()),
+                "bookmark" => kind = HgSourceKind::Bookmark(val.into_owned()),
+                "rev" => kind = HgSourceKind::Rev(val.into_owned()),
+                "subdirectory" => subdirectory = Some(val.into_owned()),
+                _ => continue,
+            };
+        }
+        let precise = HgNode::from_str(url.fragment().ok_or(HgSourceError::MissingNode)?)
+            .map_err(|_| HgSourceError::InvalidNode)?;
+
+        Ok(HgSource {
+            precise,
+            subdirectory,
+            kind,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, serde::Deserialize)]
+enum HgSourceKind {
+    Branch(String),
+    Bookmark(String),
+    Rev(String),
+    DefaultBranch,
+}
+
+/// Construct the lockfile-compatible [`URL`] for a [`HgSourceDist`].
+fn locked_hg_url(hg_dist: &HgSourceDist) -> Url {
+    let mut url = hg_dist.hg.repository().clone();
+
+    // Redact the credentials.
+    redact_credentials(&mut url);
+
+    // Clear out any existing state.
+    url.set_fragment(None);
+    url.set_query(None);
+
+    // Put the subdirectory in the query.
+    if let Some(subdirectory) = hg_dist
+        .subdirectory
+        .as_deref()
+        .map(PortablePath::from)
+        .as_ref()
+        .map(PortablePath::to_string)
+    {
+        url.query_pairs_mut()
+            .append_pair("subdirectory", &subdirectory);
+    }
+
+    // Put the requested reference in the query.
+    match hg_dist.hg.reference() {
+        HgReference::Branch(branch) => {
+            url.query_pairs_mut().append_pair("branch", branch);
+        }
+        HgReference::Bookmark(bookmark) => {
+            url.query_pairs_mut().append_pair("bookmark", bookmark);
+        }
+        HgReference::Revision(rev) | HgReference::FullNode(rev) => {
+            url.query_pairs_mut().append_pair("rev", rev);
+        }
+        HgReference::DefaultBranch => {}
+    }
+
+    // Put the precise changeset in the fragment.
+    url.set_fragment(
+        hg_dist
+            .hg
+            .precise()
+            .as_ref()
+            .map(HgNode::to_string)
+            .as_deref(),
+    );
+
+    url
+}
+
 /// Inspired by: <https://discuss.python.org/t/lock-files-again-but-this-time-w-sdists/46593>
 #[derive(Clone, Debug, serde::Deserialize, PartialEq, Eq)]
 struct SourceDistMetadata {
@@ -2991,6 +3493,7 @@ impl SourceDist {
             // it's from a registry or a direct URL. Otherwise, it's strictly
             // redundant with the information in all other kinds of `source`.
             uv_distribution_types::SourceDist::Git(_)
+            | uv_distribution_types::SourceDist::Hg(_)
             | uv_distribution_types::SourceDist::Path(_)
             | uv_distribution_types::SourceDist::Directory(_) => Ok(None),
         }
@@ -3140,6 +3643,28 @@ impl From<GitSourceKind> for GitReference {
     }
 }
 
+impl From<HgReference> for HgSourceKind {
+    fn from(value: HgReference) -> Self {
+        match value {
+            HgReference::Branch(branch) => HgSourceKind::Branch(branch),
+            HgReference::Bookmark(bookmark) => HgSourceKind::Bookmark(bookmark),
+            HgReference::Revision(rev) | HgReference::FullNode(rev) => HgSourceKind::Rev(rev),
+            HgReference::DefaultBranch => HgSourceKind::DefaultBranch,
+        }
+    }
+}
+
+impl From<HgSourceKind> for HgReference {
+    fn from(value: HgSourceKind) -> Self {
+        match value {
+            HgSourceKind::Branch(branch) => HgReference::Branch(branch),
+            HgSourceKind::Bookmark(bookmark) => HgReference::Bookmark(bookmark),
+            HgSourceKind::Rev(rev) => HgReference::from_rev(rev),
+            HgSourceKind::DefaultBranch => HgReference::DefaultBranch,
+        }
+    }
+}
+
 /// Construct the lockfile-compatible [`URL`] for a [`GitSourceDist`].
 fn locked_git_url(git_dist: &GitSourceDist) -> Url {
     let mut url = git_dist.git.repository().clone();
@@ -3761,6 +4286,35 @@ fn normalize_requirement(
                 origin: None,
             })
         }
+        RequirementSource::Hg {
+            mut repository,
+            reference,
+            precise,
+            subdirectory,
+            url,
+        } => {
+            // Redact the credentials.
+            redact_credentials(&mut repository);
+
+            // Redact the PEP 508 URL.
+            let mut url = url.to_url();
+            redact_credentials(&mut url);
+            let url = VerbatimUrl::from_url(url);
+
+            Ok(Requirement {
+                name: requirement.name,
+                extras: requirement.extras,
+                marker: requirement.marker,
+                source: RequirementSource::Hg {
+                    repository,
+                    reference,
+                    precise,
+                    subdirectory,
+                    url,
+                },
+                origin: None,
+            })
+        }
         RequirementSource::Path {
             install_path,
             ext,
@@ -3932,6 +4486,14 @@ enum LockErrorKind {
         #[source]
         SourceParseError,
     ),
+    /// Failed to parse a Mercurial source URL.
+    #[error("Failed to parse source Mercurial URL")]
+    InvalidHgSourceUrl(
+        /// The underlying error that occurred. This includes the
+        /// errant URL in the message.
+        #[source]
+        SourceParseError,
+    ),
     /// An error that occurs when there's an unrecognized dependency.
     ///
     /// That is, a dependency for a package that isn't in the lockfile.
@@ -4149,6 +4711,37 @@ enum LockErrorKind {
         #[source]
         err: DependencyGroupError,
     },
+    /// An error that occurs when the distribution locked for a URL source doesn't match the hash
+    /// declared in `tool.uv.sources`.
+    #[error("Hash mismatch for `{id}`\n\nExpected:\n  {expected}\n\nLocked:\n{locked}")]
+    SourceHashMismatch {
+        /// The ID of the package.
+        id: PackageId,
+        /// The hash declared in `tool.uv.sources`.
+        expected: HashDigest,
+        /// The hashes of the locked distributions, one per line.
+        locked: String,
+    },
+    /// An error that occurs when `tool.uv.sources` declares a hash for a URL source, but the
+    /// package is locked from a different source.
+    #[error("Hash-checking is enabled for `{name}`, but the lockfile does not contain it from the declared URL: `{url}`\n\nExpected:\n  {expected}")]
+    MissingSourceUrl {
+        /// The name of the package.
+        name: PackageName,
+        /// The URL declared in `tool.uv.sources`.
+        url: Url,
+        /// The hash declared in `tool.uv.sources`.
+        expected: HashDigest,
+    },
+    /// An error that occurs when `tool.uv.sources` declares a hash for a URL source, but the
+    /// lockfile doesn't record any hashes for its distributions.
+    #[error("Hash-checking is enabled for `{id}`, but the lockfile does not record any hashes\n\nExpected:\n  {expected}")]
+    MissingSourceHash {
+        /// The ID of the package.
+        id: PackageId,
+        /// The hash declared in `tool.uv.sources`.
+        expected: HashDigest,
+    },
 }
 
 /// An error that occurs when a source string could not be parsed.
@@ -4175,6 +4768,18 @@ enum SourceParseError {
         /// The source string given.
         given: String,
     },
+    /// An error that occurs when a Mercurial URL is missing a precise node ID.
+    #[error("Missing node ID in source `{given}`")]
+    MissingNode {
+        /// The source string given.
+        given: String,
+    },
+    /// An error that occurs when a Mercurial URL has an invalid node ID.
+    #[error("Invalid node ID in source `{given}`")]
+    InvalidNode {
+        /// The source string given.
+        given: String,
+    },
 }
 
 /// An error that occurs when a hash digest could not be parsed.
@@ -4199,6 +4804,18 @@ impl std::fmt::Display for HashParseError {
 ///     { name = "sniffio" },
 /// ]
 /// ```
+/// Returns an array of serialized requirements.
+///
+/// In the default format, a single requirement is written inline; otherwise, each requirement is
+/// written on its own line.
+fn requirements_array(requirements: &[Value], format: LockfileFormat) -> Array {
+    match (requirements, format) {
+        ([], _) => Array::new(),
+        ([requirement], LockfileFormat::Default) => Array::from_iter([requirement]),
+        (requirements, _) => each_element_on_its_line_array(requirements.iter()),
+    }
+}
+
 fn each_element_on_its_line_array(elements: impl Iterator<Item = impl Into<Value>>) -> Array {
     let mut array = elements
         .map(|item| {
@@ -14,9 +14,10 @@ use uv_configuration::{DevGroupsManifest, EditableMode, ExtrasSpecification, Ins
 use uv_distribution_filename::{DistExtension, SourceDistExtension};
 use uv_fs::Simplified;
 use uv_git::GitReference;
+use uv_hg::HgReference;
 use uv_normalize::{ExtraName, PackageName};
 use uv_pep508::MarkerTree;
-use uv_pypi_types::{ParsedArchiveUrl, ParsedGitUrl};
+use uv_pypi_types::{ParsedArchiveUrl, ParsedGitUrl, ParsedHgUrl};
 
 use crate::graph_ops::marker_reachability;
 use crate::lock::{Package, PackageId, Source};
@@ -221,6 +222,28 @@ impl std::fmt::Display for RequirementsTxtExport<'_> {
 
                     write!(f, "{} @ {}", package.id.name, url)?;
                 }
+                Source::Hg(url, hg) => {
+                    // Remove the fragment and query from the URL; they're already present in the
+                    // `HgSource`.
+                    let mut url = url.to_url();
+                    url.set_fragment(None);
+                    url.set_query(None);
+
+                    // Reconstruct the `HgUrl` from the `HgSource`.
+                    let hg_url = uv_hg::HgUrl::from_node(
+                        url,
+                        HgReference::from(hg.kind.clone()),
+                        hg.precise,
+                    );
+
+                    // Reconstruct the PEP 508-compatible URL from the `HgSource`.
+                    let url = Url::from(ParsedHgUrl {
+                        url: hg_url.clone(),
+                        subdirectory: hg.subdirectory.as_ref().map(PathBuf::from),
+                    });
+
+                    write!(f, "{} @ {}", package.id.name, url)?;
+                }
                 Source::Direct(url, direct) => {
                     let subdirectory = direct.subdirectory.as_ref().map(PathBuf::from);
                     let url = Url::from(ParsedArchiveUrl {
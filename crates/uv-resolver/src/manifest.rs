@@ -52,6 +52,14 @@ pub struct Manifest {
     /// determinations around "allowed" versions (for example, "allowed" URLs or "allowed"
     /// pre-release versions).
     pub(crate) lookaheads: Vec<RequestedRequirements>,
+
+    /// The packages (or package versions) that should be permitted, even if they're marked as
+    /// yanked by the relevant index (e.g., `--allow-yanked flask==3.0.0`).
+    pub(crate) allowed_yanks: Vec<Requirement>,
+
+    /// The local version label (e.g., `acme`, as in `1.0.0+acme`) to prefer when selecting among
+    /// the versions that satisfy a requirement.
+    pub(crate) prefer_local_version: Option<String>,
 }
 
 impl Manifest {
@@ -76,6 +84,8 @@ impl Manifest {
             workspace_members: workspace_members.unwrap_or_default(),
             exclusions,
             lookaheads,
+            allowed_yanks: Vec::new(),
+            prefer_local_version: None,
         }
     }
 
@@ -90,6 +100,8 @@ impl Manifest {
             exclusions: Exclusions::default(),
             workspace_members: BTreeSet::new(),
             lookaheads: Vec::new(),
+            allowed_yanks: Vec::new(),
+            prefer_local_version: None,
         }
     }
 
@@ -99,6 +111,18 @@ impl Manifest {
         self
     }
 
+    #[must_use]
+    pub fn with_allowed_yanks(mut self, allowed_yanks: Vec<Requirement>) -> Self {
+        self.allowed_yanks = allowed_yanks;
+        self
+    }
+
+    #[must_use]
+    pub fn with_prefer_local_version(mut self, prefer_local_version: Option<String>) -> Self {
+        self.prefer_local_version = prefer_local_version;
+        self
+    }
+
     /// Return an iterator over all requirements, constraints, and overrides, in priority order,
     /// such that requirements come first, followed by constraints, followed by overrides.
     ///
@@ -1,16 +1,21 @@
 use uv_configuration::IndexStrategy;
 
-use crate::{DependencyMode, ExcludeNewer, PrereleaseMode, ResolutionMode};
+use crate::{
+    DependencyMode, ExcludeNewer, PrereleaseMode, ResolutionBudget, ResolutionMode, YankedMode,
+};
 
 /// Options for resolving a manifest.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub struct Options {
     pub resolution_mode: ResolutionMode,
     pub prerelease_mode: PrereleaseMode,
+    pub yanked_mode: YankedMode,
     pub dependency_mode: DependencyMode,
     pub exclude_newer: Option<ExcludeNewer>,
     pub index_strategy: IndexStrategy,
     pub flexibility: Flexibility,
+    pub minimal_changes: bool,
+    pub budget: ResolutionBudget,
 }
 
 /// Builder for [`Options`].
@@ -18,10 +23,13 @@ pub struct Options {
 pub struct OptionsBuilder {
     resolution_mode: ResolutionMode,
     prerelease_mode: PrereleaseMode,
+    yanked_mode: YankedMode,
     dependency_mode: DependencyMode,
     exclude_newer: Option<ExcludeNewer>,
     index_strategy: IndexStrategy,
     flexibility: Flexibility,
+    minimal_changes: bool,
+    budget: ResolutionBudget,
 }
 
 impl OptionsBuilder {
@@ -44,6 +52,13 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets the [`YankedMode`].
+    #[must_use]
+    pub fn yanked_mode(mut self, yanked_mode: YankedMode) -> Self {
+        self.yanked_mode = yanked_mode;
+        self
+    }
+
     /// Sets the dependency mode.
     #[must_use]
     pub fn dependency_mode(mut self, dependency_mode: DependencyMode) -> Self {
@@ -72,15 +87,33 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets whether to select the nearest version to a preference that doesn't satisfy the
+    /// requirements, rather than the highest (or lowest) compatible version.
+    #[must_use]
+    pub fn minimal_changes(mut self, minimal_changes: bool) -> Self {
+        self.minimal_changes = minimal_changes;
+        self
+    }
+
+    /// Sets the [`ResolutionBudget`].
+    #[must_use]
+    pub fn budget(mut self, budget: ResolutionBudget) -> Self {
+        self.budget = budget;
+        self
+    }
+
     /// Builds the options.
     pub fn build(self) -> Options {
         Options {
             resolution_mode: self.resolution_mode,
             prerelease_mode: self.prerelease_mode,
+            yanked_mode: self.yanked_mode,
             dependency_mode: self.dependency_mode,
             exclude_newer: self.exclude_newer,
             index_strategy: self.index_strategy,
             flexibility: self.flexibility,
+            minimal_changes: self.minimal_changes,
+            budget: self.budget,
         }
     }
 }
@@ -6,8 +6,8 @@ use tracing::warn;
 use uv_normalize::{ExtraName, PackageName};
 use uv_pep440::{Version, VersionSpecifiers};
 use uv_pypi_types::{
-    ParsedArchiveUrl, ParsedDirectoryUrl, ParsedGitUrl, ParsedPathUrl, ParsedUrl, Requirement,
-    RequirementSource, VerbatimParsedUrl,
+    ParsedArchiveUrl, ParsedDirectoryUrl, ParsedGitUrl, ParsedHgUrl, ParsedPathUrl, ParsedUrl,
+    Requirement, RequirementSource, VerbatimParsedUrl,
 };
 
 use crate::pubgrub::{PubGrubPackage, PubGrubPackageInner};
@@ -126,6 +126,21 @@ impl PubGrubRequirement {
                 ));
                 (url, parsed_url)
             }
+            RequirementSource::Hg {
+                repository,
+                reference,
+                precise,
+                url,
+                subdirectory,
+            } => {
+                let parsed_url = ParsedUrl::Hg(ParsedHgUrl::from_source(
+                    repository.clone(),
+                    reference.clone(),
+                    *precise,
+                    subdirectory.clone(),
+                ));
+                (url, parsed_url)
+            }
             RequirementSource::Path {
                 ext,
                 url,
@@ -1,36 +1,65 @@
 use url::Url;
 use uv_git::{GitReference, GitResolver};
+use uv_hg::{HgReference, HgResolver};
 use uv_pep508::VerbatimUrl;
-use uv_pypi_types::{ParsedGitUrl, ParsedUrl, VerbatimParsedUrl};
+use uv_pypi_types::{ParsedGitUrl, ParsedHgUrl, ParsedUrl, VerbatimParsedUrl};
 
 /// Map a URL to a precise URL, if possible.
-pub(crate) fn url_to_precise(url: VerbatimParsedUrl, git: &GitResolver) -> VerbatimParsedUrl {
-    let ParsedUrl::Git(ParsedGitUrl {
-        url: git_url,
-        subdirectory,
-    }) = &url.parsed_url
-    else {
-        return url;
-    };
+pub(crate) fn url_to_precise(
+    url: VerbatimParsedUrl,
+    git: &GitResolver,
+    hg: &HgResolver,
+) -> VerbatimParsedUrl {
+    match &url.parsed_url {
+        ParsedUrl::Git(ParsedGitUrl {
+            url: git_url,
+            subdirectory,
+        }) => {
+            let Some(new_git_url) = git.precise(git_url.clone()) else {
+                debug_assert!(
+                    matches!(git_url.reference(), GitReference::FullCommit(_)),
+                    "Unseen Git URL: {}, {git_url:?}",
+                    url.verbatim,
+                );
+                return url;
+            };
 
-    let Some(new_git_url) = git.precise(git_url.clone()) else {
-        debug_assert!(
-            matches!(git_url.reference(), GitReference::FullCommit(_)),
-            "Unseen Git URL: {}, {git_url:?}",
-            url.verbatim,
-        );
-        return url;
-    };
+            let new_parsed_url = ParsedGitUrl {
+                url: new_git_url,
+                subdirectory: subdirectory.clone(),
+            };
+            let new_url = Url::from(new_parsed_url.clone());
+            let new_verbatim_url = apply_redirect(&url.verbatim, new_url);
+            VerbatimParsedUrl {
+                parsed_url: ParsedUrl::Git(new_parsed_url),
+                verbatim: new_verbatim_url,
+            }
+        }
+        ParsedUrl::Hg(ParsedHgUrl {
+            url: hg_url,
+            subdirectory,
+        }) => {
+            let Some(new_hg_url) = hg.precise(hg_url.clone()) else {
+                debug_assert!(
+                    matches!(hg_url.reference(), HgReference::FullNode(_)),
+                    "Unseen Mercurial URL: {}, {hg_url:?}",
+                    url.verbatim,
+                );
+                return url;
+            };
 
-    let new_parsed_url = ParsedGitUrl {
-        url: new_git_url,
-        subdirectory: subdirectory.clone(),
-    };
-    let new_url = Url::from(new_parsed_url.clone());
-    let new_verbatim_url = apply_redirect(&url.verbatim, new_url);
-    VerbatimParsedUrl {
-        parsed_url: ParsedUrl::Git(new_parsed_url),
-        verbatim: new_verbatim_url,
+            let new_parsed_url = ParsedHgUrl {
+                url: new_hg_url,
+                subdirectory: subdirectory.clone(),
+            };
+            let new_url = Url::from(new_parsed_url.clone());
+            let new_verbatim_url = apply_redirect(&url.verbatim, new_url);
+            VerbatimParsedUrl {
+                parsed_url: ParsedUrl::Hg(new_parsed_url),
+                verbatim: new_verbatim_url,
+            }
+        }
+        _ => url,
     }
 }
 
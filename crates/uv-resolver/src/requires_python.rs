@@ -93,6 +93,46 @@ impl RequiresPython {
         })
     }
 
+    /// Returns a [`RequiresPython`] to express the union of the given version specifiers.
+    ///
+    /// For example, given `>=3.8` and `>=3.9`, this would return `>=3.8`. Any gaps between the
+    /// specifiers are filled, such that `==3.8.*` and `>=3.10` would return `>=3.8`.
+    pub fn union<'a>(specifiers: impl Iterator<Item = &'a VersionSpecifiers>) -> Option<Self> {
+        // Convert to PubGrub range and perform a union.
+        let range = specifiers
+            .into_iter()
+            .map(|specifier| release_specifiers_to_ranges(specifier.clone()))
+            .fold(None, |range: Option<Range<Version>>, requires_python| {
+                if let Some(range) = range {
+                    Some(range.union(&requires_python))
+                } else {
+                    Some(requires_python)
+                }
+            })?;
+
+        // Extract the bounds, discarding any gaps.
+        let (lower_bound, upper_bound) = range
+            .bounding_range()
+            .map(|(lower_bound, upper_bound)| {
+                (
+                    LowerBound(lower_bound.cloned()),
+                    UpperBound(upper_bound.cloned()),
+                )
+            })
+            .unwrap_or((LowerBound::default(), UpperBound::default()));
+
+        // Convert back to PEP 440 specifiers.
+        let specifiers = VersionSpecifiers::from_release_only_bounds(std::iter::once((
+            &*lower_bound,
+            &*upper_bound,
+        )));
+
+        Some(Self {
+            specifiers,
+            range: RequiresPythonRange(lower_bound, upper_bound),
+        })
+    }
+
     /// Narrow the [`RequiresPython`] by computing the intersection with the given range.
     pub fn narrow(&self, range: &RequiresPythonRange) -> Option<Self> {
         let lower = if range.0 >= self.range.0 {
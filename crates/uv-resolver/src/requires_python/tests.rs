@@ -156,3 +156,21 @@ fn is_exact_without_patch() {
         assert_eq!(requires_python.is_exact_without_patch(), expected);
     }
 }
+
+#[test]
+fn union() {
+    let test_cases = [
+        (vec![">=3.8", ">=3.9"], ">=3.8"),
+        (vec![">=3.8,<3.10", ">=3.9,<3.12"], ">=3.8, <3.12"),
+        (vec!["==3.8.*", ">=3.10"], ">=3.8"),
+        (vec![">=3.12"], ">=3.12"),
+    ];
+    for (specifiers, expected) in test_cases {
+        let specifiers = specifiers
+            .into_iter()
+            .map(|specifier| VersionSpecifiers::from_str(specifier).unwrap())
+            .collect::<Vec<_>>();
+        let requires_python = RequiresPython::union(specifiers.iter()).unwrap();
+        assert_eq!(requires_python.to_string(), expected);
+    }
+}
@@ -14,6 +14,7 @@ use uv_distribution_types::{
     VersionOrUrlRef,
 };
 use uv_git::GitResolver;
+use uv_hg::HgResolver;
 use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::{Version, VersionSpecifier};
 use uv_pep508::{MarkerEnvironment, MarkerTree, MarkerTreeKind};
@@ -100,6 +101,7 @@ impl ResolutionGraph {
         preferences: &Preferences,
         index: &InMemoryIndex,
         git: &GitResolver,
+        hg: &HgResolver,
         python: &PythonRequirement,
         resolution_strategy: &ResolutionStrategy,
         options: Options,
@@ -144,6 +146,7 @@ impl ResolutionGraph {
                     &resolution.pins,
                     index,
                     git,
+                    hg,
                     package,
                     version,
                 )?;
@@ -319,6 +322,7 @@ impl ResolutionGraph {
         pins: &FilePins,
         in_memory: &InMemoryIndex,
         git: &GitResolver,
+        hg: &HgResolver,
         package: &'a ResolutionPackage,
         version: &'a Version,
     ) -> Result<(), ResolveError> {
@@ -340,6 +344,7 @@ impl ResolutionGraph {
             preferences,
             in_memory,
             git,
+            hg,
         )?;
 
         if let Some(metadata) = metadata.as_ref() {
@@ -399,10 +404,11 @@ impl ResolutionGraph {
         preferences: &Preferences,
         in_memory: &InMemoryIndex,
         git: &GitResolver,
+        hg: &HgResolver,
     ) -> Result<(ResolvedDist, Vec<HashDigest>, Option<Metadata>), ResolveError> {
         Ok(if let Some(url) = url {
             // Create the distribution.
-            let dist = Dist::from_url(name.clone(), url_to_precise(url.clone(), git))?;
+            let dist = Dist::from_url(name.clone(), url_to_precise(url.clone(), git, hg))?;
 
             let version_id = VersionId::from_url(&url.verbatim);
 
@@ -547,7 +553,7 @@ impl ResolutionGraph {
     }
 
     /// Returns an iterator over the distinct packages in the graph.
-    fn dists(&self) -> impl Iterator<Item = &AnnotatedDist> {
+    pub(crate) fn dists(&self) -> impl Iterator<Item = &AnnotatedDist> {
         self.petgraph
             .node_indices()
             .filter_map(move |index| match &self.petgraph[index] {
@@ -576,6 +582,21 @@ impl ResolutionGraph {
         &self.diagnostics
     }
 
+    /// Returns an iterator over a [`Requirement`] pinning each distinct package in the graph to its
+    /// resolved version (or source), along with the hashes of the resolved distribution.
+    ///
+    /// Registry requirements include the index from which the package was resolved, and each
+    /// requirement includes the markers under which the package is included in the resolution.
+    pub fn pinned_requirements(&self) -> impl Iterator<Item = (Requirement, &[HashDigest])> {
+        self.dists().filter(|dist| dist.is_base()).map(|dist| {
+            let requirement = Requirement {
+                marker: dist.marker.clone(),
+                ..Requirement::from(&dist.dist)
+            };
+            (requirement, dist.hashes.as_slice())
+        })
+    }
+
     /// Return the marker tree specific to this resolution.
     ///
     /// This accepts an in-memory-index and marker environment, all
@@ -802,6 +823,12 @@ impl std::fmt::Display for ConflictingDistributionError {
 
 impl From<ResolutionGraph> for uv_distribution_types::Resolution {
     fn from(graph: ResolutionGraph) -> Self {
+        Self::from(&graph)
+    }
+}
+
+impl From<&ResolutionGraph> for uv_distribution_types::Resolution {
+    fn from(graph: &ResolutionGraph) -> Self {
         Self::new(
             graph
                 .dists()
@@ -811,7 +838,7 @@ impl From<ResolutionGraph> for uv_distribution_types::Resolution {
                 .dists()
                 .map(|node| (node.name().clone(), node.hashes.clone()))
                 .collect(),
-            graph.diagnostics,
+            graph.diagnostics.clone(),
         )
     }
 }
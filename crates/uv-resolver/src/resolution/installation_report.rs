@@ -0,0 +1,194 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use uv_distribution_types::{BuiltDist, Dist, Name, ResolvedDist, SourceDist, ToUrlError};
+use uv_normalize::{ExtraName, PackageName};
+use uv_pep440::Version;
+use uv_pep508::{MarkerEnvironment, VerbatimUrl};
+use uv_pypi_types::{ArchiveInfo, DirectUrl, ParsedUrlError, Requirement};
+
+use crate::resolution::AnnotatedDist;
+use crate::ResolutionGraph;
+
+/// The version of the installation report format.
+const REPORT_VERSION: &str = "1";
+
+#[derive(Debug, thiserror::Error)]
+pub enum InstallationReportError {
+    #[error(transparent)]
+    ToUrl(#[from] ToUrlError),
+
+    #[error(transparent)]
+    ParsedUrl(#[from] ParsedUrlError),
+
+    #[error("Failed to determine the source of `{0}`")]
+    MissingSource(PackageName),
+}
+
+/// A report of the distributions selected for installation, in the format produced by pip's
+/// `--report` option.
+///
+/// Distributions that are already installed in the environment are omitted from the report.
+///
+/// See: <https://pip.pypa.io/en/stable/reference/installation-report/>
+#[derive(Debug, Serialize)]
+pub struct InstallationReport<'a> {
+    version: &'static str,
+    pip_version: &'a str,
+    install: Vec<InstallationReportItem<'a>>,
+    environment: &'a MarkerEnvironment,
+}
+
+/// A distribution selected for installation.
+#[derive(Debug, Serialize)]
+struct InstallationReportItem<'a> {
+    /// The location from which the distribution would be downloaded, in the format of PEP 610's
+    /// `direct_url.json`.
+    download_info: DirectUrl,
+    /// Whether the distribution was requested via a direct URL, rather than from a registry.
+    is_direct: bool,
+    /// Whether the selected distribution has been yanked from the registry.
+    is_yanked: bool,
+    /// Whether the package was requested directly, rather than as a dependency.
+    requested: bool,
+    /// The extras that were requested for the package, if it was requested directly.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    requested_extras: Vec<&'a ExtraName>,
+    /// The core metadata of the distribution.
+    metadata: InstallationReportMetadata<'a>,
+}
+
+/// The subset of a distribution's core metadata that's known after resolution, in the JSON form
+/// described by PEP 566.
+#[derive(Debug, Serialize)]
+struct InstallationReportMetadata<'a> {
+    name: &'a PackageName,
+    version: &'a Version,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    requires_dist: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    requires_python: Option<String>,
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    provides_extra: &'a [ExtraName],
+}
+
+impl<'a> InstallationReport<'a> {
+    /// Create an [`InstallationReport`] from a [`ResolutionGraph`].
+    ///
+    /// The `installer_version` is reported in place of pip's version.
+    pub fn from_resolution(
+        resolution: &'a ResolutionGraph,
+        environment: &'a MarkerEnvironment,
+        installer_version: &'a str,
+    ) -> Result<Self, InstallationReportError> {
+        // Index the extras requested for each direct requirement.
+        let mut requested: BTreeMap<&PackageName, Vec<&ExtraName>> = BTreeMap::new();
+        for requirement in &resolution.requirements {
+            requested
+                .entry(&requirement.name)
+                .or_default()
+                .extend(requirement.extras.iter());
+        }
+
+        let mut install = resolution
+            .dists()
+            .filter(|dist| dist.is_base())
+            .filter_map(|dist| match &dist.dist {
+                ResolvedDist::Installable(installable) => Some((dist, installable)),
+                ResolvedDist::Installed(_) => None,
+            })
+            .map(|(dist, installable)| {
+                let requested_extras = requested.get(&dist.name);
+                Ok(InstallationReportItem {
+                    download_info: download_info(&dist.dist, installable)?,
+                    is_direct: !matches!(
+                        installable,
+                        Dist::Built(BuiltDist::Registry(_)) | Dist::Source(SourceDist::Registry(_))
+                    ),
+                    is_yanked: dist.dist.yanked().is_some_and(|yanked| yanked.is_yanked()),
+                    requested: requested_extras.is_some(),
+                    requested_extras: requested_extras
+                        .map(|extras| {
+                            let mut extras = extras.clone();
+                            extras.sort_unstable();
+                            extras.dedup();
+                            extras
+                        })
+                        .unwrap_or_default(),
+                    metadata: InstallationReportMetadata::from_dist(dist),
+                })
+            })
+            .collect::<Result<Vec<_>, InstallationReportError>>()?;
+        install.sort_by(|a, b| a.metadata.name.cmp(b.metadata.name));
+
+        Ok(Self {
+            version: REPORT_VERSION,
+            pip_version: installer_version,
+            install,
+            environment,
+        })
+    }
+}
+
+impl<'a> InstallationReportMetadata<'a> {
+    fn from_dist(dist: &'a AnnotatedDist) -> Self {
+        match dist.metadata.as_ref() {
+            Some(metadata) => Self {
+                name: &metadata.name,
+                version: &metadata.version,
+                requires_dist: metadata
+                    .requires_dist
+                    .iter()
+                    .cloned()
+                    .map(uv_pep508::Requirement::<VerbatimUrl>::from)
+                    .map(|requirement| requirement.to_string())
+                    .collect(),
+                requires_python: metadata.requires_python.as_ref().map(ToString::to_string),
+                provides_extra: &metadata.provides_extras,
+            },
+            None => Self {
+                name: &dist.name,
+                version: &dist.version,
+                requires_dist: Vec::new(),
+                requires_python: None,
+                provides_extra: &[],
+            },
+        }
+    }
+}
+
+/// Return the PEP 610 download information for a distribution.
+fn download_info(
+    resolved: &ResolvedDist,
+    dist: &Dist,
+) -> Result<DirectUrl, InstallationReportError> {
+    // For registry distributions, report the selected file.
+    if let Some(file) = dist.file() {
+        let hashes = file
+            .hashes
+            .iter()
+            .map(|digest| (digest.algorithm.to_string(), digest.digest.to_string()))
+            .collect::<BTreeMap<_, _>>();
+        let hash = file
+            .hashes
+            .first()
+            .map(|digest| format!("{}={}", digest.algorithm, digest.digest));
+        return Ok(DirectUrl::ArchiveUrl {
+            url: file.url.to_url()?.to_string(),
+            archive_info: ArchiveInfo {
+                hash,
+                hashes: (!hashes.is_empty()).then_some(hashes),
+            },
+            subdirectory: None,
+        });
+    }
+
+    // Otherwise, report the direct URL from which the distribution was requested.
+    let requirement = Requirement::from(resolved);
+    let parsed_url = requirement
+        .source
+        .to_verbatim_parsed_url()
+        .ok_or_else(|| InstallationReportError::MissingSource(dist.name().clone()))?;
+    Ok(DirectUrl::try_from(&parsed_url.parsed_url)?)
+}
@@ -13,10 +13,12 @@ use uv_pypi_types::HashDigest;
 pub use crate::resolution::display::{AnnotationStyle, DisplayResolutionGraph};
 pub(crate) use crate::resolution::graph::ResolutionGraphNode;
 pub use crate::resolution::graph::{ConflictingDistributionError, ResolutionGraph};
+pub use crate::resolution::installation_report::{InstallationReport, InstallationReportError};
 pub(crate) use crate::resolution::requirements_txt::RequirementsTxtDist;
 
 mod display;
 mod graph;
+mod installation_report;
 mod requirements_txt;
 
 /// A pinned package with its resolved distribution and metadata. The [`ResolvedDist`] refers to a
@@ -60,6 +62,7 @@ impl AnnotatedDist {
                     SourceDist::Registry(dist) => Some(&dist.index),
                     SourceDist::DirectUrl(_) => None,
                     SourceDist::Git(_) => None,
+                    SourceDist::Hg(_) => None,
                     SourceDist::Path(_) => None,
                     SourceDist::Directory(_) => None,
                 },
@@ -0,0 +1,207 @@
+use std::fmt::{Display, Formatter};
+use std::time::{Duration, Instant};
+
+use rustc_hash::FxHashMap;
+
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+
+use crate::pubgrub::{PubGrubPackage, PubGrubPackageInner};
+
+/// The number of packages to include when reporting an exceeded [`ResolutionBudget`].
+const REPORTED_PACKAGES: usize = 5;
+
+/// A limit on the number of steps, or the amount of time, that a resolution may take.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ResolutionBudget {
+    /// The maximum number of versions to try across all packages.
+    max_steps: Option<usize>,
+    /// The maximum amount of time to spend resolving, including fetching metadata.
+    timeout: Option<Duration>,
+}
+
+impl ResolutionBudget {
+    /// Create a [`ResolutionBudget`] with the given limits.
+    pub fn new(max_steps: Option<usize>, timeout: Option<Duration>) -> Self {
+        Self { max_steps, timeout }
+    }
+}
+
+/// Tracks the progress of a resolution against its [`ResolutionBudget`], along with the versions
+/// tried for each package, to explain which packages drove the search if the budget is exceeded.
+#[derive(Debug)]
+pub(crate) struct BudgetTracker {
+    budget: ResolutionBudget,
+    start: Instant,
+    steps: usize,
+    tried: FxHashMap<PackageName, TriedVersions>,
+}
+
+#[derive(Debug)]
+struct TriedVersions {
+    count: usize,
+    lowest: Version,
+    highest: Version,
+}
+
+impl BudgetTracker {
+    pub(crate) fn new(budget: ResolutionBudget) -> Self {
+        Self {
+            budget,
+            start: Instant::now(),
+            steps: 0,
+            tried: FxHashMap::default(),
+        }
+    }
+
+    /// The instant at which the resolution will exceed its timeout, if any.
+    pub(crate) fn deadline(&self) -> Option<Instant> {
+        self.budget.timeout.map(|timeout| self.start + timeout)
+    }
+
+    /// Record that a version was tried for the given package.
+    pub(crate) fn version_tried(&mut self, package: &PubGrubPackage, version: &Version) {
+        self.steps += 1;
+
+        // Only track base packages, no virtual packages from extras.
+        let PubGrubPackageInner::Package {
+            name,
+            extra: None,
+            dev: None,
+            marker: None,
+        } = &**package
+        else {
+            return;
+        };
+        self.tried
+            .entry(name.clone())
+            .and_modify(|tried| {
+                tried.count += 1;
+                if *version < tried.lowest {
+                    tried.lowest = version.clone();
+                }
+                if *version > tried.highest {
+                    tried.highest = version.clone();
+                }
+            })
+            .or_insert_with(|| TriedVersions {
+                count: 1,
+                lowest: version.clone(),
+                highest: version.clone(),
+            });
+    }
+
+    /// Return an error if the resolution has exceeded its budget.
+    pub(crate) fn check(&self) -> Result<(), Box<BudgetExceeded>> {
+        let limit = if let Some(max_steps) = self
+            .budget
+            .max_steps
+            .filter(|max_steps| self.steps >= *max_steps)
+        {
+            BudgetLimit::Steps(max_steps)
+        } else if let Some(timeout) = self
+            .budget
+            .timeout
+            .filter(|timeout| self.start.elapsed() >= *timeout)
+        {
+            BudgetLimit::Timeout(timeout)
+        } else {
+            return Ok(());
+        };
+        Err(self.exceeded(limit))
+    }
+
+    /// Return the error for a resolution that exceeded its timeout while waiting on metadata.
+    pub(crate) fn timed_out(&self) -> Box<BudgetExceeded> {
+        self.exceeded(BudgetLimit::Timeout(
+            self.budget.timeout.unwrap_or_default(),
+        ))
+    }
+
+    fn exceeded(&self, limit: BudgetLimit) -> Box<BudgetExceeded> {
+        // Report the packages for which the most versions were tried, i.e., those that required
+        // the most backtracking.
+        let mut packages = self
+            .tried
+            .iter()
+            .filter(|(_, tried)| tried.count > 1)
+            .map(|(name, tried)| BacktrackedPackage {
+                name: name.clone(),
+                count: tried.count,
+                lowest: tried.lowest.clone(),
+                highest: tried.highest.clone(),
+            })
+            .collect::<Vec<_>>();
+        packages.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.name.cmp(&b.name)));
+        packages.truncate(REPORTED_PACKAGES);
+
+        Box::new(BudgetExceeded {
+            limit,
+            steps: self.steps,
+            elapsed: self.start.elapsed(),
+            packages,
+        })
+    }
+}
+
+#[derive(Debug)]
+enum BudgetLimit {
+    Steps(usize),
+    Timeout(Duration),
+}
+
+#[derive(Debug)]
+struct BacktrackedPackage {
+    name: PackageName,
+    count: usize,
+    lowest: Version,
+    highest: Version,
+}
+
+/// A resolution that exceeded its [`ResolutionBudget`].
+#[derive(Debug)]
+pub struct BudgetExceeded {
+    limit: BudgetLimit,
+    steps: usize,
+    elapsed: Duration,
+    packages: Vec<BacktrackedPackage>,
+}
+
+impl Display for BudgetExceeded {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.limit {
+            BudgetLimit::Steps(max_steps) => write!(
+                f,
+                "Resolution exceeded the limit of {max_steps} steps (`--resolution-max-steps`) after {:.1}s",
+                self.elapsed.as_secs_f32()
+            )?,
+            BudgetLimit::Timeout(timeout) => write!(
+                f,
+                "Resolution exceeded the timeout of {}s (`--resolution-timeout`) after {} steps",
+                timeout.as_secs(),
+                self.steps
+            )?,
+        }
+
+        if self.packages.is_empty() {
+            return Ok(());
+        }
+
+        write!(
+            f,
+            "\n\nThe following packages required the most backtracking:"
+        )?;
+        for package in &self.packages {
+            write!(
+                f,
+                "\n  - {}: tried {} versions, from {} to {}",
+                package.name, package.count, package.highest, package.lowest
+            )?;
+        }
+        write!(
+            f,
+            "\n\nhint: Consider adding lower bounds on these packages to narrow the range of versions considered"
+        )?;
+        Ok(())
+    }
+}
@@ -21,12 +21,26 @@ struct SharedInMemoryIndex {
     explicit: FxOnceMap<(PackageName, IndexUrl), Arc<VersionsResponse>>,
 
     /// A map from package ID to metadata for that distribution.
-    distributions: FxOnceMap<VersionId, Arc<MetadataResponse>>,
+    distributions: Arc<FxOnceMap<VersionId, Arc<MetadataResponse>>>,
 }
 
 pub(crate) type FxOnceMap<K, V> = OnceMap<K, V, BuildHasherDefault<FxHasher>>;
 
 impl InMemoryIndex {
+    /// Create an empty index that shares the distribution metadata of this index.
+    ///
+    /// Unlike the package metadata, which is built against the tags and Python requirement of a
+    /// resolution, distribution metadata is independent of the resolution environment, so it can
+    /// be reused across resolutions for different environments.
+    #[must_use]
+    pub fn with_shared_distributions(&self) -> Self {
+        Self(Arc::new(SharedInMemoryIndex {
+            implicit: FxOnceMap::default(),
+            explicit: FxOnceMap::default(),
+            distributions: self.0.distributions.clone(),
+        }))
+    }
+
     /// Returns a reference to the package metadata map.
     pub fn implicit(&self) -> &FxOnceMap<PackageName, Arc<VersionsResponse>> {
         &self.0.implicit
@@ -175,7 +175,7 @@ pub(crate) fn from_source(source: &RequirementSource) -> Option<Version> {
                 }
             })
             .filter(uv_pep440::Version::is_local),
-        RequirementSource::Git { .. } => None,
+        RequirementSource::Git { .. } | RequirementSource::Hg { .. } => None,
         RequirementSource::Path {
             install_path: path, ..
         } => path
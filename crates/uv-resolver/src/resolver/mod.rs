@@ -5,7 +5,7 @@ use std::cmp::Ordering;
 use std::collections::{BTreeMap, BTreeSet, VecDeque};
 use std::fmt::{Display, Formatter, Write};
 use std::ops::Bound;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::time::Instant;
 use std::{iter, thread};
 
@@ -33,6 +33,7 @@ use uv_distribution_types::{
     VersionOrUrlRef,
 };
 use uv_git::GitResolver;
+use uv_hg::HgResolver;
 use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::{release_specifiers_to_ranges, Version, MIN_VERSION};
 use uv_pep508::MarkerTree;
@@ -60,6 +61,8 @@ pub(crate) use crate::resolver::availability::{
     IncompletePackage, ResolverVersion, UnavailablePackage, UnavailableReason, UnavailableVersion,
 };
 use crate::resolver::batch_prefetch::BatchPrefetcher;
+use crate::resolver::budget::BudgetTracker;
+pub use crate::resolver::budget::{BudgetExceeded, ResolutionBudget};
 use crate::resolver::groups::Groups;
 pub use crate::resolver::index::InMemoryIndex;
 use crate::resolver::indexes::Indexes;
@@ -74,6 +77,7 @@ use crate::{marker, DependencyMode, Exclusions, FlatIndex, Options};
 
 mod availability;
 mod batch_prefetch;
+mod budget;
 mod fork_map;
 mod groups;
 mod index;
@@ -99,6 +103,7 @@ struct ResolverState<InstalledPackages: InstalledPackagesProvider> {
     groups: Groups,
     preferences: Preferences,
     git: GitResolver,
+    hg: HgResolver,
     capabilities: IndexCapabilities,
     locations: IndexLocations,
     exclusions: Exclusions,
@@ -119,6 +124,8 @@ struct ResolverState<InstalledPackages: InstalledPackagesProvider> {
     incomplete_packages: DashMap<PackageName, DashMap<Version, IncompletePackage>>,
     /// The options that were used to configure this resolver.
     options: Options,
+    /// The limit on the number of steps and the amount of time to spend solving.
+    budget: Mutex<BudgetTracker>,
     /// The reporter to use for this resolver.
     reporter: Option<Arc<dyn Reporter>>,
 }
@@ -162,7 +169,12 @@ impl<'a, Context: BuildContext, InstalledPackages: InstalledPackagesProvider>
             flat_index,
             tags,
             python_requirement.target(),
-            AllowedYanks::from_manifest(&manifest, &markers, options.dependency_mode),
+            AllowedYanks::from_manifest(
+                &manifest,
+                &markers,
+                options.dependency_mode,
+                options.yanked_mode,
+            ),
             hasher,
             options.exclude_newer,
             build_context.build_options(),
@@ -177,6 +189,7 @@ impl<'a, Context: BuildContext, InstalledPackages: InstalledPackagesProvider>
             python_requirement,
             index,
             build_context.git(),
+            build_context.hg(),
             build_context.capabilities(),
             build_context.locations(),
             provider,
@@ -197,6 +210,7 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
         python_requirement: &PythonRequirement,
         index: &InMemoryIndex,
         git: &GitResolver,
+        hg: &HgResolver,
         capabilities: &IndexCapabilities,
         locations: &IndexLocations,
         provider: Provider,
@@ -205,10 +219,11 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
         let state = ResolverState {
             index: index.clone(),
             git: git.clone(),
+            hg: hg.clone(),
             capabilities: capabilities.clone(),
             selector: CandidateSelector::for_resolution(options, &manifest, &markers),
             dependency_mode: options.dependency_mode,
-            urls: Urls::from_manifest(&manifest, &markers, git, options.dependency_mode)?,
+            urls: Urls::from_manifest(&manifest, &markers, git, hg, options.dependency_mode)?,
             locals: Locals::from_manifest(&manifest, &markers, options.dependency_mode),
             indexes: Indexes::from_manifest(&manifest, &markers, options.dependency_mode),
             groups: Groups::from_manifest(&manifest, &markers),
@@ -227,6 +242,7 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
             unavailable_packages: DashMap::default(),
             incomplete_packages: DashMap::default(),
             options,
+            budget: Mutex::new(BudgetTracker::new(options.budget)),
             reporter: None,
         };
         Ok(Self { state, provider })
@@ -472,6 +488,13 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                     }
                 };
 
+                // Abort if the resolution has exceeded its budget.
+                {
+                    let mut budget = self.budget.lock().unwrap();
+                    budget.version_tried(&state.next, &version);
+                    budget.check().map_err(ResolveError::BudgetExceeded)?;
+                }
+
                 // Only consider registry packages for prefetch.
                 if url.is_none() {
                     prefetcher.prefetch_batches(
@@ -541,6 +564,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                             &self.locals,
                             dependencies.clone(),
                             &self.git,
+                            &self.hg,
                             self.selector.resolution_strategy(),
                         )?;
 
@@ -608,6 +632,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             &self.preferences,
             &self.index,
             &self.git,
+            &self.hg,
             &self.python_requirement,
             self.selector.resolution_strategy(),
             self.options,
@@ -714,6 +739,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                     &self.locals,
                     fork.dependencies.clone(),
                     &self.git,
+                    &self.hg,
                     self.selector.resolution_strategy(),
                 )?;
                 // Emit a request to fetch the metadata for each registry package.
@@ -1698,7 +1724,32 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             // and `SourceDispatch`, as well as the bounded request channel.
             .buffer_unordered(usize::MAX);
 
-        while let Some(response) = response_stream.next().await {
+        // Enforce the timeout while waiting on metadata, since a single slow request (e.g., a
+        // source distribution build) could otherwise overrun it.
+        let deadline = self
+            .budget
+            .lock()
+            .unwrap()
+            .deadline()
+            .map(tokio::time::Instant::from_std);
+
+        loop {
+            let response = if let Some(deadline) = deadline {
+                if let Ok(response) =
+                    tokio::time::timeout_at(deadline, response_stream.next()).await
+                {
+                    response
+                } else {
+                    let budget = self.budget.lock().unwrap();
+                    return Err(ResolveError::BudgetExceeded(budget.timed_out()));
+                }
+            } else {
+                response_stream.next().await
+            };
+            let Some(response) = response else {
+                break;
+            };
+
             match response? {
                 Some(Response::Package(name, index, version_map)) => {
                     trace!("Received package metadata for: {name}");
@@ -2192,6 +2243,7 @@ impl ForkState {
         locals: &Locals,
         mut dependencies: Vec<PubGrubDependency>,
         git: &GitResolver,
+        hg: &HgResolver,
         resolution_strategy: &ResolutionStrategy,
     ) -> Result<(), ResolveError> {
         for dependency in &mut dependencies {
@@ -2208,7 +2260,7 @@ impl ForkState {
                 // requirement was a URL requirement. `Urls` applies canonicalization to this and
                 // override URLs to both URL and registry requirements, which we then check for
                 // conflicts using [`ForkUrl`].
-                if let Some(url) = urls.get_url(name, url.as_ref(), git)? {
+                if let Some(url) = urls.get_url(name, url.as_ref(), git, hg)? {
                     self.fork_urls.insert(name, url, &self.markers)?;
                     has_url = true;
                 };
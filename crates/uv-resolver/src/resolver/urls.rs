@@ -7,6 +7,7 @@ use tracing::debug;
 use uv_cache_key::CanonicalUrl;
 use uv_distribution_types::Verbatim;
 use uv_git::GitResolver;
+use uv_hg::HgResolver;
 use uv_normalize::PackageName;
 use uv_pep508::VerbatimUrl;
 use uv_pypi_types::{ParsedDirectoryUrl, ParsedUrl, VerbatimParsedUrl};
@@ -38,6 +39,7 @@ impl Urls {
         manifest: &Manifest,
         markers: &ResolverMarkers,
         git: &GitResolver,
+        hg: &HgResolver,
         dependencies: DependencyMode,
     ) -> Result<Self, ResolveError> {
         let mut urls: FxHashMap<PackageName, Vec<VerbatimParsedUrl>> = FxHashMap::default();
@@ -51,10 +53,9 @@ impl Urls {
             };
 
             let package_urls = urls.entry(requirement.name.clone()).or_default();
-            if let Some(package_url) = package_urls
-                .iter_mut()
-                .find(|package_url| same_resource(&package_url.parsed_url, &url.parsed_url, git))
-            {
+            if let Some(package_url) = package_urls.iter_mut().find(|package_url| {
+                same_resource(&package_url.parsed_url, &url.parsed_url, git, hg)
+            }) {
                 // Allow editables to override non-editables.
                 let previous_editable = package_url.is_editable();
                 *package_url = url;
@@ -88,7 +89,7 @@ impl Urls {
             urls.remove(&requirement.name);
             let previous = overrides.insert(requirement.name.clone(), url.clone());
             if let Some(previous) = previous {
-                if !same_resource(&previous.parsed_url, &url.parsed_url, git) {
+                if !same_resource(&previous.parsed_url, &url.parsed_url, git, hg) {
                     return Err(ResolveError::ConflictingOverrideUrls(
                         requirement.name.clone(),
                         previous.verbatim.verbatim().to_string(),
@@ -115,6 +116,7 @@ impl Urls {
         name: &'a PackageName,
         url: Option<&'a VerbatimParsedUrl>,
         git: &'a GitResolver,
+        hg: &'a HgResolver,
     ) -> Result<Option<&'a VerbatimParsedUrl>, ResolveError> {
         if let Some(override_url) = self.get_override(name) {
             Ok(Some(override_url))
@@ -122,6 +124,7 @@ impl Urls {
             Ok(Some(self.canonicalize_allowed_url(
                 name,
                 git,
+                hg,
                 &url.verbatim,
                 &url.parsed_url,
             )?))
@@ -152,6 +155,7 @@ impl Urls {
         &'a self,
         package_name: &'a PackageName,
         git: &'a GitResolver,
+        hg: &'a HgResolver,
         verbatim_url: &'a VerbatimUrl,
         parsed_url: &'a ParsedUrl,
     ) -> Result<&'a VerbatimParsedUrl, ResolveError> {
@@ -164,7 +168,7 @@ impl Urls {
 
         let matching_urls: Vec<_> = expected
             .iter()
-            .filter(|requirement| same_resource(&requirement.parsed_url, parsed_url, git))
+            .filter(|requirement| same_resource(&requirement.parsed_url, parsed_url, git, hg))
             .collect();
 
         let [allowed_url] = matching_urls.as_slice() else {
@@ -184,7 +188,7 @@ impl Urls {
 }
 
 /// Returns `true` if the [`ParsedUrl`] instances point to the same resource.
-fn same_resource(a: &ParsedUrl, b: &ParsedUrl, git: &GitResolver) -> bool {
+fn same_resource(a: &ParsedUrl, b: &ParsedUrl, git: &GitResolver, hg: &HgResolver) -> bool {
     match (a, b) {
         (ParsedUrl::Archive(a), ParsedUrl::Archive(b)) => {
             a.subdirectory == b.subdirectory
@@ -193,6 +197,9 @@ fn same_resource(a: &ParsedUrl, b: &ParsedUrl, git: &GitResolver) -> bool {
         (ParsedUrl::Git(a), ParsedUrl::Git(b)) => {
             a.subdirectory == b.subdirectory && git.same_ref(&a.url, &b.url)
         }
+        (ParsedUrl::Hg(a), ParsedUrl::Hg(b)) => {
+            a.subdirectory == b.subdirectory && hg.same_ref(&a.url, &b.url)
+        }
         (ParsedUrl::Path(a), ParsedUrl::Path(b)) => {
             a.install_path == b.install_path
                 || is_same_file(&a.install_path, &b.install_path).unwrap_or(false)
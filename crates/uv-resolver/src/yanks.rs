@@ -8,16 +8,52 @@ use uv_pypi_types::RequirementSource;
 
 use crate::{DependencyMode, Manifest, ResolverMarkers};
 
+/// The strategy to use when considering yanked versions.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum YankedMode {
+    /// Disallow all yanked versions, even if they're pinned by a requirement or an existing
+    /// lockfile.
+    Error,
+
+    /// Allow yanked versions if they're pinned by a requirement, an existing lockfile, or
+    /// `--allow-yanked`, with a warning.
+    #[default]
+    Warn,
+
+    /// Allow all yanked versions, with a warning.
+    Allow,
+}
+
+impl std::fmt::Display for YankedMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Error => write!(f, "error"),
+            Self::Warn => write!(f, "warn"),
+            Self::Allow => write!(f, "allow"),
+        }
+    }
+}
+
 /// A set of package versions that are permitted, even if they're marked as yanked by the
 /// relevant index.
 #[derive(Debug, Default, Clone)]
-pub struct AllowedYanks(Arc<FxHashMap<PackageName, FxHashSet<Version>>>);
+pub struct AllowedYanks {
+    mode: YankedMode,
+    /// The versions that are permitted for each package.
+    versions: Arc<FxHashMap<PackageName, FxHashSet<Version>>>,
+    /// The packages for which all versions are permitted.
+    packages: Arc<FxHashSet<PackageName>>,
+}
 
 impl AllowedYanks {
     pub fn from_manifest(
         manifest: &Manifest,
         markers: &ResolverMarkers,
         dependencies: DependencyMode,
+        mode: YankedMode,
     ) -> Self {
         let mut allowed_yanks = FxHashMap::<PackageName, FxHashSet<Version>>::default();
 
@@ -48,13 +84,49 @@ impl AllowedYanks {
                 .extend(preferences.map(|(_markers, version)| version.clone()));
         }
 
-        Self(Arc::new(allowed_yanks))
+        // Allow yanks for any packages that were explicitly allowed via `--allow-yanked`.
+        let mut allowed_packages = FxHashSet::default();
+        for requirement in &manifest.allowed_yanks {
+            let RequirementSource::Registry { specifier, .. } = &requirement.source else {
+                continue;
+            };
+            match specifier.as_ref() {
+                [] => {
+                    allowed_packages.insert(requirement.name.clone());
+                }
+                [specifier]
+                    if matches!(
+                        specifier.operator(),
+                        uv_pep440::Operator::Equal | uv_pep440::Operator::ExactEqual
+                    ) =>
+                {
+                    allowed_yanks
+                        .entry(requirement.name.clone())
+                        .or_default()
+                        .insert(specifier.version().clone());
+                }
+                _ => {}
+            }
+        }
+        Self {
+            mode,
+            versions: Arc::new(allowed_yanks),
+            packages: Arc::new(allowed_packages),
+        }
     }
 
     /// Returns `true` if the package-version is allowed, even if it's marked as yanked.
     pub fn contains(&self, package_name: &PackageName, version: &Version) -> bool {
-        self.0
-            .get(package_name)
-            .map_or(false, |versions| versions.contains(version))
+        match self.mode {
+            YankedMode::Error => false,
+            YankedMode::Allow => true,
+            YankedMode::Warn => {
+                self.packages.contains(package_name)
+                    || self
+                        .versions
+                        .get(package_name)
+                        .map_or(false, |versions| versions.contains(version))
+            }
+        }
     }
 }
@@ -1,15 +1,19 @@
+use std::collections::BTreeMap;
 use std::num::NonZeroUsize;
 use std::path::PathBuf;
 use url::Url;
 
+use uv_auth::Secret;
 use uv_configuration::{
-    ConfigSettings, IndexStrategy, KeyringProviderType, TargetTriple, TrustedPublishing,
+    Backoff, BytecodeOptimization, ConfigSettings, IndexStrategy, KeyringProviderType,
+    TargetTriple, TrustedPublishing,
 };
 use uv_distribution_types::{Index, IndexUrl, PipExtraIndex, PipFindLinks, PipIndex};
 use uv_install_wheel::linker::LinkMode;
+use uv_pep440::VersionSpecifiers;
 use uv_pypi_types::SupportedEnvironments;
 use uv_python::{PythonDownloads, PythonPreference, PythonVersion};
-use uv_resolver::{AnnotationStyle, ExcludeNewer, PrereleaseMode, ResolutionMode};
+use uv_resolver::{AnnotationStyle, ExcludeNewer, PrereleaseMode, ResolutionMode, YankedMode};
 
 use crate::{FilesystemOptions, Options, PipOptions};
 
@@ -71,6 +75,8 @@ macro_rules! impl_combine_or {
 }
 
 impl_combine_or!(AnnotationStyle);
+impl_combine_or!(Backoff);
+impl_combine_or!(BytecodeOptimization);
 impl_combine_or!(ExcludeNewer);
 impl_combine_or!(Index);
 impl_combine_or!(IndexStrategy);
@@ -83,16 +89,21 @@ impl_combine_or!(PipExtraIndex);
 impl_combine_or!(PipFindLinks);
 impl_combine_or!(PipIndex);
 impl_combine_or!(PrereleaseMode);
+impl_combine_or!(YankedMode);
 impl_combine_or!(PythonDownloads);
 impl_combine_or!(PythonPreference);
 impl_combine_or!(PythonVersion);
 impl_combine_or!(ResolutionMode);
+impl_combine_or!(Secret);
 impl_combine_or!(String);
 impl_combine_or!(SupportedEnvironments);
 impl_combine_or!(TargetTriple);
 impl_combine_or!(TrustedPublishing);
 impl_combine_or!(Url);
+impl_combine_or!(VersionSpecifiers);
 impl_combine_or!(bool);
+impl_combine_or!(u32);
+impl_combine_or!(u64);
 
 impl<T> Combine for Option<Vec<T>> {
     /// Combine two vectors by extending the vector in `self` with the vector in `other`, if they're
@@ -108,6 +119,22 @@ impl<T> Combine for Option<Vec<T>> {
     }
 }
 
+impl<K: Ord, V> Combine for Option<BTreeMap<K, V>> {
+    /// Combine two maps by merging the map in `self` with the map in `other`, if they're both
+    /// `Some`, preferring the values in `self` for any shared keys.
+    fn combine(self, other: Option<BTreeMap<K, V>>) -> Option<BTreeMap<K, V>> {
+        match (self, other) {
+            (Some(mut a), Some(b)) => {
+                for (key, value) in b {
+                    a.entry(key).or_insert(value);
+                }
+                Some(a)
+            }
+            (a, b) => a.or(b),
+        }
+    }
+}
+
 impl Combine for Option<ConfigSettings> {
     /// Combine two maps by merging the map in `self` with the map in `other`, if they're both
     /// `Some`.
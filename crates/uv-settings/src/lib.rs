@@ -124,7 +124,8 @@ impl FilesystemOptions {
                 }
 
                 debug!("Found workspace configuration at `{}`", path.display());
-                return Ok(Some(Self(options)));
+                check_project_secrets(&options, &path)?;
+                return Ok(Some(Self(options.relative_to(dir))));
             }
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
             Err(err) => return Err(err.into()),
@@ -153,7 +154,8 @@ impl FilesystemOptions {
                 };
 
                 debug!("Found workspace configuration at `{}`", path.display());
-                return Ok(Some(Self(options)));
+                check_project_secrets(&options, &path)?;
+                return Ok(Some(Self(options.relative_to(dir))));
             }
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
             Err(err) => return Err(err.into()),
@@ -166,6 +168,22 @@ impl FilesystemOptions {
     pub fn from_file(path: impl AsRef<Path>) -> Result<Self, Error> {
         Ok(Self(read_file(path.as_ref())?))
     }
+
+    /// Apply the named profile, such that its settings take precedence over those defined outside
+    /// of any profile.
+    pub fn with_profile(self, name: &str) -> Result<Self, Error> {
+        let mut options = self.0;
+        let profile = options
+            .profile
+            .as_mut()
+            .and_then(|profiles| profiles.remove(name))
+            .ok_or_else(|| Error::MissingProfile(name.to_string()))?;
+        if profile.profile.is_some() {
+            return Err(Error::NestedProfile(name.to_string()));
+        }
+        debug!("Applying configuration profile: `{name}`");
+        Ok(Self(profile.combine(options)))
+    }
 }
 
 impl From<Options> for FilesystemOptions {
@@ -174,6 +192,12 @@ impl From<Options> for FilesystemOptions {
     }
 }
 
+/// Returns the path to the user configuration file (e.g., `~/.config/uv/uv.toml`), whether or
+/// not it exists.
+pub fn user_config_file() -> Option<PathBuf> {
+    user_config_dir().map(|dir| dir.join("uv").join("uv.toml"))
+}
+
 /// Returns the path to the user configuration directory.
 ///
 /// This is similar to the `config_dir()` returned by the `dirs` crate, but it uses the
@@ -245,12 +269,71 @@ fn system_config_file() -> Option<PathBuf> {
     }
 }
 
-/// Load [`Options`] from a `uv.toml` file.
+/// Returns the path to the system configuration file, whether or not it exists.
+///
+/// If no system configuration file exists, returns the path at which one would be created: the
+/// first directory in `XDG_CONFIG_DIRS` (or `/etc/uv/uv.toml`, if unset) on Unix-like systems, and
+/// `%SYSTEMDRIVE%\ProgramData\uv\uv.toml` on Windows.
+pub fn system_config_file_or_default() -> Option<PathBuf> {
+    if let Some(path) = system_config_file() {
+        return Some(path);
+    }
+
+    #[cfg(windows)]
+    {
+        env::var_os(EnvVars::SYSTEMDRIVE)
+            .map(|system_drive| PathBuf::from(system_drive).join("ProgramData\\uv\\uv.toml"))
+    }
+
+    #[cfg(not(windows))]
+    {
+        let dir = env::var(EnvVars::XDG_CONFIG_DIRS)
+            .ok()
+            .and_then(|dirs| dirs.split(':').next().map(PathBuf::from))
+            .filter(|dir| !dir.as_os_str().is_empty());
+        Some(dir.map_or_else(
+            || PathBuf::from("/etc/uv/uv.toml"),
+            |dir| dir.join("uv").join("uv.toml"),
+        ))
+    }
+}
+
+/// Reject `file:` and `cmd:` secret references in project-level configuration (i.e., a project's
+/// `uv.toml` or `pyproject.toml`, or a script's inline metadata), unless `UV_TRUST_PROJECT_SECRETS`
+/// is set.
+///
+/// A project may come from an untrusted source, and honoring such references would allow it to run
+/// arbitrary commands, or to send the contents of local files to an index of its choosing. As such,
+/// they're only accepted from user- and system-level configuration, and from the command line.
+/// `env:` references and literal values are always accepted.
+pub fn check_project_secrets(options: &Options, source: &Path) -> Result<(), Error> {
+    let Some(secret) = options.local_secrets().into_iter().next() else {
+        return Ok(());
+    };
+    if env::var(EnvVars::UV_TRUST_PROJECT_SECRETS)
+        .is_ok_and(|value| value == "1" || value.eq_ignore_ascii_case("true"))
+    {
+        debug!(
+            "Allowing secret reference `{secret}` in `{}`",
+            source.display()
+        );
+        return Ok(());
+    }
+    Err(Error::UntrustedSecret(
+        source.user_display().to_string(),
+        secret.to_string(),
+    ))
+}
+
+/// Load [`Options`] from a `uv.toml` file, resolving any relative paths against its directory.
 fn read_file(path: &Path) -> Result<Options, Error> {
     let content = fs_err::read_to_string(path)?;
     let options: Options = toml::from_str(&content)
         .map_err(|err| Error::UvToml(path.user_display().to_string(), err))?;
-    Ok(options)
+    Ok(match path.parent() {
+        Some(root_dir) => options.relative_to(root_dir),
+        None => options,
+    })
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -263,10 +346,21 @@ pub enum Error {
 
     #[error("Failed to parse: `{0}`")]
     UvToml(String, #[source] toml::de::Error),
+
+    #[error("Profile `{0}` is not defined in any configuration file")]
+    MissingProfile(String),
+
+    #[error("Profile `{0}` defines a nested profile, which is not supported")]
+    NestedProfile(String),
+
+    #[error("`{0}` references a secret via `{1}`, but `file:` and `cmd:` references are only allowed in user- or system-level configuration; move the reference there, use an `env:` reference instead, or set `UV_TRUST_PROJECT_SECRETS=1` to trust the project")]
+    UntrustedSecret(String, String),
 }
 
 #[cfg(test)]
 mod test {
+    use crate::{Error, FilesystemOptions, Options};
+
     #[cfg(windows)]
     use crate::locate_system_config_windows;
     #[cfg(not(windows))]
@@ -274,7 +368,134 @@ mod test {
 
     use assert_fs::fixture::FixtureError;
     use assert_fs::prelude::*;
-    use indoc::indoc;
+    use indoc::{formatdoc, indoc};
+
+    #[test]
+    fn test_profile() -> Result<(), Box<dyn std::error::Error>> {
+        let options = FilesystemOptions(toml::from_str::<Options>(indoc! {r#"
+            offline = false
+            no-build = true
+
+            [profile.airgapped]
+            offline = true
+            compile-bytecode = true
+        "#})?);
+
+        // The profile's settings take precedence, and the remaining settings are retained.
+        let profiled = options.clone().with_profile("airgapped")?.into_options();
+        assert_eq!(profiled.globals.offline, Some(true));
+        assert_eq!(profiled.top_level.no_build, Some(true));
+        assert_eq!(profiled.top_level.compile_bytecode, Some(true));
+
+        // Unknown profiles are rejected.
+        assert!(matches!(
+            options.with_profile("gpu"),
+            Err(Error::MissingProfile(name)) if name == "gpu"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_local_secrets() -> Result<(), Box<dyn std::error::Error>> {
+        let options = toml::from_str::<Options>(indoc! {r#"
+            [[index]]
+            name = "internal"
+            url = "https://pypi.internal.example.com/simple"
+            username = "env:INTERNAL_USERNAME"
+            password = "hunter2"
+        "#})?;
+
+        // Literal values and `env:` references don't read local files or run commands.
+        assert!(options.local_secrets().is_empty());
+
+        let options = toml::from_str::<Options>(indoc! {r#"
+            [[index]]
+            name = "internal"
+            url = "https://pypi.internal.example.com/simple"
+            password = "env:INTERNAL_PASSWORD"
+
+            [[profile.ci.index]]
+            name = "ci"
+            url = "https://pypi.ci.example.com/simple"
+            password = "cmd:cat ~/.ssh/id_rsa"
+        "#})?;
+
+        // References in profiles are detected, too.
+        let secrets = options
+            .local_secrets()
+            .into_iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(secrets, ["cmd:cat ~/.ssh/id_rsa"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_relative_certificates() -> Result<(), Box<dyn std::error::Error>> {
+        let context = assert_fs::TempDir::new()?;
+        let client_cert = context.child("client.pem");
+        context.child("uv.toml").write_str(&formatdoc! {r#"
+            [[index]]
+            name = "internal"
+            url = "https://pypi.internal.example.com/simple"
+            ca-cert = "certs/internal-ca.pem"
+            client-cert = '{}'
+
+            [[profile.ci.index]]
+            name = "ci"
+            url = "https://pypi.ci.example.com/simple"
+            ca-cert = "certs/ci-ca.pem"
+        "#, client_cert.display()})?;
+
+        // Relative paths are resolved against the directory of the declaring file, while absolute
+        // paths are retained.
+        let options = FilesystemOptions::from_directory(context.path())?
+            .expect("`uv.toml` should be found")
+            .with_profile("ci")?
+            .into_options();
+        let indexes = options.top_level.index.expect("indexes should be defined");
+        assert_eq!(
+            indexes[0].ca_cert.as_deref(),
+            Some(context.child("certs").child("ci-ca.pem").path())
+        );
+        assert_eq!(
+            indexes[1].ca_cert.as_deref(),
+            Some(context.child("certs").child("internal-ca.pem").path())
+        );
+        assert_eq!(indexes[1].client_cert.as_deref(), Some(client_cert.path()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_relative_git_ssh() -> Result<(), Box<dyn std::error::Error>> {
+        let context = assert_fs::TempDir::new()?;
+        context.child("uv.toml").write_str(indoc! {r#"
+            [git-ssh."git.example.com"]
+            identity-file = "keys/id_ci"
+            known-hosts-file = "~/.ssh/known_hosts_ci"
+        "#})?;
+
+        // Relative paths are resolved against the directory of the declaring file, while paths
+        // relative to the home directory are left for SSH to expand.
+        let options = FilesystemOptions::from_directory(context.path())?
+            .expect("`uv.toml` should be found")
+            .into_options();
+        let hosts = options.globals.git_ssh.expect("hosts should be defined");
+        let host = &hosts["git.example.com"];
+        assert_eq!(
+            host.identity_file.as_deref(),
+            Some(context.child("keys").child("id_ci").path())
+        );
+        assert_eq!(
+            host.known_hosts_file.as_deref(),
+            Some(std::path::Path::new("~/.ssh/known_hosts_ci"))
+        );
+
+        Ok(())
+    }
 
     #[test]
     #[cfg(not(windows))]
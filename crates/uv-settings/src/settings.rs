@@ -1,19 +1,29 @@
 use serde::{Deserialize, Serialize};
-use std::{fmt::Debug, num::NonZeroUsize, path::PathBuf};
+use std::{
+    collections::BTreeMap,
+    fmt::Debug,
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+};
 use url::Url;
+use uv_auth::Secret;
 use uv_cache_info::CacheKey;
 use uv_configuration::{
-    ConfigSettings, IndexStrategy, KeyringProviderType, PackageNameSpecifier, TargetTriple,
-    TrustedHost, TrustedPublishing,
+    Backoff, BytecodeOptimization, ConfigSettings, IndexStrategy, KeyringProviderType,
+    PackageNameSpecifier, TargetTriple, TrustedHost, TrustedPublishing,
+};
+use uv_distribution_types::{
+    DependencyOverride, Index, PipExtraIndex, PipFindLinks, PipIndex, StaticMetadata,
 };
-use uv_distribution_types::{Index, PipExtraIndex, PipFindLinks, PipIndex, StaticMetadata};
+use uv_git::GitSshHost;
 use uv_install_wheel::linker::LinkMode;
 use uv_macros::{CombineOptions, OptionsMetadata};
 use uv_normalize::{ExtraName, PackageName};
+use uv_pep440::VersionSpecifiers;
 use uv_pep508::Requirement;
 use uv_pypi_types::{SupportedEnvironments, VerbatimParsedUrl};
 use uv_python::{PythonDownloads, PythonPreference, PythonVersion};
-use uv_resolver::{AnnotationStyle, ExcludeNewer, PrereleaseMode, ResolutionMode};
+use uv_resolver::{AnnotationStyle, ExcludeNewer, PrereleaseMode, ResolutionMode, YankedMode};
 
 /// A `pyproject.toml` with an (optional) `[tool.uv]` section.
 #[allow(dead_code)]
@@ -85,6 +95,29 @@ pub struct Options {
     )]
     cache_keys: Option<Vec<CacheKey>>,
 
+    /// Named sets of settings that can be selected per invocation via `--profile` (or
+    /// `UV_PROFILE`), keyed by name.
+    ///
+    /// A profile accepts the same settings as the top level (e.g., `index-url`, `exclude-newer`,
+    /// `offline`, or `no-build`). When a profile is selected, its settings take precedence over
+    /// those defined outside of any profile, while command-line arguments and environment
+    /// variables continue to take precedence over both. As elsewhere, arrays (like `index`) are
+    /// merged, with the profile's entries taking priority.
+    ///
+    /// Useful for sharing a project between environments with different requirements, e.g., to
+    /// install from an internal mirror in an air-gapped environment, while using PyPI elsewhere.
+    /// If a profile with the same name is defined in multiple configuration files, the definition
+    /// in the most specific file is used.
+    #[option(
+        default = "{}",
+        value_type = "dict",
+        example = r#"
+            profile.airgapped = { index-url = "https://mirror.example.com/simple", offline = true }
+        "#
+    )]
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub profile: Option<BTreeMap<String, Options>>,
+
     // NOTE(charlie): These fields are shared with `ToolUv` in
     // `crates/uv-workspace/src/pyproject.rs`, and the documentation lives on that struct.
     #[cfg_attr(feature = "schemars", schemars(skip))]
@@ -106,6 +139,62 @@ impl Options {
             ..Default::default()
         }
     }
+
+    /// Resolve any relative paths in the options against the given directory, i.e., that of the
+    /// configuration file from which the options were read.
+    #[must_use]
+    pub fn relative_to(mut self, root_dir: &Path) -> Self {
+        let resolve = |indexes: Option<Vec<Index>>| {
+            indexes.map(|indexes| {
+                indexes
+                    .into_iter()
+                    .map(|index| index.relative_to(root_dir))
+                    .collect()
+            })
+        };
+        self.top_level.index = resolve(self.top_level.index);
+        if let Some(pip) = self.pip.as_mut() {
+            pip.index = resolve(pip.index.take());
+        }
+        self.profile = self.profile.map(|profiles| {
+            profiles
+                .into_iter()
+                .map(|(name, profile)| (name, profile.relative_to(root_dir)))
+                .collect()
+        });
+        self.globals.git_ssh = self.globals.git_ssh.map(|hosts| {
+            hosts
+                .into_iter()
+                .map(|(host, options)| (host, options.relative_to(root_dir)))
+                .collect()
+        });
+        if let Some(icon) = self.globals.launcher_icon.as_mut() {
+            if icon.is_relative() {
+                *icon = root_dir.join(&*icon);
+            }
+        }
+        self
+    }
+
+    /// Returns the secrets that read a local file or run a command when resolved (i.e., `file:`
+    /// and `cmd:` references), including those defined in profiles.
+    pub fn local_secrets(&self) -> Vec<&Secret> {
+        let indexes = self
+            .top_level
+            .index
+            .iter()
+            .chain(self.pip.iter().flat_map(|pip| pip.index.iter()))
+            .flatten();
+        let mut secrets = indexes
+            .flat_map(|index| index.username.iter().chain(index.password.iter()))
+            .chain(self.globals.azure_artifacts_pat.iter())
+            .filter(|secret| secret.is_local())
+            .collect::<Vec<_>>();
+        for profile in self.profile.iter().flat_map(BTreeMap::values) {
+            secrets.extend(profile.local_secrets());
+        }
+        secrets
+    }
 }
 
 /// Global settings, relevant to all invocations.
@@ -161,6 +250,62 @@ pub struct GlobalOptions {
         "#
     )]
     pub cache_dir: Option<PathBuf>,
+    /// A namespace in which to store artifacts built from source distributions.
+    ///
+    /// Wheels built from source distributions are isolated from those built in other namespaces
+    /// (and from those built without a namespace), while downloaded wheels and other artifacts
+    /// remain shared across projects. Useful for projects that build packages with mutually
+    /// incompatible local patches.
+    ///
+    /// Namespaces may only contain ASCII letters, digits, `-`, `_`, and `.`.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            cache-namespace = "patched"
+        "#
+    )]
+    pub cache_namespace: Option<String>,
+    /// The time (in seconds) for which remote requirements and constraints files are cached
+    /// before they're fetched again.
+    ///
+    /// By default, remote files (e.g., `-r https://example.com/requirements.txt`) are fetched on
+    /// every invocation. Cached files are always used when running with `--offline`, and
+    /// `--refresh` forces them to be fetched again.
+    #[option(
+        default = "0",
+        value_type = "int",
+        example = r#"
+            requirements-cache-ttl = 3600
+        "#
+    )]
+    pub requirements_cache_ttl: Option<u64>,
+    /// The URL of a remote cache of wheels built from source distributions, shared across
+    /// machines.
+    ///
+    /// Either an HTTPS URL that supports `GET` and `PUT` requests, or an S3 bucket, as in
+    /// `s3://bucket/prefix`. Wheels fetched from the cache are verified against the SHA-256 hash
+    /// recorded when they were uploaded.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            build-cache-url = "s3://uv-build-cache/wheels"
+        "#
+    )]
+    pub build_cache_url: Option<Url>,
+    /// Upload the wheels built from source distributions to the remote build cache.
+    ///
+    /// Anyone who can write to the remote build cache can change the wheels installed by every
+    /// machine that reads from it, so uploads should be limited to trusted builders (e.g., CI).
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            build-cache-upload = true
+        "#
+    )]
+    pub build_cache_upload: Option<bool>,
     /// Whether to enable experimental, preview features.
     #[option(
         default = "false",
@@ -201,6 +346,22 @@ pub struct GlobalOptions {
         "#
     )]
     pub concurrent_downloads: Option<NonZeroUsize>,
+    /// The maximum number of in-flight concurrent downloads that uv will perform against any
+    /// single host at any given time.
+    ///
+    /// The limit applies to every request to the host, including index pages and package
+    /// metadata, not just archive downloads.
+    ///
+    /// Useful for mirrors that throttle clients opening too many simultaneous connections. By
+    /// default, downloads are limited only by `concurrent-downloads`.
+    #[option(
+        default = "None",
+        value_type = "int",
+        example = r#"
+            concurrent-downloads-per-host = 4
+        "#
+    )]
+    pub concurrent_downloads_per_host: Option<NonZeroUsize>,
     /// The maximum number of source distributions that uv will build concurrently at any given
     /// time.
     ///
@@ -224,6 +385,173 @@ pub struct GlobalOptions {
         "#
     )]
     pub concurrent_installs: Option<NonZeroUsize>,
+    /// SSH options to use when fetching Git dependencies from specific hosts, keyed by host.
+    ///
+    /// For each host, an `identity-file` (the private key with which to authenticate), a
+    /// `known-hosts-file` (the known hosts file with which to verify the host), and a `user` (the
+    /// username with which to connect, if the Git URL doesn't include one) may be provided.
+    ///
+    /// Useful for fetching from multiple Git hosts on machines without a full `~/.ssh/config`,
+    /// e.g., in CI. The options apply to `ssh://` URLs and scp-like addresses (e.g.,
+    /// `git@github.com:astral-sh/uv.git`), and extend any command provided via `GIT_SSH_COMMAND`.
+    ///
+    /// Relative paths are resolved against the directory of the configuration file.
+    #[option(
+        default = "{}",
+        value_type = "dict",
+        example = r#"
+            git-ssh = { "git.example.com" = { identity-file = "~/.ssh/id_ci", known-hosts-file = "~/.ssh/known_hosts_ci", user = "git" } }
+        "#
+    )]
+    pub git_ssh: Option<BTreeMap<String, GitSshHost>>,
+    /// Check out the entire repository for Git dependencies in a subdirectory, rather than only
+    /// the subdirectory.
+    ///
+    /// By default, uv uses a sparse checkout to materialize only the subdirectory (along with the
+    /// files in each of its parent directories), unless the package is part of a workspace or
+    /// declares a path source. Disable sparse checkouts for packages whose builds read other files
+    /// in the repository, e.g., a sibling directory referenced from a `setup.py`.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            no-git-sparse-checkout = true
+        "#
+    )]
+    pub no_git_sparse_checkout: Option<bool>,
+    /// The number of times to retry a failed HTTP request.
+    ///
+    /// Requests that fail with a transient error, e.g., a connection reset or a retryable HTTP
+    /// status, are retried up to this many times. Set to `0` to disable retries.
+    ///
+    /// The `UV_HTTP_RETRIES` environment variable takes precedence over this setting.
+    #[option(
+        default = "3",
+        value_type = "int",
+        example = r#"
+            http-retries = 5
+        "#
+    )]
+    pub http_retries: Option<u32>,
+    /// The strategy used to wait between retries of a failed HTTP request.
+    ///
+    /// With `exponential`, the delay doubles after each failed attempt. With `constant`, uv waits
+    /// one second between attempts.
+    ///
+    /// The `UV_HTTP_RETRY_BACKOFF` environment variable takes precedence over this setting.
+    #[option(
+        default = "\"exponential\"",
+        value_type = "str",
+        example = r#"
+            http-retry-backoff = "constant"
+        "#
+    )]
+    pub http_retry_backoff: Option<Backoff>,
+    /// The HTTP status codes on which to retry a request, replacing the default set.
+    ///
+    /// By default, uv retries on server errors (`5xx`) and on `408` and `429` responses.
+    ///
+    /// The `UV_HTTP_RETRY_STATUS` environment variable takes precedence over this setting.
+    #[option(
+        default = "null",
+        value_type = "list[int]",
+        example = r#"
+            http-retry-status = [429, 502, 503]
+        "#
+    )]
+    pub http_retry_status: Option<Vec<u16>>,
+    /// The timeout for establishing an HTTP connection, in seconds.
+    ///
+    /// By default, only the overall request timeout applies.
+    ///
+    /// The `UV_HTTP_CONNECT_TIMEOUT` environment variable takes precedence over this setting.
+    #[option(
+        default = "null",
+        value_type = "int",
+        example = r#"
+            http-connect-timeout = 10
+        "#
+    )]
+    pub http_connect_timeout: Option<u64>,
+    /// The personal access token with which to authenticate to Azure Artifacts feeds.
+    ///
+    /// Accepts either a literal token or a reference to a secret: `env:NAME` reads the `NAME`
+    /// environment variable, `file:PATH` reads the file at `PATH`, and `cmd:COMMAND` reads the
+    /// output of `COMMAND`. Prefer a reference over committing the token to a project. `file:` and
+    /// `cmd:` references are only accepted in user- and system-level configuration, unless
+    /// `UV_TRUST_PROJECT_SECRETS` is set.
+    ///
+    /// The `UV_AZURE_ARTIFACTS_PAT` environment variable takes precedence over this setting.
+    #[option(
+        default = "null",
+        value_type = "str",
+        example = r#"
+            azure-artifacts-pat = "env:ADO_PAT"
+        "#
+    )]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    pub azure_artifacts_pat: Option<Secret>,
+    /// The product name to embed in the Windows launchers of tool entrypoints.
+    ///
+    /// Some endpoint protection software refuses to run executables that lack version
+    /// information. When set, the launchers created by `uv tool install`, `uv tool upgrade`, and
+    /// `uv tool sync` record the product name as both their `ProductName` and `FileDescription`.
+    /// Has no effect on other platforms.
+    ///
+    /// The `UV_LAUNCHER_PRODUCT_NAME` environment variable takes precedence over this setting.
+    #[option(
+        default = "null",
+        value_type = "str",
+        example = r#"
+            launcher-product-name = "Example Tools"
+        "#
+    )]
+    pub launcher_product_name: Option<String>,
+    /// The version to embed in the Windows launchers of tool entrypoints, e.g., `1.2.3`.
+    ///
+    /// Accepts up to four dot-separated integers, and is recorded as both the file and the
+    /// product version. Has no effect on other platforms.
+    ///
+    /// The `UV_LAUNCHER_VERSION` environment variable takes precedence over this setting.
+    #[option(
+        default = "null",
+        value_type = "str",
+        example = r#"
+            launcher-version = "1.2.3"
+        "#
+    )]
+    pub launcher_version: Option<String>,
+    /// The path to an `.ico` file to embed as the icon of the Windows launchers of tool
+    /// entrypoints.
+    ///
+    /// Relative paths are resolved against the directory of the configuration file. Has no effect
+    /// on other platforms.
+    ///
+    /// The `UV_LAUNCHER_ICON` environment variable takes precedence over this setting.
+    #[option(
+        default = "null",
+        value_type = "str",
+        example = r#"
+            launcher-icon = "assets/tools.ico"
+        "#
+    )]
+    pub launcher_icon: Option<PathBuf>,
+    /// Enforce a requirement on the version of uv.
+    ///
+    /// If the version of uv does not meet the requirement at runtime, uv will exit with an error.
+    /// `uv self update` will warn instead, such that uv can be updated to a satisfying version.
+    ///
+    /// Accepts a [PEP 440](https://peps.python.org/pep-0440/) specifier, like `==0.5.0` or
+    /// `>=0.5.0`.
+    #[option(
+        default = "null",
+        value_type = "str",
+        example = r#"
+            required-version = ">=0.5.0"
+        "#
+    )]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    pub required_version: Option<VersionSpecifiers>,
 }
 
 /// Settings relevant to all installer operations.
@@ -234,13 +562,19 @@ pub struct InstallerOptions {
     pub extra_index_url: Option<Vec<PipExtraIndex>>,
     pub no_index: Option<bool>,
     pub find_links: Option<Vec<PipFindLinks>>,
+    pub find_links_recursive: Option<bool>,
     pub index_strategy: Option<IndexStrategy>,
     pub keyring_provider: Option<KeyringProviderType>,
     pub allow_insecure_host: Option<Vec<TrustedHost>>,
     pub config_settings: Option<ConfigSettings>,
+    pub build_container: Option<String>,
+    pub build_container_package: Option<BTreeMap<PackageName, String>>,
     pub exclude_newer: Option<ExcludeNewer>,
     pub link_mode: Option<LinkMode>,
     pub compile_bytecode: Option<bool>,
+    pub compile_bytecode_optimization: Option<BytecodeOptimization>,
+    pub compile_bytecode_workers: Option<NonZeroUsize>,
+    pub no_compile_bytecode_package: Option<Vec<PackageName>>,
     pub reinstall: Option<bool>,
     pub reinstall_package: Option<Vec<PackageName>>,
     pub no_build: Option<bool>,
@@ -259,13 +593,22 @@ pub struct ResolverOptions {
     pub extra_index_url: Option<Vec<PipExtraIndex>>,
     pub no_index: Option<bool>,
     pub find_links: Option<Vec<PipFindLinks>>,
+    pub find_links_recursive: Option<bool>,
     pub index_strategy: Option<IndexStrategy>,
     pub keyring_provider: Option<KeyringProviderType>,
     pub allow_insecure_host: Option<Vec<TrustedHost>>,
     pub resolution: Option<ResolutionMode>,
     pub prerelease: Option<PrereleaseMode>,
+    pub yanked: Option<YankedMode>,
+    pub allow_yanked: Option<Vec<Requirement<VerbatimParsedUrl>>>,
+    pub prefer_local_version: Option<String>,
+    pub resolution_timeout: Option<u64>,
+    pub resolution_max_steps: Option<usize>,
     pub dependency_metadata: Option<Vec<StaticMetadata>>,
+    pub dependency_overrides: Option<Vec<DependencyOverride>>,
     pub config_settings: Option<ConfigSettings>,
+    pub build_container: Option<String>,
+    pub build_container_package: Option<BTreeMap<PackageName, String>>,
     pub exclude_newer: Option<ExcludeNewer>,
     pub link_mode: Option<LinkMode>,
     pub upgrade: Option<bool>,
@@ -277,6 +620,7 @@ pub struct ResolverOptions {
     pub no_build_isolation: Option<bool>,
     pub no_build_isolation_package: Option<Vec<PackageName>>,
     pub no_sources: Option<bool>,
+    pub variants: Option<Vec<String>>,
 }
 
 /// Shared settings, relevant to all operations that must resolve and install dependencies. The
@@ -386,6 +730,18 @@ pub struct ResolverInstallerOptions {
         "#
     )]
     pub find_links: Option<Vec<PipFindLinks>>,
+    /// Search `find-links` directories recursively.
+    ///
+    /// By default, only packages at the top level of a `find-links` directory are considered.
+    /// When enabled, packages in any subdirectory are included as well.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            find-links-recursive = true
+        "#
+    )]
+    pub find_links_recursive: Option<bool>,
     /// The strategy to use when resolving against multiple index URLs.
     ///
     /// By default, uv will stop at the first index on which a given package is available, and
@@ -456,6 +812,78 @@ pub struct ResolverInstallerOptions {
         possible_values = true
     )]
     pub prerelease: Option<PrereleaseMode>,
+    /// The strategy to use when considering yanked versions.
+    ///
+    /// By default, uv will reject yanked versions unless they're pinned by an exact requirement
+    /// (e.g., `==1.0.0`), an existing lockfile, or `allow-yanked`, and will emit a warning when a
+    /// yanked version is selected (`warn`). Use `error` to reject all yanked versions, or `allow`
+    /// to accept any yanked version (with a warning).
+    #[option(
+        default = "\"warn\"",
+        value_type = "str",
+        example = r#"
+            yanked = "error"
+        "#,
+        possible_values = true
+    )]
+    pub yanked: Option<YankedMode>,
+    /// Allow the resolver to select yanked versions of specific packages.
+    ///
+    /// Accepts both standalone package names (`attrs`), which permit any yanked version of the
+    /// package, and exact version specifiers (`attrs==21.1.0`), which permit only the given
+    /// version. A warning is emitted whenever a yanked version is selected.
+    ///
+    /// Ignored when `yanked` is set to `error`.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            allow-yanked = ["attrs==21.1.0"]
+        "#
+    )]
+    pub allow_yanked: Option<Vec<Requirement<VerbatimParsedUrl>>>,
+    /// Prefer versions with the given local version label (e.g., `acme`, to prefer `1.0.0+acme`).
+    ///
+    /// When a version with the label satisfies a requirement, it's selected over any other
+    /// version, even if a higher version without the label is available, such that patched
+    /// releases of internal forks (as published to an internal index) are preferred over the
+    /// corresponding public releases without requiring exact pins.
+    ///
+    /// Versions pinned in an existing lockfile take precedence; use `--upgrade` to apply the
+    /// preference to locked packages.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            prefer-local-version = "acme"
+        "#
+    )]
+    pub prefer_local_version: Option<String>,
+    /// Abort a resolution that takes longer than the given number of seconds.
+    ///
+    /// The timeout includes time spent fetching metadata and building source distributions. On
+    /// timeout, uv reports the packages that required the most backtracking, which are good
+    /// candidates for tighter lower bounds.
+    #[option(
+        default = "None",
+        value_type = "int",
+        example = r#"
+            resolution-timeout = 60
+        "#
+    )]
+    pub resolution_timeout: Option<u64>,
+    /// Abort a resolution after trying the given number of package versions.
+    ///
+    /// On abort, uv reports the packages that required the most backtracking, which are good
+    /// candidates for tighter lower bounds.
+    #[option(
+        default = "None",
+        value_type = "int",
+        example = r#"
+            resolution-max-steps = 10000
+        "#
+    )]
+    pub resolution_max_steps: Option<usize>,
     /// Pre-defined static metadata for dependencies of the project (direct or transitive). When
     /// provided, enables the resolver to use the specified metadata instead of querying the
     /// registry or building the relevant package from source.
@@ -479,6 +907,32 @@ pub struct ResolverInstallerOptions {
         "#
     )]
     pub dependency_metadata: Option<Vec<StaticMetadata>>,
+    /// Patches to apply to the declared dependencies of specific packages (direct or transitive).
+    ///
+    /// Unlike `dependency-metadata`, which replaces a package's metadata wholesale, dependency
+    /// overrides edit individual requirements in the metadata reported by the registry or build
+    /// backend, e.g., to strip an erroneous upper bound from a third-party package without
+    /// maintaining a fork.
+    ///
+    /// Each entry supports the following fields:
+    ///
+    /// - `name`: The name of the package whose dependencies should be patched.
+    /// - (Optional) `version`: The version of the package. If omitted, the patch will be applied to
+    ///   all versions of the package.
+    /// - (Optional) `remove`: The names of dependencies to remove (e.g., `["typing-extensions"]`).
+    /// - (Optional) `replace`: Requirements that replace any existing requirements on the same
+    ///   package (e.g., `["numpy>=1.20"]`). If the package is not already a dependency, the
+    ///   requirement is added.
+    #[option(
+        default = r#"[]"#,
+        value_type = "list[dict]",
+        example = r#"
+            dependency-overrides = [
+                { name = "flask", version = "1.0.0", remove = ["itsdangerous"], replace = ["werkzeug>=0.14"] },
+            ]
+        "#
+    )]
+    pub dependency_overrides: Option<Vec<DependencyOverride>>,
     /// Settings to pass to the [PEP 517](https://peps.python.org/pep-0517/) build backend,
     /// specified as `KEY=VALUE` pairs.
     #[option(
@@ -489,6 +943,34 @@ pub struct ResolverInstallerOptions {
         "#
     )]
     pub config_settings: Option<ConfigSettings>,
+    /// Build source distributions inside a container created from the given image.
+    ///
+    /// When provided, wheels are built by running the build backend inside a container (with
+    /// `docker`, or the runtime set via `UV_BUILD_CONTAINER_RUNTIME`), such that native build
+    /// toolchains needn't exist on the host. The image must provide a Python interpreter of the
+    /// same minor version as the target environment (e.g., `python3.12`), along with `pip`.
+    ///
+    /// Editable installs and source distribution builds always run on the host.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            build-container = "quay.io/pypa/manylinux_2_28_x86_64"
+        "#
+    )]
+    pub build_container: Option<String>,
+    /// Build source distributions for specific packages inside a container created from the
+    /// given image.
+    ///
+    /// Takes precedence over `build-container` for the given packages.
+    #[option(
+        default = "{}",
+        value_type = "dict[str, str]",
+        example = r#"
+            build-container-package = { numpy = "quay.io/pypa/manylinux_2_28_x86_64" }
+        "#
+    )]
+    pub build_container_package: Option<BTreeMap<PackageName, String>>,
     /// Disable isolation when building source distributions.
     ///
     /// Assumes that build dependencies specified by [PEP 518](https://peps.python.org/pep-0518/)
@@ -528,10 +1010,10 @@ pub struct ResolverInstallerOptions {
     pub exclude_newer: Option<ExcludeNewer>,
     /// The method to use when installing packages from the global cache.
     ///
-    /// Defaults to `clone` (also known as Copy-on-Write) on macOS, and `hardlink` on Linux and
+    /// Defaults to `clone` (also known as Copy-on-Write) on macOS, and `auto` on Linux and
     /// Windows.
     #[option(
-        default = "\"clone\" (macOS) or \"hardlink\" (Linux, Windows)",
+        default = "\"clone\" (macOS) or \"auto\" (Linux, Windows)",
         value_type = "str",
         example = r#"
             link-mode = "copy"
@@ -557,6 +1039,50 @@ pub struct ResolverInstallerOptions {
         "#
     )]
     pub compile_bytecode: Option<bool>,
+    /// The optimization level to use when compiling Python files to bytecode.
+    ///
+    /// Corresponds to the `-O` (`1`) and `-OO` (`2`) flags of the Python interpreter. Level `1`
+    /// removes `assert` statements and code conditional on `__debug__`; level `2` additionally
+    /// removes docstrings. Optimized bytecode is only used by interpreters running at the same
+    /// optimization level.
+    ///
+    /// Only used when `compile-bytecode` is enabled.
+    #[option(
+        default = "\"0\"",
+        value_type = "str",
+        example = r#"
+            compile-bytecode-optimization = "2"
+        "#,
+        possible_values = true
+    )]
+    pub compile_bytecode_optimization: Option<BytecodeOptimization>,
+    /// The number of workers to use when compiling Python files to bytecode.
+    ///
+    /// Defaults to the number of available CPU cores.
+    ///
+    /// Only used when `compile-bytecode` is enabled.
+    #[option(
+        default = "None",
+        value_type = "int",
+        example = r#"
+            compile-bytecode-workers = 4
+        "#
+    )]
+    pub compile_bytecode_workers: Option<NonZeroUsize>,
+    /// Exclude the given packages from bytecode compilation.
+    ///
+    /// Useful for packages whose sources are slow to compile, or which contain files that fail to
+    /// compile. The source files of the given packages are identified via their `RECORD` files.
+    ///
+    /// Only used when `compile-bytecode` is enabled.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            no-compile-bytecode-package = ["ruff"]
+        "#
+    )]
+    pub no_compile_bytecode_package: Option<Vec<PackageName>>,
     /// Ignore the `tool.uv.sources` table when resolving dependencies. Used to lock against the
     /// standards-compliant, publishable package metadata, as opposed to using any local or Git
     /// sources.
@@ -568,6 +1094,22 @@ pub struct ResolverInstallerOptions {
         "#
     )]
     pub no_sources: Option<bool>,
+    /// The variants to use when selecting among the sources in `tool.uv.sources`, e.g., `cu121`.
+    ///
+    /// For each dependency, uv uses the sources for the first of the given variants that the
+    /// dependency declares, falling back to its sources without a variant. Each variant must be
+    /// declared by at least one source in the workspace.
+    ///
+    /// Typically set in a user-level `uv.toml`, or via `UV_VARIANT`, to select the variant for
+    /// the current machine.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            variants = ["cu121"]
+        "#
+    )]
+    pub variants: Option<Vec<String>>,
     /// Allow package upgrades, ignoring pinned versions in any existing output file.
     #[option(
         default = "false",
@@ -798,6 +1340,18 @@ pub struct PipOptions {
         "#
     )]
     pub find_links: Option<Vec<PipFindLinks>>,
+    /// Search `find-links` directories recursively.
+    ///
+    /// By default, only packages at the top level of a `find-links` directory are considered.
+    /// When enabled, packages in any subdirectory are included as well.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            find-links-recursive = true
+        "#
+    )]
+    pub find_links_recursive: Option<bool>,
     /// The strategy to use when resolving against multiple index URLs.
     ///
     /// By default, uv will stop at the first index on which a given package is available, and
@@ -990,6 +1544,78 @@ pub struct PipOptions {
         possible_values = true
     )]
     pub prerelease: Option<PrereleaseMode>,
+    /// The strategy to use when considering yanked versions.
+    ///
+    /// By default, uv will reject yanked versions unless they're pinned by an exact requirement
+    /// (e.g., `==1.0.0`), an existing lockfile, or `allow-yanked`, and will emit a warning when a
+    /// yanked version is selected (`warn`). Use `error` to reject all yanked versions, or `allow`
+    /// to accept any yanked version (with a warning).
+    #[option(
+        default = "\"warn\"",
+        value_type = "str",
+        example = r#"
+            yanked = "error"
+        "#,
+        possible_values = true
+    )]
+    pub yanked: Option<YankedMode>,
+    /// Allow the resolver to select yanked versions of specific packages.
+    ///
+    /// Accepts both standalone package names (`attrs`), which permit any yanked version of the
+    /// package, and exact version specifiers (`attrs==21.1.0`), which permit only the given
+    /// version. A warning is emitted whenever a yanked version is selected.
+    ///
+    /// Ignored when `yanked` is set to `error`.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            allow-yanked = ["attrs==21.1.0"]
+        "#
+    )]
+    pub allow_yanked: Option<Vec<Requirement<VerbatimParsedUrl>>>,
+    /// Prefer versions with the given local version label (e.g., `acme`, to prefer `1.0.0+acme`).
+    ///
+    /// When a version with the label satisfies a requirement, it's selected over any other
+    /// version, even if a higher version without the label is available, such that patched
+    /// releases of internal forks (as published to an internal index) are preferred over the
+    /// corresponding public releases without requiring exact pins.
+    ///
+    /// Versions pinned in an existing lockfile take precedence; use `--upgrade` to apply the
+    /// preference to locked packages.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            prefer-local-version = "acme"
+        "#
+    )]
+    pub prefer_local_version: Option<String>,
+    /// Abort a resolution that takes longer than the given number of seconds.
+    ///
+    /// The timeout includes time spent fetching metadata and building source distributions. On
+    /// timeout, uv reports the packages that required the most backtracking, which are good
+    /// candidates for tighter lower bounds.
+    #[option(
+        default = "None",
+        value_type = "int",
+        example = r#"
+            resolution-timeout = 60
+        "#
+    )]
+    pub resolution_timeout: Option<u64>,
+    /// Abort a resolution after trying the given number of package versions.
+    ///
+    /// On abort, uv reports the packages that required the most backtracking, which are good
+    /// candidates for tighter lower bounds.
+    #[option(
+        default = "None",
+        value_type = "int",
+        example = r#"
+            resolution-max-steps = 10000
+        "#
+    )]
+    pub resolution_max_steps: Option<usize>,
     /// Pre-defined static metadata for dependencies of the project (direct or transitive). When
     /// provided, enables the resolver to use the specified metadata instead of querying the
     /// registry or building the relevant package from source.
@@ -1013,6 +1639,32 @@ pub struct PipOptions {
         "#
     )]
     pub dependency_metadata: Option<Vec<StaticMetadata>>,
+    /// Patches to apply to the declared dependencies of specific packages (direct or transitive).
+    ///
+    /// Unlike `dependency-metadata`, which replaces a package's metadata wholesale, dependency
+    /// overrides edit individual requirements in the metadata reported by the registry or build
+    /// backend, e.g., to strip an erroneous upper bound from a third-party package without
+    /// maintaining a fork.
+    ///
+    /// Each entry supports the following fields:
+    ///
+    /// - `name`: The name of the package whose dependencies should be patched.
+    /// - (Optional) `version`: The version of the package. If omitted, the patch will be applied to
+    ///   all versions of the package.
+    /// - (Optional) `remove`: The names of dependencies to remove (e.g., `["typing-extensions"]`).
+    /// - (Optional) `replace`: Requirements that replace any existing requirements on the same
+    ///   package (e.g., `["numpy>=1.20"]`). If the package is not already a dependency, the
+    ///   requirement is added.
+    #[option(
+        default = r#"[]"#,
+        value_type = "list[dict]",
+        example = r#"
+            dependency-overrides = [
+                { name = "flask", version = "1.0.0", remove = ["itsdangerous"], replace = ["werkzeug>=0.14"] },
+            ]
+        "#
+    )]
+    pub dependency_overrides: Option<Vec<DependencyOverride>>,
     /// Write the requirements generated by `uv pip compile` to the given `requirements.txt` file.
     ///
     /// If the file already exists, the existing versions will be preferred when resolving
@@ -1099,6 +1751,34 @@ pub struct PipOptions {
         "#
     )]
     pub config_settings: Option<ConfigSettings>,
+    /// Build source distributions inside a container created from the given image.
+    ///
+    /// When provided, wheels are built by running the build backend inside a container (with
+    /// `docker`, or the runtime set via `UV_BUILD_CONTAINER_RUNTIME`), such that native build
+    /// toolchains needn't exist on the host. The image must provide a Python interpreter of the
+    /// same minor version as the target environment (e.g., `python3.12`), along with `pip`.
+    ///
+    /// Editable installs and source distribution builds always run on the host.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            build-container = "quay.io/pypa/manylinux_2_28_x86_64"
+        "#
+    )]
+    pub build_container: Option<String>,
+    /// Build source distributions for specific packages inside a container created from the
+    /// given image.
+    ///
+    /// Takes precedence over `build-container` for the given packages.
+    #[option(
+        default = "{}",
+        value_type = "dict[str, str]",
+        example = r#"
+            build-container-package = { numpy = "quay.io/pypa/manylinux_2_28_x86_64" }
+        "#
+    )]
+    pub build_container_package: Option<BTreeMap<PackageName, String>>,
     /// The minimum Python version that should be supported by the resolved requirements (e.g.,
     /// `3.8` or `3.8.17`).
     ///
@@ -1227,10 +1907,10 @@ pub struct PipOptions {
     pub annotation_style: Option<AnnotationStyle>,
     /// The method to use when installing packages from the global cache.
     ///
-    /// Defaults to `clone` (also known as Copy-on-Write) on macOS, and `hardlink` on Linux and
+    /// Defaults to `clone` (also known as Copy-on-Write) on macOS, and `auto` on Linux and
     /// Windows.
     #[option(
-        default = "\"clone\" (macOS) or \"hardlink\" (Linux, Windows)",
+        default = "\"clone\" (macOS) or \"auto\" (Linux, Windows)",
         value_type = "str",
         example = r#"
             link-mode = "copy"
@@ -1256,6 +1936,50 @@ pub struct PipOptions {
         "#
     )]
     pub compile_bytecode: Option<bool>,
+    /// The optimization level to use when compiling Python files to bytecode.
+    ///
+    /// Corresponds to the `-O` (`1`) and `-OO` (`2`) flags of the Python interpreter. Level `1`
+    /// removes `assert` statements and code conditional on `__debug__`; level `2` additionally
+    /// removes docstrings. Optimized bytecode is only used by interpreters running at the same
+    /// optimization level.
+    ///
+    /// Only used when `compile-bytecode` is enabled.
+    #[option(
+        default = "\"0\"",
+        value_type = "str",
+        example = r#"
+            compile-bytecode-optimization = "2"
+        "#,
+        possible_values = true
+    )]
+    pub compile_bytecode_optimization: Option<BytecodeOptimization>,
+    /// The number of workers to use when compiling Python files to bytecode.
+    ///
+    /// Defaults to the number of available CPU cores.
+    ///
+    /// Only used when `compile-bytecode` is enabled.
+    #[option(
+        default = "None",
+        value_type = "int",
+        example = r#"
+            compile-bytecode-workers = 4
+        "#
+    )]
+    pub compile_bytecode_workers: Option<NonZeroUsize>,
+    /// Exclude the given packages from bytecode compilation.
+    ///
+    /// Useful for packages whose sources are slow to compile, or which contain files that fail to
+    /// compile. The source files of the given packages are identified via their `RECORD` files.
+    ///
+    /// Only used when `compile-bytecode` is enabled.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            no-compile-bytecode-package = ["ruff"]
+        "#
+    )]
+    pub no_compile_bytecode_package: Option<Vec<PackageName>>,
     /// Require a matching hash for each requirement.
     ///
     /// Hash-checking mode is all or nothing. If enabled, _all_ requirements must be provided
@@ -1300,6 +2024,22 @@ pub struct PipOptions {
         "#
     )]
     pub no_sources: Option<bool>,
+    /// The variants to use when selecting among the sources in `tool.uv.sources`, e.g., `cu121`.
+    ///
+    /// For each dependency, uv uses the sources for the first of the given variants that the
+    /// dependency declares, falling back to its sources without a variant. Each variant must be
+    /// declared by at least one source in the workspace.
+    ///
+    /// Typically set in a user-level `uv.toml`, or via `UV_VARIANT`, to select the variant for
+    /// the current machine.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            variants = ["cu121"]
+        "#
+    )]
+    pub variants: Option<Vec<String>>,
     /// Allow package upgrades, ignoring pinned versions in any existing output file.
     #[option(
         default = "false",
@@ -1350,13 +2090,22 @@ impl From<ResolverInstallerOptions> for ResolverOptions {
             extra_index_url: value.extra_index_url,
             no_index: value.no_index,
             find_links: value.find_links,
+            find_links_recursive: value.find_links_recursive,
             index_strategy: value.index_strategy,
             keyring_provider: value.keyring_provider,
             allow_insecure_host: value.allow_insecure_host,
             resolution: value.resolution,
             prerelease: value.prerelease,
+            yanked: value.yanked,
+            allow_yanked: value.allow_yanked,
+            prefer_local_version: value.prefer_local_version,
+            resolution_timeout: value.resolution_timeout,
+            resolution_max_steps: value.resolution_max_steps,
             dependency_metadata: value.dependency_metadata,
+            dependency_overrides: value.dependency_overrides,
             config_settings: value.config_settings,
+            build_container: value.build_container,
+            build_container_package: value.build_container_package,
             exclude_newer: value.exclude_newer,
             link_mode: value.link_mode,
             upgrade: value.upgrade,
@@ -1368,6 +2117,7 @@ impl From<ResolverInstallerOptions> for ResolverOptions {
             no_build_isolation: value.no_build_isolation,
             no_build_isolation_package: value.no_build_isolation_package,
             no_sources: value.no_sources,
+            variants: value.variants,
         }
     }
 }
@@ -1380,13 +2130,19 @@ impl From<ResolverInstallerOptions> for InstallerOptions {
             extra_index_url: value.extra_index_url,
             no_index: value.no_index,
             find_links: value.find_links,
+            find_links_recursive: value.find_links_recursive,
             index_strategy: value.index_strategy,
             keyring_provider: value.keyring_provider,
             allow_insecure_host: value.allow_insecure_host,
             config_settings: value.config_settings,
+            build_container: value.build_container,
+            build_container_package: value.build_container_package,
             exclude_newer: value.exclude_newer,
             link_mode: value.link_mode,
             compile_bytecode: value.compile_bytecode,
+            compile_bytecode_optimization: value.compile_bytecode_optimization,
+            compile_bytecode_workers: value.compile_bytecode_workers,
+            no_compile_bytecode_package: value.no_compile_bytecode_package,
             reinstall: value.reinstall,
             reinstall_package: value.reinstall_package,
             no_build: value.no_build,
@@ -1414,18 +2170,28 @@ pub struct ToolOptions {
     pub extra_index_url: Option<Vec<PipExtraIndex>>,
     pub no_index: Option<bool>,
     pub find_links: Option<Vec<PipFindLinks>>,
+    pub find_links_recursive: Option<bool>,
     pub index_strategy: Option<IndexStrategy>,
     pub keyring_provider: Option<KeyringProviderType>,
     pub allow_insecure_host: Option<Vec<TrustedHost>>,
     pub resolution: Option<ResolutionMode>,
     pub prerelease: Option<PrereleaseMode>,
+    pub yanked: Option<YankedMode>,
+    pub allow_yanked: Option<Vec<Requirement<VerbatimParsedUrl>>>,
+    pub prefer_local_version: Option<String>,
     pub dependency_metadata: Option<Vec<StaticMetadata>>,
+    pub dependency_overrides: Option<Vec<DependencyOverride>>,
     pub config_settings: Option<ConfigSettings>,
+    pub build_container: Option<String>,
+    pub build_container_package: Option<BTreeMap<PackageName, String>>,
     pub no_build_isolation: Option<bool>,
     pub no_build_isolation_package: Option<Vec<PackageName>>,
     pub exclude_newer: Option<ExcludeNewer>,
     pub link_mode: Option<LinkMode>,
     pub compile_bytecode: Option<bool>,
+    pub compile_bytecode_optimization: Option<BytecodeOptimization>,
+    pub compile_bytecode_workers: Option<NonZeroUsize>,
+    pub no_compile_bytecode_package: Option<Vec<PackageName>>,
     pub no_sources: Option<bool>,
     pub no_build: Option<bool>,
     pub no_build_package: Option<Vec<PackageName>>,
@@ -1441,18 +2207,28 @@ impl From<ResolverInstallerOptions> for ToolOptions {
             extra_index_url: value.extra_index_url,
             no_index: value.no_index,
             find_links: value.find_links,
+            find_links_recursive: value.find_links_recursive,
             index_strategy: value.index_strategy,
             keyring_provider: value.keyring_provider,
             allow_insecure_host: value.allow_insecure_host,
             resolution: value.resolution,
             prerelease: value.prerelease,
+            yanked: value.yanked,
+            allow_yanked: value.allow_yanked,
+            prefer_local_version: value.prefer_local_version,
             dependency_metadata: value.dependency_metadata,
+            dependency_overrides: value.dependency_overrides,
             config_settings: value.config_settings,
+            build_container: value.build_container,
+            build_container_package: value.build_container_package,
             no_build_isolation: value.no_build_isolation,
             no_build_isolation_package: value.no_build_isolation_package,
             exclude_newer: value.exclude_newer,
             link_mode: value.link_mode,
             compile_bytecode: value.compile_bytecode,
+            compile_bytecode_optimization: value.compile_bytecode_optimization,
+            compile_bytecode_workers: value.compile_bytecode_workers,
+            no_compile_bytecode_package: value.no_compile_bytecode_package,
             no_sources: value.no_sources,
             no_build: value.no_build,
             no_build_package: value.no_build_package,
@@ -1470,19 +2246,32 @@ impl From<ToolOptions> for ResolverInstallerOptions {
             extra_index_url: value.extra_index_url,
             no_index: value.no_index,
             find_links: value.find_links,
+            find_links_recursive: value.find_links_recursive,
             index_strategy: value.index_strategy,
             keyring_provider: value.keyring_provider,
             allow_insecure_host: value.allow_insecure_host,
             resolution: value.resolution,
             prerelease: value.prerelease,
+            yanked: value.yanked,
+            allow_yanked: value.allow_yanked,
+            prefer_local_version: value.prefer_local_version,
+            resolution_timeout: None,
+            resolution_max_steps: None,
             dependency_metadata: value.dependency_metadata,
+            dependency_overrides: value.dependency_overrides,
             config_settings: value.config_settings,
+            build_container: value.build_container,
+            build_container_package: value.build_container_package,
             no_build_isolation: value.no_build_isolation,
             no_build_isolation_package: value.no_build_isolation_package,
             exclude_newer: value.exclude_newer,
             link_mode: value.link_mode,
             compile_bytecode: value.compile_bytecode,
+            compile_bytecode_optimization: value.compile_bytecode_optimization,
+            compile_bytecode_workers: value.compile_bytecode_workers,
+            no_compile_bytecode_package: value.no_compile_bytecode_package,
             no_sources: value.no_sources,
+            variants: None,
             upgrade: None,
             upgrade_package: None,
             reinstall: None,
@@ -1506,12 +2295,28 @@ pub struct OptionsWire {
     offline: Option<bool>,
     no_cache: Option<bool>,
     cache_dir: Option<PathBuf>,
+    cache_namespace: Option<String>,
+    requirements_cache_ttl: Option<u64>,
+    build_cache_url: Option<Url>,
+    build_cache_upload: Option<bool>,
     preview: Option<bool>,
     python_preference: Option<PythonPreference>,
     python_downloads: Option<PythonDownloads>,
     concurrent_downloads: Option<NonZeroUsize>,
+    concurrent_downloads_per_host: Option<NonZeroUsize>,
     concurrent_builds: Option<NonZeroUsize>,
     concurrent_installs: Option<NonZeroUsize>,
+    git_ssh: Option<BTreeMap<String, GitSshHost>>,
+    no_git_sparse_checkout: Option<bool>,
+    http_retries: Option<u32>,
+    http_retry_backoff: Option<Backoff>,
+    http_retry_status: Option<Vec<u16>>,
+    http_connect_timeout: Option<u64>,
+    azure_artifacts_pat: Option<Secret>,
+    launcher_product_name: Option<String>,
+    launcher_version: Option<String>,
+    launcher_icon: Option<PathBuf>,
+    required_version: Option<VersionSpecifiers>,
 
     // #[serde(flatten)]
     // top_level: ResolverInstallerOptions
@@ -1520,19 +2325,32 @@ pub struct OptionsWire {
     extra_index_url: Option<Vec<PipExtraIndex>>,
     no_index: Option<bool>,
     find_links: Option<Vec<PipFindLinks>>,
+    find_links_recursive: Option<bool>,
     index_strategy: Option<IndexStrategy>,
     keyring_provider: Option<KeyringProviderType>,
     allow_insecure_host: Option<Vec<TrustedHost>>,
     resolution: Option<ResolutionMode>,
     prerelease: Option<PrereleaseMode>,
+    yanked: Option<YankedMode>,
+    allow_yanked: Option<Vec<Requirement<VerbatimParsedUrl>>>,
+    prefer_local_version: Option<String>,
+    resolution_timeout: Option<u64>,
+    resolution_max_steps: Option<usize>,
     dependency_metadata: Option<Vec<StaticMetadata>>,
+    dependency_overrides: Option<Vec<DependencyOverride>>,
     config_settings: Option<ConfigSettings>,
+    build_container: Option<String>,
+    build_container_package: Option<BTreeMap<PackageName, String>>,
     no_build_isolation: Option<bool>,
     no_build_isolation_package: Option<Vec<PackageName>>,
     exclude_newer: Option<ExcludeNewer>,
     link_mode: Option<LinkMode>,
     compile_bytecode: Option<bool>,
+    compile_bytecode_optimization: Option<BytecodeOptimization>,
+    compile_bytecode_workers: Option<NonZeroUsize>,
+    no_compile_bytecode_package: Option<Vec<PackageName>>,
     no_sources: Option<bool>,
+    variants: Option<Vec<String>>,
     upgrade: Option<bool>,
     upgrade_package: Option<Vec<Requirement<VerbatimParsedUrl>>>,
     reinstall: Option<bool>,
@@ -1549,6 +2367,7 @@ pub struct OptionsWire {
 
     pip: Option<PipOptions>,
     cache_keys: Option<Vec<CacheKey>>,
+    profile: Option<BTreeMap<String, Options>>,
 
     // NOTE(charlie): These fields are shared with `ToolUv` in
     // `crates/uv-workspace/src/pyproject.rs`, and the documentation lives on that struct.
@@ -1561,14 +2380,30 @@ pub struct OptionsWire {
     #[allow(dead_code)]
     workspace: Option<serde::de::IgnoredAny>,
     #[allow(dead_code)]
+    hooks: Option<serde::de::IgnoredAny>,
+    #[allow(dead_code)]
+    build_backend: Option<serde::de::IgnoredAny>,
+    #[allow(dead_code)]
+    tasks: Option<serde::de::IgnoredAny>,
+    #[allow(dead_code)]
+    required_tools: Option<serde::de::IgnoredAny>,
+    #[allow(dead_code)]
     sources: Option<serde::de::IgnoredAny>,
     #[allow(dead_code)]
+    enforce_index_provenance: Option<serde::de::IgnoredAny>,
+    #[allow(dead_code)]
     managed: Option<serde::de::IgnoredAny>,
     #[allow(dead_code)]
+    env_files: Option<serde::de::IgnoredAny>,
+    #[allow(dead_code)]
     r#package: Option<serde::de::IgnoredAny>,
     #[allow(dead_code)]
     default_groups: Option<serde::de::IgnoredAny>,
     #[allow(dead_code)]
+    dependency_groups: Option<serde::de::IgnoredAny>,
+    #[allow(dead_code)]
+    conflicts: Option<serde::de::IgnoredAny>,
+    #[allow(dead_code)]
     dev_dependencies: Option<serde::de::IgnoredAny>,
 }
 
@@ -1579,30 +2414,59 @@ impl From<OptionsWire> for Options {
             offline,
             no_cache,
             cache_dir,
+            cache_namespace,
+            requirements_cache_ttl,
+            build_cache_url,
+            build_cache_upload,
             preview,
             python_preference,
             python_downloads,
             concurrent_downloads,
+            concurrent_downloads_per_host,
             concurrent_builds,
             concurrent_installs,
+            git_ssh,
+            no_git_sparse_checkout,
+            http_retries,
+            http_retry_backoff,
+            http_retry_status,
+            http_connect_timeout,
+            azure_artifacts_pat,
+            launcher_product_name,
+            launcher_version,
+            launcher_icon,
+            required_version,
             index,
             index_url,
             extra_index_url,
             no_index,
             find_links,
+            find_links_recursive,
             index_strategy,
             keyring_provider,
             allow_insecure_host,
             resolution,
             prerelease,
+            yanked,
+            allow_yanked,
+            prefer_local_version,
+            resolution_timeout,
+            resolution_max_steps,
             dependency_metadata,
+            dependency_overrides,
             config_settings,
+            build_container,
+            build_container_package,
             no_build_isolation,
             no_build_isolation_package,
             exclude_newer,
             link_mode,
             compile_bytecode,
+            compile_bytecode_optimization,
+            compile_bytecode_workers,
+            no_compile_bytecode_package,
             no_sources,
+            variants,
             upgrade,
             upgrade_package,
             reinstall,
@@ -1613,16 +2477,25 @@ impl From<OptionsWire> for Options {
             no_binary_package,
             pip,
             cache_keys,
+            profile,
             override_dependencies,
             constraint_dependencies,
             environments,
             publish_url,
             trusted_publishing,
             workspace: _,
+            hooks: _,
+            build_backend: _,
+            tasks: _,
+            required_tools: _,
             sources: _,
+            enforce_index_provenance: _,
             managed: _,
+            env_files: _,
             package: _,
             default_groups: _,
+            dependency_groups: _,
+            conflicts: _,
             dev_dependencies: _,
         } = value;
 
@@ -1632,12 +2505,28 @@ impl From<OptionsWire> for Options {
                 offline,
                 no_cache,
                 cache_dir,
+                cache_namespace,
+                requirements_cache_ttl,
+                build_cache_url,
+                build_cache_upload,
                 preview,
                 python_preference,
                 python_downloads,
                 concurrent_downloads,
+                concurrent_downloads_per_host,
                 concurrent_builds,
                 concurrent_installs,
+                git_ssh,
+                no_git_sparse_checkout,
+                http_retries,
+                http_retry_backoff,
+                http_retry_status,
+                http_connect_timeout,
+                azure_artifacts_pat,
+                launcher_product_name,
+                launcher_version,
+                launcher_icon,
+                required_version,
             },
             top_level: ResolverInstallerOptions {
                 index,
@@ -1645,19 +2534,32 @@ impl From<OptionsWire> for Options {
                 extra_index_url,
                 no_index,
                 find_links,
+                find_links_recursive,
                 index_strategy,
                 keyring_provider,
                 allow_insecure_host,
                 resolution,
                 prerelease,
+                yanked,
+                allow_yanked,
+                prefer_local_version,
+                resolution_timeout,
+                resolution_max_steps,
                 dependency_metadata,
+                dependency_overrides,
                 config_settings,
+                build_container,
+                build_container_package,
                 no_build_isolation,
                 no_build_isolation_package,
                 exclude_newer,
                 link_mode,
                 compile_bytecode,
+                compile_bytecode_optimization,
+                compile_bytecode_workers,
+                no_compile_bytecode_package,
                 no_sources,
+                variants,
                 upgrade,
                 upgrade_package,
                 reinstall,
@@ -1673,6 +2575,7 @@ impl From<OptionsWire> for Options {
             },
             pip,
             cache_keys,
+            profile,
             override_dependencies,
             constraint_dependencies,
             environments,
@@ -170,6 +170,47 @@ impl Shell {
         }
     }
 
+    /// Return the file to which the completion script for the given command should be written,
+    /// such that it is loaded automatically by this shell.
+    ///
+    /// Returns `None` for shells that lack a per-user completion directory.
+    pub fn completion_file(self, command: &str) -> Option<PathBuf> {
+        let home_dir = home::home_dir()?;
+        match self {
+            Shell::Bash => {
+                // `bash-completion` lazily loads completions from
+                // `$XDG_DATA_HOME/bash-completion/completions`, falling back to
+                // `~/.local/share/bash-completion/completions`.
+                let data_dir = std::env::var(EnvVars::XDG_DATA_HOME)
+                    .ok()
+                    .filter(|dir| !dir.is_empty())
+                    .map(PathBuf::from)
+                    .unwrap_or_else(|| home_dir.join(".local/share"));
+                Some(data_dir.join("bash-completion/completions").join(command))
+            }
+            Shell::Zsh => {
+                // Zsh has no standard per-user completion directory; follow the common convention
+                // of `~/.zfunc`, which must be added to `fpath`.
+                Some(home_dir.join(".zfunc").join(format!("_{command}")))
+            }
+            Shell::Fish => {
+                // Fish loads completions from `$XDG_CONFIG_HOME/fish/completions`, falling back to
+                // `~/.config/fish/completions`.
+                let config_dir = std::env::var(EnvVars::XDG_CONFIG_HOME)
+                    .ok()
+                    .filter(|dir| !dir.is_empty())
+                    .map(PathBuf::from)
+                    .unwrap_or_else(|| home_dir.join(".config"));
+                Some(
+                    config_dir
+                        .join("fish/completions")
+                        .join(format!("{command}.fish")),
+                )
+            }
+            Shell::Powershell | Shell::Cmd | Shell::Nushell | Shell::Csh | Shell::Ksh => None,
+        }
+    }
+
     /// Returns `true` if the given path is on the `PATH` in this shell.
     pub fn contains_path(path: &Path) -> bool {
         let home_dir = home::home_dir();
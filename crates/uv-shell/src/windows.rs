@@ -39,6 +39,30 @@ pub fn prepend_path(path: &Path) -> anyhow::Result<bool> {
     Ok(true)
 }
 
+/// Remove the given [`Path`] from the `PATH` environment variable in the Windows registry.
+///
+/// Returns `Ok(true)` if the path was successfully removed, and `Ok(false)` if the path was not
+/// in `PATH`.
+pub fn remove_path(path: &Path) -> anyhow::Result<bool> {
+    // Get the existing `PATH` variable from the registry.
+    let windows_path = get_windows_path_var()?;
+
+    // Remove the path from the existing `PATH` variable.
+    let windows_path = windows_path.and_then(|windows_path| {
+        remove_from_path(windows_path, OsString::from(path).encode_wide().collect())
+    });
+
+    // If the path didn't change, then we don't need to do anything.
+    let Some(windows_path) = windows_path else {
+        return Ok(false);
+    };
+
+    // Set the `PATH` variable in the registry.
+    apply_windows_path_var(windows_path)?;
+
+    Ok(true)
+}
+
 /// Set the windows `PATH` variable in the registry.
 fn apply_windows_path_var(path: Vec<u16>) -> anyhow::Result<()> {
     let root = RegKey::predef(HKEY_CURRENT_USER);
@@ -87,7 +111,10 @@ fn get_windows_path_var() -> anyhow::Result<Option<Vec<u16>>> {
 fn prepend_to_path(existing_path: Vec<u16>, path: Vec<u16>) -> Option<Vec<u16>> {
     if existing_path.is_empty() {
         Some(path)
-    } else if existing_path.windows(path.len()).any(|p| p == path) {
+    } else if existing_path
+        .split(|c| *c == u16::from(b';'))
+        .any(|entry| normalize(entry) == normalize(&path))
+    {
         None
     } else {
         let mut new_path = path;
@@ -97,6 +124,37 @@ fn prepend_to_path(existing_path: Vec<u16>, path: Vec<u16>) -> Option<Vec<u16>>
     }
 }
 
+/// Remove a path from the `PATH` variable in the Windows registry.
+///
+/// Entries are compared in full, ignoring case and trailing separators, such that other entries
+/// that merely contain the given path (e.g., a subdirectory) are left untouched.
+///
+/// Returns `Ok(None)` if the given path is not in `PATH`.
+fn remove_from_path(existing_path: Vec<u16>, path: Vec<u16>) -> Option<Vec<u16>> {
+    let separator = u16::from(b';');
+    let path = normalize(&path);
+
+    let entries = existing_path.split(|c| *c == separator).collect::<Vec<_>>();
+    let retained = entries
+        .iter()
+        .filter(|entry| normalize(entry) != path)
+        .copied()
+        .collect::<Vec<_>>();
+    if retained.len() == entries.len() {
+        return None;
+    }
+
+    Some(retained.join(&separator))
+}
+
+/// Normalize a `PATH` entry for comparison, as Windows paths are case-insensitive and may include
+/// a trailing separator.
+fn normalize(entry: &[u16]) -> String {
+    String::from_utf16_lossy(entry)
+        .trim_end_matches(['\\', '/'])
+        .to_lowercase()
+}
+
 /// Convert a vector UCS-2 chars to a null-terminated UCS-2 string in bytes.
 fn to_winreg_bytes(mut value: Vec<u16>) -> Vec<u8> {
     value.push(0);
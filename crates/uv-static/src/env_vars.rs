@@ -21,18 +21,70 @@ impl EnvVars {
     /// Equivalent to the `--find-links` argument. Additional package search locations.
     pub const UV_FIND_LINKS: &'static str = "UV_FIND_LINKS";
 
+    /// Equivalent to the `--find-links-recursive` argument. Search `--find-links` directories
+    /// recursively.
+    pub const UV_FIND_LINKS_RECURSIVE: &'static str = "UV_FIND_LINKS_RECURSIVE";
+
     /// Equivalent to the `--cache-dir` argument. Custom directory for caching.
     pub const UV_CACHE_DIR: &'static str = "UV_CACHE_DIR";
 
+    /// Equivalent to the `--cache-namespace` argument. Isolates artifacts built from source
+    /// distributions in a namespace within the cache.
+    pub const UV_CACHE_NAMESPACE: &'static str = "UV_CACHE_NAMESPACE";
+
     /// Equivalent to the `--no-cache` argument. Disables cache usage.
     pub const UV_NO_CACHE: &'static str = "UV_NO_CACHE";
 
+    /// Equivalent to the `--requirements-cache-ttl` argument. The time (in seconds) for which
+    /// remote `requirements.txt` and constraints files are cached before they're fetched again.
+    pub const UV_REQUIREMENTS_CACHE_TTL: &'static str = "UV_REQUIREMENTS_CACHE_TTL";
+
     /// Equivalent to the `--resolution` argument. Controls dependency resolution strategy.
     pub const UV_RESOLUTION: &'static str = "UV_RESOLUTION";
 
+    /// Equivalent to the `--resolution-max-steps` argument. The maximum number of package versions
+    /// the resolver may try before aborting.
+    pub const UV_RESOLUTION_MAX_STEPS: &'static str = "UV_RESOLUTION_MAX_STEPS";
+
+    /// Equivalent to the `--resolution-timeout` argument. The maximum time (in seconds) the
+    /// resolver may spend before aborting.
+    pub const UV_RESOLUTION_TIMEOUT: &'static str = "UV_RESOLUTION_TIMEOUT";
+
+    /// Equivalent to the `--variant` command-line argument. A comma-separated list of variants
+    /// (e.g., `cu121`) used to select among the sources declared in `tool.uv.sources`.
+    pub const UV_VARIANT: &'static str = "UV_VARIANT";
+
     /// Equivalent to the `--prerelease` argument. Allows or disallows pre-release versions.
     pub const UV_PRERELEASE: &'static str = "UV_PRERELEASE";
 
+    /// Equivalent to the `--yanked` argument. Allows or disallows yanked versions.
+    pub const UV_YANKED: &'static str = "UV_YANKED";
+
+    /// Equivalent to the `--prefer-local-version` argument. Prefers versions with the given local
+    /// version label.
+    pub const UV_PREFER_LOCAL_VERSION: &'static str = "UV_PREFER_LOCAL_VERSION";
+
+    /// Equivalent to the `--build-container` argument. Builds source distributions inside a
+    /// container created from the given image.
+    pub const UV_BUILD_CONTAINER: &'static str = "UV_BUILD_CONTAINER";
+
+    /// The container runtime with which to run containerized builds, e.g., `podman`. Defaults
+    /// to `docker`.
+    pub const UV_BUILD_CONTAINER_RUNTIME: &'static str = "UV_BUILD_CONTAINER_RUNTIME";
+
+    /// Equivalent to the `no-git-sparse-checkout` setting. If set to `true`, uv will check out the
+    /// entire repository for Git dependencies in a subdirectory, rather than only the
+    /// subdirectory.
+    pub const UV_NO_GIT_SPARSE_CHECKOUT: &'static str = "UV_NO_GIT_SPARSE_CHECKOUT";
+
+    /// Equivalent to the `build-cache-url` setting. The URL of a remote cache of wheels built from
+    /// source distributions, either an HTTPS URL or an S3 bucket (e.g., `s3://bucket/prefix`).
+    pub const UV_BUILD_CACHE_URL: &'static str = "UV_BUILD_CACHE_URL";
+
+    /// Equivalent to the `build-cache-upload` setting. If set to `true`, uv will upload the wheels
+    /// it builds from source distributions to the remote build cache.
+    pub const UV_BUILD_CACHE_UPLOAD: &'static str = "UV_BUILD_CACHE_UPLOAD";
+
     /// Equivalent to the `--system` argument. Use system Python interpreter.
     pub const UV_SYSTEM_PYTHON: &'static str = "UV_SYSTEM_PYTHON";
 
@@ -78,6 +130,9 @@ impl EnvVars {
     /// Equivalent to the `--no-config` argument. Prevents reading configuration files.
     pub const UV_NO_CONFIG: &'static str = "UV_NO_CONFIG";
 
+    /// Equivalent to the `--profile` argument. Applies the named configuration profile.
+    pub const UV_PROFILE: &'static str = "UV_PROFILE";
+
     /// Equivalent to the `--exclude-newer` argument. Excludes newer distributions after a date.
     pub const UV_EXCLUDE_NEWER: &'static str = "UV_EXCLUDE_NEWER";
 
@@ -108,12 +163,26 @@ impl EnvVars {
     /// Equivalent to the `--no-sync` argument. Skips syncing the environment.
     pub const UV_NO_SYNC: &'static str = "UV_NO_SYNC";
 
+    /// Equivalent to the `--no-hooks` argument. Skips running the project's lifecycle hooks.
+    pub const UV_NO_HOOKS: &'static str = "UV_NO_HOOKS";
+
+    /// Equivalent to the `--env-file` argument. Load environment variables from the given
+    /// `.env` files (separated by the platform's path separator) in `uv run`.
+    pub const UV_ENV_FILE: &'static str = "UV_ENV_FILE";
+
+    /// Equivalent to the `--no-env-file` argument. Avoid reading environment variables from
+    /// `.env` files in `uv run`.
+    pub const UV_NO_ENV_FILE: &'static str = "UV_NO_ENV_FILE";
+
     /// Equivalent to the `--locked` argument. Assert that the `uv.lock` will remain unchanged.
     pub const UV_LOCKED: &'static str = "UV_LOCKED";
 
     /// Equivalent to the `--frozen` argument. Run without updating the `uv.lock` file.
     pub const UV_FROZEN: &'static str = "UV_FROZEN";
 
+    /// Equivalent to the `--policy` argument in `uv licenses`. The path to a license policy file.
+    pub const UV_LICENSE_POLICY: &'static str = "UV_LICENSE_POLICY";
+
     /// Equivalent to the `--preview` argument. Enables preview mode.
     pub const UV_PREVIEW: &'static str = "UV_PREVIEW";
 
@@ -129,6 +198,9 @@ impl EnvVars {
     /// Sets the maximum number of in-flight concurrent downloads.
     pub const UV_CONCURRENT_DOWNLOADS: &'static str = "UV_CONCURRENT_DOWNLOADS";
 
+    /// Sets the maximum number of in-flight concurrent downloads to any single host.
+    pub const UV_CONCURRENT_DOWNLOADS_PER_HOST: &'static str = "UV_CONCURRENT_DOWNLOADS_PER_HOST";
+
     /// Sets the maximum number of concurrent builds for source distributions.
     pub const UV_CONCURRENT_BUILDS: &'static str = "UV_CONCURRENT_BUILDS";
 
@@ -141,6 +213,29 @@ impl EnvVars {
     /// Specifies the "bin" directory for installing tool executables.
     pub const UV_TOOL_BIN_DIR: &'static str = "UV_TOOL_BIN_DIR";
 
+    /// Equivalent to the `launcher-product-name` setting. The product name to embed in the
+    /// version information of the Windows launchers generated for tool entry points.
+    pub const UV_LAUNCHER_PRODUCT_NAME: &'static str = "UV_LAUNCHER_PRODUCT_NAME";
+
+    /// Equivalent to the `launcher-version` setting. The version (e.g., `1.2.3`) to embed in the
+    /// version information of the Windows launchers generated for tool entry points.
+    pub const UV_LAUNCHER_VERSION: &'static str = "UV_LAUNCHER_VERSION";
+
+    /// Equivalent to the `launcher-icon` setting. The path to an `.ico` file to embed as the icon
+    /// of the Windows launchers generated for tool entry points.
+    pub const UV_LAUNCHER_ICON: &'static str = "UV_LAUNCHER_ICON";
+
+    /// Specifies the directory where `uv auth` stores credentials.
+    pub const UV_CREDENTIALS_DIR: &'static str = "UV_CREDENTIALS_DIR";
+
+    /// A personal access token used to authenticate with Azure Artifacts feeds.
+    pub const UV_AZURE_ARTIFACTS_PAT: &'static str = "UV_AZURE_ARTIFACTS_PAT";
+
+    /// Allow `file:` and `cmd:` secret references in project-level configuration (i.e., a
+    /// project's `uv.toml` or `pyproject.toml`, or a script's inline metadata). By default, such
+    /// references are only allowed in user- and system-level configuration.
+    pub const UV_TRUST_PROJECT_SECRETS: &'static str = "UV_TRUST_PROJECT_SECRETS";
+
     /// Specifies the path to the project virtual environment.
     pub const UV_PROJECT_ENVIRONMENT: &'static str = "UV_PROJECT_ENVIRONMENT";
 
@@ -156,6 +251,13 @@ impl EnvVars {
     /// Mirror URL for downloading managed PyPy installations.
     pub const UV_PYPY_INSTALL_MIRROR: &'static str = "UV_PYPY_INSTALL_MIRROR";
 
+    /// Mirror URL for downloading managed GraalPy installations.
+    pub const UV_GRAALPY_INSTALL_MIRROR: &'static str = "UV_GRAALPY_INSTALL_MIRROR";
+
+    /// Equivalent to the `--registry` command-line argument. If set, managed Python installations
+    /// are registered in the Windows registry following PEP 514.
+    pub const UV_PYTHON_INSTALL_REGISTRY: &'static str = "UV_PYTHON_INSTALL_REGISTRY";
+
     /// Used to override `PATH` to limit Python executable availability in the test suite.
     pub const UV_TEST_PYTHON_PATH: &'static str = "UV_TEST_PYTHON_PATH";
 
@@ -171,6 +273,10 @@ impl EnvVars {
     /// Use to control the stack size used by uv. Typically more relevant for Windows in debug mode.
     pub const UV_STACK_SIZE: &'static str = "UV_STACK_SIZE";
 
+    /// Set by the scripts from `uv generate-shell-completion` to request dynamic completions for
+    /// the current command line from uv, rather than running the command.
+    pub const UV_COMPLETE: &'static str = "UV_COMPLETE";
+
     /// Generates the environment variable key for the HTTP Basic authentication username.
     pub fn index_username(name: &str) -> String {
         format!("UV_INDEX_{name}_USERNAME")
@@ -232,12 +338,27 @@ impl EnvVars {
     /// Timeout (in seconds) for HTTP requests.
     pub const HTTP_TIMEOUT: &'static str = "HTTP_TIMEOUT";
 
+    /// Timeout (in seconds) for establishing HTTP connections.
+    pub const UV_HTTP_CONNECT_TIMEOUT: &'static str = "UV_HTTP_CONNECT_TIMEOUT";
+
+    /// The number of times to retry a failed HTTP request.
+    pub const UV_HTTP_RETRIES: &'static str = "UV_HTTP_RETRIES";
+
+    /// The backoff strategy to use between HTTP retries (`exponential` or `constant`).
+    pub const UV_HTTP_RETRY_BACKOFF: &'static str = "UV_HTTP_RETRY_BACKOFF";
+
+    /// A comma-separated list of HTTP status codes on which to retry a request.
+    pub const UV_HTTP_RETRY_STATUS: &'static str = "UV_HTTP_RETRY_STATUS";
+
     /// Custom certificate bundle file path for SSL connections.
     pub const SSL_CERT_FILE: &'static str = "SSL_CERT_FILE";
 
     /// File for mTLS authentication (contains certificate and private key).
     pub const SSL_CLIENT_CERT: &'static str = "SSL_CLIENT_CERT";
 
+    /// The password with which to decrypt PKCS#12 (`.p12` or `.pfx`) client certificates.
+    pub const UV_CLIENT_CERT_PASSWORD: &'static str = "UV_CLIENT_CERT_PASSWORD";
+
     /// Proxy for HTTP requests.
     pub const HTTP_PROXY: &'static str = "HTTP_PROXY";
 
@@ -247,6 +368,9 @@ impl EnvVars {
     /// General proxy for all network requests.
     pub const ALL_PROXY: &'static str = "ALL_PROXY";
 
+    /// Comma-separated list of hosts for which to bypass the proxy.
+    pub const NO_PROXY: &'static str = "NO_PROXY";
+
     /// Used to detect an activated virtual environment.
     pub const VIRTUAL_ENV: &'static str = "VIRTUAL_ENV";
 
@@ -286,6 +410,10 @@ impl EnvVars {
     /// Disables all progress output. For example, spinners and progress bars.
     pub const UV_NO_PROGRESS: &'static str = "UV_NO_PROGRESS";
 
+    /// Equivalent to the `--log-format` argument. The format in which to emit log messages,
+    /// either `text` or `json`.
+    pub const UV_LOG_FORMAT: &'static str = "UV_LOG_FORMAT";
+
     /// Forces colored output regardless of terminal support.
     pub const FORCE_COLOR: &'static str = "FORCE_COLOR";
 
@@ -322,6 +450,23 @@ impl EnvVars {
     /// Alternate locations for git objects. Ignored by `uv` when performing fetch.
     pub const GIT_ALTERNATE_OBJECT_DIRECTORIES: &'static str = "GIT_ALTERNATE_OBJECT_DIRECTORIES";
 
+    /// Disables Git LFS's smudge filter. Set by `uv` when checking out Git dependencies, which
+    /// fetch files tracked by Git LFS separately.
+    pub const GIT_LFS_SKIP_SMUDGE: &'static str = "GIT_LFS_SKIP_SMUDGE";
+
+    /// The number of Git configuration values provided via `GIT_CONFIG_KEY_<n>` and
+    /// `GIT_CONFIG_VALUE_<n>`. Extended by `uv` to configure the remote from which Git LFS objects
+    /// are fetched, without exposing its credentials on the command line.
+    pub const GIT_CONFIG_COUNT: &'static str = "GIT_CONFIG_COUNT";
+
+    /// The SSH command used by `git`. Extended by `uv` with any SSH options configured for a
+    /// Git host.
+    pub const GIT_SSH_COMMAND: &'static str = "GIT_SSH_COMMAND";
+
+    /// Disables Mercurial's output customizations, like aliases and localization. Set by `uv`
+    /// when invoking `hg` to fetch Mercurial dependencies.
+    pub const HGPLAIN: &'static str = "HGPLAIN";
+
     /// Used in tests for better git isolation.
     ///
     /// For example, we run some tests in ~/.local/share/uv/tests.
@@ -359,6 +504,41 @@ impl EnvVars {
     /// Use to set the .netrc file location.
     pub const NETRC: &'static str = "NETRC";
 
+    /// The path to the Google application default credentials file, used to authenticate with
+    /// Google Artifact Registry.
+    pub const GOOGLE_APPLICATION_CREDENTIALS: &'static str = "GOOGLE_APPLICATION_CREDENTIALS";
+
+    /// The Google Cloud SDK configuration directory, used to locate the application default
+    /// credentials.
+    pub const CLOUDSDK_CONFIG: &'static str = "CLOUDSDK_CONFIG";
+
+    /// The host of the Google Compute Engine metadata server, used to authenticate with Google
+    /// Artifact Registry when no application default credentials are configured.
+    pub const GCE_METADATA_HOST: &'static str = "GCE_METADATA_HOST";
+
+    /// The AWS access key ID, used to authenticate with an S3 remote build cache.
+    pub const AWS_ACCESS_KEY_ID: &'static str = "AWS_ACCESS_KEY_ID";
+
+    /// The AWS secret access key, used to authenticate with an S3 remote build cache.
+    pub const AWS_SECRET_ACCESS_KEY: &'static str = "AWS_SECRET_ACCESS_KEY";
+
+    /// The AWS session token, used to authenticate with an S3 remote build cache when using
+    /// temporary credentials.
+    pub const AWS_SESSION_TOKEN: &'static str = "AWS_SESSION_TOKEN";
+
+    /// The AWS region of an S3 remote build cache.
+    pub const AWS_REGION: &'static str = "AWS_REGION";
+
+    /// The AWS region of an S3 remote build cache, if `AWS_REGION` is not set.
+    pub const AWS_DEFAULT_REGION: &'static str = "AWS_DEFAULT_REGION";
+
+    /// The endpoint of an S3-compatible service (e.g., MinIO or Cloudflare R2) to use for the
+    /// remote build cache, in place of AWS S3.
+    pub const AWS_ENDPOINT_URL: &'static str = "AWS_ENDPOINT_URL";
+
+    /// Used to locate the Google Cloud SDK configuration directory on Windows.
+    pub const APPDATA: &'static str = "APPDATA";
+
     /// The standard `PAGER` posix env var. Used by `uv` to configure the appropriate pager.
     pub const PAGER: &'static str = "PAGER";
 
@@ -371,6 +551,15 @@ impl EnvVars {
     /// Use to create the tracing durations file via the `tracing-durations-export` feature.
     pub const TRACING_DURATIONS_FILE: &'static str = "TRACING_DURATIONS_FILE";
 
+    /// The endpoint of an OpenTelemetry collector to which traces are exported via the
+    /// `tracing-otlp-export` feature.
+    pub const OTEL_EXPORTER_OTLP_ENDPOINT: &'static str = "OTEL_EXPORTER_OTLP_ENDPOINT";
+
+    /// The endpoint of an OpenTelemetry collector to which traces are exported via the
+    /// `tracing-otlp-export` feature, taking precedence over `OTEL_EXPORTER_OTLP_ENDPOINT`.
+    pub const OTEL_EXPORTER_OTLP_TRACES_ENDPOINT: &'static str =
+        "OTEL_EXPORTER_OTLP_TRACES_ENDPOINT";
+
     /// Used to set `RUST_HOST_TARGET` at build time via `build.rs`.
     pub const TARGET: &'static str = "TARGET";
 
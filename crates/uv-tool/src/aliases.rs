@@ -0,0 +1,120 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::Deserialize;
+use toml_edit::{value, Array, DocumentMut};
+
+/// An `aliases.toml` file mapping user-defined alias names to tool invocations, e.g.,
+/// `fmt = "ruff@0.6 format"`.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ToolAliases(BTreeMap<String, ToolAlias>);
+
+/// A tool invocation, i.e., the tool to run followed by any arguments to pass to it.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(from = "ToolAliasWire")]
+pub struct ToolAlias(Vec<String>);
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+enum ToolAliasWire {
+    /// A whitespace-separated command, e.g., `"ruff@0.6 format"`.
+    String(String),
+    /// A list of arguments, e.g., `["ruff@0.6", "format"]`.
+    List(Vec<String>),
+}
+
+impl From<ToolAliasWire> for ToolAlias {
+    fn from(wire: ToolAliasWire) -> Self {
+        match wire {
+            ToolAliasWire::String(command) => Self(
+                command
+                    .split_whitespace()
+                    .map(ToString::to_string)
+                    .collect(),
+            ),
+            ToolAliasWire::List(command) => Self(command),
+        }
+    }
+}
+
+impl ToolAlias {
+    /// Create a new [`ToolAlias`] from the tool to run and its arguments.
+    pub fn new(command: Vec<String>) -> Self {
+        Self(command)
+    }
+
+    /// Return the tool to run (e.g., `ruff@0.6`), if any.
+    pub fn target(&self) -> Option<&str> {
+        self.0.first().map(String::as_str)
+    }
+
+    /// Return the arguments to pass to the tool.
+    pub fn args(&self) -> &[String] {
+        self.0.get(1..).unwrap_or_default()
+    }
+
+    /// Returns the TOML value for this alias.
+    ///
+    /// Aliases are written as a single string, unless an argument contains whitespace.
+    fn to_toml(&self) -> toml_edit::Value {
+        if self.0.iter().any(|arg| arg.contains(char::is_whitespace)) {
+            toml_edit::Value::Array(self.0.iter().collect::<Array>())
+        } else {
+            toml_edit::Value::from(self.0.join(" "))
+        }
+    }
+}
+
+impl std::fmt::Display for ToolAlias {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.join(" "))
+    }
+}
+
+impl ToolAliases {
+    /// Read the [`ToolAliases`] from the given path.
+    ///
+    /// If the file does not exist, returns an empty set of aliases.
+    pub(crate) fn from_path(path: &Path) -> Result<Self, crate::Error> {
+        match fs_err::read_to_string(path) {
+            Ok(contents) => toml::from_str(&contents)
+                .map_err(|err| crate::Error::AliasesRead(path.to_owned(), Box::new(err))),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Returns the TOML representation of the aliases.
+    pub(crate) fn to_toml(&self) -> String {
+        let mut doc = DocumentMut::new();
+        for (name, alias) in &self.0 {
+            doc.insert(name, value(alias.to_toml()));
+        }
+        doc.to_string()
+    }
+
+    /// Return the alias with the given name, if any.
+    pub fn get(&self, name: &str) -> Option<&ToolAlias> {
+        self.0.get(name)
+    }
+
+    /// Add an alias, returning the alias it replaced, if any.
+    pub fn insert(&mut self, name: String, alias: ToolAlias) -> Option<ToolAlias> {
+        self.0.insert(name, alias)
+    }
+
+    /// Remove an alias, returning it if it existed.
+    pub fn remove(&mut self, name: &str) -> Option<ToolAlias> {
+        self.0.remove(name)
+    }
+
+    /// Iterate over the aliases, sorted by name.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &ToolAlias)> {
+        self.0.iter().map(|(name, alias)| (name.as_str(), alias))
+    }
+
+    /// Returns `true` if there are no aliases.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
@@ -16,8 +16,9 @@ use tracing::{debug, warn};
 
 use uv_install_wheel::read_record_file;
 
+pub use aliases::{ToolAlias, ToolAliases};
 pub use receipt::ToolReceipt;
-pub use tool::{Tool, ToolEntrypoint};
+pub use tool::{Tool, ToolEntrypoint, ToolLockedRequirement};
 use uv_cache::Cache;
 use uv_fs::{LockedFile, Simplified};
 use uv_installer::SitePackages;
@@ -25,6 +26,7 @@ use uv_python::{Interpreter, PythonEnvironment};
 use uv_state::{StateBucket, StateStore};
 use uv_static::EnvVars;
 
+mod aliases;
 mod receipt;
 mod tool;
 
@@ -36,6 +38,10 @@ pub enum Error {
     ReceiptWrite(PathBuf, #[source] Box<toml::ser::Error>),
     #[error("Failed to read `uv-receipt.toml` at {0}")]
     ReceiptRead(PathBuf, #[source] Box<toml::de::Error>),
+    #[error("Failed to resolve the locked requirements in `uv-receipt.toml` at {0}")]
+    ReceiptPath(PathBuf, #[source] uv_pep508::VerbatimUrlError),
+    #[error("Failed to read `aliases.toml` at {0}")]
+    AliasesRead(PathBuf, #[source] Box<toml::de::Error>),
     #[error(transparent)]
     VirtualEnvError(#[from] uv_virtualenv::Error),
     #[error("Failed to read package entry points {0}")]
@@ -116,7 +122,10 @@ impl InstalledTools {
                 Err(err) => return Err(err.into()),
             };
             match ToolReceipt::from_string(contents) {
-                Ok(tool_receipt) => tools.push((name, Ok(tool_receipt.tool))),
+                Ok(tool_receipt) => match tool_receipt.tool.absolute_from(&directory) {
+                    Ok(tool) => tools.push((name, Ok(tool))),
+                    Err(err) => tools.push((name, Err(Error::ReceiptPath(path, err)))),
+                },
                 Err(err) => {
                     let err = Error::ReceiptRead(path, Box::new(err));
                     tools.push((name, Err(err)));
@@ -135,7 +144,12 @@ impl InstalledTools {
     pub fn get_tool_receipt(&self, name: &PackageName) -> Result<Option<Tool>, Error> {
         let path = self.tool_dir(name).join("uv-receipt.toml");
         match ToolReceipt::from_path(&path) {
-            Ok(tool_receipt) => Ok(Some(tool_receipt.tool)),
+            Ok(tool_receipt) => Ok(Some(
+                tool_receipt
+                    .tool
+                    .absolute_from(&self.tool_dir(name))
+                    .map_err(|err| Error::ReceiptPath(path, err))?,
+            )),
             Err(Error::Io(err)) if err.kind() == io::ErrorKind::NotFound => Ok(None),
             Err(err) => Err(err),
         }
@@ -152,7 +166,8 @@ impl InstalledTools {
     ///
     /// Note it is generally incorrect to use this without [`Self::acquire_lock`].
     pub fn add_tool_receipt(&self, name: &PackageName, tool: Tool) -> Result<(), Error> {
-        let tool_receipt = ToolReceipt::from(tool);
+        // Store any local paths in the locked requirements relative to the receipt.
+        let tool_receipt = ToolReceipt::from(tool.relative_to(&self.tool_dir(name))?);
         let path = self.tool_dir(name).join("uv-receipt.toml");
 
         debug!(
@@ -168,6 +183,26 @@ impl InstalledTools {
         Ok(())
     }
 
+    /// Return the user-defined tool aliases.
+    ///
+    /// If no aliases have been defined, returns an empty set of aliases.
+    pub fn aliases(&self) -> Result<ToolAliases, Error> {
+        ToolAliases::from_path(&self.root.join("aliases.toml"))
+    }
+
+    /// Replace the user-defined tool aliases.
+    ///
+    /// Note it is generally incorrect to use this without [`Self::acquire_lock`].
+    pub fn write_aliases(&self, aliases: &ToolAliases) -> Result<(), Error> {
+        let path = self.root.join("aliases.toml");
+
+        debug!("Writing tool aliases to {}", path.user_display());
+
+        fs_err::write(&path, aliases.to_toml())?;
+
+        Ok(())
+    }
+
     /// Remove the environment for a tool.
     ///
     /// Does not remove the tool's entrypoints.
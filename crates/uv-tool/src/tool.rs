@@ -1,13 +1,15 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use toml_edit::value;
 use toml_edit::Table;
 use toml_edit::Value;
 use toml_edit::{Array, Item};
 
-use uv_fs::PortablePath;
-use uv_pypi_types::{Requirement, VerbatimParsedUrl};
+use uv_fs::{normalize_path, PortablePath};
+use uv_pep508::{VerbatimUrl, VerbatimUrlError};
+use uv_pypi_types::{HashDigest, Requirement, RequirementSource, VerbatimParsedUrl};
 use uv_settings::ToolOptions;
 
 /// A tool entry.
@@ -20,6 +22,11 @@ pub struct Tool {
     python: Option<String>,
     /// A mapping of entry point names to their metadata.
     entrypoints: Vec<ToolEntrypoint>,
+    /// The paths of any shell completion scripts installed for the tool's entry points.
+    completions: Vec<PathBuf>,
+    /// The exact packages installed into the tool environment, as of the last installation or
+    /// upgrade.
+    locked: Vec<ToolLockedRequirement>,
     /// The [`ToolOptions`] used to install this tool.
     options: ToolOptions,
 }
@@ -30,6 +37,10 @@ struct ToolWire {
     python: Option<String>,
     entrypoints: Vec<ToolEntrypoint>,
     #[serde(default)]
+    completions: Vec<PathBuf>,
+    #[serde(default)]
+    locked: Vec<ToolLockedRequirement>,
+    #[serde(default)]
     options: ToolOptions,
 }
 
@@ -53,6 +64,8 @@ impl From<Tool> for ToolWire {
                 .collect(),
             python: tool.python,
             entrypoints: tool.entrypoints,
+            completions: tool.completions,
+            locked: tool.locked,
             options: tool.options,
         }
     }
@@ -73,11 +86,127 @@ impl TryFrom<ToolWire> for Tool {
                 .collect(),
             python: tool.python,
             entrypoints: tool.entrypoints,
+            completions: tool.completions,
+            locked: tool.locked,
             options: tool.options,
         })
     }
 }
 
+/// A package installed into a tool environment, pinned to its exact version (or source).
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(
+    try_from = "ToolLockedRequirementWire",
+    into = "ToolLockedRequirementWire"
+)]
+pub struct ToolLockedRequirement {
+    /// The pinned requirement, including the index from which the package was installed and the
+    /// markers under which it's included.
+    requirement: Requirement,
+    /// The hashes of the installed distribution, if known.
+    hashes: Vec<HashDigest>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ToolLockedRequirementWire {
+    #[serde(flatten)]
+    requirement: Requirement,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    hashes: Vec<String>,
+}
+
+impl From<ToolLockedRequirement> for ToolLockedRequirementWire {
+    fn from(locked: ToolLockedRequirement) -> Self {
+        Self {
+            requirement: locked.requirement,
+            hashes: locked.hashes.iter().map(ToString::to_string).collect(),
+        }
+    }
+}
+
+impl TryFrom<ToolLockedRequirementWire> for ToolLockedRequirement {
+    type Error = uv_pypi_types::HashError;
+
+    fn try_from(wire: ToolLockedRequirementWire) -> Result<Self, Self::Error> {
+        Ok(Self {
+            requirement: wire.requirement,
+            hashes: wire
+                .hashes
+                .iter()
+                .map(|hash| HashDigest::from_str(hash))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl ToolLockedRequirement {
+    /// Create a new [`ToolLockedRequirement`].
+    pub fn new(requirement: Requirement, hashes: Vec<HashDigest>) -> Self {
+        Self {
+            requirement,
+            hashes,
+        }
+    }
+
+    /// Returns the pinned [`Requirement`].
+    pub fn requirement(&self) -> &Requirement {
+        &self.requirement
+    }
+
+    /// Returns the hashes of the installed distribution, if known.
+    pub fn hashes(&self) -> &[HashDigest] {
+        &self.hashes
+    }
+
+    /// Convert the requirement's local paths to be relative to the given directory.
+    fn relative_to(self, path: &Path) -> Result<Self, std::io::Error> {
+        Ok(Self {
+            requirement: self.requirement.relative_to(path)?,
+            ..self
+        })
+    }
+
+    /// Resolve the requirement's relative local paths against the given directory.
+    fn absolute_from(self, path: &Path) -> Result<Self, VerbatimUrlError> {
+        let source = match self.requirement.source {
+            RequirementSource::Path {
+                install_path,
+                ext,
+                url,
+            } if install_path.is_relative() => {
+                let install_path = normalize_path(&path.join(install_path));
+                RequirementSource::Path {
+                    url: VerbatimUrl::from_absolute_path(&install_path)?,
+                    install_path,
+                    ext,
+                }
+            }
+            RequirementSource::Directory {
+                install_path,
+                editable,
+                r#virtual,
+                url,
+            } if install_path.is_relative() => {
+                let install_path = normalize_path(&path.join(install_path));
+                RequirementSource::Directory {
+                    url: VerbatimUrl::from_absolute_path(&install_path)?,
+                    install_path,
+                    editable,
+                    r#virtual,
+                }
+            }
+            source => source,
+        };
+        Ok(Self {
+            requirement: Requirement {
+                source,
+                ..self.requirement
+            },
+            ..self
+        })
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub struct ToolEntrypoint {
@@ -118,14 +247,19 @@ impl Tool {
         requirements: Vec<Requirement>,
         python: Option<String>,
         entrypoints: impl Iterator<Item = ToolEntrypoint>,
+        locked: impl Iterator<Item = ToolLockedRequirement>,
         options: ToolOptions,
     ) -> Self {
         let mut entrypoints: Vec<_> = entrypoints.collect();
         entrypoints.sort();
+        let mut locked: Vec<_> = locked.collect();
+        locked.sort();
         Self {
             requirements,
             python,
             entrypoints,
+            completions: vec![],
+            locked,
             options,
         }
     }
@@ -136,6 +270,49 @@ impl Tool {
         Self { options, ..self }
     }
 
+    /// Create a new [`Tool`] with the given locked requirements.
+    #[must_use]
+    pub fn with_locked(self, locked: impl Iterator<Item = ToolLockedRequirement>) -> Self {
+        let mut locked: Vec<_> = locked.collect();
+        locked.sort();
+        Self { locked, ..self }
+    }
+
+    /// Convert any local paths in the locked requirements to be relative to the given directory
+    /// (i.e., the directory containing the receipt).
+    pub(crate) fn relative_to(self, path: &Path) -> Result<Self, std::io::Error> {
+        Ok(Self {
+            locked: self
+                .locked
+                .into_iter()
+                .map(|locked| locked.relative_to(path))
+                .collect::<Result<_, _>>()?,
+            ..self
+        })
+    }
+
+    /// Resolve any relative local paths in the locked requirements against the given directory
+    /// (i.e., the directory containing the receipt).
+    pub(crate) fn absolute_from(self, path: &Path) -> Result<Self, VerbatimUrlError> {
+        Ok(Self {
+            locked: self
+                .locked
+                .into_iter()
+                .map(|locked| locked.absolute_from(path))
+                .collect::<Result<_, _>>()?,
+            ..self
+        })
+    }
+
+    /// Create a new [`Tool`] with the given shell completion scripts.
+    #[must_use]
+    pub fn with_completions(self, completions: Vec<PathBuf>) -> Self {
+        Self {
+            completions,
+            ..self
+        }
+    }
+
     /// Returns the TOML table for this tool.
     pub(crate) fn to_toml(&self) -> Result<Table, toml_edit::ser::Error> {
         let mut table = Table::new();
@@ -174,6 +351,34 @@ impl Tool {
             value(entrypoints)
         });
 
+        if !self.completions.is_empty() {
+            table.insert("completions", {
+                let completions = each_element_on_its_line_array(
+                    self.completions
+                        .iter()
+                        // Use cross-platform slashes so the toml string type does not change
+                        .map(|path| PortablePath::from(path).to_string()),
+                );
+                value(completions)
+            });
+        }
+
+        if !self.locked.is_empty() {
+            table.insert("locked", {
+                let locked = self
+                    .locked
+                    .iter()
+                    .map(|requirement| {
+                        serde::Serialize::serialize(
+                            &requirement,
+                            toml_edit::ser::ValueSerializer::new(),
+                        )
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                value(each_element_on_its_line_array(locked.into_iter()))
+            });
+        }
+
         if self.options != ToolOptions::default() {
             let serialized =
                 serde::Serialize::serialize(&self.options, toml_edit::ser::ValueSerializer::new())?;
@@ -192,10 +397,22 @@ impl Tool {
         &self.entrypoints
     }
 
+    pub fn completions(&self) -> &[PathBuf] {
+        &self.completions
+    }
+
     pub fn requirements(&self) -> &[Requirement] {
         &self.requirements
     }
 
+    /// Returns the exact packages installed into the tool environment, as of the last
+    /// installation or upgrade.
+    ///
+    /// Empty for receipts written before locked requirements were recorded.
+    pub fn locked(&self) -> &[ToolLockedRequirement] {
+        &self.locked
+    }
+
     pub fn python(&self) -> &Option<String> {
         &self.python
     }
@@ -8,6 +8,10 @@ use uv_fs::Simplified;
 use zip::write::FileOptions;
 use zip::ZipWriter;
 
+pub use crate::resources::LauncherResources;
+
+mod resources;
+
 #[cfg(all(windows, target_arch = "x86"))]
 const LAUNCHER_I686_GUI: &[u8] =
     include_bytes!("../../uv-trampoline/trampolines/uv-trampoline-i686-gui.exe");
@@ -189,6 +193,12 @@ pub enum Error {
     UnsupportedWindowsArch(&'static str),
     #[error("Unable to create Windows launcher on non-Windows platform")]
     NotWindows,
+    #[error("Invalid launcher icon (expected an `.ico` file): {}", _0.user_display())]
+    InvalidIcon(PathBuf),
+    #[error(
+        "Invalid launcher version `{0}` (expected up to four dot-separated integers, like `1.2.3`)"
+    )]
+    InvalidLauncherVersion(String),
 }
 
 #[allow(clippy::unnecessary_wraps, unused_variables)]
@@ -230,12 +240,15 @@ fn get_launcher_bin(gui: bool) -> Result<&'static [u8], Error> {
 /// A Windows script is a minimal .exe launcher binary with the python entrypoint script appended as
 /// stored zip file.
 ///
+/// If provided, the [`LauncherResources`] are embedded into the launcher binary.
+///
 /// <https://github.com/pypa/pip/blob/fd0ea6bc5e8cb95e518c23d901c26ca14db17f89/src/pip/_vendor/distlib/scripts.py#L248-L262>
 #[allow(unused_variables)]
 pub fn windows_script_launcher(
     launcher_python_script: &str,
     is_gui: bool,
     python_executable: impl AsRef<Path>,
+    resources: Option<&LauncherResources>,
 ) -> Result<Vec<u8>, Error> {
     // This method should only be called on Windows, but we avoid `#[cfg(windows)]` to retain
     // compilation on all platforms.
@@ -245,6 +258,12 @@ pub fn windows_script_launcher(
 
     let launcher_bin: &[u8] = get_launcher_bin(is_gui)?;
 
+    // If requested, embed version information and an icon into the launcher.
+    let launcher_bin = match resources {
+        Some(resources) => resources.stamp(launcher_bin, is_gui)?,
+        None => launcher_bin,
+    };
+
     let mut payload: Vec<u8> = Vec::new();
     {
         // We're using the zip writer, but with stored compression
@@ -323,7 +342,9 @@ mod test {
 
     use which::which;
 
-    use super::{windows_python_launcher, windows_script_launcher, Launcher, LauncherKind};
+    use super::{
+        windows_python_launcher, windows_script_launcher, Launcher, LauncherKind, LauncherResources,
+    };
 
     #[test]
     #[cfg(all(windows, target_arch = "x86", feature = "production"))]
@@ -438,8 +459,12 @@ if __name__ == "__main__":
             get_script_launcher(&format_shebang(&python_executable_path), false);
 
         // Generate Launcher Payload
-        let console_launcher =
-            windows_script_launcher(&launcher_console_script, false, &python_executable_path)?;
+        let console_launcher = windows_script_launcher(
+            &launcher_console_script,
+            false,
+            &python_executable_path,
+            None,
+        )?;
 
         // Create Launcher
         File::create(console_bin_path.path())?.write_all(console_launcher.as_ref())?;
@@ -481,6 +506,58 @@ if __name__ == "__main__":
         Ok(())
     }
 
+    #[test]
+    fn console_script_launcher_resources() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+
+        // Locate an arbitrary python installation from PATH
+        let python_executable_path = which("python")?;
+
+        let launcher_console_script =
+            get_script_launcher(&format_shebang(&python_executable_path), false);
+
+        let resources = LauncherResources::new(Some("Example"), Some("1.2.3"), None)?
+            .expect("The resources should not be empty");
+
+        // Stamp two launchers with the same resources, as when installing multiple entrypoints.
+        for name in ["first.exe", "second.exe"] {
+            let bin_path = temp_dir.child(name);
+            let console_launcher = windows_script_launcher(
+                &launcher_console_script,
+                false,
+                &python_executable_path,
+                Some(&resources),
+            )?;
+            File::create(bin_path.path())?.write_all(console_launcher.as_ref())?;
+
+            // The version information should be embedded in the launcher.
+            let product_name = "Example"
+                .encode_utf16()
+                .flat_map(u16::to_le_bytes)
+                .collect::<Vec<_>>();
+            assert!(console_launcher
+                .windows(product_name.len())
+                .any(|window| window == product_name));
+
+            // The launcher should still run the script...
+            Command::new(bin_path.path())
+                .arg("foo")
+                .assert()
+                .success()
+                .stdout("Hello from uv-trampoline-console.exe\r\n")
+                .stderr("Hello from uv-trampoline-console.exe\r\nfoo\r\n");
+
+            // ...and be recognized as a launcher.
+            let launcher = Launcher::try_from_path(bin_path.path())
+                .expect("We should succeed at reading the launcher")
+                .expect("The launcher should be valid");
+            assert!(launcher.kind == LauncherKind::Script);
+            assert!(launcher.python_path == python_executable_path);
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn console_python_launcher() -> Result<()> {
         // Create Temp Dirs
@@ -535,7 +612,7 @@ if __name__ == "__main__":
 
         // Generate Launcher Payload
         let gui_launcher =
-            windows_script_launcher(&launcher_gui_script, true, &pythonw_executable_path)?;
+            windows_script_launcher(&launcher_gui_script, true, &pythonw_executable_path, None)?;
 
         // Create Launcher
         File::create(gui_bin_path.path())?.write_all(gui_launcher.as_ref())?;
@@ -0,0 +1,397 @@
+//! Embedding of version information and icons into the resources of launcher executables.
+//!
+//! Some endpoint protection software refuses to run executables that lack version information,
+//! so users can opt in to stamping launchers with a product name, a version, and an icon.
+
+use std::path::Path;
+use std::sync::OnceLock;
+
+use crate::Error;
+
+/// The resource type of an individual icon image.
+const RT_ICON: u16 = 3;
+/// The resource type of an icon group, which references the individual images.
+const RT_GROUP_ICON: u16 = 14;
+/// The resource type of the version information.
+const RT_VERSION: u16 = 16;
+
+/// Metadata to embed into the resources of launcher executables.
+///
+/// The icon is read once, upon construction, and each kind of launcher (console or GUI) is stamped
+/// at most once, such that installing many entrypoints doesn't repeat the work.
+#[derive(Debug, Clone)]
+pub struct LauncherResources {
+    /// The resources to embed, as `(type, name, data)` tuples.
+    entries: Vec<(u16, u16, Vec<u8>)>,
+    /// The console launcher, with the resources embedded.
+    console: OnceLock<Vec<u8>>,
+    /// The GUI launcher, with the resources embedded.
+    gui: OnceLock<Vec<u8>>,
+}
+
+impl LauncherResources {
+    /// Create the [`LauncherResources`] to embed the given product name (used for both the
+    /// `ProductName` and `FileDescription` fields), version (used for both the file and the
+    /// product version), and `.ico` file.
+    ///
+    /// Returns `None` if there's nothing to embed.
+    pub fn new(
+        product_name: Option<&str>,
+        version: Option<&str>,
+        icon: Option<&Path>,
+    ) -> Result<Option<Self>, Error> {
+        let mut entries = Vec::new();
+
+        if let Some(icon) = icon {
+            let contents = fs_err::read(icon)?;
+            let images =
+                parse_ico(&contents).ok_or_else(|| Error::InvalidIcon(icon.to_path_buf()))?;
+
+            // Store each image as a separate resource, and reference them from an icon group.
+            let mut group = Vec::new();
+            push_u16(&mut group, 0);
+            push_u16(&mut group, 1);
+            push_u16(
+                &mut group,
+                u16::try_from(images.len()).expect("count is read as a u16"),
+            );
+            for (index, image) in images.into_iter().enumerate() {
+                let id = u16::try_from(index + 1).expect("count is read as a u16");
+                group.extend_from_slice(&image.header[..12]);
+                push_u16(&mut group, id);
+                entries.push((RT_ICON, id, image.data.to_vec()));
+            }
+            entries.push((RT_GROUP_ICON, 1, group));
+        }
+
+        if product_name.is_some() || version.is_some() {
+            entries.push((RT_VERSION, 1, version_info(product_name, version)?));
+        }
+
+        if entries.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(Self {
+            entries,
+            console: OnceLock::new(),
+            gui: OnceLock::new(),
+        }))
+    }
+
+    /// Return a copy of the given launcher binary with the resources embedded.
+    pub(crate) fn stamp(&self, launcher_bin: &[u8], is_gui: bool) -> Result<&[u8], Error> {
+        let cell = if is_gui { &self.gui } else { &self.console };
+        if let Some(stamped) = cell.get() {
+            return Ok(stamped.as_slice());
+        }
+
+        // The resource APIs operate on files, so stage the launcher in a temporary directory. Note
+        // that the resources must be embedded before appending the payload, since updating the
+        // resources discards any data after the last section of the executable.
+        let temp_dir = tempfile::tempdir()?;
+        let path = temp_dir.path().join("launcher.exe");
+        fs_err::write(&path, launcher_bin)?;
+        update_resources(&path, &self.entries)?;
+        let stamped = fs_err::read(&path)?;
+
+        Ok(cell.get_or_init(|| stamped).as_slice())
+    }
+}
+
+/// Build a `VS_VERSIONINFO` structure for the launcher.
+///
+/// See: <https://learn.microsoft.com/en-us/windows/win32/menurc/vs-versioninfo>
+fn version_info(product_name: Option<&str>, version: Option<&str>) -> Result<Vec<u8>, Error> {
+    let parsed = match version {
+        Some(version) => parse_version(version)
+            .ok_or_else(|| Error::InvalidLauncherVersion(version.to_string()))?,
+        None => [0; 4],
+    };
+    let ms = (u32::from(parsed[0]) << 16) | u32::from(parsed[1]);
+    let ls = (u32::from(parsed[2]) << 16) | u32::from(parsed[3]);
+
+    // The `VS_FIXEDFILEINFO` structure.
+    let mut fixed = Vec::with_capacity(52);
+    for value in [
+        // The signature and structure version.
+        0xFEEF_04BD,
+        0x0001_0000,
+        // The file version.
+        ms,
+        ls,
+        // The product version.
+        ms,
+        ls,
+        // The file flags mask and flags.
+        0x3F,
+        0,
+        // `VOS_NT_WINDOWS32`.
+        0x0004_0004,
+        // `VFT_APP`.
+        1,
+        // The file subtype and date.
+        0,
+        0,
+        0,
+    ] {
+        fixed.extend_from_slice(&u32::to_le_bytes(value));
+    }
+
+    // The strings, in a U.S. English, Unicode string table.
+    let mut strings = Vec::new();
+    if let Some(product_name) = product_name {
+        strings.push(text_node("ProductName", product_name));
+        strings.push(text_node("FileDescription", product_name));
+    }
+    if let Some(version) = version {
+        strings.push(text_node("ProductVersion", version));
+        strings.push(text_node("FileVersion", version));
+    }
+    let table = node("040904B0", 1, &[], 0, &strings);
+    let string_file_info = node("StringFileInfo", 1, &[], 0, &[table]);
+
+    let mut translation = Vec::new();
+    push_u16(&mut translation, 0x0409);
+    push_u16(&mut translation, 0x04B0);
+    let var = node("Translation", 0, &translation, translation.len(), &[]);
+    let var_file_info = node("VarFileInfo", 1, &[], 0, &[var]);
+
+    Ok(node(
+        "VS_VERSION_INFO",
+        0,
+        &fixed,
+        fixed.len(),
+        &[string_file_info, var_file_info],
+    ))
+}
+
+/// An image within an `.ico` file.
+struct IconImage<'a> {
+    /// The `ICONDIRENTRY` header, excluding the offset.
+    header: &'a [u8],
+    /// The image data.
+    data: &'a [u8],
+}
+
+/// Parse the images from an `.ico` file.
+///
+/// See: <https://learn.microsoft.com/en-us/previous-versions/ms997538(v=msdn.10)>
+fn parse_ico(contents: &[u8]) -> Option<Vec<IconImage<'_>>> {
+    let read_u16 = |offset: usize| {
+        contents
+            .get(offset..offset + 2)
+            .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+    };
+    let read_u32 = |offset: usize| {
+        contents
+            .get(offset..offset + 4)
+            .map(|bytes| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    };
+
+    // The header must indicate an icon (as opposed to a cursor), with at least one image.
+    if read_u16(0)? != 0 || read_u16(2)? != 1 {
+        return None;
+    }
+    let count = usize::from(read_u16(4)?);
+    if count == 0 {
+        return None;
+    }
+
+    let mut images = Vec::with_capacity(count);
+    for index in 0..count {
+        let entry = 6 + index * 16;
+        let size = usize::try_from(read_u32(entry + 8)?).ok()?;
+        let offset = usize::try_from(read_u32(entry + 12)?).ok()?;
+        images.push(IconImage {
+            header: contents.get(entry..entry + 12)?,
+            data: contents.get(offset..offset.checked_add(size)?)?,
+        });
+    }
+    Some(images)
+}
+
+/// Parse a version like `1.2.3` into the four 16-bit components of a Windows file version.
+fn parse_version(version: &str) -> Option<[u16; 4]> {
+    let mut parts = [0; 4];
+    let mut count = 0;
+    for part in version.split('.') {
+        *parts.get_mut(count)? = part.parse().ok()?;
+        count += 1;
+    }
+    Some(parts)
+}
+
+/// Build a text `String` node of a version resource.
+fn text_node(key: &str, value: &str) -> Vec<u8> {
+    let value = utf16(value);
+    let length = value.len() / 2;
+    node(key, 1, &value, length, &[])
+}
+
+/// Build a node of a version resource, i.e., a header followed by the key, the value, and any
+/// children, each aligned to 32 bits.
+///
+/// The `value_length` is in bytes for binary values, and in 16-bit words for text values.
+fn node(key: &str, kind: u16, value: &[u8], value_length: usize, children: &[Vec<u8>]) -> Vec<u8> {
+    let mut buffer = Vec::new();
+
+    // The header, with a placeholder for the length.
+    push_u16(&mut buffer, 0);
+    push_u16(
+        &mut buffer,
+        u16::try_from(value_length).expect("value should be smaller than 64KB"),
+    );
+    push_u16(&mut buffer, kind);
+    buffer.extend_from_slice(&utf16(key));
+
+    pad(&mut buffer);
+    buffer.extend_from_slice(value);
+    for child in children {
+        pad(&mut buffer);
+        buffer.extend_from_slice(child);
+    }
+
+    let length = u16::try_from(buffer.len()).expect("resource should be smaller than 64KB");
+    buffer[..2].copy_from_slice(&length.to_le_bytes());
+    buffer
+}
+
+/// Encode a string as null-terminated UTF-16.
+fn utf16(value: &str) -> Vec<u8> {
+    value
+        .encode_utf16()
+        .chain(std::iter::once(0))
+        .flat_map(u16::to_le_bytes)
+        .collect()
+}
+
+fn push_u16(buffer: &mut Vec<u8>, value: u16) {
+    buffer.extend_from_slice(&value.to_le_bytes());
+}
+
+/// Pad the buffer to a 32-bit boundary.
+fn pad(buffer: &mut Vec<u8>) {
+    while buffer.len() % 4 != 0 {
+        buffer.push(0);
+    }
+}
+
+/// Embed the given resources into the launcher executable at `path`.
+#[cfg(windows)]
+fn update_resources(path: &Path, entries: &[(u16, u16, Vec<u8>)]) -> Result<(), Error> {
+    use std::os::windows::ffi::OsStrExt;
+
+    use windows_sys::Win32::System::LibraryLoader::{
+        BeginUpdateResourceW, EndUpdateResourceW, UpdateResourceW,
+    };
+
+    let path = path
+        .as_os_str()
+        .encode_wide()
+        .chain(std::iter::once(0))
+        .collect::<Vec<_>>();
+
+    // SAFETY: The path is a valid, null-terminated wide string.
+    let handle = unsafe { BeginUpdateResourceW(path.as_ptr(), 0) };
+    if handle.is_null() {
+        return Err(Error::Io(std::io::Error::last_os_error()));
+    }
+
+    for (kind, name, data) in entries {
+        // SAFETY: The handle is valid, the type and name are integer resource identifiers (i.e.,
+        // `MAKEINTRESOURCE`), and the data outlives the call.
+        let result = unsafe {
+            UpdateResourceW(
+                handle,
+                usize::from(*kind) as *const u16,
+                usize::from(*name) as *const u16,
+                0,
+                data.as_ptr().cast(),
+                u32::try_from(data.len()).expect("resource should be smaller than 4GB"),
+            )
+        };
+        if result == 0 {
+            let err = std::io::Error::last_os_error();
+            // SAFETY: The handle is valid; discard the pending updates.
+            unsafe { EndUpdateResourceW(handle, 1) };
+            return Err(Error::Io(err));
+        }
+    }
+
+    // SAFETY: The handle is valid; commit the pending updates.
+    if unsafe { EndUpdateResourceW(handle, 0) } == 0 {
+        return Err(Error::Io(std::io::Error::last_os_error()));
+    }
+
+    Ok(())
+}
+
+/// Embed the given resources into the launcher executable at `path`.
+#[cfg(not(windows))]
+#[allow(clippy::unnecessary_wraps)]
+fn update_resources(_path: &Path, _entries: &[(u16, u16, Vec<u8>)]) -> Result<(), Error> {
+    Err(Error::NotWindows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_ico, parse_version, LauncherResources, RT_VERSION};
+
+    #[test]
+    fn version() {
+        assert_eq!(parse_version("1"), Some([1, 0, 0, 0]));
+        assert_eq!(parse_version("1.2.3"), Some([1, 2, 3, 0]));
+        assert_eq!(parse_version("1.2.3.4"), Some([1, 2, 3, 4]));
+        assert_eq!(parse_version("1.2.3.4.5"), None);
+        assert_eq!(parse_version("1.2.3a1"), None);
+    }
+
+    #[test]
+    fn version_info() {
+        let version_info = super::version_info(Some("Example"), Some("1.2.3")).unwrap();
+
+        // The length is recorded in the header.
+        assert_eq!(
+            usize::from(u16::from_le_bytes([version_info[0], version_info[1]])),
+            version_info.len()
+        );
+        // The `VS_FIXEDFILEINFO` follows the key, at a 32-bit boundary.
+        assert_eq!(&version_info[40..44], &0xFEEF_04BD_u32.to_le_bytes());
+        assert_eq!(&version_info[48..52], &0x0001_0002_u32.to_le_bytes());
+        assert_eq!(&version_info[52..56], &0x0003_0000_u32.to_le_bytes());
+
+        // Invalid versions are rejected.
+        assert!(super::version_info(None, Some("1.2.3a1")).is_err());
+    }
+
+    #[test]
+    fn entries() {
+        assert!(LauncherResources::new(None, None, None).unwrap().is_none());
+
+        let resources = LauncherResources::new(None, Some("1.2.3"), None)
+            .unwrap()
+            .unwrap();
+        assert_eq!(resources.entries.len(), 1);
+        assert_eq!(resources.entries[0].0, RT_VERSION);
+    }
+
+    #[test]
+    fn ico() {
+        let mut contents = vec![0, 0, 1, 0, 1, 0];
+        contents.extend_from_slice(&[16, 16, 0, 0, 1, 0, 32, 0]);
+        contents.extend_from_slice(&4u32.to_le_bytes());
+        contents.extend_from_slice(&22u32.to_le_bytes());
+        contents.extend_from_slice(&[1, 2, 3, 4]);
+
+        let images = parse_ico(&contents).unwrap();
+        assert_eq!(images.len(), 1);
+        assert_eq!(images[0].header, &contents[6..18]);
+        assert_eq!(images[0].data, &[1, 2, 3, 4]);
+
+        // Truncated images are rejected.
+        assert!(parse_ico(&contents[..24]).is_none());
+        // Cursors are rejected.
+        assert!(parse_ico(&[0, 0, 2, 0, 1, 0]).is_none());
+    }
+}
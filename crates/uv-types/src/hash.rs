@@ -18,8 +18,9 @@ pub enum HashStrategy {
     /// No hash policy is specified.
     #[default]
     None,
-    /// Hashes should be generated (specifically, a SHA-256 hash), but not validated.
-    Generate,
+    /// Hashes should be generated (specifically, a SHA-256 hash), and validated only for the
+    /// distributions with expected hashes (e.g., URL sources that declare a `hash`).
+    Generate(Arc<FxHashMap<VersionId, Vec<HashDigest>>>),
     /// Hashes should be validated, if present, but ignored if absent.
     ///
     /// If necessary, hashes should be generated to ensure that the archive is valid.
@@ -35,7 +36,11 @@ impl HashStrategy {
     pub fn get<T: DistributionMetadata>(&self, distribution: &T) -> HashPolicy {
         match self {
             Self::None => HashPolicy::None,
-            Self::Generate => HashPolicy::Generate,
+            Self::Generate(hashes) => hashes
+                .get(&distribution.version_id())
+                .map_or(HashPolicy::Generate, |hashes| {
+                    HashPolicy::Validate(hashes.as_slice())
+                }),
             Self::Verify(hashes) => {
                 if let Some(hashes) = hashes.get(&distribution.version_id()) {
                     HashPolicy::Validate(hashes.as_slice())
@@ -56,7 +61,11 @@ impl HashStrategy {
     pub fn get_package(&self, name: &PackageName, version: &Version) -> HashPolicy {
         match self {
             Self::None => HashPolicy::None,
-            Self::Generate => HashPolicy::Generate,
+            Self::Generate(hashes) => hashes
+                .get(&VersionId::from_registry(name.clone(), version.clone()))
+                .map_or(HashPolicy::Generate, |hashes| {
+                    HashPolicy::Validate(hashes.as_slice())
+                }),
             Self::Verify(hashes) => {
                 if let Some(hashes) =
                     hashes.get(&VersionId::from_registry(name.clone(), version.clone()))
@@ -79,7 +88,11 @@ impl HashStrategy {
     pub fn get_url(&self, url: &Url) -> HashPolicy {
         match self {
             Self::None => HashPolicy::None,
-            Self::Generate => HashPolicy::Generate,
+            Self::Generate(hashes) => hashes
+                .get(&VersionId::from_url(url))
+                .map_or(HashPolicy::Generate, |hashes| {
+                    HashPolicy::Validate(hashes.as_slice())
+                }),
             Self::Verify(hashes) => {
                 if let Some(hashes) = hashes.get(&VersionId::from_url(url)) {
                     HashPolicy::Validate(hashes.as_slice())
@@ -100,7 +113,7 @@ impl HashStrategy {
     pub fn allows_package(&self, name: &PackageName, version: &Version) -> bool {
         match self {
             Self::None => true,
-            Self::Generate => true,
+            Self::Generate(_) => true,
             Self::Verify(_) => true,
             Self::Require(hashes) => {
                 hashes.contains_key(&VersionId::from_registry(name.clone(), version.clone()))
@@ -112,12 +125,46 @@ impl HashStrategy {
     pub fn allows_url(&self, url: &Url) -> bool {
         match self {
             Self::None => true,
-            Self::Generate => true,
+            Self::Generate(_) => true,
             Self::Verify(_) => true,
             Self::Require(hashes) => hashes.contains_key(&VersionId::from_url(url)),
         }
     }
 
+    /// Hashes should be generated, but not validated.
+    pub fn generate() -> Self {
+        Self::Generate(Arc::default())
+    }
+
+    /// Validate the given expected hashes for direct URL distributions, e.g., as declared via
+    /// `hash` on a URL source in `tool.uv.sources`, in addition to any existing hash policy.
+    ///
+    /// An expected hash replaces any other hashes known for the same URL.
+    #[must_use]
+    pub fn with_url_hashes<'a>(
+        self,
+        expected: impl IntoIterator<Item = (&'a Url, &'a HashDigest)>,
+    ) -> Self {
+        let mut expected = expected.into_iter().peekable();
+        if expected.peek().is_none() {
+            return self;
+        }
+
+        let extend = |hashes: &FxHashMap<VersionId, Vec<HashDigest>>| {
+            let mut hashes = hashes.clone();
+            for (url, hash) in expected {
+                hashes.insert(VersionId::from_url(url), vec![hash.clone()]);
+            }
+            Arc::new(hashes)
+        };
+        match self {
+            Self::None => Self::Verify(extend(&FxHashMap::default())),
+            Self::Generate(hashes) => Self::Generate(extend(&hashes)),
+            Self::Verify(hashes) => Self::Verify(extend(&hashes)),
+            Self::Require(hashes) => Self::Require(extend(&hashes)),
+        }
+    }
+
     /// Generate the required hashes from a set of [`UnresolvedRequirement`] entries.
     ///
     /// When the environment is not given, this treats all marker expressions
@@ -316,6 +363,7 @@ impl HashStrategy {
             }
             RequirementSource::Url { url, .. }
             | RequirementSource::Git { url, .. }
+            | RequirementSource::Hg { url, .. }
             | RequirementSource::Path { url, .. }
             | RequirementSource::Directory { url, .. } => Some(VersionId::from_url(url)),
         }
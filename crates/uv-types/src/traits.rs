@@ -5,13 +5,15 @@ use anyhow::Result;
 
 use uv_cache::Cache;
 use uv_configuration::{
-    BuildKind, BuildOptions, BuildOutput, ConfigSettings, LowerBound, SourceStrategy,
+    BuildContainers, BuildKind, BuildOptions, BuildOutput, ConfigSettings, LowerBound,
+    SourceStrategy,
 };
 use uv_distribution_types::{
     CachedDist, DependencyMetadata, IndexCapabilities, IndexLocations, InstalledDist, Resolution,
     SourceDist,
 };
 use uv_git::GitResolver;
+use uv_hg::HgResolver;
 use uv_pep508::PackageName;
 use uv_pypi_types::Requirement;
 use uv_python::PythonEnvironment;
@@ -62,6 +64,9 @@ pub trait BuildContext {
     /// Return a reference to the Git resolver.
     fn git(&self) -> &GitResolver;
 
+    /// Return a reference to the Mercurial resolver.
+    fn hg(&self) -> &HgResolver;
+
     /// Return a reference to the discovered registry capabilities.
     fn capabilities(&self) -> &IndexCapabilities;
 
@@ -77,12 +82,18 @@ pub trait BuildContext {
     /// The [`ConfigSettings`] used to build distributions.
     fn config_settings(&self) -> &ConfigSettings;
 
+    /// The container images in which to build distributions, if any.
+    fn build_containers(&self) -> &BuildContainers;
+
     /// Whether to warn on missing lower bounds.
     fn bounds(&self) -> LowerBound;
 
     /// Whether to incorporate `tool.uv.sources` when resolving requirements.
     fn sources(&self) -> SourceStrategy;
 
+    /// The variants to use when selecting among the sources in `tool.uv.sources`.
+    fn variants(&self) -> &[String];
+
     /// The index locations being searched.
     fn locations(&self) -> &IndexLocations;
 
@@ -4,11 +4,12 @@ use std::str::FromStr;
 use thiserror::Error;
 use tracing::warn;
 
-use uv_normalize::GroupName;
+use uv_normalize::{GroupName, PackageName};
 use uv_pep508::Pep508Error;
 use uv_pypi_types::VerbatimParsedUrl;
 
-use crate::pyproject::DependencyGroupSpecifier;
+use crate::pyproject::{DependencyGroupSettings, DependencyGroupSpecifier, IncludeGroupSource};
+use crate::{Workspace, WorkspaceMember};
 
 /// PEP 735 dependency groups, with any `include-group` entries resolved.
 #[derive(Debug, Clone)]
@@ -19,21 +20,93 @@ pub struct FlatDependencyGroups(
 impl FlatDependencyGroups {
     /// Resolve the dependency groups (which may contain references to other groups) into concrete
     /// lists of requirements.
+    ///
+    /// If a group declares settings in `tool.uv.dependency-groups`, its requirements are
+    /// restricted to the environments on which the group should be included.
+    ///
+    /// An `include-group` entry with a `from` key refers to a group defined by another member of
+    /// the given workspace, while `from-workspace-root` refers to a group defined in the
+    /// workspace root (which may be virtual).
     pub fn from_dependency_groups(
         groups: &BTreeMap<&GroupName, &Vec<DependencyGroupSpecifier>>,
+        settings: Option<&BTreeMap<GroupName, DependencyGroupSettings>>,
+        workspace: &Workspace,
     ) -> Result<Self, DependencyGroupError> {
         fn resolve_group<'data>(
-            resolved: &mut BTreeMap<GroupName, Vec<uv_pep508::Requirement<VerbatimParsedUrl>>>,
+            resolved: &mut BTreeMap<
+                GroupKey<'data>,
+                Vec<uv_pep508::Requirement<VerbatimParsedUrl>>,
+            >,
             groups: &'data BTreeMap<&GroupName, &Vec<DependencyGroupSpecifier>>,
-            name: &'data GroupName,
-            parents: &mut Vec<&'data GroupName>,
+            settings: Option<&'data BTreeMap<GroupName, DependencyGroupSettings>>,
+            workspace: &'data Workspace,
+            key: GroupKey<'data>,
+            parents: &mut Vec<GroupKey<'data>>,
         ) -> Result<(), DependencyGroupError> {
-            let Some(specifiers) = groups.get(name) else {
+            let (source, name) = key;
+
+            // Find the group, either in the current project, in another workspace member, or in
+            // the workspace root.
+            let (specifiers, settings) = match source {
+                None => (groups.get(name).copied(), settings),
+                Some(source) => {
+                    let parent_name = parents
+                        .iter()
+                        .last()
+                        .map(|(_, name)| *name)
+                        .expect("parent when group is included from another project");
+                    let pyproject_toml = match source {
+                        IncludeGroupSource::Member(member) => {
+                            let Some(pyproject_toml) = workspace
+                                .packages()
+                                .get(member)
+                                .map(WorkspaceMember::pyproject_toml)
+                            else {
+                                return Err(DependencyGroupError::MemberNotFound(
+                                    member.clone(),
+                                    parent_name.clone(),
+                                ));
+                            };
+                            pyproject_toml
+                        }
+                        IncludeGroupSource::WorkspaceRoot => workspace.pyproject_toml(),
+                    };
+                    let Some(specifiers) = pyproject_toml
+                        .dependency_groups
+                        .as_ref()
+                        .and_then(|groups| groups.get(name))
+                    else {
+                        return Err(match source {
+                            IncludeGroupSource::Member(member) => {
+                                DependencyGroupError::MemberGroupNotFound(
+                                    name.clone(),
+                                    member.clone(),
+                                    parent_name.clone(),
+                                )
+                            }
+                            IncludeGroupSource::WorkspaceRoot => {
+                                DependencyGroupError::RootGroupNotFound(
+                                    name.clone(),
+                                    parent_name.clone(),
+                                )
+                            }
+                        });
+                    };
+                    let settings = pyproject_toml
+                        .tool
+                        .as_ref()
+                        .and_then(|tool| tool.uv.as_ref())
+                        .and_then(|uv| uv.dependency_groups.as_ref());
+                    (Some(specifiers), settings)
+                }
+            };
+
+            let Some(specifiers) = specifiers else {
                 // Missing group
                 let parent_name = parents
                     .iter()
                     .last()
-                    .copied()
+                    .map(|(_, name)| *name)
                     .expect("parent when group is missing");
                 return Err(DependencyGroupError::GroupNotFound(
                     name.clone(),
@@ -42,20 +115,23 @@ impl FlatDependencyGroups {
             };
 
             // "Dependency Group Includes MUST NOT include cycles, and tools SHOULD report an error if they detect a cycle."
-            if parents.contains(&name) {
+            if let Some(position) = parents.iter().position(|parent| *parent == key) {
                 return Err(DependencyGroupError::DependencyGroupCycle(Cycle(
-                    parents.iter().copied().cloned().collect(),
+                    parents[position..]
+                        .iter()
+                        .map(|(source, name)| (source.cloned(), (*name).clone()))
+                        .collect(),
                 )));
             }
 
             // If we already resolved this group, short-circuit.
-            if resolved.contains_key(name) {
+            if resolved.contains_key(&key) {
                 return Ok(());
             }
 
-            parents.push(name);
+            parents.push(key);
             let mut requirements = Vec::with_capacity(specifiers.len());
-            for specifier in *specifiers {
+            for specifier in specifiers {
                 match specifier {
                     DependencyGroupSpecifier::Requirement(requirement) => {
                         match uv_pep508::Requirement::<VerbatimParsedUrl>::from_str(requirement) {
@@ -69,10 +145,14 @@ impl FlatDependencyGroups {
                             }
                         }
                     }
-                    DependencyGroupSpecifier::IncludeGroup { include_group } => {
-                        resolve_group(resolved, groups, include_group, parents)?;
-                        requirements
-                            .extend(resolved.get(include_group).into_iter().flatten().cloned());
+                    DependencyGroupSpecifier::IncludeGroup {
+                        include_group,
+                        from,
+                    } => {
+                        // Without a `from`, the group is defined alongside the including group.
+                        let include = (from.as_ref().or(source), include_group);
+                        resolve_group(resolved, groups, settings, workspace, include, parents)?;
+                        requirements.extend(resolved.get(&include).into_iter().flatten().cloned());
                     }
                     DependencyGroupSpecifier::Object(map) => {
                         warn!(
@@ -83,16 +163,42 @@ impl FlatDependencyGroups {
             }
             parents.pop();
 
-            resolved.insert(name.clone(), requirements);
+            // Restrict the group's requirements to the environments on which it's included.
+            if let Some(marker) = settings
+                .and_then(|settings| settings.get(name))
+                .map(DependencyGroupSettings::marker)
+                .filter(|marker| !marker.is_true())
+            {
+                for requirement in &mut requirements {
+                    requirement.marker.and(marker.clone());
+                }
+            }
+
+            resolved.insert(key, requirements);
             Ok(())
         }
 
         let mut resolved = BTreeMap::new();
         for name in groups.keys() {
             let mut parents = Vec::new();
-            resolve_group(&mut resolved, groups, name, &mut parents)?;
+            resolve_group(
+                &mut resolved,
+                groups,
+                settings,
+                workspace,
+                (None, *name),
+                &mut parents,
+            )?;
         }
-        Ok(Self(resolved))
+
+        // Retain the groups defined by the current project.
+        Ok(Self(
+            resolved
+                .into_iter()
+                .filter(|((source, _), _)| source.is_none())
+                .map(|((_, name), requirements)| (name.clone(), requirements))
+                .collect(),
+        ))
     }
 
     /// Return the requirements for a given group, if any.
@@ -126,25 +232,52 @@ pub enum DependencyGroupError {
     ),
     #[error("Failed to find group `{0}` included by `{1}`")]
     GroupNotFound(GroupName, GroupName),
+    #[error("Failed to find workspace member `{0}` referenced by group `{1}`")]
+    MemberNotFound(PackageName, GroupName),
+    #[error("Failed to find group `{0}` in workspace member `{1}`, included by `{2}`")]
+    MemberGroupNotFound(GroupName, PackageName, GroupName),
+    #[error("Failed to find group `{0}` in the workspace root, included by `{1}`")]
+    RootGroupNotFound(GroupName, GroupName),
     #[error("Detected a cycle in `dependency-groups`: {0}")]
     DependencyGroupCycle(Cycle),
 }
 
+/// A dependency group, along with the workspace member or root that defines it (or `None` for the
+/// current project).
+type GroupKey<'data> = (Option<&'data IncludeGroupSource>, &'data GroupName);
+
 /// A cycle in the `dependency-groups` table.
 #[derive(Debug)]
-pub struct Cycle(Vec<GroupName>);
+pub struct Cycle(Vec<(Option<IncludeGroupSource>, GroupName)>);
 
 /// Display a cycle, e.g., `a -> b -> c -> a`.
 impl std::fmt::Display for Cycle {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fn write_group(
+            f: &mut std::fmt::Formatter<'_>,
+            (source, group): &(Option<IncludeGroupSource>, GroupName),
+        ) -> std::fmt::Result {
+            match source {
+                Some(IncludeGroupSource::Member(member)) => {
+                    write!(f, "`{group}` (from `{member}`)")
+                }
+                Some(IncludeGroupSource::WorkspaceRoot) => {
+                    write!(f, "`{group}` (from the workspace root)")
+                }
+                None => write!(f, "`{group}`"),
+            }
+        }
+
         let [first, rest @ ..] = self.0.as_slice() else {
             return Ok(());
         };
-        write!(f, "`{first}`")?;
+        write_group(f, first)?;
         for group in rest {
-            write!(f, " -> `{group}`")?;
+            write!(f, " -> ")?;
+            write_group(f, group)?;
         }
-        write!(f, " -> `{first}`")?;
+        write!(f, " -> ")?;
+        write_group(f, first)?;
         Ok(())
     }
 }
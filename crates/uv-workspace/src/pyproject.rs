@@ -20,11 +20,12 @@ use url::Url;
 use uv_distribution_types::{Index, IndexName};
 use uv_fs::{relative_to, PortablePathBuf};
 use uv_git::GitReference;
+use uv_hg::HgReference;
 use uv_macros::OptionsMetadata;
 use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::{Version, VersionSpecifiers};
-use uv_pep508::MarkerTree;
-use uv_pypi_types::{RequirementSource, SupportedEnvironments, VerbatimParsedUrl};
+use uv_pep508::{MarkerExpression, MarkerTree, MarkerValueVersion};
+use uv_pypi_types::{HashDigest, RequirementSource, SupportedEnvironments, VerbatimParsedUrl};
 
 #[derive(Error, Debug)]
 pub enum PyprojectTomlError {
@@ -125,11 +126,24 @@ pub enum DependencyGroupSpecifier {
     IncludeGroup {
         /// The name of the group to include.
         include_group: GroupName,
+        /// Where the group is defined, if not in the current project.
+        from: Option<IncludeGroupSource>,
     },
     /// A Dependency Object Specifier.
     Object(BTreeMap<String, String>),
 }
 
+/// The location of a dependency group included from outside the current project.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(test, derive(Serialize))]
+pub enum IncludeGroupSource {
+    /// A group defined by another workspace member (`from = "member"`).
+    Member(PackageName),
+    /// A group defined in the workspace root's `pyproject.toml`, which may be virtual
+    /// (`from-workspace-root = true`).
+    WorkspaceRoot,
+}
+
 impl<'de> Deserialize<'de> for DependencyGroupSpecifier {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -156,8 +170,13 @@ impl<'de> Deserialize<'de> for DependencyGroupSpecifier {
                 M: serde::de::MapAccess<'de>,
             {
                 let mut map_data = BTreeMap::new();
-                while let Some((key, value)) = map.next_entry()? {
-                    map_data.insert(key, value);
+                let mut from_workspace_root = false;
+                while let Some(key) = map.next_key::<String>()? {
+                    if key == "from-workspace-root" {
+                        from_workspace_root = map.next_value()?;
+                    } else {
+                        map_data.insert(key, map.next_value::<String>()?);
+                    }
                 }
 
                 if map_data.is_empty() {
@@ -171,7 +190,30 @@ impl<'de> Deserialize<'de> for DependencyGroupSpecifier {
                     .transpose()
                     .map_err(serde::de::Error::custom)?
                 {
-                    Ok(DependencyGroupSpecifier::IncludeGroup { include_group })
+                    let member = map_data
+                        .get("from")
+                        .map(String::as_str)
+                        .map(PackageName::from_str)
+                        .transpose()
+                        .map_err(serde::de::Error::custom)?;
+                    let from = match (member, from_workspace_root) {
+                        (Some(_), true) => {
+                            return Err(serde::de::Error::custom(
+                                "`from` and `from-workspace-root` are mutually exclusive",
+                            ));
+                        }
+                        (Some(member), false) => Some(IncludeGroupSource::Member(member)),
+                        (None, true) => Some(IncludeGroupSource::WorkspaceRoot),
+                        (None, false) => None,
+                    };
+                    Ok(DependencyGroupSpecifier::IncludeGroup {
+                        include_group,
+                        from,
+                    })
+                } else if from_workspace_root {
+                    Err(serde::de::Error::custom(
+                        "`from-workspace-root` requires `include-group`",
+                    ))
                 } else {
                     Ok(DependencyGroupSpecifier::Object(map_data))
                 }
@@ -225,8 +267,8 @@ pub struct ToolUv {
     /// The sources to use when resolving dependencies.
     ///
     /// `tool.uv.sources` enriches the dependency metadata with additional sources, incorporated
-    /// during development. A dependency source can be a Git repository, a URL, a local path, or an
-    /// alternative registry.
+    /// during development. A dependency source can be a Git or Mercurial repository, a URL, a local
+    /// path, or an alternative registry.
     ///
     /// See [Dependencies](../concepts/dependencies.md) for more.
     #[option(
@@ -279,10 +321,113 @@ pub struct ToolUv {
     )]
     pub index: Option<Vec<Index>>,
 
+    /// Whether to refuse to install packages from an index other than the one the project's
+    /// configuration selects for them.
+    ///
+    /// The lockfile records the index against which each package was locked. When enabled,
+    /// `uv sync` (and any command that syncs the environment, like `uv run`) will fail if a
+    /// package was locked against an index that is not among the configured indexes, if it was
+    /// locked against an `explicit` index without being pinned to it, or if it's pinned to an
+    /// index via `tool.uv.sources` but was locked against a different index.
+    ///
+    /// This guards against dependency confusion attacks, in which a lockfile is modified to
+    /// fetch an internal package from a public index (or vice versa).
+    ///
+    /// !!! note
+    ///     uv will only read `enforce-index-provenance` from the `pyproject.toml` at the
+    ///     workspace root, and will ignore any declarations in other workspace members.
+    #[option(
+        default = r#"false"#,
+        value_type = "bool",
+        example = r#"
+            enforce-index-provenance = true
+        "#
+    )]
+    pub enforce_index_provenance: Option<bool>,
+
     /// The workspace definition for the project, if any.
     #[option_group]
     pub workspace: Option<ToolUvWorkspace>,
 
+    /// Commands to run at points in the project lifecycle, e.g., after the environment is synced.
+    ///
+    /// Hooks are run from the workspace root, with the project environment activated (i.e., with
+    /// its executables on the `PATH`, and `VIRTUAL_ENV` set). If a hook exits with a non-zero
+    /// status, the remaining hooks are skipped and uv exits with an error. Output from hooks is
+    /// written to stderr.
+    ///
+    /// Hooks can be disabled with `--no-hooks` (or `UV_NO_HOOKS`), e.g., in CI.
+    ///
+    /// !!! note
+    ///     uv will only read `hooks` from the `pyproject.toml` at the workspace root, and will
+    ///     ignore any declarations in other workspace members.
+    #[option_group]
+    pub hooks: Option<ToolUvHooks>,
+
+    /// The settings of the uv build backend, used when the project declares `uv` as its
+    /// `build-system.build-backend`.
+    ///
+    /// Other build backends (e.g., `hatchling`) are configured in their own sections.
+    #[option_group]
+    pub build_backend: Option<ToolUvBuildBackend>,
+
+    /// Named commands that can be invoked with `uv run <task>`.
+    ///
+    /// A task can be defined as a string, which is run in a shell (`sh` on Unix, `cmd` on
+    /// Windows); as a list of arguments, which is run directly; or as a table with a `cmd` key
+    /// (in either form) alongside any of the following:
+    ///
+    /// - `groups`: Dependency groups to include in the environment when running the task.
+    /// - `extras`: Optional dependencies to include in the environment when running the task.
+    /// - `env`: Environment variables to set when running the task.
+    ///
+    /// Any additional arguments passed to `uv run <task>` are appended to the task's command. For
+    /// a task defined as a string, they're appended to the end of the string, so only the last
+    /// command of a compound command (e.g., `pytest` in `ruff check && pytest`) receives them.
+    ///
+    /// !!! note
+    ///     uv will only read `tasks` from the `pyproject.toml` at the workspace root, and will
+    ///     ignore any declarations in other workspace members.
+    #[option(
+        default = r#"{}"#,
+        value_type = "dict",
+        example = r#"
+            [tool.uv.tasks]
+            lint = "ruff check . && ruff format --check ."
+            test = { cmd = ["pytest", "-x"], groups = ["test"], env = { PYTHONDEVMODE = "1" } }
+        "#
+    )]
+    pub tasks: Option<BTreeMap<String, ToolUvTask>>,
+
+    /// Command-line tools required by the project, mapped to the versions that should be used.
+    ///
+    /// When `uv run` is invoked with a command that matches a required tool, the tool is
+    /// installed at the requested version into a cached environment layered on top of the
+    /// project environment, rather than into the project environment itself. As such, all
+    /// contributors to the project run the same version of the tool, without it being added to
+    /// the project's dependencies.
+    ///
+    /// If the project environment already contains a version of the tool that satisfies the
+    /// requested version, it will be used directly.
+    ///
+    /// !!! note
+    ///     uv will only read `required-tools` from the `pyproject.toml` at the workspace root,
+    ///     and will ignore any declarations in other workspace members.
+    #[option(
+        default = r#"{}"#,
+        value_type = "dict",
+        example = r#"
+            [tool.uv.required-tools]
+            ruff = "==0.6.9"
+            mypy = ">=1.11"
+        "#
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<BTreeMap<PackageName, String>>")
+    )]
+    pub required_tools: Option<BTreeMap<PackageName, VersionSpecifiers>>,
+
     /// Whether the project is managed by uv. If `false`, uv will ignore the project when
     /// `uv run` is invoked.
     #[option(
@@ -294,6 +439,27 @@ pub struct ToolUv {
     )]
     pub managed: Option<bool>,
 
+    /// Files from which to read environment variables when invoking `uv run`, relative to the
+    /// workspace root.
+    ///
+    /// Files are read in order, such that variables defined in later files override those defined
+    /// in earlier files. Files provided via `--env-file` take precedence over those listed here,
+    /// and variables that are already set in the environment take precedence over both.
+    ///
+    /// Each file should be in the `.env` format, e.g., `KEY=value`, one variable per line.
+    ///
+    /// !!! note
+    ///     uv will only read `env-files` from the `pyproject.toml` at the workspace root, and will
+    ///     ignore any declarations in other workspace members.
+    #[option(
+        default = r#"[]"#,
+        value_type = "list[str]",
+        example = r#"
+            env-files = [".env", ".env.local"]
+        "#
+    )]
+    pub env_files: Option<Vec<PathBuf>>,
+
     /// Whether the project should be considered a Python package, or a non-package ("virtual")
     /// project.
     ///
@@ -323,6 +489,46 @@ pub struct ToolUv {
     )]
     pub default_groups: Option<Vec<GroupName>>,
 
+    /// Additional settings for the project's `dependency-groups`.
+    ///
+    /// A group may declare a `requires-python` specifier or environment `marker`, in which case
+    /// its requirements are only included on matching environments. On other environments, the
+    /// group's requirements are omitted, rather than causing resolution to fail.
+    ///
+    /// If a group includes another group (via `include-group`), the included group's settings
+    /// continue to apply to its requirements.
+    #[option(
+        default = r#"{}"#,
+        value_type = "dict",
+        example = r#"
+            [tool.uv.dependency-groups]
+            profiling = { requires-python = ">=3.12", marker = "platform_python_implementation == 'CPython' and sys_platform == 'linux'" }
+        "#
+    )]
+    pub dependency_groups: Option<BTreeMap<GroupName, DependencyGroupSettings>>,
+
+    /// Sets of extras and dependency groups that are never installed together.
+    ///
+    /// Each entry is a list of extras (`{ extra = "..." }`) and dependency groups
+    /// (`{ group = "..." }`) declared by the project, of which at most one may be enabled at a
+    /// time. `uv sync` and `uv run` will fail if more than one item from the same set is
+    /// requested, and uv will not warn about incompatible requirements between them before
+    /// locking.
+    ///
+    /// All extras and dependency groups are still resolved together, so incompatible
+    /// requirements must be limited to disjoint environments (e.g., with markers) for the lock
+    /// to succeed.
+    #[option(
+        default = r#"[]"#,
+        value_type = "list[list[dict]]",
+        example = r#"
+            conflicts = [
+                [{ extra = "cpu" }, { extra = "gpu" }],
+            ]
+        "#
+    )]
+    pub conflicts: Option<Vec<Vec<ConflictItem>>>,
+
     /// The project's development dependencies.
     ///
     /// Development dependencies will be installed by default in `uv run` and `uv sync`, but will
@@ -532,6 +738,247 @@ pub struct ToolUvWorkspace {
         "#
     )]
     pub exclude: Option<Vec<SerdePattern>>,
+    /// Whether to create a separate virtual environment for each workspace member.
+    ///
+    /// By default, all members share a single environment at the workspace root, and must agree
+    /// on a Python version: the workspace's `requires-python` is the intersection of the
+    /// `requires-python` values of its members.
+    ///
+    /// When enabled, members may declare incompatible `requires-python` values. The lockfile
+    /// covers the union of the members' Python requirements, with each member only included for
+    /// the Python versions it supports, and `uv sync --package` creates the member's environment
+    /// (at `.venv` within the member directory) with an interpreter that satisfies the member's
+    /// own `requires-python`.
+    #[option(
+        default = r#"false"#,
+        value_type = "bool",
+        example = r#"
+            member-environments = true
+        "#
+    )]
+    pub member_environments: Option<bool>,
+}
+
+#[derive(Deserialize, OptionsMetadata, Default, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct ToolUvHooks {
+    /// Commands to run before packages are installed into the project environment, e.g., by `uv sync`,
+    /// `uv run`, `uv add`, or `uv remove`.
+    ///
+    /// Each command is run in a shell (`sh` on Unix, `cmd` on Windows), in the order in which it's
+    /// declared.
+    #[option(
+        default = r#"[]"#,
+        value_type = "list[str]",
+        example = r#"
+            pre-sync = ["python scripts/check.py"]
+        "#
+    )]
+    pub pre_sync: Option<Vec<String>>,
+    /// Commands to run after packages are installed into the project environment, e.g., by `uv sync`,
+    /// `uv run`, `uv add`, or `uv remove`.
+    ///
+    /// Each command is run in a shell (`sh` on Unix, `cmd` on Windows), in the order in which it's
+    /// declared.
+    #[option(
+        default = r#"[]"#,
+        value_type = "list[str]",
+        example = r#"
+            post-sync = ["pre-commit install", "python scripts/codegen.py"]
+        "#
+    )]
+    pub post_sync: Option<Vec<String>>,
+    /// Commands to run after the lockfile is updated, whether by `uv lock`, `uv sync`, `uv run`,
+    /// `uv add`, or `uv remove`.
+    ///
+    /// Each command is run in a shell (`sh` on Unix, `cmd` on Windows), in the order in which it's
+    /// declared. If the lockfile is unchanged, the hooks are not run.
+    #[option(
+        default = r#"[]"#,
+        value_type = "list[str]",
+        example = r#"
+            post-lock = ["uv export --frozen --output-file requirements.txt"]
+        "#
+    )]
+    pub post_lock: Option<Vec<String>>,
+}
+
+#[derive(Deserialize, OptionsMetadata, Default, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct ToolUvBuildBackend {
+    /// Custom build steps, such as code generation or asset compilation, that run before the
+    /// wheel is assembled.
+    ///
+    /// Each hook is a command, run in the project root with the environment of the build backend
+    /// (i.e., `python` refers to the interpreter of the isolated build environment).
+    #[option(
+        default = r#"[]"#,
+        value_type = "list[dict]",
+        example = r#"
+            hooks = [{ command = ["python", "scripts/generate.py"] }]
+        "#
+    )]
+    pub hooks: Option<Vec<BuildBackendHook>>,
+    /// Glob patterns for additional files to include, relative to the project root.
+    ///
+    /// By default, only the module in `src/<module>` is included in the wheel. Matching files in
+    /// the `src` directory, e.g., another top-level module, are added with their path relative to
+    /// `src`.
+    #[option(
+        default = r#"[]"#,
+        value_type = "list[str]",
+        example = r#"
+            include = ["src/py.typed", "src/_vendor/**"]
+        "#
+    )]
+    pub include: Option<Vec<String>>,
+    /// Glob patterns for files and directories to exclude, relative to the project root.
+    ///
+    /// Exclusions take precedence over both the module and the `include` patterns.
+    #[option(
+        default = r#"[]"#,
+        value_type = "list[str]",
+        example = r#"
+            exclude = ["**/__pycache__", "**/*.pyc"]
+        "#
+    )]
+    pub exclude: Option<Vec<String>>,
+    /// Directories to install into the wheel's `.data` categories, relative to the project root.
+    ///
+    /// The keys are the categories from the wheel specification, i.e., `purelib`, `platlib`,
+    /// `headers`, `scripts` and `data`. For example, `scripts = "scripts"` installs the files in
+    /// the `scripts` directory as executables, alongside the console and GUI entry points.
+    #[option(
+        default = r#"{}"#,
+        value_type = "dict[str, str]",
+        example = r#"
+            data = { scripts = "scripts", headers = "include" }
+        "#
+    )]
+    pub data: Option<BTreeMap<String, PathBuf>>,
+    /// How editable installs expose the module.
+    ///
+    /// With `pth`, the `src` directory is added to `sys.path` with a `.pth` file, which is the
+    /// most compatible mechanism but also exposes any other modules in the `src` directory. With
+    /// `strict`, an import hook is installed that only exposes the module of the project.
+    #[option(
+        default = r#""pth""#,
+        value_type = "str",
+        example = r#"
+            editable-mode = "strict"
+        "#
+    )]
+    pub editable_mode: Option<BuildBackendEditableMode>,
+}
+
+/// A custom build step declared in `tool.uv.build-backend.hooks`.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct BuildBackendHook {
+    /// The program to run, followed by its arguments.
+    pub command: Vec<String>,
+}
+
+/// The mechanism used by editable installs to make the module importable.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum BuildBackendEditableMode {
+    /// Add the `src` directory to `sys.path` with a `.pth` file.
+    Pth,
+    /// Install an import hook that only exposes the module of the project.
+    Strict,
+}
+
+/// A task declared in `tool.uv.tasks`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum ToolUvTask {
+    /// A command, e.g., `"pytest -x"` or `["pytest", "-x"]`.
+    Command(TaskCommand),
+    /// A command, along with the dependency groups, extras, and environment variables with which
+    /// to run it.
+    Detailed(TaskDefinition),
+}
+
+impl ToolUvTask {
+    /// Returns the command to run for the task.
+    pub fn command(&self) -> &TaskCommand {
+        match self {
+            Self::Command(command) => command,
+            Self::Detailed(definition) => &definition.cmd,
+        }
+    }
+
+    /// Returns the dependency groups to include when running the task.
+    pub fn groups(&self) -> &[GroupName] {
+        match self {
+            Self::Command(_) => &[],
+            Self::Detailed(definition) => &definition.groups,
+        }
+    }
+
+    /// Returns the extras to include when running the task.
+    pub fn extras(&self) -> &[ExtraName] {
+        match self {
+            Self::Command(_) => &[],
+            Self::Detailed(definition) => &definition.extras,
+        }
+    }
+
+    /// Returns the environment variables to set when running the task.
+    pub fn env(&self) -> Option<&BTreeMap<String, String>> {
+        match self {
+            Self::Command(_) => None,
+            Self::Detailed(definition) => Some(&definition.env),
+        }
+    }
+}
+
+/// The command to run for a task in `tool.uv.tasks`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum TaskCommand {
+    /// A command to run in a shell, e.g., `"pytest -x"`.
+    Shell(String),
+    /// A command to run directly, as a list of arguments, e.g., `["pytest", "-x"]`.
+    Args(Vec<String>),
+}
+
+impl std::fmt::Display for TaskCommand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Shell(command) => write!(f, "{command}"),
+            Self::Args(args) => write!(f, "{}", args.join(" ")),
+        }
+    }
+}
+
+/// A task in `tool.uv.tasks`, defined as a table.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct TaskDefinition {
+    /// The command to run.
+    pub cmd: TaskCommand,
+    /// The dependency groups to include in the environment when running the task.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub groups: Vec<GroupName>,
+    /// The extras to include in the environment when running the task.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extras: Vec<ExtraName>,
+    /// The environment variables to set when running the task.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
 }
 
 /// (De)serialize globs as strings.
@@ -635,6 +1082,65 @@ impl<'de> serde::de::Deserialize<'de> for DependencyGroups {
     }
 }
 
+/// An extra or dependency group declared by a project, as referenced by `tool.uv.conflicts`.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub enum ConflictItem {
+    /// An extra (`project.optional-dependencies`).
+    Extra(ExtraName),
+    /// A dependency group (`dependency-groups`).
+    Group(GroupName),
+}
+
+impl std::fmt::Display for ConflictItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Extra(extra) => write!(f, "extra `{extra}`"),
+            Self::Group(group) => write!(f, "group `{group}`"),
+        }
+    }
+}
+
+/// Settings for a single dependency group (`tool.uv.dependency-groups`).
+#[derive(Deserialize, Default, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct DependencyGroupSettings {
+    /// The Python versions on which the group's requirements should be included.
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    pub requires_python: Option<VersionSpecifiers>,
+    /// The environments on which the group's requirements should be included.
+    #[serde(
+        default,
+        skip_serializing_if = "uv_pep508::marker::ser::is_empty",
+        serialize_with = "uv_pep508::marker::ser::serialize"
+    )]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    pub marker: MarkerTree,
+}
+
+impl DependencyGroupSettings {
+    /// Return the marker under which the group's requirements should be included, combining the
+    /// group's `requires-python` and `marker`.
+    pub fn marker(&self) -> MarkerTree {
+        let mut marker = self.marker.clone();
+        for specifier in self
+            .requires_python
+            .iter()
+            .flat_map(|specifiers| specifiers.iter())
+        {
+            marker.and(MarkerTree::expression(MarkerExpression::Version {
+                key: MarkerValueVersion::PythonFullVersion,
+                specifier: specifier.clone(),
+            }));
+        }
+        marker
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "kebab-case", try_from = "SourcesWire")]
@@ -659,6 +1165,44 @@ impl Sources {
     pub fn len(&self) -> usize {
         self.0.len()
     }
+
+    /// Select the sources that apply to the given active variants.
+    ///
+    /// If the sources declare variants, only the sources for a single variant are retained: the
+    /// first active variant for which a source is declared, or, if none apply, the sources without
+    /// a variant. If every source declares a variant, the first declared variant is the default.
+    #[must_use]
+    pub fn select_variant(self, variants: &[String]) -> Self {
+        if self.0.iter().all(|source| source.variant().is_none()) {
+            return self;
+        }
+
+        let selected = variants
+            .iter()
+            .find(|variant| {
+                self.0
+                    .iter()
+                    .any(|source| source.variant() == Some(variant.as_str()))
+            })
+            .cloned()
+            .or_else(|| {
+                if self.0.iter().any(|source| source.variant().is_none()) {
+                    None
+                } else {
+                    self.0
+                        .first()
+                        .and_then(Source::variant)
+                        .map(ToString::to_string)
+                }
+            });
+
+        Self(
+            self.0
+                .into_iter()
+                .filter(|source| source.variant() == selected.as_deref())
+                .collect(),
+        )
+    }
 }
 
 impl IntoIterator for Sources {
@@ -724,11 +1268,12 @@ impl TryFrom<SourcesWire> for Sources {
         match wire {
             SourcesWire::One(source) => Ok(Self(vec![source])),
             SourcesWire::Many(sources) => {
-                // Ensure that the markers are disjoint.
+                // Ensure that the markers are disjoint, within each variant.
                 for (lhs, rhs) in sources
                     .iter()
-                    .map(Source::marker)
-                    .zip(sources.iter().skip(1).map(Source::marker))
+                    .zip(sources.iter().skip(1))
+                    .filter(|(lhs, rhs)| lhs.variant() == rhs.variant())
+                    .map(|(lhs, rhs)| (lhs.marker(), rhs.marker()))
                 {
                     if !lhs.is_disjoint(&rhs) {
                         let mut hint = lhs.negate();
@@ -782,6 +1327,33 @@ pub enum Source {
         rev: Option<String>,
         tag: Option<String>,
         branch: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        variant: Option<String>,
+        #[serde(
+            skip_serializing_if = "uv_pep508::marker::ser::is_empty",
+            serialize_with = "uv_pep508::marker::ser::serialize",
+            default
+        )]
+        marker: MarkerTree,
+    },
+    /// A remote Mercurial repository, available over HTTPS or SSH.
+    ///
+    /// Example:
+    /// ```toml
+    /// flask = { hg = "https://hg.example.com/flask", rev = "3.0.0" }
+    /// ```
+    Hg {
+        /// The repository URL (without the `hg+` prefix).
+        hg: Url,
+        /// The path to the directory with the `pyproject.toml`, if it's not in the repository
+        /// root.
+        subdirectory: Option<PortablePathBuf>,
+        // Only one of the three may be used; we'll validate this later and emit a custom error.
+        rev: Option<String>,
+        branch: Option<String>,
+        bookmark: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        variant: Option<String>,
         #[serde(
             skip_serializing_if = "uv_pep508::marker::ser::is_empty",
             serialize_with = "uv_pep508::marker::ser::serialize",
@@ -801,6 +1373,18 @@ pub enum Source {
         /// For source distributions, the path to the directory with the `pyproject.toml`, if it's
         /// not in the archive root.
         subdirectory: Option<PortablePathBuf>,
+        /// The expected hash of the distribution, in the format `{algorithm}:{digest}` (e.g.,
+        /// `sha256:...`). If set, the hash is verified when locking and installing.
+        #[serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            serialize_with = "serialize_hash",
+            deserialize_with = "deserialize_hash"
+        )]
+        #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+        hash: Option<HashDigest>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        variant: Option<String>,
         #[serde(
             skip_serializing_if = "uv_pep508::marker::ser::is_empty",
             serialize_with = "uv_pep508::marker::ser::serialize",
@@ -815,6 +1399,8 @@ pub enum Source {
         path: PortablePathBuf,
         /// `false` by default.
         editable: Option<bool>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        variant: Option<String>,
         #[serde(
             skip_serializing_if = "uv_pep508::marker::ser::is_empty",
             serialize_with = "uv_pep508::marker::ser::serialize",
@@ -825,6 +1411,8 @@ pub enum Source {
     /// A dependency pinned to a specific index, e.g., `torch` after setting `torch` to `https://download.pytorch.org/whl/cu118`.
     Registry {
         index: IndexName,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        variant: Option<String>,
         #[serde(
             skip_serializing_if = "uv_pep508::marker::ser::is_empty",
             serialize_with = "uv_pep508::marker::ser::serialize",
@@ -837,6 +1425,8 @@ pub enum Source {
         /// When set to `false`, the package will be fetched from the remote index, rather than
         /// included as a workspace package.
         workspace: bool,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        variant: Option<String>,
         #[serde(
             skip_serializing_if = "uv_pep508::marker::ser::is_empty",
             serialize_with = "uv_pep508::marker::ser::serialize",
@@ -857,15 +1447,20 @@ impl<'de> Deserialize<'de> for Source {
         #[serde(rename_all = "kebab-case", deny_unknown_fields)]
         struct CatchAll {
             git: Option<Url>,
+            hg: Option<Url>,
             subdirectory: Option<PortablePathBuf>,
             rev: Option<String>,
             tag: Option<String>,
             branch: Option<String>,
+            bookmark: Option<String>,
             url: Option<Url>,
+            #[serde(default, deserialize_with = "deserialize_hash")]
+            hash: Option<HashDigest>,
             path: Option<PortablePathBuf>,
             editable: Option<bool>,
             index: Option<IndexName>,
             workspace: Option<bool>,
+            variant: Option<String>,
             #[serde(
                 skip_serializing_if = "uv_pep508::marker::ser::is_empty",
                 serialize_with = "uv_pep508::marker::ser::serialize",
@@ -877,15 +1472,19 @@ impl<'de> Deserialize<'de> for Source {
         // Attempt to deserialize as `CatchAll`.
         let CatchAll {
             git,
+            hg,
             subdirectory,
             rev,
             tag,
             branch,
+            bookmark,
             url,
+            hash,
             path,
             editable,
             index,
             workspace,
+            variant,
             marker,
         } = CatchAll::deserialize(deserializer)?;
 
@@ -911,11 +1510,26 @@ impl<'de> Deserialize<'de> for Source {
                     "cannot specify both `git` and `url`",
                 ));
             }
+            if hash.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `git` and `hash`",
+                ));
+            }
             if editable.is_some() {
                 return Err(serde::de::Error::custom(
                     "cannot specify both `git` and `editable`",
                 ));
             }
+            if hg.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `git` and `hg`",
+                ));
+            }
+            if bookmark.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `git` and `bookmark`",
+                ));
+            }
 
             // At most one of `rev`, `tag`, or `branch` may be set.
             match (rev.as_ref(), tag.as_ref(), branch.as_ref()) {
@@ -943,6 +1557,76 @@ impl<'de> Deserialize<'de> for Source {
                 rev,
                 tag,
                 branch,
+                variant,
+                marker,
+            });
+        }
+
+        // If the `hg` field is set, we're dealing with a Mercurial source.
+        if let Some(hg) = hg {
+            if index.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `hg` and `index`",
+                ));
+            }
+            if workspace.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `hg` and `workspace`",
+                ));
+            }
+            if path.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `hg` and `path`",
+                ));
+            }
+            if url.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `hg` and `url`",
+                ));
+            }
+            if hash.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `hg` and `hash`",
+                ));
+            }
+            if editable.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `hg` and `editable`",
+                ));
+            }
+            if tag.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `hg` and `tag` (use `rev` to select a Mercurial tag)",
+                ));
+            }
+
+            // At most one of `rev`, `branch`, or `bookmark` may be set.
+            match (rev.as_ref(), branch.as_ref(), bookmark.as_ref()) {
+                (None, None, None) => {}
+                (Some(_), None, None) => {}
+                (None, Some(_), None) => {}
+                (None, None, Some(_)) => {}
+                _ => {
+                    return Err(serde::de::Error::custom(
+                        "expected at most one of `rev`, `branch`, or `bookmark`",
+                    ))
+                }
+            };
+
+            // If the user prefixed the URL with `hg+`, strip it.
+            let hg = if let Some(hg) = hg.as_str().strip_prefix("hg+") {
+                Url::parse(hg).map_err(serde::de::Error::custom)?
+            } else {
+                hg
+            };
+
+            return Ok(Self::Hg {
+                hg,
+                subdirectory,
+                rev,
+                branch,
+                bookmark,
+                variant,
                 marker,
             });
         }
@@ -969,6 +1653,11 @@ impl<'de> Deserialize<'de> for Source {
                     "cannot specify both `url` and `git`",
                 ));
             }
+            if hg.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `url` and `hg`",
+                ));
+            }
             if rev.is_some() {
                 return Err(serde::de::Error::custom(
                     "cannot specify both `url` and `rev`",
@@ -984,6 +1673,11 @@ impl<'de> Deserialize<'de> for Source {
                     "cannot specify both `url` and `branch`",
                 ));
             }
+            if bookmark.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `url` and `bookmark`",
+                ));
+            }
             if editable.is_some() {
                 return Err(serde::de::Error::custom(
                     "cannot specify both `url` and `editable`",
@@ -993,6 +1687,8 @@ impl<'de> Deserialize<'de> for Source {
             return Ok(Self::Url {
                 url,
                 subdirectory,
+                hash,
+                variant,
                 marker,
             });
         }
@@ -1014,11 +1710,21 @@ impl<'de> Deserialize<'de> for Source {
                     "cannot specify both `path` and `git`",
                 ));
             }
+            if hg.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `path` and `hg`",
+                ));
+            }
             if url.is_some() {
                 return Err(serde::de::Error::custom(
                     "cannot specify both `path` and `url`",
                 ));
             }
+            if hash.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `path` and `hash`",
+                ));
+            }
             if rev.is_some() {
                 return Err(serde::de::Error::custom(
                     "cannot specify both `path` and `rev`",
@@ -1034,10 +1740,16 @@ impl<'de> Deserialize<'de> for Source {
                     "cannot specify both `path` and `branch`",
                 ));
             }
+            if bookmark.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `path` and `bookmark`",
+                ));
+            }
 
             return Ok(Self::Path {
                 path,
                 editable,
+                variant,
                 marker,
             });
         }
@@ -1054,11 +1766,21 @@ impl<'de> Deserialize<'de> for Source {
                     "cannot specify both `index` and `git`",
                 ));
             }
+            if hg.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `index` and `hg`",
+                ));
+            }
             if url.is_some() {
                 return Err(serde::de::Error::custom(
                     "cannot specify both `index` and `url`",
                 ));
             }
+            if hash.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `index` and `hash`",
+                ));
+            }
             if path.is_some() {
                 return Err(serde::de::Error::custom(
                     "cannot specify both `index` and `path`",
@@ -1079,13 +1801,22 @@ impl<'de> Deserialize<'de> for Source {
                     "cannot specify both `index` and `branch`",
                 ));
             }
+            if bookmark.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `index` and `bookmark`",
+                ));
+            }
             if editable.is_some() {
                 return Err(serde::de::Error::custom(
                     "cannot specify both `index` and `editable`",
                 ));
             }
 
-            return Ok(Self::Registry { index, marker });
+            return Ok(Self::Registry {
+                index,
+                variant,
+                marker,
+            });
         }
 
         // If the `workspace` field is set, we're dealing with a workspace source.
@@ -1100,11 +1831,21 @@ impl<'de> Deserialize<'de> for Source {
                     "cannot specify both `index` and `git`",
                 ));
             }
+            if hg.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `index` and `hg`",
+                ));
+            }
             if url.is_some() {
                 return Err(serde::de::Error::custom(
                     "cannot specify both `index` and `url`",
                 ));
             }
+            if hash.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `index` and `hash`",
+                ));
+            }
             if path.is_some() {
                 return Err(serde::de::Error::custom(
                     "cannot specify both `index` and `path`",
@@ -1125,28 +1866,65 @@ impl<'de> Deserialize<'de> for Source {
                     "cannot specify both `index` and `branch`",
                 ));
             }
+            if bookmark.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `index` and `bookmark`",
+                ));
+            }
             if editable.is_some() {
                 return Err(serde::de::Error::custom(
                     "cannot specify both `index` and `editable`",
                 ));
             }
 
-            return Ok(Self::Workspace { workspace, marker });
+            return Ok(Self::Workspace {
+                workspace,
+                variant,
+                marker,
+            });
         }
 
         // If none of the fields are set, we're dealing with an error.
         Err(serde::de::Error::custom(
-            "expected one of `git`, `url`, `path`, `index`, or `workspace`",
+            "expected one of `git`, `hg`, `url`, `path`, `index`, or `workspace`",
         ))
     }
 }
 
+/// Serialize a [`HashDigest`] as a single `{algorithm}:{digest}` string.
+fn serialize_hash<S>(hash: &Option<HashDigest>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    match hash {
+        Some(hash) => serializer.serialize_str(&hash.to_string()),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Deserialize a [`HashDigest`] from a single `{algorithm}:{digest}` string.
+fn deserialize_hash<'de, D>(deserializer: D) -> Result<Option<HashDigest>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let Some(hash) = Option::<String>::deserialize(deserializer)? else {
+        return Ok(None);
+    };
+    HashDigest::from_str(&hash)
+        .map(Some)
+        .map_err(serde::de::Error::custom)
+}
+
 #[derive(Error, Debug)]
 pub enum SourceError {
     #[error("Failed to resolve Git reference: `{0}`")]
     UnresolvedReference(String),
     #[error("Workspace dependency `{0}` must refer to local directory, not a Git repository")]
     WorkspacePackageGit(String),
+    #[error(
+        "Workspace dependency `{0}` must refer to local directory, not a Mercurial repository"
+    )]
+    WorkspacePackageHg(String),
     #[error("Workspace dependency `{0}` must refer to local directory, not a URL")]
     WorkspacePackageUrl(String),
     #[error("Workspace dependency `{0}` must refer to local directory, not a file")]
@@ -1157,6 +1935,8 @@ pub enum SourceError {
     UnusedTag(String, String),
     #[error("`{0}` did not resolve to a Git repository, but a Git reference (`--branch {1}`) was provided.")]
     UnusedBranch(String, String),
+    #[error("`{0}` did not resolve to a Mercurial repository, but a Mercurial bookmark (`--bookmark {1}`) was provided.")]
+    UnusedBookmark(String, String),
     #[error("Failed to resolve absolute path")]
     Absolute(#[from] std::io::Error),
     #[error("Path contains invalid characters: `{}`", _0.display())]
@@ -1177,18 +1957,35 @@ impl Source {
         rev: Option<String>,
         tag: Option<String>,
         branch: Option<String>,
+        bookmark: Option<String>,
         root: &Path,
     ) -> Result<Option<Source>, SourceError> {
         // If we resolved to a non-Git source, and the user specified a Git reference, error.
-        if !matches!(source, RequirementSource::Git { .. }) {
-            if let Some(rev) = rev {
-                return Err(SourceError::UnusedRev(name.to_string(), rev));
+        // Mercurial sources accept a revision, a branch, or a bookmark, but not a tag.
+        match source {
+            RequirementSource::Git { .. } => {
+                if let Some(bookmark) = bookmark {
+                    return Err(SourceError::UnusedBookmark(name.to_string(), bookmark));
+                }
             }
-            if let Some(tag) = tag {
-                return Err(SourceError::UnusedTag(name.to_string(), tag));
+            RequirementSource::Hg { .. } => {
+                if let Some(tag) = tag {
+                    return Err(SourceError::UnusedTag(name.to_string(), tag));
+                }
             }
-            if let Some(branch) = branch {
-                return Err(SourceError::UnusedBranch(name.to_string(), branch));
+            _ => {
+                if let Some(bookmark) = bookmark {
+                    return Err(SourceError::UnusedBookmark(name.to_string(), bookmark));
+                }
+                if let Some(rev) = rev {
+                    return Err(SourceError::UnusedRev(name.to_string(), rev));
+                }
+                if let Some(tag) = tag {
+                    return Err(SourceError::UnusedTag(name.to_string(), tag));
+                }
+                if let Some(branch) = branch {
+                    return Err(SourceError::UnusedBranch(name.to_string(), branch));
+                }
             }
         }
 
@@ -1198,6 +1995,7 @@ impl Source {
                 RequirementSource::Registry { .. } | RequirementSource::Directory { .. } => {
                     Ok(Some(Source::Workspace {
                         workspace: true,
+                        variant: None,
                         marker: MarkerTree::TRUE,
                     }))
                 }
@@ -1207,6 +2005,9 @@ impl Source {
                 RequirementSource::Git { .. } => {
                     Err(SourceError::WorkspacePackageGit(name.to_string()))
                 }
+                RequirementSource::Hg { .. } => {
+                    Err(SourceError::WorkspacePackageHg(name.to_string()))
+                }
                 RequirementSource::Path { .. } => {
                     Err(SourceError::WorkspacePackageFile(name.to_string()))
                 }
@@ -1221,6 +2022,7 @@ impl Source {
                 if let Some(index) = index {
                     Source::Registry {
                         index,
+                        variant: None,
                         marker: MarkerTree::TRUE,
                     }
                 } else {
@@ -1235,6 +2037,7 @@ impl Source {
                         .or_else(|_| std::path::absolute(&install_path))
                         .map_err(SourceError::Absolute)?,
                 ),
+                variant: None,
                 marker: MarkerTree::TRUE,
             },
             RequirementSource::Url {
@@ -1242,6 +2045,8 @@ impl Source {
             } => Source::Url {
                 url: url.to_url(),
                 subdirectory: subdirectory.map(PortablePathBuf::from),
+                hash: None,
+                variant: None,
                 marker: MarkerTree::TRUE,
             },
             RequirementSource::Git {
@@ -1267,6 +2072,7 @@ impl Source {
                         branch,
                         git: repository,
                         subdirectory: subdirectory.map(PortablePathBuf::from),
+                        variant: None,
                         marker: MarkerTree::TRUE,
                     }
                 } else {
@@ -1276,10 +2082,40 @@ impl Source {
                         branch,
                         git: repository,
                         subdirectory: subdirectory.map(PortablePathBuf::from),
+                        variant: None,
                         marker: MarkerTree::TRUE,
                     }
                 }
             }
+            RequirementSource::Hg {
+                repository,
+                reference,
+                subdirectory,
+                ..
+            } => {
+                let (rev, branch, bookmark) =
+                    if rev.is_none() && branch.is_none() && bookmark.is_none() {
+                        match reference {
+                            HgReference::Branch(branch) => (None, Some(branch), None),
+                            HgReference::Bookmark(bookmark) => (None, None, Some(bookmark)),
+                            HgReference::Revision(rev) | HgReference::FullNode(rev) => {
+                                (Some(rev), None, None)
+                            }
+                            HgReference::DefaultBranch => (None, None, None),
+                        }
+                    } else {
+                        (rev, branch, bookmark)
+                    };
+                Source::Hg {
+                    rev,
+                    branch,
+                    bookmark,
+                    hg: repository,
+                    subdirectory: subdirectory.map(PortablePathBuf::from),
+                    variant: None,
+                    marker: MarkerTree::TRUE,
+                }
+            }
         };
 
         Ok(Some(source))
@@ -1289,12 +2125,28 @@ impl Source {
     pub fn marker(&self) -> MarkerTree {
         match self {
             Source::Git { marker, .. } => marker.clone(),
+            Source::Hg { marker, .. } => marker.clone(),
             Source::Url { marker, .. } => marker.clone(),
             Source::Path { marker, .. } => marker.clone(),
             Source::Registry { marker, .. } => marker.clone(),
             Source::Workspace { marker, .. } => marker.clone(),
         }
     }
+
+    /// Return the variant to which the source applies, if any (e.g., `cu121`).
+    ///
+    /// When a package declares sources for multiple variants, the sources are selected based on
+    /// the `variants` setting (see [`Sources::select_variant`]).
+    pub fn variant(&self) -> Option<&str> {
+        match self {
+            Source::Git { variant, .. } => variant.as_deref(),
+            Source::Hg { variant, .. } => variant.as_deref(),
+            Source::Url { variant, .. } => variant.as_deref(),
+            Source::Path { variant, .. } => variant.as_deref(),
+            Source::Registry { variant, .. } => variant.as_deref(),
+            Source::Workspace { variant, .. } => variant.as_deref(),
+        }
+    }
 }
 
 /// The type of a dependency in a `pyproject.toml`.
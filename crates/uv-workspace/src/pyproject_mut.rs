@@ -5,14 +5,14 @@ use std::{fmt, mem};
 use itertools::Itertools;
 use thiserror::Error;
 use toml_edit::{
-    Array, ArrayOfTables, DocumentMut, Formatted, Item, RawString, Table, TomlError, Value,
+    Array, ArrayOfTables, DocumentMut, Item, RawString, Table, TableLike, TomlError, Value,
 };
 use url::Url;
 
 use uv_cache_key::CanonicalUrl;
 use uv_distribution_types::Index;
 use uv_fs::PortablePath;
-use uv_normalize::GroupName;
+use uv_normalize::{GroupName, DEV_DEPENDENCIES};
 use uv_pep440::{Version, VersionSpecifier, VersionSpecifiers};
 use uv_pep508::{ExtraName, MarkerTree, PackageName, Requirement, VersionOrUrl};
 
@@ -41,6 +41,12 @@ pub enum Error {
     MalformedSources,
     #[error("Workspace in `pyproject.toml` is malformed")]
     MalformedWorkspace,
+    #[error("Indexes in `pyproject.toml` or `uv.toml` are malformed")]
+    MalformedIndexes,
+    #[error("Index `{0}` is still referenced in `tool.uv.sources` by: {}", .1.join(", "))]
+    IndexInUse(String, Vec<String>),
+    #[error("Version in `pyproject.toml` is missing or malformed")]
+    MalformedVersion,
     #[error("Expected a dependency at index {0}")]
     MissingDependency(usize),
     #[error("Cannot perform ambiguous update; found multiple entries with matching package names")]
@@ -78,6 +84,16 @@ impl ArrayEdit {
     }
 }
 
+/// The position at which to add an index, relative to the existing indexes.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum IndexPosition {
+    /// Add the index before any existing indexes, i.e., with the highest priority.
+    #[default]
+    First,
+    /// Add the index after any existing indexes, i.e., with the lowest priority.
+    Last,
+}
+
 /// Specifies whether dependencies are added to a script file or a `pyproject.toml` file.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum DependencyTarget {
@@ -219,7 +235,7 @@ impl PyProjectTomlMut {
 
     /// Add an [`Index`] to `tool.uv.index`.
     pub fn add_index(&mut self, index: &Index) -> Result<(), Error> {
-        let existing = self
+        let uv = self
             .doc
             .entry("tool")
             .or_insert(implicit())
@@ -228,162 +244,8 @@ impl PyProjectTomlMut {
             .entry("uv")
             .or_insert(implicit())
             .as_table_mut()
-            .ok_or(Error::MalformedSources)?
-            .entry("index")
-            .or_insert(Item::ArrayOfTables(ArrayOfTables::new()))
-            .as_array_of_tables_mut()
             .ok_or(Error::MalformedSources)?;
-
-        // If there's already an index with the same name or URL, update it (and move it to the top).
-        let mut table = existing
-            .iter()
-            .find(|table| {
-                // If the index has the same name, reuse it.
-                if let Some(index) = index.name.as_deref() {
-                    if table
-                        .get("name")
-                        .and_then(|name| name.as_str())
-                        .is_some_and(|name| name == index)
-                    {
-                        return true;
-                    }
-                }
-
-                // If the index is the default, and there's another default index, reuse it.
-                if index.default
-                    && table
-                        .get("default")
-                        .is_some_and(|default| default.as_bool() == Some(true))
-                {
-                    return true;
-                }
-
-                // If there's another index with the same URL, reuse it.
-                if table
-                    .get("url")
-                    .and_then(|item| item.as_str())
-                    .and_then(|url| Url::parse(url).ok())
-                    .is_some_and(|url| {
-                        CanonicalUrl::new(&url) == CanonicalUrl::new(index.url.url())
-                    })
-                {
-                    return true;
-                }
-
-                false
-            })
-            .cloned()
-            .unwrap_or_default();
-
-        // If necessary, update the name.
-        if let Some(index) = index.name.as_deref() {
-            if !table
-                .get("name")
-                .and_then(|name| name.as_str())
-                .is_some_and(|name| name == index)
-            {
-                let mut formatted = Formatted::new(index.to_string());
-                if let Some(value) = table.get("name").and_then(Item::as_value) {
-                    if let Some(prefix) = value.decor().prefix() {
-                        formatted.decor_mut().set_prefix(prefix.clone());
-                    }
-                    if let Some(suffix) = value.decor().suffix() {
-                        formatted.decor_mut().set_suffix(suffix.clone());
-                    }
-                }
-                table.insert("name", Value::String(formatted).into());
-            }
-        }
-
-        // If necessary, update the URL.
-        if !table
-            .get("url")
-            .and_then(|item| item.as_str())
-            .and_then(|url| Url::parse(url).ok())
-            .is_some_and(|url| CanonicalUrl::new(&url) == CanonicalUrl::new(index.url.url()))
-        {
-            let mut formatted = Formatted::new(index.url.to_string());
-            if let Some(value) = table.get("url").and_then(Item::as_value) {
-                if let Some(prefix) = value.decor().prefix() {
-                    formatted.decor_mut().set_prefix(prefix.clone());
-                }
-                if let Some(suffix) = value.decor().suffix() {
-                    formatted.decor_mut().set_suffix(suffix.clone());
-                }
-            }
-            table.insert("url", Value::String(formatted).into());
-        }
-
-        // If necessary, update the default.
-        if index.default {
-            if !table
-                .get("default")
-                .and_then(toml_edit::Item::as_bool)
-                .is_some_and(|default| default)
-            {
-                let mut formatted = Formatted::new(true);
-                if let Some(value) = table.get("default").and_then(Item::as_value) {
-                    if let Some(prefix) = value.decor().prefix() {
-                        formatted.decor_mut().set_prefix(prefix.clone());
-                    }
-                    if let Some(suffix) = value.decor().suffix() {
-                        formatted.decor_mut().set_suffix(suffix.clone());
-                    }
-                }
-                table.insert("default", Value::Boolean(formatted).into());
-            }
-        }
-
-        // Remove any replaced tables.
-        existing.retain(|table| {
-            // If the index has the same name, skip it.
-            if let Some(index) = index.name.as_deref() {
-                if table
-                    .get("name")
-                    .and_then(|name| name.as_str())
-                    .is_some_and(|name| name == index)
-                {
-                    return false;
-                }
-            }
-
-            // If there's another default index, skip it.
-            if index.default
-                && table
-                    .get("default")
-                    .is_some_and(|default| default.as_bool() == Some(true))
-            {
-                return false;
-            }
-
-            // If there's another index with the same URL, skip it.
-            if table
-                .get("url")
-                .and_then(|item| item.as_str())
-                .and_then(|url| Url::parse(url).ok())
-                .is_some_and(|url| CanonicalUrl::new(&url) == CanonicalUrl::new(index.url.url()))
-            {
-                return false;
-            }
-
-            true
-        });
-
-        // Set the position to the minimum, if it's not already the first element.
-        if let Some(min) = existing.iter().filter_map(toml_edit::Table::position).min() {
-            table.set_position(min);
-
-            // Increment the position of all existing elements.
-            for table in existing.iter_mut() {
-                if let Some(position) = table.position() {
-                    table.set_position(position + 1);
-                }
-            }
-        }
-
-        // Push the item to the table.
-        existing.push(table);
-
+        add_index(uv, index, IndexPosition::First)?;
         Ok(())
     }
 
@@ -741,6 +603,113 @@ impl PyProjectTomlMut {
         Ok(requirements)
     }
 
+    /// Move all occurrences of dependencies with the given name from one [`DependencyType`] to
+    /// another, preserving their specifiers, markers, and extras.
+    ///
+    /// The dependency is added to the destination before it's removed from the origin, such that
+    /// any matching entry in `tool.uv.sources` is retained.
+    ///
+    /// Returns the moved requirements, which are empty if the dependency was not found.
+    pub fn move_dependency(
+        &mut self,
+        name: &PackageName,
+        from: &DependencyType,
+        to: &DependencyType,
+    ) -> Result<Vec<Requirement>, Error> {
+        let requirements = self.find_requirements(name, from);
+        if requirements.is_empty() {
+            return Ok(requirements);
+        }
+
+        // Add the requirements to the destination.
+        for requirement in &requirements {
+            match to {
+                DependencyType::Production => {
+                    self.add_dependency(requirement, None)?;
+                }
+                DependencyType::Dev => {
+                    self.add_dev_dependency(requirement, None)?;
+                }
+                DependencyType::Optional(extra) => {
+                    self.add_optional_dependency(extra, requirement, None)?;
+                }
+                DependencyType::Group(group) => {
+                    self.add_dependency_group_requirement(group, requirement, None)?;
+                }
+            }
+        }
+
+        // Remove the requirements from the origin.
+        match from {
+            DependencyType::Production => {
+                self.remove_dependency(name)?;
+            }
+            DependencyType::Optional(extra) => {
+                self.remove_optional_dependency(name, extra)?;
+            }
+            DependencyType::Group(group) if *group != *DEV_DEPENDENCIES => {
+                self.remove_dependency_group_requirement(name, group)?;
+            }
+            DependencyType::Dev | DependencyType::Group(_) => {
+                self.remove_dev_dependency(name)?;
+                self.remove_dependency_group_requirement(name, &DEV_DEPENDENCIES)?;
+            }
+        }
+
+        Ok(requirements)
+    }
+
+    /// Returns all dependencies with the given name in the given [`DependencyType`].
+    ///
+    /// Development dependencies are read from both `tool.uv.dev-dependencies` and
+    /// `dependency-groups.dev`.
+    fn find_requirements(
+        &self,
+        name: &PackageName,
+        dependency_type: &DependencyType,
+    ) -> Vec<Requirement> {
+        let project = match self.target {
+            DependencyTarget::Script => Some(self.doc.as_table()),
+            DependencyTarget::PyProjectToml => self.doc.get("project").and_then(Item::as_table),
+        };
+        let dependency_groups = self.doc.get("dependency-groups").and_then(Item::as_table);
+        let dev_dependencies = self
+            .doc
+            .get("tool")
+            .and_then(Item::as_table)
+            .and_then(|tool| tool.get("uv"))
+            .and_then(Item::as_table)
+            .and_then(|uv| uv.get("dev-dependencies"))
+            .and_then(Item::as_array);
+
+        let arrays = match dependency_type {
+            DependencyType::Production => vec![project
+                .and_then(|project| project.get("dependencies"))
+                .and_then(Item::as_array)],
+            DependencyType::Optional(extra) => vec![project
+                .and_then(|project| project.get("optional-dependencies"))
+                .and_then(Item::as_table)
+                .and_then(|extras| extras.get(extra.as_ref()))
+                .and_then(Item::as_array)],
+            DependencyType::Group(group) if *group != *DEV_DEPENDENCIES => vec![dependency_groups
+                .and_then(|groups| groups.get(group.as_ref()))
+                .and_then(Item::as_array)],
+            DependencyType::Dev | DependencyType::Group(_) => vec![
+                dev_dependencies,
+                dependency_groups
+                    .and_then(|groups| groups.get(DEV_DEPENDENCIES.as_ref()))
+                    .and_then(Item::as_array),
+            ],
+        };
+
+        arrays
+            .into_iter()
+            .flatten()
+            .flat_map(|deps| find_dependencies(name, None, deps))
+            .map(|(_, requirement)| requirement)
+            .collect()
+    }
+
     /// Remove a matching source from `tool.uv.sources`, if it exists.
     fn remove_source(&mut self, name: &PackageName) -> Result<(), Error> {
         // If the dependency is still in use, don't remove the source.
@@ -782,6 +751,93 @@ impl PyProjectTomlMut {
         Ok(())
     }
 
+    /// Set the version in `project.version`.
+    ///
+    /// The version must already be present; dynamic versions can't be updated.
+    pub fn set_version(&mut self, version: &Version) -> Result<(), Error> {
+        let value = self
+            .project_mut()?
+            .and_then(|project| project.get_mut("version"))
+            .and_then(Item::as_value_mut)
+            .filter(|value| value.is_str())
+            .ok_or(Error::MalformedVersion)?;
+
+        let mut updated = Value::from(version.to_string());
+        *updated.decor_mut() = value.decor().clone();
+        *value = updated;
+
+        Ok(())
+    }
+
+    /// Update any dependencies on the given package that reference the `previous` version, such
+    /// that they reference `version` instead (e.g., `foo>=0.1.0` becomes `foo>=0.2.0`).
+    ///
+    /// This method searches `project.dependencies`, `project.optional-dependencies`,
+    /// `dependency-groups`, and `tool.uv.dev-dependencies`.
+    ///
+    /// Returns `true` if any dependency was updated.
+    pub fn update_dependency_version(
+        &mut self,
+        name: &PackageName,
+        previous: &Version,
+        version: &Version,
+    ) -> Result<bool, Error> {
+        let mut updated = false;
+
+        if let Some(project) = self.project_mut()? {
+            // Update `project.dependencies`.
+            if let Some(dependencies) = project.get_mut("dependencies") {
+                let dependencies = dependencies
+                    .as_array_mut()
+                    .ok_or(Error::MalformedDependencies)?;
+                updated |= update_dependency_version(name, previous, version, dependencies);
+            }
+
+            // Update `project.optional-dependencies`.
+            if let Some(extras) = project.get_mut("optional-dependencies") {
+                let extras = extras
+                    .as_table_like_mut()
+                    .ok_or(Error::MalformedDependencies)?;
+                for (_, dependencies) in extras.iter_mut() {
+                    let dependencies = dependencies
+                        .as_array_mut()
+                        .ok_or(Error::MalformedDependencies)?;
+                    updated |= update_dependency_version(name, previous, version, dependencies);
+                }
+            }
+        }
+
+        // Update `dependency-groups`.
+        if let Some(groups) = self.doc.get_mut("dependency-groups") {
+            let groups = groups
+                .as_table_like_mut()
+                .ok_or(Error::MalformedDependencies)?;
+            for (_, dependencies) in groups.iter_mut() {
+                let dependencies = dependencies
+                    .as_array_mut()
+                    .ok_or(Error::MalformedDependencies)?;
+                updated |= update_dependency_version(name, previous, version, dependencies);
+            }
+        }
+
+        // Update `tool.uv.dev-dependencies`.
+        if let Some(dev_dependencies) = self
+            .doc
+            .get_mut("tool")
+            .and_then(Item::as_table_mut)
+            .and_then(|tool| tool.get_mut("uv"))
+            .and_then(Item::as_table_mut)
+            .and_then(|uv| uv.get_mut("dev-dependencies"))
+        {
+            let dev_dependencies = dev_dependencies
+                .as_array_mut()
+                .ok_or(Error::MalformedDependencies)?;
+            updated |= update_dependency_version(name, previous, version, dev_dependencies);
+        }
+
+        Ok(updated)
+    }
+
     /// Returns `true` if the `tool.uv.dev-dependencies` table is present.
     pub fn has_dev_dependencies(&self) -> bool {
         self.doc
@@ -884,6 +940,340 @@ fn implicit() -> Item {
     Item::Table(table)
 }
 
+/// Adds an [`Index`] to the `index` array in the given settings table (i.e., `tool.uv` in a
+/// `pyproject.toml`, or the root table of a `uv.toml`).
+///
+/// If there's already an index with the same name or URL (or, if the index is the default, another
+/// default index), it's updated and moved to the given position.
+///
+/// Returns `true` if the index was added, `false` if it was updated.
+pub fn add_index(
+    settings: &mut Table,
+    index: &Index,
+    position: IndexPosition,
+) -> Result<bool, Error> {
+    let existing = settings
+        .entry("index")
+        .or_insert(Item::ArrayOfTables(ArrayOfTables::new()))
+        .as_array_of_tables_mut()
+        .ok_or(Error::MalformedIndexes)?;
+
+    // If there's already an index with the same name or URL, update it.
+    let replaced = existing.iter().find(|table| {
+        // If the index has the same name, reuse it.
+        if let Some(index) = index.name.as_deref() {
+            if table
+                .get("name")
+                .and_then(|name| name.as_str())
+                .is_some_and(|name| name == index)
+            {
+                return true;
+            }
+        }
+
+        // If the index is the default, and there's another default index, reuse it.
+        if index.default
+            && table
+                .get("default")
+                .is_some_and(|default| default.as_bool() == Some(true))
+        {
+            return true;
+        }
+
+        // If there's another index with the same URL, reuse it.
+        if table
+            .get("url")
+            .and_then(|item| item.as_str())
+            .and_then(|url| Url::parse(url).ok())
+            .is_some_and(|url| CanonicalUrl::new(&url) == CanonicalUrl::new(index.url.url()))
+        {
+            return true;
+        }
+
+        false
+    });
+    let added = replaced.is_none();
+    let mut table = replaced.cloned().unwrap_or_default();
+
+    // If necessary, update the name.
+    if let Some(index) = index.name.as_deref() {
+        if !table
+            .get("name")
+            .and_then(|name| name.as_str())
+            .is_some_and(|name| name == index)
+        {
+            set_preserving_decor(&mut table, "name", Value::from(index.to_string()));
+        }
+    }
+
+    // If necessary, update the URL.
+    if !table
+        .get("url")
+        .and_then(|item| item.as_str())
+        .and_then(|url| Url::parse(url).ok())
+        .is_some_and(|url| CanonicalUrl::new(&url) == CanonicalUrl::new(index.url.url()))
+    {
+        set_preserving_decor(&mut table, "url", Value::from(index.url.to_string()));
+    }
+
+    // If necessary, update the default.
+    if index.default
+        && !table
+            .get("default")
+            .and_then(Item::as_bool)
+            .is_some_and(|default| default)
+    {
+        set_preserving_decor(&mut table, "default", Value::from(true));
+    }
+
+    // If necessary, update the explicit flag.
+    if index.explicit
+        && !table
+            .get("explicit")
+            .and_then(Item::as_bool)
+            .is_some_and(|explicit| explicit)
+    {
+        set_preserving_decor(&mut table, "explicit", Value::from(true));
+    }
+
+    // Remove any replaced tables.
+    existing.retain(|table| {
+        // If the index has the same name, skip it.
+        if let Some(index) = index.name.as_deref() {
+            if table
+                .get("name")
+                .and_then(|name| name.as_str())
+                .is_some_and(|name| name == index)
+            {
+                return false;
+            }
+        }
+
+        // If there's another default index, skip it.
+        if index.default
+            && table
+                .get("default")
+                .is_some_and(|default| default.as_bool() == Some(true))
+        {
+            return false;
+        }
+
+        // If there's another index with the same URL, skip it.
+        if table
+            .get("url")
+            .and_then(|item| item.as_str())
+            .and_then(|url| Url::parse(url).ok())
+            .is_some_and(|url| CanonicalUrl::new(&url) == CanonicalUrl::new(index.url.url()))
+        {
+            return false;
+        }
+
+        true
+    });
+
+    match position {
+        IndexPosition::First => {
+            // Set the position to the minimum, if it's not already the first element.
+            if let Some(min) = existing.iter().filter_map(Table::position).min() {
+                table.set_position(min);
+
+                // Increment the position of all existing elements.
+                for table in existing.iter_mut() {
+                    if let Some(position) = table.position() {
+                        table.set_position(position + 1);
+                    }
+                }
+            }
+        }
+        IndexPosition::Last => {
+            // Set the position to that of the last element, such that the table is rendered
+            // immediately after it.
+            if let Some(max) = existing.iter().filter_map(Table::position).max() {
+                table.set_position(max);
+            }
+        }
+    }
+
+    // Push the item to the table.
+    existing.push(table);
+
+    Ok(added)
+}
+
+/// Removes the index with the given name or URL from the `index` array in the given settings
+/// table.
+///
+/// Returns an error if any of the `sources` in the settings table still refer to the index by name,
+/// as the sources would be left dangling.
+///
+/// Returns `true` if the index was removed, `false` if no such index exists.
+pub fn remove_index(settings: &mut Table, index: &str) -> Result<bool, Error> {
+    let Some(existing) = settings.get("index") else {
+        return Ok(false);
+    };
+    let existing = existing
+        .as_array_of_tables()
+        .ok_or(Error::MalformedIndexes)?;
+
+    // Refuse to remove an index that's still used by a source.
+    for name in existing
+        .iter()
+        .filter(|table| is_index(table, index))
+        .filter_map(|table| table.get("name").and_then(Item::as_str))
+    {
+        let packages = sources_using_index(settings, name);
+        if !packages.is_empty() {
+            return Err(Error::IndexInUse(name.to_string(), packages));
+        }
+    }
+
+    let existing = settings
+        .get_mut("index")
+        .and_then(Item::as_array_of_tables_mut)
+        .ok_or(Error::MalformedIndexes)?;
+
+    let len = existing.len();
+    existing.retain(|table| !is_index(table, index));
+    let removed = existing.len() < len;
+
+    // If there are no remaining indexes, remove the array entirely.
+    if existing.is_empty() {
+        settings.remove("index");
+    }
+
+    Ok(removed)
+}
+
+/// Returns the packages in the `sources` of the given settings table that are pinned to the index
+/// with the given name.
+fn sources_using_index(settings: &Table, index: &str) -> Vec<String> {
+    let Some(sources) = settings.get("sources").and_then(Item::as_table_like) else {
+        return Vec::new();
+    };
+    let uses_index = |source: &dyn TableLike| {
+        source
+            .get("index")
+            .and_then(Item::as_str)
+            .is_some_and(|name| name == index)
+    };
+    sources
+        .iter()
+        .filter(|(_, source)| {
+            if let Some(source) = source.as_table_like() {
+                uses_index(source)
+            } else if let Some(sources) = source.as_array() {
+                // A list of sources, each with its own marker.
+                sources
+                    .iter()
+                    .filter_map(toml_edit::Value::as_inline_table)
+                    .any(|source| uses_index(source))
+            } else {
+                false
+            }
+        })
+        .map(|(package, _)| package.to_string())
+        .collect()
+}
+
+/// Marks the index with the given name or URL as explicit (or not) in the given settings table.
+///
+/// Returns `true` if the index was found, `false` if no such index exists.
+pub fn set_explicit_index(
+    settings: &mut Table,
+    index: &str,
+    explicit: bool,
+) -> Result<bool, Error> {
+    let Some(existing) = settings.get_mut("index") else {
+        return Ok(false);
+    };
+    let existing = existing
+        .as_array_of_tables_mut()
+        .ok_or(Error::MalformedIndexes)?;
+
+    let mut found = false;
+    for table in existing.iter_mut().filter(|table| is_index(table, index)) {
+        found = true;
+        if !explicit {
+            table.remove("explicit");
+        } else if !table
+            .get("explicit")
+            .and_then(Item::as_bool)
+            .is_some_and(|explicit| explicit)
+        {
+            set_preserving_decor(table, "explicit", Value::from(true));
+        }
+    }
+
+    Ok(found)
+}
+
+/// Marks the index with the given name or URL as the default index in the given settings table,
+/// removing the `default` flag from any other index.
+///
+/// Returns `true` if the index was found, `false` if no such index exists.
+pub fn set_default_index(settings: &mut Table, index: &str) -> Result<bool, Error> {
+    let Some(existing) = settings.get_mut("index") else {
+        return Ok(false);
+    };
+    let existing = existing
+        .as_array_of_tables_mut()
+        .ok_or(Error::MalformedIndexes)?;
+
+    if !existing.iter().any(|table| is_index(table, index)) {
+        return Ok(false);
+    }
+
+    for table in existing.iter_mut() {
+        if is_index(table, index) {
+            if !table
+                .get("default")
+                .and_then(Item::as_bool)
+                .is_some_and(|default| default)
+            {
+                set_preserving_decor(table, "default", Value::from(true));
+            }
+        } else {
+            table.remove("default");
+        }
+    }
+
+    Ok(true)
+}
+
+/// Returns `true` if the given index table has the given name or URL.
+fn is_index(table: &Table, index: &str) -> bool {
+    if table
+        .get("name")
+        .and_then(|name| name.as_str())
+        .is_some_and(|name| name == index)
+    {
+        return true;
+    }
+
+    let Ok(index) = Url::parse(index) else {
+        return false;
+    };
+    table
+        .get("url")
+        .and_then(|item| item.as_str())
+        .and_then(|url| Url::parse(url).ok())
+        .is_some_and(|url| CanonicalUrl::new(&url) == CanonicalUrl::new(&index))
+}
+
+/// Sets a value in the given table, preserving the decor (i.e., surrounding whitespace and
+/// comments) of any existing value.
+fn set_preserving_decor(table: &mut Table, key: &str, mut value: Value) {
+    if let Some(existing) = table.get(key).and_then(Item::as_value) {
+        if let Some(prefix) = existing.decor().prefix() {
+            value.decor_mut().set_prefix(prefix.clone());
+        }
+        if let Some(suffix) = existing.decor().suffix() {
+            value.decor_mut().set_suffix(suffix.clone());
+        }
+    }
+    table.insert(key, value.into());
+}
+
 /// Adds a dependency to the given `deps` array.
 ///
 /// Returns `true` if the dependency was added, `false` if it was updated.
@@ -1038,6 +1428,48 @@ fn remove_dependency(name: &PackageName, deps: &mut Array) -> Vec<Requirement> {
     removed
 }
 
+/// Replace any references to the `previous` version in the specifiers of dependencies with the
+/// given name in the `deps` array.
+///
+/// Returns `true` if any dependency was updated.
+fn update_dependency_version(
+    name: &PackageName,
+    previous: &Version,
+    version: &Version,
+    deps: &mut Array,
+) -> bool {
+    let mut updated = false;
+    for (i, mut req) in find_dependencies(name, None, deps) {
+        let Some(VersionOrUrl::VersionSpecifier(specifiers)) = req.version_or_url.as_ref() else {
+            continue;
+        };
+        if !specifiers
+            .iter()
+            .any(|specifier| specifier.version() == previous)
+        {
+            continue;
+        }
+
+        let specifiers = specifiers
+            .iter()
+            .map(|specifier| {
+                if specifier.version() == previous {
+                    // If the new version isn't valid for the operator (e.g., `~=` with a single
+                    // release segment), retain the existing specifier.
+                    VersionSpecifier::from_version(*specifier.operator(), version.clone())
+                        .unwrap_or_else(|_| specifier.clone())
+                } else {
+                    specifier.clone()
+                }
+            })
+            .collect::<VersionSpecifiers>();
+        req.version_or_url = Some(VersionOrUrl::VersionSpecifier(specifiers));
+        deps.replace(i, req.to_string());
+        updated = true;
+    }
+    updated
+}
+
 /// Returns a `Vec` containing the all dependencies with the given name, along with their positions
 /// in the array.
 fn find_dependencies(
@@ -2,8 +2,8 @@
 
 use crate::dependency_groups::{DependencyGroupError, FlatDependencyGroups};
 use crate::pyproject::{
-    DependencyGroups, Project, PyProjectToml, PyprojectTomlError, Sources, ToolUvSources,
-    ToolUvWorkspace,
+    DependencyGroups, Project, PyProjectToml, PyprojectTomlError, Source, Sources, ToolUvHooks,
+    ToolUvSources, ToolUvTask, ToolUvWorkspace,
 };
 use either::Either;
 use glob::{glob, GlobError, PatternError};
@@ -11,11 +11,15 @@ use rustc_hash::FxHashSet;
 use std::collections::{BTreeMap, BTreeSet};
 use std::path::{Path, PathBuf};
 use tracing::{debug, trace, warn};
+use url::Url;
 use uv_distribution_types::Index;
 use uv_fs::{Simplified, CWD};
 use uv_normalize::{GroupName, PackageName, DEV_DEPENDENCIES};
-use uv_pep508::{MarkerTree, RequirementOrigin, VerbatimUrl};
-use uv_pypi_types::{Requirement, RequirementSource, SupportedEnvironments, VerbatimParsedUrl};
+use uv_pep440::VersionSpecifiers;
+use uv_pep508::{MarkerExpression, MarkerTree, MarkerValueVersion, RequirementOrigin, VerbatimUrl};
+use uv_pypi_types::{
+    HashDigest, Requirement, RequirementSource, SupportedEnvironments, VerbatimParsedUrl,
+};
 use uv_static::EnvVars;
 use uv_warnings::{warn_user, warn_user_once};
 
@@ -270,15 +274,29 @@ impl Workspace {
     }
 
     /// Returns the set of requirements that include all packages in the workspace.
+    ///
+    /// If members use separate environments, each member is only required on the Python versions
+    /// permitted by its `requires-python`.
     pub fn members_requirements(&self) -> impl Iterator<Item = Requirement> + '_ {
-        self.packages.values().filter_map(|member| {
+        let member_environments = self.member_environments();
+        self.packages.values().filter_map(move |member| {
+            let project = member.pyproject_toml.project.as_ref()?;
             let url = VerbatimUrl::from_absolute_path(&member.root)
                 .expect("path is valid URL")
                 .with_given(member.root.to_string_lossy());
+            let marker = if member_environments {
+                project
+                    .requires_python
+                    .as_ref()
+                    .map(requires_python_marker)
+                    .unwrap_or_default()
+            } else {
+                MarkerTree::TRUE
+            };
             Some(Requirement {
-                name: member.pyproject_toml.project.as_ref()?.name.clone(),
+                name: project.name.clone(),
                 extras: vec![],
-                marker: MarkerTree::TRUE,
+                marker,
                 source: if member.pyproject_toml.is_package() {
                     RequirementSource::Directory {
                         install_path: member.root.clone(),
@@ -334,8 +352,15 @@ impl Workspace {
                 .collect::<BTreeMap<_, _>>();
 
             // Resolve any `include-group` entries in `dependency-groups`.
-            let dependency_groups =
-                FlatDependencyGroups::from_dependency_groups(&dependency_groups)?;
+            let dependency_groups = FlatDependencyGroups::from_dependency_groups(
+                &dependency_groups,
+                self.pyproject_toml
+                    .tool
+                    .as_ref()
+                    .and_then(|tool| tool.uv.as_ref())
+                    .and_then(|uv| uv.dependency_groups.as_ref()),
+                self,
+            )?;
 
             // Concatenate the two sets of requirements.
             let dev_dependencies = dependency_groups
@@ -390,6 +415,60 @@ impl Workspace {
             .and_then(|uv| uv.environments.as_ref())
     }
 
+    /// Returns `true` if packages must be installed from the index selected by the workspace's
+    /// configuration.
+    pub fn enforce_index_provenance(&self) -> bool {
+        self.pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.enforce_index_provenance)
+            .unwrap_or(false)
+    }
+
+    /// Returns the environment files declared by the workspace, resolved relative to the workspace
+    /// root.
+    pub fn env_files(&self) -> Vec<PathBuf> {
+        self.pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.env_files.as_ref())
+            .into_iter()
+            .flatten()
+            .map(|path| self.install_path.join(path))
+            .collect()
+    }
+
+    /// Returns the lifecycle hooks declared by the workspace, if any.
+    pub fn hooks(&self) -> Option<&ToolUvHooks> {
+        self.pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.hooks.as_ref())
+    }
+
+    /// Returns the task with the given name, as declared by the workspace, if any.
+    pub fn task(&self, name: &str) -> Option<&ToolUvTask> {
+        self.pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.tasks.as_ref())
+            .and_then(|tasks| tasks.get(name))
+    }
+
+    /// Returns the version of the given tool required by the workspace, if any.
+    pub fn required_tool(&self, name: &PackageName) -> Option<&VersionSpecifiers> {
+        self.pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.required_tools.as_ref())
+            .and_then(|tools| tools.get(name))
+    }
+
     /// Returns the set of constraints for the workspace.
     pub fn constraints(&self) -> Vec<Requirement> {
         let Some(constraints) = self
@@ -543,6 +622,33 @@ impl Workspace {
         project_env
     }
 
+    /// Returns `true` if each workspace member uses its own virtual environment, as opposed to a
+    /// single environment shared across the workspace.
+    ///
+    /// See `tool.uv.workspace.member-environments`.
+    pub fn member_environments(&self) -> bool {
+        self.pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.workspace.as_ref())
+            .and_then(|workspace| workspace.member_environments)
+            .unwrap_or(false)
+    }
+
+    /// The path to the virtual environment for the given workspace member, if members use
+    /// separate environments.
+    ///
+    /// The workspace root uses the project environment (see [`Workspace::venv`]), while all other
+    /// members use a `.venv` directory within the member.
+    pub fn member_venv(&self, member: &WorkspaceMember) -> PathBuf {
+        if member.root == self.install_path {
+            self.venv()
+        } else {
+            member.root.join(".venv")
+        }
+    }
+
     /// The members of the workspace.
     pub fn packages(&self) -> &BTreeMap<PackageName, WorkspaceMember> {
         &self.packages
@@ -553,6 +659,48 @@ impl Workspace {
         &self.sources
     }
 
+    /// The `tool.uv.sources` tables of all workspace members, followed by that of the workspace
+    /// root.
+    fn all_sources(&self) -> impl Iterator<Item = &BTreeMap<PackageName, Sources>> {
+        self.packages
+            .values()
+            .filter_map(|member| {
+                member
+                    .pyproject_toml
+                    .tool
+                    .as_ref()
+                    .and_then(|tool| tool.uv.as_ref())
+                    .and_then(|uv| uv.sources.as_ref())
+                    .map(ToolUvSources::inner)
+            })
+            .chain(std::iter::once(&self.sources))
+    }
+
+    /// The expected hashes of the URL sources across all workspace members, as declared via
+    /// `hash` in `tool.uv.sources`.
+    pub fn url_source_hashes(&self) -> impl Iterator<Item = (&PackageName, &Url, &HashDigest)> {
+        self.all_sources().flat_map(|sources| {
+            sources.iter().flat_map(|(name, sources)| {
+                sources.iter().filter_map(move |source| match source {
+                    Source::Url {
+                        url,
+                        hash: Some(hash),
+                        ..
+                    } => Some((name, url, hash)),
+                    _ => None,
+                })
+            })
+        })
+    }
+
+    /// The variants declared across all workspace members, via `variant` in `tool.uv.sources`.
+    pub fn source_variants(&self) -> BTreeSet<&str> {
+        self.all_sources()
+            .flat_map(BTreeMap::values)
+            .flat_map(|sources| sources.iter().filter_map(Source::variant))
+            .collect()
+    }
+
     /// The index table from the workspace `pyproject.toml`.
     pub fn indexes(&self) -> &[Index] {
         &self.indexes
@@ -1209,6 +1357,18 @@ async fn find_workspace(
     Ok(None)
 }
 
+/// Convert a `requires-python` specifier into the equivalent marker on `python_full_version`.
+fn requires_python_marker(specifiers: &VersionSpecifiers) -> MarkerTree {
+    let mut marker = MarkerTree::TRUE;
+    for specifier in specifiers.iter() {
+        marker.and(MarkerTree::expression(MarkerExpression::Version {
+            key: MarkerValueVersion::PythonFullVersion,
+            specifier: specifier.clone(),
+        }));
+    }
+    marker
+}
+
 /// Warn when the valid workspace is included in another workspace.
 pub fn check_nested_workspaces(inner_workspace_root: &Path, options: &DiscoveryOptions) {
     for outer_workspace_root in inner_workspace_root
@@ -1563,16 +1723,23 @@ impl<'env> InstallTarget<'env> {
 
                 // Merge any overlapping groups.
                 let mut map = BTreeMap::new();
-                for (name, dependencies) in
-                    FlatDependencyGroups::from_dependency_groups(&dependency_groups)?
+                for (name, dependencies) in FlatDependencyGroups::from_dependency_groups(
+                    &dependency_groups,
+                    workspace
+                        .pyproject_toml()
+                        .tool
+                        .as_ref()
+                        .and_then(|tool| tool.uv.as_ref())
+                        .and_then(|uv| uv.dependency_groups.as_ref()),
+                    workspace,
+                )?
+                .into_iter()
+                .chain(
+                    // Only add the `dev` group if `dev-dependencies` is defined.
+                    dev_dependencies
                         .into_iter()
-                        .chain(
-                            // Only add the `dev` group if `dev-dependencies` is defined.
-                            dev_dependencies.into_iter().map(|requirements| {
-                                (DEV_DEPENDENCIES.clone(), requirements.clone())
-                            }),
-                        )
-                {
+                        .map(|requirements| (DEV_DEPENDENCIES.clone(), requirements.clone())),
+                ) {
                     match map.entry(name) {
                         std::collections::btree_map::Entry::Vacant(entry) => {
                             entry.insert(dependencies);
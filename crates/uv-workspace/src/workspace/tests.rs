@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::env;
 use std::path::Path;
 use std::str::FromStr;
@@ -9,6 +10,7 @@ use insta::assert_json_snapshot;
 
 use uv_normalize::GroupName;
 
+use crate::dependency_groups::FlatDependencyGroups;
 use crate::pyproject::{DependencyGroupSpecifier, PyProjectToml};
 use crate::workspace::{DiscoveryOptions, ProjectWorkspace};
 
@@ -229,15 +231,24 @@ async fn albatross_root_workspace() {
                     ]
                   },
                   "index": null,
+                  "enforce-index-provenance": null,
                   "workspace": {
                     "members": [
                       "packages/*"
                     ],
-                    "exclude": null
+                    "exclude": null,
+                    "member-environments": null
                   },
+                  "hooks": null,
+                  "build-backend": null,
+                  "tasks": null,
+                  "required-tools": null,
                   "managed": null,
+                  "env-files": null,
                   "package": null,
                   "default-groups": null,
+                  "dependency-groups": null,
+                  "conflicts": null,
                   "dev-dependencies": null,
                   "override-dependencies": null,
                   "constraint-dependencies": null,
@@ -320,15 +331,24 @@ async fn albatross_virtual_workspace() {
                 "uv": {
                   "sources": null,
                   "index": null,
+                  "enforce-index-provenance": null,
                   "workspace": {
                     "members": [
                       "packages/*"
                     ],
-                    "exclude": null
+                    "exclude": null,
+                    "member-environments": null
                   },
+                  "hooks": null,
+                  "build-backend": null,
+                  "tasks": null,
+                  "required-tools": null,
                   "managed": null,
+                  "env-files": null,
                   "package": null,
                   "default-groups": null,
+                  "dependency-groups": null,
+                  "conflicts": null,
                   "dev-dependencies": null,
                   "override-dependencies": null,
                   "constraint-dependencies": null,
@@ -523,17 +543,26 @@ async fn exclude_package() -> Result<()> {
                 "uv": {
                   "sources": null,
                   "index": null,
+                  "enforce-index-provenance": null,
                   "workspace": {
                     "members": [
                       "packages/*"
                     ],
                     "exclude": [
                       "packages/bird-feeder"
-                    ]
+                    ],
+                    "member-environments": null
                   },
+                  "hooks": null,
+                  "build-backend": null,
+                  "tasks": null,
+                  "required-tools": null,
                   "managed": null,
+                  "env-files": null,
                   "package": null,
                   "default-groups": null,
+                  "dependency-groups": null,
+                  "conflicts": null,
                   "dev-dependencies": null,
                   "override-dependencies": null,
                   "constraint-dependencies": null,
@@ -625,6 +654,7 @@ async fn exclude_package() -> Result<()> {
                 "uv": {
                   "sources": null,
                   "index": null,
+                  "enforce-index-provenance": null,
                   "workspace": {
                     "members": [
                       "packages/seeds",
@@ -632,11 +662,19 @@ async fn exclude_package() -> Result<()> {
                     ],
                     "exclude": [
                       "packages/bird-feeder"
-                    ]
+                    ],
+                    "member-environments": null
                   },
+                  "hooks": null,
+                  "build-backend": null,
+                  "tasks": null,
+                  "required-tools": null,
                   "managed": null,
+                  "env-files": null,
                   "package": null,
                   "default-groups": null,
+                  "dependency-groups": null,
+                  "conflicts": null,
                   "dev-dependencies": null,
                   "override-dependencies": null,
                   "constraint-dependencies": null,
@@ -741,6 +779,7 @@ async fn exclude_package() -> Result<()> {
                 "uv": {
                   "sources": null,
                   "index": null,
+                  "enforce-index-provenance": null,
                   "workspace": {
                     "members": [
                       "packages/seeds",
@@ -748,11 +787,19 @@ async fn exclude_package() -> Result<()> {
                     ],
                     "exclude": [
                       "packages"
-                    ]
+                    ],
+                    "member-environments": null
                   },
+                  "hooks": null,
+                  "build-backend": null,
+                  "tasks": null,
+                  "required-tools": null,
                   "managed": null,
+                  "env-files": null,
                   "package": null,
                   "default-groups": null,
+                  "dependency-groups": null,
+                  "conflicts": null,
                   "dev-dependencies": null,
                   "override-dependencies": null,
                   "constraint-dependencies": null,
@@ -831,6 +878,7 @@ async fn exclude_package() -> Result<()> {
                 "uv": {
                   "sources": null,
                   "index": null,
+                  "enforce-index-provenance": null,
                   "workspace": {
                     "members": [
                       "packages/seeds",
@@ -838,11 +886,19 @@ async fn exclude_package() -> Result<()> {
                     ],
                     "exclude": [
                       "packages/*"
-                    ]
+                    ],
+                    "member-environments": null
                   },
+                  "hooks": null,
+                  "build-backend": null,
+                  "tasks": null,
+                  "required-tools": null,
                   "managed": null,
+                  "env-files": null,
                   "package": null,
                   "default-groups": null,
+                  "dependency-groups": null,
+                  "conflicts": null,
                   "dev-dependencies": null,
                   "override-dependencies": null,
                   "constraint-dependencies": null,
@@ -882,6 +938,7 @@ bar = ["b"]
             DependencyGroupSpecifier::Requirement("a".to_string()),
             DependencyGroupSpecifier::IncludeGroup {
                 include_group: GroupName::from_str("bar").unwrap(),
+                from: None,
             }
         ]
     );
@@ -894,3 +951,166 @@ bar = ["b"]
         &[DependencyGroupSpecifier::Requirement("b".to_string())]
     );
 }
+
+#[tokio::test]
+async fn include_group_from_member() -> Result<()> {
+    let root = tempfile::TempDir::new()?;
+    let root = ChildPath::new(root.path());
+
+    // Create the root, which defines a shared `lint` group.
+    root.child("pyproject.toml").write_str(
+        r#"
+            [project]
+            name = "albatross"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+
+            [dependency-groups]
+            lint = ["ruff", {include-group = "typecheck", from = "seeds"}]
+
+            [tool.uv.workspace]
+            members = ["packages/*"]
+            "#,
+    )?;
+
+    // Create a member (`seeds`) that includes the shared group.
+    let seeds = root.child("packages").child("seeds");
+    seeds.child("pyproject.toml").write_str(
+        r#"
+            [project]
+            name = "seeds"
+            version = "1.0.0"
+            requires-python = ">=3.12"
+
+            [dependency-groups]
+            dev = [{include-group = "lint", from = "albatross"}]
+            typecheck = ["mypy"]
+            "#,
+    )?;
+
+    let (project, _) = temporary_test(seeds.as_ref()).await;
+    let groups = project
+        .current_project()
+        .pyproject_toml()
+        .dependency_groups
+        .iter()
+        .flatten()
+        .collect::<BTreeMap<_, _>>();
+    let flat = FlatDependencyGroups::from_dependency_groups(&groups, None, project.workspace())?;
+    let dev = flat
+        .get(&GroupName::from_str("dev").unwrap())
+        .expect("Group `dev` should be present")
+        .iter()
+        .map(|requirement| requirement.name.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(dev, ["ruff", "mypy"]);
+
+    // Introduce a cycle across the members.
+    seeds.child("pyproject.toml").write_str(
+        r#"
+            [project]
+            name = "seeds"
+            version = "1.0.0"
+            requires-python = ">=3.12"
+
+            [dependency-groups]
+            dev = [{include-group = "lint", from = "albatross"}]
+            typecheck = [{include-group = "dev"}]
+            "#,
+    )?;
+
+    let (project, _) = temporary_test(seeds.as_ref()).await;
+    let groups = project
+        .current_project()
+        .pyproject_toml()
+        .dependency_groups
+        .iter()
+        .flatten()
+        .collect::<BTreeMap<_, _>>();
+    let err = FlatDependencyGroups::from_dependency_groups(&groups, None, project.workspace())
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Detected a cycle in `dependency-groups`: `lint` (from `albatross`) -> `typecheck` (from `seeds`) -> `dev` (from `seeds`) -> `lint` (from `albatross`)"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn include_group_from_virtual_root() -> Result<()> {
+    let root = tempfile::TempDir::new()?;
+    let root = ChildPath::new(root.path());
+
+    // Create a virtual root, which defines a shared `lint` group.
+    root.child("pyproject.toml").write_str(
+        r#"
+            [dependency-groups]
+            lint = ["ruff"]
+
+            [tool.uv.workspace]
+            members = ["packages/*"]
+            "#,
+    )?;
+
+    // Create a member (`seeds`) that includes the shared group.
+    let seeds = root.child("packages").child("seeds");
+    seeds.child("pyproject.toml").write_str(
+        r#"
+            [project]
+            name = "seeds"
+            version = "1.0.0"
+            requires-python = ">=3.12"
+
+            [dependency-groups]
+            dev = [{include-group = "lint", from-workspace-root = true}, "pytest"]
+            "#,
+    )?;
+
+    let (project, _) = temporary_test(seeds.as_ref()).await;
+    let groups = project
+        .current_project()
+        .pyproject_toml()
+        .dependency_groups
+        .iter()
+        .flatten()
+        .collect::<BTreeMap<_, _>>();
+    let flat = FlatDependencyGroups::from_dependency_groups(&groups, None, project.workspace())?;
+    let dev = flat
+        .get(&GroupName::from_str("dev").unwrap())
+        .expect("Group `dev` should be present")
+        .iter()
+        .map(|requirement| requirement.name.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(dev, ["ruff", "pytest"]);
+
+    // Reference a group that the root does not define.
+    seeds.child("pyproject.toml").write_str(
+        r#"
+            [project]
+            name = "seeds"
+            version = "1.0.0"
+            requires-python = ">=3.12"
+
+            [dependency-groups]
+            dev = [{include-group = "typecheck", from-workspace-root = true}]
+            "#,
+    )?;
+
+    let (project, _) = temporary_test(seeds.as_ref()).await;
+    let groups = project
+        .current_project()
+        .pyproject_toml()
+        .dependency_groups
+        .iter()
+        .flatten()
+        .collect::<BTreeMap<_, _>>();
+    let err = FlatDependencyGroups::from_dependency_groups(&groups, None, project.workspace())
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Failed to find group `typecheck` in the workspace root, included by `dev`"
+    );
+
+    Ok(())
+}
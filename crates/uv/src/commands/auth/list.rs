@@ -0,0 +1,35 @@
+use std::fmt::Write;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+
+use crate::commands::auth::read_store;
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// List the indexes with stored credentials.
+pub(crate) fn list(printer: Printer) -> Result<ExitStatus> {
+    let (store, _) = read_store()?;
+
+    if store.is_empty() {
+        writeln!(printer.stderr(), "No credentials stored")?;
+        return Ok(ExitStatus::Success);
+    }
+
+    for entry in store.iter() {
+        let location = if entry.password().is_some() {
+            ""
+        } else {
+            " (keyring)"
+        };
+        writeln!(
+            printer.stdout(),
+            "{} {}{}",
+            entry.url().bold(),
+            entry.username(),
+            location.dimmed()
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
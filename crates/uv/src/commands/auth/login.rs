@@ -0,0 +1,88 @@
+use std::fmt::Write;
+use std::io::Read;
+
+use anyhow::{bail, Context, Result};
+use console::Term;
+use owo_colors::OwoColorize;
+
+use uv_auth::{KeyringProvider, StoredCredentials};
+use uv_configuration::KeyringProviderType;
+use uv_distribution_types::Index;
+
+use crate::commands::auth::{read_store, resolve_index};
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Store credentials for an index.
+pub(crate) async fn login(
+    index: &str,
+    indexes: &[Index],
+    username: Option<String>,
+    password_stdin: bool,
+    keyring_provider: KeyringProviderType,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let url = resolve_index(index, indexes)?;
+
+    // Prompt for any missing credentials.
+    let term = Term::stderr();
+    let username = match username {
+        Some(username) => username,
+        None if term.is_term() => {
+            uv_console::input("Enter username: ", &term).context("Failed to read username")?
+        }
+        None => bail!("No username provided; use `--username` or `--token` to provide one"),
+    };
+    let password = if password_stdin {
+        let mut password = String::new();
+        std::io::stdin()
+            .read_to_string(&mut password)
+            .context("Failed to read password from stdin")?;
+        password.trim_end_matches(['\r', '\n']).to_string()
+    } else if term.is_term() {
+        let prompt = if username == "__token__" {
+            "Enter token: "
+        } else {
+            "Enter password: "
+        };
+        uv_console::password(prompt, &term).context("Failed to read password")?
+    } else {
+        bail!("No password provided; use `--password-stdin` to read it from stdin");
+    };
+    if password.is_empty() {
+        bail!("The password must not be empty");
+    }
+    if username.is_empty() {
+        bail!("The username must not be empty");
+    }
+
+    let (mut store, path) = read_store()?;
+
+    // Store the password in the keyring, if requested; otherwise, store it in the file.
+    let entry = match keyring_provider {
+        KeyringProviderType::Disabled => StoredCredentials::new(url, username, Some(password)),
+        KeyringProviderType::Subprocess => {
+            KeyringProvider::subprocess()
+                .store(&url, &username, &password)
+                .await
+                .context("Failed to store password in the keyring")?;
+            StoredCredentials::new(url, username, None)
+        }
+    };
+
+    writeln!(
+        printer.stderr(),
+        "Stored credentials for `{}` as `{}`{}",
+        entry.url().cyan(),
+        entry.username().cyan(),
+        if entry.password().is_none() {
+            " (password stored in the keyring)"
+        } else {
+            ""
+        }
+    )?;
+    store.insert(entry);
+    store.write(&path)?;
+
+    Ok(ExitStatus::Success)
+}
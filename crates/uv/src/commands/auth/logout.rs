@@ -0,0 +1,46 @@
+use std::fmt::Write;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+use tracing::warn;
+
+use uv_auth::KeyringProvider;
+use uv_distribution_types::Index;
+
+use crate::commands::auth::{read_store, resolve_index};
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Remove the stored credentials for an index.
+pub(crate) async fn logout(index: &str, indexes: &[Index], printer: Printer) -> Result<ExitStatus> {
+    let url = resolve_index(index, indexes)?;
+
+    let (mut store, path) = read_store()?;
+    let Some(entry) = store.remove(&url) else {
+        writeln!(
+            printer.stderr(),
+            "No credentials stored for `{}`",
+            url.cyan()
+        )?;
+        return Ok(ExitStatus::Failure);
+    };
+
+    // If the password was stored in the keyring, remove it from there too.
+    if entry.password().is_none() {
+        if let Err(err) = KeyringProvider::subprocess()
+            .remove(entry.url(), entry.username())
+            .await
+        {
+            warn!("Failed to remove password from the keyring: {err}");
+        }
+    }
+
+    store.write(&path)?;
+    writeln!(
+        printer.stderr(),
+        "Removed credentials for `{}`",
+        entry.url().cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
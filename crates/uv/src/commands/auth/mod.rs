@@ -0,0 +1,37 @@
+use anyhow::{bail, Result};
+use url::Url;
+
+use uv_auth::CredentialStore;
+use uv_distribution_types::Index;
+
+pub(crate) mod list;
+pub(crate) mod login;
+pub(crate) mod logout;
+pub(crate) mod token;
+
+/// Resolve an index argument, i.e., the name of an index defined in the `index` setting or the
+/// URL of an index, to a URL.
+fn resolve_index(index: &str, indexes: &[Index]) -> Result<Url> {
+    if let Some(index) = indexes.iter().find(|candidate| {
+        candidate
+            .name
+            .as_ref()
+            .is_some_and(|name| name.as_ref() == index)
+    }) {
+        return Ok(index.raw_url().clone());
+    }
+
+    match Url::parse(index) {
+        Ok(url) if matches!(url.scheme(), "http" | "https") => Ok(url),
+        _ => bail!(
+            "`{index}` is neither a valid index URL nor the name of an index defined in the `index` setting"
+        ),
+    }
+}
+
+/// Read the [`CredentialStore`], along with the path from which it was read.
+fn read_store() -> Result<(CredentialStore, std::path::PathBuf)> {
+    let path = CredentialStore::default_path()?;
+    let store = CredentialStore::read(&path)?;
+    Ok((store, path))
+}
@@ -0,0 +1,40 @@
+use std::fmt::Write;
+
+use anyhow::{bail, Result};
+use owo_colors::OwoColorize;
+
+use uv_auth::KeyringProvider;
+use uv_distribution_types::Index;
+
+use crate::commands::auth::{read_store, resolve_index};
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Display the stored token (or password) for an index.
+pub(crate) async fn token(index: &str, indexes: &[Index], printer: Printer) -> Result<ExitStatus> {
+    let url = resolve_index(index, indexes)?;
+
+    let (store, _) = read_store()?;
+    let Some(entry) = store.get(&url) else {
+        bail!("No credentials stored for `{}`", url.cyan());
+    };
+
+    let password = if let Some(password) = entry.password() {
+        password.to_string()
+    } else {
+        let Some(credentials) = KeyringProvider::subprocess()
+            .fetch(entry.url(), entry.username())
+            .await
+        else {
+            bail!(
+                "Failed to retrieve the password for `{}` from the keyring",
+                entry.url().cyan()
+            );
+        };
+        credentials.password().unwrap_or_default().to_string()
+    };
+
+    writeln!(printer.stdout(), "{password}")?;
+
+    Ok(ExitStatus::Success)
+}
@@ -23,10 +23,17 @@ pub(crate) fn build_wheel(
 }
 
 pub(crate) fn build_editable(
-    _wheel_directory: &Path,
-    _metadata_directory: Option<&Path>,
+    wheel_directory: &Path,
+    metadata_directory: Option<&Path>,
 ) -> Result<ExitStatus> {
-    todo!()
+    let filename = uv_build_backend::build_editable(
+        &env::current_dir()?,
+        wheel_directory,
+        metadata_directory,
+        uv_version::version(),
+    )?;
+    println!("{filename}");
+    Ok(ExitStatus::Success)
 }
 
 pub(crate) fn get_requires_for_build_sdist() -> Result<ExitStatus> {
@@ -50,6 +57,12 @@ pub(crate) fn get_requires_for_build_editable() -> Result<ExitStatus> {
     todo!()
 }
 
-pub(crate) fn prepare_metadata_for_build_editable(_wheel_directory: &Path) -> Result<ExitStatus> {
-    todo!()
+pub(crate) fn prepare_metadata_for_build_editable(metadata_directory: &Path) -> Result<ExitStatus> {
+    let filename = uv_build_backend::metadata(
+        &env::current_dir()?,
+        metadata_directory,
+        uv_version::version(),
+    )?;
+    println!("{filename}");
+    Ok(ExitStatus::Success)
 }
@@ -11,12 +11,13 @@ use uv_distribution_filename::SourceDistExtension;
 use uv_distribution_types::{DependencyMetadata, Index, IndexLocations};
 use uv_install_wheel::linker::LinkMode;
 
-use uv_auth::store_credentials;
+use uv_auth::{store_credentials, store_secret_credentials};
 use uv_cache::{Cache, CacheBucket};
 use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    BuildKind, BuildOptions, BuildOutput, Concurrency, ConfigSettings, Constraints,
-    HashCheckingMode, IndexStrategy, KeyringProviderType, LowerBound, SourceStrategy, TrustedHost,
+    BuildContainers, BuildKind, BuildOptions, BuildOutput, Concurrency, ConfigSettings,
+    Constraints, HashCheckingMode, IndexStrategy, KeyringProviderType, LowerBound, SourceStrategy,
+    TrustedHost,
 };
 use uv_dispatch::BuildDispatch;
 use uv_fs::Simplified;
@@ -131,8 +132,13 @@ async fn build_impl(
         allow_insecure_host,
         resolution: _,
         prerelease: _,
+        yanked: _,
+        allow_yanked: _,
+        prefer_local_version: _,
+        resolution_budget: _,
         dependency_metadata,
         config_setting,
+        build_containers,
         no_build_isolation,
         no_build_isolation_package,
         exclude_newer,
@@ -140,6 +146,10 @@ async fn build_impl(
         upgrade: _,
         build_options,
         sources,
+        variants,
+        preference_files: _,
+        minimal_changes: _,
+        upgrade_git: _,
     } = settings;
 
     let client_builder = BaseClientBuilder::default()
@@ -274,6 +284,7 @@ async fn build_impl(
             dependency_metadata,
             link_mode,
             config_setting,
+            build_containers,
         );
         async {
             let result = future.await;
@@ -369,6 +380,7 @@ async fn build_package(
     dependency_metadata: &DependencyMetadata,
     link_mode: LinkMode,
     config_setting: &ConfigSettings,
+    build_containers: &BuildContainers,
 ) -> Result<BuiltDistributions> {
     let output_dir = if let Some(output_dir) = output_dir {
         Cow::Owned(std::path::absolute(output_dir)?)
@@ -425,11 +437,14 @@ async fn build_package(
     for index in index_locations.allowed_indexes() {
         if let Some(credentials) = index.credentials() {
             store_credentials(index.raw_url(), credentials);
+        } else if let Some(secrets) = index.secret_credentials() {
+            store_secret_credentials(secrets);
         }
     }
 
     // Read build constraints.
-    let build_constraints = operations::read_constraints(build_constraints, client_builder).await?;
+    let build_constraints =
+        operations::read_constraints(build_constraints, client_builder, cache).await?;
 
     // Collect the set of required hashes.
     let hasher = if let Some(hash_checking) = hash_checking {
@@ -477,7 +492,8 @@ async fn build_package(
 
     // Resolve the flat indexes from `--find-links`.
     let flat_index = {
-        let client = FlatIndexClient::new(&client, cache);
+        let client = FlatIndexClient::new(&client, cache)
+            .with_recursive(index_locations.find_links_recursive());
         let entries = client
             .fetch(index_locations.flat_indexes().map(Index::url))
             .await?;
@@ -498,10 +514,12 @@ async fn build_package(
         dependency_metadata,
         &state.index,
         &state.git,
+        &state.hg,
         &state.capabilities,
         &state.in_flight,
         index_strategy,
         config_setting,
+        build_containers,
         build_isolation,
         link_mode,
         build_options,
@@ -510,7 +528,8 @@ async fn build_package(
         LowerBound::Allow,
         sources,
         concurrency,
-    );
+    )
+    .with_variants(variants);
 
     // Create the output directory.
     fs_err::tokio::create_dir_all(&output_dir).await?;
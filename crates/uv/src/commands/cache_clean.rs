@@ -3,7 +3,7 @@ use std::fmt::Write;
 use anyhow::{Context, Result};
 use owo_colors::OwoColorize;
 
-use uv_cache::Cache;
+use uv_cache::{Cache, CacheBucket};
 use uv_fs::Simplified;
 use uv_normalize::PackageName;
 
@@ -13,6 +13,7 @@ use crate::printer::Printer;
 /// Clear the cache, removing all entries or those linked to specific packages.
 pub(crate) fn cache_clean(
     packages: &[PackageName],
+    build_environments: bool,
     cache: &Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
@@ -26,15 +27,31 @@ pub(crate) fn cache_clean(
     }
 
     if packages.is_empty() {
-        writeln!(
-            printer.stderr(),
-            "Clearing cache at: {}",
-            cache.root().user_display().cyan()
-        )?;
+        let summary = if build_environments {
+            let bucket = cache.bucket(CacheBucket::BuildEnvironments);
+            writeln!(
+                printer.stderr(),
+                "Clearing build environments at: {}",
+                bucket.user_display().cyan()
+            )?;
+
+            cache.clear_build_environments().with_context(|| {
+                format!(
+                    "Failed to clear build environments at: {}",
+                    bucket.user_display()
+                )
+            })?
+        } else {
+            writeln!(
+                printer.stderr(),
+                "Clearing cache at: {}",
+                cache.root().user_display().cyan()
+            )?;
 
-        let summary = cache.clear().with_context(|| {
-            format!("Failed to clear cache at: {}", cache.root().user_display())
-        })?;
+            cache.clear().with_context(|| {
+                format!("Failed to clear cache at: {}", cache.root().user_display())
+            })?
+        };
 
         // Write a summary of the number of files and directories removed.
         match (summary.num_files, summary.num_dirs) {
@@ -0,0 +1,199 @@
+use std::error::Error;
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use tracing::debug;
+
+use uv_cache::{rm_rf, Cache, CacheBucket, Removal};
+use uv_fs::Simplified;
+use uv_install_wheel::{verify_unzipped_wheel, verify_wheel_archive, VerifyError};
+
+use crate::commands::{human_readable_bytes, ExitStatus};
+use crate::printer::Printer;
+
+/// An entry in the cache that can be verified against the hashes in its `RECORD` file.
+#[derive(Debug)]
+enum Entry {
+    /// An unzipped wheel in the archive bucket.
+    Unzipped(PathBuf),
+    /// A wheel archive, e.g., a wheel built from a source distribution.
+    Archive(PathBuf),
+}
+
+impl Entry {
+    fn path(&self) -> &PathBuf {
+        match self {
+            Self::Unzipped(path) | Self::Archive(path) => path,
+        }
+    }
+
+    fn verify(&self) -> Result<(), VerifyError> {
+        match self {
+            Self::Unzipped(path) => verify_unzipped_wheel(path),
+            Self::Archive(path) => verify_wheel_archive(path),
+        }
+    }
+
+    /// Remove the entry from the cache.
+    ///
+    /// Pointers to a removed unzipped wheel are left in place, as uv re-downloads (or re-unzips)
+    /// any wheel whose archive is missing. For wheel archives, the adjacent unzipped wheel (if
+    /// any) is removed too, such that the wheel is rebuilt when next needed.
+    fn remove(&self) -> Result<Removal, std::io::Error> {
+        match self {
+            Self::Unzipped(path) => rm_rf(path),
+            Self::Archive(path) => {
+                let mut removal = rm_rf(path)?;
+                let target = path.with_extension("");
+                if target.is_symlink() || target.exists() {
+                    removal += rm_rf(target)?;
+                }
+                Ok(removal)
+            }
+        }
+    }
+}
+
+/// Verify the integrity of the cached wheels, removing any corrupt entries.
+pub(crate) fn cache_verify(dry_run: bool, cache: &Cache, printer: Printer) -> Result<ExitStatus> {
+    if !cache.root().exists() {
+        writeln!(
+            printer.stderr(),
+            "No cache found at: {}",
+            cache.root().user_display().cyan()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    writeln!(
+        printer.stderr(),
+        "Verifying cache at: {}",
+        cache.root().user_display().cyan()
+    )?;
+
+    let entries = collect_entries(cache)
+        .with_context(|| format!("Failed to read cache at: {}", cache.root().user_display()))?;
+    let num_entries = entries.len();
+
+    // Verify the entries in parallel, since each requires reading (and hashing) every file.
+    let mut corrupt = entries
+        .into_par_iter()
+        .filter_map(|entry| match entry.verify() {
+            Ok(()) => None,
+            Err(err) => Some((entry, err)),
+        })
+        .collect::<Vec<_>>();
+    corrupt.sort_by(|(a, _), (b, _)| a.path().cmp(b.path()));
+
+    for (entry, err) in &corrupt {
+        let path = entry
+            .path()
+            .strip_prefix(cache.root())
+            .unwrap_or(entry.path());
+        let mut message = err.to_string();
+        let mut source = err.source();
+        while let Some(err) = source {
+            write!(message, ": {err}")?;
+            source = err.source();
+        }
+        writeln!(
+            printer.stderr(),
+            "{}: {} ({message})",
+            "Corrupt".red().bold(),
+            path.user_display().cyan()
+        )?;
+    }
+
+    let num_corrupt = corrupt.len();
+    let entries_label = if num_entries == 1 { "entry" } else { "entries" };
+    if num_corrupt == 0 {
+        writeln!(
+            printer.stderr(),
+            "Verified {num_entries} cache {entries_label}; no corruption found"
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    let corrupt_label = if num_corrupt == 1 { "entry" } else { "entries" };
+    if dry_run {
+        writeln!(
+            printer.stderr(),
+            "Found {num_corrupt} corrupt cache {corrupt_label} out of {num_entries} verified"
+        )?;
+        return Ok(ExitStatus::Failure);
+    }
+
+    // Evict the corrupt entries.
+    let mut summary = Removal::default();
+    for (entry, _) in &corrupt {
+        debug!("Removing corrupt cache entry: {}", entry.path().display());
+        summary += entry.remove().with_context(|| {
+            format!(
+                "Failed to remove corrupt cache entry: {}",
+                entry.path().user_display()
+            )
+        })?;
+    }
+
+    write!(
+        printer.stderr(),
+        "Removed {num_corrupt} corrupt cache {corrupt_label} out of {num_entries} verified"
+    )?;
+    if summary.total_bytes > 0 {
+        let bytes = if summary.total_bytes < 1024 {
+            format!("{}B", summary.total_bytes)
+        } else {
+            let (bytes, unit) = human_readable_bytes(summary.total_bytes);
+            format!("{bytes:.1}{unit}")
+        };
+        write!(printer.stderr(), " ({})", bytes.green())?;
+    }
+    writeln!(printer.stderr())?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Collect the verifiable entries in the cache.
+fn collect_entries(cache: &Cache) -> Result<Vec<Entry>, std::io::Error> {
+    let mut entries = Vec::new();
+
+    // Collect the unzipped wheels. The archive bucket also contains cached virtual environments,
+    // which have no `RECORD` file and are skipped.
+    match fs_err::read_dir(cache.bucket(CacheBucket::Archive)) {
+        Ok(dir) => {
+            for entry in dir {
+                let path = entry?.path();
+                if path.is_dir() && !path.join("pyvenv.cfg").exists() {
+                    entries.push(Entry::Unzipped(path));
+                }
+            }
+        }
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+        Err(err) => return Err(err),
+    }
+
+    // Collect the wheel archives, i.e., wheels built from source distributions, along with any
+    // downloaded wheels that were persisted without unzipping.
+    for bucket in [CacheBucket::SourceDistributions, CacheBucket::Wheels] {
+        let bucket = cache.bucket(bucket);
+        if !bucket.is_dir() {
+            continue;
+        }
+        for entry in walkdir::WalkDir::new(bucket) {
+            let entry = entry.map_err(std::io::Error::from)?;
+            if entry.file_type().is_file()
+                && entry
+                    .path()
+                    .extension()
+                    .is_some_and(|extension| extension.eq_ignore_ascii_case("whl"))
+            {
+                entries.push(Entry::Archive(entry.into_path()));
+            }
+        }
+    }
+
+    Ok(entries)
+}
@@ -0,0 +1,56 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+
+use uv_cli::ConfigFormat;
+use uv_fs::Simplified;
+
+use crate::commands::config::{parse_key, ConfigFile, ConfigScope};
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Display the value of a setting in the project, user, or system configuration.
+pub(crate) async fn get(
+    project_dir: &Path,
+    key: &str,
+    scope: ConfigScope,
+    format: ConfigFormat,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let keys = parse_key(key)?;
+    let config = ConfigFile::read(project_dir, scope).await?;
+    let settings = config.settings()?;
+
+    let mut value = None;
+    let mut table = Some(&settings);
+    for component in &keys {
+        value = table.and_then(|table| table.get(component.get()));
+        table = value.and_then(toml::Value::as_table);
+    }
+    let Some(value) = value else {
+        writeln!(
+            printer.stderr(),
+            "Setting `{}` is not defined in `{}`",
+            key.cyan(),
+            config.path().user_display().cyan()
+        )?;
+        return Ok(ExitStatus::Failure);
+    };
+
+    match format {
+        ConfigFormat::Text => {
+            let value = match value {
+                toml::Value::String(value) => value.clone(),
+                value => value.to_string(),
+            };
+            writeln!(printer.stdout(), "{value}")?;
+        }
+        ConfigFormat::Json => {
+            writeln!(printer.stdout(), "{}", serde_json::to_string_pretty(value)?)?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
@@ -0,0 +1,68 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+
+use uv_cli::ConfigFormat;
+use uv_fs::Simplified;
+
+use crate::commands::config::{format_key, ConfigFile, ConfigScope};
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// List the settings defined in the project, user, or system configuration.
+pub(crate) async fn list(
+    project_dir: &Path,
+    scope: ConfigScope,
+    format: ConfigFormat,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let config = ConfigFile::read(project_dir, scope).await?;
+    let settings = config.settings()?;
+
+    match format {
+        ConfigFormat::Text => {
+            if settings.is_empty() {
+                writeln!(
+                    printer.stderr(),
+                    "No settings defined in `{}`",
+                    config.path().user_display().cyan()
+                )?;
+                return Ok(ExitStatus::Success);
+            }
+
+            let mut entries = Vec::new();
+            flatten(&settings, &mut Vec::new(), &mut entries);
+            for (key, value) in entries {
+                writeln!(printer.stdout(), "{key} = {value}")?;
+            }
+        }
+        ConfigFormat::Json => {
+            writeln!(
+                printer.stdout(),
+                "{}",
+                serde_json::to_string_pretty(&settings)?
+            )?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Flatten a table of settings into dotted names and TOML values, such that (e.g.) `[pip]` with
+/// `index-url = "..."` is listed as `pip.index-url = "..."`.
+fn flatten<'a>(
+    table: &'a toml::Table,
+    prefix: &mut Vec<&'a str>,
+    entries: &mut Vec<(String, String)>,
+) {
+    for (key, value) in table {
+        prefix.push(key);
+        match value {
+            toml::Value::Table(table) if !table.is_empty() => flatten(table, prefix, entries),
+            value => entries.push((format_key(prefix.iter().copied()), value.to_string())),
+        }
+        prefix.pop();
+    }
+}
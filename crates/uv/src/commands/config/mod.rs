@@ -0,0 +1,169 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, bail, Context, Result};
+use toml_edit::{DocumentMut, Item, Key, Table};
+
+use uv_fs::Simplified;
+use uv_settings::Options;
+use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceError};
+
+pub(crate) mod get;
+pub(crate) mod list;
+pub(crate) mod set;
+
+/// The configuration file to read or write.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum ConfigScope {
+    /// The `uv.toml` or `pyproject.toml` at the workspace root.
+    Project,
+    /// The user-level `uv.toml`.
+    User,
+    /// The system-level `uv.toml`.
+    System,
+}
+
+impl ConfigScope {
+    /// Determine the [`ConfigScope`] from the `--user` and `--system` flags.
+    pub(crate) fn from_args(user: bool, system: bool) -> Self {
+        if user {
+            Self::User
+        } else if system {
+            Self::System
+        } else {
+            Self::Project
+        }
+    }
+}
+
+/// A configuration file in which uv settings are defined, i.e., a `uv.toml` (at the top level) or
+/// a `pyproject.toml` (under `tool.uv`).
+///
+/// Shared by the `uv config` and `uv index` commands, such that both edit the same file for a
+/// given scope.
+pub(crate) struct ConfigFile {
+    path: PathBuf,
+    doc: DocumentMut,
+    pyproject: bool,
+}
+
+impl ConfigFile {
+    /// Read the [`ConfigFile`] for the given scope.
+    ///
+    /// For the project scope, a `uv.toml` at the workspace root takes precedence over the
+    /// `pyproject.toml`, mirroring settings discovery. Missing `uv.toml` files are created on
+    /// write.
+    pub(crate) async fn read(project_dir: &Path, scope: ConfigScope) -> Result<Self> {
+        let path = match scope {
+            ConfigScope::User => uv_settings::user_config_file()
+                .ok_or_else(|| anyhow!("Failed to determine the user configuration directory"))?,
+            ConfigScope::System => uv_settings::system_config_file_or_default()
+                .ok_or_else(|| anyhow!("Failed to determine the system configuration directory"))?,
+            ConfigScope::Project => {
+                match Workspace::discover(project_dir, &DiscoveryOptions::default()).await {
+                    Ok(workspace) => {
+                        let uv_toml = workspace.install_path().join("uv.toml");
+                        if uv_toml.is_file() {
+                            uv_toml
+                        } else {
+                            workspace.install_path().join("pyproject.toml")
+                        }
+                    }
+                    // Outside of a project, use a `uv.toml` in the current directory.
+                    Err(WorkspaceError::MissingPyprojectToml) => project_dir.join("uv.toml"),
+                    Err(err) => return Err(err.into()),
+                }
+            }
+        };
+        let pyproject = path
+            .file_name()
+            .is_some_and(|file_name| file_name == "pyproject.toml");
+
+        let contents = match fs_err::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(err) if !pyproject && err.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(err.into()),
+        };
+        let doc = contents
+            .parse::<DocumentMut>()
+            .with_context(|| format!("Failed to parse `{}`", path.user_display()))?;
+
+        Ok(Self {
+            path,
+            doc,
+            pyproject,
+        })
+    }
+
+    /// Return the settings defined in the configuration file.
+    pub(crate) fn settings(&self) -> Result<toml::Table> {
+        let mut value = toml::from_str::<toml::Table>(&self.doc.to_string())?;
+        if !self.pyproject {
+            return Ok(value);
+        }
+        let Some(toml::Value::Table(mut tool)) = value.remove("tool") else {
+            return Ok(toml::Table::new());
+        };
+        match tool.remove("uv") {
+            Some(toml::Value::Table(settings)) => Ok(settings),
+            Some(_) => bail!("`tool.uv` in `{}` is malformed", self.path.user_display()),
+            None => Ok(toml::Table::new()),
+        }
+    }
+
+    /// Return the table containing the settings, creating it if necessary.
+    pub(crate) fn settings_mut(&mut self) -> Result<&mut Table> {
+        if !self.pyproject {
+            return Ok(self.doc.as_table_mut());
+        }
+        self.doc
+            .entry("tool")
+            .or_insert(implicit())
+            .as_table_mut()
+            .and_then(|tool| tool.entry("uv").or_insert(implicit()).as_table_mut())
+            .ok_or_else(|| anyhow!("`tool.uv` in `{}` is malformed", self.path.user_display()))
+    }
+
+    /// Validate the settings defined in the configuration file.
+    pub(crate) fn validate(&self) -> Result<()> {
+        toml::Value::Table(self.settings()?).try_into::<Options>()?;
+        Ok(())
+    }
+
+    /// Write the configuration file back to disk.
+    pub(crate) fn write(&self) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs_err::create_dir_all(parent)?;
+        }
+        fs_err::write(&self.path, self.doc.to_string())?;
+        Ok(())
+    }
+
+    /// Return the path to the configuration file.
+    pub(crate) fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+/// Parse a dotted setting name (e.g., `pip.index-url`) into its components.
+fn parse_key(key: &str) -> Result<Vec<Key>> {
+    let keys = Key::parse(key).map_err(|_| anyhow!("Invalid setting name: `{key}`"))?;
+    if keys.is_empty() {
+        bail!("Invalid setting name: `{key}`");
+    }
+    Ok(keys)
+}
+
+/// Format a dotted setting name from its components, quoting any that aren't bare keys.
+fn format_key<'a>(keys: impl IntoIterator<Item = &'a str>) -> String {
+    keys.into_iter()
+        .map(|key| Key::new(key).display_repr().into_owned())
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// Returns an implicit table.
+pub(crate) fn implicit() -> Item {
+    let mut table = Table::new();
+    table.set_implicit(true);
+    Item::Table(table)
+}
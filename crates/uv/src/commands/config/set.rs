@@ -0,0 +1,92 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{anyhow, Result};
+use owo_colors::OwoColorize;
+use toml_edit::{Item, Key, Table, Value};
+
+use uv_fs::Simplified;
+
+use crate::commands::config::{format_key, implicit, parse_key, ConfigFile, ConfigScope};
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Set the value of a setting in the project, user, or system configuration.
+pub(crate) async fn set(
+    project_dir: &Path,
+    key: &str,
+    value: &str,
+    scope: ConfigScope,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let keys = parse_key(key)?;
+    let name = format_key(keys.iter().map(Key::get));
+    let mut config = ConfigFile::read(project_dir, scope).await?;
+
+    // Prefer to interpret the value as TOML, such that (e.g.) `true` is a boolean, but fall back
+    // to a string, such that (e.g.) `3.12` can be provided for a setting that expects a string.
+    let mut candidates = Vec::with_capacity(2);
+    if let Ok(value) = value.trim().parse::<Value>() {
+        candidates.push(value);
+    }
+    candidates.push(Value::from(value));
+
+    let original = config.doc.clone();
+    let mut error = None;
+    for candidate in candidates {
+        config.doc = original.clone();
+        insert(config.settings_mut()?, &keys, candidate)?;
+        match config.validate() {
+            Ok(()) => {
+                error = None;
+                break;
+            }
+            Err(err) => {
+                error.get_or_insert(err);
+            }
+        }
+    }
+    if let Some(err) = error {
+        return Err(err.context(format!(
+            "Invalid value for `{name}` in `{}`",
+            config.path().user_display()
+        )));
+    }
+    config.write()?;
+
+    writeln!(
+        printer.stderr(),
+        "Set `{}` in `{}`",
+        name.cyan(),
+        config.path().user_display().cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Insert a value at the given (dotted) key, creating any intermediate tables and retaining the
+/// formatting of any existing value.
+fn insert(table: &mut Table, keys: &[Key], mut value: Value) -> Result<()> {
+    let (last, parents) = keys
+        .split_last()
+        .expect("setting names have at least one component");
+
+    let mut table: &mut dyn toml_edit::TableLike = table;
+    for key in parents {
+        if table.get(key.get()).is_none() {
+            table.insert(key.get(), implicit());
+        }
+        table = table
+            .get_mut(key.get())
+            .and_then(Item::as_table_like_mut)
+            .ok_or_else(|| anyhow!("`{}` is not a table", key.get()))?;
+    }
+
+    value.decor_mut().clear();
+    if let Some(existing) = table.get(last.get()).and_then(Item::as_value) {
+        *value.decor_mut() = existing.decor().clone();
+    }
+    table.insert(last.get(), Item::Value(value));
+
+    Ok(())
+}
@@ -0,0 +1,904 @@
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+use serde::Serialize;
+
+use uv_cache::Cache;
+use uv_cli::DoctorFormat;
+use uv_client::{
+    Connectivity, IndexProxy, IndexTls, IndexTlsError, RegistryClient, RegistryClientBuilder,
+};
+use uv_distribution_types::{Index, IndexUrl};
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_python::{
+    EnvironmentPreference, PythonEnvironment, PythonInstallation, PythonPreference, PythonRequest,
+    PythonVersionFile,
+};
+use uv_static::EnvVars;
+use uv_workspace::{DiscoveryOptions, VirtualProject, Workspace, WorkspaceError};
+
+use crate::commands::project::find_requires_python;
+use crate::commands::project::lock::read;
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+use crate::settings::ResolverSettings;
+
+/// Diagnose common problems with uv's environment and configuration.
+pub(crate) async fn doctor(
+    project_dir: &Path,
+    python: Option<String>,
+    format: DoctorFormat,
+    settings: &ResolverSettings,
+    python_preference: PythonPreference,
+    connectivity: Connectivity,
+    native_tls: bool,
+    no_config: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let mut checks = Vec::new();
+
+    // Discover the project, if any, which informs the environment and lockfile checks.
+    let project = match VirtualProject::discover(project_dir, &DiscoveryOptions::default()).await {
+        Ok(project) => Some(project),
+        Err(
+            WorkspaceError::MissingPyprojectToml
+            | WorkspaceError::MissingProject(_)
+            | WorkspaceError::NonWorkspace(_),
+        ) => None,
+        Err(err) => {
+            checks.push(Check::fail(
+                Category::Project,
+                format!("Failed to discover the project: {err}"),
+                "Fix the errors in your `pyproject.toml`",
+            ));
+            None
+        }
+    };
+    let workspace = project.as_ref().map(VirtualProject::workspace);
+
+    check_python(
+        &mut checks,
+        project_dir,
+        python,
+        python_preference,
+        no_config,
+        cache,
+    )
+    .await;
+    check_environment(&mut checks, project_dir, workspace, cache);
+    check_cache(&mut checks, cache);
+    check_network(&mut checks, settings, native_tls);
+    check_indexes(&mut checks, settings, connectivity, native_tls, cache).await;
+    if let Some(workspace) = workspace {
+        check_lockfile(&mut checks, workspace).await;
+    }
+
+    let report = Report::new(checks);
+    match format {
+        DoctorFormat::Text => report.write_text(printer)?,
+        DoctorFormat::Json => {
+            writeln!(
+                printer.stdout(),
+                "{}",
+                serde_json::to_string_pretty(&report)?
+            )?;
+        }
+    }
+
+    if report.errors > 0 {
+        Ok(ExitStatus::Failure)
+    } else {
+        Ok(ExitStatus::Success)
+    }
+}
+
+/// Check that a Python interpreter can be found.
+async fn check_python(
+    checks: &mut Vec<Check>,
+    project_dir: &Path,
+    python: Option<String>,
+    python_preference: PythonPreference,
+    no_config: bool,
+    cache: &Cache,
+) {
+    // Respect an explicit request, then any `.python-version` file.
+    let request = match python {
+        Some(python) => Some(PythonRequest::parse(&python)),
+        None => match PythonVersionFile::discover(project_dir, no_config, false).await {
+            Ok(file) => file.and_then(PythonVersionFile::into_version),
+            Err(err) => {
+                checks.push(Check::fail(
+                    Category::Python,
+                    format!("Failed to read the `.python-version` file: {err}"),
+                    "Fix or remove the `.python-version` file",
+                ));
+                None
+            }
+        },
+    };
+    let request = request.unwrap_or_default();
+
+    match PythonInstallation::find(
+        &request,
+        EnvironmentPreference::OnlySystem,
+        python_preference,
+        cache,
+    ) {
+        Ok(installation) => {
+            let interpreter = installation.interpreter();
+            checks.push(Check::pass(
+                Category::Python,
+                format!(
+                    "Found {} {} at `{}`",
+                    installation.implementation().pretty(),
+                    interpreter.python_version(),
+                    interpreter.sys_executable().user_display()
+                ),
+            ));
+        }
+        Err(uv_python::Error::MissingPython(err)) => {
+            checks.push(Check::fail(
+                Category::Python,
+                err.to_string(),
+                if matches!(request, PythonRequest::Default | PythonRequest::Any) {
+                    "Run `uv python install` to install a managed Python version".to_string()
+                } else {
+                    format!(
+                        "Run `uv python install {}` to install a matching Python version",
+                        request.to_canonical_string()
+                    )
+                },
+            ));
+        }
+        Err(err) => {
+            checks.push(Check::fail(
+                Category::Python,
+                format!("Failed to discover a Python interpreter: {err}"),
+                "Run with `--verbose` to see the interpreters that were queried",
+            ));
+        }
+    }
+}
+
+/// Check that the project environment, or the active virtual environment, is usable.
+fn check_environment(
+    checks: &mut Vec<Check>,
+    project_dir: &Path,
+    workspace: Option<&Workspace>,
+    cache: &Cache,
+) {
+    let active = std::env::var_os(EnvVars::VIRTUAL_ENV)
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from);
+
+    let (root, recreate) = if let Some(workspace) = workspace {
+        let root = workspace.venv();
+
+        // Warn if the active virtual environment will be ignored in favor of the project
+        // environment.
+        if let Some(active) = active.as_ref() {
+            if !is_same_path(active, &root) {
+                checks.push(Check::warn(
+                    Category::Environment,
+                    format!(
+                        "`VIRTUAL_ENV` is set to `{}`, which doesn't match the project environment at `{}` and will be ignored",
+                        active.user_display(),
+                        root.user_display()
+                    ),
+                    "Run `deactivate` to deactivate the environment, or set `UV_PROJECT_ENVIRONMENT` to use it for the project",
+                ));
+            }
+        }
+
+        if !root.exists() {
+            checks.push(Check::warn(
+                Category::Environment,
+                format!(
+                    "The project environment at `{}` does not exist",
+                    root.user_display()
+                ),
+                "Run `uv sync` to create it",
+            ));
+            return;
+        }
+
+        (
+            root,
+            "Remove the environment and run `uv sync` to recreate it".to_string(),
+        )
+    } else if let Some(active) = active {
+        if !active.exists() {
+            checks.push(Check::fail(
+                Category::Environment,
+                format!(
+                    "`VIRTUAL_ENV` is set to `{}`, which does not exist",
+                    active.user_display()
+                ),
+                format!(
+                    "Run `deactivate` or unset `VIRTUAL_ENV`, or run `uv venv {}` to create it",
+                    active.user_display()
+                ),
+            ));
+            return;
+        }
+        let recreate = format!("Run `uv venv {}` to recreate it", active.user_display());
+        (active, recreate)
+    } else if project_dir.join(".venv").is_dir() {
+        (
+            project_dir.join(".venv"),
+            "Run `uv venv` to recreate it".to_string(),
+        )
+    } else {
+        checks.push(Check::skip(
+            Category::Environment,
+            "No virtual environment found",
+        ));
+        return;
+    };
+
+    let environment = match PythonEnvironment::from_root(&root, cache) {
+        Ok(environment) => environment,
+        Err(err) => {
+            checks.push(Check::fail(
+                Category::Environment,
+                format!(
+                    "The virtual environment at `{}` is broken: {err}",
+                    root.user_display()
+                ),
+                recreate,
+            ));
+            return;
+        }
+    };
+    let version = environment.interpreter().python_version();
+
+    // Ensure that the environment's interpreter satisfies the project's Python requirement.
+    if let Some(requires_python) = workspace.and_then(find_requires_python) {
+        if !requires_python.contains(version) {
+            checks.push(Check::fail(
+                Category::Environment,
+                format!(
+                    "The virtual environment at `{}` uses Python {version}, which is incompatible with the project's Python requirement (`{requires_python}`)",
+                    root.user_display()
+                ),
+                "Run `uv sync` to recreate the environment with a compatible interpreter",
+            ));
+            return;
+        }
+    }
+
+    checks.push(Check::pass(
+        Category::Environment,
+        format!(
+            "Found a virtual environment at `{}` using Python {version}",
+            root.user_display()
+        ),
+    ));
+}
+
+/// Check that the cache directory is writable.
+fn check_cache(checks: &mut Vec<Check>, cache: &Cache) {
+    if cache.is_temporary() {
+        checks.push(Check::skip(
+            Category::Cache,
+            "Using a temporary cache directory (`--no-cache`)",
+        ));
+        return;
+    }
+
+    let root = cache.root();
+    let result = fs_err::create_dir_all(root).and_then(|()| tempfile::tempfile_in(root).map(drop));
+    match result {
+        Ok(()) => checks.push(Check::pass(
+            Category::Cache,
+            format!("The cache directory at `{}` is writable", root.user_display()),
+        )),
+        Err(err) => checks.push(Check::fail(
+            Category::Cache,
+            format!(
+                "The cache directory at `{}` is not writable: {err}",
+                root.user_display()
+            ),
+            "Fix the permissions on the cache directory, or set `UV_CACHE_DIR` to a writable directory",
+        )),
+    }
+}
+
+/// Check that the TLS and proxy configuration is valid, including any per-index settings.
+fn check_network(checks: &mut Vec<Check>, settings: &ResolverSettings, native_tls: bool) {
+    checks.push(Check::pass(
+        Category::Network,
+        if native_tls {
+            "Using the platform's native certificate store (`--native-tls`)"
+        } else {
+            "Using the bundled `webpki-roots` certificates"
+        },
+    ));
+
+    for (var, description) in [
+        (EnvVars::SSL_CERT_FILE, "certificate bundle"),
+        (EnvVars::SSL_CLIENT_CERT, "client certificate"),
+    ] {
+        let Some(path) = std::env::var_os(var).filter(|value| !value.is_empty()) else {
+            continue;
+        };
+        let path = PathBuf::from(path);
+        if path.is_file() {
+            checks.push(Check::pass(
+                Category::Network,
+                format!(
+                    "Using the {description} from `{var}`: `{}`",
+                    path.user_display()
+                ),
+            ));
+        } else {
+            checks.push(Check::fail(
+                Category::Network,
+                format!(
+                    "`{var}` is set to `{}`, which does not exist",
+                    path.user_display()
+                ),
+                format!("Set `{var}` to the path of a PEM-encoded {description}, or unset it"),
+            ));
+        }
+    }
+
+    for var in [
+        EnvVars::HTTPS_PROXY,
+        EnvVars::HTTP_PROXY,
+        EnvVars::ALL_PROXY,
+        EnvVars::NO_PROXY,
+    ] {
+        let Some(value) = env_var(var) else {
+            continue;
+        };
+        if var == EnvVars::NO_PROXY {
+            checks.push(Check::pass(
+                Category::Network,
+                format!("Bypassing the proxy for hosts in `{var}`: `{value}`"),
+            ));
+            continue;
+        }
+
+        // Like `reqwest`, assume HTTP for proxies without a scheme.
+        match url::Url::parse(&value).or_else(|_| url::Url::parse(&format!("http://{value}"))) {
+            Ok(mut url) if url.has_host() => {
+                let _ = url.set_username("");
+                let _ = url.set_password(None);
+                checks.push(Check::pass(
+                    Category::Network,
+                    format!("Using the proxy from `{var}`: `{url}`"),
+                ));
+            }
+            _ => {
+                checks.push(Check::fail(
+                    Category::Network,
+                    format!("`{var}` is not a valid proxy URL"),
+                    format!(
+                        "Set `{var}` to a URL like `http://proxy.example.com:8080`, or unset it"
+                    ),
+                ));
+            }
+        }
+    }
+
+    // Per-index settings take precedence over the environment for requests to the index's host.
+    for index in settings.index_locations.allowed_indexes() {
+        let url = index.url().redacted().into_owned();
+
+        if let Some(proxy) = IndexProxy::from_index(index).and(index.proxy.as_ref()) {
+            let mut proxy = proxy.clone();
+            let _ = proxy.set_username("");
+            let _ = proxy.set_password(None);
+            checks.push(Check::pass(
+                Category::Network,
+                format!("Using the proxy `{proxy}` for `{url}`"),
+            ));
+        }
+
+        let Some(tls) = IndexTls::from_index(index) else {
+            continue;
+        };
+        match tls.validate() {
+            Ok(()) => {
+                if let Some(ca_cert) = &index.ca_cert {
+                    checks.push(Check::pass(
+                        Category::Network,
+                        format!(
+                            "Using the certificate bundle `{}` for `{url}`",
+                            ca_cert.user_display()
+                        ),
+                    ));
+                }
+                if let Some(client_cert) = &index.client_cert {
+                    checks.push(Check::pass(
+                        Category::Network,
+                        format!(
+                            "Using the client certificate `{}` for `{url}`",
+                            client_cert.user_display()
+                        ),
+                    ));
+                }
+            }
+            Err(err) => {
+                let fix = match &err {
+                    IndexTlsError::CaCert(..) => {
+                        "Set the index's `ca-cert` to the path of a PEM-encoded certificate bundle"
+                    }
+                    IndexTlsError::ClientCert(..) => {
+                        "Set the index's `client-cert` (and `client-key`) to the path of a PEM-encoded certificate and private key, or a PKCS#12 archive"
+                    }
+                };
+                checks.push(Check::fail(
+                    Category::Network,
+                    format!(
+                        "The TLS settings for `{url}` are invalid: {:#}",
+                        anyhow::Error::from(err)
+                    ),
+                    fix,
+                ));
+            }
+        }
+    }
+}
+
+/// Check that the configured package indexes are reachable.
+async fn check_indexes(
+    checks: &mut Vec<Check>,
+    settings: &ResolverSettings,
+    connectivity: Connectivity,
+    native_tls: bool,
+    cache: &Cache,
+) {
+    let indexes = settings.index_locations.allowed_indexes();
+    if indexes.is_empty() {
+        checks.push(Check::skip(
+            Category::Index,
+            "No package indexes are configured",
+        ));
+        return;
+    }
+    if connectivity.is_offline() {
+        checks.push(Check::skip(
+            Category::Index,
+            "Skipped checking the package indexes, as network access is disabled (`--offline`)",
+        ));
+        return;
+    }
+
+    // Add all authenticated sources to the cache.
+    for index in &indexes {
+        if let Some(credentials) = index.credentials() {
+            uv_auth::store_credentials(index.raw_url(), credentials);
+        } else if let Some(secrets) = index.secret_credentials() {
+            uv_auth::store_secret_credentials(secrets);
+        }
+    }
+
+    // Use a registry client, such that any per-index TLS and proxy settings are respected.
+    let client = RegistryClientBuilder::new(cache.clone())
+        .connectivity(connectivity)
+        .native_tls(native_tls)
+        .keyring(settings.keyring_provider)
+        .allow_insecure_host(settings.allow_insecure_host.clone())
+        .index_urls(settings.index_locations.index_urls())
+        .build();
+
+    for index in indexes {
+        checks.push(check_index(index, &client, native_tls).await);
+    }
+}
+
+/// Check that a single package index is reachable.
+async fn check_index(index: &Index, client: &RegistryClient, native_tls: bool) -> Check {
+    let url = index.url().redacted().into_owned();
+
+    // Local indexes only need to exist.
+    if let IndexUrl::Path(_) = index.url() {
+        return match index.url().url().to_file_path() {
+            Ok(path) if path.exists() => {
+                Check::pass(Category::Index, format!("`{}` exists", path.user_display()))
+            }
+            _ => Check::fail(
+                Category::Index,
+                format!("The index at `{url}` does not exist"),
+                "Create the index directory, or remove the index from your configuration",
+            ),
+        };
+    }
+
+    let response = client
+        .uncached_client(index.raw_url())
+        .head(index.raw_url().clone())
+        .send()
+        .await;
+    match response {
+        Ok(response) => {
+            let status = response.status();
+            if status.is_success() || status.is_redirection() {
+                Check::pass(Category::Index, format!("`{url}` is reachable ({status})"))
+            } else if status == reqwest::StatusCode::UNAUTHORIZED
+                || status == reqwest::StatusCode::FORBIDDEN
+            {
+                Check::fail(
+                    Category::Index,
+                    format!("`{url}` rejected the request ({status})"),
+                    format!("Provide credentials for the index with `uv auth login {url}`"),
+                )
+            } else if status == reqwest::StatusCode::NOT_FOUND {
+                Check::warn(
+                    Category::Index,
+                    format!("`{url}` returned {status}"),
+                    "Check that the index URL is correct",
+                )
+            } else if status.is_server_error() {
+                Check::fail(
+                    Category::Index,
+                    format!("`{url}` returned {status}"),
+                    "The index may be experiencing an outage; try again later",
+                )
+            } else {
+                // The index may not support `HEAD` requests, but it's reachable.
+                Check::pass(Category::Index, format!("`{url}` is reachable ({status})"))
+            }
+        }
+        Err(err) => {
+            let err = anyhow::Error::from(err);
+            let tls = err.chain().any(|cause| {
+                let cause = cause.to_string().to_lowercase();
+                cause.contains("certificate") || cause.contains("tls") || cause.contains("ssl")
+            });
+            let fix = if tls && !native_tls {
+                "If the index uses a certificate from a corporate or custom certificate authority, use `--native-tls` or set `SSL_CERT_FILE` to a bundle that includes it"
+            } else if tls {
+                "Ensure that the index's certificate authority is trusted by the platform, or set `SSL_CERT_FILE` to a bundle that includes it"
+            } else if env_var(EnvVars::HTTPS_PROXY).is_some()
+                || env_var(EnvVars::ALL_PROXY).is_some()
+            {
+                "Check your network connection and that the configured proxy is reachable"
+            } else {
+                "Check your network connection, or configure a proxy with `HTTPS_PROXY`"
+            };
+            Check::fail(
+                Category::Index,
+                format!("Failed to connect to `{url}`: {err:#}"),
+                fix,
+            )
+        }
+    }
+}
+
+/// Check that the lockfile is consistent with the workspace.
+///
+/// Unlike `uv lock --check`, this check doesn't require a resolution, so it can only detect
+/// changes to the workspace members and Python requirement.
+async fn check_lockfile(checks: &mut Vec<Check>, workspace: &Workspace) {
+    let path = workspace.install_path().join("uv.lock");
+    let lock = match read(workspace).await {
+        Ok(Some(lock)) => lock,
+        Ok(None) => {
+            checks.push(Check::warn(
+                Category::Project,
+                format!(
+                    "The workspace at `{}` does not have a lockfile",
+                    workspace.install_path().user_display()
+                ),
+                "Run `uv lock` to create one",
+            ));
+            return;
+        }
+        Err(err) => {
+            checks.push(Check::fail(
+                Category::Project,
+                format!("Failed to read `{}`: {err}", path.user_display()),
+                "Run `uv lock` to regenerate the lockfile",
+            ));
+            return;
+        }
+    };
+
+    // As in `uv lock`, a single-member project omits its members from the lockfile.
+    let mut members = workspace
+        .packages()
+        .keys()
+        .cloned()
+        .collect::<BTreeSet<_>>();
+    if members.len() == 1 && !workspace.is_non_project() {
+        members.clear();
+    }
+    if *lock.members() != members {
+        checks.push(Check::fail(
+            Category::Project,
+            format!(
+                "The workspace members in `{}` ({}) don't match the workspace ({})",
+                path.user_display(),
+                join(lock.members()),
+                join(&members)
+            ),
+            "Run `uv lock` to update the lockfile",
+        ));
+        return;
+    }
+
+    // Every member should be present in the lockfile.
+    let missing = workspace
+        .packages()
+        .keys()
+        .filter(|name| {
+            !lock
+                .packages()
+                .iter()
+                .any(|package| package.name() == *name)
+        })
+        .cloned()
+        .collect::<BTreeSet<_>>();
+    if !missing.is_empty() {
+        checks.push(Check::fail(
+            Category::Project,
+            format!(
+                "`{}` is missing workspace members: {}",
+                path.user_display(),
+                join(&missing)
+            ),
+            "Run `uv lock` to update the lockfile",
+        ));
+        return;
+    }
+
+    if let Some(requires_python) = find_requires_python(workspace) {
+        if lock.requires_python().range() != requires_python.range() {
+            checks.push(Check::fail(
+                Category::Project,
+                format!(
+                    "The Python requirement in `{}` (`{}`) doesn't match the workspace (`{requires_python}`)",
+                    path.user_display(),
+                    lock.requires_python()
+                ),
+                "Run `uv lock` to update the lockfile",
+            ));
+            return;
+        }
+    }
+
+    checks.push(Check::pass(
+        Category::Project,
+        format!(
+            "`{}` is consistent with the workspace members and Python requirement",
+            path.user_display()
+        ),
+    ));
+}
+
+/// Read an environment variable, falling back to its lowercase form (as for proxy variables).
+fn env_var(var: &str) -> Option<String> {
+    std::env::var(var)
+        .or_else(|_| std::env::var(var.to_lowercase()))
+        .ok()
+        .filter(|value| !value.is_empty())
+}
+
+/// Returns `true` if both paths refer to the same directory.
+fn is_same_path(left: &Path, right: &Path) -> bool {
+    match (fs_err::canonicalize(left), fs_err::canonicalize(right)) {
+        (Ok(left), Ok(right)) => left == right,
+        _ => std::path::absolute(left).ok() == std::path::absolute(right).ok(),
+    }
+}
+
+/// Join package names into a comma-separated list.
+fn join(names: &BTreeSet<PackageName>) -> String {
+    if names.is_empty() {
+        "none".to_string()
+    } else {
+        names
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// The area of the environment or configuration to which a [`Check`] applies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+enum Category {
+    Python,
+    Environment,
+    Cache,
+    Network,
+    Index,
+    Project,
+}
+
+impl Category {
+    /// The heading under which checks in this category are displayed.
+    fn heading(self) -> &'static str {
+        match self {
+            Self::Python => "Python",
+            Self::Environment => "Virtual environment",
+            Self::Cache => "Cache",
+            Self::Network => "TLS and proxies",
+            Self::Index => "Package indexes",
+            Self::Project => "Project",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+enum Status {
+    /// The check passed.
+    Pass,
+    /// The check passed, but uv may not behave as expected.
+    Warn,
+    /// The check failed.
+    Fail,
+    /// The check doesn't apply.
+    Skip,
+}
+
+/// The outcome of a single diagnostic check.
+#[derive(Debug, Serialize)]
+struct Check {
+    category: Category,
+    status: Status,
+    message: String,
+    /// A suggested fix, for checks that didn't pass.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fix: Option<String>,
+}
+
+impl Check {
+    fn pass(category: Category, message: impl Into<String>) -> Self {
+        Self {
+            category,
+            status: Status::Pass,
+            message: message.into(),
+            fix: None,
+        }
+    }
+
+    fn skip(category: Category, message: impl Into<String>) -> Self {
+        Self {
+            category,
+            status: Status::Skip,
+            message: message.into(),
+            fix: None,
+        }
+    }
+
+    fn warn(category: Category, message: impl Into<String>, fix: impl Into<String>) -> Self {
+        Self {
+            category,
+            status: Status::Warn,
+            message: message.into(),
+            fix: Some(fix.into()),
+        }
+    }
+
+    fn fail(category: Category, message: impl Into<String>, fix: impl Into<String>) -> Self {
+        Self {
+            category,
+            status: Status::Fail,
+            message: message.into(),
+            fix: Some(fix.into()),
+        }
+    }
+}
+
+/// The outcome of all diagnostic checks.
+#[derive(Debug, Serialize)]
+struct Report {
+    checks: Vec<Check>,
+    warnings: usize,
+    errors: usize,
+}
+
+impl Report {
+    fn new(checks: Vec<Check>) -> Self {
+        let warnings = checks
+            .iter()
+            .filter(|check| check.status == Status::Warn)
+            .count();
+        let errors = checks
+            .iter()
+            .filter(|check| check.status == Status::Fail)
+            .count();
+        Self {
+            checks,
+            warnings,
+            errors,
+        }
+    }
+
+    /// Write the report as human-readable text, grouping the checks by category.
+    fn write_text(&self, printer: Printer) -> Result<()> {
+        let mut categories = Vec::new();
+        for check in &self.checks {
+            if !categories.contains(&check.category) {
+                categories.push(check.category);
+            }
+        }
+
+        for category in categories {
+            writeln!(printer.stdout(), "{}", category.heading().bold())?;
+            for check in self
+                .checks
+                .iter()
+                .filter(|check| check.category == category)
+            {
+                match check.status {
+                    Status::Pass => {
+                        writeln!(
+                            printer.stdout(),
+                            "  {} {}",
+                            "ok".green().bold(),
+                            check.message
+                        )?;
+                    }
+                    Status::Skip => {
+                        writeln!(
+                            printer.stdout(),
+                            "  {} {}",
+                            "skip".dimmed(),
+                            check.message.dimmed()
+                        )?;
+                    }
+                    Status::Warn => {
+                        writeln!(
+                            printer.stdout(),
+                            "  {} {}",
+                            "warning".yellow().bold(),
+                            check.message
+                        )?;
+                    }
+                    Status::Fail => {
+                        writeln!(
+                            printer.stdout(),
+                            "  {} {}",
+                            "error".red().bold(),
+                            check.message
+                        )?;
+                    }
+                }
+                if let Some(fix) = &check.fix {
+                    writeln!(
+                        printer.stdout(),
+                        "    {}{} {fix}",
+                        "hint".bold().cyan(),
+                        ":".bold()
+                    )?;
+                }
+            }
+        }
+
+        writeln!(printer.stdout())?;
+        if self.errors == 0 && self.warnings == 0 {
+            writeln!(printer.stdout(), "{}", "No problems found".green())?;
+        } else {
+            writeln!(
+                printer.stdout(),
+                "Found {} and {}",
+                format!(
+                    "{} error{}",
+                    self.errors,
+                    if self.errors == 1 { "" } else { "s" }
+                )
+                .bold(),
+                format!(
+                    "{} warning{}",
+                    self.warnings,
+                    if self.warnings == 1 { "" } else { "s" }
+                )
+                .bold()
+            )?;
+        }
+
+        Ok(())
+    }
+}
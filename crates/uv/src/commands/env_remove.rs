@@ -0,0 +1,202 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_cache::{rm_rf, Cache, CacheBucket};
+use uv_fs::Simplified;
+use uv_warnings::warn_user;
+use uv_workspace::{DiscoveryOptions, Workspace};
+
+use crate::commands::project::run::OVERLAY_PTH;
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Remove the project's virtual environment, along with any cached environments layered on top
+/// of it.
+pub(crate) async fn env_remove(
+    project_dir: &Path,
+    all: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let workspace = Workspace::discover(project_dir, &DiscoveryOptions::default()).await?;
+
+    // Collect the environments to remove.
+    let mut environments = vec![workspace.venv()];
+    if all {
+        for member in workspace.packages().values() {
+            let venv = member.root().join(".venv");
+            if !environments.contains(&venv) {
+                environments.push(venv);
+            }
+        }
+    }
+
+    for venv in environments {
+        // Avoid removing things that are not virtual environments.
+        match (venv.try_exists(), venv.join("pyvenv.cfg").try_exists()) {
+            (Ok(false), _) => {
+                writeln!(
+                    printer.stderr(),
+                    "No virtual environment found at: {}",
+                    venv.user_display().cyan()
+                )?;
+                continue;
+            }
+            (Ok(true), Ok(false)) => {
+                warn_user!(
+                    "Skipping {}, as it is not a virtual environment",
+                    venv.user_display().cyan()
+                );
+                continue;
+            }
+            (Err(err), _) | (_, Err(err)) => {
+                return Err(err).with_context(|| {
+                    format!(
+                        "Failed to determine if {} is a virtual environment",
+                        venv.user_display()
+                    )
+                });
+            }
+            (Ok(true), Ok(true)) => {}
+        }
+
+        // Remove any cached environments that layer on top of this environment. Otherwise, they'd
+        // refer to a `site-packages` directory that no longer exists.
+        let overlays = remove_cached_overlays(&venv, cache)?;
+
+        fs_err::remove_dir_all(&venv)?;
+        writeln!(
+            printer.stderr(),
+            "Removed virtual environment at: {}",
+            venv.user_display().cyan()
+        )?;
+
+        match overlays {
+            0 => {}
+            1 => writeln!(printer.stderr(), "Removed 1 cached environment")?,
+            n => writeln!(printer.stderr(), "Removed {n} cached environments")?,
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Remove any cached environments (e.g., from `uv run --with`) whose overlay path file refers
+/// to the given base environment, returning the number of environments removed.
+fn remove_cached_overlays(venv: &Path, cache: &Cache) -> Result<usize> {
+    let bucket = cache.bucket(CacheBucket::Environments);
+    if !bucket.exists() {
+        return Ok(0);
+    }
+
+    // The overlay refers to the base environment by its `site-packages` directory, which may be
+    // expressed relative to the environment as provided or as canonicalized by the interpreter.
+    let mut prefixes = vec![venv.to_path_buf()];
+    if let Ok(canonical) = fs_err::canonicalize(venv) {
+        if !prefixes.contains(&canonical) {
+            prefixes.push(canonical);
+        }
+    }
+
+    let mut removed = 0;
+
+    // Cached environments are stored as `{interpreter}/{resolution}`, linking to an archive.
+    for interpreter in fs_err::read_dir(&bucket)? {
+        let interpreter = interpreter?;
+        if !interpreter.file_type()?.is_dir() {
+            continue;
+        }
+        for entry in fs_err::read_dir(interpreter.path())? {
+            let entry = entry?;
+            let Ok(root) = fs_err::canonicalize(entry.path()) else {
+                continue;
+            };
+            let Some(base) = read_overlay(&root) else {
+                continue;
+            };
+            if !prefixes.iter().any(|prefix| base.starts_with(prefix)) {
+                continue;
+            }
+
+            debug!(
+                "Removing cached environment layered on {}: {}",
+                venv.user_display(),
+                root.user_display()
+            );
+            rm_rf(entry.path())?;
+            rm_rf(&root)?;
+            removed += 1;
+        }
+    }
+
+    Ok(removed)
+}
+
+/// Read the overlay path file in the `site-packages` directory of the given environment, if any,
+/// returning the base `site-packages` directory that it refers to.
+fn read_overlay(root: &Path) -> Option<PathBuf> {
+    // On Windows, `site-packages` is at `Lib/site-packages`.
+    let contents = fs_err::read_to_string(root.join("Lib").join("site-packages").join(OVERLAY_PTH))
+        .ok()
+        .or_else(|| {
+            // On Unix, `site-packages` is at `lib/pythonX.Y/site-packages`.
+            fs_err::read_dir(root.join("lib"))
+                .ok()?
+                .filter_map(Result::ok)
+                .find_map(|entry| {
+                    fs_err::read_to_string(entry.path().join("site-packages").join(OVERLAY_PTH))
+                        .ok()
+                })
+        })?;
+    parse_overlay(&contents)
+}
+
+/// Parse the base `site-packages` directory from the contents of an overlay path file, as
+/// written by `uv run` (i.e., `import site; site.addsitedir("...")`).
+fn parse_overlay(contents: &str) -> Option<PathBuf> {
+    let escaped = contents
+        .trim()
+        .strip_prefix("import site; site.addsitedir(\"")?
+        .strip_suffix("\")")?;
+
+    // Reverse the escaping applied by `escape_for_python`.
+    let mut path = String::with_capacity(escaped.len());
+    let mut chars = escaped.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            path.push(chars.next()?);
+        } else {
+            path.push(c);
+        }
+    }
+    Some(PathBuf::from(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use uv_fs::PythonExt;
+
+    use super::parse_overlay;
+
+    #[test]
+    fn parse_overlay_roundtrip() {
+        for path in [
+            "/home/ferris/project/.venv/lib/python3.12/site-packages",
+            r"C:\Users\ferris\project\.venv\Lib\site-packages",
+            r#"/home/ferris/"quoted"/.venv/lib/python3.12/site-packages"#,
+        ] {
+            let contents = format!(
+                "import site; site.addsitedir(\"{}\")",
+                path.escape_for_python()
+            );
+            assert_eq!(parse_overlay(&contents), Some(PathBuf::from(path)));
+        }
+        assert_eq!(parse_overlay("import os"), None);
+    }
+}
@@ -0,0 +1,85 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+
+use uv_distribution_types::{Index, IndexName, IndexUrl};
+use uv_fs::Simplified;
+use uv_static::EnvVars;
+use uv_warnings::warn_user;
+use uv_workspace::pyproject_mut::{add_index, set_explicit_index, IndexPosition};
+
+use crate::commands::config::{ConfigFile, ConfigScope};
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Add an index to the project or user configuration.
+#[allow(clippy::fn_params_excessive_bools)]
+pub(crate) async fn add(
+    project_dir: &Path,
+    name: IndexName,
+    url: IndexUrl,
+    explicit: bool,
+    default: bool,
+    last: bool,
+    user: bool,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let mut config = ConfigFile::read(project_dir, ConfigScope::from_args(user, false)).await?;
+
+    let mut index = Index::from_extra_index_url(url);
+    index.name = Some(name.clone());
+    index.explicit = explicit;
+    index.default = default;
+
+    let has_password = index.raw_url().password().is_some();
+    if has_password {
+        warn_user!(
+            "The URL for index `{name}` includes a password, which will be stored in plain text in `{}`; consider `uv auth login {name}` instead",
+            config.path().user_display()
+        );
+    }
+
+    let position = if last {
+        IndexPosition::Last
+    } else {
+        IndexPosition::First
+    };
+    let added = add_index(config.settings_mut()?, &index, position)?;
+    // An existing index is only explicit if `--explicit` is (still) given.
+    set_explicit_index(config.settings_mut()?, name.as_ref(), explicit)?;
+    config.write()?;
+
+    if added {
+        writeln!(
+            printer.stderr(),
+            "Added index `{}` to `{}`",
+            name.cyan(),
+            config.path().user_display().cyan()
+        )?;
+    } else {
+        writeln!(
+            printer.stderr(),
+            "Updated index `{}` in `{}`",
+            name.cyan(),
+            config.path().user_display().cyan()
+        )?;
+    }
+
+    // Remote indexes may require authentication; point the user at the supported mechanisms.
+    if !has_password && matches!(index.raw_url().scheme(), "http" | "https") {
+        let env_var = name.to_env_var();
+        writeln!(
+            printer.stderr(),
+            "{}{} If the index requires authentication, run `{}`, or set `{}` and `{}`",
+            "hint".bold().cyan(),
+            ":".bold(),
+            format!("uv auth login {name}").green(),
+            EnvVars::index_username(&env_var).green(),
+            EnvVars::index_password(&env_var).green()
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
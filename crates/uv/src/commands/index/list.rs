@@ -0,0 +1,55 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+
+use uv_fs::Simplified;
+
+use crate::commands::config::{ConfigFile, ConfigScope};
+use crate::commands::index::read_indexes;
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// List the indexes defined in the project or user configuration, in priority order.
+pub(crate) async fn list(project_dir: &Path, user: bool, printer: Printer) -> Result<ExitStatus> {
+    let config = ConfigFile::read(project_dir, ConfigScope::from_args(user, false)).await?;
+
+    // The default index is always consulted last, regardless of where it's defined.
+    let (default, mut indexes): (Vec<_>, Vec<_>) = read_indexes(&config)?
+        .into_iter()
+        .partition(|index| index.default);
+    indexes.extend(default);
+
+    if indexes.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "No indexes defined in `{}`",
+            config.path().user_display().cyan()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    // Compute the width of the name column.
+    let name_width = indexes.iter().fold(0usize, |acc, index| {
+        acc.max(index.name.as_ref().map_or(0, |name| name.as_ref().len()))
+    });
+
+    for index in indexes {
+        let name = index
+            .name
+            .as_ref()
+            .map(ToString::to_string)
+            .unwrap_or_default();
+        let mut line = format!("{name:name_width$}  {}", index.url.cyan());
+        if index.default {
+            write!(line, " {}", "(default)".dimmed())?;
+        }
+        if index.explicit {
+            write!(line, " {}", "(explicit)".dimmed())?;
+        }
+        writeln!(printer.stdout(), "{}", line.trim_end())?;
+    }
+
+    Ok(ExitStatus::Success)
+}
@@ -0,0 +1,24 @@
+use anyhow::{Context, Result};
+
+use uv_distribution_types::Index;
+use uv_fs::Simplified;
+
+use crate::commands::config::ConfigFile;
+
+pub(crate) mod add;
+pub(crate) mod list;
+pub(crate) mod remove;
+pub(crate) mod set_default;
+
+/// Return the indexes defined in the configuration file, in the order in which they're defined.
+fn read_indexes(config: &ConfigFile) -> Result<Vec<Index>> {
+    let Some(indexes) = config.settings()?.remove("index") else {
+        return Ok(Vec::new());
+    };
+    indexes.try_into::<Vec<Index>>().with_context(|| {
+        format!(
+            "Indexes in `{}` are malformed",
+            config.path().user_display()
+        )
+    })
+}
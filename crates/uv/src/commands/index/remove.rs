@@ -0,0 +1,42 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+
+use uv_fs::Simplified;
+use uv_workspace::pyproject_mut::remove_index;
+
+use crate::commands::config::{ConfigFile, ConfigScope};
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Remove an index from the project or user configuration.
+pub(crate) async fn remove(
+    project_dir: &Path,
+    index: &str,
+    user: bool,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let mut config = ConfigFile::read(project_dir, ConfigScope::from_args(user, false)).await?;
+
+    if !remove_index(config.settings_mut()?, index)? {
+        writeln!(
+            printer.stderr(),
+            "Index `{}` is not defined in `{}`",
+            index.cyan(),
+            config.path().user_display().cyan()
+        )?;
+        return Ok(ExitStatus::Failure);
+    }
+    config.write()?;
+
+    writeln!(
+        printer.stderr(),
+        "Removed index `{}` from `{}`",
+        index.cyan(),
+        config.path().user_display().cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
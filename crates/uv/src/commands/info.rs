@@ -0,0 +1,368 @@
+use std::fmt::Write;
+
+use anyhow::{bail, Result};
+use owo_colors::OwoColorize;
+use serde::Serialize;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_cli::InfoFormat;
+use uv_client::{Connectivity, OwnedArchive, RegistryClientBuilder, VersionFiles};
+use uv_distribution_types::{File, IndexCapabilities};
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_platform_tags::Tags;
+use uv_pypi_types::Metadata23;
+use uv_python::{EnvironmentPreference, PythonEnvironment, PythonRequest};
+use uv_warnings::warn_user;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+use crate::settings::ResolverSettings;
+
+/// Display information about a package from the configured indexes.
+pub(crate) async fn info(
+    package: &PackageName,
+    versions: usize,
+    python: Option<&str>,
+    format: InfoFormat,
+    settings: &ResolverSettings,
+    connectivity: Connectivity,
+    native_tls: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    // Determine the platform against which to check wheel compatibility. If no interpreter was
+    // requested explicitly, compatibility is optional.
+    let environment = match PythonEnvironment::find(
+        &python.map(PythonRequest::parse).unwrap_or_default(),
+        EnvironmentPreference::Any,
+        cache,
+    ) {
+        Ok(environment) => Some(environment),
+        Err(err) if python.is_none() => {
+            debug!("Skipping wheel compatibility checks, as no interpreter was found: {err}");
+            None
+        }
+        Err(err) => return Err(err.into()),
+    };
+    let tags = environment
+        .as_ref()
+        .map(|environment| environment.interpreter().tags())
+        .transpose()?;
+
+    // Add all authenticated sources to the cache.
+    for index in settings.index_locations.allowed_indexes() {
+        if let Some(credentials) = index.credentials() {
+            uv_auth::store_credentials(index.raw_url(), credentials);
+        } else if let Some(secrets) = index.secret_credentials() {
+            uv_auth::store_secret_credentials(secrets);
+        }
+    }
+
+    let client = RegistryClientBuilder::new(cache.clone())
+        .connectivity(connectivity)
+        .native_tls(native_tls)
+        .index_strategy(settings.index_strategy)
+        .keyring(settings.keyring_provider)
+        .allow_insecure_host(settings.allow_insecure_host.clone())
+        .index_urls(settings.index_locations.index_urls())
+        .build();
+
+    // Fetch the available versions from the first index that provides the package.
+    let capabilities = IndexCapabilities::default();
+    let response = client.simple(package, None, &capabilities).await?;
+    let Some((index, metadata)) = response.into_iter().next() else {
+        bail!("Package `{package}` was not found in the registry");
+    };
+    let mut releases = OwnedArchive::deserialize(&metadata)
+        .into_iter()
+        .map(|metadatum| (metadatum.version, metadatum.files))
+        .collect::<Vec<_>>();
+    releases.sort_by(|(a, _), (b, _)| b.cmp(a));
+
+    // Prefer the latest stable release, ignoring any that have been yanked.
+    let latest = releases
+        .iter()
+        .filter(|(_, files)| !is_yanked(files))
+        .find(|(version, _)| !version.any_prerelease())
+        .or_else(|| releases.iter().find(|(_, files)| !is_yanked(files)))
+        .or_else(|| releases.first());
+
+    // Read the metadata of the latest release from one of its wheels, preferring a compatible
+    // wheel, since source distributions would need to be built.
+    let core_metadata = match latest.and_then(|(_, files)| best_wheel(files, tags)) {
+        Some(file) => match client.wheel_core_metadata(index, file, &capabilities).await {
+            Ok(metadata) => Some(metadata),
+            Err(err) => {
+                warn_user!("Failed to read the metadata for `{}`: {err}", file.filename);
+                None
+            }
+        },
+        None => None,
+    };
+
+    let report = Report {
+        name: package.clone(),
+        index: index.redacted().to_string(),
+        latest: latest.map(|(version, _)| version.clone()),
+        summary: core_metadata
+            .as_ref()
+            .and_then(|metadata| metadata.summary.clone())
+            .filter(|summary| !summary.trim().is_empty()),
+        license: core_metadata.as_ref().and_then(license),
+        requires_python: core_metadata
+            .as_ref()
+            .and_then(|metadata| metadata.requires_python.clone()),
+        dependencies: core_metadata
+            .as_ref()
+            .map(|metadata| metadata.requires_dist.clone())
+            .unwrap_or_default(),
+        extras: core_metadata
+            .as_ref()
+            .map(|metadata| metadata.provides_extras.clone())
+            .unwrap_or_default(),
+        total_versions: releases.len(),
+        versions: releases
+            .iter()
+            .take(versions)
+            .map(|(version, files)| Release::new(version, files, tags))
+            .collect(),
+    };
+
+    match format {
+        InfoFormat::Text => report.write_text(core_metadata.is_some(), tags.is_some(), printer)?,
+        InfoFormat::Json => {
+            writeln!(
+                printer.stdout(),
+                "{}",
+                serde_json::to_string_pretty(&report)?
+            )?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Information about a package, as displayed by `uv info`.
+#[derive(Debug, Serialize)]
+struct Report {
+    name: PackageName,
+    /// The index from which the package information was retrieved.
+    index: String,
+    /// The latest version of the package, preferring stable releases that haven't been yanked.
+    latest: Option<Version>,
+    summary: Option<String>,
+    license: Option<String>,
+    requires_python: Option<String>,
+    dependencies: Vec<String>,
+    extras: Vec<String>,
+    /// The total number of versions available, including those omitted from `versions`.
+    total_versions: usize,
+    versions: Vec<Release>,
+}
+
+/// A single version of a package, and the distributions it provides.
+#[derive(Debug, Serialize)]
+struct Release {
+    version: Version,
+    /// Whether every distribution for the version has been yanked.
+    yanked: bool,
+    /// The number of wheels available for the version.
+    wheels: usize,
+    /// Whether a source distribution is available for the version.
+    sdist: bool,
+    /// Whether any of the wheels is compatible with the current platform, if known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    compatible_wheel: Option<bool>,
+}
+
+impl Release {
+    fn new(version: &Version, files: &VersionFiles, tags: Option<&Tags>) -> Self {
+        Self {
+            version: version.clone(),
+            yanked: is_yanked(files),
+            wheels: files.wheels.len(),
+            sdist: !files.source_dists.is_empty(),
+            compatible_wheel: tags.map(|tags| {
+                files
+                    .wheels
+                    .iter()
+                    .any(|wheel| wheel.name.is_compatible(tags))
+            }),
+        }
+    }
+
+    /// Describe the distributions that are available for the version.
+    fn availability(&self) -> &'static str {
+        match self.compatible_wheel {
+            Some(true) => "compatible wheel",
+            Some(false) if self.sdist => "build from source",
+            Some(false) => "no compatible distributions",
+            None if self.wheels > 0 => "wheels",
+            None if self.sdist => "source distribution only",
+            None => "no distributions",
+        }
+    }
+}
+
+impl Report {
+    /// Write the report as human-readable text.
+    fn write_text(&self, has_metadata: bool, has_tags: bool, printer: Printer) -> Result<()> {
+        match &self.latest {
+            Some(latest) => writeln!(printer.stdout(), "{} {}", self.name.bold(), latest.bold())?,
+            None => writeln!(printer.stdout(), "{}", self.name.bold())?,
+        }
+        if let Some(summary) = &self.summary {
+            writeln!(printer.stdout(), "{}", summary.trim())?;
+        }
+        writeln!(printer.stdout())?;
+
+        if has_metadata {
+            if let Some(license) = &self.license {
+                writeln!(printer.stdout(), "{} {license}", "License:".bold())?;
+            }
+            if let Some(requires_python) = &self.requires_python {
+                writeln!(
+                    printer.stdout(),
+                    "{} {requires_python}",
+                    "Requires-Python:".bold()
+                )?;
+            }
+            if self.dependencies.is_empty() {
+                writeln!(printer.stdout(), "{} none", "Dependencies:".bold())?;
+            } else {
+                writeln!(printer.stdout(), "{}", "Dependencies:".bold())?;
+                for dependency in &self.dependencies {
+                    writeln!(printer.stdout(), "  {dependency}")?;
+                }
+            }
+            if !self.extras.is_empty() {
+                writeln!(
+                    printer.stdout(),
+                    "{} {}",
+                    "Extras:".bold(),
+                    self.extras.join(", ")
+                )?;
+            }
+        } else if let Some(latest) = &self.latest {
+            writeln!(
+                printer.stdout(),
+                "{}",
+                format!(
+                    "No metadata is available, as {} {latest} doesn't provide a readable wheel",
+                    self.name
+                )
+                .dimmed()
+            )?;
+        }
+        writeln!(printer.stdout())?;
+
+        if has_tags {
+            writeln!(printer.stdout(), "{}", "Versions:".bold())?;
+        } else {
+            writeln!(
+                printer.stdout(),
+                "{} {}",
+                "Versions:".bold(),
+                "(no Python interpreter found; wheel compatibility is unknown)".dimmed()
+            )?;
+        }
+        let width = self
+            .versions
+            .iter()
+            .map(|release| release.version.to_string().len())
+            .max()
+            .unwrap_or(0);
+        for release in &self.versions {
+            let version = release.version.to_string();
+            if release.yanked {
+                writeln!(
+                    printer.stdout(),
+                    "  {version:width$}  {} {}",
+                    release.availability(),
+                    "(yanked)".yellow()
+                )?;
+            } else {
+                writeln!(
+                    printer.stdout(),
+                    "  {version:width$}  {}",
+                    release.availability()
+                )?;
+            }
+        }
+        if self.total_versions > self.versions.len() {
+            writeln!(
+                printer.stdout(),
+                "{}",
+                format!(
+                    "  ...and {} more (use `--versions` to show more)",
+                    self.total_versions - self.versions.len()
+                )
+                .dimmed()
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns `true` if every distribution for a version has been yanked.
+fn is_yanked(files: &VersionFiles) -> bool {
+    let mut files = files
+        .wheels
+        .iter()
+        .map(|wheel| &wheel.file)
+        .chain(files.source_dists.iter().map(|sdist| &sdist.file))
+        .peekable();
+    files.peek().is_some()
+        && files.all(|file| {
+            file.yanked
+                .as_ref()
+                .is_some_and(|yanked| yanked.is_yanked())
+        })
+}
+
+/// Select the wheel from which to read a version's metadata, preferring one that's compatible
+/// with the current platform.
+fn best_wheel<'a>(files: &'a VersionFiles, tags: Option<&Tags>) -> Option<&'a File> {
+    tags.and_then(|tags| {
+        files
+            .wheels
+            .iter()
+            .find(|wheel| wheel.name.is_compatible(tags))
+    })
+    .or_else(|| files.wheels.first())
+    .map(|wheel| &wheel.file)
+}
+
+/// Determine the license of a package from its metadata, preferring an SPDX expression, then the
+/// first line of the free-form license text, then the license classifiers.
+pub(crate) fn license(metadata: &Metadata23) -> Option<String> {
+    metadata
+        .license_expression
+        .clone()
+        .or_else(|| {
+            metadata
+                .license
+                .as_deref()
+                .and_then(|license| license.lines().next())
+                .map(str::trim)
+                .filter(|license| !license.is_empty())
+                .map(ToString::to_string)
+        })
+        .or_else(|| {
+            metadata
+                .classifiers
+                .iter()
+                .filter_map(|classifier| classifier.strip_prefix("License :: "))
+                .map(|classifier| {
+                    classifier
+                        .rsplit(" :: ")
+                        .next()
+                        .unwrap_or(classifier)
+                        .to_string()
+                })
+                .next()
+        })
+}
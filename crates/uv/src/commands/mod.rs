@@ -1,19 +1,36 @@
 use anstream::AutoStream;
 use anyhow::Context;
 use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
 use std::borrow::Cow;
 use std::io::stdout;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 use std::{fmt::Display, fmt::Write, process::ExitCode};
 
+pub(crate) use auth::list::list as auth_list;
+pub(crate) use auth::login::login as auth_login;
+pub(crate) use auth::logout::logout as auth_logout;
+pub(crate) use auth::token::token as auth_token;
 pub(crate) use build_frontend::build_frontend;
 pub(crate) use cache_clean::cache_clean;
 pub(crate) use cache_dir::cache_dir;
 pub(crate) use cache_prune::cache_prune;
+pub(crate) use cache_verify::cache_verify;
+pub(crate) use config::get::get as config_get;
+pub(crate) use config::list::list as config_list;
+pub(crate) use config::set::set as config_set;
+pub(crate) use config::ConfigScope;
+pub(crate) use doctor::doctor;
+pub(crate) use env_remove::env_remove;
 pub(crate) use help::help;
+pub(crate) use index::add::add as index_add;
+pub(crate) use index::list::list as index_list;
+pub(crate) use index::remove::remove as index_remove;
+pub(crate) use index::set_default::set_default as index_set_default;
+pub(crate) use info::info;
 pub(crate) use pip::check::pip_check;
-pub(crate) use pip::compile::pip_compile;
+pub(crate) use pip::compile::{pip_compile, CompileTarget};
 pub(crate) use pip::freeze::pip_freeze;
 pub(crate) use pip::install::pip_install;
 pub(crate) use pip::list::pip_list;
@@ -21,14 +38,21 @@ pub(crate) use pip::show::pip_show;
 pub(crate) use pip::sync::pip_sync;
 pub(crate) use pip::tree::pip_tree;
 pub(crate) use pip::uninstall::pip_uninstall;
+pub(crate) use pip::verify::pip_verify;
 pub(crate) use project::add::add;
+pub(crate) use project::bundle::bundle;
+pub(crate) use project::deploy::deploy;
 pub(crate) use project::export::export;
 pub(crate) use project::init::{init, InitKind, InitProjectKind};
+pub(crate) use project::licenses::licenses;
 pub(crate) use project::lock::lock;
+pub(crate) use project::outdated::outdated;
 pub(crate) use project::remove::remove;
 pub(crate) use project::run::{run, RunCommand};
 pub(crate) use project::sync::sync;
 pub(crate) use project::tree::tree;
+pub(crate) use project::vendor::vendor;
+pub(crate) use project::version::project_version;
 pub(crate) use publish::publish;
 pub(crate) use python::dir::dir as python_dir;
 pub(crate) use python::find::find as python_find;
@@ -36,47 +60,70 @@ pub(crate) use python::install::install as python_install;
 pub(crate) use python::list::list as python_list;
 pub(crate) use python::pin::pin as python_pin;
 pub(crate) use python::uninstall::uninstall as python_uninstall;
+pub(crate) use search::search;
+pub(crate) use self_uninstall::self_uninstall;
 #[cfg(feature = "self-update")]
 pub(crate) use self_update::self_update;
+pub(crate) use stats::stats;
+pub(crate) use tool::alias::add as tool_alias_add;
+pub(crate) use tool::alias::list as tool_alias_list;
+pub(crate) use tool::alias::remove as tool_alias_remove;
 pub(crate) use tool::dir::dir as tool_dir;
 pub(crate) use tool::install::install as tool_install;
 pub(crate) use tool::list::list as tool_list;
 pub(crate) use tool::run::run as tool_run;
 pub(crate) use tool::run::ToolRunCommand;
+pub(crate) use tool::sync::sync as tool_sync;
 pub(crate) use tool::uninstall::uninstall as tool_uninstall;
 pub(crate) use tool::update_shell::update_shell as tool_update_shell;
 pub(crate) use tool::upgrade::upgrade as tool_upgrade;
 use uv_cache::Cache;
+use uv_configuration::BytecodeOptions;
 use uv_distribution_types::{IndexCapabilities, InstalledMetadata};
 use uv_fs::Simplified;
 use uv_git::GitResolver;
-use uv_installer::compile_tree;
+use uv_hg::HgResolver;
+use uv_installer::{compile_tree, SitePackages};
 use uv_normalize::PackageName;
 use uv_python::PythonEnvironment;
 use uv_resolver::InMemoryIndex;
 use uv_types::InFlight;
+use uv_warnings::warn_user;
 pub(crate) use venv::venv;
 pub(crate) use version::version;
+pub(crate) use workspace::graph::graph as workspace_graph;
+pub(crate) use workspace::list::list as workspace_list;
 
 use crate::printer::Printer;
 
+mod auth;
 pub(crate) mod build_backend;
 mod build_frontend;
 mod cache_clean;
 mod cache_dir;
 mod cache_prune;
+mod cache_verify;
+mod config;
 mod diagnostics;
+mod doctor;
+mod env_remove;
 mod help;
+mod index;
+mod info;
 pub(crate) mod pip;
 mod project;
 mod publish;
 mod python;
 pub(crate) mod reporters;
+mod search;
+mod self_uninstall;
 #[cfg(feature = "self-update")]
 mod self_update;
+mod stats;
 mod tool;
 mod venv;
 mod version;
+mod workspace;
 
 #[derive(Copy, Clone)]
 pub(crate) enum ExitStatus {
@@ -141,6 +188,8 @@ pub(super) struct DryRunEvent<T: Display> {
     name: PackageName,
     version: T,
     kind: ChangeEventKind,
+    /// The reason for the change, if it isn't self-evident (e.g., for removals).
+    reason: Option<String>,
 }
 
 /// Compile all Python source files in site-packages to bytecode, to speed up the
@@ -149,20 +198,29 @@ pub(super) struct DryRunEvent<T: Display> {
 /// See the `--compile` option on `pip sync` and `pip install`.
 pub(super) async fn compile_bytecode(
     venv: &PythonEnvironment,
+    options: &BytecodeOptions,
     cache: &Cache,
     printer: Printer,
 ) -> anyhow::Result<()> {
     let start = std::time::Instant::now();
+    let exclude = excluded_source_files(venv, options.no_compile_package())?;
     let mut files = 0;
     for site_packages in venv.site_packages() {
-        files += compile_tree(&site_packages, venv.python_executable(), cache.root())
-            .await
-            .with_context(|| {
-                format!(
-                    "Failed to bytecode-compile Python file in: {}",
-                    site_packages.user_display()
-                )
-            })?;
+        files += compile_tree(
+            &site_packages,
+            venv.python_executable(),
+            cache.root(),
+            options.optimization(),
+            options.workers(),
+            &exclude,
+        )
+        .await
+        .with_context(|| {
+            format!(
+                "Failed to bytecode-compile Python file in: {}",
+                site_packages.user_display()
+            )
+        })?;
     }
     let s = if files == 1 { "" } else { "s" };
     writeln!(
@@ -178,6 +236,49 @@ pub(super) async fn compile_bytecode(
     Ok(())
 }
 
+/// Collect the Python source files installed by the given packages, as listed in their `RECORD`
+/// files, to exclude them from bytecode compilation.
+fn excluded_source_files(
+    venv: &PythonEnvironment,
+    packages: &[PackageName],
+) -> anyhow::Result<FxHashSet<PathBuf>> {
+    let mut exclude = FxHashSet::default();
+    if packages.is_empty() {
+        return Ok(exclude);
+    }
+
+    let site_packages = SitePackages::from_environment(venv)?;
+    for name in packages {
+        for dist in site_packages.get_packages(name) {
+            let Some(root) = dist.path().parent() else {
+                continue;
+            };
+            let record_path = dist.path().join("RECORD");
+            let mut record_file = match fs_err::File::open(&record_path) {
+                Ok(record_file) => record_file,
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                    warn_user!(
+                        "Failed to exclude `{name}` from bytecode compilation due to missing `RECORD` file at: {}",
+                        record_path.user_display()
+                    );
+                    continue;
+                }
+                Err(err) => return Err(err.into()),
+            };
+            for entry in uv_install_wheel::read_record_file(&mut record_file)? {
+                if Path::new(&entry.path)
+                    .extension()
+                    .is_some_and(|ext| ext == "py")
+                {
+                    exclude.insert(root.join(&entry.path));
+                }
+            }
+        }
+    }
+
+    Ok(exclude)
+}
+
 /// Formats a number of bytes into a human readable SI-prefixed size.
 ///
 /// Returns a tuple of `(quantity, units)`.
@@ -199,6 +300,8 @@ pub(super) fn human_readable_bytes(bytes: u64) -> (f32, &'static str) {
 pub(crate) struct SharedState {
     /// The resolved Git references.
     pub(crate) git: GitResolver,
+    /// The resolved Mercurial references.
+    pub(crate) hg: HgResolver,
     /// The fetched package versions and metadata.
     pub(crate) index: InMemoryIndex,
     /// The downloaded distributions.
@@ -1,7 +1,7 @@
 use std::env;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, Context, Result};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
 use rustc_hash::FxHashSet;
@@ -10,8 +10,8 @@ use tracing::debug;
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    BuildOptions, Concurrency, ConfigSettings, Constraints, ExtrasSpecification, IndexStrategy,
-    LowerBound, NoBinary, NoBuild, Reinstall, SourceStrategy, TrustedHost, Upgrade,
+    BuildContainers, BuildOptions, Concurrency, ConfigSettings, Constraints, ExtrasSpecification,
+    IndexStrategy, LowerBound, NoBinary, NoBuild, Reinstall, SourceStrategy, TrustedHost, Upgrade,
 };
 use uv_configuration::{KeyringProviderType, TargetTriple};
 use uv_dispatch::BuildDispatch;
@@ -21,9 +21,12 @@ use uv_distribution_types::{
 };
 use uv_fs::Simplified;
 use uv_git::GitResolver;
+use uv_hg::HgResolver;
 use uv_install_wheel::linker::LinkMode;
 use uv_normalize::PackageName;
-use uv_pypi_types::{Requirement, SupportedEnvironments};
+use uv_pep440::{VersionSpecifier, VersionSpecifiers};
+use uv_pep508::{MarkerTree, RequirementOrigin};
+use uv_pypi_types::{Requirement, RequirementSource, SupportedEnvironments};
 use uv_python::{
     EnvironmentPreference, PythonEnvironment, PythonInstallation, PythonPreference, PythonRequest,
     PythonVersion, VersionRequest,
@@ -33,11 +36,12 @@ use uv_requirements::{
 };
 use uv_resolver::{
     AnnotationStyle, DependencyMode, DisplayResolutionGraph, ExcludeNewer, FlatIndex,
-    InMemoryIndex, OptionsBuilder, PrereleaseMode, PythonRequirement, RequiresPython,
-    ResolutionMode, ResolverMarkers,
+    InMemoryIndex, Lock, OptionsBuilder, PrereleaseMode, PythonRequirement, RequiresPython,
+    ResolutionBudget, ResolutionMode, ResolverMarkers, YankedMode, DIFFABLE_VERSION, VERSION,
 };
 use uv_types::{BuildIsolation, EmptyInstalledPackages, HashStrategy, InFlight};
 use uv_warnings::warn_user;
+use uv_workspace::Workspace;
 
 use crate::commands::pip::loggers::DefaultResolveLogger;
 use crate::commands::pip::{operations, resolution_environment};
@@ -53,11 +57,16 @@ pub(crate) async fn pip_compile(
     build_constraints: &[RequirementsSource],
     constraints_from_workspace: Vec<Requirement>,
     overrides_from_workspace: Vec<Requirement>,
+    constraint_from_lock: Option<&Path>,
     environments: SupportedEnvironments,
     extras: ExtrasSpecification,
-    output_file: Option<&Path>,
+    targets: &[CompileTarget],
     resolution_mode: ResolutionMode,
     prerelease_mode: PrereleaseMode,
+    yanked_mode: YankedMode,
+    allow_yanked: Vec<Requirement>,
+    prefer_local_version: Option<String>,
+    resolution_budget: ResolutionBudget,
     dependency_mode: DependencyMode,
     upgrade: Upgrade,
     generate_hashes: bool,
@@ -78,15 +87,15 @@ pub(crate) async fn pip_compile(
     keyring_provider: KeyringProviderType,
     allow_insecure_host: Vec<TrustedHost>,
     config_settings: ConfigSettings,
+    build_containers: BuildContainers,
     connectivity: Connectivity,
     no_build_isolation: bool,
     no_build_isolation_package: Vec<PackageName>,
     build_options: BuildOptions,
-    python_version: Option<PythonVersion>,
-    python_platform: Option<TargetTriple>,
     universal: bool,
     exclude_newer: Option<ExcludeNewer>,
     sources: SourceStrategy,
+    variants: Vec<String>,
     annotation_style: AnnotationStyle,
     link_mode: LinkMode,
     python: Option<String>,
@@ -110,7 +119,8 @@ pub(crate) async fn pip_compile(
         .connectivity(connectivity)
         .native_tls(native_tls)
         .keyring(keyring_provider)
-        .allow_insecure_host(allow_insecure_host);
+        .allow_insecure_host(allow_insecure_host)
+        .indexes(index_locations.allowed_indexes());
 
     // Read all requirements from the provided sources.
     let RequirementsSpecification {
@@ -131,15 +141,24 @@ pub(crate) async fn pip_compile(
         constraints,
         overrides,
         &client_builder,
+        &cache,
     )
     .await?;
 
-    let constraints = constraints
+    // Read the locked versions from the lockfile, if provided.
+    let constraints_from_lock = if let Some(lock_path) = constraint_from_lock {
+        read_lock_constraints(lock_path).await?
+    } else {
+        Vec::new()
+    };
+
+    let constraints: Vec<NameRequirementSpecification> = constraints
         .iter()
         .cloned()
         .chain(
             constraints_from_workspace
                 .into_iter()
+                .chain(constraints_from_lock)
                 .map(NameRequirementSpecification::from),
         )
         .collect();
@@ -156,7 +175,7 @@ pub(crate) async fn pip_compile(
 
     // Read build constraints.
     let build_constraints =
-        operations::read_constraints(build_constraints, &client_builder).await?;
+        operations::read_constraints(build_constraints, &client_builder, &cache).await?;
 
     // If all the metadata could be statically resolved, validate that every extra was used. If we
     // need to resolve metadata via PEP 517, we don't know which extras are used until much later.
@@ -178,7 +197,11 @@ pub(crate) async fn pip_compile(
         }
     }
 
-    // Find an interpreter to use for building distributions
+    // Find an interpreter to use for building distributions. If multiple targets were requested,
+    // the first target's Python version is used to select the interpreter.
+    let python_version = targets
+        .first()
+        .and_then(|target| target.python_version.as_ref());
     let environment_preference = EnvironmentPreference::from_system_flag(system, false);
     let interpreter = if let Some(python) = python.as_ref() {
         let request = PythonRequest::parse(python);
@@ -186,7 +209,7 @@ pub(crate) async fn pip_compile(
     } else {
         // TODO(zanieb): The split here hints at a problem with the abstraction; we should be able to use
         // `PythonInstallation::find(...)` here.
-        let request = if let Some(version) = python_version.as_ref() {
+        let request = if let Some(version) = python_version {
             // TODO(zanieb): We should consolidate `VersionRequest` and `PythonVersion`
             PythonRequest::Version(VersionRequest::from(version))
         } else {
@@ -202,7 +225,11 @@ pub(crate) async fn pip_compile(
         interpreter.sys_executable().user_display().cyan()
     );
 
-    if let Some(python_version) = python_version.as_ref() {
+    for python_version in targets
+        .iter()
+        .filter_map(|target| target.python_version.as_ref())
+        .unique_by(ToString::to_string)
+    {
         // If the requested version does not match the version we're using warn the user
         // _unless_ they have not specified a patch version and that is the only difference
         // _or_ if builds are disabled
@@ -225,50 +252,24 @@ pub(crate) async fn pip_compile(
     // Create a shared in-memory index.
     let source_index = InMemoryIndex::default();
 
-    // If we're resolving against a different Python version, use a separate index. Source
-    // distributions will be built against the installed version, and so the index may contain
-    // different package priorities than in the top-level resolution.
-    let top_level_index = if python_version.is_some() {
-        InMemoryIndex::default()
-    } else {
-        source_index.clone()
-    };
-
-    // Determine the Python requirement, if the user requested a specific version.
-    let python_requirement = if universal {
-        let requires_python = RequiresPython::greater_than_equal_version(
-            if let Some(python_version) = python_version.as_ref() {
-                &python_version.version
-            } else {
-                interpreter.python_version()
-            },
-        );
-        PythonRequirement::from_requires_python(&interpreter, requires_python)
-    } else if let Some(python_version) = python_version.as_ref() {
-        PythonRequirement::from_python_version(&interpreter, python_version)
-    } else {
-        PythonRequirement::from_interpreter(&interpreter)
-    };
-
-    // Determine the environment for the resolution.
-    let (tags, markers) = if universal {
-        (
-            None,
-            ResolverMarkers::universal(environments.into_markers()),
-        )
-    } else {
-        let (tags, markers) =
-            resolution_environment(python_version, python_platform, &interpreter)?;
-        (Some(tags), ResolverMarkers::specific_environment(markers))
-    };
-
     // Generate, but don't enforce hashes for the requirements.
     let hasher = if generate_hashes {
-        HashStrategy::Generate
+        HashStrategy::generate()
     } else {
         HashStrategy::None
     };
 
+    // Read the `tool.uv.sources` of the source trees, to validate the requested variants and
+    // the hashes declared for URL sources (before building).
+    let workspaces = operations::source_tree_workspaces(&source_trees, sources).await;
+    operations::validate_variants(&workspaces, &variants)?;
+    let hasher = hasher.with_url_hashes(
+        workspaces
+            .iter()
+            .flat_map(Workspace::url_source_hashes)
+            .map(|(_, url, hash)| (url, hash)),
+    );
+
     // Ignore development dependencies.
     let dev = Vec::default();
 
@@ -292,6 +293,8 @@ pub(crate) async fn pip_compile(
     for index in index_locations.allowed_indexes() {
         if let Some(credentials) = index.credentials() {
             uv_auth::store_credentials(index.raw_url(), credentials);
+        } else if let Some(secrets) = index.secret_credentials() {
+            uv_auth::store_secret_credentials(secrets);
         }
     }
 
@@ -304,21 +307,20 @@ pub(crate) async fn pip_compile(
         .platform(interpreter.platform())
         .build();
 
-    // Read the lockfile, if present.
-    let preferences = read_requirements_txt(output_file, &upgrade).await?;
     let git = GitResolver::default();
+    let hg = HgResolver::default();
     let capabilities = IndexCapabilities::default();
 
     // Combine the `--no-binary` and `--no-build` flags from the requirements files.
     let build_options = build_options.combine(no_binary, no_build);
 
-    // Resolve the flat indexes from `--find-links`.
-    let flat_index = {
-        let client = FlatIndexClient::new(&client, &cache);
-        let entries = client
+    // Fetch the flat indexes from `--find-links`, once for all targets.
+    let flat_index_entries = {
+        let client = FlatIndexClient::new(&client, &cache)
+            .with_recursive(index_locations.find_links_recursive());
+        client
             .fetch(index_locations.flat_indexes().map(Index::url))
-            .await?;
-        FlatIndex::from_entries(entries, tags.as_deref(), &hasher, &build_options)
+            .await?
     };
 
     // Track in-flight downloads, builds, etc., across resolutions.
@@ -344,223 +346,470 @@ pub(crate) async fn pip_compile(
             .map(|constraint| constraint.requirement.clone()),
     );
 
-    let build_dispatch = BuildDispatch::new(
-        &client,
-        &cache,
-        build_constraints,
-        &interpreter,
-        &index_locations,
-        &flat_index,
-        &dependency_metadata,
-        &source_index,
-        &git,
-        &capabilities,
-        &in_flight,
-        index_strategy,
-        &config_settings,
-        build_isolation,
-        link_mode,
-        &build_options,
-        &build_hashes,
-        exclude_newer,
-        LowerBound::Warn,
-        sources,
-        concurrency,
-    );
-
     let options = OptionsBuilder::new()
         .resolution_mode(resolution_mode)
         .prerelease_mode(prerelease_mode)
+        .yanked_mode(yanked_mode)
         .dependency_mode(dependency_mode)
         .exclude_newer(exclude_newer)
         .index_strategy(index_strategy)
+        .budget(resolution_budget)
         .build();
 
-    // Resolve the requirements.
-    let resolution = match operations::resolve(
-        requirements,
-        constraints,
-        overrides,
-        dev,
-        source_trees,
-        project,
-        None,
-        &extras,
-        preferences,
-        EmptyInstalledPackages,
-        &hasher,
-        &Reinstall::None,
-        &upgrade,
-        tags.as_deref(),
-        markers.clone(),
-        python_requirement,
-        &client,
-        &flat_index,
-        &top_level_index,
-        &build_dispatch,
-        concurrency,
-        options,
-        Box::new(DefaultResolveLogger),
-        printer,
-    )
-    .await
-    {
-        Ok(resolution) => resolution,
-        Err(operations::Error::Resolve(uv_resolver::ResolveError::NoSolution(err))) => {
-            diagnostics::no_solution(&err);
-            return Ok(ExitStatus::Failure);
-        }
-        Err(operations::Error::Resolve(uv_resolver::ResolveError::FetchAndBuild(dist, err))) => {
-            diagnostics::fetch_and_build(dist, err);
-            return Ok(ExitStatus::Failure);
-        }
-        Err(operations::Error::Resolve(uv_resolver::ResolveError::Build(dist, err))) => {
-            diagnostics::build(dist, err);
-            return Ok(ExitStatus::Failure);
+    for target in targets {
+        let python_version = target.python_version.as_ref();
+        let output_file = target.output_file.as_deref();
+
+        if targets.len() > 1 {
+            debug!("Resolving for target: {}", target.describe());
         }
-        Err(err) => return Err(err.into()),
-    };
 
-    // Write the resolved dependencies to the output channel.
-    let mut writer = OutputWriter::new(!quiet || output_file.is_none(), output_file);
+        // If we're resolving against a different Python version, use a separate index. Source
+        // distributions will be built against the installed version, and so the index may contain
+        // different package priorities than in the top-level resolution. Similarly, each target
+        // needs its own package priorities, since they depend on the target's tags. Distribution
+        // metadata doesn't depend on the target, so it's shared across all resolutions.
+        let top_level_index = if python_version.is_some() || targets.len() > 1 {
+            source_index.with_shared_distributions()
+        } else {
+            source_index.clone()
+        };
 
-    if include_header {
-        writeln!(
-            writer,
-            "{}",
-            "# This file was autogenerated by uv via the following command:".green()
-        )?;
-        writeln!(
-            writer,
-            "{}",
-            format!(
-                "#    {}",
-                cmd(
-                    include_index_url,
-                    include_find_links,
-                    custom_compile_command
-                )
+        // Determine the Python requirement, if the user requested a specific version.
+        let python_requirement = if universal {
+            let requires_python = RequiresPython::greater_than_equal_version(
+                if let Some(python_version) = python_version {
+                    &python_version.version
+                } else {
+                    interpreter.python_version()
+                },
+            );
+            PythonRequirement::from_requires_python(&interpreter, requires_python)
+        } else if let Some(python_version) = python_version {
+            PythonRequirement::from_python_version(&interpreter, python_version)
+        } else {
+            PythonRequirement::from_interpreter(&interpreter)
+        };
+
+        // Determine the environment for the resolution.
+        let (tags, markers) = if universal {
+            (
+                None,
+                ResolverMarkers::universal(environments.clone().into_markers()),
             )
-            .green()
-        )?;
-    }
+        } else {
+            let (tags, markers) = resolution_environment(
+                target.python_version.clone(),
+                target.python_platform,
+                &interpreter,
+            )?;
+            (Some(tags), ResolverMarkers::specific_environment(markers))
+        };
 
-    if include_marker_expression {
-        if let ResolverMarkers::SpecificEnvironment(markers) = &markers {
-            let relevant_markers = resolution.marker_tree(&top_level_index, markers)?;
-            if let Some(relevant_markers) = relevant_markers.contents() {
-                writeln!(
-                    writer,
-                    "{}",
-                    "# Pinned dependencies known to be valid for:".green()
-                )?;
-                writeln!(writer, "{}", format!("#    {relevant_markers}").green())?;
-            }
-        }
-    }
+        // Read the lockfile, if present.
+        let preferences = read_requirements_txt(output_file, &upgrade).await?;
 
-    let mut wrote_preamble = false;
+        // Resolve the flat indexes from `--find-links`.
+        let flat_index = FlatIndex::from_entries(
+            flat_index_entries.clone(),
+            tags.as_deref(),
+            &hasher,
+            &build_options,
+        );
 
-    // If necessary, include the `--index-url` and `--extra-index-url` locations.
-    if include_index_url {
-        if let Some(index) = index_locations.default_index() {
-            writeln!(writer, "--index-url {}", index.url().verbatim())?;
-            wrote_preamble = true;
-        }
-        let mut seen = FxHashSet::default();
-        for extra_index in index_locations.implicit_indexes() {
-            if seen.insert(extra_index.url()) {
-                writeln!(writer, "--extra-index-url {}", extra_index.url().verbatim())?;
-                wrote_preamble = true;
+        let build_dispatch = BuildDispatch::new(
+            &client,
+            &cache,
+            build_constraints.clone(),
+            &interpreter,
+            &index_locations,
+            &flat_index,
+            &dependency_metadata,
+            &source_index,
+            &git,
+            &hg,
+            &capabilities,
+            &in_flight,
+            index_strategy,
+            &config_settings,
+            &build_containers,
+            build_isolation,
+            link_mode,
+            &build_options,
+            &build_hashes,
+            exclude_newer,
+            LowerBound::Warn,
+            sources,
+            concurrency,
+        )
+        .with_variants(&variants);
+
+        // Resolve the requirements.
+        let resolution = match operations::resolve(
+            requirements.clone(),
+            constraints.clone(),
+            overrides.clone(),
+            dev.clone(),
+            source_trees.clone(),
+            project.clone(),
+            None,
+            &extras,
+            preferences,
+            EmptyInstalledPackages,
+            &hasher,
+            &Reinstall::None,
+            &upgrade,
+            &allow_yanked,
+            prefer_local_version.as_deref(),
+            tags.as_deref(),
+            markers.clone(),
+            python_requirement,
+            &client,
+            &flat_index,
+            &top_level_index,
+            &build_dispatch,
+            concurrency,
+            options,
+            Box::new(DefaultResolveLogger),
+            printer,
+        )
+        .await
+        {
+            Ok(resolution) => resolution,
+            Err(operations::Error::Resolve(uv_resolver::ResolveError::NoSolution(err))) => {
+                diagnostics::no_solution(&err);
+                return Ok(ExitStatus::Failure);
+            }
+            Err(operations::Error::Resolve(uv_resolver::ResolveError::FetchAndBuild(
+                dist,
+                err,
+            ))) => {
+                diagnostics::fetch_and_build(dist, err);
+                return Ok(ExitStatus::Failure);
+            }
+            Err(operations::Error::Resolve(uv_resolver::ResolveError::Build(dist, err))) => {
+                diagnostics::build(dist, err);
+                return Ok(ExitStatus::Failure);
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        // Write the resolved dependencies to the output channel.
+        let mut writer = OutputWriter::new(!quiet || output_file.is_none(), output_file);
+
+        if include_header {
+            writeln!(
+                writer,
+                "{}",
+                "# This file was autogenerated by uv via the following command:".green()
+            )?;
+            writeln!(
+                writer,
+                "{}",
+                format!(
+                    "#    {}",
+                    cmd(
+                        include_index_url,
+                        include_find_links,
+                        custom_compile_command.clone()
+                    )
+                )
+                .green()
+            )?;
+            if targets.len() > 1 {
+                writeln!(writer, "{}", "# Resolved for the following target:".green())?;
+                writeln!(writer, "{}", format!("#    {}", target.describe()).green())?;
             }
         }
-    }
 
-    // If necessary, include the `--find-links` locations.
-    if include_find_links {
-        for flat_index in index_locations.flat_indexes() {
-            writeln!(writer, "--find-links {}", flat_index.url().verbatim())?;
-            wrote_preamble = true;
+        if include_marker_expression {
+            if let ResolverMarkers::SpecificEnvironment(markers) = &markers {
+                let relevant_markers = resolution.marker_tree(&top_level_index, markers)?;
+                if let Some(relevant_markers) = relevant_markers.contents() {
+                    writeln!(
+                        writer,
+                        "{}",
+                        "# Pinned dependencies known to be valid for:".green()
+                    )?;
+                    writeln!(writer, "{}", format!("#    {relevant_markers}").green())?;
+                }
+            }
         }
-    }
 
-    // If necessary, include the `--no-binary` and `--only-binary` options.
-    if include_build_options {
-        match build_options.no_binary() {
-            NoBinary::None => {}
-            NoBinary::All => {
-                writeln!(writer, "--no-binary :all:")?;
+        let mut wrote_preamble = false;
+
+        // If necessary, include the `--index-url` and `--extra-index-url` locations.
+        if include_index_url {
+            if let Some(index) = index_locations.default_index() {
+                writeln!(writer, "--index-url {}", index.url().verbatim())?;
                 wrote_preamble = true;
             }
-            NoBinary::Packages(packages) => {
-                for package in packages {
-                    writeln!(writer, "--no-binary {package}")?;
+            let mut seen = FxHashSet::default();
+            for extra_index in index_locations.implicit_indexes() {
+                if seen.insert(extra_index.url()) {
+                    writeln!(writer, "--extra-index-url {}", extra_index.url().verbatim())?;
                     wrote_preamble = true;
                 }
             }
         }
-        match build_options.no_build() {
-            NoBuild::None => {}
-            NoBuild::All => {
-                writeln!(writer, "--only-binary :all:")?;
+
+        // If necessary, include the `--find-links` locations.
+        if include_find_links {
+            for flat_index in index_locations.flat_indexes() {
+                writeln!(writer, "--find-links {}", flat_index.url().verbatim())?;
                 wrote_preamble = true;
             }
-            NoBuild::Packages(packages) => {
-                for package in packages {
-                    writeln!(writer, "--only-binary {package}")?;
+        }
+
+        // If necessary, include the `--no-binary` and `--only-binary` options.
+        if include_build_options {
+            match build_options.no_binary() {
+                NoBinary::None => {}
+                NoBinary::All => {
+                    writeln!(writer, "--no-binary :all:")?;
+                    wrote_preamble = true;
+                }
+                NoBinary::Packages(packages) => {
+                    for package in packages {
+                        writeln!(writer, "--no-binary {package}")?;
+                        wrote_preamble = true;
+                    }
+                }
+            }
+            match build_options.no_build() {
+                NoBuild::None => {}
+                NoBuild::All => {
+                    writeln!(writer, "--only-binary :all:")?;
                     wrote_preamble = true;
                 }
+                NoBuild::Packages(packages) => {
+                    for package in packages {
+                        writeln!(writer, "--only-binary {package}")?;
+                        wrote_preamble = true;
+                    }
+                }
             }
         }
-    }
-
-    // If we wrote an index, add a newline to separate it from the requirements
-    if wrote_preamble {
-        writeln!(writer)?;
-    }
 
-    write!(
-        writer,
-        "{}",
-        DisplayResolutionGraph::new(
-            &resolution,
-            &markers,
-            &no_emit_packages,
-            generate_hashes,
-            include_extras,
-            include_markers || universal,
-            include_annotations,
-            include_index_annotation,
-            annotation_style,
-        )
-    )?;
+        // If we wrote an index, add a newline to separate it from the requirements
+        if wrote_preamble {
+            writeln!(writer)?;
+        }
 
-    // If any "unsafe" packages were excluded, notify the user.
-    let excluded = no_emit_packages
-        .into_iter()
-        .filter(|name| resolution.contains(name))
-        .collect::<Vec<_>>();
-    if !excluded.is_empty() {
-        writeln!(writer)?;
-        writeln!(
+        write!(
             writer,
             "{}",
-            "# The following packages were excluded from the output:".green()
+            DisplayResolutionGraph::new(
+                &resolution,
+                &markers,
+                &no_emit_packages,
+                generate_hashes,
+                include_extras,
+                include_markers || universal,
+                include_annotations,
+                include_index_annotation,
+                annotation_style,
+            )
         )?;
-        for package in excluded {
-            writeln!(writer, "# {package}")?;
+
+        // If any "unsafe" packages were excluded, notify the user.
+        let excluded = no_emit_packages
+            .iter()
+            .filter(|name| resolution.contains(name))
+            .collect::<Vec<_>>();
+        if !excluded.is_empty() {
+            writeln!(writer)?;
+            writeln!(
+                writer,
+                "{}",
+                "# The following packages were excluded from the output:".green()
+            )?;
+            for package in excluded {
+                writeln!(writer, "# {package}")?;
+            }
         }
-    }
 
-    // Commit the output to disk.
-    writer.commit().await?;
+        // Commit the output to disk.
+        writer.commit().await?;
 
-    // Notify the user of any resolution diagnostics.
-    operations::diagnose_resolution(resolution.diagnostics(), printer)?;
+        // Notify the user of any resolution diagnostics.
+        operations::diagnose_resolution(resolution.diagnostics(), printer)?;
+    }
 
     Ok(ExitStatus::Success)
 }
 
+/// A target environment for which `pip compile` should produce a resolution.
+#[derive(Debug, Clone)]
+pub(crate) struct CompileTarget {
+    /// The Python version to resolve for, if not that of the interpreter.
+    pub(crate) python_version: Option<PythonVersion>,
+    /// The platform to resolve for, if not that of the interpreter.
+    pub(crate) python_platform: Option<TargetTriple>,
+    /// The file to which the resolution should be written, if any.
+    pub(crate) output_file: Option<PathBuf>,
+}
+
+impl CompileTarget {
+    /// Determine the targets for a `pip compile` invocation.
+    ///
+    /// If multiple `--python-version` or `--python-platform` values were provided, they're paired
+    /// in the order given (with a single value applying to every target), and each target is
+    /// written to a separate file derived from `--output-file`. Otherwise, a single target is
+    /// returned, using the resolved `python_version` and `python_platform` settings.
+    pub(crate) fn from_settings(
+        python_versions: Vec<PythonVersion>,
+        python_platforms: Vec<TargetTriple>,
+        python_version: Option<PythonVersion>,
+        python_platform: Option<TargetTriple>,
+        output_file: Option<PathBuf>,
+    ) -> Result<Vec<Self>> {
+        let count = python_versions.len().max(python_platforms.len());
+        if count <= 1 {
+            return Ok(vec![Self {
+                python_version,
+                python_platform,
+                output_file,
+            }]);
+        }
+
+        if python_versions.len() > 1
+            && python_platforms.len() > 1
+            && python_versions.len() != python_platforms.len()
+        {
+            return Err(anyhow!(
+                "Received {} values for `--python-version` and {} values for `--python-platform`; provide the same number of each to compile paired targets, or a single value to use for every target",
+                python_versions.len(),
+                python_platforms.len(),
+            ));
+        }
+
+        let Some(output_file) = output_file else {
+            return Err(anyhow!(
+                "Compiling for multiple targets requires `--output-file`"
+            ));
+        };
+
+        let mut seen = FxHashSet::default();
+        let mut targets = Vec::with_capacity(count);
+        for index in 0..count {
+            let python_version = python_versions
+                .get(index)
+                .cloned()
+                .or_else(|| python_version.clone());
+            let python_platform = python_platforms.get(index).copied().or(python_platform);
+            let output_file =
+                target_output_file(&output_file, python_version.as_ref(), python_platform);
+            if !seen.insert(output_file.clone()) {
+                return Err(anyhow!(
+                    "Multiple targets would be written to `{}`",
+                    output_file.user_display()
+                ));
+            }
+            targets.push(Self {
+                python_version,
+                python_platform,
+                output_file: Some(output_file),
+            });
+        }
+        Ok(targets)
+    }
+
+    /// Describe the target as the equivalent command-line arguments, e.g.,
+    /// `--python-platform linux --python-version 3.12`.
+    fn describe(&self) -> String {
+        let mut args = Vec::new();
+        if let Some(python_platform) = self.python_platform {
+            args.push(format!("--python-platform {python_platform}"));
+        }
+        if let Some(python_version) = self.python_version.as_ref() {
+            args.push(format!("--python-version {python_version}"));
+        }
+        args.join(" ")
+    }
+}
+
+/// Read the versions pinned in a `uv.lock` file as constraints.
+///
+/// Each package resolved from a registry is constrained to its locked version, limited to the
+/// forks in which that version was selected. Packages from other sources (e.g., Git repositories or
+/// local directories) are left unconstrained.
+async fn read_lock_constraints(path: &Path) -> Result<Vec<Requirement>> {
+    let encoded = fs_err::tokio::read_to_string(path).await?;
+    let lock = toml::from_str::<Lock>(&encoded)
+        .with_context(|| format!("Failed to parse lockfile at: `{}`", path.user_display()))?;
+    if !matches!(lock.version(), VERSION | DIFFABLE_VERSION) {
+        return Err(anyhow!(
+            "Unsupported lockfile version in `{}`: expected at most {DIFFABLE_VERSION}, found {}",
+            path.user_display(),
+            lock.version()
+        ));
+    }
+
+    let constraints = lock
+        .packages()
+        .iter()
+        .filter(|package| package.is_registry())
+        .map(|package| {
+            let marker = if package.fork_markers().is_empty() {
+                MarkerTree::TRUE
+            } else {
+                let mut marker = MarkerTree::FALSE;
+                for fork_marker in package.fork_markers() {
+                    marker.or(fork_marker.clone());
+                }
+                marker
+            };
+            Requirement {
+                name: package.name().clone(),
+                extras: Vec::new(),
+                marker,
+                source: RequirementSource::Registry {
+                    specifier: VersionSpecifiers::from(VersionSpecifier::equals_version(
+                        package.version().clone(),
+                    )),
+                    index: None,
+                },
+                origin: Some(RequirementOrigin::File(path.to_path_buf())),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    debug!(
+        "Using {} constraint(s) from lockfile: `{}`",
+        constraints.len(),
+        path.user_display()
+    );
+
+    Ok(constraints)
+}
+
+/// Derive the output file for a target from the user-provided output file, e.g.,
+/// `requirements.txt` becomes `requirements-linux-py3.12.txt`.
+fn target_output_file(
+    output_file: &Path,
+    python_version: Option<&PythonVersion>,
+    python_platform: Option<TargetTriple>,
+) -> PathBuf {
+    let mut file_name = output_file
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    if let Some(python_platform) = python_platform {
+        file_name.push('-');
+        file_name.push_str(python_platform.as_str());
+    }
+    if let Some(python_version) = python_version {
+        file_name.push_str("-py");
+        file_name.push_str(&python_version.to_string());
+    }
+    if let Some(extension) = output_file.extension() {
+        file_name.push('.');
+        file_name.push_str(&extension.to_string_lossy());
+    }
+    output_file.with_file_name(file_name)
+}
+
 /// Format the uv command used to generate the output file.
 #[allow(clippy::fn_params_excessive_bools)]
 fn cmd(
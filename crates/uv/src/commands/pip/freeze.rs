@@ -1,21 +1,29 @@
 use std::fmt::Write;
+use std::path::PathBuf;
 
 use anyhow::Result;
 use itertools::Itertools;
 use owo_colors::OwoColorize;
+use serde::Serialize;
 
 use uv_cache::Cache;
+use uv_cli::FreezeFormat;
 use uv_distribution_types::{Diagnostic, InstalledDist, Name};
+use uv_fs::Simplified;
 use uv_installer::SitePackages;
-use uv_python::{EnvironmentPreference, PythonEnvironment, PythonRequest};
+use uv_python::{EnvironmentPreference, PythonEnvironment, PythonRequest, Target};
 
 use crate::commands::pip::operations::report_target_environment;
 use crate::commands::ExitStatus;
 use crate::printer::Printer;
 
 /// Enumerate the installed packages in the current environment.
+#[allow(clippy::fn_params_excessive_bools)]
 pub(crate) fn pip_freeze(
     exclude_editable: bool,
+    direct_url: bool,
+    paths: Option<Vec<PathBuf>>,
+    format: FreezeFormat,
     strict: bool,
     python: Option<&str>,
     system: bool,
@@ -31,33 +39,65 @@ pub(crate) fn pip_freeze(
 
     report_target_environment(&environment, cache, printer)?;
 
-    // Build the installed index.
-    let site_packages = SitePackages::from_environment(&environment)?;
-    for dist in site_packages
+    // Build the installed index for each requested path, or for the environment itself.
+    let site_packages = match paths {
+        Some(paths) => paths
+            .into_iter()
+            // Like `pip freeze`, ignore any paths that don't exist.
+            .filter(|path| path.is_dir())
+            .map(|path| environment.clone().with_target(Target::from(path)))
+            .map(|environment| Ok(SitePackages::from_environment(&environment?)?))
+            .collect::<Result<Vec<_>>>()?,
+        None => vec![SitePackages::from_environment(&environment)?],
+    };
+
+    let dists = site_packages
         .iter()
-        .filter(|dist| !(exclude_editable && dist.is_editable()))
-        .sorted_unstable_by(|a, b| a.name().cmp(b.name()).then(a.version().cmp(b.version())))
-    {
-        match dist {
-            InstalledDist::Registry(dist) => {
-                writeln!(printer.stdout(), "{}=={}", dist.name().bold(), dist.version)?;
-            }
-            InstalledDist::Url(dist) => {
-                if dist.editable {
-                    writeln!(printer.stdout(), "-e {}", dist.url)?;
-                } else {
-                    writeln!(printer.stdout(), "{} @ {}", dist.name().bold(), dist.url)?;
+        .flat_map(|site_packages| {
+            site_packages
+                .iter()
+                .filter(|dist| !(exclude_editable && dist.is_editable()))
+                .sorted_unstable_by(|a, b| {
+                    a.name().cmp(b.name()).then(a.version().cmp(b.version()))
+                })
+        })
+        .collect_vec();
+
+    match format {
+        FreezeFormat::Requirements => {
+            for dist in dists {
+                match dist {
+                    InstalledDist::Registry(dist) => {
+                        writeln!(printer.stdout(), "{}=={}", dist.name().bold(), dist.version)?;
+                    }
+                    InstalledDist::Url(dist) => {
+                        if dist.editable {
+                            writeln!(printer.stdout(), "-e {}", dist.url)?;
+                        } else if direct_url {
+                            writeln!(printer.stdout(), "{} @ {}", dist.name().bold(), dist.url)?;
+                        } else {
+                            writeln!(printer.stdout(), "{}=={}", dist.name().bold(), dist.version)?;
+                        }
+                    }
+                    InstalledDist::EggInfoFile(dist) => {
+                        writeln!(printer.stdout(), "{}=={}", dist.name().bold(), dist.version)?;
+                    }
+                    InstalledDist::EggInfoDirectory(dist) => {
+                        writeln!(printer.stdout(), "{}=={}", dist.name().bold(), dist.version)?;
+                    }
+                    InstalledDist::LegacyEditable(dist) => {
+                        writeln!(printer.stdout(), "-e {}", dist.target.display())?;
+                    }
                 }
             }
-            InstalledDist::EggInfoFile(dist) => {
-                writeln!(printer.stdout(), "{}=={}", dist.name().bold(), dist.version)?;
-            }
-            InstalledDist::EggInfoDirectory(dist) => {
-                writeln!(printer.stdout(), "{}=={}", dist.name().bold(), dist.version)?;
-            }
-            InstalledDist::LegacyEditable(dist) => {
-                writeln!(printer.stdout(), "-e {}", dist.target.display())?;
-            }
+        }
+        FreezeFormat::Json => {
+            let entries = dists
+                .into_iter()
+                .map(|dist| Entry::from_dist(dist, direct_url))
+                .collect::<Result<Vec<_>>>()?;
+            let output = serde_json::to_string(&entries)?;
+            writeln!(printer.stdout(), "{output}")?;
         }
     }
 
@@ -66,16 +106,71 @@ pub(crate) fn pip_freeze(
         // Determine the markers to use for resolution.
         let markers = environment.interpreter().resolver_markers();
 
-        for diagnostic in site_packages.diagnostics(&markers)? {
-            writeln!(
-                printer.stderr(),
-                "{}{} {}",
-                "warning".yellow().bold(),
-                ":".bold(),
-                diagnostic.message().bold()
-            )?;
+        for site_packages in &site_packages {
+            for diagnostic in site_packages.diagnostics(&markers)? {
+                writeln!(
+                    printer.stderr(),
+                    "{}{} {}",
+                    "warning".yellow().bold(),
+                    ":".bold(),
+                    diagnostic.message().bold()
+                )?;
+            }
         }
     }
 
     Ok(ExitStatus::Success)
 }
+
+/// An entry in a JSON list of installed packages.
+#[derive(Debug, Serialize)]
+struct Entry {
+    name: String,
+    version: String,
+    /// The direct URL from which the package was installed, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    editable_project_location: Option<String>,
+    /// The tool that installed the package, as recorded in its `INSTALLER` file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    installer: Option<String>,
+    /// The `site-packages` directory in which the package is installed.
+    location: String,
+}
+
+impl Entry {
+    fn from_dist(dist: &InstalledDist, direct_url: bool) -> Result<Self> {
+        let url = match dist {
+            InstalledDist::Url(dist) if direct_url && !dist.editable => Some(dist.url.to_string()),
+            _ => None,
+        };
+
+        // Only `.dist-info` directories include an `INSTALLER` file.
+        let installer = match dist {
+            InstalledDist::Registry(_) | InstalledDist::Url(_) => dist
+                .installer()?
+                .map(|installer| installer.trim().to_string())
+                .filter(|installer| !installer.is_empty()),
+            _ => None,
+        };
+
+        let location = dist
+            .path()
+            .parent()
+            .unwrap_or(dist.path())
+            .simplified_display()
+            .to_string();
+
+        Ok(Self {
+            name: dist.name().to_string(),
+            version: dist.version().to_string(),
+            url,
+            editable_project_location: dist
+                .as_editable()
+                .map(|url| url.to_file_path().unwrap().simplified_display().to_string()),
+            installer,
+            location,
+        })
+    }
+}
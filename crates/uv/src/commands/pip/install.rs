@@ -1,4 +1,5 @@
 use std::fmt::Write;
+use std::path::Path;
 
 use itertools::Itertools;
 use owo_colors::OwoColorize;
@@ -7,8 +8,9 @@ use tracing::{debug, enabled, Level};
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    BuildOptions, Concurrency, ConfigSettings, Constraints, ExtrasSpecification, HashCheckingMode,
-    IndexStrategy, LowerBound, Reinstall, SourceStrategy, TrustedHost, Upgrade,
+    BuildContainers, BuildOptions, BytecodeOptions, Concurrency, ConfigSettings, Constraints,
+    ExtrasSpecification, HashCheckingMode, IndexStrategy, LowerBound, Reinstall, SourceStrategy,
+    TrustedHost, Upgrade,
 };
 use uv_configuration::{KeyringProviderType, TargetTriple};
 use uv_dispatch::BuildDispatch;
@@ -26,14 +28,16 @@ use uv_python::{
 };
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
 use uv_resolver::{
-    DependencyMode, ExcludeNewer, FlatIndex, OptionsBuilder, PrereleaseMode, PythonRequirement,
-    ResolutionMode, ResolverMarkers,
+    DependencyMode, ExcludeNewer, FlatIndex, InstallationReport, OptionsBuilder, PrereleaseMode,
+    PythonRequirement, ResolutionBudget, ResolutionMode, ResolverMarkers, YankedMode,
 };
 use uv_types::{BuildIsolation, HashStrategy};
+use uv_workspace::Workspace;
 
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger, InstallLogger};
 use crate::commands::pip::operations::report_target_environment;
-use crate::commands::pip::operations::Modifications;
+use crate::commands::pip::operations::{DryRun, Modifications};
+use crate::commands::pip::report::write_installation_report;
 use crate::commands::pip::{operations, resolution_markers, resolution_tags};
 use crate::commands::{diagnostics, ExitStatus, SharedState};
 use crate::printer::Printer;
@@ -50,6 +54,10 @@ pub(crate) async fn pip_install(
     extras: &ExtrasSpecification,
     resolution_mode: ResolutionMode,
     prerelease_mode: PrereleaseMode,
+    yanked_mode: YankedMode,
+    allow_yanked: Vec<Requirement>,
+    prefer_local_version: Option<String>,
+    resolution_budget: ResolutionBudget,
     dependency_mode: DependencyMode,
     upgrade: Upgrade,
     index_locations: IndexLocations,
@@ -60,9 +68,11 @@ pub(crate) async fn pip_install(
     reinstall: Reinstall,
     link_mode: LinkMode,
     compile: bool,
+    bytecode_options: &BytecodeOptions,
     hash_checking: Option<HashCheckingMode>,
     connectivity: Connectivity,
     config_settings: &ConfigSettings,
+    build_containers: &BuildContainers,
     no_build_isolation: bool,
     no_build_isolation_package: Vec<PackageName>,
     build_options: BuildOptions,
@@ -72,6 +82,7 @@ pub(crate) async fn pip_install(
     strict: bool,
     exclude_newer: Option<ExcludeNewer>,
     sources: SourceStrategy,
+    variants: Vec<String>,
     python: Option<String>,
     system: bool,
     break_system_packages: bool,
@@ -80,7 +91,8 @@ pub(crate) async fn pip_install(
     concurrency: Concurrency,
     native_tls: bool,
     cache: Cache,
-    dry_run: bool,
+    dry_run: DryRun,
+    report: Option<&Path>,
     printer: Printer,
 ) -> anyhow::Result<ExitStatus> {
     let start = std::time::Instant::now();
@@ -89,7 +101,8 @@ pub(crate) async fn pip_install(
         .connectivity(connectivity)
         .native_tls(native_tls)
         .keyring(keyring_provider)
-        .allow_insecure_host(allow_insecure_host);
+        .allow_insecure_host(allow_insecure_host)
+        .indexes(index_locations.allowed_indexes());
 
     // Read all requirements from the provided sources.
     let RequirementsSpecification {
@@ -111,12 +124,13 @@ pub(crate) async fn pip_install(
         overrides,
         extras,
         &client_builder,
+        &cache,
     )
     .await?;
 
     // Read build constraints.
     let build_constraints =
-        operations::read_constraints(build_constraints, &client_builder).await?;
+        operations::read_constraints(build_constraints, &client_builder, &cache).await?;
 
     let constraints: Vec<NameRequirementSpecification> = constraints
         .iter()
@@ -203,7 +217,9 @@ pub(crate) async fn pip_install(
     // Check if the current environment satisfies the requirements.
     // Ideally, the resolver would be fast enough to let us remove this check. But right now, for large environments,
     // it's an order of magnitude faster to validate the environment than to resolve the requirements.
+    // If a report was requested, we always resolve, since the report is derived from the resolution.
     if reinstall.is_none()
+        && report.is_none()
         && upgrade.is_none()
         && source_trees.is_empty()
         && overrides.is_empty()
@@ -224,7 +240,7 @@ pub(crate) async fn pip_install(
                     }
                 }
                 DefaultInstallLogger.on_audit(requirements.len(), start, printer)?;
-                if dry_run {
+                if dry_run.enabled() {
                     writeln!(printer.stderr(), "Would make no changes")?;
                 }
 
@@ -267,6 +283,17 @@ pub(crate) async fn pip_install(
         HashStrategy::None
     };
 
+    // Read the `tool.uv.sources` of the source trees, to validate the requested variants and
+    // the hashes declared for URL sources (before building).
+    let workspaces = operations::source_tree_workspaces(&source_trees, sources).await;
+    operations::validate_variants(&workspaces, &variants)?;
+    let hasher = hasher.with_url_hashes(
+        workspaces
+            .iter()
+            .flat_map(Workspace::url_source_hashes)
+            .map(|(_, url, hash)| (url, hash)),
+    );
+
     // When resolving, don't take any external preferences into account.
     let preferences = Vec::default();
 
@@ -293,6 +320,8 @@ pub(crate) async fn pip_install(
     for index in index_locations.allowed_indexes() {
         if let Some(credentials) = index.credentials() {
             uv_auth::store_credentials(index.raw_url(), credentials);
+        } else if let Some(secrets) = index.secret_credentials() {
+            uv_auth::store_secret_credentials(secrets);
         }
     }
 
@@ -310,7 +339,8 @@ pub(crate) async fn pip_install(
 
     // Resolve the flat indexes from `--find-links`.
     let flat_index = {
-        let client = FlatIndexClient::new(&client, &cache);
+        let client = FlatIndexClient::new(&client, &cache)
+            .with_recursive(index_locations.find_links_recursive());
         let entries = client
             .fetch(index_locations.flat_indexes().map(Index::url))
             .await?;
@@ -360,10 +390,12 @@ pub(crate) async fn pip_install(
         &dependency_metadata,
         &state.index,
         &state.git,
+        &state.hg,
         &state.capabilities,
         &state.in_flight,
         index_strategy,
         config_settings,
+        build_containers,
         build_isolation,
         link_mode,
         &build_options,
@@ -372,14 +404,17 @@ pub(crate) async fn pip_install(
         LowerBound::Warn,
         sources,
         concurrency,
-    );
+    )
+    .with_variants(&variants);
 
     let options = OptionsBuilder::new()
         .resolution_mode(resolution_mode)
         .prerelease_mode(prerelease_mode)
+        .yanked_mode(yanked_mode)
         .dependency_mode(dependency_mode)
         .exclude_newer(exclude_newer)
         .index_strategy(index_strategy)
+        .budget(resolution_budget)
         .build();
 
     // Resolve the requirements.
@@ -397,6 +432,8 @@ pub(crate) async fn pip_install(
         &hasher,
         &reinstall,
         &upgrade,
+        &allow_yanked,
+        prefer_local_version.as_deref(),
         Some(&tags),
         ResolverMarkers::specific_environment(markers.clone()),
         python_requirement,
@@ -411,7 +448,17 @@ pub(crate) async fn pip_install(
     )
     .await
     {
-        Ok(resolution) => Resolution::from(resolution),
+        Ok(resolution) => {
+            if let Some(path) = report {
+                let report = InstallationReport::from_resolution(
+                    &resolution,
+                    &markers,
+                    uv_version::version(),
+                )?;
+                write_installation_report(&report, path, printer)?;
+            }
+            Resolution::from(resolution)
+        }
         Err(operations::Error::Resolve(uv_resolver::ResolveError::NoSolution(err))) => {
             diagnostics::no_solution(&err);
             return Ok(ExitStatus::Failure);
@@ -436,6 +483,7 @@ pub(crate) async fn pip_install(
         &build_options,
         link_mode,
         compile,
+        bytecode_options,
         &index_locations,
         config_settings,
         &hasher,
@@ -446,6 +494,7 @@ pub(crate) async fn pip_install(
         &build_dispatch,
         &cache,
         &environment,
+        None,
         Box::new(DefaultInstallLogger),
         dry_run,
         printer,
@@ -456,7 +505,7 @@ pub(crate) async fn pip_install(
     operations::diagnose_resolution(resolution.diagnostics(), printer)?;
 
     // Notify the user of any environment diagnostics.
-    if strict && !dry_run {
+    if strict && !dry_run.enabled() {
         operations::diagnose_environment(&resolution, &environment, &markers, printer)?;
     }
 
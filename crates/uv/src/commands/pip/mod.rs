@@ -12,10 +12,12 @@ pub(crate) mod install;
 pub(crate) mod list;
 pub(crate) mod loggers;
 pub(crate) mod operations;
+pub(crate) mod report;
 pub(crate) mod show;
 pub(crate) mod sync;
 pub(crate) mod tree;
 pub(crate) mod uninstall;
+pub(crate) mod verify;
 
 pub(crate) fn resolution_markers(
     python_version: Option<&PythonVersion>,
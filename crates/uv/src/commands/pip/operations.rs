@@ -3,33 +3,36 @@
 use anyhow::{anyhow, Context};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
-use std::collections::{BTreeSet, HashSet};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
 use std::fmt::Write;
 use std::path::PathBuf;
 use tracing::debug;
 use uv_tool::InstalledTools;
 
 use uv_cache::Cache;
+use uv_cli::DryRunFormat;
 use uv_client::{BaseClientBuilder, RegistryClient};
 use uv_configuration::{
-    BuildOptions, Concurrency, ConfigSettings, Constraints, ExtrasSpecification, Overrides,
-    Reinstall, Upgrade,
+    BuildOptions, BytecodeOptions, Concurrency, ConfigSettings, Constraints, ExtrasSpecification,
+    Overrides, Reinstall, SourceStrategy, Upgrade,
 };
 use uv_dispatch::BuildDispatch;
 use uv_distribution::DistributionDatabase;
 use uv_distribution_types::{
-    CachedDist, Diagnostic, InstalledDist, LocalDist, NameRequirementSpecification,
-    ResolutionDiagnostic, UnresolvedRequirement, UnresolvedRequirementSpecification,
+    BuiltDist, CachedDist, Diagnostic, Dist, FileLocation, InstalledDist, LocalDist,
+    NameRequirementSpecification, ResolutionDiagnostic, SourceDist, UnresolvedRequirement,
+    UnresolvedRequirementSpecification,
 };
 use uv_distribution_types::{
-    DistributionMetadata, IndexLocations, InstalledMetadata, Name, Resolution,
+    DistributionMetadata, IndexLocations, InstalledMetadata, Name, Resolution, VersionOrUrlRef,
 };
 use uv_fs::Simplified;
 use uv_install_wheel::linker::LinkMode;
-use uv_installer::{Plan, Planner, Preparer, SitePackages};
+use uv_installer::{Plan, Planner, Preparer, ReinstallReason, SitePackages};
 use uv_normalize::{GroupName, PackageName};
 use uv_platform_tags::Tags;
-use uv_pypi_types::ResolverMarkerEnvironment;
+use uv_pypi_types::{Requirement, ResolverMarkerEnvironment};
 use uv_python::PythonEnvironment;
 use uv_requirements::{
     LookaheadResolver, NamedRequirementsResolver, RequirementsSource, RequirementsSpecification,
@@ -41,12 +44,69 @@ use uv_resolver::{
 };
 use uv_types::{HashStrategy, InFlight, InstalledPackagesProvider};
 use uv_warnings::warn_user;
+use uv_workspace::{DiscoveryOptions, Workspace};
 
 use crate::commands::pip::loggers::{DefaultInstallLogger, InstallLogger, ResolveLogger};
 use crate::commands::reporters::{InstallReporter, PrepareReporter, ResolverReporter};
 use crate::commands::{compile_bytecode, ChangeEventKind, DryRunEvent};
 use crate::printer::Printer;
 
+/// Discover the workspaces that contain the given source trees, to read their `tool.uv.sources`.
+///
+/// Returns an empty list if sources are disabled.
+pub(crate) async fn source_tree_workspaces(
+    source_trees: &[PathBuf],
+    sources: SourceStrategy,
+) -> Vec<Workspace> {
+    if matches!(sources, SourceStrategy::Disabled) {
+        return Vec::new();
+    }
+
+    let mut workspaces = Vec::new();
+    for source_tree in source_trees {
+        match Workspace::discover(source_tree, &DiscoveryOptions::default()).await {
+            Ok(workspace) => workspaces.push(workspace),
+            Err(err) => {
+                debug!(
+                    "No workspace found for source tree `{}`: {err}",
+                    source_tree.user_display()
+                );
+            }
+        }
+    }
+    workspaces
+}
+
+/// Ensure that each of the requested variants is declared by a source in `tool.uv.sources`, in
+/// at least one of the given workspaces.
+pub(crate) fn validate_variants<'a>(
+    workspaces: impl IntoIterator<Item = &'a Workspace>,
+    variants: &[String],
+) -> Result<(), Error> {
+    if variants.is_empty() {
+        return Ok(());
+    }
+
+    let mut workspaces = workspaces.into_iter().peekable();
+    if workspaces.peek().is_none() {
+        return Ok(());
+    }
+
+    let declared = workspaces
+        .flat_map(Workspace::source_variants)
+        .collect::<BTreeSet<_>>();
+    if let Some(variant) = variants
+        .iter()
+        .find(|variant| !declared.contains(variant.as_str()))
+    {
+        return Err(Error::UnknownVariant(
+            variant.clone(),
+            declared.into_iter().map(ToString::to_string).collect(),
+        ));
+    }
+    Ok(())
+}
+
 /// Consolidate the requirements for an installation.
 pub(crate) async fn read_requirements(
     requirements: &[RequirementsSource],
@@ -54,6 +114,7 @@ pub(crate) async fn read_requirements(
     overrides: &[RequirementsSource],
     extras: &ExtrasSpecification,
     client_builder: &BaseClientBuilder<'_>,
+    cache: &Cache,
 ) -> Result<RequirementsSpecification, Error> {
     // If the user requests `extras` but does not provide a valid source (e.g., a `pyproject.toml`),
     // return an error.
@@ -70,6 +131,7 @@ pub(crate) async fn read_requirements(
         constraints,
         overrides,
         client_builder,
+        cache,
     )
     .await?)
 }
@@ -78,9 +140,10 @@ pub(crate) async fn read_requirements(
 pub(crate) async fn read_constraints(
     constraints: &[RequirementsSource],
     client_builder: &BaseClientBuilder<'_>,
+    cache: &Cache,
 ) -> Result<Vec<NameRequirementSpecification>, Error> {
     Ok(
-        RequirementsSpecification::from_sources(&[], constraints, &[], client_builder)
+        RequirementsSpecification::from_sources(&[], constraints, &[], client_builder, cache)
             .await?
             .constraints,
     )
@@ -101,6 +164,8 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
     hasher: &HashStrategy,
     reinstall: &Reinstall,
     upgrade: &Upgrade,
+    allow_yanked: &[Requirement],
+    prefer_local_version: Option<&str>,
     tags: Option<&Tags>,
     markers: ResolverMarkers,
     python_requirement: PythonRequirement,
@@ -136,7 +201,7 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
                 NamedRequirementsResolver::new(
                     hasher,
                     index,
-                    DistributionDatabase::new(client, build_dispatch, concurrency.downloads),
+                    DistributionDatabase::new(client, build_dispatch, concurrency),
                 )
                 .with_reporter(ResolverReporter::from(printer))
                 .resolve(unnamed.into_iter())
@@ -150,7 +215,7 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
                 extras,
                 hasher,
                 index,
-                DistributionDatabase::new(client, build_dispatch, concurrency.downloads),
+                DistributionDatabase::new(client, build_dispatch, concurrency),
             )
             .with_reporter(ResolverReporter::from(printer))
             .resolve(source_trees.iter().map(PathBuf::as_path))
@@ -219,7 +284,7 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
                 NamedRequirementsResolver::new(
                     hasher,
                     index,
-                    DistributionDatabase::new(client, build_dispatch, concurrency.downloads),
+                    DistributionDatabase::new(client, build_dispatch, concurrency),
                 )
                 .with_reporter(ResolverReporter::from(printer))
                 .resolve(unnamed.into_iter())
@@ -250,7 +315,7 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
                 &dev,
                 hasher,
                 index,
-                DistributionDatabase::new(client, build_dispatch, concurrency.downloads),
+                DistributionDatabase::new(client, build_dispatch, concurrency),
             )
             .with_reporter(ResolverReporter::from(printer))
             .resolve(&markers)
@@ -273,7 +338,9 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
         workspace_members,
         exclusions,
         lookaheads,
-    );
+    )
+    .with_allowed_yanks(allow_yanked.to_vec())
+    .with_prefer_local_version(prefer_local_version.map(ToString::to_string));
 
     // Resolve the dependencies.
     let resolution = {
@@ -296,7 +363,7 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
             hasher,
             build_dispatch,
             installed_packages,
-            DistributionDatabase::new(client, build_dispatch, concurrency.downloads),
+            DistributionDatabase::new(client, build_dispatch, concurrency),
         )?
         .with_reporter(reporter);
 
@@ -323,6 +390,40 @@ pub(crate) enum Modifications {
     Exact,
 }
 
+/// Whether to perform a dry run and, if so, how to report the planned changes.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DryRun {
+    /// Apply the planned changes to the environment.
+    #[default]
+    Disabled,
+    /// Report the planned changes as human-readable text, without applying them.
+    Text,
+    /// Report the planned changes as JSON, without applying them.
+    Json,
+}
+
+impl DryRun {
+    /// Determine the [`DryRun`] mode from the command-line arguments.
+    pub(crate) fn from_args(dry_run: bool, output_format: DryRunFormat) -> Self {
+        match (dry_run, output_format) {
+            (false, _) => Self::Disabled,
+            (true, DryRunFormat::Text) => Self::Text,
+            (true, DryRunFormat::Json) => Self::Json,
+        }
+    }
+
+    /// Returns `true` if the changes should be reported, but not applied.
+    pub(crate) fn enabled(self) -> bool {
+        !matches!(self, Self::Disabled)
+    }
+}
+
+impl From<bool> for DryRun {
+    fn from(dry_run: bool) -> Self {
+        Self::from_args(dry_run, DryRunFormat::Text)
+    }
+}
+
 /// A summary of the changes made to the environment during an installation.
 #[derive(Debug, Clone, Default)]
 pub(crate) struct Changelog {
@@ -386,6 +487,7 @@ pub(crate) async fn install(
     build_options: &BuildOptions,
     link_mode: LinkMode,
     compile: bool,
+    bytecode_options: &BytecodeOptions,
     index_urls: &IndexLocations,
     config_settings: &ConfigSettings,
     hasher: &HashStrategy,
@@ -397,7 +499,7 @@ pub(crate) async fn install(
     cache: &Cache,
     venv: &PythonEnvironment,
     logger: Box<dyn InstallLogger>,
-    dry_run: bool,
+    dry_run: DryRun,
     printer: Printer,
 ) -> Result<Changelog, Error> {
     let start = std::time::Instant::now();
@@ -418,18 +520,55 @@ pub(crate) async fn install(
         )
         .context("Failed to determine installation plan")?;
 
-    if dry_run {
-        report_dry_run(resolution, plan, modifications, start, printer)?;
-        return Ok(Changelog::default());
+    // In strict offline mode, verify that every distribution is available locally before making
+    // any changes to the environment.
+    //
+    // The build dependencies of local source distributions are only resolved when building, so
+    // they aren't covered here; if any are missing from the cache, the build fails (before the
+    // environment is modified) with the usual offline error.
+    if client.connectivity().is_strict_offline() {
+        let missing = plan
+            .remote
+            .iter()
+            .filter(|dist| requires_network(dist))
+            .map(ToString::to_string)
+            .sorted()
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            return Err(Error::MissingFromCache(missing));
+        }
+    }
+
+    match dry_run {
+        DryRun::Disabled => {}
+        DryRun::Text => {
+            report_dry_run(resolution, plan, modifications, start, printer)?;
+            return Ok(Changelog::default());
+        }
+        DryRun::Json => {
+            report_dry_run_json(plan, modifications, printer)?;
+            return Ok(Changelog::default());
+        }
     }
 
     let Plan {
         cached,
         remote,
         reinstalls,
+        reinstall_reasons: _,
         extraneous,
     } = plan;
 
+    // Record the cache activity, counting the size of each cached distribution as downloaded
+    // bytes saved.
+    let bytes_saved = cached
+        .iter()
+        .filter_map(|dist| resolution.get_remote(dist.name()))
+        .filter_map(|dist| dist.file().and_then(|file| file.size))
+        .sum();
+    cache.recorder().record_hits(cached.len(), bytes_saved);
+    cache.recorder().record_misses(remote.len());
+
     // If we're in `install` mode, ignore any extraneous distributions.
     let extraneous = match modifications {
         Modifications::Sufficient => vec![],
@@ -453,7 +592,7 @@ pub(crate) async fn install(
             tags,
             hasher,
             build_options,
-            DistributionDatabase::new(client, build_dispatch, concurrency.downloads),
+            DistributionDatabase::new(client, build_dispatch, concurrency),
         )
         .with_reporter(PrepareReporter::from(printer).with_length(remote.len() as u64));
 
@@ -524,7 +663,7 @@ pub(crate) async fn install(
     }
 
     if compile {
-        compile_bytecode(venv, cache, printer).await?;
+        compile_bytecode(venv, bytecode_options, cache, printer).await?;
     }
 
     // Construct a summary of the changes made to the environment.
@@ -578,6 +717,21 @@ pub(crate) fn report_target_environment(
     Ok(writeln!(printer.stderr(), "{}", message.dimmed())?)
 }
 
+/// Returns `true` if the [`Dist`] must be fetched over the network, i.e., it isn't a local file
+/// or directory.
+fn requires_network(dist: &Dist) -> bool {
+    let is_local =
+        |location: &FileLocation| location.to_url().is_ok_and(|url| url.scheme() == "file");
+    match dist {
+        Dist::Built(BuiltDist::Registry(wheels)) => !is_local(&wheels.best_wheel().file.url),
+        Dist::Source(SourceDist::Registry(sdist)) => !is_local(&sdist.file.url),
+        Dist::Built(BuiltDist::DirectUrl(_))
+        | Dist::Source(SourceDist::DirectUrl(_) | SourceDist::Git(_) | SourceDist::Hg(_)) => true,
+        Dist::Built(BuiltDist::Path(_))
+        | Dist::Source(SourceDist::Path(_) | SourceDist::Directory(_)) => false,
+    }
+}
+
 /// Report on the results of a dry-run installation.
 fn report_dry_run(
     resolution: &Resolution,
@@ -590,6 +744,7 @@ fn report_dry_run(
         cached,
         remote,
         reinstalls,
+        reinstall_reasons,
         extraneous,
     } = plan;
 
@@ -654,21 +809,35 @@ fn report_dry_run(
     // TODO(charlie): DRY this up with `report_modifications`. The types don't quite line up.
     for event in reinstalls
         .into_iter()
-        .chain(extraneous.into_iter())
         .map(|distribution| DryRunEvent {
             name: distribution.name().clone(),
             version: distribution.installed_version().to_string(),
             kind: ChangeEventKind::Removed,
+            reason: Some(
+                reinstall_reasons
+                    .get(distribution.name())
+                    .copied()
+                    .unwrap_or(ReinstallReason::Requested)
+                    .to_string(),
+            ),
         })
+        .chain(extraneous.into_iter().map(|distribution| DryRunEvent {
+            name: distribution.name().clone(),
+            version: distribution.installed_version().to_string(),
+            kind: ChangeEventKind::Removed,
+            reason: Some("not required".to_string()),
+        }))
         .chain(wheels.into_iter().map(|distribution| DryRunEvent {
             name: distribution.name().clone(),
             version: distribution.version_or_url().to_string(),
             kind: ChangeEventKind::Added,
+            reason: None,
         }))
         .chain(cached.into_iter().map(|distribution| DryRunEvent {
             name: distribution.name().clone(),
             version: distribution.installed_version().to_string(),
             kind: ChangeEventKind::Added,
+            reason: None,
         }))
         .sorted_unstable_by(|a, b| a.name.cmp(&b.name).then_with(|| a.kind.cmp(&b.kind)))
     {
@@ -685,10 +854,14 @@ fn report_dry_run(
             ChangeEventKind::Removed => {
                 writeln!(
                     printer.stderr(),
-                    " {} {}{}",
+                    " {} {}{}{}",
                     "-".red(),
                     event.name.bold(),
-                    event.version.dimmed()
+                    event.version.dimmed(),
+                    event
+                        .reason
+                        .map(|reason| format!(" ({reason})").dimmed().to_string())
+                        .unwrap_or_default()
                 )?;
             }
             ChangeEventKind::Reinstalled => {
@@ -706,6 +879,172 @@ fn report_dry_run(
     Ok(())
 }
 
+/// Report the planned changes to the environment as JSON, for consumption by automated tooling.
+fn report_dry_run_json(
+    plan: Plan,
+    modifications: Modifications,
+    printer: Printer,
+) -> Result<(), Error> {
+    /// The change that would be made to a package in the environment.
+    #[derive(Debug, Serialize)]
+    #[serde(rename_all = "lowercase")]
+    enum Action {
+        Install,
+        Reinstall,
+        Uninstall,
+    }
+
+    /// The reason for which a change would be made.
+    #[derive(Debug, Serialize)]
+    #[serde(rename_all = "kebab-case")]
+    enum Reason {
+        /// The package is required, but not installed.
+        Missing,
+        /// A reinstall was requested (e.g., via `--reinstall`).
+        Requested,
+        /// The installed version does not satisfy the requirement.
+        VersionMismatch,
+        /// The installed distribution is from a different source than the requirement.
+        SourceMismatch,
+        /// The installed distribution was built from a local source that has since changed.
+        OutOfDate,
+        /// Multiple versions of the package are installed.
+        MultipleVersions,
+        /// The installed archive does not match the required hashes.
+        HashMismatch,
+        /// The package is installed, but not required.
+        Extraneous,
+    }
+
+    impl From<ReinstallReason> for Reason {
+        fn from(reason: ReinstallReason) -> Self {
+            match reason {
+                ReinstallReason::Requested => Self::Requested,
+                ReinstallReason::VersionMismatch => Self::VersionMismatch,
+                ReinstallReason::SourceMismatch => Self::SourceMismatch,
+                ReinstallReason::OutOfDate => Self::OutOfDate,
+                ReinstallReason::MultipleVersions => Self::MultipleVersions,
+                ReinstallReason::HashMismatch => Self::HashMismatch,
+            }
+        }
+    }
+
+    /// A version or URL for a distribution.
+    #[derive(Debug, Clone, Serialize)]
+    struct Target {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        version: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        url: Option<String>,
+    }
+
+    impl From<VersionOrUrlRef<'_>> for Target {
+        fn from(version_or_url: VersionOrUrlRef<'_>) -> Self {
+            match version_or_url {
+                VersionOrUrlRef::Version(version) => Self {
+                    version: Some(version.to_string()),
+                    url: None,
+                },
+                VersionOrUrlRef::Url(url) => Self {
+                    version: None,
+                    url: Some(url.to_string()),
+                },
+            }
+        }
+    }
+
+    /// An entry in the JSON list of planned changes.
+    #[derive(Debug, Serialize)]
+    struct Change {
+        name: PackageName,
+        action: Action,
+        reason: Reason,
+        /// The currently installed distribution, if any.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        installed: Option<Target>,
+        /// The distribution that would be installed, if any.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        target: Option<Target>,
+    }
+
+    /// The JSON report of planned changes.
+    #[derive(Debug, Serialize)]
+    struct Report {
+        changes: Vec<Change>,
+    }
+
+    let Plan {
+        cached,
+        remote,
+        reinstalls,
+        reinstall_reasons,
+        extraneous,
+    } = plan;
+
+    // If we're in `install` mode, ignore any extraneous distributions.
+    let extraneous = match modifications {
+        Modifications::Sufficient => vec![],
+        Modifications::Exact => extraneous,
+    };
+
+    // Index the distributions that would be installed.
+    let mut targets = remote
+        .iter()
+        .map(|dist| (dist.name().clone(), Target::from(dist.version_or_url())))
+        .chain(
+            cached
+                .iter()
+                .map(|dist| (dist.name().clone(), Target::from(dist.version_or_url()))),
+        )
+        .collect::<BTreeMap<_, _>>();
+
+    let mut changes = Vec::new();
+    for dist in reinstalls {
+        let reason = reinstall_reasons
+            .get(dist.name())
+            .copied()
+            .map_or(Reason::Requested, Reason::from);
+        changes.push(Change {
+            name: dist.name().clone(),
+            action: Action::Reinstall,
+            reason,
+            installed: Some(Target::from(dist.version_or_url())),
+            target: targets.get(dist.name()).cloned(),
+        });
+    }
+    for change in &changes {
+        targets.remove(&change.name);
+    }
+    for (name, target) in targets {
+        changes.push(Change {
+            name,
+            action: Action::Install,
+            reason: Reason::Missing,
+            installed: None,
+            target: Some(target),
+        });
+    }
+    for dist in extraneous {
+        changes.push(Change {
+            name: dist.name().clone(),
+            action: Action::Uninstall,
+            reason: Reason::Extraneous,
+            installed: Some(Target::from(dist.version_or_url())),
+            target: None,
+        });
+    }
+    changes.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let report = Report { changes };
+    writeln!(
+        printer.stdout(),
+        "{}",
+        serde_json::to_string_pretty(&report).map_err(anyhow::Error::from)?
+    )?;
+
+    Ok(())
+}
+
 /// Report any diagnostics on resolved distributions.
 pub(crate) fn diagnose_resolution(
     diagnostics: &[ResolutionDiagnostic],
@@ -771,4 +1110,10 @@ pub(crate) enum Error {
 
     #[error(transparent)]
     Anyhow(#[from] anyhow::Error),
+
+    #[error("Network connectivity is disabled, but the following distributions to install are not available in the cache:\n{}", .0.iter().map(|dist| format!("  - {dist}")).join("\n"))]
+    MissingFromCache(Vec<String>),
+
+    #[error("The variant `{0}` is not declared by any source in `tool.uv.sources`{}", if .1.is_empty() { String::new() } else { format!(" (declared variants: {})", .1.iter().map(|variant| format!("`{variant}`")).join(", ")) })]
+    UnknownVariant(String, Vec<String>),
 }
@@ -0,0 +1,207 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use rustc_hash::FxHashSet;
+use serde::Serialize;
+
+use uv_distribution_types::{InstalledMetadata, Name, Resolution};
+use uv_fs::Simplified;
+use uv_installer::SitePackages;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_python::PythonEnvironment;
+use uv_resolver::InstallationReport;
+
+use crate::commands::pip::operations::Changelog;
+use crate::printer::Printer;
+
+/// The version of the install report format.
+const REPORT_VERSION: u32 = 1;
+
+/// A machine-readable report of the changes made to an environment during an installation.
+#[derive(Debug, Serialize)]
+pub(crate) struct InstallReport {
+    /// The version of the report format.
+    version: u32,
+    /// The environment into which the distributions were installed.
+    environment: ReportEnvironment,
+    /// The distributions that were installed, upgraded, downgraded, reinstalled, removed, or kept.
+    packages: Vec<ReportPackage>,
+}
+
+#[derive(Debug, Serialize)]
+struct ReportEnvironment {
+    /// The root of the environment.
+    path: String,
+    /// The version of the environment's Python interpreter.
+    python: String,
+}
+
+#[derive(Debug, Serialize)]
+struct ReportPackage {
+    name: PackageName,
+    version: Version,
+    action: ReportAction,
+    /// The version that was replaced, if the distribution was upgraded or downgraded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    previous_version: Option<Version>,
+    /// Whether the distribution was requested directly, as opposed to being included as a
+    /// transitive dependency.
+    requested: bool,
+    /// Whether the distribution is installed in editable mode.
+    editable: bool,
+    /// The direct URL from which the distribution was installed, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    /// The hashes of the distribution, as recorded in the resolution.
+    hashes: Vec<String>,
+}
+
+/// The action taken for a given distribution.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "kebab-case")]
+enum ReportAction {
+    /// The distribution was newly installed.
+    Installed,
+    /// The distribution replaced an existing installation at an older version.
+    Upgraded,
+    /// The distribution replaced an existing installation at a newer version.
+    Downgraded,
+    /// The distribution replaced an existing installation at the same version (e.g., from a
+    /// different source).
+    Reinstalled,
+    /// The distribution was removed, as it's no longer required.
+    Removed,
+    /// The distribution was already installed, and left as-is.
+    Kept,
+}
+
+impl InstallReport {
+    /// Construct an [`InstallReport`] from the current state of the environment, following an
+    /// installation of the given [`Resolution`].
+    pub(crate) fn from_environment(
+        venv: &PythonEnvironment,
+        resolution: &Resolution,
+        changelog: &Changelog,
+        requested: &FxHashSet<PackageName>,
+    ) -> Result<Self> {
+        let site_packages = SitePackages::from_environment(venv)?;
+
+        let mut packages = Vec::new();
+        let mut replaced = FxHashSet::default();
+
+        // Report on every distribution in the resolution, as installed in the environment.
+        for name in resolution.packages() {
+            let Some(dist) = site_packages.get_packages(name).into_iter().next() else {
+                continue;
+            };
+
+            let installed_version = dist.installed_version();
+            let version = installed_version.version();
+
+            // If the distribution replaced an existing installation of the same package, report
+            // the replacement as a single change.
+            let previous = changelog
+                .uninstalled
+                .iter()
+                .find(|dist| dist.name() == name)
+                .map(|dist| dist.installed_version().version().clone());
+
+            let action = if changelog.reinstalled.iter().any(|dist| dist.name() == name) {
+                ReportAction::Reinstalled
+            } else if changelog.installed.iter().any(|dist| dist.name() == name) {
+                match previous.as_ref() {
+                    None => ReportAction::Installed,
+                    Some(previous) if previous < version => ReportAction::Upgraded,
+                    Some(previous) if previous > version => ReportAction::Downgraded,
+                    Some(_) => ReportAction::Reinstalled,
+                }
+            } else {
+                ReportAction::Kept
+            };
+            if matches!(action, ReportAction::Upgraded | ReportAction::Downgraded) {
+                replaced.insert(name.clone());
+            }
+
+            packages.push(ReportPackage {
+                name: name.clone(),
+                version: version.clone(),
+                action,
+                previous_version: previous.filter(|_| {
+                    matches!(action, ReportAction::Upgraded | ReportAction::Downgraded)
+                }),
+                requested: requested.contains(name),
+                editable: dist.is_editable(),
+                url: installed_version.url().map(ToString::to_string),
+                hashes: resolution
+                    .get_hashes(name)
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect(),
+            });
+        }
+
+        // Report on any distributions that were removed from the environment, omitting those that
+        // were replaced by an upgrade or downgrade.
+        for dist in &changelog.uninstalled {
+            if replaced.contains(dist.name()) {
+                continue;
+            }
+            let installed_version = dist.installed_version();
+            packages.push(ReportPackage {
+                name: dist.name().clone(),
+                version: installed_version.version().clone(),
+                action: ReportAction::Removed,
+                previous_version: None,
+                requested: false,
+                editable: false,
+                url: installed_version.url().map(ToString::to_string),
+                hashes: Vec::new(),
+            });
+        }
+
+        packages.sort_by(|a, b| {
+            a.name
+                .cmp(&b.name)
+                .then_with(|| a.action.cmp(&b.action))
+                .then_with(|| a.version.cmp(&b.version))
+        });
+
+        Ok(Self {
+            version: REPORT_VERSION,
+            environment: ReportEnvironment {
+                path: venv.root().simplified_display().to_string(),
+                python: venv.interpreter().python_version().to_string(),
+            },
+            packages,
+        })
+    }
+
+    /// Write the report to the given path as JSON.
+    pub(crate) fn write(&self, path: &Path) -> Result<()> {
+        let mut contents = serde_json::to_string_pretty(self)?;
+        contents.push('\n');
+        fs_err::write(path, contents)
+            .with_context(|| format!("Failed to write report to: `{}`", path.user_display()))?;
+        Ok(())
+    }
+}
+
+/// Write a pip-compatible [`InstallationReport`] to the given path as JSON, or to stdout if the
+/// path is `-`.
+pub(crate) fn write_installation_report(
+    report: &InstallationReport,
+    path: &Path,
+    printer: Printer,
+) -> Result<()> {
+    let mut contents = serde_json::to_string_pretty(report)?;
+    contents.push('\n');
+    if path == Path::new("-") {
+        write!(printer.stdout(), "{contents}")?;
+    } else {
+        fs_err::write(path, contents)
+            .with_context(|| format!("Failed to write report to: `{}`", path.user_display()))?;
+    }
+    Ok(())
+}
@@ -1,4 +1,6 @@
 use std::fmt::Write;
+use std::io;
+use std::path::Path;
 
 use anyhow::Result;
 use fs_err::File;
@@ -7,8 +9,8 @@ use owo_colors::OwoColorize;
 use rustc_hash::FxHashMap;
 
 use uv_cache::Cache;
-use uv_distribution_types::{Diagnostic, Name};
-use uv_fs::Simplified;
+use uv_distribution_types::{Diagnostic, InstalledDist, Name};
+use uv_fs::{normalize_path, Simplified};
 use uv_install_wheel::read_record_file;
 use uv_installer::SitePackages;
 use uv_normalize::PackageName;
@@ -87,16 +89,14 @@ pub(crate) fn pip_show(
         return Ok(ExitStatus::Failure);
     }
 
-    // Since Requires and Required-by fields need data parsed from metadata, especially the
-    // Required-by field which needs to iterate over other installed packages' metadata.
-    // To prevent the need to parse metadata repeatedly when multiple packages need to be shown,
-    // we parse the metadata once and collect the needed data beforehand.
+    // Determine the requirements of every installed package, such that the `Required-by` field
+    // reflects the entire environment. Like `pip`, only unconditional requirements are included
+    // (i.e., those that apply without enabling any extras).
     let mut requires_map = FxHashMap::default();
-    // For Requires field
-    for dist in &distributions {
-        if let Ok(metadata) = dist.metadata() {
+    for installed in site_packages.iter() {
+        if let Ok(metadata) = installed.metadata() {
             requires_map.insert(
-                dist.name(),
+                installed.name(),
                 metadata
                     .requires_dist
                     .into_iter()
@@ -108,25 +108,6 @@ pub(crate) fn pip_show(
             );
         }
     }
-    // For Required-by field
-    if !requires_map.is_empty() {
-        for installed in site_packages.iter() {
-            if requires_map.contains_key(installed.name()) {
-                continue;
-            }
-            if let Ok(metadata) = installed.metadata() {
-                let requires = metadata
-                    .requires_dist
-                    .into_iter()
-                    .filter(|req| req.evaluate_markers(&markers, &[]))
-                    .map(|req| req.name)
-                    .collect_vec();
-                if !requires.is_empty() {
-                    requires_map.insert(installed.name(), requires);
-                }
-            }
-        }
-    }
 
     // Print the information for each package.
     for (i, distribution) in distributions.iter().enumerate() {
@@ -159,44 +140,46 @@ pub(crate) fn pip_show(
             )?;
         }
 
-        // If available, print the requirements.
-        if let Some(requires) = requires_map.get(distribution.name()) {
-            if requires.is_empty() {
-                writeln!(printer.stdout(), "Requires:")?;
-            } else {
-                writeln!(printer.stdout(), "Requires: {}", requires.iter().join(", "))?;
-            }
+        // Print the requirements, along with the installed packages that require this package.
+        let requires = requires_map
+            .get(distribution.name())
+            .map(|requires| requires.iter().join(", "))
+            .unwrap_or_default();
+        if requires.is_empty() {
+            writeln!(printer.stdout(), "Requires:")?;
+        } else {
+            writeln!(printer.stdout(), "Requires: {requires}")?;
+        }
+
+        let required_by = requires_map
+            .iter()
+            .filter(|(name, requires)| {
+                **name != distribution.name() && requires.contains(distribution.name())
+            })
+            .map(|(name, _)| name)
+            .sorted_unstable()
+            .dedup()
+            .join(", ");
+        if required_by.is_empty() {
+            writeln!(printer.stdout(), "Required-by:")?;
+        } else {
+            writeln!(printer.stdout(), "Required-by: {required_by}")?;
+        }
 
-            let required_by = requires_map
-                .iter()
-                .filter(|(name, pkgs)| {
-                    **name != distribution.name()
-                        && pkgs.iter().any(|pkg| pkg == distribution.name())
-                })
-                .map(|(name, _)| name)
-                .sorted_unstable()
-                .dedup()
-                .collect_vec();
-            if required_by.is_empty() {
-                writeln!(printer.stdout(), "Required-by:")?;
+        // If requested, show the list of installed files.
+        if files {
+            if let Some(files) = installed_files(distribution)? {
+                writeln!(printer.stdout(), "Files:")?;
+                for file in files {
+                    writeln!(printer.stdout(), "  {file}")?;
+                }
             } else {
                 writeln!(
                     printer.stdout(),
-                    "Required-by: {}",
-                    required_by.into_iter().join(", "),
+                    "Cannot locate RECORD or installed-files.txt"
                 )?;
             }
         }
-
-        // If requests, show the list of installed files.
-        if files {
-            let path = distribution.path().join("RECORD");
-            let record = read_record_file(&mut File::open(path)?)?;
-            writeln!(printer.stdout(), "Files:")?;
-            for entry in record {
-                writeln!(printer.stdout(), "  {}", entry.path)?;
-            }
-        }
     }
 
     // Validate that the environment is consistent.
@@ -214,3 +197,49 @@ pub(crate) fn pip_show(
 
     Ok(ExitStatus::Success)
 }
+
+/// Return the installed files of a distribution, relative to its `site-packages` directory.
+///
+/// Like `pip`, files are read from the `RECORD` file of a `.dist-info` directory, or from the
+/// `installed-files.txt` file of an `.egg-info` directory. Returns `None` if neither is available.
+fn installed_files(dist: &InstalledDist) -> Result<Option<Vec<String>>> {
+    match dist {
+        InstalledDist::Registry(_) | InstalledDist::Url(_) => {
+            let mut record = match File::open(dist.path().join("RECORD")) {
+                Ok(record) => record,
+                Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+                Err(err) => return Err(err.into()),
+            };
+            Ok(Some(
+                read_record_file(&mut record)?
+                    .into_iter()
+                    .map(|entry| entry.path)
+                    .collect(),
+            ))
+        }
+        InstalledDist::EggInfoDirectory(dist) => {
+            let contents = match fs_err::read_to_string(dist.path.join("installed-files.txt")) {
+                Ok(contents) => contents,
+                Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+                Err(err) => return Err(err.into()),
+            };
+
+            // The paths in `installed-files.txt` are relative to the `.egg-info` directory.
+            let Some(egg_info) = dist.path.file_name() else {
+                return Ok(None);
+            };
+            Ok(Some(
+                contents
+                    .lines()
+                    .filter(|line| !line.trim().is_empty())
+                    .map(|line| {
+                        normalize_path(&Path::new(egg_info).join(line))
+                            .portable_display()
+                            .to_string()
+                    })
+                    .collect(),
+            ))
+        }
+        InstalledDist::EggInfoFile(_) | InstalledDist::LegacyEditable(_) => Ok(None),
+    }
+}
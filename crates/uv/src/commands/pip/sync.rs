@@ -7,8 +7,9 @@ use tracing::debug;
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    BuildOptions, Concurrency, ConfigSettings, Constraints, ExtrasSpecification, HashCheckingMode,
-    IndexStrategy, LowerBound, Reinstall, SourceStrategy, TrustedHost, Upgrade,
+    BuildContainers, BuildOptions, BytecodeOptions, Concurrency, ConfigSettings, Constraints,
+    ExtrasSpecification, HashCheckingMode, IndexStrategy, LowerBound, Reinstall, SourceStrategy,
+    TrustedHost, Upgrade,
 };
 use uv_configuration::{KeyringProviderType, TargetTriple};
 use uv_dispatch::BuildDispatch;
@@ -17,19 +18,20 @@ use uv_fs::Simplified;
 use uv_install_wheel::linker::LinkMode;
 use uv_installer::SitePackages;
 use uv_pep508::PackageName;
+use uv_pypi_types::Requirement;
 use uv_python::{
     EnvironmentPreference, Prefix, PythonEnvironment, PythonRequest, PythonVersion, Target,
 };
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
 use uv_resolver::{
     DependencyMode, ExcludeNewer, FlatIndex, OptionsBuilder, PrereleaseMode, PythonRequirement,
-    ResolutionMode, ResolverMarkers,
+    ResolutionMode, ResolverMarkers, YankedMode,
 };
 use uv_types::{BuildIsolation, HashStrategy};
 
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger};
 use crate::commands::pip::operations::report_target_environment;
-use crate::commands::pip::operations::Modifications;
+use crate::commands::pip::operations::{DryRun, Modifications};
 use crate::commands::pip::{operations, resolution_markers, resolution_tags};
 use crate::commands::{diagnostics, ExitStatus, SharedState};
 use crate::printer::Printer;
@@ -43,15 +45,19 @@ pub(crate) async fn pip_sync(
     reinstall: Reinstall,
     link_mode: LinkMode,
     compile: bool,
+    bytecode_options: &BytecodeOptions,
     hash_checking: Option<HashCheckingMode>,
     index_locations: IndexLocations,
     index_strategy: IndexStrategy,
+    yanked_mode: YankedMode,
+    allow_yanked: Vec<Requirement>,
     dependency_metadata: DependencyMetadata,
     keyring_provider: KeyringProviderType,
     allow_insecure_host: Vec<TrustedHost>,
     allow_empty_requirements: bool,
     connectivity: Connectivity,
     config_settings: &ConfigSettings,
+    build_containers: &BuildContainers,
     no_build_isolation: bool,
     no_build_isolation_package: Vec<PackageName>,
     build_options: BuildOptions,
@@ -68,14 +74,15 @@ pub(crate) async fn pip_sync(
     concurrency: Concurrency,
     native_tls: bool,
     cache: Cache,
-    dry_run: bool,
+    dry_run: DryRun,
     printer: Printer,
 ) -> Result<ExitStatus> {
     let client_builder = BaseClientBuilder::new()
         .connectivity(connectivity)
         .native_tls(native_tls)
         .keyring(keyring_provider)
-        .allow_insecure_host(allow_insecure_host);
+        .allow_insecure_host(allow_insecure_host)
+        .indexes(index_locations.allowed_indexes());
 
     // Initialize a few defaults.
     let overrides = &[];
@@ -105,12 +112,13 @@ pub(crate) async fn pip_sync(
         overrides,
         &extras,
         &client_builder,
+        &cache,
     )
     .await?;
 
     // Read build constraints.
     let build_constraints =
-        operations::read_constraints(build_constraints, &client_builder).await?;
+        operations::read_constraints(build_constraints, &client_builder, &cache).await?;
 
     // Validate that the requirements are non-empty.
     if !allow_empty_requirements {
@@ -229,6 +237,8 @@ pub(crate) async fn pip_sync(
     for index in index_locations.allowed_indexes() {
         if let Some(credentials) = index.credentials() {
             uv_auth::store_credentials(index.raw_url(), credentials);
+        } else if let Some(secrets) = index.secret_credentials() {
+            uv_auth::store_secret_credentials(secrets);
         }
     }
 
@@ -246,7 +256,8 @@ pub(crate) async fn pip_sync(
 
     // Resolve the flat indexes from `--find-links`.
     let flat_index = {
-        let client = FlatIndexClient::new(&client, &cache);
+        let client = FlatIndexClient::new(&client, &cache)
+            .with_recursive(index_locations.find_links_recursive());
         let entries = client
             .fetch(index_locations.flat_indexes().map(Index::url))
             .await?;
@@ -302,10 +313,12 @@ pub(crate) async fn pip_sync(
         &dependency_metadata,
         &state.index,
         &state.git,
+        &state.hg,
         &state.capabilities,
         &state.in_flight,
         index_strategy,
         config_settings,
+        build_containers,
         build_isolation,
         link_mode,
         &build_options,
@@ -322,6 +335,7 @@ pub(crate) async fn pip_sync(
     let options = OptionsBuilder::new()
         .resolution_mode(resolution_mode)
         .prerelease_mode(prerelease_mode)
+        .yanked_mode(yanked_mode)
         .dependency_mode(dependency_mode)
         .exclude_newer(exclude_newer)
         .index_strategy(index_strategy)
@@ -341,6 +355,8 @@ pub(crate) async fn pip_sync(
         &hasher,
         &reinstall,
         &upgrade,
+        &allow_yanked,
+        None,
         Some(&tags),
         ResolverMarkers::specific_environment(markers.clone()),
         python_requirement,
@@ -380,6 +396,7 @@ pub(crate) async fn pip_sync(
         &build_options,
         link_mode,
         compile,
+        bytecode_options,
         &index_locations,
         config_settings,
         &hasher,
@@ -390,6 +407,7 @@ pub(crate) async fn pip_sync(
         &build_dispatch,
         &cache,
         &environment,
+        None,
         Box::new(DefaultInstallLogger),
         dry_run,
         printer,
@@ -400,7 +418,7 @@ pub(crate) async fn pip_sync(
     operations::diagnose_resolution(resolution.diagnostics(), printer)?;
 
     // Notify the user of any environment diagnostics.
-    if strict && !dry_run {
+    if strict && !dry_run.enabled() {
         operations::diagnose_environment(&resolution, &environment, &markers, printer)?;
     }
 
@@ -46,7 +46,8 @@ pub(crate) async fn pip_uninstall(
         .allow_insecure_host(allow_insecure_host);
 
     // Read all requirements from the provided sources.
-    let spec = RequirementsSpecification::from_simple_sources(sources, &client_builder).await?;
+    let spec =
+        RequirementsSpecification::from_simple_sources(sources, &client_builder, &cache).await?;
 
     // Detect the current Python interpreter.
     let environment = PythonEnvironment::find(
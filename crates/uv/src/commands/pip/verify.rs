@@ -0,0 +1,176 @@
+use std::fmt::Write;
+use std::path::Path;
+use std::time::Instant;
+
+use anyhow::{Context, Result};
+use itertools::{Either, Itertools};
+use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_distribution_types::{InstalledDist, Name};
+use uv_fs::Simplified;
+use uv_install_wheel::{read_installed_record, verify_installed, RecordMismatch, VerifyError};
+use uv_installer::SitePackages;
+use uv_normalize::PackageName;
+use uv_python::{EnvironmentPreference, PythonEnvironment, PythonRequest};
+
+use crate::commands::pip::operations::report_target_environment;
+use crate::commands::{elapsed, ExitStatus};
+use crate::printer::Printer;
+
+/// Verify installed packages against the hashes and sizes in their `RECORD` files.
+pub(crate) fn pip_verify(
+    mut packages: Vec<PackageName>,
+    python: Option<&str>,
+    system: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let start = Instant::now();
+
+    // Detect the current Python interpreter.
+    let environment = PythonEnvironment::find(
+        &python.map(PythonRequest::parse).unwrap_or_default(),
+        EnvironmentPreference::from_system_flag(system, false),
+        cache,
+    )?;
+
+    report_target_environment(&environment, cache, printer)?;
+
+    // Build the installed index.
+    let site_packages = SitePackages::from_environment(&environment)?;
+
+    // Read the `RECORD` file of every installed distribution, such that files recorded by one
+    // distribution aren't reported as extra files of another (e.g., in namespace packages).
+    let mut records = Vec::new();
+    let mut recorded = FxHashSet::default();
+    for dist in site_packages.iter() {
+        if !matches!(dist, InstalledDist::Registry(_) | InstalledDist::Url(_)) {
+            continue;
+        }
+        match read_installed_record(dist.path()) {
+            Ok(files) => {
+                recorded.extend(files.iter().map(|file| file.path.clone()));
+                records.push((dist, Ok(files)));
+            }
+            Err(err) => records.push((dist, Err(err))),
+        }
+    }
+
+    // If specific packages were requested, only verify those packages.
+    if !packages.is_empty() {
+        packages.sort_unstable();
+        packages.dedup();
+
+        let (missing, _): (Vec<_>, Vec<_>) = packages.iter().partition_map(|name| {
+            if site_packages.get_packages(name).is_empty() {
+                Either::Left(name)
+            } else {
+                Either::Right(name)
+            }
+        });
+        if !missing.is_empty() {
+            writeln!(
+                printer.stderr(),
+                "{}{} Package(s) not found for: {}",
+                "warning".yellow().bold(),
+                ":".bold(),
+                missing.iter().join(", ").bold()
+            )?;
+        }
+
+        records.retain(|(dist, _)| packages.contains(dist.name()));
+    }
+
+    // Verify each distribution.
+    let mut diagnostics = Vec::new();
+    for (dist, files) in &records {
+        let site_packages = dist.path().parent().unwrap_or(dist.path());
+        let mismatches = match files {
+            Ok(files) => verify_installed(site_packages, files, &recorded)
+                .with_context(|| format!("Failed to verify `{}`", dist.name()))?,
+            Err(VerifyError::MissingRecord) => {
+                debug!("Skipping `{dist}`, which is missing a `RECORD` file");
+                continue;
+            }
+            Err(err) => {
+                diagnostics.push(format!(
+                    "The package `{}` has an invalid `RECORD` file: {err}",
+                    dist.name()
+                ));
+                continue;
+            }
+        };
+        for mismatch in mismatches {
+            diagnostics.push(message(dist, &mismatch, site_packages));
+        }
+    }
+
+    let s = if records.len() == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Verified {} {}",
+            format!("{} package{}", records.len(), s).bold(),
+            format!("in {}", elapsed(start.elapsed())).dimmed()
+        )
+        .dimmed()
+    )?;
+
+    if diagnostics.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "{}",
+            "All installed files match their `RECORD` files"
+                .to_string()
+                .dimmed()
+        )?;
+
+        Ok(ExitStatus::Success)
+    } else {
+        let discrepancies = if diagnostics.len() == 1 {
+            "discrepancy"
+        } else {
+            "discrepancies"
+        };
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!(
+                "Found {}",
+                format!("{} {}", diagnostics.len(), discrepancies).bold()
+            )
+            .dimmed()
+        )?;
+
+        for diagnostic in &diagnostics {
+            writeln!(printer.stderr(), "{}", diagnostic.bold())?;
+        }
+
+        Ok(ExitStatus::Failure)
+    }
+}
+
+/// Render a [`RecordMismatch`] for the given distribution.
+fn message(dist: &InstalledDist, mismatch: &RecordMismatch, site_packages: &Path) -> String {
+    match mismatch {
+        RecordMismatch::Modified(path) => format!(
+            "The package `{}` has a modified file: `{}`",
+            dist.name(),
+            path.user_display_from(site_packages)
+        ),
+        RecordMismatch::Missing(path) => format!(
+            "The package `{}` is missing a file: `{}`",
+            dist.name(),
+            path.user_display_from(site_packages)
+        ),
+        RecordMismatch::Extra(path) => format!(
+            "The package `{}` has an extra file: `{}`",
+            dist.name(),
+            path.user_display_from(site_packages)
+        ),
+    }
+}
@@ -21,6 +21,7 @@ use uv_distribution::DistributionDatabase;
 use uv_distribution_types::{Index, IndexName, UnresolvedRequirement, VersionId};
 use uv_fs::Simplified;
 use uv_git::{GitReference, GIT_STORE};
+use uv_hg::HgReference;
 use uv_normalize::{PackageName, DEV_DEPENDENCIES};
 use uv_pep508::{ExtraName, Requirement, UnnamedRequirement, VersionOrUrl};
 use uv_pypi_types::{redact_credentials, ParsedUrl, RequirementSource, VerbatimParsedUrl};
@@ -42,7 +43,8 @@ use crate::commands::pip::loggers::{
 };
 use crate::commands::pip::operations::Modifications;
 use crate::commands::pip::resolution_environment;
-use crate::commands::project::lock::LockMode;
+use crate::commands::project::hooks::{run_hooks, Hook};
+use crate::commands::project::lock::{LockMode, LockResult};
 use crate::commands::project::{script_python_requirement, ProjectError};
 use crate::commands::reporters::{PythonDownloadReporter, ResolverReporter};
 use crate::commands::{diagnostics, pip, project, ExitStatus, SharedState};
@@ -56,6 +58,7 @@ pub(crate) async fn add(
     locked: bool,
     frozen: bool,
     no_sync: bool,
+    no_hooks: bool,
     requirements: Vec<RequirementsSource>,
     editable: Option<bool>,
     dependency_type: DependencyType,
@@ -64,6 +67,7 @@ pub(crate) async fn add(
     rev: Option<String>,
     tag: Option<String>,
     branch: Option<String>,
+    bookmark: Option<String>,
     extras: Vec<ExtraName>,
     package: Option<PackageName>,
     python: Option<String>,
@@ -124,7 +128,8 @@ pub(crate) async fn add(
 
         let client_builder = BaseClientBuilder::new()
             .connectivity(connectivity)
-            .native_tls(native_tls);
+            .native_tls(native_tls)
+            .indexes(settings.index_locations.allowed_indexes());
 
         // If we found a script, add to the existing metadata. Otherwise, create a new inline
         // metadata tag.
@@ -228,11 +233,13 @@ pub(crate) async fn add(
     let client_builder = BaseClientBuilder::new()
         .connectivity(connectivity)
         .native_tls(native_tls)
-        .keyring(settings.keyring_provider);
+        .keyring(settings.keyring_provider)
+        .indexes(settings.index_locations.allowed_indexes());
 
     // Read the requirements.
     let RequirementsSpecification { requirements, .. } =
-        RequirementsSpecification::from_simple_sources(&requirements, &client_builder).await?;
+        RequirementsSpecification::from_simple_sources(&requirements, &client_builder, cache)
+            .await?;
 
     // TODO(charlie): These are all default values. We should consider whether we want to make them
     // optional on the downstream APIs.
@@ -252,6 +259,8 @@ pub(crate) async fn add(
     for index in settings.index_locations.allowed_indexes() {
         if let Some(credentials) = index.credentials() {
             uv_auth::store_credentials(index.raw_url(), credentials);
+        } else if let Some(secrets) = index.secret_credentials() {
+            uv_auth::store_secret_credentials(secrets);
         }
     }
 
@@ -280,7 +289,8 @@ pub(crate) async fn add(
 
     // Resolve the flat indexes from `--find-links`.
     let flat_index = {
-        let client = FlatIndexClient::new(&client, cache);
+        let client = FlatIndexClient::new(&client, cache)
+            .with_recursive(settings.index_locations.find_links_recursive());
         let entries = client
             .fetch(settings.index_locations.flat_indexes().map(Index::url))
             .await?;
@@ -298,10 +308,12 @@ pub(crate) async fn add(
         &settings.dependency_metadata,
         &state.index,
         &state.git,
+        &state.hg,
         &state.capabilities,
         &state.in_flight,
         settings.index_strategy,
         &settings.config_setting,
+        &settings.build_containers,
         build_isolation,
         settings.link_mode,
         &settings.build_options,
@@ -310,7 +322,8 @@ pub(crate) async fn add(
         bounds,
         sources,
         concurrency,
-    );
+    )
+    .with_variants(&settings.variants);
 
     // Resolve any unnamed requirements.
     let requirements = {
@@ -323,6 +336,7 @@ pub(crate) async fn add(
                     rev.as_deref(),
                     tag.as_deref(),
                     branch.as_deref(),
+                    bookmark.as_deref(),
                 )
             })
             .partition_map(|requirement| match requirement {
@@ -338,7 +352,7 @@ pub(crate) async fn add(
                 NamedRequirementsResolver::new(
                     &hasher,
                     &state.index,
-                    DistributionDatabase::new(&client, &build_dispatch, concurrency.downloads),
+                    DistributionDatabase::new(&client, &build_dispatch, concurrency),
                 )
                 .with_reporter(ResolverReporter::from(printer))
                 .resolve(unnamed.into_iter())
@@ -409,6 +423,7 @@ pub(crate) async fn add(
                     rev.clone(),
                     tag.clone(),
                     branch.clone(),
+                    bookmark.clone(),
                     script_dir,
                 )?
             }
@@ -425,6 +440,7 @@ pub(crate) async fn add(
                     rev.clone(),
                     tag.clone(),
                     branch.clone(),
+                    bookmark.clone(),
                     project.root(),
                 )?
             }
@@ -442,6 +458,7 @@ pub(crate) async fn add(
                 rev,
                 tag,
                 branch,
+                variant,
                 marker,
             }) => {
                 let credentials = uv_auth::Credentials::from_url(&git);
@@ -458,6 +475,7 @@ pub(crate) async fn add(
                     rev,
                     tag,
                     branch,
+                    variant,
                     marker,
                 })
             }
@@ -629,6 +647,7 @@ pub(crate) async fn add(
         state,
         locked,
         no_sync,
+        no_hooks,
         &dependency_type,
         raw_sources,
         settings.as_ref(),
@@ -689,6 +708,7 @@ async fn lock_and_sync(
     state: SharedState,
     locked: bool,
     no_sync: bool,
+    no_hooks: bool,
     dependency_type: &DependencyType,
     raw_sources: bool,
     settings: ResolverInstallerSettingsRef<'_>,
@@ -705,7 +725,7 @@ async fn lock_and_sync(
         LockMode::Write(venv.interpreter())
     };
 
-    let mut lock = project::lock::do_safe_lock(
+    let result = project::lock::do_safe_lock(
         mode,
         project.workspace(),
         settings.into(),
@@ -718,8 +738,9 @@ async fn lock_and_sync(
         cache,
         printer,
     )
-    .await?
-    .into_lock();
+    .await?;
+    let mut changed = matches!(result, LockResult::Changed(..));
+    let mut lock = result.into_lock();
 
     // Avoid modifying the user request further if `--raw-sources` is set.
     if !raw_sources {
@@ -822,7 +843,7 @@ async fn lock_and_sync(
 
             // If the file was modified, we have to lock again, though the only expected change is
             // the addition of the minimum version specifiers.
-            lock = project::lock::do_safe_lock(
+            let result = project::lock::do_safe_lock(
                 mode,
                 project.workspace(),
                 settings.into(),
@@ -835,11 +856,17 @@ async fn lock_and_sync(
                 cache,
                 printer,
             )
-            .await?
-            .into_lock();
+            .await?;
+            changed |= matches!(result, LockResult::Changed(..));
+            lock = result.into_lock();
         }
     }
 
+    // If the lockfile was updated, run the `post-lock` hooks.
+    if !no_hooks && changed {
+        run_hooks(Hook::PostLock, project.workspace(), Some(venv), printer).await?;
+    }
+
     if no_sync {
         return Ok(());
     }
@@ -869,6 +896,10 @@ async fn lock_and_sync(
         }
     };
 
+    if !no_hooks {
+        run_hooks(Hook::PreSync, project.workspace(), Some(venv), printer).await?;
+    }
+
     project::sync::do_sync(
         InstallTarget::from(&project),
         venv,
@@ -878,6 +909,7 @@ async fn lock_and_sync(
         EditableMode::Editable,
         InstallOptions::default(),
         Modifications::Sufficient,
+        None,
         settings.into(),
         Box::new(DefaultInstallLogger),
         connectivity,
@@ -888,6 +920,10 @@ async fn lock_and_sync(
     )
     .await?;
 
+    if !no_hooks {
+        run_hooks(Hook::PostSync, project.workspace(), Some(venv), printer).await?;
+    }
+
     Ok(())
 }
 
@@ -898,6 +934,7 @@ fn augment_requirement(
     rev: Option<&str>,
     tag: Option<&str>,
     branch: Option<&str>,
+    bookmark: Option<&str>,
 ) -> UnresolvedRequirement {
     match requirement {
         UnresolvedRequirement::Named(requirement) => {
@@ -927,6 +964,30 @@ fn augment_requirement(
                             url,
                         }
                     }
+                    RequirementSource::Hg {
+                        repository,
+                        reference,
+                        precise,
+                        subdirectory,
+                        url,
+                    } => {
+                        let reference = if let Some(rev) = rev {
+                            HgReference::from_rev(rev.to_string())
+                        } else if let Some(branch) = branch {
+                            HgReference::Branch(branch.to_string())
+                        } else if let Some(bookmark) = bookmark {
+                            HgReference::Bookmark(bookmark.to_string())
+                        } else {
+                            reference
+                        };
+                        RequirementSource::Hg {
+                            repository,
+                            reference,
+                            precise,
+                            subdirectory,
+                            url,
+                        }
+                    }
                     _ => requirement.source,
                 },
                 ..requirement
@@ -951,6 +1012,22 @@ fn augment_requirement(
                             verbatim: requirement.url.verbatim,
                         }
                     }
+                    ParsedUrl::Hg(mut hg) => {
+                        let reference = if let Some(rev) = rev {
+                            Some(HgReference::from_rev(rev.to_string()))
+                        } else if let Some(branch) = branch {
+                            Some(HgReference::Branch(branch.to_string()))
+                        } else {
+                            bookmark.map(|bookmark| HgReference::Bookmark(bookmark.to_string()))
+                        };
+                        if let Some(reference) = reference {
+                            hg.url = hg.url.with_reference(reference);
+                        }
+                        VerbatimParsedUrl {
+                            parsed_url: ParsedUrl::Hg(hg),
+                            verbatim: requirement.url.verbatim,
+                        }
+                    }
                     _ => requirement.url,
                 },
                 ..requirement
@@ -968,6 +1045,7 @@ fn resolve_requirement(
     rev: Option<String>,
     tag: Option<String>,
     branch: Option<String>,
+    bookmark: Option<String>,
     root: &Path,
 ) -> Result<(Requirement, Option<Source>), anyhow::Error> {
     let result = Source::from_requirement(
@@ -979,6 +1057,7 @@ fn resolve_requirement(
         rev,
         tag,
         branch,
+        bookmark,
         root,
     );
 
@@ -0,0 +1,364 @@
+use std::fmt::Write;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+use tracing::debug;
+use zip::write::FileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+use uv_cache::Cache;
+use uv_client::Connectivity;
+use uv_configuration::{
+    Concurrency, DevGroupsSpecification, EditableMode, ExtrasSpecification, InstallOptions,
+    LowerBound,
+};
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_python::managed::ManagedPythonInstallations;
+use uv_python::{Interpreter, PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
+use uv_virtualenv::Prompt;
+use uv_warnings::warn_user;
+use uv_workspace::{DiscoveryOptions, InstallTarget, MemberDiscovery, VirtualProject, Workspace};
+
+use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger};
+use crate::commands::pip::operations::{self, Modifications};
+use crate::commands::project::lock::{do_safe_lock, LockMode};
+use crate::commands::project::sync::do_sync;
+use crate::commands::project::{
+    default_dependency_groups, validate_dependency_groups, ProjectError, ProjectInterpreter,
+};
+use crate::commands::{diagnostics, ExitStatus, SharedState};
+use crate::printer::Printer;
+use crate::settings::ResolverInstallerSettings;
+
+/// Bundle the project environment into a relocatable archive.
+#[allow(clippy::fn_params_excessive_bools)]
+pub(crate) async fn bundle(
+    project_dir: &Path,
+    output_file: Option<PathBuf>,
+    include_python: Option<bool>,
+    package: Option<PackageName>,
+    extras: ExtrasSpecification,
+    dev: DevGroupsSpecification,
+    install_options: InstallOptions,
+    locked: bool,
+    frozen: bool,
+    python: Option<String>,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    settings: ResolverInstallerSettings,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    native_tls: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    // Identify the project.
+    let project = if frozen {
+        VirtualProject::discover(
+            project_dir,
+            &DiscoveryOptions {
+                members: MemberDiscovery::None,
+                ..DiscoveryOptions::default()
+            },
+        )
+        .await?
+    } else if let Some(package) = package.as_ref() {
+        VirtualProject::Project(
+            Workspace::discover(project_dir, &DiscoveryOptions::default())
+                .await?
+                .with_current_project(package.clone())
+                .with_context(|| format!("Package `{package}` not found in workspace"))?,
+        )
+    } else {
+        VirtualProject::discover(project_dir, &DiscoveryOptions::default()).await?
+    };
+
+    // Identify the target.
+    let target = if let Some(package) = package.as_ref().filter(|_| frozen) {
+        InstallTarget::frozen_member(&project, package)
+    } else {
+        InstallTarget::from(&project)
+    };
+
+    // Determine the default groups to include.
+    validate_dependency_groups(&project, &dev)?;
+    let defaults = default_dependency_groups(project.pyproject_toml())?;
+
+    let VirtualProject::Project(project) = &project else {
+        return Err(anyhow::anyhow!("Legacy non-project roots are not supported in `uv bundle`; add a `[project]` table to your `pyproject.toml` to enable bundling"));
+    };
+
+    // Determine the output path.
+    let output_file = output_file.unwrap_or_else(|| {
+        project
+            .workspace()
+            .install_path()
+            .join("dist")
+            .join(format!("{}-bundle.zip", project.project_name()))
+    });
+
+    // Find an interpreter for the project.
+    let interpreter = ProjectInterpreter::discover(
+        project.workspace(),
+        python.as_deref().map(PythonRequest::parse),
+        python_preference,
+        python_downloads,
+        connectivity,
+        native_tls,
+        cache,
+        printer,
+    )
+    .await?
+    .into_interpreter();
+
+    // The bundle must be built against the base interpreter, rather than the project environment.
+    let interpreter = match interpreter.to_base_interpreter(cache)? {
+        Some(base) => base,
+        None => interpreter,
+    };
+
+    // Initialize any shared state.
+    let state = SharedState::default();
+
+    // Determine the lock mode.
+    let mode = if frozen {
+        LockMode::Frozen
+    } else if locked {
+        LockMode::Locked(&interpreter)
+    } else {
+        LockMode::Write(&interpreter)
+    };
+
+    let lock = match do_safe_lock(
+        mode,
+        project.workspace(),
+        settings.as_ref().into(),
+        LowerBound::Warn,
+        &state,
+        Box::new(DefaultResolveLogger),
+        connectivity,
+        concurrency,
+        native_tls,
+        cache,
+        printer,
+    )
+    .await
+    {
+        Ok(result) => result.into_lock(),
+        Err(ProjectError::Operation(operations::Error::Resolve(
+            uv_resolver::ResolveError::NoSolution(err),
+        ))) => {
+            diagnostics::no_solution(&err);
+            return Ok(ExitStatus::Failure);
+        }
+        Err(ProjectError::Operation(operations::Error::Resolve(
+            uv_resolver::ResolveError::FetchAndBuild(dist, err),
+        ))) => {
+            diagnostics::fetch_and_build(dist, err);
+            return Ok(ExitStatus::Failure);
+        }
+        Err(ProjectError::Operation(operations::Error::Resolve(
+            uv_resolver::ResolveError::Build(dist, err),
+        ))) => {
+            diagnostics::build(dist, err);
+            return Ok(ExitStatus::Failure);
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    // Stage the bundle in the cache, such that wheels can be linked rather than copied.
+    let staging = cache.environment()?;
+    let root = staging.path().join(project.project_name().as_str());
+
+    // A virtual environment is only usable on machines with the same interpreter at the same path,
+    // so include the interpreter whenever it can be relocated.
+    let managed = is_managed(&interpreter, &ManagedPythonInstallations::from_settings()?);
+    let include_python = match include_python {
+        Some(true) if !managed => {
+            return Err(anyhow::anyhow!(
+                "`--include-python` requires a managed Python installation, but {} is not managed by uv; use `--python-preference only-managed` to select a managed interpreter",
+                interpreter.sys_executable().user_display().cyan()
+            ));
+        }
+        Some(include_python) => include_python,
+        None => {
+            if !managed {
+                warn_user!(
+                    "The bundle refers to the Python interpreter at `{}`, which is not managed by uv and must be present at the same path on the target machine; use `--python-preference only-managed` to include a managed interpreter in the bundle, or `--no-include-python` to silence this warning",
+                    interpreter.sys_executable().user_display()
+                );
+            }
+            managed
+        }
+    };
+
+    let environment = if include_python {
+        // Copy the managed Python installation into the bundle, then install into it directly.
+        debug!(
+            "Copying Python installation from: {}",
+            interpreter.sys_base_prefix().user_display()
+        );
+        copy_dir_all(interpreter.sys_base_prefix(), &root)?;
+
+        // Locate the interpreter within the bundle.
+        let executable = interpreter
+            .sys_executable()
+            .strip_prefix(interpreter.sys_base_prefix())
+            .map(|executable| root.join(executable))
+            .with_context(|| {
+                format!(
+                    "Failed to locate {} within the Python installation",
+                    interpreter.sys_executable().user_display()
+                )
+            })?;
+
+        PythonEnvironment::from_interpreter(Interpreter::query(executable, cache)?)
+            .with_relocatable()
+    } else {
+        uv_virtualenv::create_venv(
+            &root,
+            interpreter,
+            Prompt::Static(project.project_name().to_string()),
+            false,
+            false,
+            true,
+            false,
+        )?
+    };
+
+    // Install the project and its dependencies. Workspace members are always installed as
+    // non-editable, as the bundle must not refer back to the source tree.
+    do_sync(
+        target,
+        &environment,
+        &lock,
+        &extras,
+        &dev.with_defaults(defaults),
+        EditableMode::NonEditable,
+        install_options,
+        Modifications::Sufficient,
+        None,
+        settings.as_ref().into(),
+        Box::new(DefaultInstallLogger),
+        connectivity,
+        concurrency,
+        native_tls,
+        cache,
+        printer,
+    )
+    .await?;
+
+    // Write the archive.
+    if let Some(parent) = output_file.parent() {
+        fs_err::create_dir_all(parent)?;
+    }
+    write_archive(staging.path(), &output_file)
+        .with_context(|| format!("Failed to write bundle to: {}", output_file.user_display()))?;
+
+    writeln!(
+        printer.stderr(),
+        "Created bundle at: {}",
+        output_file.user_display().cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Returns `true` if the [`Interpreter`] is part of a managed Python installation.
+fn is_managed(interpreter: &Interpreter, installations: &ManagedPythonInstallations) -> bool {
+    let Ok(root) = fs_err::canonicalize(installations.root()) else {
+        return false;
+    };
+    fs_err::canonicalize(interpreter.sys_base_prefix()).is_ok_and(|prefix| prefix.starts_with(root))
+}
+
+/// Recursively copy a directory, preserving symbolic links.
+fn copy_dir_all(src: &Path, dst: &Path) -> std::io::Result<()> {
+    fs_err::create_dir_all(dst)?;
+    for entry in fs_err::read_dir(src)? {
+        let entry = entry?;
+        let path = entry.path();
+        let target = dst.join(entry.file_name());
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            copy_dir_all(&path, &target)?;
+        } else if file_type.is_symlink() {
+            #[cfg(unix)]
+            fs_err::os::unix::fs::symlink(fs_err::read_link(&path)?, &target)?;
+            #[cfg(windows)]
+            fs_err::copy(&path, &target)?;
+        } else {
+            fs_err::copy(&path, &target)?;
+        }
+    }
+    Ok(())
+}
+
+/// Write the contents of the staging directory to a zip archive, preserving symbolic links and
+/// Unix permissions.
+fn write_archive(staging: &Path, output_file: &Path) -> Result<()> {
+    let file = fs_err::File::create(output_file)?;
+    let mut writer = ZipWriter::new(file);
+    add_directory(&mut writer, staging, staging)?;
+    writer.finish()?;
+    Ok(())
+}
+
+/// Add the contents of a directory to the archive, recursively.
+pub(super) fn add_directory<W: std::io::Write + std::io::Seek>(
+    writer: &mut ZipWriter<W>,
+    staging: &Path,
+    directory: &Path,
+) -> Result<()> {
+    let mut entries = fs_err::read_dir(directory)?.collect::<Result<Vec<_>, _>>()?;
+    entries.sort_by_key(fs_err::DirEntry::file_name);
+
+    for entry in entries {
+        let path = entry.path();
+        let name = path
+            .strip_prefix(staging)?
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        let metadata = fs_err::symlink_metadata(&path)?;
+        let options = FileOptions::default()
+            .compression_method(CompressionMethod::Deflated)
+            .unix_permissions(permissions(&metadata));
+
+        if metadata.is_symlink() {
+            let link = fs_err::read_link(&path)?;
+            writer.add_symlink(name, link.to_string_lossy(), options)?;
+        } else if metadata.is_dir() {
+            writer.add_directory(name, options)?;
+            add_directory(writer, staging, &path)?;
+        } else {
+            writer.start_file(name, options)?;
+            let mut reader = BufReader::new(fs_err::File::open(&path)?);
+            std::io::copy(&mut reader, writer)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the Unix permissions for a file, defaulting to `644` (or `755` for directories) on
+/// other platforms.
+fn permissions(metadata: &std::fs::Metadata) -> u32 {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        metadata.permissions().mode()
+    }
+    #[cfg(not(unix))]
+    {
+        if metadata.is_dir() {
+            0o755
+        } else {
+            0o644
+        }
+    }
+}
@@ -0,0 +1,265 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_client::Connectivity;
+use uv_configuration::{
+    Concurrency, DevGroupsSpecification, EditableMode, ExtrasSpecification, InstallOptions,
+    LowerBound,
+};
+use uv_fs::Simplified;
+use uv_install_wheel::{read_record_file, write_record_file};
+use uv_normalize::PackageName;
+use uv_python::{Prefix, PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
+use uv_workspace::{DiscoveryOptions, InstallTarget, MemberDiscovery, VirtualProject, Workspace};
+
+use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger};
+use crate::commands::pip::operations::{self, Modifications};
+use crate::commands::project::lock::{do_safe_lock, LockMode};
+use crate::commands::project::sync::do_sync;
+use crate::commands::project::{ProjectError, ProjectInterpreter};
+use crate::commands::{diagnostics, ExitStatus, SharedState};
+use crate::printer::Printer;
+use crate::settings::{InstallerSettingsRef, ResolverInstallerSettings};
+
+/// The files to retain in each `.dist-info` directory, as required at runtime (e.g., by
+/// `importlib.metadata`).
+const DIST_INFO_FILES: &[&str] = &["METADATA", "RECORD", "entry_points.txt"];
+
+/// Install the project into a target prefix for deployment.
+#[allow(clippy::fn_params_excessive_bools)]
+pub(crate) async fn deploy(
+    project_dir: &Path,
+    prefix: &Path,
+    package: Option<PackageName>,
+    extras: ExtrasSpecification,
+    install_options: InstallOptions,
+    frozen: bool,
+    python: Option<String>,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    settings: ResolverInstallerSettings,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    native_tls: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    // Identify the project.
+    let project = if frozen {
+        VirtualProject::discover(
+            project_dir,
+            &DiscoveryOptions {
+                members: MemberDiscovery::None,
+                ..DiscoveryOptions::default()
+            },
+        )
+        .await?
+    } else if let Some(package) = package.as_ref() {
+        VirtualProject::Project(
+            Workspace::discover(project_dir, &DiscoveryOptions::default())
+                .await?
+                .with_current_project(package.clone())
+                .with_context(|| format!("Package `{package}` not found in workspace"))?,
+        )
+    } else {
+        VirtualProject::discover(project_dir, &DiscoveryOptions::default()).await?
+    };
+
+    // Identify the target.
+    let target = if let Some(package) = package.as_ref().filter(|_| frozen) {
+        InstallTarget::frozen_member(&project, package)
+    } else {
+        InstallTarget::from(&project)
+    };
+
+    // Find an interpreter for the project.
+    let interpreter = ProjectInterpreter::discover(
+        project.workspace(),
+        python.as_deref().map(PythonRequest::parse),
+        python_preference,
+        python_downloads,
+        connectivity,
+        native_tls,
+        cache,
+        printer,
+    )
+    .await?
+    .into_interpreter();
+
+    // Install into the prefix, rather than the project environment.
+    let interpreter = match interpreter.to_base_interpreter(cache)? {
+        Some(base) => base,
+        None => interpreter,
+    };
+    debug!("Using `--prefix` directory at {}", prefix.user_display());
+    let environment = PythonEnvironment::from_interpreter(interpreter)
+        .with_prefix(Prefix::from(prefix.to_path_buf()))?;
+
+    // Initialize any shared state.
+    let state = SharedState::default();
+
+    // Never update the lockfile when deploying.
+    let mode = if frozen {
+        LockMode::Frozen
+    } else {
+        LockMode::Locked(environment.interpreter())
+    };
+
+    let lock = match do_safe_lock(
+        mode,
+        project.workspace(),
+        settings.as_ref().into(),
+        LowerBound::Warn,
+        &state,
+        Box::new(DefaultResolveLogger),
+        connectivity,
+        concurrency,
+        native_tls,
+        cache,
+        printer,
+    )
+    .await
+    {
+        Ok(result) => result.into_lock(),
+        Err(ProjectError::Operation(operations::Error::Resolve(
+            uv_resolver::ResolveError::NoSolution(err),
+        ))) => {
+            diagnostics::no_solution(&err);
+            return Ok(ExitStatus::Failure);
+        }
+        Err(ProjectError::Operation(operations::Error::Resolve(
+            uv_resolver::ResolveError::FetchAndBuild(dist, err),
+        ))) => {
+            diagnostics::fetch_and_build(dist, err);
+            return Ok(ExitStatus::Failure);
+        }
+        Err(ProjectError::Operation(operations::Error::Resolve(
+            uv_resolver::ResolveError::Build(dist, err),
+        ))) => {
+            diagnostics::build(dist, err);
+            return Ok(ExitStatus::Failure);
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    // Install the required dependencies only, omitting all dependency groups (including `dev`),
+    // and always compile bytecode.
+    let dev = DevGroupsSpecification::default().with_defaults(Vec::new());
+    let installer = InstallerSettingsRef {
+        compile_bytecode: true,
+        ..settings.as_ref().into()
+    };
+
+    do_sync(
+        target,
+        &environment,
+        &lock,
+        &extras,
+        &dev,
+        EditableMode::NonEditable,
+        install_options,
+        Modifications::Exact,
+        None,
+        installer,
+        Box::new(DefaultInstallLogger),
+        connectivity,
+        concurrency,
+        native_tls,
+        cache,
+        printer,
+    )
+    .await?;
+
+    // Remove any files from the `.dist-info` directories that aren't required at runtime.
+    let mut stripped = 0;
+    for site_packages in environment.site_packages() {
+        stripped += strip_dist_info(&site_packages)?;
+    }
+    if stripped > 0 {
+        debug!("Removed {stripped} non-essential files from `.dist-info` directories");
+    }
+
+    writeln!(
+        printer.stderr(),
+        "Deployed to: {}",
+        prefix.user_display().cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Remove any non-essential files (e.g., `INSTALLER`, `REQUESTED`, or license files) from the
+/// `.dist-info` directories in the given `site-packages` directory, returning the number of
+/// entries removed.
+///
+/// The `RECORD` is rewritten to omit the removed files, such that the installation can still be
+/// verified (or uninstalled) with standard tooling.
+fn strip_dist_info(site_packages: &Path) -> Result<usize> {
+    let entries = match fs_err::read_dir(site_packages) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut removed = 0;
+    for entry in entries {
+        let dist_info = entry?.path();
+        if !dist_info
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("dist-info"))
+        {
+            continue;
+        }
+        let mut stripped = Vec::new();
+        for entry in fs_err::read_dir(&dist_info)? {
+            let entry = entry?;
+            if DIST_INFO_FILES
+                .iter()
+                .any(|file| entry.file_name() == *file)
+            {
+                continue;
+            }
+            if entry.file_type()?.is_dir() {
+                fs_err::remove_dir_all(entry.path())?;
+            } else {
+                fs_err::remove_file(entry.path())?;
+            }
+            stripped.push(entry.file_name().to_string_lossy().to_string());
+        }
+        if stripped.is_empty() {
+            continue;
+        }
+        removed += stripped.len();
+
+        let record_path = dist_info.join("RECORD");
+        if !record_path.is_file() {
+            continue;
+        }
+        let Some(prefix) = dist_info.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        let record = read_record_file(&mut fs_err::File::open(&record_path)?)?
+            .into_iter()
+            .filter(|entry| {
+                // Entries are relative to `site-packages`, e.g., `foo-1.0.dist-info/licenses/LICENSE`.
+                let Some(relative) = entry
+                    .path
+                    .strip_prefix(prefix)
+                    .and_then(|path| path.strip_prefix('/'))
+                else {
+                    return true;
+                };
+                let name = relative.split('/').next().unwrap_or(relative);
+                !stripped.iter().any(|stripped| stripped == name)
+            })
+            .collect();
+        write_record_file(&record_path, record)?;
+    }
+
+    Ok(removed)
+}
@@ -13,6 +13,7 @@ use uv_client::Connectivity;
 use uv_configuration::Concurrency;
 use uv_distribution_types::Resolution;
 use uv_python::{Interpreter, PythonEnvironment};
+use uv_types::HashStrategy;
 
 /// A [`PythonEnvironment`] stored in the cache.
 #[derive(Debug)]
@@ -110,9 +111,11 @@ impl CachedEnvironment {
         sync_environment(
             venv,
             &resolution,
+            &HashStrategy::default(),
             settings.as_ref().into(),
             state,
             install,
+            None,
             connectivity,
             concurrency,
             native_tls,
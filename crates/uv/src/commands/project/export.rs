@@ -1,35 +1,51 @@
 use std::env;
+use std::fmt::Write;
+use std::io::Write as _;
 
 use anyhow::{Context, Result};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
 use std::path::{Path, PathBuf};
+use tokio::process::Command;
+use tracing::debug;
+use zip::ZipWriter;
 
 use uv_cache::Cache;
 use uv_client::Connectivity;
 use uv_configuration::{
-    Concurrency, DevGroupsSpecification, EditableMode, ExportFormat, ExtrasSpecification,
-    InstallOptions, LowerBound,
+    BytecodeOptions, Concurrency, DevGroupsManifest, DevGroupsSpecification, EditableMode,
+    ExportFormat, ExtrasSpecification, InstallOptions, LowerBound, Reinstall,
 };
+use uv_fs::Simplified;
 use uv_normalize::PackageName;
-use uv_python::{PythonDownloads, PythonPreference, PythonRequest};
-use uv_resolver::RequirementsTxtExport;
-use uv_workspace::{DiscoveryOptions, MemberDiscovery, VirtualProject, Workspace};
+use uv_python::{
+    Interpreter, PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest, Target,
+};
+use uv_resolver::{Lock, RequirementsTxtExport};
+use uv_warnings::warn_user;
+use uv_workspace::{
+    DiscoveryOptions, InstallTarget, MemberDiscovery, ProjectWorkspace, VirtualProject, Workspace,
+};
 
-use crate::commands::pip::loggers::DefaultResolveLogger;
+use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger};
+use crate::commands::pip::operations::Modifications;
+use crate::commands::project::bundle::add_directory;
 use crate::commands::project::lock::{do_safe_lock, LockMode};
+use crate::commands::project::sync::do_sync;
 use crate::commands::project::{
     default_dependency_groups, validate_dependency_groups, ProjectError, ProjectInterpreter,
 };
 use crate::commands::{diagnostics, pip, ExitStatus, OutputWriter, SharedState};
 use crate::printer::Printer;
-use crate::settings::ResolverSettings;
+use crate::settings::{InstallerSettingsRef, ResolverSettings};
 
 /// Export the project's `uv.lock` in an alternate format.
 #[allow(clippy::fn_params_excessive_bools)]
 pub(crate) async fn export(
     project_dir: &Path,
     format: ExportFormat,
+    entry_point: Option<String>,
+    zipapp_python: Option<String>,
     package: Option<PackageName>,
     hashes: bool,
     install_options: InstallOptions,
@@ -51,6 +67,17 @@ pub(crate) async fn export(
     cache: &Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
+    // A PEX is built with the `pex` executable, which must be installed separately.
+    let pex = if format == ExportFormat::Pex {
+        Some(which::which("pex").map_err(|_| {
+            anyhow::anyhow!(
+                "The `pex` format requires the `pex` executable, which was not found on the `PATH`; install it with `uv tool install pex`"
+            )
+        })?)
+    } else {
+        None
+    };
+
     // Identify the project.
     let project = if let Some(package) = package {
         VirtualProject::Project(
@@ -80,30 +107,37 @@ pub(crate) async fn export(
         return Err(anyhow::anyhow!("Legacy non-project roots are not supported in `uv export`; add a `[project]` table to your `pyproject.toml` to enable exports"));
     };
 
-    // Determine the lock mode.
-    let interpreter;
-    let mode = if frozen {
-        LockMode::Frozen
+    // Find an interpreter for the project. An interpreter is required to lock the project, and to
+    // install the project into a `zipapp`, but not to export a frozen lockfile.
+    let interpreter = if frozen && format == ExportFormat::RequirementsTxt {
+        None
     } else {
-        // Find an interpreter for the project
-        interpreter = ProjectInterpreter::discover(
-            project.workspace(),
-            python.as_deref().map(PythonRequest::parse),
-            python_preference,
-            python_downloads,
-            connectivity,
-            native_tls,
-            cache,
-            printer,
+        Some(
+            ProjectInterpreter::discover(
+                project.workspace(),
+                python.as_deref().map(PythonRequest::parse),
+                python_preference,
+                python_downloads,
+                connectivity,
+                native_tls,
+                cache,
+                printer,
+            )
+            .await?
+            .into_interpreter(),
         )
-        .await?
-        .into_interpreter();
+    };
 
-        if locked {
-            LockMode::Locked(&interpreter)
-        } else {
-            LockMode::Write(&interpreter)
+    // Determine the lock mode.
+    let mode = match interpreter.as_ref() {
+        Some(interpreter) if !frozen => {
+            if locked {
+                LockMode::Locked(interpreter)
+            } else {
+                LockMode::Write(interpreter)
+            }
         }
+        _ => LockMode::Frozen,
     };
 
     // Initialize any shared state.
@@ -147,12 +181,13 @@ pub(crate) async fn export(
         Err(err) => return Err(err.into()),
     };
 
-    // Write the resolved dependencies to the output channel.
-    let mut writer = OutputWriter::new(!quiet || output_file.is_none(), output_file.as_deref());
-
     // Generate the export.
     match format {
         ExportFormat::RequirementsTxt => {
+            // Write the resolved dependencies to the output channel.
+            let mut writer =
+                OutputWriter::new(!quiet || output_file.is_none(), output_file.as_deref());
+
             let export = RequirementsTxtExport::from_lock(
                 &lock,
                 project.project_name(),
@@ -172,14 +207,282 @@ pub(crate) async fn export(
                 writeln!(writer, "{}", format!("#    {}", cmd()).green())?;
             }
             write!(writer, "{export}")?;
+
+            writer.commit().await?;
         }
-    }
+        ExportFormat::Zipapp | ExportFormat::Pex => {
+            let name = if format == ExportFormat::Pex {
+                "pex"
+            } else {
+                "zipapp"
+            };
+
+            // An archive is written directly to the output file, rather than to the output channel.
+            let (Some(output_file), Some(entry_point), Some(interpreter)) =
+                (output_file, entry_point, interpreter)
+            else {
+                return Err(anyhow::anyhow!(
+                    "The `{name}` format requires both `--output-file` and `--entry-point`"
+                ));
+            };
+
+            // Validate the entry point before installing anything.
+            let main = zipapp_main(&entry_point)?;
+
+            let staging = stage_archive(
+                &project,
+                interpreter,
+                &lock,
+                &extras,
+                &dev.with_defaults(defaults),
+                install_options,
+                &settings,
+                connectivity,
+                concurrency,
+                native_tls,
+                cache,
+                printer,
+            )
+            .await?;
+
+            if let Some(parent) = output_file.parent() {
+                fs_err::create_dir_all(parent)?;
+            }
+            if let Some(pex) = pex {
+                write_pex(
+                    &pex,
+                    staging.path(),
+                    &entry_point,
+                    zipapp_python.as_deref(),
+                    &output_file,
+                )
+                .await?;
+            } else {
+                write_zipapp(
+                    staging.path(),
+                    &main,
+                    zipapp_python.as_deref(),
+                    &output_file,
+                )?;
+            }
 
-    writer.commit().await?;
+            writeln!(
+                printer.stderr(),
+                "Created {name} at: {}",
+                output_file.user_display().cyan()
+            )?;
+        }
+    }
 
     Ok(ExitStatus::Success)
 }
 
+/// Install the project and its locked dependencies into a staging directory, from which an
+/// executable archive can be built.
+#[allow(clippy::fn_params_excessive_bools)]
+async fn stage_archive(
+    project: &ProjectWorkspace,
+    interpreter: Interpreter,
+    lock: &Lock,
+    extras: &ExtrasSpecification,
+    dev: &DevGroupsManifest,
+    install_options: InstallOptions,
+    settings: &ResolverSettings,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    native_tls: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<tempfile::TempDir> {
+    // Install into a `--target` directory in the cache, such that wheels can be linked rather than
+    // copied.
+    let staging = cache.environment()?;
+    let target = Target::from(staging.path().to_path_buf());
+    target.init()?;
+    let environment = PythonEnvironment::from_interpreter(interpreter).with_target(target)?;
+
+    // Bytecode can't be written to (or read from) the archive, so it's never compiled.
+    let bytecode_options = BytecodeOptions::default();
+    let reinstall = Reinstall::default();
+    let installer = InstallerSettingsRef {
+        index_locations: &settings.index_locations,
+        index_strategy: settings.index_strategy,
+        keyring_provider: settings.keyring_provider,
+        allow_insecure_host: &settings.allow_insecure_host,
+        dependency_metadata: &settings.dependency_metadata,
+        config_setting: &settings.config_setting,
+        build_containers: &settings.build_containers,
+        no_build_isolation: settings.no_build_isolation,
+        no_build_isolation_package: &settings.no_build_isolation_package,
+        exclude_newer: settings.exclude_newer,
+        link_mode: settings.link_mode,
+        compile_bytecode: false,
+        bytecode_options: &bytecode_options,
+        reinstall: &reinstall,
+        build_options: &settings.build_options,
+        sources: settings.sources,
+    };
+
+    // Workspace members are always installed as non-editable, as the archive must not refer back
+    // to the source tree.
+    do_sync(
+        InstallTarget::Project(project),
+        &environment,
+        lock,
+        extras,
+        dev,
+        EditableMode::NonEditable,
+        install_options,
+        Modifications::Sufficient,
+        None,
+        installer,
+        Box::new(DefaultInstallLogger),
+        connectivity,
+        concurrency,
+        native_tls,
+        cache,
+        printer,
+    )
+    .await?;
+
+    // Any installed scripts refer to the staging directory, and can't be used from the archive.
+    // Scripts are installed into the `--target` scheme's `scripts` directory (i.e., `bin`), but
+    // Windows launchers may also be shipped as data files under `Scripts`.
+    let scripts = environment.interpreter().layout().scheme.scripts;
+    for scripts in [scripts, staging.path().join("Scripts")] {
+        if scripts.is_dir() {
+            fs_err::remove_dir_all(&scripts)?;
+        }
+    }
+
+    Ok(staging)
+}
+
+/// Write the staged installation to an executable zip application.
+fn write_zipapp(
+    staging: &Path,
+    main: &str,
+    python: Option<&str>,
+    output_file: &Path,
+) -> Result<()> {
+    // Extension modules can't be imported from a zip archive.
+    let extensions = extension_modules(staging, staging)?;
+    if !extensions.is_empty() {
+        warn_user!(
+            "The zipapp includes compiled extension modules, which cannot be imported from a zip archive: {}",
+            extensions.iter().map(|path| path.cyan()).join(", ")
+        );
+    }
+
+    fs_err::write(staging.join("__main__.py"), main)?;
+
+    // Write the archive, prefixed by the shebang.
+    let mut file = fs_err::File::create(output_file)?;
+    file.write_all(format!("#!{}\n", python.unwrap_or("/usr/bin/env python3")).as_bytes())?;
+    let mut writer = ZipWriter::new(file);
+    add_directory(&mut writer, staging, staging)
+        .with_context(|| format!("Failed to write zipapp to: {}", output_file.user_display()))?;
+    writer.finish()?;
+
+    // Mark the archive as executable.
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs_err::set_permissions(output_file, std::fs::Permissions::from_mode(0o755))?;
+    }
+
+    Ok(())
+}
+
+/// Write the staged installation to a PEX file, using the `pex` executable.
+///
+/// The staged installation is added to the PEX as-is, such that `pex` doesn't re-resolve (or
+/// download) any dependencies. The PEX is built in `--venv` mode, such that it's unpacked into a
+/// virtual environment on first run, and can include compiled extension modules.
+async fn write_pex(
+    pex: &Path,
+    staging: &Path,
+    entry_point: &str,
+    python: Option<&str>,
+    output_file: &Path,
+) -> Result<()> {
+    debug!("Building PEX with: {}", pex.user_display());
+
+    let status = Command::new(pex)
+        .arg("--no-pypi")
+        .arg("--sources-directory")
+        .arg(staging)
+        .arg("--entry-point")
+        .arg(entry_point)
+        .arg("--python-shebang")
+        .arg(python.unwrap_or("/usr/bin/env python3"))
+        .arg("--venv")
+        .arg("--output-file")
+        .arg(output_file)
+        .status()
+        .await
+        .with_context(|| format!("Failed to run `{}`", pex.user_display()))?;
+
+    if !status.success() {
+        return Err(anyhow::anyhow!(
+            "Failed to build PEX at {} (`pex` exited with {status})",
+            output_file.user_display()
+        ));
+    }
+
+    Ok(())
+}
+
+/// Generate the `__main__.py` for a `zipapp` that invokes the given entry point.
+///
+/// The entry point is either a module (e.g., `example`), or a callable (e.g., `example.cli:main`).
+fn zipapp_main(entry_point: &str) -> Result<String> {
+    /// Returns `true` if the given string is a (possibly dotted) Python identifier.
+    fn is_dotted_identifier(value: &str) -> bool {
+        value.split('.').all(|part| {
+            part.chars()
+                .next()
+                .is_some_and(|first| first.is_alphabetic() || first == '_')
+                && part.chars().all(|c| c.is_alphanumeric() || c == '_')
+        })
+    }
+
+    match entry_point.split_once(':') {
+        Some((module, function))
+            if is_dotted_identifier(module) && is_dotted_identifier(function) =>
+        {
+            Ok(format!(
+                "import sys\n\nimport {module}\n\nsys.exit({module}.{function}())\n"
+            ))
+        }
+        None if is_dotted_identifier(entry_point) => Ok(format!(
+            "import runpy\n\nrunpy.run_module(\"{entry_point}\", run_name=\"__main__\", alter_sys=True)\n"
+        )),
+        _ => Err(anyhow::anyhow!(
+            "Invalid entry point `{entry_point}`; expected a module (e.g., `example`) or a callable (e.g., `example.cli:main`)"
+        )),
+    }
+}
+
+/// Find any compiled extension modules in the given directory, relative to the root.
+fn extension_modules(root: &Path, directory: &Path) -> Result<Vec<String>> {
+    let mut extensions = Vec::new();
+    for entry in fs_err::read_dir(directory)? {
+        let entry = entry?;
+        let path = entry.path();
+        if entry.file_type()?.is_dir() {
+            extensions.extend(extension_modules(root, &path)?);
+        } else if path
+            .extension()
+            .is_some_and(|ext| ext == "so" || ext == "pyd" || ext == "dylib")
+        {
+            extensions.push(path.strip_prefix(root)?.portable_display().to_string());
+        }
+    }
+    extensions.sort();
+    Ok(extensions)
+}
+
 /// Format the uv command used to generate the output file.
 fn cmd() -> String {
     let args = env::args_os()
@@ -0,0 +1,103 @@
+use std::fmt::Write;
+
+use anyhow::{bail, Context, Result};
+use owo_colors::OwoColorize;
+use tokio::process::Command;
+use tracing::debug;
+
+use uv_python::PythonEnvironment;
+use uv_static::EnvVars;
+use uv_workspace::Workspace;
+
+use crate::printer::Printer;
+
+/// A point in the project lifecycle at which the commands declared in `tool.uv.hooks` are run.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum Hook {
+    /// Before packages are installed into the project environment.
+    PreSync,
+    /// After packages are installed into the project environment.
+    PostSync,
+    /// After the lockfile is updated.
+    PostLock,
+}
+
+impl std::fmt::Display for Hook {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::PreSync => write!(f, "pre-sync"),
+            Self::PostSync => write!(f, "post-sync"),
+            Self::PostLock => write!(f, "post-lock"),
+        }
+    }
+}
+
+/// Run the commands declared for the given [`Hook`] in the workspace's `tool.uv.hooks`, if any.
+///
+/// Each command is run in a shell from the workspace root, with the given environment (if any)
+/// activated. If a command fails, the remaining commands are skipped.
+pub(crate) async fn run_hooks(
+    hook: Hook,
+    workspace: &Workspace,
+    venv: Option<&PythonEnvironment>,
+    printer: Printer,
+) -> Result<()> {
+    let Some(hooks) = workspace.hooks() else {
+        return Ok(());
+    };
+    let commands = match hook {
+        Hook::PreSync => hooks.pre_sync.as_deref(),
+        Hook::PostSync => hooks.post_sync.as_deref(),
+        Hook::PostLock => hooks.post_lock.as_deref(),
+    }
+    .unwrap_or_default();
+
+    for command in commands {
+        writeln!(
+            printer.stderr(),
+            "Running `{}` hook: `{}`",
+            hook.cyan(),
+            command.cyan()
+        )?;
+
+        let mut process = if cfg!(windows) {
+            let mut process = Command::new("cmd");
+            process.arg("/C").arg(command);
+            process
+        } else {
+            let mut process = Command::new("sh");
+            process.arg("-c").arg(command);
+            process
+        };
+        process.current_dir(workspace.install_path());
+
+        // Redirect the hook's output to stderr, to avoid interleaving it with uv's own output
+        // (e.g., `uv export` or `uv run` output intended for another program).
+        process.stdout(std::io::stderr());
+
+        // Activate the project environment, if it exists.
+        if let Some(venv) = venv {
+            let new_path = std::env::join_paths(
+                std::iter::once(venv.scripts().to_path_buf()).chain(
+                    std::env::var_os(EnvVars::PATH)
+                        .as_ref()
+                        .iter()
+                        .flat_map(std::env::split_paths),
+                ),
+            )?;
+            process.env(EnvVars::PATH, new_path);
+            process.env(EnvVars::VIRTUAL_ENV, venv.root().as_os_str());
+        }
+
+        debug!("Running `{hook}` hook: `{command}`");
+        let status = process
+            .status()
+            .await
+            .with_context(|| format!("Failed to spawn the `{hook}` hook: `{command}`"))?;
+        if !status.success() {
+            bail!("The `{hook}` hook failed with {status}: `{command}`");
+        }
+    }
+
+    Ok(())
+}
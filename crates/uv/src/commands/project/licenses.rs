@@ -0,0 +1,641 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use futures::StreamExt;
+use owo_colors::OwoColorize;
+use serde::{Deserialize, Serialize};
+
+use uv_auth::{store_credentials, store_secret_credentials};
+use uv_cache::Cache;
+use uv_cli::LicensesFormat;
+use uv_client::{Connectivity, FlatIndexClient, RegistryClientBuilder};
+use uv_configuration::{Concurrency, Constraints, LowerBound};
+use uv_dispatch::BuildDispatch;
+use uv_distribution::DistributionDatabase;
+use uv_distribution_types::{Dist, HashPolicy, Index, IndexCapabilities};
+use uv_fs::Simplified;
+use uv_metadata::{find_flat_dist_info, read_dist_info_metadata};
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_platform_tags::Tags;
+use uv_pypi_types::Metadata23;
+use uv_python::{PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
+use uv_resolver::{FlatIndex, Package};
+use uv_types::{BuildIsolation, HashStrategy};
+use uv_warnings::warn_user;
+use uv_workspace::{DiscoveryOptions, Workspace};
+
+use crate::commands::info::license;
+use crate::commands::pip::loggers::DefaultResolveLogger;
+use crate::commands::project::lock::{do_safe_lock, LockMode};
+use crate::commands::project::{ProjectError, ProjectInterpreter};
+use crate::commands::{diagnostics, pip, ExitStatus, SharedState};
+use crate::printer::Printer;
+use crate::settings::ResolverSettings;
+
+/// Display the licenses of the locked packages, optionally enforcing a license policy.
+#[allow(clippy::fn_params_excessive_bools)]
+pub(crate) async fn licenses(
+    project_dir: &Path,
+    policy: Option<&Path>,
+    format: LicensesFormat,
+    locked: bool,
+    frozen: bool,
+    python: Option<String>,
+    settings: ResolverSettings,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    native_tls: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    // Read the policy upfront, such that a malformed policy fails fast.
+    let policy = policy.map(LicensePolicy::read).transpose()?;
+
+    // Find the workspace.
+    let workspace = Workspace::discover(project_dir, &DiscoveryOptions::default()).await?;
+
+    // Find an interpreter for the project. Even if the lockfile is frozen, an interpreter is
+    // required to build any source distributions from which a license is read.
+    let interpreter = ProjectInterpreter::discover(
+        &workspace,
+        python.as_deref().map(PythonRequest::parse),
+        python_preference,
+        python_downloads,
+        connectivity,
+        native_tls,
+        cache,
+        printer,
+    )
+    .await?
+    .into_interpreter();
+
+    // Determine the lock mode.
+    let mode = if frozen {
+        LockMode::Frozen
+    } else if locked {
+        LockMode::Locked(&interpreter)
+    } else {
+        LockMode::Write(&interpreter)
+    };
+
+    // Initialize any shared state.
+    let state = SharedState::default();
+
+    // Lock the project.
+    let lock = match do_safe_lock(
+        mode,
+        &workspace,
+        settings.as_ref(),
+        LowerBound::Warn,
+        &state,
+        Box::new(DefaultResolveLogger),
+        connectivity,
+        concurrency,
+        native_tls,
+        cache,
+        printer,
+    )
+    .await
+    {
+        Ok(result) => result.into_lock(),
+        Err(ProjectError::Operation(pip::operations::Error::Resolve(
+            uv_resolver::ResolveError::NoSolution(err),
+        ))) => {
+            diagnostics::no_solution(&err);
+            return Ok(ExitStatus::Failure);
+        }
+        Err(ProjectError::Operation(pip::operations::Error::Resolve(
+            uv_resolver::ResolveError::FetchAndBuild(dist, err),
+        ))) => {
+            diagnostics::fetch_and_build(dist, err);
+            return Ok(ExitStatus::Failure);
+        }
+        Err(ProjectError::Operation(pip::operations::Error::Resolve(
+            uv_resolver::ResolveError::Build(dist, err),
+        ))) => {
+            diagnostics::build(dist, err);
+            return Ok(ExitStatus::Failure);
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    // Add all authenticated sources to the cache.
+    for index in settings.index_locations.allowed_indexes() {
+        if let Some(credentials) = index.credentials() {
+            store_credentials(index.raw_url(), credentials);
+        } else if let Some(secrets) = index.secret_credentials() {
+            store_secret_credentials(secrets);
+        }
+    }
+
+    let client = RegistryClientBuilder::new(cache.clone())
+        .connectivity(connectivity)
+        .native_tls(native_tls)
+        .index_strategy(settings.index_strategy)
+        .keyring(settings.keyring_provider)
+        .allow_insecure_host(settings.allow_insecure_host.clone())
+        .index_urls(settings.index_locations.index_urls())
+        .markers(interpreter.markers())
+        .platform(interpreter.platform())
+        .build();
+
+    // Determine whether to enable build isolation.
+    let environment;
+    let build_isolation = if settings.no_build_isolation {
+        environment = PythonEnvironment::from_interpreter(interpreter.clone());
+        BuildIsolation::Shared(&environment)
+    } else if settings.no_build_isolation_package.is_empty() {
+        BuildIsolation::Isolated
+    } else {
+        environment = PythonEnvironment::from_interpreter(interpreter.clone());
+        BuildIsolation::SharedPackage(&environment, &settings.no_build_isolation_package)
+    };
+
+    // TODO(charlie): These are all default values. We should consider whether we want to make them
+    // optional on the downstream APIs.
+    let build_constraints = Constraints::default();
+    let build_hasher = HashStrategy::default();
+
+    // Resolve the flat indexes from `--find-links`.
+    let flat_index = {
+        let client = FlatIndexClient::new(&client, cache)
+            .with_recursive(settings.index_locations.find_links_recursive());
+        let entries = client
+            .fetch(settings.index_locations.flat_indexes().map(Index::url))
+            .await?;
+        FlatIndex::from_entries(entries, None, &build_hasher, &settings.build_options)
+    };
+
+    // Create a build dispatch, to read the metadata of packages that don't provide a wheel.
+    let build_dispatch = BuildDispatch::new(
+        &client,
+        cache,
+        build_constraints,
+        &interpreter,
+        &settings.index_locations,
+        &flat_index,
+        &settings.dependency_metadata,
+        &state.index,
+        &state.git,
+        &state.hg,
+        &state.capabilities,
+        &state.in_flight,
+        settings.index_strategy,
+        &settings.config_setting,
+        &settings.build_containers,
+        build_isolation,
+        settings.link_mode,
+        &settings.build_options,
+        &build_hasher,
+        settings.exclude_newer,
+        LowerBound::Allow,
+        settings.sources,
+        concurrency,
+    )
+    .with_variants(&settings.variants);
+
+    let database = DistributionDatabase::new(&client, &build_dispatch, concurrency);
+    let tags = interpreter.tags()?;
+
+    // Read the license of every locked package, omitting the workspace members.
+    let policy = policy.as_ref();
+    let mut packages = futures::stream::iter(
+        lock.packages()
+            .iter()
+            .filter(|package| !workspace.packages().contains_key(package.name())),
+    )
+    .map(|package| {
+        let database = &database;
+        let workspace = &workspace;
+        let capabilities = &state.capabilities;
+        async move {
+            let license = match read_license(
+                package,
+                workspace.install_path(),
+                database,
+                tags,
+                capabilities,
+            )
+            .await
+            {
+                Ok(license) => license,
+                // With a policy, a package whose license can't be read must not pass as unknown.
+                Err(err)
+                    if policy.is_some_and(|policy| !policy.ignore.contains(package.name())) =>
+                {
+                    return Err(err)
+                }
+                Err(err) => {
+                    warn_user!("{err:#}");
+                    None
+                }
+            };
+            let violation = policy
+                .filter(|policy| !policy.ignore.contains(package.name()))
+                .and_then(|policy| policy.check(license.as_deref()))
+                .map(|violation| violation.to_string());
+            Ok::<_, anyhow::Error>(PackageLicense {
+                name: package.name().clone(),
+                version: package.version().clone(),
+                license,
+                violation,
+            })
+        }
+    })
+    .buffer_unordered(concurrency.downloads)
+    .collect::<Vec<_>>()
+    .await
+    .into_iter()
+    .collect::<Result<Vec<_>>>()?;
+    packages.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.version.cmp(&b.version)));
+
+    match format {
+        LicensesFormat::Text => {
+            // Group the packages by license, with unknown licenses last.
+            let mut groups: BTreeMap<(bool, &str), Vec<&PackageLicense>> = BTreeMap::new();
+            for package in &packages {
+                let key = match package.license.as_deref() {
+                    Some(license) => (false, license),
+                    None => (true, "Unknown"),
+                };
+                groups.entry(key).or_default().push(package);
+            }
+
+            for (index, ((_, license), packages)) in groups.iter().enumerate() {
+                if index > 0 {
+                    writeln!(printer.stdout())?;
+                }
+                writeln!(
+                    printer.stdout(),
+                    "{} {}",
+                    license.bold(),
+                    format!(
+                        "({} package{})",
+                        packages.len(),
+                        if packages.len() == 1 { "" } else { "s" }
+                    )
+                    .dimmed()
+                )?;
+                for package in packages {
+                    writeln!(printer.stdout(), "  {} {}", package.name, package.version)?;
+                }
+            }
+        }
+        LicensesFormat::Json => {
+            writeln!(
+                printer.stdout(),
+                "{}",
+                serde_json::to_string_pretty(&packages)?
+            )?;
+        }
+    }
+
+    if policy.is_none() {
+        return Ok(ExitStatus::Success);
+    }
+
+    let violations = packages
+        .iter()
+        .filter_map(|package| {
+            package
+                .violation
+                .as_ref()
+                .map(|violation| (package, violation))
+        })
+        .collect::<Vec<_>>();
+    if violations.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "{}",
+            "All licenses are permitted by the license policy".dimmed()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    writeln!(
+        printer.stderr(),
+        "Found {} with a disallowed license:",
+        format!(
+            "{} package{}",
+            violations.len(),
+            if violations.len() == 1 { "" } else { "s" }
+        )
+        .bold()
+    )?;
+    for (package, violation) in violations {
+        writeln!(
+            printer.stderr(),
+            "  - {} {}: {violation}",
+            package.name.bold(),
+            package.version
+        )?;
+    }
+
+    Ok(ExitStatus::Failure)
+}
+
+/// The license of a locked package.
+#[derive(Debug, Serialize)]
+struct PackageLicense {
+    name: PackageName,
+    version: Version,
+    license: Option<String>,
+    /// The reason the license is disallowed by the policy, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    violation: Option<String>,
+}
+
+/// Read the license of a locked package from its metadata.
+///
+/// The metadata is read from one of the package's wheels, if it provides any, and otherwise from
+/// a wheel built from its source distribution (e.g., for Git dependencies).
+///
+/// Returns `None` if the package's metadata doesn't include a license.
+async fn read_license(
+    package: &Package,
+    workspace_root: &Path,
+    database: &DistributionDatabase<'_, BuildDispatch<'_>>,
+    tags: &Tags,
+    capabilities: &IndexCapabilities,
+) -> Result<Option<String>> {
+    // The license is shared across all wheels for a given version, so any wheel will do.
+    let metadata = if let Some(wheel) = package.registry_wheels(workspace_root)?.into_iter().next()
+    {
+        database
+            .client()
+            .managed(|client| client.wheel_core_metadata(&wheel.index, &wheel.file, capabilities))
+            .await
+            .with_context(|| format!("Failed to read the metadata for `{}`", wheel.filename))?
+    } else if let Some(sdist) = package.source_dist(workspace_root)? {
+        let wheel = database
+            .get_or_build_wheel(&Dist::Source(sdist.clone()), tags, HashPolicy::None)
+            .await
+            .with_context(|| format!("Failed to build `{sdist}`"))?;
+        let dist_info = find_flat_dist_info(wheel.filename(), wheel.target())?;
+        let contents = read_dist_info_metadata(&dist_info, wheel.target())?;
+        Metadata23::parse(&contents)
+            .with_context(|| format!("Failed to read the metadata for `{sdist}`"))?
+    } else {
+        return Ok(None);
+    };
+    Ok(license(&metadata))
+}
+
+/// A policy of allowed and denied licenses, as read from a policy file.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+struct LicensePolicy {
+    /// The licenses that are allowed. If empty, any license that isn't denied is allowed.
+    #[serde(default)]
+    allow: Vec<String>,
+    /// The licenses that are denied.
+    #[serde(default)]
+    deny: Vec<String>,
+    /// The packages that are exempt from the policy.
+    #[serde(default)]
+    ignore: Vec<PackageName>,
+}
+
+impl LicensePolicy {
+    /// Read a [`LicensePolicy`] from the given TOML file.
+    fn read(path: &Path) -> Result<Self> {
+        let contents = fs_err::read_to_string(path)?;
+        toml::from_str(&contents)
+            .with_context(|| format!("Failed to parse license policy: `{}`", path.user_display()))
+    }
+
+    /// Check a license against the policy, returning the [`Violation`], if any.
+    fn check(&self, license: Option<&str>) -> Option<Violation> {
+        let Some(license) = license else {
+            return (!self.allow.is_empty()).then_some(Violation::Unknown);
+        };
+
+        // Match the license verbatim, to support licenses that aren't SPDX expressions (e.g.,
+        // `BSD License`).
+        if contains(&self.deny, license) {
+            return Some(Violation::Denied(license.to_string()));
+        }
+        if contains(&self.allow, license) {
+            return None;
+        }
+
+        let expression =
+            Expression::parse(license).unwrap_or_else(|| Expression::License(license.to_string()));
+        if expression.is_satisfied(&|id| self.is_permitted(id)) {
+            None
+        } else if self.allow.is_empty() || expression.any(&|id| self.is_denied(id)) {
+            Some(Violation::Denied(license.to_string()))
+        } else {
+            Some(Violation::NotAllowed(license.to_string()))
+        }
+    }
+
+    /// Returns `true` if the license identifier (e.g., `Apache-2.0 WITH LLVM-exception`) is denied,
+    /// either in full or without its exception.
+    fn is_denied(&self, id: &str) -> bool {
+        contains(&self.deny, id) || contains(&self.deny, base(id))
+    }
+
+    /// Returns `true` if the license identifier is permitted by the policy.
+    fn is_permitted(&self, id: &str) -> bool {
+        !self.is_denied(id)
+            && (self.allow.is_empty()
+                || contains(&self.allow, id)
+                || contains(&self.allow, base(id)))
+    }
+}
+
+/// Returns `true` if the list contains the license, ignoring case.
+fn contains(licenses: &[String], license: &str) -> bool {
+    licenses
+        .iter()
+        .any(|entry| entry.eq_ignore_ascii_case(license))
+}
+
+/// Strip the exception from a license identifier, e.g., `Apache-2.0 WITH LLVM-exception`.
+fn base(id: &str) -> &str {
+    id.split_once(" WITH ").map_or(id, |(base, _)| base)
+}
+
+/// The reason a license is disallowed by a [`LicensePolicy`].
+#[derive(Debug)]
+enum Violation {
+    /// The license is unknown, and so can't be matched against the allowed licenses.
+    Unknown,
+    /// The license is denied.
+    Denied(String),
+    /// The license isn't in the list of allowed licenses.
+    NotAllowed(String),
+}
+
+impl std::fmt::Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unknown => write!(f, "the license is unknown"),
+            Self::Denied(license) => write!(f, "`{license}` is denied"),
+            Self::NotAllowed(license) => write!(f, "`{license}` is not allowed"),
+        }
+    }
+}
+
+/// A parsed SPDX license expression, e.g., `MIT OR (Apache-2.0 AND BSD-3-Clause)`.
+#[derive(Debug, PartialEq, Eq)]
+enum Expression {
+    /// A license identifier, including its exception, if any.
+    License(String),
+    And(Vec<Expression>),
+    Or(Vec<Expression>),
+}
+
+impl Expression {
+    /// Parse an SPDX license expression, returning `None` if the license isn't a valid expression.
+    fn parse(license: &str) -> Option<Self> {
+        let license = license.replace('(', " ( ").replace(')', " ) ");
+        let tokens = license.split_whitespace().collect::<Vec<_>>();
+        let mut position = 0;
+        let expression = Self::parse_or(&tokens, &mut position)?;
+        (position == tokens.len()).then_some(expression)
+    }
+
+    fn parse_or(tokens: &[&str], position: &mut usize) -> Option<Self> {
+        let mut operands = vec![Self::parse_and(tokens, position)?];
+        while tokens.get(*position) == Some(&"OR") {
+            *position += 1;
+            operands.push(Self::parse_and(tokens, position)?);
+        }
+        Some(if operands.len() == 1 {
+            operands.remove(0)
+        } else {
+            Self::Or(operands)
+        })
+    }
+
+    fn parse_and(tokens: &[&str], position: &mut usize) -> Option<Self> {
+        let mut operands = vec![Self::parse_primary(tokens, position)?];
+        while tokens.get(*position) == Some(&"AND") {
+            *position += 1;
+            operands.push(Self::parse_primary(tokens, position)?);
+        }
+        Some(if operands.len() == 1 {
+            operands.remove(0)
+        } else {
+            Self::And(operands)
+        })
+    }
+
+    fn parse_primary(tokens: &[&str], position: &mut usize) -> Option<Self> {
+        let token = *tokens.get(*position)?;
+        *position += 1;
+        match token {
+            "(" => {
+                let expression = Self::parse_or(tokens, position)?;
+                if tokens.get(*position) != Some(&")") {
+                    return None;
+                }
+                *position += 1;
+                Some(expression)
+            }
+            ")" | "AND" | "OR" | "WITH" => None,
+            id => {
+                if tokens.get(*position) == Some(&"WITH") {
+                    let exception = tokens
+                        .get(*position + 1)
+                        .filter(|token| !matches!(**token, "(" | ")" | "AND" | "OR" | "WITH"))?;
+                    *position += 2;
+                    Some(Self::License(format!("{id} WITH {exception}")))
+                } else {
+                    Some(Self::License(id.to_string()))
+                }
+            }
+        }
+    }
+
+    /// Returns `true` if the expression is satisfied, given a predicate for each identifier.
+    fn is_satisfied(&self, permitted: &impl Fn(&str) -> bool) -> bool {
+        match self {
+            Self::License(id) => permitted(id),
+            Self::And(operands) => operands
+                .iter()
+                .all(|operand| operand.is_satisfied(permitted)),
+            Self::Or(operands) => operands
+                .iter()
+                .any(|operand| operand.is_satisfied(permitted)),
+        }
+    }
+
+    /// Returns `true` if any identifier in the expression matches the predicate.
+    fn any(&self, predicate: &impl Fn(&str) -> bool) -> bool {
+        match self {
+            Self::License(id) => predicate(id),
+            Self::And(operands) | Self::Or(operands) => {
+                operands.iter().any(|operand| operand.any(predicate))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Expression, LicensePolicy, Violation};
+
+    #[test]
+    fn parse_expression() {
+        assert_eq!(
+            Expression::parse("MIT OR (Apache-2.0 AND BSD-3-Clause)"),
+            Some(Expression::Or(vec![
+                Expression::License("MIT".to_string()),
+                Expression::And(vec![
+                    Expression::License("Apache-2.0".to_string()),
+                    Expression::License("BSD-3-Clause".to_string()),
+                ]),
+            ]))
+        );
+        assert_eq!(
+            Expression::parse("Apache-2.0 WITH LLVM-exception"),
+            Some(Expression::License(
+                "Apache-2.0 WITH LLVM-exception".to_string()
+            ))
+        );
+        assert_eq!(Expression::parse("BSD License"), None);
+        assert_eq!(Expression::parse("(MIT"), None);
+    }
+
+    #[test]
+    fn check_policy() {
+        let policy = LicensePolicy {
+            allow: vec!["MIT".to_string(), "Apache-2.0".to_string()],
+            deny: vec!["GPL-3.0-only".to_string()],
+            ignore: vec![],
+        };
+        assert!(policy.check(Some("mit")).is_none());
+        assert!(policy.check(Some("MIT OR GPL-3.0-only")).is_none());
+        assert!(policy
+            .check(Some("Apache-2.0 WITH LLVM-exception"))
+            .is_none());
+        assert!(matches!(
+            policy.check(Some("MIT AND GPL-3.0-only")),
+            Some(Violation::Denied(_))
+        ));
+        assert!(matches!(
+            policy.check(Some("BSD License")),
+            Some(Violation::NotAllowed(_))
+        ));
+        assert!(matches!(policy.check(None), Some(Violation::Unknown)));
+
+        let policy = LicensePolicy {
+            deny: vec!["GPL-3.0-only".to_string()],
+            ..LicensePolicy::default()
+        };
+        assert!(policy.check(Some("BSD License")).is_none());
+        assert!(policy.check(None).is_none());
+        assert!(matches!(
+            policy.check(Some("GPL-3.0-only")),
+            Some(Violation::Denied(_))
+        ));
+    }
+}
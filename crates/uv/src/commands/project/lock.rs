@@ -1,40 +1,54 @@
 #![allow(clippy::single_match_else)]
 
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Write;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
+use futures::StreamExt;
+use itertools::Itertools;
 use owo_colors::OwoColorize;
-use rustc_hash::{FxBuildHasher, FxHashMap};
+use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet};
+use serde::Serialize;
 use tracing::debug;
 
 use uv_cache::Cache;
+use uv_cli::LockFormat;
 use uv_client::{Connectivity, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    BuildOptions, Concurrency, Constraints, ExtrasSpecification, LowerBound, Reinstall, Upgrade,
+    BuildOptions, Concurrency, Constraints, ExtrasSpecification, GitUpgrade, LowerBound, Reinstall,
+    SourceStrategy, Upgrade,
 };
 use uv_dispatch::BuildDispatch;
 use uv_distribution::DistributionDatabase;
 use uv_distribution_types::{
-    DependencyMetadata, Index, IndexLocations, NameRequirementSpecification,
+    DependencyMetadata, Index, IndexCapabilities, IndexLocations, NameRequirementSpecification,
     UnresolvedRequirementSpecification,
 };
+use uv_fs::Simplified;
 use uv_git::ResolvedRepositoryReference;
-use uv_normalize::PackageName;
-use uv_pep440::Version;
-use uv_pypi_types::{Requirement, SupportedEnvironments};
+use uv_hg::ResolvedHgRepositoryReference;
+use uv_normalize::{GroupName, PackageName};
+use uv_pep440::{Operator, Version, VersionSpecifier};
+use uv_pep508::MarkerTree;
+use uv_pypi_types::{Requirement, RequirementSource, SupportedEnvironments, VerbatimParsedUrl};
 use uv_python::{Interpreter, PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
-use uv_requirements::upgrade::{read_lock_requirements, LockedRequirements};
+use uv_requirements::upgrade::{read_lock_requirements, read_requirements_txt, LockedRequirements};
 use uv_requirements::ExtrasResolver;
 use uv_resolver::{
-    FlatIndex, InMemoryIndex, Lock, LockVersion, Options, OptionsBuilder, PythonRequirement,
-    RequiresPython, ResolverManifest, ResolverMarkers, SatisfiesResult, VERSION,
+    Conflict, ExcludeNewer, FlatIndex, GraphExport, GraphFormat, InMemoryIndex, Lock, LockVersion,
+    LockfileFormat, Options, OptionsBuilder, Preference, PrereleaseMode, PythonRequirement,
+    RequiresPython, ResolutionMode, ResolverManifest, ResolverMarkers, SatisfiesResult, YankedMode,
+    DIFFABLE_VERSION, VERSION,
 };
 use uv_types::{BuildContext, BuildIsolation, EmptyInstalledPackages, HashStrategy};
 use uv_warnings::{warn_user, warn_user_once};
+use uv_workspace::dependency_groups::FlatDependencyGroups;
 use uv_workspace::{DiscoveryOptions, Workspace};
 
 use crate::commands::pip::loggers::{DefaultResolveLogger, ResolveLogger, SummaryResolveLogger};
+use crate::commands::project::hooks::{run_hooks, Hook};
+use crate::commands::project::outdated::available_versions;
 use crate::commands::project::{
     find_requires_python, ProjectError, ProjectInterpreter, SharedState,
 };
@@ -75,6 +89,12 @@ pub(crate) async fn lock(
     locked: bool,
     frozen: bool,
     dry_run: bool,
+    check_bounds: bool,
+    check_platforms: bool,
+    output_format: LockFormat,
+    migrate_format: Option<LockfileFormat>,
+    graph_output: Option<PathBuf>,
+    no_hooks: bool,
     python: Option<String>,
     settings: ResolverSettings,
     python_preference: PythonPreference,
@@ -136,7 +156,13 @@ pub(crate) async fn lock(
     .await
     {
         Ok(lock) => {
-            if dry_run {
+            if matches!(output_format, LockFormat::Json) {
+                let previous = match &lock {
+                    LockResult::Unchanged(lock) => Some(lock),
+                    LockResult::Changed(previous, _) => previous.as_ref(),
+                };
+                report_upgrades_json(previous, lock.lock(), printer, dry_run)?;
+            } else if dry_run {
                 let changed = if let LockResult::Changed(previous, lock) = &lock {
                     report_upgrades(previous.as_ref(), lock, printer, dry_run)?
                 } else {
@@ -155,6 +181,95 @@ pub(crate) async fn lock(
                 }
             }
 
+            // Rewrite the lockfile in the requested format, if it differs from the current format.
+            if let Some(format) = migrate_format {
+                if lock.lock().format() != format {
+                    if !dry_run {
+                        commit(&lock.lock().clone().with_format(format), &workspace).await?;
+                    }
+                    writeln!(
+                        printer.stderr(),
+                        "{} lockfile to the `{}` format",
+                        if dry_run { "Would migrate" } else { "Migrated" },
+                        format.cyan()
+                    )?;
+                }
+            }
+
+            // Write the resolved dependency graph, if requested.
+            if let Some(graph_output) = graph_output {
+                let graph =
+                    GraphExport::from_lock(lock.lock(), GraphFormat::from_path(&graph_output));
+                if graph_output == Path::new("-") {
+                    write!(printer.stdout(), "{graph}")?;
+                } else {
+                    fs_err::write(&graph_output, graph.to_string())?;
+                    writeln!(
+                        printer.stderr(),
+                        "Wrote dependency graph to `{}`",
+                        graph_output.user_display().cyan()
+                    )?;
+                }
+            }
+
+            // If the lockfile was updated, run the `post-lock` hooks in the project environment,
+            // if it exists.
+            if !no_hooks && !dry_run && matches!(lock, LockResult::Changed(..)) {
+                let venv = PythonEnvironment::from_root(workspace.venv(), cache).ok();
+                run_hooks(Hook::PostLock, &workspace, venv.as_ref(), printer).await?;
+            }
+
+            if check_bounds {
+                let stale = report_bounds(
+                    lock.lock(),
+                    &settings,
+                    connectivity,
+                    concurrency,
+                    native_tls,
+                    cache,
+                    printer,
+                )
+                .await?;
+                if stale > 0 {
+                    writeln!(
+                        printer.stderr(),
+                        "{}",
+                        format!(
+                            "Found {stale} stale {}",
+                            if stale == 1 { "bound" } else { "bounds" }
+                        )
+                        .bold()
+                    )?;
+                    return Ok(ExitStatus::Failure);
+                }
+                writeln!(printer.stderr(), "{}", "No stale bounds detected".bold())?;
+            }
+
+            if check_platforms {
+                let unsatisfied = report_platforms(lock.lock(), printer)?;
+                if unsatisfied > 0 {
+                    writeln!(
+                        printer.stderr(),
+                        "{}",
+                        format!(
+                            "Found {unsatisfied} unsatisfiable {}",
+                            if unsatisfied == 1 {
+                                "environment"
+                            } else {
+                                "environments"
+                            }
+                        )
+                        .bold()
+                    )?;
+                    return Ok(ExitStatus::Failure);
+                }
+                writeln!(
+                    printer.stderr(),
+                    "{}",
+                    "All supported environments are satisfiable".bold()
+                )?;
+            }
+
             Ok(ExitStatus::Success)
         }
         Err(ProjectError::Operation(pip::operations::Error::Resolve(
@@ -222,7 +337,7 @@ pub(super) async fn do_safe_lock(
                 .ok_or_else(|| ProjectError::MissingLockfile)?;
 
             // Perform the lock operation, but don't write the lockfile to disk.
-            let result = do_lock(
+            let (result, staleness) = do_lock(
                 workspace,
                 interpreter,
                 Some(existing),
@@ -238,9 +353,10 @@ pub(super) async fn do_safe_lock(
             )
             .await?;
 
-            // If the lockfile changed, return an error.
+            // If the lockfile changed, return an error, along with the reason the existing lockfile
+            // is out-of-date.
             if matches!(result, LockResult::Changed(_, _)) {
-                return Err(ProjectError::LockMismatch);
+                return Err(ProjectError::LockMismatch(staleness));
             }
 
             Ok(result)
@@ -260,7 +376,7 @@ pub(super) async fn do_safe_lock(
             };
 
             // Perform the lock operation.
-            let result = do_lock(
+            let (result, _) = do_lock(
                 workspace,
                 interpreter,
                 existing,
@@ -289,6 +405,9 @@ pub(super) async fn do_safe_lock(
 }
 
 /// Lock the project requirements into a lockfile.
+///
+/// Returns the result of the lock operation, along with the reason that the existing lockfile (if
+/// any) did not satisfy the project requirements.
 async fn do_lock(
     workspace: &Workspace,
     interpreter: &Interpreter,
@@ -302,7 +421,7 @@ async fn do_lock(
     native_tls: bool,
     cache: &Cache,
     printer: Printer,
-) -> Result<LockResult, ProjectError> {
+) -> Result<(LockResult, Option<LockStaleness>), ProjectError> {
     let start = std::time::Instant::now();
 
     // Extract the project settings.
@@ -313,8 +432,13 @@ async fn do_lock(
         allow_insecure_host,
         resolution,
         prerelease,
+        yanked,
+        allow_yanked,
+        prefer_local_version,
+        resolution_budget,
         dependency_metadata,
         config_setting,
+        build_containers,
         no_build_isolation,
         no_build_isolation_package,
         exclude_newer,
@@ -322,8 +446,17 @@ async fn do_lock(
         upgrade,
         build_options,
         sources,
+        variants,
+        preference_files,
+        minimal_changes,
+        upgrade_git,
     } = settings;
 
+    // Ensure that the requested variants are declared in `tool.uv.sources`.
+    if matches!(sources, SourceStrategy::Enabled) {
+        pip::operations::validate_variants([workspace], variants)?;
+    }
+
     // Collect the requirements, etc.
     let requirements = workspace.non_project_requirements()?;
     let overrides = workspace.overrides().into_iter().collect::<Vec<_>>();
@@ -430,6 +563,8 @@ async fn do_lock(
     for index in index_locations.allowed_indexes() {
         if let Some(credentials) = index.credentials() {
             uv_auth::store_credentials(index.raw_url(), credentials);
+        } else if let Some(secrets) = index.secret_credentials() {
+            uv_auth::store_secret_credentials(secrets);
         }
     }
 
@@ -460,10 +595,23 @@ async fn do_lock(
     let options = OptionsBuilder::new()
         .resolution_mode(resolution)
         .prerelease_mode(prerelease)
+        .yanked_mode(yanked)
         .exclude_newer(exclude_newer)
         .index_strategy(index_strategy)
+        .minimal_changes(minimal_changes)
+        .budget(resolution_budget)
         .build();
-    let hasher = HashStrategy::Generate;
+    // Generate hashes for all distributions, and validate those declared for URL sources before
+    // building them.
+    let hasher = if matches!(sources, SourceStrategy::Enabled) {
+        HashStrategy::generate().with_url_hashes(
+            workspace
+                .url_source_hashes()
+                .map(|(_, url, hash)| (url, hash)),
+        )
+    } else {
+        HashStrategy::generate()
+    };
 
     // TODO(charlie): These are all default values. We should consider whether we want to make them
     // optional on the downstream APIs.
@@ -473,7 +621,8 @@ async fn do_lock(
 
     // Resolve the flat indexes from `--find-links`.
     let flat_index = {
-        let client = FlatIndexClient::new(&client, cache);
+        let client = FlatIndexClient::new(&client, cache)
+            .with_recursive(index_locations.find_links_recursive());
         let entries = client
             .fetch(index_locations.flat_indexes().map(Index::url))
             .await?;
@@ -491,10 +640,12 @@ async fn do_lock(
         dependency_metadata,
         &state.index,
         &state.git,
+        &state.hg,
         &state.capabilities,
         &state.in_flight,
         index_strategy,
         config_setting,
+        build_containers,
         build_isolation,
         link_mode,
         build_options,
@@ -503,9 +654,22 @@ async fn do_lock(
         bounds,
         sources,
         concurrency,
-    );
-
-    let database = DistributionDatabase::new(&client, &build_dispatch, concurrency.downloads);
+    )
+    .with_variants(variants);
+
+    let database = DistributionDatabase::new(&client, &build_dispatch, concurrency);
+
+    // If requested, advance the Git sources that track a branch or tag by marking them for upgrade,
+    // such that their locked commits are discarded while all other pins are retained.
+    let git_upgrade;
+    let upgrade = if let Some(lock) = existing_lock.as_ref().filter(|_| !upgrade_git.is_none()) {
+        git_upgrade = upgrade
+            .clone()
+            .combine(upgrade_git_sources(lock, upgrade_git));
+        &git_upgrade
+    } else {
+        upgrade
+    };
 
     // If any of the resolution-determining settings changed, invalidate the lock.
     let existing_lock = if let Some(existing_lock) = existing_lock {
@@ -549,40 +713,72 @@ async fn do_lock(
     match existing_lock {
         // Resolution from the lockfile succeeded.
         Some(ValidatedLock::Satisfies(lock)) => {
+            // Verify the hashes of any URL sources.
+            if matches!(sources, SourceStrategy::Enabled) {
+                lock.verify_source_hashes(workspace)?;
+            }
+
             // Print the success message after completing resolution.
             logger.on_complete(lock.len(), start, printer)?;
 
-            Ok(LockResult::Unchanged(lock))
+            Ok((LockResult::Unchanged(lock), None))
         }
 
         // The lockfile did not contain enough information to obtain a resolution, fallback
         // to a fresh resolve.
         _ => {
+            // Warn about any extras or dependency groups that can't be resolved together.
+            warn_on_conflicts(workspace)?;
+
             // Determine whether we can reuse the existing package versions.
             let versions_lock = existing_lock.as_ref().and_then(|lock| match &lock {
                 ValidatedLock::Satisfies(lock) => Some(lock),
-                ValidatedLock::Preferable(lock) => Some(lock),
-                ValidatedLock::Versions(lock) => Some(lock),
-                ValidatedLock::Unusable(_) => None,
+                ValidatedLock::Preferable(lock, _) => Some(lock),
+                ValidatedLock::Versions(lock, _) => Some(lock),
+                ValidatedLock::Unusable(..) => None,
             });
 
             // If an existing lockfile exists, build up a set of preferences.
-            let LockedRequirements { preferences, git } = versions_lock
+            let LockedRequirements {
+                mut preferences,
+                git,
+                hg,
+            } = versions_lock
                 .map(|lock| read_lock_requirements(lock, upgrade))
                 .unwrap_or_default();
 
+            // Seed any additional preferences from the provided preference files, deferring to
+            // the existing lockfile for any packages that it already pins.
+            if !preference_files.is_empty() {
+                let locked = preferences
+                    .iter()
+                    .map(|preference| preference.name().clone())
+                    .collect::<FxHashSet<_>>();
+                for preference in read_preference_files(preference_files, upgrade).await? {
+                    if !locked.contains(preference.name()) {
+                        preferences.push(preference);
+                    }
+                }
+            }
+
             // Populate the Git resolver.
             for ResolvedRepositoryReference { reference, sha } in git {
                 debug!("Inserting Git reference into resolver: `{reference:?}` at `{sha}`");
                 state.git.insert(reference, sha);
             }
 
+            // Populate the Mercurial resolver.
+            for ResolvedHgRepositoryReference { reference, node } in hg {
+                debug!("Inserting Mercurial reference into resolver: `{reference:?}` at `{node}`");
+                state.hg.insert(reference, node);
+            }
+
             // Determine whether we can reuse the existing package forks.
             let forks_lock = existing_lock.as_ref().and_then(|lock| match &lock {
                 ValidatedLock::Satisfies(lock) => Some(lock),
-                ValidatedLock::Preferable(lock) => Some(lock),
-                ValidatedLock::Versions(_) => None,
-                ValidatedLock::Unusable(_) => None,
+                ValidatedLock::Preferable(lock, _) => Some(lock),
+                ValidatedLock::Versions(..) => None,
+                ValidatedLock::Unusable(..) => None,
             });
 
             // When we run the same resolution from the lockfile again, we could get a different result the
@@ -632,6 +828,8 @@ async fn do_lock(
                 &hasher,
                 &Reinstall::default(),
                 upgrade,
+                allow_yanked,
+                prefer_local_version,
                 None,
                 resolver_markers,
                 python_requirement,
@@ -658,10 +856,14 @@ async fn do_lock(
                 constraints,
                 overrides,
                 dependency_metadata.values().cloned(),
+                dependency_metadata.overrides().cloned(),
             )
             .relative_to(workspace)?;
 
-            let previous = existing_lock.map(ValidatedLock::into_lock);
+            let (previous, staleness) = match existing_lock.map(ValidatedLock::into_parts) {
+                Some((lock, staleness)) => (Some(lock), staleness),
+                None => (None, None),
+            };
             let lock = Lock::from_resolution_graph(&resolution, workspace.install_path())?
                 .with_manifest(manifest)
                 .with_supported_environments(
@@ -669,9 +871,16 @@ async fn do_lock(
                         .cloned()
                         .map(SupportedEnvironments::into_markers)
                         .unwrap_or_default(),
-                );
+                )
+                // Retain the format of the existing lockfile, if any.
+                .with_format(previous.as_ref().map(Lock::format).unwrap_or_default());
+
+            // Verify the hashes of any URL sources.
+            if matches!(sources, SourceStrategy::Enabled) {
+                lock.verify_source_hashes(workspace)?;
+            }
 
-            Ok(LockResult::Changed(previous, lock))
+            Ok((LockResult::Changed(previous, lock), staleness))
         }
     }
 }
@@ -681,13 +890,13 @@ enum ValidatedLock {
     /// An existing lockfile was provided, and it satisfies the workspace requirements.
     Satisfies(Lock),
     /// An existing lockfile was provided, but its contents should be ignored.
-    Unusable(Lock),
+    Unusable(Lock, LockStaleness),
     /// An existing lockfile was provided, and the locked versions and forks should be preferred if
     /// possible, even though the lockfile does not satisfy the workspace requirements.
-    Preferable(Lock),
+    Preferable(Lock, LockStaleness),
     /// An existing lockfile was provided, and the locked versions should be preferred if possible,
     /// though the forks should be ignored.
-    Versions(Lock),
+    Versions(Lock, LockStaleness),
 }
 
 impl ValidatedLock {
@@ -720,7 +929,9 @@ impl ValidatedLock {
                 lock.resolution_mode().cyan(),
                 options.resolution_mode.cyan()
             );
-            return Ok(Self::Unusable(lock));
+            let staleness =
+                LockStaleness::ResolutionMode(lock.resolution_mode(), options.resolution_mode);
+            return Ok(Self::Unusable(lock, staleness));
         }
         if lock.prerelease_mode() != options.prerelease_mode {
             let _ = writeln!(
@@ -729,7 +940,9 @@ impl ValidatedLock {
                 lock.prerelease_mode().cyan(),
                 options.prerelease_mode.cyan()
             );
-            return Ok(Self::Unusable(lock));
+            let staleness =
+                LockStaleness::PrereleaseMode(lock.prerelease_mode(), options.prerelease_mode);
+            return Ok(Self::Unusable(lock, staleness));
         }
         match (lock.exclude_newer(), options.exclude_newer) {
             (None, None) => (),
@@ -741,7 +954,8 @@ impl ValidatedLock {
                     existing.cyan(),
                     provided.cyan()
                 );
-                return Ok(Self::Unusable(lock));
+                let staleness = LockStaleness::ExcludeNewer(Some(existing), Some(provided));
+                return Ok(Self::Unusable(lock, staleness));
             }
             (Some(existing), None) => {
                 let _ = writeln!(
@@ -749,7 +963,8 @@ impl ValidatedLock {
                     "Ignoring existing lockfile due to removal of timestamp cutoff: `{}`",
                     existing.cyan(),
                 );
-                return Ok(Self::Unusable(lock));
+                let staleness = LockStaleness::ExcludeNewer(Some(existing), None);
+                return Ok(Self::Unusable(lock, staleness));
             }
             (None, Some(provided)) => {
                 let _ = writeln!(
@@ -757,22 +972,35 @@ impl ValidatedLock {
                     "Ignoring existing lockfile due to addition of timestamp cutoff: `{}`",
                     provided.cyan()
                 );
-                return Ok(Self::Unusable(lock));
+                let staleness = LockStaleness::ExcludeNewer(None, Some(provided));
+                return Ok(Self::Unusable(lock, staleness));
             }
         }
 
+        // If the yanked mode has changed, the existing versions may no longer be permitted (or
+        // previously-excluded versions may now be permitted), but we can still prefer them.
+        if lock.yanked_mode() != options.yanked_mode {
+            debug!(
+                "Ignoring existing lockfile due to change in yanked mode: `{}` vs. `{}`",
+                lock.yanked_mode(),
+                options.yanked_mode
+            );
+            let staleness = LockStaleness::YankedMode(lock.yanked_mode(), options.yanked_mode);
+            return Ok(Self::Preferable(lock, staleness));
+        }
+
         match upgrade {
             Upgrade::None => {}
             Upgrade::All => {
                 // If the user specified `--upgrade`, then we can't use the existing lockfile.
                 debug!("Ignoring existing lockfile due to `--upgrade`");
-                return Ok(Self::Unusable(lock));
+                return Ok(Self::Unusable(lock, LockStaleness::Upgrade));
             }
             Upgrade::Packages(_) => {
                 // If the user specified `--upgrade-package`, then at best we can prefer some of
                 // the existing versions.
                 debug!("Ignoring existing lockfile due to `--upgrade-package`");
-                return Ok(Self::Preferable(lock));
+                return Ok(Self::Preferable(lock, LockStaleness::UpgradePackage));
             }
         }
 
@@ -784,10 +1012,14 @@ impl ValidatedLock {
                 lock.requires_python(),
                 requires_python,
             );
+            let staleness = LockStaleness::RequiresPython(
+                lock.requires_python().clone(),
+                requires_python.clone(),
+            );
             return if lock.fork_markers().is_empty() {
-                Ok(Self::Preferable(lock))
+                Ok(Self::Preferable(lock, staleness))
             } else {
-                Ok(Self::Versions(lock))
+                Ok(Self::Versions(lock, staleness))
             };
         }
 
@@ -805,7 +1037,17 @@ impl ValidatedLock {
                 "Ignoring existing lockfile due to change in supported environments: `{:?}` vs. `{:?}`",
                 expected, actual
             );
-            return Ok(Self::Versions(lock));
+            let staleness = LockStaleness::Environments(
+                expected
+                    .iter()
+                    .filter_map(MarkerTree::try_to_string)
+                    .collect(),
+                actual
+                    .iter()
+                    .filter_map(MarkerTree::try_to_string)
+                    .collect(),
+            );
+            return Ok(Self::Versions(lock, staleness));
         }
 
         // If the user provided at least one index URL (from the command line, or from a configuration
@@ -822,7 +1064,7 @@ impl ValidatedLock {
         };
 
         // Determine whether the lockfile satisfies the workspace requirements.
-        match lock
+        let staleness = match lock
             .satisfies(
                 workspace,
                 members,
@@ -841,112 +1083,297 @@ impl ValidatedLock {
         {
             SatisfiesResult::Satisfied => {
                 debug!("Existing `uv.lock` satisfies workspace requirements");
-                Ok(Self::Satisfies(lock))
+                return Ok(Self::Satisfies(lock));
             }
             SatisfiesResult::MismatchedMembers(expected, actual) => {
-                debug!(
-                    "Ignoring existing lockfile due to mismatched members:\n  Expected: {:?}\n  Actual: {:?}",
-                    expected, actual
-                );
-                Ok(Self::Preferable(lock))
+                LockStaleness::Members(expected, actual.clone())
             }
             SatisfiesResult::MismatchedSources(name, expected) => {
-                if expected {
-                    debug!(
-                        "Ignoring existing lockfile due to mismatched source: `{name}` (expected: `virtual`)"
-                    );
+                // If the member is absent from the lockfile entirely (e.g., it was renamed), report
+                // it as missing, rather than as a change in source.
+                if !lock
+                    .packages()
+                    .iter()
+                    .any(|package| package.name() == &name)
+                {
+                    LockStaleness::MissingRoot(name)
                 } else {
-                    debug!(
-                        "Ignoring existing lockfile due to mismatched source: `{name}` (expected: `editable`)"
-                    );
+                    LockStaleness::Sources(name, expected)
                 }
-                Ok(Self::Preferable(lock))
             }
             SatisfiesResult::MismatchedVersion(name, expected, actual) => {
-                if let Some(actual) = actual {
-                    debug!(
-                        "Ignoring existing lockfile due to mismatched version: `{name}` (expected: `{expected}`, found: `{actual}`)"
-                    );
-                } else {
-                    debug!(
-                        "Ignoring existing lockfile due to mismatched version: `{name}` (expected: `{expected}`)"
-                    );
-                }
-                Ok(Self::Preferable(lock))
+                LockStaleness::Version(name, expected, actual)
             }
             SatisfiesResult::MismatchedRequirements(expected, actual) => {
-                debug!(
-                    "Ignoring existing lockfile due to mismatched requirements:\n  Expected: {:?}\n  Actual: {:?}",
-                    expected, actual
-                );
-                Ok(Self::Preferable(lock))
+                LockStaleness::Requirements(expected, actual)
             }
             SatisfiesResult::MismatchedConstraints(expected, actual) => {
-                debug!(
-                    "Ignoring existing lockfile due to mismatched constraints:\n  Expected: {:?}\n  Actual: {:?}",
-                    expected, actual
-                );
-                Ok(Self::Preferable(lock))
+                LockStaleness::Constraints(expected, actual)
             }
             SatisfiesResult::MismatchedOverrides(expected, actual) => {
-                debug!(
-                    "Ignoring existing lockfile due to mismatched overrides:\n  Expected: {:?}\n  Actual: {:?}",
-                    expected, actual
-                );
-                Ok(Self::Preferable(lock))
-            }
-            SatisfiesResult::MismatchedStaticMetadata(expected, actual) => {
-                debug!(
-                    "Ignoring existing lockfile due to mismatched static metadata:\n  Expected: {:?}\n  Actual: {:?}",
-                    expected, actual
-                );
-                Ok(Self::Preferable(lock))
+                LockStaleness::Overrides(expected, actual)
             }
-            SatisfiesResult::MissingRoot(name) => {
-                debug!("Ignoring existing lockfile due to missing root package: `{name}`");
-                Ok(Self::Preferable(lock))
+            SatisfiesResult::MismatchedStaticMetadata(..) => LockStaleness::StaticMetadata,
+            SatisfiesResult::MismatchedDependencyOverrides(..) => {
+                LockStaleness::DependencyOverrides
             }
+            SatisfiesResult::MissingRoot(name) => LockStaleness::MissingRoot(name),
             SatisfiesResult::MissingRemoteIndex(name, version, index) => {
-                debug!(
-                    "Ignoring existing lockfile due to missing remote index: `{name}` `{version}` from `{index}`"
-                );
-                Ok(Self::Preferable(lock))
+                LockStaleness::MissingIndex(name.clone(), version.clone(), index.to_string())
             }
             SatisfiesResult::MissingLocalIndex(name, version, index) => {
-                debug!(
-                    "Ignoring existing lockfile due to missing local index: `{name}` `{version}` from `{}`", index.display()
-                );
-                Ok(Self::Preferable(lock))
+                LockStaleness::MissingIndex(
+                    name.clone(),
+                    version.clone(),
+                    index.user_display().to_string(),
+                )
             }
-            SatisfiesResult::MismatchedRequiresDist(name, version, expected, actual) => {
-                debug!(
-                    "Ignoring existing lockfile due to mismatched `requires-dist` for: `{name}=={version}`\n  Expected: {:?}\n  Actual: {:?}",
-                    expected, actual
-                );
-                Ok(Self::Preferable(lock))
+            SatisfiesResult::MismatchedRequiresDist(name, _version, expected, actual) => {
+                LockStaleness::RequiresDist(name.clone(), expected, actual)
             }
-            SatisfiesResult::MismatchedDependencyGroups(name, version, expected, actual) => {
-                debug!(
-                    "Ignoring existing lockfile due to mismatched dev dependencies for: `{name}=={version}`\n  Expected: {:?}\n  Actual: {:?}",
-                    expected, actual
-                );
-                Ok(Self::Preferable(lock))
+            SatisfiesResult::MismatchedDependencyGroups(name, _version, expected, actual) => {
+                LockStaleness::DependencyGroups(name.clone(), expected, actual)
             }
-        }
+        };
+
+        debug!("Ignoring existing lockfile: {staleness}");
+        Ok(Self::Preferable(lock, staleness))
     }
 
-    /// Convert the [`ValidatedLock`] into a [`Lock`].
+    /// Convert the [`ValidatedLock`] into a [`Lock`], along with the reason that the lockfile
+    /// doesn't satisfy the workspace requirements, if any.
     #[must_use]
-    fn into_lock(self) -> Lock {
+    fn into_parts(self) -> (Lock, Option<LockStaleness>) {
+        match self {
+            Self::Satisfies(lock) => (lock, None),
+            Self::Unusable(lock, staleness) => (lock, Some(staleness)),
+            Self::Preferable(lock, staleness) => (lock, Some(staleness)),
+            Self::Versions(lock, staleness) => (lock, Some(staleness)),
+        }
+    }
+}
+
+/// The reason that an existing lockfile doesn't satisfy the workspace requirements, determined by
+/// comparing the inputs recorded in the lockfile against the current state of the workspace.
+#[derive(Debug)]
+pub(crate) enum LockStaleness {
+    /// The resolution mode changed.
+    ResolutionMode(ResolutionMode, ResolutionMode),
+    /// The pre-release mode changed.
+    PrereleaseMode(PrereleaseMode, PrereleaseMode),
+    /// The timestamp cutoff was added, removed, or changed.
+    ExcludeNewer(Option<ExcludeNewer>, Option<ExcludeNewer>),
+    /// The yanked mode changed.
+    YankedMode(YankedMode, YankedMode),
+    /// All packages were marked for upgrade.
+    Upgrade,
+    /// Some packages were marked for upgrade.
+    UpgradePackage,
+    /// The `requires-python` bound changed.
+    RequiresPython(RequiresPython, RequiresPython),
+    /// The supported environments changed.
+    Environments(Vec<String>, Vec<String>),
+    /// The set of workspace members changed.
+    Members(BTreeSet<PackageName>, BTreeSet<PackageName>),
+    /// A workspace member switched between a package and a virtual project.
+    Sources(PackageName, bool),
+    /// The version of a workspace member changed.
+    Version(PackageName, Version, Option<Version>),
+    /// The requirements provided to the resolver (exclusive of workspace members) changed.
+    Requirements(BTreeSet<Requirement>, BTreeSet<Requirement>),
+    /// The constraints changed.
+    Constraints(BTreeSet<Requirement>, BTreeSet<Requirement>),
+    /// The overrides changed.
+    Overrides(BTreeSet<Requirement>, BTreeSet<Requirement>),
+    /// The static metadata changed.
+    StaticMetadata,
+    /// The dependency overrides changed.
+    DependencyOverrides,
+    /// A workspace member is missing from the lockfile.
+    MissingRoot(PackageName),
+    /// A package was locked against an index that is no longer provided.
+    MissingIndex(PackageName, Version, String),
+    /// The requirements of a workspace member (or other mutable package) changed.
+    RequiresDist(PackageName, BTreeSet<Requirement>, BTreeSet<Requirement>),
+    /// The dependency groups of a workspace member (or other mutable package) changed.
+    DependencyGroups(
+        PackageName,
+        BTreeMap<GroupName, BTreeSet<Requirement>>,
+        BTreeMap<GroupName, BTreeSet<Requirement>>,
+    ),
+}
+
+impl std::fmt::Display for LockStaleness {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::Unusable(lock) => lock,
-            Self::Satisfies(lock) => lock,
-            Self::Preferable(lock) => lock,
-            Self::Versions(lock) => lock,
+            Self::ResolutionMode(locked, current) => {
+                write!(
+                    f,
+                    "The resolution mode changed from `{locked}` to `{current}`"
+                )
+            }
+            Self::PrereleaseMode(locked, current) => {
+                write!(
+                    f,
+                    "The pre-release mode changed from `{locked}` to `{current}`"
+                )
+            }
+            Self::ExcludeNewer(Some(locked), Some(current)) => {
+                write!(
+                    f,
+                    "The timestamp cutoff changed from `{locked}` to `{current}`"
+                )
+            }
+            Self::ExcludeNewer(Some(locked), None) => {
+                write!(f, "The timestamp cutoff (`{locked}`) was removed")
+            }
+            Self::ExcludeNewer(None, Some(current)) => {
+                write!(f, "A timestamp cutoff (`{current}`) was added")
+            }
+            Self::ExcludeNewer(None, None) => write!(f, "The timestamp cutoff changed"),
+            Self::YankedMode(locked, current) => {
+                write!(f, "The yanked mode changed from `{locked}` to `{current}`")
+            }
+            Self::Upgrade => write!(f, "All packages were marked for upgrade with `--upgrade`"),
+            Self::UpgradePackage => write!(
+                f,
+                "Some packages were marked for upgrade with `--upgrade-package`"
+            ),
+            Self::RequiresPython(locked, current) => {
+                write!(
+                    f,
+                    "The Python requirement changed from `{locked}` to `{current}`"
+                )
+            }
+            Self::Environments(locked, current) => {
+                write!(
+                    f,
+                    "The supported environments changed from {} to {}",
+                    display_markers(locked),
+                    display_markers(current)
+                )
+            }
+            Self::Members(current, locked) => {
+                write!(
+                    f,
+                    "The workspace members changed ({})",
+                    display_changes(current, locked)
+                )
+            }
+            Self::Sources(name, true) => {
+                write!(f, "`{name}` changed from a package to a virtual project")
+            }
+            Self::Sources(name, false) => {
+                write!(f, "`{name}` changed from a virtual project to a package")
+            }
+            Self::Version(name, current, Some(locked)) => {
+                write!(
+                    f,
+                    "The version of `{name}` changed from `{locked}` to `{current}`"
+                )
+            }
+            Self::Version(name, current, None) => {
+                write!(f, "The version of `{name}` changed to `{current}`")
+            }
+            Self::Requirements(current, locked) => {
+                write!(
+                    f,
+                    "The workspace requirements changed ({})",
+                    display_changes(current, locked)
+                )
+            }
+            Self::Constraints(current, locked) => {
+                write!(
+                    f,
+                    "The workspace constraints changed ({})",
+                    display_changes(current, locked)
+                )
+            }
+            Self::Overrides(current, locked) => {
+                write!(
+                    f,
+                    "The workspace overrides changed ({})",
+                    display_changes(current, locked)
+                )
+            }
+            Self::StaticMetadata => write!(f, "The static dependency metadata changed"),
+            Self::DependencyOverrides => write!(f, "The dependency overrides changed"),
+            Self::MissingRoot(name) => {
+                write!(
+                    f,
+                    "The workspace member `{name}` is missing from the lockfile"
+                )
+            }
+            Self::MissingIndex(name, version, index) => {
+                write!(
+                    f,
+                    "`{name}=={version}` was locked against `{index}`, which is not one of the configured indexes"
+                )
+            }
+            Self::RequiresDist(name, current, locked) => {
+                write!(
+                    f,
+                    "The requirements of `{name}` changed ({})",
+                    display_changes(current, locked)
+                )
+            }
+            Self::DependencyGroups(name, current, locked) => {
+                let flatten = |groups: &BTreeMap<GroupName, BTreeSet<Requirement>>| {
+                    groups
+                        .iter()
+                        .flat_map(|(group, requirements)| {
+                            requirements
+                                .iter()
+                                .map(move |requirement| format!("{requirement} (group: {group})"))
+                        })
+                        .collect::<BTreeSet<_>>()
+                };
+                write!(
+                    f,
+                    "The dependency groups of `{name}` changed ({})",
+                    display_changes(&flatten(current), &flatten(locked))
+                )
+            }
         }
     }
 }
 
+impl std::error::Error for LockStaleness {}
+
+/// Describe the items that were added to (i.e., are in `current`, but not in `locked`) and removed
+/// from (i.e., are in `locked`, but not in `current`) a set of lockfile inputs.
+fn display_changes<T: Ord + std::fmt::Display>(
+    current: &BTreeSet<T>,
+    locked: &BTreeSet<T>,
+) -> String {
+    let added = current
+        .difference(locked)
+        .map(|item| format!("`{item}`"))
+        .join(", ");
+    let removed = locked
+        .difference(current)
+        .map(|item| format!("`{item}`"))
+        .join(", ");
+    match (added.is_empty(), removed.is_empty()) {
+        (false, false) => format!("added: {added}; removed: {removed}"),
+        (false, true) => format!("added: {added}"),
+        (true, false) => format!("removed: {removed}"),
+        (true, true) => "no changes".to_string(),
+    }
+}
+
+/// Display a list of environment markers.
+fn display_markers(markers: &[String]) -> String {
+    if markers.is_empty() {
+        "all environments".to_string()
+    } else {
+        markers
+            .iter()
+            .map(|marker| format!("`{marker}`"))
+            .join(", ")
+    }
+}
+
 /// Write the lockfile to disk.
 async fn commit(lock: &Lock, workspace: &Workspace) -> Result<(), ProjectError> {
     let encoded = lock.to_toml()?;
@@ -954,6 +1381,121 @@ async fn commit(lock: &Lock, workspace: &Workspace) -> Result<(), ProjectError>
     Ok(())
 }
 
+/// Determine the [`Upgrade`] strategy that advances the requested Git sources in the lockfile to
+/// their latest commit.
+///
+/// Git sources that are pinned to a specific commit are retained, as they can't move.
+///
+/// Packages that share a repository and reference resolve to the same commit, so advancing any one
+/// of them advances all of them.
+fn upgrade_git_sources(lock: &Lock, upgrade_git: &GitUpgrade) -> Upgrade {
+    let mut packages = FxHashMap::default();
+    let mut references = FxHashSet::default();
+    for package in lock.packages() {
+        if !upgrade_git.contains(package.name()) {
+            continue;
+        }
+        let Some(git) = package.as_git_ref() else {
+            continue;
+        };
+        if git.reference.reference.is_commit() {
+            debug!(
+                "Retaining Git source for `{}`, which is pinned to a commit",
+                package.name()
+            );
+            continue;
+        }
+        debug!(
+            "Advancing Git source for `{}` from `{}`",
+            package.name(),
+            git.sha
+        );
+        packages
+            .entry(package.name().clone())
+            .or_insert_with(Vec::new);
+        references.insert(git.reference);
+    }
+
+    // Advance any other packages that share a reference with an advanced package, as their locked
+    // commits would otherwise continue to pin the reference.
+    for package in lock.packages() {
+        if packages.contains_key(package.name()) {
+            continue;
+        }
+        let Some(git) = package.as_git_ref() else {
+            continue;
+        };
+        if references.contains(&git.reference) {
+            debug!(
+                "Advancing Git source for `{}` from `{}`, which shares a reference with an advanced package",
+                package.name(),
+                git.sha
+            );
+            packages
+                .entry(package.name().clone())
+                .or_insert_with(Vec::new);
+        }
+    }
+
+    // Warn about any requested packages that can't be advanced.
+    if let GitUpgrade::Packages(requested) = upgrade_git {
+        for name in requested {
+            if !packages.contains_key(name) {
+                warn_user!(
+                    "`{name}` is not locked to a Git source that tracks a branch or tag; ignoring `--upgrade-git-package {name}`"
+                );
+            }
+        }
+    }
+
+    if packages.is_empty() {
+        Upgrade::None
+    } else {
+        Upgrade::Packages(packages)
+    }
+}
+
+/// Read the preferred versions from a set of preference files, applying the upgrade strategy.
+///
+/// Each file may either be a `uv.lock` (e.g., from a related workspace) or a `requirements.txt`.
+async fn read_preference_files(
+    paths: &[PathBuf],
+    upgrade: &Upgrade,
+) -> Result<Vec<Preference>, ProjectError> {
+    let mut preferences = Vec::new();
+    for path in paths {
+        if !path.is_file() {
+            return Err(ProjectError::MissingPreferenceFile(
+                path.user_display().to_string(),
+            ));
+        }
+        if path
+            .extension()
+            .is_some_and(|extension| extension == "lock")
+        {
+            let encoded = fs_err::tokio::read_to_string(path).await?;
+            let lock = toml::from_str::<Lock>(&encoded).map_err(|err| {
+                ProjectError::PreferenceFileParse(path.user_display().to_string(), err)
+            })?;
+            debug!(
+                "Using {} preference(s) from lockfile: `{}`",
+                lock.len(),
+                path.user_display()
+            );
+            preferences.extend(read_lock_requirements(&lock, upgrade).preferences);
+        } else {
+            let entries = read_requirements_txt(Some(path), upgrade).await?;
+            debug!(
+                "Using {} preference(s) from requirements file: `{}`",
+                entries.len(),
+                path.user_display()
+            );
+            preferences.extend(entries);
+        }
+    }
+    Ok(preferences)
+}
+
 /// Read the lockfile from the workspace.
 ///
 /// Returns `Ok(None)` if the lockfile does not exist.
@@ -963,9 +1505,9 @@ pub(crate) async fn read(workspace: &Workspace) -> Result<Option<Lock>, ProjectE
             match toml::from_str::<Lock>(&encoded) {
                 Ok(lock) => {
                     // If the lockfile uses an unsupported version, raise an error.
-                    if lock.version() != VERSION {
+                    if !matches!(lock.version(), VERSION | DIFFABLE_VERSION) {
                         return Err(ProjectError::UnsupportedLockVersion(
-                            VERSION,
+                            DIFFABLE_VERSION,
                             lock.version(),
                         ));
                     }
@@ -975,9 +1517,9 @@ pub(crate) async fn read(workspace: &Workspace) -> Result<Option<Lock>, ProjectE
                     // If we failed to parse the lockfile, determine whether it's a supported
                     // version.
                     if let Ok(lock) = toml::from_str::<LockVersion>(&encoded) {
-                        if lock.version() != VERSION {
+                        if !matches!(lock.version(), VERSION | DIFFABLE_VERSION) {
                             return Err(ProjectError::UnparsableLockVersion(
-                                VERSION,
+                                DIFFABLE_VERSION,
                                 lock.version(),
                                 err,
                             ));
@@ -1086,3 +1628,351 @@ fn report_upgrades(
 
     Ok(updated)
 }
+
+/// Report the upgrades between two lockfiles as JSON, for consumption by automated tooling.
+fn report_upgrades_json(
+    existing_lock: Option<&Lock>,
+    new_lock: &Lock,
+    printer: Printer,
+    dry_run: bool,
+) -> anyhow::Result<()> {
+    /// The change to a package between two lockfiles.
+    #[derive(Debug, Serialize)]
+    #[serde(rename_all = "lowercase")]
+    enum Action {
+        Add,
+        Remove,
+        Update,
+    }
+
+    /// An entry in the JSON list of lockfile changes.
+    #[derive(Debug, Serialize)]
+    struct Change<'lock> {
+        name: &'lock PackageName,
+        action: Action,
+        previous: Vec<&'lock Version>,
+        current: Vec<&'lock Version>,
+    }
+
+    /// The JSON report of lockfile changes.
+    #[derive(Debug, Serialize)]
+    struct Report<'lock> {
+        dry_run: bool,
+        changes: Vec<Change<'lock>>,
+    }
+
+    /// Collect the versions of each package in a lockfile.
+    fn versions(lock: &Lock) -> BTreeMap<&PackageName, BTreeSet<&Version>> {
+        lock.packages()
+            .iter()
+            .fold(BTreeMap::new(), |mut acc, package| {
+                acc.entry(package.name())
+                    .or_default()
+                    .insert(package.version());
+                acc
+            })
+    }
+
+    let existing_packages = existing_lock.map(versions).unwrap_or_default();
+    let new_packages = versions(new_lock);
+
+    let changes = existing_packages
+        .keys()
+        .chain(new_packages.keys())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .filter_map(|name| {
+            let previous = existing_packages.get(name);
+            let current = new_packages.get(name);
+            let action = match (previous, current) {
+                (Some(previous), Some(current)) if previous == current => return None,
+                (Some(_), Some(_)) => Action::Update,
+                (Some(_), None) => Action::Remove,
+                (None, Some(_)) => Action::Add,
+                (None, None) => {
+                    unreachable!("The key `{name}` should exist in at least one of the maps")
+                }
+            };
+            Some(Change {
+                name,
+                action,
+                previous: previous.into_iter().flatten().copied().collect(),
+                current: current.into_iter().flatten().copied().collect(),
+            })
+        })
+        .collect();
+
+    let report = Report { dry_run, changes };
+    writeln!(
+        printer.stdout(),
+        "{}",
+        serde_json::to_string_pretty(&report)?
+    )?;
+
+    Ok(())
+}
+
+/// Report any requirements declared by the workspace whose bounds are stale relative to the
+/// versions in the lockfile, returning the number of stale bounds.
+///
+/// An upper bound is considered stale if it excludes a newer release that's available on the
+/// index (e.g., `<2` for `v1.4.2`, when `v2.0.0` has been published). A lower bound is considered
+/// stale if it admits an older release series than the resolved version (e.g., `>=1.2` for
+/// `v3.4.0`).
+async fn report_bounds(
+    lock: &Lock,
+    settings: &ResolverSettings,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    native_tls: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> anyhow::Result<usize> {
+    let resolved: FxHashMap<&PackageName, BTreeSet<&Version>> = lock.packages().iter().fold(
+        FxHashMap::with_capacity_and_hasher(lock.packages().len(), FxBuildHasher),
+        |mut acc, package| {
+            acc.entry(package.name())
+                .or_default()
+                .insert(package.version());
+            acc
+        },
+    );
+
+    // Only source trees (e.g., workspace members) record their declared requirements.
+    let mut requirements = Vec::new();
+    for package in lock.packages() {
+        let mut seen = FxHashSet::default();
+        for requirement in package
+            .requires_dist()
+            .iter()
+            .chain(package.requires_dev().values().flatten())
+        {
+            let RequirementSource::Registry { specifier, .. } = &requirement.source else {
+                continue;
+            };
+            if !seen.insert((&requirement.name, specifier)) {
+                continue;
+            }
+            let Some(versions) = resolved.get(&requirement.name) else {
+                continue;
+            };
+            let (Some(min), Some(max)) = (versions.first(), versions.last()) else {
+                continue;
+            };
+            requirements.push((package, &requirement.name, specifier, *min, *max));
+        }
+    }
+
+    // Fetch the versions available for any requirement with an upper bound.
+    let client = RegistryClientBuilder::new(cache.clone())
+        .connectivity(connectivity)
+        .native_tls(native_tls)
+        .index_strategy(settings.index_strategy)
+        .keyring(settings.keyring_provider)
+        .allow_insecure_host(settings.allow_insecure_host.clone())
+        .index_urls(settings.index_locations.index_urls())
+        .build();
+    let capabilities = IndexCapabilities::default();
+    let names = requirements
+        .iter()
+        .filter(|(.., specifier, _, _)| specifier.iter().any(is_upper_bound))
+        .map(|(_, name, ..)| *name)
+        .collect::<BTreeSet<_>>();
+    let available = futures::stream::iter(names)
+        .map(|name| {
+            let client = &client;
+            let capabilities = &capabilities;
+            async move {
+                let versions = available_versions(
+                    name,
+                    client,
+                    capabilities,
+                    lock.requires_python(),
+                    settings.exclude_newer,
+                )
+                .await;
+                (name, versions)
+            }
+        })
+        .buffer_unordered(concurrency.downloads)
+        .collect::<FxHashMap<_, _>>()
+        .await;
+
+    let mut stale = 0;
+    for (package, name, specifier, min, max) in requirements {
+        // Only consider pre-releases if a pre-release is already locked.
+        let latest = available.get(name).and_then(|versions| {
+            versions
+                .iter()
+                .filter(|version| max.any_prerelease() || !version.any_prerelease())
+                .max()
+        });
+        if let Some((bound, latest)) = latest.filter(|latest| *latest > max).and_then(|latest| {
+            specifier
+                .iter()
+                .find(|bound| is_upper_bound(bound) && !bound.contains(latest))
+                .map(|bound| (bound, latest))
+        }) {
+            writeln!(
+                printer.stderr(),
+                "{} {}{} in {} (resolved v{max}, but `{bound}` excludes v{latest})",
+                "Stale upper bound".yellow().bold(),
+                name.cyan(),
+                specifier.cyan(),
+                package.name().bold(),
+            )?;
+            stale += 1;
+        }
+
+        if let Some(bound) = specifier
+            .iter()
+            .find(|bound| is_below_release_series(bound, min))
+        {
+            writeln!(
+                printer.stderr(),
+                "{} {}{} in {} (resolved v{min}, but `{bound}` admits older release series)",
+                "Stale lower bound".yellow().bold(),
+                name.cyan(),
+                specifier.cyan(),
+                package.name().bold(),
+            )?;
+            stale += 1;
+        }
+    }
+
+    Ok(stale)
+}
+
+/// Report any supported environments in which the lockfile can't be installed, returning the
+/// number of such environments.
+///
+/// An environment is considered unsatisfiable if any package that may be installed in it has
+/// neither a source distribution nor a wheel compatible with the environment's platform.
+fn report_platforms(lock: &Lock, printer: Printer) -> anyhow::Result<usize> {
+    let unsatisfied = lock.unsatisfied_environments();
+    for environment in &unsatisfied {
+        writeln!(
+            printer.stderr(),
+            "{} {}",
+            "Unsatisfiable environment".yellow().bold(),
+            environment
+                .environment
+                .try_to_string()
+                .unwrap_or_else(|| "(all)".to_string())
+                .cyan(),
+        )?;
+        for package in &environment.packages {
+            writeln!(
+                printer.stderr(),
+                "  {} {} has no compatible wheel or source distribution",
+                package.name().bold(),
+                format!("v{}", package.version()).bold(),
+            )?;
+        }
+    }
+    Ok(unsatisfied.len())
+}
+
+/// Warn if any workspace member declares extras or dependency groups with incompatible
+/// requirements.
+///
+/// All extras and dependency groups are resolved together, so incompatible requirements (e.g.,
+/// different versions of `torch` in `cpu` and `gpu` extras) will cause the resolution to fail.
+/// Pairs declared in `tool.uv.conflicts` are not reported.
+#[allow(clippy::result_large_err)]
+fn warn_on_conflicts(workspace: &Workspace) -> Result<(), ProjectError> {
+    for (name, member) in workspace.packages() {
+        let pyproject_toml = member.pyproject_toml();
+        let tool_uv = pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref());
+
+        // Collect the extras.
+        let extras = pyproject_toml
+            .project
+            .as_ref()
+            .and_then(|project| project.optional_dependencies.as_ref())
+            .into_iter()
+            .flatten()
+            .map(|(extra, requirements)| {
+                let requirements = requirements
+                    .iter()
+                    .map(|requirement| {
+                        uv_pep508::Requirement::<VerbatimParsedUrl>::from_str(requirement).map_err(
+                            |err| {
+                                ProjectError::ExtraParse(
+                                    extra.clone(),
+                                    requirement.clone(),
+                                    Box::new(err),
+                                )
+                            },
+                        )
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok((ConflictItem::Extra(extra.clone()), requirements))
+            })
+            .collect::<Result<Vec<_>, ProjectError>>()?;
+
+        // Collect the dependency groups, with any `include-group` entries resolved.
+        let dependency_groups = pyproject_toml
+            .dependency_groups
+            .iter()
+            .flatten()
+            .collect::<BTreeMap<_, _>>();
+        let group_settings = tool_uv.and_then(|uv| uv.dependency_groups.as_ref());
+        let groups = FlatDependencyGroups::from_dependency_groups(
+            &dependency_groups,
+            group_settings,
+            workspace,
+        )?
+        .into_iter()
+        .map(|(group, requirements)| (ConflictItem::Group(group), requirements));
+
+        let items = extras.into_iter().chain(groups).collect::<Vec<_>>();
+        let declared = tool_uv
+            .and_then(|uv| uv.conflicts.as_deref())
+            .unwrap_or_default();
+        for Conflict {
+            package,
+            left: (left_item, left),
+            right: (right_item, right),
+        } in Conflict::find(&items, declared)
+        {
+            warn_user!(
+                "`{name}` declares incompatible requirements on `{package}` in its {left_item} (`{left}`) and {right_item} (`{right}`). All extras and dependency groups are resolved together, so the resolution will fail unless the requirements are limited to disjoint environments (e.g., with markers)."
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Returns `true` if the given specifier places an upper bound on the version (e.g., `<2` or
+/// `~=1.4`).
+fn is_upper_bound(specifier: &VersionSpecifier) -> bool {
+    matches!(
+        specifier.operator(),
+        Operator::LessThan | Operator::LessThanEqual | Operator::TildeEqual | Operator::EqualStar
+    )
+}
+
+/// Returns `true` if the given lower bound admits an older release series than `version` (e.g.,
+/// `>=1.2` for `v3.4.0`, or `>=0.3` for `v0.9.1`).
+fn is_below_release_series(specifier: &VersionSpecifier, version: &Version) -> bool {
+    /// Returns the release series of a version: the major version, or the minor version for
+    /// `0.x` releases.
+    fn release_series(version: &Version) -> (u64, u64) {
+        match version.release() {
+            [0, minor, ..] => (0, *minor),
+            [major, ..] => (*major, 0),
+            [] => (0, 0),
+        }
+    }
+
+    match specifier.operator() {
+        Operator::GreaterThan | Operator::GreaterThanEqual | Operator::TildeEqual => {
+            release_series(specifier.version()) < release_series(version)
+        }
+        _ => false,
+    }
+}
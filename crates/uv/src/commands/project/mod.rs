@@ -18,11 +18,13 @@ use uv_distribution_types::{
 };
 use uv_fs::Simplified;
 use uv_git::ResolvedRepositoryReference;
+use uv_hg::ResolvedHgRepositoryReference;
+use uv_install_wheel::LauncherResources;
 use uv_installer::{SatisfiesResult, SitePackages};
-use uv_normalize::{GroupName, PackageName, DEV_DEPENDENCIES};
+use uv_normalize::{ExtraName, GroupName, PackageName, DEV_DEPENDENCIES};
 use uv_pep440::{Version, VersionSpecifiers};
 use uv_pep508::MarkerTreeContents;
-use uv_pypi_types::Requirement;
+use uv_pypi_types::{Requirement, VerbatimParsedUrl};
 use uv_python::{
     EnvironmentPreference, Interpreter, InvalidEnvironmentKind, PythonDownloads, PythonEnvironment,
     PythonInstallation, PythonPreference, PythonRequest, PythonVariant, PythonVersionFile,
@@ -37,40 +39,48 @@ use uv_resolver::{
 use uv_types::{BuildIsolation, EmptyInstalledPackages, HashStrategy};
 use uv_warnings::{warn_user, warn_user_once};
 use uv_workspace::dependency_groups::DependencyGroupError;
-use uv_workspace::pyproject::PyProjectToml;
-use uv_workspace::{VirtualProject, Workspace};
+use uv_workspace::pyproject::{ConflictItem, PyProjectToml};
+use uv_workspace::{VirtualProject, Workspace, WorkspaceMember};
 
 use crate::commands::pip::loggers::{InstallLogger, ResolveLogger};
-use crate::commands::pip::operations::{Changelog, Modifications};
+use crate::commands::pip::operations::{Changelog, DryRun, Modifications};
+use crate::commands::project::lock::LockStaleness;
 use crate::commands::reporters::{PythonDownloadReporter, ResolverReporter};
 use crate::commands::{pip, SharedState};
 use crate::printer::Printer;
 use crate::settings::{InstallerSettingsRef, ResolverInstallerSettings, ResolverSettingsRef};
 
 pub(crate) mod add;
+pub(crate) mod bundle;
+pub(crate) mod deploy;
 pub(crate) mod environment;
 pub(crate) mod export;
+pub(crate) mod hooks;
 pub(crate) mod init;
+pub(crate) mod licenses;
 pub(crate) mod lock;
+pub(crate) mod outdated;
 pub(crate) mod remove;
 pub(crate) mod run;
 pub(crate) mod sync;
 pub(crate) mod tree;
+pub(crate) mod vendor;
+pub(crate) mod version;
 
 #[derive(thiserror::Error, Debug)]
 pub(crate) enum ProjectError {
     #[error("The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.")]
-    LockMismatch,
+    LockMismatch(#[source] Option<LockStaleness>),
 
     #[error(
         "Unable to find lockfile at `uv.lock`. To create a lockfile, run `uv lock` or `uv sync`."
     )]
     MissingLockfile,
 
-    #[error("The lockfile at `uv.lock` uses an unsupported schema version (v{1}, but only v{0} is supported). Downgrade to a compatible uv version, or remove the `uv.lock` prior to running `uv lock` or `uv sync`.")]
+    #[error("The lockfile at `uv.lock` uses an unsupported schema version (v{1}, but only up to v{0} is supported). Downgrade to a compatible uv version, or remove the `uv.lock` prior to running `uv lock` or `uv sync`.")]
     UnsupportedLockVersion(u32, u32),
 
-    #[error("Failed to parse `uv.lock`, which uses an unsupported schema version (v{1}, but only v{0} is supported). Downgrade to a compatible uv version, or remove the `uv.lock` prior to running `uv lock` or `uv sync`.")]
+    #[error("Failed to parse `uv.lock`, which uses an unsupported schema version (v{1}, but only up to v{0} is supported). Downgrade to a compatible uv version, or remove the `uv.lock` prior to running `uv lock` or `uv sync`.")]
     UnparsableLockVersion(u32, u32, #[source] toml::de::Error),
 
     #[error("The current Python version ({0}) is not compatible with the locked Python requirement: `{1}`")]
@@ -79,6 +89,12 @@ pub(crate) enum ProjectError {
     #[error("The current Python platform is not compatible with the lockfile's supported environments: {0}")]
     LockedPlatformIncompatibility(String),
 
+    #[error("`{0}` was locked against `{1}`, which is not one of the project's configured indexes, but `tool.uv.enforce-index-provenance` is enabled. If the index is intended, add it to `tool.uv.index` (and pin `{0}` to it in `tool.uv.sources`, if the index is `explicit`).")]
+    UnconfiguredIndexProvenance(PackageName, String),
+
+    #[error("`{0}` was locked against `{1}`, but is pinned to a different index in `tool.uv.sources`, and `tool.uv.enforce-index-provenance` is enabled. To re-lock against the pinned index, run `uv lock`.")]
+    PinnedIndexProvenance(PackageName, String),
+
     #[error("The requested interpreter resolved to Python {0}, which is incompatible with the project's Python requirement: `{1}`")]
     RequestedPythonProjectIncompatibility(Version, RequiresPython),
 
@@ -128,6 +144,16 @@ pub(crate) enum ProjectError {
     #[error("Group `{0}` is not defined in the project's `dependency-group` table")]
     MissingGroupProject(GroupName),
 
+    #[error("Failed to parse entry in extra `{0}`: `{1}`")]
+    ExtraParse(
+        ExtraName,
+        String,
+        #[source] Box<uv_pep508::Pep508Error<VerbatimParsedUrl>>,
+    ),
+
+    #[error("`{0}` declares its {1} and {2} as conflicting (in `tool.uv.conflicts`), so they can't be installed together")]
+    ConflictingItems(PackageName, ConflictItem, ConflictItem),
+
     #[error("Group `{0}` is not defined in any project's `dependency-group` table")]
     MissingGroupWorkspace(GroupName),
 
@@ -149,6 +175,12 @@ pub(crate) enum ProjectError {
     #[error("Failed to parse `uv.lock`")]
     UvLockParse(#[source] toml::de::Error),
 
+    #[error("Failed to parse preference file at: `{0}`")]
+    PreferenceFileParse(String, #[source] toml::de::Error),
+
+    #[error("Preference file not found at: `{0}`")]
+    MissingPreferenceFile(String),
+
     #[error("Failed to parse `pyproject.toml`")]
     PyprojectTomlParse(#[source] toml::de::Error),
 
@@ -206,16 +238,35 @@ pub(crate) enum ProjectError {
 
 /// Compute the `Requires-Python` bound for the [`Workspace`].
 ///
-/// For a [`Workspace`] with multiple packages, the `Requires-Python` bound is the union of the
-/// `Requires-Python` bounds of all the packages.
+/// For a [`Workspace`] with multiple packages, the `Requires-Python` bound is the intersection of
+/// the `Requires-Python` bounds of all the packages. If the workspace members use separate
+/// environments, the bound is instead the union of the `Requires-Python` bounds, as each member
+/// only needs to be installable on its own supported Python versions.
 pub(crate) fn find_requires_python(workspace: &Workspace) -> Option<RequiresPython> {
-    RequiresPython::intersection(workspace.packages().values().filter_map(|member| {
+    let specifiers = workspace.packages().values().filter_map(|member| {
+        member
+            .pyproject_toml()
+            .project
+            .as_ref()
+            .and_then(|project| project.requires_python.as_ref())
+    });
+    if workspace.member_environments() {
+        RequiresPython::union(specifiers)
+    } else {
+        RequiresPython::intersection(specifiers)
+    }
+}
+
+/// Compute the `Requires-Python` bound for a single [`WorkspaceMember`].
+fn find_member_requires_python(member: &WorkspaceMember) -> Option<RequiresPython> {
+    RequiresPython::intersection(
         member
             .pyproject_toml()
             .project
             .as_ref()
             .and_then(|project| project.requires_python.as_ref())
-    }))
+            .into_iter(),
+    )
 }
 
 /// Returns an error if the [`Interpreter`] does not satisfy the [`Workspace`] `requires-python`.
@@ -276,26 +327,54 @@ pub(crate) fn validate_requires_python(
         }
     }
 
+    Err(project_incompatibility(
+        interpreter,
+        requires_python,
+        source,
+    ))
+}
+
+/// Returns an error if the [`Interpreter`] does not satisfy the `requires-python` of a single
+/// [`WorkspaceMember`] with its own environment.
+#[allow(clippy::result_large_err)]
+fn validate_member_requires_python(
+    interpreter: &Interpreter,
+    requires_python: &RequiresPython,
+    source: &PythonRequestSource,
+) -> Result<(), ProjectError> {
+    if requires_python.contains(interpreter.python_version()) {
+        return Ok(());
+    }
+    Err(project_incompatibility(
+        interpreter,
+        requires_python,
+        source,
+    ))
+}
+
+/// Construct the error for an [`Interpreter`] that does not satisfy the project's
+/// `requires-python`.
+fn project_incompatibility(
+    interpreter: &Interpreter,
+    requires_python: &RequiresPython,
+    source: &PythonRequestSource,
+) -> ProjectError {
     match source {
-        PythonRequestSource::UserRequest => {
-            Err(ProjectError::RequestedPythonProjectIncompatibility(
-                interpreter.python_version().clone(),
-                requires_python.clone(),
-            ))
-        }
+        PythonRequestSource::UserRequest => ProjectError::RequestedPythonProjectIncompatibility(
+            interpreter.python_version().clone(),
+            requires_python.clone(),
+        ),
         PythonRequestSource::DotPythonVersion(file) => {
-            Err(ProjectError::DotPythonVersionProjectIncompatibility(
+            ProjectError::DotPythonVersionProjectIncompatibility(
                 file.to_string(),
                 interpreter.python_version().clone(),
                 requires_python.clone(),
-            ))
-        }
-        PythonRequestSource::RequiresPython => {
-            Err(ProjectError::RequiresPythonProjectIncompatibility(
-                interpreter.python_version().clone(),
-                requires_python.clone(),
-            ))
+            )
         }
+        PythonRequestSource::RequiresPython => ProjectError::RequiresPythonProjectIncompatibility(
+            interpreter.python_version().clone(),
+            requires_python.clone(),
+        ),
     }
 }
 
@@ -340,15 +419,32 @@ impl WorkspacePython {
         workspace: &Workspace,
     ) -> Result<Self, ProjectError> {
         let requires_python = find_requires_python(workspace);
+        Self::from_parts(python_request, workspace.install_path(), requires_python).await
+    }
+
+    /// Determine the [`WorkspacePython`] for a [`WorkspaceMember`] with its own environment.
+    ///
+    /// Unlike [`WorkspacePython::from_request`], only the member's own `requires-python` is
+    /// considered, and any `.python-version` file is discovered from the member directory.
+    pub(crate) async fn from_member_request(
+        python_request: Option<PythonRequest>,
+        member: &WorkspaceMember,
+    ) -> Result<Self, ProjectError> {
+        let requires_python = find_member_requires_python(member);
+        Self::from_parts(python_request, member.root(), requires_python).await
+    }
 
+    async fn from_parts(
+        python_request: Option<PythonRequest>,
+        directory: &Path,
+        requires_python: Option<RequiresPython>,
+    ) -> Result<Self, ProjectError> {
         let (source, python_request) = if let Some(request) = python_request {
             // (1) Explicit request from user
             let source = PythonRequestSource::UserRequest;
             let request = Some(request);
             (source, request)
-        } else if let Some(file) =
-            PythonVersionFile::discover(workspace.install_path(), false, false).await?
-        {
+        } else if let Some(file) = PythonVersionFile::discover(directory, false, false).await? {
             // (2) Request from `.python-version`
             let source = PythonRequestSource::DotPythonVersion(file.file_name().to_string());
             let request = file.into_version();
@@ -389,14 +485,76 @@ impl ProjectInterpreter {
         printer: Printer,
     ) -> Result<Self, ProjectError> {
         // Resolve the Python request and requirement for the workspace.
+        let workspace_python = WorkspacePython::from_request(python_request, workspace).await?;
+
+        Self::discover_in(
+            workspace.venv(),
+            workspace_python,
+            |interpreter, requires_python, source| {
+                validate_requires_python(interpreter, workspace, requires_python, source)
+            },
+            python_preference,
+            python_downloads,
+            connectivity,
+            native_tls,
+            cache,
+            printer,
+        )
+        .await
+    }
+
+    /// Discover the interpreter to use for a [`WorkspaceMember`] with its own environment.
+    pub(crate) async fn discover_member(
+        workspace: &Workspace,
+        member: &WorkspaceMember,
+        python_request: Option<PythonRequest>,
+        python_preference: PythonPreference,
+        python_downloads: PythonDownloads,
+        connectivity: Connectivity,
+        native_tls: bool,
+        cache: &Cache,
+        printer: Printer,
+    ) -> Result<Self, ProjectError> {
+        // Resolve the Python request and requirement for the member.
+        let workspace_python = WorkspacePython::from_member_request(python_request, member).await?;
+
+        Self::discover_in(
+            workspace.member_venv(member),
+            workspace_python,
+            validate_member_requires_python,
+            python_preference,
+            python_downloads,
+            connectivity,
+            native_tls,
+            cache,
+            printer,
+        )
+        .await
+    }
+
+    /// Discover the interpreter to use for the virtual environment at the given path.
+    async fn discover_in(
+        venv: PathBuf,
+        workspace_python: WorkspacePython,
+        validate: impl FnOnce(
+            &Interpreter,
+            &RequiresPython,
+            &PythonRequestSource,
+        ) -> Result<(), ProjectError>,
+        python_preference: PythonPreference,
+        python_downloads: PythonDownloads,
+        connectivity: Connectivity,
+        native_tls: bool,
+        cache: &Cache,
+        printer: Printer,
+    ) -> Result<Self, ProjectError> {
         let WorkspacePython {
             source,
             python_request,
             requires_python,
-        } = WorkspacePython::from_request(python_request, workspace).await?;
+        } = workspace_python;
 
         // Read from the virtual environment first.
-        let venv = workspace.venv();
         match PythonEnvironment::from_root(&venv, cache) {
             Ok(venv) => {
                 if python_request.as_ref().map_or(true, |request| {
@@ -497,7 +655,7 @@ impl ProjectInterpreter {
         }
 
         if let Some(requires_python) = requires_python.as_ref() {
-            validate_requires_python(&interpreter, workspace, requires_python, &source)?;
+            validate(&interpreter, requires_python, &source)?;
         }
 
         Ok(Self::Interpreter(interpreter))
@@ -540,51 +698,6 @@ pub(crate) async fn get_or_init_environment(
 
         // Otherwise, create a virtual environment with the discovered interpreter.
         ProjectInterpreter::Interpreter(interpreter) => {
-            let venv = workspace.venv();
-
-            // Avoid removing things that are not virtual environments
-            let should_remove = match (venv.try_exists(), venv.join("pyvenv.cfg").try_exists()) {
-                // It's a virtual environment we can remove it
-                (_, Ok(true)) => true,
-                // It doesn't exist at all, we should use it without deleting it to avoid TOCTOU bugs
-                (Ok(false), Ok(false)) => false,
-                // If it's not a virtual environment, bail
-                (Ok(true), Ok(false)) => {
-                    return Err(ProjectError::InvalidProjectEnvironmentDir(
-                        venv,
-                        "it is not a compatible environment but cannot be recreated because it is not a virtual environment".to_string(),
-                    ));
-                }
-                // Similarly, if we can't _tell_ if it exists we should bail
-                (_, Err(err)) | (Err(err), _) => {
-                    return Err(ProjectError::InvalidProjectEnvironmentDir(
-                        venv,
-                        format!("it is not a compatible environment but cannot be recreated because uv cannot determine if it is a virtual environment: {err}"),
-                    ));
-                }
-            };
-
-            // Remove the existing virtual environment if it doesn't meet the requirements.
-            if should_remove {
-                match fs_err::remove_dir_all(&venv) {
-                    Ok(()) => {
-                        writeln!(
-                            printer.stderr(),
-                            "Removed virtual environment at: {}",
-                            venv.user_display().cyan()
-                        )?;
-                    }
-                    Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
-                    Err(e) => return Err(e.into()),
-                }
-            }
-
-            writeln!(
-                printer.stderr(),
-                "Creating virtual environment at: {}",
-                venv.user_display().cyan()
-            )?;
-
             // Determine a prompt for the environment, in order of preference:
             //
             // 1) The name of the project
@@ -604,19 +717,118 @@ pub(crate) async fn get_or_init_environment(
                 .map(uv_virtualenv::Prompt::Static)
                 .unwrap_or(uv_virtualenv::Prompt::None);
 
-            Ok(uv_virtualenv::create_venv(
-                &venv,
-                interpreter,
-                prompt,
-                false,
-                false,
-                false,
-                false,
-            )?)
+            init_environment(workspace.venv(), interpreter, prompt, printer)
+        }
+    }
+}
+
+/// Initialize a virtual environment for a [`WorkspaceMember`] with its own environment.
+///
+/// See `tool.uv.workspace.member-environments`.
+pub(crate) async fn get_or_init_member_environment(
+    workspace: &Workspace,
+    member: &WorkspaceMember,
+    python: Option<PythonRequest>,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    connectivity: Connectivity,
+    native_tls: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<PythonEnvironment, ProjectError> {
+    match ProjectInterpreter::discover_member(
+        workspace,
+        member,
+        python,
+        python_preference,
+        python_downloads,
+        connectivity,
+        native_tls,
+        cache,
+        printer,
+    )
+    .await?
+    {
+        // If we found an existing, compatible environment, use it.
+        ProjectInterpreter::Environment(environment) => Ok(environment),
+
+        // Otherwise, create a virtual environment with the discovered interpreter, using the
+        // name of the member as the prompt.
+        ProjectInterpreter::Interpreter(interpreter) => {
+            let prompt = member
+                .pyproject_toml()
+                .project
+                .as_ref()
+                .map(|p| uv_virtualenv::Prompt::Static(p.name.to_string()))
+                .unwrap_or(uv_virtualenv::Prompt::None);
+
+            init_environment(workspace.member_venv(member), interpreter, prompt, printer)
         }
     }
 }
 
+/// Create a virtual environment at the given path, replacing any existing virtual environment.
+#[allow(clippy::result_large_err)]
+fn init_environment(
+    venv: PathBuf,
+    interpreter: Interpreter,
+    prompt: uv_virtualenv::Prompt,
+    printer: Printer,
+) -> Result<PythonEnvironment, ProjectError> {
+    // Avoid removing things that are not virtual environments
+    let should_remove = match (venv.try_exists(), venv.join("pyvenv.cfg").try_exists()) {
+        // It's a virtual environment we can remove it
+        (_, Ok(true)) => true,
+        // It doesn't exist at all, we should use it without deleting it to avoid TOCTOU bugs
+        (Ok(false), Ok(false)) => false,
+        // If it's not a virtual environment, bail
+        (Ok(true), Ok(false)) => {
+            return Err(ProjectError::InvalidProjectEnvironmentDir(
+                venv,
+                "it is not a compatible environment but cannot be recreated because it is not a virtual environment".to_string(),
+            ));
+        }
+        // Similarly, if we can't _tell_ if it exists we should bail
+        (_, Err(err)) | (Err(err), _) => {
+            return Err(ProjectError::InvalidProjectEnvironmentDir(
+                venv,
+                format!("it is not a compatible environment but cannot be recreated because uv cannot determine if it is a virtual environment: {err}"),
+            ));
+        }
+    };
+
+    // Remove the existing virtual environment if it doesn't meet the requirements.
+    if should_remove {
+        match fs_err::remove_dir_all(&venv) {
+            Ok(()) => {
+                writeln!(
+                    printer.stderr(),
+                    "Removed virtual environment at: {}",
+                    venv.user_display().cyan()
+                )?;
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e.into()),
+        }
+    }
+
+    writeln!(
+        printer.stderr(),
+        "Creating virtual environment at: {}",
+        venv.user_display().cyan()
+    )?;
+
+    Ok(uv_virtualenv::create_venv(
+        &venv,
+        interpreter,
+        prompt,
+        false,
+        false,
+        false,
+        false,
+    )?)
+}
+
 /// Resolve any [`UnresolvedRequirementSpecification`] into a fully-qualified [`Requirement`].
 pub(crate) async fn resolve_names(
     requirements: Vec<UnresolvedRequirementSpecification>,
@@ -653,23 +865,33 @@ pub(crate) async fn resolve_names(
         allow_insecure_host,
         resolution: _,
         prerelease: _,
+        yanked: _,
+        allow_yanked: _,
+        prefer_local_version: _,
+        resolution_budget: _,
         dependency_metadata,
         config_setting,
+        build_containers,
         no_build_isolation,
         no_build_isolation_package,
         exclude_newer,
         link_mode,
         compile_bytecode: _,
+        bytecode_options: _,
         sources,
+        variants,
         upgrade: _,
         reinstall: _,
         build_options,
+        minimal_changes: _,
     } = settings;
 
     // Add all authenticated sources to the cache.
     for index in index_locations.allowed_indexes() {
         if let Some(credentials) = index.credentials() {
             uv_auth::store_credentials(index.raw_url(), credentials);
+        } else if let Some(secrets) = index.secret_credentials() {
+            uv_auth::store_secret_credentials(secrets);
         }
     }
 
@@ -715,10 +937,12 @@ pub(crate) async fn resolve_names(
         dependency_metadata,
         &state.index,
         &state.git,
+        &state.hg,
         &state.capabilities,
         &state.in_flight,
         *index_strategy,
         config_setting,
+        build_containers,
         build_isolation,
         *link_mode,
         build_options,
@@ -727,14 +951,15 @@ pub(crate) async fn resolve_names(
         LowerBound::Allow,
         *sources,
         concurrency,
-    );
+    )
+    .with_variants(variants);
 
     // Resolve the unnamed requirements.
     requirements.extend(
         NamedRequirementsResolver::new(
             &hasher,
             &state.index,
-            DistributionDatabase::new(&client, &build_dispatch, concurrency.downloads),
+            DistributionDatabase::new(&client, &build_dispatch, concurrency),
         )
         .with_reporter(ResolverReporter::from(printer))
         .resolve(unnamed.into_iter())
@@ -790,8 +1015,13 @@ pub(crate) async fn resolve_environment<'a>(
         allow_insecure_host,
         resolution,
         prerelease,
+        yanked,
+        allow_yanked,
+        prefer_local_version,
+        resolution_budget,
         dependency_metadata,
         config_setting,
+        build_containers,
         no_build_isolation,
         no_build_isolation_package,
         exclude_newer,
@@ -799,6 +1029,10 @@ pub(crate) async fn resolve_environment<'a>(
         upgrade: _,
         build_options,
         sources,
+        variants,
+        preference_files: _,
+        minimal_changes: _,
+        upgrade_git: _,
     } = settings;
 
     // Respect all requirements from the provided sources.
@@ -820,6 +1054,8 @@ pub(crate) async fn resolve_environment<'a>(
     for index in index_locations.allowed_indexes() {
         if let Some(credentials) = index.credentials() {
             uv_auth::store_credentials(index.raw_url(), credentials);
+        } else if let Some(secrets) = index.secret_credentials() {
+            uv_auth::store_secret_credentials(secrets);
         }
     }
 
@@ -850,8 +1086,10 @@ pub(crate) async fn resolve_environment<'a>(
     let options = OptionsBuilder::new()
         .resolution_mode(resolution)
         .prerelease_mode(prerelease)
+        .yanked_mode(yanked)
         .exclude_newer(exclude_newer)
         .index_strategy(index_strategy)
+        .budget(resolution_budget)
         .build();
 
     // TODO(charlie): These are all default values. We should consider whether we want to make them
@@ -868,7 +1106,11 @@ pub(crate) async fn resolve_environment<'a>(
     let upgrade = Upgrade::default();
 
     // If an existing lockfile exists, build up a set of preferences.
-    let LockedRequirements { preferences, git } = spec
+    let LockedRequirements {
+        preferences,
+        git,
+        hg,
+    } = spec
         .lock
         .map(|lock| read_lock_requirements(lock, &upgrade))
         .unwrap_or_default();
@@ -879,9 +1121,16 @@ pub(crate) async fn resolve_environment<'a>(
         state.git.insert(reference, sha);
     }
 
+    // Populate the Mercurial resolver.
+    for ResolvedHgRepositoryReference { reference, node } in hg {
+        debug!("Inserting Mercurial reference into resolver: `{reference:?}` at `{node}`");
+        state.hg.insert(reference, node);
+    }
+
     // Resolve the flat indexes from `--find-links`.
     let flat_index = {
-        let client = FlatIndexClient::new(&client, cache);
+        let client = FlatIndexClient::new(&client, cache)
+            .with_recursive(index_locations.find_links_recursive());
         let entries = client
             .fetch(index_locations.flat_indexes().map(Index::url))
             .await?;
@@ -899,10 +1148,12 @@ pub(crate) async fn resolve_environment<'a>(
         dependency_metadata,
         &state.index,
         &state.git,
+        &state.hg,
         &state.capabilities,
         &state.in_flight,
         index_strategy,
         config_setting,
+        build_containers,
         build_isolation,
         link_mode,
         build_options,
@@ -911,7 +1162,8 @@ pub(crate) async fn resolve_environment<'a>(
         LowerBound::Allow,
         sources,
         concurrency,
-    );
+    )
+    .with_variants(variants);
 
     // Resolve the requirements.
     Ok(pip::operations::resolve(
@@ -928,6 +1180,8 @@ pub(crate) async fn resolve_environment<'a>(
         &hasher,
         &reinstall,
         &upgrade,
+        allow_yanked,
+        prefer_local_version,
         Some(tags),
         ResolverMarkers::specific_environment(markers),
         python_requirement,
@@ -944,12 +1198,16 @@ pub(crate) async fn resolve_environment<'a>(
 }
 
 /// Sync a [`PythonEnvironment`] with a set of resolved requirements.
+///
+/// Distributions are verified against the given [`HashStrategy`], if any hashes are known.
 pub(crate) async fn sync_environment(
     venv: PythonEnvironment,
     resolution: &Resolution,
+    hasher: &HashStrategy,
     settings: InstallerSettingsRef<'_>,
     state: &SharedState,
     logger: Box<dyn InstallLogger>,
+    launcher_resources: Option<&LauncherResources>,
     connectivity: Connectivity,
     concurrency: Concurrency,
     native_tls: bool,
@@ -963,11 +1221,13 @@ pub(crate) async fn sync_environment(
         allow_insecure_host,
         dependency_metadata,
         config_setting,
+        build_containers,
         no_build_isolation,
         no_build_isolation_package,
         exclude_newer,
         link_mode,
         compile_bytecode,
+        bytecode_options,
         reinstall,
         build_options,
         sources,
@@ -983,6 +1243,8 @@ pub(crate) async fn sync_environment(
     for index in index_locations.allowed_indexes() {
         if let Some(credentials) = index.credentials() {
             uv_auth::store_credentials(index.raw_url(), credentials);
+        } else if let Some(secrets) = index.secret_credentials() {
+            uv_auth::store_secret_credentials(secrets);
         }
     }
 
@@ -1011,16 +1273,16 @@ pub(crate) async fn sync_environment(
     // optional on the downstream APIs.
     let build_constraints = Constraints::default();
     let build_hasher = HashStrategy::default();
-    let dry_run = false;
-    let hasher = HashStrategy::default();
+    let dry_run = DryRun::Disabled;
 
     // Resolve the flat indexes from `--find-links`.
     let flat_index = {
-        let client = FlatIndexClient::new(&client, cache);
+        let client = FlatIndexClient::new(&client, cache)
+            .with_recursive(index_locations.find_links_recursive());
         let entries = client
             .fetch(index_locations.flat_indexes().map(Index::url))
             .await?;
-        FlatIndex::from_entries(entries, Some(tags), &hasher, build_options)
+        FlatIndex::from_entries(entries, Some(tags), hasher, build_options)
     };
 
     // Create a build dispatch.
@@ -1034,10 +1296,12 @@ pub(crate) async fn sync_environment(
         dependency_metadata,
         &state.index,
         &state.git,
+        &state.hg,
         &state.capabilities,
         &state.in_flight,
         index_strategy,
         config_setting,
+        build_containers,
         build_isolation,
         link_mode,
         build_options,
@@ -1057,9 +1321,10 @@ pub(crate) async fn sync_environment(
         build_options,
         link_mode,
         compile_bytecode,
+        bytecode_options,
         index_locations,
         config_setting,
-        &hasher,
+        hasher,
         tags,
         &client,
         &state.in_flight,
@@ -1067,6 +1332,7 @@ pub(crate) async fn sync_environment(
         &build_dispatch,
         cache,
         &venv,
+        launcher_resources,
         logger,
         dry_run,
         printer,
@@ -1086,13 +1352,9 @@ pub(crate) struct EnvironmentUpdate {
     pub(crate) environment: PythonEnvironment,
     /// The [`Changelog`] of changes made to the environment.
     pub(crate) changelog: Changelog,
-}
-
-impl EnvironmentUpdate {
-    /// Convert the [`EnvironmentUpdate`] into a [`PythonEnvironment`].
-    pub(crate) fn into_environment(self) -> PythonEnvironment {
-        self.environment
-    }
+    /// The [`ResolutionGraph`] used to update the environment, if the existing environment did not
+    /// already satisfy the requirements.
+    pub(crate) resolution: Option<ResolutionGraph>,
 }
 
 /// Update a [`PythonEnvironment`] to satisfy a set of [`RequirementsSource`]s.
@@ -1103,6 +1365,7 @@ pub(crate) async fn update_environment(
     state: &SharedState,
     resolve: Box<dyn ResolveLogger>,
     install: Box<dyn InstallLogger>,
+    launcher_resources: Option<&LauncherResources>,
     connectivity: Connectivity,
     concurrency: Concurrency,
     native_tls: bool,
@@ -1118,17 +1381,25 @@ pub(crate) async fn update_environment(
         allow_insecure_host,
         resolution,
         prerelease,
+        yanked,
+        allow_yanked,
+        prefer_local_version,
+        resolution_budget,
         dependency_metadata,
         config_setting,
+        build_containers,
         no_build_isolation,
         no_build_isolation_package,
         exclude_newer,
         link_mode,
         compile_bytecode,
+        bytecode_options,
         sources,
+        variants,
         upgrade,
         reinstall,
         build_options,
+        minimal_changes: _,
     } = settings;
 
     // Respect all requirements from the provided sources.
@@ -1164,6 +1435,7 @@ pub(crate) async fn update_environment(
                 return Ok(EnvironmentUpdate {
                     environment: venv,
                     changelog: Changelog::default(),
+                    resolution: None,
                 });
             }
             SatisfiesResult::Unsatisfied(requirement) => {
@@ -1176,6 +1448,8 @@ pub(crate) async fn update_environment(
     for index in index_locations.allowed_indexes() {
         if let Some(credentials) = index.credentials() {
             uv_auth::store_credentials(index.raw_url(), credentials);
+        } else if let Some(secrets) = index.secret_credentials() {
+            uv_auth::store_secret_credentials(secrets);
         }
     }
 
@@ -1203,8 +1477,10 @@ pub(crate) async fn update_environment(
     let options = OptionsBuilder::new()
         .resolution_mode(*resolution)
         .prerelease_mode(*prerelease)
+        .yanked_mode(*yanked)
         .exclude_newer(*exclude_newer)
         .index_strategy(*index_strategy)
+        .budget(*resolution_budget)
         .build();
 
     // TODO(charlie): These are all default values. We should consider whether we want to make them
@@ -1212,7 +1488,7 @@ pub(crate) async fn update_environment(
     let build_constraints = Constraints::default();
     let build_hasher = HashStrategy::default();
     let dev = Vec::default();
-    let dry_run = false;
+    let dry_run = DryRun::Disabled;
     let extras = ExtrasSpecification::default();
     let hasher = HashStrategy::default();
     let preferences = Vec::default();
@@ -1223,7 +1499,8 @@ pub(crate) async fn update_environment(
 
     // Resolve the flat indexes from `--find-links`.
     let flat_index = {
-        let client = FlatIndexClient::new(&client, cache);
+        let client = FlatIndexClient::new(&client, cache)
+            .with_recursive(index_locations.find_links_recursive());
         let entries = client
             .fetch(index_locations.flat_indexes().map(Index::url))
             .await?;
@@ -1241,10 +1518,12 @@ pub(crate) async fn update_environment(
         dependency_metadata,
         &state.index,
         &state.git,
+        &state.hg,
         &state.capabilities,
         &state.in_flight,
         *index_strategy,
         config_setting,
+        build_containers,
         build_isolation,
         *link_mode,
         build_options,
@@ -1253,10 +1532,11 @@ pub(crate) async fn update_environment(
         LowerBound::Allow,
         *sources,
         concurrency,
-    );
+    )
+    .with_variants(variants);
 
     // Resolve the requirements.
-    let resolution = match pip::operations::resolve(
+    let graph = match pip::operations::resolve(
         requirements,
         constraints,
         overrides,
@@ -1270,6 +1550,8 @@ pub(crate) async fn update_environment(
         &hasher,
         reinstall,
         upgrade,
+        allow_yanked,
+        prefer_local_version.as_deref(),
         Some(tags),
         ResolverMarkers::specific_environment(markers.clone()),
         python_requirement,
@@ -1284,9 +1566,10 @@ pub(crate) async fn update_environment(
     )
     .await
     {
-        Ok(resolution) => Resolution::from(resolution),
+        Ok(graph) => graph,
         Err(err) => return Err(err.into()),
     };
+    let resolution = Resolution::from(&graph);
 
     // Sync the environment.
     let changelog = pip::operations::install(
@@ -1297,6 +1580,7 @@ pub(crate) async fn update_environment(
         build_options,
         *link_mode,
         *compile_bytecode,
+        bytecode_options,
         index_locations,
         config_setting,
         &hasher,
@@ -1307,6 +1591,7 @@ pub(crate) async fn update_environment(
         &build_dispatch,
         cache,
         &venv,
+        launcher_resources,
         install,
         dry_run,
         printer,
@@ -1319,6 +1604,7 @@ pub(crate) async fn update_environment(
     Ok(EnvironmentUpdate {
         environment: venv,
         changelog,
+        resolution: Some(graph),
     })
 }
 
@@ -0,0 +1,548 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use std::path::Path;
+use std::str::FromStr;
+
+use anyhow::Result;
+use futures::StreamExt;
+use owo_colors::OwoColorize;
+use serde::Serialize;
+
+use uv_auth::{store_credentials, store_secret_credentials};
+use uv_cache::Cache;
+use uv_cli::OutdatedFormat;
+use uv_client::{Connectivity, OwnedArchive, RegistryClient, RegistryClientBuilder};
+use uv_configuration::{Concurrency, LowerBound};
+use uv_distribution_types::{File, IndexCapabilities};
+use uv_normalize::{ExtraName, GroupName, PackageName, DEV_DEPENDENCIES};
+use uv_pep440::{Version, VersionSpecifier, VersionSpecifiers};
+use uv_pep508::VersionOrUrl;
+use uv_pypi_types::VerbatimParsedUrl;
+use uv_python::{PythonDownloads, PythonPreference, PythonRequest};
+use uv_resolver::{ExcludeNewer, RequiresPython};
+use uv_warnings::warn_user;
+use uv_workspace::pyproject::DependencyGroupSpecifier;
+use uv_workspace::{DiscoveryOptions, Workspace};
+
+use crate::commands::pip::loggers::DefaultResolveLogger;
+use crate::commands::project::lock::{do_safe_lock, LockMode};
+use crate::commands::project::{ProjectError, ProjectInterpreter};
+use crate::commands::{diagnostics, pip, ExitStatus, SharedState};
+use crate::printer::Printer;
+use crate::settings::ResolverSettings;
+
+/// List the direct dependencies of the project that have newer releases.
+#[allow(clippy::fn_params_excessive_bools)]
+pub(crate) async fn outdated(
+    project_dir: &Path,
+    format: OutdatedFormat,
+    locked: bool,
+    frozen: bool,
+    python: Option<String>,
+    settings: ResolverSettings,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    native_tls: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    // Find the workspace.
+    let workspace = Workspace::discover(project_dir, &DiscoveryOptions::default()).await?;
+
+    // Find an interpreter for the project, unless the lockfile is frozen.
+    let interpreter = if frozen {
+        None
+    } else {
+        Some(
+            ProjectInterpreter::discover(
+                &workspace,
+                python.as_deref().map(PythonRequest::parse),
+                python_preference,
+                python_downloads,
+                connectivity,
+                native_tls,
+                cache,
+                printer,
+            )
+            .await?
+            .into_interpreter(),
+        )
+    };
+
+    // Determine the lock mode.
+    let mode = match interpreter.as_ref() {
+        Some(interpreter) if locked => LockMode::Locked(interpreter),
+        Some(interpreter) => LockMode::Write(interpreter),
+        None => LockMode::Frozen,
+    };
+
+    // Initialize any shared state.
+    let state = SharedState::default();
+
+    // Lock the project.
+    let lock = match do_safe_lock(
+        mode,
+        &workspace,
+        settings.as_ref(),
+        LowerBound::Warn,
+        &state,
+        Box::new(DefaultResolveLogger),
+        connectivity,
+        concurrency,
+        native_tls,
+        cache,
+        printer,
+    )
+    .await
+    {
+        Ok(result) => result.into_lock(),
+        Err(ProjectError::Operation(pip::operations::Error::Resolve(
+            uv_resolver::ResolveError::NoSolution(err),
+        ))) => {
+            diagnostics::no_solution(&err);
+            return Ok(ExitStatus::Failure);
+        }
+        Err(ProjectError::Operation(pip::operations::Error::Resolve(
+            uv_resolver::ResolveError::FetchAndBuild(dist, err),
+        ))) => {
+            diagnostics::fetch_and_build(dist, err);
+            return Ok(ExitStatus::Failure);
+        }
+        Err(ProjectError::Operation(pip::operations::Error::Resolve(
+            uv_resolver::ResolveError::Build(dist, err),
+        ))) => {
+            diagnostics::build(dist, err);
+            return Ok(ExitStatus::Failure);
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    let dependencies = direct_dependencies(&workspace)?;
+
+    // Add all authenticated sources to the cache.
+    for index in settings.index_locations.allowed_indexes() {
+        if let Some(credentials) = index.credentials() {
+            store_credentials(index.raw_url(), credentials);
+        } else if let Some(secrets) = index.secret_credentials() {
+            store_secret_credentials(secrets);
+        }
+    }
+
+    let client = RegistryClientBuilder::new(cache.clone())
+        .connectivity(connectivity)
+        .native_tls(native_tls)
+        .index_strategy(settings.index_strategy)
+        .keyring(settings.keyring_provider)
+        .allow_insecure_host(settings.allow_insecure_host.clone())
+        .index_urls(settings.index_locations.index_urls())
+        .build();
+
+    // Fetch the available versions of each dependency.
+    let capabilities = IndexCapabilities::default();
+    let exclude_newer = settings.exclude_newer;
+    let names = dependencies
+        .iter()
+        .map(|dependency| &dependency.requirement.name)
+        .collect::<BTreeSet<_>>();
+    let available = futures::stream::iter(names)
+        .map(|name| {
+            let client = &client;
+            let capabilities = &capabilities;
+            let requires_python = lock.requires_python();
+            async move {
+                let versions =
+                    available_versions(name, client, capabilities, requires_python, exclude_newer)
+                        .await;
+                (name, versions)
+            }
+        })
+        .buffer_unordered(concurrency.downloads)
+        .collect::<BTreeMap<_, _>>()
+        .await;
+
+    // Compare the locked version of each dependency to the newest compatible and newest
+    // available versions.
+    let mut outdated = Vec::new();
+    for dependency in &dependencies {
+        let name = &dependency.requirement.name;
+        let Some(locked) = lock
+            .packages()
+            .iter()
+            .filter(|package| package.name() == name)
+            .map(uv_resolver::Package::version)
+            .max()
+        else {
+            continue;
+        };
+        let Some(versions) = available.get(name) else {
+            continue;
+        };
+
+        // Only consider pre-releases if a pre-release is already locked.
+        let candidates = versions
+            .iter()
+            .filter(|version| locked.any_prerelease() || !version.any_prerelease());
+        let specifier = match &dependency.requirement.version_or_url {
+            Some(VersionOrUrl::VersionSpecifier(specifier)) => specifier.clone(),
+            _ => VersionSpecifiers::empty(),
+        };
+        let Some(latest) = candidates.clone().max() else {
+            continue;
+        };
+        if latest <= locked {
+            continue;
+        }
+        let compatible = candidates
+            .filter(|version| specifier.contains(version))
+            .max()
+            .filter(|compatible| *compatible > locked)
+            .unwrap_or(locked);
+
+        outdated.push(Outdated {
+            name: name.clone(),
+            member: dependency.member.clone(),
+            section: dependency.kind.to_string(),
+            specifier: specifier.to_string(),
+            locked: locked.clone(),
+            compatible: compatible.clone(),
+            latest: latest.clone(),
+            dependency,
+        });
+    }
+
+    match format {
+        OutdatedFormat::Text => {
+            if outdated.is_empty() {
+                writeln!(
+                    printer.stderr(),
+                    "{}",
+                    "All direct dependencies are up-to-date".dimmed()
+                )?;
+                return Ok(ExitStatus::Success);
+            }
+            write_table(&outdated, workspace.packages().len() > 1, printer)?;
+            write_commands(&outdated, workspace.packages().len() > 1, printer)?;
+        }
+        OutdatedFormat::Json => {
+            writeln!(
+                printer.stdout(),
+                "{}",
+                serde_json::to_string_pretty(&outdated)?
+            )?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// A direct dependency of a workspace member.
+#[derive(Debug)]
+struct Dependency<'a> {
+    /// The workspace member that declares the dependency.
+    member: &'a PackageName,
+    kind: DependencyKind,
+    requirement: uv_pep508::Requirement<VerbatimParsedUrl>,
+}
+
+/// The section of the `pyproject.toml` in which a dependency is declared.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum DependencyKind {
+    /// A dependency in `project.dependencies`.
+    Production,
+    /// A dependency in `project.optional-dependencies`.
+    Optional(ExtraName),
+    /// A dependency in a `dependency-groups` table (or the legacy `tool.uv.dev-dependencies`).
+    Group(GroupName),
+}
+
+impl DependencyKind {
+    /// The arguments to pass to `uv add` to add a dependency to this section.
+    fn add_args(&self) -> Vec<String> {
+        match self {
+            Self::Production => vec![],
+            Self::Optional(extra) => vec!["--optional".to_string(), extra.to_string()],
+            Self::Group(group) if *group == *DEV_DEPENDENCIES => vec!["--dev".to_string()],
+            Self::Group(group) => vec!["--group".to_string(), group.to_string()],
+        }
+    }
+}
+
+impl std::fmt::Display for DependencyKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Production => write!(f, "dependencies"),
+            Self::Optional(extra) => write!(f, "optional-dependencies.{extra}"),
+            Self::Group(group) => write!(f, "dependency-groups.{group}"),
+        }
+    }
+}
+
+/// A direct dependency with a newer release.
+#[derive(Debug, Serialize)]
+struct Outdated<'a> {
+    name: PackageName,
+    member: PackageName,
+    /// The section of the `pyproject.toml` in which the dependency is declared.
+    section: String,
+    /// The version specifier of the dependency, as declared in the `pyproject.toml`.
+    specifier: String,
+    locked: Version,
+    /// The newest version allowed by the current specifier.
+    compatible: Version,
+    latest: Version,
+    #[serde(skip)]
+    dependency: &'a Dependency<'a>,
+}
+
+/// Collect the direct dependencies of every workspace member that are resolved from a registry.
+///
+/// Dependencies on URLs, on other workspace members, and with `tool.uv.sources` entries are
+/// omitted, as their versions aren't determined by the index.
+fn direct_dependencies(workspace: &Workspace) -> Result<Vec<Dependency<'_>>> {
+    let mut dependencies = Vec::new();
+    for (member_name, member) in workspace.packages() {
+        let pyproject_toml = member.pyproject_toml();
+        let tool_uv = pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref());
+        let sources = tool_uv.and_then(|uv| uv.sources.as_ref());
+
+        let mut requirements = Vec::new();
+        if let Some(project) = pyproject_toml.project.as_ref() {
+            for requirement in project.dependencies.iter().flatten() {
+                requirements.push((DependencyKind::Production, requirement.as_str()));
+            }
+            for (extra, extra_requirements) in project.optional_dependencies.iter().flatten() {
+                for requirement in extra_requirements {
+                    requirements.push((
+                        DependencyKind::Optional(extra.clone()),
+                        requirement.as_str(),
+                    ));
+                }
+            }
+        }
+        for (group, specifiers) in pyproject_toml.dependency_groups.iter().flatten() {
+            for specifier in specifiers {
+                if let DependencyGroupSpecifier::Requirement(requirement) = specifier {
+                    requirements.push((DependencyKind::Group(group.clone()), requirement.as_str()));
+                }
+            }
+        }
+
+        let mut parsed = requirements
+            .into_iter()
+            .map(|(kind, requirement)| {
+                Ok((
+                    kind,
+                    uv_pep508::Requirement::<VerbatimParsedUrl>::from_str(requirement)?,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        for requirement in tool_uv
+            .and_then(|uv| uv.dev_dependencies.as_ref())
+            .into_iter()
+            .flatten()
+        {
+            parsed.push((
+                DependencyKind::Group(DEV_DEPENDENCIES.clone()),
+                requirement.clone(),
+            ));
+        }
+
+        for (kind, requirement) in parsed {
+            if matches!(requirement.version_or_url, Some(VersionOrUrl::Url(_)))
+                || workspace.packages().contains_key(&requirement.name)
+                || sources.is_some_and(|sources| sources.inner().contains_key(&requirement.name))
+            {
+                continue;
+            }
+            dependencies.push(Dependency {
+                member: member_name,
+                kind,
+                requirement,
+            });
+        }
+    }
+    Ok(dependencies)
+}
+
+/// Fetch the versions of a package that are available for the project, in ascending order.
+///
+/// A version is available if any of its distributions is compatible with the project's
+/// `requires-python`, hasn't been yanked, and (if `--exclude-newer` is set) was uploaded before
+/// the cutoff. Versions are collected from every index queried under the index strategy (e.g.,
+/// from all indexes with `unsafe-best-match`).
+pub(crate) async fn available_versions(
+    name: &PackageName,
+    client: &RegistryClient,
+    capabilities: &IndexCapabilities,
+    requires_python: &RequiresPython,
+    exclude_newer: Option<ExcludeNewer>,
+) -> Vec<Version> {
+    let response = match client.simple(name, None, capabilities).await {
+        Ok(response) => response,
+        Err(err) => {
+            warn_user!("Failed to fetch the available versions of `{name}`: {err}");
+            return Vec::new();
+        }
+    };
+    let is_available = |file: &File| {
+        !file
+            .yanked
+            .as_ref()
+            .is_some_and(|yanked| yanked.is_yanked())
+            && file
+                .requires_python
+                .as_ref()
+                .map_or(true, |specifier| requires_python.is_contained_by(specifier))
+            && exclude_newer.map_or(true, |exclude_newer| {
+                file.upload_time_utc_ms
+                    .is_some_and(|upload_time| upload_time < exclude_newer.timestamp_millis())
+            })
+    };
+
+    let mut versions = response
+        .iter()
+        .flat_map(|(_, metadata)| OwnedArchive::deserialize(metadata))
+        .filter_map(|metadatum| {
+            metadatum
+                .files
+                .all()
+                .any(|(_, file)| is_available(&file))
+                .then_some(metadatum.version)
+        })
+        .collect::<Vec<_>>();
+    versions.sort();
+    versions.dedup();
+    versions
+}
+
+/// Write the outdated dependencies as a table.
+fn write_table(outdated: &[Outdated], show_member: bool, printer: Printer) -> Result<()> {
+    let mut header = vec![
+        "Package",
+        "Specifier",
+        "Locked",
+        "Compatible",
+        "Latest",
+        "Section",
+    ];
+    if show_member {
+        header.push("Member");
+    }
+    let rows = outdated
+        .iter()
+        .map(|outdated| {
+            let mut row = vec![
+                outdated.name.to_string(),
+                if outdated.specifier.is_empty() {
+                    "*".to_string()
+                } else {
+                    outdated.specifier.clone()
+                },
+                outdated.locked.to_string(),
+                outdated.compatible.to_string(),
+                outdated.latest.to_string(),
+                outdated.section.clone(),
+            ];
+            if show_member {
+                row.push(outdated.member.to_string());
+            }
+            row
+        })
+        .collect::<Vec<_>>();
+
+    let widths = header
+        .iter()
+        .enumerate()
+        .map(|(column, title)| {
+            rows.iter()
+                .map(|row| row[column].len())
+                .chain(std::iter::once(title.len()))
+                .max()
+                .unwrap_or(0)
+        })
+        .collect::<Vec<_>>();
+    let format_row = |row: &[&str]| {
+        row.iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:width$}"))
+            .collect::<Vec<_>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+
+    writeln!(printer.stdout(), "{}", format_row(&header).bold())?;
+    for row in &rows {
+        let row = row.iter().map(String::as_str).collect::<Vec<_>>();
+        writeln!(printer.stdout(), "{}", format_row(&row))?;
+    }
+    Ok(())
+}
+
+/// Write the commands to perform the available upgrades.
+///
+/// Upgrades that are allowed by the current specifiers only require updating the lockfile;
+/// others require updating the specifier in the `pyproject.toml`, via `uv add`.
+fn write_commands(outdated: &[Outdated], show_member: bool, printer: Printer) -> Result<()> {
+    let within = outdated
+        .iter()
+        .filter(|outdated| outdated.compatible > outdated.locked)
+        .map(|outdated| &outdated.name)
+        .collect::<BTreeSet<_>>();
+    if !within.is_empty() {
+        writeln!(printer.stdout())?;
+        writeln!(
+            printer.stdout(),
+            "To upgrade within the current constraints, run:"
+        )?;
+        let command = std::iter::once("uv lock".to_string())
+            .chain(
+                within
+                    .iter()
+                    .map(|name| format!("--upgrade-package {name}")),
+            )
+            .collect::<Vec<_>>()
+            .join(" ");
+        writeln!(printer.stdout(), "  {}", command.green())?;
+    }
+
+    // Group the remaining upgrades by the section of the `pyproject.toml` they apply to, as
+    // each section requires a separate `uv add` invocation.
+    let mut beyond: BTreeMap<(&PackageName, &DependencyKind), Vec<String>> = BTreeMap::new();
+    for outdated in outdated {
+        if outdated.latest <= outdated.compatible {
+            continue;
+        }
+        let mut requirement = outdated.dependency.requirement.clone();
+        requirement.version_or_url = Some(VersionOrUrl::VersionSpecifier(VersionSpecifiers::from(
+            VersionSpecifier::greater_than_equal_version(outdated.latest.clone()),
+        )));
+        beyond
+            .entry((outdated.dependency.member, &outdated.dependency.kind))
+            .or_default()
+            .push(format!("\"{requirement}\""));
+    }
+    if !beyond.is_empty() {
+        writeln!(printer.stdout())?;
+        writeln!(
+            printer.stdout(),
+            "To upgrade beyond the current constraints (updating `pyproject.toml`), run:"
+        )?;
+        for ((member, kind), requirements) in beyond {
+            let mut command = vec!["uv add".to_string()];
+            if show_member {
+                command.push(format!("--package {member}"));
+            }
+            command.extend(kind.add_args());
+            command.extend(requirements);
+            writeln!(printer.stdout(), "  {}", command.join(" ").green())?;
+        }
+    }
+
+    Ok(())
+}
@@ -21,20 +21,25 @@ use uv_workspace::{DiscoveryOptions, InstallTarget, VirtualProject, Workspace};
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger};
 use crate::commands::pip::operations::Modifications;
 use crate::commands::project::default_dependency_groups;
-use crate::commands::project::lock::LockMode;
+use crate::commands::project::hooks::{run_hooks, Hook};
+use crate::commands::project::lock::{LockMode, LockResult};
 use crate::commands::{project, ExitStatus, SharedState};
 use crate::printer::Printer;
 use crate::settings::ResolverInstallerSettings;
 
 /// Remove one or more packages from the project requirements.
+///
+/// If `move_to` is provided, the packages are instead moved to the given dependency type.
 #[allow(clippy::fn_params_excessive_bools)]
 pub(crate) async fn remove(
     project_dir: &Path,
     locked: bool,
     frozen: bool,
     no_sync: bool,
+    no_hooks: bool,
     packages: Vec<PackageName>,
     dependency_type: DependencyType,
+    move_to: Option<DependencyType>,
     package: Option<PackageName>,
     python: Option<String>,
     settings: ResolverInstallerSettings,
@@ -96,7 +101,47 @@ pub(crate) async fn remove(
         ),
     }?;
 
+    // If moving to the development group, respect the existing `tool.uv.dev-dependencies` table,
+    // unless it would introduce a separate table.
+    let move_to = move_to.map(|move_to| match move_to {
+        DependencyType::Group(group)
+            if group == *DEV_DEPENDENCIES
+                && toml.has_dev_dependencies()
+                && !toml.has_dependency_group(&DEV_DEPENDENCIES) =>
+        {
+            DependencyType::Dev
+        }
+        move_to => move_to,
+    });
+
+    if let Some(move_to) = move_to.as_ref() {
+        if is_same_dependency_type(&dependency_type, move_to) {
+            anyhow::bail!(
+                "Cannot move dependencies to the same location from which they would be removed"
+            );
+        }
+    }
+
     for package in packages {
+        if let Some(move_to) = move_to.as_ref() {
+            let moved = toml.move_dependency(&package, &dependency_type, move_to)?;
+            if moved.is_empty() {
+                warn_if_present(&package, &toml);
+                anyhow::bail!(
+                    "The dependency `{package}` could not be found in {}",
+                    match dependency_type {
+                        DependencyType::Production => "`dependencies`",
+                        DependencyType::Optional(_) => "`optional-dependencies`",
+                        DependencyType::Group(ref group) if *group != *DEV_DEPENDENCIES =>
+                            "`dependency-groups`",
+                        DependencyType::Dev | DependencyType::Group(_) =>
+                            "`dev-dependencies` or `dependency-groups.dev`",
+                    }
+                );
+            }
+            continue;
+        }
+
         match dependency_type {
             DependencyType::Production => {
                 let deps = toml.remove_dependency(&package)?;
@@ -207,7 +252,7 @@ pub(crate) async fn remove(
     let state = SharedState::default();
 
     // Lock and sync the environment, if necessary.
-    let lock = project::lock::do_safe_lock(
+    let result = project::lock::do_safe_lock(
         mode,
         project.workspace(),
         settings.as_ref().into(),
@@ -220,8 +265,14 @@ pub(crate) async fn remove(
         cache,
         printer,
     )
-    .await?
-    .into_lock();
+    .await?;
+
+    // If the lockfile was updated, run the `post-lock` hooks.
+    if !no_hooks && matches!(result, LockResult::Changed(..)) {
+        run_hooks(Hook::PostLock, project.workspace(), Some(&venv), printer).await?;
+    }
+
+    let lock = result.into_lock();
 
     if no_sync {
         return Ok(ExitStatus::Success);
@@ -235,6 +286,10 @@ pub(crate) async fn remove(
     // Determine the default groups to include.
     let defaults = default_dependency_groups(project.pyproject_toml())?;
 
+    if !no_hooks {
+        run_hooks(Hook::PreSync, project.workspace(), Some(&venv), printer).await?;
+    }
+
     project::sync::do_sync(
         InstallTarget::from(&project),
         &venv,
@@ -244,6 +299,7 @@ pub(crate) async fn remove(
         EditableMode::Editable,
         install_options,
         Modifications::Exact,
+        None,
         settings.as_ref().into(),
         Box::new(DefaultInstallLogger),
         connectivity,
@@ -254,6 +310,10 @@ pub(crate) async fn remove(
     )
     .await?;
 
+    if !no_hooks {
+        run_hooks(Hook::PostSync, project.workspace(), Some(&venv), printer).await?;
+    }
+
     Ok(ExitStatus::Success)
 }
 
@@ -266,6 +326,17 @@ enum Target {
     Script(Pep723Script),
 }
 
+/// Returns `true` if the two [`DependencyType`]s refer to the same dependency table, treating
+/// `tool.uv.dev-dependencies` and `dependency-groups.dev` as equivalent.
+fn is_same_dependency_type(left: &DependencyType, right: &DependencyType) -> bool {
+    let is_dev = |dependency_type: &DependencyType| match dependency_type {
+        DependencyType::Dev => true,
+        DependencyType::Group(group) => *group == *DEV_DEPENDENCIES,
+        _ => false,
+    };
+    left == right || (is_dev(left) && is_dev(right))
+}
+
 /// Emit a warning if a dependency with the given name is present as any dependency type.
 ///
 /// This is useful when a dependency of the user-specified type was not found, but it may be present
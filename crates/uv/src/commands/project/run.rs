@@ -1,9 +1,10 @@
 use std::borrow::Cow;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::ffi::OsString;
 use std::fmt::Write;
 use std::io::Read;
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
 use anstream::eprint;
 use anyhow::{anyhow, bail, Context};
@@ -35,6 +36,7 @@ use uv_resolver::Lock;
 use uv_scripts::Pep723Item;
 use uv_static::EnvVars;
 use uv_warnings::warn_user;
+use uv_workspace::pyproject::TaskCommand;
 use uv_workspace::{DiscoveryOptions, InstallTarget, VirtualProject, Workspace, WorkspaceError};
 
 use crate::commands::pip::loggers::{
@@ -43,7 +45,8 @@ use crate::commands::pip::loggers::{
 use crate::commands::pip::operations;
 use crate::commands::pip::operations::Modifications;
 use crate::commands::project::environment::CachedEnvironment;
-use crate::commands::project::lock::LockMode;
+use crate::commands::project::hooks::{run_hooks, Hook};
+use crate::commands::project::lock::{LockMode, LockResult};
 use crate::commands::project::{
     default_dependency_groups, validate_dependency_groups, validate_requires_python,
     EnvironmentSpecification, ProjectError, PythonRequestSource, WorkspacePython,
@@ -53,23 +56,29 @@ use crate::commands::{diagnostics, project, ExitStatus, SharedState};
 use crate::printer::Printer;
 use crate::settings::ResolverInstallerSettings;
 
+/// The name of the path file used to layer an ephemeral environment on top of a base environment.
+pub(crate) const OVERLAY_PTH: &str = "_uv_ephemeral_overlay.pth";
+
 /// Run a command.
 #[allow(clippy::fn_params_excessive_bools)]
 pub(crate) async fn run(
     project_dir: &Path,
     script: Option<Pep723Item>,
-    command: Option<RunCommand>,
-    requirements: Vec<RequirementsSource>,
+    mut command: Option<RunCommand>,
+    mut requirements: Vec<RequirementsSource>,
     show_resolution: bool,
     locked: bool,
     frozen: bool,
     no_sync: bool,
+    no_hooks: bool,
     isolated: bool,
     package: Option<PackageName>,
     no_project: bool,
     no_config: bool,
-    extras: ExtrasSpecification,
-    dev: DevGroupsSpecification,
+    env_file: Vec<PathBuf>,
+    no_env_file: bool,
+    mut extras: ExtrasSpecification,
+    mut dev: DevGroupsSpecification,
     editable: EditableMode,
     python: Option<String>,
     settings: ResolverInstallerSettings,
@@ -103,6 +112,14 @@ pub(crate) async fn run(
         }
     }
 
+    // Read any environment files provided on the command line up front, such that a missing or
+    // invalid file is reported before the environment is synced.
+    let mut env_vars = if no_env_file {
+        BTreeMap::new()
+    } else {
+        read_env_files(&env_file)?
+    };
+
     // Initialize any shared state.
     let state = SharedState::default();
 
@@ -164,7 +181,8 @@ pub(crate) async fn run(
 
         let client_builder = BaseClientBuilder::new()
             .connectivity(connectivity)
-            .native_tls(native_tls);
+            .native_tls(native_tls)
+            .indexes(settings.index_locations.allowed_indexes());
 
         let interpreter = PythonInstallation::find_or_download(
             python_request.as_ref(),
@@ -251,6 +269,7 @@ pub(crate) async fn run(
                         script_indexes,
                         &settings.index_locations,
                         LowerBound::Allow,
+                        &settings.variants,
                     )
                     .map_ok(LoweredRequirement::into_inner)
                 })
@@ -325,6 +344,8 @@ pub(crate) async fn run(
     // The lockfile used for the base environment.
     let mut lock: Option<Lock> = None;
 
+    let mut task_env = BTreeMap::new();
+
     // Discover and sync the base environment.
     let temp_dir;
     let base_interpreter = if let Some(script_interpreter) = script_interpreter {
@@ -457,6 +478,62 @@ pub(crate) async fn run(
             }
         }
 
+        if let Some(project) = project.as_ref() {
+            // Read the environment files declared by the project (via `tool.uv.env-files`), with
+            // those provided on the command line taking precedence.
+            if !no_env_file {
+                let mut project_env_vars = read_env_files(&project.workspace().env_files())?;
+                project_env_vars.append(&mut env_vars);
+                env_vars = project_env_vars;
+            }
+
+            // If the command refers to a task declared in `tool.uv.tasks`, expand it.
+            let mut shell_task = None;
+            if let Some(RunCommand::External(executable, args)) = command.as_ref() {
+                if let Some((name, task)) = executable
+                    .to_str()
+                    .and_then(|name| Some((name, project.workspace().task(name)?)))
+                {
+                    debug!("Running task `{name}`: `{}`", task.command());
+                    command = Some(RunCommand::from_task(name, task.command(), args.clone())?);
+                    extras = extras.with_extras(task.extras().iter().cloned());
+                    dev = dev.with_groups(task.groups().iter().cloned());
+                    task_env = task.env().cloned().unwrap_or_default();
+                    if let TaskCommand::Shell(command) = task.command() {
+                        shell_task = Some(command.clone());
+                    }
+                }
+            }
+
+            // If the command refers to a tool declared in `tool.uv.required-tools`, include the
+            // tool (at the required version) in the ephemeral environment. For tasks that run in a
+            // shell, we can't know which commands will be invoked, so we include any required tool
+            // that appears as a word in the task's command.
+            let executables: Vec<&str> = if let Some(shell_task) = shell_task.as_deref() {
+                shell_task
+                    .split(|c: char| c.is_whitespace() || matches!(c, ';' | '&' | '|' | '(' | ')'))
+                    .filter(|word| !word.is_empty())
+                    .collect()
+            } else if let Some(RunCommand::External(executable, _)) = command.as_ref() {
+                executable.to_str().into_iter().collect()
+            } else {
+                Vec::new()
+            };
+            let mut seen = BTreeSet::new();
+            for name in executables
+                .into_iter()
+                .filter_map(|name| PackageName::from_str(name).ok())
+            {
+                if !seen.insert(name.clone()) {
+                    continue;
+                }
+                if let Some(specifiers) = project.workspace().required_tool(&name) {
+                    debug!("Using required tool: `{name}{specifiers}`");
+                    requirements.push(RequirementsSource::Package(format!("{name}{specifiers}")));
+                }
+            }
+        }
+
         let interpreter = if let Some(project) = project {
             if let Some(project_name) = project.project_name() {
                 debug!(
@@ -477,7 +554,8 @@ pub(crate) async fn run(
                 // base environment for the project.
                 let client_builder = BaseClientBuilder::new()
                     .connectivity(connectivity)
-                    .native_tls(native_tls);
+                    .native_tls(native_tls)
+                    .indexes(settings.index_locations.allowed_indexes());
 
                 // Resolve the Python request and requirement for the workspace.
                 let WorkspacePython {
@@ -604,6 +682,15 @@ pub(crate) async fn run(
                     Err(err) => return Err(err.into()),
                 };
 
+                // If the lockfile was updated, run the `post-lock` hooks.
+                if !no_hooks && matches!(result, LockResult::Changed(..)) {
+                    run_hooks(Hook::PostLock, project.workspace(), Some(&venv), printer).await?;
+                }
+
+                if !no_hooks {
+                    run_hooks(Hook::PreSync, project.workspace(), Some(&venv), printer).await?;
+                }
+
                 let install_options = InstallOptions::default();
 
                 project::sync::do_sync(
@@ -615,6 +702,7 @@ pub(crate) async fn run(
                     editable,
                     install_options,
                     Modifications::Sufficient,
+                    None,
                     settings.as_ref().into(),
                     if show_resolution {
                         Box::new(DefaultInstallLogger)
@@ -629,6 +717,10 @@ pub(crate) async fn run(
                 )
                 .await?;
 
+                if !no_hooks {
+                    run_hooks(Hook::PostSync, project.workspace(), Some(&venv), printer).await?;
+                }
+
                 lock = Some(result.into_lock());
             }
 
@@ -639,7 +731,8 @@ pub(crate) async fn run(
             let interpreter = {
                 let client_builder = BaseClientBuilder::new()
                     .connectivity(connectivity)
-                    .native_tls(native_tls);
+                    .native_tls(native_tls)
+                    .indexes(settings.index_locations.allowed_indexes());
 
                 // (1) Explicit request from user
                 let python_request = if let Some(request) = python.as_deref() {
@@ -701,10 +794,12 @@ pub(crate) async fn run(
     } else {
         let client_builder = BaseClientBuilder::new()
             .connectivity(connectivity)
-            .native_tls(native_tls);
+            .native_tls(native_tls)
+            .indexes(settings.index_locations.allowed_indexes());
 
         let spec =
-            RequirementsSpecification::from_simple_sources(&requirements, &client_builder).await?;
+            RequirementsSpecification::from_simple_sources(&requirements, &client_builder, cache)
+                .await?;
 
         Some(spec)
     };
@@ -806,7 +901,7 @@ pub(crate) async fn run(
             .ok_or_else(|| anyhow!("Base environment has no site packages directory"))?;
 
         fs_err::write(
-            ephemeral_site_packages.join("_uv_ephemeral_overlay.pth"),
+            ephemeral_site_packages.join(OVERLAY_PTH),
             format!(
                 "import site; site.addsitedir(\"{}\")",
                 base_site_packages.escape_for_python()
@@ -889,6 +984,17 @@ pub(crate) async fn run(
     debug!("Running `{command}`");
     let mut process = command.as_command(interpreter);
 
+    // Set any variables read from environment files, unless they're already set in the
+    // environment.
+    for (key, value) in env_vars {
+        if std::env::var_os(&key).is_none() {
+            process.env(key, value);
+        }
+    }
+
+    // Set any environment variables declared by the task.
+    process.envs(task_env);
+
     // Construct the `PATH` environment variable.
     let new_path = std::env::join_paths(
         ephemeral_env
@@ -944,6 +1050,38 @@ pub(crate) async fn run(
     }
 }
 
+/// Read the variables defined in the given `.env` files, such that variables defined in later files
+/// override those defined in earlier files.
+fn read_env_files<'a>(
+    paths: impl IntoIterator<Item = &'a PathBuf>,
+) -> anyhow::Result<BTreeMap<String, String>> {
+    let mut variables = BTreeMap::new();
+    for path in paths {
+        let iter = match dotenvy::from_path_iter(path) {
+            Ok(iter) => iter,
+            Err(dotenvy::Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => {
+                bail!("No environment file found at: `{}`", path.user_display());
+            }
+            Err(err) => {
+                return Err(err).with_context(|| {
+                    format!("Failed to read environment file: `{}`", path.user_display())
+                });
+            }
+        };
+        for item in iter {
+            let (key, value) = item.with_context(|| {
+                format!(
+                    "Failed to parse environment file: `{}`",
+                    path.user_display()
+                )
+            })?;
+            variables.insert(key, value);
+        }
+        debug!("Read environment file at: `{}`", path.user_display());
+    }
+    Ok(variables)
+}
+
 /// Returns `true` if we can skip creating an additional ephemeral environment in `uv run`.
 fn can_skip_ephemeral(
     spec: Option<&RequirementsSpecification>,
@@ -1168,11 +1306,52 @@ impl std::fmt::Display for RunCommand {
 }
 
 impl RunCommand {
+    /// Determine the [`RunCommand`] for a task declared in `tool.uv.tasks`, with the given
+    /// additional arguments appended to the task's command.
+    fn from_task(name: &str, command: &TaskCommand, args: Vec<OsString>) -> anyhow::Result<Self> {
+        match command {
+            TaskCommand::Shell(command) => {
+                if cfg!(windows) {
+                    Ok(Self::External(
+                        OsString::from("cmd"),
+                        [OsString::from("/C"), OsString::from(command)]
+                            .into_iter()
+                            .chain(args)
+                            .collect(),
+                    ))
+                } else {
+                    // Forward any additional arguments to the shell as positional parameters.
+                    Ok(Self::External(
+                        OsString::from("sh"),
+                        [
+                            OsString::from("-c"),
+                            OsString::from(format!("{command} \"$@\"")),
+                            OsString::from(name),
+                        ]
+                        .into_iter()
+                        .chain(args)
+                        .collect(),
+                    ))
+                }
+            }
+            TaskCommand::Args(command) => {
+                let Some((executable, rest)) = command.split_first() else {
+                    bail!("Task `{name}` has an empty command");
+                };
+                Ok(Self::External(
+                    OsString::from(executable),
+                    rest.iter().map(OsString::from).chain(args).collect(),
+                ))
+            }
+        }
+    }
+
     /// Determine the [`RunCommand`] for a given set of arguments.
     pub(crate) async fn from_args(
         command: &ExternalCommand,
         module: bool,
         script: bool,
+        gui_script: bool,
         connectivity: Connectivity,
         native_tls: bool,
     ) -> anyhow::Result<Self> {
@@ -1224,6 +1403,11 @@ impl RunCommand {
             return Ok(Self::PythonModule(target.clone(), args.to_vec()));
         } else if script {
             return Ok(Self::PythonScript(target.clone().into(), args.to_vec()));
+        } else if gui_script {
+            if cfg!(windows) {
+                return Ok(Self::PythonGuiScript(target.clone().into(), args.to_vec()));
+            }
+            bail!("`--gui-script` is only supported on Windows. Did you mean `--script`?");
         }
 
         let metadata = target_path.metadata();
@@ -4,15 +4,19 @@ use std::str::FromStr;
 
 use anyhow::{Context, Result};
 use itertools::Itertools;
-use uv_auth::store_credentials;
+use rustc_hash::{FxHashMap, FxHashSet};
+use uv_auth::{store_credentials, store_secret_credentials};
 use uv_cache::Cache;
+use uv_cache_key::CanonicalUrl;
 use uv_client::{Connectivity, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     Concurrency, Constraints, DevGroupsManifest, DevGroupsSpecification, EditableMode,
-    ExtrasSpecification, HashCheckingMode, InstallOptions, LowerBound,
+    ExtrasSpecification, HashCheckingMode, InstallOptions, LowerBound, SourceStrategy,
 };
 use uv_dispatch::BuildDispatch;
-use uv_distribution_types::{DirectorySourceDist, Dist, Index, ResolvedDist, SourceDist};
+use uv_distribution_types::{
+    DirectorySourceDist, Dist, Index, IndexLocations, ResolvedDist, SourceDist,
+};
 use uv_installer::SitePackages;
 use uv_normalize::PackageName;
 use uv_pep508::{MarkerTree, Requirement, VersionOrUrl};
@@ -23,13 +27,17 @@ use uv_python::{PythonDownloads, PythonEnvironment, PythonPreference, PythonRequ
 use uv_resolver::{FlatIndex, Lock};
 use uv_types::{BuildIsolation, HashStrategy};
 use uv_warnings::warn_user;
-use uv_workspace::pyproject::{DependencyGroupSpecifier, Source, Sources, ToolUvSources};
+use uv_workspace::pyproject::{
+    ConflictItem, DependencyGroupSpecifier, Source, Sources, ToolUvSources,
+};
 use uv_workspace::{DiscoveryOptions, InstallTarget, MemberDiscovery, VirtualProject, Workspace};
 
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger, InstallLogger};
 use crate::commands::pip::operations;
-use crate::commands::pip::operations::Modifications;
-use crate::commands::project::lock::{do_safe_lock, LockMode};
+use crate::commands::pip::operations::{DryRun, Modifications};
+use crate::commands::pip::report::InstallReport;
+use crate::commands::project::hooks::{run_hooks, Hook};
+use crate::commands::project::lock::{do_safe_lock, LockMode, LockResult};
 use crate::commands::project::{
     default_dependency_groups, validate_dependency_groups, ProjectError, SharedState,
 };
@@ -49,6 +57,8 @@ pub(crate) async fn sync(
     editable: EditableMode,
     install_options: InstallOptions,
     modifications: Modifications,
+    report: Option<&Path>,
+    no_hooks: bool,
     python: Option<String>,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
@@ -99,18 +109,38 @@ pub(crate) async fn sync(
     validate_dependency_groups(&project, &dev)?;
     let defaults = default_dependency_groups(project.pyproject_toml())?;
 
-    // Discover or create the virtual environment.
-    let venv = project::get_or_init_environment(
-        target.workspace(),
-        python.as_deref().map(PythonRequest::parse),
-        python_preference,
-        python_downloads,
-        connectivity,
-        native_tls,
-        cache,
-        printer,
-    )
-    .await?;
+    // Discover or create the virtual environment. If workspace members use separate environments,
+    // sync the member into its own environment.
+    let member = package
+        .as_ref()
+        .filter(|_| target.workspace().member_environments())
+        .and_then(|package| target.workspace().packages().get(package));
+    let venv = if let Some(member) = member {
+        project::get_or_init_member_environment(
+            target.workspace(),
+            member,
+            python.as_deref().map(PythonRequest::parse),
+            python_preference,
+            python_downloads,
+            connectivity,
+            native_tls,
+            cache,
+            printer,
+        )
+        .await?
+    } else {
+        project::get_or_init_environment(
+            target.workspace(),
+            python.as_deref().map(PythonRequest::parse),
+            python_preference,
+            python_downloads,
+            connectivity,
+            native_tls,
+            cache,
+            printer,
+        )
+        .await?
+    };
 
     // Initialize any shared state.
     let state = SharedState::default();
@@ -124,7 +154,7 @@ pub(crate) async fn sync(
         LockMode::Write(venv.interpreter())
     };
 
-    let lock = match do_safe_lock(
+    let result = match do_safe_lock(
         mode,
         target.workspace(),
         settings.as_ref().into(),
@@ -139,7 +169,7 @@ pub(crate) async fn sync(
     )
     .await
     {
-        Ok(result) => result.into_lock(),
+        Ok(result) => result,
         Err(ProjectError::Operation(operations::Error::Resolve(
             uv_resolver::ResolveError::NoSolution(err),
         ))) => {
@@ -161,6 +191,17 @@ pub(crate) async fn sync(
         Err(err) => return Err(err.into()),
     };
 
+    // If the lockfile was updated, run the `post-lock` hooks.
+    if !no_hooks && matches!(result, LockResult::Changed(..)) {
+        run_hooks(Hook::PostLock, target.workspace(), Some(&venv), printer).await?;
+    }
+
+    let lock = result.into_lock();
+
+    if !no_hooks {
+        run_hooks(Hook::PreSync, target.workspace(), Some(&venv), printer).await?;
+    }
+
     // Perform the sync operation.
     do_sync(
         target,
@@ -171,6 +212,7 @@ pub(crate) async fn sync(
         editable,
         install_options,
         modifications,
+        report,
         settings.as_ref().into(),
         Box::new(DefaultInstallLogger),
         connectivity,
@@ -181,6 +223,10 @@ pub(crate) async fn sync(
     )
     .await?;
 
+    if !no_hooks {
+        run_hooks(Hook::PostSync, target.workspace(), Some(&venv), printer).await?;
+    }
+
     Ok(ExitStatus::Success)
 }
 
@@ -195,6 +241,7 @@ pub(super) async fn do_sync(
     editable: EditableMode,
     install_options: InstallOptions,
     modifications: Modifications,
+    report: Option<&Path>,
     settings: InstallerSettingsRef<'_>,
     logger: Box<dyn InstallLogger>,
     connectivity: Connectivity,
@@ -222,16 +269,23 @@ pub(super) async fn do_sync(
         allow_insecure_host,
         dependency_metadata,
         config_setting,
+        build_containers,
         no_build_isolation,
         no_build_isolation_package,
         exclude_newer,
         link_mode,
         compile_bytecode,
+        bytecode_options,
         reinstall,
         build_options,
         sources,
     } = settings;
 
+    // Verify the hashes of any URL sources.
+    if matches!(sources, SourceStrategy::Enabled) {
+        lock.verify_source_hashes(target.workspace())?;
+    }
+
     // Validate that the Python version is supported by the lockfile.
     if !lock
         .requires_python()
@@ -285,10 +339,18 @@ pub(super) async fn do_sync(
     // If necessary, convert editable to non-editable distributions.
     let resolution = apply_editable_mode(resolution, editable);
 
+    // If requested, verify that each package is installed from the index selected by the
+    // project's configuration.
+    if target.workspace().enforce_index_provenance() {
+        verify_index_provenance(lock, target.workspace(), index_locations, sources)?;
+    }
+
     // Add all authenticated sources to the cache.
     for index in index_locations.allowed_indexes() {
         if let Some(credentials) = index.credentials() {
             store_credentials(index.raw_url(), credentials);
+        } else if let Some(secrets) = index.secret_credentials() {
+            store_secret_credentials(secrets);
         }
     }
 
@@ -321,14 +383,25 @@ pub(super) async fn do_sync(
     let bounds = LowerBound::default();
     let build_constraints = Constraints::default();
     let build_hasher = HashStrategy::default();
-    let dry_run = false;
+    let dry_run = DryRun::Disabled;
 
-    // Extract the hashes from the lockfile.
+    // Extract the hashes from the lockfile, along with those declared for URL sources.
     let hasher = HashStrategy::from_resolution(&resolution, HashCheckingMode::Verify)?;
+    let hasher = if matches!(sources, SourceStrategy::Enabled) {
+        hasher.with_url_hashes(
+            target
+                .workspace()
+                .url_source_hashes()
+                .map(|(_, url, hash)| (url, hash)),
+        )
+    } else {
+        hasher
+    };
 
     // Resolve the flat indexes from `--find-links`.
     let flat_index = {
-        let client = FlatIndexClient::new(&client, cache);
+        let client = FlatIndexClient::new(&client, cache)
+            .with_recursive(index_locations.find_links_recursive());
         let entries = client
             .fetch(index_locations.flat_indexes().map(Index::url))
             .await?;
@@ -346,10 +419,12 @@ pub(super) async fn do_sync(
         dependency_metadata,
         &state.index,
         &state.git,
+        &state.hg,
         &state.capabilities,
         &state.in_flight,
         index_strategy,
         config_setting,
+        build_containers,
         build_isolation,
         link_mode,
         build_options,
@@ -363,7 +438,7 @@ pub(super) async fn do_sync(
     let site_packages = SitePackages::from_environment(venv)?;
 
     // Sync the environment.
-    pip::operations::install(
+    let changelog = pip::operations::install(
         &resolution,
         site_packages,
         modifications,
@@ -371,6 +446,7 @@ pub(super) async fn do_sync(
         build_options,
         link_mode,
         compile_bytecode,
+        bytecode_options,
         index_locations,
         config_setting,
         &hasher,
@@ -381,12 +457,33 @@ pub(super) async fn do_sync(
         &build_dispatch,
         cache,
         venv,
+        None,
         logger,
         dry_run,
         printer,
     )
     .await?;
 
+    // If requested, write a report of the changes made to the environment.
+    if let Some(report) = report {
+        // Treat the target packages, along with their direct dependencies, as requested.
+        let mut requested = target.packages().cloned().collect::<FxHashSet<_>>();
+        for package in lock.packages() {
+            if target.packages().any(|name| name == package.name()) {
+                requested.extend(
+                    package
+                        .requires_dist()
+                        .iter()
+                        .chain(package.requires_dev().values().flatten())
+                        .map(|requirement| requirement.name.clone()),
+                );
+            }
+        }
+
+        InstallReport::from_environment(venv, &resolution, &changelog, &requested)?
+            .write(report)?;
+    }
+
     Ok(())
 }
 
@@ -446,6 +543,120 @@ fn apply_editable_mode(
     }
 }
 
+/// Verify that at most one item from each set of conflicting extras and dependency groups (as
+/// declared in `tool.uv.conflicts`) is enabled.
+#[allow(clippy::result_large_err)]
+fn validate_conflicts(
+    target: &InstallTarget<'_>,
+    extras: &ExtrasSpecification,
+    dev: &DevGroupsManifest,
+) -> Result<(), ProjectError> {
+    for name in target.packages() {
+        let Some(member) = target.workspace().packages().get(name) else {
+            continue;
+        };
+        let Some(conflicts) = member
+            .pyproject_toml()
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.conflicts.as_ref())
+        else {
+            continue;
+        };
+        for set in conflicts {
+            let mut enabled = set.iter().filter(|item| match item {
+                ConflictItem::Extra(extra) => extras.contains(extra),
+                ConflictItem::Group(group) => dev.iter().any(|enabled| enabled == group),
+            });
+            if let (Some(first), Some(second)) = (enabled.next(), enabled.next()) {
+                return Err(ProjectError::ConflictingItems(
+                    name.clone(),
+                    first.clone(),
+                    second.clone(),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Verify that each registry package in the lockfile was locked against the index that the
+/// project's configuration selects for it: either the index to which it's pinned via
+/// `tool.uv.sources`, or, if it isn't pinned, one of the configured (non-`explicit`) indexes.
+///
+/// Every locked package is verified, including transitive dependencies and packages that won't be
+/// installed on the current platform.
+fn verify_index_provenance(
+    lock: &Lock,
+    workspace: &Workspace,
+    index_locations: &IndexLocations,
+    sources: SourceStrategy,
+) -> Result<(), ProjectError> {
+    let indexes = index_locations.allowed_indexes();
+
+    // Collect the indexes to which each package is pinned.
+    let mut pins: FxHashMap<&PackageName, FxHashSet<CanonicalUrl>> = FxHashMap::default();
+    if matches!(sources, SourceStrategy::Enabled) {
+        let member_sources = workspace.packages().values().filter_map(|member| {
+            member
+                .pyproject_toml()
+                .tool
+                .as_ref()
+                .and_then(|tool| tool.uv.as_ref())
+                .and_then(|uv| uv.sources.as_ref())
+                .map(ToolUvSources::inner)
+        });
+        for (name, sources) in std::iter::once(workspace.sources())
+            .chain(member_sources)
+            .flatten()
+        {
+            for source in sources.iter() {
+                let Source::Registry { index, .. } = source else {
+                    continue;
+                };
+                if let Some(index) = indexes
+                    .iter()
+                    .find(|candidate| candidate.name.as_ref() == Some(index))
+                {
+                    pins.entry(name)
+                        .or_default()
+                        .insert(CanonicalUrl::new(index.url()));
+                }
+            }
+        }
+    }
+
+    // Collect the indexes that may be used for packages that aren't pinned.
+    let implicit = indexes
+        .iter()
+        .filter(|index| !index.explicit)
+        .map(|index| CanonicalUrl::new(index.url()))
+        .collect::<FxHashSet<_>>();
+
+    for package in lock.packages() {
+        let Some(index) = package.index(workspace.install_path())? else {
+            continue;
+        };
+        let url = CanonicalUrl::new(&index);
+        if let Some(pinned) = pins.get(package.name()) {
+            if !pinned.contains(&url) {
+                return Err(ProjectError::PinnedIndexProvenance(
+                    package.name().clone(),
+                    index.redacted().to_string(),
+                ));
+            }
+        } else if !implicit.contains(&url) {
+            return Err(ProjectError::UnconfiguredIndexProvenance(
+                package.name().clone(),
+                index.redacted().to_string(),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
 /// Extract any credentials that are defined on the workspace dependencies themselves. While we
 /// don't store plaintext credentials in the `uv.lock`, we do respect credentials that are defined
 /// in the `pyproject.toml`.
@@ -0,0 +1,328 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::io::{Read as _, Write as _};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use futures::stream::FuturesUnordered;
+use futures::StreamExt;
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_auth::{store_credentials, store_secret_credentials};
+use uv_cache::Cache;
+use uv_client::{BaseClient, BaseClientBuilder, Connectivity};
+use uv_configuration::{Concurrency, LowerBound};
+use uv_distribution_types::File;
+use uv_extract::hash::Hasher;
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_pypi_types::HashDigest;
+use uv_python::{PythonDownloads, PythonPreference, PythonRequest};
+use uv_warnings::warn_user;
+use uv_workspace::{DiscoveryOptions, Workspace};
+
+use crate::commands::pip::loggers::DefaultResolveLogger;
+use crate::commands::project::lock::{do_safe_lock, LockMode};
+use crate::commands::project::{ProjectError, ProjectInterpreter};
+use crate::commands::{diagnostics, pip, ExitStatus, SharedState};
+use crate::printer::Printer;
+use crate::settings::ResolverSettings;
+
+/// Download the locked distributions into a local PEP 503-compatible index.
+#[allow(clippy::fn_params_excessive_bools)]
+pub(crate) async fn vendor(
+    project_dir: &Path,
+    output_dir: Option<PathBuf>,
+    locked: bool,
+    frozen: bool,
+    python: Option<String>,
+    settings: ResolverSettings,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    native_tls: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    // Find the workspace.
+    let workspace = Workspace::discover(project_dir, &DiscoveryOptions::default()).await?;
+
+    // Find an interpreter for the project, unless the lockfile is frozen.
+    let interpreter = if frozen {
+        None
+    } else {
+        Some(
+            ProjectInterpreter::discover(
+                &workspace,
+                python.as_deref().map(PythonRequest::parse),
+                python_preference,
+                python_downloads,
+                connectivity,
+                native_tls,
+                cache,
+                printer,
+            )
+            .await?
+            .into_interpreter(),
+        )
+    };
+
+    // Determine the lock mode.
+    let mode = match interpreter.as_ref() {
+        Some(interpreter) if locked => LockMode::Locked(interpreter),
+        Some(interpreter) => LockMode::Write(interpreter),
+        None => LockMode::Frozen,
+    };
+
+    // Initialize any shared state.
+    let state = SharedState::default();
+
+    // Lock the project.
+    let lock = match do_safe_lock(
+        mode,
+        &workspace,
+        settings.as_ref(),
+        LowerBound::Warn,
+        &state,
+        Box::new(DefaultResolveLogger),
+        connectivity,
+        concurrency,
+        native_tls,
+        cache,
+        printer,
+    )
+    .await
+    {
+        Ok(result) => result.into_lock(),
+        Err(ProjectError::Operation(pip::operations::Error::Resolve(
+            uv_resolver::ResolveError::NoSolution(err),
+        ))) => {
+            diagnostics::no_solution(&err);
+            return Ok(ExitStatus::Failure);
+        }
+        Err(ProjectError::Operation(pip::operations::Error::Resolve(
+            uv_resolver::ResolveError::FetchAndBuild(dist, err),
+        ))) => {
+            diagnostics::fetch_and_build(dist, err);
+            return Ok(ExitStatus::Failure);
+        }
+        Err(ProjectError::Operation(pip::operations::Error::Resolve(
+            uv_resolver::ResolveError::Build(dist, err),
+        ))) => {
+            diagnostics::build(dist, err);
+            return Ok(ExitStatus::Failure);
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    // Collect the files for every locked package, across all environments, extras, and groups.
+    let mut index: BTreeMap<&PackageName, Vec<File>> = BTreeMap::new();
+    for package in lock.packages() {
+        match package.registry_files(workspace.install_path())? {
+            Some(files) => {
+                index.entry(package.name()).or_default().extend(files);
+            }
+            None => {
+                // Workspace members are built from source, and so don't need to be vendored.
+                if !workspace.packages().contains_key(package.name()) {
+                    warn_user!(
+                        "`{}` is not from a registry and will not be vendored",
+                        package.name()
+                    );
+                }
+            }
+        }
+    }
+
+    let output_dir = output_dir.unwrap_or_else(|| workspace.install_path().join("vendor"));
+
+    // Add all authenticated sources to the cache.
+    for index in settings.index_locations.allowed_indexes() {
+        if let Some(credentials) = index.credentials() {
+            store_credentials(index.raw_url(), credentials);
+        } else if let Some(secrets) = index.secret_credentials() {
+            store_secret_credentials(secrets);
+        }
+    }
+
+    let client = BaseClientBuilder::new()
+        .connectivity(connectivity)
+        .native_tls(native_tls)
+        .keyring(settings.keyring_provider)
+        .allow_insecure_host(settings.allow_insecure_host.clone())
+        .indexes(settings.index_locations.allowed_indexes())
+        .build();
+
+    // Download the files concurrently, writing each package to its own directory.
+    let semaphore = tokio::sync::Semaphore::new(concurrency.downloads);
+    let mut tasks = FuturesUnordered::new();
+    for (name, files) in &index {
+        let directory = output_dir.join(name.as_str());
+        fs_err::create_dir_all(&directory)?;
+        for file in files {
+            let directory = directory.clone();
+            let client = &client;
+            let semaphore = &semaphore;
+            tasks.push(async move {
+                let _permit = semaphore.acquire().await?;
+                download(client, file, &directory)
+                    .await
+                    .with_context(|| format!("Failed to download `{}`", file.filename))
+            });
+        }
+    }
+
+    let mut downloaded = 0;
+    while let Some(result) = tasks.next().await {
+        if result? {
+            downloaded += 1;
+        }
+    }
+
+    // Write the index pages.
+    write_index(&output_dir, &index)?;
+
+    let files = index.values().map(Vec::len).sum::<usize>();
+    debug!("Downloaded {downloaded} of {files} files");
+    writeln!(
+        printer.stderr(),
+        "Vendored {} {} ({} {}) to: {}",
+        index.len(),
+        if index.len() == 1 {
+            "package"
+        } else {
+            "packages"
+        },
+        files,
+        if files == 1 { "file" } else { "files" },
+        output_dir.user_display().cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Download a file into the given directory, verifying its hashes against the lockfile.
+///
+/// Returns `false` if the file was already present with the expected hashes.
+async fn download(client: &BaseClient, file: &File, directory: &Path) -> Result<bool> {
+    let path = directory.join(&file.filename);
+    if path.is_file() {
+        if hash_file(&path, &file.hashes)? == file.hashes {
+            debug!("Skipping existing file: {}", path.user_display());
+            return Ok(false);
+        }
+        debug!(
+            "Replacing existing file with mismatched hashes: {}",
+            path.user_display()
+        );
+    }
+
+    let mut hashers = file
+        .hashes
+        .iter()
+        .map(|hash| Hasher::from(hash.algorithm()))
+        .collect::<Vec<_>>();
+
+    let mut temp = tempfile::NamedTempFile::new_in(directory)?;
+    let url = file.url.to_url()?;
+    if url.scheme() == "file" {
+        let source = url
+            .to_file_path()
+            .map_err(|()| anyhow::anyhow!("Invalid file URL: {url}"))?;
+        let contents = fs_err::read(source)?;
+        for hasher in &mut hashers {
+            hasher.update(&contents);
+        }
+        temp.write_all(&contents)?;
+    } else {
+        let response = client
+            .for_host(&url)
+            .get(url.clone())
+            .send()
+            .await?
+            .error_for_status()?;
+        let mut reader = response.bytes_stream();
+        while let Some(chunk) = reader.next().await {
+            let chunk = chunk?;
+            for hasher in &mut hashers {
+                hasher.update(&chunk);
+            }
+            temp.write_all(&chunk)?;
+        }
+    }
+
+    // Verify the hashes.
+    let computed = hashers
+        .into_iter()
+        .map(HashDigest::from)
+        .collect::<Vec<_>>();
+    if computed != file.hashes {
+        return Err(anyhow::anyhow!(
+            "Hash mismatch for `{}`\n\nExpected:\n{}\n\nComputed:\n{}",
+            file.filename,
+            file.hashes
+                .iter()
+                .map(|hash| format!("  {hash}"))
+                .join("\n"),
+            computed.iter().map(|hash| format!("  {hash}")).join("\n"),
+        ));
+    }
+
+    temp.persist(&path)?;
+    Ok(true)
+}
+
+/// Compute the digests of an existing file, for each of the algorithms in the given hashes.
+fn hash_file(path: &Path, hashes: &[HashDigest]) -> Result<Vec<HashDigest>> {
+    let mut hashers = hashes
+        .iter()
+        .map(|hash| Hasher::from(hash.algorithm()))
+        .collect::<Vec<_>>();
+
+    let mut reader = fs_err::File::open(path)?;
+    let mut buffer = vec![0; 64 * 1024];
+    loop {
+        let read = reader.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        for hasher in &mut hashers {
+            hasher.update(&buffer[..read]);
+        }
+    }
+
+    Ok(hashers.into_iter().map(HashDigest::from).collect())
+}
+
+/// Write the PEP 503 index pages: a root page listing each package, and a page per package
+/// listing its files.
+fn write_index(output_dir: &Path, index: &BTreeMap<&PackageName, Vec<File>>) -> Result<()> {
+    let mut root = String::from("<!DOCTYPE html>\n<html>\n  <body>\n");
+    for (name, files) in index {
+        writeln!(root, "    <a href=\"{name}/\">{name}</a>")?;
+
+        let mut page = String::from("<!DOCTYPE html>\n<html>\n  <body>\n");
+        for file in files.iter().sorted_by(|a, b| a.filename.cmp(&b.filename)) {
+            match file.hashes.first() {
+                Some(hash) => writeln!(
+                    page,
+                    "    <a href=\"{}#{}={}\">{}</a>",
+                    file.filename, hash.algorithm, hash.digest, file.filename
+                )?,
+                None => writeln!(
+                    page,
+                    "    <a href=\"{}\">{}</a>",
+                    file.filename, file.filename
+                )?,
+            }
+        }
+        page.push_str("  </body>\n</html>\n");
+        fs_err::write(output_dir.join(name.as_str()).join("index.html"), page)?;
+    }
+    root.push_str("  </body>\n</html>\n");
+    fs_err::write(output_dir.join("index.html"), root)?;
+    Ok(())
+}
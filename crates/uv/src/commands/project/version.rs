@@ -0,0 +1,280 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use owo_colors::OwoColorize;
+use serde::Serialize;
+
+use uv_cli::{VersionBump, VersionFormat};
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_warnings::warn_user;
+use uv_workspace::pyproject::PyProjectToml;
+use uv_workspace::pyproject_mut::{DependencyTarget, PyProjectTomlMut};
+use uv_workspace::{DiscoveryOptions, VirtualProject, Workspace};
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Display or update the version of a project, along with any dependencies on the project within
+/// the workspace.
+pub(crate) async fn project_version(
+    project_dir: &Path,
+    value: Option<Version>,
+    bump: Option<VersionBump>,
+    package: Option<PackageName>,
+    all_packages: bool,
+    dry_run: bool,
+    output_format: VersionFormat,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    // Identify the project.
+    let project = if let Some(package) = package.as_ref() {
+        VirtualProject::Project(
+            Workspace::discover(project_dir, &DiscoveryOptions::default())
+                .await?
+                .with_current_project(package.clone())
+                .with_context(|| format!("Package `{package}` not found in workspace"))?,
+        )
+    } else {
+        VirtualProject::discover(project_dir, &DiscoveryOptions::default()).await?
+    };
+
+    let workspace = project.workspace();
+
+    // In lockstep, the bumped version must be computed from a specific member.
+    if all_packages && bump.is_some() && package.is_none() {
+        bail!(
+            "`{}` with `{}` requires `{}`, to select the workspace member whose version is bumped",
+            "--bump".green(),
+            "--all-packages".green(),
+            "--package".green()
+        );
+    }
+
+    // Determine the current version of the project.
+    let current = match &project {
+        // Every member is displayed or set to the given version, so the current project, which
+        // may be a virtual workspace root, needn't declare a version.
+        _ if all_packages && bump.is_none() => None,
+        VirtualProject::Project(project) => Some((
+            project.project_name().clone(),
+            static_version(
+                project.project_name(),
+                project.current_project().pyproject_toml(),
+            )?,
+        )),
+        VirtualProject::NonProject(_) => bail!(
+            "The workspace root does not declare a `[project]`; use `{}` to select a workspace member",
+            "--package".green()
+        ),
+    };
+
+    // Determine the packages to display or update.
+    let packages = if all_packages {
+        workspace
+            .packages()
+            .iter()
+            .filter_map(|(name, member)| {
+                let version = member
+                    .pyproject_toml()
+                    .project
+                    .as_ref()
+                    .and_then(|project| project.version.clone());
+                if version.is_none() {
+                    warn_user!(
+                        "Skipping `{name}`, which does not declare a static `project.version`"
+                    );
+                }
+                Some((name.clone(), version?))
+            })
+            .collect::<Vec<_>>()
+    } else {
+        current.iter().cloned().collect()
+    };
+
+    // Determine the new version, if any.
+    let version = match (value, bump) {
+        (Some(value), _) => Some(value),
+        (None, Some(bump)) => current
+            .as_ref()
+            .map(|(_, current)| bump_version(current, bump)),
+        (None, None) => None,
+    };
+
+    let Some(version) = version else {
+        let entries = packages
+            .into_iter()
+            .map(|(name, version)| VersionEntry {
+                name,
+                previous_version: None,
+                version,
+            })
+            .collect::<Vec<_>>();
+        write_entries(&entries, output_format, printer)?;
+        return Ok(ExitStatus::Success);
+    };
+
+    let entries = packages
+        .into_iter()
+        .map(|(name, previous)| VersionEntry {
+            name,
+            previous_version: Some(previous),
+            version: version.clone(),
+        })
+        .collect::<Vec<_>>();
+
+    // Collect every `pyproject.toml` in the workspace, including a virtual workspace root.
+    let mut pyprojects = workspace
+        .packages()
+        .iter()
+        .map(|(name, member)| {
+            (
+                Some(name),
+                member.root().join("pyproject.toml"),
+                member.pyproject_toml(),
+            )
+        })
+        .collect::<Vec<_>>();
+    if workspace
+        .packages()
+        .values()
+        .all(|member| member.root() != workspace.install_path())
+    {
+        pyprojects.push((
+            None,
+            workspace.install_path().join("pyproject.toml"),
+            workspace.pyproject_toml(),
+        ));
+    }
+
+    // Update the versions, along with any dependencies that reference the previous versions.
+    let mut written = false;
+    for (name, path, pyproject_toml) in pyprojects {
+        let mut toml =
+            PyProjectTomlMut::from_toml(&pyproject_toml.raw, DependencyTarget::PyProjectToml)?;
+        let mut modified = false;
+
+        for entry in &entries {
+            let Some(previous) = entry.previous_version.as_ref() else {
+                continue;
+            };
+
+            if name == Some(&entry.name) {
+                toml.set_version(&entry.version)?;
+                modified = true;
+            }
+
+            if toml.update_dependency_version(&entry.name, previous, &entry.version)? {
+                modified = true;
+                writeln!(
+                    printer.stderr(),
+                    "{} dependency on `{}` in: {}",
+                    if dry_run { "Would update" } else { "Updated" },
+                    entry.name.cyan(),
+                    relative_path(&path, workspace).user_display().cyan()
+                )?;
+            }
+        }
+
+        if modified && !dry_run {
+            fs_err::write(&path, toml.to_string())?;
+            written = true;
+        }
+    }
+
+    write_entries(&entries, output_format, printer)?;
+
+    // The lockfile records the version of every workspace member.
+    let lock_path = workspace.install_path().join("uv.lock");
+    if written && lock_path.is_file() {
+        warn_user!(
+            "`{}` is out of date with the new version; run `{}` to update it",
+            relative_path(&lock_path, workspace).user_display(),
+            "uv lock".green()
+        );
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Return the static `project.version` declared by the given `pyproject.toml`.
+fn static_version(name: &PackageName, pyproject_toml: &PyProjectToml) -> Result<Version> {
+    pyproject_toml
+        .project
+        .as_ref()
+        .and_then(|project| project.version.clone())
+        .with_context(|| {
+            format!("Project `{name}` does not declare a static `project.version`, and so its version cannot be read or updated")
+        })
+}
+
+/// Bump the given component of a version, dropping any pre-release, post-release, development,
+/// and local segments.
+fn bump_version(version: &Version, bump: VersionBump) -> Version {
+    let release = version.release();
+    let major = release.first().copied().unwrap_or(0);
+    let minor = release.get(1).copied().unwrap_or(0);
+    let patch = release.get(2).copied().unwrap_or(0);
+    let release = match bump {
+        VersionBump::Major => [major + 1, 0, 0],
+        VersionBump::Minor => [major, minor + 1, 0],
+        VersionBump::Patch => [major, minor, patch + 1],
+    };
+    Version::new(release).with_epoch(version.epoch())
+}
+
+/// Return the path relative to the workspace root, if possible.
+fn relative_path(path: &Path, workspace: &Workspace) -> PathBuf {
+    path.strip_prefix(workspace.install_path())
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// Write the version of each package to stdout.
+fn write_entries(
+    entries: &[VersionEntry],
+    output_format: VersionFormat,
+    printer: Printer,
+) -> Result<()> {
+    match output_format {
+        VersionFormat::Text => {
+            for entry in entries {
+                if let Some(previous) = entry.previous_version.as_ref() {
+                    writeln!(
+                        printer.stdout(),
+                        "{} {} => {}",
+                        entry.name.as_str().bold(),
+                        previous,
+                        entry.version.to_string().green()
+                    )?;
+                } else {
+                    writeln!(
+                        printer.stdout(),
+                        "{} {}",
+                        entry.name.as_str().bold(),
+                        entry.version
+                    )?;
+                }
+            }
+        }
+        VersionFormat::Json => {
+            writeln!(
+                printer.stdout(),
+                "{}",
+                serde_json::to_string_pretty(entries)?
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// The version of a package in the workspace.
+#[derive(Debug, Serialize)]
+struct VersionEntry {
+    name: PackageName,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    previous_version: Option<Version>,
+    version: Version,
+}
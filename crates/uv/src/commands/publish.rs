@@ -6,14 +6,14 @@ use console::Term;
 use owo_colors::OwoColorize;
 use std::fmt::Write;
 use std::iter;
+use std::str::FromStr;
 use std::sync::Arc;
 use std::time::Duration;
 use tracing::info;
 use url::Url;
+use uv_auth::Secret;
 use uv_cache::Cache;
-use uv_client::{
-    AuthIntegration, BaseClientBuilder, Connectivity, RegistryClientBuilder, DEFAULT_RETRIES,
-};
+use uv_client::{AuthIntegration, BaseClientBuilder, Connectivity, RegistryClientBuilder};
 use uv_configuration::{KeyringProviderType, TrustedHost, TrustedPublishing};
 use uv_distribution_types::{Index, IndexCapabilities, IndexLocations, IndexUrl};
 use uv_publish::{
@@ -54,7 +54,10 @@ pub(crate) async fn publish(
     //   shouldn't try cloning the request to make an unauthenticated request first, but we want
     //   keyring integration. For trusted publishing, we use an OIDC auth routine without keyring
     //   or other auth integration.
-    let upload_client = BaseClientBuilder::new()
+    let upload_client_builder = BaseClientBuilder::new();
+    let retry_policy = upload_client_builder.retry_policy();
+    let retry_strategy = upload_client_builder.retry_strategy();
+    let upload_client = upload_client_builder
         .retries(0)
         .keyring(keyring_provider)
         .native_tls(native_tls)
@@ -94,6 +97,17 @@ pub(crate) async fn publish(
         None
     };
 
+    // Resolve the password (or token), if provided as a reference to a secret.
+    let password = match password {
+        Some(password) => Some(
+            Secret::from_str(&password)?
+                .resolve()
+                .await
+                .context("Failed to read the publish password")?,
+        ),
+        None => None,
+    };
+
     // If applicable, attempt obtaining a token for trusted publishing.
     let trusted_publishing_token = check_trusted_publishing(
         username.as_deref(),
@@ -176,7 +190,8 @@ pub(crate) async fn publish(
             &filename,
             &publish_url,
             &upload_client,
-            DEFAULT_RETRIES,
+            &retry_policy,
+            &retry_strategy,
             username.as_deref(),
             password.as_deref(),
             check_url_client.as_ref(),
@@ -1,9 +1,11 @@
 use anstream::println;
-use anyhow::Result;
+use anyhow::{bail, Result};
 use std::path::Path;
 
 use uv_cache::Cache;
 use uv_fs::Simplified;
+use uv_python::downloads::PythonDownloadRequest;
+use uv_python::platform::Arch;
 use uv_python::{
     EnvironmentPreference, PythonInstallation, PythonPreference, PythonRequest, PythonVariant,
     PythonVersionFile, VersionRequest,
@@ -21,6 +23,8 @@ pub(crate) async fn find(
     no_project: bool,
     no_config: bool,
     system: bool,
+    arch: Option<Arch>,
+    variant: Option<PythonVariant>,
     python_preference: PythonPreference,
     cache: &Cache,
 ) -> Result<ExitStatus> {
@@ -67,12 +71,21 @@ pub(crate) async fn find(
         }
     }
 
-    let python = PythonInstallation::find(
-        &request.unwrap_or_default(),
-        environment_preference,
-        python_preference,
-        cache,
-    )?;
+    let mut request = request.unwrap_or_default();
+
+    // (4) Narrow the request by build variant and architecture, if requested
+    if let Some(variant) = variant {
+        request = request.with_variant(variant);
+    }
+    if let Some(arch) = arch {
+        let Some(download_request) = PythonDownloadRequest::from_request(&request) else {
+            bail!("`--arch` cannot be used with a request for {request}");
+        };
+        request = PythonRequest::Key(download_request.with_arch(arch));
+    }
+
+    let python =
+        PythonInstallation::find(&request, environment_preference, python_preference, cache)?;
 
     println!(
         "{}",
@@ -18,7 +18,9 @@ use uv_python::downloads::{DownloadResult, ManagedPythonDownload, PythonDownload
 use uv_python::managed::{
     python_executable_dir, ManagedPythonInstallation, ManagedPythonInstallations,
 };
-use uv_python::{PythonDownloads, PythonInstallationKey, PythonRequest, PythonVersionFile};
+use uv_python::{
+    PythonDownloads, PythonInstallationKey, PythonRequest, PythonVariant, PythonVersionFile,
+};
 use uv_shell::Shell;
 use uv_warnings::warn_user;
 
@@ -108,6 +110,8 @@ pub(crate) async fn install(
     project_dir: &Path,
     targets: Vec<String>,
     reinstall: bool,
+    variant: Option<PythonVariant>,
+    registry: bool,
     python_downloads: PythonDownloads,
     native_tls: bool,
     connectivity: Connectivity,
@@ -129,12 +133,14 @@ pub(crate) async fn install(
                 vec![PythonRequest::Default]
             })
             .into_iter()
+            .map(|request| apply_variant(request, variant))
             .map(InstallRequest::new)
             .collect::<Result<Vec<_>>>()?
     } else {
         targets
             .iter()
             .map(|target| PythonRequest::parse(target.as_str()))
+            .map(|request| apply_variant(request, variant))
             .map(InstallRequest::new)
             .collect::<Result<Vec<_>>>()?
     };
@@ -265,6 +271,16 @@ pub(crate) async fn install(
         installation.ensure_externally_managed()?;
         installation.ensure_canonical_executables()?;
 
+        if registry {
+            #[cfg(windows)]
+            installation.register_windows_registry()?;
+            #[cfg(not(windows))]
+            debug!(
+                "Skipping registration of {} in the Windows registry on a non-Windows platform",
+                installation.key()
+            );
+        }
+
         if preview.is_disabled() {
             debug!("Skipping installation of Python executables, use `--preview` to enable.");
             continue;
@@ -483,3 +499,11 @@ fn warn_if_not_on_path(bin: &Path) {
         }
     }
 }
+
+/// Apply the requested [`PythonVariant`], if any, to a [`PythonRequest`].
+fn apply_variant(request: PythonRequest, variant: Option<PythonVariant>) -> PythonRequest {
+    match variant {
+        Some(variant) => request.with_variant(variant),
+        None => request,
+    }
+}
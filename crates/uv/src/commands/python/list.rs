@@ -1,15 +1,19 @@
 use std::collections::BTreeSet;
 use std::fmt::Write;
+use std::path::Path;
 
 use anyhow::Result;
 use owo_colors::OwoColorize;
 use rustc_hash::FxHashSet;
+use serde::Serialize;
 use uv_cache::Cache;
+use uv_cli::PythonListFormat;
 use uv_fs::Simplified;
 use uv_python::downloads::PythonDownloadRequest;
 use uv_python::{
     find_python_installations, DiscoveryError, EnvironmentPreference, PythonDownloads,
-    PythonInstallation, PythonNotFound, PythonPreference, PythonRequest, PythonSource,
+    PythonInstallation, PythonInstallationKey, PythonNotFound, PythonPreference, PythonRequest,
+    PythonSource,
 };
 
 use crate::commands::ExitStatus;
@@ -29,6 +33,7 @@ pub(crate) async fn list(
     kinds: PythonListKinds,
     all_versions: bool,
     all_platforms: bool,
+    output_format: PythonListFormat,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
     cache: &Cache,
@@ -61,7 +66,12 @@ pub(crate) async fn list(
             .flatten();
 
         for download in downloads {
-            output.insert((download.key().clone(), Kind::Download, None));
+            output.insert((
+                download.key().clone(),
+                Kind::Download,
+                None,
+                Some(download.url()),
+            ));
         }
     };
 
@@ -93,6 +103,7 @@ pub(crate) async fn list(
             installation.key(),
             kind,
             Some(installation.interpreter().sys_executable().to_path_buf()),
+            None,
         ));
     }
 
@@ -100,7 +111,7 @@ pub(crate) async fn list(
     let mut seen_patch = FxHashSet::default();
     let mut seen_paths = FxHashSet::default();
     let mut include = Vec::new();
-    for (key, kind, path) in output.iter().rev() {
+    for (key, kind, path, url) in output.iter().rev() {
         // Do not show the same path more than once
         if let Some(path) = path {
             if !seen_paths.insert(path) {
@@ -118,6 +129,7 @@ pub(crate) async fn list(
                     key.implementation(),
                     *key.arch(),
                     *key.libc(),
+                    *key.variant(),
                 )) {
                     if matches!(kind, Kind::Download) && !all_versions {
                         continue;
@@ -133,6 +145,7 @@ pub(crate) async fn list(
                     key.implementation(),
                     *key.arch(),
                     key.libc(),
+                    *key.variant(),
                 )) {
                     if matches!(kind, Kind::Download) {
                         continue;
@@ -140,15 +153,24 @@ pub(crate) async fn list(
                 }
             }
         }
-        include.push((key, path));
+        include.push((key, kind, path, url));
+    }
+
+    if matches!(output_format, PythonListFormat::Json) {
+        let entries = include
+            .into_iter()
+            .map(|(key, kind, path, url)| PythonListEntry::new(key, kind, path.as_deref(), *url))
+            .collect::<Result<Vec<_>>>()?;
+        writeln!(printer.stdout(), "{}", serde_json::to_string(&entries)?)?;
+        return Ok(ExitStatus::Success);
     }
 
     // Compute the width of the first column.
     let width = include
         .iter()
-        .fold(0usize, |acc, (key, _)| acc.max(key.to_string().len()));
+        .fold(0usize, |acc, (key, ..)| acc.max(key.to_string().len()));
 
-    for (key, path) in include {
+    for (key, _, path, _) in include {
         let key = key.to_string();
         if let Some(path) = path {
             let is_symlink = fs_err::symlink_metadata(path)?.is_symlink();
@@ -177,3 +199,62 @@ pub(crate) async fn list(
 
     Ok(ExitStatus::Success)
 }
+
+/// A Python installation or download, as displayed by `uv python list --output-format json`.
+#[derive(Debug, Serialize)]
+struct PythonListEntry {
+    key: String,
+    version: String,
+    version_parts: VersionParts,
+    implementation: String,
+    variant: String,
+    os: String,
+    arch: String,
+    libc: String,
+    managed: bool,
+    path: Option<String>,
+    symlink: Option<String>,
+    url: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct VersionParts {
+    major: u8,
+    minor: u8,
+    patch: Option<u8>,
+}
+
+impl PythonListEntry {
+    fn new(
+        key: &PythonInstallationKey,
+        kind: &Kind,
+        path: Option<&Path>,
+        url: Option<&str>,
+    ) -> Result<Self> {
+        let version = key.version();
+        let symlink = match path {
+            Some(path) if fs_err::symlink_metadata(path)?.is_symlink() => {
+                Some(path.read_link()?.simplified_display().to_string())
+            }
+            _ => None,
+        };
+        Ok(Self {
+            key: key.to_string(),
+            version: version.to_string(),
+            version_parts: VersionParts {
+                major: version.major(),
+                minor: version.minor(),
+                patch: version.patch(),
+            },
+            implementation: key.implementation().to_string(),
+            variant: key.variant().to_string(),
+            os: key.os().to_string(),
+            arch: key.arch().to_string(),
+            libc: key.libc().to_string(),
+            managed: matches!(kind, Kind::Managed),
+            path: path.map(|path| path.simplified_display().to_string()),
+            symlink,
+            url: url.map(ToString::to_string),
+        })
+    }
+}
@@ -9,8 +9,8 @@ use tracing::debug;
 use uv_cache::Cache;
 use uv_fs::Simplified;
 use uv_python::{
-    EnvironmentPreference, PythonInstallation, PythonPreference, PythonRequest, PythonVersionFile,
-    PYTHON_VERSION_FILENAME,
+    EnvironmentPreference, PythonInstallation, PythonPreference, PythonRequest, PythonVariant,
+    PythonVersionFile, PYTHON_VERSION_FILENAME,
 };
 use uv_warnings::warn_user_once;
 use uv_workspace::{DiscoveryOptions, VirtualProject};
@@ -23,6 +23,7 @@ pub(crate) async fn pin(
     project_dir: &Path,
     request: Option<String>,
     resolved: bool,
+    variant: Option<PythonVariant>,
     python_preference: PythonPreference,
     no_project: bool,
     cache: &Cache,
@@ -61,6 +62,10 @@ pub(crate) async fn pin(
         bail!("No pinned Python version found")
     };
     let request = PythonRequest::parse(&request);
+    let request = match variant {
+        Some(variant) => request.with_variant(variant),
+        None => request,
+    };
 
     let python = match PythonInstallation::find(
         &request,
@@ -159,6 +159,14 @@ async fn do_uninstall(
         debug!("Removed {}", executable.user_display());
     }
 
+    // Remove any PEP 514 registry entries, i.e., from `uv python install --registry`.
+    #[cfg(windows)]
+    for installation in &matching_installations {
+        if let Err(err) = installation.unregister_windows_registry() {
+            warn!("{err}");
+        }
+    }
+
     let mut tasks = FuturesUnordered::new();
     for installation in &matching_installations {
         tasks.push(async {
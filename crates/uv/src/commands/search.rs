@@ -0,0 +1,291 @@
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+use anyhow::{bail, Result};
+use futures::StreamExt;
+use owo_colors::OwoColorize;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_cli::SearchFormat;
+use uv_client::{Connectivity, OwnedArchive, RegistryClient, RegistryClientBuilder};
+use uv_distribution_types::{Index, IndexCapabilities, IndexUrl};
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_warnings::warn_user;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+use crate::settings::ResolverSettings;
+
+/// Search the configured indexes for packages whose names start with the given query.
+pub(crate) async fn search(
+    query: &str,
+    limit: usize,
+    format: SearchFormat,
+    settings: &ResolverSettings,
+    connectivity: Connectivity,
+    native_tls: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let prefix = normalize(query);
+    if prefix.is_empty() {
+        bail!("The search query must contain at least one letter or digit");
+    }
+
+    // Add all authenticated sources to the cache.
+    let indexes = settings.index_locations.allowed_indexes();
+    for index in &indexes {
+        if let Some(credentials) = index.credentials() {
+            uv_auth::store_credentials(index.raw_url(), credentials);
+        } else if let Some(secrets) = index.secret_credentials() {
+            uv_auth::store_secret_credentials(secrets);
+        }
+    }
+
+    let client = RegistryClientBuilder::new(cache.clone())
+        .connectivity(connectivity)
+        .native_tls(native_tls)
+        .keyring(settings.keyring_provider)
+        .allow_insecure_host(settings.allow_insecure_host.clone())
+        .index_urls(settings.index_locations.index_urls())
+        .build();
+
+    // Match against the project list of each index, in priority order, such that a package
+    // that's available on multiple indexes is attributed to the first.
+    let mut seen = BTreeSet::new();
+    let mut matches = Vec::new();
+    let mut searched = 0;
+    for index in indexes {
+        let names = match client.project_names(index.url()).await {
+            Ok(names) => names,
+            Err(err) => {
+                warn_user!(
+                    "Failed to list the packages available on `{}`: {err}",
+                    index.url().redacted()
+                );
+                continue;
+            }
+        };
+        searched += 1;
+        for name in names {
+            if name.as_ref().starts_with(&prefix) && seen.insert(name.clone()) {
+                matches.push((name, index));
+            }
+        }
+    }
+    if searched == 0 {
+        bail!("Failed to search the configured indexes");
+    }
+
+    // Rank an exact match first, followed by the shortest names.
+    matches.sort_by(|(a, _), (b, _)| {
+        (a.as_ref() != prefix)
+            .cmp(&(b.as_ref() != prefix))
+            .then_with(|| a.as_ref().len().cmp(&b.as_ref().len()))
+            .then_with(|| a.cmp(b))
+    });
+    let total = matches.len();
+    matches.truncate(limit);
+
+    // Fetch the latest version (and, where available, the summary) of each match.
+    let capabilities = IndexCapabilities::default();
+    let results = futures::stream::iter(matches)
+        .map(|(name, index)| {
+            let client = &client;
+            let capabilities = &capabilities;
+            async move {
+                let details = describe(&name, index, client, capabilities).await;
+                SearchResult {
+                    name,
+                    version: details.version,
+                    summary: details.summary,
+                    index: index.url().redacted().to_string(),
+                }
+            }
+        })
+        .buffered(8)
+        .collect::<Vec<_>>()
+        .await;
+
+    match format {
+        SearchFormat::Text => {
+            if results.is_empty() {
+                writeln!(
+                    printer.stderr(),
+                    "No packages found matching `{}`",
+                    query.cyan()
+                )?;
+                return Ok(ExitStatus::Success);
+            }
+
+            let labels = results
+                .iter()
+                .map(|result| match &result.version {
+                    Some(version) => format!("{} ({version})", result.name),
+                    None => result.name.to_string(),
+                })
+                .collect::<Vec<_>>();
+            let width = labels.iter().map(String::len).max().unwrap_or(0);
+            for (label, result) in labels.iter().zip(&results) {
+                match &result.summary {
+                    Some(summary) => writeln!(
+                        printer.stdout(),
+                        "{}  {}",
+                        format!("{label:width$}").bold(),
+                        summary.dimmed()
+                    )?,
+                    None => writeln!(printer.stdout(), "{}", label.bold())?,
+                }
+            }
+
+            if total > results.len() {
+                writeln!(
+                    printer.stderr(),
+                    "Showing {} of {total} matching packages (use `--limit` to show more)",
+                    results.len()
+                )?;
+            }
+        }
+        SearchFormat::Json => {
+            writeln!(
+                printer.stdout(),
+                "{}",
+                serde_json::to_string_pretty(&results)?
+            )?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// A package that matched the search query.
+#[derive(Debug, Serialize)]
+struct SearchResult {
+    name: PackageName,
+    /// The latest version of the package, preferring stable releases.
+    version: Option<Version>,
+    /// The summary of the package, if the index exposes it.
+    summary: Option<String>,
+    /// The index on which the package was found.
+    index: String,
+}
+
+/// The details of a package, as retrieved from an index.
+#[derive(Debug, Default)]
+struct Details {
+    version: Option<Version>,
+    summary: Option<String>,
+}
+
+/// Retrieve the latest version and summary of a package.
+///
+/// For PyPI, the details are read from its JSON API; for other indexes, the latest version is
+/// determined from the Simple API, which doesn't include a summary.
+async fn describe(
+    name: &PackageName,
+    index: &Index,
+    client: &RegistryClient,
+    capabilities: &IndexCapabilities,
+) -> Details {
+    if let IndexUrl::Pypi(_) = index.url() {
+        match describe_pypi(name, index.url(), client).await {
+            Ok(details) => return details,
+            Err(err) => debug!("Failed to fetch details for {name} from the PyPI JSON API: {err}"),
+        }
+    }
+
+    let response = match client.simple(name, Some(index.url()), capabilities).await {
+        Ok(response) => response,
+        Err(err) => {
+            debug!(
+                "Failed to fetch details for {name} from {}: {err}",
+                index.url()
+            );
+            return Details::default();
+        }
+    };
+    let Some((_, metadata)) = response.first() else {
+        return Details::default();
+    };
+
+    // Ignore versions for which every file has been yanked.
+    let versions = OwnedArchive::deserialize(metadata)
+        .into_iter()
+        .filter_map(|metadatum| {
+            metadatum
+                .files
+                .all()
+                .any(|(_, file)| !file.yanked.is_some_and(|yanked| yanked.is_yanked()))
+                .then_some(metadatum.version)
+        })
+        .collect::<Vec<_>>();
+    let version = versions
+        .iter()
+        .filter(|version| !version.any_prerelease())
+        .max()
+        .or_else(|| versions.iter().max())
+        .cloned();
+
+    Details {
+        version,
+        summary: None,
+    }
+}
+
+/// Retrieve the details of a package from the PyPI JSON API.
+async fn describe_pypi(
+    name: &PackageName,
+    index: &IndexUrl,
+    client: &RegistryClient,
+) -> Result<Details> {
+    /// The subset of a response from `https://pypi.org/pypi/<name>/json` that's used for search.
+    #[derive(Debug, Deserialize)]
+    struct Project {
+        info: ProjectInfo,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct ProjectInfo {
+        version: Version,
+        summary: Option<String>,
+    }
+
+    let mut url = index.url().clone();
+    url.set_path(&format!("pypi/{name}/json"));
+    let project = client
+        .uncached_client(&url)
+        .get(url.clone())
+        .send()
+        .await?
+        .error_for_status()?
+        .json::<Project>()
+        .await?;
+
+    Ok(Details {
+        version: Some(project.info.version),
+        summary: project
+            .info
+            .summary
+            .map(|summary| summary.trim().to_string())
+            .filter(|summary| !summary.is_empty()),
+    })
+}
+
+/// Normalize a search query in the same manner as a package name, such that (e.g.) `Zope_` matches
+/// `zope-interface`.
+fn normalize(query: &str) -> String {
+    let mut normalized = String::with_capacity(query.len());
+    for c in query.trim().chars() {
+        if matches!(c, '-' | '_' | '.') {
+            if !normalized.is_empty() && !normalized.ends_with('-') {
+                normalized.push('-');
+            }
+        } else {
+            normalized.push(c.to_ascii_lowercase());
+        }
+    }
+    normalized
+}
@@ -0,0 +1,475 @@
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+#[cfg(feature = "self-update")]
+use axoupdater::AxoUpdater;
+
+use uv_cache::Cache;
+use uv_fs::Simplified;
+use uv_python::managed::ManagedPythonInstallations;
+use uv_shell::Shell;
+use uv_static::EnvVars;
+use uv_tool::{tool_executable_dir, InstalledTools};
+
+use crate::commands::{cache_clean, python_uninstall, tool_uninstall, ExitStatus};
+use crate::printer::Printer;
+
+/// The binaries distributed by the standalone installer.
+const BINARIES: &[&str] = &["uv", "uvx", "uvw"];
+
+/// The shell configuration files that the standalone installer modifies to source its `env`
+/// script.
+const INSTALLER_RC_FILES: &[&str] = &[
+    ".profile",
+    ".bashrc",
+    ".bash_profile",
+    ".bash_login",
+    ".zshrc",
+    ".zshenv",
+];
+
+/// Uninstall uv, removing its binaries, tools, cache, and shell integration.
+pub(crate) async fn self_uninstall(
+    managed_python: bool,
+    dry_run: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    // Determine whether the current binary was installed via the standalone installer, in which
+    // case we're responsible for removing the binaries themselves.
+    let install_dir = installer_install_dir()?;
+    let home_dir = home::home_dir();
+
+    // Remove any installed tools, along with their executables.
+    let installed_tools = InstalledTools::from_settings()?;
+    let tools = installed_tools.tools()?;
+
+    // Track the executables that we're about to remove, to determine whether the directories that
+    // contain them are shared with other tools.
+    #[cfg(windows)]
+    let removed = tools
+        .iter()
+        .flat_map(|(_, tool)| tool.iter().flat_map(|tool| tool.entrypoints()))
+        .map(|entrypoint| entrypoint.install_path.clone())
+        .chain(install_dir.iter().flat_map(|install_dir| {
+            BINARIES
+                .iter()
+                .map(|name| install_dir.join(format!("{name}{}", std::env::consts::EXE_SUFFIX)))
+        }))
+        .collect::<BTreeSet<_>>();
+    if dry_run {
+        for (name, tool) in &tools {
+            writeln!(printer.stderr(), "Would uninstall tool: {}", name.cyan())?;
+            for entrypoint in tool.iter().flat_map(|tool| tool.entrypoints()) {
+                writeln!(
+                    printer.stderr(),
+                    "Would remove executable: {}",
+                    entrypoint.install_path.simplified_display().cyan()
+                )?;
+            }
+        }
+    } else if !tools.is_empty() {
+        tool_uninstall(Vec::new(), printer).await?;
+    }
+
+    // Remove any managed Python installations, if requested.
+    let installations = ManagedPythonInstallations::from_settings()?;
+    let pythons = installations.find_all()?.collect_vec();
+    if !managed_python {
+        if !pythons.is_empty() {
+            let s = if pythons.len() == 1 { "" } else { "s" };
+            writeln!(
+                printer.stderr(),
+                "{}",
+                format!(
+                    "Keeping {} (use `--managed-python` to remove them)",
+                    format!("{} managed Python installation{s}", pythons.len()).bold()
+                )
+                .dimmed()
+            )?;
+        }
+    } else if dry_run {
+        for installation in &pythons {
+            writeln!(
+                printer.stderr(),
+                "Would uninstall Python: {} ({})",
+                installation.key().cyan(),
+                installation.path().simplified_display().dimmed()
+            )?;
+        }
+    } else if !pythons.is_empty() {
+        python_uninstall(Vec::new(), true, printer).await?;
+    }
+
+    // Remove the `PATH` updates from the shell configuration files.
+    for (path, contents) in
+        shell_configuration_updates(install_dir.as_deref(), home_dir.as_deref())?
+    {
+        if dry_run {
+            writeln!(
+                printer.stderr(),
+                "Would update configuration file: {}",
+                path.simplified_display().cyan()
+            )?;
+        } else {
+            fs_err::write(&path, contents)?;
+            writeln!(
+                printer.stderr(),
+                "Updated configuration file: {}",
+                path.simplified_display().cyan()
+            )?;
+        }
+    }
+
+    // Remove the `PATH` entries from the Windows registry.
+    #[cfg(windows)]
+    {
+        let directories = std::iter::once(tool_executable_dir()?)
+            .chain(install_dir.clone())
+            .collect::<BTreeSet<_>>();
+        for directory in directories {
+            // Directories like `~/.local/bin` are commonly shared with other tools, so leave them
+            // on the `PATH` unless they only contain executables installed by uv.
+            if is_shared(&directory, &removed)? {
+                debug!(
+                    "Keeping shared directory on PATH: {}",
+                    directory.simplified_display()
+                );
+                continue;
+            }
+            if dry_run {
+                writeln!(
+                    printer.stderr(),
+                    "Would remove from PATH: {}",
+                    directory.simplified_display().cyan()
+                )?;
+            } else if uv_shell::windows::remove_path(&directory)? {
+                writeln!(
+                    printer.stderr(),
+                    "Removed from PATH: {}",
+                    directory.simplified_display().cyan()
+                )?;
+            }
+        }
+    }
+
+    // Clear the cache.
+    if dry_run {
+        if cache.root().exists() {
+            writeln!(
+                printer.stderr(),
+                "Would remove cache: {}",
+                cache.root().simplified_display().cyan()
+            )?;
+        }
+    } else {
+        cache_clean(&[], false, cache, printer)?;
+    }
+
+    let Some(install_dir) = install_dir else {
+        let current_exe = std::env::current_exe()?;
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format_args!(
+                concat!(
+                    "{}{} uv was not installed via the standalone installation scripts, so the uv binary at {} was not removed.",
+                    "\n",
+                    "\n",
+                    "If you installed uv with pip, brew, or another package manager, remove uv with `pip uninstall`, `brew uninstall`, or similar."
+                ),
+                "warning".yellow().bold(),
+                ":".bold(),
+                current_exe.simplified_display().cyan()
+            )
+        )?;
+        return Ok(ExitStatus::Success);
+    };
+
+    // Remove the files created by the standalone installer: the `env` scripts, the Fish
+    // configuration that sources them, and the install receipt.
+    let mut files = vec![install_dir.join("env"), install_dir.join("env.fish")];
+    files.extend(
+        home_dir
+            .as_deref()
+            .map(|home_dir| home_dir.join(".config/fish/conf.d/uv.env.fish")),
+    );
+    files.extend(receipt_path());
+    for file in files.into_iter().filter(|file| file.is_file()) {
+        if dry_run {
+            writeln!(
+                printer.stderr(),
+                "Would remove file: {}",
+                file.simplified_display().cyan()
+            )?;
+        } else {
+            fs_err::remove_file(&file)?;
+            writeln!(
+                printer.stderr(),
+                "Removed file: {}",
+                file.simplified_display().cyan()
+            )?;
+        }
+    }
+
+    // Finally, remove the binaries, including the one that's currently running.
+    let current_exe = std::env::current_exe()?;
+    let binaries = BINARIES
+        .iter()
+        .map(|name| install_dir.join(format!("{name}{}", std::env::consts::EXE_SUFFIX)))
+        .filter(|path| path.is_file());
+    for binary in binaries {
+        if dry_run {
+            writeln!(
+                printer.stderr(),
+                "Would remove executable: {}",
+                binary.simplified_display().cyan()
+            )?;
+            continue;
+        }
+
+        if same_file::is_same_file(&binary, &current_exe).unwrap_or(false) {
+            // On Windows, a running executable can't be removed directly.
+            self_replace::self_delete().with_context(|| {
+                format!("Failed to remove executable: {}", binary.user_display())
+            })?;
+        } else {
+            fs_err::remove_file(&binary)?;
+        }
+        writeln!(
+            printer.stderr(),
+            "Removed executable: {}",
+            binary.simplified_display().cyan()
+        )?;
+    }
+
+    if !dry_run {
+        writeln!(printer.stderr(), "Restart your shell to apply changes")?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Returns `true` if the given directory contains any files other than those that are being
+/// removed.
+#[cfg(windows)]
+fn is_shared(directory: &Path, removed: &BTreeSet<PathBuf>) -> Result<bool> {
+    let entries = match fs_err::read_dir(directory) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(false),
+        Err(err) => return Err(err.into()),
+    };
+    for entry in entries {
+        let path = entry?.path();
+        if !removed.contains(&path) {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Return the directory into which uv was installed by the standalone installer, if the current
+/// binary was installed that way.
+#[cfg(feature = "self-update")]
+fn installer_install_dir() -> Result<Option<PathBuf>> {
+    let mut updater = AxoUpdater::new_for("uv");
+    updater.disable_installer_output();
+
+    // Load the "install receipt" for the current binary. If the receipt is not found, then
+    // uv was likely installed via a package manager.
+    let Ok(updater) = updater.load_receipt() else {
+        debug!("no receipt found; assuming uv was installed via a package manager");
+        return Ok(None);
+    };
+
+    // Ensure the receipt is for the current binary. If it's not, then the user likely has multiple
+    // uv binaries installed, and the current binary was _not_ installed via the standalone
+    // installation scripts.
+    if !updater.check_receipt_is_for_this_executable()? {
+        debug!(
+            "receipt is not for this executable; assuming uv was installed via a package manager"
+        );
+        return Ok(None);
+    }
+
+    let current_exe = std::env::current_exe()?;
+    Ok(current_exe.parent().map(Path::to_path_buf))
+}
+
+/// Return the directory into which uv was installed by the standalone installer.
+///
+/// Without the `self-update` feature, uv can't read the install receipt, and so assumes that it
+/// was installed via a package manager.
+#[cfg(not(feature = "self-update"))]
+#[allow(clippy::unnecessary_wraps)]
+fn installer_install_dir() -> Result<Option<PathBuf>> {
+    debug!("no receipt support; assuming uv was installed via a package manager");
+    Ok(None)
+}
+
+/// Return the path to the install receipt written by the standalone installer.
+fn receipt_path() -> Option<PathBuf> {
+    let config_dir = if cfg!(windows) {
+        std::env::var_os(EnvVars::LOCALAPPDATA).map(PathBuf::from)
+    } else {
+        std::env::var_os(EnvVars::XDG_CONFIG_HOME)
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| home::home_dir().map(|home_dir| home_dir.join(".config")))
+    }?;
+    Some(config_dir.join("uv").join("uv-receipt.json"))
+}
+
+/// Return the shell configuration files that contain `PATH` updates made by uv, along with their
+/// contents after removing those updates.
+///
+/// This includes the updates made by `uv tool update-shell` and, if uv was installed via the
+/// standalone installer into `install_dir`, the lines that source the installer's `env` script.
+fn shell_configuration_updates(
+    install_dir: Option<&Path>,
+    home_dir: Option<&Path>,
+) -> Result<Vec<(PathBuf, String)>> {
+    // Collect the blocks of lines that uv may have added to each file.
+    let mut blocks: Vec<(PathBuf, String)> = Vec::new();
+
+    // `uv tool update-shell` adds a `# uv` comment followed by the command to update `PATH`.
+    let executable_directory = tool_executable_dir()?;
+    for shell in [Shell::Bash, Shell::Zsh, Shell::Fish, Shell::Csh, Shell::Ksh] {
+        let Some(command) = shell.prepend_path(&executable_directory) else {
+            continue;
+        };
+        for file in shell.configuration_files() {
+            blocks.push((file, format!("# uv\n{command}")));
+        }
+    }
+
+    // The standalone installer sources its `env` script from each shell configuration file.
+    if let (Some(install_dir), Some(home_dir)) = (install_dir, home_dir) {
+        let env_script = install_dir.join("env");
+        let expressions = [
+            env_script.simplified_display().to_string(),
+            env_script
+                .strip_prefix(home_dir)
+                .map(|path| format!("$HOME/{}", path.portable_display()))
+                .unwrap_or_default(),
+        ];
+        let mut directories = vec![home_dir.to_path_buf()];
+        directories.extend(
+            std::env::var_os(EnvVars::ZDOTDIR)
+                .filter(|dir| !dir.is_empty())
+                .map(PathBuf::from),
+        );
+        for directory in &directories {
+            for file in INSTALLER_RC_FILES {
+                for expression in expressions
+                    .iter()
+                    .filter(|expression| !expression.is_empty())
+                {
+                    blocks.push((directory.join(file), format!(". \"{expression}\"")));
+                }
+            }
+        }
+    }
+
+    // Remove the blocks from each file, in turn.
+    let files = blocks
+        .iter()
+        .map(|(file, _)| file.clone())
+        .collect::<BTreeSet<_>>();
+    let mut updates = Vec::new();
+    for file in files {
+        let contents = match fs_err::read_to_string(&file) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(err) => return Err(err.into()),
+        };
+        let mut updated = contents.clone();
+        for (_, block) in blocks.iter().filter(|(path, _)| *path == file) {
+            if let Some(removed) = remove_block(&updated, block) {
+                updated = removed;
+            }
+        }
+        if updated != contents {
+            debug!(
+                "Removing uv `PATH` updates from: {}",
+                file.simplified_display()
+            );
+            updates.push((file, updated));
+        }
+    }
+
+    Ok(updates)
+}
+
+/// Remove every occurrence of the given block of lines from `contents`, along with the blank line
+/// that precedes it, if any.
+///
+/// Returns `None` if the block is not present.
+fn remove_block(contents: &str, block: &str) -> Option<String> {
+    let lines = contents.lines().collect::<Vec<_>>();
+    let block = block.lines().collect::<Vec<_>>();
+
+    let mut output: Vec<&str> = Vec::with_capacity(lines.len());
+    let mut found = false;
+    let mut index = 0;
+    while index < lines.len() {
+        if lines[index..].starts_with(&block) {
+            // Both `uv tool update-shell` and the standalone installer prepend a newline.
+            if output.last().is_some_and(|line| line.is_empty()) {
+                output.pop();
+            }
+            index += block.len();
+            found = true;
+        } else {
+            output.push(lines[index]);
+            index += 1;
+        }
+    }
+
+    found.then(|| {
+        let mut contents = String::with_capacity(contents.len());
+        for line in output {
+            contents.push_str(line);
+            contents.push('\n');
+        }
+        contents
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::remove_block;
+
+    #[test]
+    fn remove_blocks() {
+        let block = "# uv\nexport PATH=\"/home/ferris/.local/bin:$PATH\"";
+
+        // Appended to an existing file by `uv tool update-shell`.
+        let contents = "alias ll='ls -l'\n\n# uv\nexport PATH=\"/home/ferris/.local/bin:$PATH\"\n";
+        assert_eq!(
+            remove_block(contents, block).as_deref(),
+            Some("alias ll='ls -l'\n")
+        );
+
+        // Written to a new file by `uv tool update-shell`.
+        let contents = "# uv\nexport PATH=\"/home/ferris/.local/bin:$PATH\"\n";
+        assert_eq!(remove_block(contents, block).as_deref(), Some(""));
+
+        // Unrelated lines are left untouched.
+        let contents = "export PATH=\"/home/ferris/.local/bin:$PATH\"\nalias ll='ls -l'\n";
+        assert_eq!(remove_block(contents, block), None);
+
+        // Lines that source the standalone installer's `env` script.
+        let contents = "alias ll='ls -l'\n\n. \"$HOME/.local/bin/env\"\n";
+        assert_eq!(
+            remove_block(contents, ". \"$HOME/.local/bin/env\"").as_deref(),
+            Some("alias ll='ls -l'\n")
+        );
+    }
+}
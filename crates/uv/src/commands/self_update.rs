@@ -1,11 +1,16 @@
 use std::fmt::Write;
+use std::str::FromStr;
 
 use anyhow::Result;
 use axoupdater::{AxoUpdater, AxoupdateError, UpdateRequest};
 use owo_colors::OwoColorize;
+use serde::Deserialize;
 use tracing::debug;
 
-use uv_client::WrappedReqwestError;
+use url::Url;
+use uv_cli::SelfUpdateChannel;
+use uv_client::{BaseClient, BaseClientBuilder, Connectivity, WrappedReqwestError};
+use uv_pep440::{Version, VersionSpecifiers};
 
 use crate::commands::ExitStatus;
 use crate::printer::Printer;
@@ -13,9 +18,17 @@ use crate::printer::Printer;
 /// Attempt to update the uv binary.
 pub(crate) async fn self_update(
     version: Option<String>,
+    channel: SelfUpdateChannel,
     token: Option<String>,
+    connectivity: Connectivity,
+    native_tls: bool,
     printer: Printer,
 ) -> Result<ExitStatus> {
+    let target = version
+        .as_deref()
+        .map(TargetVersion::from_str)
+        .transpose()?;
+
     let mut updater = AxoUpdater::new_for("uv");
     updater.disable_installer_output();
 
@@ -78,10 +91,50 @@ pub(crate) async fn self_update(
         )
     )?;
 
-    let update_request = if let Some(version) = version {
-        UpdateRequest::SpecificTag(version)
-    } else {
-        UpdateRequest::Latest
+    let update_request = match target {
+        Some(TargetVersion::Exact(version)) => UpdateRequest::SpecificTag(version),
+        Some(TargetVersion::Range(specifiers)) => {
+            let client = BaseClientBuilder::new()
+                .connectivity(connectivity)
+                .native_tls(native_tls)
+                .build();
+            let releases = match list_releases(&client, token.as_deref()).await {
+                Ok(releases) => releases,
+                Err(err) => {
+                    return if err.status() == Some(http::StatusCode::FORBIDDEN) && token.is_none() {
+                        report_rate_limit(printer)?;
+                        Ok(ExitStatus::Error)
+                    } else {
+                        Err(err.into())
+                    };
+                }
+            };
+
+            let Some(version) = select_release(releases, &specifiers, channel) else {
+                writeln!(
+                    printer.stderr(),
+                    "{}",
+                    format_args!(
+                        "{}{} No {} release of uv matches {}",
+                        "error".red().bold(),
+                        ":".bold(),
+                        match channel {
+                            SelfUpdateChannel::Stable => "stable",
+                            SelfUpdateChannel::Preview => "preview",
+                        },
+                        format!("`{specifiers}`").bold()
+                    )
+                )?;
+                return Ok(ExitStatus::Error);
+            };
+
+            debug!("Resolved `{specifiers}` to uv {version}");
+            UpdateRequest::SpecificVersion(version.to_string())
+        }
+        None => match channel {
+            SelfUpdateChannel::Stable => UpdateRequest::Latest,
+            SelfUpdateChannel::Preview => UpdateRequest::LatestMaybePrerelease,
+        },
     };
 
     updater.configure_version_specifier(update_request);
@@ -131,16 +184,7 @@ pub(crate) async fn self_update(
         Err(err) => {
             return if let AxoupdateError::Reqwest(err) = err {
                 if err.status() == Some(http::StatusCode::FORBIDDEN) && token.is_none() {
-                    writeln!(
-                        printer.stderr(),
-                        "{}",
-                        format_args!(
-                            "{}{} GitHub API rate limit exceeded. Please provide a GitHub token via the {} option.",
-                            "error".red().bold(),
-                            ":".bold(),
-                            "`--token`".green().bold()
-                        )
-                    )?;
+                    report_rate_limit(printer)?;
                     Ok(ExitStatus::Error)
                 } else {
                     Err(WrappedReqwestError::from(err).into())
@@ -153,3 +197,195 @@ pub(crate) async fn self_update(
 
     Ok(ExitStatus::Success)
 }
+
+/// Report that the GitHub API rate limit was exceeded.
+fn report_rate_limit(printer: Printer) -> Result<()> {
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format_args!(
+            "{}{} GitHub API rate limit exceeded. Please provide a GitHub token via the {} option.",
+            "error".red().bold(),
+            ":".bold(),
+            "`--token`".green().bold()
+        )
+    )?;
+    Ok(())
+}
+
+/// The version requested by `uv self update`.
+#[derive(Debug)]
+enum TargetVersion {
+    /// An exact version, like `0.5.1`, which is used as the release tag.
+    Exact(String),
+    /// A range of versions, like `0.5.x` or `>=0.5.0,<0.6.0`.
+    Range(VersionSpecifiers),
+}
+
+impl FromStr for TargetVersion {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        // PEP 440 specifiers, like `>=0.5.0,<0.6.0`.
+        if s.contains(['<', '>', '=', '~', '!', ',']) {
+            return Ok(Self::Range(VersionSpecifiers::from_str(s)?));
+        }
+
+        // Wildcards, like `0.5.x` or `0.5.*`, which match any version with the given prefix.
+        if let Some(wildcard) = s
+            .split('.')
+            .position(|segment| matches!(segment, "x" | "X" | "*"))
+        {
+            let prefix = s.split('.').take(wildcard).collect::<Vec<_>>();
+            if prefix.is_empty() {
+                anyhow::bail!(
+                    "Invalid version range `{s}`: expected a version prefix, like `0.5.x`"
+                );
+            }
+            let specifier = format!("=={}.*", prefix.join("."));
+            return Ok(Self::Range(VersionSpecifiers::from_str(&specifier)?));
+        }
+
+        Ok(Self::Exact(s.to_string()))
+    }
+}
+
+/// A uv release, as returned by the GitHub API.
+#[derive(Debug, Deserialize)]
+struct Release {
+    tag_name: String,
+    prerelease: bool,
+    draft: bool,
+}
+
+/// List the releases of uv on GitHub.
+async fn list_releases(
+    client: &BaseClient,
+    token: Option<&str>,
+) -> Result<Vec<Release>, WrappedReqwestError> {
+    const PER_PAGE: usize = 100;
+
+    let url = Url::parse("https://api.github.com/repos/astral-sh/uv/releases")
+        .expect("URL should be valid");
+
+    let mut releases = Vec::new();
+    for page in 1.. {
+        let mut request = client
+            .for_host(&url)
+            .get(url.clone())
+            .query(&[("per_page", PER_PAGE), ("page", page)]);
+        if let Some(token) = token {
+            request = request.bearer_auth(token);
+        }
+        let response = request.send().await?.error_for_status()?;
+        let page = response.json::<Vec<Release>>().await?;
+        let done = page.len() < PER_PAGE;
+        releases.extend(page);
+        if done {
+            break;
+        }
+    }
+
+    Ok(releases)
+}
+
+/// Select the latest release of uv that satisfies the specifiers, on the requested channel.
+fn select_release(
+    releases: Vec<Release>,
+    specifiers: &VersionSpecifiers,
+    channel: SelfUpdateChannel,
+) -> Option<Version> {
+    releases
+        .into_iter()
+        .filter(|release| !release.draft)
+        .filter(|release| channel == SelfUpdateChannel::Preview || !release.prerelease)
+        .filter_map(|release| Version::from_str(&release.tag_name).ok())
+        .filter(|version| channel == SelfUpdateChannel::Preview || !version.any_prerelease())
+        .filter(|version| specifiers.contains(version))
+        .max()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use uv_cli::SelfUpdateChannel;
+    use uv_pep440::{Version, VersionSpecifiers};
+
+    use super::{select_release, Release, TargetVersion};
+
+    #[test]
+    fn target_version() {
+        let TargetVersion::Exact(version) = TargetVersion::from_str("0.5.1").unwrap() else {
+            panic!("expected an exact version");
+        };
+        assert_eq!(version, "0.5.1");
+
+        let TargetVersion::Range(specifiers) = TargetVersion::from_str("0.5.x").unwrap() else {
+            panic!("expected a range");
+        };
+        assert_eq!(specifiers.to_string(), "==0.5.*");
+
+        let TargetVersion::Range(specifiers) = TargetVersion::from_str("1.*").unwrap() else {
+            panic!("expected a range");
+        };
+        assert_eq!(specifiers.to_string(), "==1.*");
+
+        let TargetVersion::Range(specifiers) = TargetVersion::from_str(">=0.5.0, <0.6.0").unwrap()
+        else {
+            panic!("expected a range");
+        };
+        assert_eq!(specifiers.to_string(), ">=0.5.0, <0.6.0");
+
+        assert!(TargetVersion::from_str("x").is_err());
+    }
+
+    #[test]
+    fn select() {
+        let release = |tag_name: &str, prerelease: bool, draft: bool| Release {
+            tag_name: tag_name.to_string(),
+            prerelease,
+            draft,
+        };
+        let releases = || {
+            vec![
+                release("0.4.30", false, false),
+                release("0.5.0", false, false),
+                release("0.5.2", false, false),
+                release("0.5.3", false, true),
+                release("0.5.4rc1", true, false),
+                release("0.6.0", false, false),
+                release("not-a-version", false, false),
+            ]
+        };
+
+        // The latest published, stable release in the range is selected.
+        let specifiers = VersionSpecifiers::from_str("==0.5.*").unwrap();
+        assert_eq!(
+            select_release(releases(), &specifiers, SelfUpdateChannel::Stable),
+            Some(Version::from_str("0.5.2").unwrap())
+        );
+
+        // Prereleases are only selected on the preview channel, and drafts never are.
+        assert_eq!(
+            select_release(releases(), &specifiers, SelfUpdateChannel::Preview),
+            Some(Version::from_str("0.5.4rc1").unwrap())
+        );
+
+        // The upper bound is respected.
+        let specifiers = VersionSpecifiers::from_str(">=0.4.0, <0.6.0").unwrap();
+        assert_eq!(
+            select_release(releases(), &specifiers, SelfUpdateChannel::Stable),
+            Some(Version::from_str("0.5.2").unwrap())
+        );
+
+        // No release matches.
+        let specifiers = VersionSpecifiers::from_str("==0.7.*").unwrap();
+        assert_eq!(
+            select_release(releases(), &specifiers, SelfUpdateChannel::Stable),
+            None
+        );
+    }
+}
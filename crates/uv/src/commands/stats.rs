@@ -0,0 +1,220 @@
+use std::fmt::Write;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+use serde::Serialize;
+
+use uv_cache::{BuildActivity, Cache, CacheBucket};
+use uv_cli::StatsFormat;
+use uv_fs::Simplified;
+
+use crate::commands::{human_readable_bytes, ExitStatus};
+use crate::printer::Printer;
+
+/// Summarize the size of the cache and its recent activity.
+pub(crate) fn stats(
+    builds: usize,
+    format: StatsFormat,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if !cache.root().exists() {
+        writeln!(
+            printer.stderr(),
+            "No cache found at: {}",
+            cache.root().user_display().cyan()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    // Measure the size of each bucket.
+    let buckets = CacheBucket::iter()
+        .map(|bucket| {
+            Ok(BucketReport {
+                name: bucket.to_string(),
+                bytes: cache.bucket_size(bucket)?,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // Aggregate the recent activity.
+    let operations = cache.stats()?;
+    let hits = operations
+        .iter()
+        .map(|operation| operation.hits)
+        .sum::<u64>();
+    let misses = operations
+        .iter()
+        .map(|operation| operation.misses)
+        .sum::<u64>();
+    let mut slowest_builds = operations
+        .iter()
+        .flat_map(|operation| operation.builds.iter().cloned())
+        .collect::<Vec<_>>();
+    slowest_builds.sort_by(|a, b| b.duration.total_cmp(&a.duration));
+    slowest_builds.truncate(builds);
+
+    let report = Report {
+        cache_dir: cache.root().simplified_display().to_string(),
+        total_bytes: buckets.iter().map(|bucket| bucket.bytes).sum(),
+        buckets,
+        operations: operations.len(),
+        since: operations
+            .first()
+            .and_then(|operation| i64::try_from(operation.timestamp).ok())
+            .and_then(|timestamp| jiff::Timestamp::from_second(timestamp).ok())
+            .map(|timestamp| timestamp.to_string()),
+        hits,
+        misses,
+        hit_rate: hit_rate(hits, misses),
+        bytes_saved: operations
+            .iter()
+            .map(|operation| operation.bytes_saved)
+            .sum(),
+        slowest_builds,
+    };
+
+    match format {
+        StatsFormat::Text => report.write_text(printer)?,
+        StatsFormat::Json => {
+            writeln!(
+                printer.stdout(),
+                "{}",
+                serde_json::to_string_pretty(&report)?
+            )?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// A summary of the cache's size and recent activity.
+#[derive(Debug, Serialize)]
+struct Report {
+    cache_dir: String,
+    total_bytes: u64,
+    buckets: Vec<BucketReport>,
+    /// The number of recent operations from which activity was aggregated.
+    operations: usize,
+    /// The time at which the oldest of the recent operations completed.
+    since: Option<String>,
+    hits: u64,
+    misses: u64,
+    hit_rate: Option<f64>,
+    bytes_saved: u64,
+    slowest_builds: Vec<BuildActivity>,
+}
+
+/// The size of a single cache bucket.
+#[derive(Debug, Serialize)]
+struct BucketReport {
+    name: String,
+    bytes: u64,
+}
+
+impl Report {
+    /// Write the report as human-readable text.
+    fn write_text(&self, printer: Printer) -> Result<()> {
+        writeln!(
+            printer.stdout(),
+            "{} {}",
+            "Cache directory:".bold(),
+            self.cache_dir.cyan()
+        )?;
+        writeln!(
+            printer.stdout(),
+            "{} {}",
+            "Cache size:".bold(),
+            bytes(self.total_bytes)
+        )?;
+        let width = self
+            .buckets
+            .iter()
+            .map(|bucket| bucket.name.len())
+            .max()
+            .unwrap_or(0);
+        for bucket in self.buckets.iter().filter(|bucket| bucket.bytes > 0) {
+            writeln!(
+                printer.stdout(),
+                "  {:width$}  {}",
+                bucket.name,
+                bytes(bucket.bytes).dimmed()
+            )?;
+        }
+
+        writeln!(printer.stdout())?;
+        let Some(hit_rate) = self.hit_rate else {
+            writeln!(
+                printer.stdout(),
+                "{}",
+                "No recent activity recorded".dimmed()
+            )?;
+            return Ok(());
+        };
+
+        let s = if self.operations == 1 { "" } else { "s" };
+        match self.since.as_deref() {
+            Some(since) => writeln!(
+                printer.stdout(),
+                "{} {}",
+                "Recent activity:".bold(),
+                format!("{} operation{s} since {since}", self.operations).dimmed()
+            )?,
+            None => writeln!(
+                printer.stdout(),
+                "{} {}",
+                "Recent activity:".bold(),
+                format!("{} operation{s}", self.operations).dimmed()
+            )?,
+        }
+        writeln!(
+            printer.stdout(),
+            "  Cache hits:   {} ({:.1}%)",
+            self.hits,
+            hit_rate * 100.0
+        )?;
+        writeln!(
+            printer.stdout(),
+            "  Cache misses: {} ({:.1}%)",
+            self.misses,
+            (1.0 - hit_rate) * 100.0
+        )?;
+        writeln!(
+            printer.stdout(),
+            "  Bytes saved:  {}",
+            bytes(self.bytes_saved)
+        )?;
+
+        if !self.slowest_builds.is_empty() {
+            writeln!(printer.stdout())?;
+            writeln!(printer.stdout(), "{}", "Slowest recent builds:".bold())?;
+            for build in &self.slowest_builds {
+                writeln!(
+                    printer.stdout(),
+                    "  {}{} {}",
+                    build.name.bold(),
+                    format!("=={}", build.version).bold(),
+                    format!("in {:.2}s", build.duration).dimmed()
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Return the fraction of lookups that were cache hits, if any lookups were recorded.
+#[allow(clippy::cast_precision_loss)]
+fn hit_rate(hits: u64, misses: u64) -> Option<f64> {
+    if hits + misses == 0 {
+        None
+    } else {
+        Some(hits as f64 / (hits + misses) as f64)
+    }
+}
+
+/// Format a number of bytes in a human-readable form (e.g., `1.2MiB`).
+fn bytes(bytes: u64) -> String {
+    let (bytes, unit) = human_readable_bytes(bytes);
+    format!("{bytes:.1}{unit}")
+}
@@ -0,0 +1,97 @@
+use std::fmt::Write;
+
+use anyhow::{bail, Result};
+use owo_colors::OwoColorize;
+
+use uv_tool::{InstalledTools, ToolAlias};
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Add an alias for a tool invocation.
+pub(crate) async fn add(
+    name: String,
+    command: Vec<String>,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if name.is_empty() || name.contains(char::is_whitespace) {
+        bail!("Invalid alias name `{name}`: alias names must be non-empty and cannot contain whitespace");
+    }
+    if command.first().is_some_and(|target| target == &name) {
+        bail!("Alias `{}` cannot refer to itself", name.cyan());
+    }
+
+    let installed_tools = InstalledTools::from_settings()?.init()?;
+    let _lock = installed_tools.lock().await?;
+
+    let mut aliases = installed_tools.aliases()?;
+    let alias = ToolAlias::new(command);
+    writeln!(
+        printer.stderr(),
+        "Added alias `{}` for `{}`",
+        name.cyan(),
+        alias.cyan()
+    )?;
+    aliases.insert(name, alias);
+    installed_tools.write_aliases(&aliases)?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Remove one or more aliases.
+pub(crate) async fn remove(names: Vec<String>, printer: Printer) -> Result<ExitStatus> {
+    let installed_tools = InstalledTools::from_settings()?;
+    let _lock = match installed_tools.lock().await {
+        Ok(lock) => lock,
+        Err(uv_tool::Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => {
+            for name in names {
+                writeln!(printer.stderr(), "Alias `{}` is not defined", name.cyan())?;
+            }
+            return Ok(ExitStatus::Failure);
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut aliases = installed_tools.aliases()?;
+    let mut missing = false;
+    for name in names {
+        if aliases.remove(&name).is_some() {
+            writeln!(printer.stderr(), "Removed alias `{}`", name.cyan())?;
+        } else {
+            writeln!(printer.stderr(), "Alias `{}` is not defined", name.cyan())?;
+            missing = true;
+        }
+    }
+    installed_tools.write_aliases(&aliases)?;
+
+    if missing {
+        return Ok(ExitStatus::Failure);
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// List the defined aliases.
+pub(crate) async fn list(printer: Printer) -> Result<ExitStatus> {
+    let installed_tools = InstalledTools::from_settings()?;
+    let _lock = match installed_tools.lock().await {
+        Ok(lock) => lock,
+        Err(uv_tool::Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => {
+            writeln!(printer.stderr(), "No aliases defined")?;
+            return Ok(ExitStatus::Success);
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    let aliases = installed_tools.aliases()?;
+    if aliases.is_empty() {
+        writeln!(printer.stderr(), "No aliases defined")?;
+        return Ok(ExitStatus::Success);
+    }
+
+    for (name, alias) in aliases.iter() {
+        writeln!(printer.stdout(), "{} = {alias}", name.bold())?;
+    }
+
+    Ok(ExitStatus::Success)
+}
@@ -6,19 +6,27 @@ use itertools::Itertools;
 use owo_colors::OwoColorize;
 use tracing::{debug, warn};
 
-use uv_distribution_types::{InstalledDist, Name};
+use uv_configuration::HashCheckingMode;
+use uv_distribution_types::{InstalledDist, Name, Resolution};
 #[cfg(unix)]
 use uv_fs::replace_symlink;
 use uv_fs::Simplified;
 use uv_installer::SitePackages;
-use uv_pep508::PackageName;
-use uv_pypi_types::Requirement;
+use uv_pep440::{VersionSpecifier, VersionSpecifiers};
+use uv_pep508::{MarkerTree, PackageName};
+use uv_pypi_types::{ParsedUrl, Requirement, RequirementSource};
 use uv_python::PythonEnvironment;
+use uv_resolver::ResolutionGraph;
 use uv_settings::ToolOptions;
 use uv_shell::Shell;
-use uv_tool::{entrypoint_paths, tool_executable_dir, InstalledTools, Tool, ToolEntrypoint};
+use uv_tool::{
+    entrypoint_paths, tool_executable_dir, InstalledTools, Tool, ToolEntrypoint,
+    ToolLockedRequirement,
+};
+use uv_types::{HashStrategy, HashStrategyError};
 use uv_warnings::warn_user;
 
+use crate::commands::tool::completions::remove_completions;
 use crate::commands::ExitStatus;
 use crate::printer::Printer;
 
@@ -44,7 +52,7 @@ pub(super) fn matching_packages(name: &str, site_packages: &SitePackages) -> Vec
         .collect()
 }
 
-/// Remove any entrypoints attached to the [`Tool`].
+/// Remove any entrypoints (and shell completion scripts) attached to the [`Tool`].
 pub(crate) fn remove_entrypoints(tool: &Tool) {
     for executable in tool
         .entrypoints()
@@ -59,6 +67,7 @@ pub(crate) fn remove_entrypoints(tool: &Tool) {
             );
         }
     }
+    remove_completions(tool);
 }
 
 /// Installs tool executables for a given package and handles any conflicts.
@@ -70,6 +79,7 @@ pub(crate) fn install_executables(
     force: bool,
     python: Option<String>,
     requirements: Vec<Requirement>,
+    resolution: Option<&ResolutionGraph>,
     printer: Printer,
 ) -> anyhow::Result<ExitStatus> {
     let site_packages = SitePackages::from_environment(environment)?;
@@ -188,6 +198,7 @@ pub(crate) fn install_executables(
         target_entry_points
             .into_iter()
             .map(|(name, _, target_path)| ToolEntrypoint::new(name, target_path)),
+        locked_requirements(resolution, &site_packages).into_iter(),
         options,
     );
     installed_tools.add_tool_receipt(name, tool)?;
@@ -226,6 +237,82 @@ pub(crate) fn install_executables(
     Ok(ExitStatus::Success)
 }
 
+/// Return the locked requirements to record in the tool receipt.
+///
+/// If the environment was synced from a resolution, the locked requirements include the hashes,
+/// markers, and index of each distribution; otherwise, they're inferred from the installed
+/// packages.
+pub(crate) fn locked_requirements(
+    resolution: Option<&ResolutionGraph>,
+    site_packages: &SitePackages,
+) -> Vec<ToolLockedRequirement> {
+    if let Some(resolution) = resolution {
+        resolution
+            .pinned_requirements()
+            .map(|(requirement, hashes)| ToolLockedRequirement::new(requirement, hashes.to_vec()))
+            .collect()
+    } else {
+        site_packages
+            .iter()
+            .map(|dist| ToolLockedRequirement::new(installed_requirement(dist), vec![]))
+            .collect()
+    }
+}
+
+/// Return a [`HashStrategy`] that verifies each distribution in the [`Resolution`] against the
+/// hashes recorded in the tool's locked requirements.
+pub(crate) fn locked_hasher(
+    resolution: &Resolution,
+    locked: &[ToolLockedRequirement],
+) -> Result<HashStrategy, HashStrategyError> {
+    let resolution = Resolution::new(
+        resolution
+            .distributions()
+            .map(|dist| (dist.name().clone(), dist.clone()))
+            .collect(),
+        locked
+            .iter()
+            .filter(|locked| !locked.hashes().is_empty())
+            .map(|locked| (locked.requirement().name.clone(), locked.hashes().to_vec()))
+            .collect(),
+        vec![],
+    );
+    HashStrategy::from_resolution(&resolution, HashCheckingMode::Verify)
+}
+
+/// Return a [`Requirement`] that pins an installed distribution to its exact version (or, for
+/// distributions installed from a URL, its exact source).
+fn installed_requirement(dist: &InstalledDist) -> Requirement {
+    let source = match dist {
+        InstalledDist::Url(dist) => match ParsedUrl::try_from(dist.url.clone()) {
+            Ok(ParsedUrl::Directory(mut directory)) => {
+                directory.editable = dist.editable;
+                Some(RequirementSource::from_verbatim_parsed_url(
+                    ParsedUrl::Directory(directory),
+                ))
+            }
+            Ok(parsed_url) => Some(RequirementSource::from_verbatim_parsed_url(parsed_url)),
+            Err(err) => {
+                debug!("Failed to parse URL for `{}`: {err}", dist.name);
+                None
+            }
+        },
+        _ => None,
+    };
+    Requirement {
+        name: dist.name().clone(),
+        extras: vec![],
+        marker: MarkerTree::TRUE,
+        source: source.unwrap_or_else(|| RequirementSource::Registry {
+            specifier: VersionSpecifiers::from(VersionSpecifier::equals_version(
+                dist.version().clone(),
+            )),
+            index: None,
+        }),
+        origin: None,
+    }
+}
+
 /// Displays a hint if an executable matching the package name can be found in a dependency of the package.
 fn hint_executable_from_dependency(
     name: &PackageName,
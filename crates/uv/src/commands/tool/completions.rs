@@ -0,0 +1,282 @@
+use std::fmt::Write;
+use std::io::Read;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+use anyhow::Context;
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use tracing::{debug, warn};
+
+use uv_distribution_types::Name;
+use uv_fs::Simplified;
+use uv_installer::SitePackages;
+use uv_normalize::PackageName;
+use uv_python::PythonEnvironment;
+use uv_shell::Shell;
+use uv_tool::{InstalledTools, Tool};
+use uv_warnings::warn_user;
+
+use crate::printer::Printer;
+
+/// The maximum amount of time to wait for an executable to emit its completion script.
+const COMPLETION_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A mechanism by which a Python command-line application can generate its own completion script.
+#[derive(Debug, Copy, Clone)]
+enum CompletionGenerator {
+    /// Applications built with Click, which emit a completion script when invoked with
+    /// `_{PROG}_COMPLETE={shell}_source`.
+    Click,
+    /// Applications built with `argcomplete`, whose completion script is emitted by
+    /// `register-python-argcomplete`.
+    Argcomplete,
+}
+
+impl CompletionGenerator {
+    /// Detect the completion generator used by the given tool, if any.
+    ///
+    /// Only the tool's own declared dependencies are considered, such that we never probe an
+    /// executable that doesn't use a supported framework (e.g., a tool that merely pulls in Click
+    /// transitively), since the probe would otherwise run the executable as normal.
+    fn detect(site_packages: &SitePackages, name: &PackageName) -> Option<Self> {
+        let dist = site_packages.get_packages(name).into_iter().next()?;
+        let metadata = match dist.metadata() {
+            Ok(metadata) => metadata,
+            Err(err) => {
+                debug!("Failed to read metadata for `{name}`: {err}");
+                return None;
+            }
+        };
+        let requires = |package: &str| {
+            metadata
+                .requires_dist
+                .iter()
+                .any(|requirement| requirement.name.as_ref() == package)
+        };
+        if requires("click") {
+            Some(Self::Click)
+        } else if requires("argcomplete") {
+            Some(Self::Argcomplete)
+        } else {
+            None
+        }
+    }
+
+    /// Return the [`Command`] that writes the completion script for the given entry point to
+    /// `stdout`.
+    fn command(
+        self,
+        environment: &PythonEnvironment,
+        name: &str,
+        install_path: &Path,
+        shell: &str,
+    ) -> Command {
+        match self {
+            Self::Click => {
+                // See: https://click.palletsprojects.com/en/8.1.x/shell-completion/
+                let variable =
+                    format!("_{}_COMPLETE", name.replace(['-', '.'], "_").to_uppercase());
+                let mut command = Command::new(install_path);
+                command.env(variable, format!("{shell}_source"));
+                command
+            }
+            Self::Argcomplete => {
+                let mut command = Command::new(environment.scripts().join(format!(
+                    "register-python-argcomplete{}",
+                    std::env::consts::EXE_SUFFIX
+                )));
+                command.arg("--shell").arg(shell).arg(name);
+                command
+            }
+        }
+    }
+}
+
+/// Remove any shell completion scripts attached to the [`Tool`].
+pub(crate) fn remove_completions(tool: &Tool) {
+    for completion in tool.completions() {
+        debug!(
+            "Removing completion script: `{}`",
+            completion.simplified_display()
+        );
+        if let Err(err) = fs_err::remove_file(completion) {
+            warn!(
+                "Failed to remove completion script: `{}`: {err}",
+                completion.simplified_display()
+            );
+        }
+    }
+}
+
+/// Generate and install shell completion scripts for the executables of an installed tool,
+/// recording them in the tool receipt.
+///
+/// Completions are generated for the user's current shell, using the completion generator
+/// provided by the tool's command-line framework (Click or `argcomplete`). Failing to generate
+/// completions is not an error, since the tool itself was installed successfully.
+pub(crate) fn install_completions(
+    environment: &PythonEnvironment,
+    name: &PackageName,
+    installed_tools: &InstalledTools,
+    printer: Printer,
+) -> anyhow::Result<()> {
+    let Some(receipt) = installed_tools.get_tool_receipt(name)? else {
+        return Ok(());
+    };
+
+    let Some(shell) = Shell::from_env() else {
+        warn_user!(
+            "Unable to determine the current shell; skipping completions for `{}`",
+            name.cyan()
+        );
+        return Ok(());
+    };
+    let shell_name = match shell {
+        Shell::Bash => "bash",
+        Shell::Zsh => "zsh",
+        Shell::Fish => "fish",
+        _ => {
+            warn_user!(
+                "Installing completions is not supported for {shell}; skipping completions for `{}`",
+                name.cyan()
+            );
+            return Ok(());
+        }
+    };
+
+    let site_packages = SitePackages::from_environment(environment)?;
+    let Some(generator) = CompletionGenerator::detect(&site_packages, name) else {
+        warn_user!(
+            "`{}` does not provide shell completions that uv can generate",
+            name.cyan()
+        );
+        return Ok(());
+    };
+    debug!("Generating {shell} completions for `{name}` with: {generator:?}");
+
+    let mut completions = Vec::new();
+    let mut installed = Vec::new();
+    for entrypoint in receipt.entrypoints() {
+        let Some(target) = shell.completion_file(&entrypoint.name) else {
+            continue;
+        };
+
+        let command = generator.command(
+            environment,
+            &entrypoint.name,
+            &entrypoint.install_path,
+            shell_name,
+        );
+        let script = match run_with_timeout(command, COMPLETION_TIMEOUT) {
+            Ok(Some((status, stdout))) if status.success() && !stdout.trim_ascii().is_empty() => {
+                stdout
+            }
+            Ok(Some((status, _))) => {
+                debug!(
+                    "Failed to generate completions for `{}` ({status})",
+                    entrypoint.name
+                );
+                continue;
+            }
+            Ok(None) => {
+                debug!(
+                    "Timed out generating completions for `{}` after {}s",
+                    entrypoint.name,
+                    COMPLETION_TIMEOUT.as_secs()
+                );
+                continue;
+            }
+            Err(err) => {
+                debug!(
+                    "Failed to generate completions for `{}`: {err}",
+                    entrypoint.name
+                );
+                continue;
+            }
+        };
+
+        debug!(
+            "Installing completion script: `{}`",
+            target.simplified_display()
+        );
+        if let Some(parent) = target.parent() {
+            fs_err::create_dir_all(parent).context("Failed to create completion directory")?;
+        }
+        fs_err::write(&target, script).context("Failed to install completion script")?;
+
+        completions.push(target);
+        installed.push(entrypoint.name.clone());
+    }
+
+    if installed.is_empty() {
+        warn_user!(
+            "`{}` does not provide shell completions that uv can generate",
+            name.cyan()
+        );
+        return Ok(());
+    }
+
+    let s = if installed.len() == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "Installed {shell} completions for {} executable{s}: {}",
+        installed.len(),
+        installed.iter().map(|name| name.bold()).join(", ")
+    )?;
+
+    if shell == Shell::Zsh {
+        if let Some(directory) = completions.first().and_then(|path| path.parent()) {
+            writeln!(
+                printer.stderr(),
+                "To enable the completions, add `{}` to your `fpath` before calling `compinit`",
+                directory.simplified_display().cyan()
+            )?;
+        }
+    }
+
+    debug!("Updating receipt for tool `{name}`");
+    installed_tools.add_tool_receipt(name, receipt.with_completions(completions))?;
+
+    Ok(())
+}
+
+/// Run a [`Command`], capturing its `stdout`.
+///
+/// Returns `None` if the command doesn't exit within the given timeout, in which case it's killed.
+fn run_with_timeout(
+    mut command: Command,
+    timeout: Duration,
+) -> std::io::Result<Option<(std::process::ExitStatus, Vec<u8>)>> {
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()?;
+
+    // Read `stdout` on a separate thread, such that the child can't block on a full pipe.
+    let mut stdout = child.stdout.take().expect("stdout is piped");
+    let reader = std::thread::spawn(move || {
+        let mut buffer = Vec::new();
+        stdout.read_to_end(&mut buffer).map(|_| buffer)
+    });
+
+    let start = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if start.elapsed() > timeout {
+            child.kill()?;
+            child.wait()?;
+            return Ok(None);
+        }
+        std::thread::sleep(Duration::from_millis(50));
+    };
+
+    let stdout = reader
+        .join()
+        .map_err(|_| std::io::Error::other("Failed to read completion script"))??;
+    Ok(Some((status, stdout)))
+}
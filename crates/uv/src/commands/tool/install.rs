@@ -8,7 +8,8 @@ use uv_cache::{Cache, Refresh};
 use uv_cache_info::Timestamp;
 use uv_client::{BaseClientBuilder, Connectivity};
 use uv_configuration::{Concurrency, Upgrade};
-use uv_distribution_types::UnresolvedRequirementSpecification;
+use uv_distribution_types::{Resolution, UnresolvedRequirementSpecification};
+use uv_install_wheel::LauncherResources;
 use uv_normalize::PackageName;
 use uv_pep440::{VersionSpecifier, VersionSpecifiers};
 use uv_pep508::MarkerTree;
@@ -19,6 +20,7 @@ use uv_python::{
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
 use uv_settings::{ResolverInstallerOptions, ToolOptions};
 use uv_tool::InstalledTools;
+use uv_types::HashStrategy;
 use uv_warnings::warn_user;
 
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger};
@@ -27,7 +29,8 @@ use crate::commands::project::{
     resolve_environment, resolve_names, sync_environment, update_environment,
     EnvironmentSpecification,
 };
-use crate::commands::tool::common::remove_entrypoints;
+use crate::commands::tool::common::{locked_hasher, remove_entrypoints};
+use crate::commands::tool::completions;
 use crate::commands::tool::Target;
 use crate::commands::{reporters::PythonDownloadReporter, tool::common::install_executables};
 use crate::commands::{ExitStatus, SharedState};
@@ -42,8 +45,11 @@ pub(crate) async fn install(
     with: &[RequirementsSource],
     python: Option<String>,
     force: bool,
+    frozen: bool,
+    install_completions: bool,
     options: ResolverInstallerOptions,
     settings: ResolverInstallerSettings,
+    launcher_resources: Option<&LauncherResources>,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
     connectivity: Connectivity,
@@ -54,7 +60,8 @@ pub(crate) async fn install(
 ) -> Result<ExitStatus> {
     let client_builder = BaseClientBuilder::new()
         .connectivity(connectivity)
-        .native_tls(native_tls);
+        .native_tls(native_tls)
+        .indexes(settings.index_locations.allowed_indexes());
 
     let reporter = PythonDownloadReporter::single(printer);
 
@@ -79,7 +86,8 @@ pub(crate) async fn install(
 
     let client_builder = BaseClientBuilder::new()
         .connectivity(connectivity)
-        .native_tls(native_tls);
+        .native_tls(native_tls)
+        .indexes(settings.index_locations.allowed_indexes());
 
     // Parse the input requirement.
     let target = Target::parse(&package, from.as_deref());
@@ -100,9 +108,10 @@ pub(crate) async fn install(
             } else {
                 RequirementsSource::Package(name.to_string())
             };
-            let requirements = RequirementsSpecification::from_source(&source, &client_builder)
-                .await?
-                .requirements;
+            let requirements =
+                RequirementsSpecification::from_source(&source, &client_builder, &cache)
+                    .await?
+                    .requirements;
             resolve_names(
                 requirements,
                 &interpreter,
@@ -167,9 +176,10 @@ pub(crate) async fn install(
             } else {
                 RequirementsSource::Package(from.to_string())
             };
-            let requirements = RequirementsSpecification::from_source(&source, &client_builder)
-                .await?
-                .requirements;
+            let requirements =
+                RequirementsSpecification::from_source(&source, &client_builder, &cache)
+                    .await?
+                    .requirements;
 
             // Parse the `--from` requirement.
             let from_requirement = resolve_names(
@@ -214,7 +224,8 @@ pub(crate) async fn install(
     };
 
     // Read the `--with` requirements.
-    let spec = RequirementsSpecification::from_simple_sources(with, &client_builder).await?;
+    let spec =
+        RequirementsSpecification::from_simple_sources(with, &client_builder, &cache).await?;
 
     // Resolve the `--from` and `--with` requirements.
     let requirements = {
@@ -273,10 +284,44 @@ pub(crate) async fn install(
             }
         };
 
-    let existing_environment =
-        installed_tools
-            .get_environment(&from.name, &cache)?
-            .filter(|environment| {
+    // If `--frozen` was provided, install the locked requirements from the existing receipt.
+    let locked = if frozen {
+        let Some(tool_receipt) = existing_tool_receipt.as_ref() else {
+            bail!(
+                "`--frozen` was provided, but no receipt was found for `{}`",
+                from.name.cyan()
+            );
+        };
+        if tool_receipt.requirements() != requirements.as_slice() {
+            bail!(
+                "The requested requirements for `{}` do not match those in its receipt; run `uv tool install` without `--frozen` to update them",
+                from.name.cyan()
+            );
+        }
+        if tool_receipt.locked().is_empty() {
+            let install_command = format!("uv tool install --reinstall {}", from.name);
+            bail!(
+                "The receipt for `{}` does not include locked requirements; run `{}` to record them",
+                from.name.cyan(),
+                install_command.green()
+            );
+        }
+        Some(tool_receipt.locked().to_vec())
+    } else {
+        None
+    };
+
+    // If the tool was installed with shell completions, regenerate them alongside the executables.
+    let install_completions = install_completions
+        || existing_tool_receipt
+            .as_ref()
+            .is_some_and(|receipt| !receipt.completions().is_empty());
+
+    let existing_environment = installed_tools
+        .get_environment(&from.name, &cache)?
+        // If we're installing the locked requirements, always recreate the environment.
+        .filter(|_| locked.is_none())
+        .filter(|environment| {
                 if environment.uses(&interpreter) {
                     trace!(
                         "Existing interpreter matches the requested interpreter for `{}`: {}",
@@ -322,41 +367,62 @@ pub(crate) async fn install(
                     from = from.cyan()
                 )?;
 
+                // If requested, install any shell completions that are missing.
+                if install_completions && tool_receipt.completions().is_empty() {
+                    if let Some(environment) = existing_environment.as_ref() {
+                        completions::install_completions(
+                            environment,
+                            &from.name,
+                            &installed_tools,
+                            printer,
+                        )?;
+                    }
+                }
+
                 return Ok(ExitStatus::Success);
             }
         }
     }
 
     // Create a `RequirementsSpecification` from the resolved requirements, to avoid re-resolving.
-    let spec = RequirementsSpecification {
-        requirements: requirements
-            .iter()
-            .cloned()
-            .map(UnresolvedRequirementSpecification::from)
-            .collect(),
-        ..spec
+    let spec = if let Some(locked) = locked.as_ref() {
+        RequirementsSpecification::from_requirements(
+            locked
+                .iter()
+                .map(|locked| locked.requirement().clone())
+                .collect(),
+        )
+    } else {
+        RequirementsSpecification {
+            requirements: requirements
+                .iter()
+                .cloned()
+                .map(UnresolvedRequirementSpecification::from)
+                .collect(),
+            ..spec
+        }
     };
 
     // TODO(zanieb): Build the environment in the cache directory then copy into the tool directory.
     // This lets us confirm the environment is valid before removing an existing install. However,
     // entrypoints always contain an absolute path to the relevant Python interpreter, which would
     // be invalidated by moving the environment.
-    let environment = if let Some(environment) = existing_environment {
-        let environment = update_environment(
+    let (environment, resolution) = if let Some(environment) = existing_environment {
+        let update = update_environment(
             environment,
             spec,
             &settings,
             &state,
             Box::new(DefaultResolveLogger),
             Box::new(DefaultInstallLogger),
+            launcher_resources,
             connectivity,
             concurrency,
             native_tls,
             &cache,
             printer,
         )
-        .await?
-        .into_environment();
+        .await?;
 
         // At this point, we updated the existing environment, so we should remove any of its
         // existing executables.
@@ -364,7 +430,7 @@ pub(crate) async fn install(
             remove_entrypoints(&existing_receipt);
         }
 
-        environment
+        (update.environment, update.resolution)
     } else {
         // If we're creating a new environment, ensure that we can resolve the requirements prior
         // to removing any existing tools.
@@ -390,13 +456,24 @@ pub(crate) async fn install(
             remove_entrypoints(&existing_receipt);
         }
 
+        // If we're installing the locked requirements, verify the distributions against the
+        // recorded hashes.
+        let install = Resolution::from(&resolution);
+        let hasher = if let Some(locked) = locked.as_ref() {
+            locked_hasher(&install, locked)?
+        } else {
+            HashStrategy::default()
+        };
+
         // Sync the environment with the resolved requirements.
-        sync_environment(
+        let environment = sync_environment(
             environment,
-            &resolution.into(),
+            &install,
+            &hasher,
             settings.as_ref().into(),
             &state,
             Box::new(DefaultInstallLogger),
+            launcher_resources,
             connectivity,
             concurrency,
             native_tls,
@@ -408,10 +485,12 @@ pub(crate) async fn install(
             // If we failed to sync, remove the newly created environment.
             debug!("Failed to sync environment; removing `{}`", from.name);
             let _ = installed_tools.remove_environment(&from.name);
-        })?
+        })?;
+
+        (environment, Some(resolution))
     };
 
-    install_executables(
+    let status = install_executables(
         &environment,
         &from.name,
         &installed_tools,
@@ -419,6 +498,13 @@ pub(crate) async fn install(
         force || invalid_tool_receipt,
         python,
         requirements,
+        resolution.as_ref(),
         printer,
-    )
+    )?;
+
+    if install_completions && matches!(status, ExitStatus::Success) {
+        completions::install_completions(&environment, &from.name, &installed_tools, printer)?;
+    }
+
+    Ok(status)
 }
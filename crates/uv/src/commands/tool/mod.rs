@@ -5,11 +5,14 @@ use tracing::debug;
 use uv_normalize::PackageName;
 use uv_pep440::Version;
 
+pub(crate) mod alias;
 mod common;
+mod completions;
 pub(crate) mod dir;
 pub(crate) mod install;
 pub(crate) mod list;
 pub(crate) mod run;
+pub(crate) mod sync;
 pub(crate) mod uninstall;
 pub(crate) mod update_shell;
 pub(crate) mod upgrade;
@@ -1,3 +1,4 @@
+use std::ffi::OsString;
 use std::fmt::Display;
 use std::fmt::Write;
 use std::path::PathBuf;
@@ -96,6 +97,32 @@ pub(crate) async fn run(
         return Err(anyhow::anyhow!("Tool command could not be parsed as UTF-8 string. Use `--from` to specify the package name."));
     };
 
+    // If the command is a user-defined alias, expand it into the aliased tool and arguments, unless
+    // the package was provided explicitly via `--from`.
+    let alias = if from.is_none() {
+        InstalledTools::from_settings()?
+            .aliases()?
+            .get(target)
+            .cloned()
+    } else {
+        None
+    };
+    let (target, args) = if let Some(alias) = alias.as_ref() {
+        let Some(aliased) = alias.target() else {
+            bail!("Alias `{}` does not specify a tool", target.cyan());
+        };
+        debug!("Expanding alias `{target}` to `{alias}`");
+        let args = alias
+            .args()
+            .iter()
+            .map(OsString::from)
+            .chain(args.iter().cloned())
+            .collect::<Vec<_>>();
+        (aliased, args)
+    } else {
+        (target, args.to_vec())
+    };
+
     let target = Target::parse(target, from.as_deref());
 
     // If the user passed, e.g., `ruff@latest`, refresh the cache.
@@ -144,7 +171,7 @@ pub(crate) async fn run(
 
     // Construct the command
     let mut process = Command::new(executable);
-    process.args(args);
+    process.args(&args);
 
     // Construct the `PATH` environment variable.
     let new_path = std::env::join_paths(
@@ -407,7 +434,8 @@ async fn get_or_create_environment(
 ) -> Result<(Requirement, PythonEnvironment), ProjectError> {
     let client_builder = BaseClientBuilder::new()
         .connectivity(connectivity)
-        .native_tls(native_tls);
+        .native_tls(native_tls)
+        .indexes(settings.index_locations.allowed_indexes());
 
     let reporter = PythonDownloadReporter::single(printer);
 
@@ -487,8 +515,9 @@ async fn get_or_create_environment(
     let spec = {
         let client_builder = BaseClientBuilder::new()
             .connectivity(connectivity)
-            .native_tls(native_tls);
-        RequirementsSpecification::from_simple_sources(with, &client_builder).await?
+            .native_tls(native_tls)
+            .indexes(settings.index_locations.allowed_indexes());
+        RequirementsSpecification::from_simple_sources(with, &client_builder, cache).await?
     };
 
     // Resolve the `--from` and `--with` requirements.
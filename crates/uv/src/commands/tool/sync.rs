@@ -0,0 +1,258 @@
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_client::{BaseClientBuilder, Connectivity};
+use uv_configuration::Concurrency;
+use uv_distribution_types::Resolution;
+use uv_install_wheel::LauncherResources;
+use uv_normalize::PackageName;
+use uv_python::{
+    EnvironmentPreference, PythonDownloads, PythonInstallation, PythonPreference, PythonRequest,
+};
+use uv_requirements::RequirementsSpecification;
+use uv_settings::{Combine, ResolverInstallerOptions, ToolOptions};
+use uv_tool::InstalledTools;
+
+use crate::commands::pip::loggers::{DefaultInstallLogger, SummaryResolveLogger};
+use crate::commands::project::{resolve_environment, sync_environment};
+use crate::commands::reporters::PythonDownloadReporter;
+use crate::commands::tool::common::{install_executables, locked_hasher, remove_entrypoints};
+use crate::commands::tool::completions::install_completions;
+use crate::commands::{ExitStatus, SharedState};
+use crate::printer::Printer;
+use crate::settings::ResolverInstallerSettings;
+
+/// Sync tools from the locked requirements in their receipts.
+pub(crate) async fn sync(
+    name: Vec<PackageName>,
+    connectivity: Connectivity,
+    args: ResolverInstallerOptions,
+    filesystem: ResolverInstallerOptions,
+    launcher_resources: Option<&LauncherResources>,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    concurrency: Concurrency,
+    native_tls: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let installed_tools = InstalledTools::from_settings()?.init()?;
+    let _lock = installed_tools.lock().await?;
+
+    // Collect the tools to sync.
+    let names: BTreeSet<PackageName> = {
+        if name.is_empty() {
+            installed_tools
+                .tools()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(name, _)| name)
+                .collect()
+        } else {
+            name.into_iter().collect()
+        }
+    };
+
+    if names.is_empty() {
+        writeln!(printer.stderr(), "Nothing to sync")?;
+        return Ok(ExitStatus::Success);
+    }
+
+    // Determine whether any tool sync failed.
+    let mut failed_sync = false;
+
+    for name in &names {
+        debug!("Syncing tool: `{name}`");
+        let result = sync_tool(
+            name,
+            &installed_tools,
+            &args,
+            &filesystem,
+            launcher_resources,
+            python_preference,
+            python_downloads,
+            connectivity,
+            concurrency,
+            native_tls,
+            cache,
+            printer,
+        )
+        .await;
+
+        match result {
+            Ok(ExitStatus::Success) => {}
+            Ok(_) => {
+                failed_sync = true;
+            }
+            Err(err) => {
+                // If we have a single tool, return the error directly.
+                if names.len() > 1 {
+                    writeln!(printer.stderr(), "Failed to sync `{}`: {err}", name.cyan())?;
+                } else {
+                    writeln!(printer.stderr(), "{err}")?;
+                }
+                failed_sync = true;
+            }
+        }
+    }
+
+    if failed_sync {
+        return Ok(ExitStatus::Failure);
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Recreate the environment for a specific tool from the locked requirements in its receipt.
+async fn sync_tool(
+    name: &PackageName,
+    installed_tools: &InstalledTools,
+    args: &ResolverInstallerOptions,
+    filesystem: &ResolverInstallerOptions,
+    launcher_resources: Option<&LauncherResources>,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    native_tls: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    // Read the receipt.
+    let existing_tool_receipt = match installed_tools.get_tool_receipt(name) {
+        Ok(Some(receipt)) => receipt,
+        Ok(None) => {
+            let install_command = format!("uv tool install {name}");
+            return Err(anyhow::anyhow!(
+                "`{}` is not installed; run `{}` to install",
+                name.cyan(),
+                install_command.green()
+            ));
+        }
+        Err(_) => {
+            let install_command = format!("uv tool install --force {name}");
+            return Err(anyhow::anyhow!(
+                "`{}` is missing a valid receipt; run `{}` to reinstall",
+                name.cyan(),
+                install_command.green()
+            ));
+        }
+    };
+
+    if existing_tool_receipt.locked().is_empty() {
+        let install_command = format!("uv tool install --reinstall {name}");
+        return Err(anyhow::anyhow!(
+            "The receipt for `{}` does not include locked requirements; run `{}` to record them",
+            name.cyan(),
+            install_command.green()
+        ));
+    }
+
+    // Resolve the appropriate settings, preferring: CLI > receipt > user.
+    let options = args.clone().combine(
+        ResolverInstallerOptions::from(existing_tool_receipt.options().clone())
+            .combine(filesystem.clone()),
+    );
+    let settings = ResolverInstallerSettings::from(options.clone());
+
+    // Find the Python interpreter requested in the receipt.
+    let reporter = PythonDownloadReporter::single(printer);
+    let client_builder = BaseClientBuilder::new()
+        .connectivity(connectivity)
+        .native_tls(native_tls);
+    let python_request = existing_tool_receipt
+        .python()
+        .as_deref()
+        .map(PythonRequest::parse);
+    let interpreter = PythonInstallation::find_or_download(
+        python_request.as_ref(),
+        EnvironmentPreference::OnlySystem,
+        python_preference,
+        python_downloads,
+        &client_builder,
+        cache,
+        Some(&reporter),
+    )
+    .await?
+    .into_interpreter();
+
+    // Initialize any shared state.
+    let state = SharedState::default();
+
+    // Resolve the locked requirements prior to removing the existing environment.
+    let resolution = resolve_environment(
+        RequirementsSpecification::from_requirements(
+            existing_tool_receipt
+                .locked()
+                .iter()
+                .map(|locked| locked.requirement().clone())
+                .collect(),
+        )
+        .into(),
+        &interpreter,
+        settings.as_ref().into(),
+        &state,
+        Box::new(SummaryResolveLogger),
+        connectivity,
+        concurrency,
+        native_tls,
+        cache,
+        printer,
+    )
+    .await?;
+
+    // Verify the resolved distributions against the hashes recorded in the receipt.
+    let install = Resolution::from(&resolution);
+    let hasher = locked_hasher(&install, existing_tool_receipt.locked())?;
+
+    let environment = installed_tools.create_environment(name, interpreter)?;
+
+    // At this point, we removed any existing environment, so we should remove any of its
+    // executables.
+    remove_entrypoints(&existing_tool_receipt);
+
+    let environment = sync_environment(
+        environment,
+        &install,
+        &hasher,
+        settings.as_ref().into(),
+        &state,
+        Box::new(DefaultInstallLogger),
+        launcher_resources,
+        connectivity,
+        concurrency,
+        native_tls,
+        cache,
+        printer,
+    )
+    .await
+    .inspect_err(|_| {
+        // If we failed to sync, retain the receipt, such that the sync can be retried.
+        debug!("Failed to sync environment; restoring receipt for `{name}`");
+        let _ = installed_tools.add_tool_receipt(name, existing_tool_receipt.clone());
+    })?;
+
+    let status = install_executables(
+        &environment,
+        name,
+        installed_tools,
+        ToolOptions::from(options),
+        true,
+        existing_tool_receipt.python().to_owned(),
+        existing_tool_receipt.requirements().to_vec(),
+        Some(&resolution),
+        printer,
+    )?;
+
+    // If the tool was installed with shell completions, regenerate them.
+    if matches!(status, ExitStatus::Success) && !existing_tool_receipt.completions().is_empty() {
+        install_completions(&environment, name, installed_tools, printer)?;
+    }
+
+    Ok(status)
+}
@@ -201,5 +201,19 @@ async fn uninstall_tool(
         }
     }
 
+    // Remove the tool's shell completion scripts.
+    for completion in receipt.completions() {
+        debug!("Removing completion script: {}", completion.user_display());
+        match fs_err::tokio::remove_file(completion).await {
+            Ok(()) => {}
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                debug!("Completion script not found: {}", completion.user_display());
+            }
+            Err(err) => {
+                return Err(err.into());
+            }
+        }
+    }
+
     Ok(entrypoints.to_vec())
 }
@@ -7,6 +7,9 @@ use tracing::debug;
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, Connectivity};
 use uv_configuration::Concurrency;
+use uv_distribution_types::Resolution;
+use uv_install_wheel::LauncherResources;
+use uv_installer::SitePackages;
 use uv_normalize::PackageName;
 use uv_python::{
     EnvironmentPreference, Interpreter, PythonDownloads, PythonInstallation, PythonPreference,
@@ -15,6 +18,7 @@ use uv_python::{
 use uv_requirements::RequirementsSpecification;
 use uv_settings::{Combine, ResolverInstallerOptions, ToolOptions};
 use uv_tool::InstalledTools;
+use uv_types::HashStrategy;
 
 use crate::commands::pip::loggers::{
     DefaultInstallLogger, SummaryResolveLogger, UpgradeInstallLogger,
@@ -23,7 +27,8 @@ use crate::commands::project::{
     resolve_environment, sync_environment, update_environment, EnvironmentUpdate,
 };
 use crate::commands::reporters::PythonDownloadReporter;
-use crate::commands::tool::common::remove_entrypoints;
+use crate::commands::tool::common::{locked_requirements, remove_entrypoints};
+use crate::commands::tool::completions::install_completions;
 use crate::commands::{tool::common::install_executables, ExitStatus, SharedState};
 use crate::printer::Printer;
 use crate::settings::ResolverInstallerSettings;
@@ -35,6 +40,7 @@ pub(crate) async fn upgrade(
     connectivity: Connectivity,
     args: ResolverInstallerOptions,
     filesystem: ResolverInstallerOptions,
+    launcher_resources: Option<&LauncherResources>,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
     concurrency: Concurrency,
@@ -108,6 +114,7 @@ pub(crate) async fn upgrade(
             &args,
             cache,
             &filesystem,
+            launcher_resources,
             connectivity,
             concurrency,
             native_tls,
@@ -186,6 +193,7 @@ async fn upgrade_tool(
     args: &ResolverInstallerOptions,
     cache: &Cache,
     filesystem: &ResolverInstallerOptions,
+    launcher_resources: Option<&LauncherResources>,
     connectivity: Connectivity,
     concurrency: Concurrency,
     native_tls: bool,
@@ -247,7 +255,7 @@ async fn upgrade_tool(
 
     // Check if we need to create a new environment — if so, resolve it first, then
     // install the requested tool
-    let (environment, outcome) = if let Some(interpreter) =
+    let (environment, resolution, outcome) = if let Some(interpreter) =
         interpreter.filter(|interpreter| !environment.uses(interpreter))
     {
         // If we're using a new interpreter, re-create the environment for each tool.
@@ -269,10 +277,12 @@ async fn upgrade_tool(
 
         let environment = sync_environment(
             environment,
-            &resolution.into(),
+            &Resolution::from(&resolution),
+            &HashStrategy::default(),
             settings.as_ref().into(),
             &state,
             Box::new(DefaultInstallLogger),
+            launcher_resources,
             connectivity,
             concurrency,
             native_tls,
@@ -281,7 +291,11 @@ async fn upgrade_tool(
         )
         .await?;
 
-        (environment, UpgradeOutcome::UpgradeEnvironment)
+        (
+            environment,
+            Some(resolution),
+            UpgradeOutcome::UpgradeEnvironment,
+        )
     } else {
         // Otherwise, upgrade the existing environment.
         // TODO(zanieb): Build the environment in the cache directory then copy into the tool
@@ -289,6 +303,7 @@ async fn upgrade_tool(
         let EnvironmentUpdate {
             environment,
             changelog,
+            resolution,
         } = update_environment(
             environment,
             spec,
@@ -296,6 +311,7 @@ async fn upgrade_tool(
             &state,
             Box::new(SummaryResolveLogger),
             Box::new(UpgradeInstallLogger::new(name.clone())),
+            launcher_resources,
             connectivity,
             concurrency,
             native_tls,
@@ -312,7 +328,7 @@ async fn upgrade_tool(
             UpgradeOutcome::UpgradeDependencies
         };
 
-        (environment, outcome)
+        (environment, resolution, outcome)
     };
 
     if matches!(
@@ -332,8 +348,21 @@ async fn upgrade_tool(
             true,
             existing_tool_receipt.python().to_owned(),
             requirements.to_vec(),
+            resolution.as_ref(),
             printer,
         )?;
+
+        // If the tool was installed with shell completions, regenerate them.
+        if !existing_tool_receipt.completions().is_empty() {
+            install_completions(&environment, name, installed_tools, printer)?;
+        }
+    } else if matches!(outcome, UpgradeOutcome::UpgradeDependencies) {
+        // If we only modified the tool's dependencies, update the locked requirements in the
+        // receipt.
+        let site_packages = SitePackages::from_environment(&environment)?;
+        let locked = locked_requirements(resolution.as_ref(), &site_packages);
+        installed_tools
+            .add_tool_receipt(name, existing_tool_receipt.with_locked(locked.into_iter()))?;
     }
 
     Ok(outcome)
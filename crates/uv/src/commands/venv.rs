@@ -12,8 +12,8 @@ use thiserror::Error;
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    BuildOptions, Concurrency, ConfigSettings, Constraints, IndexStrategy, KeyringProviderType,
-    LowerBound, NoBinary, NoBuild, SourceStrategy, TrustedHost,
+    BuildContainers, BuildOptions, Concurrency, ConfigSettings, Constraints, IndexStrategy,
+    KeyringProviderType, LowerBound, NoBinary, NoBuild, SourceStrategy, TrustedHost,
 };
 use uv_dispatch::BuildDispatch;
 use uv_distribution_types::{DependencyMetadata, Index, IndexLocations};
@@ -230,6 +230,8 @@ async fn venv_impl(
     for index in index_locations.allowed_indexes() {
         if let Some(credentials) = index.credentials() {
             uv_auth::store_credentials(index.raw_url(), credentials);
+        } else if let Some(secrets) = index.secret_credentials() {
+            uv_auth::store_secret_credentials(secrets);
         }
     }
 
@@ -281,6 +283,8 @@ async fn venv_impl(
         for index in index_locations.allowed_indexes() {
             if let Some(credentials) = index.credentials() {
                 uv_auth::store_credentials(index.raw_url(), credentials);
+            } else if let Some(secrets) = index.secret_credentials() {
+                uv_auth::store_secret_credentials(secrets);
             }
         }
 
@@ -299,7 +303,8 @@ async fn venv_impl(
         // Resolve the flat indexes from `--find-links`.
         let flat_index = {
             let tags = interpreter.tags().map_err(VenvError::Tags)?;
-            let client = FlatIndexClient::new(&client, cache);
+            let client = FlatIndexClient::new(&client, cache)
+                .with_recursive(index_locations.find_links_recursive());
             let entries = client
                 .fetch(index_locations.flat_indexes().map(Index::url))
                 .await
@@ -319,6 +324,7 @@ async fn venv_impl(
         let build_constraints = Constraints::default();
         let build_hasher = HashStrategy::default();
         let config_settings = ConfigSettings::default();
+        let build_containers = BuildContainers::default();
         let sources = SourceStrategy::Disabled;
 
         // Do not allow builds
@@ -335,10 +341,12 @@ async fn venv_impl(
             &dependency_metadata,
             &state.index,
             &state.git,
+            &state.hg,
             &state.capabilities,
             &state.in_flight,
             index_strategy,
             &config_settings,
+            &build_containers,
             BuildIsolation::Isolated,
             link_mode,
             &build_options,
@@ -0,0 +1,198 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+use serde::Serialize;
+
+use uv_cli::WorkspaceGraphFormat;
+use uv_normalize::PackageName;
+use uv_pypi_types::VerbatimParsedUrl;
+use uv_workspace::pyproject::{DependencyGroupSpecifier, Source, ToolUvSources};
+use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceMember};
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Display the dependencies between the members of the workspace.
+pub(crate) async fn graph(
+    project_dir: &Path,
+    output_format: WorkspaceGraphFormat,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let workspace = Workspace::discover(project_dir, &DiscoveryOptions::default()).await?;
+
+    // Collect the workspace dependencies of each member.
+    let mut dependencies = BTreeMap::new();
+    for (name, member) in workspace.packages() {
+        dependencies.insert(name, member_dependencies(&workspace, member)?);
+    }
+
+    // Invert the graph, to determine the dependents of each member.
+    let mut dependents: BTreeMap<&PackageName, BTreeSet<&PackageName>> = dependencies
+        .keys()
+        .map(|name| (*name, BTreeSet::new()))
+        .collect();
+    for (name, deps) in &dependencies {
+        for dep in deps {
+            if let Some(entry) = dependents.get_mut(dep) {
+                entry.insert(*name);
+            }
+        }
+    }
+
+    match output_format {
+        WorkspaceGraphFormat::Text => {
+            for (name, deps) in &dependencies {
+                if deps.is_empty() {
+                    writeln!(printer.stdout(), "{}", name.bold())?;
+                } else {
+                    writeln!(
+                        printer.stdout(),
+                        "{} -> {}",
+                        name.bold(),
+                        deps.iter()
+                            .map(ToString::to_string)
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )?;
+                }
+            }
+        }
+        WorkspaceGraphFormat::Dot => {
+            let mut dot = String::from("digraph {\n");
+            for name in dependencies.keys() {
+                writeln!(dot, "    \"{name}\";")?;
+            }
+            for (name, deps) in &dependencies {
+                for dep in deps {
+                    writeln!(dot, "    \"{name}\" -> \"{dep}\";")?;
+                }
+            }
+            dot.push('}');
+            writeln!(printer.stdout(), "{dot}")?;
+        }
+        WorkspaceGraphFormat::Json => {
+            let entries = dependencies
+                .iter()
+                .map(|(name, deps)| WorkspaceGraphEntry {
+                    name,
+                    path: workspace.packages()[*name].root(),
+                    dependencies: deps.iter().copied().collect(),
+                    dependents: dependents[name].iter().copied().collect(),
+                })
+                .collect::<Vec<_>>();
+            writeln!(
+                printer.stdout(),
+                "{}",
+                serde_json::to_string_pretty(&entries)?
+            )?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// A workspace member, as displayed by `uv workspace graph --output-format json`.
+#[derive(Debug, Serialize)]
+struct WorkspaceGraphEntry<'a> {
+    name: &'a PackageName,
+    /// The absolute path to the member's root directory.
+    path: &'a PathBuf,
+    /// The workspace members that this member depends on.
+    dependencies: Vec<&'a PackageName>,
+    /// The workspace members that depend on this member.
+    dependents: Vec<&'a PackageName>,
+}
+
+/// Return the workspace members on which the given member depends, across its dependencies,
+/// optional dependencies, and development dependencies.
+///
+/// Only dependencies that are resolved from the workspace (i.e., via a `workspace = true` source)
+/// are included.
+fn member_dependencies<'a>(
+    workspace: &'a Workspace,
+    member: &WorkspaceMember,
+) -> Result<BTreeSet<&'a PackageName>> {
+    let pyproject_toml = member.pyproject_toml();
+    let project = member.project();
+
+    // Collect the names of all declared dependencies.
+    let mut names = Vec::new();
+    let requirements = project
+        .dependencies
+        .iter()
+        .flatten()
+        .chain(
+            project
+                .optional_dependencies
+                .iter()
+                .flat_map(|extras| extras.values().flatten()),
+        )
+        .chain(
+            pyproject_toml
+                .dependency_groups
+                .iter()
+                .flat_map(|groups| groups.values().flatten())
+                .filter_map(|specifier| match specifier {
+                    DependencyGroupSpecifier::Requirement(requirement) => Some(requirement),
+                    _ => None,
+                }),
+        );
+    for requirement in requirements {
+        let requirement = uv_pep508::Requirement::<VerbatimParsedUrl>::from_str(requirement)
+            .with_context(|| {
+                format!(
+                    "Failed to parse dependency of `{}`: `{requirement}`",
+                    project.name
+                )
+            })?;
+        names.push(requirement.name);
+    }
+    if let Some(dev_dependencies) = pyproject_toml
+        .tool
+        .as_ref()
+        .and_then(|tool| tool.uv.as_ref())
+        .and_then(|uv| uv.dev_dependencies.as_ref())
+    {
+        names.extend(
+            dev_dependencies
+                .iter()
+                .map(|requirement| requirement.name.clone()),
+        );
+    }
+
+    // Retain those that refer to other members via a workspace source. Sources declared by the
+    // member take precedence over those declared by the workspace root.
+    let member_sources = pyproject_toml
+        .tool
+        .as_ref()
+        .and_then(|tool| tool.uv.as_ref())
+        .and_then(|uv| uv.sources.as_ref())
+        .map(ToolUvSources::inner);
+
+    Ok(names
+        .iter()
+        .filter(|name| **name != project.name)
+        .filter_map(|name| {
+            let (name, _) = workspace.packages().get_key_value(name)?;
+            let sources = member_sources
+                .and_then(|sources| sources.get(name))
+                .or_else(|| workspace.sources().get(name))?;
+            sources
+                .iter()
+                .any(|source| {
+                    matches!(
+                        source,
+                        Source::Workspace {
+                            workspace: true,
+                            ..
+                        }
+                    )
+                })
+                .then_some(name)
+        })
+        .collect())
+}
@@ -0,0 +1,119 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+use serde::Serialize;
+
+use uv_cli::WorkspaceListFormat;
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_pep440::{Version, VersionSpecifiers};
+use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceMember};
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// List the members of the workspace.
+pub(crate) async fn list(
+    project_dir: &Path,
+    output_format: WorkspaceListFormat,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let workspace = Workspace::discover(project_dir, &DiscoveryOptions::default()).await?;
+
+    let members = workspace
+        .packages()
+        .iter()
+        .map(|(name, member)| WorkspaceListEntry::new(&workspace, name, member))
+        .collect::<Vec<_>>();
+
+    if matches!(output_format, WorkspaceListFormat::Json) {
+        writeln!(
+            printer.stdout(),
+            "{}",
+            serde_json::to_string_pretty(&members)?
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    // Compute the width of the name and version columns.
+    let name_width = members
+        .iter()
+        .fold(0usize, |acc, member| acc.max(member.name.as_str().len()));
+    let version_width = members.iter().fold(0usize, |acc, member| {
+        acc.max(
+            member
+                .version
+                .as_ref()
+                .map_or(0, |version| version.to_string().len() + 1),
+        )
+    });
+
+    for member in members {
+        let version = member
+            .version
+            .as_ref()
+            .map(|version| format!("v{version}"))
+            .unwrap_or_default();
+        let relative = member
+            .path
+            .strip_prefix(workspace.install_path())
+            .unwrap_or(&member.path);
+        let relative = if relative.as_os_str().is_empty() {
+            Path::new(".")
+        } else {
+            relative
+        };
+
+        let mut line = format!(
+            "{:name_width$}  {version:version_width$}  {}",
+            member.name.as_str(),
+            relative.user_display().cyan()
+        );
+        if let Some(requires_python) = member.requires_python.as_ref() {
+            write!(
+                line,
+                " {}",
+                format!("(requires-python: {requires_python})").dimmed()
+            )?;
+        }
+        if member.root {
+            write!(line, " {}", "(root)".dimmed())?;
+        }
+        if member.r#virtual {
+            write!(line, " {}", "(virtual)".dimmed())?;
+        }
+        writeln!(printer.stdout(), "{}", line.trim_end())?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// A workspace member, as displayed by `uv workspace list`.
+#[derive(Debug, Serialize)]
+struct WorkspaceListEntry {
+    name: PackageName,
+    version: Option<Version>,
+    /// The absolute path to the member's root directory.
+    path: PathBuf,
+    requires_python: Option<VersionSpecifiers>,
+    /// Whether the member is the workspace root.
+    root: bool,
+    /// Whether the member is a virtual project, i.e., not built or installed as a package.
+    r#virtual: bool,
+}
+
+impl WorkspaceListEntry {
+    fn new(workspace: &Workspace, name: &PackageName, member: &WorkspaceMember) -> Self {
+        let project = member.project();
+        Self {
+            name: name.clone(),
+            version: project.version.clone(),
+            path: member.root().clone(),
+            requires_python: project.requires_python.clone(),
+            root: member.root() == workspace.install_path(),
+            r#virtual: !member.pyproject_toml().is_package(),
+        }
+    }
+}
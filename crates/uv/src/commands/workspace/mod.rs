@@ -0,0 +1,2 @@
+pub(crate) mod graph;
+pub(crate) mod list;
@@ -4,24 +4,33 @@ use std::fmt::Write;
 use std::io::stdout;
 use std::path::Path;
 use std::process::ExitCode;
+use std::str::FromStr;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
 
 use anstream::eprintln;
 use anyhow::Result;
 use clap::error::{ContextKind, ContextValue};
-use clap::{CommandFactory, Parser};
+use clap::{CommandFactory, Parser, ValueEnum};
+use clap_complete::env::{CompleteEnv, Shells};
 use owo_colors::OwoColorize;
 use settings::PipTreeSettings;
 use tokio::task::spawn_blocking;
 use tracing::{debug, instrument};
 use uv_cache::{Cache, Refresh};
 use uv_cache_info::Timestamp;
+#[cfg(feature = "self-update")]
+use uv_cli::SelfUpdateArgs;
 use uv_cli::{
-    compat::CompatArgs, BuildBackendCommand, CacheCommand, CacheNamespace, Cli, Commands,
-    PipCommand, PipNamespace, ProjectCommand,
+    compat::CompatArgs, AuthCommand, AuthNamespace, BuildBackendCommand, CacheCommand,
+    CacheNamespace, Cli, Commands, ConfigCommand, ConfigNamespace, EnvCommand, EnvNamespace,
+    IndexCommand, IndexNamespace, PipCommand, PipNamespace, ProjectCommand,
 };
-use uv_cli::{PythonCommand, PythonNamespace, ToolCommand, ToolNamespace, TopLevelArgs};
-#[cfg(feature = "self-update")]
-use uv_cli::{SelfCommand, SelfNamespace, SelfUpdateArgs};
+use uv_cli::{
+    PythonCommand, PythonNamespace, ToolAliasCommand, ToolAliasNamespace, ToolCommand,
+    ToolNamespace, TopLevelArgs, WorkspaceCommand, WorkspaceNamespace,
+};
+use uv_cli::{SelfCommand, SelfNamespace, SelfUninstallArgs};
 use uv_fs::CWD;
 use uv_requirements::RequirementsSource;
 use uv_scripts::{Pep723Item, Pep723Metadata, Pep723Script};
@@ -30,12 +39,12 @@ use uv_static::EnvVars;
 use uv_warnings::{warn_user, warn_user_once};
 use uv_workspace::{DiscoveryOptions, Workspace};
 
-use crate::commands::{ExitStatus, RunCommand, ToolRunCommand};
+use crate::commands::{ConfigScope, ExitStatus, RunCommand, ToolRunCommand};
 use crate::printer::Printer;
 use crate::settings::{
     CacheSettings, GlobalSettings, PipCheckSettings, PipCompileSettings, PipFreezeSettings,
     PipInstallSettings, PipListSettings, PipShowSettings, PipSyncSettings, PipUninstallSettings,
-    PublishSettings,
+    PipVerifySettings, PublishSettings,
 };
 
 pub(crate) mod commands;
@@ -132,12 +141,22 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         project.combine(user).combine(system)
     };
 
+    // If a profile was requested, apply it on top of the discovered configuration.
+    let filesystem = if let Some(profile) = cli.top_level.profile.as_deref() {
+        let filesystem =
+            filesystem.ok_or_else(|| uv_settings::Error::MissingProfile(profile.to_string()))?;
+        Some(filesystem.with_profile(profile)?)
+    } else {
+        filesystem
+    };
+
     // Parse the external command, if necessary.
     let run_command = if let Commands::Project(command) = &mut *cli.command {
         if let ProjectCommand::Run(uv_cli::RunArgs {
             command: Some(command),
             module,
             script,
+            gui_script,
             ..
         }) = &mut **command
         {
@@ -147,6 +166,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                     command,
                     *module,
                     *script,
+                    *gui_script,
                     settings.connectivity,
                     settings.native_tls,
                 )
@@ -188,12 +208,21 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     };
 
     // If the target is a PEP 723 script, merge the metadata into the filesystem metadata.
-    let filesystem = script
+    let script_options = script
         .as_ref()
         .map(Pep723Item::metadata)
         .and_then(|metadata| metadata.tool.as_ref())
         .and_then(|tool| tool.uv.as_ref())
-        .map(|uv| Options::simple(uv.globals.clone(), uv.top_level.clone()))
+        .map(|uv| Options::simple(uv.globals.clone(), uv.top_level.clone()));
+    if let (Some(script), Some(options)) = (script.as_ref(), script_options.as_ref()) {
+        // Like project configuration, scripts may not reference local secrets.
+        let source = match script {
+            Pep723Item::Script(script) => script.path.as_path(),
+            Pep723Item::Stdin(_) | Pep723Item::Remote(_) => Path::new("-"),
+        };
+        uv_settings::check_project_secrets(options, source)?;
+    }
+    let filesystem = script_options
         .map(FilesystemOptions::from)
         .combine(filesystem);
 
@@ -208,13 +237,19 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     let (duration_layer, _duration_guard) = logging::setup_duration()?;
     #[cfg(not(feature = "tracing-durations-export"))]
     let duration_layer = None::<tracing_subscriber::layer::Identity>;
+    #[cfg(feature = "tracing-otlp-export")]
+    let (otlp_layer, _otlp_guard) = logging::setup_otlp()?;
+    #[cfg(not(feature = "tracing-otlp-export"))]
+    let otlp_layer = None::<tracing_subscriber::layer::Identity>;
     logging::setup_logging(
         match globals.verbose {
             0 => logging::Level::Default,
             1 => logging::Level::Verbose,
             2.. => logging::Level::ExtraVerbose,
         },
+        globals.log_format,
         duration_layer,
+        otlp_layer,
     )?;
 
     // Configure the `Printer`, which controls user-facing output in the CLI.
@@ -257,8 +292,49 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         .build_global()
         .expect("failed to initialize global rayon pool");
 
+    // Configure the SSH options for Git hosts.
+    for (host, options) in &globals.git_ssh {
+        uv_git::GIT_SSH.insert(host.clone(), options.clone());
+    }
+    uv_git::GIT_SPARSE_CHECKOUT.store(!globals.no_git_sparse_checkout, Ordering::Relaxed);
+
+    // Configure the HTTP retry settings, to be used by every client.
+    let _ = uv_client::HTTP_RETRY.set(globals.http_retry.clone());
+
+    // Configure the per-host request limit, to be used by every client.
+    if let Some(per_host) = globals.concurrency.downloads_per_host {
+        let _ = uv_client::CONCURRENT_REQUESTS_PER_HOST.set(per_host);
+    }
+
+    // Configure the Azure Artifacts token. Unlike other settings, it's read directly from the
+    // filesystem options, rather than the resolved `GlobalSettings`, such that it isn't displayed
+    // by `--show-settings`.
+    if let Some(pat) = filesystem
+        .as_ref()
+        .and_then(|filesystem| filesystem.globals.azure_artifacts_pat.clone())
+    {
+        let _ = uv_auth::AZURE_ARTIFACTS_PAT.set(pat);
+    }
+
     debug!("uv {}", uv_cli::version::version());
 
+    // Enforce the `required-version`, if any. `uv self update` is allowed to proceed, such that uv
+    // can be updated to a satisfying version.
+    if let Some(required_version) = globals.required_version.as_ref() {
+        let uv_version = uv_pep440::Version::from_str(uv_version::version())?;
+        if !required_version.contains(&uv_version) {
+            if matches!(&*cli.command, Commands::Self_(_)) {
+                warn_user!(
+                    "Required uv version `{required_version}` does not match the running version `{uv_version}`"
+                );
+            } else {
+                anyhow::bail!(
+                    "Required uv version `{required_version}` does not match the running version `{uv_version}`"
+                );
+            }
+        }
+    }
+
     // Write out any resolved settings.
     macro_rules! show_settings {
         ($arg:expr) => {
@@ -277,7 +353,18 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     show_settings!(cache_settings, false);
 
     // Configure the cache.
-    let cache = Cache::from_settings(cache_settings.no_cache, cache_settings.cache_dir)?;
+    let cache = Cache::from_settings(cache_settings.no_cache, cache_settings.cache_dir)?
+        .with_namespace(cache_settings.cache_namespace)?
+        .with_build_cache(
+            cache_settings.build_cache_url,
+            cache_settings.build_cache_upload,
+        )
+        .with_requirements_ttl(Duration::from_secs(
+            cache_settings.requirements_cache_ttl.unwrap_or_default(),
+        ));
+
+    // Retain a handle to the cache, to persist any recorded activity once the command completes.
+    let recorded_cache = cache.clone();
 
     let result = match *cli.command {
         Commands::Help(args) => commands::help(
@@ -321,6 +408,13 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 .into_iter()
                 .map(RequirementsSource::from_constraints_txt)
                 .collect::<Vec<_>>();
+            let targets = commands::CompileTarget::from_settings(
+                args.python_versions,
+                args.python_platforms,
+                args.settings.python_version,
+                args.settings.python_platform,
+                args.settings.output_file,
+            )?;
 
             commands::pip_compile(
                 &requirements,
@@ -329,11 +423,16 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 &build_constraints,
                 args.constraints_from_workspace,
                 args.overrides_from_workspace,
+                args.constraint_from_lock.as_deref(),
                 args.environments,
                 args.settings.extras,
-                args.settings.output_file.as_deref(),
+                &targets,
                 args.settings.resolution,
                 args.settings.prerelease,
+                args.settings.yanked,
+                args.settings.allow_yanked,
+                args.settings.prefer_local_version,
+                args.settings.resolution_budget,
                 args.settings.dependency_mode,
                 args.settings.upgrade,
                 args.settings.generate_hashes,
@@ -354,15 +453,15 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.keyring_provider,
                 args.settings.allow_insecure_host,
                 args.settings.config_setting,
+                args.settings.build_containers,
                 globals.connectivity,
                 args.settings.no_build_isolation,
                 args.settings.no_build_isolation_package,
                 args.settings.build_options,
-                args.settings.python_version,
-                args.settings.python_platform,
                 args.settings.universal,
                 args.settings.exclude_newer,
                 args.settings.sources,
+                args.settings.variants,
                 args.settings.annotation_style,
                 args.settings.link_mode,
                 args.settings.python,
@@ -415,15 +514,19 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.reinstall,
                 args.settings.link_mode,
                 args.settings.compile_bytecode,
+                &args.settings.bytecode_options,
                 args.settings.hash_checking,
                 args.settings.index_locations,
                 args.settings.index_strategy,
+                args.settings.yanked,
+                args.settings.allow_yanked,
                 args.settings.dependency_metadata,
                 args.settings.keyring_provider,
                 args.settings.allow_insecure_host,
                 args.settings.allow_empty_requirements,
                 globals.connectivity,
                 &args.settings.config_setting,
+                &args.settings.build_containers,
                 args.settings.no_build_isolation,
                 args.settings.no_build_isolation_package,
                 args.settings.build_options,
@@ -437,6 +540,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.target,
                 args.settings.prefix,
                 args.settings.sources,
+                args.settings.variants,
                 globals.concurrency,
                 globals.native_tls,
                 cache,
@@ -498,6 +602,10 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 &args.settings.extras,
                 args.settings.resolution,
                 args.settings.prerelease,
+                args.settings.yanked,
+                args.settings.allow_yanked,
+                args.settings.prefer_local_version,
+                args.settings.resolution_budget,
                 args.settings.dependency_mode,
                 args.settings.upgrade,
                 args.settings.index_locations,
@@ -508,9 +616,11 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.reinstall,
                 args.settings.link_mode,
                 args.settings.compile_bytecode,
+                &args.settings.bytecode_options,
                 args.settings.hash_checking,
                 globals.connectivity,
                 &args.settings.config_setting,
+                &args.settings.build_containers,
                 args.settings.no_build_isolation,
                 args.settings.no_build_isolation_package,
                 args.settings.build_options,
@@ -529,6 +639,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 globals.native_tls,
                 cache,
                 args.dry_run,
+                args.report.as_deref(),
                 printer,
             )
             .await
@@ -581,6 +692,9 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
 
             commands::pip_freeze(
                 args.exclude_editable,
+                args.direct_url,
+                args.paths,
+                args.format,
                 args.settings.strict,
                 args.settings.python.as_deref(),
                 args.settings.system,
@@ -671,12 +785,30 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 printer,
             )
         }
+        Commands::Pip(PipNamespace {
+            command: PipCommand::Verify(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = PipVerifySettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::pip_verify(
+                args.package,
+                args.settings.python.as_deref(),
+                args.settings.system,
+                &cache,
+                printer,
+            )
+        }
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Clean(args),
         })
         | Commands::Clean(args) => {
             show_settings!(args);
-            commands::cache_clean(&args.package, &cache, printer)
+            commands::cache_clean(&args.package, args.build_environments, &cache, printer)
         }
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Prune(args),
@@ -684,12 +816,193 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             show_settings!(args);
             commands::cache_prune(args.ci, &cache, printer)
         }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Verify(args),
+        }) => {
+            show_settings!(args);
+            commands::cache_verify(args.dry_run, &cache, printer)
+        }
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Dir,
         }) => {
             commands::cache_dir(&cache);
             Ok(ExitStatus::Success)
         }
+        Commands::Stats(args) => {
+            show_settings!(args);
+            commands::stats(args.builds, args.output_format, &cache, printer)
+        }
+        Commands::Doctor(args) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::DoctorSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::doctor(
+                &project_dir,
+                args.python,
+                args.output_format,
+                &args.settings,
+                globals.python_preference,
+                globals.connectivity,
+                globals.native_tls,
+                cli.top_level.no_config,
+                &cache,
+                printer,
+            )
+            .await
+        }
+        Commands::Search(args) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::SearchSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::search(
+                &args.query,
+                args.limit,
+                args.output_format,
+                &args.settings,
+                globals.connectivity,
+                globals.native_tls,
+                &cache,
+                printer,
+            )
+            .await
+        }
+        Commands::Info(args) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::InfoSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::info(
+                &args.package,
+                args.versions,
+                args.python.as_deref(),
+                args.output_format,
+                &args.settings,
+                globals.connectivity,
+                globals.native_tls,
+                &cache,
+                printer,
+            )
+            .await
+        }
+        Commands::Auth(AuthNamespace {
+            command: AuthCommand::Login(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::AuthLoginSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::auth_login(
+                &args.index,
+                &args.indexes,
+                args.username,
+                args.password_stdin,
+                args.keyring_provider,
+                printer,
+            )
+            .await
+        }
+        Commands::Auth(AuthNamespace {
+            command: AuthCommand::Logout(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::AuthLogoutSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::auth_logout(&args.index, &args.indexes, printer).await
+        }
+        Commands::Auth(AuthNamespace {
+            command: AuthCommand::List,
+        }) => commands::auth_list(printer),
+        Commands::Auth(AuthNamespace {
+            command: AuthCommand::Token(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::AuthTokenSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::auth_token(&args.index, &args.indexes, printer).await
+        }
+        Commands::Index(IndexNamespace {
+            command: IndexCommand::Add(args),
+        }) => {
+            show_settings!(args);
+
+            commands::index_add(
+                &project_dir,
+                args.name,
+                args.url,
+                args.explicit,
+                args.default,
+                args.last,
+                args.user,
+                printer,
+            )
+            .await
+        }
+        Commands::Index(IndexNamespace {
+            command: IndexCommand::Remove(args),
+        }) => {
+            show_settings!(args);
+
+            commands::index_remove(&project_dir, &args.index, args.user, printer).await
+        }
+        Commands::Index(IndexNamespace {
+            command: IndexCommand::List(args),
+        }) => {
+            show_settings!(args);
+
+            commands::index_list(&project_dir, args.user, printer).await
+        }
+        Commands::Index(IndexNamespace {
+            command: IndexCommand::SetDefault(args),
+        }) => {
+            show_settings!(args);
+
+            commands::index_set_default(&project_dir, &args.index, args.user, printer).await
+        }
+        Commands::Config(ConfigNamespace {
+            command: ConfigCommand::Get(args),
+        }) => {
+            show_settings!(args);
+
+            commands::config_get(
+                &project_dir,
+                &args.key,
+                ConfigScope::from_args(args.scope.user, args.scope.system),
+                args.output_format,
+                printer,
+            )
+            .await
+        }
+        Commands::Config(ConfigNamespace {
+            command: ConfigCommand::Set(args),
+        }) => {
+            show_settings!(args);
+
+            commands::config_set(
+                &project_dir,
+                &args.key,
+                &args.value,
+                ConfigScope::from_args(args.scope.user, args.scope.system),
+                printer,
+            )
+            .await
+        }
+        Commands::Config(ConfigNamespace {
+            command: ConfigCommand::List(args),
+        }) => {
+            show_settings!(args);
+
+            commands::config_list(
+                &project_dir,
+                ConfigScope::from_args(args.scope.user, args.scope.system),
+                args.output_format,
+                printer,
+            )
+            .await
+        }
         Commands::Build(args) => {
             // Resolve the settings from the command-line arguments and workspace configuration.
             let args = settings::BuildSettings::resolve(args, filesystem);
@@ -787,6 +1100,30 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             )
             .await
         }
+        Commands::Env(EnvNamespace {
+            command: EnvCommand::Remove(args),
+        }) => {
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::env_remove(&project_dir, args.all, &cache, printer).await
+        }
+        Commands::Workspace(WorkspaceNamespace {
+            command: WorkspaceCommand::List(args),
+        }) => {
+            show_settings!(args);
+
+            commands::workspace_list(&project_dir, args.output_format, printer).await
+        }
+        Commands::Workspace(WorkspaceNamespace {
+            command: WorkspaceCommand::Graph(args),
+        }) => {
+            show_settings!(args);
+
+            commands::workspace_graph(&project_dir, args.output_format, printer).await
+        }
         Commands::Project(project) => {
             Box::pin(run_project(
                 project,
@@ -806,22 +1143,76 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             command:
                 SelfCommand::Update(SelfUpdateArgs {
                     target_version,
+                    channel,
                     token,
                 }),
-        }) => commands::self_update(target_version, token, printer).await,
+        }) => {
+            commands::self_update(
+                target_version,
+                channel,
+                token,
+                globals.connectivity,
+                globals.native_tls,
+                printer,
+            )
+            .await
+        }
+        Commands::Self_(SelfNamespace {
+            command:
+                SelfCommand::Uninstall(SelfUninstallArgs {
+                    managed_python,
+                    dry_run,
+                }),
+        }) => commands::self_uninstall(managed_python, dry_run, &cache, printer).await,
         #[cfg(not(feature = "self-update"))]
-        Commands::Self_(_) => {
+        Commands::Self_(SelfNamespace {
+            command: SelfCommand::Update(_),
+        }) => {
             anyhow::bail!(
                 "uv was installed through an external package manager, and self-update \
                 is not available. Please use your package manager to update uv."
             );
         }
-        Commands::Version { output_format } => {
-            commands::version(output_format, &mut stdout())?;
+        Commands::Version(args) if args.is_project() => {
+            show_settings!(args);
+
+            commands::project_version(
+                &project_dir,
+                args.value,
+                args.bump,
+                args.package,
+                args.all_packages,
+                args.dry_run,
+                args.output_format,
+                printer,
+            )
+            .await
+        }
+        Commands::Version(args) => {
+            commands::version(args.output_format, &mut stdout())?;
             Ok(ExitStatus::Success)
         }
         Commands::GenerateShellCompletion(args) => {
-            args.shell.generate(&mut Cli::command(), &mut stdout());
+            // In preview, register uv itself as the completer where supported, such that argument
+            // values (e.g., package names and dependency groups) are completed dynamically.
+            // Otherwise, generate a static completion script.
+            let shells = Shells::builtins();
+            let completer = args
+                .shell
+                .to_possible_value()
+                .filter(|_| globals.preview.is_enabled())
+                .and_then(|value| shells.completer(value.get_name()));
+            if let Some(completer) = completer {
+                completer.write_registration(
+                    EnvVars::UV_COMPLETE,
+                    "uv",
+                    "uv",
+                    "uv",
+                    &mut stdout(),
+                )?;
+            } else {
+                args.shell.generate(&mut Cli::command(), &mut stdout());
+            }
             Ok(ExitStatus::Success)
         }
         Commands::Tool(ToolNamespace {
@@ -934,6 +1325,8 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 )
                 .collect::<Vec<_>>();
 
+            let launcher_resources = args.launcher.resources()?;
+
             Box::pin(commands::tool_install(
                 args.package,
                 args.editable,
@@ -941,8 +1334,11 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 &requirements,
                 args.python,
                 args.force,
+                args.frozen,
+                args.install_completions,
                 args.options,
                 args.settings,
+                launcher_resources.as_ref(),
                 globals.python_preference,
                 globals.python_downloads,
                 globals.connectivity,
@@ -981,12 +1377,42 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             // Initialize the cache.
             let cache = cache.init()?.with_refresh(Refresh::All(Timestamp::now()));
 
+            let launcher_resources = args.launcher.resources()?;
+
             Box::pin(commands::tool_upgrade(
                 args.name,
                 args.python,
                 globals.connectivity,
                 args.args,
                 args.filesystem,
+                launcher_resources.as_ref(),
+                globals.python_preference,
+                globals.python_downloads,
+                globals.concurrency,
+                globals.native_tls,
+                &cache,
+                printer,
+            ))
+            .await
+        }
+        Commands::Tool(ToolNamespace {
+            command: ToolCommand::Sync(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::ToolSyncSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            let launcher_resources = args.launcher.resources()?;
+
+            Box::pin(commands::tool_sync(
+                args.name,
+                globals.connectivity,
+                args.args,
+                args.filesystem,
+                launcher_resources.as_ref(),
                 globals.python_preference,
                 globals.python_downloads,
                 globals.concurrency,
@@ -1005,6 +1431,36 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
 
             commands::tool_uninstall(args.name, printer).await
         }
+        Commands::Tool(ToolNamespace {
+            command:
+                ToolCommand::Alias(ToolAliasNamespace {
+                    command: ToolAliasCommand::Add(args),
+                }),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::ToolAliasAddSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::tool_alias_add(args.name, args.command, printer).await
+        }
+        Commands::Tool(ToolNamespace {
+            command:
+                ToolCommand::Alias(ToolAliasNamespace {
+                    command: ToolAliasCommand::Remove(args),
+                }),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::ToolAliasRemoveSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::tool_alias_remove(args.name, printer).await
+        }
+        Commands::Tool(ToolNamespace {
+            command:
+                ToolCommand::Alias(ToolAliasNamespace {
+                    command: ToolAliasCommand::List,
+                }),
+        }) => commands::tool_alias_list(printer).await,
         Commands::Tool(ToolNamespace {
             command: ToolCommand::UpdateShell,
         }) => {
@@ -1035,6 +1491,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.kinds,
                 args.all_versions,
                 args.all_platforms,
+                args.output_format,
                 globals.python_preference,
                 globals.python_downloads,
                 &cache,
@@ -1053,6 +1510,8 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 &project_dir,
                 args.targets,
                 args.reinstall,
+                args.variant,
+                args.registry,
                 globals.python_downloads,
                 globals.native_tls,
                 globals.connectivity,
@@ -1086,6 +1545,8 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.no_project,
                 cli.top_level.no_config,
                 args.system,
+                args.arch,
+                args.variant,
                 globals.python_preference,
                 &cache,
             )
@@ -1104,6 +1565,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 &project_dir,
                 args.request,
                 args.resolved,
+                args.variant,
                 globals.python_preference,
                 args.no_project,
                 &cache,
@@ -1193,6 +1655,12 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         .await
         .expect("tokio threadpool exited unexpectedly"),
     };
+
+    // Persist the cache activity, for `uv stats`.
+    if let Err(err) = recorded_cache.persist_stats() {
+        debug!("Failed to persist cache statistics: {err}");
+    }
+
     result
 }
 
@@ -1292,10 +1760,13 @@ async fn run_project(
                 args.locked,
                 args.frozen,
                 args.no_sync,
+                args.no_hooks,
                 args.isolated,
                 args.package,
                 args.no_project,
                 no_config,
+                args.env_file,
+                args.no_env_file,
                 args.extras,
                 args.dev,
                 args.editable,
@@ -1333,6 +1804,8 @@ async fn run_project(
                 args.editable,
                 args.install_options,
                 args.modifications,
+                args.report.as_deref(),
+                args.no_hooks,
                 args.python,
                 globals.python_preference,
                 globals.python_downloads,
@@ -1361,6 +1834,12 @@ async fn run_project(
                 args.locked,
                 args.frozen,
                 args.dry_run,
+                args.check_bounds,
+                args.check_platforms,
+                args.output_format,
+                args.migrate_format,
+                args.graph_output,
+                args.no_hooks,
                 args.python,
                 args.settings,
                 globals.python_preference,
@@ -1401,6 +1880,7 @@ async fn run_project(
                 args.locked,
                 args.frozen,
                 args.no_sync,
+                args.no_hooks,
                 requirements,
                 args.editable,
                 args.dependency_type,
@@ -1409,6 +1889,7 @@ async fn run_project(
                 args.rev,
                 args.tag,
                 args.branch,
+                args.bookmark,
                 args.extras,
                 args.package,
                 args.python,
@@ -1448,8 +1929,10 @@ async fn run_project(
                 args.locked,
                 args.frozen,
                 args.no_sync,
+                args.no_hooks,
                 args.packages,
                 args.dependency_type,
+                args.move_to,
                 args.package,
                 args.python,
                 args.settings,
@@ -1508,6 +1991,8 @@ async fn run_project(
             commands::export(
                 project_dir,
                 args.format,
+                args.entry_point,
+                args.zipapp_python,
                 args.package,
                 args.hashes,
                 args.install_options,
@@ -1531,6 +2016,156 @@ async fn run_project(
             )
             .await
         }
+        ProjectCommand::Deploy(args) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::DeploySettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?.with_refresh(
+                args.refresh
+                    .combine(Refresh::from(args.settings.reinstall.clone()))
+                    .combine(Refresh::from(args.settings.upgrade.clone())),
+            );
+
+            commands::deploy(
+                project_dir,
+                &args.prefix,
+                args.package,
+                args.extras,
+                args.install_options,
+                args.frozen,
+                args.python,
+                globals.python_preference,
+                globals.python_downloads,
+                args.settings,
+                globals.connectivity,
+                globals.concurrency,
+                globals.native_tls,
+                &cache,
+                printer,
+            )
+            .await
+        }
+        ProjectCommand::Vendor(args) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::VendorSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?.with_refresh(
+                args.refresh
+                    .combine(Refresh::from(args.settings.upgrade.clone())),
+            );
+
+            commands::vendor(
+                project_dir,
+                args.output_dir,
+                args.locked,
+                args.frozen,
+                args.python,
+                args.settings,
+                globals.python_preference,
+                globals.python_downloads,
+                globals.connectivity,
+                globals.concurrency,
+                globals.native_tls,
+                &cache,
+                printer,
+            )
+            .await
+        }
+        ProjectCommand::Licenses(args) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::LicensesSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?.with_refresh(
+                args.refresh
+                    .combine(Refresh::from(args.settings.upgrade.clone())),
+            );
+
+            commands::licenses(
+                project_dir,
+                args.policy.as_deref(),
+                args.output_format,
+                args.locked,
+                args.frozen,
+                args.python,
+                args.settings,
+                globals.python_preference,
+                globals.python_downloads,
+                globals.connectivity,
+                globals.concurrency,
+                globals.native_tls,
+                &cache,
+                printer,
+            )
+            .await
+        }
+        ProjectCommand::Outdated(args) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::OutdatedSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?.with_refresh(
+                args.refresh
+                    .combine(Refresh::from(args.settings.upgrade.clone())),
+            );
+
+            commands::outdated(
+                project_dir,
+                args.output_format,
+                args.locked,
+                args.frozen,
+                args.python,
+                args.settings,
+                globals.python_preference,
+                globals.python_downloads,
+                globals.connectivity,
+                globals.concurrency,
+                globals.native_tls,
+                &cache,
+                printer,
+            )
+            .await
+        }
+        ProjectCommand::Bundle(args) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::BundleSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?.with_refresh(
+                args.refresh
+                    .combine(Refresh::from(args.settings.reinstall.clone()))
+                    .combine(Refresh::from(args.settings.upgrade.clone())),
+            );
+
+            commands::bundle(
+                project_dir,
+                args.output_file,
+                args.include_python,
+                args.package,
+                args.extras,
+                args.dev,
+                args.install_options,
+                args.locked,
+                args.frozen,
+                args.python,
+                globals.python_preference,
+                globals.python_downloads,
+                args.settings,
+                globals.connectivity,
+                globals.concurrency,
+                globals.native_tls,
+                &cache,
+                printer,
+            )
+            .await
+        }
     }
 }
 
@@ -1546,6 +2181,12 @@ where
     I: IntoIterator<Item = T>,
     T: Into<OsString> + Clone,
 {
+    // Respond to completion requests from the scripts registered by `uv generate-shell-completion`,
+    // exiting after the candidates are written.
+    CompleteEnv::with_factory(Cli::command)
+        .var(EnvVars::UV_COMPLETE)
+        .complete();
+
     // `std::env::args` is not `Send` so we parse before passing to our runtime
     // https://github.com/rust-lang/rust/pull/48005
     let cli = match Cli::try_parse_from(args) {
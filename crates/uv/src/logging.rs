@@ -4,6 +4,12 @@ use std::str::FromStr;
 use anstream::ColorChoice;
 use anyhow::Context;
 use jiff::Timestamp;
+#[cfg(feature = "tracing-otlp-export")]
+use opentelemetry::trace::TracerProvider as _;
+#[cfg(feature = "tracing-otlp-export")]
+use opentelemetry::KeyValue;
+#[cfg(feature = "tracing-otlp-export")]
+use opentelemetry_sdk::Resource;
 use owo_colors::OwoColorize;
 use tracing::{Event, Subscriber};
 #[cfg(feature = "tracing-durations-export")]
@@ -11,7 +17,7 @@ use tracing_durations_export::{
     plot::PlotConfig, DurationsLayer, DurationsLayerBuilder, DurationsLayerDropGuard,
 };
 use tracing_subscriber::filter::Directive;
-use tracing_subscriber::fmt::format::Writer;
+use tracing_subscriber::fmt::format::{FmtSpan, Writer};
 use tracing_subscriber::fmt::{FmtContext, FormatEvent, FormatFields};
 use tracing_subscriber::layer::SubscriberExt;
 use tracing_subscriber::registry::LookupSpan;
@@ -19,7 +25,8 @@ use tracing_subscriber::util::SubscriberInitExt;
 use tracing_subscriber::{EnvFilter, Layer, Registry};
 use tracing_tree::time::Uptime;
 use tracing_tree::HierarchicalLayer;
-#[cfg(feature = "tracing-durations-export")]
+use uv_cli::LogFormat;
+#[cfg(any(feature = "tracing-durations-export", feature = "tracing-otlp-export"))]
 use uv_static::EnvVars;
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
@@ -108,15 +115,20 @@ where
     }
 }
 
-/// Configure `tracing` based on the given [`Level`], taking into account the `RUST_LOG` environment
-/// variable.
+/// Configure `tracing` based on the given [`Level`] and [`LogFormat`], taking into account the
+/// `RUST_LOG` environment variable.
 ///
 /// The [`Level`] is used to dictate the default filters (which can be overridden by the `RUST_LOG`
 /// environment variable) along with the formatting of the output. For example, [`Level::Verbose`]
 /// includes targets and timestamps, along with all `uv=debug` messages by default.
+///
+/// With [`LogFormat::Json`], each message is instead written as a JSON object, along with the spans
+/// in which it was emitted.
 pub(crate) fn setup_logging(
     level: Level,
+    format: LogFormat,
     durations: impl Layer<Registry> + Send + Sync,
+    otlp: impl Layer<Registry> + Send + Sync,
 ) -> anyhow::Result<()> {
     let default_directive = match level {
         Level::Default => {
@@ -134,14 +146,18 @@ pub(crate) fn setup_logging(
         tracing_subscriber::filter::Targets::new()
             .with_target("", tracing::level_filters::LevelFilter::INFO),
     );
+    let otlp_layer = otlp.with_filter(
+        tracing_subscriber::filter::Targets::new()
+            .with_target("", tracing::level_filters::LevelFilter::INFO),
+    );
 
     let filter = EnvFilter::builder()
         .with_default_directive(default_directive)
         .from_env()
         .context("Invalid RUST_LOG directives")?;
 
-    match level {
-        Level::Default | Level::Verbose => {
+    match (level, format) {
+        (Level::Default | Level::Verbose, LogFormat::Text) => {
             // Regardless of the tracing level, show messages without any adornment.
             let format = UvFormat {
                 display_timestamp: false,
@@ -156,6 +172,7 @@ pub(crate) fn setup_logging(
             };
             tracing_subscriber::registry()
                 .with(durations_layer)
+                .with(otlp_layer)
                 .with(
                     tracing_subscriber::fmt::layer()
                         .event_format(format)
@@ -165,10 +182,11 @@ pub(crate) fn setup_logging(
                 )
                 .init();
         }
-        Level::ExtraVerbose => {
+        (Level::ExtraVerbose, LogFormat::Text) => {
             // Regardless of the tracing level, include the uptime and target for each message.
             tracing_subscriber::registry()
                 .with(durations_layer)
+                .with(otlp_layer)
                 .with(
                     HierarchicalLayer::default()
                         .with_targets(true)
@@ -178,6 +196,28 @@ pub(crate) fn setup_logging(
                 )
                 .init();
         }
+        (level, LogFormat::Json) => {
+            // Include the current span and its parents with each message. In extra-verbose mode,
+            // also emit an event when each span closes, which includes the time spent in the span.
+            let span_events = if level == Level::ExtraVerbose {
+                FmtSpan::CLOSE
+            } else {
+                FmtSpan::NONE
+            };
+            tracing_subscriber::registry()
+                .with(durations_layer)
+                .with(otlp_layer)
+                .with(
+                    tracing_subscriber::fmt::layer()
+                        .json()
+                        .with_current_span(true)
+                        .with_span_list(true)
+                        .with_span_events(span_events)
+                        .with_writer(std::io::stderr)
+                        .with_filter(filter),
+                )
+                .init();
+        }
     }
 
     Ok(())
@@ -216,3 +256,49 @@ pub(crate) fn setup_duration() -> anyhow::Result<(
         Ok((None, None))
     }
 }
+
+/// Export spans to an OpenTelemetry collector over OTLP/HTTP, if `OTEL_EXPORTER_OTLP_ENDPOINT` or
+/// `OTEL_EXPORTER_OTLP_TRACES_ENDPOINT` is set.
+///
+/// The remaining exporter configuration (e.g., headers and timeouts) is read from the standard
+/// `OTEL_EXPORTER_OTLP_*` environment variables.
+#[cfg(feature = "tracing-otlp-export")]
+pub(crate) fn setup_otlp() -> anyhow::Result<(
+    Option<tracing_opentelemetry::OpenTelemetryLayer<Registry, opentelemetry_sdk::trace::Tracer>>,
+    Option<OtlpGuard>,
+)> {
+    if std::env::var_os(EnvVars::OTEL_EXPORTER_OTLP_ENDPOINT).is_none()
+        && std::env::var_os(EnvVars::OTEL_EXPORTER_OTLP_TRACES_ENDPOINT).is_none()
+    {
+        return Ok((None, None));
+    }
+
+    // Export spans from a dedicated thread, such that exports don't block the main runtime.
+    let provider = opentelemetry_otlp::new_pipeline()
+        .tracing()
+        .with_exporter(opentelemetry_otlp::new_exporter().http())
+        .with_trace_config(opentelemetry_sdk::trace::Config::default().with_resource(
+            Resource::new([
+                KeyValue::new("service.name", "uv"),
+                KeyValue::new("service.version", uv_version::version()),
+            ]),
+        ))
+        .install_batch(opentelemetry_sdk::runtime::TokioCurrentThread)
+        .context("Failed to initialize the OpenTelemetry exporter")?;
+    let layer = tracing_opentelemetry::layer().with_tracer(provider.tracer("uv"));
+
+    Ok((Some(layer), Some(OtlpGuard(provider))))
+}
+
+/// Flushes any pending spans to the OpenTelemetry collector when dropped.
+#[cfg(feature = "tracing-otlp-export")]
+pub(crate) struct OtlpGuard(opentelemetry_sdk::trace::TracerProvider);
+
+#[cfg(feature = "tracing-otlp-export")]
+impl Drop for OtlpGuard {
+    fn drop(&mut self) {
+        if let Err(err) = self.0.shutdown() {
+            tracing::debug!("Failed to export traces: {err}");
+        }
+    }
+}
@@ -1,36 +1,51 @@
+use std::collections::BTreeMap;
 use std::env::VarError;
 use std::num::NonZeroUsize;
 use std::path::PathBuf;
 use std::process;
 use std::str::FromStr;
+use std::time::Duration;
 
 use url::Url;
 use uv_cache::{CacheArgs, Refresh};
 use uv_cli::{
     options::{flag, resolver_installer_options, resolver_options},
-    AuthorFrom, BuildArgs, ExportArgs, PublishArgs, PythonDirArgs, ToolUpgradeArgs,
+    AuthLoginArgs, AuthLogoutArgs, AuthTokenArgs, AuthorFrom, BuildArgs, BundleArgs, DeployArgs,
+    DoctorArgs, DoctorFormat, ExportArgs, InfoArgs, InfoFormat, LicensesArgs, LicensesFormat,
+    OutdatedArgs, OutdatedFormat, PublishArgs, PythonDirArgs, SearchArgs, SearchFormat,
+    ToolSyncArgs, ToolUpgradeArgs, VendorArgs,
 };
 use uv_cli::{
-    AddArgs, ColorChoice, ExternalCommand, GlobalArgs, InitArgs, ListFormat, LockArgs, Maybe,
-    PipCheckArgs, PipCompileArgs, PipFreezeArgs, PipInstallArgs, PipListArgs, PipShowArgs,
-    PipSyncArgs, PipTreeArgs, PipUninstallArgs, PythonFindArgs, PythonInstallArgs, PythonListArgs,
-    PythonPinArgs, PythonUninstallArgs, RemoveArgs, RunArgs, SyncArgs, ToolDirArgs,
-    ToolInstallArgs, ToolListArgs, ToolRunArgs, ToolUninstallArgs, TreeArgs, VenvArgs,
+    AddArgs, ColorChoice, ExternalCommand, FreezeFormat, GlobalArgs, InitArgs, ListFormat,
+    LockArgs, LockFormat, LogFormat, Maybe, OfflineMode, PipCheckArgs, PipCompileArgs,
+    PipFreezeArgs, PipInstallArgs, PipListArgs, PipShowArgs, PipSyncArgs, PipTreeArgs,
+    PipUninstallArgs, PipVerifyArgs, PythonFindArgs, PythonInstallArgs, PythonListArgs,
+    PythonListFormat, PythonPinArgs, PythonUninstallArgs, RemoveArgs, RunArgs, SyncArgs,
+    ToolAliasAddArgs, ToolAliasRemoveArgs, ToolDirArgs, ToolInstallArgs, ToolListArgs, ToolRunArgs,
+    ToolUninstallArgs, TreeArgs, VenvArgs,
 };
-use uv_client::Connectivity;
+use uv_client::{Connectivity, RetrySettings};
 use uv_configuration::{
-    BuildOptions, Concurrency, ConfigSettings, DevGroupsSpecification, EditableMode, ExportFormat,
-    ExtrasSpecification, HashCheckingMode, IndexStrategy, InstallOptions, KeyringProviderType,
-    NoBinary, NoBuild, PreviewMode, ProjectBuildBackend, Reinstall, SourceStrategy, TargetTriple,
-    TrustedHost, TrustedPublishing, Upgrade, VersionControlSystem,
+    BuildContainers, BuildOptions, BytecodeOptions, Concurrency, ConfigSettings,
+    DevGroupsSpecification, EditableMode, ExportFormat, ExtrasSpecification, GitUpgrade,
+    HashCheckingMode, IndexStrategy, InstallOptions, KeyringProviderType, NoBinary, NoBuild,
+    PreviewMode, ProjectBuildBackend, Reinstall, SourceStrategy, TargetTriple, TrustedHost,
+    TrustedPublishing, Upgrade, VersionControlSystem,
 };
 use uv_distribution_types::{DependencyMetadata, Index, IndexLocations, IndexUrl};
+use uv_git::GitSshHost;
 use uv_install_wheel::linker::LinkMode;
+use uv_install_wheel::LauncherResources;
 use uv_normalize::PackageName;
+use uv_pep440::VersionSpecifiers;
 use uv_pep508::{ExtraName, RequirementOrigin};
 use uv_pypi_types::{Requirement, SupportedEnvironments};
-use uv_python::{Prefix, PythonDownloads, PythonPreference, PythonVersion, Target};
-use uv_resolver::{AnnotationStyle, DependencyMode, ExcludeNewer, PrereleaseMode, ResolutionMode};
+use uv_python::platform::Arch;
+use uv_python::{Prefix, PythonDownloads, PythonPreference, PythonVariant, PythonVersion, Target};
+use uv_resolver::{
+    AnnotationStyle, DependencyMode, ExcludeNewer, LockfileFormat, PrereleaseMode,
+    ResolutionBudget, ResolutionMode, YankedMode,
+};
 use uv_settings::{
     Combine, FilesystemOptions, Options, PipOptions, PublishOptions, ResolverInstallerOptions,
     ResolverOptions,
@@ -39,8 +54,9 @@ use uv_static::EnvVars;
 use uv_warnings::warn_user_once;
 use uv_workspace::pyproject::DependencyType;
 
+use crate::commands::pip::operations::{DryRun, Modifications};
 use crate::commands::ToolRunCommand;
-use crate::commands::{pip::operations::Modifications, InitKind, InitProjectKind};
+use crate::commands::{InitKind, InitProjectKind};
 
 /// The default publish URL.
 const PYPI_PUBLISH_URL: &str = "https://upload.pypi.org/legacy/";
@@ -51,6 +67,7 @@ const PYPI_PUBLISH_URL: &str = "https://upload.pypi.org/legacy/";
 pub(crate) struct GlobalSettings {
     pub(crate) quiet: bool,
     pub(crate) verbose: u8,
+    pub(crate) log_format: LogFormat,
     pub(crate) color: ColorChoice,
     pub(crate) native_tls: bool,
     pub(crate) concurrency: Concurrency,
@@ -60,6 +77,10 @@ pub(crate) struct GlobalSettings {
     pub(crate) python_preference: PythonPreference,
     pub(crate) python_downloads: PythonDownloads,
     pub(crate) no_progress: bool,
+    pub(crate) git_ssh: BTreeMap<String, GitSshHost>,
+    pub(crate) no_git_sparse_checkout: bool,
+    pub(crate) required_version: Option<VersionSpecifiers>,
+    pub(crate) http_retry: RetrySettings,
 }
 
 impl GlobalSettings {
@@ -74,6 +95,7 @@ impl GlobalSettings {
         Self {
             quiet: args.quiet,
             verbose: args.verbose,
+            log_format: args.log_format,
             color: if args.no_color
                 || std::env::var_os(EnvVars::NO_COLOR)
                     .filter(|v| !v.is_empty())
@@ -99,7 +121,15 @@ impl GlobalSettings {
                     .combine(workspace.and_then(|workspace| workspace.globals.concurrent_downloads))
                     .map(NonZeroUsize::get)
                     .unwrap_or(Concurrency::DEFAULT_DOWNLOADS),
-                builds: env(env::CONCURRENT_BUILDS)
+                downloads_per_host: env(env::CONCURRENT_DOWNLOADS_PER_HOST)
+                    .combine(
+                        workspace
+                            .and_then(|workspace| workspace.globals.concurrent_downloads_per_host),
+                    )
+                    .map(NonZeroUsize::get),
+                builds: args
+                    .build_jobs
+                    .combine(env(env::CONCURRENT_BUILDS))
                     .combine(workspace.and_then(|workspace| workspace.globals.concurrent_builds))
                     .map(NonZeroUsize::get)
                     .unwrap_or_else(Concurrency::threads),
@@ -108,7 +138,9 @@ impl GlobalSettings {
                     .map(NonZeroUsize::get)
                     .unwrap_or_else(Concurrency::threads),
             },
-            connectivity: if flag(args.offline, args.no_offline)
+            connectivity: if args.offline == Some(Some(OfflineMode::Strict)) {
+                Connectivity::StrictOffline
+            } else if flag(args.offline.is_some(), args.no_offline)
                 .combine(workspace.and_then(|workspace| workspace.globals.offline))
                 .unwrap_or(false)
             {
@@ -128,6 +160,24 @@ impl GlobalSettings {
                 .combine(workspace.and_then(|workspace| workspace.globals.python_downloads))
                 .unwrap_or_default(),
             no_progress: args.no_progress,
+            git_ssh: workspace
+                .and_then(|workspace| workspace.globals.git_ssh.clone())
+                .unwrap_or_default(),
+            no_git_sparse_checkout: env(env::NO_GIT_SPARSE_CHECKOUT)
+                .or_else(|| {
+                    workspace.and_then(|workspace| workspace.globals.no_git_sparse_checkout)
+                })
+                .unwrap_or(false),
+            required_version: workspace
+                .and_then(|workspace| workspace.globals.required_version.clone()),
+            http_retry: RetrySettings {
+                retries: workspace.and_then(|workspace| workspace.globals.http_retries),
+                backoff: workspace.and_then(|workspace| workspace.globals.http_retry_backoff),
+                retry_status: workspace
+                    .and_then(|workspace| workspace.globals.http_retry_status.clone()),
+                connect_timeout: workspace
+                    .and_then(|workspace| workspace.globals.http_connect_timeout),
+            },
         }
     }
 }
@@ -138,6 +188,10 @@ impl GlobalSettings {
 pub(crate) struct CacheSettings {
     pub(crate) no_cache: bool,
     pub(crate) cache_dir: Option<PathBuf>,
+    pub(crate) cache_namespace: Option<String>,
+    pub(crate) requirements_cache_ttl: Option<u64>,
+    pub(crate) build_cache_url: Option<Url>,
+    pub(crate) build_cache_upload: bool,
 }
 
 impl CacheSettings {
@@ -151,6 +205,18 @@ impl CacheSettings {
             cache_dir: args
                 .cache_dir
                 .or_else(|| workspace.and_then(|workspace| workspace.globals.cache_dir.clone())),
+            cache_namespace: args.cache_namespace.or_else(|| {
+                workspace.and_then(|workspace| workspace.globals.cache_namespace.clone())
+            }),
+            requirements_cache_ttl: args.requirements_cache_ttl.or_else(|| {
+                workspace.and_then(|workspace| workspace.globals.requirements_cache_ttl)
+            }),
+            build_cache_url: env(env::BUILD_CACHE_URL).or_else(|| {
+                workspace.and_then(|workspace| workspace.globals.build_cache_url.clone())
+            }),
+            build_cache_upload: env(env::BUILD_CACHE_UPLOAD)
+                .or_else(|| workspace.and_then(|workspace| workspace.globals.build_cache_upload))
+                .unwrap_or(false),
         }
     }
 }
@@ -238,6 +304,9 @@ pub(crate) struct RunSettings {
     pub(crate) package: Option<PackageName>,
     pub(crate) no_project: bool,
     pub(crate) no_sync: bool,
+    pub(crate) no_hooks: bool,
+    pub(crate) env_file: Vec<PathBuf>,
+    pub(crate) no_env_file: bool,
     pub(crate) python: Option<String>,
     pub(crate) refresh: Refresh,
     pub(crate) settings: ResolverInstallerSettings,
@@ -260,12 +329,16 @@ impl RunSettings {
             only_dev,
             no_editable,
             script: _,
+            gui_script: _,
             command: _,
             with,
             with_editable,
             with_requirements,
             isolated,
             no_sync,
+            no_hooks,
+            env_file,
+            no_env_file,
             locked,
             frozen,
             installer,
@@ -299,6 +372,9 @@ impl RunSettings {
             package,
             no_project,
             no_sync,
+            no_hooks,
+            env_file,
+            no_env_file,
             python: python.and_then(Maybe::into_option),
             refresh: Refresh::from(refresh),
             settings: ResolverInstallerSettings::combine(
@@ -387,6 +463,46 @@ impl ToolRunSettings {
     }
 }
 
+/// The resolved settings for the Windows launchers of tool entrypoints.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct LauncherSettings {
+    pub(crate) product_name: Option<String>,
+    pub(crate) version: Option<String>,
+    pub(crate) icon: Option<PathBuf>,
+}
+
+impl LauncherSettings {
+    /// Resolve the [`LauncherSettings`] from the environment and filesystem configuration.
+    fn resolve(filesystem: Option<&FilesystemOptions>) -> Self {
+        let globals = filesystem.map(|filesystem| &filesystem.globals);
+        Self {
+            product_name: env(env::LAUNCHER_PRODUCT_NAME)
+                .filter(|value: &String| !value.is_empty())
+                .or_else(|| globals.and_then(|globals| globals.launcher_product_name.clone())),
+            version: env(env::LAUNCHER_VERSION)
+                .filter(|value: &String| !value.is_empty())
+                .or_else(|| globals.and_then(|globals| globals.launcher_version.clone())),
+            icon: env(env::LAUNCHER_ICON)
+                .filter(|value: &PathBuf| !value.as_os_str().is_empty())
+                .or_else(|| globals.and_then(|globals| globals.launcher_icon.clone())),
+        }
+    }
+
+    /// Read the [`LauncherResources`] to embed into the launchers, if any.
+    ///
+    /// Launchers are only created on Windows, so nothing is read on other platforms.
+    pub(crate) fn resources(&self) -> Result<Option<LauncherResources>, uv_install_wheel::Error> {
+        if cfg!(not(windows)) {
+            return Ok(None);
+        }
+        Ok(LauncherResources::new(
+            self.product_name.as_deref(),
+            self.version.as_deref(),
+            self.icon.as_deref(),
+        )?)
+    }
+}
+
 /// The resolved settings to use for a `tool install` invocation.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
@@ -402,6 +518,9 @@ pub(crate) struct ToolInstallSettings {
     pub(crate) settings: ResolverInstallerSettings,
     pub(crate) force: bool,
     pub(crate) editable: bool,
+    pub(crate) frozen: bool,
+    pub(crate) install_completions: bool,
+    pub(crate) launcher: LauncherSettings,
 }
 
 impl ToolInstallSettings {
@@ -417,11 +536,14 @@ impl ToolInstallSettings {
             with_requirements,
             installer,
             force,
+            frozen,
+            install_completions,
             build,
             refresh,
             python,
         } = args;
 
+        let launcher = LauncherSettings::resolve(filesystem.as_ref());
         let options = resolver_installer_options(installer, build).combine(
             filesystem
                 .map(FilesystemOptions::into_options)
@@ -443,9 +565,12 @@ impl ToolInstallSettings {
             python: python.and_then(Maybe::into_option),
             force,
             editable,
+            frozen,
+            install_completions,
             refresh: Refresh::from(refresh),
             options,
             settings,
+            launcher,
         }
     }
 }
@@ -458,6 +583,7 @@ pub(crate) struct ToolUpgradeSettings {
     pub(crate) python: Option<String>,
     pub(crate) args: ResolverInstallerOptions,
     pub(crate) filesystem: ResolverInstallerOptions,
+    pub(crate) launcher: LauncherSettings,
 }
 
 impl ToolUpgradeSettings {
@@ -481,6 +607,7 @@ impl ToolUpgradeSettings {
         }
 
         let args = resolver_installer_options(installer, build);
+        let launcher = LauncherSettings::resolve(filesystem.as_ref());
         let filesystem = filesystem
             .map(FilesystemOptions::into_options)
             .map(|options| options.top_level)
@@ -491,6 +618,42 @@ impl ToolUpgradeSettings {
             python: python.and_then(Maybe::into_option),
             args,
             filesystem,
+            launcher,
+        }
+    }
+}
+
+/// The resolved settings to use for a `tool sync` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct ToolSyncSettings {
+    pub(crate) name: Vec<PackageName>,
+    pub(crate) args: ResolverInstallerOptions,
+    pub(crate) filesystem: ResolverInstallerOptions,
+    pub(crate) launcher: LauncherSettings,
+}
+
+impl ToolSyncSettings {
+    /// Resolve the [`ToolSyncSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: ToolSyncArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let ToolSyncArgs {
+            name,
+            installer,
+            build,
+        } = args;
+
+        let args = resolver_installer_options(installer, build);
+        let launcher = LauncherSettings::resolve(filesystem.as_ref());
+        let filesystem = filesystem
+            .map(FilesystemOptions::into_options)
+            .map(|options| options.top_level)
+            .unwrap_or_default();
+
+        Self {
+            name,
+            args,
+            filesystem,
+            launcher,
         }
     }
 }
@@ -540,6 +703,42 @@ impl ToolUninstallSettings {
     }
 }
 
+/// The resolved settings to use for a `tool alias add` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct ToolAliasAddSettings {
+    pub(crate) name: String,
+    pub(crate) command: Vec<String>,
+}
+
+impl ToolAliasAddSettings {
+    /// Resolve the [`ToolAliasAddSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: ToolAliasAddArgs, _filesystem: Option<FilesystemOptions>) -> Self {
+        let ToolAliasAddArgs { name, command } = args;
+
+        Self { name, command }
+    }
+}
+
+/// The resolved settings to use for a `tool alias remove` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct ToolAliasRemoveSettings {
+    pub(crate) name: Vec<String>,
+}
+
+impl ToolAliasRemoveSettings {
+    /// Resolve the [`ToolAliasRemoveSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(
+        args: ToolAliasRemoveArgs,
+        _filesystem: Option<FilesystemOptions>,
+    ) -> Self {
+        let ToolAliasRemoveArgs { name } = args;
+
+        Self { name }
+    }
+}
+
 /// The resolved settings to use for a `tool dir` invocation.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
@@ -571,6 +770,7 @@ pub(crate) struct PythonListSettings {
     pub(crate) kinds: PythonListKinds,
     pub(crate) all_platforms: bool,
     pub(crate) all_versions: bool,
+    pub(crate) output_format: PythonListFormat,
 }
 
 impl PythonListSettings {
@@ -581,6 +781,7 @@ impl PythonListSettings {
             all_versions,
             all_platforms,
             only_installed,
+            output_format,
         } = args;
 
         let kinds = if only_installed {
@@ -593,6 +794,7 @@ impl PythonListSettings {
             kinds,
             all_platforms,
             all_versions,
+            output_format,
         }
     }
 }
@@ -620,15 +822,28 @@ impl PythonDirSettings {
 pub(crate) struct PythonInstallSettings {
     pub(crate) targets: Vec<String>,
     pub(crate) reinstall: bool,
+    pub(crate) variant: Option<PythonVariant>,
+    pub(crate) registry: bool,
 }
 
 impl PythonInstallSettings {
     /// Resolve the [`PythonInstallSettings`] from the CLI and filesystem configuration.
     #[allow(clippy::needless_pass_by_value)]
     pub(crate) fn resolve(args: PythonInstallArgs, _filesystem: Option<FilesystemOptions>) -> Self {
-        let PythonInstallArgs { targets, reinstall } = args;
+        let PythonInstallArgs {
+            targets,
+            reinstall,
+            variant,
+            registry,
+            no_registry,
+        } = args;
 
-        Self { targets, reinstall }
+        Self {
+            targets,
+            reinstall,
+            variant,
+            registry: flag(registry, no_registry).unwrap_or_default(),
+        }
     }
 }
 
@@ -660,6 +875,8 @@ pub(crate) struct PythonFindSettings {
     pub(crate) request: Option<String>,
     pub(crate) no_project: bool,
     pub(crate) system: bool,
+    pub(crate) arch: Option<Arch>,
+    pub(crate) variant: Option<PythonVariant>,
 }
 
 impl PythonFindSettings {
@@ -671,12 +888,16 @@ impl PythonFindSettings {
             no_project,
             system,
             no_system,
+            arch,
+            variant,
         } = args;
 
         Self {
             request,
             no_project,
             system: flag(system, no_system).unwrap_or_default(),
+            arch,
+            variant,
         }
     }
 }
@@ -687,6 +908,7 @@ impl PythonFindSettings {
 pub(crate) struct PythonPinSettings {
     pub(crate) request: Option<String>,
     pub(crate) resolved: bool,
+    pub(crate) variant: Option<PythonVariant>,
     pub(crate) no_project: bool,
 }
 
@@ -698,12 +920,14 @@ impl PythonPinSettings {
             request,
             no_resolved,
             resolved,
+            variant,
             no_project,
         } = args;
 
         Self {
             request,
             resolved: flag(resolved, no_resolved).unwrap_or(false),
+            variant,
             no_project,
         }
     }
@@ -720,6 +944,8 @@ pub(crate) struct SyncSettings {
     pub(crate) editable: EditableMode,
     pub(crate) install_options: InstallOptions,
     pub(crate) modifications: Modifications,
+    pub(crate) report: Option<PathBuf>,
+    pub(crate) no_hooks: bool,
     pub(crate) package: Option<PackageName>,
     pub(crate) python: Option<String>,
     pub(crate) refresh: Refresh,
@@ -746,8 +972,11 @@ impl SyncSettings {
             no_install_project,
             no_install_workspace,
             no_install_package,
+            report,
+            no_hooks,
             locked,
             frozen,
+            minimal_changes,
             installer,
             build,
             refresh,
@@ -755,10 +984,13 @@ impl SyncSettings {
             python,
         } = args;
 
-        let settings = ResolverInstallerSettings::combine(
-            resolver_installer_options(installer, build),
-            filesystem,
-        );
+        let settings = ResolverInstallerSettings {
+            minimal_changes,
+            ..ResolverInstallerSettings::combine(
+                resolver_installer_options(installer, build),
+                filesystem,
+            )
+        };
 
         Self {
             locked,
@@ -781,6 +1013,8 @@ impl SyncSettings {
             } else {
                 Modifications::Sufficient
             },
+            report,
+            no_hooks,
             package,
             python: python.and_then(Maybe::into_option),
             refresh: Refresh::from(refresh),
@@ -796,6 +1030,12 @@ pub(crate) struct LockSettings {
     pub(crate) locked: bool,
     pub(crate) frozen: bool,
     pub(crate) dry_run: bool,
+    pub(crate) check_bounds: bool,
+    pub(crate) check_platforms: bool,
+    pub(crate) output_format: LockFormat,
+    pub(crate) migrate_format: Option<LockfileFormat>,
+    pub(crate) graph_output: Option<PathBuf>,
+    pub(crate) no_hooks: bool,
     pub(crate) python: Option<String>,
     pub(crate) refresh: Refresh,
     pub(crate) settings: ResolverSettings,
@@ -809,6 +1049,16 @@ impl LockSettings {
             locked,
             frozen,
             dry_run,
+            check_bounds,
+            check_platforms,
+            output_format,
+            migrate_format,
+            graph_output,
+            no_hooks,
+            preference_file,
+            minimal_changes,
+            upgrade_git,
+            upgrade_git_package,
             resolver,
             build,
             refresh,
@@ -819,9 +1069,20 @@ impl LockSettings {
             locked,
             frozen,
             dry_run,
+            check_bounds,
+            check_platforms,
+            output_format,
+            migrate_format,
+            graph_output,
+            no_hooks,
             python: python.and_then(Maybe::into_option),
             refresh: Refresh::from(refresh),
-            settings: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
+            settings: ResolverSettings {
+                preference_files: preference_file,
+                minimal_changes,
+                upgrade_git: GitUpgrade::from_args(upgrade_git, upgrade_git_package),
+                ..ResolverSettings::combine(resolver_options(resolver, build), filesystem)
+            },
         }
     }
 }
@@ -833,6 +1094,7 @@ pub(crate) struct AddSettings {
     pub(crate) locked: bool,
     pub(crate) frozen: bool,
     pub(crate) no_sync: bool,
+    pub(crate) no_hooks: bool,
     pub(crate) packages: Vec<String>,
     pub(crate) requirements: Vec<PathBuf>,
     pub(crate) dependency_type: DependencyType,
@@ -842,6 +1104,7 @@ pub(crate) struct AddSettings {
     pub(crate) rev: Option<String>,
     pub(crate) tag: Option<String>,
     pub(crate) branch: Option<String>,
+    pub(crate) bookmark: Option<String>,
     pub(crate) package: Option<PackageName>,
     pub(crate) script: Option<PathBuf>,
     pub(crate) python: Option<String>,
@@ -867,9 +1130,12 @@ impl AddSettings {
             rev,
             tag,
             branch,
+            bookmark,
             no_sync,
+            no_hooks,
             locked,
             frozen,
+            minimal_changes,
             installer,
             build,
             refresh,
@@ -937,6 +1203,7 @@ impl AddSettings {
             locked,
             frozen,
             no_sync,
+            no_hooks,
             packages,
             requirements,
             dependency_type,
@@ -944,6 +1211,7 @@ impl AddSettings {
             rev,
             tag,
             branch,
+            bookmark,
             package,
             script,
             python: python.and_then(Maybe::into_option),
@@ -951,10 +1219,13 @@ impl AddSettings {
             extras: extra.unwrap_or_default(),
             refresh: Refresh::from(refresh),
             indexes,
-            settings: ResolverInstallerSettings::combine(
-                resolver_installer_options(installer, build),
-                filesystem,
-            ),
+            settings: ResolverInstallerSettings {
+                minimal_changes,
+                ..ResolverInstallerSettings::combine(
+                    resolver_installer_options(installer, build),
+                    filesystem,
+                )
+            },
         }
     }
 }
@@ -966,8 +1237,10 @@ pub(crate) struct RemoveSettings {
     pub(crate) locked: bool,
     pub(crate) frozen: bool,
     pub(crate) no_sync: bool,
+    pub(crate) no_hooks: bool,
     pub(crate) packages: Vec<PackageName>,
     pub(crate) dependency_type: DependencyType,
+    pub(crate) move_to: Option<DependencyType>,
     pub(crate) package: Option<PackageName>,
     pub(crate) script: Option<PathBuf>,
     pub(crate) python: Option<String>,
@@ -984,7 +1257,11 @@ impl RemoveSettings {
             optional,
             packages,
             group,
+            move_to_group,
+            move_to_optional,
+            move_to_project,
             no_sync,
+            no_hooks,
             locked,
             frozen,
             installer,
@@ -1005,12 +1282,24 @@ impl RemoveSettings {
             DependencyType::Production
         };
 
+        let move_to = if let Some(extra) = move_to_optional {
+            Some(DependencyType::Optional(extra))
+        } else if let Some(group) = move_to_group {
+            Some(DependencyType::Group(group))
+        } else if move_to_project {
+            Some(DependencyType::Production)
+        } else {
+            None
+        };
+
         Self {
             locked,
             frozen,
             no_sync,
+            no_hooks,
             packages,
             dependency_type,
+            move_to,
             package,
             script,
             python: python.and_then(Maybe::into_option),
@@ -1088,6 +1377,8 @@ impl TreeSettings {
 #[derive(Debug, Clone)]
 pub(crate) struct ExportSettings {
     pub(crate) format: ExportFormat,
+    pub(crate) entry_point: Option<String>,
+    pub(crate) zipapp_python: Option<String>,
     pub(crate) package: Option<PackageName>,
     pub(crate) extras: ExtrasSpecification,
     pub(crate) dev: DevGroupsSpecification,
@@ -1109,6 +1400,8 @@ impl ExportSettings {
     pub(crate) fn resolve(args: ExportArgs, filesystem: Option<FilesystemOptions>) -> Self {
         let ExportArgs {
             format,
+            entry_point,
+            zipapp_python,
             package,
             extra,
             all_extras,
@@ -1139,6 +1432,8 @@ impl ExportSettings {
         Self {
             package,
             format,
+            entry_point,
+            zipapp_python,
             extras: ExtrasSpecification::from_args(
                 flag(all_extras, no_all_extras).unwrap_or_default(),
                 extra.unwrap_or_default(),
@@ -1164,17 +1459,355 @@ impl ExportSettings {
     }
 }
 
+/// The resolved settings to use for a `bundle` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct BundleSettings {
+    pub(crate) output_file: Option<PathBuf>,
+    pub(crate) include_python: Option<bool>,
+    pub(crate) package: Option<PackageName>,
+    pub(crate) extras: ExtrasSpecification,
+    pub(crate) dev: DevGroupsSpecification,
+    pub(crate) install_options: InstallOptions,
+    pub(crate) locked: bool,
+    pub(crate) frozen: bool,
+    pub(crate) python: Option<String>,
+    pub(crate) refresh: Refresh,
+    pub(crate) settings: ResolverInstallerSettings,
+}
+
+impl BundleSettings {
+    /// Resolve the [`BundleSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: BundleArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let BundleArgs {
+            output_file,
+            include_python,
+            no_include_python,
+            package,
+            extra,
+            all_extras,
+            no_all_extras,
+            dev,
+            no_dev,
+            only_dev,
+            group,
+            no_group,
+            only_group,
+            no_install_project,
+            no_install_workspace,
+            no_install_package,
+            locked,
+            frozen,
+            installer,
+            build,
+            refresh,
+            python,
+        } = args;
+
+        let settings = ResolverInstallerSettings::combine(
+            resolver_installer_options(installer, build),
+            filesystem,
+        );
+
+        Self {
+            output_file,
+            include_python: flag(include_python, no_include_python),
+            package,
+            extras: ExtrasSpecification::from_args(
+                flag(all_extras, no_all_extras).unwrap_or_default(),
+                extra.unwrap_or_default(),
+            ),
+            dev: DevGroupsSpecification::from_args(
+                dev, no_dev, only_dev, group, no_group, only_group,
+            ),
+            install_options: InstallOptions::new(
+                no_install_project,
+                no_install_workspace,
+                no_install_package,
+            ),
+            locked,
+            frozen,
+            python: python.and_then(Maybe::into_option),
+            refresh: Refresh::from(refresh),
+            settings,
+        }
+    }
+}
+
+/// The resolved settings to use for a `deploy` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct DeploySettings {
+    pub(crate) prefix: PathBuf,
+    pub(crate) package: Option<PackageName>,
+    pub(crate) extras: ExtrasSpecification,
+    pub(crate) install_options: InstallOptions,
+    pub(crate) frozen: bool,
+    pub(crate) python: Option<String>,
+    pub(crate) refresh: Refresh,
+    pub(crate) settings: ResolverInstallerSettings,
+}
+
+impl DeploySettings {
+    /// Resolve the [`DeploySettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: DeployArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let DeployArgs {
+            prefix,
+            extra,
+            all_extras,
+            no_all_extras,
+            no_install_project,
+            no_install_workspace,
+            no_install_package,
+            frozen,
+            installer,
+            build,
+            refresh,
+            package,
+            python,
+        } = args;
+
+        let settings = ResolverInstallerSettings::combine(
+            resolver_installer_options(installer, build),
+            filesystem,
+        );
+
+        Self {
+            prefix,
+            package,
+            extras: ExtrasSpecification::from_args(
+                flag(all_extras, no_all_extras).unwrap_or_default(),
+                extra.unwrap_or_default(),
+            ),
+            install_options: InstallOptions::new(
+                no_install_project,
+                no_install_workspace,
+                no_install_package,
+            ),
+            frozen,
+            python: python.and_then(Maybe::into_option),
+            refresh: Refresh::from(refresh),
+            settings,
+        }
+    }
+}
+
+/// The resolved settings to use for a `vendor` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct VendorSettings {
+    pub(crate) output_dir: Option<PathBuf>,
+    pub(crate) locked: bool,
+    pub(crate) frozen: bool,
+    pub(crate) python: Option<String>,
+    pub(crate) refresh: Refresh,
+    pub(crate) settings: ResolverSettings,
+}
+
+impl VendorSettings {
+    /// Resolve the [`VendorSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: VendorArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let VendorArgs {
+            output_dir,
+            locked,
+            frozen,
+            resolver,
+            build,
+            refresh,
+            python,
+        } = args;
+
+        Self {
+            output_dir,
+            locked,
+            frozen,
+            python: python.and_then(Maybe::into_option),
+            refresh: Refresh::from(refresh),
+            settings: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
+        }
+    }
+}
+
+/// The resolved settings to use for a `licenses` invocation.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone)]
+pub(crate) struct LicensesSettings {
+    pub(crate) policy: Option<PathBuf>,
+    pub(crate) output_format: LicensesFormat,
+    pub(crate) locked: bool,
+    pub(crate) frozen: bool,
+    pub(crate) python: Option<String>,
+    pub(crate) refresh: Refresh,
+    pub(crate) settings: ResolverSettings,
+}
+
+impl LicensesSettings {
+    /// Resolve the [`LicensesSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: LicensesArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let LicensesArgs {
+            policy,
+            output_format,
+            locked,
+            frozen,
+            resolver,
+            build,
+            refresh,
+            python,
+        } = args;
+
+        Self {
+            policy,
+            output_format,
+            locked,
+            frozen,
+            python: python.and_then(Maybe::into_option),
+            refresh: Refresh::from(refresh),
+            settings: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
+        }
+    }
+}
+
+/// The resolved settings to use for an `outdated` invocation.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone)]
+pub(crate) struct OutdatedSettings {
+    pub(crate) output_format: OutdatedFormat,
+    pub(crate) locked: bool,
+    pub(crate) frozen: bool,
+    pub(crate) python: Option<String>,
+    pub(crate) refresh: Refresh,
+    pub(crate) settings: ResolverSettings,
+}
+
+impl OutdatedSettings {
+    /// Resolve the [`OutdatedSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: OutdatedArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let OutdatedArgs {
+            output_format,
+            locked,
+            frozen,
+            resolver,
+            build,
+            refresh,
+            python,
+        } = args;
+
+        Self {
+            output_format,
+            locked,
+            frozen,
+            python: python.and_then(Maybe::into_option),
+            refresh: Refresh::from(refresh),
+            settings: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
+        }
+    }
+}
+
+/// The resolved settings to use for a `doctor` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct DoctorSettings {
+    pub(crate) output_format: DoctorFormat,
+    pub(crate) python: Option<String>,
+    pub(crate) settings: ResolverSettings,
+}
+
+impl DoctorSettings {
+    /// Resolve the [`DoctorSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: DoctorArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let DoctorArgs {
+            output_format,
+            json,
+            index_args,
+            python,
+        } = args;
+
+        Self {
+            output_format: if json {
+                DoctorFormat::Json
+            } else {
+                output_format
+            },
+            python: python.and_then(Maybe::into_option),
+            settings: ResolverSettings::combine(ResolverOptions::from(index_args), filesystem),
+        }
+    }
+}
+
+/// The resolved settings to use for a `search` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct SearchSettings {
+    pub(crate) query: String,
+    pub(crate) limit: usize,
+    pub(crate) output_format: SearchFormat,
+    pub(crate) settings: ResolverSettings,
+}
+
+impl SearchSettings {
+    /// Resolve the [`SearchSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: SearchArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let SearchArgs {
+            query,
+            limit,
+            output_format,
+            index_args,
+        } = args;
+
+        Self {
+            query,
+            limit,
+            output_format,
+            settings: ResolverSettings::combine(ResolverOptions::from(index_args), filesystem),
+        }
+    }
+}
+
+/// The resolved settings to use for an `info` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct InfoSettings {
+    pub(crate) package: PackageName,
+    pub(crate) versions: usize,
+    pub(crate) output_format: InfoFormat,
+    pub(crate) python: Option<String>,
+    pub(crate) settings: ResolverSettings,
+}
+
+impl InfoSettings {
+    /// Resolve the [`InfoSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: InfoArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let InfoArgs {
+            package,
+            versions,
+            output_format,
+            index_args,
+            python,
+        } = args;
+
+        Self {
+            package,
+            versions,
+            output_format,
+            python: python.and_then(Maybe::into_option),
+            settings: ResolverSettings::combine(ResolverOptions::from(index_args), filesystem),
+        }
+    }
+}
+
 /// The resolved settings to use for a `pip compile` invocation.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
 pub(crate) struct PipCompileSettings {
     pub(crate) src_file: Vec<PathBuf>,
     pub(crate) constraint: Vec<PathBuf>,
+    pub(crate) constraint_from_lock: Option<PathBuf>,
     pub(crate) r#override: Vec<PathBuf>,
     pub(crate) build_constraint: Vec<PathBuf>,
     pub(crate) constraints_from_workspace: Vec<Requirement>,
     pub(crate) overrides_from_workspace: Vec<Requirement>,
     pub(crate) environments: SupportedEnvironments,
+    pub(crate) python_versions: Vec<PythonVersion>,
+    pub(crate) python_platforms: Vec<TargetTriple>,
     pub(crate) refresh: Refresh,
     pub(crate) settings: PipSettings,
 }
@@ -1185,6 +1818,7 @@ impl PipCompileSettings {
         let PipCompileArgs {
             src_file,
             constraint,
+            constraint_from_lock,
             r#override,
             extra,
             all_extras,
@@ -1272,6 +1906,7 @@ impl PipCompileSettings {
                 .into_iter()
                 .filter_map(Maybe::into_option)
                 .collect(),
+            constraint_from_lock,
             build_constraint: build_constraint
                 .into_iter()
                 .filter_map(Maybe::into_option)
@@ -1283,6 +1918,8 @@ impl PipCompileSettings {
             constraints_from_workspace,
             overrides_from_workspace,
             environments,
+            python_versions: python_version.clone(),
+            python_platforms: python_platform.clone(),
             refresh: Refresh::from(refresh),
             settings: PipSettings::combine(
                 PipOptions {
@@ -1301,8 +1938,8 @@ impl PipCompileSettings {
                     no_header: flag(no_header, header),
                     custom_compile_command,
                     generate_hashes: flag(generate_hashes, no_generate_hashes),
-                    python_version,
-                    python_platform,
+                    python_version: python_version.into_iter().next(),
+                    python_platform: python_platform.into_iter().next(),
                     universal: flag(universal, no_universal),
                     no_emit_package,
                     emit_index_url: flag(emit_index_url, no_emit_index_url),
@@ -1326,7 +1963,7 @@ pub(crate) struct PipSyncSettings {
     pub(crate) src_file: Vec<PathBuf>,
     pub(crate) constraint: Vec<PathBuf>,
     pub(crate) build_constraint: Vec<PathBuf>,
-    pub(crate) dry_run: bool,
+    pub(crate) dry_run: DryRun,
     pub(crate) refresh: Refresh,
     pub(crate) settings: PipSettings,
 }
@@ -1357,11 +1994,14 @@ impl PipSyncSettings {
             build,
             no_binary,
             only_binary,
+            yanked,
+            allow_yanked,
             python_version,
             python_platform,
             strict,
             no_strict,
             dry_run,
+            output_format,
             compat_args: _,
         } = *args;
 
@@ -1375,7 +2015,7 @@ impl PipSyncSettings {
                 .into_iter()
                 .filter_map(Maybe::into_option)
                 .collect(),
-            dry_run,
+            dry_run: DryRun::from_args(dry_run, output_format),
             refresh: Refresh::from(refresh),
             settings: PipSettings::combine(
                 PipOptions {
@@ -1389,6 +2029,12 @@ impl PipSyncSettings {
                     no_build: flag(no_build, build),
                     no_binary,
                     only_binary,
+                    yanked,
+                    allow_yanked: if allow_yanked.is_empty() {
+                        None
+                    } else {
+                        Some(allow_yanked)
+                    },
                     allow_empty_requirements: flag(
                         allow_empty_requirements,
                         no_allow_empty_requirements,
@@ -1414,7 +2060,8 @@ pub(crate) struct PipInstallSettings {
     pub(crate) constraint: Vec<PathBuf>,
     pub(crate) r#override: Vec<PathBuf>,
     pub(crate) build_constraint: Vec<PathBuf>,
-    pub(crate) dry_run: bool,
+    pub(crate) dry_run: DryRun,
+    pub(crate) report: Option<PathBuf>,
     pub(crate) constraints_from_workspace: Vec<Requirement>,
     pub(crate) overrides_from_workspace: Vec<Requirement>,
     pub(crate) modifications: Modifications,
@@ -1461,6 +2108,7 @@ impl PipInstallSettings {
             strict,
             no_strict,
             dry_run,
+            report,
             compat_args: _,
         } = args;
 
@@ -1508,7 +2156,8 @@ impl PipInstallSettings {
                 .into_iter()
                 .filter_map(Maybe::into_option)
                 .collect(),
-            dry_run,
+            dry_run: DryRun::from(dry_run),
+            report,
             constraints_from_workspace,
             overrides_from_workspace,
             modifications: if flag(exact, inexact).unwrap_or(false) {
@@ -1600,6 +2249,9 @@ impl PipUninstallSettings {
 #[derive(Debug, Clone)]
 pub(crate) struct PipFreezeSettings {
     pub(crate) exclude_editable: bool,
+    pub(crate) direct_url: bool,
+    pub(crate) paths: Option<Vec<PathBuf>>,
+    pub(crate) format: FreezeFormat,
     pub(crate) settings: PipSettings,
 }
 
@@ -1608,6 +2260,10 @@ impl PipFreezeSettings {
     pub(crate) fn resolve(args: PipFreezeArgs, filesystem: Option<FilesystemOptions>) -> Self {
         let PipFreezeArgs {
             exclude_editable,
+            no_direct_url,
+            direct_url,
+            path,
+            format,
             strict,
             no_strict,
             python,
@@ -1618,6 +2274,9 @@ impl PipFreezeSettings {
 
         Self {
             exclude_editable,
+            direct_url: flag(direct_url, no_direct_url).unwrap_or(true),
+            paths: path,
+            format,
             settings: PipSettings::combine(
                 PipOptions {
                     python: python.and_then(Maybe::into_option),
@@ -1791,6 +2450,37 @@ impl PipCheckSettings {
     }
 }
 
+/// The resolved settings to use for a `pip verify` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct PipVerifySettings {
+    pub(crate) package: Vec<PackageName>,
+    pub(crate) settings: PipSettings,
+}
+
+impl PipVerifySettings {
+    /// Resolve the [`PipVerifySettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: PipVerifyArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let PipVerifyArgs {
+            package,
+            python,
+            system,
+            no_system,
+        } = args;
+
+        Self {
+            package,
+            settings: PipSettings::combine(
+                PipOptions {
+                    python: python.and_then(Maybe::into_option),
+                    system: flag(system, no_system),
+                    ..PipOptions::default()
+                },
+                filesystem,
+            ),
+        }
+    }
+}
+
 /// The resolved settings to use for a `build` invocation.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
@@ -1934,11 +2624,13 @@ pub(crate) struct InstallerSettingsRef<'a> {
     pub(crate) allow_insecure_host: &'a [TrustedHost],
     pub(crate) dependency_metadata: &'a DependencyMetadata,
     pub(crate) config_setting: &'a ConfigSettings,
+    pub(crate) build_containers: &'a BuildContainers,
     pub(crate) no_build_isolation: bool,
     pub(crate) no_build_isolation_package: &'a [PackageName],
     pub(crate) exclude_newer: Option<ExcludeNewer>,
     pub(crate) link_mode: LinkMode,
     pub(crate) compile_bytecode: bool,
+    pub(crate) bytecode_options: &'a BytecodeOptions,
     pub(crate) reinstall: &'a Reinstall,
     pub(crate) build_options: &'a BuildOptions,
     pub(crate) sources: SourceStrategy,
@@ -1957,8 +2649,13 @@ pub(crate) struct ResolverSettings {
     pub(crate) allow_insecure_host: Vec<TrustedHost>,
     pub(crate) resolution: ResolutionMode,
     pub(crate) prerelease: PrereleaseMode,
+    pub(crate) yanked: YankedMode,
+    pub(crate) allow_yanked: Vec<Requirement>,
+    pub(crate) prefer_local_version: Option<String>,
+    pub(crate) resolution_budget: ResolutionBudget,
     pub(crate) dependency_metadata: DependencyMetadata,
     pub(crate) config_setting: ConfigSettings,
+    pub(crate) build_containers: BuildContainers,
     pub(crate) no_build_isolation: bool,
     pub(crate) no_build_isolation_package: Vec<PackageName>,
     pub(crate) exclude_newer: Option<ExcludeNewer>,
@@ -1966,6 +2663,10 @@ pub(crate) struct ResolverSettings {
     pub(crate) upgrade: Upgrade,
     pub(crate) build_options: BuildOptions,
     pub(crate) sources: SourceStrategy,
+    pub(crate) variants: Vec<String>,
+    pub(crate) preference_files: Vec<PathBuf>,
+    pub(crate) minimal_changes: bool,
+    pub(crate) upgrade_git: GitUpgrade,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -1976,8 +2677,13 @@ pub(crate) struct ResolverSettingsRef<'a> {
     pub(crate) allow_insecure_host: &'a [TrustedHost],
     pub(crate) resolution: ResolutionMode,
     pub(crate) prerelease: PrereleaseMode,
+    pub(crate) yanked: YankedMode,
+    pub(crate) allow_yanked: &'a [Requirement],
+    pub(crate) prefer_local_version: Option<&'a str>,
+    pub(crate) resolution_budget: ResolutionBudget,
     pub(crate) dependency_metadata: &'a DependencyMetadata,
     pub(crate) config_setting: &'a ConfigSettings,
+    pub(crate) build_containers: &'a BuildContainers,
     pub(crate) no_build_isolation: bool,
     pub(crate) no_build_isolation_package: &'a [PackageName],
     pub(crate) exclude_newer: Option<ExcludeNewer>,
@@ -1985,6 +2691,10 @@ pub(crate) struct ResolverSettingsRef<'a> {
     pub(crate) upgrade: &'a Upgrade,
     pub(crate) build_options: &'a BuildOptions,
     pub(crate) sources: SourceStrategy,
+    pub(crate) variants: &'a [String],
+    pub(crate) preference_files: &'a [PathBuf],
+    pub(crate) minimal_changes: bool,
+    pub(crate) upgrade_git: &'a GitUpgrade,
 }
 
 impl ResolverSettings {
@@ -2008,8 +2718,13 @@ impl ResolverSettings {
             allow_insecure_host: &self.allow_insecure_host,
             resolution: self.resolution,
             prerelease: self.prerelease,
+            yanked: self.yanked,
+            allow_yanked: &self.allow_yanked,
+            prefer_local_version: self.prefer_local_version.as_deref(),
+            resolution_budget: self.resolution_budget,
             dependency_metadata: &self.dependency_metadata,
             config_setting: &self.config_setting,
+            build_containers: &self.build_containers,
             no_build_isolation: self.no_build_isolation,
             no_build_isolation_package: &self.no_build_isolation_package,
             exclude_newer: self.exclude_newer,
@@ -2017,6 +2732,10 @@ impl ResolverSettings {
             upgrade: &self.upgrade,
             build_options: &self.build_options,
             sources: self.sources,
+            variants: &self.variants,
+            preference_files: &self.preference_files,
+            minimal_changes: self.minimal_changes,
+            upgrade_git: &self.upgrade_git,
         }
     }
 }
@@ -2039,21 +2758,40 @@ impl From<ResolverOptions> for ResolverSettings {
                     .map(Index::from)
                     .collect(),
                 value.no_index.unwrap_or_default(),
-            ),
+            )
+            .with_find_links_recursive(value.find_links_recursive.unwrap_or_default()),
             resolution: value.resolution.unwrap_or_default(),
             prerelease: value.prerelease.unwrap_or_default(),
+            yanked: value.yanked.unwrap_or_default(),
+            allow_yanked: value
+                .allow_yanked
+                .into_iter()
+                .flatten()
+                .map(Requirement::from)
+                .collect(),
+            prefer_local_version: value.prefer_local_version,
+            resolution_budget: ResolutionBudget::new(
+                value.resolution_max_steps,
+                value.resolution_timeout.map(Duration::from_secs),
+            ),
             dependency_metadata: DependencyMetadata::from_entries(
                 value.dependency_metadata.into_iter().flatten(),
-            ),
+            )
+            .with_overrides(value.dependency_overrides.into_iter().flatten()),
             index_strategy: value.index_strategy.unwrap_or_default(),
             keyring_provider: value.keyring_provider.unwrap_or_default(),
             allow_insecure_host: value.allow_insecure_host.unwrap_or_default(),
             config_setting: value.config_settings.unwrap_or_default(),
+            build_containers: BuildContainers::new(
+                value.build_container,
+                value.build_container_package.into_iter().flatten(),
+            ),
             no_build_isolation: value.no_build_isolation.unwrap_or_default(),
             no_build_isolation_package: value.no_build_isolation_package.unwrap_or_default(),
             exclude_newer: value.exclude_newer,
             link_mode: value.link_mode.unwrap_or_default(),
             sources: SourceStrategy::from_args(value.no_sources.unwrap_or_default()),
+            variants: value.variants.unwrap_or_default(),
             upgrade: Upgrade::from_args(
                 value.upgrade,
                 value
@@ -2067,6 +2805,9 @@ impl From<ResolverOptions> for ResolverSettings {
                 NoBinary::from_args(value.no_binary, value.no_binary_package.unwrap_or_default()),
                 NoBuild::from_args(value.no_build, value.no_build_package.unwrap_or_default()),
             ),
+            preference_files: Vec::new(),
+            minimal_changes: false,
+            upgrade_git: GitUpgrade::None,
         }
     }
 }
@@ -2079,17 +2820,25 @@ pub(crate) struct ResolverInstallerSettingsRef<'a> {
     pub(crate) allow_insecure_host: &'a [TrustedHost],
     pub(crate) resolution: ResolutionMode,
     pub(crate) prerelease: PrereleaseMode,
+    pub(crate) yanked: YankedMode,
+    pub(crate) allow_yanked: &'a [Requirement],
+    pub(crate) prefer_local_version: Option<&'a str>,
+    pub(crate) resolution_budget: ResolutionBudget,
     pub(crate) dependency_metadata: &'a DependencyMetadata,
     pub(crate) config_setting: &'a ConfigSettings,
+    pub(crate) build_containers: &'a BuildContainers,
     pub(crate) no_build_isolation: bool,
     pub(crate) no_build_isolation_package: &'a [PackageName],
     pub(crate) exclude_newer: Option<ExcludeNewer>,
     pub(crate) link_mode: LinkMode,
     pub(crate) compile_bytecode: bool,
+    pub(crate) bytecode_options: &'a BytecodeOptions,
     pub(crate) sources: SourceStrategy,
+    pub(crate) variants: &'a [String],
     pub(crate) upgrade: &'a Upgrade,
     pub(crate) reinstall: &'a Reinstall,
     pub(crate) build_options: &'a BuildOptions,
+    pub(crate) minimal_changes: bool,
 }
 
 /// The resolved settings to use for an invocation of the uv CLI with both resolver and installer
@@ -2106,17 +2855,25 @@ pub(crate) struct ResolverInstallerSettings {
     pub(crate) allow_insecure_host: Vec<TrustedHost>,
     pub(crate) resolution: ResolutionMode,
     pub(crate) prerelease: PrereleaseMode,
+    pub(crate) yanked: YankedMode,
+    pub(crate) allow_yanked: Vec<Requirement>,
+    pub(crate) prefer_local_version: Option<String>,
+    pub(crate) resolution_budget: ResolutionBudget,
     pub(crate) dependency_metadata: DependencyMetadata,
     pub(crate) config_setting: ConfigSettings,
+    pub(crate) build_containers: BuildContainers,
     pub(crate) no_build_isolation: bool,
     pub(crate) no_build_isolation_package: Vec<PackageName>,
     pub(crate) exclude_newer: Option<ExcludeNewer>,
     pub(crate) link_mode: LinkMode,
     pub(crate) compile_bytecode: bool,
+    pub(crate) bytecode_options: BytecodeOptions,
     pub(crate) sources: SourceStrategy,
+    pub(crate) variants: Vec<String>,
     pub(crate) upgrade: Upgrade,
     pub(crate) reinstall: Reinstall,
     pub(crate) build_options: BuildOptions,
+    pub(crate) minimal_changes: bool,
 }
 
 impl ResolverInstallerSettings {
@@ -2143,17 +2900,25 @@ impl ResolverInstallerSettings {
             allow_insecure_host: &self.allow_insecure_host,
             resolution: self.resolution,
             prerelease: self.prerelease,
+            yanked: self.yanked,
+            allow_yanked: &self.allow_yanked,
+            prefer_local_version: self.prefer_local_version.as_deref(),
+            resolution_budget: self.resolution_budget,
             dependency_metadata: &self.dependency_metadata,
             config_setting: &self.config_setting,
+            build_containers: &self.build_containers,
             no_build_isolation: self.no_build_isolation,
             no_build_isolation_package: &self.no_build_isolation_package,
             exclude_newer: self.exclude_newer,
             link_mode: self.link_mode,
             compile_bytecode: self.compile_bytecode,
+            bytecode_options: &self.bytecode_options,
             sources: self.sources,
+            variants: &self.variants,
             upgrade: &self.upgrade,
             reinstall: &self.reinstall,
             build_options: &self.build_options,
+            minimal_changes: self.minimal_changes,
         }
     }
 }
@@ -2176,22 +2941,46 @@ impl From<ResolverInstallerOptions> for ResolverInstallerSettings {
                     .map(Index::from)
                     .collect(),
                 value.no_index.unwrap_or_default(),
-            ),
+            )
+            .with_find_links_recursive(value.find_links_recursive.unwrap_or_default()),
             resolution: value.resolution.unwrap_or_default(),
             prerelease: value.prerelease.unwrap_or_default(),
+            yanked: value.yanked.unwrap_or_default(),
+            allow_yanked: value
+                .allow_yanked
+                .into_iter()
+                .flatten()
+                .map(Requirement::from)
+                .collect(),
+            prefer_local_version: value.prefer_local_version,
+            resolution_budget: ResolutionBudget::new(
+                value.resolution_max_steps,
+                value.resolution_timeout.map(Duration::from_secs),
+            ),
             dependency_metadata: DependencyMetadata::from_entries(
                 value.dependency_metadata.into_iter().flatten(),
-            ),
+            )
+            .with_overrides(value.dependency_overrides.into_iter().flatten()),
             index_strategy: value.index_strategy.unwrap_or_default(),
             keyring_provider: value.keyring_provider.unwrap_or_default(),
             allow_insecure_host: value.allow_insecure_host.unwrap_or_default(),
             config_setting: value.config_settings.unwrap_or_default(),
+            build_containers: BuildContainers::new(
+                value.build_container,
+                value.build_container_package.into_iter().flatten(),
+            ),
             no_build_isolation: value.no_build_isolation.unwrap_or_default(),
             no_build_isolation_package: value.no_build_isolation_package.unwrap_or_default(),
             exclude_newer: value.exclude_newer,
             link_mode: value.link_mode.unwrap_or_default(),
             sources: SourceStrategy::from_args(value.no_sources.unwrap_or_default()),
+            variants: value.variants.unwrap_or_default(),
             compile_bytecode: value.compile_bytecode.unwrap_or_default(),
+            bytecode_options: BytecodeOptions::new(
+                value.compile_bytecode_optimization.unwrap_or_default(),
+                value.compile_bytecode_workers,
+                value.no_compile_bytecode_package.unwrap_or_default(),
+            ),
             upgrade: Upgrade::from_args(
                 value.upgrade,
                 value
@@ -2209,6 +2998,7 @@ impl From<ResolverInstallerOptions> for ResolverInstallerSettings {
                 NoBinary::from_args(value.no_binary, value.no_binary_package.unwrap_or_default()),
                 NoBuild::from_args(value.no_build, value.no_build_package.unwrap_or_default()),
             ),
+            minimal_changes: false,
         }
     }
 }
@@ -2238,6 +3028,10 @@ pub(crate) struct PipSettings {
     pub(crate) dependency_mode: DependencyMode,
     pub(crate) resolution: ResolutionMode,
     pub(crate) prerelease: PrereleaseMode,
+    pub(crate) yanked: YankedMode,
+    pub(crate) allow_yanked: Vec<Requirement>,
+    pub(crate) prefer_local_version: Option<String>,
+    pub(crate) resolution_budget: ResolutionBudget,
     pub(crate) dependency_metadata: DependencyMetadata,
     pub(crate) output_file: Option<PathBuf>,
     pub(crate) no_strip_extras: bool,
@@ -2247,6 +3041,7 @@ pub(crate) struct PipSettings {
     pub(crate) custom_compile_command: Option<String>,
     pub(crate) generate_hashes: bool,
     pub(crate) config_setting: ConfigSettings,
+    pub(crate) build_containers: BuildContainers,
     pub(crate) python_version: Option<PythonVersion>,
     pub(crate) python_platform: Option<TargetTriple>,
     pub(crate) universal: bool,
@@ -2260,7 +3055,9 @@ pub(crate) struct PipSettings {
     pub(crate) annotation_style: AnnotationStyle,
     pub(crate) link_mode: LinkMode,
     pub(crate) compile_bytecode: bool,
+    pub(crate) bytecode_options: BytecodeOptions,
     pub(crate) sources: SourceStrategy,
+    pub(crate) variants: Vec<String>,
     pub(crate) hash_checking: Option<HashCheckingMode>,
     pub(crate) upgrade: Upgrade,
     pub(crate) reinstall: Reinstall,
@@ -2284,6 +3081,7 @@ impl PipSettings {
             extra_index_url,
             no_index,
             find_links,
+            find_links_recursive,
             index_strategy,
             keyring_provider,
             allow_insecure_host,
@@ -2299,7 +3097,13 @@ impl PipSettings {
             allow_empty_requirements,
             resolution,
             prerelease,
+            yanked,
+            allow_yanked,
+            prefer_local_version,
+            resolution_timeout,
+            resolution_max_steps,
             dependency_metadata,
+            dependency_overrides,
             output_file,
             no_strip_extras,
             no_strip_markers,
@@ -2308,6 +3112,8 @@ impl PipSettings {
             custom_compile_command,
             generate_hashes,
             config_settings,
+            build_container,
+            build_container_package,
             python_version,
             python_platform,
             universal,
@@ -2321,9 +3127,13 @@ impl PipSettings {
             annotation_style,
             link_mode,
             compile_bytecode,
+            compile_bytecode_optimization,
+            compile_bytecode_workers,
+            no_compile_bytecode_package,
             require_hashes,
             verify_hashes,
             no_sources,
+            variants,
             upgrade,
             upgrade_package,
             reinstall,
@@ -2336,19 +3146,32 @@ impl PipSettings {
             extra_index_url: top_level_extra_index_url,
             no_index: top_level_no_index,
             find_links: top_level_find_links,
+            find_links_recursive: top_level_find_links_recursive,
             index_strategy: top_level_index_strategy,
             keyring_provider: top_level_keyring_provider,
             allow_insecure_host: top_level_allow_insecure_host,
             resolution: top_level_resolution,
             prerelease: top_level_prerelease,
+            yanked: top_level_yanked,
+            allow_yanked: top_level_allow_yanked,
+            prefer_local_version: top_level_prefer_local_version,
+            resolution_timeout: top_level_resolution_timeout,
+            resolution_max_steps: top_level_resolution_max_steps,
             dependency_metadata: top_level_dependency_metadata,
+            dependency_overrides: top_level_dependency_overrides,
             config_settings: top_level_config_settings,
+            build_container: top_level_build_container,
+            build_container_package: top_level_build_container_package,
             no_build_isolation: top_level_no_build_isolation,
             no_build_isolation_package: top_level_no_build_isolation_package,
             exclude_newer: top_level_exclude_newer,
             link_mode: top_level_link_mode,
             compile_bytecode: top_level_compile_bytecode,
+            compile_bytecode_optimization: top_level_compile_bytecode_optimization,
+            compile_bytecode_workers: top_level_compile_bytecode_workers,
+            no_compile_bytecode_package: top_level_no_compile_bytecode_package,
             no_sources: top_level_no_sources,
+            variants: top_level_variants,
             upgrade: top_level_upgrade,
             upgrade_package: top_level_upgrade_package,
             reinstall: top_level_reinstall,
@@ -2368,20 +3191,37 @@ impl PipSettings {
         let index_url = index_url.combine(top_level_index_url);
         let extra_index_url = extra_index_url.combine(top_level_extra_index_url);
         let find_links = find_links.combine(top_level_find_links);
+        let find_links_recursive = find_links_recursive.combine(top_level_find_links_recursive);
         let index_strategy = index_strategy.combine(top_level_index_strategy);
         let keyring_provider = keyring_provider.combine(top_level_keyring_provider);
         let allow_insecure_host = allow_insecure_host.combine(top_level_allow_insecure_host);
         let resolution = resolution.combine(top_level_resolution);
         let prerelease = prerelease.combine(top_level_prerelease);
+        let yanked = yanked.combine(top_level_yanked);
+        let allow_yanked = allow_yanked.combine(top_level_allow_yanked);
+        let prefer_local_version = prefer_local_version.combine(top_level_prefer_local_version);
+        let resolution_timeout = resolution_timeout.combine(top_level_resolution_timeout);
+        let resolution_max_steps = resolution_max_steps.combine(top_level_resolution_max_steps);
         let dependency_metadata = dependency_metadata.combine(top_level_dependency_metadata);
+        let dependency_overrides = dependency_overrides.combine(top_level_dependency_overrides);
         let config_settings = config_settings.combine(top_level_config_settings);
+        let build_container = build_container.combine(top_level_build_container);
+        let build_container_package =
+            build_container_package.combine(top_level_build_container_package);
         let no_build_isolation = no_build_isolation.combine(top_level_no_build_isolation);
         let no_build_isolation_package =
             no_build_isolation_package.combine(top_level_no_build_isolation_package);
         let exclude_newer = exclude_newer.combine(top_level_exclude_newer);
         let link_mode = link_mode.combine(top_level_link_mode);
         let compile_bytecode = compile_bytecode.combine(top_level_compile_bytecode);
+        let compile_bytecode_optimization =
+            compile_bytecode_optimization.combine(top_level_compile_bytecode_optimization);
+        let compile_bytecode_workers =
+            compile_bytecode_workers.combine(top_level_compile_bytecode_workers);
+        let no_compile_bytecode_package =
+            no_compile_bytecode_package.combine(top_level_no_compile_bytecode_package);
         let no_sources = no_sources.combine(top_level_no_sources);
+        let variants = variants.combine(top_level_variants);
         let upgrade = upgrade.combine(top_level_upgrade);
         let upgrade_package = upgrade_package.combine(top_level_upgrade_package);
         let reinstall = reinstall.combine(top_level_reinstall);
@@ -2405,6 +3245,11 @@ impl PipSettings {
                     .map(Index::from)
                     .collect(),
                 args.no_index.combine(no_index).unwrap_or_default(),
+            )
+            .with_find_links_recursive(
+                args.find_links_recursive
+                    .combine(find_links_recursive)
+                    .unwrap_or_default(),
             ),
             extras: ExtrasSpecification::from_args(
                 args.all_extras.combine(all_extras).unwrap_or_default(),
@@ -2417,10 +3262,30 @@ impl PipSettings {
             },
             resolution: args.resolution.combine(resolution).unwrap_or_default(),
             prerelease: args.prerelease.combine(prerelease).unwrap_or_default(),
+            yanked: args.yanked.combine(yanked).unwrap_or_default(),
+            allow_yanked: args
+                .allow_yanked
+                .combine(allow_yanked)
+                .into_iter()
+                .flatten()
+                .map(Requirement::from)
+                .collect(),
+            prefer_local_version: args.prefer_local_version.combine(prefer_local_version),
+            resolution_budget: ResolutionBudget::new(
+                args.resolution_max_steps.combine(resolution_max_steps),
+                args.resolution_timeout
+                    .combine(resolution_timeout)
+                    .map(Duration::from_secs),
+            ),
             dependency_metadata: DependencyMetadata::from_entries(
                 args.dependency_metadata
                     .combine(dependency_metadata)
                     .unwrap_or_default(),
+            )
+            .with_overrides(
+                args.dependency_overrides
+                    .combine(dependency_overrides)
+                    .unwrap_or_default(),
             ),
             output_file: args.output_file.combine(output_file),
             no_strip_extras: args
@@ -2470,6 +3335,13 @@ impl PipSettings {
                 .config_settings
                 .combine(config_settings)
                 .unwrap_or_default(),
+            build_containers: BuildContainers::new(
+                args.build_container.combine(build_container),
+                args.build_container_package
+                    .combine(build_container_package)
+                    .into_iter()
+                    .flatten(),
+            ),
             python_version: args.python_version.combine(python_version),
             python_platform: args.python_platform.combine(python_platform),
             universal: args.universal.combine(universal).unwrap_or_default(),
@@ -2519,9 +3391,20 @@ impl PipSettings {
                 .compile_bytecode
                 .combine(compile_bytecode)
                 .unwrap_or_default(),
+            bytecode_options: BytecodeOptions::new(
+                args.compile_bytecode_optimization
+                    .combine(compile_bytecode_optimization)
+                    .unwrap_or_default(),
+                args.compile_bytecode_workers
+                    .combine(compile_bytecode_workers),
+                args.no_compile_bytecode_package
+                    .combine(no_compile_bytecode_package)
+                    .unwrap_or_default(),
+            ),
             sources: SourceStrategy::from_args(
                 args.no_sources.combine(no_sources).unwrap_or_default(),
             ),
+            variants: args.variants.combine(variants).unwrap_or_default(),
             strict: args.strict.combine(strict).unwrap_or_default(),
             upgrade: Upgrade::from_args(
                 args.upgrade.combine(upgrade),
@@ -2566,8 +3449,13 @@ impl<'a> From<ResolverInstallerSettingsRef<'a>> for ResolverSettingsRef<'a> {
             allow_insecure_host: settings.allow_insecure_host,
             resolution: settings.resolution,
             prerelease: settings.prerelease,
+            yanked: settings.yanked,
+            allow_yanked: settings.allow_yanked,
+            prefer_local_version: settings.prefer_local_version,
+            resolution_budget: settings.resolution_budget,
             dependency_metadata: settings.dependency_metadata,
             config_setting: settings.config_setting,
+            build_containers: settings.build_containers,
             no_build_isolation: settings.no_build_isolation,
             no_build_isolation_package: settings.no_build_isolation_package,
             exclude_newer: settings.exclude_newer,
@@ -2575,6 +3463,10 @@ impl<'a> From<ResolverInstallerSettingsRef<'a>> for ResolverSettingsRef<'a> {
             upgrade: settings.upgrade,
             build_options: settings.build_options,
             sources: settings.sources,
+            variants: settings.variants,
+            preference_files: &[],
+            minimal_changes: settings.minimal_changes,
+            upgrade_git: &GitUpgrade::None,
         }
     }
 }
@@ -2588,11 +3480,13 @@ impl<'a> From<ResolverInstallerSettingsRef<'a>> for InstallerSettingsRef<'a> {
             allow_insecure_host: settings.allow_insecure_host,
             dependency_metadata: settings.dependency_metadata,
             config_setting: settings.config_setting,
+            build_containers: settings.build_containers,
             no_build_isolation: settings.no_build_isolation,
             no_build_isolation_package: settings.no_build_isolation_package,
             exclude_newer: settings.exclude_newer,
             link_mode: settings.link_mode,
             compile_bytecode: settings.compile_bytecode,
+            bytecode_options: settings.bytecode_options,
             reinstall: settings.reinstall,
             build_options: settings.build_options,
             sources: settings.sources,
@@ -2673,6 +3567,94 @@ impl PublishSettings {
     }
 }
 
+/// The resolved settings to use for an invocation of the `uv auth login` CLI.
+#[derive(Debug, Clone)]
+pub(crate) struct AuthLoginSettings {
+    // CLI only, see [`AuthLoginArgs`] for docs.
+    pub(crate) index: String,
+    pub(crate) username: Option<String>,
+    pub(crate) password_stdin: bool,
+
+    // Both CLI and configuration.
+    pub(crate) keyring_provider: KeyringProviderType,
+    pub(crate) indexes: Vec<Index>,
+}
+
+impl AuthLoginSettings {
+    /// Resolve the [`AuthLoginSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: AuthLoginArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let Options { top_level, .. } = filesystem
+            .map(FilesystemOptions::into_options)
+            .unwrap_or_default();
+
+        let ResolverInstallerOptions {
+            keyring_provider,
+            index,
+            ..
+        } = top_level;
+
+        // Tokens are encoded in the same way as username/password
+        let username = if args.token {
+            Some("__token__".to_string())
+        } else {
+            args.username
+        };
+
+        Self {
+            index: args.index,
+            username,
+            password_stdin: args.password_stdin,
+            keyring_provider: args
+                .keyring_provider
+                .combine(keyring_provider)
+                .unwrap_or_default(),
+            indexes: index.unwrap_or_default(),
+        }
+    }
+}
+
+/// The resolved settings to use for an invocation of the `uv auth logout` CLI.
+#[derive(Debug, Clone)]
+pub(crate) struct AuthLogoutSettings {
+    pub(crate) index: String,
+    pub(crate) indexes: Vec<Index>,
+}
+
+impl AuthLogoutSettings {
+    /// Resolve the [`AuthLogoutSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: AuthLogoutArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let Options { top_level, .. } = filesystem
+            .map(FilesystemOptions::into_options)
+            .unwrap_or_default();
+
+        Self {
+            index: args.index,
+            indexes: top_level.index.unwrap_or_default(),
+        }
+    }
+}
+
+/// The resolved settings to use for an invocation of the `uv auth token` CLI.
+#[derive(Debug, Clone)]
+pub(crate) struct AuthTokenSettings {
+    pub(crate) index: String,
+    pub(crate) indexes: Vec<Index>,
+}
+
+impl AuthTokenSettings {
+    /// Resolve the [`AuthTokenSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: AuthTokenArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let Options { top_level, .. } = filesystem
+            .map(FilesystemOptions::into_options)
+            .unwrap_or_default();
+
+        Self {
+            index: args.index,
+            indexes: top_level.index.unwrap_or_default(),
+        }
+    }
+}
+
 // Environment variables that are not exposed as CLI arguments.
 mod env {
     use uv_static::EnvVars;
@@ -2680,12 +3662,35 @@ mod env {
     pub(super) const CONCURRENT_DOWNLOADS: (&str, &str) =
         (EnvVars::UV_CONCURRENT_DOWNLOADS, "a non-zero integer");
 
+    pub(super) const CONCURRENT_DOWNLOADS_PER_HOST: (&str, &str) = (
+        EnvVars::UV_CONCURRENT_DOWNLOADS_PER_HOST,
+        "a non-zero integer",
+    );
+
     pub(super) const CONCURRENT_BUILDS: (&str, &str) =
         (EnvVars::UV_CONCURRENT_BUILDS, "a non-zero integer");
 
     pub(super) const CONCURRENT_INSTALLS: (&str, &str) =
         (EnvVars::UV_CONCURRENT_INSTALLS, "a non-zero integer");
 
+    pub(super) const NO_GIT_SPARSE_CHECKOUT: (&str, &str) =
+        (EnvVars::UV_NO_GIT_SPARSE_CHECKOUT, "'true' or 'false'");
+
+    pub(super) const LAUNCHER_PRODUCT_NAME: (&str, &str) =
+        (EnvVars::UV_LAUNCHER_PRODUCT_NAME, "a string");
+
+    pub(super) const LAUNCHER_VERSION: (&str, &str) = (
+        EnvVars::UV_LAUNCHER_VERSION,
+        "up to four dot-separated integers, like '1.2.3'",
+    );
+
+    pub(super) const LAUNCHER_ICON: (&str, &str) = (EnvVars::UV_LAUNCHER_ICON, "a path");
+
+    pub(super) const BUILD_CACHE_URL: (&str, &str) = (EnvVars::UV_BUILD_CACHE_URL, "a URL");
+
+    pub(super) const BUILD_CACHE_UPLOAD: (&str, &str) =
+        (EnvVars::UV_BUILD_CACHE_UPLOAD, "'true' or 'false'");
+
     pub(super) const UV_PYTHON_DOWNLOADS: (&str, &str) = (
         EnvVars::UV_PYTHON_DOWNLOADS,
         "one of 'auto', 'true', 'manual', 'never', or 'false'",
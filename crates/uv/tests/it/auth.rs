@@ -0,0 +1,218 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+use indoc::indoc;
+use insta::assert_snapshot;
+
+use uv_static::EnvVars;
+
+use crate::common::{uv_snapshot, TestContext};
+
+#[test]
+fn auth_login_logout() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let credentials_dir = context.temp_dir.child("credentials");
+
+    // No credentials are stored initially.
+    uv_snapshot!(context.filters(), context.auth().arg("list")
+        .env(EnvVars::UV_CREDENTIALS_DIR, credentials_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No credentials stored
+    "###);
+
+    // The password is read from stdin.
+    let password = context.temp_dir.child("password.txt");
+    password.write_str("secret\n")?;
+
+    uv_snapshot!(context.filters(), context.auth()
+        .arg("login")
+        .arg("https://example.com/simple/")
+        .arg("--username")
+        .arg("user")
+        .arg("--password-stdin")
+        .stdin(std::fs::File::open(&password)?)
+        .env(EnvVars::UV_CREDENTIALS_DIR, credentials_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Stored credentials for `https://example.com/simple` as `user`
+    "###);
+
+    // The password should be encrypted.
+    let contents = fs_err::read_to_string(credentials_dir.join("credentials.toml"))?;
+    assert!(!contents.contains("secret"));
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(
+            r#"encrypted-password = ".*""#,
+            r#"encrypted-password = "[ENCRYPTED]""#,
+        )])
+        .collect::<Vec<_>>();
+    insta::with_settings!({
+        filters => filters,
+    }, {
+        assert_snapshot!(contents, @r###"
+        [[credentials]]
+        url = "https://example.com/simple"
+        username = "user"
+        encrypted-password = "[ENCRYPTED]"
+        "###);
+    });
+
+    // Without `--password-stdin` or a terminal, there's no way to provide a password.
+    uv_snapshot!(context.filters(), context.auth()
+        .arg("login")
+        .arg("https://example.com/simple")
+        .arg("--username")
+        .arg("user")
+        .env(EnvVars::UV_CREDENTIALS_DIR, credentials_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No password provided; use `--password-stdin` to read it from stdin
+    "###);
+
+    // Logging in again should replace the existing credentials.
+    let token = context.temp_dir.child("token.txt");
+    token.write_str("token")?;
+
+    uv_snapshot!(context.filters(), context.auth()
+        .arg("login")
+        .arg("https://example.com/simple")
+        .arg("--token")
+        .arg("--password-stdin")
+        .stdin(std::fs::File::open(&token)?)
+        .env(EnvVars::UV_CREDENTIALS_DIR, credentials_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Stored credentials for `https://example.com/simple` as `__token__`
+    "###);
+
+    uv_snapshot!(context.filters(), context.auth().arg("list")
+        .env(EnvVars::UV_CREDENTIALS_DIR, credentials_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    https://example.com/simple __token__
+
+    ----- stderr -----
+    "###);
+
+    // The token should apply to any URL below the index URL.
+    uv_snapshot!(context.filters(), context.auth()
+        .arg("token")
+        .arg("https://example.com/simple/anyio")
+        .env(EnvVars::UV_CREDENTIALS_DIR, credentials_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    token
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.auth()
+        .arg("logout")
+        .arg("https://example.com/simple")
+        .env(EnvVars::UV_CREDENTIALS_DIR, credentials_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Removed credentials for `https://example.com/simple`
+    "###);
+
+    uv_snapshot!(context.filters(), context.auth()
+        .arg("logout")
+        .arg("https://example.com/simple")
+        .env(EnvVars::UV_CREDENTIALS_DIR, credentials_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    No credentials stored for `https://example.com/simple`
+    "###);
+
+    uv_snapshot!(context.filters(), context.auth()
+        .arg("token")
+        .arg("https://example.com/simple")
+        .env(EnvVars::UV_CREDENTIALS_DIR, credentials_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No credentials stored for `https://example.com/simple`
+    "###);
+
+    Ok(())
+}
+
+/// Indexes can be referenced by the name used in the `index` setting.
+#[test]
+fn auth_login_index_name() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let credentials_dir = context.temp_dir.child("credentials");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [[tool.uv.index]]
+        name = "internal"
+        url = "https://example.com/internal/simple"
+        "#
+    })?;
+
+    let password = context.temp_dir.child("password.txt");
+    password.write_str("secret")?;
+
+    uv_snapshot!(context.filters(), context.auth()
+        .arg("login")
+        .arg("internal")
+        .arg("--username")
+        .arg("user")
+        .arg("--password-stdin")
+        .stdin(std::fs::File::open(&password)?)
+        .env(EnvVars::UV_CREDENTIALS_DIR, credentials_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Stored credentials for `https://example.com/internal/simple` as `user`
+    "###);
+
+    uv_snapshot!(context.filters(), context.auth()
+        .arg("login")
+        .arg("missing")
+        .arg("--token")
+        .arg("--password-stdin")
+        .env(EnvVars::UV_CREDENTIALS_DIR, credentials_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `missing` is neither a valid index URL nor the name of an index defined in the `index` setting
+    "###);
+
+    Ok(())
+}
@@ -1,10 +1,13 @@
 use crate::common::{uv_snapshot, TestContext};
 use anyhow::Result;
 use assert_cmd::assert::OutputAssertExt;
+use assert_fs::prelude::{FileTouch, FileWriteStr, PathChild};
+use indoc::{formatdoc, indoc};
 use std::env;
 use std::path::Path;
 use tempfile::TempDir;
 use uv_static::EnvVars;
+use zip::ZipArchive;
 
 /// Test that build backend works if we invoke it directly.
 ///
@@ -52,3 +55,220 @@ fn uv_backend_direct() -> Result<()> {
 
     Ok(())
 }
+
+/// Test that a strict editable install only exposes the module of the project, while a `.pth`
+/// editable install exposes the entire `src` directory.
+#[test]
+fn uv_backend_editable_mode() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    for mode in ["strict", "pth"] {
+        let project = context.temp_dir.child(format!("project-{mode}"));
+        project.child("pyproject.toml").write_str(&formatdoc! {r#"
+            [project]
+            name = "greeter"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+
+            [build-system]
+            requires = ["uv>=0.4.15,<5"]
+            build-backend = "uv"
+
+            [tool.uv.build-backend]
+            editable-mode = "{mode}"
+        "#})?;
+        project
+            .child("src")
+            .child("greeter")
+            .child("__init__.py")
+            .write_str("def greet():\n    print('Hello')\n")?;
+        project
+            .child("src")
+            .child("sibling")
+            .child("__init__.py")
+            .touch()?;
+
+        // The build backend command loads the uv settings of the project, which include the
+        // `tool.uv.build-backend` table.
+        let wheel_dir = TempDir::new()?;
+        uv_snapshot!(context
+            .build_backend()
+            .arg("build-editable")
+            .arg(wheel_dir.path())
+            .current_dir(project.path()), @r###"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+        greeter-0.1.0-py3-none-any.whl
+
+        ----- stderr -----
+        "###);
+        context
+            .pip_install()
+            .arg("--reinstall")
+            .arg(wheel_dir.path().join("greeter-0.1.0-py3-none-any.whl"))
+            .assert()
+            .success();
+    }
+
+    // The last install used a `.pth` file, so the undeclared sibling module is importable.
+    uv_snapshot!(context
+        .run()
+        .arg("python")
+        .arg("-c")
+        .arg("import greeter, sibling\ngreeter.greet()"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Hello
+
+    ----- stderr -----
+    "###);
+
+    // Reinstall in strict mode: the project is importable from the source tree, and changes to it
+    // are picked up, but the sibling module is hidden.
+    let project = context.temp_dir.child("project-strict");
+    project
+        .child("src")
+        .child("greeter")
+        .child("__init__.py")
+        .write_str("def greet():\n    print('Hello again')\n")?;
+    let wheel_dir = TempDir::new()?;
+    context
+        .build_backend()
+        .arg("build-editable")
+        .arg(wheel_dir.path())
+        .current_dir(project.path())
+        .assert()
+        .success();
+    context
+        .pip_install()
+        .arg("--reinstall")
+        .arg(wheel_dir.path().join("greeter-0.1.0-py3-none-any.whl"))
+        .assert()
+        .success();
+
+    uv_snapshot!(context
+        .run()
+        .arg("python")
+        .arg("-c")
+        .arg("import greeter\ngreeter.greet()\nimport sibling"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    Hello again
+
+    ----- stderr -----
+    Traceback (most recent call last):
+      File "<string>", line 3, in <module>
+    ModuleNotFoundError: No module named 'sibling'
+    "###);
+
+    Ok(())
+}
+
+/// Commands other than the build backend accept the `tool.uv.build-backend` settings.
+#[test]
+fn uv_backend_settings_project_commands() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "greeter"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [build-system]
+        requires = ["uv>=0.4.15,<5"]
+        build-backend = "uv"
+
+        [tool.uv.build-backend]
+        include = ["src/extra.py"]
+        exclude = ["**/__pycache__"]
+        data = { scripts = "scripts" }
+        editable-mode = "strict"
+        hooks = [{ command = ["python", "-c", "print('generating')"] }]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###);
+
+    Ok(())
+}
+
+/// The `include`, `exclude` and `data` settings are read from the project when building a wheel
+/// through the CLI.
+#[test]
+fn uv_backend_include_exclude_data() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let project = context.temp_dir.child("project");
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "greeter"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [build-system]
+        requires = ["uv>=0.4.15,<5"]
+        build-backend = "uv"
+
+        [tool.uv.build-backend]
+        include = ["src/extra.py"]
+        exclude = ["**/__pycache__"]
+        data = { scripts = "scripts" }
+    "#})?;
+    project
+        .child("src")
+        .child("greeter")
+        .child("__init__.py")
+        .write_str("def greet():\n    print('Hello')\n")?;
+    project
+        .child("src")
+        .child("greeter")
+        .child("__pycache__")
+        .child("__init__.cpython-312.pyc")
+        .touch()?;
+    project.child("src").child("extra.py").touch()?;
+    project.child("src").child("unlisted.py").touch()?;
+    project
+        .child("scripts")
+        .child("greet")
+        .write_str("#!python\nimport greeter\ngreeter.greet()\n")?;
+
+    let wheel_dir = TempDir::new()?;
+    uv_snapshot!(context
+        .build_backend()
+        .arg("build-wheel")
+        .arg(wheel_dir.path())
+        .current_dir(project.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    greeter-0.1.0-py3-none-any.whl
+
+    ----- stderr -----
+    "###);
+
+    let wheel = fs_err::File::open(wheel_dir.path().join("greeter-0.1.0-py3-none-any.whl"))?;
+    let files: Vec<_> = ZipArchive::new(wheel)?
+        .file_names()
+        .map(ToString::to_string)
+        .collect();
+    assert!(files.contains(&"greeter/__init__.py".to_string()));
+    assert!(files.contains(&"extra.py".to_string()));
+    assert!(files.contains(&"greeter-0.1.0.data/scripts/greet".to_string()));
+    assert!(!files.iter().any(|file| file.contains("__pycache__")));
+    assert!(!files.contains(&"unlisted.py".to_string()));
+
+    Ok(())
+}
@@ -0,0 +1,128 @@
+use crate::common::{uv_snapshot, TestContext};
+use anyhow::Result;
+use assert_fs::prelude::*;
+use fs_err::File;
+use indoc::indoc;
+use predicates::prelude::predicate;
+use zip::ZipArchive;
+
+/// Bundle the project environment into a relocatable archive.
+#[test]
+fn bundle() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+        "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.bundle(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    warning: The bundle refers to the Python interpreter at `[PYTHON-3.12]`, which is not managed by uv and must be present at the same path on the target machine; use `--python-preference only-managed` to include a managed interpreter in the bundle, or `--no-include-python` to silence this warning
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    Created bundle at: dist/foo-bundle.zip
+    "###);
+
+    let bundle = context.temp_dir.child("dist").child("foo-bundle.zip");
+    bundle.assert(predicate::path::is_file());
+
+    // The archive should contain a relocatable environment, rooted at the project name.
+    let archive = ZipArchive::new(File::open(bundle.path())?)?;
+    let files: Vec<_> = archive.file_names().collect();
+    assert!(files.contains(&"foo/pyvenv.cfg"));
+    assert!(files
+        .iter()
+        .any(|file| file.starts_with("foo/")
+            && file.ends_with("site-packages/iniconfig/__init__.py")));
+
+    // The project environment should be left untouched.
+    context
+        .temp_dir
+        .child(".venv")
+        .child("lib")
+        .child("python3.12")
+        .child("site-packages")
+        .child("iniconfig")
+        .assert(predicate::path::missing());
+
+    Ok(())
+}
+
+/// Write the bundle to a custom path, excluding the development dependencies and the interpreter.
+#[test]
+fn bundle_output_file() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+
+        [dependency-groups]
+        dev = ["anyio==4.3.0"]
+        "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.bundle().arg("--no-dev").arg("--no-include-python").arg("--output-file").arg("bundle.zip"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    Created bundle at: bundle.zip
+    "###);
+
+    let archive = ZipArchive::new(File::open(context.temp_dir.child("bundle.zip").path())?)?;
+    assert!(!archive
+        .file_names()
+        .any(|file| file.ends_with("site-packages/anyio/__init__.py")));
+
+    Ok(())
+}
+
+/// Refuse to include a Python interpreter that isn't managed by uv.
+#[test]
+fn bundle_include_python_unmanaged() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.bundle().arg("--include-python"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: `--include-python` requires a managed Python installation, but [PYTHON-3.12] is not managed by uv; use `--python-preference only-managed` to select a managed interpreter
+    "###);
+
+    Ok(())
+}
@@ -163,3 +163,48 @@ fn clean_package_index() -> Result<()> {
 
     Ok(())
 }
+
+/// `cache clean iniconfig` should remove a package built in a cache namespace.
+#[test]
+fn clean_package_namespace() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0")?;
+
+    // Build a requirement from source in a namespace, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--no-binary")
+        .arg("iniconfig")
+        .arg("--cache-namespace")
+        .arg("patched")
+        .assert()
+        .success();
+
+    // Assert that the built wheel is stored in the namespace, rather than the shared bucket.
+    let sdists = context.cache_dir.child("sdists-v5");
+    let namespaced = sdists
+        .child("namespaces")
+        .child("patched")
+        .child("pypi")
+        .child("iniconfig");
+    assert!(
+        namespaced.exists(),
+        "Expected the source distribution to be built in the `patched` namespace"
+    );
+    assert!(
+        !sdists.child("pypi").child("iniconfig").exists(),
+        "Expected the source distribution to be absent from the shared bucket"
+    );
+
+    // Cleaning the package should remove the namespaced entry, even without the namespace.
+    context.clean().arg("iniconfig").assert().success();
+    assert!(
+        !namespaced.exists(),
+        "Expected the namespaced source distribution to be removed for `iniconfig`"
+    );
+
+    Ok(())
+}
@@ -361,3 +361,101 @@ fn prune_stale_revision() -> Result<()> {
 
     Ok(())
 }
+
+/// `cache prune` should retain cached build environments, unless their base interpreter no longer
+/// exists.
+#[test]
+fn prune_build_environments() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#,
+    )?;
+
+    context.temp_dir.child("src").child("__init__.py").touch()?;
+    context.temp_dir.child("README").touch()?;
+
+    // Install the package, to populate the cached build environment.
+    context.pip_install().arg(".").assert().success();
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain([
+            // The cache entry does not have a stable key, so we filter it out
+            (
+                r"\[CACHE_DIR\](\\|\/)(.*?)(\\|\/).*",
+                "[CACHE_DIR]/$2/[ENTRY]",
+            ),
+            // The number of files depends on the size of the build environment
+            ("Removed \\d+ files", "Removed [N] files"),
+        ])
+        .collect();
+
+    // Pruning should retain the build environment, which is still in use.
+    uv_snapshot!(&filters, context.prune().arg("--verbose"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    DEBUG uv [VERSION] ([COMMIT] DATE)
+    Pruning cache at: [CACHE_DIR]/
+    No unused entries found
+    "###);
+
+    // Point the build environment at an interpreter that no longer exists.
+    let environments = walkdir::WalkDir::new(context.cache_dir.child("build-environments-v0"))
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_symlink())
+        .map(|entry| entry.into_path())
+        .collect::<Vec<_>>();
+    assert_eq!(environments.len(), 1);
+    let pyvenv_cfg = environments[0].join("pyvenv.cfg");
+    let contents = fs_err::read_to_string(&pyvenv_cfg)?
+        .lines()
+        .map(|line| {
+            if line.starts_with("home") {
+                "home = /nonexistent/bin".to_string()
+            } else {
+                line.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    fs_err::write(&pyvenv_cfg, contents)?;
+
+    // Pruning should now remove the stale build environment.
+    uv_snapshot!(&filters, context.prune().arg("--verbose"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    DEBUG uv [VERSION] ([COMMIT] DATE)
+    Pruning cache at: [CACHE_DIR]/
+    DEBUG Removing stale build environment: [CACHE_DIR]/build-environments-v0/[ENTRY]
+    DEBUG Removing dangling cache archive: [CACHE_DIR]/archive-v0/[ENTRY]
+    Removed [N] files ([SIZE])
+    "###);
+
+    assert!(
+        fs_err::read_dir(context.cache_dir.child("build-environments-v0"))?
+            .next()
+            .is_none()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+
+use crate::common::uv_snapshot;
+use crate::common::TestContext;
+
+/// `cache verify` should detect and remove corrupt unzipped wheels.
+#[test]
+fn verify_corrupt_archive() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0")?;
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.verify(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    Verified 1 cache entry; no corruption found
+    "###);
+
+    // Corrupt a file in the unzipped wheel.
+    let archive = fs_err::read_dir(context.cache_dir.child("archive-v0"))?
+        .next()
+        .expect("archive entry")?
+        .path();
+    fs_err::write(archive.join("iniconfig").join("__init__.py"), "corrupt")?;
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain([
+            (r"archive-v0/[A-Za-z0-9_-]+", "archive-v0/[ENTRY]"),
+            (r"found `sha256=[A-Za-z0-9_-]+`", "found `sha256=[HASH]`"),
+            (
+                r"expected `sha256=[A-Za-z0-9_-]+`",
+                "expected `sha256=[HASH]`",
+            ),
+        ])
+        .collect();
+
+    // With `--dry-run`, the corruption should be reported, but the entry should be retained.
+    uv_snapshot!(filters, context.verify().arg("--dry-run"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    Corrupt: archive-v0/[ENTRY] (Hash mismatch for `iniconfig/__init__.py`: expected `sha256=[HASH]`, found `sha256=[HASH]`)
+    Found 1 corrupt cache entry out of 1 verified
+    "###);
+    assert!(archive.exists());
+
+    uv_snapshot!(filters, context.verify(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    Corrupt: archive-v0/[ENTRY] (Hash mismatch for `iniconfig/__init__.py`: expected `sha256=[HASH]`, found `sha256=[HASH]`)
+    Removed 1 corrupt cache entry out of 1 verified ([SIZE])
+    "###);
+    assert!(!archive.exists());
+
+    // Reinstalling should re-download the wheel.
+    uv_snapshot!(context.filters(), context.pip_sync().arg("requirements.txt").arg("--reinstall"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ iniconfig==2.0.0
+    "###);
+
+    uv_snapshot!(context.filters(), context.verify(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    Verified 1 cache entry; no corruption found
+    "###);
+
+    Ok(())
+}
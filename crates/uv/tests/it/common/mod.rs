@@ -57,6 +57,11 @@ pub const INSTA_FILTERS: &[(&str, &str)] = &[
     // Rewrite Windows output to Unix output
     (r"\\([\w\d]|\.\.)", "/$1"),
     (r"uv\.exe", "uv"),
+    // Hashes recorded in tool receipts
+    (
+        r#"hashes = \[("sha256:[0-9a-f]{64}"(, )?)+\]"#,
+        r#"hashes = ["[HASHES]"]"#,
+    ),
     // uv version display
     (
         r"uv(-.*)? \d+\.\d+\.\d+( \(.*\))?",
@@ -208,16 +213,28 @@ impl TestContext {
     /// on the same file system.)
     #[inline]
     pub fn with_filtered_link_mode_warning(mut self) -> Self {
-        let pattern = "warning: Failed to hardlink files; .*\n.*\n.*\n";
+        let pattern = "warning: Failed to hardlink files.*\n.*\n.*\n";
         self.filters.push((pattern.to_string(), String::new()));
         self
     }
 
+    /// Adds a filter for paths relative to the workspace root, such as the locked editable
+    /// requirements in a tool receipt, which depend on the location of the temporary directory.
+    #[must_use]
+    pub fn with_filtered_relative_workspace(mut self) -> Self {
+        self.filters.push((
+            r#""(?:\.\./)+[^"]*scripts/packages/"#.to_string(),
+            r#""[WORKSPACE_RELATIVE]/scripts/packages/"#.to_string(),
+        ));
+        self
+    }
+
     /// Adds a filter that ignores platform information in a Python installation key.
     pub fn with_filtered_python_keys(mut self) -> Self {
         // Filter platform keys
         self.filters.push((
-            r"((?:cpython|pypy)-\d+\.\d+(?:\.(?:\[X\]|\d+))?[a-z]?(?:\+[a-z]+)?)-.*".to_string(),
+            r"((?:cpython|pypy|graalpy)-\d+\.\d+(?:\.(?:\[X\]|\d+))?[a-z]?(?:\+[a-z]+)?)-.*"
+                .to_string(),
             "$1-[PLATFORM]".to_string(),
         ));
         self
@@ -527,6 +544,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `pip verify` command with options shared across scenarios.
+    pub fn pip_verify(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("pip").arg("verify");
+        self.add_shared_args(&mut command, true);
+        command
+    }
+
     pub fn pip_list(&self) -> Command {
         let mut command = self.new_command();
         command.arg("pip").arg("list");
@@ -615,6 +640,46 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv bundle` command with options shared across scenarios.
+    pub fn bundle(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("bundle");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
+    /// Create a `uv deploy` command with options shared across scenarios.
+    pub fn deploy(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("deploy");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
+    /// Create a `uv vendor` command with options shared across scenarios.
+    pub fn vendor(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("vendor");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
+    /// Create a `uv licenses` command with options shared across scenarios.
+    pub fn licenses(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("licenses");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
+    /// Create a `uv outdated` command with options shared across scenarios.
+    pub fn outdated(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("outdated");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
     /// Create a `uv build` command with options shared across scenarios.
     pub fn build(&self) -> Command {
         let mut command = self.new_command();
@@ -650,6 +715,18 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv python list` command with options shared across scenarios.
+    pub fn python_list(&self) -> Command {
+        let mut command = self.new_command();
+        command
+            .arg("python")
+            .arg("list")
+            .env(EnvVars::UV_PYTHON_INSTALL_DIR, "")
+            .current_dir(&self.temp_dir);
+        self.add_shared_args(&mut command, true);
+        command
+    }
+
     /// Create a `uv python install` command with options shared across scenarios.
     pub fn python_install(&self) -> Command {
         let mut command = self.new_command();
@@ -731,6 +808,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv tool sync` command with options shared across scenarios.
+    pub fn tool_sync(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("tool").arg("sync");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
     /// Create a `uv tool install` command with options shared across scenarios.
     pub fn tool_install(&self) -> Command {
         let mut command = self.new_command();
@@ -756,6 +841,38 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv auth` command with options shared across scenarios.
+    pub fn auth(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("auth");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
+    /// Create a `uv config` command with options shared across scenarios.
+    pub fn config(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("config");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
+    /// Create a `uv index` command with options shared across scenarios.
+    pub fn index(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("index");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
+    /// Create a `uv tool alias` command with options shared across scenarios.
+    pub fn tool_alias(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("tool").arg("alias");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
     /// Create a `uv tool uninstall` command with options shared across scenarios.
     pub fn tool_uninstall(&self) -> Command {
         let mut command = self.new_command();
@@ -796,6 +913,86 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv generate-shell-completion` command with options shared across scenarios.
+    pub fn generate_shell_completion(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("generate-shell-completion");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
+    /// Create a `uv self uninstall` command with options shared across scenarios.
+    pub fn self_uninstall(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("self").arg("uninstall");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
+    /// Create a `uv stats` command with options shared across scenarios.
+    pub fn stats(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("stats");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
+    /// Create a `uv doctor` command with options shared across scenarios.
+    pub fn doctor(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("doctor");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
+    /// Create a `uv search` command with options shared across scenarios.
+    pub fn search(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("search");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
+    /// Create a `uv info` command with options shared across scenarios.
+    pub fn info(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("info");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
+    /// Create a `uv env remove` command.
+    pub fn env_remove(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("env").arg("remove");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
+    /// Create a `uv workspace list` command.
+    pub fn workspace_list(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("workspace").arg("list");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
+    /// Create a `uv workspace graph` command.
+    pub fn workspace_graph(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("workspace").arg("graph");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
+    /// Create a `uv version` command.
+    pub fn version(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("version");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
     /// Create a `uv cache prune` command.
     pub fn prune(&self) -> Command {
         let mut command = self.new_command();
@@ -804,6 +1001,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv cache verify` command.
+    pub fn verify(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("cache").arg("verify");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
     /// Create a `uv build_backend` command.
     ///
     /// Note that this command is hidden and only invoking it through a build frontend is supported.
@@ -1343,6 +1548,32 @@ pub fn make_project(dir: &Path, name: &str, body: &str) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Run `hg` in the given directory, returning its trimmed output.
+pub fn hg(dir: &Path, args: &[&str]) -> anyhow::Result<String> {
+    let output = Command::new("hg")
+        .args(["--config", "ui.username=uv <uv@example.com>"])
+        .args(args)
+        .current_dir(dir)
+        .env(EnvVars::HGPLAIN, "1")
+        .output()?;
+    anyhow::ensure!(
+        output.status.success(),
+        "`hg {}` failed: {}",
+        args.join(" "),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    Ok(String::from_utf8(output.stdout)?.trim().to_string())
+}
+
+/// Create a Mercurial repository in `dir` containing a stub package `name`, returning the ID of
+/// its initial changeset.
+pub fn make_hg_project(dir: &Path, name: &str) -> anyhow::Result<String> {
+    make_project(dir, name, "dependencies = []")?;
+    hg(dir, &["init"])?;
+    hg(dir, &["commit", "--addremove", "-m", "initial"])?;
+    hg(dir, &["log", "-r", ".", "-T", "{node}"])
+}
+
 // This is a fine-grained token that only has read-only access to the `uv-private-pypackage` repository
 pub const READ_ONLY_GITHUB_TOKEN: &[&str] = &[
     "Z2l0aHViX3BhdA==",
@@ -0,0 +1,70 @@
+use std::process::Command;
+
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+
+use uv_static::EnvVars;
+
+use crate::common::{get_bin, uv_snapshot, TestContext};
+
+/// `uv generate-shell-completion` should generate a static completion script, unless preview is
+/// enabled.
+#[test]
+fn generate_shell_completion() {
+    let context = TestContext::new_with_versions(&[]);
+
+    let output = context
+        .generate_shell_completion()
+        .arg("bash")
+        .assert()
+        .success()
+        .get_output()
+        .clone();
+    let script = String::from_utf8_lossy(&output.stdout);
+    assert!(script.contains("_uv()"));
+    assert!(!script.contains(EnvVars::UV_COMPLETE));
+
+    // In preview, uv registers itself as the completer.
+    let output = context
+        .generate_shell_completion()
+        .arg("bash")
+        .arg("--preview")
+        .assert()
+        .success()
+        .get_output()
+        .clone();
+    let script = String::from_utf8_lossy(&output.stdout);
+    assert!(script.contains(EnvVars::UV_COMPLETE));
+}
+
+/// Package names should be completed from the cache given by `--cache-dir`.
+#[test]
+fn complete_package_name() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+
+    let cache_dir = context.temp_dir.child("completion-cache");
+    let simple = cache_dir.child("simple-v13").child("pypi");
+    simple.create_dir_all()?;
+    simple.child("iniconfig.rkyv").touch()?;
+    simple.child("anyio.rkyv").touch()?;
+
+    uv_snapshot!(Command::new(get_bin())
+        .env(EnvVars::UV_COMPLETE, "fish")
+        .current_dir(&context.temp_dir)
+        .arg("--")
+        .arg("uv")
+        .arg("add")
+        .arg("--cache-dir")
+        .arg(cache_dir.path())
+        .arg("ini"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    iniconfig
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
@@ -0,0 +1,289 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+use indoc::indoc;
+use insta::assert_snapshot;
+
+#[cfg(not(windows))]
+use uv_static::EnvVars;
+
+use crate::common::{uv_snapshot, TestContext};
+
+/// `uv config set` should write to `tool.uv` in the project's `pyproject.toml`, preserving the
+/// rest of the file, and `uv config get` and `uv config list` should read the settings back.
+#[test]
+fn config_set_get_project() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv]
+        # Prefer the managed interpreters.
+        python-preference = "managed"  # Set by CI.
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.config()
+        .arg("set")
+        .arg("python-preference")
+        .arg("only-managed"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Set `python-preference` in `pyproject.toml`
+    "###);
+
+    // Values are parsed as TOML where possible.
+    context
+        .config()
+        .arg("set")
+        .arg("concurrent-downloads")
+        .arg("4")
+        .assert()
+        .success();
+
+    // Nested settings are separated by dots, and values that aren't valid for the setting as TOML
+    // are stored as strings.
+    context
+        .config()
+        .arg("set")
+        .arg("pip.python")
+        .arg("3.12")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.config().arg("get").arg("python-preference"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    only-managed
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.config().arg("get").arg("concurrent-downloads").arg("--output-format").arg("json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    4
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.config().arg("list"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    concurrent-downloads = 4
+    pip.python = "3.12"
+    python-preference = "only-managed"
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.config().arg("get").arg("pip.index-url"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Setting `pip.index-url` is not defined in `pyproject.toml`
+    "###);
+
+    assert_snapshot!(fs_err::read_to_string(&pyproject_toml)?, @r###"
+    [project]
+    name = "project"
+    version = "0.1.0"
+    requires-python = ">=3.12"
+    dependencies = []
+
+    [tool.uv]
+    # Prefer the managed interpreters.
+    python-preference = "only-managed"  # Set by CI.
+    concurrent-downloads = 4
+
+    [tool.uv.pip]
+    python = "3.12"
+    "###);
+
+    Ok(())
+}
+
+/// `uv config set` should reject unknown settings and invalid values without modifying the file.
+#[test]
+fn config_set_invalid() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let uv_toml = context.temp_dir.child("uv.toml");
+    uv_toml.write_str(indoc! {r#"
+        native-tls = true
+    "#})?;
+
+    context
+        .config()
+        .arg("set")
+        .arg("not-a-setting")
+        .arg("1")
+        .assert()
+        .failure();
+
+    context
+        .config()
+        .arg("set")
+        .arg("native-tls")
+        .arg("maybe")
+        .assert()
+        .failure();
+
+    assert_snapshot!(fs_err::read_to_string(&uv_toml)?, @r###"
+    native-tls = true
+    "###);
+
+    Ok(())
+}
+
+/// With `--user`, `uv config set` should create the user-level `uv.toml`.
+#[test]
+#[cfg(not(windows))]
+fn config_set_user() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let config_dir = context.temp_dir.child("config");
+
+    uv_snapshot!(context.filters(), context.config()
+        .arg("set")
+        .arg("pip.index-url")
+        .arg("https://pypi.example.com/simple")
+        .arg("--user")
+        .env(EnvVars::XDG_CONFIG_HOME, config_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Set `pip.index-url` in `config/uv/uv.toml`
+    "###);
+
+    uv_snapshot!(context.filters(), context.config()
+        .arg("get")
+        .arg("pip.index-url")
+        .arg("--user")
+        .env(EnvVars::XDG_CONFIG_HOME, config_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    https://pypi.example.com/simple
+
+    ----- stderr -----
+    "###);
+
+    assert_snapshot!(fs_err::read_to_string(config_dir.join("uv").join("uv.toml"))?, @r###"
+    [pip]
+    index-url = "https://pypi.example.com/simple"
+    "###);
+
+    Ok(())
+}
+
+/// With `--system`, `uv config set` should write to the first directory in `XDG_CONFIG_DIRS`.
+#[test]
+#[cfg(not(windows))]
+fn config_set_system() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let config_dir = context.temp_dir.child("xdg");
+
+    uv_snapshot!(context.filters(), context.config()
+        .arg("set")
+        .arg("offline")
+        .arg("true")
+        .arg("--system")
+        .env(EnvVars::XDG_CONFIG_DIRS, config_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Set `offline` in `xdg/uv/uv.toml`
+    "###);
+
+    uv_snapshot!(context.filters(), context.config()
+        .arg("list")
+        .arg("--system")
+        .env(EnvVars::XDG_CONFIG_DIRS, config_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    offline = true
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
+
+/// Enforce the `required-version` of uv, as configured in the project.
+#[test]
+fn required_version() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(
+            regex::escape(env!("CARGO_PKG_VERSION")),
+            "[VERSION]".to_string(),
+        )])
+        .collect::<Vec<_>>();
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv]
+        required-version = "<0.1.0"
+        "#,
+    )?;
+
+    uv_snapshot!(filters, context.lock(), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Required uv version `<0.1.0` does not match the running version `[VERSION]`
+    "###);
+
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv]
+        required-version = ">=0.1.0"
+        "#,
+    )?;
+
+    uv_snapshot!(filters, context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###);
+
+    Ok(())
+}
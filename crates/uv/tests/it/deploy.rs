@@ -0,0 +1,133 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::prelude::*;
+use indoc::indoc;
+use predicates::prelude::predicate;
+
+use crate::common::{uv_snapshot, TestContext};
+
+/// Deploy the project's required dependencies into a prefix, omitting development dependencies.
+#[test]
+fn deploy() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+
+        [dependency-groups]
+        dev = ["anyio==4.3.0"]
+        "#
+    })?;
+
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.deploy().arg("--prefix").arg("prefix"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+    Bytecode compiled 3 files in [TIME]
+     + iniconfig==2.0.0
+    Deployed to: prefix
+    "###);
+
+    let site_packages = context
+        .temp_dir
+        .child("prefix")
+        .child("lib")
+        .child("python3.12")
+        .child("site-packages");
+
+    // The development dependencies should be omitted.
+    site_packages
+        .child("iniconfig")
+        .child("__pycache__")
+        .assert(predicate::path::is_dir());
+    site_packages
+        .child("anyio")
+        .assert(predicate::path::missing());
+
+    // Only the essential `.dist-info` files should be retained.
+    let dist_info = site_packages.child("iniconfig-2.0.0.dist-info");
+    dist_info
+        .child("METADATA")
+        .assert(predicate::path::is_file());
+    dist_info.child("RECORD").assert(predicate::path::is_file());
+    dist_info
+        .child("INSTALLER")
+        .assert(predicate::path::missing());
+    dist_info
+        .child("licenses")
+        .assert(predicate::path::missing());
+
+    // The `RECORD` should only refer to the retained files.
+    let record = fs_err::read_to_string(dist_info.child("RECORD"))?;
+    assert!(record.contains("iniconfig-2.0.0.dist-info/METADATA,"));
+    assert!(!record.contains("iniconfig-2.0.0.dist-info/INSTALLER"));
+    assert!(!record.contains("iniconfig-2.0.0.dist-info/licenses/"));
+
+    Ok(())
+}
+
+/// Deploying requires an up-to-date lockfile.
+#[test]
+fn deploy_outdated_lockfile() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+        "#
+    })?;
+
+    context.lock().assert().success();
+
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0", "sniffio==1.3.1"]
+        "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.deploy().arg("--prefix").arg("prefix"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    error: The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+      Caused by: The requirements of `foo` changed (added: `sniffio==1.3.1`)
+    "###);
+
+    // With `--frozen`, the lockfile is used as-is.
+    uv_snapshot!(context.filters(), context.deploy().arg("--prefix").arg("prefix").arg("--frozen"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+    Bytecode compiled 3 files in [TIME]
+     + iniconfig==2.0.0
+    Deployed to: prefix
+    "###);
+
+    Ok(())
+}
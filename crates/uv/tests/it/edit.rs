@@ -4860,6 +4860,122 @@ fn remove_group() -> Result<()> {
     Ok(())
 }
 
+/// Move requirements between the project dependencies, dependency groups, and optional
+/// dependencies, preserving their specifiers and sources.
+#[test]
+fn remove_move_to_group() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "anyio[trio]==3.7.0",
+            "iniconfig",
+        ]
+
+        [tool.uv.sources]
+        iniconfig = { git = "https://github.com/pytest-dev/iniconfig" }
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.remove().arg("iniconfig").arg("--move-to-group").arg("test").arg("--frozen"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    "###);
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "anyio[trio]==3.7.0",
+        ]
+
+        [tool.uv.sources]
+        iniconfig = { git = "https://github.com/pytest-dev/iniconfig" }
+
+        [dependency-groups]
+        test = [
+            "iniconfig",
+        ]
+        "###
+        );
+    });
+
+    uv_snapshot!(context.filters(), context.remove().arg("anyio").arg("--move-to-optional").arg("io").arg("--frozen"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    "###);
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [project.optional-dependencies]
+        io = [
+            "anyio[trio]==3.7.0",
+        ]
+
+        [tool.uv.sources]
+        iniconfig = { git = "https://github.com/pytest-dev/iniconfig" }
+
+        [dependency-groups]
+        test = [
+            "iniconfig",
+        ]
+        "###
+        );
+    });
+
+    // Moving a dependency to its current location is an error.
+    uv_snapshot!(context.filters(), context.remove().arg("iniconfig").arg("--group").arg("test").arg("--move-to-group").arg("test").arg("--frozen"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Cannot move dependencies to the same location from which they would be removed
+    "###);
+
+    // Moving a missing dependency is an error.
+    uv_snapshot!(context.filters(), context.remove().arg("iniconfig").arg("--move-to-project").arg("--frozen"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `iniconfig` is in the `test` group; try calling `uv remove --group test`
+    error: The dependency `iniconfig` could not be found in `dependencies`
+    "###);
+
+    Ok(())
+}
+
 /// Add to a PEP 732 script.
 #[test]
 fn add_script() -> Result<()> {
@@ -7494,3 +7610,82 @@ fn add_preserves_trailing_depth() -> Result<()> {
 
     Ok(())
 }
+
+/// With `--minimal-changes`, select the nearest version to the locked version when adding a
+/// requirement that no longer admits it.
+#[test]
+fn add_minimal_changes() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==1.0.0"]
+    "#})?;
+
+    context.lock().assert().success();
+
+    // Replace the requirement with one that excludes the locked version.
+    context
+        .add()
+        .arg("iniconfig>1.0.0")
+        .arg("--minimal-changes")
+        .assert()
+        .success();
+
+    let lock = context.read("uv.lock");
+    assert!(lock.contains("version = \"1.0.1\""));
+
+    Ok(())
+}
+
+/// Add a Mercurial dependency at a bookmark.
+#[test]
+#[cfg(feature = "hg")]
+fn add_hg_bookmark() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Create a repository with a `release` bookmark that trails the default branch.
+    let repo = context.temp_dir.child("repo");
+    let initial = common::make_hg_project(&repo, "dependency")?;
+    common::hg(&repo, &["bookmark", "-r", ".", "release"])?;
+    repo.child("README.md").write_str("")?;
+    common::hg(&repo, &["commit", "--addremove", "-m", "advance"])?;
+    let url = url::Url::from_directory_path(repo.path()).unwrap();
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    context
+        .add()
+        .arg(format!("dependency @ hg+{url}"))
+        .arg("--bookmark")
+        .arg("release")
+        .assert()
+        .success();
+
+    let pyproject_toml = context.read("pyproject.toml");
+    assert!(pyproject_toml.contains(r#"bookmark = "release""#));
+    assert!(context.read("uv.lock").contains(&initial));
+
+    // A bookmark is only valid for Mercurial dependencies.
+    uv_snapshot!(context.filters(), context.add().arg("anyio==3.7.0").arg("--bookmark").arg("release"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `anyio` did not resolve to a Mercurial repository, but a Mercurial bookmark (`--bookmark release`) was provided.
+    "###);
+
+    Ok(())
+}
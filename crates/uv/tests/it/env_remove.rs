@@ -0,0 +1,164 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::prelude::*;
+use indoc::indoc;
+use predicates::prelude::predicate;
+
+use crate::common::{uv_snapshot, TestContext};
+
+/// Remove the project environment, along with any cached environments layered on top of it.
+#[test]
+fn env_remove() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = ["sniffio==1.3.0"]
+        "#
+    })?;
+
+    // Create the project environment, and a cached environment layered on top of it.
+    context
+        .run()
+        .arg("--with")
+        .arg("iniconfig")
+        .arg("python")
+        .arg("-c")
+        .arg("import sniffio, iniconfig")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.env_remove(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Removed virtual environment at: .venv
+    Removed 1 cached environment
+    "###);
+
+    context
+        .temp_dir
+        .child(".venv")
+        .assert(predicate::path::missing());
+
+    // Removing the environment again should be a no-op.
+    uv_snapshot!(context.filters(), context.env_remove(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No virtual environment found at: .venv
+    "###);
+
+    Ok(())
+}
+
+/// Refuse to remove a project environment directory that isn't a virtual environment.
+#[test]
+fn env_remove_not_virtual_environment() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#
+    })?;
+
+    // Replace the virtual environment with a regular directory.
+    fs_err::remove_dir_all(context.temp_dir.child(".venv"))?;
+    context.temp_dir.child(".venv").child("file").touch()?;
+
+    uv_snapshot!(context.filters(), context.env_remove(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Skipping .venv, as it is not a virtual environment
+    "###);
+
+    context
+        .temp_dir
+        .child(".venv")
+        .child("file")
+        .assert(predicate::path::exists());
+
+    Ok(())
+}
+
+/// With `--all`, remove any environments in the directories of workspace members.
+#[test]
+fn env_remove_all() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = ["bar"]
+
+        [tool.uv.sources]
+        bar = { workspace = true }
+
+        [tool.uv.workspace]
+        members = ["bar"]
+        "#
+    })?;
+
+    let bar = context.temp_dir.child("bar");
+    bar.child("pyproject.toml").write_str(indoc! { r#"
+        [project]
+        name = "bar"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#
+    })?;
+
+    // Create the workspace environment, and a stale environment in the member directory.
+    context.sync().assert().success();
+    context
+        .venv()
+        .arg(bar.child(".venv").path())
+        .assert()
+        .success();
+
+    // By default, only the workspace environment is removed.
+    uv_snapshot!(context.filters(), context.env_remove(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Removed virtual environment at: .venv
+    "###);
+
+    bar.child(".venv").assert(predicate::path::exists());
+
+    uv_snapshot!(context.filters(), context.env_remove().arg("--all"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No virtual environment found at: .venv
+    Removed virtual environment at: bar/.venv
+    "###);
+
+    bar.child(".venv").assert(predicate::path::missing());
+
+    Ok(())
+}
@@ -4,7 +4,10 @@ use crate::common::{apply_filters, uv_snapshot, TestContext};
 use anyhow::{Ok, Result};
 use assert_cmd::assert::OutputAssertExt;
 use assert_fs::prelude::*;
+use indoc::indoc;
+use predicates::prelude::predicate;
 use std::process::Stdio;
+use uv_static::EnvVars;
 
 #[test]
 fn dependency() -> Result<()> {
@@ -1117,3 +1120,231 @@ fn export_group() -> Result<()> {
 
     Ok(())
 }
+
+/// Export the project and its dependencies as an executable zip application.
+#[test]
+fn zipapp() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#,
+    )?;
+    context
+        .temp_dir
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .write_str(indoc! { r"
+            def main():
+                import iniconfig
+                print(iniconfig.__name__)
+                return 3
+        "})?;
+
+    uv_snapshot!(context.filters(), context.export().arg("--format").arg("zipapp").arg("--entry-point").arg("project:main").arg("-o").arg("project.pyz"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    Created zipapp at: project.pyz
+    "###);
+
+    // The archive should be runnable, and should propagate the entry point's exit status.
+    uv_snapshot!(context.filters(), std::process::Command::new(context.interpreter()).arg("project.pyz").current_dir(&context.temp_dir), @r###"
+    success: false
+    exit_code: 3
+    ----- stdout -----
+    iniconfig
+
+    ----- stderr -----
+    "###);
+
+    // The project environment should be left untouched.
+    context
+        .temp_dir
+        .child(".venv")
+        .child("lib")
+        .child("python3.12")
+        .child("site-packages")
+        .child("iniconfig")
+        .assert(predicate::path::missing());
+
+    Ok(())
+}
+
+/// The `zipapp` format requires an entry point.
+#[test]
+fn zipapp_missing_entry_point() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.export().arg("--format").arg("zipapp").arg("-o").arg("project.pyz"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the following required arguments were not provided:
+      --entry-point <ENTRY_POINT>
+
+    Usage: uv export --format <FORMAT> --output-file <OUTPUT_FILE> --entry-point <ENTRY_POINT> --cache-dir [CACHE_DIR]
+
+    For more information, try '--help'.
+    "###);
+
+    uv_snapshot!(context.filters(), context.export().arg("--format").arg("zipapp").arg("--entry-point").arg("project:").arg("-o").arg("project.pyz"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: Invalid entry point `project:`; expected a module (e.g., `example`) or a callable (e.g., `example.cli:main`)
+    "###);
+
+    Ok(())
+}
+
+/// Export the project and its dependencies as a PEX file, using the `pex` executable to build the
+/// archive from the staged installation.
+#[test]
+#[cfg(unix)]
+fn pex() -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#,
+    )?;
+    context
+        .temp_dir
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .write_str("def main():\n    pass\n")?;
+
+    // Stub out `pex`, recording its arguments and a copy of the sources directory.
+    let bin = context.temp_dir.child("bin");
+    let pex = bin.child("pex");
+    pex.write_str(indoc! { r#"
+        #!/bin/sh
+        printf '%s\n' "$@" > pex-args.txt
+        cp -R "$3" staged
+        touch "$(eval echo \${$#})"
+    "#})?;
+    fs_err::set_permissions(pex.path(), std::fs::Permissions::from_mode(0o755))?;
+    let path = std::env::join_paths(std::iter::once(bin.to_path_buf()).chain(
+        std::env::split_paths(&std::env::var_os(EnvVars::PATH).unwrap_or_default()),
+    ))?;
+
+    uv_snapshot!(context.filters(), context.export().arg("--format").arg("pex").arg("--entry-point").arg("project:main").arg("-o").arg("project.pex").env(EnvVars::PATH, path), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    Created pex at: project.pex
+    "###);
+
+    // `pex` should be invoked with the staged installation, rather than re-resolving.
+    let args = fs_err::read_to_string(context.temp_dir.child("pex-args.txt"))?;
+    let args = args.lines().collect::<Vec<_>>();
+    assert_eq!(args[..2], ["--no-pypi", "--sources-directory"]);
+    assert_eq!(
+        args[3..],
+        [
+            "--entry-point",
+            "project:main",
+            "--python-shebang",
+            "/usr/bin/env python3",
+            "--venv",
+            "--output-file",
+            "project.pex",
+        ]
+    );
+
+    let staged = context.temp_dir.child("staged");
+    staged
+        .child("iniconfig")
+        .child("__init__.py")
+        .assert(predicate::path::is_file());
+    staged
+        .child("project")
+        .child("__init__.py")
+        .assert(predicate::path::is_file());
+    staged.child("bin").assert(predicate::path::missing());
+
+    Ok(())
+}
+
+/// The `pex` format requires the `pex` executable.
+#[test]
+fn pex_missing() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.export().arg("--format").arg("pex").arg("--entry-point").arg("project:main").arg("-o").arg("project.pex").env(EnvVars::PATH, ""), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The `pex` format requires the `pex` executable, which was not found on the `PATH`; install it with `uv tool install pex`
+    "###);
+
+    Ok(())
+}
@@ -21,14 +21,28 @@ fn help() {
       sync                       Update the project's environment
       lock                       Update the project's lockfile
       export                     Export the project's lockfile to an alternate format
+      bundle                     Bundle the project environment into a relocatable archive
+      deploy                     Install the project into a target prefix for deployment
+      vendor                     Download the project's locked distributions into a local index
+      licenses                   Display the licenses of the project's locked dependencies
+      outdated                   List the project's direct dependencies that have newer releases
       tree                       Display the project's dependency tree
       tool                       Run and install commands provided by Python packages
       python                     Manage Python versions and installations
       pip                        Manage Python packages with a pip-compatible interface
       venv                       Create a virtual environment
+      env                        Manage project environments
+      workspace                  Manage workspaces
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
+      auth                       Manage credentials for package indexes
+      index                      Manage package indexes in the project or user configuration
+      config                     Read and write settings in the project, user, or system configuration
+      search                     Search the configured package indexes for packages by name
+      info                       Display information about a package from the configured package indexes
       cache                      Manage uv's cache
+      stats                      Summarize the size of uv's cache and its recent activity
+      doctor                     Diagnose common problems with uv's environment and configuration
       self                       Manage the uv executable
       version                    Display uv's version
       generate-shell-completion  Generate shell completion
@@ -51,9 +65,13 @@ fn help() {
       -v, --verbose...                 Use verbose output
           --color <COLOR_CHOICE>       Control colors in output [default: auto] [possible values: auto,
                                        always, never]
+          --log-format <LOG_FORMAT>    The format in which to emit log messages [env: UV_LOG_FORMAT=]
+                                       [default: text] [possible values: text, json]
           --native-tls                 Whether to load TLS certificates from the platform's native
                                        certificate store [env: UV_NATIVE_TLS=]
-          --offline                    Disable network access
+          --offline[=<MODE>]           Disable network access [possible values: strict]
+          --build-jobs <JOBS>          The maximum number of source distributions that uv will build
+                                       concurrently
           --no-progress                Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>      Change to the given directory prior to running the command
           --project <PROJECT>          Run the command within the given project directory
@@ -61,6 +79,7 @@ fn help() {
                                        UV_CONFIG_FILE=]
           --no-config                  Avoid discovering configuration files (`pyproject.toml`,
                                        `uv.toml`) [env: UV_NO_CONFIG=]
+          --profile <PROFILE>          Apply the named configuration profile [env: UV_PROFILE=]
       -h, --help                       Display the concise help for this command
       -V, --version                    Display the uv version
 
@@ -84,24 +103,38 @@ fn help_flag() {
     Usage: uv [OPTIONS] <COMMAND>
 
     Commands:
-      run      Run a command or script
-      init     Create a new project
-      add      Add dependencies to the project
-      remove   Remove dependencies from the project
-      sync     Update the project's environment
-      lock     Update the project's lockfile
-      export   Export the project's lockfile to an alternate format
-      tree     Display the project's dependency tree
-      tool     Run and install commands provided by Python packages
-      python   Manage Python versions and installations
-      pip      Manage Python packages with a pip-compatible interface
-      venv     Create a virtual environment
-      build    Build Python packages into source distributions and wheels
-      publish  Upload distributions to an index
-      cache    Manage uv's cache
-      self     Manage the uv executable
-      version  Display uv's version
-      help     Display documentation for a command
+      run        Run a command or script
+      init       Create a new project
+      add        Add dependencies to the project
+      remove     Remove dependencies from the project
+      sync       Update the project's environment
+      lock       Update the project's lockfile
+      export     Export the project's lockfile to an alternate format
+      bundle     Bundle the project environment into a relocatable archive
+      deploy     Install the project into a target prefix for deployment
+      vendor     Download the project's locked distributions into a local index
+      licenses   Display the licenses of the project's locked dependencies
+      outdated   List the project's direct dependencies that have newer releases
+      tree       Display the project's dependency tree
+      tool       Run and install commands provided by Python packages
+      python     Manage Python versions and installations
+      pip        Manage Python packages with a pip-compatible interface
+      venv       Create a virtual environment
+      env        Manage project environments
+      workspace  Manage workspaces
+      build      Build Python packages into source distributions and wheels
+      publish    Upload distributions to an index
+      auth       Manage credentials for package indexes
+      index      Manage package indexes in the project or user configuration
+      config     Read and write settings in the project, user, or system configuration
+      search     Search the configured package indexes for packages by name
+      info       Display information about a package from the configured package indexes
+      cache      Manage uv's cache
+      stats      Summarize the size of uv's cache and its recent activity
+      doctor     Diagnose common problems with uv's environment and configuration
+      self       Manage the uv executable
+      version    Display uv's version
+      help       Display documentation for a command
 
     Cache options:
       -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
@@ -120,9 +153,13 @@ fn help_flag() {
       -v, --verbose...                 Use verbose output
           --color <COLOR_CHOICE>       Control colors in output [default: auto] [possible values: auto,
                                        always, never]
+          --log-format <LOG_FORMAT>    The format in which to emit log messages [env: UV_LOG_FORMAT=]
+                                       [default: text] [possible values: text, json]
           --native-tls                 Whether to load TLS certificates from the platform's native
                                        certificate store [env: UV_NATIVE_TLS=]
-          --offline                    Disable network access
+          --offline[=<MODE>]           Disable network access [possible values: strict]
+          --build-jobs <JOBS>          The maximum number of source distributions that uv will build
+                                       concurrently
           --no-progress                Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>      Change to the given directory prior to running the command
           --project <PROJECT>          Run the command within the given project directory
@@ -130,6 +167,7 @@ fn help_flag() {
                                        UV_CONFIG_FILE=]
           --no-config                  Avoid discovering configuration files (`pyproject.toml`,
                                        `uv.toml`) [env: UV_NO_CONFIG=]
+          --profile <PROFILE>          Apply the named configuration profile [env: UV_PROFILE=]
       -h, --help                       Display the concise help for this command
       -V, --version                    Display the uv version
 
@@ -152,24 +190,38 @@ fn help_short_flag() {
     Usage: uv [OPTIONS] <COMMAND>
 
     Commands:
-      run      Run a command or script
-      init     Create a new project
-      add      Add dependencies to the project
-      remove   Remove dependencies from the project
-      sync     Update the project's environment
-      lock     Update the project's lockfile
-      export   Export the project's lockfile to an alternate format
-      tree     Display the project's dependency tree
-      tool     Run and install commands provided by Python packages
-      python   Manage Python versions and installations
-      pip      Manage Python packages with a pip-compatible interface
-      venv     Create a virtual environment
-      build    Build Python packages into source distributions and wheels
-      publish  Upload distributions to an index
-      cache    Manage uv's cache
-      self     Manage the uv executable
-      version  Display uv's version
-      help     Display documentation for a command
+      run        Run a command or script
+      init       Create a new project
+      add        Add dependencies to the project
+      remove     Remove dependencies from the project
+      sync       Update the project's environment
+      lock       Update the project's lockfile
+      export     Export the project's lockfile to an alternate format
+      bundle     Bundle the project environment into a relocatable archive
+      deploy     Install the project into a target prefix for deployment
+      vendor     Download the project's locked distributions into a local index
+      licenses   Display the licenses of the project's locked dependencies
+      outdated   List the project's direct dependencies that have newer releases
+      tree       Display the project's dependency tree
+      tool       Run and install commands provided by Python packages
+      python     Manage Python versions and installations
+      pip        Manage Python packages with a pip-compatible interface
+      venv       Create a virtual environment
+      env        Manage project environments
+      workspace  Manage workspaces
+      build      Build Python packages into source distributions and wheels
+      publish    Upload distributions to an index
+      auth       Manage credentials for package indexes
+      index      Manage package indexes in the project or user configuration
+      config     Read and write settings in the project, user, or system configuration
+      search     Search the configured package indexes for packages by name
+      info       Display information about a package from the configured package indexes
+      cache      Manage uv's cache
+      stats      Summarize the size of uv's cache and its recent activity
+      doctor     Diagnose common problems with uv's environment and configuration
+      self       Manage the uv executable
+      version    Display uv's version
+      help       Display documentation for a command
 
     Cache options:
       -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
@@ -188,9 +240,13 @@ fn help_short_flag() {
       -v, --verbose...                 Use verbose output
           --color <COLOR_CHOICE>       Control colors in output [default: auto] [possible values: auto,
                                        always, never]
+          --log-format <LOG_FORMAT>    The format in which to emit log messages [env: UV_LOG_FORMAT=]
+                                       [default: text] [possible values: text, json]
           --native-tls                 Whether to load TLS certificates from the platform's native
                                        certificate store [env: UV_NATIVE_TLS=]
-          --offline                    Disable network access
+          --offline[=<MODE>]           Disable network access [possible values: strict]
+          --build-jobs <JOBS>          The maximum number of source distributions that uv will build
+                                       concurrently
           --no-progress                Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>      Change to the given directory prior to running the command
           --project <PROJECT>          Run the command within the given project directory
@@ -198,6 +254,7 @@ fn help_short_flag() {
                                        UV_CONFIG_FILE=]
           --no-config                  Avoid discovering configuration files (`pyproject.toml`,
                                        `uv.toml`) [env: UV_NO_CONFIG=]
+          --profile <PROFILE>          Apply the named configuration profile [env: UV_PROFILE=]
       -h, --help                       Display the concise help for this command
       -V, --version                    Display the uv version
 
@@ -324,6 +381,20 @@ fn help_subcommand() {
               - always: Enables colored output regardless of the detected environment
               - never:  Disables colored output
 
+          --log-format <LOG_FORMAT>
+              The format in which to emit log messages.
+              
+              Log messages are written to stderr when enabled via `--verbose` or `RUST_LOG`. With
+              `json`, each message is written as a JSON object on its own line, along with the spans in
+              which it was emitted, for consumption by log aggregation tools.
+              
+              [env: UV_LOG_FORMAT=]
+              [default: text]
+
+              Possible values:
+              - text: Display log messages as human-readable text
+              - json: Display log messages as newline-delimited JSON objects
+
           --native-tls
               Whether to load TLS certificates from the platform's native certificate store.
               
@@ -337,10 +408,28 @@ fn help_subcommand() {
               
               [env: UV_NATIVE_TLS=]
 
-          --offline
+          --offline[=<MODE>]
               Disable network access.
               
               When disabled, uv will only use locally cached data and locally available files.
+              
+              With `--offline=strict`, uv will additionally verify that every distribution required
+              by an installation is available locally before making any changes, and fail with a
+              complete list of the missing distributions, rather than erroring partway through.
+
+              Possible values:
+              - strict: Verify that all required distributions are available locally before
+                installing
+
+          --build-jobs <JOBS>
+              The maximum number of source distributions that uv will build concurrently.
+              
+              When a resolution or sync requires building multiple source distributions, builds are
+              performed in parallel up to this limit. In verbose output, the build backend logs of each
+              build are prefixed with the distribution being built.
+              
+              Defaults to the number of available CPU cores. Overrides the `concurrent-builds` setting
+              and `UV_CONCURRENT_BUILDS`.
 
           --no-progress
               Hide all progress outputs.
@@ -386,6 +475,15 @@ fn help_subcommand() {
               
               [env: UV_NO_CONFIG=]
 
+          --profile <PROFILE>
+              Apply the named configuration profile.
+              
+              Profiles are defined under `profile` in a `uv.toml` (e.g., `[profile.airgapped]`), or
+              under `tool.uv.profile` in a `pyproject.toml`. The profile's settings take precedence
+              over the settings defined outside of any profile.
+              
+              [env: UV_PROFILE=]
+
       -h, --help
               Display the concise help for this command
 
@@ -411,8 +509,9 @@ fn help_subsubcommand() {
 
     Multiple Python versions may be requested.
 
-    Supports CPython and PyPy. CPython distributions are downloaded from the `python-build-standalone`
-    project. PyPy distributions are downloaded from `python.org`.
+    Supports CPython, PyPy, and GraalPy. CPython distributions are downloaded from the
+    `python-build-standalone` project. PyPy distributions are downloaded from `python.org`. GraalPy
+    distributions are downloaded from GitHub.
 
     Python versions are installed into the uv Python directory, which can be retrieved with `uv python
     dir`.
@@ -441,6 +540,31 @@ fn help_subsubcommand() {
               
               By default, uv will exit successfully if the version is already installed.
 
+          --variant <VARIANT>
+              The build variant of the Python version(s) to install.
+              
+              By default, uv installs the most optimized build available for the platform (e.g., with
+              PGO and LTO), with the GIL enabled. The variant can also be requested directly, e.g.,
+              `3.13d` or `3.13+debug`.
+
+              Possible values:
+              - default:      The default build, with the GIL enabled
+              - freethreaded: A free-threaded build, with the GIL disabled
+              - debug:        A debug build, e.g., with assertions enabled and without optimizations
+
+          --registry
+              Register the installed Python version(s) in the Windows registry.
+              
+              Following PEP 514, the installations are registered under the `Astral` company in
+              `HKEY_CURRENT_USER`, such that the `py` launcher (e.g., `py -V:3.12`) and other tools that
+              read the registry can discover them.
+              
+              The registry entries are removed when the Python version is uninstalled.
+              
+              This option has no effect on platforms other than Windows.
+              
+              [env: UV_PYTHON_INSTALL_REGISTRY=]
+
     Cache options:
       -n, --no-cache
               Avoid reading from or writing to the cache, instead using a temporary directory for the
@@ -500,6 +624,20 @@ fn help_subsubcommand() {
               - always: Enables colored output regardless of the detected environment
               - never:  Disables colored output
 
+          --log-format <LOG_FORMAT>
+              The format in which to emit log messages.
+              
+              Log messages are written to stderr when enabled via `--verbose` or `RUST_LOG`. With
+              `json`, each message is written as a JSON object on its own line, along with the spans in
+              which it was emitted, for consumption by log aggregation tools.
+              
+              [env: UV_LOG_FORMAT=]
+              [default: text]
+
+              Possible values:
+              - text: Display log messages as human-readable text
+              - json: Display log messages as newline-delimited JSON objects
+
           --native-tls
               Whether to load TLS certificates from the platform's native certificate store.
               
@@ -513,10 +651,28 @@ fn help_subsubcommand() {
               
               [env: UV_NATIVE_TLS=]
 
-          --offline
+          --offline[=<MODE>]
               Disable network access.
               
               When disabled, uv will only use locally cached data and locally available files.
+              
+              With `--offline=strict`, uv will additionally verify that every distribution required
+              by an installation is available locally before making any changes, and fail with a
+              complete list of the missing distributions, rather than erroring partway through.
+
+              Possible values:
+              - strict: Verify that all required distributions are available locally before
+                installing
+
+          --build-jobs <JOBS>
+              The maximum number of source distributions that uv will build concurrently.
+              
+              When a resolution or sync requires building multiple source distributions, builds are
+              performed in parallel up to this limit. In verbose output, the build backend logs of each
+              build are prefixed with the distribution being built.
+              
+              Defaults to the number of available CPU cores. Overrides the `concurrent-builds` setting
+              and `UV_CONCURRENT_BUILDS`.
 
           --no-progress
               Hide all progress outputs.
@@ -562,6 +718,15 @@ fn help_subsubcommand() {
               
               [env: UV_NO_CONFIG=]
 
+          --profile <PROFILE>
+              Apply the named configuration profile.
+              
+              Profiles are defined under `profile` in a `uv.toml` (e.g., `[profile.airgapped]`), or
+              under `tool.uv.profile` in a `pyproject.toml`. The profile's settings take precedence
+              over the settings defined outside of any profile.
+              
+              [env: UV_PROFILE=]
+
       -h, --help
               Display the concise help for this command
 
@@ -610,9 +775,13 @@ fn help_flag_subcommand() {
       -v, --verbose...                 Use verbose output
           --color <COLOR_CHOICE>       Control colors in output [default: auto] [possible values: auto,
                                        always, never]
+          --log-format <LOG_FORMAT>    The format in which to emit log messages [env: UV_LOG_FORMAT=]
+                                       [default: text] [possible values: text, json]
           --native-tls                 Whether to load TLS certificates from the platform's native
                                        certificate store [env: UV_NATIVE_TLS=]
-          --offline                    Disable network access
+          --offline[=<MODE>]           Disable network access [possible values: strict]
+          --build-jobs <JOBS>          The maximum number of source distributions that uv will build
+                                       concurrently
           --no-progress                Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>      Change to the given directory prior to running the command
           --project <PROJECT>          Run the command within the given project directory
@@ -620,6 +789,7 @@ fn help_flag_subcommand() {
                                        UV_CONFIG_FILE=]
           --no-config                  Avoid discovering configuration files (`pyproject.toml`,
                                        `uv.toml`) [env: UV_NO_CONFIG=]
+          --profile <PROFILE>          Apply the named configuration profile [env: UV_PROFILE=]
       -h, --help                       Display the concise help for this command
       -V, --version                    Display the uv version
 
@@ -645,7 +815,9 @@ fn help_flag_subsubcommand() {
       [TARGETS]...  The Python version(s) to install
 
     Options:
-      -r, --reinstall  Reinstall the requested Python version, if it's already installed
+      -r, --reinstall          Reinstall the requested Python version, if it's already installed
+          --variant <VARIANT>  The build variant of the Python version(s) to install [possible values:
+                               default, freethreaded, debug]
 
     Cache options:
       -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
@@ -664,9 +836,13 @@ fn help_flag_subsubcommand() {
       -v, --verbose...                 Use verbose output
           --color <COLOR_CHOICE>       Control colors in output [default: auto] [possible values: auto,
                                        always, never]
+          --log-format <LOG_FORMAT>    The format in which to emit log messages [env: UV_LOG_FORMAT=]
+                                       [default: text] [possible values: text, json]
           --native-tls                 Whether to load TLS certificates from the platform's native
                                        certificate store [env: UV_NATIVE_TLS=]
-          --offline                    Disable network access
+          --offline[=<MODE>]           Disable network access [possible values: strict]
+          --build-jobs <JOBS>          The maximum number of source distributions that uv will build
+                                       concurrently
           --no-progress                Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>      Change to the given directory prior to running the command
           --project <PROJECT>          Run the command within the given project directory
@@ -674,6 +850,7 @@ fn help_flag_subsubcommand() {
                                        UV_CONFIG_FILE=]
           --no-config                  Avoid discovering configuration files (`pyproject.toml`,
                                        `uv.toml`) [env: UV_NO_CONFIG=]
+          --profile <PROFILE>          Apply the named configuration profile [env: UV_PROFILE=]
       -h, --help                       Display the concise help for this command
       -V, --version                    Display the uv version
 
@@ -780,14 +957,28 @@ fn help_with_global_option() {
       sync                       Update the project's environment
       lock                       Update the project's lockfile
       export                     Export the project's lockfile to an alternate format
+      bundle                     Bundle the project environment into a relocatable archive
+      deploy                     Install the project into a target prefix for deployment
+      vendor                     Download the project's locked distributions into a local index
+      licenses                   Display the licenses of the project's locked dependencies
+      outdated                   List the project's direct dependencies that have newer releases
       tree                       Display the project's dependency tree
       tool                       Run and install commands provided by Python packages
       python                     Manage Python versions and installations
       pip                        Manage Python packages with a pip-compatible interface
       venv                       Create a virtual environment
+      env                        Manage project environments
+      workspace                  Manage workspaces
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
+      auth                       Manage credentials for package indexes
+      index                      Manage package indexes in the project or user configuration
+      config                     Read and write settings in the project, user, or system configuration
+      search                     Search the configured package indexes for packages by name
+      info                       Display information about a package from the configured package indexes
       cache                      Manage uv's cache
+      stats                      Summarize the size of uv's cache and its recent activity
+      doctor                     Diagnose common problems with uv's environment and configuration
       self                       Manage the uv executable
       version                    Display uv's version
       generate-shell-completion  Generate shell completion
@@ -810,9 +1001,13 @@ fn help_with_global_option() {
       -v, --verbose...                 Use verbose output
           --color <COLOR_CHOICE>       Control colors in output [default: auto] [possible values: auto,
                                        always, never]
+          --log-format <LOG_FORMAT>    The format in which to emit log messages [env: UV_LOG_FORMAT=]
+                                       [default: text] [possible values: text, json]
           --native-tls                 Whether to load TLS certificates from the platform's native
                                        certificate store [env: UV_NATIVE_TLS=]
-          --offline                    Disable network access
+          --offline[=<MODE>]           Disable network access [possible values: strict]
+          --build-jobs <JOBS>          The maximum number of source distributions that uv will build
+                                       concurrently
           --no-progress                Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>      Change to the given directory prior to running the command
           --project <PROJECT>          Run the command within the given project directory
@@ -820,6 +1015,7 @@ fn help_with_global_option() {
                                        UV_CONFIG_FILE=]
           --no-config                  Avoid discovering configuration files (`pyproject.toml`,
                                        `uv.toml`) [env: UV_NO_CONFIG=]
+          --profile <PROFILE>          Apply the named configuration profile [env: UV_PROFILE=]
       -h, --help                       Display the concise help for this command
       -V, --version                    Display the uv version
 
@@ -885,14 +1081,28 @@ fn help_with_no_pager() {
       sync                       Update the project's environment
       lock                       Update the project's lockfile
       export                     Export the project's lockfile to an alternate format
+      bundle                     Bundle the project environment into a relocatable archive
+      deploy                     Install the project into a target prefix for deployment
+      vendor                     Download the project's locked distributions into a local index
+      licenses                   Display the licenses of the project's locked dependencies
+      outdated                   List the project's direct dependencies that have newer releases
       tree                       Display the project's dependency tree
       tool                       Run and install commands provided by Python packages
       python                     Manage Python versions and installations
       pip                        Manage Python packages with a pip-compatible interface
       venv                       Create a virtual environment
+      env                        Manage project environments
+      workspace                  Manage workspaces
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
+      auth                       Manage credentials for package indexes
+      index                      Manage package indexes in the project or user configuration
+      config                     Read and write settings in the project, user, or system configuration
+      search                     Search the configured package indexes for packages by name
+      info                       Display information about a package from the configured package indexes
       cache                      Manage uv's cache
+      stats                      Summarize the size of uv's cache and its recent activity
+      doctor                     Diagnose common problems with uv's environment and configuration
       self                       Manage the uv executable
       version                    Display uv's version
       generate-shell-completion  Generate shell completion
@@ -915,9 +1125,13 @@ fn help_with_no_pager() {
       -v, --verbose...                 Use verbose output
           --color <COLOR_CHOICE>       Control colors in output [default: auto] [possible values: auto,
                                        always, never]
+          --log-format <LOG_FORMAT>    The format in which to emit log messages [env: UV_LOG_FORMAT=]
+                                       [default: text] [possible values: text, json]
           --native-tls                 Whether to load TLS certificates from the platform's native
                                        certificate store [env: UV_NATIVE_TLS=]
-          --offline                    Disable network access
+          --offline[=<MODE>]           Disable network access [possible values: strict]
+          --build-jobs <JOBS>          The maximum number of source distributions that uv will build
+                                       concurrently
           --no-progress                Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>      Change to the given directory prior to running the command
           --project <PROJECT>          Run the command within the given project directory
@@ -925,6 +1139,7 @@ fn help_with_no_pager() {
                                        UV_CONFIG_FILE=]
           --no-config                  Avoid discovering configuration files (`pyproject.toml`,
                                        `uv.toml`) [env: UV_NO_CONFIG=]
+          --profile <PROFILE>          Apply the named configuration profile [env: UV_PROFILE=]
       -h, --help                       Display the concise help for this command
       -V, --version                    Display the uv version
 
@@ -0,0 +1,309 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+use indoc::indoc;
+use insta::assert_snapshot;
+
+use uv_static::EnvVars;
+
+use crate::common::{uv_snapshot, TestContext};
+
+#[test]
+fn index_add_remove() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.index()
+        .arg("add")
+        .arg("pytorch")
+        .arg("https://download.pytorch.org/whl/cpu")
+        .arg("--explicit"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Added index `pytorch` to `pyproject.toml`
+    hint: If the index requires authentication, run `uv auth login pytorch`, or set `UV_INDEX_PYTORCH_USERNAME` and `UV_INDEX_PYTORCH_PASSWORD`
+    "###);
+
+    // By default, new indexes are given the highest priority.
+    context
+        .index()
+        .arg("add")
+        .arg("internal")
+        .arg("https://pypi.example.com/simple")
+        .assert()
+        .success();
+
+    // With `--last`, new indexes are given the lowest priority.
+    context
+        .index()
+        .arg("add")
+        .arg("extra")
+        .arg("https://extra.example.com/simple")
+        .arg("--last")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.index().arg("list"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    internal  https://pypi.example.com/simple
+    pytorch   https://download.pytorch.org/whl/cpu (explicit)
+    extra     https://extra.example.com/simple
+
+    ----- stderr -----
+    "###);
+
+    // The default index is always given the lowest priority.
+    uv_snapshot!(context.filters(), context.index().arg("set-default").arg("internal"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Set index `internal` as the default in `pyproject.toml`
+    "###);
+
+    uv_snapshot!(context.filters(), context.index().arg("list"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    pytorch   https://download.pytorch.org/whl/cpu (explicit)
+    extra     https://extra.example.com/simple
+    internal  https://pypi.example.com/simple (default)
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.index().arg("remove").arg("pytorch"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Removed index `pytorch` from `pyproject.toml`
+    "###);
+
+    uv_snapshot!(context.filters(), context.index().arg("remove").arg("pytorch"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Index `pytorch` is not defined in `pyproject.toml`
+    "###);
+
+    assert_snapshot!(fs_err::read_to_string(&pyproject_toml)?, @r###"
+    [project]
+    name = "project"
+    version = "0.1.0"
+    requires-python = ">=3.12"
+    dependencies = []
+
+    [[tool.uv.index]]
+    name = "internal"
+    url = "https://pypi.example.com/simple"
+    default = true
+
+    [[tool.uv.index]]
+    name = "extra"
+    url = "https://extra.example.com/simple"
+    "###);
+
+    Ok(())
+}
+
+/// With a `uv.toml` at the workspace root, `uv index add` edits it, like `uv config set`.
+#[test]
+fn index_add_uv_toml() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+    let uv_toml = context.temp_dir.child("uv.toml");
+    uv_toml.write_str("native-tls = true\n")?;
+
+    uv_snapshot!(context.filters(), context.index()
+        .arg("add")
+        .arg("internal")
+        .arg("https://pypi.example.com/simple"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Added index `internal` to `uv.toml`
+    hint: If the index requires authentication, run `uv auth login internal`, or set `UV_INDEX_INTERNAL_USERNAME` and `UV_INDEX_INTERNAL_PASSWORD`
+    "###);
+
+    uv_snapshot!(context.filters(), context.config().arg("set").arg("offline").arg("true"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Set `offline` in `uv.toml`
+    "###);
+
+    assert_snapshot!(fs_err::read_to_string(&uv_toml)?, @r###"
+    native-tls = true
+    offline = true
+
+    [[index]]
+    name = "internal"
+    url = "https://pypi.example.com/simple"
+    "###);
+
+    // The `pyproject.toml` is left untouched.
+    assert_snapshot!(fs_err::read_to_string(&pyproject_toml)?, @r###"
+    [project]
+    name = "project"
+    version = "0.1.0"
+    requires-python = ">=3.12"
+    dependencies = []
+    "###);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(not(windows))]
+fn index_add_user() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let config_dir = context.temp_dir.child("config");
+
+    uv_snapshot!(context.filters(), context.index()
+        .arg("add")
+        .arg("internal")
+        .arg("https://pypi.example.com/simple")
+        .arg("--default")
+        .arg("--user")
+        .env(EnvVars::XDG_CONFIG_HOME, config_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Added index `internal` to `config/uv/uv.toml`
+    hint: If the index requires authentication, run `uv auth login internal`, or set `UV_INDEX_INTERNAL_USERNAME` and `UV_INDEX_INTERNAL_PASSWORD`
+    "###);
+
+    assert_snapshot!(fs_err::read_to_string(config_dir.join("uv").join("uv.toml"))?, @r###"
+    [[index]]
+    name = "internal"
+    url = "https://pypi.example.com/simple"
+    default = true
+    "###);
+
+    Ok(())
+}
+
+/// Re-adding an explicit index without `--explicit` makes it non-explicit.
+#[test]
+fn index_add_explicit_update() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [[tool.uv.index]]
+        name = "pytorch"
+        url = "https://download.pytorch.org/whl/cpu"
+        explicit = true
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.index()
+        .arg("add")
+        .arg("pytorch")
+        .arg("https://download.pytorch.org/whl/cpu"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Updated index `pytorch` in `pyproject.toml`
+    hint: If the index requires authentication, run `uv auth login pytorch`, or set `UV_INDEX_PYTORCH_USERNAME` and `UV_INDEX_PYTORCH_PASSWORD`
+    "###);
+
+    assert_snapshot!(fs_err::read_to_string(&pyproject_toml)?, @r###"
+    [project]
+    name = "project"
+    version = "0.1.0"
+    requires-python = ">=3.12"
+    dependencies = []
+
+    [[tool.uv.index]]
+    name = "pytorch"
+    url = "https://download.pytorch.org/whl/cpu"
+    "###);
+
+    Ok(())
+}
+
+/// An index that's still referenced by `tool.uv.sources` can't be removed.
+#[test]
+fn index_remove_referenced() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["torch", "torchvision"]
+
+        [[tool.uv.index]]
+        name = "pytorch"
+        url = "https://download.pytorch.org/whl/cpu"
+        explicit = true
+
+        [tool.uv.sources]
+        torch = { index = "pytorch" }
+        torchvision = [
+            { index = "pytorch", marker = "sys_platform == 'linux'" },
+        ]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.index().arg("remove").arg("pytorch"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Index `pytorch` is still referenced in `tool.uv.sources` by: torch, torchvision
+    "###);
+
+    // The index is left in place.
+    uv_snapshot!(context.filters(), context.index().arg("list"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    pytorch  https://download.pytorch.org/whl/cpu (explicit)
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
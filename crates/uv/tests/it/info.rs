@@ -0,0 +1,85 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+
+use crate::common::{uv_snapshot, TestContext};
+
+/// `uv info` should read the metadata of the latest release from a local index, and list the
+/// available versions from newest to oldest.
+#[test]
+fn info_local_index() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let index = context.temp_dir.child("index");
+    let ok = index.child("ok");
+    ok.child("index.html").write_str(
+        r#"
+        <!DOCTYPE html>
+        <html>
+          <body>
+            <a href="ok-1.0.0-py3-none-any.whl" data-yanked="Broken build">ok-1.0.0-py3-none-any.whl</a>
+            <a href="ok-2.0.0-py3-none-any.whl">ok-2.0.0-py3-none-any.whl</a>
+          </body>
+        </html>
+        "#,
+    )?;
+    for wheel in ["ok-1.0.0-py3-none-any.whl", "ok-2.0.0-py3-none-any.whl"] {
+        fs_err::copy(
+            context.workspace_root.join("scripts/links").join(wheel),
+            ok.child(wheel).path(),
+        )?;
+    }
+
+    uv_snapshot!(context.filters(), context.info().arg("ok").arg("--default-index").arg(index.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    ok 2.0.0
+
+    Requires-Python: >=3.8
+    Dependencies: none
+
+    Versions:
+      2.0.0  compatible wheel
+      1.0.0  compatible wheel (yanked)
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.info().arg("ok").arg("--versions").arg("1").arg("--default-index").arg(index.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    ok 2.0.0
+
+    Requires-Python: >=3.8
+    Dependencies: none
+
+    Versions:
+      2.0.0  compatible wheel
+      ...and 1 more (use `--versions` to show more)
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
+
+/// `uv info` should fail if the package isn't available on any index.
+#[test]
+fn info_not_found() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let index = context.temp_dir.child("index");
+    index.create_dir_all()?;
+
+    uv_snapshot!(context.filters(), context.info().arg("ok").arg("--default-index").arg(index.path()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Package `ok` was not found in the registry
+    "###);
+
+    Ok(())
+}
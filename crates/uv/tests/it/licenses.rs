@@ -0,0 +1,258 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+use indoc::indoc;
+
+use crate::common::{uv_snapshot, TestContext};
+
+/// Group the locked packages by license.
+#[test]
+fn licenses() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+        "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.licenses(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    MIT (1 package)
+      iniconfig 2.0.0
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###);
+
+    uv_snapshot!(context.filters(), context.licenses().arg("--frozen").arg("--output-format").arg("json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [
+      {
+        "name": "iniconfig",
+        "version": "2.0.0",
+        "license": "MIT"
+      }
+    ]
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
+
+/// Enforce a license policy, failing if a disallowed license is found.
+#[test]
+fn licenses_policy() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+        "#
+    })?;
+
+    let policy = context.temp_dir.child("licenses.toml");
+    policy.write_str(indoc! { r#"
+        allow = ["Apache-2.0"]
+        "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.licenses().arg("--policy").arg("licenses.toml"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    MIT (1 package)
+      iniconfig 2.0.0
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Found 1 package with a disallowed license:
+      - iniconfig 2.0.0: `MIT` is not allowed
+    "###);
+
+    policy.write_str(indoc! { r#"
+        deny = ["GPL-3.0-only", "MIT"]
+        "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.licenses().arg("--frozen").arg("--policy").arg("licenses.toml"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    MIT (1 package)
+      iniconfig 2.0.0
+
+    ----- stderr -----
+    Found 1 package with a disallowed license:
+      - iniconfig 2.0.0: `MIT` is denied
+    "###);
+
+    // Ignored packages are exempt from the policy.
+    policy.write_str(indoc! { r#"
+        deny = ["MIT"]
+        ignore = ["iniconfig"]
+        "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.licenses().arg("--frozen").arg("--policy").arg("licenses.toml"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    MIT (1 package)
+      iniconfig 2.0.0
+
+    ----- stderr -----
+    All licenses are permitted by the license policy
+    "###);
+
+    Ok(())
+}
+
+/// Reject a malformed license policy.
+#[test]
+fn licenses_invalid_policy() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#
+    })?;
+
+    let policy = context.temp_dir.child("licenses.toml");
+    policy.write_str(indoc! { r#"
+        permit = ["MIT"]
+        "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.licenses().arg("--policy").arg("licenses.toml"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to parse license policy: `licenses.toml`
+      Caused by: TOML parse error at line 1, column 1
+      |
+    1 | permit = ["MIT"]
+      | ^^^^^^
+    unknown field `permit`, expected one of `allow`, `deny`, `ignore`
+
+    "###);
+
+    Ok(())
+}
+
+/// Read the license of a package without a wheel from its source distribution.
+#[test]
+fn licenses_source_dist() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = ["bar"]
+
+        [tool.uv.sources]
+        bar = { path = "bar" }
+        "#
+    })?;
+
+    let bar = context.temp_dir.child("bar");
+    bar.child("pyproject.toml").write_str(indoc! { r#"
+        [project]
+        name = "bar"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        license = { text = "BSD-3-Clause" }
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+        "#
+    })?;
+    bar.child("src").child("bar").child("__init__.py").touch()?;
+
+    uv_snapshot!(context.filters(), context.licenses(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    BSD-3-Clause (1 package)
+      bar 0.1.0
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###);
+
+    Ok(())
+}
+
+/// Fail if the metadata of a package can't be read while enforcing a license policy, rather than
+/// treating its license as unknown.
+#[test]
+fn licenses_policy_unreadable() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+        "#
+    })?;
+
+    let policy = context.temp_dir.child("licenses.toml");
+    policy.write_str(indoc! { r#"
+        deny = ["GPL-3.0-only"]
+        "#
+    })?;
+
+    context.lock().assert().success();
+    context.clean().assert().success();
+
+    // Without a policy, the license is reported as unknown.
+    uv_snapshot!(context.filters(), context.licenses().arg("--frozen").arg("--offline"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Unknown (1 package)
+      iniconfig 2.0.0
+
+    ----- stderr -----
+    warning: Failed to read the metadata for `iniconfig-2.0.0-py3-none-any.whl`: Network connectivity is disabled, but the requested data wasn't found in the cache for: `https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl`
+    "###);
+
+    // With a policy, the failure is fatal, even though no license is denied.
+    uv_snapshot!(context.filters(), context.licenses().arg("--frozen").arg("--offline").arg("--policy").arg("licenses.toml"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to read the metadata for `iniconfig-2.0.0-py3-none-any.whl`
+      Caused by: Network connectivity is disabled, but the requested data wasn't found in the cache for: `https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl`
+    "###);
+
+    Ok(())
+}
@@ -5544,6 +5544,7 @@ fn lock_invalid_hash() -> Result<()> {
     ----- stderr -----
     Resolved 4 packages in [TIME]
     error: The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+      Caused by: The requirements of `project` changed (added: `anyio==3.7.0`)
     "###);
 
     // Install from the lockfile.
@@ -6987,6 +6988,63 @@ fn lock_env_credentials() -> Result<()> {
     Ok(())
 }
 
+/// Pass credentials for an index as references to secrets, resolved at request time.
+#[test]
+fn lock_secret_credentials() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let token = context.temp_dir.child("token");
+    token.write_str("heron\n")?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! {r#"
+        [project]
+        name = "foo"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [[tool.uv.index]]
+        name = "internal-proxy"
+        url = "https://pypi-proxy.fly.dev/basic-auth/simple"
+        default = true
+        username = "env:PROXY_USERNAME"
+        password = 'file:{}'
+        "#,
+        token.path().simplified_display()
+    })?;
+
+    // `file:` references aren't accepted from project configuration by default.
+    uv_snapshot!(context.filters(), context.lock()
+        .env("PROXY_USERNAME", "public"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `pyproject.toml` references a secret via `file:[TEMP_DIR]/token`, but `file:` and `cmd:` references are only allowed in user- or system-level configuration; move the reference there, use an `env:` reference instead, or set `UV_TRUST_PROJECT_SECRETS=1` to trust the project
+    "###);
+
+    // Once the project is trusted, the username is read from the environment, and the password
+    // from the file.
+    uv_snapshot!(context.filters(), context.lock()
+        .env("PROXY_USERNAME", "public")
+        .env(EnvVars::UV_TRUST_PROJECT_SECRETS, "1"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###);
+
+    // The lockfile should omit the credentials.
+    let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock"))?;
+    assert!(!lock.contains("heron"));
+
+    Ok(())
+}
+
 /// Resolve against an index that uses relative links.
 #[test]
 fn lock_relative_index() -> Result<()> {
@@ -8770,6 +8828,166 @@ fn lock_sources_source_tree() -> Result<()> {
     Ok(())
 }
 
+/// Lock a workspace with a path dependency that lives outside the workspace root (e.g., a shared
+/// library in a sibling repository).
+#[test]
+fn lock_path_outside_workspace() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Create a workspace in a subdirectory.
+    let workspace = context.temp_dir.child("workspace");
+    workspace.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "workspace"
+        version = "0.1.0"
+        dependencies = ["shared"]
+        requires-python = ">=3.12"
+
+        [tool.uv.sources]
+        shared = { path = "../shared" }
+
+        [tool.uv.workspace]
+        members = ["packages/*"]
+    "#})?;
+
+    // Create a member that references the same library, relative to the member directory.
+    let leaf = workspace.child("packages").child("leaf");
+    leaf.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "leaf"
+        version = "0.1.0"
+        dependencies = ["shared"]
+
+        [tool.uv.sources]
+        shared = { path = "../../../shared" }
+    "#})?;
+
+    // Create a library in a sibling directory of the workspace.
+    let shared = context.temp_dir.child("shared");
+    shared.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "shared"
+        version = "0.1.0"
+        dependencies = []
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+    "#})?;
+    shared.child("src/__init__.py").touch()?;
+
+    uv_snapshot!(context.filters(), context.lock().current_dir(&workspace), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###);
+
+    let lock = fs_err::read_to_string(workspace.join("uv.lock"))?;
+
+    // Both members should record the library relative to the workspace root.
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r###"
+        version = 1
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [manifest]
+        members = [
+            "leaf",
+            "workspace",
+        ]
+
+        [[package]]
+        name = "leaf"
+        version = "0.1.0"
+        source = { virtual = "packages/leaf" }
+        dependencies = [
+            { name = "shared" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "shared", directory = "../shared" }]
+
+        [[package]]
+        name = "shared"
+        version = "0.1.0"
+        source = { directory = "../shared" }
+
+        [[package]]
+        name = "workspace"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "shared" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "shared", directory = "../shared" }]
+        "###
+        );
+    });
+
+    // Re-run with `--locked`.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked").current_dir(&workspace), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###);
+
+    // Install from the lockfile.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen").current_dir(&workspace), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + shared==0.1.0 (from file://[TEMP_DIR]/shared)
+    "###);
+
+    // Modify the library; syncing should detect the change, re-lock, and reinstall it.
+    shared.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "shared"
+        version = "0.1.0"
+        dependencies = ["iniconfig"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.sync().current_dir(&workspace), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     ~ shared==0.1.0 (from file://[TEMP_DIR]/shared)
+    "###);
+
+    Ok(())
+}
+
 /// Lock a project in which a given dependency is requested from two different members, once as
 /// editable, and once as non-editable.
 #[test]
@@ -9858,6 +10076,7 @@ fn lock_remove_member() -> Result<()> {
     ----- stderr -----
     Resolved 1 package in [TIME]
     error: The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+      Caused by: The workspace members changed (removed: `leaf`, `project`)
     "###);
 
     // Re-run without `--locked`.
@@ -10009,6 +10228,7 @@ fn lock_add_member() -> Result<()> {
     ----- stderr -----
     Resolved 5 packages in [TIME]
     error: The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+      Caused by: The workspace members changed (added: `leaf`, `project`)
     "###);
 
     // Re-run with `--offline`. This should also fail, during the resolve phase.
@@ -10252,6 +10472,7 @@ fn lock_redundant_add_member() -> Result<()> {
     ----- stderr -----
     Resolved 4 packages in [TIME]
     error: The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+      Caused by: The requirements of `project` changed (added: `idna`)
     "###);
 
     // Re-run without `--locked`.
@@ -10456,6 +10677,7 @@ fn lock_new_constraints() -> Result<()> {
     ----- stderr -----
     Resolved 4 packages in [TIME]
     error: The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+      Caused by: The workspace constraints changed (added: `anyio<4.3`)
     "###);
 
     // Re-run without `--locked`.
@@ -10668,6 +10890,7 @@ fn lock_remove_member_non_project() -> Result<()> {
     warning: No `requires-python` value found in the workspace. Defaulting to `>=3.12`.
     Resolved in [TIME]
     error: The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+      Caused by: The workspace members changed (removed: `leaf`)
     "###);
 
     // Re-run without `--locked`.
@@ -10805,6 +11028,7 @@ fn lock_rename_project() -> Result<()> {
     ----- stderr -----
     Resolved 2 packages in [TIME]
     error: The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+      Caused by: The workspace member `renamed` is missing from the lockfile
     "###);
 
     // Re-run without `--locked`.
@@ -11830,6 +12054,7 @@ fn lock_constrained_environment() -> Result<()> {
     ----- stderr -----
     Resolved 8 packages in [TIME]
     error: The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+      Caused by: The supported environments changed from `platform_system != 'Windows'` to all environments
     "###);
 
     uv_snapshot!(context.filters(), context.lock(), @r###"
@@ -13284,6 +13509,45 @@ fn lock_named_index() -> Result<()> {
     Ok(())
 }
 
+/// An invalid CA certificate bundle for an index should be ignored, with a warning.
+#[test]
+fn lock_index_invalid_ca_cert() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("internal-ca.pem")
+        .write_str("not a certificate")?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["typing-extensions"]
+
+        [[tool.uv.index]]
+        name = "heron"
+        url = "https://pypi-proxy.fly.dev/simple"
+        ca-cert = "internal-ca.pem"
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Ignoring invalid CA certificate bundle `internal-ca.pem`: No certificates found
+    Resolved 2 packages in [TIME]
+    "###);
+
+    Ok(())
+}
+
 #[test]
 fn lock_default_index() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -15142,7 +15406,7 @@ fn lock_dependency_metadata() -> Result<()> {
 }
 
 #[test]
-fn lock_dependency_metadata_git() -> Result<()> {
+fn lock_dependency_overrides() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
@@ -15152,19 +15416,17 @@ fn lock_dependency_metadata_git() -> Result<()> {
         name = "project"
         version = "0.1.0"
         requires-python = ">=3.12"
-        dependencies = ["anyio"]
-
-        [tool.uv.sources]
-        anyio = { git = "https://github.com/agronholm/anyio", tag = "4.6.2" }
+        dependencies = ["anyio==3.7.0"]
 
         [build-system]
         requires = ["setuptools>=42"]
         build-backend = "setuptools.build_meta"
 
-        [[tool.uv.dependency-metadata]]
+        [[tool.uv.dependency-overrides]]
         name = "anyio"
-        version = "4.6.0.post2"
-        requires-dist = ["iniconfig"]
+        version = "3.7.0"
+        remove = ["sniffio"]
+        replace = ["iniconfig"]
         "#,
     )?;
 
@@ -15174,7 +15436,7 @@ fn lock_dependency_metadata_git() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 3 packages in [TIME]
+    Resolved 4 packages in [TIME]
     "###);
 
     let lock = context.read("uv.lock");
@@ -15192,18 +15454,33 @@ fn lock_dependency_metadata_git() -> Result<()> {
 
         [manifest]
 
-        [[manifest.dependency-metadata]]
+        [[manifest.dependency-overrides]]
         name = "anyio"
-        version = "4.6.0.post2"
-        requires-dist = ["iniconfig"]
+        version = "3.7.0"
+        remove = ["sniffio"]
+        replace = ["iniconfig"]
 
         [[package]]
         name = "anyio"
-        version = "4.6.0.post2"
-        source = { git = "https://github.com/agronholm/anyio?tag=4.6.2#c4844254e6db0cb804c240ba07405db73d810e0b" }
+        version = "3.7.0"
+        source = { registry = "https://pypi.org/simple" }
         dependencies = [
+            { name = "idna" },
             { name = "iniconfig" },
         ]
+        sdist = { url = "https://files.pythonhosted.org/packages/c6/b3/fefbf7e78ab3b805dec67d698dc18dd505af7a18a8dd08868c9b4fa736b5/anyio-3.7.0.tar.gz", hash = "sha256:275d9973793619a5374e1c89a4f4ad3f4b0a5510a2b5b939444bee8f4c4d37ce", size = 142737 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/68/fe/7ce1926952c8a403b35029e194555558514b365ad77d75125f521a2bec62/anyio-3.7.0-py3-none-any.whl", hash = "sha256:eddca883c4175f14df8aedce21054bfca3adb70ffe76a9f607aef9d7fa2ea7f0", size = 80873 },
+        ]
+
+        [[package]]
+        name = "idna"
+        version = "3.6"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/bf/3f/ea4b9117521a1e9c50344b909be7886dd00a519552724809bb1f486986c2/idna-3.6.tar.gz", hash = "sha256:9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca", size = 175426 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/c2/e7/a82b05cf63a603df6e68d59ae6a68bf5064484a0718ea5033660af4b54a9/idna-3.6-py3-none-any.whl", hash = "sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f", size = 61567 },
+        ]
 
         [[package]]
         name = "iniconfig"
@@ -15223,7 +15500,7 @@ fn lock_dependency_metadata_git() -> Result<()> {
         ]
 
         [package.metadata]
-        requires-dist = [{ name = "anyio", git = "https://github.com/agronholm/anyio?tag=4.6.2" }]
+        requires-dist = [{ name = "anyio", specifier = "==3.7.0" }]
         "###
         );
     });
@@ -15235,18 +15512,145 @@ fn lock_dependency_metadata_git() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 3 packages in [TIME]
+    Resolved 4 packages in [TIME]
     "###);
 
-    // Re-run with `--offline`. We shouldn't need a network connection to validate an
-    // already-correct lockfile with immutable metadata.
-    uv_snapshot!(context.filters(), context.lock().arg("--locked").arg("--offline").arg("--no-cache"), @r###"
-    success: true
-    exit_code: 0
-    ----- stdout -----
+    // Remove the override.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
 
-    ----- stderr -----
-    Resolved 3 packages in [TIME]
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#,
+    )?;
+
+    // The lockfile should update.
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Removed iniconfig v2.0.0
+    Added sniffio v1.3.1
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn lock_dependency_metadata_git() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio"]
+
+        [tool.uv.sources]
+        anyio = { git = "https://github.com/agronholm/anyio", tag = "4.6.2" }
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+
+        [[tool.uv.dependency-metadata]]
+        name = "anyio"
+        version = "4.6.0.post2"
+        requires-dist = ["iniconfig"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###);
+
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r###"
+        version = 1
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [manifest]
+
+        [[manifest.dependency-metadata]]
+        name = "anyio"
+        version = "4.6.0.post2"
+        requires-dist = ["iniconfig"]
+
+        [[package]]
+        name = "anyio"
+        version = "4.6.0.post2"
+        source = { git = "https://github.com/agronholm/anyio?tag=4.6.2#c4844254e6db0cb804c240ba07405db73d810e0b" }
+        dependencies = [
+            { name = "iniconfig" },
+        ]
+
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374", size = 5892 },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { editable = "." }
+        dependencies = [
+            { name = "anyio" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "anyio", git = "https://github.com/agronholm/anyio?tag=4.6.2" }]
+        "###
+        );
+    });
+
+    // Re-run with `--locked`.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###);
+
+    // Re-run with `--offline`. We shouldn't need a network connection to validate an
+    // already-correct lockfile with immutable metadata.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked").arg("--offline").arg("--no-cache"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
     "###);
 
     // Install from the lockfile.
@@ -15543,7 +15947,7 @@ fn lock_unsupported_version() -> Result<()> {
     // Validate schema, invalid version.
     context.temp_dir.child("uv.lock").write_str(
         r#"
-        version = 2
+        version = 3
         requires-python = ">=3.12"
 
         [options]
@@ -15577,13 +15981,13 @@ fn lock_unsupported_version() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    error: The lockfile at `uv.lock` uses an unsupported schema version (v2, but only v1 is supported). Downgrade to a compatible uv version, or remove the `uv.lock` prior to running `uv lock` or `uv sync`.
+    error: The lockfile at `uv.lock` uses an unsupported schema version (v3, but only up to v2 is supported). Downgrade to a compatible uv version, or remove the `uv.lock` prior to running `uv lock` or `uv sync`.
     "###);
 
     // Invalid schema (`iniconfig` is referenced, but missing), invalid version.
     context.temp_dir.child("uv.lock").write_str(
         r#"
-        version = 2
+        version = 3
         requires-python = ">=3.12"
 
         [options]
@@ -15608,7 +16012,7 @@ fn lock_unsupported_version() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    error: Failed to parse `uv.lock`, which uses an unsupported schema version (v2, but only v1 is supported). Downgrade to a compatible uv version, or remove the `uv.lock` prior to running `uv lock` or `uv sync`.
+    error: Failed to parse `uv.lock`, which uses an unsupported schema version (v3, but only up to v2 is supported). Downgrade to a compatible uv version, or remove the `uv.lock` prior to running `uv lock` or `uv sync`.
       Caused by: Dependency `iniconfig` has missing `version` field but has more than one matching package
     "###);
 
@@ -16922,6 +17326,153 @@ fn lock_dry_run_noop() -> Result<()> {
     Ok(())
 }
 
+/// Preview the lockfile changes as JSON with `--dry-run --output-format json`.
+#[test]
+fn lock_dry_run_json() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "###);
+
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.1", "iniconfig"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--dry-run").arg("--output-format").arg("json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "dry_run": true,
+      "changes": [
+        {
+          "name": "anyio",
+          "action": "update",
+          "previous": [
+            "3.7.0"
+          ],
+          "current": [
+            "3.7.1"
+          ]
+        },
+        {
+          "name": "iniconfig",
+          "action": "add",
+          "previous": [],
+          "current": [
+            "2.0.0"
+          ]
+        }
+      ]
+    }
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    "###);
+
+    // The lockfile should be unchanged.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    error: The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+      Caused by: The requirements of `project` changed (added: `anyio==3.7.1`, `iniconfig`; removed: `anyio==3.7.0`)
+    "###);
+
+    Ok(())
+}
+
+/// Report the reason that the lockfile is out-of-date when `--locked` is provided.
+#[test]
+fn lock_locked_staleness() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    // Change the Python requirement.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12.1"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    error: The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+      Caused by: The Python requirement changed from `>=3.12` to `>=3.12.1`
+    "###);
+
+    // Change the version of the project.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.2.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    error: The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+      Caused by: The version of `project` changed from `0.1.0` to `0.2.0`
+    "###);
+
+    Ok(())
+}
+
 #[test]
 fn lock_group_include() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -17105,8 +17656,9 @@ fn lock_group_include() -> Result<()> {
     Ok(())
 }
 
+/// Include a dependency group defined by another workspace member.
 #[test]
-fn lock_group_include_cycle() -> Result<()> {
+fn lock_group_include_from_member() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
@@ -17116,43 +17668,111 @@ fn lock_group_include_cycle() -> Result<()> {
         name = "project"
         version = "0.1.0"
         requires-python = ">=3.12"
-        dependencies = ["typing-extensions"]
+        dependencies = []
 
         [dependency-groups]
-        foo = ["anyio", {include-group = "bar"}]
-        bar = [{include-group = "foobar"}]
-        foobar = [{include-group = "foo"}]
+        lint = ["iniconfig"]
+
+        [tool.uv.workspace]
+        members = ["child"]
         "#,
     )?;
 
-    uv_snapshot!(context.filters(), context.lock(), @r###"
-    success: false
-    exit_code: 2
+    let child = context.temp_dir.child("child");
+    child.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [dependency-groups]
+        dev = [{include-group = "lint", from = "project"}]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    error: Failed to build: `project @ file://[TEMP_DIR]/`
-      Caused by: Detected a cycle in `dependency-groups`: `bar` -> `foobar` -> `foo` -> `bar`
+    Resolved 3 packages in [TIME]
     "###);
 
-    Ok(())
-}
+    let lock = context.read("uv.lock");
 
-#[test]
-fn lock_group_include_missing() -> Result<()> {
-    let context = TestContext::new("3.12");
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r###"
+        version = 1
+        requires-python = ">=3.12"
 
-    let pyproject_toml = context.temp_dir.child("pyproject.toml");
-    pyproject_toml.write_str(
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [manifest]
+        members = [
+            "child",
+            "project",
+        ]
+
+        [[package]]
+        name = "child"
+        version = "0.1.0"
+        source = { virtual = "child" }
+
+        [package.dev-dependencies]
+        dev = [
+            { name = "iniconfig" },
+        ]
+
+        [package.metadata]
+
+        [package.metadata.requires-dev]
+        dev = [{ name = "iniconfig" }]
+
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374", size = 5892 },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+
+        [package.dev-dependencies]
+        lint = [
+            { name = "iniconfig" },
+        ]
+
+        [package.metadata]
+
+        [package.metadata.requires-dev]
+        lint = [{ name = "iniconfig" }]
+        "###
+        );
+    });
+
+    // Including a group from a missing member should fail.
+    child.child("pyproject.toml").write_str(
         r#"
         [project]
-        name = "project"
+        name = "child"
         version = "0.1.0"
         requires-python = ">=3.12"
-        dependencies = ["typing-extensions"]
+        dependencies = []
 
         [dependency-groups]
-        foo = ["anyio", {include-group = "bar"}]
+        dev = [{include-group = "lint", from = "missing"}]
         "#,
     )?;
 
@@ -17162,15 +17782,16 @@ fn lock_group_include_missing() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    error: Failed to build: `project @ file://[TEMP_DIR]/`
-      Caused by: Failed to find group `bar` included by `foo`
+    error: Failed to build: `child @ file://[TEMP_DIR]/child`
+      Caused by: Failed to find workspace member `missing` referenced by group `dev`
     "###);
 
     Ok(())
 }
 
+/// Restrict a dependency group to the environments declared in `tool.uv.dependency-groups`.
 #[test]
-fn lock_group_invalid_entry_package() -> Result<()> {
+fn lock_group_requires_python() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
@@ -17179,45 +17800,82 @@ fn lock_group_invalid_entry_package() -> Result<()> {
         [project]
         name = "project"
         version = "0.1.0"
-        requires-python = ">=3.12"
-        dependencies = ["typing-extensions"]
+        requires-python = ">=3.8"
+        dependencies = []
 
         [dependency-groups]
-        foo = ["invalid!"]
+        profiling = ["iniconfig"]
+
+        [tool.uv.dependency-groups]
+        profiling = { requires-python = ">=3.12", marker = "sys_platform == 'linux'" }
         "#,
     )?;
 
     uv_snapshot!(context.filters(), context.lock(), @r###"
-    success: false
-    exit_code: 2
+    success: true
+    exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    error: Failed to build: `project @ file://[TEMP_DIR]/`
-      Caused by: Failed to parse entry in group `foo`: `invalid!`
-      Caused by: no such comparison operator "!", must be one of ~= == != <= >= < > ===
-    invalid!
-           ^
+    Resolved 2 packages in [TIME]
     "###);
 
-    uv_snapshot!(context.filters(), context.sync().arg("--group").arg("foo"), @r###"
-    success: false
-    exit_code: 2
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r###"
+        version = 1
+        requires-python = ">=3.8"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374", size = 5892 },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+
+        [package.dev-dependencies]
+        profiling = [
+            { name = "iniconfig", marker = "python_full_version >= '3.12' and sys_platform == 'linux'" },
+        ]
+
+        [package.metadata]
+
+        [package.metadata.requires-dev]
+        profiling = [{ name = "iniconfig", marker = "python_full_version >= '3.12' and sys_platform == 'linux'" }]
+        "###
+        );
+    });
+
+    // Re-run with `--locked`.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r###"
+    success: true
+    exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    error: Failed to build: `project @ file://[TEMP_DIR]/`
-      Caused by: Failed to parse entry in group `foo`: `invalid!`
-      Caused by: no such comparison operator "!", must be one of ~= == != <= >= < > ===
-    invalid!
-           ^
+    Resolved 2 packages in [TIME]
     "###);
 
     Ok(())
 }
 
+/// Warn when two extras declare incompatible requirements, since they're resolved together.
 #[test]
-fn lock_group_invalid_entry_group_name() -> Result<()> {
+fn lock_conflicting_extras() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
@@ -17227,71 +17885,59 @@ fn lock_group_invalid_entry_group_name() -> Result<()> {
         name = "project"
         version = "0.1.0"
         requires-python = ">=3.12"
-        dependencies = ["typing-extensions"]
+        dependencies = []
 
-        [dependency-groups]
-        foo = [{include-group = "invalid!"}]
+        [project.optional-dependencies]
+        project1 = ["sortedcontainers==2.3.0"]
+        project2 = ["sortedcontainers==2.4.0"]
         "#,
     )?;
 
     uv_snapshot!(context.filters(), context.lock(), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
-    error: Failed to parse: `pyproject.toml`
-      Caused by: TOML parse error at line 9, column 16
-      |
-    9 |         foo = [{include-group = "invalid!"}]
-      |                ^^^^^^^^^^^^^^^^^^^^^^^^^^^^
-    Not a valid package or extra name: "invalid!". Names must start and end with a letter or digit and may only contain -, _, ., and alphanumeric characters.
-
+    warning: `project` declares incompatible requirements on `sortedcontainers` in its extra `project1` (`sortedcontainers==2.3.0`) and extra `project2` (`sortedcontainers==2.4.0`). All extras and dependency groups are resolved together, so the resolution will fail unless the requirements are limited to disjoint environments (e.g., with markers).
+      × No solution found when resolving dependencies:
+      ╰─▶ Because project[project2] depends on sortedcontainers==2.4.0 and project[project1] depends on sortedcontainers==2.3.0, we can conclude that project[project1] and project[project2] are incompatible.
+          And because your project requires project[project1] and project[project2], we can conclude that your project's requirements are unsatisfiable.
     "###);
 
-    Ok(())
-}
-
-#[test]
-fn lock_group_invalid_duplicate_group_name() -> Result<()> {
-    let context = TestContext::new("3.12");
-
-    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    // If the requirements are limited to disjoint environments, no warning is shown.
     pyproject_toml.write_str(
         r#"
         [project]
         name = "project"
         version = "0.1.0"
         requires-python = ">=3.12"
-        dependencies = ["typing-extensions"]
+        dependencies = []
 
-        [dependency-groups]
-        foo-bar = []
-        foo_bar = []
+        [project.optional-dependencies]
+        project1 = ["sortedcontainers==2.3.0 ; sys_platform == 'linux'"]
+        project2 = ["sortedcontainers==2.4.0 ; sys_platform != 'linux'"]
         "#,
     )?;
 
     uv_snapshot!(context.filters(), context.lock(), @r###"
-    success: false
-    exit_code: 2
+    success: true
+    exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    error: Failed to parse: `pyproject.toml`
-      Caused by: TOML parse error at line 8, column 9
-      |
-    8 |         [dependency-groups]
-      |         ^^^^^^^^^^^^^^^^^^^
-    duplicate dependency group: `foo-bar`
+    Resolved 3 packages in [TIME]
     "###);
 
     Ok(())
 }
 
+/// Respect the conflicts declared in `tool.uv.conflicts`.
 #[test]
-fn lock_group_invalid_entry_table() -> Result<()> {
+fn lock_declared_conflicting_extras() -> Result<()> {
     let context = TestContext::new("3.12");
 
+    // Declared conflicts are not reported before locking.
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
     pyproject_toml.write_str(
         r#"
@@ -17299,63 +17945,74 @@ fn lock_group_invalid_entry_table() -> Result<()> {
         name = "project"
         version = "0.1.0"
         requires-python = ">=3.12"
-        dependencies = ["typing-extensions"]
+        dependencies = []
 
-        [dependency-groups]
-        foo = [{bar = "unknown"}]
+        [project.optional-dependencies]
+        project1 = ["sortedcontainers==2.3.0"]
+        project2 = ["sortedcontainers==2.4.0"]
+
+        [tool.uv]
+        conflicts = [[{ extra = "project1" }, { extra = "project2" }]]
         "#,
     )?;
 
     uv_snapshot!(context.filters(), context.lock(), @r###"
-    success: true
-    exit_code: 0
+    success: false
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 2 packages in [TIME]
+      × No solution found when resolving dependencies:
+      ╰─▶ Because project[project2] depends on sortedcontainers==2.4.0 and project[project1] depends on sortedcontainers==2.3.0, we can conclude that project[project1] and project[project2] are incompatible.
+          And because your project requires project[project1] and project[project2], we can conclude that your project's requirements are unsatisfiable.
     "###);
 
-    Ok(())
-}
-
-#[test]
-fn lock_group_invalid_entry_type() -> Result<()> {
-    let context = TestContext::new("3.12");
-
-    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    // Conflicting extras can't be installed together.
     pyproject_toml.write_str(
         r#"
         [project]
         name = "project"
         version = "0.1.0"
         requires-python = ">=3.12"
-        dependencies = ["typing-extensions"]
+        dependencies = []
 
-        [dependency-groups]
-        foo = [{include-group = true}]
+        [project.optional-dependencies]
+        project1 = ["sortedcontainers==2.3.0 ; python_version < '3.13'"]
+        project2 = ["sortedcontainers==2.4.0 ; python_version >= '3.13'"]
+
+        [tool.uv]
+        conflicts = [[{ extra = "project1" }, { extra = "project2" }]]
         "#,
     )?;
 
-    uv_snapshot!(context.filters(), context.lock(), @r###"
+    uv_snapshot!(context.filters(), context.sync().arg("--extra").arg("project1").arg("--extra").arg("project2"), @r###"
     success: false
     exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
-    error: Failed to parse: `pyproject.toml`
-      Caused by: TOML parse error at line 9, column 33
-      |
-    9 |         foo = [{include-group = true}]
-      |                                 ^^^^
-    invalid type: boolean `true`, expected a string
+    Resolved 3 packages in [TIME]
+    error: `project` declares its extra `project1` and extra `project2` as conflicting (in `tool.uv.conflicts`), so they can't be installed together
+    "###);
+
+    uv_snapshot!(context.filters(), context.sync().arg("--extra").arg("project1"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
 
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + sortedcontainers==2.3.0
     "###);
 
     Ok(())
 }
 
+/// Migrate the lockfile to the diffable format, and retain the format when re-locking.
 #[test]
-fn lock_group_empty_entry_table() -> Result<()> {
+fn lock_migrate_format() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
@@ -17365,87 +18022,18 @@ fn lock_group_empty_entry_table() -> Result<()> {
         name = "project"
         version = "0.1.0"
         requires-python = ">=3.12"
-        dependencies = ["typing-extensions"]
-
-        [dependency-groups]
-        foo = [{}]
+        dependencies = ["iniconfig==2.0.0"]
         "#,
     )?;
 
-    uv_snapshot!(context.filters(), context.lock(), @r###"
-    success: false
-    exit_code: 2
+    uv_snapshot!(context.filters(), context.lock().arg("--migrate-format").arg("diffable"), @r###"
+    success: true
+    exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    error: Failed to parse: `pyproject.toml`
-      Caused by: TOML parse error at line 9, column 16
-      |
-    9 |         foo = [{}]
-      |                ^^
-    missing field `include-group`
-
-    "###);
-
-    Ok(())
-}
-
-#[test]
-fn lock_group_workspace() -> Result<()> {
-    let context = TestContext::new("3.12");
-
-    let pyproject_toml = context.temp_dir.child("pyproject.toml");
-    pyproject_toml.write_str(
-        r#"
-        [project]
-        name = "project"
-        version = "0.1.0"
-        requires-python = ">=3.12"
-        dependencies = ["child"]
-
-        [dependency-groups]
-        types = ["sniffio>1"]
-        async = ["anyio>3"]
-
-        [build-system]
-        requires = ["setuptools>=42"]
-        build-backend = "setuptools.build_meta"
-
-        [tool.uv.workspace]
-        members = ["child"]
-
-        [tool.uv.sources]
-        child = { workspace = true }
-        "#,
-    )?;
-
-    // Add a workspace member.
-    let child = context.temp_dir.child("child");
-    child.child("pyproject.toml").write_str(
-        r#"
-        [project]
-        name = "child"
-        version = "0.1.0"
-        requires-python = ">=3.12"
-        dependencies = ["iniconfig>1"]
-
-        [dependency-groups]
-        types = ["typing-extensions>4"]
-        testing = ["pytest>8"]
-
-        [build-system]
-        requires = ["setuptools>=42"]
-        build-backend = "setuptools.build_meta"
-        "#,
-    )?;
-
-    uv_snapshot!(context.filters(), context.lock(), @r###"
-    success: true
-    exit_code: 0
-    ----- stdout -----
-
-    ----- stderr -----
-    Resolved 11 packages in [TIME]
+    Resolved 2 packages in [TIME]
+    Migrated lockfile to the `diffable` format
     "###);
 
     let lock = context.read("uv.lock");
@@ -17455,298 +18043,1557 @@ fn lock_group_workspace() -> Result<()> {
     }, {
         assert_snapshot!(
             lock, @r###"
-        version = 1
+        version = 2
+        format = "diffable"
         requires-python = ">=3.12"
 
         [options]
         exclude-newer = "2024-03-25T00:00:00Z"
 
-        [manifest]
-        members = [
-            "child",
-            "project",
-        ]
-
         [[package]]
-        name = "anyio"
-        version = "4.3.0"
+        name = "iniconfig"
+        version = "2.0.0"
         source = { registry = "https://pypi.org/simple" }
-        dependencies = [
-            { name = "idna" },
-            { name = "sniffio" },
-        ]
-        sdist = { url = "https://files.pythonhosted.org/packages/db/4d/3970183622f0330d3c23d9b8a5f52e365e50381fd484d08e3285104333d3/anyio-4.3.0.tar.gz", hash = "sha256:f75253795a87df48568485fd18cdd2a3fa5c4f7c5be8e5e36637733fce06fed6", size = 159642 }
+        sdist = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646 }
         wheels = [
-            { url = "https://files.pythonhosted.org/packages/14/fd/2f20c40b45e4fb4324834aea24bd4afdf1143390242c0b33774da0e2e34f/anyio-4.3.0-py3-none-any.whl", hash = "sha256:048e05d0f6caeed70d731f3db756d35dcc1f35747c8c403364a8332c630441b8", size = 85584 },
+            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374", size = 5892 },
         ]
 
         [[package]]
-        name = "child"
+        name = "project"
         version = "0.1.0"
-        source = { editable = "child" }
+        source = { virtual = "." }
         dependencies = [
             { name = "iniconfig" },
         ]
 
-        [package.dev-dependencies]
-        testing = [
-            { name = "pytest" },
-        ]
-        types = [
-            { name = "typing-extensions" },
-        ]
-
         [package.metadata]
-        requires-dist = [{ name = "iniconfig", specifier = ">1" }]
-
-        [package.metadata.requires-dev]
-        testing = [{ name = "pytest", specifier = ">8" }]
-        types = [{ name = "typing-extensions", specifier = ">4" }]
-
-        [[package]]
-        name = "colorama"
-        version = "0.4.6"
-        source = { registry = "https://pypi.org/simple" }
-        sdist = { url = "https://files.pythonhosted.org/packages/d8/53/6f443c9a4a8358a93a6792e2acffb9d9d5cb0a5cfd8802644b7b1c9a02e4/colorama-0.4.6.tar.gz", hash = "sha256:08695f5cb7ed6e0531a20572697297273c47b8cae5a63ffc6d6ed5c201be6e44", size = 27697 }
-        wheels = [
-            { url = "https://files.pythonhosted.org/packages/d1/d6/3965ed04c63042e047cb6a3e6ed1a63a35087b6a609aa3a15ed8ac56c221/colorama-0.4.6-py2.py3-none-any.whl", hash = "sha256:4f1d9991f5acc0ca119f9d443620b77f9d6b33703e51011c16baf57afb285fc6", size = 25335 },
-        ]
-
-        [[package]]
-        name = "idna"
-        version = "3.6"
-        source = { registry = "https://pypi.org/simple" }
-        sdist = { url = "https://files.pythonhosted.org/packages/bf/3f/ea4b9117521a1e9c50344b909be7886dd00a519552724809bb1f486986c2/idna-3.6.tar.gz", hash = "sha256:9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca", size = 175426 }
-        wheels = [
-            { url = "https://files.pythonhosted.org/packages/c2/e7/a82b05cf63a603df6e68d59ae6a68bf5064484a0718ea5033660af4b54a9/idna-3.6-py3-none-any.whl", hash = "sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f", size = 61567 },
-        ]
-
-        [[package]]
-        name = "iniconfig"
-        version = "2.0.0"
-        source = { registry = "https://pypi.org/simple" }
-        sdist = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646 }
-        wheels = [
-            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374", size = 5892 },
+        requires-dist = [
+            { name = "iniconfig", specifier = "==2.0.0" },
         ]
+        "###
+        );
+    });
 
-        [[package]]
-        name = "packaging"
-        version = "24.0"
-        source = { registry = "https://pypi.org/simple" }
-        sdist = { url = "https://files.pythonhosted.org/packages/ee/b5/b43a27ac7472e1818c4bafd44430e69605baefe1f34440593e0332ec8b4d/packaging-24.0.tar.gz", hash = "sha256:eb82c5e3e56209074766e6885bb04b8c38a0c015d0a30036ebe7ece34c9989e9", size = 147882 }
-        wheels = [
-            { url = "https://files.pythonhosted.org/packages/49/df/1fceb2f8900f8639e278b056416d49134fb8d84c5942ffaa01ad34782422/packaging-24.0-py3-none-any.whl", hash = "sha256:2ddfb553fdf02fb784c234c7ba6ccc288296ceabec964ad2eae3777778130bc5", size = 53488 },
-        ]
+    // Re-run with `--locked`.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
 
-        [[package]]
-        name = "pluggy"
-        version = "1.4.0"
-        source = { registry = "https://pypi.org/simple" }
-        sdist = { url = "https://files.pythonhosted.org/packages/54/c6/43f9d44d92aed815e781ca25ba8c174257e27253a94630d21be8725a2b59/pluggy-1.4.0.tar.gz", hash = "sha256:8c85c2876142a764e5b7548e7d9a0e0ddb46f5185161049a79b7e974454223be", size = 65812 }
-        wheels = [
-            { url = "https://files.pythonhosted.org/packages/a5/5b/0cc789b59e8cc1bf288b38111d002d8c5917123194d45b29dcdac64723cc/pluggy-1.4.0-py3-none-any.whl", hash = "sha256:7db9f7b503d67d1c5b95f59773ebb58a8c1c288129a88665838012cfb07b8981", size = 20120 },
-        ]
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###);
 
-        [[package]]
+    // Changing the requirements should retain the format.
+    pyproject_toml.write_str(
+        r#"
+        [project]
         name = "project"
         version = "0.1.0"
-        source = { editable = "." }
-        dependencies = [
-            { name = "child" },
-        ]
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig>=2"]
+        "#,
+    )?;
 
-        [package.dev-dependencies]
-        async = [
-            { name = "anyio" },
-        ]
-        types = [
-            { name = "sniffio" },
-        ]
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
 
-        [package.metadata]
-        requires-dist = [{ name = "child", editable = "child" }]
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###);
 
-        [package.metadata.requires-dev]
-        async = [{ name = "anyio", specifier = ">3" }]
-        types = [{ name = "sniffio", specifier = ">1" }]
+    let lock = context.read("uv.lock");
+    assert!(lock.starts_with("version = 2\nformat = \"diffable\"\n"));
 
-        [[package]]
-        name = "pytest"
-        version = "8.1.1"
-        source = { registry = "https://pypi.org/simple" }
-        dependencies = [
-            { name = "colorama", marker = "sys_platform == 'win32'" },
-            { name = "iniconfig" },
-            { name = "packaging" },
-            { name = "pluggy" },
-        ]
-        sdist = { url = "https://files.pythonhosted.org/packages/30/b7/7d44bbc04c531dcc753056920e0988032e5871ac674b5a84cb979de6e7af/pytest-8.1.1.tar.gz", hash = "sha256:ac978141a75948948817d360297b7aae0fcb9d6ff6bc9ec6d514b85d5a65c044", size = 1409703 }
-        wheels = [
-            { url = "https://files.pythonhosted.org/packages/4d/7e/c79cecfdb6aa85c6c2e3cf63afc56d0f165f24f5c66c03c695c4d9b84756/pytest-8.1.1-py3-none-any.whl", hash = "sha256:2a8386cfc11fa9d2c50ee7b2a57e7d898ef90470a7a34c4b949ff59662bb78b7", size = 337359 },
-        ]
+    // Migrate back to the default format.
+    uv_snapshot!(context.filters(), context.lock().arg("--migrate-format").arg("default"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
 
-        [[package]]
-        name = "sniffio"
-        version = "1.3.1"
-        source = { registry = "https://pypi.org/simple" }
-        sdist = { url = "https://files.pythonhosted.org/packages/a2/87/a6771e1546d97e7e041b6ae58d80074f81b7d5121207425c964ddf5cfdbd/sniffio-1.3.1.tar.gz", hash = "sha256:f4324edc670a0f49750a81b895f35c3adb843cca46f0530f79fc1babb23789dc", size = 20372 }
-        wheels = [
-            { url = "https://files.pythonhosted.org/packages/e9/44/75a9c9421471a6c4805dbf2356f7c181a29c1879239abab1ea2cc8f38b40/sniffio-1.3.1-py3-none-any.whl", hash = "sha256:2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2", size = 10235 },
-        ]
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Migrated lockfile to the `default` format
+    "###);
 
-        [[package]]
-        name = "typing-extensions"
-        version = "4.10.0"
-        source = { registry = "https://pypi.org/simple" }
-        sdist = { url = "https://files.pythonhosted.org/packages/16/3a/0d26ce356c7465a19c9ea8814b960f8a36c3b0d07c323176620b7b483e44/typing_extensions-4.10.0.tar.gz", hash = "sha256:b0abd7c89e8fb96f98db18d86106ff1d90ab692004eb746cf6eda2682f91b3cb", size = 77558 }
-        wheels = [
-            { url = "https://files.pythonhosted.org/packages/f9/de/dc04a3ea60b22624b51c703a84bbe0184abcd1d0b9bc8074b5d6b7ab90bb/typing_extensions-4.10.0-py3-none-any.whl", hash = "sha256:69b1a937c3a517342112fb4c6df7e72fc39a38e7891a5730ed4985b5214b5475", size = 33926 },
-        ]
-        "###
-        );
-    });
+    let lock = context.read("uv.lock");
+    assert!(lock.starts_with("version = 1\n"));
+    assert!(!lock.contains("format = "));
+    assert!(lock.contains(r#"requires-dist = [{ name = "iniconfig", specifier = ">=2" }]"#));
 
     Ok(())
 }
 
+/// Reject lockfiles that use an unknown format, rather than rewriting them.
 #[test]
-fn lock_transitive_git() -> Result<()> {
+fn lock_unknown_format() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
     pyproject_toml.write_str(
         r#"
         [project]
-        name = "a"
+        name = "project"
         version = "0.1.0"
         requires-python = ">=3.12"
-        dependencies = ["c"]
-
-        [tool.uv.sources]
-        c = { git = "https://github.com/astral-sh/workspace-virtual-root-test", subdirectory = "packages/c", rev = "fac39c8d4c5d0ef32744e2bb309bbe34a759fd46" }
+        dependencies = []
         "#,
     )?;
 
+    context
+        .temp_dir
+        .child("uv.lock")
+        .write_str("version = 2\nformat = \"compact\"\nrequires-python = \">=3.12\"\n")?;
+
     uv_snapshot!(context.filters(), context.lock(), @r###"
-    success: true
-    exit_code: 0
+    success: false
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 6 packages in [TIME]
+    error: Failed to parse `uv.lock`
+      Caused by: TOML parse error at line 2, column 10
+      |
+    2 | format = "compact"
+      |          ^^^^^^^^^
+    unknown variant `compact`, expected `default` or `diffable`
+
     "###);
 
-    let lock = context.read("uv.lock");
+    Ok(())
+}
 
-    insta::with_settings!({
-        filters => context.filters(),
+#[test]
+fn lock_group_include_cycle() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["typing-extensions"]
+
+        [dependency-groups]
+        foo = ["anyio", {include-group = "bar"}]
+        bar = [{include-group = "foobar"}]
+        foobar = [{include-group = "foo"}]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to build: `project @ file://[TEMP_DIR]/`
+      Caused by: Detected a cycle in `dependency-groups`: `bar` -> `foobar` -> `foo` -> `bar`
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn lock_group_include_missing() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["typing-extensions"]
+
+        [dependency-groups]
+        foo = ["anyio", {include-group = "bar"}]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to build: `project @ file://[TEMP_DIR]/`
+      Caused by: Failed to find group `bar` included by `foo`
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn lock_group_invalid_entry_package() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["typing-extensions"]
+
+        [dependency-groups]
+        foo = ["invalid!"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to build: `project @ file://[TEMP_DIR]/`
+      Caused by: Failed to parse entry in group `foo`: `invalid!`
+      Caused by: no such comparison operator "!", must be one of ~= == != <= >= < > ===
+    invalid!
+           ^
+    "###);
+
+    uv_snapshot!(context.filters(), context.sync().arg("--group").arg("foo"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to build: `project @ file://[TEMP_DIR]/`
+      Caused by: Failed to parse entry in group `foo`: `invalid!`
+      Caused by: no such comparison operator "!", must be one of ~= == != <= >= < > ===
+    invalid!
+           ^
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn lock_group_invalid_entry_group_name() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["typing-extensions"]
+
+        [dependency-groups]
+        foo = [{include-group = "invalid!"}]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to parse: `pyproject.toml`
+      Caused by: TOML parse error at line 9, column 16
+      |
+    9 |         foo = [{include-group = "invalid!"}]
+      |                ^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    Not a valid package or extra name: "invalid!". Names must start and end with a letter or digit and may only contain -, _, ., and alphanumeric characters.
+
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn lock_group_invalid_duplicate_group_name() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["typing-extensions"]
+
+        [dependency-groups]
+        foo-bar = []
+        foo_bar = []
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to parse: `pyproject.toml`
+      Caused by: TOML parse error at line 8, column 9
+      |
+    8 |         [dependency-groups]
+      |         ^^^^^^^^^^^^^^^^^^^
+    duplicate dependency group: `foo-bar`
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn lock_group_invalid_entry_table() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["typing-extensions"]
+
+        [dependency-groups]
+        foo = [{bar = "unknown"}]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn lock_group_invalid_entry_type() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["typing-extensions"]
+
+        [dependency-groups]
+        foo = [{include-group = true}]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to parse: `pyproject.toml`
+      Caused by: TOML parse error at line 9, column 33
+      |
+    9 |         foo = [{include-group = true}]
+      |                                 ^^^^
+    invalid type: boolean `true`, expected a string
+
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn lock_group_empty_entry_table() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["typing-extensions"]
+
+        [dependency-groups]
+        foo = [{}]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to parse: `pyproject.toml`
+      Caused by: TOML parse error at line 9, column 16
+      |
+    9 |         foo = [{}]
+      |                ^^
+    missing field `include-group`
+
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn lock_group_workspace() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["child"]
+
+        [dependency-groups]
+        types = ["sniffio>1"]
+        async = ["anyio>3"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+
+        [tool.uv.workspace]
+        members = ["child"]
+
+        [tool.uv.sources]
+        child = { workspace = true }
+        "#,
+    )?;
+
+    // Add a workspace member.
+    let child = context.temp_dir.child("child");
+    child.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig>1"]
+
+        [dependency-groups]
+        types = ["typing-extensions>4"]
+        testing = ["pytest>8"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 11 packages in [TIME]
+    "###);
+
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r###"
+        version = 1
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [manifest]
+        members = [
+            "child",
+            "project",
+        ]
+
+        [[package]]
+        name = "anyio"
+        version = "4.3.0"
+        source = { registry = "https://pypi.org/simple" }
+        dependencies = [
+            { name = "idna" },
+            { name = "sniffio" },
+        ]
+        sdist = { url = "https://files.pythonhosted.org/packages/db/4d/3970183622f0330d3c23d9b8a5f52e365e50381fd484d08e3285104333d3/anyio-4.3.0.tar.gz", hash = "sha256:f75253795a87df48568485fd18cdd2a3fa5c4f7c5be8e5e36637733fce06fed6", size = 159642 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/14/fd/2f20c40b45e4fb4324834aea24bd4afdf1143390242c0b33774da0e2e34f/anyio-4.3.0-py3-none-any.whl", hash = "sha256:048e05d0f6caeed70d731f3db756d35dcc1f35747c8c403364a8332c630441b8", size = 85584 },
+        ]
+
+        [[package]]
+        name = "child"
+        version = "0.1.0"
+        source = { editable = "child" }
+        dependencies = [
+            { name = "iniconfig" },
+        ]
+
+        [package.dev-dependencies]
+        testing = [
+            { name = "pytest" },
+        ]
+        types = [
+            { name = "typing-extensions" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "iniconfig", specifier = ">1" }]
+
+        [package.metadata.requires-dev]
+        testing = [{ name = "pytest", specifier = ">8" }]
+        types = [{ name = "typing-extensions", specifier = ">4" }]
+
+        [[package]]
+        name = "colorama"
+        version = "0.4.6"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/d8/53/6f443c9a4a8358a93a6792e2acffb9d9d5cb0a5cfd8802644b7b1c9a02e4/colorama-0.4.6.tar.gz", hash = "sha256:08695f5cb7ed6e0531a20572697297273c47b8cae5a63ffc6d6ed5c201be6e44", size = 27697 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/d1/d6/3965ed04c63042e047cb6a3e6ed1a63a35087b6a609aa3a15ed8ac56c221/colorama-0.4.6-py2.py3-none-any.whl", hash = "sha256:4f1d9991f5acc0ca119f9d443620b77f9d6b33703e51011c16baf57afb285fc6", size = 25335 },
+        ]
+
+        [[package]]
+        name = "idna"
+        version = "3.6"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/bf/3f/ea4b9117521a1e9c50344b909be7886dd00a519552724809bb1f486986c2/idna-3.6.tar.gz", hash = "sha256:9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca", size = 175426 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/c2/e7/a82b05cf63a603df6e68d59ae6a68bf5064484a0718ea5033660af4b54a9/idna-3.6-py3-none-any.whl", hash = "sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f", size = 61567 },
+        ]
+
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374", size = 5892 },
+        ]
+
+        [[package]]
+        name = "packaging"
+        version = "24.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/ee/b5/b43a27ac7472e1818c4bafd44430e69605baefe1f34440593e0332ec8b4d/packaging-24.0.tar.gz", hash = "sha256:eb82c5e3e56209074766e6885bb04b8c38a0c015d0a30036ebe7ece34c9989e9", size = 147882 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/49/df/1fceb2f8900f8639e278b056416d49134fb8d84c5942ffaa01ad34782422/packaging-24.0-py3-none-any.whl", hash = "sha256:2ddfb553fdf02fb784c234c7ba6ccc288296ceabec964ad2eae3777778130bc5", size = 53488 },
+        ]
+
+        [[package]]
+        name = "pluggy"
+        version = "1.4.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/54/c6/43f9d44d92aed815e781ca25ba8c174257e27253a94630d21be8725a2b59/pluggy-1.4.0.tar.gz", hash = "sha256:8c85c2876142a764e5b7548e7d9a0e0ddb46f5185161049a79b7e974454223be", size = 65812 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/a5/5b/0cc789b59e8cc1bf288b38111d002d8c5917123194d45b29dcdac64723cc/pluggy-1.4.0-py3-none-any.whl", hash = "sha256:7db9f7b503d67d1c5b95f59773ebb58a8c1c288129a88665838012cfb07b8981", size = 20120 },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { editable = "." }
+        dependencies = [
+            { name = "child" },
+        ]
+
+        [package.dev-dependencies]
+        async = [
+            { name = "anyio" },
+        ]
+        types = [
+            { name = "sniffio" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "child", editable = "child" }]
+
+        [package.metadata.requires-dev]
+        async = [{ name = "anyio", specifier = ">3" }]
+        types = [{ name = "sniffio", specifier = ">1" }]
+
+        [[package]]
+        name = "pytest"
+        version = "8.1.1"
+        source = { registry = "https://pypi.org/simple" }
+        dependencies = [
+            { name = "colorama", marker = "sys_platform == 'win32'" },
+            { name = "iniconfig" },
+            { name = "packaging" },
+            { name = "pluggy" },
+        ]
+        sdist = { url = "https://files.pythonhosted.org/packages/30/b7/7d44bbc04c531dcc753056920e0988032e5871ac674b5a84cb979de6e7af/pytest-8.1.1.tar.gz", hash = "sha256:ac978141a75948948817d360297b7aae0fcb9d6ff6bc9ec6d514b85d5a65c044", size = 1409703 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/4d/7e/c79cecfdb6aa85c6c2e3cf63afc56d0f165f24f5c66c03c695c4d9b84756/pytest-8.1.1-py3-none-any.whl", hash = "sha256:2a8386cfc11fa9d2c50ee7b2a57e7d898ef90470a7a34c4b949ff59662bb78b7", size = 337359 },
+        ]
+
+        [[package]]
+        name = "sniffio"
+        version = "1.3.1"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/a2/87/a6771e1546d97e7e041b6ae58d80074f81b7d5121207425c964ddf5cfdbd/sniffio-1.3.1.tar.gz", hash = "sha256:f4324edc670a0f49750a81b895f35c3adb843cca46f0530f79fc1babb23789dc", size = 20372 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/e9/44/75a9c9421471a6c4805dbf2356f7c181a29c1879239abab1ea2cc8f38b40/sniffio-1.3.1-py3-none-any.whl", hash = "sha256:2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2", size = 10235 },
+        ]
+
+        [[package]]
+        name = "typing-extensions"
+        version = "4.10.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/16/3a/0d26ce356c7465a19c9ea8814b960f8a36c3b0d07c323176620b7b483e44/typing_extensions-4.10.0.tar.gz", hash = "sha256:b0abd7c89e8fb96f98db18d86106ff1d90ab692004eb746cf6eda2682f91b3cb", size = 77558 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/f9/de/dc04a3ea60b22624b51c703a84bbe0184abcd1d0b9bc8074b5d6b7ab90bb/typing_extensions-4.10.0-py3-none-any.whl", hash = "sha256:69b1a937c3a517342112fb4c6df7e72fc39a38e7891a5730ed4985b5214b5475", size = 33926 },
+        ]
+        "###
+        );
+    });
+
+    Ok(())
+}
+
+#[test]
+fn lock_transitive_git() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "a"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["c"]
+
+        [tool.uv.sources]
+        c = { git = "https://github.com/astral-sh/workspace-virtual-root-test", subdirectory = "packages/c", rev = "fac39c8d4c5d0ef32744e2bb309bbe34a759fd46" }
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    "###);
+
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!({
+        filters => context.filters(),
     }, {
         assert_snapshot!(
             lock, @r###"
         version = 1
         requires-python = ">=3.12"
 
-        [options]
-        exclude-newer = "2024-03-25T00:00:00Z"
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "a"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "c" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "c", git = "https://github.com/astral-sh/workspace-virtual-root-test?subdirectory=packages%2Fc&rev=fac39c8d4c5d0ef32744e2bb309bbe34a759fd46" }]
+
+        [[package]]
+        name = "anyio"
+        version = "4.3.0"
+        source = { registry = "https://pypi.org/simple" }
+        dependencies = [
+            { name = "idna" },
+            { name = "sniffio" },
+        ]
+        sdist = { url = "https://files.pythonhosted.org/packages/db/4d/3970183622f0330d3c23d9b8a5f52e365e50381fd484d08e3285104333d3/anyio-4.3.0.tar.gz", hash = "sha256:f75253795a87df48568485fd18cdd2a3fa5c4f7c5be8e5e36637733fce06fed6", size = 159642 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/14/fd/2f20c40b45e4fb4324834aea24bd4afdf1143390242c0b33774da0e2e34f/anyio-4.3.0-py3-none-any.whl", hash = "sha256:048e05d0f6caeed70d731f3db756d35dcc1f35747c8c403364a8332c630441b8", size = 85584 },
+        ]
+
+        [[package]]
+        name = "c"
+        version = "1.0.0"
+        source = { git = "https://github.com/astral-sh/workspace-virtual-root-test?subdirectory=packages%2Fc&rev=fac39c8d4c5d0ef32744e2bb309bbe34a759fd46#fac39c8d4c5d0ef32744e2bb309bbe34a759fd46" }
+        dependencies = [
+            { name = "d" },
+        ]
+
+        [[package]]
+        name = "d"
+        version = "1.0.0"
+        source = { git = "https://github.com/astral-sh/workspace-virtual-root-test?subdirectory=packages%2Fd&rev=fac39c8d4c5d0ef32744e2bb309bbe34a759fd46#fac39c8d4c5d0ef32744e2bb309bbe34a759fd46" }
+        dependencies = [
+            { name = "anyio" },
+        ]
+
+        [[package]]
+        name = "idna"
+        version = "3.6"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/bf/3f/ea4b9117521a1e9c50344b909be7886dd00a519552724809bb1f486986c2/idna-3.6.tar.gz", hash = "sha256:9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca", size = 175426 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/c2/e7/a82b05cf63a603df6e68d59ae6a68bf5064484a0718ea5033660af4b54a9/idna-3.6-py3-none-any.whl", hash = "sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f", size = 61567 },
+        ]
+
+        [[package]]
+        name = "sniffio"
+        version = "1.3.1"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/a2/87/a6771e1546d97e7e041b6ae58d80074f81b7d5121207425c964ddf5cfdbd/sniffio-1.3.1.tar.gz", hash = "sha256:f4324edc670a0f49750a81b895f35c3adb843cca46f0530f79fc1babb23789dc", size = 20372 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/e9/44/75a9c9421471a6c4805dbf2356f7c181a29c1879239abab1ea2cc8f38b40/sniffio-1.3.1-py3-none-any.whl", hash = "sha256:2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2", size = 10235 },
+        ]
+        "###
+        );
+    });
+
+    // Re-run with `--locked`.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    "###);
+
+    // Re-run with `--offline`. We shouldn't need a network connection to validate an
+    // already-correct lockfile with immutable metadata.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked").arg("--offline").arg("--no-cache"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    "###);
+
+    // Install from the lockfile.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Prepared 5 packages in [TIME]
+    Installed 5 packages in [TIME]
+     + anyio==4.3.0
+     + c==1.0.0 (from git+https://github.com/astral-sh/workspace-virtual-root-test@fac39c8d4c5d0ef32744e2bb309bbe34a759fd46#subdirectory=packages/c)
+     + d==1.0.0 (from git+https://github.com/astral-sh/workspace-virtual-root-test@fac39c8d4c5d0ef32744e2bb309bbe34a759fd46#subdirectory=packages/d)
+     + idna==3.6
+     + sniffio==1.3.1
+    "###);
+
+    Ok(())
+}
+
+/// Audit the declared bounds against the resolved versions with `--check-bounds`.
+#[test]
+fn lock_check_bounds() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio>=2,<4", "iniconfig>=2"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--check-bounds"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Stale upper bound anyio>=2, <4 in project (resolved v3.7.1, but `<4` excludes v4.3.0)
+    Stale lower bound anyio>=2, <4 in project (resolved v3.7.1, but `>=2` admits older release series)
+    Found 2 stale bounds
+    "###);
+
+    // Removing the upper bound and raising the lower bound should satisfy the audit. An upper
+    // bound that doesn't exclude any published release is not considered stale.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio>=4", "iniconfig>=2,<3"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--check-bounds"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    No stale bounds detected
+    "###);
+
+    Ok(())
+}
+
+/// Verify that the lockfile can be installed in each supported environment with
+/// `--check-platforms`.
+#[test]
+fn lock_check_platforms() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.10"
+        dependencies = [
+            "cffi @ https://files.pythonhosted.org/packages/08/fd/cc2fedbd887223f9f5d170c96e57cbf655df9831a6546c1727ae13fa977a/cffi-1.17.1-cp310-cp310-macosx_11_0_arm64.whl",
+        ]
+
+        [tool.uv]
+        environments = ["sys_platform == 'darwin'", "sys_platform == 'linux'"]
+        "#,
+    )?;
+
+    // The macOS wheel can't be installed on Linux, and there's no source distribution.
+    uv_snapshot!(context.filters(), context.lock().arg("--check-platforms"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Unsatisfiable environment sys_platform == 'linux'
+      cffi v1.17.1 has no compatible wheel or source distribution
+    Found 1 unsatisfiable environment
+    "###);
+
+    // Restricting the supported environments to macOS should satisfy the check.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.10"
+        dependencies = [
+            "cffi @ https://files.pythonhosted.org/packages/08/fd/cc2fedbd887223f9f5d170c96e57cbf655df9831a6546c1727ae13fa977a/cffi-1.17.1-cp310-cp310-macosx_11_0_arm64.whl",
+        ]
+
+        [tool.uv]
+        environments = ["sys_platform == 'darwin'"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--check-platforms"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    All supported environments are satisfiable
+    "###);
+
+    // The wheel is specific to both the architecture and the Python version.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.10"
+        dependencies = [
+            "cffi @ https://files.pythonhosted.org/packages/08/fd/cc2fedbd887223f9f5d170c96e57cbf655df9831a6546c1727ae13fa977a/cffi-1.17.1-cp310-cp310-macosx_11_0_arm64.whl",
+        ]
+
+        [tool.uv]
+        environments = [
+            "sys_platform == 'darwin' and platform_machine == 'x86_64'",
+            "sys_platform == 'darwin' and platform_machine == 'arm64' and python_version >= '3.11'",
+        ]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--check-platforms"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Unsatisfiable environment platform_machine == 'x86_64' and sys_platform == 'darwin'
+      cffi v1.17.1 has no compatible wheel or source distribution
+    Unsatisfiable environment python_full_version >= '3.11' and platform_machine == 'arm64' and sys_platform == 'darwin'
+      cffi v1.17.1 has no compatible wheel or source distribution
+    Found 2 unsatisfiable environments
+    "###);
+
+    Ok(())
+}
+
+/// Seed the resolution with the versions pinned by a related project via `--preference-file`.
+#[test]
+fn lock_preference_file() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio"]
+        "#,
+    )?;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("anyio==3.7.0\nidna==3.6\n")?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--preference-file").arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "###);
+
+    let lock = context.read("uv.lock");
+    assert!(lock.contains("name = \"anyio\"\nversion = \"3.7.0\""));
+
+    // The existing lockfile takes precedence over the preference file.
+    requirements_txt.write_str("anyio==3.6.2\n")?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--preference-file").arg("requirements.txt").arg("--upgrade-package").arg("idna"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "###);
+
+    let lock = context.read("uv.lock");
+    assert!(lock.contains("name = \"anyio\"\nversion = \"3.7.0\""));
+
+    // A missing preference file is an error.
+    uv_snapshot!(context.filters(), context.lock().arg("--preference-file").arg("missing.txt").arg("--upgrade-package").arg("idna"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Preference file not found at: `missing.txt`
+    "###);
+
+    // The same is true for a missing lockfile.
+    uv_snapshot!(context.filters(), context.lock().arg("--preference-file").arg("missing/uv.lock").arg("--upgrade-package").arg("idna"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Preference file not found at: `missing/uv.lock`
+    "###);
+
+    Ok(())
+}
+
+/// Write the resolved dependency graph in the DOT and GraphML formats.
+#[test]
+fn lock_graph_output() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["colorama==0.4.6 ; sys_platform == 'win32'"]
+
+        [project.optional-dependencies]
+        test = ["iniconfig==2.0.0"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--graph-output").arg("-"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    digraph {
+        node [shape=ellipse];
+        n0 [label="colorama\n0.4.6"];
+        n1 [label="iniconfig\n2.0.0"];
+        n2 [label="project\n0.1.0", shape=box];
+        n2 -> n0 [label="sys_platform == 'win32'"];
+        n2 -> n1 [label="extra: test"];
+    }
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###);
+
+    uv_snapshot!(context.filters(), context.lock().arg("--graph-output").arg("graph.graphml"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Wrote dependency graph to `graph.graphml`
+    "###);
+
+    assert_snapshot!(fs_err::read_to_string(context.temp_dir.join("graph.graphml"))?, @r###"
+    <?xml version="1.0" encoding="UTF-8"?>
+    <graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+      <key id="forks" for="graph" attr.name="fork-markers" attr.type="string"/>
+      <key id="name" for="node" attr.name="name" attr.type="string"/>
+      <key id="version" for="node" attr.name="version" attr.type="string"/>
+      <key id="source" for="node" attr.name="source" attr.type="string"/>
+      <key id="member" for="node" attr.name="member" attr.type="boolean"/>
+      <key id="node-forks" for="node" attr.name="fork-markers" attr.type="string"/>
+      <key id="marker" for="edge" attr.name="marker" attr.type="string"/>
+      <key id="extra" for="edge" attr.name="extra" attr.type="string"/>
+      <key id="group" for="edge" attr.name="group" attr.type="string"/>
+      <graph id="lock" edgedefault="directed">
+        <node id="n0">
+          <data key="name">colorama</data>
+          <data key="version">0.4.6</data>
+          <data key="source">registry+https://pypi.org/simple</data>
+          <data key="member">false</data>
+        </node>
+        <node id="n1">
+          <data key="name">iniconfig</data>
+          <data key="version">2.0.0</data>
+          <data key="source">registry+https://pypi.org/simple</data>
+          <data key="member">false</data>
+        </node>
+        <node id="n2">
+          <data key="name">project</data>
+          <data key="version">0.1.0</data>
+          <data key="source">virtual+.</data>
+          <data key="member">true</data>
+        </node>
+        <edge source="n2" target="n0">
+          <data key="marker">sys_platform == 'win32'</data>
+        </edge>
+        <edge source="n2" target="n1">
+          <data key="extra">test</data>
+        </edge>
+      </graph>
+    </graphml>
+    "###);
+
+    Ok(())
+}
+
+/// With `--minimal-changes`, select the nearest version to the locked version when it no longer
+/// satisfies the requirements, rather than the latest compatible version.
+#[test]
+fn lock_minimal_changes() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==1.0.0"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    // Exclude the locked version.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig>1.0.0"]
+        "#,
+    )?;
+
+    // By default, the latest compatible version is selected.
+    uv_snapshot!(context.filters(), context.lock().arg("--dry-run"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Update iniconfig v1.0.0 -> v2.0.0
+    "###);
+
+    // With `--minimal-changes`, the nearest compatible version is selected.
+    uv_snapshot!(context.filters(), context.lock().arg("--minimal-changes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Updated iniconfig v1.0.0 -> v1.0.1
+    "###);
+
+    Ok(())
+}
+
+/// Lock a URL source with an expected hash in `tool.uv.sources`.
+#[test]
+fn lock_url_source_hash() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [tool.uv.sources]
+        iniconfig = { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374" }
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###);
+
+    // Changing the expected hash should fail, even though the lockfile is otherwise up-to-date.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [tool.uv.sources]
+        iniconfig = { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:0000000000000000000000000000000000000000000000000000000000000000" }
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Hash mismatch for `iniconfig==2.0.0 @ direct+https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl`
 
-        [[package]]
-        name = "a"
+    Expected:
+      sha256:0000000000000000000000000000000000000000000000000000000000000000
+
+    Locked:
+      sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374
+    "###);
+
+    Ok(())
+}
+
+/// Lock a URL source with an incorrect `hash` in `tool.uv.sources`, which should fail before the
+/// distribution is used.
+#[test]
+fn lock_url_source_hash_mismatch() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
         version = "0.1.0"
-        source = { virtual = "." }
-        dependencies = [
-            { name = "c" },
-        ]
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
 
-        [package.metadata]
-        requires-dist = [{ name = "c", git = "https://github.com/astral-sh/workspace-virtual-root-test?subdirectory=packages%2Fc&rev=fac39c8d4c5d0ef32744e2bb309bbe34a759fd46" }]
+        [tool.uv.sources]
+        iniconfig = { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:0000000000000000000000000000000000000000000000000000000000000000" }
+        "#,
+    )?;
 
-        [[package]]
-        name = "anyio"
-        version = "4.3.0"
-        source = { registry = "https://pypi.org/simple" }
-        dependencies = [
-            { name = "idna" },
-            { name = "sniffio" },
-        ]
-        sdist = { url = "https://files.pythonhosted.org/packages/db/4d/3970183622f0330d3c23d9b8a5f52e365e50381fd484d08e3285104333d3/anyio-4.3.0.tar.gz", hash = "sha256:f75253795a87df48568485fd18cdd2a3fa5c4f7c5be8e5e36637733fce06fed6", size = 159642 }
-        wheels = [
-            { url = "https://files.pythonhosted.org/packages/14/fd/2f20c40b45e4fb4324834aea24bd4afdf1143390242c0b33774da0e2e34f/anyio-4.3.0-py3-none-any.whl", hash = "sha256:048e05d0f6caeed70d731f3db756d35dcc1f35747c8c403364a8332c630441b8", size = 85584 },
-        ]
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
 
-        [[package]]
-        name = "c"
-        version = "1.0.0"
-        source = { git = "https://github.com/astral-sh/workspace-virtual-root-test?subdirectory=packages%2Fc&rev=fac39c8d4c5d0ef32744e2bb309bbe34a759fd46#fac39c8d4c5d0ef32744e2bb309bbe34a759fd46" }
-        dependencies = [
-            { name = "d" },
-        ]
+    ----- stderr -----
+    error: Failed to download: `iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl`
+      Caused by: Hash mismatch for `iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl`
 
-        [[package]]
-        name = "d"
-        version = "1.0.0"
-        source = { git = "https://github.com/astral-sh/workspace-virtual-root-test?subdirectory=packages%2Fd&rev=fac39c8d4c5d0ef32744e2bb309bbe34a759fd46#fac39c8d4c5d0ef32744e2bb309bbe34a759fd46" }
-        dependencies = [
-            { name = "anyio" },
-        ]
+    Expected:
+      sha256:0000000000000000000000000000000000000000000000000000000000000000
 
-        [[package]]
-        name = "idna"
-        version = "3.6"
-        source = { registry = "https://pypi.org/simple" }
-        sdist = { url = "https://files.pythonhosted.org/packages/bf/3f/ea4b9117521a1e9c50344b909be7886dd00a519552724809bb1f486986c2/idna-3.6.tar.gz", hash = "sha256:9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca", size = 175426 }
-        wheels = [
-            { url = "https://files.pythonhosted.org/packages/c2/e7/a82b05cf63a603df6e68d59ae6a68bf5064484a0718ea5033660af4b54a9/idna-3.6-py3-none-any.whl", hash = "sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f", size = 61567 },
-        ]
+    Computed:
+      sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374
+    "###);
 
-        [[package]]
-        name = "sniffio"
-        version = "1.3.1"
-        source = { registry = "https://pypi.org/simple" }
-        sdist = { url = "https://files.pythonhosted.org/packages/a2/87/a6771e1546d97e7e041b6ae58d80074f81b7d5121207425c964ddf5cfdbd/sniffio-1.3.1.tar.gz", hash = "sha256:f4324edc670a0f49750a81b895f35c3adb843cca46f0530f79fc1babb23789dc", size = 20372 }
-        wheels = [
-            { url = "https://files.pythonhosted.org/packages/e9/44/75a9c9421471a6c4805dbf2356f7c181a29c1879239abab1ea2cc8f38b40/sniffio-1.3.1-py3-none-any.whl", hash = "sha256:2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2", size = 10235 },
+    // The same hash should be enforced by `uv pip install`.
+    uv_snapshot!(context.filters(), context.pip_install().arg("."), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to download: `iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl`
+      Caused by: Hash mismatch for `iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl`
+
+    Expected:
+      sha256:0000000000000000000000000000000000000000000000000000000000000000
+
+    Computed:
+      sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374
+    "###);
+
+    Ok(())
+}
+
+/// Select among variant sources in `tool.uv.sources` via `--variant`, `UV_VARIANT`, and the
+/// `variants` setting.
+#[test]
+fn lock_source_variant() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio"]
+
+        [tool.uv.sources]
+        anyio = [
+            { url = "https://files.pythonhosted.org/packages/14/fd/2f20c40b45e4fb4324834aea24bd4afdf1143390242c0b33774da0e2e34f/anyio-4.3.0-py3-none-any.whl", variant = "wheel" },
+            { url = "https://files.pythonhosted.org/packages/db/4d/3970183622f0330d3c23d9b8a5f52e365e50381fd484d08e3285104333d3/anyio-4.3.0.tar.gz", variant = "sdist" },
         ]
-        "###
-        );
-    });
+        "#,
+    )?;
 
-    // Re-run with `--locked`.
-    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r###"
+    // By default, the first variant is used.
+    uv_snapshot!(context.filters(), context.lock(), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 6 packages in [TIME]
+    Resolved 4 packages in [TIME]
     "###);
 
-    // Re-run with `--offline`. We shouldn't need a network connection to validate an
-    // already-correct lockfile with immutable metadata.
-    uv_snapshot!(context.filters(), context.lock().arg("--locked").arg("--offline").arg("--no-cache"), @r###"
+    let lock = context.read("uv.lock");
+    assert!(lock.contains("anyio-4.3.0-py3-none-any.whl"));
+    assert!(!lock.contains("anyio-4.3.0.tar.gz"));
+
+    // Selecting the default variant explicitly leaves the lockfile unchanged.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked").env(EnvVars::UV_VARIANT, "wheel"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 6 packages in [TIME]
+    Resolved 4 packages in [TIME]
     "###);
 
-    // Install from the lockfile.
-    uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @r###"
+    // Selecting another variant requires updating the lockfile.
+    context
+        .lock()
+        .arg("--locked")
+        .arg("--variant")
+        .arg("sdist")
+        .assert()
+        .failure();
+
+    context
+        .lock()
+        .arg("--variant")
+        .arg("sdist")
+        .assert()
+        .success();
+
+    let lock = context.read("uv.lock");
+    assert!(lock.contains("anyio-4.3.0.tar.gz"));
+    assert!(!lock.contains("anyio-4.3.0-py3-none-any.whl"));
+
+    // Selecting the variant in `uv.toml` keeps the lockfile stable across runs.
+    context
+        .temp_dir
+        .child("uv.toml")
+        .write_str(r#"variants = ["sdist"]"#)?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Prepared 5 packages in [TIME]
-    Installed 5 packages in [TIME]
-     + anyio==4.3.0
-     + c==1.0.0 (from git+https://github.com/astral-sh/workspace-virtual-root-test@fac39c8d4c5d0ef32744e2bb309bbe34a759fd46#subdirectory=packages/c)
-     + d==1.0.0 (from git+https://github.com/astral-sh/workspace-virtual-root-test@fac39c8d4c5d0ef32744e2bb309bbe34a759fd46#subdirectory=packages/d)
-     + idna==3.6
-     + sniffio==1.3.1
+    Resolved 4 packages in [TIME]
+    "###);
+
+    // Unknown variants are rejected.
+    uv_snapshot!(context.filters(), context.lock().arg("--variant").arg("cu121"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The variant `cu121` is not declared by any source in `tool.uv.sources` (declared variants: `sdist`, `wheel`)
+    "###);
+
+    uv_snapshot!(context.filters(), context.lock().env(EnvVars::UV_VARIANT, "cu121"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The variant `cu121` is not declared by any source in `tool.uv.sources` (declared variants: `sdist`, `wheel`)
+    "###);
+
+    Ok(())
+}
+
+/// Run `git` in the given directory, returning its trimmed output.
+fn git(dir: &std::path::Path, args: &[&str]) -> Result<String> {
+    let output = std::process::Command::new("git")
+        .args([
+            "-c",
+            "user.name=uv",
+            "-c",
+            "user.email=uv@example.com",
+            "-c",
+            "commit.gpgsign=false",
+        ])
+        .args(args)
+        .current_dir(dir)
+        .output()?;
+    anyhow::ensure!(output.status.success(), "`git {}` failed", args.join(" "));
+    Ok(String::from_utf8(output.stdout)?.trim().to_string())
+}
+
+/// Create a Git repository on the `main` branch with two packages, `a` and `b`, in subdirectories,
+/// returning its URL and the commit.
+fn git_packages(context: &TestContext) -> Result<(Url, String)> {
+    let repo = context.temp_dir.child("repo");
+    for name in ["a", "b"] {
+        repo.child(name)
+            .child("pyproject.toml")
+            .write_str(&formatdoc! {r#"
+            [project]
+            name = "{name}"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = []
+
+            [build-system]
+            requires = ["setuptools>=42"]
+            build-backend = "setuptools.build_meta"
+            "#,
+            })?;
+    }
+    git(&repo, &["init", "--initial-branch", "main"])?;
+    git(&repo, &["add", "."])?;
+    git(&repo, &["commit", "-m", "initial"])?;
+    Ok((
+        Url::from_directory_path(repo.path()).unwrap(),
+        git(&repo, &["rev-parse", "HEAD"])?,
+    ))
+}
+
+/// Advance the Git repository created by [`git_packages`], returning the new commit.
+fn git_packages_advance(context: &TestContext) -> Result<String> {
+    let repo = context.temp_dir.child("repo");
+    repo.child("README.md").write_str("")?;
+    git(&repo, &["add", "README.md"])?;
+    git(&repo, &["commit", "-m", "advance"])?;
+    git(&repo, &["rev-parse", "HEAD"])
+}
+
+/// `--upgrade-git-package` should advance the requested package, along with any other package that
+/// shares its repository and branch, since both resolve to the same commit.
+#[test]
+fn lock_upgrade_git_package_shared_reference() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let (url, initial) = git_packages(&context)?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["a", "b"]
+
+        [tool.uv.sources]
+        a = {{ git = "{url}", subdirectory = "a", branch = "main" }}
+        b = {{ git = "{url}", subdirectory = "b", branch = "main" }}
+        "#,
+    })?;
+
+    context.lock().assert().success();
+    let lock = context.read("uv.lock");
+    assert!(lock.contains(&initial));
+
+    let advanced = git_packages_advance(&context)?;
+
+    // Without an upgrade, the locked commit is retained.
+    context.lock().assert().success();
+    let lock = context.read("uv.lock");
+    assert!(lock.contains(&initial));
+    assert!(!lock.contains(&advanced));
+
+    // Advancing `a` advances `b`, too.
+    context
+        .lock()
+        .arg("--upgrade-git-package")
+        .arg("a")
+        .assert()
+        .success();
+    let lock = context.read("uv.lock");
+    assert!(!lock.contains(&initial));
+    assert!(lock.contains(&advanced));
+
+    Ok(())
+}
+
+/// `--upgrade-git` should advance Git sources that track a branch, but retain those pinned to a
+/// commit.
+#[test]
+fn lock_upgrade_git_pinned() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let (url, initial) = git_packages(&context)?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["a", "b"]
+
+        [tool.uv.sources]
+        a = {{ git = "{url}", subdirectory = "a", branch = "main" }}
+        b = {{ git = "{url}", subdirectory = "b", rev = "{initial}" }}
+        "#,
+    })?;
+
+    context.lock().assert().success();
+
+    let advanced = git_packages_advance(&context)?;
+
+    context.lock().arg("--upgrade-git").assert().success();
+    let lock = context.read("uv.lock");
+    assert!(lock.contains(&advanced));
+    assert!(lock.contains(&format!("rev={initial}")));
+
+    // Requesting a pinned package is a no-op.
+    uv_snapshot!(context.filters(), context.lock().arg("--upgrade-git-package").arg("b"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `b` is not locked to a Git source that tracks a branch or tag; ignoring `--upgrade-git-package b`
+    Resolved 3 packages in [TIME]
     "###);
 
     Ok(())
 }
+
+/// Lock and sync a Mercurial dependency from a local repository. The changeset is pinned in the
+/// lockfile until the dependency is upgraded.
+#[test]
+#[cfg(feature = "hg")]
+fn lock_hg() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let repo = context.temp_dir.child("repo");
+    let initial = common::make_hg_project(&repo, "dependency")?;
+    let url = Url::from_directory_path(repo.path()).unwrap();
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["dependency"]
+
+        [tool.uv.sources]
+        dependency = {{ hg = "{url}" }}
+        "#,
+    })?;
+
+    context.lock().assert().success();
+    assert!(context.read("uv.lock").contains(&initial));
+
+    context.sync().arg("--locked").assert().success();
+    context.assert_command("import dependency").success();
+
+    // Advance the repository.
+    repo.child("README.md").write_str("")?;
+    common::hg(&repo, &["commit", "--addremove", "-m", "advance"])?;
+    let advanced = common::hg(&repo, &["log", "-r", ".", "-T", "{node}"])?;
+
+    // The locked changeset is retained...
+    context.lock().arg("--locked").assert().success();
+
+    // ...until the dependency is upgraded.
+    context
+        .lock()
+        .arg("--upgrade-package")
+        .arg("dependency")
+        .assert()
+        .success();
+    let lock = context.read("uv.lock");
+    assert!(!lock.contains(&initial));
+    assert!(lock.contains(&advanced));
+
+    context.sync().arg("--locked").assert().success();
+    context.assert_command("import dependency").success();
+
+    Ok(())
+}
@@ -3,6 +3,9 @@
 
 pub(crate) mod common;
 
+#[cfg(feature = "python")]
+mod auth;
+
 mod branching_urls;
 
 #[cfg(all(feature = "python", feature = "pypi"))]
@@ -11,31 +14,63 @@ mod build;
 #[cfg(feature = "python")]
 mod build_backend;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod bundle;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod cache_clean;
 
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod cache_prune;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod cache_verify;
+
+mod completion;
+
+#[cfg(feature = "python")]
+mod config;
+
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod deploy;
+
+#[cfg(feature = "python")]
+mod doctor;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod ecosystem;
 
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod edit;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod env_remove;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod export;
 
 mod help;
 
+#[cfg(feature = "python")]
+mod index;
+
+#[cfg(feature = "python")]
+mod info;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod init;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod licenses;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod lock;
 
 mod lock_scenarios;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod outdated;
+
 mod pip_check;
 
 #[cfg(all(feature = "python", feature = "pypi"))]
@@ -61,6 +96,9 @@ mod pip_sync;
 mod pip_tree;
 mod pip_uninstall;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod pip_verify;
+
 #[cfg(feature = "pypi")]
 mod publish;
 
@@ -72,21 +110,35 @@ mod python_find;
 #[cfg(feature = "python-managed")]
 mod python_install;
 
+#[cfg(feature = "python")]
+mod python_list;
+
 #[cfg(feature = "python")]
 mod python_pin;
 
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod run;
 
+mod search;
+
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod self_uninstall;
+
 #[cfg(feature = "self-update")]
 mod self_update;
 
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod show_settings;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod stats;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod sync;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod tool_alias;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod tool_dir;
 
@@ -99,6 +151,9 @@ mod tool_list;
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod tool_run;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod tool_sync;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod tool_uninstall;
 
@@ -108,6 +163,9 @@ mod tool_upgrade;
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod tree;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod vendor;
+
 #[cfg(feature = "python")]
 mod venv;
 
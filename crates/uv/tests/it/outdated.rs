@@ -0,0 +1,124 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+use indoc::indoc;
+
+use crate::common::{uv_snapshot, TestContext};
+
+/// List outdated dependencies, distinguishing upgrades within the current specifiers from those
+/// that require updating the `pyproject.toml`.
+#[test]
+fn outdated() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Lock against an older version of `anyio`.
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.6.2"]
+
+        [dependency-groups]
+        dev = ["iniconfig==1.1.1"]
+        "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    "###);
+
+    // Relax the specifier, such that a newer version is allowed without updating the lockfile.
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio>=3,<4"]
+
+        [dependency-groups]
+        dev = ["iniconfig==1.1.1"]
+        "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.outdated().arg("--frozen"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Package    Specifier  Locked  Compatible  Latest  Section
+    anyio      >=3, <4    3.6.2   3.7.1       4.3.0   dependencies
+    iniconfig  ==1.1.1    1.1.1   1.1.1       2.0.0   dependency-groups.dev
+
+    To upgrade within the current constraints, run:
+      uv lock --upgrade-package anyio
+
+    To upgrade beyond the current constraints (updating `pyproject.toml`), run:
+      uv add "anyio>=4.3.0"
+      uv add --dev "iniconfig>=2.0.0"
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.outdated().arg("--frozen").arg("--output-format").arg("json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [
+      {
+        "name": "anyio",
+        "member": "foo",
+        "section": "dependencies",
+        "specifier": ">=3, <4",
+        "locked": "3.6.2",
+        "compatible": "3.7.1",
+        "latest": "4.3.0"
+      },
+      {
+        "name": "iniconfig",
+        "member": "foo",
+        "section": "dependency-groups.dev",
+        "specifier": "==1.1.1",
+        "locked": "1.1.1",
+        "compatible": "1.1.1",
+        "latest": "2.0.0"
+      }
+    ]
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
+
+/// Report that the dependencies are up-to-date.
+#[test]
+fn outdated_up_to_date() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.outdated(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    All direct dependencies are up-to-date
+    "###);
+
+    Ok(())
+}
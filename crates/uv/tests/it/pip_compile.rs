@@ -297,6 +297,57 @@ fn compile_constraints_txt() -> Result<()> {
     Ok(())
 }
 
+/// Resolve a package from a `requirements.in` file, constrained by the versions in a `uv.lock`.
+#[test]
+fn compile_constraint_from_lock() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--constraint-from-lock")
+            .arg("uv.lock"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --constraint-from-lock uv.lock
+    anyio==3.7.0
+        # via
+        #   -c uv.lock
+        #   -r requirements.in
+    idna==3.6
+        # via
+        #   -c uv.lock
+        #   anyio
+    sniffio==1.3.1
+        # via
+        #   -c uv.lock
+        #   anyio
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
 /// Resolve a package from a `requirements.in` file, with an inline constraint.
 #[test]
 fn compile_constraints_inline() -> Result<()> {
@@ -3210,6 +3261,60 @@ fn compile_yanked_version_indirect() -> Result<()> {
     Ok(())
 }
 
+/// Resolve a yanked version of `attrs` through an indirect requirement, by allowing it
+/// explicitly via `--allow-yanked`.
+#[test]
+fn compile_yanked_version_indirect_allowed() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("attrs>20.3.0,<21.2.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--allow-yanked")
+            .arg("attrs==21.1.0"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --allow-yanked attrs==21.1.0
+    attrs==21.1.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    warning: `attrs==21.1.0` is yanked (reason: "Installable but not importable on Python 3.4.")
+    "###
+    );
+
+    Ok(())
+}
+
+/// Fail to resolve a yanked version of `attrs`, even when specifying the version directly, with
+/// `--yanked error`.
+#[test]
+fn compile_yanked_version_direct_error() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("attrs==21.1.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--yanked")
+            .arg("error"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because attrs==21.1.0 was yanked (reason: Installable but not importable on Python 3.4) and you require attrs==21.1.0, we can conclude that your requirements are unsatisfiable.
+    "###
+    );
+
+    Ok(())
+}
+
 /// Flask==3.0.0 depends on Werkzeug>=3.0.0. Demonstrate that we can override this
 /// requirement with an incompatible version.
 #[test]
@@ -7150,6 +7255,58 @@ fn universal_disjoint_locals() -> Result<()> {
     Ok(())
 }
 
+/// Prefer versions with a given local version label, e.g., `2.0.0+cpu` over `2.0.0+cu118`.
+#[test]
+fn prefer_local_version() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str(indoc::indoc! {r"
+        --find-links https://download.pytorch.org/whl/torch_stable.html
+
+        torch>=2.0.0,<2.0.1
+    "})?;
+
+    uv_snapshot!(context.filters(), windows_filters=false, context.pip_compile()
+            .arg("requirements.in")
+            .arg("--universal")
+            .arg("--no-deps")
+            .arg("--prefer-local-version")
+            .arg("cpu"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --universal --no-deps --prefer-local-version cpu
+    torch==2.0.0+cpu
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    // The label is also respected when provided via the environment.
+    uv_snapshot!(context.filters(), windows_filters=false, context.pip_compile()
+            .arg("requirements.in")
+            .arg("--universal")
+            .arg("--no-deps")
+            .env(EnvVars::UV_PREFER_LOCAL_VERSION, "cu118"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --universal --no-deps
+    torch==2.0.0+cu118
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
 // Requested distinct local versions with disjoint markers of a package
 // that is also present as a transitive dependency.
 #[test]
@@ -11414,6 +11571,144 @@ fn python_platform() -> Result<()> {
     Ok(())
 }
 
+/// Compile against multiple platforms in a single invocation, writing an output file per target.
+#[test]
+fn python_platform_multiple() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("black")?;
+
+    uv_snapshot!(context.filters(),
+        windows_filters=false,
+        context.pip_compile()
+        .arg("requirements.in")
+        .arg("--quiet")
+        .arg("--output-file")
+        .arg("requirements.txt")
+        .arg("--python-platform")
+        .arg("aarch64-unknown-linux-gnu")
+        .arg("--python-platform")
+        .arg("x86_64-pc-windows-msvc")
+        .arg("--python-version")
+        .arg("3.12"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    "###
+    );
+
+    context
+        .temp_dir
+        .child("requirements.txt")
+        .assert(predicates::path::missing());
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        insta::assert_snapshot!(
+            context.read("requirements-aarch64-unknown-linux-gnu-py3.12.txt"), @r###"
+        # This file was autogenerated by uv via the following command:
+        #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --output-file requirements.txt --python-platform aarch64-unknown-linux-gnu --python-platform x86_64-pc-windows-msvc --python-version 3.12
+        # Resolved for the following target:
+        #    --python-platform aarch64-unknown-linux-gnu --python-version 3.12
+        black==24.3.0
+            # via -r requirements.in
+        click==8.1.7
+            # via black
+        mypy-extensions==1.0.0
+            # via black
+        packaging==24.0
+            # via black
+        pathspec==0.12.1
+            # via black
+        platformdirs==4.2.0
+            # via black
+        "###
+        );
+    });
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        insta::assert_snapshot!(
+            context.read("requirements-x86_64-pc-windows-msvc-py3.12.txt"), @r###"
+        # This file was autogenerated by uv via the following command:
+        #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --output-file requirements.txt --python-platform aarch64-unknown-linux-gnu --python-platform x86_64-pc-windows-msvc --python-version 3.12
+        # Resolved for the following target:
+        #    --python-platform x86_64-pc-windows-msvc --python-version 3.12
+        black==24.3.0
+            # via -r requirements.in
+        click==8.1.7
+            # via black
+        colorama==0.4.6
+            # via click
+        mypy-extensions==1.0.0
+            # via black
+        packaging==24.0
+            # via black
+        pathspec==0.12.1
+            # via black
+        platformdirs==4.2.0
+            # via black
+        "###
+        );
+    });
+
+    Ok(())
+}
+
+/// Compiling for multiple targets requires an output file, and paired values must line up.
+#[test]
+fn python_platform_multiple_invalid() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("black")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--python-platform")
+        .arg("linux")
+        .arg("--python-platform")
+        .arg("windows"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Compiling for multiple targets requires `--output-file`
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--output-file")
+        .arg("requirements.txt")
+        .arg("--python-platform")
+        .arg("linux")
+        .arg("--python-platform")
+        .arg("windows")
+        .arg("--python-version")
+        .arg("3.11")
+        .arg("--python-version")
+        .arg("3.12")
+        .arg("--python-version")
+        .arg("3.13"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Received 3 values for `--python-version` and 2 values for `--python-platform`; provide the same number of each to compile paired targets, or a single value to use for every target
+    "###
+    );
+
+    Ok(())
+}
+
 /// Resolve a specific source distribution via a Git HTTPS dependency.
 #[test]
 #[cfg(feature = "git")]
@@ -11863,7 +12158,7 @@ fn invalid_tool_uv_sources() -> Result<()> {
 
     ----- stderr -----
     error: Failed to parse metadata from built wheel
-      Caused by: Expected direct URL (`https://files.pythonhosted.org/packages/a2/73/a68704750a7679d0b6d3ad7aa8d4da8e14e151ae82e6fee774e6e0d05ec8/urllib3-2.2.1-py3-none-any.tar.baz`) to end in a supported file extension: `.whl`, `.tar.gz`, `.zip`, `.tar.bz2`, `.tar.lz`, `.tar.lzma`, `.tar.xz`, `.tar.zst`, `.tar`, `.tbz`, `.tgz`, `.tlz`, or `.txz`
+      Caused by: Expected direct URL (`https://files.pythonhosted.org/packages/a2/73/a68704750a7679d0b6d3ad7aa8d4da8e14e151ae82e6fee774e6e0d05ec8/urllib3-2.2.1-py3-none-any.tar.baz`) to end in a supported file extension: `.whl`, `.tar.gz`, `.zip`, `.tar.bz2`, `.tar.lz`, `.tar.lzma`, `.tar.xz`, `.tar.zst`, `.tar`, `.tbz`, `.tgz`, `.tlz`, `.txz`, or `.tzst`
     urllib3 @ https://files.pythonhosted.org/packages/a2/73/a68704750a7679d0b6d3ad7aa8d4da8e14e151ae82e6fee774e6e0d05ec8/urllib3-2.2.1-py3-none-any.tar.baz
               ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
     "###
@@ -11891,7 +12186,7 @@ fn invalid_tool_uv_sources() -> Result<()> {
 
     ----- stderr -----
     error: Failed to parse entry: `urllib3`
-      Caused by: Expected direct URL (`https://files.pythonhosted.org/packages/a2/73/a68704750a7679d0b6d3ad7aa8d4da8e14e151ae82e6fee774e6e0d05ec8/urllib3-2.2.1-py3-none-any.tar.baz`) to end in a supported file extension: `.whl`, `.tar.gz`, `.zip`, `.tar.bz2`, `.tar.lz`, `.tar.lzma`, `.tar.xz`, `.tar.zst`, `.tar`, `.tbz`, `.tgz`, `.tlz`, or `.txz`
+      Caused by: Expected direct URL (`https://files.pythonhosted.org/packages/a2/73/a68704750a7679d0b6d3ad7aa8d4da8e14e151ae82e6fee774e6e0d05ec8/urllib3-2.2.1-py3-none-any.tar.baz`) to end in a supported file extension: `.whl`, `.tar.gz`, `.zip`, `.tar.bz2`, `.tar.lz`, `.tar.lzma`, `.tar.xz`, `.tar.zst`, `.tar`, `.tbz`, `.tgz`, `.tlz`, `.txz`, or `.tzst`
     "###
     );
 
@@ -12661,3 +12956,125 @@ fn prune_unreachable() -> Result<()> {
 
     Ok(())
 }
+
+/// Abort a resolution that exceeds the step budget set with `UV_RESOLUTION_MAX_STEPS`.
+#[test]
+fn resolution_max_steps() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio==4.3.0")?;
+
+    uv_snapshot!(context
+        .pip_compile()
+        .arg("requirements.in")
+        .env(EnvVars::UV_RESOLUTION_MAX_STEPS, "2"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Resolution exceeded the limit of 2 steps (`--resolution-max-steps`) after [TIME]
+    "###);
+
+    Ok(())
+}
+
+/// Read the step budget from the `resolution-max-steps` setting.
+#[test]
+fn resolution_max_steps_setting() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio==4.3.0")?;
+
+    context
+        .temp_dir
+        .child("uv.toml")
+        .write_str("resolution-max-steps = 2")?;
+
+    uv_snapshot!(context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--config-file")
+        .arg("uv.toml"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Resolution exceeded the limit of 2 steps (`--resolution-max-steps`) after [TIME]
+    "###);
+
+    // The command-line flag takes precedence over the setting.
+    uv_snapshot!(context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--config-file")
+        .arg("uv.toml")
+        .arg("--resolution-max-steps")
+        .arg("100"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --config-file uv.toml --resolution-max-steps 100
+    anyio==4.3.0
+        # via -r requirements.in
+    idna==3.6
+        # via anyio
+    sniffio==1.3.1
+        # via anyio
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###);
+
+    Ok(())
+}
+
+/// Abort a resolution that exceeds `--resolution-timeout` while waiting on a slow build, rather
+/// than only checking the timeout between versions.
+#[test]
+fn resolution_timeout() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // A build backend that takes far longer than the timeout to prepare metadata.
+    let project = context.temp_dir.child("slow");
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "slow"
+        version = "0.1.0"
+        dynamic = ["dependencies"]
+
+        [build-system]
+        requires = []
+        build-backend = "backend"
+        backend-path = ["."]
+    "#})?;
+    project
+        .child("backend.py")
+        .write_str("import time\n\ntime.sleep(30)\n")?;
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("./slow")?;
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"after \d+ steps", "after [STEPS] steps")])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--resolution-timeout")
+        .arg("1"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Resolution exceeded the timeout of 1s (`--resolution-timeout`) after [STEPS] steps
+    "###);
+
+    Ok(())
+}
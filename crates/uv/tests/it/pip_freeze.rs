@@ -354,3 +354,107 @@ Version: 0.22.0
 
     Ok(())
 }
+
+/// Emit packages installed from a direct URL as `name==version`.
+#[test]
+fn freeze_no_direct_url() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl")?;
+
+    // Run `pip sync`.
+    context
+        .pip_sync()
+        .arg(requirements_txt.path())
+        .assert()
+        .success();
+
+    // Run `pip freeze`.
+    uv_snapshot!(context.pip_freeze()
+        .arg("--no-direct-url"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    iniconfig==2.0.0
+
+    ----- stderr -----
+    "###
+    );
+
+    Ok(())
+}
+
+/// List the packages in a `--target` directory.
+#[test]
+fn freeze_path() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0")?;
+
+    // Run `pip sync` into the virtual environment, and into a `--target` directory.
+    context
+        .pip_sync()
+        .arg(requirements_txt.path())
+        .assert()
+        .success();
+
+    requirements_txt.write_str("MarkupSafe==2.1.3")?;
+    context
+        .pip_sync()
+        .arg(requirements_txt.path())
+        .arg("--target")
+        .arg("target")
+        .assert()
+        .success();
+
+    // Only the packages in the `--target` directory are listed; missing paths are ignored.
+    uv_snapshot!(context.pip_freeze()
+        .arg("--path")
+        .arg("target")
+        .arg("--path")
+        .arg("missing"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    markupsafe==2.1.3
+
+    ----- stderr -----
+    "###
+    );
+
+    Ok(())
+}
+
+/// Emit the installed packages as JSON.
+#[test]
+#[cfg(unix)]
+fn freeze_json() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3\niniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl")?;
+
+    // Run `pip sync`.
+    context
+        .pip_sync()
+        .arg(requirements_txt.path())
+        .assert()
+        .success();
+
+    // Run `pip freeze`.
+    uv_snapshot!(context.filters(), context.pip_freeze()
+        .arg("--format")
+        .arg("json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [{"name":"iniconfig","version":"2.0.0","url":"https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl","installer":"uv","location":"[SITE_PACKAGES]/"},{"name":"markupsafe","version":"2.1.3","installer":"uv","location":"[SITE_PACKAGES]/"}]
+
+    ----- stderr -----
+    "###
+    );
+
+    Ok(())
+}
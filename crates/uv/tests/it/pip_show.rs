@@ -524,3 +524,66 @@ fn show_files() {
     ----- stderr -----
     "#);
 }
+
+/// Show the files of a package installed as an `.egg-info` directory, via its
+/// `installed-files.txt`.
+#[test]
+fn show_files_egg_info() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let site_packages = context.site_packages();
+    let egg_info = site_packages.join("zstandard-0.22.0-py3.12.egg-info");
+    fs_err::create_dir_all(&egg_info)?;
+    fs_err::write(
+        egg_info.join("PKG-INFO"),
+        indoc! {"
+            Metadata-Version: 2.1
+            Name: zstandard
+            Version: 0.22.0
+        "},
+    )?;
+    fs_err::write(
+        egg_info.join("installed-files.txt"),
+        indoc! {"
+            ../zstd/__init__.py
+            PKG-INFO
+        "},
+    )?;
+
+    uv_snapshot!(context.filters(), context.pip_show().arg("zstandard").arg("--files"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Name: zstandard
+    Version: 0.22.0
+    Location: [SITE_PACKAGES]/
+    Requires:
+    Required-by:
+    Files:
+      zstd/__init__.py
+      zstandard-0.22.0-py3.12.egg-info/PKG-INFO
+
+    ----- stderr -----
+    "###
+    );
+
+    // Without an `installed-files.txt`, the files can't be determined.
+    fs_err::remove_file(egg_info.join("installed-files.txt"))?;
+
+    uv_snapshot!(context.filters(), context.pip_show().arg("zstandard").arg("--files"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Name: zstandard
+    Version: 0.22.0
+    Location: [SITE_PACKAGES]/
+    Requires:
+    Required-by:
+    Cannot locate RECORD or installed-files.txt
+
+    ----- stderr -----
+    "###
+    );
+
+    Ok(())
+}
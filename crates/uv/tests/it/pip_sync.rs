@@ -1065,6 +1065,153 @@ fn warn_on_yanked_dry_run() -> Result<()> {
     Ok(())
 }
 
+/// Fail to install a yanked version, even when pinned, with `--yanked error`.
+#[test]
+fn yanked_error() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // This version is yanked.
+    let requirements_in = context.temp_dir.child("requirements.txt");
+    requirements_in.write_str("colorama==0.4.2")?;
+
+    uv_snapshot!(context.filters(), windows_filters=false, context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--yanked")
+        .arg("error"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because colorama==0.4.2 was yanked (reason: Bad build, missing files, will not install) and you require colorama==0.4.2, we can conclude that your requirements are unsatisfiable.
+    "###
+    );
+
+    Ok(())
+}
+
+/// Report the planned changes of a dry run as JSON, including the reason for each change.
+#[test]
+fn dry_run_json() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("markupsafe==2.1.3\ntomli==2.0.1")?;
+
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    requirements_txt.write_str("markupsafe==2.1.5\niniconfig==2.0.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--dry-run")
+        .arg("--output-format")
+        .arg("json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "changes": [
+        {
+          "name": "iniconfig",
+          "action": "install",
+          "reason": "missing",
+          "target": {
+            "version": "2.0.0"
+          }
+        },
+        {
+          "name": "markupsafe",
+          "action": "reinstall",
+          "reason": "version-mismatch",
+          "installed": {
+            "version": "2.1.3"
+          },
+          "target": {
+            "version": "2.1.5"
+          }
+        },
+        {
+          "name": "tomli",
+          "action": "uninstall",
+          "reason": "extraneous",
+          "installed": {
+            "version": "2.0.1"
+          }
+        }
+      ]
+    }
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###
+    );
+
+    // The environment should be unchanged.
+    context.assert_command("import tomli").success();
+
+    Ok(())
+}
+
+/// Reinstall a URL archive whose recorded hashes no longer match the required hashes.
+#[test]
+fn dry_run_hash_mismatch() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl --hash=sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374")?;
+
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes")
+        .assert()
+        .success();
+
+    // The archive hashes should be recorded at install time.
+    let direct_url = context
+        .site_packages()
+        .join("iniconfig-2.0.0.dist-info")
+        .join("direct_url.json");
+    let contents = fs::read_to_string(&direct_url)?;
+    assert!(contents.contains(
+        r#""sha256":"b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374""#
+    ));
+
+    // Simulate an installation from a different archive at the same URL.
+    fs::write(
+        &direct_url,
+        contents.replace(
+            "b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374",
+            "0000000000000000000000000000000000000000000000000000000000000000",
+        ),
+    )?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes")
+        .arg("--dry-run"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Would uninstall 1 package
+    Would install 1 package
+     - iniconfig==2.0.0 (from https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl) (hash mismatch)
+     + iniconfig==2.0.0 (from https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl)
+    "###
+    );
+
+    Ok(())
+}
+
 /// Resolve a local wheel.
 #[test]
 fn install_local_wheel() -> Result<()> {
@@ -3296,6 +3443,81 @@ fn compile() -> Result<()> {
     Ok(())
 }
 
+/// Install with bytecode compilation at an optimization level of `-OO`.
+#[test]
+fn compile_optimization() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--compile")
+        .arg("--compile-bytecode-optimization")
+        .arg("2")
+        .arg("--compile-bytecode-workers")
+        .arg("1")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+    Bytecode compiled 3 files in [TIME]
+     + markupsafe==2.1.3
+    "###
+    );
+
+    let pycache = context
+        .site_packages()
+        .join("markupsafe")
+        .join("__pycache__");
+    assert!(pycache.join("__init__.cpython-312.opt-2.pyc").exists());
+    assert!(!pycache.join("__init__.cpython-312.pyc").exists());
+
+    Ok(())
+}
+
+/// Install with bytecode compilation, excluding a package from compilation.
+#[test]
+fn compile_no_compile_package() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--compile")
+        .arg("--no-compile-bytecode-package")
+        .arg("markupsafe")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+    Bytecode compiled 0 files in [TIME]
+     + markupsafe==2.1.3
+    "###
+    );
+
+    assert!(!context
+        .site_packages()
+        .join("markupsafe")
+        .join("__pycache__")
+        .exists());
+
+    Ok(())
+}
+
 /// Raise an error when an editable's `Requires-Python` constraint is not met.
 #[test]
 fn requires_python_editable() -> Result<()> {
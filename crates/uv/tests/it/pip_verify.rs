@@ -0,0 +1,99 @@
+use crate::common::uv_snapshot;
+use crate::common::TestContext;
+use anyhow::Result;
+
+#[test]
+fn verify_unmodified_packages() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.pip_install().arg("iniconfig==2.0.0"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+
+    uv_snapshot!(context.pip_verify(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verified 1 package in [TIME]
+    All installed files match their `RECORD` files
+    "###
+    );
+
+    Ok(())
+}
+
+/// Modify, remove, and add files within an installed package.
+#[test]
+fn verify_modified_packages() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.pip_install().arg("iniconfig==2.0.0"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+
+    let package = context.site_packages().join("iniconfig");
+    fs_err::write(package.join("__init__.py"), "")?;
+    fs_err::remove_file(package.join("py.typed"))?;
+    fs_err::write(package.join("extra.py"), "print('Hello, world!')")?;
+
+    // Bytecode isn't recorded, and should be ignored.
+    fs_err::create_dir_all(package.join("__pycache__"))?;
+    fs_err::write(
+        package
+            .join("__pycache__")
+            .join("exceptions.cpython-312.pyc"),
+        "",
+    )?;
+
+    uv_snapshot!(context.filters(), context.pip_verify(), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Verified 1 package in [TIME]
+    Found 3 discrepancies
+    The package `iniconfig` has a modified file: `iniconfig/__init__.py`
+    The package `iniconfig` is missing a file: `iniconfig/py.typed`
+    The package `iniconfig` has an extra file: `iniconfig/extra.py`
+    "###
+    );
+
+    // Only the requested packages are verified.
+    uv_snapshot!(context.filters(), context.pip_verify().arg("iniconfig").arg("flask"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Package(s) not found for: flask
+    Verified 1 package in [TIME]
+    Found 3 discrepancies
+    The package `iniconfig` has a modified file: `iniconfig/__init__.py`
+    The package `iniconfig` is missing a file: `iniconfig/py.typed`
+    The package `iniconfig` has an extra file: `iniconfig/extra.py`
+    "###
+    );
+
+    Ok(())
+}
@@ -53,6 +53,31 @@ fn invalid_token() {
     );
 }
 
+/// The token may reference a secret, which must be resolvable.
+#[test]
+fn missing_token_secret() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.publish()
+        .arg("--token")
+        .arg("env:UV_TEST_PUBLISH_TOKEN")
+        .arg("--publish-url")
+        .arg("https://test.pypi.org/legacy/")
+        .arg("../../scripts/links/ok-1.0.0-py3-none-any.whl")
+        .env_remove("UV_TEST_PUBLISH_TOKEN"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv publish` is experimental and may change without warning
+    Publishing 1 file to https://test.pypi.org/legacy/
+    error: Failed to read the publish password
+      Caused by: Environment variable `UV_TEST_PUBLISH_TOKEN` is not set
+    "###
+    );
+}
+
 /// Emulate a missing `permission` `id-token: write` situation.
 #[test]
 fn mixed_credentials() {
@@ -152,6 +152,116 @@ fn python_find() {
     "###);
 }
 
+#[test]
+fn python_find_query() {
+    let context: TestContext = TestContext::new_with_versions(&["3.11", "3.12"]);
+
+    let arch = Arch::from_env();
+
+    // Request a version range for a specific implementation and architecture
+    uv_snapshot!(context.filters(), context.python_find()
+        .arg("cpython>=3.11,<3.13")
+        .arg("--arch")
+        .arg(arch.to_string()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [PYTHON-3.11]
+
+    ----- stderr -----
+    "###);
+
+    // The range is respected alongside the architecture
+    uv_snapshot!(context.filters(), context.python_find()
+        .arg("cpython>3.11")
+        .arg("--arch")
+        .arg(arch.to_string()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [PYTHON-3.12]
+
+    ----- stderr -----
+    "###);
+
+    // Request a different architecture, which should be missing
+    let other = if arch.to_string() == "aarch64" {
+        "x86_64"
+    } else {
+        "aarch64"
+    };
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(other, "[ARCH]")])
+        .collect::<Vec<_>>();
+    if cfg!(windows) {
+        uv_snapshot!(filters, context.python_find()
+            .arg("cpython>=3.11,<3.13")
+            .arg("--arch")
+            .arg(other), @r###"
+        success: false
+        exit_code: 2
+        ----- stdout -----
+
+        ----- stderr -----
+        error: No interpreter found for cpython->=3.11, <3.13-any-[ARCH]-any in virtual environments, managed installations, system path, or `py` launcher
+        "###);
+    } else {
+        uv_snapshot!(filters, context.python_find()
+            .arg("cpython>=3.11,<3.13")
+            .arg("--arch")
+            .arg(other), @r###"
+        success: false
+        exit_code: 2
+        ----- stdout -----
+
+        ----- stderr -----
+        error: No interpreter found for cpython->=3.11, <3.13-any-[ARCH]-any in virtual environments, managed installations, or system path
+        "###);
+    }
+
+    // Request a free-threaded build, which should be missing
+    if cfg!(windows) {
+        uv_snapshot!(context.filters(), context.python_find()
+            .arg("3.12")
+            .arg("--variant")
+            .arg("freethreaded"), @r###"
+        success: false
+        exit_code: 2
+        ----- stdout -----
+
+        ----- stderr -----
+        error: No interpreter found for Python 3.12t in virtual environments, managed installations, system path, or `py` launcher
+        "###);
+    } else {
+        uv_snapshot!(context.filters(), context.python_find()
+            .arg("3.12")
+            .arg("--variant")
+            .arg("freethreaded"), @r###"
+        success: false
+        exit_code: 2
+        ----- stdout -----
+
+        ----- stderr -----
+        error: No interpreter found for Python 3.12t in virtual environments, managed installations, or system path
+        "###);
+    }
+
+    // `--arch` can't be combined with an executable name
+    uv_snapshot!(context.filters(), context.python_find()
+        .arg("foobar")
+        .arg("--arch")
+        .arg(arch.to_string()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `--arch` cannot be used with a request for executable name `foobar`
+    "###);
+}
+
 #[test]
 fn python_find_pin() {
     let context: TestContext = TestContext::new_with_versions(&["3.11", "3.12"]);
@@ -1,7 +1,11 @@
 use std::process::Command;
 
-use assert_fs::{assert::PathAssert, prelude::PathChild};
+use assert_fs::{
+    assert::PathAssert,
+    prelude::{FileWriteStr, PathChild},
+};
 use predicates::prelude::predicate;
+use uv_static::EnvVars;
 
 use crate::common::{uv_snapshot, TestContext};
 
@@ -249,6 +253,122 @@ fn python_install_freethreaded() {
     "###);
 }
 
+#[test]
+fn python_install_variant() {
+    let context: TestContext = TestContext::new_with_versions(&[]).with_filtered_python_keys();
+
+    // Install a debug build
+    uv_snapshot!(context.filters(), context.python_install().arg("3.13").arg("--variant").arg("debug"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.13.0 in [TIME]
+     + cpython-3.13.0+debug-[PLATFORM]
+    "###);
+
+    // The suffix is equivalent to the flag
+    uv_snapshot!(context.filters(), context.python_install().arg("3.13d"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    All requested versions already installed
+    "###);
+
+    // A debug build doesn't satisfy a request for the default build
+    uv_snapshot!(context.filters(), context.python_install().arg("3.13"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.13.0 in [TIME]
+     + cpython-3.13.0-[PLATFORM]
+    "###);
+
+    // Nor does the default build satisfy a request for a free-threaded build
+    uv_snapshot!(context.filters(), context.python_install().arg("3.13").arg("--variant").arg("freethreaded"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.13.0 in [TIME]
+     + cpython-3.13.0+freethreaded-[PLATFORM]
+    "###);
+
+    // The flag applies to versions read from a `.python-version` file
+    context
+        .temp_dir
+        .child(".python-version")
+        .write_str("3.12")
+        .unwrap();
+    uv_snapshot!(context.filters(), context.python_install().arg("--variant").arg("debug"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.12.7 in [TIME]
+     + cpython-3.12.7+debug-[PLATFORM]
+    "###);
+
+    // Unknown variants are rejected
+    uv_snapshot!(context.filters(), context.python_install().arg("3.13").arg("--variant").arg("pgo"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'pgo' for '--variant <VARIANT>'
+      [possible values: default, freethreaded, debug]
+
+    For more information, try '--help'.
+    "###);
+}
+
+#[test]
+fn python_install_graalpy() {
+    let context: TestContext = TestContext::new_with_versions(&[]).with_filtered_python_keys();
+
+    // Install GraalPy, verifying the archive against its published checksum
+    uv_snapshot!(context.filters(), context.python_install().arg("graalpy@3.11"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.11.7 in [TIME]
+     + graalpy-3.11.7-[PLATFORM]
+    "###);
+
+    // The installation should be discoverable
+    uv_snapshot!(context.filters(), context.python_find()
+        .env(EnvVars::UV_PYTHON_INSTALL_DIR, context.temp_dir.join("managed"))
+        .arg("graalpy"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [TEMP_DIR]/managed/graalpy-3.11.7-[PLATFORM]
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.python_uninstall().arg("graalpy"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Searching for Python versions matching: GraalPy
+    Uninstalled Python 3.11.7 in [TIME]
+     - graalpy-3.11.7-[PLATFORM]
+    "###);
+}
+
 #[test]
 fn python_install_invalid_request() {
     let context: TestContext = TestContext::new_with_versions(&[]).with_filtered_python_keys();
@@ -0,0 +1,36 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+
+use crate::common::TestContext;
+
+#[test]
+fn python_list_json() -> Result<()> {
+    let context: TestContext = TestContext::new_with_versions(&["3.11", "3.12"]);
+
+    let output = context
+        .python_list()
+        .arg("--only-installed")
+        .arg("--output-format")
+        .arg("json")
+        .assert()
+        .success()
+        .get_output()
+        .clone();
+
+    let entries: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout)?;
+
+    // Both test interpreters are reported, with their install paths.
+    for minor in [11, 12] {
+        let entry = entries
+            .iter()
+            .find(|entry| entry["version_parts"]["minor"] == minor)
+            .unwrap_or_else(|| panic!("missing Python 3.{minor} in {entries:?}"));
+        assert_eq!(entry["implementation"], "cpython");
+        assert_eq!(entry["version_parts"]["major"], 3);
+        assert_eq!(entry["managed"], false);
+        assert!(entry["path"].is_string());
+        assert!(entry["url"].is_null());
+    }
+
+    Ok(())
+}
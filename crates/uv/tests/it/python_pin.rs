@@ -387,6 +387,57 @@ fn python_pin_compatible_with_requires_python() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn python_pin_variant() {
+    let context: TestContext = TestContext::new_with_versions(&["3.12"]);
+
+    let mut filters = context.filters();
+    filters.push((
+        r"managed installations, system path, or `py` launcher",
+        "managed installations or system path",
+    ));
+
+    // The variant is recorded in the pin, even if no matching interpreter is installed
+    uv_snapshot!(filters, context.python_pin().arg("3.12").arg("--variant").arg("debug"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Pinned `.python-version` to `3.12d`
+
+    ----- stderr -----
+    warning: No interpreter found for Python 3.12d in managed installations or system path
+    "###);
+
+    let python_version = context.read(PYTHON_VERSION_FILENAME);
+    assert_snapshot!(python_version, @"3.12d");
+
+    // Pinning the free-threaded variant replaces the debug variant
+    uv_snapshot!(filters, context.python_pin().arg("3.12").arg("--variant").arg("freethreaded"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Updated `.python-version` from `3.12d` -> `3.12t`
+
+    ----- stderr -----
+    warning: No interpreter found for Python 3.12t in managed installations or system path
+    "###);
+
+    // A variant requires a request
+    uv_snapshot!(context.filters(), context.python_pin().arg("--variant").arg("debug"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the following required arguments were not provided:
+      <REQUEST>
+
+    Usage: uv python pin --cache-dir [CACHE_DIR] --variant <VARIANT> <REQUEST>
+
+    For more information, try '--help'.
+    "###);
+}
+
 #[test]
 fn warning_pinned_python_version_not_installed() -> anyhow::Result<()> {
     let context: TestContext = TestContext::new_with_versions(&["3.10", "3.11"]);
@@ -584,6 +584,63 @@ fn run_pythonw_script() -> Result<()> {
     Ok(())
 }
 
+/// Run a script with `--gui-script`. The script should be executed with `pythonw.exe`,
+/// irrespective of its extension.
+#[test]
+#[cfg(windows)]
+fn run_gui_script() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let test_script = context.temp_dir.child("main.py");
+    test_script.write_str(indoc! { r#"
+        import os
+        import sys
+
+        with open("executable.txt", "w") as f:
+            f.write(os.path.basename(sys.executable))
+       "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.run().arg("--gui-script").arg("main.py"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    "###);
+
+    assert_eq!(
+        fs_err::read_to_string(context.temp_dir.child("executable.txt"))?,
+        "pythonw.exe"
+    );
+
+    Ok(())
+}
+
+/// `--gui-script` is only supported on Windows.
+#[test]
+#[cfg(not(windows))]
+fn run_gui_script_unsupported() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let test_script = context.temp_dir.child("main.py");
+    test_script.write_str(indoc! { r#"
+        print("Hello, world!")
+       "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.run().arg("--gui-script").arg("main.py"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `--gui-script` is only supported on Windows. Did you mean `--script`?
+    "###);
+
+    Ok(())
+}
+
 /// Run a PEP 723-compatible script with `tool.uv` metadata.
 #[test]
 fn run_pep723_script_metadata() -> Result<()> {
@@ -1218,6 +1275,7 @@ fn run_locked() -> Result<()> {
     ----- stderr -----
     Resolved 2 packages in [TIME]
     error: The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+      Caused by: The requirements of `project` changed (added: `iniconfig`; removed: `anyio==3.7.0`)
     "###);
 
     let updated = context.read("uv.lock");
@@ -1388,6 +1446,330 @@ fn run_no_sync() -> Result<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(target_family = "unix")]
+fn run_task() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [dependency-groups]
+        test = ["iniconfig"]
+
+        [tool.uv.tasks]
+        hello = "echo hello"
+        args = ["python", "-c", "import sys; print(sys.argv[1:])"]
+        check = { cmd = ["python", "-c", "import iniconfig, os; print(os.environ['MY_VAR'])"], groups = ["test"], env = { MY_VAR = "value" } }
+        "#
+    })?;
+
+    // A string task should be run in a shell.
+    uv_snapshot!(context.filters(), context.run().arg("hello"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    hello
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited in [TIME]
+    "###);
+
+    // Additional arguments should be forwarded to the shell.
+    uv_snapshot!(context.filters(), context.run().arg("hello").arg("world"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    hello world
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited in [TIME]
+    "###);
+
+    // A list task should be run directly, with additional arguments appended.
+    uv_snapshot!(context.filters(), context.run().arg("args").arg("a b").arg("c"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    ['a b', 'c']
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited in [TIME]
+    "###);
+
+    // A table task should include its groups and set its environment variables.
+    uv_snapshot!(context.filters(), context.run().arg("check"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    value
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###);
+
+    Ok(())
+}
+
+/// Additional arguments to a compound shell task are only received by its last command.
+#[test]
+#[cfg(unix)]
+fn run_task_compound() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv.tasks]
+        both = "echo first && echo second"
+        "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.run().arg("both").arg("extra"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    first
+    second extra
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited in [TIME]
+    "###);
+
+    Ok(())
+}
+
+/// Run a tool declared in `tool.uv.required-tools`.
+#[test]
+fn run_required_tool() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv.required-tools]
+        pytest = "==8.0.0"
+        "#
+    })?;
+
+    // The tool should be installed at the required version, outside the project environment.
+    uv_snapshot!(context.filters(), context.run().arg("pytest").arg("--version"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    pytest 8.0.0
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited in [TIME]
+    Resolved 4 packages in [TIME]
+    Prepared 4 packages in [TIME]
+    Installed 4 packages in [TIME]
+     + iniconfig==2.0.0
+     + packaging==24.0
+     + pluggy==1.4.0
+     + pytest==8.0.0
+    "###);
+
+    // The project environment should be unchanged.
+    uv_snapshot!(context.filters(), context.pip_list(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    "###);
+
+    // Subsequent invocations should reuse the cached environment.
+    uv_snapshot!(context.filters(), context.run().arg("pytest").arg("--version"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    pytest 8.0.0
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited in [TIME]
+    Resolved 4 packages in [TIME]
+    "###);
+
+    Ok(())
+}
+
+/// Run a task that invokes a tool declared in `tool.uv.required-tools` via a shell command.
+#[cfg(unix)]
+#[test]
+fn run_required_tool_shell_task() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv.required-tools]
+        pytest = "==8.0.0"
+
+        [tool.uv.tasks]
+        check = "echo checking && pytest --version"
+        "#
+    })?;
+
+    // The tool should be installed at the required version, even though the task runs in a shell.
+    uv_snapshot!(context.filters(), context.run().arg("check"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    checking
+    pytest 8.0.0
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited in [TIME]
+    Resolved 4 packages in [TIME]
+    Prepared 4 packages in [TIME]
+    Installed 4 packages in [TIME]
+     + iniconfig==2.0.0
+     + packaging==24.0
+     + pluggy==1.4.0
+     + pytest==8.0.0
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn run_env_file() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv]
+        env-files = [".env"]
+        "#
+    })?;
+
+    context
+        .temp_dir
+        .child(".env")
+        .write_str("FOO=project\nBAR=project\n")?;
+    context
+        .temp_dir
+        .child(".env.local")
+        .write_str("BAR=local\n")?;
+
+    let script = r#"import os; print(os.getenv("FOO"), os.getenv("BAR"))"#;
+
+    // Variables from `tool.uv.env-files` should be loaded.
+    uv_snapshot!(context.filters(), context.run().arg("python").arg("-c").arg(script), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project project
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited in [TIME]
+    "###);
+
+    // Files provided via `--env-file` should take precedence over `tool.uv.env-files`.
+    uv_snapshot!(context.filters(), context.run().arg("--env-file").arg(".env.local").arg("python").arg("-c").arg(script), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project local
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited in [TIME]
+    "###);
+
+    // Variables that are already set should take precedence over both.
+    uv_snapshot!(context.filters(), context.run().arg("--env-file").arg(".env.local").arg("python").arg("-c").arg(script).env("BAR", "shell"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project shell
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited in [TIME]
+    "###);
+
+    // With `--no-env-file`, no files should be loaded.
+    uv_snapshot!(context.filters(), context.run().arg("--no-env-file").arg("python").arg("-c").arg(script), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    None None
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited in [TIME]
+    "###);
+
+    // A missing file should be an error, reported before the environment is synced.
+    uv_snapshot!(context.filters(), context.run().arg("--env-file").arg("missing.env").arg("python").arg("-c").arg(script), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No environment file found at: `missing.env`
+    "###);
+
+    // Multiple files may be provided via `UV_ENV_FILE`, separated by the platform's path
+    // separator, and may contain spaces.
+    context
+        .temp_dir
+        .child("env files/.env.extra")
+        .write_str("FOO=extra\n")?;
+    let env_file = std::env::join_paths([".env.local", "env files/.env.extra"])?;
+    uv_snapshot!(context.filters(), context.run().env(EnvVars::UV_ENV_FILE, env_file).arg("python").arg("-c").arg(script), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    extra local
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited in [TIME]
+    "###);
+
+    Ok(())
+}
+
 #[test]
 fn run_empty_requirements_txt() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -0,0 +1,76 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+
+use crate::common::{uv_snapshot, TestContext};
+
+/// `uv search` should match package names by prefix against a local index, reporting the latest
+/// non-yanked stable version of each.
+#[test]
+fn search_local_index() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+
+    let index = context.temp_dir.child("index");
+    index.child("anyio").child("index.html").write_str(
+        r#"
+        <!DOCTYPE html>
+        <html>
+          <body>
+            <a href="anyio-4.0.0.tar.gz">anyio-4.0.0.tar.gz</a>
+            <a href="anyio-4.0.1.tar.gz" data-yanked="Broken build">anyio-4.0.1.tar.gz</a>
+            <a href="anyio-4.1.0rc1-py3-none-any.whl">anyio-4.1.0rc1-py3-none-any.whl</a>
+          </body>
+        </html>
+        "#,
+    )?;
+    index.child("anyio-extra").child("index.html").write_str(
+        r#"
+        <!DOCTYPE html>
+        <html>
+          <body>
+            <a href="anyio_extra-1.0.0-py3-none-any.whl">anyio_extra-1.0.0-py3-none-any.whl</a>
+          </body>
+        </html>
+        "#,
+    )?;
+    index.child("sniffio").child("index.html").write_str(
+        r#"
+        <!DOCTYPE html>
+        <html>
+          <body>
+            <a href="sniffio-1.3.1.tar.gz">sniffio-1.3.1.tar.gz</a>
+          </body>
+        </html>
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.search().arg("AnyIO").arg("--default-index").arg(index.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    anyio (4.0.0)
+    anyio-extra (1.0.0)
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.search().arg("anyio").arg("--limit").arg("1").arg("--default-index").arg(index.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    anyio (4.0.0)
+
+    ----- stderr -----
+    Showing 1 of 2 matching packages (use `--limit` to show more)
+    "###);
+
+    uv_snapshot!(context.filters(), context.search().arg("flask").arg("--default-index").arg(index.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No packages found matching `flask`
+    "###);
+
+    Ok(())
+}
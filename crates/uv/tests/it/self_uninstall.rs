@@ -0,0 +1,92 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::fixture::{FileTouch, FileWriteStr, PathChild};
+
+use uv_static::EnvVars;
+
+use crate::common::{get_bin, uv_snapshot, TestContext};
+
+#[test]
+#[cfg(not(windows))]
+fn self_uninstall() -> Result<()> {
+    let context = TestContext::new("3.12").with_filtered_counts();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+    let python_dir = context.temp_dir.child("python");
+
+    // Install `black`.
+    context
+        .tool_install()
+        .arg("black==24.2.0")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    // Add an executable that wasn't installed by uv.
+    bin_dir.child("other").touch()?;
+
+    // Add the executable directory to the `PATH`, as in `uv tool update-shell`.
+    let bashrc = context.home_dir.child(".bashrc");
+    bashrc.write_str(&format!(
+        "alias ll='ls -l'\n\n# uv\nexport PATH=\"{}:$PATH\"\n",
+        bin_dir.display()
+    ))?;
+
+    let uv = regex::escape(&get_bin().display().to_string());
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(uv.as_str(), "[UV]")])
+        .collect::<Vec<_>>();
+
+    // With `--dry-run`, nothing should be removed.
+    uv_snapshot!(filters, context.self_uninstall()
+        .arg("--dry-run")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::UV_PYTHON_INSTALL_DIR, python_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Would uninstall tool: black
+    Would remove executable: [TEMP_DIR]/bin/black
+    Would remove executable: [TEMP_DIR]/bin/blackd
+    Would update configuration file: [HOME]/.bashrc
+    Would remove cache: [CACHE_DIR]/
+    warning: uv was not installed via the standalone installation scripts, so the uv binary at [UV] was not removed.
+
+    If you installed uv with pip, brew, or another package manager, remove uv with `pip uninstall`, `brew uninstall`, or similar.
+    "###);
+
+    assert!(bin_dir.child("black").exists());
+    assert!(fs_err::read_to_string(&bashrc)?.contains("# uv"));
+
+    uv_snapshot!(filters, context.self_uninstall()
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::UV_PYTHON_INSTALL_DIR, python_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 2 executables: black, blackd
+    Updated configuration file: [HOME]/.bashrc
+    Clearing cache at: [CACHE_DIR]/
+    Removed [N] files ([SIZE])
+    warning: uv was not installed via the standalone installation scripts, so the uv binary at [UV] was not removed.
+
+    If you installed uv with pip, brew, or another package manager, remove uv with `pip uninstall`, `brew uninstall`, or similar.
+    "###);
+
+    // The tool executables and the `PATH` update should be removed, but unrelated files and
+    // configuration should be left untouched.
+    assert!(!bin_dir.child("black").exists());
+    assert!(bin_dir.child("other").exists());
+    assert_eq!(fs_err::read_to_string(&bashrc)?, "alias ll='ls -l'\n");
+
+    Ok(())
+}
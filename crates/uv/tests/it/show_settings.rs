@@ -46,10 +46,12 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
     GlobalSettings {
         quiet: false,
         verbose: 0,
+        log_format: Text,
         color: Auto,
         native_tls: false,
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: None,
             builds: 16,
             installs: 8,
         },
@@ -59,18 +61,32 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        git_ssh: {},
+        no_git_sparse_checkout: false,
+        required_version: None,
+        http_retry: RetrySettings {
+            retries: None,
+            backoff: None,
+            retry_status: None,
+            connect_timeout: None,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_namespace: None,
+        requirements_cache_ttl: None,
+        build_cache_url: None,
+        build_cache_upload: false,
     }
     PipCompileSettings {
         src_file: [
             "requirements.in",
         ],
         constraint: [],
+        constraint_from_lock: None,
         override: [],
         build_constraint: [],
         constraints_from_workspace: [],
@@ -78,6 +94,8 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -115,11 +133,19 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        proxy: None,
+                        no_proxy: [],
+                        ca_cert: None,
+                        client_cert: None,
+                        client_key: None,
+                        username: None,
+                        password: None,
                         origin: None,
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                find_links_recursive: false,
             },
             python: None,
             system: false,
@@ -141,9 +167,17 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
-            dependency_metadata: DependencyMetadata(
-                {},
-            ),
+            yanked: Warn,
+            allow_yanked: [],
+            prefer_local_version: None,
+            resolution_budget: ResolutionBudget {
+                max_steps: None,
+                timeout: None,
+            },
+            dependency_metadata: DependencyMetadata {
+                entries: {},
+                overrides: {},
+            },
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -154,6 +188,10 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            build_containers: BuildContainers {
+                default: None,
+                packages: {},
+            },
             python_version: None,
             python_platform: None,
             universal: false,
@@ -171,7 +209,13 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            bytecode_options: BytecodeOptions {
+                optimization: Unoptimized,
+                workers: None,
+                no_compile_package: [],
+            },
             sources: Enabled,
+            variants: [],
             hash_checking: None,
             upgrade: None,
             reinstall: None,
@@ -193,10 +237,12 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
     GlobalSettings {
         quiet: false,
         verbose: 0,
+        log_format: Text,
         color: Auto,
         native_tls: false,
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: None,
             builds: 16,
             installs: 8,
         },
@@ -206,18 +252,32 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        git_ssh: {},
+        no_git_sparse_checkout: false,
+        required_version: None,
+        http_retry: RetrySettings {
+            retries: None,
+            backoff: None,
+            retry_status: None,
+            connect_timeout: None,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_namespace: None,
+        requirements_cache_ttl: None,
+        build_cache_url: None,
+        build_cache_upload: false,
     }
     PipCompileSettings {
         src_file: [
             "requirements.in",
         ],
         constraint: [],
+        constraint_from_lock: None,
         override: [],
         build_constraint: [],
         constraints_from_workspace: [],
@@ -225,6 +285,8 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -262,11 +324,19 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        proxy: None,
+                        no_proxy: [],
+                        ca_cert: None,
+                        client_cert: None,
+                        client_key: None,
+                        username: None,
+                        password: None,
                         origin: None,
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                find_links_recursive: false,
             },
             python: None,
             system: false,
@@ -288,9 +358,17 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
-            dependency_metadata: DependencyMetadata(
-                {},
-            ),
+            yanked: Warn,
+            allow_yanked: [],
+            prefer_local_version: None,
+            resolution_budget: ResolutionBudget {
+                max_steps: None,
+                timeout: None,
+            },
+            dependency_metadata: DependencyMetadata {
+                entries: {},
+                overrides: {},
+            },
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -301,6 +379,10 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            build_containers: BuildContainers {
+                default: None,
+                packages: {},
+            },
             python_version: None,
             python_platform: None,
             universal: false,
@@ -318,7 +400,13 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            bytecode_options: BytecodeOptions {
+                optimization: Unoptimized,
+                workers: None,
+                no_compile_package: [],
+            },
             sources: Enabled,
+            variants: [],
             hash_checking: None,
             upgrade: None,
             reinstall: None,
@@ -341,10 +429,12 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
     GlobalSettings {
         quiet: false,
         verbose: 0,
+        log_format: Text,
         color: Auto,
         native_tls: false,
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: None,
             builds: 16,
             installs: 8,
         },
@@ -354,18 +444,32 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        git_ssh: {},
+        no_git_sparse_checkout: false,
+        required_version: None,
+        http_retry: RetrySettings {
+            retries: None,
+            backoff: None,
+            retry_status: None,
+            connect_timeout: None,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_namespace: None,
+        requirements_cache_ttl: None,
+        build_cache_url: None,
+        build_cache_upload: false,
     }
     PipCompileSettings {
         src_file: [
             "requirements.in",
         ],
         constraint: [],
+        constraint_from_lock: None,
         override: [],
         build_constraint: [],
         constraints_from_workspace: [],
@@ -373,6 +477,8 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -410,11 +516,19 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        proxy: None,
+                        no_proxy: [],
+                        ca_cert: None,
+                        client_cert: None,
+                        client_key: None,
+                        username: None,
+                        password: None,
                         origin: None,
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                find_links_recursive: false,
             },
             python: None,
             system: false,
@@ -436,9 +550,17 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
-            dependency_metadata: DependencyMetadata(
-                {},
-            ),
+            yanked: Warn,
+            allow_yanked: [],
+            prefer_local_version: None,
+            resolution_budget: ResolutionBudget {
+                max_steps: None,
+                timeout: None,
+            },
+            dependency_metadata: DependencyMetadata {
+                entries: {},
+                overrides: {},
+            },
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -449,6 +571,10 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            build_containers: BuildContainers {
+                default: None,
+                packages: {},
+            },
             python_version: None,
             python_platform: None,
             universal: false,
@@ -466,7 +592,13 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            bytecode_options: BytecodeOptions {
+                optimization: Unoptimized,
+                workers: None,
+                no_compile_package: [],
+            },
             sources: Enabled,
+            variants: [],
             hash_checking: None,
             upgrade: None,
             reinstall: None,
@@ -521,10 +653,12 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
     GlobalSettings {
         quiet: false,
         verbose: 0,
+        log_format: Text,
         color: Auto,
         native_tls: false,
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: None,
             builds: 16,
             installs: 8,
         },
@@ -534,18 +668,32 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        git_ssh: {},
+        no_git_sparse_checkout: false,
+        required_version: None,
+        http_retry: RetrySettings {
+            retries: None,
+            backoff: None,
+            retry_status: None,
+            connect_timeout: None,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_namespace: None,
+        requirements_cache_ttl: None,
+        build_cache_url: None,
+        build_cache_upload: false,
     }
     PipCompileSettings {
         src_file: [
             "requirements.in",
         ],
         constraint: [],
+        constraint_from_lock: None,
         override: [],
         build_constraint: [],
         constraints_from_workspace: [],
@@ -553,6 +701,8 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -590,11 +740,19 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        proxy: None,
+                        no_proxy: [],
+                        ca_cert: None,
+                        client_cert: None,
+                        client_key: None,
+                        username: None,
+                        password: None,
                         origin: None,
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                find_links_recursive: false,
             },
             python: None,
             system: false,
@@ -616,9 +774,17 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
-            dependency_metadata: DependencyMetadata(
-                {},
-            ),
+            yanked: Warn,
+            allow_yanked: [],
+            prefer_local_version: None,
+            resolution_budget: ResolutionBudget {
+                max_steps: None,
+                timeout: None,
+            },
+            dependency_metadata: DependencyMetadata {
+                entries: {},
+                overrides: {},
+            },
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -629,6 +795,10 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            build_containers: BuildContainers {
+                default: None,
+                packages: {},
+            },
             python_version: None,
             python_platform: None,
             universal: false,
@@ -646,7 +816,13 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            bytecode_options: BytecodeOptions {
+                optimization: Unoptimized,
+                workers: None,
+                no_compile_package: [],
+            },
             sources: Enabled,
+            variants: [],
             hash_checking: None,
             upgrade: None,
             reinstall: None,
@@ -670,10 +846,12 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
     GlobalSettings {
         quiet: false,
         verbose: 0,
+        log_format: Text,
         color: Auto,
         native_tls: false,
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: None,
             builds: 16,
             installs: 8,
         },
@@ -683,18 +861,32 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        git_ssh: {},
+        no_git_sparse_checkout: false,
+        required_version: None,
+        http_retry: RetrySettings {
+            retries: None,
+            backoff: None,
+            retry_status: None,
+            connect_timeout: None,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_namespace: None,
+        requirements_cache_ttl: None,
+        build_cache_url: None,
+        build_cache_upload: false,
     }
     PipCompileSettings {
         src_file: [
             "requirements.in",
         ],
         constraint: [],
+        constraint_from_lock: None,
         override: [],
         build_constraint: [],
         constraints_from_workspace: [],
@@ -702,6 +894,8 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -715,6 +909,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                find_links_recursive: false,
             },
             python: None,
             system: false,
@@ -736,9 +931,17 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
-            dependency_metadata: DependencyMetadata(
-                {},
-            ),
+            yanked: Warn,
+            allow_yanked: [],
+            prefer_local_version: None,
+            resolution_budget: ResolutionBudget {
+                max_steps: None,
+                timeout: None,
+            },
+            dependency_metadata: DependencyMetadata {
+                entries: {},
+                overrides: {},
+            },
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -749,6 +952,10 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            build_containers: BuildContainers {
+                default: None,
+                packages: {},
+            },
             python_version: None,
             python_platform: None,
             universal: false,
@@ -766,7 +973,13 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            bytecode_options: BytecodeOptions {
+                optimization: Unoptimized,
+                workers: None,
+                no_compile_package: [],
+            },
             sources: Enabled,
+            variants: [],
             hash_checking: None,
             upgrade: None,
             reinstall: None,
@@ -799,10 +1012,12 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
     GlobalSettings {
         quiet: false,
         verbose: 0,
+        log_format: Text,
         color: Auto,
         native_tls: false,
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: None,
             builds: 16,
             installs: 8,
         },
@@ -812,18 +1027,32 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        git_ssh: {},
+        no_git_sparse_checkout: false,
+        required_version: None,
+        http_retry: RetrySettings {
+            retries: None,
+            backoff: None,
+            retry_status: None,
+            connect_timeout: None,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_namespace: None,
+        requirements_cache_ttl: None,
+        build_cache_url: None,
+        build_cache_upload: false,
     }
     PipCompileSettings {
         src_file: [
             "requirements.in",
         ],
         constraint: [],
+        constraint_from_lock: None,
         override: [],
         build_constraint: [],
         constraints_from_workspace: [],
@@ -831,6 +1060,8 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -868,11 +1099,19 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        proxy: None,
+                        no_proxy: [],
+                        ca_cert: None,
+                        client_cert: None,
+                        client_key: None,
+                        username: None,
+                        password: None,
                         origin: None,
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                find_links_recursive: false,
             },
             python: None,
             system: false,
@@ -894,9 +1133,17 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
-            dependency_metadata: DependencyMetadata(
-                {},
-            ),
+            yanked: Warn,
+            allow_yanked: [],
+            prefer_local_version: None,
+            resolution_budget: ResolutionBudget {
+                max_steps: None,
+                timeout: None,
+            },
+            dependency_metadata: DependencyMetadata {
+                entries: {},
+                overrides: {},
+            },
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -907,6 +1154,10 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            build_containers: BuildContainers {
+                default: None,
+                packages: {},
+            },
             python_version: None,
             python_platform: None,
             universal: false,
@@ -924,7 +1175,13 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            bytecode_options: BytecodeOptions {
+                optimization: Unoptimized,
+                workers: None,
+                no_compile_package: [],
+            },
             sources: Enabled,
+            variants: [],
             hash_checking: None,
             upgrade: None,
             reinstall: None,
@@ -971,10 +1228,12 @@ fn resolve_index_url() -> anyhow::Result<()> {
     GlobalSettings {
         quiet: false,
         verbose: 0,
+        log_format: Text,
         color: Auto,
         native_tls: false,
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: None,
             builds: 16,
             installs: 8,
         },
@@ -984,18 +1243,32 @@ fn resolve_index_url() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        git_ssh: {},
+        no_git_sparse_checkout: false,
+        required_version: None,
+        http_retry: RetrySettings {
+            retries: None,
+            backoff: None,
+            retry_status: None,
+            connect_timeout: None,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_namespace: None,
+        requirements_cache_ttl: None,
+        build_cache_url: None,
+        build_cache_upload: false,
     }
     PipCompileSettings {
         src_file: [
             "requirements.in",
         ],
         constraint: [],
+        constraint_from_lock: None,
         override: [],
         build_constraint: [],
         constraints_from_workspace: [],
@@ -1003,6 +1276,8 @@ fn resolve_index_url() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1040,6 +1315,13 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        proxy: None,
+                        no_proxy: [],
+                        ca_cert: None,
+                        client_cert: None,
+                        client_key: None,
+                        username: None,
+                        password: None,
                         origin: None,
                     },
                     Index {
@@ -1068,11 +1350,19 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        proxy: None,
+                        no_proxy: [],
+                        ca_cert: None,
+                        client_cert: None,
+                        client_key: None,
+                        username: None,
+                        password: None,
                         origin: None,
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                find_links_recursive: false,
             },
             python: None,
             system: false,
@@ -1094,9 +1384,17 @@ fn resolve_index_url() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
-            dependency_metadata: DependencyMetadata(
-                {},
-            ),
+            yanked: Warn,
+            allow_yanked: [],
+            prefer_local_version: None,
+            resolution_budget: ResolutionBudget {
+                max_steps: None,
+                timeout: None,
+            },
+            dependency_metadata: DependencyMetadata {
+                entries: {},
+                overrides: {},
+            },
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -1107,6 +1405,10 @@ fn resolve_index_url() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            build_containers: BuildContainers {
+                default: None,
+                packages: {},
+            },
             python_version: None,
             python_platform: None,
             universal: false,
@@ -1124,7 +1426,13 @@ fn resolve_index_url() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            bytecode_options: BytecodeOptions {
+                optimization: Unoptimized,
+                workers: None,
+                no_compile_package: [],
+            },
             sources: Enabled,
+            variants: [],
             hash_checking: None,
             upgrade: None,
             reinstall: None,
@@ -1148,10 +1456,12 @@ fn resolve_index_url() -> anyhow::Result<()> {
     GlobalSettings {
         quiet: false,
         verbose: 0,
+        log_format: Text,
         color: Auto,
         native_tls: false,
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: None,
             builds: 16,
             installs: 8,
         },
@@ -1161,18 +1471,32 @@ fn resolve_index_url() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        git_ssh: {},
+        no_git_sparse_checkout: false,
+        required_version: None,
+        http_retry: RetrySettings {
+            retries: None,
+            backoff: None,
+            retry_status: None,
+            connect_timeout: None,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_namespace: None,
+        requirements_cache_ttl: None,
+        build_cache_url: None,
+        build_cache_upload: false,
     }
     PipCompileSettings {
         src_file: [
             "requirements.in",
         ],
         constraint: [],
+        constraint_from_lock: None,
         override: [],
         build_constraint: [],
         constraints_from_workspace: [],
@@ -1180,6 +1504,8 @@ fn resolve_index_url() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1217,6 +1543,13 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        proxy: None,
+                        no_proxy: [],
+                        ca_cert: None,
+                        client_cert: None,
+                        client_key: None,
+                        username: None,
+                        password: None,
                         origin: Some(
                             Cli,
                         ),
@@ -1247,6 +1580,13 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        proxy: None,
+                        no_proxy: [],
+                        ca_cert: None,
+                        client_cert: None,
+                        client_key: None,
+                        username: None,
+                        password: None,
                         origin: None,
                     },
                     Index {
@@ -1275,11 +1615,19 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        proxy: None,
+                        no_proxy: [],
+                        ca_cert: None,
+                        client_cert: None,
+                        client_key: None,
+                        username: None,
+                        password: None,
                         origin: None,
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                find_links_recursive: false,
             },
             python: None,
             system: false,
@@ -1301,9 +1649,17 @@ fn resolve_index_url() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
-            dependency_metadata: DependencyMetadata(
-                {},
-            ),
+            yanked: Warn,
+            allow_yanked: [],
+            prefer_local_version: None,
+            resolution_budget: ResolutionBudget {
+                max_steps: None,
+                timeout: None,
+            },
+            dependency_metadata: DependencyMetadata {
+                entries: {},
+                overrides: {},
+            },
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -1314,6 +1670,10 @@ fn resolve_index_url() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            build_containers: BuildContainers {
+                default: None,
+                packages: {},
+            },
             python_version: None,
             python_platform: None,
             universal: false,
@@ -1331,7 +1691,13 @@ fn resolve_index_url() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            bytecode_options: BytecodeOptions {
+                optimization: Unoptimized,
+                workers: None,
+                no_compile_package: [],
+            },
             sources: Enabled,
+            variants: [],
             hash_checking: None,
             upgrade: None,
             reinstall: None,
@@ -1378,10 +1744,12 @@ fn resolve_find_links() -> anyhow::Result<()> {
     GlobalSettings {
         quiet: false,
         verbose: 0,
+        log_format: Text,
         color: Auto,
         native_tls: false,
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: None,
             builds: 16,
             installs: 8,
         },
@@ -1391,18 +1759,32 @@ fn resolve_find_links() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        git_ssh: {},
+        no_git_sparse_checkout: false,
+        required_version: None,
+        http_retry: RetrySettings {
+            retries: None,
+            backoff: None,
+            retry_status: None,
+            connect_timeout: None,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_namespace: None,
+        requirements_cache_ttl: None,
+        build_cache_url: None,
+        build_cache_upload: false,
     }
     PipCompileSettings {
         src_file: [
             "requirements.in",
         ],
         constraint: [],
+        constraint_from_lock: None,
         override: [],
         build_constraint: [],
         constraints_from_workspace: [],
@@ -1410,6 +1792,8 @@ fn resolve_find_links() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1448,10 +1832,18 @@ fn resolve_find_links() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        proxy: None,
+                        no_proxy: [],
+                        ca_cert: None,
+                        client_cert: None,
+                        client_key: None,
+                        username: None,
+                        password: None,
                         origin: None,
                     },
                 ],
                 no_index: true,
+                find_links_recursive: false,
             },
             python: None,
             system: false,
@@ -1473,9 +1865,17 @@ fn resolve_find_links() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
-            dependency_metadata: DependencyMetadata(
-                {},
-            ),
+            yanked: Warn,
+            allow_yanked: [],
+            prefer_local_version: None,
+            resolution_budget: ResolutionBudget {
+                max_steps: None,
+                timeout: None,
+            },
+            dependency_metadata: DependencyMetadata {
+                entries: {},
+                overrides: {},
+            },
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -1486,6 +1886,10 @@ fn resolve_find_links() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            build_containers: BuildContainers {
+                default: None,
+                packages: {},
+            },
             python_version: None,
             python_platform: None,
             universal: false,
@@ -1503,7 +1907,13 @@ fn resolve_find_links() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            bytecode_options: BytecodeOptions {
+                optimization: Unoptimized,
+                workers: None,
+                no_compile_package: [],
+            },
             sources: Enabled,
+            variants: [],
             hash_checking: None,
             upgrade: None,
             reinstall: None,
@@ -1549,10 +1959,12 @@ fn resolve_top_level() -> anyhow::Result<()> {
     GlobalSettings {
         quiet: false,
         verbose: 0,
+        log_format: Text,
         color: Auto,
         native_tls: false,
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: None,
             builds: 16,
             installs: 8,
         },
@@ -1562,18 +1974,32 @@ fn resolve_top_level() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        git_ssh: {},
+        no_git_sparse_checkout: false,
+        required_version: None,
+        http_retry: RetrySettings {
+            retries: None,
+            backoff: None,
+            retry_status: None,
+            connect_timeout: None,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_namespace: None,
+        requirements_cache_ttl: None,
+        build_cache_url: None,
+        build_cache_upload: false,
     }
     PipCompileSettings {
         src_file: [
             "requirements.in",
         ],
         constraint: [],
+        constraint_from_lock: None,
         override: [],
         build_constraint: [],
         constraints_from_workspace: [],
@@ -1581,6 +2007,8 @@ fn resolve_top_level() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1594,6 +2022,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                find_links_recursive: false,
             },
             python: None,
             system: false,
@@ -1615,9 +2044,17 @@ fn resolve_top_level() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
-            dependency_metadata: DependencyMetadata(
-                {},
-            ),
+            yanked: Warn,
+            allow_yanked: [],
+            prefer_local_version: None,
+            resolution_budget: ResolutionBudget {
+                max_steps: None,
+                timeout: None,
+            },
+            dependency_metadata: DependencyMetadata {
+                entries: {},
+                overrides: {},
+            },
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -1628,6 +2065,10 @@ fn resolve_top_level() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            build_containers: BuildContainers {
+                default: None,
+                packages: {},
+            },
             python_version: None,
             python_platform: None,
             universal: false,
@@ -1645,7 +2086,13 @@ fn resolve_top_level() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            bytecode_options: BytecodeOptions {
+                optimization: Unoptimized,
+                workers: None,
+                no_compile_package: [],
+            },
             sources: Enabled,
+            variants: [],
             hash_checking: None,
             upgrade: None,
             reinstall: None,
@@ -1684,10 +2131,12 @@ fn resolve_top_level() -> anyhow::Result<()> {
     GlobalSettings {
         quiet: false,
         verbose: 0,
+        log_format: Text,
         color: Auto,
         native_tls: false,
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: None,
             builds: 16,
             installs: 8,
         },
@@ -1697,18 +2146,32 @@ fn resolve_top_level() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        git_ssh: {},
+        no_git_sparse_checkout: false,
+        required_version: None,
+        http_retry: RetrySettings {
+            retries: None,
+            backoff: None,
+            retry_status: None,
+            connect_timeout: None,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_namespace: None,
+        requirements_cache_ttl: None,
+        build_cache_url: None,
+        build_cache_upload: false,
     }
     PipCompileSettings {
         src_file: [
             "requirements.in",
         ],
         constraint: [],
+        constraint_from_lock: None,
         override: [],
         build_constraint: [],
         constraints_from_workspace: [],
@@ -1716,6 +2179,8 @@ fn resolve_top_level() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1753,6 +2218,13 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        proxy: None,
+                        no_proxy: [],
+                        ca_cert: None,
+                        client_cert: None,
+                        client_key: None,
+                        username: None,
+                        password: None,
                         origin: None,
                     },
                     Index {
@@ -1781,11 +2253,19 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        proxy: None,
+                        no_proxy: [],
+                        ca_cert: None,
+                        client_cert: None,
+                        client_key: None,
+                        username: None,
+                        password: None,
                         origin: None,
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                find_links_recursive: false,
             },
             python: None,
             system: false,
@@ -1807,9 +2287,17 @@ fn resolve_top_level() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
-            dependency_metadata: DependencyMetadata(
-                {},
-            ),
+            yanked: Warn,
+            allow_yanked: [],
+            prefer_local_version: None,
+            resolution_budget: ResolutionBudget {
+                max_steps: None,
+                timeout: None,
+            },
+            dependency_metadata: DependencyMetadata {
+                entries: {},
+                overrides: {},
+            },
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -1820,6 +2308,10 @@ fn resolve_top_level() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            build_containers: BuildContainers {
+                default: None,
+                packages: {},
+            },
             python_version: None,
             python_platform: None,
             universal: false,
@@ -1837,7 +2329,13 @@ fn resolve_top_level() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            bytecode_options: BytecodeOptions {
+                optimization: Unoptimized,
+                workers: None,
+                no_compile_package: [],
+            },
             sources: Enabled,
+            variants: [],
             hash_checking: None,
             upgrade: None,
             reinstall: None,
@@ -1859,10 +2357,12 @@ fn resolve_top_level() -> anyhow::Result<()> {
     GlobalSettings {
         quiet: false,
         verbose: 0,
+        log_format: Text,
         color: Auto,
         native_tls: false,
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: None,
             builds: 16,
             installs: 8,
         },
@@ -1872,18 +2372,32 @@ fn resolve_top_level() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        git_ssh: {},
+        no_git_sparse_checkout: false,
+        required_version: None,
+        http_retry: RetrySettings {
+            retries: None,
+            backoff: None,
+            retry_status: None,
+            connect_timeout: None,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_namespace: None,
+        requirements_cache_ttl: None,
+        build_cache_url: None,
+        build_cache_upload: false,
     }
     PipCompileSettings {
         src_file: [
             "requirements.in",
         ],
         constraint: [],
+        constraint_from_lock: None,
         override: [],
         build_constraint: [],
         constraints_from_workspace: [],
@@ -1891,6 +2405,8 @@ fn resolve_top_level() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1928,6 +2444,13 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        proxy: None,
+                        no_proxy: [],
+                        ca_cert: None,
+                        client_cert: None,
+                        client_key: None,
+                        username: None,
+                        password: None,
                         origin: None,
                     },
                     Index {
@@ -1956,11 +2479,19 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        proxy: None,
+                        no_proxy: [],
+                        ca_cert: None,
+                        client_cert: None,
+                        client_key: None,
+                        username: None,
+                        password: None,
                         origin: None,
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                find_links_recursive: false,
             },
             python: None,
             system: false,
@@ -1982,9 +2513,17 @@ fn resolve_top_level() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
-            dependency_metadata: DependencyMetadata(
-                {},
-            ),
+            yanked: Warn,
+            allow_yanked: [],
+            prefer_local_version: None,
+            resolution_budget: ResolutionBudget {
+                max_steps: None,
+                timeout: None,
+            },
+            dependency_metadata: DependencyMetadata {
+                entries: {},
+                overrides: {},
+            },
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -1995,6 +2534,10 @@ fn resolve_top_level() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            build_containers: BuildContainers {
+                default: None,
+                packages: {},
+            },
             python_version: None,
             python_platform: None,
             universal: false,
@@ -2012,7 +2555,13 @@ fn resolve_top_level() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            bytecode_options: BytecodeOptions {
+                optimization: Unoptimized,
+                workers: None,
+                no_compile_package: [],
+            },
             sources: Enabled,
+            variants: [],
             hash_checking: None,
             upgrade: None,
             reinstall: None,
@@ -2058,10 +2607,12 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
     GlobalSettings {
         quiet: false,
         verbose: 0,
+        log_format: Text,
         color: Auto,
         native_tls: false,
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: None,
             builds: 16,
             installs: 8,
         },
@@ -2071,18 +2622,32 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        git_ssh: {},
+        no_git_sparse_checkout: false,
+        required_version: None,
+        http_retry: RetrySettings {
+            retries: None,
+            backoff: None,
+            retry_status: None,
+            connect_timeout: None,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_namespace: None,
+        requirements_cache_ttl: None,
+        build_cache_url: None,
+        build_cache_upload: false,
     }
     PipCompileSettings {
         src_file: [
             "requirements.in",
         ],
         constraint: [],
+        constraint_from_lock: None,
         override: [],
         build_constraint: [],
         constraints_from_workspace: [],
@@ -2090,6 +2655,8 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2103,6 +2670,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                find_links_recursive: false,
             },
             python: None,
             system: false,
@@ -2124,9 +2692,17 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
-            dependency_metadata: DependencyMetadata(
-                {},
-            ),
+            yanked: Warn,
+            allow_yanked: [],
+            prefer_local_version: None,
+            resolution_budget: ResolutionBudget {
+                max_steps: None,
+                timeout: None,
+            },
+            dependency_metadata: DependencyMetadata {
+                entries: {},
+                overrides: {},
+            },
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -2137,6 +2713,10 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            build_containers: BuildContainers {
+                default: None,
+                packages: {},
+            },
             python_version: None,
             python_platform: None,
             universal: false,
@@ -2154,7 +2734,13 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            bytecode_options: BytecodeOptions {
+                optimization: Unoptimized,
+                workers: None,
+                no_compile_package: [],
+            },
             sources: Enabled,
+            variants: [],
             hash_checking: None,
             upgrade: None,
             reinstall: None,
@@ -2183,10 +2769,12 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
     GlobalSettings {
         quiet: false,
         verbose: 0,
+        log_format: Text,
         color: Auto,
         native_tls: false,
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: None,
             builds: 16,
             installs: 8,
         },
@@ -2196,18 +2784,32 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        git_ssh: {},
+        no_git_sparse_checkout: false,
+        required_version: None,
+        http_retry: RetrySettings {
+            retries: None,
+            backoff: None,
+            retry_status: None,
+            connect_timeout: None,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_namespace: None,
+        requirements_cache_ttl: None,
+        build_cache_url: None,
+        build_cache_upload: false,
     }
     PipCompileSettings {
         src_file: [
             "requirements.in",
         ],
         constraint: [],
+        constraint_from_lock: None,
         override: [],
         build_constraint: [],
         constraints_from_workspace: [],
@@ -2215,6 +2817,8 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2228,6 +2832,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                find_links_recursive: false,
             },
             python: None,
             system: false,
@@ -2249,9 +2854,17 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
-            dependency_metadata: DependencyMetadata(
-                {},
-            ),
+            yanked: Warn,
+            allow_yanked: [],
+            prefer_local_version: None,
+            resolution_budget: ResolutionBudget {
+                max_steps: None,
+                timeout: None,
+            },
+            dependency_metadata: DependencyMetadata {
+                entries: {},
+                overrides: {},
+            },
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -2262,6 +2875,10 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            build_containers: BuildContainers {
+                default: None,
+                packages: {},
+            },
             python_version: None,
             python_platform: None,
             universal: false,
@@ -2279,7 +2896,13 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            bytecode_options: BytecodeOptions {
+                optimization: Unoptimized,
+                workers: None,
+                no_compile_package: [],
+            },
             sources: Enabled,
+            variants: [],
             hash_checking: None,
             upgrade: None,
             reinstall: None,
@@ -2308,10 +2931,12 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
     GlobalSettings {
         quiet: false,
         verbose: 0,
+        log_format: Text,
         color: Auto,
         native_tls: false,
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: None,
             builds: 16,
             installs: 8,
         },
@@ -2321,18 +2946,32 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        git_ssh: {},
+        no_git_sparse_checkout: false,
+        required_version: None,
+        http_retry: RetrySettings {
+            retries: None,
+            backoff: None,
+            retry_status: None,
+            connect_timeout: None,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_namespace: None,
+        requirements_cache_ttl: None,
+        build_cache_url: None,
+        build_cache_upload: false,
     }
     PipCompileSettings {
         src_file: [
             "requirements.in",
         ],
         constraint: [],
+        constraint_from_lock: None,
         override: [],
         build_constraint: [],
         constraints_from_workspace: [],
@@ -2340,6 +2979,8 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2353,6 +2994,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                find_links_recursive: false,
             },
             python: None,
             system: false,
@@ -2374,9 +3016,17 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
-            dependency_metadata: DependencyMetadata(
-                {},
-            ),
+            yanked: Warn,
+            allow_yanked: [],
+            prefer_local_version: None,
+            resolution_budget: ResolutionBudget {
+                max_steps: None,
+                timeout: None,
+            },
+            dependency_metadata: DependencyMetadata {
+                entries: {},
+                overrides: {},
+            },
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -2387,6 +3037,10 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            build_containers: BuildContainers {
+                default: None,
+                packages: {},
+            },
             python_version: None,
             python_platform: None,
             universal: false,
@@ -2404,7 +3058,13 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            bytecode_options: BytecodeOptions {
+                optimization: Unoptimized,
+                workers: None,
+                no_compile_package: [],
+            },
             sources: Enabled,
+            variants: [],
             hash_checking: None,
             upgrade: None,
             reinstall: None,
@@ -2435,10 +3095,12 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
     GlobalSettings {
         quiet: false,
         verbose: 0,
+        log_format: Text,
         color: Auto,
         native_tls: false,
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: None,
             builds: 16,
             installs: 8,
         },
@@ -2448,18 +3110,32 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        git_ssh: {},
+        no_git_sparse_checkout: false,
+        required_version: None,
+        http_retry: RetrySettings {
+            retries: None,
+            backoff: None,
+            retry_status: None,
+            connect_timeout: None,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_namespace: None,
+        requirements_cache_ttl: None,
+        build_cache_url: None,
+        build_cache_upload: false,
     }
     PipCompileSettings {
         src_file: [
             "requirements.in",
         ],
         constraint: [],
+        constraint_from_lock: None,
         override: [],
         build_constraint: [],
         constraints_from_workspace: [],
@@ -2467,6 +3143,8 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2480,6 +3158,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                find_links_recursive: false,
             },
             python: None,
             system: false,
@@ -2501,9 +3180,17 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
-            dependency_metadata: DependencyMetadata(
-                {},
-            ),
+            yanked: Warn,
+            allow_yanked: [],
+            prefer_local_version: None,
+            resolution_budget: ResolutionBudget {
+                max_steps: None,
+                timeout: None,
+            },
+            dependency_metadata: DependencyMetadata {
+                entries: {},
+                overrides: {},
+            },
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -2514,6 +3201,10 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            build_containers: BuildContainers {
+                default: None,
+                packages: {},
+            },
             python_version: None,
             python_platform: None,
             universal: false,
@@ -2531,7 +3222,13 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            bytecode_options: BytecodeOptions {
+                optimization: Unoptimized,
+                workers: None,
+                no_compile_package: [],
+            },
             sources: Enabled,
+            variants: [],
             hash_checking: None,
             upgrade: None,
             reinstall: None,
@@ -2581,10 +3278,12 @@ fn resolve_tool() -> anyhow::Result<()> {
     GlobalSettings {
         quiet: false,
         verbose: 0,
+        log_format: Text,
         color: Auto,
         native_tls: false,
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: None,
             builds: 16,
             installs: 8,
         },
@@ -2594,12 +3293,25 @@ fn resolve_tool() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        git_ssh: {},
+        no_git_sparse_checkout: false,
+        required_version: None,
+        http_retry: RetrySettings {
+            retries: None,
+            backoff: None,
+            retry_status: None,
+            connect_timeout: None,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_namespace: None,
+        requirements_cache_ttl: None,
+        build_cache_url: None,
+        build_cache_upload: false,
     }
     ToolInstallSettings {
         package: "requirements.in",
@@ -2622,6 +3334,7 @@ fn resolve_tool() -> anyhow::Result<()> {
             extra_index_url: None,
             no_index: None,
             find_links: None,
+            find_links_recursive: None,
             index_strategy: None,
             keyring_provider: None,
             allow_insecure_host: None,
@@ -2629,8 +3342,16 @@ fn resolve_tool() -> anyhow::Result<()> {
                 LowestDirect,
             ),
             prerelease: None,
+            yanked: None,
+            allow_yanked: None,
+            prefer_local_version: None,
+            resolution_timeout: None,
+            resolution_max_steps: None,
             dependency_metadata: None,
+            dependency_overrides: None,
             config_settings: None,
+            build_container: None,
+            build_container_package: None,
             no_build_isolation: None,
             no_build_isolation_package: None,
             exclude_newer: Some(
@@ -2642,6 +3363,9 @@ fn resolve_tool() -> anyhow::Result<()> {
                 Clone,
             ),
             compile_bytecode: None,
+            compile_bytecode_optimization: None,
+            compile_bytecode_workers: None,
+            no_compile_bytecode_package: None,
             no_sources: None,
             upgrade: None,
             upgrade_package: None,
@@ -2657,18 +3381,31 @@ fn resolve_tool() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                find_links_recursive: false,
             },
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             allow_insecure_host: [],
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
-            dependency_metadata: DependencyMetadata(
-                {},
-            ),
+            yanked: Warn,
+            allow_yanked: [],
+            prefer_local_version: None,
+            resolution_budget: ResolutionBudget {
+                max_steps: None,
+                timeout: None,
+            },
+            dependency_metadata: DependencyMetadata {
+                entries: {},
+                overrides: {},
+            },
             config_setting: ConfigSettings(
                 {},
             ),
+            build_containers: BuildContainers {
+                default: None,
+                packages: {},
+            },
             no_build_isolation: false,
             no_build_isolation_package: [],
             exclude_newer: Some(
@@ -2678,16 +3415,30 @@ fn resolve_tool() -> anyhow::Result<()> {
             ),
             link_mode: Clone,
             compile_bytecode: false,
+            bytecode_options: BytecodeOptions {
+                optimization: Unoptimized,
+                workers: None,
+                no_compile_package: [],
+            },
             sources: Enabled,
+            variants: [],
             upgrade: None,
             reinstall: None,
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
             },
+            minimal_changes: false,
         },
         force: false,
         editable: false,
+        frozen: false,
+        install_completions: false,
+        launcher: LauncherSettings {
+            product_name: None,
+            version: None,
+            icon: None,
+        },
     }
 
     ----- stderr -----
@@ -2739,10 +3490,12 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
     GlobalSettings {
         quiet: false,
         verbose: 0,
+        log_format: Text,
         color: Auto,
         native_tls: false,
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: None,
             builds: 16,
             installs: 8,
         },
@@ -2752,18 +3505,32 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        git_ssh: {},
+        no_git_sparse_checkout: false,
+        required_version: None,
+        http_retry: RetrySettings {
+            retries: None,
+            backoff: None,
+            retry_status: None,
+            connect_timeout: None,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_namespace: None,
+        requirements_cache_ttl: None,
+        build_cache_url: None,
+        build_cache_upload: false,
     }
     PipCompileSettings {
         src_file: [
             "requirements.in",
         ],
         constraint: [],
+        constraint_from_lock: None,
         override: [],
         build_constraint: [],
         constraints_from_workspace: [],
@@ -2771,6 +3538,8 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2784,6 +3553,7 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                find_links_recursive: false,
             },
             python: None,
             system: false,
@@ -2805,9 +3575,17 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
-            dependency_metadata: DependencyMetadata(
-                {},
-            ),
+            yanked: Warn,
+            allow_yanked: [],
+            prefer_local_version: None,
+            resolution_budget: ResolutionBudget {
+                max_steps: None,
+                timeout: None,
+            },
+            dependency_metadata: DependencyMetadata {
+                entries: {},
+                overrides: {},
+            },
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -2818,6 +3596,10 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            build_containers: BuildContainers {
+                default: None,
+                packages: {},
+            },
             python_version: None,
             python_platform: None,
             universal: false,
@@ -2835,7 +3617,13 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            bytecode_options: BytecodeOptions {
+                optimization: Unoptimized,
+                workers: None,
+                no_compile_package: [],
+            },
             sources: Enabled,
+            variants: [],
             hash_checking: None,
             upgrade: None,
             reinstall: None,
@@ -2892,10 +3680,12 @@ fn resolve_both() -> anyhow::Result<()> {
     GlobalSettings {
         quiet: false,
         verbose: 0,
+        log_format: Text,
         color: Auto,
         native_tls: false,
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: None,
             builds: 16,
             installs: 8,
         },
@@ -2905,18 +3695,32 @@ fn resolve_both() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        git_ssh: {},
+        no_git_sparse_checkout: false,
+        required_version: None,
+        http_retry: RetrySettings {
+            retries: None,
+            backoff: None,
+            retry_status: None,
+            connect_timeout: None,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_namespace: None,
+        requirements_cache_ttl: None,
+        build_cache_url: None,
+        build_cache_upload: false,
     }
     PipCompileSettings {
         src_file: [
             "requirements.in",
         ],
         constraint: [],
+        constraint_from_lock: None,
         override: [],
         build_constraint: [],
         constraints_from_workspace: [],
@@ -2924,6 +3728,8 @@ fn resolve_both() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2961,11 +3767,19 @@ fn resolve_both() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        proxy: None,
+                        no_proxy: [],
+                        ca_cert: None,
+                        client_cert: None,
+                        client_key: None,
+                        username: None,
+                        password: None,
                         origin: None,
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                find_links_recursive: false,
             },
             python: None,
             system: false,
@@ -2987,9 +3801,17 @@ fn resolve_both() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
-            dependency_metadata: DependencyMetadata(
-                {},
-            ),
+            yanked: Warn,
+            allow_yanked: [],
+            prefer_local_version: None,
+            resolution_budget: ResolutionBudget {
+                max_steps: None,
+                timeout: None,
+            },
+            dependency_metadata: DependencyMetadata {
+                entries: {},
+                overrides: {},
+            },
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -3000,6 +3822,10 @@ fn resolve_both() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            build_containers: BuildContainers {
+                default: None,
+                packages: {},
+            },
             python_version: None,
             python_platform: None,
             universal: false,
@@ -3017,7 +3843,13 @@ fn resolve_both() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            bytecode_options: BytecodeOptions {
+                optimization: Unoptimized,
+                workers: None,
+                no_compile_package: [],
+            },
             sources: Enabled,
+            variants: [],
             hash_checking: None,
             upgrade: None,
             reinstall: None,
@@ -3066,10 +3898,12 @@ fn resolve_config_file() -> anyhow::Result<()> {
     GlobalSettings {
         quiet: false,
         verbose: 0,
+        log_format: Text,
         color: Auto,
         native_tls: false,
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: None,
             builds: 16,
             installs: 8,
         },
@@ -3079,18 +3913,32 @@ fn resolve_config_file() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        git_ssh: {},
+        no_git_sparse_checkout: false,
+        required_version: None,
+        http_retry: RetrySettings {
+            retries: None,
+            backoff: None,
+            retry_status: None,
+            connect_timeout: None,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_namespace: None,
+        requirements_cache_ttl: None,
+        build_cache_url: None,
+        build_cache_upload: false,
     }
     PipCompileSettings {
         src_file: [
             "requirements.in",
         ],
         constraint: [],
+        constraint_from_lock: None,
         override: [],
         build_constraint: [],
         constraints_from_workspace: [],
@@ -3098,6 +3946,8 @@ fn resolve_config_file() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3135,11 +3985,19 @@ fn resolve_config_file() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        proxy: None,
+                        no_proxy: [],
+                        ca_cert: None,
+                        client_cert: None,
+                        client_key: None,
+                        username: None,
+                        password: None,
                         origin: None,
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                find_links_recursive: false,
             },
             python: None,
             system: false,
@@ -3161,9 +4019,17 @@ fn resolve_config_file() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
-            dependency_metadata: DependencyMetadata(
-                {},
-            ),
+            yanked: Warn,
+            allow_yanked: [],
+            prefer_local_version: None,
+            resolution_budget: ResolutionBudget {
+                max_steps: None,
+                timeout: None,
+            },
+            dependency_metadata: DependencyMetadata {
+                entries: {},
+                overrides: {},
+            },
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -3174,6 +4040,10 @@ fn resolve_config_file() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            build_containers: BuildContainers {
+                default: None,
+                packages: {},
+            },
             python_version: None,
             python_platform: None,
             universal: false,
@@ -3191,7 +4061,13 @@ fn resolve_config_file() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            bytecode_options: BytecodeOptions {
+                optimization: Unoptimized,
+                workers: None,
+                no_compile_package: [],
+            },
             sources: Enabled,
+            variants: [],
             hash_checking: None,
             upgrade: None,
             reinstall: None,
@@ -3314,10 +4190,12 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
     GlobalSettings {
         quiet: false,
         verbose: 0,
+        log_format: Text,
         color: Auto,
         native_tls: false,
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: None,
             builds: 16,
             installs: 8,
         },
@@ -3327,18 +4205,32 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        git_ssh: {},
+        no_git_sparse_checkout: false,
+        required_version: None,
+        http_retry: RetrySettings {
+            retries: None,
+            backoff: None,
+            retry_status: None,
+            connect_timeout: None,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_namespace: None,
+        requirements_cache_ttl: None,
+        build_cache_url: None,
+        build_cache_upload: false,
     }
     PipCompileSettings {
         src_file: [
             "requirements.in",
         ],
         constraint: [],
+        constraint_from_lock: None,
         override: [],
         build_constraint: [],
         constraints_from_workspace: [],
@@ -3346,6 +4238,8 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3359,6 +4253,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                find_links_recursive: false,
             },
             python: None,
             system: false,
@@ -3380,9 +4275,17 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
-            dependency_metadata: DependencyMetadata(
-                {},
-            ),
+            yanked: Warn,
+            allow_yanked: [],
+            prefer_local_version: None,
+            resolution_budget: ResolutionBudget {
+                max_steps: None,
+                timeout: None,
+            },
+            dependency_metadata: DependencyMetadata {
+                entries: {},
+                overrides: {},
+            },
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -3393,6 +4296,10 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            build_containers: BuildContainers {
+                default: None,
+                packages: {},
+            },
             python_version: None,
             python_platform: None,
             universal: false,
@@ -3410,7 +4317,13 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            bytecode_options: BytecodeOptions {
+                optimization: Unoptimized,
+                workers: None,
+                no_compile_package: [],
+            },
             sources: Enabled,
+            variants: [],
             hash_checking: None,
             upgrade: None,
             reinstall: None,
@@ -3442,10 +4355,12 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
     GlobalSettings {
         quiet: false,
         verbose: 0,
+        log_format: Text,
         color: Auto,
         native_tls: false,
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: None,
             builds: 16,
             installs: 8,
         },
@@ -3455,18 +4370,32 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        git_ssh: {},
+        no_git_sparse_checkout: false,
+        required_version: None,
+        http_retry: RetrySettings {
+            retries: None,
+            backoff: None,
+            retry_status: None,
+            connect_timeout: None,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_namespace: None,
+        requirements_cache_ttl: None,
+        build_cache_url: None,
+        build_cache_upload: false,
     }
     PipCompileSettings {
         src_file: [
             "requirements.in",
         ],
         constraint: [],
+        constraint_from_lock: None,
         override: [],
         build_constraint: [],
         constraints_from_workspace: [],
@@ -3474,6 +4403,8 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3487,6 +4418,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                find_links_recursive: false,
             },
             python: None,
             system: false,
@@ -3508,9 +4440,17 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
-            dependency_metadata: DependencyMetadata(
-                {},
-            ),
+            yanked: Warn,
+            allow_yanked: [],
+            prefer_local_version: None,
+            resolution_budget: ResolutionBudget {
+                max_steps: None,
+                timeout: None,
+            },
+            dependency_metadata: DependencyMetadata {
+                entries: {},
+                overrides: {},
+            },
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -3521,6 +4461,10 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            build_containers: BuildContainers {
+                default: None,
+                packages: {},
+            },
             python_version: None,
             python_platform: None,
             universal: false,
@@ -3538,7 +4482,13 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            bytecode_options: BytecodeOptions {
+                optimization: Unoptimized,
+                workers: None,
+                no_compile_package: [],
+            },
             sources: Enabled,
+            variants: [],
             hash_checking: None,
             upgrade: None,
             reinstall: None,
@@ -3578,10 +4528,12 @@ fn allow_insecure_host() -> anyhow::Result<()> {
     GlobalSettings {
         quiet: false,
         verbose: 0,
+        log_format: Text,
         color: Auto,
         native_tls: false,
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: None,
             builds: 16,
             installs: 8,
         },
@@ -3591,18 +4543,32 @@ fn allow_insecure_host() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        git_ssh: {},
+        no_git_sparse_checkout: false,
+        required_version: None,
+        http_retry: RetrySettings {
+            retries: None,
+            backoff: None,
+            retry_status: None,
+            connect_timeout: None,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_namespace: None,
+        requirements_cache_ttl: None,
+        build_cache_url: None,
+        build_cache_upload: false,
     }
     PipCompileSettings {
         src_file: [
             "requirements.in",
         ],
         constraint: [],
+        constraint_from_lock: None,
         override: [],
         build_constraint: [],
         constraints_from_workspace: [],
@@ -3610,6 +4576,8 @@ fn allow_insecure_host() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3623,6 +4591,7 @@ fn allow_insecure_host() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                find_links_recursive: false,
             },
             python: None,
             system: false,
@@ -3655,9 +4624,17 @@ fn allow_insecure_host() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
-            dependency_metadata: DependencyMetadata(
-                {},
-            ),
+            yanked: Warn,
+            allow_yanked: [],
+            prefer_local_version: None,
+            resolution_budget: ResolutionBudget {
+                max_steps: None,
+                timeout: None,
+            },
+            dependency_metadata: DependencyMetadata {
+                entries: {},
+                overrides: {},
+            },
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -3668,6 +4645,10 @@ fn allow_insecure_host() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            build_containers: BuildContainers {
+                default: None,
+                packages: {},
+            },
             python_version: None,
             python_platform: None,
             universal: false,
@@ -3685,7 +4666,13 @@ fn allow_insecure_host() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            bytecode_options: BytecodeOptions {
+                optimization: Unoptimized,
+                workers: None,
+                no_compile_package: [],
+            },
             sources: Enabled,
+            variants: [],
             hash_checking: None,
             upgrade: None,
             reinstall: None,
@@ -3728,10 +4715,12 @@ fn index_priority() -> anyhow::Result<()> {
     GlobalSettings {
         quiet: false,
         verbose: 0,
+        log_format: Text,
         color: Auto,
         native_tls: false,
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: None,
             builds: 16,
             installs: 8,
         },
@@ -3741,18 +4730,32 @@ fn index_priority() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        git_ssh: {},
+        no_git_sparse_checkout: false,
+        required_version: None,
+        http_retry: RetrySettings {
+            retries: None,
+            backoff: None,
+            retry_status: None,
+            connect_timeout: None,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_namespace: None,
+        requirements_cache_ttl: None,
+        build_cache_url: None,
+        build_cache_upload: false,
     }
     PipCompileSettings {
         src_file: [
             "requirements.in",
         ],
         constraint: [],
+        constraint_from_lock: None,
         override: [],
         build_constraint: [],
         constraints_from_workspace: [],
@@ -3760,6 +4763,8 @@ fn index_priority() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3797,6 +4802,13 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        proxy: None,
+                        no_proxy: [],
+                        ca_cert: None,
+                        client_cert: None,
+                        client_key: None,
+                        username: None,
+                        password: None,
                         origin: Some(
                             Cli,
                         ),
@@ -3827,11 +4839,19 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        proxy: None,
+                        no_proxy: [],
+                        ca_cert: None,
+                        client_cert: None,
+                        client_key: None,
+                        username: None,
+                        password: None,
                         origin: None,
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                find_links_recursive: false,
             },
             python: None,
             system: false,
@@ -3853,9 +4873,17 @@ fn index_priority() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
-            dependency_metadata: DependencyMetadata(
-                {},
-            ),
+            yanked: Warn,
+            allow_yanked: [],
+            prefer_local_version: None,
+            resolution_budget: ResolutionBudget {
+                max_steps: None,
+                timeout: None,
+            },
+            dependency_metadata: DependencyMetadata {
+                entries: {},
+                overrides: {},
+            },
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -3866,6 +4894,10 @@ fn index_priority() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            build_containers: BuildContainers {
+                default: None,
+                packages: {},
+            },
             python_version: None,
             python_platform: None,
             universal: false,
@@ -3883,7 +4915,13 @@ fn index_priority() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            bytecode_options: BytecodeOptions {
+                optimization: Unoptimized,
+                workers: None,
+                no_compile_package: [],
+            },
             sources: Enabled,
+            variants: [],
             hash_checking: None,
             upgrade: None,
             reinstall: None,
@@ -3905,10 +4943,12 @@ fn index_priority() -> anyhow::Result<()> {
     GlobalSettings {
         quiet: false,
         verbose: 0,
+        log_format: Text,
         color: Auto,
         native_tls: false,
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: None,
             builds: 16,
             installs: 8,
         },
@@ -3918,18 +4958,32 @@ fn index_priority() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        git_ssh: {},
+        no_git_sparse_checkout: false,
+        required_version: None,
+        http_retry: RetrySettings {
+            retries: None,
+            backoff: None,
+            retry_status: None,
+            connect_timeout: None,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_namespace: None,
+        requirements_cache_ttl: None,
+        build_cache_url: None,
+        build_cache_upload: false,
     }
     PipCompileSettings {
         src_file: [
             "requirements.in",
         ],
         constraint: [],
+        constraint_from_lock: None,
         override: [],
         build_constraint: [],
         constraints_from_workspace: [],
@@ -3937,6 +4991,8 @@ fn index_priority() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3974,6 +5030,13 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        proxy: None,
+                        no_proxy: [],
+                        ca_cert: None,
+                        client_cert: None,
+                        client_key: None,
+                        username: None,
+                        password: None,
                         origin: Some(
                             Cli,
                         ),
@@ -4004,11 +5067,19 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        proxy: None,
+                        no_proxy: [],
+                        ca_cert: None,
+                        client_cert: None,
+                        client_key: None,
+                        username: None,
+                        password: None,
                         origin: None,
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                find_links_recursive: false,
             },
             python: None,
             system: false,
@@ -4030,9 +5101,17 @@ fn index_priority() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
-            dependency_metadata: DependencyMetadata(
-                {},
-            ),
+            yanked: Warn,
+            allow_yanked: [],
+            prefer_local_version: None,
+            resolution_budget: ResolutionBudget {
+                max_steps: None,
+                timeout: None,
+            },
+            dependency_metadata: DependencyMetadata {
+                entries: {},
+                overrides: {},
+            },
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -4043,6 +5122,10 @@ fn index_priority() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            build_containers: BuildContainers {
+                default: None,
+                packages: {},
+            },
             python_version: None,
             python_platform: None,
             universal: false,
@@ -4060,7 +5143,13 @@ fn index_priority() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            bytecode_options: BytecodeOptions {
+                optimization: Unoptimized,
+                workers: None,
+                no_compile_package: [],
+            },
             sources: Enabled,
+            variants: [],
             hash_checking: None,
             upgrade: None,
             reinstall: None,
@@ -4088,10 +5177,12 @@ fn index_priority() -> anyhow::Result<()> {
     GlobalSettings {
         quiet: false,
         verbose: 0,
+        log_format: Text,
         color: Auto,
         native_tls: false,
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: None,
             builds: 16,
             installs: 8,
         },
@@ -4101,18 +5192,32 @@ fn index_priority() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        git_ssh: {},
+        no_git_sparse_checkout: false,
+        required_version: None,
+        http_retry: RetrySettings {
+            retries: None,
+            backoff: None,
+            retry_status: None,
+            connect_timeout: None,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_namespace: None,
+        requirements_cache_ttl: None,
+        build_cache_url: None,
+        build_cache_upload: false,
     }
     PipCompileSettings {
         src_file: [
             "requirements.in",
         ],
         constraint: [],
+        constraint_from_lock: None,
         override: [],
         build_constraint: [],
         constraints_from_workspace: [],
@@ -4120,6 +5225,8 @@ fn index_priority() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -4157,6 +5264,13 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        proxy: None,
+                        no_proxy: [],
+                        ca_cert: None,
+                        client_cert: None,
+                        client_key: None,
+                        username: None,
+                        password: None,
                         origin: Some(
                             Cli,
                         ),
@@ -4187,11 +5301,19 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        proxy: None,
+                        no_proxy: [],
+                        ca_cert: None,
+                        client_cert: None,
+                        client_key: None,
+                        username: None,
+                        password: None,
                         origin: None,
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                find_links_recursive: false,
             },
             python: None,
             system: false,
@@ -4213,9 +5335,17 @@ fn index_priority() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
-            dependency_metadata: DependencyMetadata(
-                {},
-            ),
+            yanked: Warn,
+            allow_yanked: [],
+            prefer_local_version: None,
+            resolution_budget: ResolutionBudget {
+                max_steps: None,
+                timeout: None,
+            },
+            dependency_metadata: DependencyMetadata {
+                entries: {},
+                overrides: {},
+            },
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -4226,6 +5356,10 @@ fn index_priority() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            build_containers: BuildContainers {
+                default: None,
+                packages: {},
+            },
             python_version: None,
             python_platform: None,
             universal: false,
@@ -4243,7 +5377,13 @@ fn index_priority() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            bytecode_options: BytecodeOptions {
+                optimization: Unoptimized,
+                workers: None,
+                no_compile_package: [],
+            },
             sources: Enabled,
+            variants: [],
             hash_checking: None,
             upgrade: None,
             reinstall: None,
@@ -4266,10 +5406,12 @@ fn index_priority() -> anyhow::Result<()> {
     GlobalSettings {
         quiet: false,
         verbose: 0,
+        log_format: Text,
         color: Auto,
         native_tls: false,
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: None,
             builds: 16,
             installs: 8,
         },
@@ -4279,18 +5421,32 @@ fn index_priority() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        git_ssh: {},
+        no_git_sparse_checkout: false,
+        required_version: None,
+        http_retry: RetrySettings {
+            retries: None,
+            backoff: None,
+            retry_status: None,
+            connect_timeout: None,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_namespace: None,
+        requirements_cache_ttl: None,
+        build_cache_url: None,
+        build_cache_upload: false,
     }
     PipCompileSettings {
         src_file: [
             "requirements.in",
         ],
         constraint: [],
+        constraint_from_lock: None,
         override: [],
         build_constraint: [],
         constraints_from_workspace: [],
@@ -4298,6 +5454,8 @@ fn index_priority() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -4335,6 +5493,13 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        proxy: None,
+                        no_proxy: [],
+                        ca_cert: None,
+                        client_cert: None,
+                        client_key: None,
+                        username: None,
+                        password: None,
                         origin: Some(
                             Cli,
                         ),
@@ -4365,11 +5530,19 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        proxy: None,
+                        no_proxy: [],
+                        ca_cert: None,
+                        client_cert: None,
+                        client_key: None,
+                        username: None,
+                        password: None,
                         origin: None,
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                find_links_recursive: false,
             },
             python: None,
             system: false,
@@ -4391,9 +5564,17 @@ fn index_priority() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
-            dependency_metadata: DependencyMetadata(
-                {},
-            ),
+            yanked: Warn,
+            allow_yanked: [],
+            prefer_local_version: None,
+            resolution_budget: ResolutionBudget {
+                max_steps: None,
+                timeout: None,
+            },
+            dependency_metadata: DependencyMetadata {
+                entries: {},
+                overrides: {},
+            },
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -4404,6 +5585,10 @@ fn index_priority() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            build_containers: BuildContainers {
+                default: None,
+                packages: {},
+            },
             python_version: None,
             python_platform: None,
             universal: false,
@@ -4421,7 +5606,13 @@ fn index_priority() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            bytecode_options: BytecodeOptions {
+                optimization: Unoptimized,
+                workers: None,
+                no_compile_package: [],
+            },
             sources: Enabled,
+            variants: [],
             hash_checking: None,
             upgrade: None,
             reinstall: None,
@@ -4451,10 +5642,12 @@ fn index_priority() -> anyhow::Result<()> {
     GlobalSettings {
         quiet: false,
         verbose: 0,
+        log_format: Text,
         color: Auto,
         native_tls: false,
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: None,
             builds: 16,
             installs: 8,
         },
@@ -4464,18 +5657,32 @@ fn index_priority() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        git_ssh: {},
+        no_git_sparse_checkout: false,
+        required_version: None,
+        http_retry: RetrySettings {
+            retries: None,
+            backoff: None,
+            retry_status: None,
+            connect_timeout: None,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_namespace: None,
+        requirements_cache_ttl: None,
+        build_cache_url: None,
+        build_cache_upload: false,
     }
     PipCompileSettings {
         src_file: [
             "requirements.in",
         ],
         constraint: [],
+        constraint_from_lock: None,
         override: [],
         build_constraint: [],
         constraints_from_workspace: [],
@@ -4483,6 +5690,8 @@ fn index_priority() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -4520,6 +5729,13 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        proxy: None,
+                        no_proxy: [],
+                        ca_cert: None,
+                        client_cert: None,
+                        client_key: None,
+                        username: None,
+                        password: None,
                         origin: Some(
                             Cli,
                         ),
@@ -4550,11 +5766,19 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        proxy: None,
+                        no_proxy: [],
+                        ca_cert: None,
+                        client_cert: None,
+                        client_key: None,
+                        username: None,
+                        password: None,
                         origin: None,
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                find_links_recursive: false,
             },
             python: None,
             system: false,
@@ -4576,9 +5800,17 @@ fn index_priority() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
-            dependency_metadata: DependencyMetadata(
-                {},
-            ),
+            yanked: Warn,
+            allow_yanked: [],
+            prefer_local_version: None,
+            resolution_budget: ResolutionBudget {
+                max_steps: None,
+                timeout: None,
+            },
+            dependency_metadata: DependencyMetadata {
+                entries: {},
+                overrides: {},
+            },
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -4589,6 +5821,10 @@ fn index_priority() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            build_containers: BuildContainers {
+                default: None,
+                packages: {},
+            },
             python_version: None,
             python_platform: None,
             universal: false,
@@ -4606,7 +5842,13 @@ fn index_priority() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            bytecode_options: BytecodeOptions {
+                optimization: Unoptimized,
+                workers: None,
+                no_compile_package: [],
+            },
             sources: Enabled,
+            variants: [],
             hash_checking: None,
             upgrade: None,
             reinstall: None,
@@ -4629,10 +5871,12 @@ fn index_priority() -> anyhow::Result<()> {
     GlobalSettings {
         quiet: false,
         verbose: 0,
+        log_format: Text,
         color: Auto,
         native_tls: false,
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: None,
             builds: 16,
             installs: 8,
         },
@@ -4642,18 +5886,32 @@ fn index_priority() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        git_ssh: {},
+        no_git_sparse_checkout: false,
+        required_version: None,
+        http_retry: RetrySettings {
+            retries: None,
+            backoff: None,
+            retry_status: None,
+            connect_timeout: None,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_namespace: None,
+        requirements_cache_ttl: None,
+        build_cache_url: None,
+        build_cache_upload: false,
     }
     PipCompileSettings {
         src_file: [
             "requirements.in",
         ],
         constraint: [],
+        constraint_from_lock: None,
         override: [],
         build_constraint: [],
         constraints_from_workspace: [],
@@ -4661,6 +5919,8 @@ fn index_priority() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -4698,6 +5958,13 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        proxy: None,
+                        no_proxy: [],
+                        ca_cert: None,
+                        client_cert: None,
+                        client_key: None,
+                        username: None,
+                        password: None,
                         origin: Some(
                             Cli,
                         ),
@@ -4728,11 +5995,19 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        proxy: None,
+                        no_proxy: [],
+                        ca_cert: None,
+                        client_cert: None,
+                        client_key: None,
+                        username: None,
+                        password: None,
                         origin: None,
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                find_links_recursive: false,
             },
             python: None,
             system: false,
@@ -4754,9 +6029,17 @@ fn index_priority() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
-            dependency_metadata: DependencyMetadata(
-                {},
-            ),
+            yanked: Warn,
+            allow_yanked: [],
+            prefer_local_version: None,
+            resolution_budget: ResolutionBudget {
+                max_steps: None,
+                timeout: None,
+            },
+            dependency_metadata: DependencyMetadata {
+                entries: {},
+                overrides: {},
+            },
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -4767,6 +6050,10 @@ fn index_priority() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            build_containers: BuildContainers {
+                default: None,
+                packages: {},
+            },
             python_version: None,
             python_platform: None,
             universal: false,
@@ -4784,7 +6071,13 @@ fn index_priority() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            bytecode_options: BytecodeOptions {
+                optimization: Unoptimized,
+                workers: None,
+                no_compile_package: [],
+            },
             sources: Enabled,
+            variants: [],
             hash_checking: None,
             upgrade: None,
             reinstall: None,
@@ -4797,3 +6090,257 @@ fn index_priority() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Request a profile that isn't defined in any configuration file.
+#[test]
+#[cfg_attr(
+    windows,
+    ignore = "Configuration tests are not yet supported on Windows"
+)]
+fn resolve_profile_missing() -> anyhow::Result<()> {
+    let context = TestContext::new("3.12");
+
+    let config = context.temp_dir.child("uv.toml");
+    config.write_str(indoc::indoc! {r#"
+        [profile.airgapped]
+        offline = true
+    "#})?;
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio>3.0.0")?;
+
+    uv_snapshot!(context.filters(), add_shared_args(context.pip_compile())
+        .arg("requirements.in")
+        .arg("--show-settings")
+        .arg("--profile")
+        .arg("gpu"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Profile `gpu` is not defined in any configuration file
+    "###);
+
+    Ok(())
+}
+
+/// Apply the settings of a selected profile, including its index URL, `exclude-newer` cutoff, and
+/// installer settings, in favor of those defined outside of any profile.
+#[test]
+#[cfg_attr(
+    windows,
+    ignore = "Configuration tests are not yet supported on Windows"
+)]
+fn resolve_profile() -> anyhow::Result<()> {
+    let context = TestContext::new("3.12");
+
+    let config = context.temp_dir.child("uv.toml");
+    config.write_str(indoc::indoc! {r#"
+        index-url = "https://pypi.org/simple"
+        compile-bytecode = false
+
+        [profile.mirror]
+        index-url = "https://mirror.example.com/simple"
+        exclude-newer = "2024-01-01T00:00:00Z"
+        compile-bytecode = true
+        reinstall = true
+    "#})?;
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio>3.0.0")?;
+
+    uv_snapshot!(context.filters(), add_shared_args(context.pip_compile())
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("requirements.in")
+        .arg("--show-settings")
+        .arg("--profile")
+        .arg("mirror"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    GlobalSettings {
+        quiet: false,
+        verbose: 0,
+        log_format: Text,
+        color: Auto,
+        native_tls: false,
+        concurrency: Concurrency {
+            downloads: 50,
+            downloads_per_host: None,
+            builds: 16,
+            installs: 8,
+        },
+        connectivity: Online,
+        show_settings: true,
+        preview: Disabled,
+        python_preference: Managed,
+        python_downloads: Automatic,
+        no_progress: false,
+        git_ssh: {},
+        no_git_sparse_checkout: false,
+        required_version: None,
+        http_retry: RetrySettings {
+            retries: None,
+            backoff: None,
+            retry_status: None,
+            connect_timeout: None,
+        },
+    }
+    CacheSettings {
+        no_cache: false,
+        cache_dir: Some(
+            "[CACHE_DIR]/",
+        ),
+        cache_namespace: None,
+        requirements_cache_ttl: None,
+        build_cache_url: None,
+        build_cache_upload: false,
+    }
+    PipCompileSettings {
+        src_file: [
+            "requirements.in",
+        ],
+        constraint: [],
+        constraint_from_lock: None,
+        override: [],
+        build_constraint: [],
+        constraints_from_workspace: [],
+        overrides_from_workspace: [],
+        environments: SupportedEnvironments(
+            [],
+        ),
+        python_versions: [],
+        python_platforms: [],
+        refresh: None(
+            Timestamp(
+                SystemTime {
+                    tv_sec: [TIME],
+                    tv_nsec: [TIME],
+                },
+            ),
+        ),
+        settings: PipSettings {
+            index_locations: IndexLocations {
+                indexes: [
+                    Index {
+                        name: None,
+                        url: Url(
+                            VerbatimUrl {
+                                url: Url {
+                                    scheme: "https",
+                                    cannot_be_a_base: false,
+                                    username: "",
+                                    password: None,
+                                    host: Some(
+                                        Domain(
+                                            "mirror.example.com",
+                                        ),
+                                    ),
+                                    port: None,
+                                    path: "/simple",
+                                    query: None,
+                                    fragment: None,
+                                },
+                                given: Some(
+                                    "https://mirror.example.com/simple",
+                                ),
+                            },
+                        ),
+                        explicit: false,
+                        default: true,
+                        proxy: None,
+                        no_proxy: [],
+                        ca_cert: None,
+                        client_cert: None,
+                        client_key: None,
+                        username: None,
+                        password: None,
+                        origin: None,
+                    },
+                ],
+                flat_index: [],
+                no_index: false,
+                find_links_recursive: false,
+            },
+            python: None,
+            system: false,
+            extras: None,
+            break_system_packages: false,
+            target: None,
+            prefix: None,
+            index_strategy: FirstIndex,
+            keyring_provider: Disabled,
+            allow_insecure_host: [],
+            no_build_isolation: false,
+            no_build_isolation_package: [],
+            build_options: BuildOptions {
+                no_binary: None,
+                no_build: None,
+            },
+            allow_empty_requirements: false,
+            strict: false,
+            dependency_mode: Transitive,
+            resolution: Highest,
+            prerelease: IfNecessaryOrExplicit,
+            yanked: Warn,
+            allow_yanked: [],
+            prefer_local_version: None,
+            resolution_budget: ResolutionBudget {
+                max_steps: None,
+                timeout: None,
+            },
+            dependency_metadata: DependencyMetadata {
+                entries: {},
+                overrides: {},
+            },
+            output_file: None,
+            no_strip_extras: false,
+            no_strip_markers: false,
+            no_annotate: false,
+            no_header: false,
+            custom_compile_command: None,
+            generate_hashes: false,
+            config_setting: ConfigSettings(
+                {},
+            ),
+            build_containers: BuildContainers {
+                default: None,
+                packages: {},
+            },
+            python_version: None,
+            python_platform: None,
+            universal: false,
+            exclude_newer: Some(
+                ExcludeNewer(
+                    2024-01-01T00:00:00Z,
+                ),
+            ),
+            no_emit_package: [],
+            emit_index_url: false,
+            emit_find_links: false,
+            emit_build_options: false,
+            emit_marker_expression: false,
+            emit_index_annotation: false,
+            annotation_style: Split,
+            link_mode: Clone,
+            compile_bytecode: true,
+            bytecode_options: BytecodeOptions {
+                optimization: Unoptimized,
+                workers: None,
+                no_compile_package: [],
+            },
+            sources: Enabled,
+            variants: [],
+            hash_checking: None,
+            upgrade: None,
+            reinstall: All,
+        },
+    }
+
+    ----- stderr -----
+    "###
+    );
+
+    Ok(())
+}
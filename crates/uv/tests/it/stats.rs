@@ -0,0 +1,119 @@
+use anyhow::Result;
+use assert_cmd::prelude::*;
+
+use crate::common::{uv_snapshot, TestContext};
+
+/// `uv stats` should report the cache hits and misses of recent installs.
+#[test]
+fn stats() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([
+            (r"\d+\.\dK?M?G?i?B", "[SIZE]"),
+            (r"since \S+", "since [TIME]"),
+        ])
+        .collect::<Vec<_>>();
+
+    // Without any recorded activity, only the cache size is reported.
+    uv_snapshot!(filters, context.stats(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Cache directory: [CACHE_DIR]/
+    Cache size: [SIZE]
+      interpreter-v3         [SIZE]
+
+    No recent activity recorded
+
+    ----- stderr -----
+    "###);
+
+    // Install a package, which must be downloaded.
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .assert()
+        .success();
+
+    // Reinstall the package, which is available in the cache.
+    context.pip_uninstall().arg("iniconfig").assert().success();
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .assert()
+        .success();
+
+    uv_snapshot!(filters, context.stats(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Cache directory: [CACHE_DIR]/
+    Cache size: [SIZE]
+      wheels-v2              [SIZE]
+      interpreter-v3         [SIZE]
+      simple-v13             [SIZE]
+      archive-v0             [SIZE]
+
+    Recent activity: 2 operations since [TIME]
+      Cache hits:   1 (50.0%)
+      Cache misses: 1 (50.0%)
+      Bytes saved:  [SIZE]
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
+
+/// `uv stats` should report the source distributions that were built.
+#[test]
+fn stats_builds() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Install a package from its source distribution, which must be built.
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--no-binary")
+        .arg("iniconfig")
+        .assert()
+        .success();
+
+    let output = context
+        .stats()
+        .arg("--output-format")
+        .arg("json")
+        .assert()
+        .success()
+        .get_output()
+        .clone();
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+
+    assert_eq!(report["operations"], 1);
+    assert_eq!(report["hits"], 0);
+    assert_eq!(report["misses"], 1);
+    let builds = report["slowest_builds"].as_array().unwrap();
+    assert_eq!(builds.len(), 1);
+    assert_eq!(builds[0]["name"], "iniconfig");
+    assert_eq!(builds[0]["version"], "2.0.0");
+    assert!(builds[0]["duration"].as_f64().unwrap() > 0.0);
+
+    // `--builds` limits the number of builds that are reported.
+    let output = context
+        .stats()
+        .arg("--output-format")
+        .arg("json")
+        .arg("--builds")
+        .arg("0")
+        .assert()
+        .success()
+        .get_output()
+        .clone();
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(report["slowest_builds"].as_array().map(Vec::len), Some(0));
+
+    Ok(())
+}
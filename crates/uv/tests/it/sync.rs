@@ -106,6 +106,7 @@ fn locked() -> Result<()> {
     ----- stderr -----
     Resolved 2 packages in [TIME]
     error: The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+      Caused by: The requirements of `project` changed (added: `iniconfig`; removed: `anyio==3.7.0`)
     "###);
 
     let updated = context.read("uv.lock");
@@ -383,6 +384,91 @@ fn mixed_requires_python() -> Result<()> {
     Ok(())
 }
 
+/// Ensure that workspace members with their own environments can use a Python version that is
+/// incompatible with the workspace root.
+#[test]
+fn mixed_requires_python_member_environments() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.8", "3.12"]);
+
+    // Create a workspace root with a minimum Python requirement of Python 3.12.
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "albatross"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio>3"]
+
+        [tool.uv.workspace]
+        members = ["packages/*"]
+        member-environments = true
+        "#,
+    )?;
+
+    // Create a child with a maximum Python requirement of Python 3.8.
+    let child = context.temp_dir.child("packages").child("bird-feeder");
+    child.create_dir_all()?;
+
+    let init = child.child("src").child("bird_feeder").child("__init__.py");
+    init.touch()?;
+
+    let pyproject_toml = child.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "bird-feeder"
+        version = "0.1.0"
+        requires-python = "==3.8.*"
+        dependencies = ["iniconfig"]
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+        "#,
+    )?;
+
+    // Syncing the child should create an environment in the member directory, using Python 3.8.
+    uv_snapshot!(context.filters(), context.sync().arg("--package").arg("bird-feeder"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.8.[X] interpreter at: [PYTHON-3.8]
+    Creating virtual environment at: packages/bird-feeder/.venv
+    Resolved 6 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + bird-feeder==0.1.0 (from file://[TEMP_DIR]/packages/bird-feeder)
+     + iniconfig==2.0.0
+    "###);
+
+    context
+        .temp_dir
+        .child(".venv")
+        .assert(predicate::path::missing());
+
+    // Syncing the root should use Python 3.12 in the workspace environment.
+    uv_snapshot!(context.filters(), context.sync(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Resolved 6 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==4.3.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "###);
+
+    Ok(())
+}
+
 /// Sync development dependencies in a (legacy) non-project workspace root.
 #[test]
 fn sync_legacy_non_project_dev_dependencies() -> Result<()> {
@@ -2973,6 +3059,61 @@ fn sync_wheel_url_source_error() -> Result<()> {
     Ok(())
 }
 
+/// With `--offline=strict`, report every distribution that's missing from the cache before
+/// installing anything.
+#[test]
+fn sync_offline_strict() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==4.3.0"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    // None of the distributions have been downloaded yet.
+    uv_snapshot!(context.filters(), context.sync().arg("--offline=strict"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    error: Network connectivity is disabled, but the following distributions to install are not available in the cache:
+      - anyio==4.3.0
+      - idna==3.6
+      - sniffio==1.3.1
+    "###);
+
+    // Populate the cache, then remove the environment.
+    context.sync().assert().success();
+    fs_err::remove_dir_all(&context.venv)?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--offline=strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Resolved 4 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==4.3.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "###);
+
+    Ok(())
+}
+
 #[test]
 fn sync_wheel_path_source_error() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -3798,3 +3939,463 @@ fn sync_explicit() -> Result<()> {
 
     Ok(())
 }
+
+/// With `enforce-index-provenance`, refuse to install packages from an index other than the one
+/// selected by the project's configuration.
+#[test]
+fn sync_enforce_index_provenance() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "root"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "idna>2",
+        ]
+
+        [tool.uv]
+        enforce-index-provenance = true
+
+        [[tool.uv.index]]
+        name = "test"
+        url = "https://test.pypi.org/simple"
+        explicit = true
+
+        [tool.uv.sources]
+        idna = { index = "test" }
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + idna==2.7
+    "###);
+
+    let lock = context.read("uv.lock");
+
+    // Modify the lockfile to fetch `idna` from PyPI, rather than the pinned index.
+    context.temp_dir.child("uv.lock").write_str(&lock.replace(
+        r#"source = { registry = "https://test.pypi.org/simple" }"#,
+        r#"source = { registry = "https://pypi.org/simple" }"#,
+    ))?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `idna` was locked against `https://pypi.org/simple`, but is pinned to a different index in `tool.uv.sources`, and `tool.uv.enforce-index-provenance` is enabled. To re-lock against the pinned index, run `uv lock`.
+    "###);
+
+    // Restore the lockfile, but remove the pin, such that `idna` would be fetched from an
+    // `explicit` index without being pinned to it.
+    context.temp_dir.child("uv.lock").write_str(&lock)?;
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "root"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "idna>2",
+        ]
+
+        [tool.uv]
+        enforce-index-provenance = true
+
+        [[tool.uv.index]]
+        name = "test"
+        url = "https://test.pypi.org/simple"
+        explicit = true
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `idna` was locked against `https://test.pypi.org/simple`, which is not one of the project's configured indexes, but `tool.uv.enforce-index-provenance` is enabled. If the index is intended, add it to `tool.uv.index` (and pin `idna` to it in `tool.uv.sources`, if the index is `explicit`).
+    "###);
+
+    Ok(())
+}
+
+/// With `enforce-index-provenance`, verify transitive dependencies, in addition to direct ones.
+#[test]
+fn sync_enforce_index_provenance_transitive() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "root"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "anyio==3.7.0",
+        ]
+
+        [tool.uv]
+        enforce-index-provenance = true
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "###);
+
+    let lock = context.read("uv.lock");
+
+    // Modify the lockfile to fetch `sniffio` (a transitive dependency) from an index that isn't
+    // configured.
+    context.temp_dir.child("uv.lock").write_str(&lock.replace(
+        "name = \"sniffio\"\nversion = \"1.3.1\"\nsource = { registry = \"https://pypi.org/simple\" }",
+        "name = \"sniffio\"\nversion = \"1.3.1\"\nsource = { registry = \"https://test.pypi.org/simple\" }",
+    ))?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `sniffio` was locked against `https://test.pypi.org/simple`, which is not one of the project's configured indexes, but `tool.uv.enforce-index-provenance` is enabled. If the index is intended, add it to `tool.uv.index` (and pin `sniffio` to it in `tool.uv.sources`, if the index is `explicit`).
+    "###);
+
+    Ok(())
+}
+
+/// Run the `tool.uv.hooks` commands before and after syncing, and after locking.
+#[test]
+#[cfg(unix)]
+fn sync_hooks() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [tool.uv.hooks]
+        pre-sync = ["echo pre-sync"]
+        post-sync = ["python -c 'import iniconfig; print(\"post-sync\")'"]
+        post-lock = ["echo post-lock"]
+        "#,
+    )?;
+
+    // The `post-sync` hook should run in the project environment. Hook output should be written to
+    // stderr.
+    uv_snapshot!(context.filters(), context.sync(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Running `post-lock` hook: `echo post-lock`
+    post-lock
+    Running `pre-sync` hook: `echo pre-sync`
+    pre-sync
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    Running `post-sync` hook: `python -c 'import iniconfig; print("post-sync")'`
+    post-sync
+    "###);
+
+    // The lockfile is unchanged, so the `post-lock` hook should not run.
+    uv_snapshot!(context.filters(), context.sync(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Running `pre-sync` hook: `echo pre-sync`
+    pre-sync
+    Audited 1 package in [TIME]
+    Running `post-sync` hook: `python -c 'import iniconfig; print("post-sync")'`
+    post-sync
+    "###);
+
+    // With `--no-hooks`, no hooks should run.
+    uv_snapshot!(context.filters(), context.sync().arg("--no-hooks"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited 1 package in [TIME]
+    "###);
+
+    // A failing hook should abort the sync.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [tool.uv.hooks]
+        pre-sync = ["exit 3"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync(), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Running `pre-sync` hook: `exit 3`
+    error: The `pre-sync` hook failed with exit status: 3: `exit 3`
+    "###);
+
+    Ok(())
+}
+
+/// Run the `tool.uv.hooks` commands from `uv add`, `uv remove`, and `uv run`, which lock and sync
+/// the project environment.
+#[test]
+#[cfg(unix)]
+fn sync_hooks_add_remove_run() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv.hooks]
+        pre-sync = ["echo pre-sync"]
+        post-sync = ["echo post-sync"]
+        post-lock = ["echo post-lock"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.add().arg("iniconfig"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Running `post-lock` hook: `echo post-lock`
+    post-lock
+    Running `pre-sync` hook: `echo pre-sync`
+    pre-sync
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    Running `post-sync` hook: `echo post-sync`
+    post-sync
+    "###);
+
+    // The lockfile is unchanged, so the `post-lock` hook should not run. The hook output should
+    // not be mixed into the command's output.
+    uv_snapshot!(context.filters(), context.run().arg("python").arg("-c").arg("print('hello')"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    hello
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Running `pre-sync` hook: `echo pre-sync`
+    pre-sync
+    Audited 1 package in [TIME]
+    Running `post-sync` hook: `echo post-sync`
+    post-sync
+    "###);
+
+    uv_snapshot!(context.filters(), context.remove().arg("iniconfig").arg("--no-hooks"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+     - iniconfig==2.0.0
+    "###);
+
+    Ok(())
+}
+
+/// Write a JSON report of the changes made to the environment with `--report`.
+#[test]
+fn sync_report() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--report").arg("report.json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###);
+
+    let report: serde_json::Value = serde_json::from_str(&context.read("report.json"))?;
+
+    let packages = report["packages"].as_array().unwrap();
+    assert_eq!(packages.len(), 1);
+    assert_eq!(packages[0]["name"], "iniconfig");
+    assert_eq!(packages[0]["version"], "2.0.0");
+    assert_eq!(packages[0]["action"], "installed");
+    assert_eq!(packages[0]["requested"], true);
+    assert_eq!(packages[0]["editable"], false);
+    assert_eq!(
+        packages[0]["hashes"],
+        serde_json::json!([
+            "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374"
+        ])
+    );
+
+    // Syncing again should report the existing installation as kept.
+    uv_snapshot!(context.filters(), context.sync().arg("--report").arg("report.json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited 1 package in [TIME]
+    "###);
+
+    let report: serde_json::Value = serde_json::from_str(&context.read("report.json"))?;
+    assert_eq!(report["packages"][0]["action"], "kept");
+
+    // Changing the version should be reported as a single change, rather than an installation
+    // and a removal.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==1.1.1"]
+        "#,
+    )?;
+    context
+        .sync()
+        .arg("--report")
+        .arg("report.json")
+        .assert()
+        .success();
+
+    let report: serde_json::Value = serde_json::from_str(&context.read("report.json"))?;
+    let packages = report["packages"].as_array().unwrap();
+    assert_eq!(packages.len(), 1);
+    assert_eq!(packages[0]["version"], "1.1.1");
+    assert_eq!(packages[0]["action"], "downgraded");
+    assert_eq!(packages[0]["previous_version"], "2.0.0");
+
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+    context
+        .sync()
+        .arg("--report")
+        .arg("report.json")
+        .assert()
+        .success();
+
+    let report: serde_json::Value = serde_json::from_str(&context.read("report.json"))?;
+    let packages = report["packages"].as_array().unwrap();
+    assert_eq!(packages.len(), 1);
+    assert_eq!(packages[0]["version"], "2.0.0");
+    assert_eq!(packages[0]["action"], "upgraded");
+    assert_eq!(packages[0]["previous_version"], "1.1.1");
+
+    Ok(())
+}
+
+/// With `--minimal-changes`, select the nearest version to the locked version when syncing a
+/// project whose requirements no longer admit it.
+#[test]
+fn sync_minimal_changes() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==1.0.0"]
+        "#,
+    )?;
+
+    context.sync().assert().success();
+
+    // Exclude the locked version.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig>1.0.0"]
+        "#,
+    )?;
+
+    context.sync().arg("--minimal-changes").assert().success();
+
+    let lock = context.read("uv.lock");
+    assert!(lock.contains("version = \"1.0.1\""));
+
+    Ok(())
+}
@@ -0,0 +1,136 @@
+use assert_fs::prelude::*;
+use insta::assert_snapshot;
+
+use uv_static::EnvVars;
+
+use crate::common::{uv_snapshot, TestContext};
+
+#[test]
+fn tool_alias() {
+    let context = TestContext::new("3.12");
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // No aliases are defined initially.
+    uv_snapshot!(context.filters(), context.tool_alias().arg("list")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No aliases defined
+    "###);
+
+    // Add an alias, including a flag that would otherwise be parsed by uv.
+    uv_snapshot!(context.filters(), context.tool_alias().arg("add").arg("pyver").arg("pytest@8.0.0").arg("--version")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Added alias `pyver` for `pytest@8.0.0 --version`
+    "###);
+
+    // Add a second alias.
+    context
+        .tool_alias()
+        .arg("add")
+        .arg("fmt")
+        .arg("ruff@0.6")
+        .arg("format")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    assert_snapshot!(fs_err::read_to_string(tool_dir.join("aliases.toml")).unwrap(), @r###"
+    fmt = "ruff@0.6 format"
+    pyver = "pytest@8.0.0 --version"
+    "###);
+
+    uv_snapshot!(context.filters(), context.tool_alias().arg("list")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    fmt = ruff@0.6 format
+    pyver = pytest@8.0.0 --version
+
+    ----- stderr -----
+    "###);
+
+    // Remove an alias, along with one that doesn't exist.
+    uv_snapshot!(context.filters(), context.tool_alias().arg("remove").arg("fmt").arg("lint")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Removed alias `fmt`
+    Alias `lint` is not defined
+    "###);
+
+    assert_snapshot!(fs_err::read_to_string(tool_dir.join("aliases.toml")).unwrap(), @r###"
+    pyver = "pytest@8.0.0 --version"
+    "###);
+}
+
+#[test]
+fn tool_alias_run() {
+    let context = TestContext::new("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    context
+        .tool_alias()
+        .arg("add")
+        .arg("pyver")
+        .arg("pytest@8.0.0")
+        .arg("--version")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    // The alias should expand to the pinned tool and its arguments.
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("pyver")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    pytest 8.0.0
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Prepared 4 packages in [TIME]
+    Installed 4 packages in [TIME]
+     + iniconfig==2.0.0
+     + packaging==24.0
+     + pluggy==1.4.0
+     + pytest==8.0.0
+    "###);
+
+    // Arguments provided by the user are appended to those of the alias.
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("pyver")
+        .arg("-q")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    pytest 8.0.0
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "###);
+}
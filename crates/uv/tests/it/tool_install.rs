@@ -83,6 +83,14 @@ fn tool_install() {
             { name = "black", install-path = "[TEMP_DIR]/bin/black" },
             { name = "blackd", install-path = "[TEMP_DIR]/bin/blackd" },
         ]
+        locked = [
+            { name = "black", specifier = "==24.3.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "click", specifier = "==8.1.7", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "mypy-extensions", specifier = "==1.0.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "packaging", specifier = "==24.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "pathspec", specifier = "==0.12.1", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "platformdirs", specifier = "==4.2.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+        ]
 
         [tool.options]
         exclude-newer = "2024-03-25T00:00:00Z"
@@ -164,6 +172,15 @@ fn tool_install() {
         entrypoints = [
             { name = "flask", install-path = "[TEMP_DIR]/bin/flask" },
         ]
+        locked = [
+            { name = "blinker", specifier = "==1.7.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "click", specifier = "==8.1.7", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "flask", specifier = "==3.0.2", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "itsdangerous", specifier = "==2.1.2", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "jinja2", specifier = "==3.1.3", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "markupsafe", specifier = "==2.1.5", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "werkzeug", specifier = "==3.0.1", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+        ]
 
         [tool.options]
         exclude-newer = "2024-03-25T00:00:00Z"
@@ -348,6 +365,14 @@ fn tool_install_version() {
             { name = "black", install-path = "[TEMP_DIR]/bin/black" },
             { name = "blackd", install-path = "[TEMP_DIR]/bin/blackd" },
         ]
+        locked = [
+            { name = "black", specifier = "==24.2.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "click", specifier = "==8.1.7", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "mypy-extensions", specifier = "==1.0.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "packaging", specifier = "==24.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "pathspec", specifier = "==0.12.1", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "platformdirs", specifier = "==4.2.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+        ]
 
         [tool.options]
         exclude-newer = "2024-03-25T00:00:00Z"
@@ -368,7 +393,9 @@ fn tool_install_version() {
 /// Test an editable installation of a tool.
 #[test]
 fn tool_install_editable() {
-    let context = TestContext::new("3.12").with_filtered_exe_suffix();
+    let context = TestContext::new("3.12")
+        .with_filtered_exe_suffix()
+        .with_filtered_relative_workspace();
     let tool_dir = context.temp_dir.child("tools");
     let bin_dir = context.temp_dir.child("bin");
 
@@ -429,6 +456,9 @@ fn tool_install_editable() {
         entrypoints = [
             { name = "black", install-path = "[TEMP_DIR]/bin/black" },
         ]
+        locked = [
+            { name = "black", editable = "[WORKSPACE_RELATIVE]/scripts/packages/black_editable" },
+        ]
 
         [tool.options]
         exclude-newer = "2024-03-25T00:00:00Z"
@@ -468,6 +498,9 @@ fn tool_install_editable() {
         entrypoints = [
             { name = "black", install-path = "[TEMP_DIR]/bin/black" },
         ]
+        locked = [
+            { name = "black", editable = "[WORKSPACE_RELATIVE]/scripts/packages/black_editable" },
+        ]
 
         [tool.options]
         exclude-newer = "2024-03-25T00:00:00Z"
@@ -512,6 +545,14 @@ fn tool_install_editable() {
             { name = "black", install-path = "[TEMP_DIR]/bin/black" },
             { name = "blackd", install-path = "[TEMP_DIR]/bin/blackd" },
         ]
+        locked = [
+            { name = "black", specifier = "==24.2.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "click", specifier = "==8.1.7", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "mypy-extensions", specifier = "==1.0.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "packaging", specifier = "==24.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "pathspec", specifier = "==0.12.1", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "platformdirs", specifier = "==4.2.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+        ]
 
         [tool.options]
         exclude-newer = "2024-03-25T00:00:00Z"
@@ -560,6 +601,14 @@ fn tool_install_remove_on_empty() -> Result<()> {
             { name = "black", install-path = "[TEMP_DIR]/bin/black" },
             { name = "blackd", install-path = "[TEMP_DIR]/bin/blackd" },
         ]
+        locked = [
+            { name = "black", specifier = "==24.3.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "click", specifier = "==8.1.7", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "mypy-extensions", specifier = "==1.0.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "packaging", specifier = "==24.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "pathspec", specifier = "==0.12.1", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "platformdirs", specifier = "==4.2.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+        ]
 
         [tool.options]
         exclude-newer = "2024-03-25T00:00:00Z"
@@ -650,6 +699,14 @@ fn tool_install_remove_on_empty() -> Result<()> {
             { name = "black", install-path = "[TEMP_DIR]/bin/black" },
             { name = "blackd", install-path = "[TEMP_DIR]/bin/blackd" },
         ]
+        locked = [
+            { name = "black", specifier = "==24.3.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "click", specifier = "==8.1.7", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "mypy-extensions", specifier = "==1.0.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "packaging", specifier = "==24.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "pathspec", specifier = "==0.12.1", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "platformdirs", specifier = "==4.2.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+        ]
 
         [tool.options]
         exclude-newer = "2024-03-25T00:00:00Z"
@@ -662,7 +719,9 @@ fn tool_install_remove_on_empty() -> Result<()> {
 /// Test an editable installation of a tool using `--from`.
 #[test]
 fn tool_install_editable_from() {
-    let context = TestContext::new("3.12").with_filtered_exe_suffix();
+    let context = TestContext::new("3.12")
+        .with_filtered_exe_suffix()
+        .with_filtered_relative_workspace();
     let tool_dir = context.temp_dir.child("tools");
     let bin_dir = context.temp_dir.child("bin");
 
@@ -725,6 +784,9 @@ fn tool_install_editable_from() {
         entrypoints = [
             { name = "black", install-path = "[TEMP_DIR]/bin/black" },
         ]
+        locked = [
+            { name = "black", editable = "[WORKSPACE_RELATIVE]/scripts/packages/black_editable" },
+        ]
 
         [tool.options]
         exclude-newer = "2024-03-25T00:00:00Z"
@@ -876,6 +938,14 @@ fn tool_install_already_installed() {
             { name = "black", install-path = "[TEMP_DIR]/bin/black" },
             { name = "blackd", install-path = "[TEMP_DIR]/bin/blackd" },
         ]
+        locked = [
+            { name = "black", specifier = "==24.3.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "click", specifier = "==8.1.7", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "mypy-extensions", specifier = "==1.0.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "packaging", specifier = "==24.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "pathspec", specifier = "==0.12.1", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "platformdirs", specifier = "==4.2.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+        ]
 
         [tool.options]
         exclude-newer = "2024-03-25T00:00:00Z"
@@ -912,6 +982,14 @@ fn tool_install_already_installed() {
             { name = "black", install-path = "[TEMP_DIR]/bin/black" },
             { name = "blackd", install-path = "[TEMP_DIR]/bin/blackd" },
         ]
+        locked = [
+            { name = "black", specifier = "==24.3.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "click", specifier = "==8.1.7", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "mypy-extensions", specifier = "==1.0.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "packaging", specifier = "==24.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "pathspec", specifier = "==0.12.1", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "platformdirs", specifier = "==4.2.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+        ]
 
         [tool.options]
         exclude-newer = "2024-03-25T00:00:00Z"
@@ -1199,6 +1277,14 @@ fn tool_install_entry_point_exists() {
             { name = "black", install-path = "[TEMP_DIR]/bin/black" },
             { name = "blackd", install-path = "[TEMP_DIR]/bin/blackd" },
         ]
+        locked = [
+            { name = "black", specifier = "==24.3.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "click", specifier = "==8.1.7", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "mypy-extensions", specifier = "==1.0.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "packaging", specifier = "==24.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "pathspec", specifier = "==0.12.1", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "platformdirs", specifier = "==4.2.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+        ]
 
         [tool.options]
         exclude-newer = "2024-03-25T00:00:00Z"
@@ -1235,6 +1321,14 @@ fn tool_install_entry_point_exists() {
             { name = "black", install-path = "[TEMP_DIR]/bin/black" },
             { name = "blackd", install-path = "[TEMP_DIR]/bin/blackd" },
         ]
+        locked = [
+            { name = "black", specifier = "==24.3.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "click", specifier = "==8.1.7", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "mypy-extensions", specifier = "==1.0.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "packaging", specifier = "==24.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "pathspec", specifier = "==0.12.1", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "platformdirs", specifier = "==4.2.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+        ]
 
         [tool.options]
         exclude-newer = "2024-03-25T00:00:00Z"
@@ -1561,6 +1655,14 @@ fn tool_install_unnamed_package() {
             { name = "black", install-path = "[TEMP_DIR]/bin/black" },
             { name = "blackd", install-path = "[TEMP_DIR]/bin/blackd" },
         ]
+        locked = [
+            { name = "black", url = "https://files.pythonhosted.org/packages/0f/89/294c9a6b6c75a08da55e9d05321d0707e9418735e3062b12ef0f54c33474/black-24.4.2-py3-none-any.whl" },
+            { name = "click", specifier = "==8.1.7", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "mypy-extensions", specifier = "==1.0.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "packaging", specifier = "==24.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "pathspec", specifier = "==0.12.1", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "platformdirs", specifier = "==4.2.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+        ]
 
         [tool.options]
         exclude-newer = "2024-03-25T00:00:00Z"
@@ -1674,6 +1776,14 @@ fn tool_install_unnamed_from() {
             { name = "black", install-path = "[TEMP_DIR]/bin/black" },
             { name = "blackd", install-path = "[TEMP_DIR]/bin/blackd" },
         ]
+        locked = [
+            { name = "black", url = "https://files.pythonhosted.org/packages/0f/89/294c9a6b6c75a08da55e9d05321d0707e9418735e3062b12ef0f54c33474/black-24.4.2-py3-none-any.whl" },
+            { name = "click", specifier = "==8.1.7", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "mypy-extensions", specifier = "==1.0.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "packaging", specifier = "==24.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "pathspec", specifier = "==0.12.1", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "platformdirs", specifier = "==4.2.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+        ]
 
         [tool.options]
         exclude-newer = "2024-03-25T00:00:00Z"
@@ -1766,6 +1876,15 @@ fn tool_install_unnamed_with() {
             { name = "black", install-path = "[TEMP_DIR]/bin/black" },
             { name = "blackd", install-path = "[TEMP_DIR]/bin/blackd" },
         ]
+        locked = [
+            { name = "black", specifier = "==24.3.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "click", specifier = "==8.1.7", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "iniconfig", url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl" },
+            { name = "mypy-extensions", specifier = "==1.0.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "packaging", specifier = "==24.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "pathspec", specifier = "==0.12.1", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "platformdirs", specifier = "==4.2.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+        ]
 
         [tool.options]
         exclude-newer = "2024-03-25T00:00:00Z"
@@ -1835,6 +1954,15 @@ fn tool_install_requirements_txt() {
             { name = "black", install-path = "[TEMP_DIR]/bin/black" },
             { name = "blackd", install-path = "[TEMP_DIR]/bin/blackd" },
         ]
+        locked = [
+            { name = "black", specifier = "==24.3.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "click", specifier = "==8.1.7", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "iniconfig", specifier = "==2.0.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "mypy-extensions", specifier = "==1.0.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "packaging", specifier = "==24.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "pathspec", specifier = "==0.12.1", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "platformdirs", specifier = "==4.2.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+        ]
 
         [tool.options]
         exclude-newer = "2024-03-25T00:00:00Z"
@@ -1880,6 +2008,15 @@ fn tool_install_requirements_txt() {
             { name = "black", install-path = "[TEMP_DIR]/bin/black" },
             { name = "blackd", install-path = "[TEMP_DIR]/bin/blackd" },
         ]
+        locked = [
+            { name = "black", specifier = "==24.3.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "click", specifier = "==8.1.7", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "idna", specifier = "==3.6", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "mypy-extensions", specifier = "==1.0.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "packaging", specifier = "==24.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "pathspec", specifier = "==0.12.1", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "platformdirs", specifier = "==4.2.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+        ]
 
         [tool.options]
         exclude-newer = "2024-03-25T00:00:00Z"
@@ -1944,6 +2081,15 @@ fn tool_install_requirements_txt_arguments() {
             { name = "black", install-path = "[TEMP_DIR]/bin/black" },
             { name = "blackd", install-path = "[TEMP_DIR]/bin/blackd" },
         ]
+        locked = [
+            { name = "black", specifier = "==24.3.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "click", specifier = "==8.1.7", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "idna", specifier = "==3.6", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "mypy-extensions", specifier = "==1.0.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "packaging", specifier = "==24.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "pathspec", specifier = "==0.12.1", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "platformdirs", specifier = "==4.2.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+        ]
 
         [tool.options]
         exclude-newer = "2024-03-25T00:00:00Z"
@@ -2058,6 +2204,14 @@ fn tool_install_upgrade() {
             { name = "black", install-path = "[TEMP_DIR]/bin/black" },
             { name = "blackd", install-path = "[TEMP_DIR]/bin/blackd" },
         ]
+        locked = [
+            { name = "black", specifier = "==24.1.1", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "click", specifier = "==8.1.7", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "mypy-extensions", specifier = "==1.0.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "packaging", specifier = "==24.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "pathspec", specifier = "==0.12.1", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "platformdirs", specifier = "==4.2.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+        ]
 
         [tool.options]
         exclude-newer = "2024-03-25T00:00:00Z"
@@ -2090,6 +2244,14 @@ fn tool_install_upgrade() {
             { name = "black", install-path = "[TEMP_DIR]/bin/black" },
             { name = "blackd", install-path = "[TEMP_DIR]/bin/blackd" },
         ]
+        locked = [
+            { name = "black", specifier = "==24.1.1", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "click", specifier = "==8.1.7", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "mypy-extensions", specifier = "==1.0.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "packaging", specifier = "==24.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "pathspec", specifier = "==0.12.1", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "platformdirs", specifier = "==4.2.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+        ]
 
         [tool.options]
         exclude-newer = "2024-03-25T00:00:00Z"
@@ -2130,6 +2292,15 @@ fn tool_install_upgrade() {
             { name = "black", install-path = "[TEMP_DIR]/bin/black" },
             { name = "blackd", install-path = "[TEMP_DIR]/bin/blackd" },
         ]
+        locked = [
+            { name = "black", specifier = "==24.1.1", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "click", specifier = "==8.1.7", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "iniconfig", url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl" },
+            { name = "mypy-extensions", specifier = "==1.0.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "packaging", specifier = "==24.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "pathspec", specifier = "==0.12.1", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "platformdirs", specifier = "==4.2.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+        ]
 
         [tool.options]
         exclude-newer = "2024-03-25T00:00:00Z"
@@ -2170,6 +2341,14 @@ fn tool_install_upgrade() {
             { name = "black", install-path = "[TEMP_DIR]/bin/black" },
             { name = "blackd", install-path = "[TEMP_DIR]/bin/blackd" },
         ]
+        locked = [
+            { name = "black", specifier = "==24.3.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "click", specifier = "==8.1.7", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "mypy-extensions", specifier = "==1.0.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "packaging", specifier = "==24.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "pathspec", specifier = "==0.12.1", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "platformdirs", specifier = "==4.2.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+        ]
 
         [tool.options]
         exclude-newer = "2024-03-25T00:00:00Z"
@@ -2632,6 +2811,9 @@ fn tool_install_malformed_dist_info() {
         entrypoints = [
             { name = "pybabel", install-path = "[TEMP_DIR]/bin/pybabel" },
         ]
+        locked = [
+            { name = "babel", specifier = "==2.14.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+        ]
 
         [tool.options]
         exclude-newer = "2024-03-25T00:00:00Z"
@@ -2710,6 +2892,15 @@ fn tool_install_settings() {
         entrypoints = [
             { name = "flask", install-path = "[TEMP_DIR]/bin/flask" },
         ]
+        locked = [
+            { name = "blinker", specifier = "==1.7.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "click", specifier = "==8.1.7", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "flask", specifier = "==3.0.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "itsdangerous", specifier = "==2.1.2", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "jinja2", specifier = "==3.1.3", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "markupsafe", specifier = "==2.1.5", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "werkzeug", specifier = "==3.0.1", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+        ]
 
         [tool.options]
         resolution = "lowest-direct"
@@ -2743,6 +2934,15 @@ fn tool_install_settings() {
         entrypoints = [
             { name = "flask", install-path = "[TEMP_DIR]/bin/flask" },
         ]
+        locked = [
+            { name = "blinker", specifier = "==1.7.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "click", specifier = "==8.1.7", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "flask", specifier = "==3.0.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "itsdangerous", specifier = "==2.1.2", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "jinja2", specifier = "==3.1.3", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "markupsafe", specifier = "==2.1.5", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "werkzeug", specifier = "==3.0.1", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+        ]
 
         [tool.options]
         resolution = "highest"
@@ -2783,6 +2983,15 @@ fn tool_install_settings() {
         entrypoints = [
             { name = "flask", install-path = "[TEMP_DIR]/bin/flask" },
         ]
+        locked = [
+            { name = "blinker", specifier = "==1.7.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "click", specifier = "==8.1.7", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "flask", specifier = "==3.0.2", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "itsdangerous", specifier = "==2.1.2", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "jinja2", specifier = "==3.1.3", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "markupsafe", specifier = "==2.1.5", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "werkzeug", specifier = "==3.0.1", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+        ]
 
         [tool.options]
         resolution = "highest"
@@ -2833,6 +3042,14 @@ fn tool_install_at_version() {
             { name = "black", install-path = "[TEMP_DIR]/bin/black" },
             { name = "blackd", install-path = "[TEMP_DIR]/bin/blackd" },
         ]
+        locked = [
+            { name = "black", specifier = "==24.1.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "click", specifier = "==8.1.7", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "mypy-extensions", specifier = "==1.0.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "packaging", specifier = "==24.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "pathspec", specifier = "==0.12.1", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "platformdirs", specifier = "==4.2.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+        ]
 
         [tool.options]
         exclude-newer = "2024-03-25T00:00:00Z"
@@ -2899,6 +3116,14 @@ fn tool_install_at_latest() {
             { name = "black", install-path = "[TEMP_DIR]/bin/black" },
             { name = "blackd", install-path = "[TEMP_DIR]/bin/blackd" },
         ]
+        locked = [
+            { name = "black", specifier = "==24.3.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "click", specifier = "==8.1.7", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "mypy-extensions", specifier = "==1.0.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "packaging", specifier = "==24.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "pathspec", specifier = "==0.12.1", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "platformdirs", specifier = "==4.2.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+        ]
 
         [tool.options]
         exclude-newer = "2024-03-25T00:00:00Z"
@@ -2943,6 +3168,9 @@ fn tool_install_from_at_latest() {
         entrypoints = [
             { name = "pybabel", install-path = "[TEMP_DIR]/bin/pybabel" },
         ]
+        locked = [
+            { name = "babel", specifier = "==2.14.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+        ]
 
         [tool.options]
         exclude-newer = "2024-03-25T00:00:00Z"
@@ -2987,6 +3215,9 @@ fn tool_install_from_at_version() {
         entrypoints = [
             { name = "pybabel", install-path = "[TEMP_DIR]/bin/pybabel" },
         ]
+        locked = [
+            { name = "babel", specifier = "==2.13.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+        ]
 
         [tool.options]
         exclude-newer = "2024-03-25T00:00:00Z"
@@ -3037,6 +3268,14 @@ fn tool_install_at_latest_upgrade() {
             { name = "black", install-path = "[TEMP_DIR]/bin/black" },
             { name = "blackd", install-path = "[TEMP_DIR]/bin/blackd" },
         ]
+        locked = [
+            { name = "black", specifier = "==24.1.1", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "click", specifier = "==8.1.7", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "mypy-extensions", specifier = "==1.0.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "packaging", specifier = "==24.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "pathspec", specifier = "==0.12.1", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "platformdirs", specifier = "==4.2.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+        ]
 
         [tool.options]
         exclude-newer = "2024-03-25T00:00:00Z"
@@ -3069,6 +3308,14 @@ fn tool_install_at_latest_upgrade() {
             { name = "black", install-path = "[TEMP_DIR]/bin/black" },
             { name = "blackd", install-path = "[TEMP_DIR]/bin/blackd" },
         ]
+        locked = [
+            { name = "black", specifier = "==24.1.1", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "click", specifier = "==8.1.7", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "mypy-extensions", specifier = "==1.0.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "packaging", specifier = "==24.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "pathspec", specifier = "==0.12.1", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "platformdirs", specifier = "==4.2.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+        ]
 
         [tool.options]
         exclude-newer = "2024-03-25T00:00:00Z"
@@ -3106,9 +3353,268 @@ fn tool_install_at_latest_upgrade() {
             { name = "black", install-path = "[TEMP_DIR]/bin/black" },
             { name = "blackd", install-path = "[TEMP_DIR]/bin/blackd" },
         ]
+        locked = [
+            { name = "black", specifier = "==24.3.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "click", specifier = "==8.1.7", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "mypy-extensions", specifier = "==1.0.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "packaging", specifier = "==24.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "pathspec", specifier = "==0.12.1", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "platformdirs", specifier = "==4.2.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+        ]
 
         [tool.options]
         exclude-newer = "2024-03-25T00:00:00Z"
         "###);
     });
 }
+
+/// Test installing a tool from the locked requirements in its receipt.
+#[test]
+fn tool_install_frozen() {
+    let context = TestContext::new("3.12")
+        .with_filtered_counts()
+        .with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // `--frozen` requires an existing receipt.
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("black")
+        .arg("--frozen")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `--frozen` was provided, but no receipt was found for `black`
+    "###);
+
+    // Install `black`.
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("black")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + black==24.3.0
+     + click==8.1.7
+     + mypy-extensions==1.0.0
+     + packaging==24.0
+     + pathspec==0.12.1
+     + platformdirs==4.2.0
+    Installed 2 executables: black, blackd
+    "###);
+
+    // Reinstall `black` from the locked requirements.
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("black")
+        .arg("--frozen")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + black==24.3.0
+     + click==8.1.7
+     + mypy-extensions==1.0.0
+     + packaging==24.0
+     + pathspec==0.12.1
+     + platformdirs==4.2.0
+    Installed 2 executables: black, blackd
+    "###);
+
+    // Requesting different requirements with `--frozen` should fail.
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("black==24.2.0")
+        .arg("--frozen")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The requested requirements for `black` do not match those in its receipt; run `uv tool install` without `--frozen` to update them
+    "###);
+}
+
+/// Test installing shell completions for a tool.
+#[test]
+fn tool_install_completions() {
+    let context = TestContext::new("3.12")
+        .with_filtered_counts()
+        .with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+    let config_dir = context.temp_dir.child("config");
+
+    // Install `black` with completions for Fish. `black` is built with Click, which can generate
+    // its own completions; `blackd` fails to start without `aiohttp`, so it's skipped.
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("black")
+        .arg("--install-completions")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::XDG_CONFIG_HOME, config_dir.as_os_str())
+        .env(EnvVars::SHELL, "fish")
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + black==24.3.0
+     + click==8.1.7
+     + mypy-extensions==1.0.0
+     + packaging==24.0
+     + pathspec==0.12.1
+     + platformdirs==4.2.0
+    Installed 2 executables: black, blackd
+    Installed Fish completions for 1 executable: black
+    "###);
+
+    config_dir
+        .child("fish")
+        .child("completions")
+        .child("black.fish")
+        .assert(predicate::path::exists());
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        // The completions should be recorded in the tool receipt.
+        assert_snapshot!(fs_err::read_to_string(tool_dir.join("black").join("uv-receipt.toml")).unwrap(), @r###"
+        [tool]
+        requirements = [{ name = "black" }]
+        entrypoints = [
+            { name = "black", install-path = "[TEMP_DIR]/bin/black" },
+            { name = "blackd", install-path = "[TEMP_DIR]/bin/blackd" },
+        ]
+        completions = [
+            "[TEMP_DIR]/config/fish/completions/black.fish",
+        ]
+        locked = [
+            { name = "black", specifier = "==24.3.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "click", specifier = "==8.1.7", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "mypy-extensions", specifier = "==1.0.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "packaging", specifier = "==24.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "pathspec", specifier = "==0.12.1", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "platformdirs", specifier = "==4.2.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+        ]
+
+        [tool.options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+        "###);
+    });
+
+    // Uninstalling the tool should remove the completions.
+    context
+        .tool_uninstall()
+        .arg("black")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    config_dir
+        .child("fish")
+        .child("completions")
+        .child("black.fish")
+        .assert(predicate::path::missing());
+}
+
+/// Test that uv doesn't probe executables for completions if the tool doesn't declare a supported
+/// command-line framework as a dependency, even if one is installed transitively.
+#[test]
+fn tool_install_completions_undeclared() -> Result<()> {
+    let context = TestContext::new("3.12")
+        .with_filtered_counts()
+        .with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+    let config_dir = context.temp_dir.child("config");
+
+    // Create a tool that depends on Click transitively (via `black`), and that records whether its
+    // executable was run.
+    let foo = context.temp_dir.child("foo");
+    foo.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "foo"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["black==24.3.0"]
+
+        [project.scripts]
+        foo = "foo:main"
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+        "#
+    })?;
+    foo.child("src")
+        .child("foo")
+        .child("__init__.py")
+        .write_str(indoc! {r#"
+        import pathlib
+
+        def main():
+            pathlib.Path(__file__).parent.joinpath("ran").touch()
+        "#
+        })?;
+
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("-e")
+        .arg(foo.path())
+        .arg("--install-completions")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::XDG_CONFIG_HOME, config_dir.as_os_str())
+        .env(EnvVars::SHELL, "fish")
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + black==24.3.0
+     + click==8.1.7
+     + foo==0.1.0 (from file://[TEMP_DIR]/foo)
+     + mypy-extensions==1.0.0
+     + packaging==24.0
+     + pathspec==0.12.1
+     + platformdirs==4.2.0
+    Installed 1 executable: foo
+    warning: `foo` does not provide shell completions that uv can generate
+    "###);
+
+    // The executable should not have been run.
+    foo.child("src")
+        .child("foo")
+        .child("ran")
+        .assert(predicate::path::missing());
+
+    Ok(())
+}
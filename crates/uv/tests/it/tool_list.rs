@@ -189,6 +189,14 @@ fn tool_list_deprecated() -> Result<()> {
             { name = "black", install-path = "[TEMP_DIR]/bin/black" },
             { name = "blackd", install-path = "[TEMP_DIR]/bin/blackd" },
         ]
+        locked = [
+            { name = "black", specifier = "==24.2.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "click", specifier = "==8.1.7", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "mypy-extensions", specifier = "==1.0.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "packaging", specifier = "==24.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "pathspec", specifier = "==0.12.1", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "platformdirs", specifier = "==4.2.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+        ]
 
         [tool.options]
         exclude-newer = "2024-03-25T00:00:00Z"
@@ -0,0 +1,280 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+use indoc::indoc;
+use insta::assert_snapshot;
+
+use uv_static::EnvVars;
+
+use crate::common::{copy_dir_all, uv_snapshot, TestContext};
+
+#[test]
+fn tool_sync() {
+    let context = TestContext::new("3.12")
+        .with_filtered_counts()
+        .with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // Install `black`.
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("black")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + black==24.3.0
+     + click==8.1.7
+     + mypy-extensions==1.0.0
+     + packaging==24.0
+     + pathspec==0.12.1
+     + platformdirs==4.2.0
+    Installed 2 executables: black, blackd
+    "###);
+
+    // Sync `black`, which should recreate the environment from the locked requirements.
+    uv_snapshot!(context.filters(), context.tool_sync()
+        .arg("black")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + black==24.3.0
+     + click==8.1.7
+     + mypy-extensions==1.0.0
+     + packaging==24.0
+     + pathspec==0.12.1
+     + platformdirs==4.2.0
+    Installed 2 executables: black, blackd
+    "###);
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        // The receipt should be unchanged.
+        assert_snapshot!(fs_err::read_to_string(tool_dir.join("black").join("uv-receipt.toml")).unwrap(), @r###"
+        [tool]
+        requirements = [{ name = "black" }]
+        entrypoints = [
+            { name = "black", install-path = "[TEMP_DIR]/bin/black" },
+            { name = "blackd", install-path = "[TEMP_DIR]/bin/blackd" },
+        ]
+        locked = [
+            { name = "black", specifier = "==24.3.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "click", specifier = "==8.1.7", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "mypy-extensions", specifier = "==1.0.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "packaging", specifier = "==24.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "pathspec", specifier = "==0.12.1", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+            { name = "platformdirs", specifier = "==4.2.0", index = "https://pypi.org/simple", hashes = ["[HASHES]"] },
+        ]
+
+        [tool.options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+        "###);
+    });
+}
+
+#[test]
+fn tool_sync_not_installed() {
+    let context = TestContext::new("3.12")
+        .with_filtered_counts()
+        .with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // Attempt to sync `black`.
+    uv_snapshot!(context.filters(), context.tool_sync()
+        .arg("black")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    `black` is not installed; run `uv tool install black` to install
+    "###);
+
+    // Attempt to sync all.
+    uv_snapshot!(context.filters(), context.tool_sync()
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Nothing to sync
+    "###);
+}
+
+#[test]
+fn tool_sync_legacy_receipt() -> Result<()> {
+    let context = TestContext::new("3.12")
+        .with_filtered_counts()
+        .with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // Install `black`.
+    context
+        .tool_install()
+        .arg("black")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    // Replace with a receipt that predates locked requirements.
+    tool_dir
+        .child("black")
+        .child("uv-receipt.toml")
+        .write_str(indoc! {r#"
+            [tool]
+            requirements = [{ name = "black" }]
+            entrypoints = [
+                { name = "black", install-path = "[TEMP_DIR]/bin/black" },
+                { name = "blackd", install-path = "[TEMP_DIR]/bin/blackd" },
+            ]
+        "#})?;
+
+    uv_snapshot!(context.filters(), context.tool_sync()
+        .arg("black")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    The receipt for `black` does not include locked requirements; run `uv tool install --reinstall black` to record them
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn tool_sync_editable() -> Result<()> {
+    let context = TestContext::new("3.12")
+        .with_filtered_counts()
+        .with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    copy_dir_all(
+        context
+            .workspace_root
+            .join("scripts/packages/black_editable"),
+        context.temp_dir.child("black_editable"),
+    )?;
+
+    // Install `black` as an editable.
+    context
+        .tool_install()
+        .arg("-e")
+        .arg("./black_editable")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        // The locked editable should be recorded relative to the receipt.
+        assert_snapshot!(fs_err::read_to_string(tool_dir.join("black").join("uv-receipt.toml")).unwrap(), @r###"
+        [tool]
+        requirements = [{ name = "black", editable = "[TEMP_DIR]/black_editable" }]
+        entrypoints = [
+            { name = "black", install-path = "[TEMP_DIR]/bin/black" },
+        ]
+        locked = [
+            { name = "black", editable = "../../black_editable" },
+        ]
+
+        [tool.options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+        "###);
+    });
+
+    // Sync `black`, which should resolve the editable relative to the receipt.
+    uv_snapshot!(context.filters(), context.tool_sync()
+        .arg("black")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + black==0.1.0 (from file://[TEMP_DIR]/black_editable)
+    Installed 1 executable: black
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn tool_sync_hash_mismatch() -> Result<()> {
+    let context = TestContext::new("3.12")
+        .with_filtered_counts()
+        .with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // Install `black`.
+    context
+        .tool_install()
+        .arg("black")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    // Replace the recorded hashes for `click` with an incorrect hash.
+    let receipt = tool_dir.child("black").child("uv-receipt.toml");
+    let contents = fs_err::read_to_string(&receipt)?;
+    let contents = regex::Regex::new(r#"(\{ name = "click", [^\n]*hashes = )\[[^\]]*\]"#)?.replace(
+        &contents,
+        r#"$1["sha256:0000000000000000000000000000000000000000000000000000000000000000"]"#,
+    );
+    receipt.write_str(&contents)?;
+
+    // Syncing should fail, since the downloaded distribution doesn't match the recorded hash.
+    uv_snapshot!(context.filters(), context.tool_sync()
+        .arg("black")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Failed to prepare distributions
+    "###);
+
+    Ok(())
+}
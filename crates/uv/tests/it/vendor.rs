@@ -0,0 +1,161 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+use indoc::indoc;
+use predicates::prelude::predicate;
+use url::Url;
+
+use crate::common::{uv_snapshot, TestContext};
+
+/// Vendor the locked distributions into a local index, then install from it offline.
+#[test]
+fn vendor() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+        "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.vendor(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Vendored 1 package (2 files) to: vendor
+    "###);
+
+    // The index should follow the PEP 503 layout.
+    let vendor = context.temp_dir.child("vendor");
+    vendor.child("index.html").assert(predicate::str::contains(
+        r#"<a href="iniconfig/">iniconfig</a>"#,
+    ));
+    vendor
+        .child("iniconfig")
+        .child("index.html")
+        .assert(predicate::str::contains(
+            r#"<a href="iniconfig-2.0.0-py3-none-any.whl#sha256="#,
+        ));
+    vendor
+        .child("iniconfig")
+        .child("iniconfig-2.0.0-py3-none-any.whl")
+        .assert(predicate::path::is_file());
+    vendor
+        .child("iniconfig")
+        .child("iniconfig-2.0.0.tar.gz")
+        .assert(predicate::path::is_file());
+
+    // The vendored index should be usable without network access.
+    let index_url = Url::from_directory_path(vendor.path()).unwrap();
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--offline")
+        .arg("--index-url")
+        .arg(index_url.as_str())
+        .arg("iniconfig"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###);
+
+    Ok(())
+}
+
+/// Re-download vendored files whose contents no longer match the lockfile.
+#[test]
+fn vendor_corrupted() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+        "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.vendor(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Vendored 1 package (2 files) to: vendor
+    "###);
+
+    // Truncate one of the vendored files.
+    let wheel = context
+        .temp_dir
+        .child("vendor")
+        .child("iniconfig")
+        .child("iniconfig-2.0.0-py3-none-any.whl");
+    let contents = fs_err::read(wheel.path())?;
+    wheel.write_binary(&contents[..contents.len() / 2])?;
+
+    uv_snapshot!(context.filters(), context.vendor(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Vendored 1 package (2 files) to: vendor
+    "###);
+
+    // The file should have been restored.
+    assert_eq!(fs_err::read(wheel.path())?, contents);
+
+    Ok(())
+}
+
+/// Warn about distributions that aren't from a registry, which can't be vendored.
+#[test]
+fn vendor_direct_url() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl",
+            "anyio==4.3.0",
+        ]
+        "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.vendor().arg("--output-dir").arg("mirror"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    warning: `iniconfig` is not from a registry and will not be vendored
+    Vendored 3 packages (6 files) to: mirror
+    "###);
+
+    context
+        .temp_dir
+        .child("mirror")
+        .child("iniconfig")
+        .assert(predicate::path::missing());
+
+    Ok(())
+}
@@ -1905,3 +1905,433 @@ fn transitive_dep_in_git_workspace_with_root() -> Result<()> {
 
     Ok(())
 }
+
+/// List the members of a workspace with `uv workspace list`.
+#[test]
+fn workspace_list() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "albatross"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["bird-feeder"]
+
+        [tool.uv.sources]
+        bird-feeder = { workspace = true }
+
+        [tool.uv.workspace]
+        members = ["packages/*"]
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+    "#})?;
+
+    context
+        .temp_dir
+        .child("packages")
+        .child("bird-feeder")
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "bird-feeder"
+        version = "1.0.0"
+        requires-python = ">=3.11"
+        dependencies = []
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.workspace_list(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    albatross    v0.1.0  . (requires-python: >=3.12) (root)
+    bird-feeder  v1.0.0  packages/bird-feeder (requires-python: >=3.11) (virtual)
+
+    ----- stderr -----
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.workspace_list().arg("--output-format").arg("json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [
+      {
+        "name": "albatross",
+        "version": "0.1.0",
+        "path": "[TEMP_DIR]/",
+        "requires_python": ">=3.12",
+        "root": true,
+        "virtual": false
+      },
+      {
+        "name": "bird-feeder",
+        "version": "1.0.0",
+        "path": "[TEMP_DIR]/packages/bird-feeder",
+        "requires_python": ">=3.11",
+        "root": false,
+        "virtual": true
+      }
+    ]
+
+    ----- stderr -----
+    "###
+    );
+
+    Ok(())
+}
+
+/// Display the dependencies between workspace members with `uv workspace graph`.
+#[test]
+fn workspace_graph() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "albatross"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["bird-feeder", "iniconfig"]
+
+        [tool.uv.sources]
+        bird-feeder = { workspace = true }
+
+        [tool.uv.workspace]
+        members = ["packages/*"]
+    "#})?;
+
+    context
+        .temp_dir
+        .child("packages")
+        .child("bird-feeder")
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "bird-feeder"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [project.optional-dependencies]
+        seeds = ["seeds"]
+
+        [tool.uv.sources]
+        seeds = { workspace = true }
+    "#})?;
+
+    context
+        .temp_dir
+        .child("packages")
+        .child("seeds")
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "seeds"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.workspace_graph(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    albatross -> bird-feeder
+    bird-feeder -> seeds
+    seeds
+
+    ----- stderr -----
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.workspace_graph().arg("--output-format").arg("dot"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    digraph {
+        "albatross";
+        "bird-feeder";
+        "seeds";
+        "albatross" -> "bird-feeder";
+        "bird-feeder" -> "seeds";
+    }
+
+    ----- stderr -----
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.workspace_graph().arg("--output-format").arg("json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [
+      {
+        "name": "albatross",
+        "path": "[TEMP_DIR]/",
+        "dependencies": [
+          "bird-feeder"
+        ],
+        "dependents": []
+      },
+      {
+        "name": "bird-feeder",
+        "path": "[TEMP_DIR]/packages/bird-feeder",
+        "dependencies": [
+          "seeds"
+        ],
+        "dependents": [
+          "albatross"
+        ]
+      },
+      {
+        "name": "seeds",
+        "path": "[TEMP_DIR]/packages/seeds",
+        "dependencies": [],
+        "dependents": [
+          "bird-feeder"
+        ]
+      }
+    ]
+
+    ----- stderr -----
+    "###
+    );
+
+    Ok(())
+}
+
+/// Bump the version of a workspace member, updating any dependencies on it.
+#[test]
+fn workspace_version_bump() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "albatross"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["bird-feeder>=1.0.0", "iniconfig"]
+
+        [dependency-groups]
+        dev = ["bird-feeder==1.0.0"]
+
+        [tool.uv.sources]
+        bird-feeder = { workspace = true }
+
+        [tool.uv.workspace]
+        members = ["packages/*"]
+    "#})?;
+
+    context
+        .temp_dir
+        .child("packages")
+        .child("bird-feeder")
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "bird-feeder"
+        version = "1.0.0" # The current version.
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    // Display the version of the member.
+    uv_snapshot!(context.filters(), context.version().arg("--package").arg("bird-feeder"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    bird-feeder 1.0.0
+
+    ----- stderr -----
+    "###
+    );
+
+    // Bump the minor version of the member.
+    uv_snapshot!(context.filters(), context.version().arg("--bump").arg("minor").arg("--package").arg("bird-feeder"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    bird-feeder 1.0.0 => 1.1.0
+
+    ----- stderr -----
+    Updated dependency on `bird-feeder` in: pyproject.toml
+    "###
+    );
+
+    let pyproject_toml = fs_err::read_to_string(
+        context
+            .temp_dir
+            .join("packages")
+            .join("bird-feeder")
+            .join("pyproject.toml"),
+    )?;
+    assert_snapshot!(pyproject_toml, @r###"
+    [project]
+    name = "bird-feeder"
+    version = "1.1.0" # The current version.
+    requires-python = ">=3.12"
+    dependencies = []
+    "###
+    );
+
+    let pyproject_toml = fs_err::read_to_string(context.temp_dir.join("pyproject.toml"))?;
+    assert_snapshot!(pyproject_toml, @r###"
+    [project]
+    name = "albatross"
+    version = "0.1.0"
+    requires-python = ">=3.12"
+    dependencies = ["bird-feeder>=1.1.0", "iniconfig"]
+
+    [dependency-groups]
+    dev = ["bird-feeder==1.1.0"]
+
+    [tool.uv.sources]
+    bird-feeder = { workspace = true }
+
+    [tool.uv.workspace]
+    members = ["packages/*"]
+    "###
+    );
+
+    // Preview setting all members to the same version, in lockstep.
+    uv_snapshot!(context.filters(), context.version().arg("2.0.0").arg("--all-packages").arg("--dry-run"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    albatross 0.1.0 => 2.0.0
+    bird-feeder 1.1.0 => 2.0.0
+
+    ----- stderr -----
+    Would update dependency on `bird-feeder` in: pyproject.toml
+    "###
+    );
+
+    Ok(())
+}
+
+/// Update the versions of all members from a virtual workspace root.
+#[test]
+fn workspace_version_virtual_root() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [tool.uv.workspace]
+        members = ["packages/*"]
+    "#})?;
+
+    context
+        .temp_dir
+        .child("packages")
+        .child("albatross")
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "albatross"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["bird-feeder>=1.0.0"]
+
+        [tool.uv.sources]
+        bird-feeder = { workspace = true }
+    "#})?;
+
+    context
+        .temp_dir
+        .child("packages")
+        .child("bird-feeder")
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "bird-feeder"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    context.lock().assert().success();
+
+    // Display the versions of all members.
+    uv_snapshot!(context.filters(), context.version().arg("--all-packages"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    albatross 0.1.0
+    bird-feeder 1.0.0
+
+    ----- stderr -----
+    "###
+    );
+
+    // Bumping in lockstep requires a member to bump from.
+    uv_snapshot!(context.filters(), context.version().arg("--bump").arg("patch").arg("--all-packages"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `--bump` with `--all-packages` requires `--package`, to select the workspace member whose version is bumped
+    "###
+    );
+
+    // Set all members to the same version; the lockfile is now out of date.
+    uv_snapshot!(context.filters(), context.version().arg("2.0.0").arg("--all-packages"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    albatross 0.1.0 => 2.0.0
+    bird-feeder 1.0.0 => 2.0.0
+
+    ----- stderr -----
+    Updated dependency on `bird-feeder` in: packages/albatross/pyproject.toml
+    warning: `uv.lock` is out of date with the new version; run `uv lock` to update it
+    "###
+    );
+
+    let pyproject_toml = fs_err::read_to_string(
+        context
+            .temp_dir
+            .join("packages")
+            .join("albatross")
+            .join("pyproject.toml"),
+    )?;
+    assert_snapshot!(pyproject_toml, @r###"
+    [project]
+    name = "albatross"
+    version = "2.0.0"
+    requires-python = ">=3.12"
+    dependencies = ["bird-feeder>=2.0.0"]
+
+    [tool.uv.sources]
+    bird-feeder = { workspace = true }
+    "###
+    );
+
+    // Bump from a specific member.
+    uv_snapshot!(context.filters(), context.version().arg("--bump").arg("minor").arg("--all-packages").arg("--package").arg("bird-feeder").arg("--dry-run"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    albatross 2.0.0 => 2.1.0
+    bird-feeder 2.0.0 => 2.1.0
+
+    ----- stderr -----
+    Would update dependency on `bird-feeder` in: packages/albatross/pyproject.toml
+    "###
+    );
+
+    Ok(())
+}